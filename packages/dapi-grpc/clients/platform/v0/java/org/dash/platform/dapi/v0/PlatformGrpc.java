@@ -201,6 +201,68 @@ public final class PlatformGrpc {
     return getGetIdentityBalanceAndRevisionMethod;
   }
 
+  private static volatile io.grpc.MethodDescriptor<org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityRequest,
+      org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityNonceResponse> getGetIdentityNonceMethod;
+
+  @io.grpc.stub.annotations.RpcMethod(
+      fullMethodName = SERVICE_NAME + '/' + "getIdentityNonce",
+      requestType = org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityRequest.class,
+      responseType = org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityNonceResponse.class,
+      methodType = io.grpc.MethodDescriptor.MethodType.UNARY)
+  public static io.grpc.MethodDescriptor<org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityRequest,
+      org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityNonceResponse> getGetIdentityNonceMethod() {
+    io.grpc.MethodDescriptor<org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityRequest, org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityNonceResponse> getGetIdentityNonceMethod;
+    if ((getGetIdentityNonceMethod = PlatformGrpc.getGetIdentityNonceMethod) == null) {
+      synchronized (PlatformGrpc.class) {
+        if ((getGetIdentityNonceMethod = PlatformGrpc.getGetIdentityNonceMethod) == null) {
+          PlatformGrpc.getGetIdentityNonceMethod = getGetIdentityNonceMethod =
+              io.grpc.MethodDescriptor.<org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityRequest, org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityNonceResponse>newBuilder()
+              .setType(io.grpc.MethodDescriptor.MethodType.UNARY)
+              .setFullMethodName(generateFullMethodName(SERVICE_NAME, "getIdentityNonce"))
+              .setSampledToLocalTracing(true)
+              .setRequestMarshaller(io.grpc.protobuf.ProtoUtils.marshaller(
+                  org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityRequest.getDefaultInstance()))
+              .setResponseMarshaller(io.grpc.protobuf.ProtoUtils.marshaller(
+                  org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityNonceResponse.getDefaultInstance()))
+              .setSchemaDescriptor(new PlatformMethodDescriptorSupplier("getIdentityNonce"))
+              .build();
+        }
+      }
+    }
+    return getGetIdentityNonceMethod;
+  }
+
+  private static volatile io.grpc.MethodDescriptor<org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityContractNonceRequest,
+      org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityContractNonceResponse> getGetIdentityContractNonceMethod;
+
+  @io.grpc.stub.annotations.RpcMethod(
+      fullMethodName = SERVICE_NAME + '/' + "getIdentityContractNonce",
+      requestType = org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityContractNonceRequest.class,
+      responseType = org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityContractNonceResponse.class,
+      methodType = io.grpc.MethodDescriptor.MethodType.UNARY)
+  public static io.grpc.MethodDescriptor<org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityContractNonceRequest,
+      org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityContractNonceResponse> getGetIdentityContractNonceMethod() {
+    io.grpc.MethodDescriptor<org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityContractNonceRequest, org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityContractNonceResponse> getGetIdentityContractNonceMethod;
+    if ((getGetIdentityContractNonceMethod = PlatformGrpc.getGetIdentityContractNonceMethod) == null) {
+      synchronized (PlatformGrpc.class) {
+        if ((getGetIdentityContractNonceMethod = PlatformGrpc.getGetIdentityContractNonceMethod) == null) {
+          PlatformGrpc.getGetIdentityContractNonceMethod = getGetIdentityContractNonceMethod =
+              io.grpc.MethodDescriptor.<org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityContractNonceRequest, org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityContractNonceResponse>newBuilder()
+              .setType(io.grpc.MethodDescriptor.MethodType.UNARY)
+              .setFullMethodName(generateFullMethodName(SERVICE_NAME, "getIdentityContractNonce"))
+              .setSampledToLocalTracing(true)
+              .setRequestMarshaller(io.grpc.protobuf.ProtoUtils.marshaller(
+                  org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityContractNonceRequest.getDefaultInstance()))
+              .setResponseMarshaller(io.grpc.protobuf.ProtoUtils.marshaller(
+                  org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityContractNonceResponse.getDefaultInstance()))
+              .setSchemaDescriptor(new PlatformMethodDescriptorSupplier("getIdentityContractNonce"))
+              .build();
+        }
+      }
+    }
+    return getGetIdentityContractNonceMethod;
+  }
+
   private static volatile io.grpc.MethodDescriptor<org.dash.platform.dapi.v0.PlatformOuterClass.GetProofsRequest,
       org.dash.platform.dapi.v0.PlatformOuterClass.GetProofsResponse> getGetProofsMethod;
 
@@ -480,6 +542,68 @@ public final class PlatformGrpc {
     return getGetConsensusParamsMethod;
   }
 
+  private static volatile io.grpc.MethodDescriptor<org.dash.platform.dapi.v0.PlatformOuterClass.GetBlockAttestationRequest,
+      org.dash.platform.dapi.v0.PlatformOuterClass.GetBlockAttestationResponse> getGetBlockAttestationMethod;
+
+  @io.grpc.stub.annotations.RpcMethod(
+      fullMethodName = SERVICE_NAME + '/' + "getBlockAttestation",
+      requestType = org.dash.platform.dapi.v0.PlatformOuterClass.GetBlockAttestationRequest.class,
+      responseType = org.dash.platform.dapi.v0.PlatformOuterClass.GetBlockAttestationResponse.class,
+      methodType = io.grpc.MethodDescriptor.MethodType.UNARY)
+  public static io.grpc.MethodDescriptor<org.dash.platform.dapi.v0.PlatformOuterClass.GetBlockAttestationRequest,
+      org.dash.platform.dapi.v0.PlatformOuterClass.GetBlockAttestationResponse> getGetBlockAttestationMethod() {
+    io.grpc.MethodDescriptor<org.dash.platform.dapi.v0.PlatformOuterClass.GetBlockAttestationRequest, org.dash.platform.dapi.v0.PlatformOuterClass.GetBlockAttestationResponse> getGetBlockAttestationMethod;
+    if ((getGetBlockAttestationMethod = PlatformGrpc.getGetBlockAttestationMethod) == null) {
+      synchronized (PlatformGrpc.class) {
+        if ((getGetBlockAttestationMethod = PlatformGrpc.getGetBlockAttestationMethod) == null) {
+          PlatformGrpc.getGetBlockAttestationMethod = getGetBlockAttestationMethod =
+              io.grpc.MethodDescriptor.<org.dash.platform.dapi.v0.PlatformOuterClass.GetBlockAttestationRequest, org.dash.platform.dapi.v0.PlatformOuterClass.GetBlockAttestationResponse>newBuilder()
+              .setType(io.grpc.MethodDescriptor.MethodType.UNARY)
+              .setFullMethodName(generateFullMethodName(SERVICE_NAME, "getBlockAttestation"))
+              .setSampledToLocalTracing(true)
+              .setRequestMarshaller(io.grpc.protobuf.ProtoUtils.marshaller(
+                  org.dash.platform.dapi.v0.PlatformOuterClass.GetBlockAttestationRequest.getDefaultInstance()))
+              .setResponseMarshaller(io.grpc.protobuf.ProtoUtils.marshaller(
+                  org.dash.platform.dapi.v0.PlatformOuterClass.GetBlockAttestationResponse.getDefaultInstance()))
+              .setSchemaDescriptor(new PlatformMethodDescriptorSupplier("getBlockAttestation"))
+              .build();
+        }
+      }
+    }
+    return getGetBlockAttestationMethod;
+  }
+
+  private static volatile io.grpc.MethodDescriptor<org.dash.platform.dapi.v0.PlatformOuterClass.EstimateFeeRequest,
+      org.dash.platform.dapi.v0.PlatformOuterClass.EstimateFeeResponse> getEstimateFeeMethod;
+
+  @io.grpc.stub.annotations.RpcMethod(
+      fullMethodName = SERVICE_NAME + '/' + "estimateFee",
+      requestType = org.dash.platform.dapi.v0.PlatformOuterClass.EstimateFeeRequest.class,
+      responseType = org.dash.platform.dapi.v0.PlatformOuterClass.EstimateFeeResponse.class,
+      methodType = io.grpc.MethodDescriptor.MethodType.UNARY)
+  public static io.grpc.MethodDescriptor<org.dash.platform.dapi.v0.PlatformOuterClass.EstimateFeeRequest,
+      org.dash.platform.dapi.v0.PlatformOuterClass.EstimateFeeResponse> getEstimateFeeMethod() {
+    io.grpc.MethodDescriptor<org.dash.platform.dapi.v0.PlatformOuterClass.EstimateFeeRequest, org.dash.platform.dapi.v0.PlatformOuterClass.EstimateFeeResponse> getEstimateFeeMethod;
+    if ((getEstimateFeeMethod = PlatformGrpc.getEstimateFeeMethod) == null) {
+      synchronized (PlatformGrpc.class) {
+        if ((getEstimateFeeMethod = PlatformGrpc.getEstimateFeeMethod) == null) {
+          PlatformGrpc.getEstimateFeeMethod = getEstimateFeeMethod =
+              io.grpc.MethodDescriptor.<org.dash.platform.dapi.v0.PlatformOuterClass.EstimateFeeRequest, org.dash.platform.dapi.v0.PlatformOuterClass.EstimateFeeResponse>newBuilder()
+              .setType(io.grpc.MethodDescriptor.MethodType.UNARY)
+              .setFullMethodName(generateFullMethodName(SERVICE_NAME, "estimateFee"))
+              .setSampledToLocalTracing(true)
+              .setRequestMarshaller(io.grpc.protobuf.ProtoUtils.marshaller(
+                  org.dash.platform.dapi.v0.PlatformOuterClass.EstimateFeeRequest.getDefaultInstance()))
+              .setResponseMarshaller(io.grpc.protobuf.ProtoUtils.marshaller(
+                  org.dash.platform.dapi.v0.PlatformOuterClass.EstimateFeeResponse.getDefaultInstance()))
+              .setSchemaDescriptor(new PlatformMethodDescriptorSupplier("estimateFee"))
+              .build();
+        }
+      }
+    }
+    return getEstimateFeeMethod;
+  }
+
   /**
    * Creates a new async stub that supports all call types for the service
    */
@@ -573,6 +697,20 @@ public final class PlatformGrpc {
       io.grpc.stub.ServerCalls.asyncUnimplementedUnaryCall(getGetIdentityBalanceAndRevisionMethod(), responseObserver);
     }
 
+    /**
+     */
+    public void getIdentityNonce(org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityRequest request,
+        io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityNonceResponse> responseObserver) {
+      io.grpc.stub.ServerCalls.asyncUnimplementedUnaryCall(getGetIdentityNonceMethod(), responseObserver);
+    }
+
+    /**
+     */
+    public void getIdentityContractNonce(org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityContractNonceRequest request,
+        io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityContractNonceResponse> responseObserver) {
+      io.grpc.stub.ServerCalls.asyncUnimplementedUnaryCall(getGetIdentityContractNonceMethod(), responseObserver);
+    }
+
     /**
      */
     public void getProofs(org.dash.platform.dapi.v0.PlatformOuterClass.GetProofsRequest request,
@@ -636,6 +774,20 @@ public final class PlatformGrpc {
       io.grpc.stub.ServerCalls.asyncUnimplementedUnaryCall(getGetConsensusParamsMethod(), responseObserver);
     }
 
+    /**
+     */
+    public void getBlockAttestation(org.dash.platform.dapi.v0.PlatformOuterClass.GetBlockAttestationRequest request,
+        io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.PlatformOuterClass.GetBlockAttestationResponse> responseObserver) {
+      io.grpc.stub.ServerCalls.asyncUnimplementedUnaryCall(getGetBlockAttestationMethod(), responseObserver);
+    }
+
+    /**
+     */
+    public void estimateFee(org.dash.platform.dapi.v0.PlatformOuterClass.EstimateFeeRequest request,
+        io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.PlatformOuterClass.EstimateFeeResponse> responseObserver) {
+      io.grpc.stub.ServerCalls.asyncUnimplementedUnaryCall(getEstimateFeeMethod(), responseObserver);
+    }
+
     @java.lang.Override public final io.grpc.ServerServiceDefinition bindService() {
       return io.grpc.ServerServiceDefinition.builder(getServiceDescriptor())
           .addMethod(
@@ -680,6 +832,20 @@ public final class PlatformGrpc {
                 org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityRequest,
                 org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityBalanceAndRevisionResponse>(
                   this, METHODID_GET_IDENTITY_BALANCE_AND_REVISION)))
+          .addMethod(
+            getGetIdentityNonceMethod(),
+            io.grpc.stub.ServerCalls.asyncUnaryCall(
+              new MethodHandlers<
+                org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityRequest,
+                org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityNonceResponse>(
+                  this, METHODID_GET_IDENTITY_NONCE)))
+          .addMethod(
+            getGetIdentityContractNonceMethod(),
+            io.grpc.stub.ServerCalls.asyncUnaryCall(
+              new MethodHandlers<
+                org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityContractNonceRequest,
+                org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityContractNonceResponse>(
+                  this, METHODID_GET_IDENTITY_CONTRACT_NONCE)))
           .addMethod(
             getGetProofsMethod(),
             io.grpc.stub.ServerCalls.asyncUnaryCall(
@@ -743,6 +909,20 @@ public final class PlatformGrpc {
                 org.dash.platform.dapi.v0.PlatformOuterClass.GetConsensusParamsRequest,
                 org.dash.platform.dapi.v0.PlatformOuterClass.GetConsensusParamsResponse>(
                   this, METHODID_GET_CONSENSUS_PARAMS)))
+          .addMethod(
+            getGetBlockAttestationMethod(),
+            io.grpc.stub.ServerCalls.asyncUnaryCall(
+              new MethodHandlers<
+                org.dash.platform.dapi.v0.PlatformOuterClass.GetBlockAttestationRequest,
+                org.dash.platform.dapi.v0.PlatformOuterClass.GetBlockAttestationResponse>(
+                  this, METHODID_GET_BLOCK_ATTESTATION)))
+          .addMethod(
+            getEstimateFeeMethod(),
+            io.grpc.stub.ServerCalls.asyncUnaryCall(
+              new MethodHandlers<
+                org.dash.platform.dapi.v0.PlatformOuterClass.EstimateFeeRequest,
+                org.dash.platform.dapi.v0.PlatformOuterClass.EstimateFeeResponse>(
+                  this, METHODID_ESTIMATE_FEE)))
           .build();
     }
   }
@@ -812,6 +992,22 @@ public final class PlatformGrpc {
           getChannel().newCall(getGetIdentityBalanceAndRevisionMethod(), getCallOptions()), request, responseObserver);
     }
 
+    /**
+     */
+    public void getIdentityNonce(org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityRequest request,
+        io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityNonceResponse> responseObserver) {
+      io.grpc.stub.ClientCalls.asyncUnaryCall(
+          getChannel().newCall(getGetIdentityNonceMethod(), getCallOptions()), request, responseObserver);
+    }
+
+    /**
+     */
+    public void getIdentityContractNonce(org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityContractNonceRequest request,
+        io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityContractNonceResponse> responseObserver) {
+      io.grpc.stub.ClientCalls.asyncUnaryCall(
+          getChannel().newCall(getGetIdentityContractNonceMethod(), getCallOptions()), request, responseObserver);
+    }
+
     /**
      */
     public void getProofs(org.dash.platform.dapi.v0.PlatformOuterClass.GetProofsRequest request,
@@ -883,6 +1079,22 @@ public final class PlatformGrpc {
       io.grpc.stub.ClientCalls.asyncUnaryCall(
           getChannel().newCall(getGetConsensusParamsMethod(), getCallOptions()), request, responseObserver);
     }
+
+    /**
+     */
+    public void getBlockAttestation(org.dash.platform.dapi.v0.PlatformOuterClass.GetBlockAttestationRequest request,
+        io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.PlatformOuterClass.GetBlockAttestationResponse> responseObserver) {
+      io.grpc.stub.ClientCalls.asyncUnaryCall(
+          getChannel().newCall(getGetBlockAttestationMethod(), getCallOptions()), request, responseObserver);
+    }
+
+    /**
+     */
+    public void estimateFee(org.dash.platform.dapi.v0.PlatformOuterClass.EstimateFeeRequest request,
+        io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.PlatformOuterClass.EstimateFeeResponse> responseObserver) {
+      io.grpc.stub.ClientCalls.asyncUnaryCall(
+          getChannel().newCall(getEstimateFeeMethod(), getCallOptions()), request, responseObserver);
+    }
   }
 
   /**
@@ -944,6 +1156,20 @@ public final class PlatformGrpc {
           getChannel(), getGetIdentityBalanceAndRevisionMethod(), getCallOptions(), request);
     }
 
+    /**
+     */
+    public org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityNonceResponse getIdentityNonce(org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityRequest request) {
+      return io.grpc.stub.ClientCalls.blockingUnaryCall(
+          getChannel(), getGetIdentityNonceMethod(), getCallOptions(), request);
+    }
+
+    /**
+     */
+    public org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityContractNonceResponse getIdentityContractNonce(org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityContractNonceRequest request) {
+      return io.grpc.stub.ClientCalls.blockingUnaryCall(
+          getChannel(), getGetIdentityContractNonceMethod(), getCallOptions(), request);
+    }
+
     /**
      */
     public org.dash.platform.dapi.v0.PlatformOuterClass.GetProofsResponse getProofs(org.dash.platform.dapi.v0.PlatformOuterClass.GetProofsRequest request) {
@@ -1006,6 +1232,20 @@ public final class PlatformGrpc {
       return io.grpc.stub.ClientCalls.blockingUnaryCall(
           getChannel(), getGetConsensusParamsMethod(), getCallOptions(), request);
     }
+
+    /**
+     */
+    public org.dash.platform.dapi.v0.PlatformOuterClass.GetBlockAttestationResponse getBlockAttestation(org.dash.platform.dapi.v0.PlatformOuterClass.GetBlockAttestationRequest request) {
+      return io.grpc.stub.ClientCalls.blockingUnaryCall(
+          getChannel(), getGetBlockAttestationMethod(), getCallOptions(), request);
+    }
+
+    /**
+     */
+    public org.dash.platform.dapi.v0.PlatformOuterClass.EstimateFeeResponse estimateFee(org.dash.platform.dapi.v0.PlatformOuterClass.EstimateFeeRequest request) {
+      return io.grpc.stub.ClientCalls.blockingUnaryCall(
+          getChannel(), getEstimateFeeMethod(), getCallOptions(), request);
+    }
   }
 
   /**
@@ -1073,6 +1313,22 @@ public final class PlatformGrpc {
           getChannel().newCall(getGetIdentityBalanceAndRevisionMethod(), getCallOptions()), request);
     }
 
+    /**
+     */
+    public com.google.common.util.concurrent.ListenableFuture<org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityNonceResponse> getIdentityNonce(
+        org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityRequest request) {
+      return io.grpc.stub.ClientCalls.futureUnaryCall(
+          getChannel().newCall(getGetIdentityNonceMethod(), getCallOptions()), request);
+    }
+
+    /**
+     */
+    public com.google.common.util.concurrent.ListenableFuture<org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityContractNonceResponse> getIdentityContractNonce(
+        org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityContractNonceRequest request) {
+      return io.grpc.stub.ClientCalls.futureUnaryCall(
+          getChannel().newCall(getGetIdentityContractNonceMethod(), getCallOptions()), request);
+    }
+
     /**
      */
     public com.google.common.util.concurrent.ListenableFuture<org.dash.platform.dapi.v0.PlatformOuterClass.GetProofsResponse> getProofs(
@@ -1144,6 +1400,22 @@ public final class PlatformGrpc {
       return io.grpc.stub.ClientCalls.futureUnaryCall(
           getChannel().newCall(getGetConsensusParamsMethod(), getCallOptions()), request);
     }
+
+    /**
+     */
+    public com.google.common.util.concurrent.ListenableFuture<org.dash.platform.dapi.v0.PlatformOuterClass.GetBlockAttestationResponse> getBlockAttestation(
+        org.dash.platform.dapi.v0.PlatformOuterClass.GetBlockAttestationRequest request) {
+      return io.grpc.stub.ClientCalls.futureUnaryCall(
+          getChannel().newCall(getGetBlockAttestationMethod(), getCallOptions()), request);
+    }
+
+    /**
+     */
+    public com.google.common.util.concurrent.ListenableFuture<org.dash.platform.dapi.v0.PlatformOuterClass.EstimateFeeResponse> estimateFee(
+        org.dash.platform.dapi.v0.PlatformOuterClass.EstimateFeeRequest request) {
+      return io.grpc.stub.ClientCalls.futureUnaryCall(
+          getChannel().newCall(getEstimateFeeMethod(), getCallOptions()), request);
+    }
   }
 
   private static final int METHODID_BROADCAST_STATE_TRANSITION = 0;
@@ -1152,15 +1424,19 @@ public final class PlatformGrpc {
   private static final int METHODID_GET_IDENTITY_KEYS = 3;
   private static final int METHODID_GET_IDENTITY_BALANCE = 4;
   private static final int METHODID_GET_IDENTITY_BALANCE_AND_REVISION = 5;
-  private static final int METHODID_GET_PROOFS = 6;
-  private static final int METHODID_GET_DATA_CONTRACT = 7;
-  private static final int METHODID_GET_DATA_CONTRACT_HISTORY = 8;
-  private static final int METHODID_GET_DATA_CONTRACTS = 9;
-  private static final int METHODID_GET_DOCUMENTS = 10;
-  private static final int METHODID_GET_IDENTITIES_BY_PUBLIC_KEY_HASHES = 11;
-  private static final int METHODID_GET_IDENTITY_BY_PUBLIC_KEY_HASHES = 12;
-  private static final int METHODID_WAIT_FOR_STATE_TRANSITION_RESULT = 13;
-  private static final int METHODID_GET_CONSENSUS_PARAMS = 14;
+  private static final int METHODID_GET_IDENTITY_NONCE = 6;
+  private static final int METHODID_GET_IDENTITY_CONTRACT_NONCE = 7;
+  private static final int METHODID_GET_PROOFS = 8;
+  private static final int METHODID_GET_DATA_CONTRACT = 9;
+  private static final int METHODID_GET_DATA_CONTRACT_HISTORY = 10;
+  private static final int METHODID_GET_DATA_CONTRACTS = 11;
+  private static final int METHODID_GET_DOCUMENTS = 12;
+  private static final int METHODID_GET_IDENTITIES_BY_PUBLIC_KEY_HASHES = 13;
+  private static final int METHODID_GET_IDENTITY_BY_PUBLIC_KEY_HASHES = 14;
+  private static final int METHODID_WAIT_FOR_STATE_TRANSITION_RESULT = 15;
+  private static final int METHODID_GET_CONSENSUS_PARAMS = 16;
+  private static final int METHODID_GET_BLOCK_ATTESTATION = 17;
+  private static final int METHODID_ESTIMATE_FEE = 18;
 
   private static final class MethodHandlers<Req, Resp> implements
       io.grpc.stub.ServerCalls.UnaryMethod<Req, Resp>,
@@ -1203,6 +1479,14 @@ public final class PlatformGrpc {
           serviceImpl.getIdentityBalanceAndRevision((org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityRequest) request,
               (io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityBalanceAndRevisionResponse>) responseObserver);
           break;
+        case METHODID_GET_IDENTITY_NONCE:
+          serviceImpl.getIdentityNonce((org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityRequest) request,
+              (io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityNonceResponse>) responseObserver);
+          break;
+        case METHODID_GET_IDENTITY_CONTRACT_NONCE:
+          serviceImpl.getIdentityContractNonce((org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityContractNonceRequest) request,
+              (io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityContractNonceResponse>) responseObserver);
+          break;
         case METHODID_GET_PROOFS:
           serviceImpl.getProofs((org.dash.platform.dapi.v0.PlatformOuterClass.GetProofsRequest) request,
               (io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.PlatformOuterClass.GetProofsResponse>) responseObserver);
@@ -1239,6 +1523,14 @@ public final class PlatformGrpc {
           serviceImpl.getConsensusParams((org.dash.platform.dapi.v0.PlatformOuterClass.GetConsensusParamsRequest) request,
               (io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.PlatformOuterClass.GetConsensusParamsResponse>) responseObserver);
           break;
+        case METHODID_GET_BLOCK_ATTESTATION:
+          serviceImpl.getBlockAttestation((org.dash.platform.dapi.v0.PlatformOuterClass.GetBlockAttestationRequest) request,
+              (io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.PlatformOuterClass.GetBlockAttestationResponse>) responseObserver);
+          break;
+        case METHODID_ESTIMATE_FEE:
+          serviceImpl.estimateFee((org.dash.platform.dapi.v0.PlatformOuterClass.EstimateFeeRequest) request,
+              (io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.PlatformOuterClass.EstimateFeeResponse>) responseObserver);
+          break;
         default:
           throw new AssertionError();
       }
@@ -1306,6 +1598,8 @@ public final class PlatformGrpc {
               .addMethod(getGetIdentityKeysMethod())
               .addMethod(getGetIdentityBalanceMethod())
               .addMethod(getGetIdentityBalanceAndRevisionMethod())
+              .addMethod(getGetIdentityNonceMethod())
+              .addMethod(getGetIdentityContractNonceMethod())
               .addMethod(getGetProofsMethod())
               .addMethod(getGetDataContractMethod())
               .addMethod(getGetDataContractHistoryMethod())
@@ -1315,6 +1609,8 @@ public final class PlatformGrpc {
               .addMethod(getGetIdentityByPublicKeyHashesMethod())
               .addMethod(getWaitForStateTransitionResultMethod())
               .addMethod(getGetConsensusParamsMethod())
+              .addMethod(getGetBlockAttestationMethod())
+              .addMethod(getEstimateFeeMethod())
               .build();
         }
       }
@@ -22,7 +22,7 @@ DESCRIPTOR = _descriptor.FileDescriptor(
   syntax='proto3',
   serialized_options=None,
   create_key=_descriptor._internal_create_key,
-  serialized_pb=b'\n\x0eplatform.proto\x12\x19org.dash.platform.dapi.v0\x1a\x1egoogle/protobuf/wrappers.proto\x1a\x1cgoogle/protobuf/struct.proto\x1a\x1fgoogle/protobuf/timestamp.proto\"U\n\x05Proof\x12\x15\n\rgrovedb_proof\x18\x01 \x01(\x0c\x12\x13\n\x0bquorum_hash\x18\x02 \x01(\x0c\x12\x11\n\tsignature\x18\x03 \x01(\x0c\x12\r\n\x05round\x18\x04 \x01(\r\"o\n\x10ResponseMetadata\x12\x0e\n\x06height\x18\x01 \x01(\x04\x12 \n\x18\x63ore_chain_locked_height\x18\x02 \x01(\r\x12\x0f\n\x07time_ms\x18\x03 \x01(\x04\x12\x18\n\x10protocol_version\x18\x04 \x01(\r\"L\n\x1dStateTransitionBroadcastError\x12\x0c\n\x04\x63ode\x18\x01 \x01(\r\x12\x0f\n\x07message\x18\x02 \x01(\t\x12\x0c\n\x04\x64\x61ta\x18\x03 \x01(\x0c\";\n\x1f\x42roadcastStateTransitionRequest\x12\x18\n\x10state_transition\x18\x01 \x01(\x0c\"\"\n BroadcastStateTransitionResponse\"/\n\x12GetIdentityRequest\x12\n\n\x02id\x18\x01 \x01(\x0c\x12\r\n\x05prove\x18\x02 \x01(\x08\"\xa5\x01\n\x13GetIdentityResponse\x12\x12\n\x08identity\x18\x01 \x01(\x0cH\x00\x12\x31\n\x05proof\x18\x02 \x01(\x0b\x32 .org.dash.platform.dapi.v0.ProofH\x00\x12=\n\x08metadata\x18\x03 \x01(\x0b\x32+.org.dash.platform.dapi.v0.ResponseMetadataB\x08\n\x06result\"2\n\x14GetIdentitiesRequest\x12\x0b\n\x03ids\x18\x01 \x03(\x0c\x12\r\n\x05prove\x18\x02 \x01(\x08\"\xdb\x03\n\x15GetIdentitiesResponse\x12Q\n\nidentities\x18\x01 \x01(\x0b\x32;.org.dash.platform.dapi.v0.GetIdentitiesResponse.IdentitiesH\x00\x12\x31\n\x05proof\x18\x02 \x01(\x0b\x32 .org.dash.platform.dapi.v0.ProofH\x00\x12=\n\x08metadata\x18\x03 \x01(\x0b\x32+.org.dash.platform.dapi.v0.ResponseMetadata\x1a\x1e\n\rIdentityValue\x12\r\n\x05value\x18\x01 \x01(\x0c\x1ak\n\rIdentityEntry\x12\x0b\n\x03key\x18\x01 \x01(\x0c\x12M\n\x05value\x18\x02 \x01(\x0b\x32>.org.dash.platform.dapi.v0.GetIdentitiesResponse.IdentityValue\x1a\x66\n\nIdentities\x12X\n\x10identity_entries\x18\x01 \x03(\x0b\x32>.org.dash.platform.dapi.v0.GetIdentitiesResponse.IdentityEntryB\x08\n\x06result\"\xc9\x01\n\x1aGetIdentityBalanceResponse\x12/\n\x07\x62\x61lance\x18\x01 \x01(\x0b\x32\x1c.google.protobuf.UInt64ValueH\x00\x12\x31\n\x05proof\x18\x02 \x01(\x0b\x32 .org.dash.platform.dapi.v0.ProofH\x00\x12=\n\x08metadata\x18\x03 \x01(\x0b\x32+.org.dash.platform.dapi.v0.ResponseMetadataB\x08\n\x06result\"\x8d\x03\n%GetIdentityBalanceAndRevisionResponse\x12s\n\x14\x62\x61lance_and_revision\x18\x01 \x01(\x0b\x32S.org.dash.platform.dapi.v0.GetIdentityBalanceAndRevisionResponse.BalanceAndRevisionH\x00\x12\x31\n\x05proof\x18\x02 \x01(\x0b\x32 .org.dash.platform.dapi.v0.ProofH\x00\x12=\n\x08metadata\x18\x03 \x01(\x0b\x32+.org.dash.platform.dapi.v0.ResponseMetadata\x1as\n\x12\x42\x61lanceAndRevision\x12-\n\x07\x62\x61lance\x18\x01 \x01(\x0b\x32\x1c.google.protobuf.UInt64Value\x12.\n\x08revision\x18\x02 \x01(\x0b\x32\x1c.google.protobuf.UInt64ValueB\x08\n\x06result\"\xd1\x01\n\x0eKeyRequestType\x12\x36\n\x08\x61ll_keys\x18\x01 \x01(\x0b\x32\".org.dash.platform.dapi.v0.AllKeysH\x00\x12@\n\rspecific_keys\x18\x02 \x01(\x0b\x32\'.org.dash.platform.dapi.v0.SpecificKeysH\x00\x12:\n\nsearch_key\x18\x03 \x01(\x0b\x32$.org.dash.platform.dapi.v0.SearchKeyH\x00\x42\t\n\x07request\"\t\n\x07\x41llKeys\"\x1f\n\x0cSpecificKeys\x12\x0f\n\x07key_ids\x18\x01 \x03(\r\"\xb6\x01\n\tSearchKey\x12I\n\x0bpurpose_map\x18\x01 \x03(\x0b\x32\x34.org.dash.platform.dapi.v0.SearchKey.PurposeMapEntry\x1a^\n\x0fPurposeMapEntry\x12\x0b\n\x03key\x18\x01 \x01(\r\x12:\n\x05value\x18\x02 \x01(\x0b\x32+.org.dash.platform.dapi.v0.SecurityLevelMap:\x02\x38\x01\"\xbf\x02\n\x10SecurityLevelMap\x12]\n\x12security_level_map\x18\x01 \x03(\x0b\x32\x41.org.dash.platform.dapi.v0.SecurityLevelMap.SecurityLevelMapEntry\x1aw\n\x15SecurityLevelMapEntry\x12\x0b\n\x03key\x18\x01 \x01(\r\x12M\n\x05value\x18\x02 \x01(\x0e\x32>.org.dash.platform.dapi.v0.SecurityLevelMap.KeyKindRequestType:\x02\x38\x01\"S\n\x12KeyKindRequestType\x12\x1f\n\x1b\x43URRENT_KEY_OF_KIND_REQUEST\x10\x00\x12\x1c\n\x18\x41LL_KEYS_OF_KIND_REQUEST\x10\x01\"\xd8\x01\n\x16GetIdentityKeysRequest\x12\x13\n\x0bidentity_id\x18\x01 \x01(\x0c\x12?\n\x0crequest_type\x18\x02 \x01(\x0b\x32).org.dash.platform.dapi.v0.KeyRequestType\x12+\n\x05limit\x18\x03 \x01(\x0b\x32\x1c.google.protobuf.UInt32Value\x12,\n\x06offset\x18\x04 \x01(\x0b\x32\x1c.google.protobuf.UInt32Value\x12\r\n\x05prove\x18\x05 \x01(\x08\"\xfa\x01\n\x17GetIdentityKeysResponse\x12G\n\x04keys\x18\x01 \x01(\x0b\x32\x37.org.dash.platform.dapi.v0.GetIdentityKeysResponse.KeysH\x00\x12\x31\n\x05proof\x18\x02 \x01(\x0b\x32 .org.dash.platform.dapi.v0.ProofH\x00\x12=\n\x08metadata\x18\x03 \x01(\x0b\x32+.org.dash.platform.dapi.v0.ResponseMetadata\x1a\x1a\n\x04Keys\x12\x12\n\nkeys_bytes\x18\x01 \x03(\x0c\x42\x08\n\x06result\"\xb2\x04\n\x18GetIdentitiesKeysRequest\x12\x14\n\x0cidentity_ids\x18\x01 \x03(\x0c\x12?\n\x0crequest_type\x18\x02 \x01(\x0b\x32).org.dash.platform.dapi.v0.KeyRequestType\x12+\n\x05limit\x18\x03 \x01(\x0b\x32\x1c.google.protobuf.UInt32Value\x12,\n\x06offset\x18\x04 \x01(\x0b\x32\x1c.google.protobuf.UInt32Value\x12\r\n\x05prove\x18\x05 \x01(\x08\x1a\xd4\x02\n\x10SecurityLevelMap\x12v\n\x12security_level_map\x18\x01 \x03(\x0b\x32Z.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.SecurityLevelMap.SecurityLevelMapEntry\x1a\x90\x01\n\x15SecurityLevelMapEntry\x12\x0b\n\x03key\x18\x01 \x01(\r\x12\x66\n\x05value\x18\x02 \x01(\x0e\x32W.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.SecurityLevelMap.KeyKindRequestType:\x02\x38\x01\"5\n\x12KeyKindRequestType\x12\x1f\n\x1b\x43URRENT_KEY_OF_KIND_REQUEST\x10\x00\"\xf4\x03\n\x19GetIdentitiesKeysResponse\x12\\\n\x0bpublic_keys\x18\x01 \x01(\x0b\x32\x45.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntriesH\x00\x12\x31\n\x05proof\x18\x02 \x01(\x0b\x32 .org.dash.platform.dapi.v0.ProofH\x00\x12=\n\x08metadata\x18\x03 \x01(\x0b\x32+.org.dash.platform.dapi.v0.ResponseMetadata\x1a\x1a\n\tPublicKey\x12\r\n\x05value\x18\x01 \x01(\x0c\x1al\n\x0ePublicKeyEntry\x12\x0b\n\x03key\x18\x01 \x01(\x0c\x12M\n\x05value\x18\x02 \x01(\x0b\x32>.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKey\x1as\n\x10PublicKeyEntries\x12_\n\x12public_key_entries\x18\x01 \x03(\x0b\x32\x43.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntryB\x08\n\x06result\"\xd7\x04\n\x10GetProofsRequest\x12O\n\nidentities\x18\x01 \x03(\x0b\x32;.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest\x12N\n\tcontracts\x18\x02 \x03(\x0b\x32;.org.dash.platform.dapi.v0.GetProofsRequest.ContractRequest\x12N\n\tdocuments\x18\x03 \x03(\x0b\x32;.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest\x1aw\n\x0f\x44ocumentRequest\x12\x13\n\x0b\x63ontract_id\x18\x01 \x01(\x0c\x12\x15\n\rdocument_type\x18\x02 \x01(\t\x12#\n\x1b\x64ocument_type_keeps_history\x18\x03 \x01(\x08\x12\x13\n\x0b\x64ocument_id\x18\x04 \x01(\x0c\x1a\xb0\x01\n\x0fIdentityRequest\x12\x13\n\x0bidentity_id\x18\x01 \x01(\x0c\x12V\n\x0crequest_type\x18\x02 \x01(\x0e\x32@.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest.Type\"0\n\x04Type\x12\x11\n\rFULL_IDENTITY\x10\x00\x12\x0b\n\x07\x42\x41LANCE\x10\x01\x12\x08\n\x04KEYS\x10\x02\x1a&\n\x0f\x43ontractRequest\x12\x13\n\x0b\x63ontract_id\x18\x01 \x01(\x0c\"\x83\x01\n\x11GetProofsResponse\x12/\n\x05proof\x18\x01 \x01(\x0b\x32 .org.dash.platform.dapi.v0.Proof\x12=\n\x08metadata\x18\x02 \x01(\x0b\x32+.org.dash.platform.dapi.v0.ResponseMetadata\"3\n\x16GetDataContractRequest\x12\n\n\x02id\x18\x01 \x01(\x0c\x12\r\n\x05prove\x18\x02 \x01(\x08\"\xae\x01\n\x17GetDataContractResponse\x12\x17\n\rdata_contract\x18\x01 \x01(\x0cH\x00\x12\x31\n\x05proof\x18\x02 \x01(\x0b\x32 .org.dash.platform.dapi.v0.ProofH\x00\x12=\n\x08metadata\x18\x03 \x01(\x0b\x32+.org.dash.platform.dapi.v0.ResponseMetadataB\x08\n\x06result\"5\n\x17GetDataContractsRequest\x12\x0b\n\x03ids\x18\x01 \x03(\x0c\x12\r\n\x05prove\x18\x02 \x01(\x08\"\x86\x04\n\x18GetDataContractsResponse\x12[\n\x0e\x64\x61ta_contracts\x18\x01 \x01(\x0b\x32\x41.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractsH\x00\x12\x31\n\x05proof\x18\x02 \x01(\x0b\x32 .org.dash.platform.dapi.v0.ProofH\x00\x12=\n\x08metadata\x18\x03 \x01(\x0b\x32+.org.dash.platform.dapi.v0.ResponseMetadata\x1a\"\n\x11\x44\x61taContractValue\x12\r\n\x05value\x18\x01 \x01(\x0c\x1av\n\x11\x44\x61taContractEntry\x12\x0b\n\x03key\x18\x01 \x01(\x0c\x12T\n\x05value\x18\x02 \x01(\x0b\x32\x45.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractValue\x1au\n\rDataContracts\x12\x64\n\x15\x64\x61ta_contract_entries\x18\x01 \x03(\x0b\x32\x45.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntryB\x08\n\x06result\"n\n\x1dGetDataContractHistoryRequest\x12\n\n\x02id\x18\x01 \x01(\x0c\x12\r\n\x05limit\x18\x02 \x01(\r\x12\x0e\n\x06offset\x18\x03 \x01(\r\x12\x13\n\x0bstart_at_ms\x18\x04 \x01(\x04\x12\r\n\x05prove\x18\x05 \x01(\x08\"\xd0\x03\n\x1eGetDataContractHistoryResponse\x12n\n\x15\x64\x61ta_contract_history\x18\x01 \x01(\x0b\x32M.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistoryH\x00\x12\x31\n\x05proof\x18\x02 \x01(\x0b\x32 .org.dash.platform.dapi.v0.ProofH\x00\x12=\n\x08metadata\x18\x03 \x01(\x0b\x32+.org.dash.platform.dapi.v0.ResponseMetadata\x1a\x37\n\x18\x44\x61taContractHistoryEntry\x12\x0c\n\x04\x64\x61te\x18\x01 \x01(\x04\x12\r\n\x05value\x18\x02 \x01(\x0c\x1a\x88\x01\n\x13\x44\x61taContractHistory\x12q\n\x15\x64\x61ta_contract_entries\x18\x01 \x03(\x0b\x32R.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistoryEntryB\x08\n\x06result\"\xb9\x01\n\x13GetDocumentsRequest\x12\x18\n\x10\x64\x61ta_contract_id\x18\x01 \x01(\x0c\x12\x15\n\rdocument_type\x18\x02 \x01(\t\x12\r\n\x05where\x18\x03 \x01(\x0c\x12\x10\n\x08order_by\x18\x04 \x01(\x0c\x12\r\n\x05limit\x18\x05 \x01(\r\x12\x15\n\x0bstart_after\x18\x06 \x01(\x0cH\x00\x12\x12\n\x08start_at\x18\x07 \x01(\x0cH\x00\x12\r\n\x05prove\x18\x08 \x01(\x08\x42\x07\n\x05start\"\x82\x02\n\x14GetDocumentsResponse\x12N\n\tdocuments\x18\x01 \x01(\x0b\x32\x39.org.dash.platform.dapi.v0.GetDocumentsResponse.DocumentsH\x00\x12\x31\n\x05proof\x18\x02 \x01(\x0b\x32 .org.dash.platform.dapi.v0.ProofH\x00\x12=\n\x08metadata\x18\x03 \x01(\x0b\x32+.org.dash.platform.dapi.v0.ResponseMetadata\x1a\x1e\n\tDocuments\x12\x11\n\tdocuments\x18\x01 \x03(\x0c\x42\x08\n\x06result\"Q\n%GetIdentitiesByPublicKeyHashesRequest\x12\x19\n\x11public_key_hashes\x18\x01 \x03(\x0c\x12\r\n\x05prove\x18\x02 \x01(\x08\"\xaa\x02\n&GetIdentitiesByPublicKeyHashesResponse\x12\x62\n\nidentities\x18\x01 \x01(\x0b\x32L.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.IdentitiesH\x00\x12\x31\n\x05proof\x18\x02 \x01(\x0b\x32 .org.dash.platform.dapi.v0.ProofH\x00\x12=\n\x08metadata\x18\x03 \x01(\x0b\x32+.org.dash.platform.dapi.v0.ResponseMetadata\x1a \n\nIdentities\x12\x12\n\nidentities\x18\x01 \x03(\x0c\x42\x08\n\x06result\"M\n#GetIdentityByPublicKeyHashesRequest\x12\x17\n\x0fpublic_key_hash\x18\x01 \x01(\x0c\x12\r\n\x05prove\x18\x02 \x01(\x08\"\xb6\x01\n$GetIdentityByPublicKeyHashesResponse\x12\x12\n\x08identity\x18\x01 \x01(\x0cH\x00\x12\x31\n\x05proof\x18\x02 \x01(\x0b\x32 .org.dash.platform.dapi.v0.ProofH\x00\x12=\n\x08metadata\x18\x03 \x01(\x0b\x32+.org.dash.platform.dapi.v0.ResponseMetadataB\x08\n\x06result\"S\n#WaitForStateTransitionResultRequest\x12\x1d\n\x15state_transition_hash\x18\x01 \x01(\x0c\x12\r\n\x05prove\x18\x02 \x01(\x08\"\xed\x01\n$WaitForStateTransitionResultResponse\x12I\n\x05\x65rror\x18\x01 \x01(\x0b\x32\x38.org.dash.platform.dapi.v0.StateTransitionBroadcastErrorH\x00\x12\x31\n\x05proof\x18\x02 \x01(\x0b\x32 .org.dash.platform.dapi.v0.ProofH\x00\x12=\n\x08metadata\x18\x03 \x01(\x0b\x32+.org.dash.platform.dapi.v0.ResponseMetadataB\x08\n\x06result\"P\n\x14\x43onsensusParamsBlock\x12\x11\n\tmax_bytes\x18\x01 \x01(\t\x12\x0f\n\x07max_gas\x18\x02 \x01(\t\x12\x14\n\x0ctime_iota_ms\x18\x03 \x01(\t\"b\n\x17\x43onsensusParamsEvidence\x12\x1a\n\x12max_age_num_blocks\x18\x01 \x01(\t\x12\x18\n\x10max_age_duration\x18\x02 \x01(\t\x12\x11\n\tmax_bytes\x18\x03 \x01(\t\":\n\x19GetConsensusParamsRequest\x12\x0e\n\x06height\x18\x01 \x01(\x03\x12\r\n\x05prove\x18\x02 \x01(\x08\"\xa2\x01\n\x1aGetConsensusParamsResponse\x12>\n\x05\x62lock\x18\x01 \x01(\x0b\x32/.org.dash.platform.dapi.v0.ConsensusParamsBlock\x12\x44\n\x08\x65vidence\x18\x02 \x01(\x0b\x32\x32.org.dash.platform.dapi.v0.ConsensusParamsEvidence2\xdb\x0f\n\x08Platform\x12\x93\x01\n\x18\x62roadcastStateTransition\x12:.org.dash.platform.dapi.v0.BroadcastStateTransitionRequest\x1a;.org.dash.platform.dapi.v0.BroadcastStateTransitionResponse\x12l\n\x0bgetIdentity\x12-.org.dash.platform.dapi.v0.GetIdentityRequest\x1a..org.dash.platform.dapi.v0.GetIdentityResponse\x12r\n\rgetIdentities\x12/.org.dash.platform.dapi.v0.GetIdentitiesRequest\x1a\x30.org.dash.platform.dapi.v0.GetIdentitiesResponse\x12x\n\x0fgetIdentityKeys\x12\x31.org.dash.platform.dapi.v0.GetIdentityKeysRequest\x1a\x32.org.dash.platform.dapi.v0.GetIdentityKeysResponse\x12z\n\x12getIdentityBalance\x12-.org.dash.platform.dapi.v0.GetIdentityRequest\x1a\x35.org.dash.platform.dapi.v0.GetIdentityBalanceResponse\x12\x90\x01\n\x1dgetIdentityBalanceAndRevision\x12-.org.dash.platform.dapi.v0.GetIdentityRequest\x1a@.org.dash.platform.dapi.v0.GetIdentityBalanceAndRevisionResponse\x12\x66\n\tgetProofs\x12+.org.dash.platform.dapi.v0.GetProofsRequest\x1a,.org.dash.platform.dapi.v0.GetProofsResponse\x12x\n\x0fgetDataContract\x12\x31.org.dash.platform.dapi.v0.GetDataContractRequest\x1a\x32.org.dash.platform.dapi.v0.GetDataContractResponse\x12\x8d\x01\n\x16getDataContractHistory\x12\x38.org.dash.platform.dapi.v0.GetDataContractHistoryRequest\x1a\x39.org.dash.platform.dapi.v0.GetDataContractHistoryResponse\x12{\n\x10getDataContracts\x12\x32.org.dash.platform.dapi.v0.GetDataContractsRequest\x1a\x33.org.dash.platform.dapi.v0.GetDataContractsResponse\x12o\n\x0cgetDocuments\x12..org.dash.platform.dapi.v0.GetDocumentsRequest\x1a/.org.dash.platform.dapi.v0.GetDocumentsResponse\x12\xa5\x01\n\x1egetIdentitiesByPublicKeyHashes\x12@.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesRequest\x1a\x41.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse\x12\x9f\x01\n\x1cgetIdentityByPublicKeyHashes\x12>.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesRequest\x1a?.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse\x12\x9f\x01\n\x1cwaitForStateTransitionResult\x12>.org.dash.platform.dapi.v0.WaitForStateTransitionResultRequest\x1a?.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse\x12\x81\x01\n\x12getConsensusParams\x12\x34.org.dash.platform.dapi.v0.GetConsensusParamsRequest\x1a\x35.org.dash.platform.dapi.v0.GetConsensusParamsResponseb\x06proto3'
+  serialized_pb=b'\n\x0eplatform.proto\x12\x19org.dash.platform.dapi.v0\x1a\x1egoogle/protobuf/wrappers.proto\x1a\x1cgoogle/protobuf/struct.proto\x1a\x1fgoogle/protobuf/timestamp.proto\"\x81\x01\n\x05Proof\x12\x15\n\rgrovedb_proof\x18\x01 \x01(\x0c\x12\x13\n\x0bquorum_hash\x18\x02 \x01(\x0c\x12\x11\n\tsignature\x18\x03 \x01(\x0c\x12\r\n\x05round\x18\x04 \x01(\r\x12\x15\n\rblock_id_hash\x18\x05 \x01(\x0c\x12\x13\n\x0bquorum_type\x18\x06 \x01(\r\"\x81\x01\n\x10ResponseMetadata\x12\x0e\n\x06height\x18\x01 \x01(\x04\x12 \n\x18\x63ore_chain_locked_height\x18\x02 \x01(\r\x12\x0f\n\x07time_ms\x18\x03 \x01(\x04\x12\x18\n\x10protocol_version\x18\x04 \x01(\r\x12\x10\n\x08\x63hain_id\x18\x05 \x01(\t\"L\n\x1dStateTransitionBroadcastError\x12\x0c\n\x04\x63ode\x18\x01 \x01(\r\x12\x0f\n\x07message\x18\x02 \x01(\t\x12\x0c\n\x04\x64\x61ta\x18\x03 \x01(\x0c\";\n\x1f\x42roadcastStateTransitionRequest\x12\x18\n\x10state_transition\x18\x01 \x01(\x0c\"\"\n BroadcastStateTransitionResponse\"/\n\x12GetIdentityRequest\x12\n\n\x02id\x18\x01 \x01(\x0c\x12\r\n\x05prove\x18\x02 \x01(\x08\"\xa5\x01\n\x13GetIdentityResponse\x12\x12\n\x08identity\x18\x01 \x01(\x0cH\x00\x12\x31\n\x05proof\x18\x02 \x01(\x0b\x32 .org.dash.platform.dapi.v0.ProofH\x00\x12=\n\x08metadata\x18\x03 \x01(\x0b\x32+.org.dash.platform.dapi.v0.ResponseMetadataB\x08\n\x06result\"2\n\x14GetIdentitiesRequest\x12\x0b\n\x03ids\x18\x01 \x03(\x0c\x12\r\n\x05prove\x18\x02 \x01(\x08\"\xdb\x03\n\x15GetIdentitiesResponse\x12Q\n\nidentities\x18\x01 \x01(\x0b\x32;.org.dash.platform.dapi.v0.GetIdentitiesResponse.IdentitiesH\x00\x12\x31\n\x05proof\x18\x02 \x01(\x0b\x32 .org.dash.platform.dapi.v0.ProofH\x00\x12=\n\x08metadata\x18\x03 \x01(\x0b\x32+.org.dash.platform.dapi.v0.ResponseMetadata\x1a\x1e\n\rIdentityValue\x12\r\n\x05value\x18\x01 \x01(\x0c\x1ak\n\rIdentityEntry\x12\x0b\n\x03key\x18\x01 \x01(\x0c\x12M\n\x05value\x18\x02 \x01(\x0b\x32>.org.dash.platform.dapi.v0.GetIdentitiesResponse.IdentityValue\x1a\x66\n\nIdentities\x12X\n\x10identity_entries\x18\x01 \x03(\x0b\x32>.org.dash.platform.dapi.v0.GetIdentitiesResponse.IdentityEntryB\x08\n\x06result\"\xc9\x01\n\x1aGetIdentityBalanceResponse\x12/\n\x07\x62\x61lance\x18\x01 \x01(\x0b\x32\x1c.google.protobuf.UInt64ValueH\x00\x12\x31\n\x05proof\x18\x02 \x01(\x0b\x32 .org.dash.platform.dapi.v0.ProofH\x00\x12=\n\x08metadata\x18\x03 \x01(\x0b\x32+.org.dash.platform.dapi.v0.ResponseMetadataB\x08\n\x06result\"\x8d\x03\n%GetIdentityBalanceAndRevisionResponse\x12s\n\x14\x62\x61lance_and_revision\x18\x01 \x01(\x0b\x32S.org.dash.platform.dapi.v0.GetIdentityBalanceAndRevisionResponse.BalanceAndRevisionH\x00\x12\x31\n\x05proof\x18\x02 \x01(\x0b\x32 .org.dash.platform.dapi.v0.ProofH\x00\x12=\n\x08metadata\x18\x03 \x01(\x0b\x32+.org.dash.platform.dapi.v0.ResponseMetadata\x1as\n\x12\x42\x61lanceAndRevision\x12-\n\x07\x62\x61lance\x18\x01 \x01(\x0b\x32\x1c.google.protobuf.UInt64Value\x12.\n\x08revision\x18\x02 \x01(\x0b\x32\x1c.google.protobuf.UInt64ValueB\x08\n\x06result\"\xc5\x01\n\x18GetIdentityNonceResponse\x12-\n\x05nonce\x18\x01 \x01(\x0b\x32\x1c.google.protobuf.UInt64ValueH\x00\x12\x31\n\x05proof\x18\x02 \x01(\x0b\x32 .org.dash.platform.dapi.v0.ProofH\x00\x12=\n\x08metadata\x18\x03 \x01(\x0b\x32+.org.dash.platform.dapi.v0.ResponseMetadataB\x08\n\x06result\"Z\n\x1fGetIdentityContractNonceRequest\x12\x13\n\x0bidentity_id\x18\x01 \x01(\x0c\x12\x13\n\x0b\x63ontract_id\x18\x02 \x01(\x0c\x12\r\n\x05prove\x18\x03 \x01(\x08\"\xcd\x01\n GetIdentityContractNonceResponse\x12-\n\x05nonce\x18\x01 \x01(\x0b\x32\x1c.google.protobuf.UInt64ValueH\x00\x12\x31\n\x05proof\x18\x02 \x01(\x0b\x32 .org.dash.platform.dapi.v0.ProofH\x00\x12=\n\x08metadata\x18\x03 \x01(\x0b\x32+.org.dash.platform.dapi.v0.ResponseMetadataB\x08\n\x06result\"\xd1\x01\n\x0eKeyRequestType\x12\x36\n\x08\x61ll_keys\x18\x01 \x01(\x0b\x32\".org.dash.platform.dapi.v0.AllKeysH\x00\x12@\n\rspecific_keys\x18\x02 \x01(\x0b\x32\'.org.dash.platform.dapi.v0.SpecificKeysH\x00\x12:\n\nsearch_key\x18\x03 \x01(\x0b\x32$.org.dash.platform.dapi.v0.SearchKeyH\x00\x42\t\n\x07request\"\t\n\x07\x41llKeys\"\x1f\n\x0cSpecificKeys\x12\x0f\n\x07key_ids\x18\x01 \x03(\r\"\xb6\x01\n\tSearchKey\x12I\n\x0bpurpose_map\x18\x01 \x03(\x0b\x32\x34.org.dash.platform.dapi.v0.SearchKey.PurposeMapEntry\x1a^\n\x0fPurposeMapEntry\x12\x0b\n\x03key\x18\x01 \x01(\r\x12:\n\x05value\x18\x02 \x01(\x0b\x32+.org.dash.platform.dapi.v0.SecurityLevelMap:\x02\x38\x01\"\xbf\x02\n\x10SecurityLevelMap\x12]\n\x12security_level_map\x18\x01 \x03(\x0b\x32\x41.org.dash.platform.dapi.v0.SecurityLevelMap.SecurityLevelMapEntry\x1aw\n\x15SecurityLevelMapEntry\x12\x0b\n\x03key\x18\x01 \x01(\r\x12M\n\x05value\x18\x02 \x01(\x0e\x32>.org.dash.platform.dapi.v0.SecurityLevelMap.KeyKindRequestType:\x02\x38\x01\"S\n\x12KeyKindRequestType\x12\x1f\n\x1b\x43URRENT_KEY_OF_KIND_REQUEST\x10\x00\x12\x1c\n\x18\x41LL_KEYS_OF_KIND_REQUEST\x10\x01\"\xd8\x01\n\x16GetIdentityKeysRequest\x12\x13\n\x0bidentity_id\x18\x01 \x01(\x0c\x12?\n\x0crequest_type\x18\x02 \x01(\x0b\x32).org.dash.platform.dapi.v0.KeyRequestType\x12+\n\x05limit\x18\x03 \x01(\x0b\x32\x1c.google.protobuf.UInt32Value\x12,\n\x06offset\x18\x04 \x01(\x0b\x32\x1c.google.protobuf.UInt32Value\x12\r\n\x05prove\x18\x05 \x01(\x08\"\xfa\x01\n\x17GetIdentityKeysResponse\x12G\n\x04keys\x18\x01 \x01(\x0b\x32\x37.org.dash.platform.dapi.v0.GetIdentityKeysResponse.KeysH\x00\x12\x31\n\x05proof\x18\x02 \x01(\x0b\x32 .org.dash.platform.dapi.v0.ProofH\x00\x12=\n\x08metadata\x18\x03 \x01(\x0b\x32+.org.dash.platform.dapi.v0.ResponseMetadata\x1a\x1a\n\x04Keys\x12\x12\n\nkeys_bytes\x18\x01 \x03(\x0c\x42\x08\n\x06result\"\xb2\x04\n\x18GetIdentitiesKeysRequest\x12\x14\n\x0cidentity_ids\x18\x01 \x03(\x0c\x12?\n\x0crequest_type\x18\x02 \x01(\x0b\x32).org.dash.platform.dapi.v0.KeyRequestType\x12+\n\x05limit\x18\x03 \x01(\x0b\x32\x1c.google.protobuf.UInt32Value\x12,\n\x06offset\x18\x04 \x01(\x0b\x32\x1c.google.protobuf.UInt32Value\x12\r\n\x05prove\x18\x05 \x01(\x08\x1a\xd4\x02\n\x10SecurityLevelMap\x12v\n\x12security_level_map\x18\x01 \x03(\x0b\x32Z.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.SecurityLevelMap.SecurityLevelMapEntry\x1a\x90\x01\n\x15SecurityLevelMapEntry\x12\x0b\n\x03key\x18\x01 \x01(\r\x12\x66\n\x05value\x18\x02 \x01(\x0e\x32W.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.SecurityLevelMap.KeyKindRequestType:\x02\x38\x01\"5\n\x12KeyKindRequestType\x12\x1f\n\x1b\x43URRENT_KEY_OF_KIND_REQUEST\x10\x00\"\xf4\x03\n\x19GetIdentitiesKeysResponse\x12\\\n\x0bpublic_keys\x18\x01 \x01(\x0b\x32\x45.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntriesH\x00\x12\x31\n\x05proof\x18\x02 \x01(\x0b\x32 .org.dash.platform.dapi.v0.ProofH\x00\x12=\n\x08metadata\x18\x03 \x01(\x0b\x32+.org.dash.platform.dapi.v0.ResponseMetadata\x1a\x1a\n\tPublicKey\x12\r\n\x05value\x18\x01 \x01(\x0c\x1al\n\x0ePublicKeyEntry\x12\x0b\n\x03key\x18\x01 \x01(\x0c\x12M\n\x05value\x18\x02 \x01(\x0b\x32>.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKey\x1as\n\x10PublicKeyEntries\x12_\n\x12public_key_entries\x18\x01 \x03(\x0b\x32\x43.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntryB\x08\n\x06result\"\xd7\x04\n\x10GetProofsRequest\x12O\n\nidentities\x18\x01 \x03(\x0b\x32;.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest\x12N\n\tcontracts\x18\x02 \x03(\x0b\x32;.org.dash.platform.dapi.v0.GetProofsRequest.ContractRequest\x12N\n\tdocuments\x18\x03 \x03(\x0b\x32;.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest\x1aw\n\x0f\x44ocumentRequest\x12\x13\n\x0b\x63ontract_id\x18\x01 \x01(\x0c\x12\x15\n\rdocument_type\x18\x02 \x01(\t\x12#\n\x1b\x64ocument_type_keeps_history\x18\x03 \x01(\x08\x12\x13\n\x0b\x64ocument_id\x18\x04 \x01(\x0c\x1a\xb0\x01\n\x0fIdentityRequest\x12\x13\n\x0bidentity_id\x18\x01 \x01(\x0c\x12V\n\x0crequest_type\x18\x02 \x01(\x0e\x32@.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest.Type\"0\n\x04Type\x12\x11\n\rFULL_IDENTITY\x10\x00\x12\x0b\n\x07\x42\x41LANCE\x10\x01\x12\x08\n\x04KEYS\x10\x02\x1a&\n\x0f\x43ontractRequest\x12\x13\n\x0b\x63ontract_id\x18\x01 \x01(\x0c\"\x83\x01\n\x11GetProofsResponse\x12/\n\x05proof\x18\x01 \x01(\x0b\x32 .org.dash.platform.dapi.v0.Proof\x12=\n\x08metadata\x18\x02 \x01(\x0b\x32+.org.dash.platform.dapi.v0.ResponseMetadata\"3\n\x16GetDataContractRequest\x12\n\n\x02id\x18\x01 \x01(\x0c\x12\r\n\x05prove\x18\x02 \x01(\x08\"\xae\x01\n\x17GetDataContractResponse\x12\x17\n\rdata_contract\x18\x01 \x01(\x0cH\x00\x12\x31\n\x05proof\x18\x02 \x01(\x0b\x32 .org.dash.platform.dapi.v0.ProofH\x00\x12=\n\x08metadata\x18\x03 \x01(\x0b\x32+.org.dash.platform.dapi.v0.ResponseMetadataB\x08\n\x06result\"5\n\x17GetDataContractsRequest\x12\x0b\n\x03ids\x18\x01 \x03(\x0c\x12\r\n\x05prove\x18\x02 \x01(\x08\"\x86\x04\n\x18GetDataContractsResponse\x12[\n\x0e\x64\x61ta_contracts\x18\x01 \x01(\x0b\x32\x41.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractsH\x00\x12\x31\n\x05proof\x18\x02 \x01(\x0b\x32 .org.dash.platform.dapi.v0.ProofH\x00\x12=\n\x08metadata\x18\x03 \x01(\x0b\x32+.org.dash.platform.dapi.v0.ResponseMetadata\x1a\"\n\x11\x44\x61taContractValue\x12\r\n\x05value\x18\x01 \x01(\x0c\x1av\n\x11\x44\x61taContractEntry\x12\x0b\n\x03key\x18\x01 \x01(\x0c\x12T\n\x05value\x18\x02 \x01(\x0b\x32\x45.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractValue\x1au\n\rDataContracts\x12\x64\n\x15\x64\x61ta_contract_entries\x18\x01 \x03(\x0b\x32\x45.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntryB\x08\n\x06result\"n\n\x1dGetDataContractHistoryRequest\x12\n\n\x02id\x18\x01 \x01(\x0c\x12\r\n\x05limit\x18\x02 \x01(\r\x12\x0e\n\x06offset\x18\x03 \x01(\r\x12\x13\n\x0bstart_at_ms\x18\x04 \x01(\x04\x12\r\n\x05prove\x18\x05 \x01(\x08\"\xd0\x03\n\x1eGetDataContractHistoryResponse\x12n\n\x15\x64\x61ta_contract_history\x18\x01 \x01(\x0b\x32M.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistoryH\x00\x12\x31\n\x05proof\x18\x02 \x01(\x0b\x32 .org.dash.platform.dapi.v0.ProofH\x00\x12=\n\x08metadata\x18\x03 \x01(\x0b\x32+.org.dash.platform.dapi.v0.ResponseMetadata\x1a\x37\n\x18\x44\x61taContractHistoryEntry\x12\x0c\n\x04\x64\x61te\x18\x01 \x01(\x04\x12\r\n\x05value\x18\x02 \x01(\x0c\x1a\x88\x01\n\x13\x44\x61taContractHistory\x12q\n\x15\x64\x61ta_contract_entries\x18\x01 \x03(\x0b\x32R.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistoryEntryB\x08\n\x06result\"\xb9\x01\n\x13GetDocumentsRequest\x12\x18\n\x10\x64\x61ta_contract_id\x18\x01 \x01(\x0c\x12\x15\n\rdocument_type\x18\x02 \x01(\t\x12\r\n\x05where\x18\x03 \x01(\x0c\x12\x10\n\x08order_by\x18\x04 \x01(\x0c\x12\r\n\x05limit\x18\x05 \x01(\r\x12\x15\n\x0bstart_after\x18\x06 \x01(\x0cH\x00\x12\x12\n\x08start_at\x18\x07 \x01(\x0cH\x00\x12\r\n\x05prove\x18\x08 \x01(\x08\x42\x07\n\x05start\"\x82\x02\n\x14GetDocumentsResponse\x12N\n\tdocuments\x18\x01 \x01(\x0b\x32\x39.org.dash.platform.dapi.v0.GetDocumentsResponse.DocumentsH\x00\x12\x31\n\x05proof\x18\x02 \x01(\x0b\x32 .org.dash.platform.dapi.v0.ProofH\x00\x12=\n\x08metadata\x18\x03 \x01(\x0b\x32+.org.dash.platform.dapi.v0.ResponseMetadata\x1a\x1e\n\tDocuments\x12\x11\n\tdocuments\x18\x01 \x03(\x0c\x42\x08\n\x06result\"Q\n%GetIdentitiesByPublicKeyHashesRequest\x12\x19\n\x11public_key_hashes\x18\x01 \x03(\x0c\x12\r\n\x05prove\x18\x02 \x01(\x08\"\xaa\x02\n&GetIdentitiesByPublicKeyHashesResponse\x12\x62\n\nidentities\x18\x01 \x01(\x0b\x32L.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.IdentitiesH\x00\x12\x31\n\x05proof\x18\x02 \x01(\x0b\x32 .org.dash.platform.dapi.v0.ProofH\x00\x12=\n\x08metadata\x18\x03 \x01(\x0b\x32+.org.dash.platform.dapi.v0.ResponseMetadata\x1a \n\nIdentities\x12\x12\n\nidentities\x18\x01 \x03(\x0c\x42\x08\n\x06result\"M\n#GetIdentityByPublicKeyHashesRequest\x12\x17\n\x0fpublic_key_hash\x18\x01 \x01(\x0c\x12\r\n\x05prove\x18\x02 \x01(\x08\"\xb6\x01\n$GetIdentityByPublicKeyHashesResponse\x12\x12\n\x08identity\x18\x01 \x01(\x0cH\x00\x12\x31\n\x05proof\x18\x02 \x01(\x0b\x32 .org.dash.platform.dapi.v0.ProofH\x00\x12=\n\x08metadata\x18\x03 \x01(\x0b\x32+.org.dash.platform.dapi.v0.ResponseMetadataB\x08\n\x06result\"S\n#WaitForStateTransitionResultRequest\x12\x1d\n\x15state_transition_hash\x18\x01 \x01(\x0c\x12\r\n\x05prove\x18\x02 \x01(\x08\"\xbd\x03\n$WaitForStateTransitionResultResponse\x12I\n\x05\x65rror\x18\x01 \x01(\x0b\x32\x38.org.dash.platform.dapi.v0.StateTransitionBroadcastErrorH\x00\x12\x31\n\x05proof\x18\x02 \x01(\x0b\x32 .org.dash.platform.dapi.v0.ProofH\x00\x12x\n\x17state_transition_result\x18\x04 \x01(\x0b\x32U.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.StateTransitionResultH\x00\x12=\n\x08metadata\x18\x03 \x01(\x0b\x32+.org.dash.platform.dapi.v0.ResponseMetadata\x1aT\n\x15StateTransitionResult\x12\x0e\n\x06height\x18\x01 \x01(\x04\x12\x13\n\x0bstorage_fee\x18\x02 \x01(\x04\x12\x16\n\x0eprocessing_fee\x18\x03 \x01(\x04\x42\x08\n\x06result\"P\n\x14\x43onsensusParamsBlock\x12\x11\n\tmax_bytes\x18\x01 \x01(\t\x12\x0f\n\x07max_gas\x18\x02 \x01(\t\x12\x14\n\x0ctime_iota_ms\x18\x03 \x01(\t\"b\n\x17\x43onsensusParamsEvidence\x12\x1a\n\x12max_age_num_blocks\x18\x01 \x01(\t\x12\x18\n\x10max_age_duration\x18\x02 \x01(\t\x12\x11\n\tmax_bytes\x18\x03 \x01(\t\":\n\x19GetConsensusParamsRequest\x12\x0e\n\x06height\x18\x01 \x01(\x03\x12\r\n\x05prove\x18\x02 \x01(\x08\"\xa2\x01\n\x1aGetConsensusParamsResponse\x12>\n\x05\x62lock\x18\x01 \x01(\x0b\x32/.org.dash.platform.dapi.v0.ConsensusParamsBlock\x12\x44\n\x08\x65vidence\x18\x02 \x01(\x0b\x32\x32.org.dash.platform.dapi.v0.ConsensusParamsEvidence\",\n\x1aGetBlockAttestationRequest\x12\x0e\n\x06height\x18\x01 \x01(\x04\"\xec\x02\n\x1bGetBlockAttestationResponse\x12\x62\n\x11\x62lock_attestation\x18\x01 \x01(\x0b\x32G.org.dash.platform.dapi.v0.GetBlockAttestationResponse.BlockAttestation\x12=\n\x08metadata\x18\x02 \x01(\x0b\x32+.org.dash.platform.dapi.v0.ResponseMetadata\x1a\xa9\x01\n\x10\x42lockAttestation\x12\x0e\n\x06height\x18\x01 \x01(\x04\x12\x10\n\x08\x61pp_hash\x18\x02 \x01(\x0c\x12\r\n\x05\x65poch\x18\x03 \x01(\r\x12\x18\n\x10protocol_version\x18\x04 \x01(\r\x12\x13\n\x0bquorum_hash\x18\x05 \x01(\x0c\x12\x13\n\x0bquorum_type\x18\x06 \x01(\r\x12\r\n\x05round\x18\x07 \x01(\r\x12\x11\n\tsignature\x18\x08 \x01(\x0c\".\n\x12\x45stimateFeeRequest\x12\x18\n\x10state_transition\x18\x01 \x01(\x0c\"\xc6\x06\n\x13\x45stimateFeeResponse\x12R\n\x0c\x66\x65\x65_estimate\x18\x01 \x01(\x0b\x32:.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimateH\x00\x12I\n\x05\x65rror\x18\x02 \x01(\x0b\x32\x38.org.dash.platform.dapi.v0.StateTransitionBroadcastErrorH\x00\x12=\n\x08metadata\x18\x03 \x01(\x0b\x32+.org.dash.platform.dapi.v0.ResponseMetadata\x1a\xc6\x04\n\x0b\x46\x65\x65\x45stimate\x12\x13\n\x0bstorage_fee\x18\x01 \x01(\x04\x12\x16\n\x0eprocessing_fee\x18\x02 \x01(\x04\x12R\n\x07refunds\x18\x03 \x03(\x0b\x32\x41.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.Refund\x12`\n\x0e\x62\x61lance_change\x18\x04 \x01(\x0b\x32H.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange\x1a.\n\x06Refund\x12\x13\n\x0bidentity_id\x18\x01 \x01(\x0c\x12\x0f\n\x07\x63redits\x18\x02 \x01(\x04\x1a\xa3\x02\n\rBalanceChange\x12\x18\n\x0e\x61\x64\x64_to_balance\x18\x01 \x01(\x04H\x00\x12y\n\x13remove_from_balance\x18\x02 \x01(\x0b\x32Z.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.RemoveFromBalanceH\x00\x12\x1b\n\x11no_balance_change\x18\x03 \x01(\x08H\x00\x1aV\n\x11RemoveFromBalance\x12 \n\x18required_removed_balance\x18\x01 \x01(\x04\x12\x1f\n\x17\x64\x65sired_removed_balance\x18\x02 \x01(\x04\x42\x08\n\x06\x63hangeB\x08\n\x06result2\xde\x13\n\x08Platform\x12\x93\x01\n\x18\x62roadcastStateTransition\x12:.org.dash.platform.dapi.v0.BroadcastStateTransitionRequest\x1a;.org.dash.platform.dapi.v0.BroadcastStateTransitionResponse\x12l\n\x0bgetIdentity\x12-.org.dash.platform.dapi.v0.GetIdentityRequest\x1a..org.dash.platform.dapi.v0.GetIdentityResponse\x12r\n\rgetIdentities\x12/.org.dash.platform.dapi.v0.GetIdentitiesRequest\x1a\x30.org.dash.platform.dapi.v0.GetIdentitiesResponse\x12x\n\x0fgetIdentityKeys\x12\x31.org.dash.platform.dapi.v0.GetIdentityKeysRequest\x1a\x32.org.dash.platform.dapi.v0.GetIdentityKeysResponse\x12z\n\x12getIdentityBalance\x12-.org.dash.platform.dapi.v0.GetIdentityRequest\x1a\x35.org.dash.platform.dapi.v0.GetIdentityBalanceResponse\x12\x90\x01\n\x1dgetIdentityBalanceAndRevision\x12-.org.dash.platform.dapi.v0.GetIdentityRequest\x1a@.org.dash.platform.dapi.v0.GetIdentityBalanceAndRevisionResponse\x12v\n\x10getIdentityNonce\x12-.org.dash.platform.dapi.v0.GetIdentityRequest\x1a\x33.org.dash.platform.dapi.v0.GetIdentityNonceResponse\x12\x93\x01\n\x18getIdentityContractNonce\x12:.org.dash.platform.dapi.v0.GetIdentityContractNonceRequest\x1a;.org.dash.platform.dapi.v0.GetIdentityContractNonceResponse\x12\x66\n\tgetProofs\x12+.org.dash.platform.dapi.v0.GetProofsRequest\x1a,.org.dash.platform.dapi.v0.GetProofsResponse\x12x\n\x0fgetDataContract\x12\x31.org.dash.platform.dapi.v0.GetDataContractRequest\x1a\x32.org.dash.platform.dapi.v0.GetDataContractResponse\x12\x8d\x01\n\x16getDataContractHistory\x12\x38.org.dash.platform.dapi.v0.GetDataContractHistoryRequest\x1a\x39.org.dash.platform.dapi.v0.GetDataContractHistoryResponse\x12{\n\x10getDataContracts\x12\x32.org.dash.platform.dapi.v0.GetDataContractsRequest\x1a\x33.org.dash.platform.dapi.v0.GetDataContractsResponse\x12o\n\x0cgetDocuments\x12..org.dash.platform.dapi.v0.GetDocumentsRequest\x1a/.org.dash.platform.dapi.v0.GetDocumentsResponse\x12\xa5\x01\n\x1egetIdentitiesByPublicKeyHashes\x12@.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesRequest\x1a\x41.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse\x12\x9f\x01\n\x1cgetIdentityByPublicKeyHashes\x12>.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesRequest\x1a?.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse\x12\x9f\x01\n\x1cwaitForStateTransitionResult\x12>.org.dash.platform.dapi.v0.WaitForStateTransitionResultRequest\x1a?.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse\x12\x81\x01\n\x12getConsensusParams\x12\x34.org.dash.platform.dapi.v0.GetConsensusParamsRequest\x1a\x35.org.dash.platform.dapi.v0.GetConsensusParamsResponse\x12\x84\x01\n\x13getBlockAttestation\x12\x35.org.dash.platform.dapi.v0.GetBlockAttestationRequest\x1a\x36.org.dash.platform.dapi.v0.GetBlockAttestationResponse\x12l\n\x0b\x65stimateFee\x12-.org.dash.platform.dapi.v0.EstimateFeeRequest\x1a..org.dash.platform.dapi.v0.EstimateFeeResponseb\x06proto3'
   ,
   dependencies=[google_dot_protobuf_dot_wrappers__pb2.DESCRIPTOR,google_dot_protobuf_dot_struct__pb2.DESCRIPTOR,google_dot_protobuf_dot_timestamp__pb2.DESCRIPTOR,])
 
@@ -48,8 +48,8 @@ _SECURITYLEVELMAP_KEYKINDREQUESTTYPE = _descriptor.EnumDescriptor(
   ],
   containing_type=None,
   serialized_options=None,
-  serialized_start=2544,
-  serialized_end=2627,
+  serialized_start=3108,
+  serialized_end=3191,
 )
 _sym_db.RegisterEnumDescriptor(_SECURITYLEVELMAP_KEYKINDREQUESTTYPE)
 
@@ -68,8 +68,8 @@ _GETIDENTITIESKEYSREQUEST_SECURITYLEVELMAP_KEYKINDREQUESTTYPE = _descriptor.Enum
   ],
   containing_type=None,
   serialized_options=None,
-  serialized_start=2544,
-  serialized_end=2597,
+  serialized_start=3108,
+  serialized_end=3161,
 )
 _sym_db.RegisterEnumDescriptor(_GETIDENTITIESKEYSREQUEST_SECURITYLEVELMAP_KEYKINDREQUESTTYPE)
 
@@ -98,8 +98,8 @@ _GETPROOFSREQUEST_IDENTITYREQUEST_TYPE = _descriptor.EnumDescriptor(
   ],
   containing_type=None,
   serialized_options=None,
-  serialized_start=4681,
-  serialized_end=4729,
+  serialized_start=5245,
+  serialized_end=5293,
 )
 _sym_db.RegisterEnumDescriptor(_GETPROOFSREQUEST_IDENTITYREQUEST_TYPE)
 
@@ -140,6 +140,20 @@ _PROOF = _descriptor.Descriptor(
       message_type=None, enum_type=None, containing_type=None,
       is_extension=False, extension_scope=None,
       serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='block_id_hash', full_name='org.dash.platform.dapi.v0.Proof.block_id_hash', index=4,
+      number=5, type=12, cpp_type=9, label=1,
+      has_default_value=False, default_value=b"",
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='quorum_type', full_name='org.dash.platform.dapi.v0.Proof.quorum_type', index=5,
+      number=6, type=13, cpp_type=3, label=1,
+      has_default_value=False, default_value=0,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
   ],
   extensions=[
   ],
@@ -152,8 +166,8 @@ _PROOF = _descriptor.Descriptor(
   extension_ranges=[],
   oneofs=[
   ],
-  serialized_start=140,
-  serialized_end=225,
+  serialized_start=141,
+  serialized_end=270,
 )
 
 
@@ -193,6 +207,13 @@ _RESPONSEMETADATA = _descriptor.Descriptor(
       message_type=None, enum_type=None, containing_type=None,
       is_extension=False, extension_scope=None,
       serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='chain_id', full_name='org.dash.platform.dapi.v0.ResponseMetadata.chain_id', index=4,
+      number=5, type=9, cpp_type=9, label=1,
+      has_default_value=False, default_value=b"".decode('utf-8'),
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
   ],
   extensions=[
   ],
@@ -205,8 +226,8 @@ _RESPONSEMETADATA = _descriptor.Descriptor(
   extension_ranges=[],
   oneofs=[
   ],
-  serialized_start=227,
-  serialized_end=338,
+  serialized_start=273,
+  serialized_end=402,
 )
 
 
@@ -251,8 +272,8 @@ _STATETRANSITIONBROADCASTERROR = _descriptor.Descriptor(
   extension_ranges=[],
   oneofs=[
   ],
-  serialized_start=340,
-  serialized_end=416,
+  serialized_start=404,
+  serialized_end=480,
 )
 
 
@@ -283,8 +304,8 @@ _BROADCASTSTATETRANSITIONREQUEST = _descriptor.Descriptor(
   extension_ranges=[],
   oneofs=[
   ],
-  serialized_start=418,
-  serialized_end=477,
+  serialized_start=482,
+  serialized_end=541,
 )
 
 
@@ -308,8 +329,8 @@ _BROADCASTSTATETRANSITIONRESPONSE = _descriptor.Descriptor(
   extension_ranges=[],
   oneofs=[
   ],
-  serialized_start=479,
-  serialized_end=513,
+  serialized_start=543,
+  serialized_end=577,
 )
 
 
@@ -347,8 +368,8 @@ _GETIDENTITYREQUEST = _descriptor.Descriptor(
   extension_ranges=[],
   oneofs=[
   ],
-  serialized_start=515,
-  serialized_end=562,
+  serialized_start=579,
+  serialized_end=626,
 )
 
 
@@ -398,8 +419,8 @@ _GETIDENTITYRESPONSE = _descriptor.Descriptor(
       create_key=_descriptor._internal_create_key,
     fields=[]),
   ],
-  serialized_start=565,
-  serialized_end=730,
+  serialized_start=629,
+  serialized_end=794,
 )
 
 
@@ -437,8 +458,8 @@ _GETIDENTITIESREQUEST = _descriptor.Descriptor(
   extension_ranges=[],
   oneofs=[
   ],
-  serialized_start=732,
-  serialized_end=782,
+  serialized_start=796,
+  serialized_end=846,
 )
 
 
@@ -469,8 +490,8 @@ _GETIDENTITIESRESPONSE_IDENTITYVALUE = _descriptor.Descriptor(
   extension_ranges=[],
   oneofs=[
   ],
-  serialized_start=1007,
-  serialized_end=1037,
+  serialized_start=1071,
+  serialized_end=1101,
 )
 
 _GETIDENTITIESRESPONSE_IDENTITYENTRY = _descriptor.Descriptor(
@@ -507,8 +528,8 @@ _GETIDENTITIESRESPONSE_IDENTITYENTRY = _descriptor.Descriptor(
   extension_ranges=[],
   oneofs=[
   ],
-  serialized_start=1039,
-  serialized_end=1146,
+  serialized_start=1103,
+  serialized_end=1210,
 )
 
 _GETIDENTITIESRESPONSE_IDENTITIES = _descriptor.Descriptor(
@@ -538,8 +559,8 @@ _GETIDENTITIESRESPONSE_IDENTITIES = _descriptor.Descriptor(
   extension_ranges=[],
   oneofs=[
   ],
-  serialized_start=1148,
-  serialized_end=1250,
+  serialized_start=1212,
+  serialized_end=1314,
 )
 
 _GETIDENTITIESRESPONSE = _descriptor.Descriptor(
@@ -588,8 +609,8 @@ _GETIDENTITIESRESPONSE = _descriptor.Descriptor(
       create_key=_descriptor._internal_create_key,
     fields=[]),
   ],
-  serialized_start=785,
-  serialized_end=1260,
+  serialized_start=849,
+  serialized_end=1324,
 )
 
 
@@ -639,8 +660,8 @@ _GETIDENTITYBALANCERESPONSE = _descriptor.Descriptor(
       create_key=_descriptor._internal_create_key,
     fields=[]),
   ],
-  serialized_start=1263,
-  serialized_end=1464,
+  serialized_start=1327,
+  serialized_end=1528,
 )
 
 
@@ -678,8 +699,8 @@ _GETIDENTITYBALANCEANDREVISIONRESPONSE_BALANCEANDREVISION = _descriptor.Descript
   extension_ranges=[],
   oneofs=[
   ],
-  serialized_start=1739,
-  serialized_end=1854,
+  serialized_start=1803,
+  serialized_end=1918,
 )
 
 _GETIDENTITYBALANCEANDREVISIONRESPONSE = _descriptor.Descriptor(
@@ -728,8 +749,156 @@ _GETIDENTITYBALANCEANDREVISIONRESPONSE = _descriptor.Descriptor(
       create_key=_descriptor._internal_create_key,
     fields=[]),
   ],
-  serialized_start=1467,
-  serialized_end=1864,
+  serialized_start=1531,
+  serialized_end=1928,
+)
+
+
+_GETIDENTITYNONCERESPONSE = _descriptor.Descriptor(
+  name='GetIdentityNonceResponse',
+  full_name='org.dash.platform.dapi.v0.GetIdentityNonceResponse',
+  filename=None,
+  file=DESCRIPTOR,
+  containing_type=None,
+  create_key=_descriptor._internal_create_key,
+  fields=[
+    _descriptor.FieldDescriptor(
+      name='nonce', full_name='org.dash.platform.dapi.v0.GetIdentityNonceResponse.nonce', index=0,
+      number=1, type=11, cpp_type=10, label=1,
+      has_default_value=False, default_value=None,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='proof', full_name='org.dash.platform.dapi.v0.GetIdentityNonceResponse.proof', index=1,
+      number=2, type=11, cpp_type=10, label=1,
+      has_default_value=False, default_value=None,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='metadata', full_name='org.dash.platform.dapi.v0.GetIdentityNonceResponse.metadata', index=2,
+      number=3, type=11, cpp_type=10, label=1,
+      has_default_value=False, default_value=None,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+  ],
+  extensions=[
+  ],
+  nested_types=[],
+  enum_types=[
+  ],
+  serialized_options=None,
+  is_extendable=False,
+  syntax='proto3',
+  extension_ranges=[],
+  oneofs=[
+    _descriptor.OneofDescriptor(
+      name='result', full_name='org.dash.platform.dapi.v0.GetIdentityNonceResponse.result',
+      index=0, containing_type=None,
+      create_key=_descriptor._internal_create_key,
+    fields=[]),
+  ],
+  serialized_start=1931,
+  serialized_end=2128,
+)
+
+
+_GETIDENTITYCONTRACTNONCEREQUEST = _descriptor.Descriptor(
+  name='GetIdentityContractNonceRequest',
+  full_name='org.dash.platform.dapi.v0.GetIdentityContractNonceRequest',
+  filename=None,
+  file=DESCRIPTOR,
+  containing_type=None,
+  create_key=_descriptor._internal_create_key,
+  fields=[
+    _descriptor.FieldDescriptor(
+      name='identity_id', full_name='org.dash.platform.dapi.v0.GetIdentityContractNonceRequest.identity_id', index=0,
+      number=1, type=12, cpp_type=9, label=1,
+      has_default_value=False, default_value=b"",
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='contract_id', full_name='org.dash.platform.dapi.v0.GetIdentityContractNonceRequest.contract_id', index=1,
+      number=2, type=12, cpp_type=9, label=1,
+      has_default_value=False, default_value=b"",
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='prove', full_name='org.dash.platform.dapi.v0.GetIdentityContractNonceRequest.prove', index=2,
+      number=3, type=8, cpp_type=7, label=1,
+      has_default_value=False, default_value=False,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+  ],
+  extensions=[
+  ],
+  nested_types=[],
+  enum_types=[
+  ],
+  serialized_options=None,
+  is_extendable=False,
+  syntax='proto3',
+  extension_ranges=[],
+  oneofs=[
+  ],
+  serialized_start=2130,
+  serialized_end=2220,
+)
+
+
+_GETIDENTITYCONTRACTNONCERESPONSE = _descriptor.Descriptor(
+  name='GetIdentityContractNonceResponse',
+  full_name='org.dash.platform.dapi.v0.GetIdentityContractNonceResponse',
+  filename=None,
+  file=DESCRIPTOR,
+  containing_type=None,
+  create_key=_descriptor._internal_create_key,
+  fields=[
+    _descriptor.FieldDescriptor(
+      name='nonce', full_name='org.dash.platform.dapi.v0.GetIdentityContractNonceResponse.nonce', index=0,
+      number=1, type=11, cpp_type=10, label=1,
+      has_default_value=False, default_value=None,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='proof', full_name='org.dash.platform.dapi.v0.GetIdentityContractNonceResponse.proof', index=1,
+      number=2, type=11, cpp_type=10, label=1,
+      has_default_value=False, default_value=None,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='metadata', full_name='org.dash.platform.dapi.v0.GetIdentityContractNonceResponse.metadata', index=2,
+      number=3, type=11, cpp_type=10, label=1,
+      has_default_value=False, default_value=None,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+  ],
+  extensions=[
+  ],
+  nested_types=[],
+  enum_types=[
+  ],
+  serialized_options=None,
+  is_extendable=False,
+  syntax='proto3',
+  extension_ranges=[],
+  oneofs=[
+    _descriptor.OneofDescriptor(
+      name='result', full_name='org.dash.platform.dapi.v0.GetIdentityContractNonceResponse.result',
+      index=0, containing_type=None,
+      create_key=_descriptor._internal_create_key,
+    fields=[]),
+  ],
+  serialized_start=2223,
+  serialized_end=2428,
 )
 
 
@@ -779,8 +948,8 @@ _KEYREQUESTTYPE = _descriptor.Descriptor(
       create_key=_descriptor._internal_create_key,
     fields=[]),
   ],
-  serialized_start=1867,
-  serialized_end=2076,
+  serialized_start=2431,
+  serialized_end=2640,
 )
 
 
@@ -804,8 +973,8 @@ _ALLKEYS = _descriptor.Descriptor(
   extension_ranges=[],
   oneofs=[
   ],
-  serialized_start=2078,
-  serialized_end=2087,
+  serialized_start=2642,
+  serialized_end=2651,
 )
 
 
@@ -836,8 +1005,8 @@ _SPECIFICKEYS = _descriptor.Descriptor(
   extension_ranges=[],
   oneofs=[
   ],
-  serialized_start=2089,
-  serialized_end=2120,
+  serialized_start=2653,
+  serialized_end=2684,
 )
 
 
@@ -875,8 +1044,8 @@ _SEARCHKEY_PURPOSEMAPENTRY = _descriptor.Descriptor(
   extension_ranges=[],
   oneofs=[
   ],
-  serialized_start=2211,
-  serialized_end=2305,
+  serialized_start=2775,
+  serialized_end=2869,
 )
 
 _SEARCHKEY = _descriptor.Descriptor(
@@ -906,8 +1075,8 @@ _SEARCHKEY = _descriptor.Descriptor(
   extension_ranges=[],
   oneofs=[
   ],
-  serialized_start=2123,
-  serialized_end=2305,
+  serialized_start=2687,
+  serialized_end=2869,
 )
 
 
@@ -945,8 +1114,8 @@ _SECURITYLEVELMAP_SECURITYLEVELMAPENTRY = _descriptor.Descriptor(
   extension_ranges=[],
   oneofs=[
   ],
-  serialized_start=2423,
-  serialized_end=2542,
+  serialized_start=2987,
+  serialized_end=3106,
 )
 
 _SECURITYLEVELMAP = _descriptor.Descriptor(
@@ -977,8 +1146,8 @@ _SECURITYLEVELMAP = _descriptor.Descriptor(
   extension_ranges=[],
   oneofs=[
   ],
-  serialized_start=2308,
-  serialized_end=2627,
+  serialized_start=2872,
+  serialized_end=3191,
 )
 
 
@@ -1037,8 +1206,8 @@ _GETIDENTITYKEYSREQUEST = _descriptor.Descriptor(
   extension_ranges=[],
   oneofs=[
   ],
-  serialized_start=2630,
-  serialized_end=2846,
+  serialized_start=3194,
+  serialized_end=3410,
 )
 
 
@@ -1069,8 +1238,8 @@ _GETIDENTITYKEYSRESPONSE_KEYS = _descriptor.Descriptor(
   extension_ranges=[],
   oneofs=[
   ],
-  serialized_start=3063,
-  serialized_end=3089,
+  serialized_start=3627,
+  serialized_end=3653,
 )
 
 _GETIDENTITYKEYSRESPONSE = _descriptor.Descriptor(
@@ -1119,8 +1288,8 @@ _GETIDENTITYKEYSRESPONSE = _descriptor.Descriptor(
       create_key=_descriptor._internal_create_key,
     fields=[]),
   ],
-  serialized_start=2849,
-  serialized_end=3099,
+  serialized_start=3413,
+  serialized_end=3663,
 )
 
 
@@ -1158,8 +1327,8 @@ _GETIDENTITIESKEYSREQUEST_SECURITYLEVELMAP_SECURITYLEVELMAPENTRY = _descriptor.D
   extension_ranges=[],
   oneofs=[
   ],
-  serialized_start=3465,
-  serialized_end=3609,
+  serialized_start=4029,
+  serialized_end=4173,
 )
 
 _GETIDENTITIESKEYSREQUEST_SECURITYLEVELMAP = _descriptor.Descriptor(
@@ -1190,8 +1359,8 @@ _GETIDENTITIESKEYSREQUEST_SECURITYLEVELMAP = _descriptor.Descriptor(
   extension_ranges=[],
   oneofs=[
   ],
-  serialized_start=3324,
-  serialized_end=3664,
+  serialized_start=3888,
+  serialized_end=4228,
 )
 
 _GETIDENTITIESKEYSREQUEST = _descriptor.Descriptor(
@@ -1249,8 +1418,8 @@ _GETIDENTITIESKEYSREQUEST = _descriptor.Descriptor(
   extension_ranges=[],
   oneofs=[
   ],
-  serialized_start=3102,
-  serialized_end=3664,
+  serialized_start=3666,
+  serialized_end=4228,
 )
 
 
@@ -1281,8 +1450,8 @@ _GETIDENTITIESKEYSRESPONSE_PUBLICKEY = _descriptor.Descriptor(
   extension_ranges=[],
   oneofs=[
   ],
-  serialized_start=3904,
-  serialized_end=3930,
+  serialized_start=4468,
+  serialized_end=4494,
 )
 
 _GETIDENTITIESKEYSRESPONSE_PUBLICKEYENTRY = _descriptor.Descriptor(
@@ -1319,8 +1488,8 @@ _GETIDENTITIESKEYSRESPONSE_PUBLICKEYENTRY = _descriptor.Descriptor(
   extension_ranges=[],
   oneofs=[
   ],
-  serialized_start=3932,
-  serialized_end=4040,
+  serialized_start=4496,
+  serialized_end=4604,
 )
 
 _GETIDENTITIESKEYSRESPONSE_PUBLICKEYENTRIES = _descriptor.Descriptor(
@@ -1350,8 +1519,8 @@ _GETIDENTITIESKEYSRESPONSE_PUBLICKEYENTRIES = _descriptor.Descriptor(
   extension_ranges=[],
   oneofs=[
   ],
-  serialized_start=4042,
-  serialized_end=4157,
+  serialized_start=4606,
+  serialized_end=4721,
 )
 
 _GETIDENTITIESKEYSRESPONSE = _descriptor.Descriptor(
@@ -1400,8 +1569,8 @@ _GETIDENTITIESKEYSRESPONSE = _descriptor.Descriptor(
       create_key=_descriptor._internal_create_key,
     fields=[]),
   ],
-  serialized_start=3667,
-  serialized_end=4167,
+  serialized_start=4231,
+  serialized_end=4731,
 )
 
 
@@ -1453,8 +1622,8 @@ _GETPROOFSREQUEST_DOCUMENTREQUEST = _descriptor.Descriptor(
   extension_ranges=[],
   oneofs=[
   ],
-  serialized_start=4431,
-  serialized_end=4550,
+  serialized_start=4995,
+  serialized_end=5114,
 )
 
 _GETPROOFSREQUEST_IDENTITYREQUEST = _descriptor.Descriptor(
@@ -1492,8 +1661,8 @@ _GETPROOFSREQUEST_IDENTITYREQUEST = _descriptor.Descriptor(
   extension_ranges=[],
   oneofs=[
   ],
-  serialized_start=4553,
-  serialized_end=4729,
+  serialized_start=5117,
+  serialized_end=5293,
 )
 
 _GETPROOFSREQUEST_CONTRACTREQUEST = _descriptor.Descriptor(
@@ -1523,8 +1692,8 @@ _GETPROOFSREQUEST_CONTRACTREQUEST = _descriptor.Descriptor(
   extension_ranges=[],
   oneofs=[
   ],
-  serialized_start=4731,
-  serialized_end=4769,
+  serialized_start=5295,
+  serialized_end=5333,
 )
 
 _GETPROOFSREQUEST = _descriptor.Descriptor(
@@ -1568,8 +1737,8 @@ _GETPROOFSREQUEST = _descriptor.Descriptor(
   extension_ranges=[],
   oneofs=[
   ],
-  serialized_start=4170,
-  serialized_end=4769,
+  serialized_start=4734,
+  serialized_end=5333,
 )
 
 
@@ -1607,8 +1776,8 @@ _GETPROOFSRESPONSE = _descriptor.Descriptor(
   extension_ranges=[],
   oneofs=[
   ],
-  serialized_start=4772,
-  serialized_end=4903,
+  serialized_start=5336,
+  serialized_end=5467,
 )
 
 
@@ -1646,8 +1815,8 @@ _GETDATACONTRACTREQUEST = _descriptor.Descriptor(
   extension_ranges=[],
   oneofs=[
   ],
-  serialized_start=4905,
-  serialized_end=4956,
+  serialized_start=5469,
+  serialized_end=5520,
 )
 
 
@@ -1697,8 +1866,8 @@ _GETDATACONTRACTRESPONSE = _descriptor.Descriptor(
       create_key=_descriptor._internal_create_key,
     fields=[]),
   ],
-  serialized_start=4959,
-  serialized_end=5133,
+  serialized_start=5523,
+  serialized_end=5697,
 )
 
 
@@ -1736,8 +1905,8 @@ _GETDATACONTRACTSREQUEST = _descriptor.Descriptor(
   extension_ranges=[],
   oneofs=[
   ],
-  serialized_start=5135,
-  serialized_end=5188,
+  serialized_start=5699,
+  serialized_end=5752,
 )
 
 
@@ -1768,8 +1937,8 @@ _GETDATACONTRACTSRESPONSE_DATACONTRACTVALUE = _descriptor.Descriptor(
   extension_ranges=[],
   oneofs=[
   ],
-  serialized_start=5426,
-  serialized_end=5460,
+  serialized_start=5990,
+  serialized_end=6024,
 )
 
 _GETDATACONTRACTSRESPONSE_DATACONTRACTENTRY = _descriptor.Descriptor(
@@ -1806,8 +1975,8 @@ _GETDATACONTRACTSRESPONSE_DATACONTRACTENTRY = _descriptor.Descriptor(
   extension_ranges=[],
   oneofs=[
   ],
-  serialized_start=5462,
-  serialized_end=5580,
+  serialized_start=6026,
+  serialized_end=6144,
 )
 
 _GETDATACONTRACTSRESPONSE_DATACONTRACTS = _descriptor.Descriptor(
@@ -1837,8 +2006,8 @@ _GETDATACONTRACTSRESPONSE_DATACONTRACTS = _descriptor.Descriptor(
   extension_ranges=[],
   oneofs=[
   ],
-  serialized_start=5582,
-  serialized_end=5699,
+  serialized_start=6146,
+  serialized_end=6263,
 )
 
 _GETDATACONTRACTSRESPONSE = _descriptor.Descriptor(
@@ -1887,8 +2056,8 @@ _GETDATACONTRACTSRESPONSE = _descriptor.Descriptor(
       create_key=_descriptor._internal_create_key,
     fields=[]),
   ],
-  serialized_start=5191,
-  serialized_end=5709,
+  serialized_start=5755,
+  serialized_end=6273,
 )
 
 
@@ -1947,8 +2116,8 @@ _GETDATACONTRACTHISTORYREQUEST = _descriptor.Descriptor(
   extension_ranges=[],
   oneofs=[
   ],
-  serialized_start=5711,
-  serialized_end=5821,
+  serialized_start=6275,
+  serialized_end=6385,
 )
 
 
@@ -1986,8 +2155,8 @@ _GETDATACONTRACTHISTORYRESPONSE_DATACONTRACTHISTORYENTRY = _descriptor.Descripto
   extension_ranges=[],
   oneofs=[
   ],
-  serialized_start=6084,
-  serialized_end=6139,
+  serialized_start=6648,
+  serialized_end=6703,
 )
 
 _GETDATACONTRACTHISTORYRESPONSE_DATACONTRACTHISTORY = _descriptor.Descriptor(
@@ -2017,8 +2186,8 @@ _GETDATACONTRACTHISTORYRESPONSE_DATACONTRACTHISTORY = _descriptor.Descriptor(
   extension_ranges=[],
   oneofs=[
   ],
-  serialized_start=6142,
-  serialized_end=6278,
+  serialized_start=6706,
+  serialized_end=6842,
 )
 
 _GETDATACONTRACTHISTORYRESPONSE = _descriptor.Descriptor(
@@ -2067,8 +2236,8 @@ _GETDATACONTRACTHISTORYRESPONSE = _descriptor.Descriptor(
       create_key=_descriptor._internal_create_key,
     fields=[]),
   ],
-  serialized_start=5824,
-  serialized_end=6288,
+  serialized_start=6388,
+  serialized_end=6852,
 )
 
 
@@ -2153,8 +2322,8 @@ _GETDOCUMENTSREQUEST = _descriptor.Descriptor(
       create_key=_descriptor._internal_create_key,
     fields=[]),
   ],
-  serialized_start=6291,
-  serialized_end=6476,
+  serialized_start=6855,
+  serialized_end=7040,
 )
 
 
@@ -2185,8 +2354,8 @@ _GETDOCUMENTSRESPONSE_DOCUMENTS = _descriptor.Descriptor(
   extension_ranges=[],
   oneofs=[
   ],
-  serialized_start=6697,
-  serialized_end=6727,
+  serialized_start=7261,
+  serialized_end=7291,
 )
 
 _GETDOCUMENTSRESPONSE = _descriptor.Descriptor(
@@ -2235,8 +2404,8 @@ _GETDOCUMENTSRESPONSE = _descriptor.Descriptor(
       create_key=_descriptor._internal_create_key,
     fields=[]),
   ],
-  serialized_start=6479,
-  serialized_end=6737,
+  serialized_start=7043,
+  serialized_end=7301,
 )
 
 
@@ -2274,8 +2443,8 @@ _GETIDENTITIESBYPUBLICKEYHASHESREQUEST = _descriptor.Descriptor(
   extension_ranges=[],
   oneofs=[
   ],
-  serialized_start=6739,
-  serialized_end=6820,
+  serialized_start=7303,
+  serialized_end=7384,
 )
 
 
@@ -2306,8 +2475,8 @@ _GETIDENTITIESBYPUBLICKEYHASHESRESPONSE_IDENTITIES = _descriptor.Descriptor(
   extension_ranges=[],
   oneofs=[
   ],
-  serialized_start=7079,
-  serialized_end=7111,
+  serialized_start=7643,
+  serialized_end=7675,
 )
 
 _GETIDENTITIESBYPUBLICKEYHASHESRESPONSE = _descriptor.Descriptor(
@@ -2356,8 +2525,8 @@ _GETIDENTITIESBYPUBLICKEYHASHESRESPONSE = _descriptor.Descriptor(
       create_key=_descriptor._internal_create_key,
     fields=[]),
   ],
-  serialized_start=6823,
-  serialized_end=7121,
+  serialized_start=7387,
+  serialized_end=7685,
 )
 
 
@@ -2395,8 +2564,8 @@ _GETIDENTITYBYPUBLICKEYHASHESREQUEST = _descriptor.Descriptor(
   extension_ranges=[],
   oneofs=[
   ],
-  serialized_start=7123,
-  serialized_end=7200,
+  serialized_start=7687,
+  serialized_end=7764,
 )
 
 
@@ -2446,8 +2615,8 @@ _GETIDENTITYBYPUBLICKEYHASHESRESPONSE = _descriptor.Descriptor(
       create_key=_descriptor._internal_create_key,
     fields=[]),
   ],
-  serialized_start=7203,
-  serialized_end=7385,
+  serialized_start=7767,
+  serialized_end=7949,
 )
 
 
@@ -2485,11 +2654,56 @@ _WAITFORSTATETRANSITIONRESULTREQUEST = _descriptor.Descriptor(
   extension_ranges=[],
   oneofs=[
   ],
-  serialized_start=7387,
-  serialized_end=7470,
+  serialized_start=7951,
+  serialized_end=8034,
 )
 
 
+_WAITFORSTATETRANSITIONRESULTRESPONSE_STATETRANSITIONRESULT = _descriptor.Descriptor(
+  name='StateTransitionResult',
+  full_name='org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.StateTransitionResult',
+  filename=None,
+  file=DESCRIPTOR,
+  containing_type=None,
+  create_key=_descriptor._internal_create_key,
+  fields=[
+    _descriptor.FieldDescriptor(
+      name='height', full_name='org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.StateTransitionResult.height', index=0,
+      number=1, type=4, cpp_type=4, label=1,
+      has_default_value=False, default_value=0,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='storage_fee', full_name='org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.StateTransitionResult.storage_fee', index=1,
+      number=2, type=4, cpp_type=4, label=1,
+      has_default_value=False, default_value=0,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='processing_fee', full_name='org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.StateTransitionResult.processing_fee', index=2,
+      number=3, type=4, cpp_type=4, label=1,
+      has_default_value=False, default_value=0,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+  ],
+  extensions=[
+  ],
+  nested_types=[],
+  enum_types=[
+  ],
+  serialized_options=None,
+  is_extendable=False,
+  syntax='proto3',
+  extension_ranges=[],
+  oneofs=[
+  ],
+  serialized_start=8388,
+  serialized_end=8472,
+)
+
 _WAITFORSTATETRANSITIONRESULTRESPONSE = _descriptor.Descriptor(
   name='WaitForStateTransitionResultResponse',
   full_name='org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse',
@@ -2513,7 +2727,14 @@ _WAITFORSTATETRANSITIONRESULTRESPONSE = _descriptor.Descriptor(
       is_extension=False, extension_scope=None,
       serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
     _descriptor.FieldDescriptor(
-      name='metadata', full_name='org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.metadata', index=2,
+      name='state_transition_result', full_name='org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.state_transition_result', index=2,
+      number=4, type=11, cpp_type=10, label=1,
+      has_default_value=False, default_value=None,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='metadata', full_name='org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.metadata', index=3,
       number=3, type=11, cpp_type=10, label=1,
       has_default_value=False, default_value=None,
       message_type=None, enum_type=None, containing_type=None,
@@ -2522,7 +2743,7 @@ _WAITFORSTATETRANSITIONRESULTRESPONSE = _descriptor.Descriptor(
   ],
   extensions=[
   ],
-  nested_types=[],
+  nested_types=[_WAITFORSTATETRANSITIONRESULTRESPONSE_STATETRANSITIONRESULT, ],
   enum_types=[
   ],
   serialized_options=None,
@@ -2536,8 +2757,8 @@ _WAITFORSTATETRANSITIONRESULTRESPONSE = _descriptor.Descriptor(
       create_key=_descriptor._internal_create_key,
     fields=[]),
   ],
-  serialized_start=7473,
-  serialized_end=7710,
+  serialized_start=8037,
+  serialized_end=8482,
 )
 
 
@@ -2582,8 +2803,8 @@ _CONSENSUSPARAMSBLOCK = _descriptor.Descriptor(
   extension_ranges=[],
   oneofs=[
   ],
-  serialized_start=7712,
-  serialized_end=7792,
+  serialized_start=8484,
+  serialized_end=8564,
 )
 
 
@@ -2628,8 +2849,8 @@ _CONSENSUSPARAMSEVIDENCE = _descriptor.Descriptor(
   extension_ranges=[],
   oneofs=[
   ],
-  serialized_start=7794,
-  serialized_end=7892,
+  serialized_start=8566,
+  serialized_end=8664,
 )
 
 
@@ -2667,8 +2888,8 @@ _GETCONSENSUSPARAMSREQUEST = _descriptor.Descriptor(
   extension_ranges=[],
   oneofs=[
   ],
-  serialized_start=7894,
-  serialized_end=7952,
+  serialized_start=8666,
+  serialized_end=8724,
 )
 
 
@@ -2706,84 +2927,514 @@ _GETCONSENSUSPARAMSRESPONSE = _descriptor.Descriptor(
   extension_ranges=[],
   oneofs=[
   ],
-  serialized_start=7955,
-  serialized_end=8117,
+  serialized_start=8727,
+  serialized_end=8889,
 )
 
-_GETIDENTITYRESPONSE.fields_by_name['proof'].message_type = _PROOF
-_GETIDENTITYRESPONSE.fields_by_name['metadata'].message_type = _RESPONSEMETADATA
-_GETIDENTITYRESPONSE.oneofs_by_name['result'].fields.append(
-  _GETIDENTITYRESPONSE.fields_by_name['identity'])
-_GETIDENTITYRESPONSE.fields_by_name['identity'].containing_oneof = _GETIDENTITYRESPONSE.oneofs_by_name['result']
-_GETIDENTITYRESPONSE.oneofs_by_name['result'].fields.append(
-  _GETIDENTITYRESPONSE.fields_by_name['proof'])
-_GETIDENTITYRESPONSE.fields_by_name['proof'].containing_oneof = _GETIDENTITYRESPONSE.oneofs_by_name['result']
-_GETIDENTITIESRESPONSE_IDENTITYVALUE.containing_type = _GETIDENTITIESRESPONSE
-_GETIDENTITIESRESPONSE_IDENTITYENTRY.fields_by_name['value'].message_type = _GETIDENTITIESRESPONSE_IDENTITYVALUE
-_GETIDENTITIESRESPONSE_IDENTITYENTRY.containing_type = _GETIDENTITIESRESPONSE
-_GETIDENTITIESRESPONSE_IDENTITIES.fields_by_name['identity_entries'].message_type = _GETIDENTITIESRESPONSE_IDENTITYENTRY
-_GETIDENTITIESRESPONSE_IDENTITIES.containing_type = _GETIDENTITIESRESPONSE
-_GETIDENTITIESRESPONSE.fields_by_name['identities'].message_type = _GETIDENTITIESRESPONSE_IDENTITIES
-_GETIDENTITIESRESPONSE.fields_by_name['proof'].message_type = _PROOF
-_GETIDENTITIESRESPONSE.fields_by_name['metadata'].message_type = _RESPONSEMETADATA
-_GETIDENTITIESRESPONSE.oneofs_by_name['result'].fields.append(
-  _GETIDENTITIESRESPONSE.fields_by_name['identities'])
-_GETIDENTITIESRESPONSE.fields_by_name['identities'].containing_oneof = _GETIDENTITIESRESPONSE.oneofs_by_name['result']
-_GETIDENTITIESRESPONSE.oneofs_by_name['result'].fields.append(
-  _GETIDENTITIESRESPONSE.fields_by_name['proof'])
-_GETIDENTITIESRESPONSE.fields_by_name['proof'].containing_oneof = _GETIDENTITIESRESPONSE.oneofs_by_name['result']
-_GETIDENTITYBALANCERESPONSE.fields_by_name['balance'].message_type = google_dot_protobuf_dot_wrappers__pb2._UINT64VALUE
-_GETIDENTITYBALANCERESPONSE.fields_by_name['proof'].message_type = _PROOF
-_GETIDENTITYBALANCERESPONSE.fields_by_name['metadata'].message_type = _RESPONSEMETADATA
-_GETIDENTITYBALANCERESPONSE.oneofs_by_name['result'].fields.append(
-  _GETIDENTITYBALANCERESPONSE.fields_by_name['balance'])
-_GETIDENTITYBALANCERESPONSE.fields_by_name['balance'].containing_oneof = _GETIDENTITYBALANCERESPONSE.oneofs_by_name['result']
-_GETIDENTITYBALANCERESPONSE.oneofs_by_name['result'].fields.append(
-  _GETIDENTITYBALANCERESPONSE.fields_by_name['proof'])
-_GETIDENTITYBALANCERESPONSE.fields_by_name['proof'].containing_oneof = _GETIDENTITYBALANCERESPONSE.oneofs_by_name['result']
-_GETIDENTITYBALANCEANDREVISIONRESPONSE_BALANCEANDREVISION.fields_by_name['balance'].message_type = google_dot_protobuf_dot_wrappers__pb2._UINT64VALUE
-_GETIDENTITYBALANCEANDREVISIONRESPONSE_BALANCEANDREVISION.fields_by_name['revision'].message_type = google_dot_protobuf_dot_wrappers__pb2._UINT64VALUE
-_GETIDENTITYBALANCEANDREVISIONRESPONSE_BALANCEANDREVISION.containing_type = _GETIDENTITYBALANCEANDREVISIONRESPONSE
-_GETIDENTITYBALANCEANDREVISIONRESPONSE.fields_by_name['balance_and_revision'].message_type = _GETIDENTITYBALANCEANDREVISIONRESPONSE_BALANCEANDREVISION
-_GETIDENTITYBALANCEANDREVISIONRESPONSE.fields_by_name['proof'].message_type = _PROOF
-_GETIDENTITYBALANCEANDREVISIONRESPONSE.fields_by_name['metadata'].message_type = _RESPONSEMETADATA
-_GETIDENTITYBALANCEANDREVISIONRESPONSE.oneofs_by_name['result'].fields.append(
-  _GETIDENTITYBALANCEANDREVISIONRESPONSE.fields_by_name['balance_and_revision'])
-_GETIDENTITYBALANCEANDREVISIONRESPONSE.fields_by_name['balance_and_revision'].containing_oneof = _GETIDENTITYBALANCEANDREVISIONRESPONSE.oneofs_by_name['result']
-_GETIDENTITYBALANCEANDREVISIONRESPONSE.oneofs_by_name['result'].fields.append(
-  _GETIDENTITYBALANCEANDREVISIONRESPONSE.fields_by_name['proof'])
-_GETIDENTITYBALANCEANDREVISIONRESPONSE.fields_by_name['proof'].containing_oneof = _GETIDENTITYBALANCEANDREVISIONRESPONSE.oneofs_by_name['result']
-_KEYREQUESTTYPE.fields_by_name['all_keys'].message_type = _ALLKEYS
-_KEYREQUESTTYPE.fields_by_name['specific_keys'].message_type = _SPECIFICKEYS
-_KEYREQUESTTYPE.fields_by_name['search_key'].message_type = _SEARCHKEY
-_KEYREQUESTTYPE.oneofs_by_name['request'].fields.append(
-  _KEYREQUESTTYPE.fields_by_name['all_keys'])
-_KEYREQUESTTYPE.fields_by_name['all_keys'].containing_oneof = _KEYREQUESTTYPE.oneofs_by_name['request']
-_KEYREQUESTTYPE.oneofs_by_name['request'].fields.append(
-  _KEYREQUESTTYPE.fields_by_name['specific_keys'])
-_KEYREQUESTTYPE.fields_by_name['specific_keys'].containing_oneof = _KEYREQUESTTYPE.oneofs_by_name['request']
-_KEYREQUESTTYPE.oneofs_by_name['request'].fields.append(
-  _KEYREQUESTTYPE.fields_by_name['search_key'])
-_KEYREQUESTTYPE.fields_by_name['search_key'].containing_oneof = _KEYREQUESTTYPE.oneofs_by_name['request']
-_SEARCHKEY_PURPOSEMAPENTRY.fields_by_name['value'].message_type = _SECURITYLEVELMAP
-_SEARCHKEY_PURPOSEMAPENTRY.containing_type = _SEARCHKEY
-_SEARCHKEY.fields_by_name['purpose_map'].message_type = _SEARCHKEY_PURPOSEMAPENTRY
-_SECURITYLEVELMAP_SECURITYLEVELMAPENTRY.fields_by_name['value'].enum_type = _SECURITYLEVELMAP_KEYKINDREQUESTTYPE
-_SECURITYLEVELMAP_SECURITYLEVELMAPENTRY.containing_type = _SECURITYLEVELMAP
-_SECURITYLEVELMAP.fields_by_name['security_level_map'].message_type = _SECURITYLEVELMAP_SECURITYLEVELMAPENTRY
-_SECURITYLEVELMAP_KEYKINDREQUESTTYPE.containing_type = _SECURITYLEVELMAP
-_GETIDENTITYKEYSREQUEST.fields_by_name['request_type'].message_type = _KEYREQUESTTYPE
-_GETIDENTITYKEYSREQUEST.fields_by_name['limit'].message_type = google_dot_protobuf_dot_wrappers__pb2._UINT32VALUE
-_GETIDENTITYKEYSREQUEST.fields_by_name['offset'].message_type = google_dot_protobuf_dot_wrappers__pb2._UINT32VALUE
-_GETIDENTITYKEYSRESPONSE_KEYS.containing_type = _GETIDENTITYKEYSRESPONSE
-_GETIDENTITYKEYSRESPONSE.fields_by_name['keys'].message_type = _GETIDENTITYKEYSRESPONSE_KEYS
-_GETIDENTITYKEYSRESPONSE.fields_by_name['proof'].message_type = _PROOF
-_GETIDENTITYKEYSRESPONSE.fields_by_name['metadata'].message_type = _RESPONSEMETADATA
-_GETIDENTITYKEYSRESPONSE.oneofs_by_name['result'].fields.append(
-  _GETIDENTITYKEYSRESPONSE.fields_by_name['keys'])
-_GETIDENTITYKEYSRESPONSE.fields_by_name['keys'].containing_oneof = _GETIDENTITYKEYSRESPONSE.oneofs_by_name['result']
-_GETIDENTITYKEYSRESPONSE.oneofs_by_name['result'].fields.append(
-  _GETIDENTITYKEYSRESPONSE.fields_by_name['proof'])
+
+_GETBLOCKATTESTATIONREQUEST = _descriptor.Descriptor(
+  name='GetBlockAttestationRequest',
+  full_name='org.dash.platform.dapi.v0.GetBlockAttestationRequest',
+  filename=None,
+  file=DESCRIPTOR,
+  containing_type=None,
+  create_key=_descriptor._internal_create_key,
+  fields=[
+    _descriptor.FieldDescriptor(
+      name='height', full_name='org.dash.platform.dapi.v0.GetBlockAttestationRequest.height', index=0,
+      number=1, type=4, cpp_type=4, label=1,
+      has_default_value=False, default_value=0,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+  ],
+  extensions=[
+  ],
+  nested_types=[],
+  enum_types=[
+  ],
+  serialized_options=None,
+  is_extendable=False,
+  syntax='proto3',
+  extension_ranges=[],
+  oneofs=[
+  ],
+  serialized_start=8891,
+  serialized_end=8935,
+)
+
+
+_GETBLOCKATTESTATIONRESPONSE_BLOCKATTESTATION = _descriptor.Descriptor(
+  name='BlockAttestation',
+  full_name='org.dash.platform.dapi.v0.GetBlockAttestationResponse.BlockAttestation',
+  filename=None,
+  file=DESCRIPTOR,
+  containing_type=None,
+  create_key=_descriptor._internal_create_key,
+  fields=[
+    _descriptor.FieldDescriptor(
+      name='height', full_name='org.dash.platform.dapi.v0.GetBlockAttestationResponse.BlockAttestation.height', index=0,
+      number=1, type=4, cpp_type=4, label=1,
+      has_default_value=False, default_value=0,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='app_hash', full_name='org.dash.platform.dapi.v0.GetBlockAttestationResponse.BlockAttestation.app_hash', index=1,
+      number=2, type=12, cpp_type=9, label=1,
+      has_default_value=False, default_value=b"",
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='epoch', full_name='org.dash.platform.dapi.v0.GetBlockAttestationResponse.BlockAttestation.epoch', index=2,
+      number=3, type=13, cpp_type=3, label=1,
+      has_default_value=False, default_value=0,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='protocol_version', full_name='org.dash.platform.dapi.v0.GetBlockAttestationResponse.BlockAttestation.protocol_version', index=3,
+      number=4, type=13, cpp_type=3, label=1,
+      has_default_value=False, default_value=0,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='quorum_hash', full_name='org.dash.platform.dapi.v0.GetBlockAttestationResponse.BlockAttestation.quorum_hash', index=4,
+      number=5, type=12, cpp_type=9, label=1,
+      has_default_value=False, default_value=b"",
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='quorum_type', full_name='org.dash.platform.dapi.v0.GetBlockAttestationResponse.BlockAttestation.quorum_type', index=5,
+      number=6, type=13, cpp_type=3, label=1,
+      has_default_value=False, default_value=0,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='round', full_name='org.dash.platform.dapi.v0.GetBlockAttestationResponse.BlockAttestation.round', index=6,
+      number=7, type=13, cpp_type=3, label=1,
+      has_default_value=False, default_value=0,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='signature', full_name='org.dash.platform.dapi.v0.GetBlockAttestationResponse.BlockAttestation.signature', index=7,
+      number=8, type=12, cpp_type=9, label=1,
+      has_default_value=False, default_value=b"",
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+  ],
+  extensions=[
+  ],
+  nested_types=[],
+  enum_types=[
+  ],
+  serialized_options=None,
+  is_extendable=False,
+  syntax='proto3',
+  extension_ranges=[],
+  oneofs=[
+  ],
+  serialized_start=9133,
+  serialized_end=9302,
+)
+
+_GETBLOCKATTESTATIONRESPONSE = _descriptor.Descriptor(
+  name='GetBlockAttestationResponse',
+  full_name='org.dash.platform.dapi.v0.GetBlockAttestationResponse',
+  filename=None,
+  file=DESCRIPTOR,
+  containing_type=None,
+  create_key=_descriptor._internal_create_key,
+  fields=[
+    _descriptor.FieldDescriptor(
+      name='block_attestation', full_name='org.dash.platform.dapi.v0.GetBlockAttestationResponse.block_attestation', index=0,
+      number=1, type=11, cpp_type=10, label=1,
+      has_default_value=False, default_value=None,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='metadata', full_name='org.dash.platform.dapi.v0.GetBlockAttestationResponse.metadata', index=1,
+      number=2, type=11, cpp_type=10, label=1,
+      has_default_value=False, default_value=None,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+  ],
+  extensions=[
+  ],
+  nested_types=[_GETBLOCKATTESTATIONRESPONSE_BLOCKATTESTATION, ],
+  enum_types=[
+  ],
+  serialized_options=None,
+  is_extendable=False,
+  syntax='proto3',
+  extension_ranges=[],
+  oneofs=[
+  ],
+  serialized_start=8938,
+  serialized_end=9302,
+)
+
+
+_ESTIMATEFEEREQUEST = _descriptor.Descriptor(
+  name='EstimateFeeRequest',
+  full_name='org.dash.platform.dapi.v0.EstimateFeeRequest',
+  filename=None,
+  file=DESCRIPTOR,
+  containing_type=None,
+  create_key=_descriptor._internal_create_key,
+  fields=[
+    _descriptor.FieldDescriptor(
+      name='state_transition', full_name='org.dash.platform.dapi.v0.EstimateFeeRequest.state_transition', index=0,
+      number=1, type=12, cpp_type=9, label=1,
+      has_default_value=False, default_value=b"",
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+  ],
+  extensions=[
+  ],
+  nested_types=[],
+  enum_types=[
+  ],
+  serialized_options=None,
+  is_extendable=False,
+  syntax='proto3',
+  extension_ranges=[],
+  oneofs=[
+  ],
+  serialized_start=9304,
+  serialized_end=9350,
+)
+
+
+_ESTIMATEFEERESPONSE_FEEESTIMATE_REFUND = _descriptor.Descriptor(
+  name='Refund',
+  full_name='org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.Refund',
+  filename=None,
+  file=DESCRIPTOR,
+  containing_type=None,
+  create_key=_descriptor._internal_create_key,
+  fields=[
+    _descriptor.FieldDescriptor(
+      name='identity_id', full_name='org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.Refund.identity_id', index=0,
+      number=1, type=12, cpp_type=9, label=1,
+      has_default_value=False, default_value=b"",
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='credits', full_name='org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.Refund.credits', index=1,
+      number=2, type=4, cpp_type=4, label=1,
+      has_default_value=False, default_value=0,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+  ],
+  extensions=[
+  ],
+  nested_types=[],
+  enum_types=[
+  ],
+  serialized_options=None,
+  is_extendable=False,
+  syntax='proto3',
+  extension_ranges=[],
+  oneofs=[
+  ],
+  serialized_start=9841,
+  serialized_end=9887,
+)
+
+_ESTIMATEFEERESPONSE_FEEESTIMATE_BALANCECHANGE_REMOVEFROMBALANCE = _descriptor.Descriptor(
+  name='RemoveFromBalance',
+  full_name='org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.RemoveFromBalance',
+  filename=None,
+  file=DESCRIPTOR,
+  containing_type=None,
+  create_key=_descriptor._internal_create_key,
+  fields=[
+    _descriptor.FieldDescriptor(
+      name='required_removed_balance', full_name='org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.RemoveFromBalance.required_removed_balance', index=0,
+      number=1, type=4, cpp_type=4, label=1,
+      has_default_value=False, default_value=0,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='desired_removed_balance', full_name='org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.RemoveFromBalance.desired_removed_balance', index=1,
+      number=2, type=4, cpp_type=4, label=1,
+      has_default_value=False, default_value=0,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+  ],
+  extensions=[
+  ],
+  nested_types=[],
+  enum_types=[
+  ],
+  serialized_options=None,
+  is_extendable=False,
+  syntax='proto3',
+  extension_ranges=[],
+  oneofs=[
+  ],
+  serialized_start=10085,
+  serialized_end=10171,
+)
+
+_ESTIMATEFEERESPONSE_FEEESTIMATE_BALANCECHANGE = _descriptor.Descriptor(
+  name='BalanceChange',
+  full_name='org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange',
+  filename=None,
+  file=DESCRIPTOR,
+  containing_type=None,
+  create_key=_descriptor._internal_create_key,
+  fields=[
+    _descriptor.FieldDescriptor(
+      name='add_to_balance', full_name='org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.add_to_balance', index=0,
+      number=1, type=4, cpp_type=4, label=1,
+      has_default_value=False, default_value=0,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='remove_from_balance', full_name='org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.remove_from_balance', index=1,
+      number=2, type=11, cpp_type=10, label=1,
+      has_default_value=False, default_value=None,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='no_balance_change', full_name='org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.no_balance_change', index=2,
+      number=3, type=8, cpp_type=7, label=1,
+      has_default_value=False, default_value=False,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+  ],
+  extensions=[
+  ],
+  nested_types=[_ESTIMATEFEERESPONSE_FEEESTIMATE_BALANCECHANGE_REMOVEFROMBALANCE, ],
+  enum_types=[
+  ],
+  serialized_options=None,
+  is_extendable=False,
+  syntax='proto3',
+  extension_ranges=[],
+  oneofs=[
+    _descriptor.OneofDescriptor(
+      name='change', full_name='org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.change',
+      index=0, containing_type=None,
+      create_key=_descriptor._internal_create_key,
+    fields=[]),
+  ],
+  serialized_start=9890,
+  serialized_end=10181,
+)
+
+_ESTIMATEFEERESPONSE_FEEESTIMATE = _descriptor.Descriptor(
+  name='FeeEstimate',
+  full_name='org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate',
+  filename=None,
+  file=DESCRIPTOR,
+  containing_type=None,
+  create_key=_descriptor._internal_create_key,
+  fields=[
+    _descriptor.FieldDescriptor(
+      name='storage_fee', full_name='org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.storage_fee', index=0,
+      number=1, type=4, cpp_type=4, label=1,
+      has_default_value=False, default_value=0,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='processing_fee', full_name='org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.processing_fee', index=1,
+      number=2, type=4, cpp_type=4, label=1,
+      has_default_value=False, default_value=0,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='refunds', full_name='org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.refunds', index=2,
+      number=3, type=11, cpp_type=10, label=3,
+      has_default_value=False, default_value=[],
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='balance_change', full_name='org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.balance_change', index=3,
+      number=4, type=11, cpp_type=10, label=1,
+      has_default_value=False, default_value=None,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+  ],
+  extensions=[
+  ],
+  nested_types=[_ESTIMATEFEERESPONSE_FEEESTIMATE_REFUND, _ESTIMATEFEERESPONSE_FEEESTIMATE_BALANCECHANGE, ],
+  enum_types=[
+  ],
+  serialized_options=None,
+  is_extendable=False,
+  syntax='proto3',
+  extension_ranges=[],
+  oneofs=[
+  ],
+  serialized_start=9599,
+  serialized_end=10181,
+)
+
+_ESTIMATEFEERESPONSE = _descriptor.Descriptor(
+  name='EstimateFeeResponse',
+  full_name='org.dash.platform.dapi.v0.EstimateFeeResponse',
+  filename=None,
+  file=DESCRIPTOR,
+  containing_type=None,
+  create_key=_descriptor._internal_create_key,
+  fields=[
+    _descriptor.FieldDescriptor(
+      name='fee_estimate', full_name='org.dash.platform.dapi.v0.EstimateFeeResponse.fee_estimate', index=0,
+      number=1, type=11, cpp_type=10, label=1,
+      has_default_value=False, default_value=None,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='error', full_name='org.dash.platform.dapi.v0.EstimateFeeResponse.error', index=1,
+      number=2, type=11, cpp_type=10, label=1,
+      has_default_value=False, default_value=None,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='metadata', full_name='org.dash.platform.dapi.v0.EstimateFeeResponse.metadata', index=2,
+      number=3, type=11, cpp_type=10, label=1,
+      has_default_value=False, default_value=None,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+  ],
+  extensions=[
+  ],
+  nested_types=[_ESTIMATEFEERESPONSE_FEEESTIMATE, ],
+  enum_types=[
+  ],
+  serialized_options=None,
+  is_extendable=False,
+  syntax='proto3',
+  extension_ranges=[],
+  oneofs=[
+    _descriptor.OneofDescriptor(
+      name='result', full_name='org.dash.platform.dapi.v0.EstimateFeeResponse.result',
+      index=0, containing_type=None,
+      create_key=_descriptor._internal_create_key,
+    fields=[]),
+  ],
+  serialized_start=9353,
+  serialized_end=10191,
+)
+
+_GETIDENTITYRESPONSE.fields_by_name['proof'].message_type = _PROOF
+_GETIDENTITYRESPONSE.fields_by_name['metadata'].message_type = _RESPONSEMETADATA
+_GETIDENTITYRESPONSE.oneofs_by_name['result'].fields.append(
+  _GETIDENTITYRESPONSE.fields_by_name['identity'])
+_GETIDENTITYRESPONSE.fields_by_name['identity'].containing_oneof = _GETIDENTITYRESPONSE.oneofs_by_name['result']
+_GETIDENTITYRESPONSE.oneofs_by_name['result'].fields.append(
+  _GETIDENTITYRESPONSE.fields_by_name['proof'])
+_GETIDENTITYRESPONSE.fields_by_name['proof'].containing_oneof = _GETIDENTITYRESPONSE.oneofs_by_name['result']
+_GETIDENTITIESRESPONSE_IDENTITYVALUE.containing_type = _GETIDENTITIESRESPONSE
+_GETIDENTITIESRESPONSE_IDENTITYENTRY.fields_by_name['value'].message_type = _GETIDENTITIESRESPONSE_IDENTITYVALUE
+_GETIDENTITIESRESPONSE_IDENTITYENTRY.containing_type = _GETIDENTITIESRESPONSE
+_GETIDENTITIESRESPONSE_IDENTITIES.fields_by_name['identity_entries'].message_type = _GETIDENTITIESRESPONSE_IDENTITYENTRY
+_GETIDENTITIESRESPONSE_IDENTITIES.containing_type = _GETIDENTITIESRESPONSE
+_GETIDENTITIESRESPONSE.fields_by_name['identities'].message_type = _GETIDENTITIESRESPONSE_IDENTITIES
+_GETIDENTITIESRESPONSE.fields_by_name['proof'].message_type = _PROOF
+_GETIDENTITIESRESPONSE.fields_by_name['metadata'].message_type = _RESPONSEMETADATA
+_GETIDENTITIESRESPONSE.oneofs_by_name['result'].fields.append(
+  _GETIDENTITIESRESPONSE.fields_by_name['identities'])
+_GETIDENTITIESRESPONSE.fields_by_name['identities'].containing_oneof = _GETIDENTITIESRESPONSE.oneofs_by_name['result']
+_GETIDENTITIESRESPONSE.oneofs_by_name['result'].fields.append(
+  _GETIDENTITIESRESPONSE.fields_by_name['proof'])
+_GETIDENTITIESRESPONSE.fields_by_name['proof'].containing_oneof = _GETIDENTITIESRESPONSE.oneofs_by_name['result']
+_GETIDENTITYBALANCERESPONSE.fields_by_name['balance'].message_type = google_dot_protobuf_dot_wrappers__pb2._UINT64VALUE
+_GETIDENTITYBALANCERESPONSE.fields_by_name['proof'].message_type = _PROOF
+_GETIDENTITYBALANCERESPONSE.fields_by_name['metadata'].message_type = _RESPONSEMETADATA
+_GETIDENTITYBALANCERESPONSE.oneofs_by_name['result'].fields.append(
+  _GETIDENTITYBALANCERESPONSE.fields_by_name['balance'])
+_GETIDENTITYBALANCERESPONSE.fields_by_name['balance'].containing_oneof = _GETIDENTITYBALANCERESPONSE.oneofs_by_name['result']
+_GETIDENTITYBALANCERESPONSE.oneofs_by_name['result'].fields.append(
+  _GETIDENTITYBALANCERESPONSE.fields_by_name['proof'])
+_GETIDENTITYBALANCERESPONSE.fields_by_name['proof'].containing_oneof = _GETIDENTITYBALANCERESPONSE.oneofs_by_name['result']
+_GETIDENTITYBALANCEANDREVISIONRESPONSE_BALANCEANDREVISION.fields_by_name['balance'].message_type = google_dot_protobuf_dot_wrappers__pb2._UINT64VALUE
+_GETIDENTITYBALANCEANDREVISIONRESPONSE_BALANCEANDREVISION.fields_by_name['revision'].message_type = google_dot_protobuf_dot_wrappers__pb2._UINT64VALUE
+_GETIDENTITYBALANCEANDREVISIONRESPONSE_BALANCEANDREVISION.containing_type = _GETIDENTITYBALANCEANDREVISIONRESPONSE
+_GETIDENTITYBALANCEANDREVISIONRESPONSE.fields_by_name['balance_and_revision'].message_type = _GETIDENTITYBALANCEANDREVISIONRESPONSE_BALANCEANDREVISION
+_GETIDENTITYBALANCEANDREVISIONRESPONSE.fields_by_name['proof'].message_type = _PROOF
+_GETIDENTITYBALANCEANDREVISIONRESPONSE.fields_by_name['metadata'].message_type = _RESPONSEMETADATA
+_GETIDENTITYBALANCEANDREVISIONRESPONSE.oneofs_by_name['result'].fields.append(
+  _GETIDENTITYBALANCEANDREVISIONRESPONSE.fields_by_name['balance_and_revision'])
+_GETIDENTITYBALANCEANDREVISIONRESPONSE.fields_by_name['balance_and_revision'].containing_oneof = _GETIDENTITYBALANCEANDREVISIONRESPONSE.oneofs_by_name['result']
+_GETIDENTITYBALANCEANDREVISIONRESPONSE.oneofs_by_name['result'].fields.append(
+  _GETIDENTITYBALANCEANDREVISIONRESPONSE.fields_by_name['proof'])
+_GETIDENTITYBALANCEANDREVISIONRESPONSE.fields_by_name['proof'].containing_oneof = _GETIDENTITYBALANCEANDREVISIONRESPONSE.oneofs_by_name['result']
+_GETIDENTITYNONCERESPONSE.fields_by_name['nonce'].message_type = google_dot_protobuf_dot_wrappers__pb2._UINT64VALUE
+_GETIDENTITYNONCERESPONSE.fields_by_name['proof'].message_type = _PROOF
+_GETIDENTITYNONCERESPONSE.fields_by_name['metadata'].message_type = _RESPONSEMETADATA
+_GETIDENTITYNONCERESPONSE.oneofs_by_name['result'].fields.append(
+  _GETIDENTITYNONCERESPONSE.fields_by_name['nonce'])
+_GETIDENTITYNONCERESPONSE.fields_by_name['nonce'].containing_oneof = _GETIDENTITYNONCERESPONSE.oneofs_by_name['result']
+_GETIDENTITYNONCERESPONSE.oneofs_by_name['result'].fields.append(
+  _GETIDENTITYNONCERESPONSE.fields_by_name['proof'])
+_GETIDENTITYNONCERESPONSE.fields_by_name['proof'].containing_oneof = _GETIDENTITYNONCERESPONSE.oneofs_by_name['result']
+_GETIDENTITYCONTRACTNONCERESPONSE.fields_by_name['nonce'].message_type = google_dot_protobuf_dot_wrappers__pb2._UINT64VALUE
+_GETIDENTITYCONTRACTNONCERESPONSE.fields_by_name['proof'].message_type = _PROOF
+_GETIDENTITYCONTRACTNONCERESPONSE.fields_by_name['metadata'].message_type = _RESPONSEMETADATA
+_GETIDENTITYCONTRACTNONCERESPONSE.oneofs_by_name['result'].fields.append(
+  _GETIDENTITYCONTRACTNONCERESPONSE.fields_by_name['nonce'])
+_GETIDENTITYCONTRACTNONCERESPONSE.fields_by_name['nonce'].containing_oneof = _GETIDENTITYCONTRACTNONCERESPONSE.oneofs_by_name['result']
+_GETIDENTITYCONTRACTNONCERESPONSE.oneofs_by_name['result'].fields.append(
+  _GETIDENTITYCONTRACTNONCERESPONSE.fields_by_name['proof'])
+_GETIDENTITYCONTRACTNONCERESPONSE.fields_by_name['proof'].containing_oneof = _GETIDENTITYCONTRACTNONCERESPONSE.oneofs_by_name['result']
+_KEYREQUESTTYPE.fields_by_name['all_keys'].message_type = _ALLKEYS
+_KEYREQUESTTYPE.fields_by_name['specific_keys'].message_type = _SPECIFICKEYS
+_KEYREQUESTTYPE.fields_by_name['search_key'].message_type = _SEARCHKEY
+_KEYREQUESTTYPE.oneofs_by_name['request'].fields.append(
+  _KEYREQUESTTYPE.fields_by_name['all_keys'])
+_KEYREQUESTTYPE.fields_by_name['all_keys'].containing_oneof = _KEYREQUESTTYPE.oneofs_by_name['request']
+_KEYREQUESTTYPE.oneofs_by_name['request'].fields.append(
+  _KEYREQUESTTYPE.fields_by_name['specific_keys'])
+_KEYREQUESTTYPE.fields_by_name['specific_keys'].containing_oneof = _KEYREQUESTTYPE.oneofs_by_name['request']
+_KEYREQUESTTYPE.oneofs_by_name['request'].fields.append(
+  _KEYREQUESTTYPE.fields_by_name['search_key'])
+_KEYREQUESTTYPE.fields_by_name['search_key'].containing_oneof = _KEYREQUESTTYPE.oneofs_by_name['request']
+_SEARCHKEY_PURPOSEMAPENTRY.fields_by_name['value'].message_type = _SECURITYLEVELMAP
+_SEARCHKEY_PURPOSEMAPENTRY.containing_type = _SEARCHKEY
+_SEARCHKEY.fields_by_name['purpose_map'].message_type = _SEARCHKEY_PURPOSEMAPENTRY
+_SECURITYLEVELMAP_SECURITYLEVELMAPENTRY.fields_by_name['value'].enum_type = _SECURITYLEVELMAP_KEYKINDREQUESTTYPE
+_SECURITYLEVELMAP_SECURITYLEVELMAPENTRY.containing_type = _SECURITYLEVELMAP
+_SECURITYLEVELMAP.fields_by_name['security_level_map'].message_type = _SECURITYLEVELMAP_SECURITYLEVELMAPENTRY
+_SECURITYLEVELMAP_KEYKINDREQUESTTYPE.containing_type = _SECURITYLEVELMAP
+_GETIDENTITYKEYSREQUEST.fields_by_name['request_type'].message_type = _KEYREQUESTTYPE
+_GETIDENTITYKEYSREQUEST.fields_by_name['limit'].message_type = google_dot_protobuf_dot_wrappers__pb2._UINT32VALUE
+_GETIDENTITYKEYSREQUEST.fields_by_name['offset'].message_type = google_dot_protobuf_dot_wrappers__pb2._UINT32VALUE
+_GETIDENTITYKEYSRESPONSE_KEYS.containing_type = _GETIDENTITYKEYSRESPONSE
+_GETIDENTITYKEYSRESPONSE.fields_by_name['keys'].message_type = _GETIDENTITYKEYSRESPONSE_KEYS
+_GETIDENTITYKEYSRESPONSE.fields_by_name['proof'].message_type = _PROOF
+_GETIDENTITYKEYSRESPONSE.fields_by_name['metadata'].message_type = _RESPONSEMETADATA
+_GETIDENTITYKEYSRESPONSE.oneofs_by_name['result'].fields.append(
+  _GETIDENTITYKEYSRESPONSE.fields_by_name['keys'])
+_GETIDENTITYKEYSRESPONSE.fields_by_name['keys'].containing_oneof = _GETIDENTITYKEYSRESPONSE.oneofs_by_name['result']
+_GETIDENTITYKEYSRESPONSE.oneofs_by_name['result'].fields.append(
+  _GETIDENTITYKEYSRESPONSE.fields_by_name['proof'])
 _GETIDENTITYKEYSRESPONSE.fields_by_name['proof'].containing_oneof = _GETIDENTITYKEYSRESPONSE.oneofs_by_name['result']
 _GETIDENTITIESKEYSREQUEST_SECURITYLEVELMAP_SECURITYLEVELMAPENTRY.fields_by_name['value'].enum_type = _GETIDENTITIESKEYSREQUEST_SECURITYLEVELMAP_KEYKINDREQUESTTYPE
 _GETIDENTITIESKEYSREQUEST_SECURITYLEVELMAP_SECURITYLEVELMAPENTRY.containing_type = _GETIDENTITIESKEYSREQUEST_SECURITYLEVELMAP
@@ -2885,8 +3536,10 @@ _GETIDENTITYBYPUBLICKEYHASHESRESPONSE.fields_by_name['identity'].containing_oneo
 _GETIDENTITYBYPUBLICKEYHASHESRESPONSE.oneofs_by_name['result'].fields.append(
   _GETIDENTITYBYPUBLICKEYHASHESRESPONSE.fields_by_name['proof'])
 _GETIDENTITYBYPUBLICKEYHASHESRESPONSE.fields_by_name['proof'].containing_oneof = _GETIDENTITYBYPUBLICKEYHASHESRESPONSE.oneofs_by_name['result']
+_WAITFORSTATETRANSITIONRESULTRESPONSE_STATETRANSITIONRESULT.containing_type = _WAITFORSTATETRANSITIONRESULTRESPONSE
 _WAITFORSTATETRANSITIONRESULTRESPONSE.fields_by_name['error'].message_type = _STATETRANSITIONBROADCASTERROR
 _WAITFORSTATETRANSITIONRESULTRESPONSE.fields_by_name['proof'].message_type = _PROOF
+_WAITFORSTATETRANSITIONRESULTRESPONSE.fields_by_name['state_transition_result'].message_type = _WAITFORSTATETRANSITIONRESULTRESPONSE_STATETRANSITIONRESULT
 _WAITFORSTATETRANSITIONRESULTRESPONSE.fields_by_name['metadata'].message_type = _RESPONSEMETADATA
 _WAITFORSTATETRANSITIONRESULTRESPONSE.oneofs_by_name['result'].fields.append(
   _WAITFORSTATETRANSITIONRESULTRESPONSE.fields_by_name['error'])
@@ -2894,8 +3547,39 @@ _WAITFORSTATETRANSITIONRESULTRESPONSE.fields_by_name['error'].containing_oneof =
 _WAITFORSTATETRANSITIONRESULTRESPONSE.oneofs_by_name['result'].fields.append(
   _WAITFORSTATETRANSITIONRESULTRESPONSE.fields_by_name['proof'])
 _WAITFORSTATETRANSITIONRESULTRESPONSE.fields_by_name['proof'].containing_oneof = _WAITFORSTATETRANSITIONRESULTRESPONSE.oneofs_by_name['result']
+_WAITFORSTATETRANSITIONRESULTRESPONSE.oneofs_by_name['result'].fields.append(
+  _WAITFORSTATETRANSITIONRESULTRESPONSE.fields_by_name['state_transition_result'])
+_WAITFORSTATETRANSITIONRESULTRESPONSE.fields_by_name['state_transition_result'].containing_oneof = _WAITFORSTATETRANSITIONRESULTRESPONSE.oneofs_by_name['result']
 _GETCONSENSUSPARAMSRESPONSE.fields_by_name['block'].message_type = _CONSENSUSPARAMSBLOCK
 _GETCONSENSUSPARAMSRESPONSE.fields_by_name['evidence'].message_type = _CONSENSUSPARAMSEVIDENCE
+_GETBLOCKATTESTATIONRESPONSE_BLOCKATTESTATION.containing_type = _GETBLOCKATTESTATIONRESPONSE
+_GETBLOCKATTESTATIONRESPONSE.fields_by_name['block_attestation'].message_type = _GETBLOCKATTESTATIONRESPONSE_BLOCKATTESTATION
+_GETBLOCKATTESTATIONRESPONSE.fields_by_name['metadata'].message_type = _RESPONSEMETADATA
+_ESTIMATEFEERESPONSE_FEEESTIMATE_REFUND.containing_type = _ESTIMATEFEERESPONSE_FEEESTIMATE
+_ESTIMATEFEERESPONSE_FEEESTIMATE_BALANCECHANGE_REMOVEFROMBALANCE.containing_type = _ESTIMATEFEERESPONSE_FEEESTIMATE_BALANCECHANGE
+_ESTIMATEFEERESPONSE_FEEESTIMATE_BALANCECHANGE.fields_by_name['remove_from_balance'].message_type = _ESTIMATEFEERESPONSE_FEEESTIMATE_BALANCECHANGE_REMOVEFROMBALANCE
+_ESTIMATEFEERESPONSE_FEEESTIMATE_BALANCECHANGE.containing_type = _ESTIMATEFEERESPONSE_FEEESTIMATE
+_ESTIMATEFEERESPONSE_FEEESTIMATE_BALANCECHANGE.oneofs_by_name['change'].fields.append(
+  _ESTIMATEFEERESPONSE_FEEESTIMATE_BALANCECHANGE.fields_by_name['add_to_balance'])
+_ESTIMATEFEERESPONSE_FEEESTIMATE_BALANCECHANGE.fields_by_name['add_to_balance'].containing_oneof = _ESTIMATEFEERESPONSE_FEEESTIMATE_BALANCECHANGE.oneofs_by_name['change']
+_ESTIMATEFEERESPONSE_FEEESTIMATE_BALANCECHANGE.oneofs_by_name['change'].fields.append(
+  _ESTIMATEFEERESPONSE_FEEESTIMATE_BALANCECHANGE.fields_by_name['remove_from_balance'])
+_ESTIMATEFEERESPONSE_FEEESTIMATE_BALANCECHANGE.fields_by_name['remove_from_balance'].containing_oneof = _ESTIMATEFEERESPONSE_FEEESTIMATE_BALANCECHANGE.oneofs_by_name['change']
+_ESTIMATEFEERESPONSE_FEEESTIMATE_BALANCECHANGE.oneofs_by_name['change'].fields.append(
+  _ESTIMATEFEERESPONSE_FEEESTIMATE_BALANCECHANGE.fields_by_name['no_balance_change'])
+_ESTIMATEFEERESPONSE_FEEESTIMATE_BALANCECHANGE.fields_by_name['no_balance_change'].containing_oneof = _ESTIMATEFEERESPONSE_FEEESTIMATE_BALANCECHANGE.oneofs_by_name['change']
+_ESTIMATEFEERESPONSE_FEEESTIMATE.fields_by_name['refunds'].message_type = _ESTIMATEFEERESPONSE_FEEESTIMATE_REFUND
+_ESTIMATEFEERESPONSE_FEEESTIMATE.fields_by_name['balance_change'].message_type = _ESTIMATEFEERESPONSE_FEEESTIMATE_BALANCECHANGE
+_ESTIMATEFEERESPONSE_FEEESTIMATE.containing_type = _ESTIMATEFEERESPONSE
+_ESTIMATEFEERESPONSE.fields_by_name['fee_estimate'].message_type = _ESTIMATEFEERESPONSE_FEEESTIMATE
+_ESTIMATEFEERESPONSE.fields_by_name['error'].message_type = _STATETRANSITIONBROADCASTERROR
+_ESTIMATEFEERESPONSE.fields_by_name['metadata'].message_type = _RESPONSEMETADATA
+_ESTIMATEFEERESPONSE.oneofs_by_name['result'].fields.append(
+  _ESTIMATEFEERESPONSE.fields_by_name['fee_estimate'])
+_ESTIMATEFEERESPONSE.fields_by_name['fee_estimate'].containing_oneof = _ESTIMATEFEERESPONSE.oneofs_by_name['result']
+_ESTIMATEFEERESPONSE.oneofs_by_name['result'].fields.append(
+  _ESTIMATEFEERESPONSE.fields_by_name['error'])
+_ESTIMATEFEERESPONSE.fields_by_name['error'].containing_oneof = _ESTIMATEFEERESPONSE.oneofs_by_name['result']
 DESCRIPTOR.message_types_by_name['Proof'] = _PROOF
 DESCRIPTOR.message_types_by_name['ResponseMetadata'] = _RESPONSEMETADATA
 DESCRIPTOR.message_types_by_name['StateTransitionBroadcastError'] = _STATETRANSITIONBROADCASTERROR
@@ -2907,6 +3591,9 @@ DESCRIPTOR.message_types_by_name['GetIdentitiesRequest'] = _GETIDENTITIESREQUEST
 DESCRIPTOR.message_types_by_name['GetIdentitiesResponse'] = _GETIDENTITIESRESPONSE
 DESCRIPTOR.message_types_by_name['GetIdentityBalanceResponse'] = _GETIDENTITYBALANCERESPONSE
 DESCRIPTOR.message_types_by_name['GetIdentityBalanceAndRevisionResponse'] = _GETIDENTITYBALANCEANDREVISIONRESPONSE
+DESCRIPTOR.message_types_by_name['GetIdentityNonceResponse'] = _GETIDENTITYNONCERESPONSE
+DESCRIPTOR.message_types_by_name['GetIdentityContractNonceRequest'] = _GETIDENTITYCONTRACTNONCEREQUEST
+DESCRIPTOR.message_types_by_name['GetIdentityContractNonceResponse'] = _GETIDENTITYCONTRACTNONCERESPONSE
 DESCRIPTOR.message_types_by_name['KeyRequestType'] = _KEYREQUESTTYPE
 DESCRIPTOR.message_types_by_name['AllKeys'] = _ALLKEYS
 DESCRIPTOR.message_types_by_name['SpecificKeys'] = _SPECIFICKEYS
@@ -2936,6 +3623,10 @@ DESCRIPTOR.message_types_by_name['ConsensusParamsBlock'] = _CONSENSUSPARAMSBLOCK
 DESCRIPTOR.message_types_by_name['ConsensusParamsEvidence'] = _CONSENSUSPARAMSEVIDENCE
 DESCRIPTOR.message_types_by_name['GetConsensusParamsRequest'] = _GETCONSENSUSPARAMSREQUEST
 DESCRIPTOR.message_types_by_name['GetConsensusParamsResponse'] = _GETCONSENSUSPARAMSRESPONSE
+DESCRIPTOR.message_types_by_name['GetBlockAttestationRequest'] = _GETBLOCKATTESTATIONREQUEST
+DESCRIPTOR.message_types_by_name['GetBlockAttestationResponse'] = _GETBLOCKATTESTATIONRESPONSE
+DESCRIPTOR.message_types_by_name['EstimateFeeRequest'] = _ESTIMATEFEEREQUEST
+DESCRIPTOR.message_types_by_name['EstimateFeeResponse'] = _ESTIMATEFEERESPONSE
 _sym_db.RegisterFileDescriptor(DESCRIPTOR)
 
 Proof = _reflection.GeneratedProtocolMessageType('Proof', (_message.Message,), {
@@ -3047,6 +3738,27 @@ GetIdentityBalanceAndRevisionResponse = _reflection.GeneratedProtocolMessageType
 _sym_db.RegisterMessage(GetIdentityBalanceAndRevisionResponse)
 _sym_db.RegisterMessage(GetIdentityBalanceAndRevisionResponse.BalanceAndRevision)
 
+GetIdentityNonceResponse = _reflection.GeneratedProtocolMessageType('GetIdentityNonceResponse', (_message.Message,), {
+  'DESCRIPTOR' : _GETIDENTITYNONCERESPONSE,
+  '__module__' : 'platform_pb2'
+  # @@protoc_insertion_point(class_scope:org.dash.platform.dapi.v0.GetIdentityNonceResponse)
+  })
+_sym_db.RegisterMessage(GetIdentityNonceResponse)
+
+GetIdentityContractNonceRequest = _reflection.GeneratedProtocolMessageType('GetIdentityContractNonceRequest', (_message.Message,), {
+  'DESCRIPTOR' : _GETIDENTITYCONTRACTNONCEREQUEST,
+  '__module__' : 'platform_pb2'
+  # @@protoc_insertion_point(class_scope:org.dash.platform.dapi.v0.GetIdentityContractNonceRequest)
+  })
+_sym_db.RegisterMessage(GetIdentityContractNonceRequest)
+
+GetIdentityContractNonceResponse = _reflection.GeneratedProtocolMessageType('GetIdentityContractNonceResponse', (_message.Message,), {
+  'DESCRIPTOR' : _GETIDENTITYCONTRACTNONCERESPONSE,
+  '__module__' : 'platform_pb2'
+  # @@protoc_insertion_point(class_scope:org.dash.platform.dapi.v0.GetIdentityContractNonceResponse)
+  })
+_sym_db.RegisterMessage(GetIdentityContractNonceResponse)
+
 KeyRequestType = _reflection.GeneratedProtocolMessageType('KeyRequestType', (_message.Message,), {
   'DESCRIPTOR' : _KEYREQUESTTYPE,
   '__module__' : 'platform_pb2'
@@ -3360,11 +4072,19 @@ WaitForStateTransitionResultRequest = _reflection.GeneratedProtocolMessageType('
 _sym_db.RegisterMessage(WaitForStateTransitionResultRequest)
 
 WaitForStateTransitionResultResponse = _reflection.GeneratedProtocolMessageType('WaitForStateTransitionResultResponse', (_message.Message,), {
+
+  'StateTransitionResult' : _reflection.GeneratedProtocolMessageType('StateTransitionResult', (_message.Message,), {
+    'DESCRIPTOR' : _WAITFORSTATETRANSITIONRESULTRESPONSE_STATETRANSITIONRESULT,
+    '__module__' : 'platform_pb2'
+    # @@protoc_insertion_point(class_scope:org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.StateTransitionResult)
+    })
+  ,
   'DESCRIPTOR' : _WAITFORSTATETRANSITIONRESULTRESPONSE,
   '__module__' : 'platform_pb2'
   # @@protoc_insertion_point(class_scope:org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse)
   })
 _sym_db.RegisterMessage(WaitForStateTransitionResultResponse)
+_sym_db.RegisterMessage(WaitForStateTransitionResultResponse.StateTransitionResult)
 
 ConsensusParamsBlock = _reflection.GeneratedProtocolMessageType('ConsensusParamsBlock', (_message.Message,), {
   'DESCRIPTOR' : _CONSENSUSPARAMSBLOCK,
@@ -3394,6 +4114,74 @@ GetConsensusParamsResponse = _reflection.GeneratedProtocolMessageType('GetConsen
   })
 _sym_db.RegisterMessage(GetConsensusParamsResponse)
 
+GetBlockAttestationRequest = _reflection.GeneratedProtocolMessageType('GetBlockAttestationRequest', (_message.Message,), {
+  'DESCRIPTOR' : _GETBLOCKATTESTATIONREQUEST,
+  '__module__' : 'platform_pb2'
+  # @@protoc_insertion_point(class_scope:org.dash.platform.dapi.v0.GetBlockAttestationRequest)
+  })
+_sym_db.RegisterMessage(GetBlockAttestationRequest)
+
+GetBlockAttestationResponse = _reflection.GeneratedProtocolMessageType('GetBlockAttestationResponse', (_message.Message,), {
+
+  'BlockAttestation' : _reflection.GeneratedProtocolMessageType('BlockAttestation', (_message.Message,), {
+    'DESCRIPTOR' : _GETBLOCKATTESTATIONRESPONSE_BLOCKATTESTATION,
+    '__module__' : 'platform_pb2'
+    # @@protoc_insertion_point(class_scope:org.dash.platform.dapi.v0.GetBlockAttestationResponse.BlockAttestation)
+    })
+  ,
+  'DESCRIPTOR' : _GETBLOCKATTESTATIONRESPONSE,
+  '__module__' : 'platform_pb2'
+  # @@protoc_insertion_point(class_scope:org.dash.platform.dapi.v0.GetBlockAttestationResponse)
+  })
+_sym_db.RegisterMessage(GetBlockAttestationResponse)
+_sym_db.RegisterMessage(GetBlockAttestationResponse.BlockAttestation)
+
+EstimateFeeRequest = _reflection.GeneratedProtocolMessageType('EstimateFeeRequest', (_message.Message,), {
+  'DESCRIPTOR' : _ESTIMATEFEEREQUEST,
+  '__module__' : 'platform_pb2'
+  # @@protoc_insertion_point(class_scope:org.dash.platform.dapi.v0.EstimateFeeRequest)
+  })
+_sym_db.RegisterMessage(EstimateFeeRequest)
+
+EstimateFeeResponse = _reflection.GeneratedProtocolMessageType('EstimateFeeResponse', (_message.Message,), {
+
+  'FeeEstimate' : _reflection.GeneratedProtocolMessageType('FeeEstimate', (_message.Message,), {
+
+    'Refund' : _reflection.GeneratedProtocolMessageType('Refund', (_message.Message,), {
+      'DESCRIPTOR' : _ESTIMATEFEERESPONSE_FEEESTIMATE_REFUND,
+      '__module__' : 'platform_pb2'
+      # @@protoc_insertion_point(class_scope:org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.Refund)
+      })
+    ,
+
+    'BalanceChange' : _reflection.GeneratedProtocolMessageType('BalanceChange', (_message.Message,), {
+
+      'RemoveFromBalance' : _reflection.GeneratedProtocolMessageType('RemoveFromBalance', (_message.Message,), {
+        'DESCRIPTOR' : _ESTIMATEFEERESPONSE_FEEESTIMATE_BALANCECHANGE_REMOVEFROMBALANCE,
+        '__module__' : 'platform_pb2'
+        # @@protoc_insertion_point(class_scope:org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.RemoveFromBalance)
+        })
+      ,
+      'DESCRIPTOR' : _ESTIMATEFEERESPONSE_FEEESTIMATE_BALANCECHANGE,
+      '__module__' : 'platform_pb2'
+      # @@protoc_insertion_point(class_scope:org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange)
+      })
+    ,
+    'DESCRIPTOR' : _ESTIMATEFEERESPONSE_FEEESTIMATE,
+    '__module__' : 'platform_pb2'
+    # @@protoc_insertion_point(class_scope:org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate)
+    })
+  ,
+  'DESCRIPTOR' : _ESTIMATEFEERESPONSE,
+  '__module__' : 'platform_pb2'
+  # @@protoc_insertion_point(class_scope:org.dash.platform.dapi.v0.EstimateFeeResponse)
+  })
+_sym_db.RegisterMessage(EstimateFeeResponse)
+_sym_db.RegisterMessage(EstimateFeeResponse.FeeEstimate)
+_sym_db.RegisterMessage(EstimateFeeResponse.FeeEstimate.Refund)
+_sym_db.RegisterMessage(EstimateFeeResponse.FeeEstimate.BalanceChange)
+_sym_db.RegisterMessage(EstimateFeeResponse.FeeEstimate.BalanceChange.RemoveFromBalance)
+
 
 _SEARCHKEY_PURPOSEMAPENTRY._options = None
 _SECURITYLEVELMAP_SECURITYLEVELMAPENTRY._options = None
@@ -3406,8 +4194,8 @@ _PLATFORM = _descriptor.ServiceDescriptor(
   index=0,
   serialized_options=None,
   create_key=_descriptor._internal_create_key,
-  serialized_start=8120,
-  serialized_end=10131,
+  serialized_start=10194,
+  serialized_end=12720,
   methods=[
   _descriptor.MethodDescriptor(
     name='broadcastStateTransition',
@@ -3469,10 +4257,30 @@ _PLATFORM = _descriptor.ServiceDescriptor(
     serialized_options=None,
     create_key=_descriptor._internal_create_key,
   ),
+  _descriptor.MethodDescriptor(
+    name='getIdentityNonce',
+    full_name='org.dash.platform.dapi.v0.Platform.getIdentityNonce',
+    index=6,
+    containing_service=None,
+    input_type=_GETIDENTITYREQUEST,
+    output_type=_GETIDENTITYNONCERESPONSE,
+    serialized_options=None,
+    create_key=_descriptor._internal_create_key,
+  ),
+  _descriptor.MethodDescriptor(
+    name='getIdentityContractNonce',
+    full_name='org.dash.platform.dapi.v0.Platform.getIdentityContractNonce',
+    index=7,
+    containing_service=None,
+    input_type=_GETIDENTITYCONTRACTNONCEREQUEST,
+    output_type=_GETIDENTITYCONTRACTNONCERESPONSE,
+    serialized_options=None,
+    create_key=_descriptor._internal_create_key,
+  ),
   _descriptor.MethodDescriptor(
     name='getProofs',
     full_name='org.dash.platform.dapi.v0.Platform.getProofs',
-    index=6,
+    index=8,
     containing_service=None,
     input_type=_GETPROOFSREQUEST,
     output_type=_GETPROOFSRESPONSE,
@@ -3482,7 +4290,7 @@ _PLATFORM = _descriptor.ServiceDescriptor(
   _descriptor.MethodDescriptor(
     name='getDataContract',
     full_name='org.dash.platform.dapi.v0.Platform.getDataContract',
-    index=7,
+    index=9,
     containing_service=None,
     input_type=_GETDATACONTRACTREQUEST,
     output_type=_GETDATACONTRACTRESPONSE,
@@ -3492,7 +4300,7 @@ _PLATFORM = _descriptor.ServiceDescriptor(
   _descriptor.MethodDescriptor(
     name='getDataContractHistory',
     full_name='org.dash.platform.dapi.v0.Platform.getDataContractHistory',
-    index=8,
+    index=10,
     containing_service=None,
     input_type=_GETDATACONTRACTHISTORYREQUEST,
     output_type=_GETDATACONTRACTHISTORYRESPONSE,
@@ -3502,7 +4310,7 @@ _PLATFORM = _descriptor.ServiceDescriptor(
   _descriptor.MethodDescriptor(
     name='getDataContracts',
     full_name='org.dash.platform.dapi.v0.Platform.getDataContracts',
-    index=9,
+    index=11,
     containing_service=None,
     input_type=_GETDATACONTRACTSREQUEST,
     output_type=_GETDATACONTRACTSRESPONSE,
@@ -3512,7 +4320,7 @@ _PLATFORM = _descriptor.ServiceDescriptor(
   _descriptor.MethodDescriptor(
     name='getDocuments',
     full_name='org.dash.platform.dapi.v0.Platform.getDocuments',
-    index=10,
+    index=12,
     containing_service=None,
     input_type=_GETDOCUMENTSREQUEST,
     output_type=_GETDOCUMENTSRESPONSE,
@@ -3522,7 +4330,7 @@ _PLATFORM = _descriptor.ServiceDescriptor(
   _descriptor.MethodDescriptor(
     name='getIdentitiesByPublicKeyHashes',
     full_name='org.dash.platform.dapi.v0.Platform.getIdentitiesByPublicKeyHashes',
-    index=11,
+    index=13,
     containing_service=None,
     input_type=_GETIDENTITIESBYPUBLICKEYHASHESREQUEST,
     output_type=_GETIDENTITIESBYPUBLICKEYHASHESRESPONSE,
@@ -3532,7 +4340,7 @@ _PLATFORM = _descriptor.ServiceDescriptor(
   _descriptor.MethodDescriptor(
     name='getIdentityByPublicKeyHashes',
     full_name='org.dash.platform.dapi.v0.Platform.getIdentityByPublicKeyHashes',
-    index=12,
+    index=14,
     containing_service=None,
     input_type=_GETIDENTITYBYPUBLICKEYHASHESREQUEST,
     output_type=_GETIDENTITYBYPUBLICKEYHASHESRESPONSE,
@@ -3542,7 +4350,7 @@ _PLATFORM = _descriptor.ServiceDescriptor(
   _descriptor.MethodDescriptor(
     name='waitForStateTransitionResult',
     full_name='org.dash.platform.dapi.v0.Platform.waitForStateTransitionResult',
-    index=13,
+    index=15,
     containing_service=None,
     input_type=_WAITFORSTATETRANSITIONRESULTREQUEST,
     output_type=_WAITFORSTATETRANSITIONRESULTRESPONSE,
@@ -3552,13 +4360,33 @@ _PLATFORM = _descriptor.ServiceDescriptor(
   _descriptor.MethodDescriptor(
     name='getConsensusParams',
     full_name='org.dash.platform.dapi.v0.Platform.getConsensusParams',
-    index=14,
+    index=16,
     containing_service=None,
     input_type=_GETCONSENSUSPARAMSREQUEST,
     output_type=_GETCONSENSUSPARAMSRESPONSE,
     serialized_options=None,
     create_key=_descriptor._internal_create_key,
   ),
+  _descriptor.MethodDescriptor(
+    name='getBlockAttestation',
+    full_name='org.dash.platform.dapi.v0.Platform.getBlockAttestation',
+    index=17,
+    containing_service=None,
+    input_type=_GETBLOCKATTESTATIONREQUEST,
+    output_type=_GETBLOCKATTESTATIONRESPONSE,
+    serialized_options=None,
+    create_key=_descriptor._internal_create_key,
+  ),
+  _descriptor.MethodDescriptor(
+    name='estimateFee',
+    full_name='org.dash.platform.dapi.v0.Platform.estimateFee',
+    index=18,
+    containing_service=None,
+    input_type=_ESTIMATEFEEREQUEST,
+    output_type=_ESTIMATEFEERESPONSE,
+    serialized_options=None,
+    create_key=_descriptor._internal_create_key,
+  ),
 ])
 _sym_db.RegisterServiceDescriptor(_PLATFORM)
 
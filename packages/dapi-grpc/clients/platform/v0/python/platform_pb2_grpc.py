@@ -44,6 +44,16 @@ class PlatformStub(object):
                 request_serializer=platform__pb2.GetIdentityRequest.SerializeToString,
                 response_deserializer=platform__pb2.GetIdentityBalanceAndRevisionResponse.FromString,
                 )
+        self.getIdentityNonce = channel.unary_unary(
+                '/org.dash.platform.dapi.v0.Platform/getIdentityNonce',
+                request_serializer=platform__pb2.GetIdentityRequest.SerializeToString,
+                response_deserializer=platform__pb2.GetIdentityNonceResponse.FromString,
+                )
+        self.getIdentityContractNonce = channel.unary_unary(
+                '/org.dash.platform.dapi.v0.Platform/getIdentityContractNonce',
+                request_serializer=platform__pb2.GetIdentityContractNonceRequest.SerializeToString,
+                response_deserializer=platform__pb2.GetIdentityContractNonceResponse.FromString,
+                )
         self.getProofs = channel.unary_unary(
                 '/org.dash.platform.dapi.v0.Platform/getProofs',
                 request_serializer=platform__pb2.GetProofsRequest.SerializeToString,
@@ -89,6 +99,16 @@ class PlatformStub(object):
                 request_serializer=platform__pb2.GetConsensusParamsRequest.SerializeToString,
                 response_deserializer=platform__pb2.GetConsensusParamsResponse.FromString,
                 )
+        self.getBlockAttestation = channel.unary_unary(
+                '/org.dash.platform.dapi.v0.Platform/getBlockAttestation',
+                request_serializer=platform__pb2.GetBlockAttestationRequest.SerializeToString,
+                response_deserializer=platform__pb2.GetBlockAttestationResponse.FromString,
+                )
+        self.estimateFee = channel.unary_unary(
+                '/org.dash.platform.dapi.v0.Platform/estimateFee',
+                request_serializer=platform__pb2.EstimateFeeRequest.SerializeToString,
+                response_deserializer=platform__pb2.EstimateFeeResponse.FromString,
+                )
 
 
 class PlatformServicer(object):
@@ -131,6 +151,18 @@ class PlatformServicer(object):
         context.set_details('Method not implemented!')
         raise NotImplementedError('Method not implemented!')
 
+    def getIdentityNonce(self, request, context):
+        """Missing associated documentation comment in .proto file."""
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+        context.set_details('Method not implemented!')
+        raise NotImplementedError('Method not implemented!')
+
+    def getIdentityContractNonce(self, request, context):
+        """Missing associated documentation comment in .proto file."""
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+        context.set_details('Method not implemented!')
+        raise NotImplementedError('Method not implemented!')
+
     def getProofs(self, request, context):
         """Missing associated documentation comment in .proto file."""
         context.set_code(grpc.StatusCode.UNIMPLEMENTED)
@@ -185,6 +217,18 @@ class PlatformServicer(object):
         context.set_details('Method not implemented!')
         raise NotImplementedError('Method not implemented!')
 
+    def getBlockAttestation(self, request, context):
+        """Missing associated documentation comment in .proto file."""
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+        context.set_details('Method not implemented!')
+        raise NotImplementedError('Method not implemented!')
+
+    def estimateFee(self, request, context):
+        """Missing associated documentation comment in .proto file."""
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+        context.set_details('Method not implemented!')
+        raise NotImplementedError('Method not implemented!')
+
 
 def add_PlatformServicer_to_server(servicer, server):
     rpc_method_handlers = {
@@ -218,6 +262,16 @@ def add_PlatformServicer_to_server(servicer, server):
                     request_deserializer=platform__pb2.GetIdentityRequest.FromString,
                     response_serializer=platform__pb2.GetIdentityBalanceAndRevisionResponse.SerializeToString,
             ),
+            'getIdentityNonce': grpc.unary_unary_rpc_method_handler(
+                    servicer.getIdentityNonce,
+                    request_deserializer=platform__pb2.GetIdentityRequest.FromString,
+                    response_serializer=platform__pb2.GetIdentityNonceResponse.SerializeToString,
+            ),
+            'getIdentityContractNonce': grpc.unary_unary_rpc_method_handler(
+                    servicer.getIdentityContractNonce,
+                    request_deserializer=platform__pb2.GetIdentityContractNonceRequest.FromString,
+                    response_serializer=platform__pb2.GetIdentityContractNonceResponse.SerializeToString,
+            ),
             'getProofs': grpc.unary_unary_rpc_method_handler(
                     servicer.getProofs,
                     request_deserializer=platform__pb2.GetProofsRequest.FromString,
@@ -263,6 +317,16 @@ def add_PlatformServicer_to_server(servicer, server):
                     request_deserializer=platform__pb2.GetConsensusParamsRequest.FromString,
                     response_serializer=platform__pb2.GetConsensusParamsResponse.SerializeToString,
             ),
+            'getBlockAttestation': grpc.unary_unary_rpc_method_handler(
+                    servicer.getBlockAttestation,
+                    request_deserializer=platform__pb2.GetBlockAttestationRequest.FromString,
+                    response_serializer=platform__pb2.GetBlockAttestationResponse.SerializeToString,
+            ),
+            'estimateFee': grpc.unary_unary_rpc_method_handler(
+                    servicer.estimateFee,
+                    request_deserializer=platform__pb2.EstimateFeeRequest.FromString,
+                    response_serializer=platform__pb2.EstimateFeeResponse.SerializeToString,
+            ),
     }
     generic_handler = grpc.method_handlers_generic_handler(
             'org.dash.platform.dapi.v0.Platform', rpc_method_handlers)
@@ -375,6 +439,40 @@ class Platform(object):
             options, channel_credentials,
             insecure, call_credentials, compression, wait_for_ready, timeout, metadata)
 
+    @staticmethod
+    def getIdentityNonce(request,
+            target,
+            options=(),
+            channel_credentials=None,
+            call_credentials=None,
+            insecure=False,
+            compression=None,
+            wait_for_ready=None,
+            timeout=None,
+            metadata=None):
+        return grpc.experimental.unary_unary(request, target, '/org.dash.platform.dapi.v0.Platform/getIdentityNonce',
+            platform__pb2.GetIdentityRequest.SerializeToString,
+            platform__pb2.GetIdentityNonceResponse.FromString,
+            options, channel_credentials,
+            insecure, call_credentials, compression, wait_for_ready, timeout, metadata)
+
+    @staticmethod
+    def getIdentityContractNonce(request,
+            target,
+            options=(),
+            channel_credentials=None,
+            call_credentials=None,
+            insecure=False,
+            compression=None,
+            wait_for_ready=None,
+            timeout=None,
+            metadata=None):
+        return grpc.experimental.unary_unary(request, target, '/org.dash.platform.dapi.v0.Platform/getIdentityContractNonce',
+            platform__pb2.GetIdentityContractNonceRequest.SerializeToString,
+            platform__pb2.GetIdentityContractNonceResponse.FromString,
+            options, channel_credentials,
+            insecure, call_credentials, compression, wait_for_ready, timeout, metadata)
+
     @staticmethod
     def getProofs(request,
             target,
@@ -527,3 +625,37 @@ class Platform(object):
             platform__pb2.GetConsensusParamsResponse.FromString,
             options, channel_credentials,
             insecure, call_credentials, compression, wait_for_ready, timeout, metadata)
+
+    @staticmethod
+    def getBlockAttestation(request,
+            target,
+            options=(),
+            channel_credentials=None,
+            call_credentials=None,
+            insecure=False,
+            compression=None,
+            wait_for_ready=None,
+            timeout=None,
+            metadata=None):
+        return grpc.experimental.unary_unary(request, target, '/org.dash.platform.dapi.v0.Platform/getBlockAttestation',
+            platform__pb2.GetBlockAttestationRequest.SerializeToString,
+            platform__pb2.GetBlockAttestationResponse.FromString,
+            options, channel_credentials,
+            insecure, call_credentials, compression, wait_for_ready, timeout, metadata)
+
+    @staticmethod
+    def estimateFee(request,
+            target,
+            options=(),
+            channel_credentials=None,
+            call_credentials=None,
+            insecure=False,
+            compression=None,
+            wait_for_ready=None,
+            timeout=None,
+            metadata=None):
+        return grpc.experimental.unary_unary(request, target, '/org.dash.platform.dapi.v0.Platform/estimateFee',
+            platform__pb2.EstimateFeeRequest.SerializeToString,
+            platform__pb2.EstimateFeeResponse.FromString,
+            options, channel_credentials,
+            insecure, call_credentials, compression, wait_for_ready, timeout, metadata)
@@ -25,6 +25,14 @@ export class Proof extends jspb.Message {
   getRound(): number;
   setRound(value: number): void;
 
+  getBlockIdHash(): Uint8Array | string;
+  getBlockIdHash_asU8(): Uint8Array;
+  getBlockIdHash_asB64(): string;
+  setBlockIdHash(value: Uint8Array | string): void;
+
+  getQuorumType(): number;
+  setQuorumType(value: number): void;
+
   serializeBinary(): Uint8Array;
   toObject(includeInstance?: boolean): Proof.AsObject;
   static toObject(includeInstance: boolean, msg: Proof): Proof.AsObject;
@@ -41,6 +49,8 @@ export namespace Proof {
     quorumHash: Uint8Array | string,
     signature: Uint8Array | string,
     round: number,
+    blockIdHash: Uint8Array | string,
+    quorumType: number,
   }
 }
 
@@ -57,6 +67,9 @@ export class ResponseMetadata extends jspb.Message {
   getProtocolVersion(): number;
   setProtocolVersion(value: number): void;
 
+  getChainId(): string;
+  setChainId(value: string): void;
+
   serializeBinary(): Uint8Array;
   toObject(includeInstance?: boolean): ResponseMetadata.AsObject;
   static toObject(includeInstance: boolean, msg: ResponseMetadata): ResponseMetadata.AsObject;
@@ -73,6 +86,7 @@ export namespace ResponseMetadata {
     coreChainLockedHeight: number,
     timeMs: number,
     protocolVersion: number,
+    chainId: string,
   }
 }
 
@@ -464,6 +478,120 @@ export namespace GetIdentityBalanceAndRevisionResponse {
   }
 }
 
+export class GetIdentityNonceResponse extends jspb.Message {
+  hasNonce(): boolean;
+  clearNonce(): void;
+  getNonce(): google_protobuf_wrappers_pb.UInt64Value | undefined;
+  setNonce(value?: google_protobuf_wrappers_pb.UInt64Value): void;
+
+  hasProof(): boolean;
+  clearProof(): void;
+  getProof(): Proof | undefined;
+  setProof(value?: Proof): void;
+
+  hasMetadata(): boolean;
+  clearMetadata(): void;
+  getMetadata(): ResponseMetadata | undefined;
+  setMetadata(value?: ResponseMetadata): void;
+
+  getResultCase(): GetIdentityNonceResponse.ResultCase;
+  serializeBinary(): Uint8Array;
+  toObject(includeInstance?: boolean): GetIdentityNonceResponse.AsObject;
+  static toObject(includeInstance: boolean, msg: GetIdentityNonceResponse): GetIdentityNonceResponse.AsObject;
+  static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+  static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+  static serializeBinaryToWriter(message: GetIdentityNonceResponse, writer: jspb.BinaryWriter): void;
+  static deserializeBinary(bytes: Uint8Array): GetIdentityNonceResponse;
+  static deserializeBinaryFromReader(message: GetIdentityNonceResponse, reader: jspb.BinaryReader): GetIdentityNonceResponse;
+}
+
+export namespace GetIdentityNonceResponse {
+  export type AsObject = {
+    nonce?: google_protobuf_wrappers_pb.UInt64Value.AsObject,
+    proof?: Proof.AsObject,
+    metadata?: ResponseMetadata.AsObject,
+  }
+
+  export enum ResultCase {
+    RESULT_NOT_SET = 0,
+    NONCE = 1,
+    PROOF = 2,
+  }
+}
+
+export class GetIdentityContractNonceRequest extends jspb.Message {
+  getIdentityId(): Uint8Array | string;
+  getIdentityId_asU8(): Uint8Array;
+  getIdentityId_asB64(): string;
+  setIdentityId(value: Uint8Array | string): void;
+
+  getContractId(): Uint8Array | string;
+  getContractId_asU8(): Uint8Array;
+  getContractId_asB64(): string;
+  setContractId(value: Uint8Array | string): void;
+
+  getProve(): boolean;
+  setProve(value: boolean): void;
+
+  serializeBinary(): Uint8Array;
+  toObject(includeInstance?: boolean): GetIdentityContractNonceRequest.AsObject;
+  static toObject(includeInstance: boolean, msg: GetIdentityContractNonceRequest): GetIdentityContractNonceRequest.AsObject;
+  static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+  static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+  static serializeBinaryToWriter(message: GetIdentityContractNonceRequest, writer: jspb.BinaryWriter): void;
+  static deserializeBinary(bytes: Uint8Array): GetIdentityContractNonceRequest;
+  static deserializeBinaryFromReader(message: GetIdentityContractNonceRequest, reader: jspb.BinaryReader): GetIdentityContractNonceRequest;
+}
+
+export namespace GetIdentityContractNonceRequest {
+  export type AsObject = {
+    identityId: Uint8Array | string,
+    contractId: Uint8Array | string,
+    prove: boolean,
+  }
+}
+
+export class GetIdentityContractNonceResponse extends jspb.Message {
+  hasNonce(): boolean;
+  clearNonce(): void;
+  getNonce(): google_protobuf_wrappers_pb.UInt64Value | undefined;
+  setNonce(value?: google_protobuf_wrappers_pb.UInt64Value): void;
+
+  hasProof(): boolean;
+  clearProof(): void;
+  getProof(): Proof | undefined;
+  setProof(value?: Proof): void;
+
+  hasMetadata(): boolean;
+  clearMetadata(): void;
+  getMetadata(): ResponseMetadata | undefined;
+  setMetadata(value?: ResponseMetadata): void;
+
+  getResultCase(): GetIdentityContractNonceResponse.ResultCase;
+  serializeBinary(): Uint8Array;
+  toObject(includeInstance?: boolean): GetIdentityContractNonceResponse.AsObject;
+  static toObject(includeInstance: boolean, msg: GetIdentityContractNonceResponse): GetIdentityContractNonceResponse.AsObject;
+  static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+  static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+  static serializeBinaryToWriter(message: GetIdentityContractNonceResponse, writer: jspb.BinaryWriter): void;
+  static deserializeBinary(bytes: Uint8Array): GetIdentityContractNonceResponse;
+  static deserializeBinaryFromReader(message: GetIdentityContractNonceResponse, reader: jspb.BinaryReader): GetIdentityContractNonceResponse;
+}
+
+export namespace GetIdentityContractNonceResponse {
+  export type AsObject = {
+    nonce?: google_protobuf_wrappers_pb.UInt64Value.AsObject,
+    proof?: Proof.AsObject,
+    metadata?: ResponseMetadata.AsObject,
+  }
+
+  export enum ResultCase {
+    RESULT_NOT_SET = 0,
+    NONCE = 1,
+    PROOF = 2,
+  }
+}
+
 export class KeyRequestType extends jspb.Message {
   hasAllKeys(): boolean;
   clearAllKeys(): void;
@@ -1706,6 +1834,11 @@ export class WaitForStateTransitionResultResponse extends jspb.Message {
   getProof(): Proof | undefined;
   setProof(value?: Proof): void;
 
+  hasStateTransitionResult(): boolean;
+  clearStateTransitionResult(): void;
+  getStateTransitionResult(): WaitForStateTransitionResultResponse.StateTransitionResult | undefined;
+  setStateTransitionResult(value?: WaitForStateTransitionResultResponse.StateTransitionResult): void;
+
   hasMetadata(): boolean;
   clearMetadata(): void;
   getMetadata(): ResponseMetadata | undefined;
@@ -1726,13 +1859,43 @@ export namespace WaitForStateTransitionResultResponse {
   export type AsObject = {
     error?: StateTransitionBroadcastError.AsObject,
     proof?: Proof.AsObject,
+    stateTransitionResult?: WaitForStateTransitionResultResponse.StateTransitionResult.AsObject,
     metadata?: ResponseMetadata.AsObject,
   }
 
+  export class StateTransitionResult extends jspb.Message {
+    getHeight(): number;
+    setHeight(value: number): void;
+
+    getStorageFee(): number;
+    setStorageFee(value: number): void;
+
+    getProcessingFee(): number;
+    setProcessingFee(value: number): void;
+
+    serializeBinary(): Uint8Array;
+    toObject(includeInstance?: boolean): StateTransitionResult.AsObject;
+    static toObject(includeInstance: boolean, msg: StateTransitionResult): StateTransitionResult.AsObject;
+    static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+    static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+    static serializeBinaryToWriter(message: StateTransitionResult, writer: jspb.BinaryWriter): void;
+    static deserializeBinary(bytes: Uint8Array): StateTransitionResult;
+    static deserializeBinaryFromReader(message: StateTransitionResult, reader: jspb.BinaryReader): StateTransitionResult;
+  }
+
+  export namespace StateTransitionResult {
+    export type AsObject = {
+      height: number,
+      storageFee: number,
+      processingFee: number,
+    }
+  }
+
   export enum ResultCase {
     RESULT_NOT_SET = 0,
     ERROR = 1,
     PROOF = 2,
+    STATE_TRANSITION_RESULT = 4,
   }
 }
 
@@ -1844,3 +2007,296 @@ export namespace GetConsensusParamsResponse {
   }
 }
 
+export class GetBlockAttestationRequest extends jspb.Message {
+  getHeight(): number;
+  setHeight(value: number): void;
+
+  serializeBinary(): Uint8Array;
+  toObject(includeInstance?: boolean): GetBlockAttestationRequest.AsObject;
+  static toObject(includeInstance: boolean, msg: GetBlockAttestationRequest): GetBlockAttestationRequest.AsObject;
+  static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+  static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+  static serializeBinaryToWriter(message: GetBlockAttestationRequest, writer: jspb.BinaryWriter): void;
+  static deserializeBinary(bytes: Uint8Array): GetBlockAttestationRequest;
+  static deserializeBinaryFromReader(message: GetBlockAttestationRequest, reader: jspb.BinaryReader): GetBlockAttestationRequest;
+}
+
+export namespace GetBlockAttestationRequest {
+  export type AsObject = {
+    height: number,
+  }
+}
+
+export class GetBlockAttestationResponse extends jspb.Message {
+  hasBlockAttestation(): boolean;
+  clearBlockAttestation(): void;
+  getBlockAttestation(): GetBlockAttestationResponse.BlockAttestation | undefined;
+  setBlockAttestation(value?: GetBlockAttestationResponse.BlockAttestation): void;
+
+  hasMetadata(): boolean;
+  clearMetadata(): void;
+  getMetadata(): ResponseMetadata | undefined;
+  setMetadata(value?: ResponseMetadata): void;
+
+  serializeBinary(): Uint8Array;
+  toObject(includeInstance?: boolean): GetBlockAttestationResponse.AsObject;
+  static toObject(includeInstance: boolean, msg: GetBlockAttestationResponse): GetBlockAttestationResponse.AsObject;
+  static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+  static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+  static serializeBinaryToWriter(message: GetBlockAttestationResponse, writer: jspb.BinaryWriter): void;
+  static deserializeBinary(bytes: Uint8Array): GetBlockAttestationResponse;
+  static deserializeBinaryFromReader(message: GetBlockAttestationResponse, reader: jspb.BinaryReader): GetBlockAttestationResponse;
+}
+
+export namespace GetBlockAttestationResponse {
+  export type AsObject = {
+    blockAttestation?: GetBlockAttestationResponse.BlockAttestation.AsObject,
+    metadata?: ResponseMetadata.AsObject,
+  }
+
+  export class BlockAttestation extends jspb.Message {
+    getHeight(): number;
+    setHeight(value: number): void;
+
+    getAppHash(): Uint8Array | string;
+    getAppHash_asU8(): Uint8Array;
+    getAppHash_asB64(): string;
+    setAppHash(value: Uint8Array | string): void;
+
+    getEpoch(): number;
+    setEpoch(value: number): void;
+
+    getProtocolVersion(): number;
+    setProtocolVersion(value: number): void;
+
+    getQuorumHash(): Uint8Array | string;
+    getQuorumHash_asU8(): Uint8Array;
+    getQuorumHash_asB64(): string;
+    setQuorumHash(value: Uint8Array | string): void;
+
+    getQuorumType(): number;
+    setQuorumType(value: number): void;
+
+    getRound(): number;
+    setRound(value: number): void;
+
+    getSignature(): Uint8Array | string;
+    getSignature_asU8(): Uint8Array;
+    getSignature_asB64(): string;
+    setSignature(value: Uint8Array | string): void;
+
+    serializeBinary(): Uint8Array;
+    toObject(includeInstance?: boolean): BlockAttestation.AsObject;
+    static toObject(includeInstance: boolean, msg: BlockAttestation): BlockAttestation.AsObject;
+    static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+    static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+    static serializeBinaryToWriter(message: BlockAttestation, writer: jspb.BinaryWriter): void;
+    static deserializeBinary(bytes: Uint8Array): BlockAttestation;
+    static deserializeBinaryFromReader(message: BlockAttestation, reader: jspb.BinaryReader): BlockAttestation;
+  }
+
+  export namespace BlockAttestation {
+    export type AsObject = {
+      height: number,
+      appHash: Uint8Array | string,
+      epoch: number,
+      protocolVersion: number,
+      quorumHash: Uint8Array | string,
+      quorumType: number,
+      round: number,
+      signature: Uint8Array | string,
+    }
+  }
+}
+
+export class EstimateFeeRequest extends jspb.Message {
+  getStateTransition(): Uint8Array | string;
+  getStateTransition_asU8(): Uint8Array;
+  getStateTransition_asB64(): string;
+  setStateTransition(value: Uint8Array | string): void;
+
+  serializeBinary(): Uint8Array;
+  toObject(includeInstance?: boolean): EstimateFeeRequest.AsObject;
+  static toObject(includeInstance: boolean, msg: EstimateFeeRequest): EstimateFeeRequest.AsObject;
+  static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+  static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+  static serializeBinaryToWriter(message: EstimateFeeRequest, writer: jspb.BinaryWriter): void;
+  static deserializeBinary(bytes: Uint8Array): EstimateFeeRequest;
+  static deserializeBinaryFromReader(message: EstimateFeeRequest, reader: jspb.BinaryReader): EstimateFeeRequest;
+}
+
+export namespace EstimateFeeRequest {
+  export type AsObject = {
+    stateTransition: Uint8Array | string,
+  }
+}
+
+export class EstimateFeeResponse extends jspb.Message {
+  hasFeeEstimate(): boolean;
+  clearFeeEstimate(): void;
+  getFeeEstimate(): EstimateFeeResponse.FeeEstimate | undefined;
+  setFeeEstimate(value?: EstimateFeeResponse.FeeEstimate): void;
+
+  hasError(): boolean;
+  clearError(): void;
+  getError(): StateTransitionBroadcastError | undefined;
+  setError(value?: StateTransitionBroadcastError): void;
+
+  hasMetadata(): boolean;
+  clearMetadata(): void;
+  getMetadata(): ResponseMetadata | undefined;
+  setMetadata(value?: ResponseMetadata): void;
+
+  getResultCase(): EstimateFeeResponse.ResultCase;
+  serializeBinary(): Uint8Array;
+  toObject(includeInstance?: boolean): EstimateFeeResponse.AsObject;
+  static toObject(includeInstance: boolean, msg: EstimateFeeResponse): EstimateFeeResponse.AsObject;
+  static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+  static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+  static serializeBinaryToWriter(message: EstimateFeeResponse, writer: jspb.BinaryWriter): void;
+  static deserializeBinary(bytes: Uint8Array): EstimateFeeResponse;
+  static deserializeBinaryFromReader(message: EstimateFeeResponse, reader: jspb.BinaryReader): EstimateFeeResponse;
+}
+
+export namespace EstimateFeeResponse {
+  export type AsObject = {
+    feeEstimate?: EstimateFeeResponse.FeeEstimate.AsObject,
+    error?: StateTransitionBroadcastError.AsObject,
+    metadata?: ResponseMetadata.AsObject,
+  }
+
+  export class FeeEstimate extends jspb.Message {
+    getStorageFee(): number;
+    setStorageFee(value: number): void;
+
+    getProcessingFee(): number;
+    setProcessingFee(value: number): void;
+
+    clearRefundsList(): void;
+    getRefundsList(): Array<EstimateFeeResponse.FeeEstimate.Refund>;
+    setRefundsList(value: Array<EstimateFeeResponse.FeeEstimate.Refund>): void;
+    addRefunds(value?: EstimateFeeResponse.FeeEstimate.Refund, index?: number): EstimateFeeResponse.FeeEstimate.Refund;
+
+    hasBalanceChange(): boolean;
+    clearBalanceChange(): void;
+    getBalanceChange(): EstimateFeeResponse.FeeEstimate.BalanceChange | undefined;
+    setBalanceChange(value?: EstimateFeeResponse.FeeEstimate.BalanceChange): void;
+
+    serializeBinary(): Uint8Array;
+    toObject(includeInstance?: boolean): FeeEstimate.AsObject;
+    static toObject(includeInstance: boolean, msg: FeeEstimate): FeeEstimate.AsObject;
+    static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+    static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+    static serializeBinaryToWriter(message: FeeEstimate, writer: jspb.BinaryWriter): void;
+    static deserializeBinary(bytes: Uint8Array): FeeEstimate;
+    static deserializeBinaryFromReader(message: FeeEstimate, reader: jspb.BinaryReader): FeeEstimate;
+  }
+
+  export namespace FeeEstimate {
+    export type AsObject = {
+      storageFee: number,
+      processingFee: number,
+      refundsList: Array<EstimateFeeResponse.FeeEstimate.Refund.AsObject>,
+      balanceChange?: EstimateFeeResponse.FeeEstimate.BalanceChange.AsObject,
+    }
+
+    export class Refund extends jspb.Message {
+      getIdentityId(): Uint8Array | string;
+      getIdentityId_asU8(): Uint8Array;
+      getIdentityId_asB64(): string;
+      setIdentityId(value: Uint8Array | string): void;
+
+      getCredits(): number;
+      setCredits(value: number): void;
+
+      serializeBinary(): Uint8Array;
+      toObject(includeInstance?: boolean): Refund.AsObject;
+      static toObject(includeInstance: boolean, msg: Refund): Refund.AsObject;
+      static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+      static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+      static serializeBinaryToWriter(message: Refund, writer: jspb.BinaryWriter): void;
+      static deserializeBinary(bytes: Uint8Array): Refund;
+      static deserializeBinaryFromReader(message: Refund, reader: jspb.BinaryReader): Refund;
+    }
+
+    export namespace Refund {
+      export type AsObject = {
+        identityId: Uint8Array | string,
+        credits: number,
+      }
+    }
+
+    export class BalanceChange extends jspb.Message {
+      hasAddToBalance(): boolean;
+      clearAddToBalance(): void;
+      getAddToBalance(): number;
+      setAddToBalance(value: number): void;
+
+      hasRemoveFromBalance(): boolean;
+      clearRemoveFromBalance(): void;
+      getRemoveFromBalance(): EstimateFeeResponse.FeeEstimate.BalanceChange.RemoveFromBalance | undefined;
+      setRemoveFromBalance(value?: EstimateFeeResponse.FeeEstimate.BalanceChange.RemoveFromBalance): void;
+
+      hasNoBalanceChange(): boolean;
+      clearNoBalanceChange(): void;
+      getNoBalanceChange(): boolean;
+      setNoBalanceChange(value: boolean): void;
+
+      getChangeCase(): EstimateFeeResponse.FeeEstimate.BalanceChange.ChangeCase;
+      serializeBinary(): Uint8Array;
+      toObject(includeInstance?: boolean): BalanceChange.AsObject;
+      static toObject(includeInstance: boolean, msg: BalanceChange): BalanceChange.AsObject;
+      static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+      static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+      static serializeBinaryToWriter(message: BalanceChange, writer: jspb.BinaryWriter): void;
+      static deserializeBinary(bytes: Uint8Array): BalanceChange;
+      static deserializeBinaryFromReader(message: BalanceChange, reader: jspb.BinaryReader): BalanceChange;
+    }
+
+    export namespace BalanceChange {
+      export type AsObject = {
+        addToBalance: number,
+        removeFromBalance?: EstimateFeeResponse.FeeEstimate.BalanceChange.RemoveFromBalance.AsObject,
+        noBalanceChange: boolean,
+      }
+
+      export class RemoveFromBalance extends jspb.Message {
+        getRequiredRemovedBalance(): number;
+        setRequiredRemovedBalance(value: number): void;
+
+        getDesiredRemovedBalance(): number;
+        setDesiredRemovedBalance(value: number): void;
+
+        serializeBinary(): Uint8Array;
+        toObject(includeInstance?: boolean): RemoveFromBalance.AsObject;
+        static toObject(includeInstance: boolean, msg: RemoveFromBalance): RemoveFromBalance.AsObject;
+        static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+        static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+        static serializeBinaryToWriter(message: RemoveFromBalance, writer: jspb.BinaryWriter): void;
+        static deserializeBinary(bytes: Uint8Array): RemoveFromBalance;
+        static deserializeBinaryFromReader(message: RemoveFromBalance, reader: jspb.BinaryReader): RemoveFromBalance;
+      }
+
+      export namespace RemoveFromBalance {
+        export type AsObject = {
+          requiredRemovedBalance: number,
+          desiredRemovedBalance: number,
+        }
+      }
+
+      export enum ChangeCase {
+        CHANGE_NOT_SET = 0,
+        ADD_TO_BALANCE = 1,
+        REMOVE_FROM_BALANCE = 2,
+        NO_BALANCE_CHANGE = 3,
+      }
+    }
+  }
+
+  export enum ResultCase {
+    RESULT_NOT_SET = 0,
+    FEE_ESTIMATE = 1,
+    ERROR = 2,
+  }
+}
+
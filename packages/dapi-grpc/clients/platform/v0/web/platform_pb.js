@@ -26,6 +26,17 @@ goog.exportSymbol('proto.org.dash.platform.dapi.v0.BroadcastStateTransitionReque
 goog.exportSymbol('proto.org.dash.platform.dapi.v0.BroadcastStateTransitionResponse', null, { proto });
 goog.exportSymbol('proto.org.dash.platform.dapi.v0.ConsensusParamsBlock', null, { proto });
 goog.exportSymbol('proto.org.dash.platform.dapi.v0.ConsensusParamsEvidence', null, { proto });
+goog.exportSymbol('proto.org.dash.platform.dapi.v0.EstimateFeeRequest', null, { proto });
+goog.exportSymbol('proto.org.dash.platform.dapi.v0.EstimateFeeResponse', null, { proto });
+goog.exportSymbol('proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate', null, { proto });
+goog.exportSymbol('proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange', null, { proto });
+goog.exportSymbol('proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.ChangeCase', null, { proto });
+goog.exportSymbol('proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.RemoveFromBalance', null, { proto });
+goog.exportSymbol('proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.Refund', null, { proto });
+goog.exportSymbol('proto.org.dash.platform.dapi.v0.EstimateFeeResponse.ResultCase', null, { proto });
+goog.exportSymbol('proto.org.dash.platform.dapi.v0.GetBlockAttestationRequest', null, { proto });
+goog.exportSymbol('proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse', null, { proto });
+goog.exportSymbol('proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse.BlockAttestation', null, { proto });
 goog.exportSymbol('proto.org.dash.platform.dapi.v0.GetConsensusParamsRequest', null, { proto });
 goog.exportSymbol('proto.org.dash.platform.dapi.v0.GetConsensusParamsResponse', null, { proto });
 goog.exportSymbol('proto.org.dash.platform.dapi.v0.GetDataContractHistoryRequest', null, { proto });
@@ -73,10 +84,15 @@ goog.exportSymbol('proto.org.dash.platform.dapi.v0.GetIdentityBalanceResponse.Re
 goog.exportSymbol('proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesRequest', null, { proto });
 goog.exportSymbol('proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse', null, { proto });
 goog.exportSymbol('proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse.ResultCase', null, { proto });
+goog.exportSymbol('proto.org.dash.platform.dapi.v0.GetIdentityContractNonceRequest', null, { proto });
+goog.exportSymbol('proto.org.dash.platform.dapi.v0.GetIdentityContractNonceResponse', null, { proto });
+goog.exportSymbol('proto.org.dash.platform.dapi.v0.GetIdentityContractNonceResponse.ResultCase', null, { proto });
 goog.exportSymbol('proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest', null, { proto });
 goog.exportSymbol('proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse', null, { proto });
 goog.exportSymbol('proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.Keys', null, { proto });
 goog.exportSymbol('proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.ResultCase', null, { proto });
+goog.exportSymbol('proto.org.dash.platform.dapi.v0.GetIdentityNonceResponse', null, { proto });
+goog.exportSymbol('proto.org.dash.platform.dapi.v0.GetIdentityNonceResponse.ResultCase', null, { proto });
 goog.exportSymbol('proto.org.dash.platform.dapi.v0.GetIdentityRequest', null, { proto });
 goog.exportSymbol('proto.org.dash.platform.dapi.v0.GetIdentityResponse', null, { proto });
 goog.exportSymbol('proto.org.dash.platform.dapi.v0.GetIdentityResponse.ResultCase', null, { proto });
@@ -98,6 +114,7 @@ goog.exportSymbol('proto.org.dash.platform.dapi.v0.StateTransitionBroadcastError
 goog.exportSymbol('proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultRequest', null, { proto });
 goog.exportSymbol('proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse', null, { proto });
 goog.exportSymbol('proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.ResultCase', null, { proto });
+goog.exportSymbol('proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.StateTransitionResult', null, { proto });
 /**
  * Generated by JsPbCodeGenerator.
  * @param {Array=} opt_data Optional initial data array, typically from a
@@ -413,6 +430,69 @@ if (goog.DEBUG && !COMPILED) {
    */
   proto.org.dash.platform.dapi.v0.GetIdentityBalanceAndRevisionResponse.BalanceAndRevision.displayName = 'proto.org.dash.platform.dapi.v0.GetIdentityBalanceAndRevisionResponse.BalanceAndRevision';
 }
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.org.dash.platform.dapi.v0.GetIdentityNonceResponse = function(opt_data) {
+  jspb.Message.initialize(this, opt_data, 0, -1, null, proto.org.dash.platform.dapi.v0.GetIdentityNonceResponse.oneofGroups_);
+};
+goog.inherits(proto.org.dash.platform.dapi.v0.GetIdentityNonceResponse, jspb.Message);
+if (goog.DEBUG && !COMPILED) {
+  /**
+   * @public
+   * @override
+   */
+  proto.org.dash.platform.dapi.v0.GetIdentityNonceResponse.displayName = 'proto.org.dash.platform.dapi.v0.GetIdentityNonceResponse';
+}
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.org.dash.platform.dapi.v0.GetIdentityContractNonceRequest = function(opt_data) {
+  jspb.Message.initialize(this, opt_data, 0, -1, null, null);
+};
+goog.inherits(proto.org.dash.platform.dapi.v0.GetIdentityContractNonceRequest, jspb.Message);
+if (goog.DEBUG && !COMPILED) {
+  /**
+   * @public
+   * @override
+   */
+  proto.org.dash.platform.dapi.v0.GetIdentityContractNonceRequest.displayName = 'proto.org.dash.platform.dapi.v0.GetIdentityContractNonceRequest';
+}
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.org.dash.platform.dapi.v0.GetIdentityContractNonceResponse = function(opt_data) {
+  jspb.Message.initialize(this, opt_data, 0, -1, null, proto.org.dash.platform.dapi.v0.GetIdentityContractNonceResponse.oneofGroups_);
+};
+goog.inherits(proto.org.dash.platform.dapi.v0.GetIdentityContractNonceResponse, jspb.Message);
+if (goog.DEBUG && !COMPILED) {
+  /**
+   * @public
+   * @override
+   */
+  proto.org.dash.platform.dapi.v0.GetIdentityContractNonceResponse.displayName = 'proto.org.dash.platform.dapi.v0.GetIdentityContractNonceResponse';
+}
 /**
  * Generated by JsPbCodeGenerator.
  * @param {Array=} opt_data Optional initial data array, typically from a
@@ -1253,6 +1333,27 @@ if (goog.DEBUG && !COMPILED) {
    */
   proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.displayName = 'proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse';
 }
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.StateTransitionResult = function(opt_data) {
+  jspb.Message.initialize(this, opt_data, 0, -1, null, null);
+};
+goog.inherits(proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.StateTransitionResult, jspb.Message);
+if (goog.DEBUG && !COMPILED) {
+  /**
+   * @public
+   * @override
+   */
+  proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.StateTransitionResult.displayName = 'proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.StateTransitionResult';
+}
 /**
  * Generated by JsPbCodeGenerator.
  * @param {Array=} opt_data Optional initial data array, typically from a
@@ -1337,6 +1438,195 @@ if (goog.DEBUG && !COMPILED) {
    */
   proto.org.dash.platform.dapi.v0.GetConsensusParamsResponse.displayName = 'proto.org.dash.platform.dapi.v0.GetConsensusParamsResponse';
 }
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.org.dash.platform.dapi.v0.GetBlockAttestationRequest = function(opt_data) {
+  jspb.Message.initialize(this, opt_data, 0, -1, null, null);
+};
+goog.inherits(proto.org.dash.platform.dapi.v0.GetBlockAttestationRequest, jspb.Message);
+if (goog.DEBUG && !COMPILED) {
+  /**
+   * @public
+   * @override
+   */
+  proto.org.dash.platform.dapi.v0.GetBlockAttestationRequest.displayName = 'proto.org.dash.platform.dapi.v0.GetBlockAttestationRequest';
+}
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse = function(opt_data) {
+  jspb.Message.initialize(this, opt_data, 0, -1, null, null);
+};
+goog.inherits(proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse, jspb.Message);
+if (goog.DEBUG && !COMPILED) {
+  /**
+   * @public
+   * @override
+   */
+  proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse.displayName = 'proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse';
+}
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse.BlockAttestation = function(opt_data) {
+  jspb.Message.initialize(this, opt_data, 0, -1, null, null);
+};
+goog.inherits(proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse.BlockAttestation, jspb.Message);
+if (goog.DEBUG && !COMPILED) {
+  /**
+   * @public
+   * @override
+   */
+  proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse.BlockAttestation.displayName = 'proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse.BlockAttestation';
+}
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.org.dash.platform.dapi.v0.EstimateFeeRequest = function(opt_data) {
+  jspb.Message.initialize(this, opt_data, 0, -1, null, null);
+};
+goog.inherits(proto.org.dash.platform.dapi.v0.EstimateFeeRequest, jspb.Message);
+if (goog.DEBUG && !COMPILED) {
+  /**
+   * @public
+   * @override
+   */
+  proto.org.dash.platform.dapi.v0.EstimateFeeRequest.displayName = 'proto.org.dash.platform.dapi.v0.EstimateFeeRequest';
+}
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse = function(opt_data) {
+  jspb.Message.initialize(this, opt_data, 0, -1, null, proto.org.dash.platform.dapi.v0.EstimateFeeResponse.oneofGroups_);
+};
+goog.inherits(proto.org.dash.platform.dapi.v0.EstimateFeeResponse, jspb.Message);
+if (goog.DEBUG && !COMPILED) {
+  /**
+   * @public
+   * @override
+   */
+  proto.org.dash.platform.dapi.v0.EstimateFeeResponse.displayName = 'proto.org.dash.platform.dapi.v0.EstimateFeeResponse';
+}
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate = function(opt_data) {
+  jspb.Message.initialize(this, opt_data, 0, -1, proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.repeatedFields_, null);
+};
+goog.inherits(proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate, jspb.Message);
+if (goog.DEBUG && !COMPILED) {
+  /**
+   * @public
+   * @override
+   */
+  proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.displayName = 'proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate';
+}
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.Refund = function(opt_data) {
+  jspb.Message.initialize(this, opt_data, 0, -1, null, null);
+};
+goog.inherits(proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.Refund, jspb.Message);
+if (goog.DEBUG && !COMPILED) {
+  /**
+   * @public
+   * @override
+   */
+  proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.Refund.displayName = 'proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.Refund';
+}
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange = function(opt_data) {
+  jspb.Message.initialize(this, opt_data, 0, -1, null, proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.oneofGroups_);
+};
+goog.inherits(proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange, jspb.Message);
+if (goog.DEBUG && !COMPILED) {
+  /**
+   * @public
+   * @override
+   */
+  proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.displayName = 'proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange';
+}
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.RemoveFromBalance = function(opt_data) {
+  jspb.Message.initialize(this, opt_data, 0, -1, null, null);
+};
+goog.inherits(proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.RemoveFromBalance, jspb.Message);
+if (goog.DEBUG && !COMPILED) {
+  /**
+   * @public
+   * @override
+   */
+  proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.RemoveFromBalance.displayName = 'proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.RemoveFromBalance';
+}
 
 
 
@@ -1372,7 +1662,9 @@ proto.org.dash.platform.dapi.v0.Proof.toObject = function(includeInstance, msg)
     grovedbProof: msg.getGrovedbProof_asB64(),
     quorumHash: msg.getQuorumHash_asB64(),
     signature: msg.getSignature_asB64(),
-    round: jspb.Message.getFieldWithDefault(msg, 4, 0)
+    round: jspb.Message.getFieldWithDefault(msg, 4, 0),
+    blockIdHash: msg.getBlockIdHash_asB64(),
+    quorumType: jspb.Message.getFieldWithDefault(msg, 6, 0)
   };
 
   if (includeInstance) {
@@ -1425,6 +1717,14 @@ proto.org.dash.platform.dapi.v0.Proof.deserializeBinaryFromReader = function(msg
       var value = /** @type {number} */ (reader.readUint32());
       msg.setRound(value);
       break;
+    case 5:
+      var value = /** @type {!Uint8Array} */ (reader.readBytes());
+      msg.setBlockIdHash(value);
+      break;
+    case 6:
+      var value = /** @type {number} */ (reader.readUint32());
+      msg.setQuorumType(value);
+      break;
     default:
       reader.skipField();
       break;
@@ -1482,6 +1782,20 @@ proto.org.dash.platform.dapi.v0.Proof.serializeBinaryToWriter = function(message
       f
     );
   }
+  f = message.getBlockIdHash_asU8();
+  if (f.length > 0) {
+    writer.writeBytes(
+      5,
+      f
+    );
+  }
+  f = message.getQuorumType();
+  if (f !== 0) {
+    writer.writeUint32(
+      6,
+      f
+    );
+  }
 };
 
 
@@ -1629,14 +1943,74 @@ proto.org.dash.platform.dapi.v0.Proof.prototype.setRound = function(value) {
 };
 
 
+/**
+ * optional bytes block_id_hash = 5;
+ * @return {string}
+ */
+proto.org.dash.platform.dapi.v0.Proof.prototype.getBlockIdHash = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 5, ""));
+};
 
 
-
-if (jspb.Message.GENERATE_TO_OBJECT) {
 /**
- * Creates an object representation of this proto.
- * Field names that are reserved in JavaScript and will be renamed to pb_name.
- * Optional fields that are not set will be set to undefined.
+ * optional bytes block_id_hash = 5;
+ * This is a type-conversion wrapper around `getBlockIdHash()`
+ * @return {string}
+ */
+proto.org.dash.platform.dapi.v0.Proof.prototype.getBlockIdHash_asB64 = function() {
+  return /** @type {string} */ (jspb.Message.bytesAsB64(
+      this.getBlockIdHash()));
+};
+
+
+/**
+ * optional bytes block_id_hash = 5;
+ * Note that Uint8Array is not supported on all browsers.
+ * @see http://caniuse.com/Uint8Array
+ * This is a type-conversion wrapper around `getBlockIdHash()`
+ * @return {!Uint8Array}
+ */
+proto.org.dash.platform.dapi.v0.Proof.prototype.getBlockIdHash_asU8 = function() {
+  return /** @type {!Uint8Array} */ (jspb.Message.bytesAsU8(
+      this.getBlockIdHash()));
+};
+
+
+/**
+ * @param {!(string|Uint8Array)} value
+ * @return {!proto.org.dash.platform.dapi.v0.Proof} returns this
+ */
+proto.org.dash.platform.dapi.v0.Proof.prototype.setBlockIdHash = function(value) {
+  return jspb.Message.setProto3BytesField(this, 5, value);
+};
+
+
+/**
+ * optional uint32 quorum_type = 6;
+ * @return {number}
+ */
+proto.org.dash.platform.dapi.v0.Proof.prototype.getQuorumType = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 6, 0));
+};
+
+
+/**
+ * @param {number} value
+ * @return {!proto.org.dash.platform.dapi.v0.Proof} returns this
+ */
+proto.org.dash.platform.dapi.v0.Proof.prototype.setQuorumType = function(value) {
+  return jspb.Message.setProto3IntField(this, 6, value);
+};
+
+
+
+
+
+if (jspb.Message.GENERATE_TO_OBJECT) {
+/**
+ * Creates an object representation of this proto.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * Optional fields that are not set will be set to undefined.
  * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
  * For the list of reserved names please see:
  *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
@@ -1664,7 +2038,8 @@ proto.org.dash.platform.dapi.v0.ResponseMetadata.toObject = function(includeInst
     height: jspb.Message.getFieldWithDefault(msg, 1, 0),
     coreChainLockedHeight: jspb.Message.getFieldWithDefault(msg, 2, 0),
     timeMs: jspb.Message.getFieldWithDefault(msg, 3, 0),
-    protocolVersion: jspb.Message.getFieldWithDefault(msg, 4, 0)
+    protocolVersion: jspb.Message.getFieldWithDefault(msg, 4, 0),
+    chainId: jspb.Message.getFieldWithDefault(msg, 5, "")
   };
 
   if (includeInstance) {
@@ -1717,6 +2092,10 @@ proto.org.dash.platform.dapi.v0.ResponseMetadata.deserializeBinaryFromReader = f
       var value = /** @type {number} */ (reader.readUint32());
       msg.setProtocolVersion(value);
       break;
+    case 5:
+      var value = /** @type {string} */ (reader.readString());
+      msg.setChainId(value);
+      break;
     default:
       reader.skipField();
       break;
@@ -1774,6 +2153,13 @@ proto.org.dash.platform.dapi.v0.ResponseMetadata.serializeBinaryToWriter = funct
       f
     );
   }
+  f = message.getChainId();
+  if (f.length > 0) {
+    writer.writeString(
+      5,
+      f
+    );
+  }
 };
 
 
@@ -1849,6 +2235,24 @@ proto.org.dash.platform.dapi.v0.ResponseMetadata.prototype.setProtocolVersion =
 };
 
 
+/**
+ * optional string chain_id = 5;
+ * @return {string}
+ */
+proto.org.dash.platform.dapi.v0.ResponseMetadata.prototype.getChainId = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 5, ""));
+};
+
+
+/**
+ * @param {string} value
+ * @return {!proto.org.dash.platform.dapi.v0.ResponseMetadata} returns this
+ */
+proto.org.dash.platform.dapi.v0.ResponseMetadata.prototype.setChainId = function(value) {
+  return jspb.Message.setProto3StringField(this, 5, value);
+};
+
+
 
 
 
@@ -4579,23 +4983,22 @@ proto.org.dash.platform.dapi.v0.GetIdentityBalanceAndRevisionResponse.prototype.
  * @private {!Array<!Array<number>>}
  * @const
  */
-proto.org.dash.platform.dapi.v0.KeyRequestType.oneofGroups_ = [[1,2,3]];
+proto.org.dash.platform.dapi.v0.GetIdentityNonceResponse.oneofGroups_ = [[1,2]];
 
 /**
  * @enum {number}
  */
-proto.org.dash.platform.dapi.v0.KeyRequestType.RequestCase = {
-  REQUEST_NOT_SET: 0,
-  ALL_KEYS: 1,
-  SPECIFIC_KEYS: 2,
-  SEARCH_KEY: 3
+proto.org.dash.platform.dapi.v0.GetIdentityNonceResponse.ResultCase = {
+  RESULT_NOT_SET: 0,
+  NONCE: 1,
+  PROOF: 2
 };
 
 /**
- * @return {proto.org.dash.platform.dapi.v0.KeyRequestType.RequestCase}
+ * @return {proto.org.dash.platform.dapi.v0.GetIdentityNonceResponse.ResultCase}
  */
-proto.org.dash.platform.dapi.v0.KeyRequestType.prototype.getRequestCase = function() {
-  return /** @type {proto.org.dash.platform.dapi.v0.KeyRequestType.RequestCase} */(jspb.Message.computeOneofCase(this, proto.org.dash.platform.dapi.v0.KeyRequestType.oneofGroups_[0]));
+proto.org.dash.platform.dapi.v0.GetIdentityNonceResponse.prototype.getResultCase = function() {
+  return /** @type {proto.org.dash.platform.dapi.v0.GetIdentityNonceResponse.ResultCase} */(jspb.Message.computeOneofCase(this, proto.org.dash.platform.dapi.v0.GetIdentityNonceResponse.oneofGroups_[0]));
 };
 
 
@@ -4613,8 +5016,8 @@ if (jspb.Message.GENERATE_TO_OBJECT) {
  *     http://goto/soy-param-migration
  * @return {!Object}
  */
-proto.org.dash.platform.dapi.v0.KeyRequestType.prototype.toObject = function(opt_includeInstance) {
-  return proto.org.dash.platform.dapi.v0.KeyRequestType.toObject(opt_includeInstance, this);
+proto.org.dash.platform.dapi.v0.GetIdentityNonceResponse.prototype.toObject = function(opt_includeInstance) {
+  return proto.org.dash.platform.dapi.v0.GetIdentityNonceResponse.toObject(opt_includeInstance, this);
 };
 
 
@@ -4623,15 +5026,15 @@ proto.org.dash.platform.dapi.v0.KeyRequestType.prototype.toObject = function(opt
  * @param {boolean|undefined} includeInstance Deprecated. Whether to include
  *     the JSPB instance for transitional soy proto support:
  *     http://goto/soy-param-migration
- * @param {!proto.org.dash.platform.dapi.v0.KeyRequestType} msg The msg instance to transform.
+ * @param {!proto.org.dash.platform.dapi.v0.GetIdentityNonceResponse} msg The msg instance to transform.
  * @return {!Object}
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.org.dash.platform.dapi.v0.KeyRequestType.toObject = function(includeInstance, msg) {
+proto.org.dash.platform.dapi.v0.GetIdentityNonceResponse.toObject = function(includeInstance, msg) {
   var f, obj = {
-    allKeys: (f = msg.getAllKeys()) && proto.org.dash.platform.dapi.v0.AllKeys.toObject(includeInstance, f),
-    specificKeys: (f = msg.getSpecificKeys()) && proto.org.dash.platform.dapi.v0.SpecificKeys.toObject(includeInstance, f),
-    searchKey: (f = msg.getSearchKey()) && proto.org.dash.platform.dapi.v0.SearchKey.toObject(includeInstance, f)
+    nonce: (f = msg.getNonce()) && google_protobuf_wrappers_pb.UInt64Value.toObject(includeInstance, f),
+    proof: (f = msg.getProof()) && proto.org.dash.platform.dapi.v0.Proof.toObject(includeInstance, f),
+    metadata: (f = msg.getMetadata()) && proto.org.dash.platform.dapi.v0.ResponseMetadata.toObject(includeInstance, f)
   };
 
   if (includeInstance) {
@@ -4645,23 +5048,23 @@ proto.org.dash.platform.dapi.v0.KeyRequestType.toObject = function(includeInstan
 /**
  * Deserializes binary data (in protobuf wire format).
  * @param {jspb.ByteSource} bytes The bytes to deserialize.
- * @return {!proto.org.dash.platform.dapi.v0.KeyRequestType}
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentityNonceResponse}
  */
-proto.org.dash.platform.dapi.v0.KeyRequestType.deserializeBinary = function(bytes) {
+proto.org.dash.platform.dapi.v0.GetIdentityNonceResponse.deserializeBinary = function(bytes) {
   var reader = new jspb.BinaryReader(bytes);
-  var msg = new proto.org.dash.platform.dapi.v0.KeyRequestType;
-  return proto.org.dash.platform.dapi.v0.KeyRequestType.deserializeBinaryFromReader(msg, reader);
+  var msg = new proto.org.dash.platform.dapi.v0.GetIdentityNonceResponse;
+  return proto.org.dash.platform.dapi.v0.GetIdentityNonceResponse.deserializeBinaryFromReader(msg, reader);
 };
 
 
 /**
  * Deserializes binary data (in protobuf wire format) from the
  * given reader into the given message object.
- * @param {!proto.org.dash.platform.dapi.v0.KeyRequestType} msg The message object to deserialize into.
+ * @param {!proto.org.dash.platform.dapi.v0.GetIdentityNonceResponse} msg The message object to deserialize into.
  * @param {!jspb.BinaryReader} reader The BinaryReader to use.
- * @return {!proto.org.dash.platform.dapi.v0.KeyRequestType}
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentityNonceResponse}
  */
-proto.org.dash.platform.dapi.v0.KeyRequestType.deserializeBinaryFromReader = function(msg, reader) {
+proto.org.dash.platform.dapi.v0.GetIdentityNonceResponse.deserializeBinaryFromReader = function(msg, reader) {
   while (reader.nextField()) {
     if (reader.isEndGroup()) {
       break;
@@ -4669,19 +5072,19 @@ proto.org.dash.platform.dapi.v0.KeyRequestType.deserializeBinaryFromReader = fun
     var field = reader.getFieldNumber();
     switch (field) {
     case 1:
-      var value = new proto.org.dash.platform.dapi.v0.AllKeys;
-      reader.readMessage(value,proto.org.dash.platform.dapi.v0.AllKeys.deserializeBinaryFromReader);
-      msg.setAllKeys(value);
+      var value = new google_protobuf_wrappers_pb.UInt64Value;
+      reader.readMessage(value,google_protobuf_wrappers_pb.UInt64Value.deserializeBinaryFromReader);
+      msg.setNonce(value);
       break;
     case 2:
-      var value = new proto.org.dash.platform.dapi.v0.SpecificKeys;
-      reader.readMessage(value,proto.org.dash.platform.dapi.v0.SpecificKeys.deserializeBinaryFromReader);
-      msg.setSpecificKeys(value);
+      var value = new proto.org.dash.platform.dapi.v0.Proof;
+      reader.readMessage(value,proto.org.dash.platform.dapi.v0.Proof.deserializeBinaryFromReader);
+      msg.setProof(value);
       break;
     case 3:
-      var value = new proto.org.dash.platform.dapi.v0.SearchKey;
-      reader.readMessage(value,proto.org.dash.platform.dapi.v0.SearchKey.deserializeBinaryFromReader);
-      msg.setSearchKey(value);
+      var value = new proto.org.dash.platform.dapi.v0.ResponseMetadata;
+      reader.readMessage(value,proto.org.dash.platform.dapi.v0.ResponseMetadata.deserializeBinaryFromReader);
+      msg.setMetadata(value);
       break;
     default:
       reader.skipField();
@@ -4696,9 +5099,9 @@ proto.org.dash.platform.dapi.v0.KeyRequestType.deserializeBinaryFromReader = fun
  * Serializes the message to binary data (in protobuf wire format).
  * @return {!Uint8Array}
  */
-proto.org.dash.platform.dapi.v0.KeyRequestType.prototype.serializeBinary = function() {
+proto.org.dash.platform.dapi.v0.GetIdentityNonceResponse.prototype.serializeBinary = function() {
   var writer = new jspb.BinaryWriter();
-  proto.org.dash.platform.dapi.v0.KeyRequestType.serializeBinaryToWriter(this, writer);
+  proto.org.dash.platform.dapi.v0.GetIdentityNonceResponse.serializeBinaryToWriter(this, writer);
   return writer.getResultBuffer();
 };
 
@@ -4706,64 +5109,64 @@ proto.org.dash.platform.dapi.v0.KeyRequestType.prototype.serializeBinary = funct
 /**
  * Serializes the given message to binary data (in protobuf wire
  * format), writing to the given BinaryWriter.
- * @param {!proto.org.dash.platform.dapi.v0.KeyRequestType} message
+ * @param {!proto.org.dash.platform.dapi.v0.GetIdentityNonceResponse} message
  * @param {!jspb.BinaryWriter} writer
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.org.dash.platform.dapi.v0.KeyRequestType.serializeBinaryToWriter = function(message, writer) {
+proto.org.dash.platform.dapi.v0.GetIdentityNonceResponse.serializeBinaryToWriter = function(message, writer) {
   var f = undefined;
-  f = message.getAllKeys();
+  f = message.getNonce();
   if (f != null) {
     writer.writeMessage(
       1,
       f,
-      proto.org.dash.platform.dapi.v0.AllKeys.serializeBinaryToWriter
+      google_protobuf_wrappers_pb.UInt64Value.serializeBinaryToWriter
     );
   }
-  f = message.getSpecificKeys();
+  f = message.getProof();
   if (f != null) {
     writer.writeMessage(
       2,
       f,
-      proto.org.dash.platform.dapi.v0.SpecificKeys.serializeBinaryToWriter
+      proto.org.dash.platform.dapi.v0.Proof.serializeBinaryToWriter
     );
   }
-  f = message.getSearchKey();
+  f = message.getMetadata();
   if (f != null) {
     writer.writeMessage(
       3,
       f,
-      proto.org.dash.platform.dapi.v0.SearchKey.serializeBinaryToWriter
+      proto.org.dash.platform.dapi.v0.ResponseMetadata.serializeBinaryToWriter
     );
   }
 };
 
 
 /**
- * optional AllKeys all_keys = 1;
- * @return {?proto.org.dash.platform.dapi.v0.AllKeys}
+ * optional google.protobuf.UInt64Value nonce = 1;
+ * @return {?proto.google.protobuf.UInt64Value}
  */
-proto.org.dash.platform.dapi.v0.KeyRequestType.prototype.getAllKeys = function() {
-  return /** @type{?proto.org.dash.platform.dapi.v0.AllKeys} */ (
-    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.AllKeys, 1));
+proto.org.dash.platform.dapi.v0.GetIdentityNonceResponse.prototype.getNonce = function() {
+  return /** @type{?proto.google.protobuf.UInt64Value} */ (
+    jspb.Message.getWrapperField(this, google_protobuf_wrappers_pb.UInt64Value, 1));
 };
 
 
 /**
- * @param {?proto.org.dash.platform.dapi.v0.AllKeys|undefined} value
- * @return {!proto.org.dash.platform.dapi.v0.KeyRequestType} returns this
+ * @param {?proto.google.protobuf.UInt64Value|undefined} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentityNonceResponse} returns this
 */
-proto.org.dash.platform.dapi.v0.KeyRequestType.prototype.setAllKeys = function(value) {
-  return jspb.Message.setOneofWrapperField(this, 1, proto.org.dash.platform.dapi.v0.KeyRequestType.oneofGroups_[0], value);
+proto.org.dash.platform.dapi.v0.GetIdentityNonceResponse.prototype.setNonce = function(value) {
+  return jspb.Message.setOneofWrapperField(this, 1, proto.org.dash.platform.dapi.v0.GetIdentityNonceResponse.oneofGroups_[0], value);
 };
 
 
 /**
  * Clears the message field making it undefined.
- * @return {!proto.org.dash.platform.dapi.v0.KeyRequestType} returns this
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentityNonceResponse} returns this
  */
-proto.org.dash.platform.dapi.v0.KeyRequestType.prototype.clearAllKeys = function() {
-  return this.setAllKeys(undefined);
+proto.org.dash.platform.dapi.v0.GetIdentityNonceResponse.prototype.clearNonce = function() {
+  return this.setNonce(undefined);
 };
 
 
@@ -4771,36 +5174,36 @@ proto.org.dash.platform.dapi.v0.KeyRequestType.prototype.clearAllKeys = function
  * Returns whether this field is set.
  * @return {boolean}
  */
-proto.org.dash.platform.dapi.v0.KeyRequestType.prototype.hasAllKeys = function() {
+proto.org.dash.platform.dapi.v0.GetIdentityNonceResponse.prototype.hasNonce = function() {
   return jspb.Message.getField(this, 1) != null;
 };
 
 
 /**
- * optional SpecificKeys specific_keys = 2;
- * @return {?proto.org.dash.platform.dapi.v0.SpecificKeys}
+ * optional Proof proof = 2;
+ * @return {?proto.org.dash.platform.dapi.v0.Proof}
  */
-proto.org.dash.platform.dapi.v0.KeyRequestType.prototype.getSpecificKeys = function() {
-  return /** @type{?proto.org.dash.platform.dapi.v0.SpecificKeys} */ (
-    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.SpecificKeys, 2));
+proto.org.dash.platform.dapi.v0.GetIdentityNonceResponse.prototype.getProof = function() {
+  return /** @type{?proto.org.dash.platform.dapi.v0.Proof} */ (
+    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.Proof, 2));
 };
 
 
 /**
- * @param {?proto.org.dash.platform.dapi.v0.SpecificKeys|undefined} value
- * @return {!proto.org.dash.platform.dapi.v0.KeyRequestType} returns this
+ * @param {?proto.org.dash.platform.dapi.v0.Proof|undefined} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentityNonceResponse} returns this
 */
-proto.org.dash.platform.dapi.v0.KeyRequestType.prototype.setSpecificKeys = function(value) {
-  return jspb.Message.setOneofWrapperField(this, 2, proto.org.dash.platform.dapi.v0.KeyRequestType.oneofGroups_[0], value);
+proto.org.dash.platform.dapi.v0.GetIdentityNonceResponse.prototype.setProof = function(value) {
+  return jspb.Message.setOneofWrapperField(this, 2, proto.org.dash.platform.dapi.v0.GetIdentityNonceResponse.oneofGroups_[0], value);
 };
 
 
 /**
  * Clears the message field making it undefined.
- * @return {!proto.org.dash.platform.dapi.v0.KeyRequestType} returns this
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentityNonceResponse} returns this
  */
-proto.org.dash.platform.dapi.v0.KeyRequestType.prototype.clearSpecificKeys = function() {
-  return this.setSpecificKeys(undefined);
+proto.org.dash.platform.dapi.v0.GetIdentityNonceResponse.prototype.clearProof = function() {
+  return this.setProof(undefined);
 };
 
 
@@ -4808,36 +5211,36 @@ proto.org.dash.platform.dapi.v0.KeyRequestType.prototype.clearSpecificKeys = fun
  * Returns whether this field is set.
  * @return {boolean}
  */
-proto.org.dash.platform.dapi.v0.KeyRequestType.prototype.hasSpecificKeys = function() {
+proto.org.dash.platform.dapi.v0.GetIdentityNonceResponse.prototype.hasProof = function() {
   return jspb.Message.getField(this, 2) != null;
 };
 
 
 /**
- * optional SearchKey search_key = 3;
- * @return {?proto.org.dash.platform.dapi.v0.SearchKey}
+ * optional ResponseMetadata metadata = 3;
+ * @return {?proto.org.dash.platform.dapi.v0.ResponseMetadata}
  */
-proto.org.dash.platform.dapi.v0.KeyRequestType.prototype.getSearchKey = function() {
-  return /** @type{?proto.org.dash.platform.dapi.v0.SearchKey} */ (
-    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.SearchKey, 3));
+proto.org.dash.platform.dapi.v0.GetIdentityNonceResponse.prototype.getMetadata = function() {
+  return /** @type{?proto.org.dash.platform.dapi.v0.ResponseMetadata} */ (
+    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.ResponseMetadata, 3));
 };
 
 
 /**
- * @param {?proto.org.dash.platform.dapi.v0.SearchKey|undefined} value
- * @return {!proto.org.dash.platform.dapi.v0.KeyRequestType} returns this
+ * @param {?proto.org.dash.platform.dapi.v0.ResponseMetadata|undefined} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentityNonceResponse} returns this
 */
-proto.org.dash.platform.dapi.v0.KeyRequestType.prototype.setSearchKey = function(value) {
-  return jspb.Message.setOneofWrapperField(this, 3, proto.org.dash.platform.dapi.v0.KeyRequestType.oneofGroups_[0], value);
+proto.org.dash.platform.dapi.v0.GetIdentityNonceResponse.prototype.setMetadata = function(value) {
+  return jspb.Message.setWrapperField(this, 3, value);
 };
 
 
 /**
  * Clears the message field making it undefined.
- * @return {!proto.org.dash.platform.dapi.v0.KeyRequestType} returns this
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentityNonceResponse} returns this
  */
-proto.org.dash.platform.dapi.v0.KeyRequestType.prototype.clearSearchKey = function() {
-  return this.setSearchKey(undefined);
+proto.org.dash.platform.dapi.v0.GetIdentityNonceResponse.prototype.clearMetadata = function() {
+  return this.setMetadata(undefined);
 };
 
 
@@ -4845,7 +5248,7 @@ proto.org.dash.platform.dapi.v0.KeyRequestType.prototype.clearSearchKey = functi
  * Returns whether this field is set.
  * @return {boolean}
  */
-proto.org.dash.platform.dapi.v0.KeyRequestType.prototype.hasSearchKey = function() {
+proto.org.dash.platform.dapi.v0.GetIdentityNonceResponse.prototype.hasMetadata = function() {
   return jspb.Message.getField(this, 3) != null;
 };
 
@@ -4866,8 +5269,8 @@ if (jspb.Message.GENERATE_TO_OBJECT) {
  *     http://goto/soy-param-migration
  * @return {!Object}
  */
-proto.org.dash.platform.dapi.v0.AllKeys.prototype.toObject = function(opt_includeInstance) {
-  return proto.org.dash.platform.dapi.v0.AllKeys.toObject(opt_includeInstance, this);
+proto.org.dash.platform.dapi.v0.GetIdentityContractNonceRequest.prototype.toObject = function(opt_includeInstance) {
+  return proto.org.dash.platform.dapi.v0.GetIdentityContractNonceRequest.toObject(opt_includeInstance, this);
 };
 
 
@@ -4876,13 +5279,15 @@ proto.org.dash.platform.dapi.v0.AllKeys.prototype.toObject = function(opt_includ
  * @param {boolean|undefined} includeInstance Deprecated. Whether to include
  *     the JSPB instance for transitional soy proto support:
  *     http://goto/soy-param-migration
- * @param {!proto.org.dash.platform.dapi.v0.AllKeys} msg The msg instance to transform.
+ * @param {!proto.org.dash.platform.dapi.v0.GetIdentityContractNonceRequest} msg The msg instance to transform.
  * @return {!Object}
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.org.dash.platform.dapi.v0.AllKeys.toObject = function(includeInstance, msg) {
+proto.org.dash.platform.dapi.v0.GetIdentityContractNonceRequest.toObject = function(includeInstance, msg) {
   var f, obj = {
-
+    identityId: msg.getIdentityId_asB64(),
+    contractId: msg.getContractId_asB64(),
+    prove: jspb.Message.getBooleanFieldWithDefault(msg, 3, false)
   };
 
   if (includeInstance) {
@@ -4896,29 +5301,41 @@ proto.org.dash.platform.dapi.v0.AllKeys.toObject = function(includeInstance, msg
 /**
  * Deserializes binary data (in protobuf wire format).
  * @param {jspb.ByteSource} bytes The bytes to deserialize.
- * @return {!proto.org.dash.platform.dapi.v0.AllKeys}
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentityContractNonceRequest}
  */
-proto.org.dash.platform.dapi.v0.AllKeys.deserializeBinary = function(bytes) {
+proto.org.dash.platform.dapi.v0.GetIdentityContractNonceRequest.deserializeBinary = function(bytes) {
   var reader = new jspb.BinaryReader(bytes);
-  var msg = new proto.org.dash.platform.dapi.v0.AllKeys;
-  return proto.org.dash.platform.dapi.v0.AllKeys.deserializeBinaryFromReader(msg, reader);
+  var msg = new proto.org.dash.platform.dapi.v0.GetIdentityContractNonceRequest;
+  return proto.org.dash.platform.dapi.v0.GetIdentityContractNonceRequest.deserializeBinaryFromReader(msg, reader);
 };
 
 
 /**
  * Deserializes binary data (in protobuf wire format) from the
  * given reader into the given message object.
- * @param {!proto.org.dash.platform.dapi.v0.AllKeys} msg The message object to deserialize into.
+ * @param {!proto.org.dash.platform.dapi.v0.GetIdentityContractNonceRequest} msg The message object to deserialize into.
  * @param {!jspb.BinaryReader} reader The BinaryReader to use.
- * @return {!proto.org.dash.platform.dapi.v0.AllKeys}
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentityContractNonceRequest}
  */
-proto.org.dash.platform.dapi.v0.AllKeys.deserializeBinaryFromReader = function(msg, reader) {
+proto.org.dash.platform.dapi.v0.GetIdentityContractNonceRequest.deserializeBinaryFromReader = function(msg, reader) {
   while (reader.nextField()) {
     if (reader.isEndGroup()) {
       break;
     }
     var field = reader.getFieldNumber();
     switch (field) {
+    case 1:
+      var value = /** @type {!Uint8Array} */ (reader.readBytes());
+      msg.setIdentityId(value);
+      break;
+    case 2:
+      var value = /** @type {!Uint8Array} */ (reader.readBytes());
+      msg.setContractId(value);
+      break;
+    case 3:
+      var value = /** @type {boolean} */ (reader.readBool());
+      msg.setProve(value);
+      break;
     default:
       reader.skipField();
       break;
@@ -4932,9 +5349,9 @@ proto.org.dash.platform.dapi.v0.AllKeys.deserializeBinaryFromReader = function(m
  * Serializes the message to binary data (in protobuf wire format).
  * @return {!Uint8Array}
  */
-proto.org.dash.platform.dapi.v0.AllKeys.prototype.serializeBinary = function() {
+proto.org.dash.platform.dapi.v0.GetIdentityContractNonceRequest.prototype.serializeBinary = function() {
   var writer = new jspb.BinaryWriter();
-  proto.org.dash.platform.dapi.v0.AllKeys.serializeBinaryToWriter(this, writer);
+  proto.org.dash.platform.dapi.v0.GetIdentityContractNonceRequest.serializeBinaryToWriter(this, writer);
   return writer.getResultBuffer();
 };
 
@@ -4942,173 +5359,164 @@ proto.org.dash.platform.dapi.v0.AllKeys.prototype.serializeBinary = function() {
 /**
  * Serializes the given message to binary data (in protobuf wire
  * format), writing to the given BinaryWriter.
- * @param {!proto.org.dash.platform.dapi.v0.AllKeys} message
+ * @param {!proto.org.dash.platform.dapi.v0.GetIdentityContractNonceRequest} message
  * @param {!jspb.BinaryWriter} writer
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.org.dash.platform.dapi.v0.AllKeys.serializeBinaryToWriter = function(message, writer) {
+proto.org.dash.platform.dapi.v0.GetIdentityContractNonceRequest.serializeBinaryToWriter = function(message, writer) {
   var f = undefined;
+  f = message.getIdentityId_asU8();
+  if (f.length > 0) {
+    writer.writeBytes(
+      1,
+      f
+    );
+  }
+  f = message.getContractId_asU8();
+  if (f.length > 0) {
+    writer.writeBytes(
+      2,
+      f
+    );
+  }
+  f = message.getProve();
+  if (f) {
+    writer.writeBool(
+      3,
+      f
+    );
+  }
 };
 
 
-
 /**
- * List of repeated fields within this message type.
- * @private {!Array<number>}
- * @const
+ * optional bytes identity_id = 1;
+ * @return {string}
  */
-proto.org.dash.platform.dapi.v0.SpecificKeys.repeatedFields_ = [1];
-
+proto.org.dash.platform.dapi.v0.GetIdentityContractNonceRequest.prototype.getIdentityId = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 1, ""));
+};
 
 
-if (jspb.Message.GENERATE_TO_OBJECT) {
 /**
- * Creates an object representation of this proto.
- * Field names that are reserved in JavaScript and will be renamed to pb_name.
- * Optional fields that are not set will be set to undefined.
- * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
- * For the list of reserved names please see:
- *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
- * @param {boolean=} opt_includeInstance Deprecated. whether to include the
- *     JSPB instance for transitional soy proto support:
- *     http://goto/soy-param-migration
- * @return {!Object}
+ * optional bytes identity_id = 1;
+ * This is a type-conversion wrapper around `getIdentityId()`
+ * @return {string}
  */
-proto.org.dash.platform.dapi.v0.SpecificKeys.prototype.toObject = function(opt_includeInstance) {
-  return proto.org.dash.platform.dapi.v0.SpecificKeys.toObject(opt_includeInstance, this);
+proto.org.dash.platform.dapi.v0.GetIdentityContractNonceRequest.prototype.getIdentityId_asB64 = function() {
+  return /** @type {string} */ (jspb.Message.bytesAsB64(
+      this.getIdentityId()));
 };
 
 
 /**
- * Static version of the {@see toObject} method.
- * @param {boolean|undefined} includeInstance Deprecated. Whether to include
- *     the JSPB instance for transitional soy proto support:
- *     http://goto/soy-param-migration
- * @param {!proto.org.dash.platform.dapi.v0.SpecificKeys} msg The msg instance to transform.
- * @return {!Object}
- * @suppress {unusedLocalVariables} f is only used for nested messages
+ * optional bytes identity_id = 1;
+ * Note that Uint8Array is not supported on all browsers.
+ * @see http://caniuse.com/Uint8Array
+ * This is a type-conversion wrapper around `getIdentityId()`
+ * @return {!Uint8Array}
  */
-proto.org.dash.platform.dapi.v0.SpecificKeys.toObject = function(includeInstance, msg) {
-  var f, obj = {
-    keyIdsList: (f = jspb.Message.getRepeatedField(msg, 1)) == null ? undefined : f
-  };
-
-  if (includeInstance) {
-    obj.$jspbMessageInstance = msg;
-  }
-  return obj;
+proto.org.dash.platform.dapi.v0.GetIdentityContractNonceRequest.prototype.getIdentityId_asU8 = function() {
+  return /** @type {!Uint8Array} */ (jspb.Message.bytesAsU8(
+      this.getIdentityId()));
 };
-}
 
 
 /**
- * Deserializes binary data (in protobuf wire format).
- * @param {jspb.ByteSource} bytes The bytes to deserialize.
- * @return {!proto.org.dash.platform.dapi.v0.SpecificKeys}
+ * @param {!(string|Uint8Array)} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentityContractNonceRequest} returns this
  */
-proto.org.dash.platform.dapi.v0.SpecificKeys.deserializeBinary = function(bytes) {
-  var reader = new jspb.BinaryReader(bytes);
-  var msg = new proto.org.dash.platform.dapi.v0.SpecificKeys;
-  return proto.org.dash.platform.dapi.v0.SpecificKeys.deserializeBinaryFromReader(msg, reader);
+proto.org.dash.platform.dapi.v0.GetIdentityContractNonceRequest.prototype.setIdentityId = function(value) {
+  return jspb.Message.setProto3BytesField(this, 1, value);
 };
 
 
 /**
- * Deserializes binary data (in protobuf wire format) from the
- * given reader into the given message object.
- * @param {!proto.org.dash.platform.dapi.v0.SpecificKeys} msg The message object to deserialize into.
- * @param {!jspb.BinaryReader} reader The BinaryReader to use.
- * @return {!proto.org.dash.platform.dapi.v0.SpecificKeys}
+ * optional bytes contract_id = 2;
+ * @return {string}
  */
-proto.org.dash.platform.dapi.v0.SpecificKeys.deserializeBinaryFromReader = function(msg, reader) {
-  while (reader.nextField()) {
-    if (reader.isEndGroup()) {
-      break;
-    }
-    var field = reader.getFieldNumber();
-    switch (field) {
-    case 1:
-      var values = /** @type {!Array<number>} */ (reader.isDelimited() ? reader.readPackedUint32() : [reader.readUint32()]);
-      for (var i = 0; i < values.length; i++) {
-        msg.addKeyIds(values[i]);
-      }
-      break;
-    default:
-      reader.skipField();
-      break;
-    }
-  }
-  return msg;
+proto.org.dash.platform.dapi.v0.GetIdentityContractNonceRequest.prototype.getContractId = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 2, ""));
 };
 
 
 /**
- * Serializes the message to binary data (in protobuf wire format).
- * @return {!Uint8Array}
+ * optional bytes contract_id = 2;
+ * This is a type-conversion wrapper around `getContractId()`
+ * @return {string}
  */
-proto.org.dash.platform.dapi.v0.SpecificKeys.prototype.serializeBinary = function() {
-  var writer = new jspb.BinaryWriter();
-  proto.org.dash.platform.dapi.v0.SpecificKeys.serializeBinaryToWriter(this, writer);
-  return writer.getResultBuffer();
+proto.org.dash.platform.dapi.v0.GetIdentityContractNonceRequest.prototype.getContractId_asB64 = function() {
+  return /** @type {string} */ (jspb.Message.bytesAsB64(
+      this.getContractId()));
 };
 
 
 /**
- * Serializes the given message to binary data (in protobuf wire
- * format), writing to the given BinaryWriter.
- * @param {!proto.org.dash.platform.dapi.v0.SpecificKeys} message
- * @param {!jspb.BinaryWriter} writer
- * @suppress {unusedLocalVariables} f is only used for nested messages
+ * optional bytes contract_id = 2;
+ * Note that Uint8Array is not supported on all browsers.
+ * @see http://caniuse.com/Uint8Array
+ * This is a type-conversion wrapper around `getContractId()`
+ * @return {!Uint8Array}
  */
-proto.org.dash.platform.dapi.v0.SpecificKeys.serializeBinaryToWriter = function(message, writer) {
-  var f = undefined;
-  f = message.getKeyIdsList();
-  if (f.length > 0) {
-    writer.writePackedUint32(
-      1,
-      f
-    );
-  }
+proto.org.dash.platform.dapi.v0.GetIdentityContractNonceRequest.prototype.getContractId_asU8 = function() {
+  return /** @type {!Uint8Array} */ (jspb.Message.bytesAsU8(
+      this.getContractId()));
 };
 
 
 /**
- * repeated uint32 key_ids = 1;
- * @return {!Array<number>}
+ * @param {!(string|Uint8Array)} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentityContractNonceRequest} returns this
  */
-proto.org.dash.platform.dapi.v0.SpecificKeys.prototype.getKeyIdsList = function() {
-  return /** @type {!Array<number>} */ (jspb.Message.getRepeatedField(this, 1));
+proto.org.dash.platform.dapi.v0.GetIdentityContractNonceRequest.prototype.setContractId = function(value) {
+  return jspb.Message.setProto3BytesField(this, 2, value);
 };
 
 
 /**
- * @param {!Array<number>} value
- * @return {!proto.org.dash.platform.dapi.v0.SpecificKeys} returns this
+ * optional bool prove = 3;
+ * @return {boolean}
  */
-proto.org.dash.platform.dapi.v0.SpecificKeys.prototype.setKeyIdsList = function(value) {
-  return jspb.Message.setField(this, 1, value || []);
+proto.org.dash.platform.dapi.v0.GetIdentityContractNonceRequest.prototype.getProve = function() {
+  return /** @type {boolean} */ (jspb.Message.getBooleanFieldWithDefault(this, 3, false));
 };
 
 
 /**
- * @param {number} value
- * @param {number=} opt_index
- * @return {!proto.org.dash.platform.dapi.v0.SpecificKeys} returns this
+ * @param {boolean} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentityContractNonceRequest} returns this
  */
-proto.org.dash.platform.dapi.v0.SpecificKeys.prototype.addKeyIds = function(value, opt_index) {
-  return jspb.Message.addToRepeatedField(this, 1, value, opt_index);
+proto.org.dash.platform.dapi.v0.GetIdentityContractNonceRequest.prototype.setProve = function(value) {
+  return jspb.Message.setProto3BooleanField(this, 3, value);
 };
 
 
+
 /**
- * Clears the list making it empty but non-null.
- * @return {!proto.org.dash.platform.dapi.v0.SpecificKeys} returns this
+ * Oneof group definitions for this message. Each group defines the field
+ * numbers belonging to that group. When of these fields' value is set, all
+ * other fields in the group are cleared. During deserialization, if multiple
+ * fields are encountered for a group, only the last value seen will be kept.
+ * @private {!Array<!Array<number>>}
+ * @const
  */
-proto.org.dash.platform.dapi.v0.SpecificKeys.prototype.clearKeyIdsList = function() {
-  return this.setKeyIdsList([]);
-};
+proto.org.dash.platform.dapi.v0.GetIdentityContractNonceResponse.oneofGroups_ = [[1,2]];
 
+/**
+ * @enum {number}
+ */
+proto.org.dash.platform.dapi.v0.GetIdentityContractNonceResponse.ResultCase = {
+  RESULT_NOT_SET: 0,
+  NONCE: 1,
+  PROOF: 2
+};
 
+/**
+ * @return {proto.org.dash.platform.dapi.v0.GetIdentityContractNonceResponse.ResultCase}
+ */
+proto.org.dash.platform.dapi.v0.GetIdentityContractNonceResponse.prototype.getResultCase = function() {
+  return /** @type {proto.org.dash.platform.dapi.v0.GetIdentityContractNonceResponse.ResultCase} */(jspb.Message.computeOneofCase(this, proto.org.dash.platform.dapi.v0.GetIdentityContractNonceResponse.oneofGroups_[0]));
+};
 
 
 
@@ -5125,8 +5533,8 @@ if (jspb.Message.GENERATE_TO_OBJECT) {
  *     http://goto/soy-param-migration
  * @return {!Object}
  */
-proto.org.dash.platform.dapi.v0.SearchKey.prototype.toObject = function(opt_includeInstance) {
-  return proto.org.dash.platform.dapi.v0.SearchKey.toObject(opt_includeInstance, this);
+proto.org.dash.platform.dapi.v0.GetIdentityContractNonceResponse.prototype.toObject = function(opt_includeInstance) {
+  return proto.org.dash.platform.dapi.v0.GetIdentityContractNonceResponse.toObject(opt_includeInstance, this);
 };
 
 
@@ -5135,13 +5543,15 @@ proto.org.dash.platform.dapi.v0.SearchKey.prototype.toObject = function(opt_incl
  * @param {boolean|undefined} includeInstance Deprecated. Whether to include
  *     the JSPB instance for transitional soy proto support:
  *     http://goto/soy-param-migration
- * @param {!proto.org.dash.platform.dapi.v0.SearchKey} msg The msg instance to transform.
+ * @param {!proto.org.dash.platform.dapi.v0.GetIdentityContractNonceResponse} msg The msg instance to transform.
  * @return {!Object}
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.org.dash.platform.dapi.v0.SearchKey.toObject = function(includeInstance, msg) {
+proto.org.dash.platform.dapi.v0.GetIdentityContractNonceResponse.toObject = function(includeInstance, msg) {
   var f, obj = {
-    purposeMapMap: (f = msg.getPurposeMapMap()) ? f.toObject(includeInstance, proto.org.dash.platform.dapi.v0.SecurityLevelMap.toObject) : []
+    nonce: (f = msg.getNonce()) && google_protobuf_wrappers_pb.UInt64Value.toObject(includeInstance, f),
+    proof: (f = msg.getProof()) && proto.org.dash.platform.dapi.v0.Proof.toObject(includeInstance, f),
+    metadata: (f = msg.getMetadata()) && proto.org.dash.platform.dapi.v0.ResponseMetadata.toObject(includeInstance, f)
   };
 
   if (includeInstance) {
@@ -5155,23 +5565,23 @@ proto.org.dash.platform.dapi.v0.SearchKey.toObject = function(includeInstance, m
 /**
  * Deserializes binary data (in protobuf wire format).
  * @param {jspb.ByteSource} bytes The bytes to deserialize.
- * @return {!proto.org.dash.platform.dapi.v0.SearchKey}
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentityContractNonceResponse}
  */
-proto.org.dash.platform.dapi.v0.SearchKey.deserializeBinary = function(bytes) {
+proto.org.dash.platform.dapi.v0.GetIdentityContractNonceResponse.deserializeBinary = function(bytes) {
   var reader = new jspb.BinaryReader(bytes);
-  var msg = new proto.org.dash.platform.dapi.v0.SearchKey;
-  return proto.org.dash.platform.dapi.v0.SearchKey.deserializeBinaryFromReader(msg, reader);
+  var msg = new proto.org.dash.platform.dapi.v0.GetIdentityContractNonceResponse;
+  return proto.org.dash.platform.dapi.v0.GetIdentityContractNonceResponse.deserializeBinaryFromReader(msg, reader);
 };
 
 
 /**
  * Deserializes binary data (in protobuf wire format) from the
  * given reader into the given message object.
- * @param {!proto.org.dash.platform.dapi.v0.SearchKey} msg The message object to deserialize into.
+ * @param {!proto.org.dash.platform.dapi.v0.GetIdentityContractNonceResponse} msg The message object to deserialize into.
  * @param {!jspb.BinaryReader} reader The BinaryReader to use.
- * @return {!proto.org.dash.platform.dapi.v0.SearchKey}
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentityContractNonceResponse}
  */
-proto.org.dash.platform.dapi.v0.SearchKey.deserializeBinaryFromReader = function(msg, reader) {
+proto.org.dash.platform.dapi.v0.GetIdentityContractNonceResponse.deserializeBinaryFromReader = function(msg, reader) {
   while (reader.nextField()) {
     if (reader.isEndGroup()) {
       break;
@@ -5179,10 +5589,19 @@ proto.org.dash.platform.dapi.v0.SearchKey.deserializeBinaryFromReader = function
     var field = reader.getFieldNumber();
     switch (field) {
     case 1:
-      var value = msg.getPurposeMapMap();
-      reader.readMessage(value, function(message, reader) {
-        jspb.Map.deserializeBinary(message, reader, jspb.BinaryReader.prototype.readUint32, jspb.BinaryReader.prototype.readMessage, proto.org.dash.platform.dapi.v0.SecurityLevelMap.deserializeBinaryFromReader, 0, new proto.org.dash.platform.dapi.v0.SecurityLevelMap());
-         });
+      var value = new google_protobuf_wrappers_pb.UInt64Value;
+      reader.readMessage(value,google_protobuf_wrappers_pb.UInt64Value.deserializeBinaryFromReader);
+      msg.setNonce(value);
+      break;
+    case 2:
+      var value = new proto.org.dash.platform.dapi.v0.Proof;
+      reader.readMessage(value,proto.org.dash.platform.dapi.v0.Proof.deserializeBinaryFromReader);
+      msg.setProof(value);
+      break;
+    case 3:
+      var value = new proto.org.dash.platform.dapi.v0.ResponseMetadata;
+      reader.readMessage(value,proto.org.dash.platform.dapi.v0.ResponseMetadata.deserializeBinaryFromReader);
+      msg.setMetadata(value);
       break;
     default:
       reader.skipField();
@@ -5197,9 +5616,9 @@ proto.org.dash.platform.dapi.v0.SearchKey.deserializeBinaryFromReader = function
  * Serializes the message to binary data (in protobuf wire format).
  * @return {!Uint8Array}
  */
-proto.org.dash.platform.dapi.v0.SearchKey.prototype.serializeBinary = function() {
+proto.org.dash.platform.dapi.v0.GetIdentityContractNonceResponse.prototype.serializeBinary = function() {
   var writer = new jspb.BinaryWriter();
-  proto.org.dash.platform.dapi.v0.SearchKey.serializeBinaryToWriter(this, writer);
+  proto.org.dash.platform.dapi.v0.GetIdentityContractNonceResponse.serializeBinaryToWriter(this, writer);
   return writer.getResultBuffer();
 };
 
@@ -5207,182 +5626,177 @@ proto.org.dash.platform.dapi.v0.SearchKey.prototype.serializeBinary = function()
 /**
  * Serializes the given message to binary data (in protobuf wire
  * format), writing to the given BinaryWriter.
- * @param {!proto.org.dash.platform.dapi.v0.SearchKey} message
+ * @param {!proto.org.dash.platform.dapi.v0.GetIdentityContractNonceResponse} message
  * @param {!jspb.BinaryWriter} writer
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.org.dash.platform.dapi.v0.SearchKey.serializeBinaryToWriter = function(message, writer) {
+proto.org.dash.platform.dapi.v0.GetIdentityContractNonceResponse.serializeBinaryToWriter = function(message, writer) {
   var f = undefined;
-  f = message.getPurposeMapMap(true);
-  if (f && f.getLength() > 0) {
-    f.serializeBinary(1, writer, jspb.BinaryWriter.prototype.writeUint32, jspb.BinaryWriter.prototype.writeMessage, proto.org.dash.platform.dapi.v0.SecurityLevelMap.serializeBinaryToWriter);
+  f = message.getNonce();
+  if (f != null) {
+    writer.writeMessage(
+      1,
+      f,
+      google_protobuf_wrappers_pb.UInt64Value.serializeBinaryToWriter
+    );
+  }
+  f = message.getProof();
+  if (f != null) {
+    writer.writeMessage(
+      2,
+      f,
+      proto.org.dash.platform.dapi.v0.Proof.serializeBinaryToWriter
+    );
+  }
+  f = message.getMetadata();
+  if (f != null) {
+    writer.writeMessage(
+      3,
+      f,
+      proto.org.dash.platform.dapi.v0.ResponseMetadata.serializeBinaryToWriter
+    );
   }
 };
 
 
 /**
- * map<uint32, SecurityLevelMap> purpose_map = 1;
- * @param {boolean=} opt_noLazyCreate Do not create the map if
- * empty, instead returning `undefined`
- * @return {!jspb.Map<number,!proto.org.dash.platform.dapi.v0.SecurityLevelMap>}
+ * optional google.protobuf.UInt64Value nonce = 1;
+ * @return {?proto.google.protobuf.UInt64Value}
  */
-proto.org.dash.platform.dapi.v0.SearchKey.prototype.getPurposeMapMap = function(opt_noLazyCreate) {
-  return /** @type {!jspb.Map<number,!proto.org.dash.platform.dapi.v0.SecurityLevelMap>} */ (
-      jspb.Message.getMapField(this, 1, opt_noLazyCreate,
-      proto.org.dash.platform.dapi.v0.SecurityLevelMap));
+proto.org.dash.platform.dapi.v0.GetIdentityContractNonceResponse.prototype.getNonce = function() {
+  return /** @type{?proto.google.protobuf.UInt64Value} */ (
+    jspb.Message.getWrapperField(this, google_protobuf_wrappers_pb.UInt64Value, 1));
 };
 
 
 /**
- * Clears values from the map. The map will be non-null.
- * @return {!proto.org.dash.platform.dapi.v0.SearchKey} returns this
- */
-proto.org.dash.platform.dapi.v0.SearchKey.prototype.clearPurposeMapMap = function() {
-  this.getPurposeMapMap().clear();
-  return this;};
-
+ * @param {?proto.google.protobuf.UInt64Value|undefined} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentityContractNonceResponse} returns this
+*/
+proto.org.dash.platform.dapi.v0.GetIdentityContractNonceResponse.prototype.setNonce = function(value) {
+  return jspb.Message.setOneofWrapperField(this, 1, proto.org.dash.platform.dapi.v0.GetIdentityContractNonceResponse.oneofGroups_[0], value);
+};
 
 
+/**
+ * Clears the message field making it undefined.
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentityContractNonceResponse} returns this
+ */
+proto.org.dash.platform.dapi.v0.GetIdentityContractNonceResponse.prototype.clearNonce = function() {
+  return this.setNonce(undefined);
+};
 
 
-if (jspb.Message.GENERATE_TO_OBJECT) {
 /**
- * Creates an object representation of this proto.
- * Field names that are reserved in JavaScript and will be renamed to pb_name.
- * Optional fields that are not set will be set to undefined.
- * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
- * For the list of reserved names please see:
- *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
- * @param {boolean=} opt_includeInstance Deprecated. whether to include the
- *     JSPB instance for transitional soy proto support:
- *     http://goto/soy-param-migration
- * @return {!Object}
+ * Returns whether this field is set.
+ * @return {boolean}
  */
-proto.org.dash.platform.dapi.v0.SecurityLevelMap.prototype.toObject = function(opt_includeInstance) {
-  return proto.org.dash.platform.dapi.v0.SecurityLevelMap.toObject(opt_includeInstance, this);
+proto.org.dash.platform.dapi.v0.GetIdentityContractNonceResponse.prototype.hasNonce = function() {
+  return jspb.Message.getField(this, 1) != null;
 };
 
 
 /**
- * Static version of the {@see toObject} method.
- * @param {boolean|undefined} includeInstance Deprecated. Whether to include
- *     the JSPB instance for transitional soy proto support:
- *     http://goto/soy-param-migration
- * @param {!proto.org.dash.platform.dapi.v0.SecurityLevelMap} msg The msg instance to transform.
- * @return {!Object}
- * @suppress {unusedLocalVariables} f is only used for nested messages
+ * optional Proof proof = 2;
+ * @return {?proto.org.dash.platform.dapi.v0.Proof}
  */
-proto.org.dash.platform.dapi.v0.SecurityLevelMap.toObject = function(includeInstance, msg) {
-  var f, obj = {
-    securityLevelMapMap: (f = msg.getSecurityLevelMapMap()) ? f.toObject(includeInstance, undefined) : []
-  };
-
-  if (includeInstance) {
-    obj.$jspbMessageInstance = msg;
-  }
-  return obj;
+proto.org.dash.platform.dapi.v0.GetIdentityContractNonceResponse.prototype.getProof = function() {
+  return /** @type{?proto.org.dash.platform.dapi.v0.Proof} */ (
+    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.Proof, 2));
 };
-}
 
 
 /**
- * Deserializes binary data (in protobuf wire format).
- * @param {jspb.ByteSource} bytes The bytes to deserialize.
- * @return {!proto.org.dash.platform.dapi.v0.SecurityLevelMap}
- */
-proto.org.dash.platform.dapi.v0.SecurityLevelMap.deserializeBinary = function(bytes) {
-  var reader = new jspb.BinaryReader(bytes);
-  var msg = new proto.org.dash.platform.dapi.v0.SecurityLevelMap;
-  return proto.org.dash.platform.dapi.v0.SecurityLevelMap.deserializeBinaryFromReader(msg, reader);
+ * @param {?proto.org.dash.platform.dapi.v0.Proof|undefined} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentityContractNonceResponse} returns this
+*/
+proto.org.dash.platform.dapi.v0.GetIdentityContractNonceResponse.prototype.setProof = function(value) {
+  return jspb.Message.setOneofWrapperField(this, 2, proto.org.dash.platform.dapi.v0.GetIdentityContractNonceResponse.oneofGroups_[0], value);
 };
 
 
 /**
- * Deserializes binary data (in protobuf wire format) from the
- * given reader into the given message object.
- * @param {!proto.org.dash.platform.dapi.v0.SecurityLevelMap} msg The message object to deserialize into.
- * @param {!jspb.BinaryReader} reader The BinaryReader to use.
- * @return {!proto.org.dash.platform.dapi.v0.SecurityLevelMap}
+ * Clears the message field making it undefined.
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentityContractNonceResponse} returns this
  */
-proto.org.dash.platform.dapi.v0.SecurityLevelMap.deserializeBinaryFromReader = function(msg, reader) {
-  while (reader.nextField()) {
-    if (reader.isEndGroup()) {
-      break;
-    }
-    var field = reader.getFieldNumber();
-    switch (field) {
-    case 1:
-      var value = msg.getSecurityLevelMapMap();
-      reader.readMessage(value, function(message, reader) {
-        jspb.Map.deserializeBinary(message, reader, jspb.BinaryReader.prototype.readUint32, jspb.BinaryReader.prototype.readEnum, null, 0, 0);
-         });
-      break;
-    default:
-      reader.skipField();
-      break;
-    }
-  }
-  return msg;
+proto.org.dash.platform.dapi.v0.GetIdentityContractNonceResponse.prototype.clearProof = function() {
+  return this.setProof(undefined);
 };
 
 
 /**
- * Serializes the message to binary data (in protobuf wire format).
- * @return {!Uint8Array}
+ * Returns whether this field is set.
+ * @return {boolean}
  */
-proto.org.dash.platform.dapi.v0.SecurityLevelMap.prototype.serializeBinary = function() {
-  var writer = new jspb.BinaryWriter();
-  proto.org.dash.platform.dapi.v0.SecurityLevelMap.serializeBinaryToWriter(this, writer);
-  return writer.getResultBuffer();
+proto.org.dash.platform.dapi.v0.GetIdentityContractNonceResponse.prototype.hasProof = function() {
+  return jspb.Message.getField(this, 2) != null;
 };
 
 
 /**
- * Serializes the given message to binary data (in protobuf wire
- * format), writing to the given BinaryWriter.
- * @param {!proto.org.dash.platform.dapi.v0.SecurityLevelMap} message
- * @param {!jspb.BinaryWriter} writer
- * @suppress {unusedLocalVariables} f is only used for nested messages
+ * optional ResponseMetadata metadata = 3;
+ * @return {?proto.org.dash.platform.dapi.v0.ResponseMetadata}
  */
-proto.org.dash.platform.dapi.v0.SecurityLevelMap.serializeBinaryToWriter = function(message, writer) {
-  var f = undefined;
-  f = message.getSecurityLevelMapMap(true);
-  if (f && f.getLength() > 0) {
-    f.serializeBinary(1, writer, jspb.BinaryWriter.prototype.writeUint32, jspb.BinaryWriter.prototype.writeEnum);
-  }
+proto.org.dash.platform.dapi.v0.GetIdentityContractNonceResponse.prototype.getMetadata = function() {
+  return /** @type{?proto.org.dash.platform.dapi.v0.ResponseMetadata} */ (
+    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.ResponseMetadata, 3));
 };
 
 
 /**
- * @enum {number}
+ * @param {?proto.org.dash.platform.dapi.v0.ResponseMetadata|undefined} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentityContractNonceResponse} returns this
+*/
+proto.org.dash.platform.dapi.v0.GetIdentityContractNonceResponse.prototype.setMetadata = function(value) {
+  return jspb.Message.setWrapperField(this, 3, value);
+};
+
+
+/**
+ * Clears the message field making it undefined.
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentityContractNonceResponse} returns this
  */
-proto.org.dash.platform.dapi.v0.SecurityLevelMap.KeyKindRequestType = {
-  CURRENT_KEY_OF_KIND_REQUEST: 0,
-  ALL_KEYS_OF_KIND_REQUEST: 1
+proto.org.dash.platform.dapi.v0.GetIdentityContractNonceResponse.prototype.clearMetadata = function() {
+  return this.setMetadata(undefined);
 };
 
+
 /**
- * map<uint32, KeyKindRequestType> security_level_map = 1;
- * @param {boolean=} opt_noLazyCreate Do not create the map if
- * empty, instead returning `undefined`
- * @return {!jspb.Map<number,!proto.org.dash.platform.dapi.v0.SecurityLevelMap.KeyKindRequestType>}
+ * Returns whether this field is set.
+ * @return {boolean}
  */
-proto.org.dash.platform.dapi.v0.SecurityLevelMap.prototype.getSecurityLevelMapMap = function(opt_noLazyCreate) {
-  return /** @type {!jspb.Map<number,!proto.org.dash.platform.dapi.v0.SecurityLevelMap.KeyKindRequestType>} */ (
-      jspb.Message.getMapField(this, 1, opt_noLazyCreate,
-      null));
+proto.org.dash.platform.dapi.v0.GetIdentityContractNonceResponse.prototype.hasMetadata = function() {
+  return jspb.Message.getField(this, 3) != null;
 };
 
 
+
 /**
- * Clears values from the map. The map will be non-null.
- * @return {!proto.org.dash.platform.dapi.v0.SecurityLevelMap} returns this
+ * Oneof group definitions for this message. Each group defines the field
+ * numbers belonging to that group. When of these fields' value is set, all
+ * other fields in the group are cleared. During deserialization, if multiple
+ * fields are encountered for a group, only the last value seen will be kept.
+ * @private {!Array<!Array<number>>}
+ * @const
  */
-proto.org.dash.platform.dapi.v0.SecurityLevelMap.prototype.clearSecurityLevelMapMap = function() {
-  this.getSecurityLevelMapMap().clear();
-  return this;};
+proto.org.dash.platform.dapi.v0.KeyRequestType.oneofGroups_ = [[1,2,3]];
 
+/**
+ * @enum {number}
+ */
+proto.org.dash.platform.dapi.v0.KeyRequestType.RequestCase = {
+  REQUEST_NOT_SET: 0,
+  ALL_KEYS: 1,
+  SPECIFIC_KEYS: 2,
+  SEARCH_KEY: 3
+};
 
+/**
+ * @return {proto.org.dash.platform.dapi.v0.KeyRequestType.RequestCase}
+ */
+proto.org.dash.platform.dapi.v0.KeyRequestType.prototype.getRequestCase = function() {
+  return /** @type {proto.org.dash.platform.dapi.v0.KeyRequestType.RequestCase} */(jspb.Message.computeOneofCase(this, proto.org.dash.platform.dapi.v0.KeyRequestType.oneofGroups_[0]));
+};
 
 
 
@@ -5399,8 +5813,8 @@ if (jspb.Message.GENERATE_TO_OBJECT) {
  *     http://goto/soy-param-migration
  * @return {!Object}
  */
-proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest.prototype.toObject = function(opt_includeInstance) {
-  return proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest.toObject(opt_includeInstance, this);
+proto.org.dash.platform.dapi.v0.KeyRequestType.prototype.toObject = function(opt_includeInstance) {
+  return proto.org.dash.platform.dapi.v0.KeyRequestType.toObject(opt_includeInstance, this);
 };
 
 
@@ -5409,17 +5823,15 @@ proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest.prototype.toObject = func
  * @param {boolean|undefined} includeInstance Deprecated. Whether to include
  *     the JSPB instance for transitional soy proto support:
  *     http://goto/soy-param-migration
- * @param {!proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest} msg The msg instance to transform.
+ * @param {!proto.org.dash.platform.dapi.v0.KeyRequestType} msg The msg instance to transform.
  * @return {!Object}
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest.toObject = function(includeInstance, msg) {
+proto.org.dash.platform.dapi.v0.KeyRequestType.toObject = function(includeInstance, msg) {
   var f, obj = {
-    identityId: msg.getIdentityId_asB64(),
-    requestType: (f = msg.getRequestType()) && proto.org.dash.platform.dapi.v0.KeyRequestType.toObject(includeInstance, f),
-    limit: (f = msg.getLimit()) && google_protobuf_wrappers_pb.UInt32Value.toObject(includeInstance, f),
-    offset: (f = msg.getOffset()) && google_protobuf_wrappers_pb.UInt32Value.toObject(includeInstance, f),
-    prove: jspb.Message.getBooleanFieldWithDefault(msg, 5, false)
+    allKeys: (f = msg.getAllKeys()) && proto.org.dash.platform.dapi.v0.AllKeys.toObject(includeInstance, f),
+    specificKeys: (f = msg.getSpecificKeys()) && proto.org.dash.platform.dapi.v0.SpecificKeys.toObject(includeInstance, f),
+    searchKey: (f = msg.getSearchKey()) && proto.org.dash.platform.dapi.v0.SearchKey.toObject(includeInstance, f)
   };
 
   if (includeInstance) {
@@ -5433,23 +5845,23 @@ proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest.toObject = function(inclu
 /**
  * Deserializes binary data (in protobuf wire format).
  * @param {jspb.ByteSource} bytes The bytes to deserialize.
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest}
+ * @return {!proto.org.dash.platform.dapi.v0.KeyRequestType}
  */
-proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest.deserializeBinary = function(bytes) {
+proto.org.dash.platform.dapi.v0.KeyRequestType.deserializeBinary = function(bytes) {
   var reader = new jspb.BinaryReader(bytes);
-  var msg = new proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest;
-  return proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest.deserializeBinaryFromReader(msg, reader);
+  var msg = new proto.org.dash.platform.dapi.v0.KeyRequestType;
+  return proto.org.dash.platform.dapi.v0.KeyRequestType.deserializeBinaryFromReader(msg, reader);
 };
 
 
 /**
  * Deserializes binary data (in protobuf wire format) from the
  * given reader into the given message object.
- * @param {!proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest} msg The message object to deserialize into.
+ * @param {!proto.org.dash.platform.dapi.v0.KeyRequestType} msg The message object to deserialize into.
  * @param {!jspb.BinaryReader} reader The BinaryReader to use.
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest}
+ * @return {!proto.org.dash.platform.dapi.v0.KeyRequestType}
  */
-proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest.deserializeBinaryFromReader = function(msg, reader) {
+proto.org.dash.platform.dapi.v0.KeyRequestType.deserializeBinaryFromReader = function(msg, reader) {
   while (reader.nextField()) {
     if (reader.isEndGroup()) {
       break;
@@ -5457,27 +5869,19 @@ proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest.deserializeBinaryFromRead
     var field = reader.getFieldNumber();
     switch (field) {
     case 1:
-      var value = /** @type {!Uint8Array} */ (reader.readBytes());
-      msg.setIdentityId(value);
+      var value = new proto.org.dash.platform.dapi.v0.AllKeys;
+      reader.readMessage(value,proto.org.dash.platform.dapi.v0.AllKeys.deserializeBinaryFromReader);
+      msg.setAllKeys(value);
       break;
     case 2:
-      var value = new proto.org.dash.platform.dapi.v0.KeyRequestType;
-      reader.readMessage(value,proto.org.dash.platform.dapi.v0.KeyRequestType.deserializeBinaryFromReader);
-      msg.setRequestType(value);
+      var value = new proto.org.dash.platform.dapi.v0.SpecificKeys;
+      reader.readMessage(value,proto.org.dash.platform.dapi.v0.SpecificKeys.deserializeBinaryFromReader);
+      msg.setSpecificKeys(value);
       break;
     case 3:
-      var value = new google_protobuf_wrappers_pb.UInt32Value;
-      reader.readMessage(value,google_protobuf_wrappers_pb.UInt32Value.deserializeBinaryFromReader);
-      msg.setLimit(value);
-      break;
-    case 4:
-      var value = new google_protobuf_wrappers_pb.UInt32Value;
-      reader.readMessage(value,google_protobuf_wrappers_pb.UInt32Value.deserializeBinaryFromReader);
-      msg.setOffset(value);
-      break;
-    case 5:
-      var value = /** @type {boolean} */ (reader.readBool());
-      msg.setProve(value);
+      var value = new proto.org.dash.platform.dapi.v0.SearchKey;
+      reader.readMessage(value,proto.org.dash.platform.dapi.v0.SearchKey.deserializeBinaryFromReader);
+      msg.setSearchKey(value);
       break;
     default:
       reader.skipField();
@@ -5492,9 +5896,9 @@ proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest.deserializeBinaryFromRead
  * Serializes the message to binary data (in protobuf wire format).
  * @return {!Uint8Array}
  */
-proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest.prototype.serializeBinary = function() {
+proto.org.dash.platform.dapi.v0.KeyRequestType.prototype.serializeBinary = function() {
   var writer = new jspb.BinaryWriter();
-  proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest.serializeBinaryToWriter(this, writer);
+  proto.org.dash.platform.dapi.v0.KeyRequestType.serializeBinaryToWriter(this, writer);
   return writer.getResultBuffer();
 };
 
@@ -5502,157 +5906,101 @@ proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest.prototype.serializeBinary
 /**
  * Serializes the given message to binary data (in protobuf wire
  * format), writing to the given BinaryWriter.
- * @param {!proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest} message
+ * @param {!proto.org.dash.platform.dapi.v0.KeyRequestType} message
  * @param {!jspb.BinaryWriter} writer
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest.serializeBinaryToWriter = function(message, writer) {
+proto.org.dash.platform.dapi.v0.KeyRequestType.serializeBinaryToWriter = function(message, writer) {
   var f = undefined;
-  f = message.getIdentityId_asU8();
-  if (f.length > 0) {
-    writer.writeBytes(
-      1,
-      f
-    );
-  }
-  f = message.getRequestType();
+  f = message.getAllKeys();
   if (f != null) {
     writer.writeMessage(
-      2,
+      1,
       f,
-      proto.org.dash.platform.dapi.v0.KeyRequestType.serializeBinaryToWriter
+      proto.org.dash.platform.dapi.v0.AllKeys.serializeBinaryToWriter
     );
   }
-  f = message.getLimit();
+  f = message.getSpecificKeys();
   if (f != null) {
     writer.writeMessage(
-      3,
+      2,
       f,
-      google_protobuf_wrappers_pb.UInt32Value.serializeBinaryToWriter
+      proto.org.dash.platform.dapi.v0.SpecificKeys.serializeBinaryToWriter
     );
   }
-  f = message.getOffset();
+  f = message.getSearchKey();
   if (f != null) {
     writer.writeMessage(
-      4,
+      3,
       f,
-      google_protobuf_wrappers_pb.UInt32Value.serializeBinaryToWriter
-    );
-  }
-  f = message.getProve();
-  if (f) {
-    writer.writeBool(
-      5,
-      f
+      proto.org.dash.platform.dapi.v0.SearchKey.serializeBinaryToWriter
     );
   }
 };
 
 
 /**
- * optional bytes identity_id = 1;
- * @return {string}
+ * optional AllKeys all_keys = 1;
+ * @return {?proto.org.dash.platform.dapi.v0.AllKeys}
  */
-proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest.prototype.getIdentityId = function() {
-  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 1, ""));
+proto.org.dash.platform.dapi.v0.KeyRequestType.prototype.getAllKeys = function() {
+  return /** @type{?proto.org.dash.platform.dapi.v0.AllKeys} */ (
+    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.AllKeys, 1));
 };
 
 
 /**
- * optional bytes identity_id = 1;
- * This is a type-conversion wrapper around `getIdentityId()`
- * @return {string}
- */
-proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest.prototype.getIdentityId_asB64 = function() {
-  return /** @type {string} */ (jspb.Message.bytesAsB64(
-      this.getIdentityId()));
+ * @param {?proto.org.dash.platform.dapi.v0.AllKeys|undefined} value
+ * @return {!proto.org.dash.platform.dapi.v0.KeyRequestType} returns this
+*/
+proto.org.dash.platform.dapi.v0.KeyRequestType.prototype.setAllKeys = function(value) {
+  return jspb.Message.setOneofWrapperField(this, 1, proto.org.dash.platform.dapi.v0.KeyRequestType.oneofGroups_[0], value);
 };
 
 
 /**
- * optional bytes identity_id = 1;
- * Note that Uint8Array is not supported on all browsers.
- * @see http://caniuse.com/Uint8Array
- * This is a type-conversion wrapper around `getIdentityId()`
- * @return {!Uint8Array}
+ * Clears the message field making it undefined.
+ * @return {!proto.org.dash.platform.dapi.v0.KeyRequestType} returns this
  */
-proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest.prototype.getIdentityId_asU8 = function() {
-  return /** @type {!Uint8Array} */ (jspb.Message.bytesAsU8(
-      this.getIdentityId()));
+proto.org.dash.platform.dapi.v0.KeyRequestType.prototype.clearAllKeys = function() {
+  return this.setAllKeys(undefined);
 };
 
 
 /**
- * @param {!(string|Uint8Array)} value
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest} returns this
+ * Returns whether this field is set.
+ * @return {boolean}
  */
-proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest.prototype.setIdentityId = function(value) {
-  return jspb.Message.setProto3BytesField(this, 1, value);
+proto.org.dash.platform.dapi.v0.KeyRequestType.prototype.hasAllKeys = function() {
+  return jspb.Message.getField(this, 1) != null;
 };
 
 
 /**
- * optional KeyRequestType request_type = 2;
- * @return {?proto.org.dash.platform.dapi.v0.KeyRequestType}
+ * optional SpecificKeys specific_keys = 2;
+ * @return {?proto.org.dash.platform.dapi.v0.SpecificKeys}
  */
-proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest.prototype.getRequestType = function() {
-  return /** @type{?proto.org.dash.platform.dapi.v0.KeyRequestType} */ (
-    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.KeyRequestType, 2));
-};
-
-
-/**
- * @param {?proto.org.dash.platform.dapi.v0.KeyRequestType|undefined} value
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest} returns this
-*/
-proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest.prototype.setRequestType = function(value) {
-  return jspb.Message.setWrapperField(this, 2, value);
-};
-
-
-/**
- * Clears the message field making it undefined.
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest} returns this
- */
-proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest.prototype.clearRequestType = function() {
-  return this.setRequestType(undefined);
-};
-
-
-/**
- * Returns whether this field is set.
- * @return {boolean}
- */
-proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest.prototype.hasRequestType = function() {
-  return jspb.Message.getField(this, 2) != null;
-};
-
-
-/**
- * optional google.protobuf.UInt32Value limit = 3;
- * @return {?proto.google.protobuf.UInt32Value}
- */
-proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest.prototype.getLimit = function() {
-  return /** @type{?proto.google.protobuf.UInt32Value} */ (
-    jspb.Message.getWrapperField(this, google_protobuf_wrappers_pb.UInt32Value, 3));
+proto.org.dash.platform.dapi.v0.KeyRequestType.prototype.getSpecificKeys = function() {
+  return /** @type{?proto.org.dash.platform.dapi.v0.SpecificKeys} */ (
+    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.SpecificKeys, 2));
 };
 
 
 /**
- * @param {?proto.google.protobuf.UInt32Value|undefined} value
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest} returns this
+ * @param {?proto.org.dash.platform.dapi.v0.SpecificKeys|undefined} value
+ * @return {!proto.org.dash.platform.dapi.v0.KeyRequestType} returns this
 */
-proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest.prototype.setLimit = function(value) {
-  return jspb.Message.setWrapperField(this, 3, value);
+proto.org.dash.platform.dapi.v0.KeyRequestType.prototype.setSpecificKeys = function(value) {
+  return jspb.Message.setOneofWrapperField(this, 2, proto.org.dash.platform.dapi.v0.KeyRequestType.oneofGroups_[0], value);
 };
 
 
 /**
  * Clears the message field making it undefined.
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest} returns this
+ * @return {!proto.org.dash.platform.dapi.v0.KeyRequestType} returns this
  */
-proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest.prototype.clearLimit = function() {
-  return this.setLimit(undefined);
+proto.org.dash.platform.dapi.v0.KeyRequestType.prototype.clearSpecificKeys = function() {
+  return this.setSpecificKeys(undefined);
 };
 
 
@@ -5660,36 +6008,36 @@ proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest.prototype.clearLimit = fu
  * Returns whether this field is set.
  * @return {boolean}
  */
-proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest.prototype.hasLimit = function() {
-  return jspb.Message.getField(this, 3) != null;
+proto.org.dash.platform.dapi.v0.KeyRequestType.prototype.hasSpecificKeys = function() {
+  return jspb.Message.getField(this, 2) != null;
 };
 
 
 /**
- * optional google.protobuf.UInt32Value offset = 4;
- * @return {?proto.google.protobuf.UInt32Value}
+ * optional SearchKey search_key = 3;
+ * @return {?proto.org.dash.platform.dapi.v0.SearchKey}
  */
-proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest.prototype.getOffset = function() {
-  return /** @type{?proto.google.protobuf.UInt32Value} */ (
-    jspb.Message.getWrapperField(this, google_protobuf_wrappers_pb.UInt32Value, 4));
+proto.org.dash.platform.dapi.v0.KeyRequestType.prototype.getSearchKey = function() {
+  return /** @type{?proto.org.dash.platform.dapi.v0.SearchKey} */ (
+    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.SearchKey, 3));
 };
 
 
 /**
- * @param {?proto.google.protobuf.UInt32Value|undefined} value
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest} returns this
+ * @param {?proto.org.dash.platform.dapi.v0.SearchKey|undefined} value
+ * @return {!proto.org.dash.platform.dapi.v0.KeyRequestType} returns this
 */
-proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest.prototype.setOffset = function(value) {
-  return jspb.Message.setWrapperField(this, 4, value);
+proto.org.dash.platform.dapi.v0.KeyRequestType.prototype.setSearchKey = function(value) {
+  return jspb.Message.setOneofWrapperField(this, 3, proto.org.dash.platform.dapi.v0.KeyRequestType.oneofGroups_[0], value);
 };
 
 
 /**
  * Clears the message field making it undefined.
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest} returns this
+ * @return {!proto.org.dash.platform.dapi.v0.KeyRequestType} returns this
  */
-proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest.prototype.clearOffset = function() {
-  return this.setOffset(undefined);
+proto.org.dash.platform.dapi.v0.KeyRequestType.prototype.clearSearchKey = function() {
+  return this.setSearchKey(undefined);
 };
 
 
@@ -5697,56 +6045,12 @@ proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest.prototype.clearOffset = f
  * Returns whether this field is set.
  * @return {boolean}
  */
-proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest.prototype.hasOffset = function() {
-  return jspb.Message.getField(this, 4) != null;
-};
-
-
-/**
- * optional bool prove = 5;
- * @return {boolean}
- */
-proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest.prototype.getProve = function() {
-  return /** @type {boolean} */ (jspb.Message.getBooleanFieldWithDefault(this, 5, false));
-};
-
-
-/**
- * @param {boolean} value
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest} returns this
- */
-proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest.prototype.setProve = function(value) {
-  return jspb.Message.setProto3BooleanField(this, 5, value);
+proto.org.dash.platform.dapi.v0.KeyRequestType.prototype.hasSearchKey = function() {
+  return jspb.Message.getField(this, 3) != null;
 };
 
 
 
-/**
- * Oneof group definitions for this message. Each group defines the field
- * numbers belonging to that group. When of these fields' value is set, all
- * other fields in the group are cleared. During deserialization, if multiple
- * fields are encountered for a group, only the last value seen will be kept.
- * @private {!Array<!Array<number>>}
- * @const
- */
-proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.oneofGroups_ = [[1,2]];
-
-/**
- * @enum {number}
- */
-proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.ResultCase = {
-  RESULT_NOT_SET: 0,
-  KEYS: 1,
-  PROOF: 2
-};
-
-/**
- * @return {proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.ResultCase}
- */
-proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.prototype.getResultCase = function() {
-  return /** @type {proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.ResultCase} */(jspb.Message.computeOneofCase(this, proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.oneofGroups_[0]));
-};
-
 
 
 if (jspb.Message.GENERATE_TO_OBJECT) {
@@ -5762,8 +6066,8 @@ if (jspb.Message.GENERATE_TO_OBJECT) {
  *     http://goto/soy-param-migration
  * @return {!Object}
  */
-proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.prototype.toObject = function(opt_includeInstance) {
-  return proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.toObject(opt_includeInstance, this);
+proto.org.dash.platform.dapi.v0.AllKeys.prototype.toObject = function(opt_includeInstance) {
+  return proto.org.dash.platform.dapi.v0.AllKeys.toObject(opt_includeInstance, this);
 };
 
 
@@ -5772,15 +6076,13 @@ proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.prototype.toObject = fun
  * @param {boolean|undefined} includeInstance Deprecated. Whether to include
  *     the JSPB instance for transitional soy proto support:
  *     http://goto/soy-param-migration
- * @param {!proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse} msg The msg instance to transform.
+ * @param {!proto.org.dash.platform.dapi.v0.AllKeys} msg The msg instance to transform.
  * @return {!Object}
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.toObject = function(includeInstance, msg) {
+proto.org.dash.platform.dapi.v0.AllKeys.toObject = function(includeInstance, msg) {
   var f, obj = {
-    keys: (f = msg.getKeys()) && proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.Keys.toObject(includeInstance, f),
-    proof: (f = msg.getProof()) && proto.org.dash.platform.dapi.v0.Proof.toObject(includeInstance, f),
-    metadata: (f = msg.getMetadata()) && proto.org.dash.platform.dapi.v0.ResponseMetadata.toObject(includeInstance, f)
+
   };
 
   if (includeInstance) {
@@ -5794,44 +6096,29 @@ proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.toObject = function(incl
 /**
  * Deserializes binary data (in protobuf wire format).
  * @param {jspb.ByteSource} bytes The bytes to deserialize.
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse}
+ * @return {!proto.org.dash.platform.dapi.v0.AllKeys}
  */
-proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.deserializeBinary = function(bytes) {
+proto.org.dash.platform.dapi.v0.AllKeys.deserializeBinary = function(bytes) {
   var reader = new jspb.BinaryReader(bytes);
-  var msg = new proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse;
-  return proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.deserializeBinaryFromReader(msg, reader);
+  var msg = new proto.org.dash.platform.dapi.v0.AllKeys;
+  return proto.org.dash.platform.dapi.v0.AllKeys.deserializeBinaryFromReader(msg, reader);
 };
 
 
 /**
  * Deserializes binary data (in protobuf wire format) from the
  * given reader into the given message object.
- * @param {!proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse} msg The message object to deserialize into.
+ * @param {!proto.org.dash.platform.dapi.v0.AllKeys} msg The message object to deserialize into.
  * @param {!jspb.BinaryReader} reader The BinaryReader to use.
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse}
+ * @return {!proto.org.dash.platform.dapi.v0.AllKeys}
  */
-proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.deserializeBinaryFromReader = function(msg, reader) {
+proto.org.dash.platform.dapi.v0.AllKeys.deserializeBinaryFromReader = function(msg, reader) {
   while (reader.nextField()) {
     if (reader.isEndGroup()) {
       break;
     }
     var field = reader.getFieldNumber();
     switch (field) {
-    case 1:
-      var value = new proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.Keys;
-      reader.readMessage(value,proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.Keys.deserializeBinaryFromReader);
-      msg.setKeys(value);
-      break;
-    case 2:
-      var value = new proto.org.dash.platform.dapi.v0.Proof;
-      reader.readMessage(value,proto.org.dash.platform.dapi.v0.Proof.deserializeBinaryFromReader);
-      msg.setProof(value);
-      break;
-    case 3:
-      var value = new proto.org.dash.platform.dapi.v0.ResponseMetadata;
-      reader.readMessage(value,proto.org.dash.platform.dapi.v0.ResponseMetadata.deserializeBinaryFromReader);
-      msg.setMetadata(value);
-      break;
     default:
       reader.skipField();
       break;
@@ -5845,9 +6132,9 @@ proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.deserializeBinaryFromRea
  * Serializes the message to binary data (in protobuf wire format).
  * @return {!Uint8Array}
  */
-proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.prototype.serializeBinary = function() {
+proto.org.dash.platform.dapi.v0.AllKeys.prototype.serializeBinary = function() {
   var writer = new jspb.BinaryWriter();
-  proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.serializeBinaryToWriter(this, writer);
+  proto.org.dash.platform.dapi.v0.AllKeys.serializeBinaryToWriter(this, writer);
   return writer.getResultBuffer();
 };
 
@@ -5855,36 +6142,12 @@ proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.prototype.serializeBinar
 /**
  * Serializes the given message to binary data (in protobuf wire
  * format), writing to the given BinaryWriter.
- * @param {!proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse} message
+ * @param {!proto.org.dash.platform.dapi.v0.AllKeys} message
  * @param {!jspb.BinaryWriter} writer
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.serializeBinaryToWriter = function(message, writer) {
+proto.org.dash.platform.dapi.v0.AllKeys.serializeBinaryToWriter = function(message, writer) {
   var f = undefined;
-  f = message.getKeys();
-  if (f != null) {
-    writer.writeMessage(
-      1,
-      f,
-      proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.Keys.serializeBinaryToWriter
-    );
-  }
-  f = message.getProof();
-  if (f != null) {
-    writer.writeMessage(
-      2,
-      f,
-      proto.org.dash.platform.dapi.v0.Proof.serializeBinaryToWriter
-    );
-  }
-  f = message.getMetadata();
-  if (f != null) {
-    writer.writeMessage(
-      3,
-      f,
-      proto.org.dash.platform.dapi.v0.ResponseMetadata.serializeBinaryToWriter
-    );
-  }
 };
 
 
@@ -5894,7 +6157,7 @@ proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.serializeBinaryToWriter
  * @private {!Array<number>}
  * @const
  */
-proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.Keys.repeatedFields_ = [1];
+proto.org.dash.platform.dapi.v0.SpecificKeys.repeatedFields_ = [1];
 
 
 
@@ -5911,8 +6174,8 @@ if (jspb.Message.GENERATE_TO_OBJECT) {
  *     http://goto/soy-param-migration
  * @return {!Object}
  */
-proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.Keys.prototype.toObject = function(opt_includeInstance) {
-  return proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.Keys.toObject(opt_includeInstance, this);
+proto.org.dash.platform.dapi.v0.SpecificKeys.prototype.toObject = function(opt_includeInstance) {
+  return proto.org.dash.platform.dapi.v0.SpecificKeys.toObject(opt_includeInstance, this);
 };
 
 
@@ -5921,13 +6184,13 @@ proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.Keys.prototype.toObject
  * @param {boolean|undefined} includeInstance Deprecated. Whether to include
  *     the JSPB instance for transitional soy proto support:
  *     http://goto/soy-param-migration
- * @param {!proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.Keys} msg The msg instance to transform.
+ * @param {!proto.org.dash.platform.dapi.v0.SpecificKeys} msg The msg instance to transform.
  * @return {!Object}
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.Keys.toObject = function(includeInstance, msg) {
+proto.org.dash.platform.dapi.v0.SpecificKeys.toObject = function(includeInstance, msg) {
   var f, obj = {
-    keysBytesList: msg.getKeysBytesList_asB64()
+    keyIdsList: (f = jspb.Message.getRepeatedField(msg, 1)) == null ? undefined : f
   };
 
   if (includeInstance) {
@@ -5941,23 +6204,23 @@ proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.Keys.toObject = function
 /**
  * Deserializes binary data (in protobuf wire format).
  * @param {jspb.ByteSource} bytes The bytes to deserialize.
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.Keys}
+ * @return {!proto.org.dash.platform.dapi.v0.SpecificKeys}
  */
-proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.Keys.deserializeBinary = function(bytes) {
+proto.org.dash.platform.dapi.v0.SpecificKeys.deserializeBinary = function(bytes) {
   var reader = new jspb.BinaryReader(bytes);
-  var msg = new proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.Keys;
-  return proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.Keys.deserializeBinaryFromReader(msg, reader);
+  var msg = new proto.org.dash.platform.dapi.v0.SpecificKeys;
+  return proto.org.dash.platform.dapi.v0.SpecificKeys.deserializeBinaryFromReader(msg, reader);
 };
 
 
 /**
  * Deserializes binary data (in protobuf wire format) from the
  * given reader into the given message object.
- * @param {!proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.Keys} msg The message object to deserialize into.
+ * @param {!proto.org.dash.platform.dapi.v0.SpecificKeys} msg The message object to deserialize into.
  * @param {!jspb.BinaryReader} reader The BinaryReader to use.
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.Keys}
+ * @return {!proto.org.dash.platform.dapi.v0.SpecificKeys}
  */
-proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.Keys.deserializeBinaryFromReader = function(msg, reader) {
+proto.org.dash.platform.dapi.v0.SpecificKeys.deserializeBinaryFromReader = function(msg, reader) {
   while (reader.nextField()) {
     if (reader.isEndGroup()) {
       break;
@@ -5965,8 +6228,10 @@ proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.Keys.deserializeBinaryFr
     var field = reader.getFieldNumber();
     switch (field) {
     case 1:
-      var value = /** @type {!Uint8Array} */ (reader.readBytes());
-      msg.addKeysBytes(value);
+      var values = /** @type {!Array<number>} */ (reader.isDelimited() ? reader.readPackedUint32() : [reader.readUint32()]);
+      for (var i = 0; i < values.length; i++) {
+        msg.addKeyIds(values[i]);
+      }
       break;
     default:
       reader.skipField();
@@ -5981,9 +6246,9 @@ proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.Keys.deserializeBinaryFr
  * Serializes the message to binary data (in protobuf wire format).
  * @return {!Uint8Array}
  */
-proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.Keys.prototype.serializeBinary = function() {
+proto.org.dash.platform.dapi.v0.SpecificKeys.prototype.serializeBinary = function() {
   var writer = new jspb.BinaryWriter();
-  proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.Keys.serializeBinaryToWriter(this, writer);
+  proto.org.dash.platform.dapi.v0.SpecificKeys.serializeBinaryToWriter(this, writer);
   return writer.getResultBuffer();
 };
 
@@ -5991,15 +6256,15 @@ proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.Keys.prototype.serialize
 /**
  * Serializes the given message to binary data (in protobuf wire
  * format), writing to the given BinaryWriter.
- * @param {!proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.Keys} message
+ * @param {!proto.org.dash.platform.dapi.v0.SpecificKeys} message
  * @param {!jspb.BinaryWriter} writer
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.Keys.serializeBinaryToWriter = function(message, writer) {
+proto.org.dash.platform.dapi.v0.SpecificKeys.serializeBinaryToWriter = function(message, writer) {
   var f = undefined;
-  f = message.getKeysBytesList_asU8();
+  f = message.getKeyIdsList();
   if (f.length > 0) {
-    writer.writeRepeatedBytes(
+    writer.writePackedUint32(
       1,
       f
     );
@@ -6008,184 +6273,175 @@ proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.Keys.serializeBinaryToWr
 
 
 /**
- * repeated bytes keys_bytes = 1;
- * @return {!Array<string>}
+ * repeated uint32 key_ids = 1;
+ * @return {!Array<number>}
  */
-proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.Keys.prototype.getKeysBytesList = function() {
-  return /** @type {!Array<string>} */ (jspb.Message.getRepeatedField(this, 1));
+proto.org.dash.platform.dapi.v0.SpecificKeys.prototype.getKeyIdsList = function() {
+  return /** @type {!Array<number>} */ (jspb.Message.getRepeatedField(this, 1));
 };
 
 
 /**
- * repeated bytes keys_bytes = 1;
- * This is a type-conversion wrapper around `getKeysBytesList()`
- * @return {!Array<string>}
- */
-proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.Keys.prototype.getKeysBytesList_asB64 = function() {
-  return /** @type {!Array<string>} */ (jspb.Message.bytesListAsB64(
-      this.getKeysBytesList()));
-};
-
-
-/**
- * repeated bytes keys_bytes = 1;
- * Note that Uint8Array is not supported on all browsers.
- * @see http://caniuse.com/Uint8Array
- * This is a type-conversion wrapper around `getKeysBytesList()`
- * @return {!Array<!Uint8Array>}
- */
-proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.Keys.prototype.getKeysBytesList_asU8 = function() {
-  return /** @type {!Array<!Uint8Array>} */ (jspb.Message.bytesListAsU8(
-      this.getKeysBytesList()));
-};
-
-
-/**
- * @param {!(Array<!Uint8Array>|Array<string>)} value
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.Keys} returns this
+ * @param {!Array<number>} value
+ * @return {!proto.org.dash.platform.dapi.v0.SpecificKeys} returns this
  */
-proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.Keys.prototype.setKeysBytesList = function(value) {
+proto.org.dash.platform.dapi.v0.SpecificKeys.prototype.setKeyIdsList = function(value) {
   return jspb.Message.setField(this, 1, value || []);
 };
 
 
 /**
- * @param {!(string|Uint8Array)} value
+ * @param {number} value
  * @param {number=} opt_index
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.Keys} returns this
+ * @return {!proto.org.dash.platform.dapi.v0.SpecificKeys} returns this
  */
-proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.Keys.prototype.addKeysBytes = function(value, opt_index) {
+proto.org.dash.platform.dapi.v0.SpecificKeys.prototype.addKeyIds = function(value, opt_index) {
   return jspb.Message.addToRepeatedField(this, 1, value, opt_index);
 };
 
 
 /**
  * Clears the list making it empty but non-null.
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.Keys} returns this
- */
-proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.Keys.prototype.clearKeysBytesList = function() {
-  return this.setKeysBytesList([]);
-};
-
-
-/**
- * optional Keys keys = 1;
- * @return {?proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.Keys}
+ * @return {!proto.org.dash.platform.dapi.v0.SpecificKeys} returns this
  */
-proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.prototype.getKeys = function() {
-  return /** @type{?proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.Keys} */ (
-    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.Keys, 1));
+proto.org.dash.platform.dapi.v0.SpecificKeys.prototype.clearKeyIdsList = function() {
+  return this.setKeyIdsList([]);
 };
 
 
-/**
- * @param {?proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.Keys|undefined} value
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse} returns this
-*/
-proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.prototype.setKeys = function(value) {
-  return jspb.Message.setOneofWrapperField(this, 1, proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.oneofGroups_[0], value);
-};
-
 
-/**
- * Clears the message field making it undefined.
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse} returns this
- */
-proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.prototype.clearKeys = function() {
-  return this.setKeys(undefined);
-};
 
 
+if (jspb.Message.GENERATE_TO_OBJECT) {
 /**
- * Returns whether this field is set.
- * @return {boolean}
+ * Creates an object representation of this proto.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * Optional fields that are not set will be set to undefined.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * For the list of reserved names please see:
+ *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
+ * @param {boolean=} opt_includeInstance Deprecated. whether to include the
+ *     JSPB instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @return {!Object}
  */
-proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.prototype.hasKeys = function() {
-  return jspb.Message.getField(this, 1) != null;
+proto.org.dash.platform.dapi.v0.SearchKey.prototype.toObject = function(opt_includeInstance) {
+  return proto.org.dash.platform.dapi.v0.SearchKey.toObject(opt_includeInstance, this);
 };
 
 
 /**
- * optional Proof proof = 2;
- * @return {?proto.org.dash.platform.dapi.v0.Proof}
+ * Static version of the {@see toObject} method.
+ * @param {boolean|undefined} includeInstance Deprecated. Whether to include
+ *     the JSPB instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @param {!proto.org.dash.platform.dapi.v0.SearchKey} msg The msg instance to transform.
+ * @return {!Object}
+ * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.prototype.getProof = function() {
-  return /** @type{?proto.org.dash.platform.dapi.v0.Proof} */ (
-    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.Proof, 2));
-};
-
+proto.org.dash.platform.dapi.v0.SearchKey.toObject = function(includeInstance, msg) {
+  var f, obj = {
+    purposeMapMap: (f = msg.getPurposeMapMap()) ? f.toObject(includeInstance, proto.org.dash.platform.dapi.v0.SecurityLevelMap.toObject) : []
+  };
 
-/**
- * @param {?proto.org.dash.platform.dapi.v0.Proof|undefined} value
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse} returns this
-*/
-proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.prototype.setProof = function(value) {
-  return jspb.Message.setOneofWrapperField(this, 2, proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.oneofGroups_[0], value);
+  if (includeInstance) {
+    obj.$jspbMessageInstance = msg;
+  }
+  return obj;
 };
+}
 
 
 /**
- * Clears the message field making it undefined.
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse} returns this
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.org.dash.platform.dapi.v0.SearchKey}
  */
-proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.prototype.clearProof = function() {
-  return this.setProof(undefined);
+proto.org.dash.platform.dapi.v0.SearchKey.deserializeBinary = function(bytes) {
+  var reader = new jspb.BinaryReader(bytes);
+  var msg = new proto.org.dash.platform.dapi.v0.SearchKey;
+  return proto.org.dash.platform.dapi.v0.SearchKey.deserializeBinaryFromReader(msg, reader);
 };
 
 
 /**
- * Returns whether this field is set.
- * @return {boolean}
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.org.dash.platform.dapi.v0.SearchKey} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.org.dash.platform.dapi.v0.SearchKey}
  */
-proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.prototype.hasProof = function() {
-  return jspb.Message.getField(this, 2) != null;
+proto.org.dash.platform.dapi.v0.SearchKey.deserializeBinaryFromReader = function(msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+    case 1:
+      var value = msg.getPurposeMapMap();
+      reader.readMessage(value, function(message, reader) {
+        jspb.Map.deserializeBinary(message, reader, jspb.BinaryReader.prototype.readUint32, jspb.BinaryReader.prototype.readMessage, proto.org.dash.platform.dapi.v0.SecurityLevelMap.deserializeBinaryFromReader, 0, new proto.org.dash.platform.dapi.v0.SecurityLevelMap());
+         });
+      break;
+    default:
+      reader.skipField();
+      break;
+    }
+  }
+  return msg;
 };
 
 
 /**
- * optional ResponseMetadata metadata = 3;
- * @return {?proto.org.dash.platform.dapi.v0.ResponseMetadata}
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
  */
-proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.prototype.getMetadata = function() {
-  return /** @type{?proto.org.dash.platform.dapi.v0.ResponseMetadata} */ (
-    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.ResponseMetadata, 3));
+proto.org.dash.platform.dapi.v0.SearchKey.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.org.dash.platform.dapi.v0.SearchKey.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
 };
 
 
 /**
- * @param {?proto.org.dash.platform.dapi.v0.ResponseMetadata|undefined} value
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse} returns this
-*/
-proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.prototype.setMetadata = function(value) {
-  return jspb.Message.setWrapperField(this, 3, value);
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.org.dash.platform.dapi.v0.SearchKey} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.org.dash.platform.dapi.v0.SearchKey.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+  f = message.getPurposeMapMap(true);
+  if (f && f.getLength() > 0) {
+    f.serializeBinary(1, writer, jspb.BinaryWriter.prototype.writeUint32, jspb.BinaryWriter.prototype.writeMessage, proto.org.dash.platform.dapi.v0.SecurityLevelMap.serializeBinaryToWriter);
+  }
 };
 
 
 /**
- * Clears the message field making it undefined.
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse} returns this
+ * map<uint32, SecurityLevelMap> purpose_map = 1;
+ * @param {boolean=} opt_noLazyCreate Do not create the map if
+ * empty, instead returning `undefined`
+ * @return {!jspb.Map<number,!proto.org.dash.platform.dapi.v0.SecurityLevelMap>}
  */
-proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.prototype.clearMetadata = function() {
-  return this.setMetadata(undefined);
+proto.org.dash.platform.dapi.v0.SearchKey.prototype.getPurposeMapMap = function(opt_noLazyCreate) {
+  return /** @type {!jspb.Map<number,!proto.org.dash.platform.dapi.v0.SecurityLevelMap>} */ (
+      jspb.Message.getMapField(this, 1, opt_noLazyCreate,
+      proto.org.dash.platform.dapi.v0.SecurityLevelMap));
 };
 
 
 /**
- * Returns whether this field is set.
- * @return {boolean}
+ * Clears values from the map. The map will be non-null.
+ * @return {!proto.org.dash.platform.dapi.v0.SearchKey} returns this
  */
-proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.prototype.hasMetadata = function() {
-  return jspb.Message.getField(this, 3) != null;
-};
-
+proto.org.dash.platform.dapi.v0.SearchKey.prototype.clearPurposeMapMap = function() {
+  this.getPurposeMapMap().clear();
+  return this;};
 
 
-/**
- * List of repeated fields within this message type.
- * @private {!Array<number>}
- * @const
- */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.repeatedFields_ = [1];
 
 
 
@@ -6202,8 +6458,8 @@ if (jspb.Message.GENERATE_TO_OBJECT) {
  *     http://goto/soy-param-migration
  * @return {!Object}
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.prototype.toObject = function(opt_includeInstance) {
-  return proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.toObject(opt_includeInstance, this);
+proto.org.dash.platform.dapi.v0.SecurityLevelMap.prototype.toObject = function(opt_includeInstance) {
+  return proto.org.dash.platform.dapi.v0.SecurityLevelMap.toObject(opt_includeInstance, this);
 };
 
 
@@ -6212,17 +6468,13 @@ proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.prototype.toObject = fu
  * @param {boolean|undefined} includeInstance Deprecated. Whether to include
  *     the JSPB instance for transitional soy proto support:
  *     http://goto/soy-param-migration
- * @param {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest} msg The msg instance to transform.
+ * @param {!proto.org.dash.platform.dapi.v0.SecurityLevelMap} msg The msg instance to transform.
  * @return {!Object}
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.toObject = function(includeInstance, msg) {
+proto.org.dash.platform.dapi.v0.SecurityLevelMap.toObject = function(includeInstance, msg) {
   var f, obj = {
-    identityIdsList: msg.getIdentityIdsList_asB64(),
-    requestType: (f = msg.getRequestType()) && proto.org.dash.platform.dapi.v0.KeyRequestType.toObject(includeInstance, f),
-    limit: (f = msg.getLimit()) && google_protobuf_wrappers_pb.UInt32Value.toObject(includeInstance, f),
-    offset: (f = msg.getOffset()) && google_protobuf_wrappers_pb.UInt32Value.toObject(includeInstance, f),
-    prove: jspb.Message.getBooleanFieldWithDefault(msg, 5, false)
+    securityLevelMapMap: (f = msg.getSecurityLevelMapMap()) ? f.toObject(includeInstance, undefined) : []
   };
 
   if (includeInstance) {
@@ -6236,23 +6488,23 @@ proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.toObject = function(inc
 /**
  * Deserializes binary data (in protobuf wire format).
  * @param {jspb.ByteSource} bytes The bytes to deserialize.
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest}
+ * @return {!proto.org.dash.platform.dapi.v0.SecurityLevelMap}
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.deserializeBinary = function(bytes) {
+proto.org.dash.platform.dapi.v0.SecurityLevelMap.deserializeBinary = function(bytes) {
   var reader = new jspb.BinaryReader(bytes);
-  var msg = new proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest;
-  return proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.deserializeBinaryFromReader(msg, reader);
+  var msg = new proto.org.dash.platform.dapi.v0.SecurityLevelMap;
+  return proto.org.dash.platform.dapi.v0.SecurityLevelMap.deserializeBinaryFromReader(msg, reader);
 };
 
 
 /**
  * Deserializes binary data (in protobuf wire format) from the
  * given reader into the given message object.
- * @param {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest} msg The message object to deserialize into.
+ * @param {!proto.org.dash.platform.dapi.v0.SecurityLevelMap} msg The message object to deserialize into.
  * @param {!jspb.BinaryReader} reader The BinaryReader to use.
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest}
+ * @return {!proto.org.dash.platform.dapi.v0.SecurityLevelMap}
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.deserializeBinaryFromReader = function(msg, reader) {
+proto.org.dash.platform.dapi.v0.SecurityLevelMap.deserializeBinaryFromReader = function(msg, reader) {
   while (reader.nextField()) {
     if (reader.isEndGroup()) {
       break;
@@ -6260,30 +6512,13 @@ proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.deserializeBinaryFromRe
     var field = reader.getFieldNumber();
     switch (field) {
     case 1:
-      var value = /** @type {!Uint8Array} */ (reader.readBytes());
-      msg.addIdentityIds(value);
-      break;
-    case 2:
-      var value = new proto.org.dash.platform.dapi.v0.KeyRequestType;
-      reader.readMessage(value,proto.org.dash.platform.dapi.v0.KeyRequestType.deserializeBinaryFromReader);
-      msg.setRequestType(value);
-      break;
-    case 3:
-      var value = new google_protobuf_wrappers_pb.UInt32Value;
-      reader.readMessage(value,google_protobuf_wrappers_pb.UInt32Value.deserializeBinaryFromReader);
-      msg.setLimit(value);
+      var value = msg.getSecurityLevelMapMap();
+      reader.readMessage(value, function(message, reader) {
+        jspb.Map.deserializeBinary(message, reader, jspb.BinaryReader.prototype.readUint32, jspb.BinaryReader.prototype.readEnum, null, 0, 0);
+         });
       break;
-    case 4:
-      var value = new google_protobuf_wrappers_pb.UInt32Value;
-      reader.readMessage(value,google_protobuf_wrappers_pb.UInt32Value.deserializeBinaryFromReader);
-      msg.setOffset(value);
-      break;
-    case 5:
-      var value = /** @type {boolean} */ (reader.readBool());
-      msg.setProve(value);
-      break;
-    default:
-      reader.skipField();
+    default:
+      reader.skipField();
       break;
     }
   }
@@ -6295,9 +6530,9 @@ proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.deserializeBinaryFromRe
  * Serializes the message to binary data (in protobuf wire format).
  * @return {!Uint8Array}
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.prototype.serializeBinary = function() {
+proto.org.dash.platform.dapi.v0.SecurityLevelMap.prototype.serializeBinary = function() {
   var writer = new jspb.BinaryWriter();
-  proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.serializeBinaryToWriter(this, writer);
+  proto.org.dash.platform.dapi.v0.SecurityLevelMap.serializeBinaryToWriter(this, writer);
   return writer.getResultBuffer();
 };
 
@@ -6305,53 +6540,49 @@ proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.prototype.serializeBina
 /**
  * Serializes the given message to binary data (in protobuf wire
  * format), writing to the given BinaryWriter.
- * @param {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest} message
+ * @param {!proto.org.dash.platform.dapi.v0.SecurityLevelMap} message
  * @param {!jspb.BinaryWriter} writer
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.serializeBinaryToWriter = function(message, writer) {
+proto.org.dash.platform.dapi.v0.SecurityLevelMap.serializeBinaryToWriter = function(message, writer) {
   var f = undefined;
-  f = message.getIdentityIdsList_asU8();
-  if (f.length > 0) {
-    writer.writeRepeatedBytes(
-      1,
-      f
-    );
-  }
-  f = message.getRequestType();
-  if (f != null) {
-    writer.writeMessage(
-      2,
-      f,
-      proto.org.dash.platform.dapi.v0.KeyRequestType.serializeBinaryToWriter
-    );
-  }
-  f = message.getLimit();
-  if (f != null) {
-    writer.writeMessage(
-      3,
-      f,
-      google_protobuf_wrappers_pb.UInt32Value.serializeBinaryToWriter
-    );
-  }
-  f = message.getOffset();
-  if (f != null) {
-    writer.writeMessage(
-      4,
-      f,
-      google_protobuf_wrappers_pb.UInt32Value.serializeBinaryToWriter
-    );
-  }
-  f = message.getProve();
-  if (f) {
-    writer.writeBool(
-      5,
-      f
-    );
+  f = message.getSecurityLevelMapMap(true);
+  if (f && f.getLength() > 0) {
+    f.serializeBinary(1, writer, jspb.BinaryWriter.prototype.writeUint32, jspb.BinaryWriter.prototype.writeEnum);
   }
 };
 
 
+/**
+ * @enum {number}
+ */
+proto.org.dash.platform.dapi.v0.SecurityLevelMap.KeyKindRequestType = {
+  CURRENT_KEY_OF_KIND_REQUEST: 0,
+  ALL_KEYS_OF_KIND_REQUEST: 1
+};
+
+/**
+ * map<uint32, KeyKindRequestType> security_level_map = 1;
+ * @param {boolean=} opt_noLazyCreate Do not create the map if
+ * empty, instead returning `undefined`
+ * @return {!jspb.Map<number,!proto.org.dash.platform.dapi.v0.SecurityLevelMap.KeyKindRequestType>}
+ */
+proto.org.dash.platform.dapi.v0.SecurityLevelMap.prototype.getSecurityLevelMapMap = function(opt_noLazyCreate) {
+  return /** @type {!jspb.Map<number,!proto.org.dash.platform.dapi.v0.SecurityLevelMap.KeyKindRequestType>} */ (
+      jspb.Message.getMapField(this, 1, opt_noLazyCreate,
+      null));
+};
+
+
+/**
+ * Clears values from the map. The map will be non-null.
+ * @return {!proto.org.dash.platform.dapi.v0.SecurityLevelMap} returns this
+ */
+proto.org.dash.platform.dapi.v0.SecurityLevelMap.prototype.clearSecurityLevelMapMap = function() {
+  this.getSecurityLevelMapMap().clear();
+  return this;};
+
+
 
 
 
@@ -6368,8 +6599,8 @@ if (jspb.Message.GENERATE_TO_OBJECT) {
  *     http://goto/soy-param-migration
  * @return {!Object}
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.SecurityLevelMap.prototype.toObject = function(opt_includeInstance) {
-  return proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.SecurityLevelMap.toObject(opt_includeInstance, this);
+proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest.prototype.toObject = function(opt_includeInstance) {
+  return proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest.toObject(opt_includeInstance, this);
 };
 
 
@@ -6378,13 +6609,17 @@ proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.SecurityLevelMap.protot
  * @param {boolean|undefined} includeInstance Deprecated. Whether to include
  *     the JSPB instance for transitional soy proto support:
  *     http://goto/soy-param-migration
- * @param {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.SecurityLevelMap} msg The msg instance to transform.
+ * @param {!proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest} msg The msg instance to transform.
  * @return {!Object}
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.SecurityLevelMap.toObject = function(includeInstance, msg) {
+proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest.toObject = function(includeInstance, msg) {
   var f, obj = {
-    securityLevelMapMap: (f = msg.getSecurityLevelMapMap()) ? f.toObject(includeInstance, undefined) : []
+    identityId: msg.getIdentityId_asB64(),
+    requestType: (f = msg.getRequestType()) && proto.org.dash.platform.dapi.v0.KeyRequestType.toObject(includeInstance, f),
+    limit: (f = msg.getLimit()) && google_protobuf_wrappers_pb.UInt32Value.toObject(includeInstance, f),
+    offset: (f = msg.getOffset()) && google_protobuf_wrappers_pb.UInt32Value.toObject(includeInstance, f),
+    prove: jspb.Message.getBooleanFieldWithDefault(msg, 5, false)
   };
 
   if (includeInstance) {
@@ -6398,23 +6633,23 @@ proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.SecurityLevelMap.toObje
 /**
  * Deserializes binary data (in protobuf wire format).
  * @param {jspb.ByteSource} bytes The bytes to deserialize.
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.SecurityLevelMap}
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest}
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.SecurityLevelMap.deserializeBinary = function(bytes) {
+proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest.deserializeBinary = function(bytes) {
   var reader = new jspb.BinaryReader(bytes);
-  var msg = new proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.SecurityLevelMap;
-  return proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.SecurityLevelMap.deserializeBinaryFromReader(msg, reader);
+  var msg = new proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest;
+  return proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest.deserializeBinaryFromReader(msg, reader);
 };
 
 
 /**
  * Deserializes binary data (in protobuf wire format) from the
  * given reader into the given message object.
- * @param {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.SecurityLevelMap} msg The message object to deserialize into.
+ * @param {!proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest} msg The message object to deserialize into.
  * @param {!jspb.BinaryReader} reader The BinaryReader to use.
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.SecurityLevelMap}
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest}
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.SecurityLevelMap.deserializeBinaryFromReader = function(msg, reader) {
+proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest.deserializeBinaryFromReader = function(msg, reader) {
   while (reader.nextField()) {
     if (reader.isEndGroup()) {
       break;
@@ -6422,10 +6657,27 @@ proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.SecurityLevelMap.deseri
     var field = reader.getFieldNumber();
     switch (field) {
     case 1:
-      var value = msg.getSecurityLevelMapMap();
-      reader.readMessage(value, function(message, reader) {
-        jspb.Map.deserializeBinary(message, reader, jspb.BinaryReader.prototype.readUint32, jspb.BinaryReader.prototype.readEnum, null, 0, 0);
-         });
+      var value = /** @type {!Uint8Array} */ (reader.readBytes());
+      msg.setIdentityId(value);
+      break;
+    case 2:
+      var value = new proto.org.dash.platform.dapi.v0.KeyRequestType;
+      reader.readMessage(value,proto.org.dash.platform.dapi.v0.KeyRequestType.deserializeBinaryFromReader);
+      msg.setRequestType(value);
+      break;
+    case 3:
+      var value = new google_protobuf_wrappers_pb.UInt32Value;
+      reader.readMessage(value,google_protobuf_wrappers_pb.UInt32Value.deserializeBinaryFromReader);
+      msg.setLimit(value);
+      break;
+    case 4:
+      var value = new google_protobuf_wrappers_pb.UInt32Value;
+      reader.readMessage(value,google_protobuf_wrappers_pb.UInt32Value.deserializeBinaryFromReader);
+      msg.setOffset(value);
+      break;
+    case 5:
+      var value = /** @type {boolean} */ (reader.readBool());
+      msg.setProve(value);
       break;
     default:
       reader.skipField();
@@ -6440,9 +6692,9 @@ proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.SecurityLevelMap.deseri
  * Serializes the message to binary data (in protobuf wire format).
  * @return {!Uint8Array}
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.SecurityLevelMap.prototype.serializeBinary = function() {
+proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest.prototype.serializeBinary = function() {
   var writer = new jspb.BinaryWriter();
-  proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.SecurityLevelMap.serializeBinaryToWriter(this, writer);
+  proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest.serializeBinaryToWriter(this, writer);
   return writer.getResultBuffer();
 };
 
@@ -6450,133 +6702,119 @@ proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.SecurityLevelMap.protot
 /**
  * Serializes the given message to binary data (in protobuf wire
  * format), writing to the given BinaryWriter.
- * @param {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.SecurityLevelMap} message
+ * @param {!proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest} message
  * @param {!jspb.BinaryWriter} writer
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.SecurityLevelMap.serializeBinaryToWriter = function(message, writer) {
+proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest.serializeBinaryToWriter = function(message, writer) {
   var f = undefined;
-  f = message.getSecurityLevelMapMap(true);
-  if (f && f.getLength() > 0) {
-    f.serializeBinary(1, writer, jspb.BinaryWriter.prototype.writeUint32, jspb.BinaryWriter.prototype.writeEnum);
+  f = message.getIdentityId_asU8();
+  if (f.length > 0) {
+    writer.writeBytes(
+      1,
+      f
+    );
+  }
+  f = message.getRequestType();
+  if (f != null) {
+    writer.writeMessage(
+      2,
+      f,
+      proto.org.dash.platform.dapi.v0.KeyRequestType.serializeBinaryToWriter
+    );
+  }
+  f = message.getLimit();
+  if (f != null) {
+    writer.writeMessage(
+      3,
+      f,
+      google_protobuf_wrappers_pb.UInt32Value.serializeBinaryToWriter
+    );
+  }
+  f = message.getOffset();
+  if (f != null) {
+    writer.writeMessage(
+      4,
+      f,
+      google_protobuf_wrappers_pb.UInt32Value.serializeBinaryToWriter
+    );
+  }
+  f = message.getProve();
+  if (f) {
+    writer.writeBool(
+      5,
+      f
+    );
   }
 };
 
 
 /**
- * @enum {number}
+ * optional bytes identity_id = 1;
+ * @return {string}
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.SecurityLevelMap.KeyKindRequestType = {
-  CURRENT_KEY_OF_KIND_REQUEST: 0
+proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest.prototype.getIdentityId = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 1, ""));
 };
 
+
 /**
- * map<uint32, KeyKindRequestType> security_level_map = 1;
- * @param {boolean=} opt_noLazyCreate Do not create the map if
- * empty, instead returning `undefined`
- * @return {!jspb.Map<number,!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.SecurityLevelMap.KeyKindRequestType>}
+ * optional bytes identity_id = 1;
+ * This is a type-conversion wrapper around `getIdentityId()`
+ * @return {string}
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.SecurityLevelMap.prototype.getSecurityLevelMapMap = function(opt_noLazyCreate) {
-  return /** @type {!jspb.Map<number,!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.SecurityLevelMap.KeyKindRequestType>} */ (
-      jspb.Message.getMapField(this, 1, opt_noLazyCreate,
-      null));
+proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest.prototype.getIdentityId_asB64 = function() {
+  return /** @type {string} */ (jspb.Message.bytesAsB64(
+      this.getIdentityId()));
 };
 
 
 /**
- * Clears values from the map. The map will be non-null.
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.SecurityLevelMap} returns this
+ * optional bytes identity_id = 1;
+ * Note that Uint8Array is not supported on all browsers.
+ * @see http://caniuse.com/Uint8Array
+ * This is a type-conversion wrapper around `getIdentityId()`
+ * @return {!Uint8Array}
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.SecurityLevelMap.prototype.clearSecurityLevelMapMap = function() {
-  this.getSecurityLevelMapMap().clear();
-  return this;};
+proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest.prototype.getIdentityId_asU8 = function() {
+  return /** @type {!Uint8Array} */ (jspb.Message.bytesAsU8(
+      this.getIdentityId()));
+};
 
 
 /**
- * repeated bytes identity_ids = 1;
- * @return {!Array<string>}
+ * @param {!(string|Uint8Array)} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest} returns this
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.prototype.getIdentityIdsList = function() {
-  return /** @type {!Array<string>} */ (jspb.Message.getRepeatedField(this, 1));
+proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest.prototype.setIdentityId = function(value) {
+  return jspb.Message.setProto3BytesField(this, 1, value);
 };
 
 
 /**
- * repeated bytes identity_ids = 1;
- * This is a type-conversion wrapper around `getIdentityIdsList()`
- * @return {!Array<string>}
+ * optional KeyRequestType request_type = 2;
+ * @return {?proto.org.dash.platform.dapi.v0.KeyRequestType}
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.prototype.getIdentityIdsList_asB64 = function() {
-  return /** @type {!Array<string>} */ (jspb.Message.bytesListAsB64(
-      this.getIdentityIdsList()));
+proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest.prototype.getRequestType = function() {
+  return /** @type{?proto.org.dash.platform.dapi.v0.KeyRequestType} */ (
+    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.KeyRequestType, 2));
 };
 
 
 /**
- * repeated bytes identity_ids = 1;
- * Note that Uint8Array is not supported on all browsers.
- * @see http://caniuse.com/Uint8Array
- * This is a type-conversion wrapper around `getIdentityIdsList()`
- * @return {!Array<!Uint8Array>}
- */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.prototype.getIdentityIdsList_asU8 = function() {
-  return /** @type {!Array<!Uint8Array>} */ (jspb.Message.bytesListAsU8(
-      this.getIdentityIdsList()));
-};
-
-
-/**
- * @param {!(Array<!Uint8Array>|Array<string>)} value
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest} returns this
- */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.prototype.setIdentityIdsList = function(value) {
-  return jspb.Message.setField(this, 1, value || []);
-};
-
-
-/**
- * @param {!(string|Uint8Array)} value
- * @param {number=} opt_index
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest} returns this
- */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.prototype.addIdentityIds = function(value, opt_index) {
-  return jspb.Message.addToRepeatedField(this, 1, value, opt_index);
-};
-
-
-/**
- * Clears the list making it empty but non-null.
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest} returns this
- */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.prototype.clearIdentityIdsList = function() {
-  return this.setIdentityIdsList([]);
-};
-
-
-/**
- * optional KeyRequestType request_type = 2;
- * @return {?proto.org.dash.platform.dapi.v0.KeyRequestType}
- */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.prototype.getRequestType = function() {
-  return /** @type{?proto.org.dash.platform.dapi.v0.KeyRequestType} */ (
-    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.KeyRequestType, 2));
-};
-
-
-/**
- * @param {?proto.org.dash.platform.dapi.v0.KeyRequestType|undefined} value
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest} returns this
-*/
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.prototype.setRequestType = function(value) {
-  return jspb.Message.setWrapperField(this, 2, value);
+ * @param {?proto.org.dash.platform.dapi.v0.KeyRequestType|undefined} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest} returns this
+*/
+proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest.prototype.setRequestType = function(value) {
+  return jspb.Message.setWrapperField(this, 2, value);
 };
 
 
 /**
  * Clears the message field making it undefined.
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest} returns this
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest} returns this
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.prototype.clearRequestType = function() {
+proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest.prototype.clearRequestType = function() {
   return this.setRequestType(undefined);
 };
 
@@ -6585,7 +6823,7 @@ proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.prototype.clearRequestT
  * Returns whether this field is set.
  * @return {boolean}
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.prototype.hasRequestType = function() {
+proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest.prototype.hasRequestType = function() {
   return jspb.Message.getField(this, 2) != null;
 };
 
@@ -6594,7 +6832,7 @@ proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.prototype.hasRequestTyp
  * optional google.protobuf.UInt32Value limit = 3;
  * @return {?proto.google.protobuf.UInt32Value}
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.prototype.getLimit = function() {
+proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest.prototype.getLimit = function() {
   return /** @type{?proto.google.protobuf.UInt32Value} */ (
     jspb.Message.getWrapperField(this, google_protobuf_wrappers_pb.UInt32Value, 3));
 };
@@ -6602,18 +6840,18 @@ proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.prototype.getLimit = fu
 
 /**
  * @param {?proto.google.protobuf.UInt32Value|undefined} value
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest} returns this
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest} returns this
 */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.prototype.setLimit = function(value) {
+proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest.prototype.setLimit = function(value) {
   return jspb.Message.setWrapperField(this, 3, value);
 };
 
 
 /**
  * Clears the message field making it undefined.
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest} returns this
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest} returns this
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.prototype.clearLimit = function() {
+proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest.prototype.clearLimit = function() {
   return this.setLimit(undefined);
 };
 
@@ -6622,7 +6860,7 @@ proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.prototype.clearLimit =
  * Returns whether this field is set.
  * @return {boolean}
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.prototype.hasLimit = function() {
+proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest.prototype.hasLimit = function() {
   return jspb.Message.getField(this, 3) != null;
 };
 
@@ -6631,7 +6869,7 @@ proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.prototype.hasLimit = fu
  * optional google.protobuf.UInt32Value offset = 4;
  * @return {?proto.google.protobuf.UInt32Value}
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.prototype.getOffset = function() {
+proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest.prototype.getOffset = function() {
   return /** @type{?proto.google.protobuf.UInt32Value} */ (
     jspb.Message.getWrapperField(this, google_protobuf_wrappers_pb.UInt32Value, 4));
 };
@@ -6639,18 +6877,18 @@ proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.prototype.getOffset = f
 
 /**
  * @param {?proto.google.protobuf.UInt32Value|undefined} value
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest} returns this
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest} returns this
 */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.prototype.setOffset = function(value) {
+proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest.prototype.setOffset = function(value) {
   return jspb.Message.setWrapperField(this, 4, value);
 };
 
 
 /**
  * Clears the message field making it undefined.
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest} returns this
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest} returns this
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.prototype.clearOffset = function() {
+proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest.prototype.clearOffset = function() {
   return this.setOffset(undefined);
 };
 
@@ -6659,7 +6897,7 @@ proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.prototype.clearOffset =
  * Returns whether this field is set.
  * @return {boolean}
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.prototype.hasOffset = function() {
+proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest.prototype.hasOffset = function() {
   return jspb.Message.getField(this, 4) != null;
 };
 
@@ -6668,16 +6906,16 @@ proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.prototype.hasOffset = f
  * optional bool prove = 5;
  * @return {boolean}
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.prototype.getProve = function() {
+proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest.prototype.getProve = function() {
   return /** @type {boolean} */ (jspb.Message.getBooleanFieldWithDefault(this, 5, false));
 };
 
 
 /**
  * @param {boolean} value
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest} returns this
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest} returns this
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.prototype.setProve = function(value) {
+proto.org.dash.platform.dapi.v0.GetIdentityKeysRequest.prototype.setProve = function(value) {
   return jspb.Message.setProto3BooleanField(this, 5, value);
 };
 
@@ -6691,22 +6929,22 @@ proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.prototype.setProve = fu
  * @private {!Array<!Array<number>>}
  * @const
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.oneofGroups_ = [[1,2]];
+proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.oneofGroups_ = [[1,2]];
 
 /**
  * @enum {number}
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.ResultCase = {
+proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.ResultCase = {
   RESULT_NOT_SET: 0,
-  PUBLIC_KEYS: 1,
+  KEYS: 1,
   PROOF: 2
 };
 
 /**
- * @return {proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.ResultCase}
+ * @return {proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.ResultCase}
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.prototype.getResultCase = function() {
-  return /** @type {proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.ResultCase} */(jspb.Message.computeOneofCase(this, proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.oneofGroups_[0]));
+proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.prototype.getResultCase = function() {
+  return /** @type {proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.ResultCase} */(jspb.Message.computeOneofCase(this, proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.oneofGroups_[0]));
 };
 
 
@@ -6724,8 +6962,8 @@ if (jspb.Message.GENERATE_TO_OBJECT) {
  *     http://goto/soy-param-migration
  * @return {!Object}
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.prototype.toObject = function(opt_includeInstance) {
-  return proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.toObject(opt_includeInstance, this);
+proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.prototype.toObject = function(opt_includeInstance) {
+  return proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.toObject(opt_includeInstance, this);
 };
 
 
@@ -6734,13 +6972,13 @@ proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.prototype.toObject = f
  * @param {boolean|undefined} includeInstance Deprecated. Whether to include
  *     the JSPB instance for transitional soy proto support:
  *     http://goto/soy-param-migration
- * @param {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse} msg The msg instance to transform.
+ * @param {!proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse} msg The msg instance to transform.
  * @return {!Object}
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.toObject = function(includeInstance, msg) {
+proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.toObject = function(includeInstance, msg) {
   var f, obj = {
-    publicKeys: (f = msg.getPublicKeys()) && proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntries.toObject(includeInstance, f),
+    keys: (f = msg.getKeys()) && proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.Keys.toObject(includeInstance, f),
     proof: (f = msg.getProof()) && proto.org.dash.platform.dapi.v0.Proof.toObject(includeInstance, f),
     metadata: (f = msg.getMetadata()) && proto.org.dash.platform.dapi.v0.ResponseMetadata.toObject(includeInstance, f)
   };
@@ -6756,23 +6994,23 @@ proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.toObject = function(in
 /**
  * Deserializes binary data (in protobuf wire format).
  * @param {jspb.ByteSource} bytes The bytes to deserialize.
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse}
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse}
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.deserializeBinary = function(bytes) {
+proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.deserializeBinary = function(bytes) {
   var reader = new jspb.BinaryReader(bytes);
-  var msg = new proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse;
-  return proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.deserializeBinaryFromReader(msg, reader);
+  var msg = new proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse;
+  return proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.deserializeBinaryFromReader(msg, reader);
 };
 
 
 /**
  * Deserializes binary data (in protobuf wire format) from the
  * given reader into the given message object.
- * @param {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse} msg The message object to deserialize into.
+ * @param {!proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse} msg The message object to deserialize into.
  * @param {!jspb.BinaryReader} reader The BinaryReader to use.
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse}
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse}
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.deserializeBinaryFromReader = function(msg, reader) {
+proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.deserializeBinaryFromReader = function(msg, reader) {
   while (reader.nextField()) {
     if (reader.isEndGroup()) {
       break;
@@ -6780,9 +7018,9 @@ proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.deserializeBinaryFromR
     var field = reader.getFieldNumber();
     switch (field) {
     case 1:
-      var value = new proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntries;
-      reader.readMessage(value,proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntries.deserializeBinaryFromReader);
-      msg.setPublicKeys(value);
+      var value = new proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.Keys;
+      reader.readMessage(value,proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.Keys.deserializeBinaryFromReader);
+      msg.setKeys(value);
       break;
     case 2:
       var value = new proto.org.dash.platform.dapi.v0.Proof;
@@ -6807,9 +7045,9 @@ proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.deserializeBinaryFromR
  * Serializes the message to binary data (in protobuf wire format).
  * @return {!Uint8Array}
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.prototype.serializeBinary = function() {
+proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.prototype.serializeBinary = function() {
   var writer = new jspb.BinaryWriter();
-  proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.serializeBinaryToWriter(this, writer);
+  proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.serializeBinaryToWriter(this, writer);
   return writer.getResultBuffer();
 };
 
@@ -6817,18 +7055,18 @@ proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.prototype.serializeBin
 /**
  * Serializes the given message to binary data (in protobuf wire
  * format), writing to the given BinaryWriter.
- * @param {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse} message
+ * @param {!proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse} message
  * @param {!jspb.BinaryWriter} writer
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.serializeBinaryToWriter = function(message, writer) {
+proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.serializeBinaryToWriter = function(message, writer) {
   var f = undefined;
-  f = message.getPublicKeys();
+  f = message.getKeys();
   if (f != null) {
     writer.writeMessage(
       1,
       f,
-      proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntries.serializeBinaryToWriter
+      proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.Keys.serializeBinaryToWriter
     );
   }
   f = message.getProof();
@@ -6851,6 +7089,13 @@ proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.serializeBinaryToWrite
 
 
 
+/**
+ * List of repeated fields within this message type.
+ * @private {!Array<number>}
+ * @const
+ */
+proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.Keys.repeatedFields_ = [1];
+
 
 
 if (jspb.Message.GENERATE_TO_OBJECT) {
@@ -6866,8 +7111,8 @@ if (jspb.Message.GENERATE_TO_OBJECT) {
  *     http://goto/soy-param-migration
  * @return {!Object}
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKey.prototype.toObject = function(opt_includeInstance) {
-  return proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKey.toObject(opt_includeInstance, this);
+proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.Keys.prototype.toObject = function(opt_includeInstance) {
+  return proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.Keys.toObject(opt_includeInstance, this);
 };
 
 
@@ -6876,13 +7121,13 @@ proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKey.prototype.to
  * @param {boolean|undefined} includeInstance Deprecated. Whether to include
  *     the JSPB instance for transitional soy proto support:
  *     http://goto/soy-param-migration
- * @param {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKey} msg The msg instance to transform.
+ * @param {!proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.Keys} msg The msg instance to transform.
  * @return {!Object}
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKey.toObject = function(includeInstance, msg) {
+proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.Keys.toObject = function(includeInstance, msg) {
   var f, obj = {
-    value: msg.getValue_asB64()
+    keysBytesList: msg.getKeysBytesList_asB64()
   };
 
   if (includeInstance) {
@@ -6896,23 +7141,23 @@ proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKey.toObject = f
 /**
  * Deserializes binary data (in protobuf wire format).
  * @param {jspb.ByteSource} bytes The bytes to deserialize.
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKey}
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.Keys}
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKey.deserializeBinary = function(bytes) {
+proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.Keys.deserializeBinary = function(bytes) {
   var reader = new jspb.BinaryReader(bytes);
-  var msg = new proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKey;
-  return proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKey.deserializeBinaryFromReader(msg, reader);
+  var msg = new proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.Keys;
+  return proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.Keys.deserializeBinaryFromReader(msg, reader);
 };
 
 
 /**
  * Deserializes binary data (in protobuf wire format) from the
  * given reader into the given message object.
- * @param {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKey} msg The message object to deserialize into.
+ * @param {!proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.Keys} msg The message object to deserialize into.
  * @param {!jspb.BinaryReader} reader The BinaryReader to use.
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKey}
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.Keys}
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKey.deserializeBinaryFromReader = function(msg, reader) {
+proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.Keys.deserializeBinaryFromReader = function(msg, reader) {
   while (reader.nextField()) {
     if (reader.isEndGroup()) {
       break;
@@ -6921,7 +7166,7 @@ proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKey.deserializeB
     switch (field) {
     case 1:
       var value = /** @type {!Uint8Array} */ (reader.readBytes());
-      msg.setValue(value);
+      msg.addKeysBytes(value);
       break;
     default:
       reader.skipField();
@@ -6936,9 +7181,9 @@ proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKey.deserializeB
  * Serializes the message to binary data (in protobuf wire format).
  * @return {!Uint8Array}
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKey.prototype.serializeBinary = function() {
+proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.Keys.prototype.serializeBinary = function() {
   var writer = new jspb.BinaryWriter();
-  proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKey.serializeBinaryToWriter(this, writer);
+  proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.Keys.serializeBinaryToWriter(this, writer);
   return writer.getResultBuffer();
 };
 
@@ -6946,15 +7191,15 @@ proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKey.prototype.se
 /**
  * Serializes the given message to binary data (in protobuf wire
  * format), writing to the given BinaryWriter.
- * @param {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKey} message
+ * @param {!proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.Keys} message
  * @param {!jspb.BinaryWriter} writer
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKey.serializeBinaryToWriter = function(message, writer) {
+proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.Keys.serializeBinaryToWriter = function(message, writer) {
   var f = undefined;
-  f = message.getValue_asU8();
+  f = message.getKeysBytesList_asU8();
   if (f.length > 0) {
-    writer.writeBytes(
+    writer.writeRepeatedBytes(
       1,
       f
     );
@@ -6963,240 +7208,165 @@ proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKey.serializeBin
 
 
 /**
- * optional bytes value = 1;
- * @return {string}
+ * repeated bytes keys_bytes = 1;
+ * @return {!Array<string>}
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKey.prototype.getValue = function() {
-  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 1, ""));
+proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.Keys.prototype.getKeysBytesList = function() {
+  return /** @type {!Array<string>} */ (jspb.Message.getRepeatedField(this, 1));
 };
 
 
 /**
- * optional bytes value = 1;
- * This is a type-conversion wrapper around `getValue()`
- * @return {string}
+ * repeated bytes keys_bytes = 1;
+ * This is a type-conversion wrapper around `getKeysBytesList()`
+ * @return {!Array<string>}
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKey.prototype.getValue_asB64 = function() {
-  return /** @type {string} */ (jspb.Message.bytesAsB64(
-      this.getValue()));
+proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.Keys.prototype.getKeysBytesList_asB64 = function() {
+  return /** @type {!Array<string>} */ (jspb.Message.bytesListAsB64(
+      this.getKeysBytesList()));
 };
 
 
 /**
- * optional bytes value = 1;
+ * repeated bytes keys_bytes = 1;
  * Note that Uint8Array is not supported on all browsers.
  * @see http://caniuse.com/Uint8Array
- * This is a type-conversion wrapper around `getValue()`
- * @return {!Uint8Array}
+ * This is a type-conversion wrapper around `getKeysBytesList()`
+ * @return {!Array<!Uint8Array>}
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKey.prototype.getValue_asU8 = function() {
-  return /** @type {!Uint8Array} */ (jspb.Message.bytesAsU8(
-      this.getValue()));
+proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.Keys.prototype.getKeysBytesList_asU8 = function() {
+  return /** @type {!Array<!Uint8Array>} */ (jspb.Message.bytesListAsU8(
+      this.getKeysBytesList()));
 };
 
 
 /**
- * @param {!(string|Uint8Array)} value
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKey} returns this
+ * @param {!(Array<!Uint8Array>|Array<string>)} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.Keys} returns this
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKey.prototype.setValue = function(value) {
-  return jspb.Message.setProto3BytesField(this, 1, value);
+proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.Keys.prototype.setKeysBytesList = function(value) {
+  return jspb.Message.setField(this, 1, value || []);
 };
 
 
+/**
+ * @param {!(string|Uint8Array)} value
+ * @param {number=} opt_index
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.Keys} returns this
+ */
+proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.Keys.prototype.addKeysBytes = function(value, opt_index) {
+  return jspb.Message.addToRepeatedField(this, 1, value, opt_index);
+};
 
 
-
-if (jspb.Message.GENERATE_TO_OBJECT) {
 /**
- * Creates an object representation of this proto.
- * Field names that are reserved in JavaScript and will be renamed to pb_name.
- * Optional fields that are not set will be set to undefined.
- * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
- * For the list of reserved names please see:
- *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
- * @param {boolean=} opt_includeInstance Deprecated. whether to include the
- *     JSPB instance for transitional soy proto support:
- *     http://goto/soy-param-migration
- * @return {!Object}
+ * Clears the list making it empty but non-null.
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.Keys} returns this
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntry.prototype.toObject = function(opt_includeInstance) {
-  return proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntry.toObject(opt_includeInstance, this);
+proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.Keys.prototype.clearKeysBytesList = function() {
+  return this.setKeysBytesList([]);
 };
 
 
 /**
- * Static version of the {@see toObject} method.
- * @param {boolean|undefined} includeInstance Deprecated. Whether to include
- *     the JSPB instance for transitional soy proto support:
- *     http://goto/soy-param-migration
- * @param {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntry} msg The msg instance to transform.
- * @return {!Object}
- * @suppress {unusedLocalVariables} f is only used for nested messages
+ * optional Keys keys = 1;
+ * @return {?proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.Keys}
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntry.toObject = function(includeInstance, msg) {
-  var f, obj = {
-    key: msg.getKey_asB64(),
-    value: (f = msg.getValue()) && proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKey.toObject(includeInstance, f)
-  };
-
-  if (includeInstance) {
-    obj.$jspbMessageInstance = msg;
-  }
-  return obj;
-};
-}
-
-
-/**
- * Deserializes binary data (in protobuf wire format).
- * @param {jspb.ByteSource} bytes The bytes to deserialize.
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntry}
- */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntry.deserializeBinary = function(bytes) {
-  var reader = new jspb.BinaryReader(bytes);
-  var msg = new proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntry;
-  return proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntry.deserializeBinaryFromReader(msg, reader);
+proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.prototype.getKeys = function() {
+  return /** @type{?proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.Keys} */ (
+    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.Keys, 1));
 };
 
 
 /**
- * Deserializes binary data (in protobuf wire format) from the
- * given reader into the given message object.
- * @param {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntry} msg The message object to deserialize into.
- * @param {!jspb.BinaryReader} reader The BinaryReader to use.
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntry}
- */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntry.deserializeBinaryFromReader = function(msg, reader) {
-  while (reader.nextField()) {
-    if (reader.isEndGroup()) {
-      break;
-    }
-    var field = reader.getFieldNumber();
-    switch (field) {
-    case 1:
-      var value = /** @type {!Uint8Array} */ (reader.readBytes());
-      msg.setKey(value);
-      break;
-    case 2:
-      var value = new proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKey;
-      reader.readMessage(value,proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKey.deserializeBinaryFromReader);
-      msg.setValue(value);
-      break;
-    default:
-      reader.skipField();
-      break;
-    }
-  }
-  return msg;
+ * @param {?proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.Keys|undefined} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse} returns this
+*/
+proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.prototype.setKeys = function(value) {
+  return jspb.Message.setOneofWrapperField(this, 1, proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.oneofGroups_[0], value);
 };
 
 
 /**
- * Serializes the message to binary data (in protobuf wire format).
- * @return {!Uint8Array}
+ * Clears the message field making it undefined.
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse} returns this
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntry.prototype.serializeBinary = function() {
-  var writer = new jspb.BinaryWriter();
-  proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntry.serializeBinaryToWriter(this, writer);
-  return writer.getResultBuffer();
+proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.prototype.clearKeys = function() {
+  return this.setKeys(undefined);
 };
 
 
 /**
- * Serializes the given message to binary data (in protobuf wire
- * format), writing to the given BinaryWriter.
- * @param {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntry} message
- * @param {!jspb.BinaryWriter} writer
- * @suppress {unusedLocalVariables} f is only used for nested messages
+ * Returns whether this field is set.
+ * @return {boolean}
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntry.serializeBinaryToWriter = function(message, writer) {
-  var f = undefined;
-  f = message.getKey_asU8();
-  if (f.length > 0) {
-    writer.writeBytes(
-      1,
-      f
-    );
-  }
-  f = message.getValue();
-  if (f != null) {
-    writer.writeMessage(
-      2,
-      f,
-      proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKey.serializeBinaryToWriter
-    );
-  }
+proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.prototype.hasKeys = function() {
+  return jspb.Message.getField(this, 1) != null;
 };
 
 
 /**
- * optional bytes key = 1;
- * @return {string}
+ * optional Proof proof = 2;
+ * @return {?proto.org.dash.platform.dapi.v0.Proof}
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntry.prototype.getKey = function() {
-  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 1, ""));
+proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.prototype.getProof = function() {
+  return /** @type{?proto.org.dash.platform.dapi.v0.Proof} */ (
+    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.Proof, 2));
 };
 
 
 /**
- * optional bytes key = 1;
- * This is a type-conversion wrapper around `getKey()`
- * @return {string}
- */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntry.prototype.getKey_asB64 = function() {
-  return /** @type {string} */ (jspb.Message.bytesAsB64(
-      this.getKey()));
+ * @param {?proto.org.dash.platform.dapi.v0.Proof|undefined} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse} returns this
+*/
+proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.prototype.setProof = function(value) {
+  return jspb.Message.setOneofWrapperField(this, 2, proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.oneofGroups_[0], value);
 };
 
 
 /**
- * optional bytes key = 1;
- * Note that Uint8Array is not supported on all browsers.
- * @see http://caniuse.com/Uint8Array
- * This is a type-conversion wrapper around `getKey()`
- * @return {!Uint8Array}
+ * Clears the message field making it undefined.
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse} returns this
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntry.prototype.getKey_asU8 = function() {
-  return /** @type {!Uint8Array} */ (jspb.Message.bytesAsU8(
-      this.getKey()));
+proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.prototype.clearProof = function() {
+  return this.setProof(undefined);
 };
 
 
 /**
- * @param {!(string|Uint8Array)} value
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntry} returns this
+ * Returns whether this field is set.
+ * @return {boolean}
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntry.prototype.setKey = function(value) {
-  return jspb.Message.setProto3BytesField(this, 1, value);
+proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.prototype.hasProof = function() {
+  return jspb.Message.getField(this, 2) != null;
 };
 
 
 /**
- * optional PublicKey value = 2;
- * @return {?proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKey}
+ * optional ResponseMetadata metadata = 3;
+ * @return {?proto.org.dash.platform.dapi.v0.ResponseMetadata}
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntry.prototype.getValue = function() {
-  return /** @type{?proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKey} */ (
-    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKey, 2));
+proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.prototype.getMetadata = function() {
+  return /** @type{?proto.org.dash.platform.dapi.v0.ResponseMetadata} */ (
+    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.ResponseMetadata, 3));
 };
 
 
 /**
- * @param {?proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKey|undefined} value
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntry} returns this
+ * @param {?proto.org.dash.platform.dapi.v0.ResponseMetadata|undefined} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse} returns this
 */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntry.prototype.setValue = function(value) {
-  return jspb.Message.setWrapperField(this, 2, value);
+proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.prototype.setMetadata = function(value) {
+  return jspb.Message.setWrapperField(this, 3, value);
 };
 
 
 /**
  * Clears the message field making it undefined.
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntry} returns this
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse} returns this
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntry.prototype.clearValue = function() {
-  return this.setValue(undefined);
+proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.prototype.clearMetadata = function() {
+  return this.setMetadata(undefined);
 };
 
 
@@ -7204,8 +7374,8 @@ proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntry.prototy
  * Returns whether this field is set.
  * @return {boolean}
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntry.prototype.hasValue = function() {
-  return jspb.Message.getField(this, 2) != null;
+proto.org.dash.platform.dapi.v0.GetIdentityKeysResponse.prototype.hasMetadata = function() {
+  return jspb.Message.getField(this, 3) != null;
 };
 
 
@@ -7215,7 +7385,7 @@ proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntry.prototy
  * @private {!Array<number>}
  * @const
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntries.repeatedFields_ = [1];
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.repeatedFields_ = [1];
 
 
 
@@ -7232,8 +7402,8 @@ if (jspb.Message.GENERATE_TO_OBJECT) {
  *     http://goto/soy-param-migration
  * @return {!Object}
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntries.prototype.toObject = function(opt_includeInstance) {
-  return proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntries.toObject(opt_includeInstance, this);
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.prototype.toObject = function(opt_includeInstance) {
+  return proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.toObject(opt_includeInstance, this);
 };
 
 
@@ -7242,14 +7412,17 @@ proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntries.proto
  * @param {boolean|undefined} includeInstance Deprecated. Whether to include
  *     the JSPB instance for transitional soy proto support:
  *     http://goto/soy-param-migration
- * @param {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntries} msg The msg instance to transform.
+ * @param {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest} msg The msg instance to transform.
  * @return {!Object}
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntries.toObject = function(includeInstance, msg) {
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.toObject = function(includeInstance, msg) {
   var f, obj = {
-    publicKeyEntriesList: jspb.Message.toObjectList(msg.getPublicKeyEntriesList(),
-    proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntry.toObject, includeInstance)
+    identityIdsList: msg.getIdentityIdsList_asB64(),
+    requestType: (f = msg.getRequestType()) && proto.org.dash.platform.dapi.v0.KeyRequestType.toObject(includeInstance, f),
+    limit: (f = msg.getLimit()) && google_protobuf_wrappers_pb.UInt32Value.toObject(includeInstance, f),
+    offset: (f = msg.getOffset()) && google_protobuf_wrappers_pb.UInt32Value.toObject(includeInstance, f),
+    prove: jspb.Message.getBooleanFieldWithDefault(msg, 5, false)
   };
 
   if (includeInstance) {
@@ -7263,23 +7436,23 @@ proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntries.toObj
 /**
  * Deserializes binary data (in protobuf wire format).
  * @param {jspb.ByteSource} bytes The bytes to deserialize.
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntries}
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest}
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntries.deserializeBinary = function(bytes) {
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.deserializeBinary = function(bytes) {
   var reader = new jspb.BinaryReader(bytes);
-  var msg = new proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntries;
-  return proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntries.deserializeBinaryFromReader(msg, reader);
+  var msg = new proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest;
+  return proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.deserializeBinaryFromReader(msg, reader);
 };
 
 
 /**
  * Deserializes binary data (in protobuf wire format) from the
  * given reader into the given message object.
- * @param {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntries} msg The message object to deserialize into.
+ * @param {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest} msg The message object to deserialize into.
  * @param {!jspb.BinaryReader} reader The BinaryReader to use.
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntries}
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest}
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntries.deserializeBinaryFromReader = function(msg, reader) {
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.deserializeBinaryFromReader = function(msg, reader) {
   while (reader.nextField()) {
     if (reader.isEndGroup()) {
       break;
@@ -7287,9 +7460,27 @@ proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntries.deser
     var field = reader.getFieldNumber();
     switch (field) {
     case 1:
-      var value = new proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntry;
-      reader.readMessage(value,proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntry.deserializeBinaryFromReader);
-      msg.addPublicKeyEntries(value);
+      var value = /** @type {!Uint8Array} */ (reader.readBytes());
+      msg.addIdentityIds(value);
+      break;
+    case 2:
+      var value = new proto.org.dash.platform.dapi.v0.KeyRequestType;
+      reader.readMessage(value,proto.org.dash.platform.dapi.v0.KeyRequestType.deserializeBinaryFromReader);
+      msg.setRequestType(value);
+      break;
+    case 3:
+      var value = new google_protobuf_wrappers_pb.UInt32Value;
+      reader.readMessage(value,google_protobuf_wrappers_pb.UInt32Value.deserializeBinaryFromReader);
+      msg.setLimit(value);
+      break;
+    case 4:
+      var value = new google_protobuf_wrappers_pb.UInt32Value;
+      reader.readMessage(value,google_protobuf_wrappers_pb.UInt32Value.deserializeBinaryFromReader);
+      msg.setOffset(value);
+      break;
+    case 5:
+      var value = /** @type {boolean} */ (reader.readBool());
+      msg.setProve(value);
       break;
     default:
       reader.skipField();
@@ -7304,9 +7495,9 @@ proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntries.deser
  * Serializes the message to binary data (in protobuf wire format).
  * @return {!Uint8Array}
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntries.prototype.serializeBinary = function() {
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.prototype.serializeBinary = function() {
   var writer = new jspb.BinaryWriter();
-  proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntries.serializeBinaryToWriter(this, writer);
+  proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.serializeBinaryToWriter(this, writer);
   return writer.getResultBuffer();
 };
 
@@ -7314,179 +7505,53 @@ proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntries.proto
 /**
  * Serializes the given message to binary data (in protobuf wire
  * format), writing to the given BinaryWriter.
- * @param {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntries} message
+ * @param {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest} message
  * @param {!jspb.BinaryWriter} writer
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntries.serializeBinaryToWriter = function(message, writer) {
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.serializeBinaryToWriter = function(message, writer) {
   var f = undefined;
-  f = message.getPublicKeyEntriesList();
+  f = message.getIdentityIdsList_asU8();
   if (f.length > 0) {
-    writer.writeRepeatedMessage(
+    writer.writeRepeatedBytes(
       1,
+      f
+    );
+  }
+  f = message.getRequestType();
+  if (f != null) {
+    writer.writeMessage(
+      2,
       f,
-      proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntry.serializeBinaryToWriter
+      proto.org.dash.platform.dapi.v0.KeyRequestType.serializeBinaryToWriter
+    );
+  }
+  f = message.getLimit();
+  if (f != null) {
+    writer.writeMessage(
+      3,
+      f,
+      google_protobuf_wrappers_pb.UInt32Value.serializeBinaryToWriter
+    );
+  }
+  f = message.getOffset();
+  if (f != null) {
+    writer.writeMessage(
+      4,
+      f,
+      google_protobuf_wrappers_pb.UInt32Value.serializeBinaryToWriter
+    );
+  }
+  f = message.getProve();
+  if (f) {
+    writer.writeBool(
+      5,
+      f
     );
   }
 };
 
 
-/**
- * repeated PublicKeyEntry public_key_entries = 1;
- * @return {!Array<!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntry>}
- */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntries.prototype.getPublicKeyEntriesList = function() {
-  return /** @type{!Array<!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntry>} */ (
-    jspb.Message.getRepeatedWrapperField(this, proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntry, 1));
-};
-
-
-/**
- * @param {!Array<!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntry>} value
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntries} returns this
-*/
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntries.prototype.setPublicKeyEntriesList = function(value) {
-  return jspb.Message.setRepeatedWrapperField(this, 1, value);
-};
-
-
-/**
- * @param {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntry=} opt_value
- * @param {number=} opt_index
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntry}
- */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntries.prototype.addPublicKeyEntries = function(opt_value, opt_index) {
-  return jspb.Message.addToRepeatedWrapperField(this, 1, opt_value, proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntry, opt_index);
-};
-
-
-/**
- * Clears the list making it empty but non-null.
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntries} returns this
- */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntries.prototype.clearPublicKeyEntriesList = function() {
-  return this.setPublicKeyEntriesList([]);
-};
-
-
-/**
- * optional PublicKeyEntries public_keys = 1;
- * @return {?proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntries}
- */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.prototype.getPublicKeys = function() {
-  return /** @type{?proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntries} */ (
-    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntries, 1));
-};
-
-
-/**
- * @param {?proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntries|undefined} value
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse} returns this
-*/
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.prototype.setPublicKeys = function(value) {
-  return jspb.Message.setOneofWrapperField(this, 1, proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.oneofGroups_[0], value);
-};
-
-
-/**
- * Clears the message field making it undefined.
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse} returns this
- */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.prototype.clearPublicKeys = function() {
-  return this.setPublicKeys(undefined);
-};
-
-
-/**
- * Returns whether this field is set.
- * @return {boolean}
- */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.prototype.hasPublicKeys = function() {
-  return jspb.Message.getField(this, 1) != null;
-};
-
-
-/**
- * optional Proof proof = 2;
- * @return {?proto.org.dash.platform.dapi.v0.Proof}
- */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.prototype.getProof = function() {
-  return /** @type{?proto.org.dash.platform.dapi.v0.Proof} */ (
-    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.Proof, 2));
-};
-
-
-/**
- * @param {?proto.org.dash.platform.dapi.v0.Proof|undefined} value
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse} returns this
-*/
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.prototype.setProof = function(value) {
-  return jspb.Message.setOneofWrapperField(this, 2, proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.oneofGroups_[0], value);
-};
-
-
-/**
- * Clears the message field making it undefined.
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse} returns this
- */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.prototype.clearProof = function() {
-  return this.setProof(undefined);
-};
-
-
-/**
- * Returns whether this field is set.
- * @return {boolean}
- */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.prototype.hasProof = function() {
-  return jspb.Message.getField(this, 2) != null;
-};
-
-
-/**
- * optional ResponseMetadata metadata = 3;
- * @return {?proto.org.dash.platform.dapi.v0.ResponseMetadata}
- */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.prototype.getMetadata = function() {
-  return /** @type{?proto.org.dash.platform.dapi.v0.ResponseMetadata} */ (
-    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.ResponseMetadata, 3));
-};
-
-
-/**
- * @param {?proto.org.dash.platform.dapi.v0.ResponseMetadata|undefined} value
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse} returns this
-*/
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.prototype.setMetadata = function(value) {
-  return jspb.Message.setWrapperField(this, 3, value);
-};
-
-
-/**
- * Clears the message field making it undefined.
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse} returns this
- */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.prototype.clearMetadata = function() {
-  return this.setMetadata(undefined);
-};
-
-
-/**
- * Returns whether this field is set.
- * @return {boolean}
- */
-proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.prototype.hasMetadata = function() {
-  return jspb.Message.getField(this, 3) != null;
-};
-
-
-
-/**
- * List of repeated fields within this message type.
- * @private {!Array<number>}
- * @const
- */
-proto.org.dash.platform.dapi.v0.GetProofsRequest.repeatedFields_ = [1,2,3];
 
 
 
@@ -7503,8 +7568,8 @@ if (jspb.Message.GENERATE_TO_OBJECT) {
  *     http://goto/soy-param-migration
  * @return {!Object}
  */
-proto.org.dash.platform.dapi.v0.GetProofsRequest.prototype.toObject = function(opt_includeInstance) {
-  return proto.org.dash.platform.dapi.v0.GetProofsRequest.toObject(opt_includeInstance, this);
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.SecurityLevelMap.prototype.toObject = function(opt_includeInstance) {
+  return proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.SecurityLevelMap.toObject(opt_includeInstance, this);
 };
 
 
@@ -7513,18 +7578,13 @@ proto.org.dash.platform.dapi.v0.GetProofsRequest.prototype.toObject = function(o
  * @param {boolean|undefined} includeInstance Deprecated. Whether to include
  *     the JSPB instance for transitional soy proto support:
  *     http://goto/soy-param-migration
- * @param {!proto.org.dash.platform.dapi.v0.GetProofsRequest} msg The msg instance to transform.
+ * @param {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.SecurityLevelMap} msg The msg instance to transform.
  * @return {!Object}
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.org.dash.platform.dapi.v0.GetProofsRequest.toObject = function(includeInstance, msg) {
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.SecurityLevelMap.toObject = function(includeInstance, msg) {
   var f, obj = {
-    identitiesList: jspb.Message.toObjectList(msg.getIdentitiesList(),
-    proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest.toObject, includeInstance),
-    contractsList: jspb.Message.toObjectList(msg.getContractsList(),
-    proto.org.dash.platform.dapi.v0.GetProofsRequest.ContractRequest.toObject, includeInstance),
-    documentsList: jspb.Message.toObjectList(msg.getDocumentsList(),
-    proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest.toObject, includeInstance)
+    securityLevelMapMap: (f = msg.getSecurityLevelMapMap()) ? f.toObject(includeInstance, undefined) : []
   };
 
   if (includeInstance) {
@@ -7538,23 +7598,23 @@ proto.org.dash.platform.dapi.v0.GetProofsRequest.toObject = function(includeInst
 /**
  * Deserializes binary data (in protobuf wire format).
  * @param {jspb.ByteSource} bytes The bytes to deserialize.
- * @return {!proto.org.dash.platform.dapi.v0.GetProofsRequest}
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.SecurityLevelMap}
  */
-proto.org.dash.platform.dapi.v0.GetProofsRequest.deserializeBinary = function(bytes) {
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.SecurityLevelMap.deserializeBinary = function(bytes) {
   var reader = new jspb.BinaryReader(bytes);
-  var msg = new proto.org.dash.platform.dapi.v0.GetProofsRequest;
-  return proto.org.dash.platform.dapi.v0.GetProofsRequest.deserializeBinaryFromReader(msg, reader);
+  var msg = new proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.SecurityLevelMap;
+  return proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.SecurityLevelMap.deserializeBinaryFromReader(msg, reader);
 };
 
 
 /**
  * Deserializes binary data (in protobuf wire format) from the
  * given reader into the given message object.
- * @param {!proto.org.dash.platform.dapi.v0.GetProofsRequest} msg The message object to deserialize into.
+ * @param {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.SecurityLevelMap} msg The message object to deserialize into.
  * @param {!jspb.BinaryReader} reader The BinaryReader to use.
- * @return {!proto.org.dash.platform.dapi.v0.GetProofsRequest}
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.SecurityLevelMap}
  */
-proto.org.dash.platform.dapi.v0.GetProofsRequest.deserializeBinaryFromReader = function(msg, reader) {
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.SecurityLevelMap.deserializeBinaryFromReader = function(msg, reader) {
   while (reader.nextField()) {
     if (reader.isEndGroup()) {
       break;
@@ -7562,19 +7622,10 @@ proto.org.dash.platform.dapi.v0.GetProofsRequest.deserializeBinaryFromReader = f
     var field = reader.getFieldNumber();
     switch (field) {
     case 1:
-      var value = new proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest;
-      reader.readMessage(value,proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest.deserializeBinaryFromReader);
-      msg.addIdentities(value);
-      break;
-    case 2:
-      var value = new proto.org.dash.platform.dapi.v0.GetProofsRequest.ContractRequest;
-      reader.readMessage(value,proto.org.dash.platform.dapi.v0.GetProofsRequest.ContractRequest.deserializeBinaryFromReader);
-      msg.addContracts(value);
-      break;
-    case 3:
-      var value = new proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest;
-      reader.readMessage(value,proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest.deserializeBinaryFromReader);
-      msg.addDocuments(value);
+      var value = msg.getSecurityLevelMapMap();
+      reader.readMessage(value, function(message, reader) {
+        jspb.Map.deserializeBinary(message, reader, jspb.BinaryReader.prototype.readUint32, jspb.BinaryReader.prototype.readEnum, null, 0, 0);
+         });
       break;
     default:
       reader.skipField();
@@ -7589,9 +7640,9 @@ proto.org.dash.platform.dapi.v0.GetProofsRequest.deserializeBinaryFromReader = f
  * Serializes the message to binary data (in protobuf wire format).
  * @return {!Uint8Array}
  */
-proto.org.dash.platform.dapi.v0.GetProofsRequest.prototype.serializeBinary = function() {
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.SecurityLevelMap.prototype.serializeBinary = function() {
   var writer = new jspb.BinaryWriter();
-  proto.org.dash.platform.dapi.v0.GetProofsRequest.serializeBinaryToWriter(this, writer);
+  proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.SecurityLevelMap.serializeBinaryToWriter(this, writer);
   return writer.getResultBuffer();
 };
 
@@ -7599,48 +7650,2990 @@ proto.org.dash.platform.dapi.v0.GetProofsRequest.prototype.serializeBinary = fun
 /**
  * Serializes the given message to binary data (in protobuf wire
  * format), writing to the given BinaryWriter.
- * @param {!proto.org.dash.platform.dapi.v0.GetProofsRequest} message
+ * @param {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.SecurityLevelMap} message
  * @param {!jspb.BinaryWriter} writer
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.org.dash.platform.dapi.v0.GetProofsRequest.serializeBinaryToWriter = function(message, writer) {
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.SecurityLevelMap.serializeBinaryToWriter = function(message, writer) {
   var f = undefined;
-  f = message.getIdentitiesList();
-  if (f.length > 0) {
-    writer.writeRepeatedMessage(
-      1,
-      f,
-      proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest.serializeBinaryToWriter
-    );
-  }
-  f = message.getContractsList();
-  if (f.length > 0) {
-    writer.writeRepeatedMessage(
-      2,
-      f,
-      proto.org.dash.platform.dapi.v0.GetProofsRequest.ContractRequest.serializeBinaryToWriter
-    );
-  }
-  f = message.getDocumentsList();
-  if (f.length > 0) {
-    writer.writeRepeatedMessage(
-      3,
-      f,
-      proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest.serializeBinaryToWriter
-    );
+  f = message.getSecurityLevelMapMap(true);
+  if (f && f.getLength() > 0) {
+    f.serializeBinary(1, writer, jspb.BinaryWriter.prototype.writeUint32, jspb.BinaryWriter.prototype.writeEnum);
   }
 };
 
 
+/**
+ * @enum {number}
+ */
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.SecurityLevelMap.KeyKindRequestType = {
+  CURRENT_KEY_OF_KIND_REQUEST: 0
+};
 
-
-
-if (jspb.Message.GENERATE_TO_OBJECT) {
 /**
- * Creates an object representation of this proto.
- * Field names that are reserved in JavaScript and will be renamed to pb_name.
- * Optional fields that are not set will be set to undefined.
- * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * map<uint32, KeyKindRequestType> security_level_map = 1;
+ * @param {boolean=} opt_noLazyCreate Do not create the map if
+ * empty, instead returning `undefined`
+ * @return {!jspb.Map<number,!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.SecurityLevelMap.KeyKindRequestType>}
+ */
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.SecurityLevelMap.prototype.getSecurityLevelMapMap = function(opt_noLazyCreate) {
+  return /** @type {!jspb.Map<number,!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.SecurityLevelMap.KeyKindRequestType>} */ (
+      jspb.Message.getMapField(this, 1, opt_noLazyCreate,
+      null));
+};
+
+
+/**
+ * Clears values from the map. The map will be non-null.
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.SecurityLevelMap} returns this
+ */
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.SecurityLevelMap.prototype.clearSecurityLevelMapMap = function() {
+  this.getSecurityLevelMapMap().clear();
+  return this;};
+
+
+/**
+ * repeated bytes identity_ids = 1;
+ * @return {!Array<string>}
+ */
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.prototype.getIdentityIdsList = function() {
+  return /** @type {!Array<string>} */ (jspb.Message.getRepeatedField(this, 1));
+};
+
+
+/**
+ * repeated bytes identity_ids = 1;
+ * This is a type-conversion wrapper around `getIdentityIdsList()`
+ * @return {!Array<string>}
+ */
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.prototype.getIdentityIdsList_asB64 = function() {
+  return /** @type {!Array<string>} */ (jspb.Message.bytesListAsB64(
+      this.getIdentityIdsList()));
+};
+
+
+/**
+ * repeated bytes identity_ids = 1;
+ * Note that Uint8Array is not supported on all browsers.
+ * @see http://caniuse.com/Uint8Array
+ * This is a type-conversion wrapper around `getIdentityIdsList()`
+ * @return {!Array<!Uint8Array>}
+ */
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.prototype.getIdentityIdsList_asU8 = function() {
+  return /** @type {!Array<!Uint8Array>} */ (jspb.Message.bytesListAsU8(
+      this.getIdentityIdsList()));
+};
+
+
+/**
+ * @param {!(Array<!Uint8Array>|Array<string>)} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest} returns this
+ */
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.prototype.setIdentityIdsList = function(value) {
+  return jspb.Message.setField(this, 1, value || []);
+};
+
+
+/**
+ * @param {!(string|Uint8Array)} value
+ * @param {number=} opt_index
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest} returns this
+ */
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.prototype.addIdentityIds = function(value, opt_index) {
+  return jspb.Message.addToRepeatedField(this, 1, value, opt_index);
+};
+
+
+/**
+ * Clears the list making it empty but non-null.
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest} returns this
+ */
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.prototype.clearIdentityIdsList = function() {
+  return this.setIdentityIdsList([]);
+};
+
+
+/**
+ * optional KeyRequestType request_type = 2;
+ * @return {?proto.org.dash.platform.dapi.v0.KeyRequestType}
+ */
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.prototype.getRequestType = function() {
+  return /** @type{?proto.org.dash.platform.dapi.v0.KeyRequestType} */ (
+    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.KeyRequestType, 2));
+};
+
+
+/**
+ * @param {?proto.org.dash.platform.dapi.v0.KeyRequestType|undefined} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest} returns this
+*/
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.prototype.setRequestType = function(value) {
+  return jspb.Message.setWrapperField(this, 2, value);
+};
+
+
+/**
+ * Clears the message field making it undefined.
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest} returns this
+ */
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.prototype.clearRequestType = function() {
+  return this.setRequestType(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.prototype.hasRequestType = function() {
+  return jspb.Message.getField(this, 2) != null;
+};
+
+
+/**
+ * optional google.protobuf.UInt32Value limit = 3;
+ * @return {?proto.google.protobuf.UInt32Value}
+ */
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.prototype.getLimit = function() {
+  return /** @type{?proto.google.protobuf.UInt32Value} */ (
+    jspb.Message.getWrapperField(this, google_protobuf_wrappers_pb.UInt32Value, 3));
+};
+
+
+/**
+ * @param {?proto.google.protobuf.UInt32Value|undefined} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest} returns this
+*/
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.prototype.setLimit = function(value) {
+  return jspb.Message.setWrapperField(this, 3, value);
+};
+
+
+/**
+ * Clears the message field making it undefined.
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest} returns this
+ */
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.prototype.clearLimit = function() {
+  return this.setLimit(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.prototype.hasLimit = function() {
+  return jspb.Message.getField(this, 3) != null;
+};
+
+
+/**
+ * optional google.protobuf.UInt32Value offset = 4;
+ * @return {?proto.google.protobuf.UInt32Value}
+ */
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.prototype.getOffset = function() {
+  return /** @type{?proto.google.protobuf.UInt32Value} */ (
+    jspb.Message.getWrapperField(this, google_protobuf_wrappers_pb.UInt32Value, 4));
+};
+
+
+/**
+ * @param {?proto.google.protobuf.UInt32Value|undefined} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest} returns this
+*/
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.prototype.setOffset = function(value) {
+  return jspb.Message.setWrapperField(this, 4, value);
+};
+
+
+/**
+ * Clears the message field making it undefined.
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest} returns this
+ */
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.prototype.clearOffset = function() {
+  return this.setOffset(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.prototype.hasOffset = function() {
+  return jspb.Message.getField(this, 4) != null;
+};
+
+
+/**
+ * optional bool prove = 5;
+ * @return {boolean}
+ */
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.prototype.getProve = function() {
+  return /** @type {boolean} */ (jspb.Message.getBooleanFieldWithDefault(this, 5, false));
+};
+
+
+/**
+ * @param {boolean} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest} returns this
+ */
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.prototype.setProve = function(value) {
+  return jspb.Message.setProto3BooleanField(this, 5, value);
+};
+
+
+
+/**
+ * Oneof group definitions for this message. Each group defines the field
+ * numbers belonging to that group. When of these fields' value is set, all
+ * other fields in the group are cleared. During deserialization, if multiple
+ * fields are encountered for a group, only the last value seen will be kept.
+ * @private {!Array<!Array<number>>}
+ * @const
+ */
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.oneofGroups_ = [[1,2]];
+
+/**
+ * @enum {number}
+ */
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.ResultCase = {
+  RESULT_NOT_SET: 0,
+  PUBLIC_KEYS: 1,
+  PROOF: 2
+};
+
+/**
+ * @return {proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.ResultCase}
+ */
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.prototype.getResultCase = function() {
+  return /** @type {proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.ResultCase} */(jspb.Message.computeOneofCase(this, proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.oneofGroups_[0]));
+};
+
+
+
+if (jspb.Message.GENERATE_TO_OBJECT) {
+/**
+ * Creates an object representation of this proto.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * Optional fields that are not set will be set to undefined.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * For the list of reserved names please see:
+ *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
+ * @param {boolean=} opt_includeInstance Deprecated. whether to include the
+ *     JSPB instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @return {!Object}
+ */
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.prototype.toObject = function(opt_includeInstance) {
+  return proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.toObject(opt_includeInstance, this);
+};
+
+
+/**
+ * Static version of the {@see toObject} method.
+ * @param {boolean|undefined} includeInstance Deprecated. Whether to include
+ *     the JSPB instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @param {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse} msg The msg instance to transform.
+ * @return {!Object}
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.toObject = function(includeInstance, msg) {
+  var f, obj = {
+    publicKeys: (f = msg.getPublicKeys()) && proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntries.toObject(includeInstance, f),
+    proof: (f = msg.getProof()) && proto.org.dash.platform.dapi.v0.Proof.toObject(includeInstance, f),
+    metadata: (f = msg.getMetadata()) && proto.org.dash.platform.dapi.v0.ResponseMetadata.toObject(includeInstance, f)
+  };
+
+  if (includeInstance) {
+    obj.$jspbMessageInstance = msg;
+  }
+  return obj;
+};
+}
+
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse}
+ */
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.deserializeBinary = function(bytes) {
+  var reader = new jspb.BinaryReader(bytes);
+  var msg = new proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse;
+  return proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.deserializeBinaryFromReader(msg, reader);
+};
+
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse}
+ */
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.deserializeBinaryFromReader = function(msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+    case 1:
+      var value = new proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntries;
+      reader.readMessage(value,proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntries.deserializeBinaryFromReader);
+      msg.setPublicKeys(value);
+      break;
+    case 2:
+      var value = new proto.org.dash.platform.dapi.v0.Proof;
+      reader.readMessage(value,proto.org.dash.platform.dapi.v0.Proof.deserializeBinaryFromReader);
+      msg.setProof(value);
+      break;
+    case 3:
+      var value = new proto.org.dash.platform.dapi.v0.ResponseMetadata;
+      reader.readMessage(value,proto.org.dash.platform.dapi.v0.ResponseMetadata.deserializeBinaryFromReader);
+      msg.setMetadata(value);
+      break;
+    default:
+      reader.skipField();
+      break;
+    }
+  }
+  return msg;
+};
+
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+  f = message.getPublicKeys();
+  if (f != null) {
+    writer.writeMessage(
+      1,
+      f,
+      proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntries.serializeBinaryToWriter
+    );
+  }
+  f = message.getProof();
+  if (f != null) {
+    writer.writeMessage(
+      2,
+      f,
+      proto.org.dash.platform.dapi.v0.Proof.serializeBinaryToWriter
+    );
+  }
+  f = message.getMetadata();
+  if (f != null) {
+    writer.writeMessage(
+      3,
+      f,
+      proto.org.dash.platform.dapi.v0.ResponseMetadata.serializeBinaryToWriter
+    );
+  }
+};
+
+
+
+
+
+if (jspb.Message.GENERATE_TO_OBJECT) {
+/**
+ * Creates an object representation of this proto.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * Optional fields that are not set will be set to undefined.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * For the list of reserved names please see:
+ *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
+ * @param {boolean=} opt_includeInstance Deprecated. whether to include the
+ *     JSPB instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @return {!Object}
+ */
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKey.prototype.toObject = function(opt_includeInstance) {
+  return proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKey.toObject(opt_includeInstance, this);
+};
+
+
+/**
+ * Static version of the {@see toObject} method.
+ * @param {boolean|undefined} includeInstance Deprecated. Whether to include
+ *     the JSPB instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @param {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKey} msg The msg instance to transform.
+ * @return {!Object}
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKey.toObject = function(includeInstance, msg) {
+  var f, obj = {
+    value: msg.getValue_asB64()
+  };
+
+  if (includeInstance) {
+    obj.$jspbMessageInstance = msg;
+  }
+  return obj;
+};
+}
+
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKey}
+ */
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKey.deserializeBinary = function(bytes) {
+  var reader = new jspb.BinaryReader(bytes);
+  var msg = new proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKey;
+  return proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKey.deserializeBinaryFromReader(msg, reader);
+};
+
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKey} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKey}
+ */
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKey.deserializeBinaryFromReader = function(msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+    case 1:
+      var value = /** @type {!Uint8Array} */ (reader.readBytes());
+      msg.setValue(value);
+      break;
+    default:
+      reader.skipField();
+      break;
+    }
+  }
+  return msg;
+};
+
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKey.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKey.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKey} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKey.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+  f = message.getValue_asU8();
+  if (f.length > 0) {
+    writer.writeBytes(
+      1,
+      f
+    );
+  }
+};
+
+
+/**
+ * optional bytes value = 1;
+ * @return {string}
+ */
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKey.prototype.getValue = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 1, ""));
+};
+
+
+/**
+ * optional bytes value = 1;
+ * This is a type-conversion wrapper around `getValue()`
+ * @return {string}
+ */
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKey.prototype.getValue_asB64 = function() {
+  return /** @type {string} */ (jspb.Message.bytesAsB64(
+      this.getValue()));
+};
+
+
+/**
+ * optional bytes value = 1;
+ * Note that Uint8Array is not supported on all browsers.
+ * @see http://caniuse.com/Uint8Array
+ * This is a type-conversion wrapper around `getValue()`
+ * @return {!Uint8Array}
+ */
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKey.prototype.getValue_asU8 = function() {
+  return /** @type {!Uint8Array} */ (jspb.Message.bytesAsU8(
+      this.getValue()));
+};
+
+
+/**
+ * @param {!(string|Uint8Array)} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKey} returns this
+ */
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKey.prototype.setValue = function(value) {
+  return jspb.Message.setProto3BytesField(this, 1, value);
+};
+
+
+
+
+
+if (jspb.Message.GENERATE_TO_OBJECT) {
+/**
+ * Creates an object representation of this proto.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * Optional fields that are not set will be set to undefined.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * For the list of reserved names please see:
+ *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
+ * @param {boolean=} opt_includeInstance Deprecated. whether to include the
+ *     JSPB instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @return {!Object}
+ */
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntry.prototype.toObject = function(opt_includeInstance) {
+  return proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntry.toObject(opt_includeInstance, this);
+};
+
+
+/**
+ * Static version of the {@see toObject} method.
+ * @param {boolean|undefined} includeInstance Deprecated. Whether to include
+ *     the JSPB instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @param {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntry} msg The msg instance to transform.
+ * @return {!Object}
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntry.toObject = function(includeInstance, msg) {
+  var f, obj = {
+    key: msg.getKey_asB64(),
+    value: (f = msg.getValue()) && proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKey.toObject(includeInstance, f)
+  };
+
+  if (includeInstance) {
+    obj.$jspbMessageInstance = msg;
+  }
+  return obj;
+};
+}
+
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntry}
+ */
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntry.deserializeBinary = function(bytes) {
+  var reader = new jspb.BinaryReader(bytes);
+  var msg = new proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntry;
+  return proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntry.deserializeBinaryFromReader(msg, reader);
+};
+
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntry} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntry}
+ */
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntry.deserializeBinaryFromReader = function(msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+    case 1:
+      var value = /** @type {!Uint8Array} */ (reader.readBytes());
+      msg.setKey(value);
+      break;
+    case 2:
+      var value = new proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKey;
+      reader.readMessage(value,proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKey.deserializeBinaryFromReader);
+      msg.setValue(value);
+      break;
+    default:
+      reader.skipField();
+      break;
+    }
+  }
+  return msg;
+};
+
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntry.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntry.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntry} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntry.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+  f = message.getKey_asU8();
+  if (f.length > 0) {
+    writer.writeBytes(
+      1,
+      f
+    );
+  }
+  f = message.getValue();
+  if (f != null) {
+    writer.writeMessage(
+      2,
+      f,
+      proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKey.serializeBinaryToWriter
+    );
+  }
+};
+
+
+/**
+ * optional bytes key = 1;
+ * @return {string}
+ */
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntry.prototype.getKey = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 1, ""));
+};
+
+
+/**
+ * optional bytes key = 1;
+ * This is a type-conversion wrapper around `getKey()`
+ * @return {string}
+ */
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntry.prototype.getKey_asB64 = function() {
+  return /** @type {string} */ (jspb.Message.bytesAsB64(
+      this.getKey()));
+};
+
+
+/**
+ * optional bytes key = 1;
+ * Note that Uint8Array is not supported on all browsers.
+ * @see http://caniuse.com/Uint8Array
+ * This is a type-conversion wrapper around `getKey()`
+ * @return {!Uint8Array}
+ */
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntry.prototype.getKey_asU8 = function() {
+  return /** @type {!Uint8Array} */ (jspb.Message.bytesAsU8(
+      this.getKey()));
+};
+
+
+/**
+ * @param {!(string|Uint8Array)} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntry} returns this
+ */
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntry.prototype.setKey = function(value) {
+  return jspb.Message.setProto3BytesField(this, 1, value);
+};
+
+
+/**
+ * optional PublicKey value = 2;
+ * @return {?proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKey}
+ */
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntry.prototype.getValue = function() {
+  return /** @type{?proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKey} */ (
+    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKey, 2));
+};
+
+
+/**
+ * @param {?proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKey|undefined} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntry} returns this
+*/
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntry.prototype.setValue = function(value) {
+  return jspb.Message.setWrapperField(this, 2, value);
+};
+
+
+/**
+ * Clears the message field making it undefined.
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntry} returns this
+ */
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntry.prototype.clearValue = function() {
+  return this.setValue(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntry.prototype.hasValue = function() {
+  return jspb.Message.getField(this, 2) != null;
+};
+
+
+
+/**
+ * List of repeated fields within this message type.
+ * @private {!Array<number>}
+ * @const
+ */
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntries.repeatedFields_ = [1];
+
+
+
+if (jspb.Message.GENERATE_TO_OBJECT) {
+/**
+ * Creates an object representation of this proto.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * Optional fields that are not set will be set to undefined.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * For the list of reserved names please see:
+ *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
+ * @param {boolean=} opt_includeInstance Deprecated. whether to include the
+ *     JSPB instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @return {!Object}
+ */
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntries.prototype.toObject = function(opt_includeInstance) {
+  return proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntries.toObject(opt_includeInstance, this);
+};
+
+
+/**
+ * Static version of the {@see toObject} method.
+ * @param {boolean|undefined} includeInstance Deprecated. Whether to include
+ *     the JSPB instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @param {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntries} msg The msg instance to transform.
+ * @return {!Object}
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntries.toObject = function(includeInstance, msg) {
+  var f, obj = {
+    publicKeyEntriesList: jspb.Message.toObjectList(msg.getPublicKeyEntriesList(),
+    proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntry.toObject, includeInstance)
+  };
+
+  if (includeInstance) {
+    obj.$jspbMessageInstance = msg;
+  }
+  return obj;
+};
+}
+
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntries}
+ */
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntries.deserializeBinary = function(bytes) {
+  var reader = new jspb.BinaryReader(bytes);
+  var msg = new proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntries;
+  return proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntries.deserializeBinaryFromReader(msg, reader);
+};
+
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntries} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntries}
+ */
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntries.deserializeBinaryFromReader = function(msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+    case 1:
+      var value = new proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntry;
+      reader.readMessage(value,proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntry.deserializeBinaryFromReader);
+      msg.addPublicKeyEntries(value);
+      break;
+    default:
+      reader.skipField();
+      break;
+    }
+  }
+  return msg;
+};
+
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntries.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntries.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntries} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntries.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+  f = message.getPublicKeyEntriesList();
+  if (f.length > 0) {
+    writer.writeRepeatedMessage(
+      1,
+      f,
+      proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntry.serializeBinaryToWriter
+    );
+  }
+};
+
+
+/**
+ * repeated PublicKeyEntry public_key_entries = 1;
+ * @return {!Array<!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntry>}
+ */
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntries.prototype.getPublicKeyEntriesList = function() {
+  return /** @type{!Array<!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntry>} */ (
+    jspb.Message.getRepeatedWrapperField(this, proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntry, 1));
+};
+
+
+/**
+ * @param {!Array<!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntry>} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntries} returns this
+*/
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntries.prototype.setPublicKeyEntriesList = function(value) {
+  return jspb.Message.setRepeatedWrapperField(this, 1, value);
+};
+
+
+/**
+ * @param {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntry=} opt_value
+ * @param {number=} opt_index
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntry}
+ */
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntries.prototype.addPublicKeyEntries = function(opt_value, opt_index) {
+  return jspb.Message.addToRepeatedWrapperField(this, 1, opt_value, proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntry, opt_index);
+};
+
+
+/**
+ * Clears the list making it empty but non-null.
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntries} returns this
+ */
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntries.prototype.clearPublicKeyEntriesList = function() {
+  return this.setPublicKeyEntriesList([]);
+};
+
+
+/**
+ * optional PublicKeyEntries public_keys = 1;
+ * @return {?proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntries}
+ */
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.prototype.getPublicKeys = function() {
+  return /** @type{?proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntries} */ (
+    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntries, 1));
+};
+
+
+/**
+ * @param {?proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntries|undefined} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse} returns this
+*/
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.prototype.setPublicKeys = function(value) {
+  return jspb.Message.setOneofWrapperField(this, 1, proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.oneofGroups_[0], value);
+};
+
+
+/**
+ * Clears the message field making it undefined.
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse} returns this
+ */
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.prototype.clearPublicKeys = function() {
+  return this.setPublicKeys(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.prototype.hasPublicKeys = function() {
+  return jspb.Message.getField(this, 1) != null;
+};
+
+
+/**
+ * optional Proof proof = 2;
+ * @return {?proto.org.dash.platform.dapi.v0.Proof}
+ */
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.prototype.getProof = function() {
+  return /** @type{?proto.org.dash.platform.dapi.v0.Proof} */ (
+    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.Proof, 2));
+};
+
+
+/**
+ * @param {?proto.org.dash.platform.dapi.v0.Proof|undefined} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse} returns this
+*/
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.prototype.setProof = function(value) {
+  return jspb.Message.setOneofWrapperField(this, 2, proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.oneofGroups_[0], value);
+};
+
+
+/**
+ * Clears the message field making it undefined.
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse} returns this
+ */
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.prototype.clearProof = function() {
+  return this.setProof(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.prototype.hasProof = function() {
+  return jspb.Message.getField(this, 2) != null;
+};
+
+
+/**
+ * optional ResponseMetadata metadata = 3;
+ * @return {?proto.org.dash.platform.dapi.v0.ResponseMetadata}
+ */
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.prototype.getMetadata = function() {
+  return /** @type{?proto.org.dash.platform.dapi.v0.ResponseMetadata} */ (
+    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.ResponseMetadata, 3));
+};
+
+
+/**
+ * @param {?proto.org.dash.platform.dapi.v0.ResponseMetadata|undefined} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse} returns this
+*/
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.prototype.setMetadata = function(value) {
+  return jspb.Message.setWrapperField(this, 3, value);
+};
+
+
+/**
+ * Clears the message field making it undefined.
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse} returns this
+ */
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.prototype.clearMetadata = function() {
+  return this.setMetadata(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.prototype.hasMetadata = function() {
+  return jspb.Message.getField(this, 3) != null;
+};
+
+
+
+/**
+ * List of repeated fields within this message type.
+ * @private {!Array<number>}
+ * @const
+ */
+proto.org.dash.platform.dapi.v0.GetProofsRequest.repeatedFields_ = [1,2,3];
+
+
+
+if (jspb.Message.GENERATE_TO_OBJECT) {
+/**
+ * Creates an object representation of this proto.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * Optional fields that are not set will be set to undefined.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * For the list of reserved names please see:
+ *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
+ * @param {boolean=} opt_includeInstance Deprecated. whether to include the
+ *     JSPB instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @return {!Object}
+ */
+proto.org.dash.platform.dapi.v0.GetProofsRequest.prototype.toObject = function(opt_includeInstance) {
+  return proto.org.dash.platform.dapi.v0.GetProofsRequest.toObject(opt_includeInstance, this);
+};
+
+
+/**
+ * Static version of the {@see toObject} method.
+ * @param {boolean|undefined} includeInstance Deprecated. Whether to include
+ *     the JSPB instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @param {!proto.org.dash.platform.dapi.v0.GetProofsRequest} msg The msg instance to transform.
+ * @return {!Object}
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.org.dash.platform.dapi.v0.GetProofsRequest.toObject = function(includeInstance, msg) {
+  var f, obj = {
+    identitiesList: jspb.Message.toObjectList(msg.getIdentitiesList(),
+    proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest.toObject, includeInstance),
+    contractsList: jspb.Message.toObjectList(msg.getContractsList(),
+    proto.org.dash.platform.dapi.v0.GetProofsRequest.ContractRequest.toObject, includeInstance),
+    documentsList: jspb.Message.toObjectList(msg.getDocumentsList(),
+    proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest.toObject, includeInstance)
+  };
+
+  if (includeInstance) {
+    obj.$jspbMessageInstance = msg;
+  }
+  return obj;
+};
+}
+
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.org.dash.platform.dapi.v0.GetProofsRequest}
+ */
+proto.org.dash.platform.dapi.v0.GetProofsRequest.deserializeBinary = function(bytes) {
+  var reader = new jspb.BinaryReader(bytes);
+  var msg = new proto.org.dash.platform.dapi.v0.GetProofsRequest;
+  return proto.org.dash.platform.dapi.v0.GetProofsRequest.deserializeBinaryFromReader(msg, reader);
+};
+
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.org.dash.platform.dapi.v0.GetProofsRequest} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.org.dash.platform.dapi.v0.GetProofsRequest}
+ */
+proto.org.dash.platform.dapi.v0.GetProofsRequest.deserializeBinaryFromReader = function(msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+    case 1:
+      var value = new proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest;
+      reader.readMessage(value,proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest.deserializeBinaryFromReader);
+      msg.addIdentities(value);
+      break;
+    case 2:
+      var value = new proto.org.dash.platform.dapi.v0.GetProofsRequest.ContractRequest;
+      reader.readMessage(value,proto.org.dash.platform.dapi.v0.GetProofsRequest.ContractRequest.deserializeBinaryFromReader);
+      msg.addContracts(value);
+      break;
+    case 3:
+      var value = new proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest;
+      reader.readMessage(value,proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest.deserializeBinaryFromReader);
+      msg.addDocuments(value);
+      break;
+    default:
+      reader.skipField();
+      break;
+    }
+  }
+  return msg;
+};
+
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.org.dash.platform.dapi.v0.GetProofsRequest.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.org.dash.platform.dapi.v0.GetProofsRequest.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.org.dash.platform.dapi.v0.GetProofsRequest} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.org.dash.platform.dapi.v0.GetProofsRequest.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+  f = message.getIdentitiesList();
+  if (f.length > 0) {
+    writer.writeRepeatedMessage(
+      1,
+      f,
+      proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest.serializeBinaryToWriter
+    );
+  }
+  f = message.getContractsList();
+  if (f.length > 0) {
+    writer.writeRepeatedMessage(
+      2,
+      f,
+      proto.org.dash.platform.dapi.v0.GetProofsRequest.ContractRequest.serializeBinaryToWriter
+    );
+  }
+  f = message.getDocumentsList();
+  if (f.length > 0) {
+    writer.writeRepeatedMessage(
+      3,
+      f,
+      proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest.serializeBinaryToWriter
+    );
+  }
+};
+
+
+
+
+
+if (jspb.Message.GENERATE_TO_OBJECT) {
+/**
+ * Creates an object representation of this proto.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * Optional fields that are not set will be set to undefined.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * For the list of reserved names please see:
+ *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
+ * @param {boolean=} opt_includeInstance Deprecated. whether to include the
+ *     JSPB instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @return {!Object}
+ */
+proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest.prototype.toObject = function(opt_includeInstance) {
+  return proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest.toObject(opt_includeInstance, this);
+};
+
+
+/**
+ * Static version of the {@see toObject} method.
+ * @param {boolean|undefined} includeInstance Deprecated. Whether to include
+ *     the JSPB instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @param {!proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest} msg The msg instance to transform.
+ * @return {!Object}
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest.toObject = function(includeInstance, msg) {
+  var f, obj = {
+    contractId: msg.getContractId_asB64(),
+    documentType: jspb.Message.getFieldWithDefault(msg, 2, ""),
+    documentTypeKeepsHistory: jspb.Message.getBooleanFieldWithDefault(msg, 3, false),
+    documentId: msg.getDocumentId_asB64()
+  };
+
+  if (includeInstance) {
+    obj.$jspbMessageInstance = msg;
+  }
+  return obj;
+};
+}
+
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest}
+ */
+proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest.deserializeBinary = function(bytes) {
+  var reader = new jspb.BinaryReader(bytes);
+  var msg = new proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest;
+  return proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest.deserializeBinaryFromReader(msg, reader);
+};
+
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest}
+ */
+proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest.deserializeBinaryFromReader = function(msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+    case 1:
+      var value = /** @type {!Uint8Array} */ (reader.readBytes());
+      msg.setContractId(value);
+      break;
+    case 2:
+      var value = /** @type {string} */ (reader.readString());
+      msg.setDocumentType(value);
+      break;
+    case 3:
+      var value = /** @type {boolean} */ (reader.readBool());
+      msg.setDocumentTypeKeepsHistory(value);
+      break;
+    case 4:
+      var value = /** @type {!Uint8Array} */ (reader.readBytes());
+      msg.setDocumentId(value);
+      break;
+    default:
+      reader.skipField();
+      break;
+    }
+  }
+  return msg;
+};
+
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+  f = message.getContractId_asU8();
+  if (f.length > 0) {
+    writer.writeBytes(
+      1,
+      f
+    );
+  }
+  f = message.getDocumentType();
+  if (f.length > 0) {
+    writer.writeString(
+      2,
+      f
+    );
+  }
+  f = message.getDocumentTypeKeepsHistory();
+  if (f) {
+    writer.writeBool(
+      3,
+      f
+    );
+  }
+  f = message.getDocumentId_asU8();
+  if (f.length > 0) {
+    writer.writeBytes(
+      4,
+      f
+    );
+  }
+};
+
+
+/**
+ * optional bytes contract_id = 1;
+ * @return {string}
+ */
+proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest.prototype.getContractId = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 1, ""));
+};
+
+
+/**
+ * optional bytes contract_id = 1;
+ * This is a type-conversion wrapper around `getContractId()`
+ * @return {string}
+ */
+proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest.prototype.getContractId_asB64 = function() {
+  return /** @type {string} */ (jspb.Message.bytesAsB64(
+      this.getContractId()));
+};
+
+
+/**
+ * optional bytes contract_id = 1;
+ * Note that Uint8Array is not supported on all browsers.
+ * @see http://caniuse.com/Uint8Array
+ * This is a type-conversion wrapper around `getContractId()`
+ * @return {!Uint8Array}
+ */
+proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest.prototype.getContractId_asU8 = function() {
+  return /** @type {!Uint8Array} */ (jspb.Message.bytesAsU8(
+      this.getContractId()));
+};
+
+
+/**
+ * @param {!(string|Uint8Array)} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest} returns this
+ */
+proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest.prototype.setContractId = function(value) {
+  return jspb.Message.setProto3BytesField(this, 1, value);
+};
+
+
+/**
+ * optional string document_type = 2;
+ * @return {string}
+ */
+proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest.prototype.getDocumentType = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 2, ""));
+};
+
+
+/**
+ * @param {string} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest} returns this
+ */
+proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest.prototype.setDocumentType = function(value) {
+  return jspb.Message.setProto3StringField(this, 2, value);
+};
+
+
+/**
+ * optional bool document_type_keeps_history = 3;
+ * @return {boolean}
+ */
+proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest.prototype.getDocumentTypeKeepsHistory = function() {
+  return /** @type {boolean} */ (jspb.Message.getBooleanFieldWithDefault(this, 3, false));
+};
+
+
+/**
+ * @param {boolean} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest} returns this
+ */
+proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest.prototype.setDocumentTypeKeepsHistory = function(value) {
+  return jspb.Message.setProto3BooleanField(this, 3, value);
+};
+
+
+/**
+ * optional bytes document_id = 4;
+ * @return {string}
+ */
+proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest.prototype.getDocumentId = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 4, ""));
+};
+
+
+/**
+ * optional bytes document_id = 4;
+ * This is a type-conversion wrapper around `getDocumentId()`
+ * @return {string}
+ */
+proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest.prototype.getDocumentId_asB64 = function() {
+  return /** @type {string} */ (jspb.Message.bytesAsB64(
+      this.getDocumentId()));
+};
+
+
+/**
+ * optional bytes document_id = 4;
+ * Note that Uint8Array is not supported on all browsers.
+ * @see http://caniuse.com/Uint8Array
+ * This is a type-conversion wrapper around `getDocumentId()`
+ * @return {!Uint8Array}
+ */
+proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest.prototype.getDocumentId_asU8 = function() {
+  return /** @type {!Uint8Array} */ (jspb.Message.bytesAsU8(
+      this.getDocumentId()));
+};
+
+
+/**
+ * @param {!(string|Uint8Array)} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest} returns this
+ */
+proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest.prototype.setDocumentId = function(value) {
+  return jspb.Message.setProto3BytesField(this, 4, value);
+};
+
+
+
+
+
+if (jspb.Message.GENERATE_TO_OBJECT) {
+/**
+ * Creates an object representation of this proto.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * Optional fields that are not set will be set to undefined.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * For the list of reserved names please see:
+ *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
+ * @param {boolean=} opt_includeInstance Deprecated. whether to include the
+ *     JSPB instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @return {!Object}
+ */
+proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest.prototype.toObject = function(opt_includeInstance) {
+  return proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest.toObject(opt_includeInstance, this);
+};
+
+
+/**
+ * Static version of the {@see toObject} method.
+ * @param {boolean|undefined} includeInstance Deprecated. Whether to include
+ *     the JSPB instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @param {!proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest} msg The msg instance to transform.
+ * @return {!Object}
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest.toObject = function(includeInstance, msg) {
+  var f, obj = {
+    identityId: msg.getIdentityId_asB64(),
+    requestType: jspb.Message.getFieldWithDefault(msg, 2, 0)
+  };
+
+  if (includeInstance) {
+    obj.$jspbMessageInstance = msg;
+  }
+  return obj;
+};
+}
+
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest}
+ */
+proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest.deserializeBinary = function(bytes) {
+  var reader = new jspb.BinaryReader(bytes);
+  var msg = new proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest;
+  return proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest.deserializeBinaryFromReader(msg, reader);
+};
+
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest}
+ */
+proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest.deserializeBinaryFromReader = function(msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+    case 1:
+      var value = /** @type {!Uint8Array} */ (reader.readBytes());
+      msg.setIdentityId(value);
+      break;
+    case 2:
+      var value = /** @type {!proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest.Type} */ (reader.readEnum());
+      msg.setRequestType(value);
+      break;
+    default:
+      reader.skipField();
+      break;
+    }
+  }
+  return msg;
+};
+
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+  f = message.getIdentityId_asU8();
+  if (f.length > 0) {
+    writer.writeBytes(
+      1,
+      f
+    );
+  }
+  f = message.getRequestType();
+  if (f !== 0.0) {
+    writer.writeEnum(
+      2,
+      f
+    );
+  }
+};
+
+
+/**
+ * @enum {number}
+ */
+proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest.Type = {
+  FULL_IDENTITY: 0,
+  BALANCE: 1,
+  KEYS: 2
+};
+
+/**
+ * optional bytes identity_id = 1;
+ * @return {string}
+ */
+proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest.prototype.getIdentityId = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 1, ""));
+};
+
+
+/**
+ * optional bytes identity_id = 1;
+ * This is a type-conversion wrapper around `getIdentityId()`
+ * @return {string}
+ */
+proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest.prototype.getIdentityId_asB64 = function() {
+  return /** @type {string} */ (jspb.Message.bytesAsB64(
+      this.getIdentityId()));
+};
+
+
+/**
+ * optional bytes identity_id = 1;
+ * Note that Uint8Array is not supported on all browsers.
+ * @see http://caniuse.com/Uint8Array
+ * This is a type-conversion wrapper around `getIdentityId()`
+ * @return {!Uint8Array}
+ */
+proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest.prototype.getIdentityId_asU8 = function() {
+  return /** @type {!Uint8Array} */ (jspb.Message.bytesAsU8(
+      this.getIdentityId()));
+};
+
+
+/**
+ * @param {!(string|Uint8Array)} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest} returns this
+ */
+proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest.prototype.setIdentityId = function(value) {
+  return jspb.Message.setProto3BytesField(this, 1, value);
+};
+
+
+/**
+ * optional Type request_type = 2;
+ * @return {!proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest.Type}
+ */
+proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest.prototype.getRequestType = function() {
+  return /** @type {!proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest.Type} */ (jspb.Message.getFieldWithDefault(this, 2, 0));
+};
+
+
+/**
+ * @param {!proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest.Type} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest} returns this
+ */
+proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest.prototype.setRequestType = function(value) {
+  return jspb.Message.setProto3EnumField(this, 2, value);
+};
+
+
+
+
+
+if (jspb.Message.GENERATE_TO_OBJECT) {
+/**
+ * Creates an object representation of this proto.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * Optional fields that are not set will be set to undefined.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * For the list of reserved names please see:
+ *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
+ * @param {boolean=} opt_includeInstance Deprecated. whether to include the
+ *     JSPB instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @return {!Object}
+ */
+proto.org.dash.platform.dapi.v0.GetProofsRequest.ContractRequest.prototype.toObject = function(opt_includeInstance) {
+  return proto.org.dash.platform.dapi.v0.GetProofsRequest.ContractRequest.toObject(opt_includeInstance, this);
+};
+
+
+/**
+ * Static version of the {@see toObject} method.
+ * @param {boolean|undefined} includeInstance Deprecated. Whether to include
+ *     the JSPB instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @param {!proto.org.dash.platform.dapi.v0.GetProofsRequest.ContractRequest} msg The msg instance to transform.
+ * @return {!Object}
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.org.dash.platform.dapi.v0.GetProofsRequest.ContractRequest.toObject = function(includeInstance, msg) {
+  var f, obj = {
+    contractId: msg.getContractId_asB64()
+  };
+
+  if (includeInstance) {
+    obj.$jspbMessageInstance = msg;
+  }
+  return obj;
+};
+}
+
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.org.dash.platform.dapi.v0.GetProofsRequest.ContractRequest}
+ */
+proto.org.dash.platform.dapi.v0.GetProofsRequest.ContractRequest.deserializeBinary = function(bytes) {
+  var reader = new jspb.BinaryReader(bytes);
+  var msg = new proto.org.dash.platform.dapi.v0.GetProofsRequest.ContractRequest;
+  return proto.org.dash.platform.dapi.v0.GetProofsRequest.ContractRequest.deserializeBinaryFromReader(msg, reader);
+};
+
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.org.dash.platform.dapi.v0.GetProofsRequest.ContractRequest} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.org.dash.platform.dapi.v0.GetProofsRequest.ContractRequest}
+ */
+proto.org.dash.platform.dapi.v0.GetProofsRequest.ContractRequest.deserializeBinaryFromReader = function(msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+    case 1:
+      var value = /** @type {!Uint8Array} */ (reader.readBytes());
+      msg.setContractId(value);
+      break;
+    default:
+      reader.skipField();
+      break;
+    }
+  }
+  return msg;
+};
+
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.org.dash.platform.dapi.v0.GetProofsRequest.ContractRequest.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.org.dash.platform.dapi.v0.GetProofsRequest.ContractRequest.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.org.dash.platform.dapi.v0.GetProofsRequest.ContractRequest} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.org.dash.platform.dapi.v0.GetProofsRequest.ContractRequest.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+  f = message.getContractId_asU8();
+  if (f.length > 0) {
+    writer.writeBytes(
+      1,
+      f
+    );
+  }
+};
+
+
+/**
+ * optional bytes contract_id = 1;
+ * @return {string}
+ */
+proto.org.dash.platform.dapi.v0.GetProofsRequest.ContractRequest.prototype.getContractId = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 1, ""));
+};
+
+
+/**
+ * optional bytes contract_id = 1;
+ * This is a type-conversion wrapper around `getContractId()`
+ * @return {string}
+ */
+proto.org.dash.platform.dapi.v0.GetProofsRequest.ContractRequest.prototype.getContractId_asB64 = function() {
+  return /** @type {string} */ (jspb.Message.bytesAsB64(
+      this.getContractId()));
+};
+
+
+/**
+ * optional bytes contract_id = 1;
+ * Note that Uint8Array is not supported on all browsers.
+ * @see http://caniuse.com/Uint8Array
+ * This is a type-conversion wrapper around `getContractId()`
+ * @return {!Uint8Array}
+ */
+proto.org.dash.platform.dapi.v0.GetProofsRequest.ContractRequest.prototype.getContractId_asU8 = function() {
+  return /** @type {!Uint8Array} */ (jspb.Message.bytesAsU8(
+      this.getContractId()));
+};
+
+
+/**
+ * @param {!(string|Uint8Array)} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetProofsRequest.ContractRequest} returns this
+ */
+proto.org.dash.platform.dapi.v0.GetProofsRequest.ContractRequest.prototype.setContractId = function(value) {
+  return jspb.Message.setProto3BytesField(this, 1, value);
+};
+
+
+/**
+ * repeated IdentityRequest identities = 1;
+ * @return {!Array<!proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest>}
+ */
+proto.org.dash.platform.dapi.v0.GetProofsRequest.prototype.getIdentitiesList = function() {
+  return /** @type{!Array<!proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest>} */ (
+    jspb.Message.getRepeatedWrapperField(this, proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest, 1));
+};
+
+
+/**
+ * @param {!Array<!proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest>} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetProofsRequest} returns this
+*/
+proto.org.dash.platform.dapi.v0.GetProofsRequest.prototype.setIdentitiesList = function(value) {
+  return jspb.Message.setRepeatedWrapperField(this, 1, value);
+};
+
+
+/**
+ * @param {!proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest=} opt_value
+ * @param {number=} opt_index
+ * @return {!proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest}
+ */
+proto.org.dash.platform.dapi.v0.GetProofsRequest.prototype.addIdentities = function(opt_value, opt_index) {
+  return jspb.Message.addToRepeatedWrapperField(this, 1, opt_value, proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest, opt_index);
+};
+
+
+/**
+ * Clears the list making it empty but non-null.
+ * @return {!proto.org.dash.platform.dapi.v0.GetProofsRequest} returns this
+ */
+proto.org.dash.platform.dapi.v0.GetProofsRequest.prototype.clearIdentitiesList = function() {
+  return this.setIdentitiesList([]);
+};
+
+
+/**
+ * repeated ContractRequest contracts = 2;
+ * @return {!Array<!proto.org.dash.platform.dapi.v0.GetProofsRequest.ContractRequest>}
+ */
+proto.org.dash.platform.dapi.v0.GetProofsRequest.prototype.getContractsList = function() {
+  return /** @type{!Array<!proto.org.dash.platform.dapi.v0.GetProofsRequest.ContractRequest>} */ (
+    jspb.Message.getRepeatedWrapperField(this, proto.org.dash.platform.dapi.v0.GetProofsRequest.ContractRequest, 2));
+};
+
+
+/**
+ * @param {!Array<!proto.org.dash.platform.dapi.v0.GetProofsRequest.ContractRequest>} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetProofsRequest} returns this
+*/
+proto.org.dash.platform.dapi.v0.GetProofsRequest.prototype.setContractsList = function(value) {
+  return jspb.Message.setRepeatedWrapperField(this, 2, value);
+};
+
+
+/**
+ * @param {!proto.org.dash.platform.dapi.v0.GetProofsRequest.ContractRequest=} opt_value
+ * @param {number=} opt_index
+ * @return {!proto.org.dash.platform.dapi.v0.GetProofsRequest.ContractRequest}
+ */
+proto.org.dash.platform.dapi.v0.GetProofsRequest.prototype.addContracts = function(opt_value, opt_index) {
+  return jspb.Message.addToRepeatedWrapperField(this, 2, opt_value, proto.org.dash.platform.dapi.v0.GetProofsRequest.ContractRequest, opt_index);
+};
+
+
+/**
+ * Clears the list making it empty but non-null.
+ * @return {!proto.org.dash.platform.dapi.v0.GetProofsRequest} returns this
+ */
+proto.org.dash.platform.dapi.v0.GetProofsRequest.prototype.clearContractsList = function() {
+  return this.setContractsList([]);
+};
+
+
+/**
+ * repeated DocumentRequest documents = 3;
+ * @return {!Array<!proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest>}
+ */
+proto.org.dash.platform.dapi.v0.GetProofsRequest.prototype.getDocumentsList = function() {
+  return /** @type{!Array<!proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest>} */ (
+    jspb.Message.getRepeatedWrapperField(this, proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest, 3));
+};
+
+
+/**
+ * @param {!Array<!proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest>} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetProofsRequest} returns this
+*/
+proto.org.dash.platform.dapi.v0.GetProofsRequest.prototype.setDocumentsList = function(value) {
+  return jspb.Message.setRepeatedWrapperField(this, 3, value);
+};
+
+
+/**
+ * @param {!proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest=} opt_value
+ * @param {number=} opt_index
+ * @return {!proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest}
+ */
+proto.org.dash.platform.dapi.v0.GetProofsRequest.prototype.addDocuments = function(opt_value, opt_index) {
+  return jspb.Message.addToRepeatedWrapperField(this, 3, opt_value, proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest, opt_index);
+};
+
+
+/**
+ * Clears the list making it empty but non-null.
+ * @return {!proto.org.dash.platform.dapi.v0.GetProofsRequest} returns this
+ */
+proto.org.dash.platform.dapi.v0.GetProofsRequest.prototype.clearDocumentsList = function() {
+  return this.setDocumentsList([]);
+};
+
+
+
+
+
+if (jspb.Message.GENERATE_TO_OBJECT) {
+/**
+ * Creates an object representation of this proto.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * Optional fields that are not set will be set to undefined.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * For the list of reserved names please see:
+ *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
+ * @param {boolean=} opt_includeInstance Deprecated. whether to include the
+ *     JSPB instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @return {!Object}
+ */
+proto.org.dash.platform.dapi.v0.GetProofsResponse.prototype.toObject = function(opt_includeInstance) {
+  return proto.org.dash.platform.dapi.v0.GetProofsResponse.toObject(opt_includeInstance, this);
+};
+
+
+/**
+ * Static version of the {@see toObject} method.
+ * @param {boolean|undefined} includeInstance Deprecated. Whether to include
+ *     the JSPB instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @param {!proto.org.dash.platform.dapi.v0.GetProofsResponse} msg The msg instance to transform.
+ * @return {!Object}
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.org.dash.platform.dapi.v0.GetProofsResponse.toObject = function(includeInstance, msg) {
+  var f, obj = {
+    proof: (f = msg.getProof()) && proto.org.dash.platform.dapi.v0.Proof.toObject(includeInstance, f),
+    metadata: (f = msg.getMetadata()) && proto.org.dash.platform.dapi.v0.ResponseMetadata.toObject(includeInstance, f)
+  };
+
+  if (includeInstance) {
+    obj.$jspbMessageInstance = msg;
+  }
+  return obj;
+};
+}
+
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.org.dash.platform.dapi.v0.GetProofsResponse}
+ */
+proto.org.dash.platform.dapi.v0.GetProofsResponse.deserializeBinary = function(bytes) {
+  var reader = new jspb.BinaryReader(bytes);
+  var msg = new proto.org.dash.platform.dapi.v0.GetProofsResponse;
+  return proto.org.dash.platform.dapi.v0.GetProofsResponse.deserializeBinaryFromReader(msg, reader);
+};
+
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.org.dash.platform.dapi.v0.GetProofsResponse} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.org.dash.platform.dapi.v0.GetProofsResponse}
+ */
+proto.org.dash.platform.dapi.v0.GetProofsResponse.deserializeBinaryFromReader = function(msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+    case 1:
+      var value = new proto.org.dash.platform.dapi.v0.Proof;
+      reader.readMessage(value,proto.org.dash.platform.dapi.v0.Proof.deserializeBinaryFromReader);
+      msg.setProof(value);
+      break;
+    case 2:
+      var value = new proto.org.dash.platform.dapi.v0.ResponseMetadata;
+      reader.readMessage(value,proto.org.dash.platform.dapi.v0.ResponseMetadata.deserializeBinaryFromReader);
+      msg.setMetadata(value);
+      break;
+    default:
+      reader.skipField();
+      break;
+    }
+  }
+  return msg;
+};
+
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.org.dash.platform.dapi.v0.GetProofsResponse.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.org.dash.platform.dapi.v0.GetProofsResponse.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.org.dash.platform.dapi.v0.GetProofsResponse} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.org.dash.platform.dapi.v0.GetProofsResponse.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+  f = message.getProof();
+  if (f != null) {
+    writer.writeMessage(
+      1,
+      f,
+      proto.org.dash.platform.dapi.v0.Proof.serializeBinaryToWriter
+    );
+  }
+  f = message.getMetadata();
+  if (f != null) {
+    writer.writeMessage(
+      2,
+      f,
+      proto.org.dash.platform.dapi.v0.ResponseMetadata.serializeBinaryToWriter
+    );
+  }
+};
+
+
+/**
+ * optional Proof proof = 1;
+ * @return {?proto.org.dash.platform.dapi.v0.Proof}
+ */
+proto.org.dash.platform.dapi.v0.GetProofsResponse.prototype.getProof = function() {
+  return /** @type{?proto.org.dash.platform.dapi.v0.Proof} */ (
+    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.Proof, 1));
+};
+
+
+/**
+ * @param {?proto.org.dash.platform.dapi.v0.Proof|undefined} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetProofsResponse} returns this
+*/
+proto.org.dash.platform.dapi.v0.GetProofsResponse.prototype.setProof = function(value) {
+  return jspb.Message.setWrapperField(this, 1, value);
+};
+
+
+/**
+ * Clears the message field making it undefined.
+ * @return {!proto.org.dash.platform.dapi.v0.GetProofsResponse} returns this
+ */
+proto.org.dash.platform.dapi.v0.GetProofsResponse.prototype.clearProof = function() {
+  return this.setProof(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.org.dash.platform.dapi.v0.GetProofsResponse.prototype.hasProof = function() {
+  return jspb.Message.getField(this, 1) != null;
+};
+
+
+/**
+ * optional ResponseMetadata metadata = 2;
+ * @return {?proto.org.dash.platform.dapi.v0.ResponseMetadata}
+ */
+proto.org.dash.platform.dapi.v0.GetProofsResponse.prototype.getMetadata = function() {
+  return /** @type{?proto.org.dash.platform.dapi.v0.ResponseMetadata} */ (
+    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.ResponseMetadata, 2));
+};
+
+
+/**
+ * @param {?proto.org.dash.platform.dapi.v0.ResponseMetadata|undefined} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetProofsResponse} returns this
+*/
+proto.org.dash.platform.dapi.v0.GetProofsResponse.prototype.setMetadata = function(value) {
+  return jspb.Message.setWrapperField(this, 2, value);
+};
+
+
+/**
+ * Clears the message field making it undefined.
+ * @return {!proto.org.dash.platform.dapi.v0.GetProofsResponse} returns this
+ */
+proto.org.dash.platform.dapi.v0.GetProofsResponse.prototype.clearMetadata = function() {
+  return this.setMetadata(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.org.dash.platform.dapi.v0.GetProofsResponse.prototype.hasMetadata = function() {
+  return jspb.Message.getField(this, 2) != null;
+};
+
+
+
+
+
+if (jspb.Message.GENERATE_TO_OBJECT) {
+/**
+ * Creates an object representation of this proto.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * Optional fields that are not set will be set to undefined.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * For the list of reserved names please see:
+ *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
+ * @param {boolean=} opt_includeInstance Deprecated. whether to include the
+ *     JSPB instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @return {!Object}
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractRequest.prototype.toObject = function(opt_includeInstance) {
+  return proto.org.dash.platform.dapi.v0.GetDataContractRequest.toObject(opt_includeInstance, this);
+};
+
+
+/**
+ * Static version of the {@see toObject} method.
+ * @param {boolean|undefined} includeInstance Deprecated. Whether to include
+ *     the JSPB instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @param {!proto.org.dash.platform.dapi.v0.GetDataContractRequest} msg The msg instance to transform.
+ * @return {!Object}
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractRequest.toObject = function(includeInstance, msg) {
+  var f, obj = {
+    id: msg.getId_asB64(),
+    prove: jspb.Message.getBooleanFieldWithDefault(msg, 2, false)
+  };
+
+  if (includeInstance) {
+    obj.$jspbMessageInstance = msg;
+  }
+  return obj;
+};
+}
+
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.org.dash.platform.dapi.v0.GetDataContractRequest}
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractRequest.deserializeBinary = function(bytes) {
+  var reader = new jspb.BinaryReader(bytes);
+  var msg = new proto.org.dash.platform.dapi.v0.GetDataContractRequest;
+  return proto.org.dash.platform.dapi.v0.GetDataContractRequest.deserializeBinaryFromReader(msg, reader);
+};
+
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.org.dash.platform.dapi.v0.GetDataContractRequest} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.org.dash.platform.dapi.v0.GetDataContractRequest}
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractRequest.deserializeBinaryFromReader = function(msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+    case 1:
+      var value = /** @type {!Uint8Array} */ (reader.readBytes());
+      msg.setId(value);
+      break;
+    case 2:
+      var value = /** @type {boolean} */ (reader.readBool());
+      msg.setProve(value);
+      break;
+    default:
+      reader.skipField();
+      break;
+    }
+  }
+  return msg;
+};
+
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractRequest.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.org.dash.platform.dapi.v0.GetDataContractRequest.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.org.dash.platform.dapi.v0.GetDataContractRequest} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractRequest.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+  f = message.getId_asU8();
+  if (f.length > 0) {
+    writer.writeBytes(
+      1,
+      f
+    );
+  }
+  f = message.getProve();
+  if (f) {
+    writer.writeBool(
+      2,
+      f
+    );
+  }
+};
+
+
+/**
+ * optional bytes id = 1;
+ * @return {string}
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractRequest.prototype.getId = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 1, ""));
+};
+
+
+/**
+ * optional bytes id = 1;
+ * This is a type-conversion wrapper around `getId()`
+ * @return {string}
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractRequest.prototype.getId_asB64 = function() {
+  return /** @type {string} */ (jspb.Message.bytesAsB64(
+      this.getId()));
+};
+
+
+/**
+ * optional bytes id = 1;
+ * Note that Uint8Array is not supported on all browsers.
+ * @see http://caniuse.com/Uint8Array
+ * This is a type-conversion wrapper around `getId()`
+ * @return {!Uint8Array}
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractRequest.prototype.getId_asU8 = function() {
+  return /** @type {!Uint8Array} */ (jspb.Message.bytesAsU8(
+      this.getId()));
+};
+
+
+/**
+ * @param {!(string|Uint8Array)} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetDataContractRequest} returns this
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractRequest.prototype.setId = function(value) {
+  return jspb.Message.setProto3BytesField(this, 1, value);
+};
+
+
+/**
+ * optional bool prove = 2;
+ * @return {boolean}
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractRequest.prototype.getProve = function() {
+  return /** @type {boolean} */ (jspb.Message.getBooleanFieldWithDefault(this, 2, false));
+};
+
+
+/**
+ * @param {boolean} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetDataContractRequest} returns this
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractRequest.prototype.setProve = function(value) {
+  return jspb.Message.setProto3BooleanField(this, 2, value);
+};
+
+
+
+/**
+ * Oneof group definitions for this message. Each group defines the field
+ * numbers belonging to that group. When of these fields' value is set, all
+ * other fields in the group are cleared. During deserialization, if multiple
+ * fields are encountered for a group, only the last value seen will be kept.
+ * @private {!Array<!Array<number>>}
+ * @const
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractResponse.oneofGroups_ = [[1,2]];
+
+/**
+ * @enum {number}
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractResponse.ResultCase = {
+  RESULT_NOT_SET: 0,
+  DATA_CONTRACT: 1,
+  PROOF: 2
+};
+
+/**
+ * @return {proto.org.dash.platform.dapi.v0.GetDataContractResponse.ResultCase}
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractResponse.prototype.getResultCase = function() {
+  return /** @type {proto.org.dash.platform.dapi.v0.GetDataContractResponse.ResultCase} */(jspb.Message.computeOneofCase(this, proto.org.dash.platform.dapi.v0.GetDataContractResponse.oneofGroups_[0]));
+};
+
+
+
+if (jspb.Message.GENERATE_TO_OBJECT) {
+/**
+ * Creates an object representation of this proto.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * Optional fields that are not set will be set to undefined.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * For the list of reserved names please see:
+ *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
+ * @param {boolean=} opt_includeInstance Deprecated. whether to include the
+ *     JSPB instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @return {!Object}
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractResponse.prototype.toObject = function(opt_includeInstance) {
+  return proto.org.dash.platform.dapi.v0.GetDataContractResponse.toObject(opt_includeInstance, this);
+};
+
+
+/**
+ * Static version of the {@see toObject} method.
+ * @param {boolean|undefined} includeInstance Deprecated. Whether to include
+ *     the JSPB instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @param {!proto.org.dash.platform.dapi.v0.GetDataContractResponse} msg The msg instance to transform.
+ * @return {!Object}
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractResponse.toObject = function(includeInstance, msg) {
+  var f, obj = {
+    dataContract: msg.getDataContract_asB64(),
+    proof: (f = msg.getProof()) && proto.org.dash.platform.dapi.v0.Proof.toObject(includeInstance, f),
+    metadata: (f = msg.getMetadata()) && proto.org.dash.platform.dapi.v0.ResponseMetadata.toObject(includeInstance, f)
+  };
+
+  if (includeInstance) {
+    obj.$jspbMessageInstance = msg;
+  }
+  return obj;
+};
+}
+
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.org.dash.platform.dapi.v0.GetDataContractResponse}
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractResponse.deserializeBinary = function(bytes) {
+  var reader = new jspb.BinaryReader(bytes);
+  var msg = new proto.org.dash.platform.dapi.v0.GetDataContractResponse;
+  return proto.org.dash.platform.dapi.v0.GetDataContractResponse.deserializeBinaryFromReader(msg, reader);
+};
+
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.org.dash.platform.dapi.v0.GetDataContractResponse} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.org.dash.platform.dapi.v0.GetDataContractResponse}
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractResponse.deserializeBinaryFromReader = function(msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+    case 1:
+      var value = /** @type {!Uint8Array} */ (reader.readBytes());
+      msg.setDataContract(value);
+      break;
+    case 2:
+      var value = new proto.org.dash.platform.dapi.v0.Proof;
+      reader.readMessage(value,proto.org.dash.platform.dapi.v0.Proof.deserializeBinaryFromReader);
+      msg.setProof(value);
+      break;
+    case 3:
+      var value = new proto.org.dash.platform.dapi.v0.ResponseMetadata;
+      reader.readMessage(value,proto.org.dash.platform.dapi.v0.ResponseMetadata.deserializeBinaryFromReader);
+      msg.setMetadata(value);
+      break;
+    default:
+      reader.skipField();
+      break;
+    }
+  }
+  return msg;
+};
+
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractResponse.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.org.dash.platform.dapi.v0.GetDataContractResponse.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.org.dash.platform.dapi.v0.GetDataContractResponse} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractResponse.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+  f = /** @type {!(string|Uint8Array)} */ (jspb.Message.getField(message, 1));
+  if (f != null) {
+    writer.writeBytes(
+      1,
+      f
+    );
+  }
+  f = message.getProof();
+  if (f != null) {
+    writer.writeMessage(
+      2,
+      f,
+      proto.org.dash.platform.dapi.v0.Proof.serializeBinaryToWriter
+    );
+  }
+  f = message.getMetadata();
+  if (f != null) {
+    writer.writeMessage(
+      3,
+      f,
+      proto.org.dash.platform.dapi.v0.ResponseMetadata.serializeBinaryToWriter
+    );
+  }
+};
+
+
+/**
+ * optional bytes data_contract = 1;
+ * @return {string}
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractResponse.prototype.getDataContract = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 1, ""));
+};
+
+
+/**
+ * optional bytes data_contract = 1;
+ * This is a type-conversion wrapper around `getDataContract()`
+ * @return {string}
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractResponse.prototype.getDataContract_asB64 = function() {
+  return /** @type {string} */ (jspb.Message.bytesAsB64(
+      this.getDataContract()));
+};
+
+
+/**
+ * optional bytes data_contract = 1;
+ * Note that Uint8Array is not supported on all browsers.
+ * @see http://caniuse.com/Uint8Array
+ * This is a type-conversion wrapper around `getDataContract()`
+ * @return {!Uint8Array}
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractResponse.prototype.getDataContract_asU8 = function() {
+  return /** @type {!Uint8Array} */ (jspb.Message.bytesAsU8(
+      this.getDataContract()));
+};
+
+
+/**
+ * @param {!(string|Uint8Array)} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetDataContractResponse} returns this
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractResponse.prototype.setDataContract = function(value) {
+  return jspb.Message.setOneofField(this, 1, proto.org.dash.platform.dapi.v0.GetDataContractResponse.oneofGroups_[0], value);
+};
+
+
+/**
+ * Clears the field making it undefined.
+ * @return {!proto.org.dash.platform.dapi.v0.GetDataContractResponse} returns this
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractResponse.prototype.clearDataContract = function() {
+  return jspb.Message.setOneofField(this, 1, proto.org.dash.platform.dapi.v0.GetDataContractResponse.oneofGroups_[0], undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractResponse.prototype.hasDataContract = function() {
+  return jspb.Message.getField(this, 1) != null;
+};
+
+
+/**
+ * optional Proof proof = 2;
+ * @return {?proto.org.dash.platform.dapi.v0.Proof}
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractResponse.prototype.getProof = function() {
+  return /** @type{?proto.org.dash.platform.dapi.v0.Proof} */ (
+    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.Proof, 2));
+};
+
+
+/**
+ * @param {?proto.org.dash.platform.dapi.v0.Proof|undefined} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetDataContractResponse} returns this
+*/
+proto.org.dash.platform.dapi.v0.GetDataContractResponse.prototype.setProof = function(value) {
+  return jspb.Message.setOneofWrapperField(this, 2, proto.org.dash.platform.dapi.v0.GetDataContractResponse.oneofGroups_[0], value);
+};
+
+
+/**
+ * Clears the message field making it undefined.
+ * @return {!proto.org.dash.platform.dapi.v0.GetDataContractResponse} returns this
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractResponse.prototype.clearProof = function() {
+  return this.setProof(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractResponse.prototype.hasProof = function() {
+  return jspb.Message.getField(this, 2) != null;
+};
+
+
+/**
+ * optional ResponseMetadata metadata = 3;
+ * @return {?proto.org.dash.platform.dapi.v0.ResponseMetadata}
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractResponse.prototype.getMetadata = function() {
+  return /** @type{?proto.org.dash.platform.dapi.v0.ResponseMetadata} */ (
+    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.ResponseMetadata, 3));
+};
+
+
+/**
+ * @param {?proto.org.dash.platform.dapi.v0.ResponseMetadata|undefined} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetDataContractResponse} returns this
+*/
+proto.org.dash.platform.dapi.v0.GetDataContractResponse.prototype.setMetadata = function(value) {
+  return jspb.Message.setWrapperField(this, 3, value);
+};
+
+
+/**
+ * Clears the message field making it undefined.
+ * @return {!proto.org.dash.platform.dapi.v0.GetDataContractResponse} returns this
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractResponse.prototype.clearMetadata = function() {
+  return this.setMetadata(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractResponse.prototype.hasMetadata = function() {
+  return jspb.Message.getField(this, 3) != null;
+};
+
+
+
+/**
+ * List of repeated fields within this message type.
+ * @private {!Array<number>}
+ * @const
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractsRequest.repeatedFields_ = [1];
+
+
+
+if (jspb.Message.GENERATE_TO_OBJECT) {
+/**
+ * Creates an object representation of this proto.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * Optional fields that are not set will be set to undefined.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * For the list of reserved names please see:
+ *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
+ * @param {boolean=} opt_includeInstance Deprecated. whether to include the
+ *     JSPB instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @return {!Object}
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractsRequest.prototype.toObject = function(opt_includeInstance) {
+  return proto.org.dash.platform.dapi.v0.GetDataContractsRequest.toObject(opt_includeInstance, this);
+};
+
+
+/**
+ * Static version of the {@see toObject} method.
+ * @param {boolean|undefined} includeInstance Deprecated. Whether to include
+ *     the JSPB instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @param {!proto.org.dash.platform.dapi.v0.GetDataContractsRequest} msg The msg instance to transform.
+ * @return {!Object}
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractsRequest.toObject = function(includeInstance, msg) {
+  var f, obj = {
+    idsList: msg.getIdsList_asB64(),
+    prove: jspb.Message.getBooleanFieldWithDefault(msg, 2, false)
+  };
+
+  if (includeInstance) {
+    obj.$jspbMessageInstance = msg;
+  }
+  return obj;
+};
+}
+
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.org.dash.platform.dapi.v0.GetDataContractsRequest}
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractsRequest.deserializeBinary = function(bytes) {
+  var reader = new jspb.BinaryReader(bytes);
+  var msg = new proto.org.dash.platform.dapi.v0.GetDataContractsRequest;
+  return proto.org.dash.platform.dapi.v0.GetDataContractsRequest.deserializeBinaryFromReader(msg, reader);
+};
+
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.org.dash.platform.dapi.v0.GetDataContractsRequest} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.org.dash.platform.dapi.v0.GetDataContractsRequest}
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractsRequest.deserializeBinaryFromReader = function(msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+    case 1:
+      var value = /** @type {!Uint8Array} */ (reader.readBytes());
+      msg.addIds(value);
+      break;
+    case 2:
+      var value = /** @type {boolean} */ (reader.readBool());
+      msg.setProve(value);
+      break;
+    default:
+      reader.skipField();
+      break;
+    }
+  }
+  return msg;
+};
+
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractsRequest.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.org.dash.platform.dapi.v0.GetDataContractsRequest.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.org.dash.platform.dapi.v0.GetDataContractsRequest} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractsRequest.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+  f = message.getIdsList_asU8();
+  if (f.length > 0) {
+    writer.writeRepeatedBytes(
+      1,
+      f
+    );
+  }
+  f = message.getProve();
+  if (f) {
+    writer.writeBool(
+      2,
+      f
+    );
+  }
+};
+
+
+/**
+ * repeated bytes ids = 1;
+ * @return {!Array<string>}
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractsRequest.prototype.getIdsList = function() {
+  return /** @type {!Array<string>} */ (jspb.Message.getRepeatedField(this, 1));
+};
+
+
+/**
+ * repeated bytes ids = 1;
+ * This is a type-conversion wrapper around `getIdsList()`
+ * @return {!Array<string>}
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractsRequest.prototype.getIdsList_asB64 = function() {
+  return /** @type {!Array<string>} */ (jspb.Message.bytesListAsB64(
+      this.getIdsList()));
+};
+
+
+/**
+ * repeated bytes ids = 1;
+ * Note that Uint8Array is not supported on all browsers.
+ * @see http://caniuse.com/Uint8Array
+ * This is a type-conversion wrapper around `getIdsList()`
+ * @return {!Array<!Uint8Array>}
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractsRequest.prototype.getIdsList_asU8 = function() {
+  return /** @type {!Array<!Uint8Array>} */ (jspb.Message.bytesListAsU8(
+      this.getIdsList()));
+};
+
+
+/**
+ * @param {!(Array<!Uint8Array>|Array<string>)} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetDataContractsRequest} returns this
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractsRequest.prototype.setIdsList = function(value) {
+  return jspb.Message.setField(this, 1, value || []);
+};
+
+
+/**
+ * @param {!(string|Uint8Array)} value
+ * @param {number=} opt_index
+ * @return {!proto.org.dash.platform.dapi.v0.GetDataContractsRequest} returns this
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractsRequest.prototype.addIds = function(value, opt_index) {
+  return jspb.Message.addToRepeatedField(this, 1, value, opt_index);
+};
+
+
+/**
+ * Clears the list making it empty but non-null.
+ * @return {!proto.org.dash.platform.dapi.v0.GetDataContractsRequest} returns this
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractsRequest.prototype.clearIdsList = function() {
+  return this.setIdsList([]);
+};
+
+
+/**
+ * optional bool prove = 2;
+ * @return {boolean}
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractsRequest.prototype.getProve = function() {
+  return /** @type {boolean} */ (jspb.Message.getBooleanFieldWithDefault(this, 2, false));
+};
+
+
+/**
+ * @param {boolean} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetDataContractsRequest} returns this
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractsRequest.prototype.setProve = function(value) {
+  return jspb.Message.setProto3BooleanField(this, 2, value);
+};
+
+
+
+/**
+ * Oneof group definitions for this message. Each group defines the field
+ * numbers belonging to that group. When of these fields' value is set, all
+ * other fields in the group are cleared. During deserialization, if multiple
+ * fields are encountered for a group, only the last value seen will be kept.
+ * @private {!Array<!Array<number>>}
+ * @const
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractsResponse.oneofGroups_ = [[1,2]];
+
+/**
+ * @enum {number}
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractsResponse.ResultCase = {
+  RESULT_NOT_SET: 0,
+  DATA_CONTRACTS: 1,
+  PROOF: 2
+};
+
+/**
+ * @return {proto.org.dash.platform.dapi.v0.GetDataContractsResponse.ResultCase}
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractsResponse.prototype.getResultCase = function() {
+  return /** @type {proto.org.dash.platform.dapi.v0.GetDataContractsResponse.ResultCase} */(jspb.Message.computeOneofCase(this, proto.org.dash.platform.dapi.v0.GetDataContractsResponse.oneofGroups_[0]));
+};
+
+
+
+if (jspb.Message.GENERATE_TO_OBJECT) {
+/**
+ * Creates an object representation of this proto.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * Optional fields that are not set will be set to undefined.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * For the list of reserved names please see:
+ *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
+ * @param {boolean=} opt_includeInstance Deprecated. whether to include the
+ *     JSPB instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @return {!Object}
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractsResponse.prototype.toObject = function(opt_includeInstance) {
+  return proto.org.dash.platform.dapi.v0.GetDataContractsResponse.toObject(opt_includeInstance, this);
+};
+
+
+/**
+ * Static version of the {@see toObject} method.
+ * @param {boolean|undefined} includeInstance Deprecated. Whether to include
+ *     the JSPB instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @param {!proto.org.dash.platform.dapi.v0.GetDataContractsResponse} msg The msg instance to transform.
+ * @return {!Object}
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractsResponse.toObject = function(includeInstance, msg) {
+  var f, obj = {
+    dataContracts: (f = msg.getDataContracts()) && proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContracts.toObject(includeInstance, f),
+    proof: (f = msg.getProof()) && proto.org.dash.platform.dapi.v0.Proof.toObject(includeInstance, f),
+    metadata: (f = msg.getMetadata()) && proto.org.dash.platform.dapi.v0.ResponseMetadata.toObject(includeInstance, f)
+  };
+
+  if (includeInstance) {
+    obj.$jspbMessageInstance = msg;
+  }
+  return obj;
+};
+}
+
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.org.dash.platform.dapi.v0.GetDataContractsResponse}
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractsResponse.deserializeBinary = function(bytes) {
+  var reader = new jspb.BinaryReader(bytes);
+  var msg = new proto.org.dash.platform.dapi.v0.GetDataContractsResponse;
+  return proto.org.dash.platform.dapi.v0.GetDataContractsResponse.deserializeBinaryFromReader(msg, reader);
+};
+
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.org.dash.platform.dapi.v0.GetDataContractsResponse} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.org.dash.platform.dapi.v0.GetDataContractsResponse}
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractsResponse.deserializeBinaryFromReader = function(msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+    case 1:
+      var value = new proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContracts;
+      reader.readMessage(value,proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContracts.deserializeBinaryFromReader);
+      msg.setDataContracts(value);
+      break;
+    case 2:
+      var value = new proto.org.dash.platform.dapi.v0.Proof;
+      reader.readMessage(value,proto.org.dash.platform.dapi.v0.Proof.deserializeBinaryFromReader);
+      msg.setProof(value);
+      break;
+    case 3:
+      var value = new proto.org.dash.platform.dapi.v0.ResponseMetadata;
+      reader.readMessage(value,proto.org.dash.platform.dapi.v0.ResponseMetadata.deserializeBinaryFromReader);
+      msg.setMetadata(value);
+      break;
+    default:
+      reader.skipField();
+      break;
+    }
+  }
+  return msg;
+};
+
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractsResponse.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.org.dash.platform.dapi.v0.GetDataContractsResponse.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.org.dash.platform.dapi.v0.GetDataContractsResponse} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractsResponse.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+  f = message.getDataContracts();
+  if (f != null) {
+    writer.writeMessage(
+      1,
+      f,
+      proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContracts.serializeBinaryToWriter
+    );
+  }
+  f = message.getProof();
+  if (f != null) {
+    writer.writeMessage(
+      2,
+      f,
+      proto.org.dash.platform.dapi.v0.Proof.serializeBinaryToWriter
+    );
+  }
+  f = message.getMetadata();
+  if (f != null) {
+    writer.writeMessage(
+      3,
+      f,
+      proto.org.dash.platform.dapi.v0.ResponseMetadata.serializeBinaryToWriter
+    );
+  }
+};
+
+
+
+
+
+if (jspb.Message.GENERATE_TO_OBJECT) {
+/**
+ * Creates an object representation of this proto.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * Optional fields that are not set will be set to undefined.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
  * For the list of reserved names please see:
  *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
  * @param {boolean=} opt_includeInstance Deprecated. whether to include the
@@ -7648,8 +10641,8 @@ if (jspb.Message.GENERATE_TO_OBJECT) {
  *     http://goto/soy-param-migration
  * @return {!Object}
  */
-proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest.prototype.toObject = function(opt_includeInstance) {
-  return proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest.toObject(opt_includeInstance, this);
+proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractValue.prototype.toObject = function(opt_includeInstance) {
+  return proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractValue.toObject(opt_includeInstance, this);
 };
 
 
@@ -7658,16 +10651,13 @@ proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest.prototype.toObj
  * @param {boolean|undefined} includeInstance Deprecated. Whether to include
  *     the JSPB instance for transitional soy proto support:
  *     http://goto/soy-param-migration
- * @param {!proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest} msg The msg instance to transform.
+ * @param {!proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractValue} msg The msg instance to transform.
  * @return {!Object}
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest.toObject = function(includeInstance, msg) {
+proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractValue.toObject = function(includeInstance, msg) {
   var f, obj = {
-    contractId: msg.getContractId_asB64(),
-    documentType: jspb.Message.getFieldWithDefault(msg, 2, ""),
-    documentTypeKeepsHistory: jspb.Message.getBooleanFieldWithDefault(msg, 3, false),
-    documentId: msg.getDocumentId_asB64()
+    value: msg.getValue_asB64()
   };
 
   if (includeInstance) {
@@ -7681,23 +10671,23 @@ proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest.toObject = func
 /**
  * Deserializes binary data (in protobuf wire format).
  * @param {jspb.ByteSource} bytes The bytes to deserialize.
- * @return {!proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest}
+ * @return {!proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractValue}
  */
-proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest.deserializeBinary = function(bytes) {
+proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractValue.deserializeBinary = function(bytes) {
   var reader = new jspb.BinaryReader(bytes);
-  var msg = new proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest;
-  return proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest.deserializeBinaryFromReader(msg, reader);
+  var msg = new proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractValue;
+  return proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractValue.deserializeBinaryFromReader(msg, reader);
 };
 
 
 /**
  * Deserializes binary data (in protobuf wire format) from the
  * given reader into the given message object.
- * @param {!proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest} msg The message object to deserialize into.
+ * @param {!proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractValue} msg The message object to deserialize into.
  * @param {!jspb.BinaryReader} reader The BinaryReader to use.
- * @return {!proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest}
+ * @return {!proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractValue}
  */
-proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest.deserializeBinaryFromReader = function(msg, reader) {
+proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractValue.deserializeBinaryFromReader = function(msg, reader) {
   while (reader.nextField()) {
     if (reader.isEndGroup()) {
       break;
@@ -7706,19 +10696,167 @@ proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest.deserializeBina
     switch (field) {
     case 1:
       var value = /** @type {!Uint8Array} */ (reader.readBytes());
-      msg.setContractId(value);
+      msg.setValue(value);
       break;
-    case 2:
-      var value = /** @type {string} */ (reader.readString());
-      msg.setDocumentType(value);
+    default:
+      reader.skipField();
       break;
-    case 3:
-      var value = /** @type {boolean} */ (reader.readBool());
-      msg.setDocumentTypeKeepsHistory(value);
+    }
+  }
+  return msg;
+};
+
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractValue.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractValue.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractValue} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractValue.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+  f = message.getValue_asU8();
+  if (f.length > 0) {
+    writer.writeBytes(
+      1,
+      f
+    );
+  }
+};
+
+
+/**
+ * optional bytes value = 1;
+ * @return {string}
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractValue.prototype.getValue = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 1, ""));
+};
+
+
+/**
+ * optional bytes value = 1;
+ * This is a type-conversion wrapper around `getValue()`
+ * @return {string}
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractValue.prototype.getValue_asB64 = function() {
+  return /** @type {string} */ (jspb.Message.bytesAsB64(
+      this.getValue()));
+};
+
+
+/**
+ * optional bytes value = 1;
+ * Note that Uint8Array is not supported on all browsers.
+ * @see http://caniuse.com/Uint8Array
+ * This is a type-conversion wrapper around `getValue()`
+ * @return {!Uint8Array}
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractValue.prototype.getValue_asU8 = function() {
+  return /** @type {!Uint8Array} */ (jspb.Message.bytesAsU8(
+      this.getValue()));
+};
+
+
+/**
+ * @param {!(string|Uint8Array)} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractValue} returns this
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractValue.prototype.setValue = function(value) {
+  return jspb.Message.setProto3BytesField(this, 1, value);
+};
+
+
+
+
+
+if (jspb.Message.GENERATE_TO_OBJECT) {
+/**
+ * Creates an object representation of this proto.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * Optional fields that are not set will be set to undefined.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * For the list of reserved names please see:
+ *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
+ * @param {boolean=} opt_includeInstance Deprecated. whether to include the
+ *     JSPB instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @return {!Object}
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntry.prototype.toObject = function(opt_includeInstance) {
+  return proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntry.toObject(opt_includeInstance, this);
+};
+
+
+/**
+ * Static version of the {@see toObject} method.
+ * @param {boolean|undefined} includeInstance Deprecated. Whether to include
+ *     the JSPB instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @param {!proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntry} msg The msg instance to transform.
+ * @return {!Object}
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntry.toObject = function(includeInstance, msg) {
+  var f, obj = {
+    key: msg.getKey_asB64(),
+    value: (f = msg.getValue()) && proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractValue.toObject(includeInstance, f)
+  };
+
+  if (includeInstance) {
+    obj.$jspbMessageInstance = msg;
+  }
+  return obj;
+};
+}
+
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntry}
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntry.deserializeBinary = function(bytes) {
+  var reader = new jspb.BinaryReader(bytes);
+  var msg = new proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntry;
+  return proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntry.deserializeBinaryFromReader(msg, reader);
+};
+
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntry} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntry}
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntry.deserializeBinaryFromReader = function(msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
       break;
-    case 4:
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+    case 1:
       var value = /** @type {!Uint8Array} */ (reader.readBytes());
-      msg.setDocumentId(value);
+      msg.setKey(value);
+      break;
+    case 2:
+      var value = new proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractValue;
+      reader.readMessage(value,proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractValue.deserializeBinaryFromReader);
+      msg.setValue(value);
       break;
     default:
       reader.skipField();
@@ -7733,9 +10871,9 @@ proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest.deserializeBina
  * Serializes the message to binary data (in protobuf wire format).
  * @return {!Uint8Array}
  */
-proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest.prototype.serializeBinary = function() {
+proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntry.prototype.serializeBinary = function() {
   var writer = new jspb.BinaryWriter();
-  proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest.serializeBinaryToWriter(this, writer);
+  proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntry.serializeBinaryToWriter(this, writer);
   return writer.getResultBuffer();
 };
 
@@ -7743,163 +10881,116 @@ proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest.prototype.seria
 /**
  * Serializes the given message to binary data (in protobuf wire
  * format), writing to the given BinaryWriter.
- * @param {!proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest} message
+ * @param {!proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntry} message
  * @param {!jspb.BinaryWriter} writer
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest.serializeBinaryToWriter = function(message, writer) {
+proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntry.serializeBinaryToWriter = function(message, writer) {
   var f = undefined;
-  f = message.getContractId_asU8();
+  f = message.getKey_asU8();
   if (f.length > 0) {
     writer.writeBytes(
       1,
       f
     );
   }
-  f = message.getDocumentType();
-  if (f.length > 0) {
-    writer.writeString(
+  f = message.getValue();
+  if (f != null) {
+    writer.writeMessage(
       2,
-      f
-    );
-  }
-  f = message.getDocumentTypeKeepsHistory();
-  if (f) {
-    writer.writeBool(
-      3,
-      f
-    );
-  }
-  f = message.getDocumentId_asU8();
-  if (f.length > 0) {
-    writer.writeBytes(
-      4,
-      f
+      f,
+      proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractValue.serializeBinaryToWriter
     );
   }
 };
 
 
 /**
- * optional bytes contract_id = 1;
+ * optional bytes key = 1;
  * @return {string}
  */
-proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest.prototype.getContractId = function() {
+proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntry.prototype.getKey = function() {
   return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 1, ""));
 };
 
 
 /**
- * optional bytes contract_id = 1;
- * This is a type-conversion wrapper around `getContractId()`
+ * optional bytes key = 1;
+ * This is a type-conversion wrapper around `getKey()`
  * @return {string}
  */
-proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest.prototype.getContractId_asB64 = function() {
+proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntry.prototype.getKey_asB64 = function() {
   return /** @type {string} */ (jspb.Message.bytesAsB64(
-      this.getContractId()));
+      this.getKey()));
 };
 
 
 /**
- * optional bytes contract_id = 1;
+ * optional bytes key = 1;
  * Note that Uint8Array is not supported on all browsers.
  * @see http://caniuse.com/Uint8Array
- * This is a type-conversion wrapper around `getContractId()`
+ * This is a type-conversion wrapper around `getKey()`
  * @return {!Uint8Array}
  */
-proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest.prototype.getContractId_asU8 = function() {
+proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntry.prototype.getKey_asU8 = function() {
   return /** @type {!Uint8Array} */ (jspb.Message.bytesAsU8(
-      this.getContractId()));
+      this.getKey()));
 };
 
 
 /**
  * @param {!(string|Uint8Array)} value
- * @return {!proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest} returns this
+ * @return {!proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntry} returns this
  */
-proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest.prototype.setContractId = function(value) {
+proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntry.prototype.setKey = function(value) {
   return jspb.Message.setProto3BytesField(this, 1, value);
 };
 
 
 /**
- * optional string document_type = 2;
- * @return {string}
- */
-proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest.prototype.getDocumentType = function() {
-  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 2, ""));
-};
-
-
-/**
- * @param {string} value
- * @return {!proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest} returns this
- */
-proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest.prototype.setDocumentType = function(value) {
-  return jspb.Message.setProto3StringField(this, 2, value);
-};
-
-
-/**
- * optional bool document_type_keeps_history = 3;
- * @return {boolean}
+ * optional DataContractValue value = 2;
+ * @return {?proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractValue}
  */
-proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest.prototype.getDocumentTypeKeepsHistory = function() {
-  return /** @type {boolean} */ (jspb.Message.getBooleanFieldWithDefault(this, 3, false));
+proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntry.prototype.getValue = function() {
+  return /** @type{?proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractValue} */ (
+    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractValue, 2));
 };
 
 
 /**
- * @param {boolean} value
- * @return {!proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest} returns this
- */
-proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest.prototype.setDocumentTypeKeepsHistory = function(value) {
-  return jspb.Message.setProto3BooleanField(this, 3, value);
+ * @param {?proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractValue|undefined} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntry} returns this
+*/
+proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntry.prototype.setValue = function(value) {
+  return jspb.Message.setWrapperField(this, 2, value);
 };
 
 
 /**
- * optional bytes document_id = 4;
- * @return {string}
+ * Clears the message field making it undefined.
+ * @return {!proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntry} returns this
  */
-proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest.prototype.getDocumentId = function() {
-  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 4, ""));
+proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntry.prototype.clearValue = function() {
+  return this.setValue(undefined);
 };
 
 
 /**
- * optional bytes document_id = 4;
- * This is a type-conversion wrapper around `getDocumentId()`
- * @return {string}
+ * Returns whether this field is set.
+ * @return {boolean}
  */
-proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest.prototype.getDocumentId_asB64 = function() {
-  return /** @type {string} */ (jspb.Message.bytesAsB64(
-      this.getDocumentId()));
+proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntry.prototype.hasValue = function() {
+  return jspb.Message.getField(this, 2) != null;
 };
 
 
-/**
- * optional bytes document_id = 4;
- * Note that Uint8Array is not supported on all browsers.
- * @see http://caniuse.com/Uint8Array
- * This is a type-conversion wrapper around `getDocumentId()`
- * @return {!Uint8Array}
- */
-proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest.prototype.getDocumentId_asU8 = function() {
-  return /** @type {!Uint8Array} */ (jspb.Message.bytesAsU8(
-      this.getDocumentId()));
-};
-
 
 /**
- * @param {!(string|Uint8Array)} value
- * @return {!proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest} returns this
+ * List of repeated fields within this message type.
+ * @private {!Array<number>}
+ * @const
  */
-proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest.prototype.setDocumentId = function(value) {
-  return jspb.Message.setProto3BytesField(this, 4, value);
-};
-
-
+proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContracts.repeatedFields_ = [1];
 
 
 
@@ -7916,8 +11007,8 @@ if (jspb.Message.GENERATE_TO_OBJECT) {
  *     http://goto/soy-param-migration
  * @return {!Object}
  */
-proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest.prototype.toObject = function(opt_includeInstance) {
-  return proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest.toObject(opt_includeInstance, this);
+proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContracts.prototype.toObject = function(opt_includeInstance) {
+  return proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContracts.toObject(opt_includeInstance, this);
 };
 
 
@@ -7926,14 +11017,14 @@ proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest.prototype.toObj
  * @param {boolean|undefined} includeInstance Deprecated. Whether to include
  *     the JSPB instance for transitional soy proto support:
  *     http://goto/soy-param-migration
- * @param {!proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest} msg The msg instance to transform.
+ * @param {!proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContracts} msg The msg instance to transform.
  * @return {!Object}
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest.toObject = function(includeInstance, msg) {
+proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContracts.toObject = function(includeInstance, msg) {
   var f, obj = {
-    identityId: msg.getIdentityId_asB64(),
-    requestType: jspb.Message.getFieldWithDefault(msg, 2, 0)
+    dataContractEntriesList: jspb.Message.toObjectList(msg.getDataContractEntriesList(),
+    proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntry.toObject, includeInstance)
   };
 
   if (includeInstance) {
@@ -7947,23 +11038,23 @@ proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest.toObject = func
 /**
  * Deserializes binary data (in protobuf wire format).
  * @param {jspb.ByteSource} bytes The bytes to deserialize.
- * @return {!proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest}
+ * @return {!proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContracts}
  */
-proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest.deserializeBinary = function(bytes) {
+proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContracts.deserializeBinary = function(bytes) {
   var reader = new jspb.BinaryReader(bytes);
-  var msg = new proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest;
-  return proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest.deserializeBinaryFromReader(msg, reader);
+  var msg = new proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContracts;
+  return proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContracts.deserializeBinaryFromReader(msg, reader);
 };
 
 
 /**
  * Deserializes binary data (in protobuf wire format) from the
  * given reader into the given message object.
- * @param {!proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest} msg The message object to deserialize into.
+ * @param {!proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContracts} msg The message object to deserialize into.
  * @param {!jspb.BinaryReader} reader The BinaryReader to use.
- * @return {!proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest}
+ * @return {!proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContracts}
  */
-proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest.deserializeBinaryFromReader = function(msg, reader) {
+proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContracts.deserializeBinaryFromReader = function(msg, reader) {
   while (reader.nextField()) {
     if (reader.isEndGroup()) {
       break;
@@ -7971,12 +11062,9 @@ proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest.deserializeBina
     var field = reader.getFieldNumber();
     switch (field) {
     case 1:
-      var value = /** @type {!Uint8Array} */ (reader.readBytes());
-      msg.setIdentityId(value);
-      break;
-    case 2:
-      var value = /** @type {!proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest.Type} */ (reader.readEnum());
-      msg.setRequestType(value);
+      var value = new proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntry;
+      reader.readMessage(value,proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntry.deserializeBinaryFromReader);
+      msg.addDataContractEntries(value);
       break;
     default:
       reader.skipField();
@@ -7988,108 +11076,182 @@ proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest.deserializeBina
 
 
 /**
- * Serializes the message to binary data (in protobuf wire format).
- * @return {!Uint8Array}
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContracts.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContracts.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContracts} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContracts.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+  f = message.getDataContractEntriesList();
+  if (f.length > 0) {
+    writer.writeRepeatedMessage(
+      1,
+      f,
+      proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntry.serializeBinaryToWriter
+    );
+  }
+};
+
+
+/**
+ * repeated DataContractEntry data_contract_entries = 1;
+ * @return {!Array<!proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntry>}
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContracts.prototype.getDataContractEntriesList = function() {
+  return /** @type{!Array<!proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntry>} */ (
+    jspb.Message.getRepeatedWrapperField(this, proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntry, 1));
+};
+
+
+/**
+ * @param {!Array<!proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntry>} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContracts} returns this
+*/
+proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContracts.prototype.setDataContractEntriesList = function(value) {
+  return jspb.Message.setRepeatedWrapperField(this, 1, value);
+};
+
+
+/**
+ * @param {!proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntry=} opt_value
+ * @param {number=} opt_index
+ * @return {!proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntry}
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContracts.prototype.addDataContractEntries = function(opt_value, opt_index) {
+  return jspb.Message.addToRepeatedWrapperField(this, 1, opt_value, proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntry, opt_index);
+};
+
+
+/**
+ * Clears the list making it empty but non-null.
+ * @return {!proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContracts} returns this
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContracts.prototype.clearDataContractEntriesList = function() {
+  return this.setDataContractEntriesList([]);
+};
+
+
+/**
+ * optional DataContracts data_contracts = 1;
+ * @return {?proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContracts}
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractsResponse.prototype.getDataContracts = function() {
+  return /** @type{?proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContracts} */ (
+    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContracts, 1));
+};
+
+
+/**
+ * @param {?proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContracts|undefined} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetDataContractsResponse} returns this
+*/
+proto.org.dash.platform.dapi.v0.GetDataContractsResponse.prototype.setDataContracts = function(value) {
+  return jspb.Message.setOneofWrapperField(this, 1, proto.org.dash.platform.dapi.v0.GetDataContractsResponse.oneofGroups_[0], value);
+};
+
+
+/**
+ * Clears the message field making it undefined.
+ * @return {!proto.org.dash.platform.dapi.v0.GetDataContractsResponse} returns this
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractsResponse.prototype.clearDataContracts = function() {
+  return this.setDataContracts(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
  */
-proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest.prototype.serializeBinary = function() {
-  var writer = new jspb.BinaryWriter();
-  proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest.serializeBinaryToWriter(this, writer);
-  return writer.getResultBuffer();
+proto.org.dash.platform.dapi.v0.GetDataContractsResponse.prototype.hasDataContracts = function() {
+  return jspb.Message.getField(this, 1) != null;
 };
 
 
 /**
- * Serializes the given message to binary data (in protobuf wire
- * format), writing to the given BinaryWriter.
- * @param {!proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest} message
- * @param {!jspb.BinaryWriter} writer
- * @suppress {unusedLocalVariables} f is only used for nested messages
+ * optional Proof proof = 2;
+ * @return {?proto.org.dash.platform.dapi.v0.Proof}
  */
-proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest.serializeBinaryToWriter = function(message, writer) {
-  var f = undefined;
-  f = message.getIdentityId_asU8();
-  if (f.length > 0) {
-    writer.writeBytes(
-      1,
-      f
-    );
-  }
-  f = message.getRequestType();
-  if (f !== 0.0) {
-    writer.writeEnum(
-      2,
-      f
-    );
-  }
+proto.org.dash.platform.dapi.v0.GetDataContractsResponse.prototype.getProof = function() {
+  return /** @type{?proto.org.dash.platform.dapi.v0.Proof} */ (
+    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.Proof, 2));
 };
 
 
 /**
- * @enum {number}
- */
-proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest.Type = {
-  FULL_IDENTITY: 0,
-  BALANCE: 1,
-  KEYS: 2
+ * @param {?proto.org.dash.platform.dapi.v0.Proof|undefined} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetDataContractsResponse} returns this
+*/
+proto.org.dash.platform.dapi.v0.GetDataContractsResponse.prototype.setProof = function(value) {
+  return jspb.Message.setOneofWrapperField(this, 2, proto.org.dash.platform.dapi.v0.GetDataContractsResponse.oneofGroups_[0], value);
 };
 
+
 /**
- * optional bytes identity_id = 1;
- * @return {string}
+ * Clears the message field making it undefined.
+ * @return {!proto.org.dash.platform.dapi.v0.GetDataContractsResponse} returns this
  */
-proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest.prototype.getIdentityId = function() {
-  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 1, ""));
+proto.org.dash.platform.dapi.v0.GetDataContractsResponse.prototype.clearProof = function() {
+  return this.setProof(undefined);
 };
 
 
 /**
- * optional bytes identity_id = 1;
- * This is a type-conversion wrapper around `getIdentityId()`
- * @return {string}
+ * Returns whether this field is set.
+ * @return {boolean}
  */
-proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest.prototype.getIdentityId_asB64 = function() {
-  return /** @type {string} */ (jspb.Message.bytesAsB64(
-      this.getIdentityId()));
+proto.org.dash.platform.dapi.v0.GetDataContractsResponse.prototype.hasProof = function() {
+  return jspb.Message.getField(this, 2) != null;
 };
 
 
 /**
- * optional bytes identity_id = 1;
- * Note that Uint8Array is not supported on all browsers.
- * @see http://caniuse.com/Uint8Array
- * This is a type-conversion wrapper around `getIdentityId()`
- * @return {!Uint8Array}
+ * optional ResponseMetadata metadata = 3;
+ * @return {?proto.org.dash.platform.dapi.v0.ResponseMetadata}
  */
-proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest.prototype.getIdentityId_asU8 = function() {
-  return /** @type {!Uint8Array} */ (jspb.Message.bytesAsU8(
-      this.getIdentityId()));
+proto.org.dash.platform.dapi.v0.GetDataContractsResponse.prototype.getMetadata = function() {
+  return /** @type{?proto.org.dash.platform.dapi.v0.ResponseMetadata} */ (
+    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.ResponseMetadata, 3));
 };
 
 
 /**
- * @param {!(string|Uint8Array)} value
- * @return {!proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest} returns this
- */
-proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest.prototype.setIdentityId = function(value) {
-  return jspb.Message.setProto3BytesField(this, 1, value);
+ * @param {?proto.org.dash.platform.dapi.v0.ResponseMetadata|undefined} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetDataContractsResponse} returns this
+*/
+proto.org.dash.platform.dapi.v0.GetDataContractsResponse.prototype.setMetadata = function(value) {
+  return jspb.Message.setWrapperField(this, 3, value);
 };
 
 
 /**
- * optional Type request_type = 2;
- * @return {!proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest.Type}
+ * Clears the message field making it undefined.
+ * @return {!proto.org.dash.platform.dapi.v0.GetDataContractsResponse} returns this
  */
-proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest.prototype.getRequestType = function() {
-  return /** @type {!proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest.Type} */ (jspb.Message.getFieldWithDefault(this, 2, 0));
+proto.org.dash.platform.dapi.v0.GetDataContractsResponse.prototype.clearMetadata = function() {
+  return this.setMetadata(undefined);
 };
 
 
 /**
- * @param {!proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest.Type} value
- * @return {!proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest} returns this
+ * Returns whether this field is set.
+ * @return {boolean}
  */
-proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest.prototype.setRequestType = function(value) {
-  return jspb.Message.setProto3EnumField(this, 2, value);
+proto.org.dash.platform.dapi.v0.GetDataContractsResponse.prototype.hasMetadata = function() {
+  return jspb.Message.getField(this, 3) != null;
 };
 
 
@@ -8109,8 +11271,8 @@ if (jspb.Message.GENERATE_TO_OBJECT) {
  *     http://goto/soy-param-migration
  * @return {!Object}
  */
-proto.org.dash.platform.dapi.v0.GetProofsRequest.ContractRequest.prototype.toObject = function(opt_includeInstance) {
-  return proto.org.dash.platform.dapi.v0.GetProofsRequest.ContractRequest.toObject(opt_includeInstance, this);
+proto.org.dash.platform.dapi.v0.GetDataContractHistoryRequest.prototype.toObject = function(opt_includeInstance) {
+  return proto.org.dash.platform.dapi.v0.GetDataContractHistoryRequest.toObject(opt_includeInstance, this);
 };
 
 
@@ -8119,13 +11281,17 @@ proto.org.dash.platform.dapi.v0.GetProofsRequest.ContractRequest.prototype.toObj
  * @param {boolean|undefined} includeInstance Deprecated. Whether to include
  *     the JSPB instance for transitional soy proto support:
  *     http://goto/soy-param-migration
- * @param {!proto.org.dash.platform.dapi.v0.GetProofsRequest.ContractRequest} msg The msg instance to transform.
+ * @param {!proto.org.dash.platform.dapi.v0.GetDataContractHistoryRequest} msg The msg instance to transform.
  * @return {!Object}
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.org.dash.platform.dapi.v0.GetProofsRequest.ContractRequest.toObject = function(includeInstance, msg) {
+proto.org.dash.platform.dapi.v0.GetDataContractHistoryRequest.toObject = function(includeInstance, msg) {
   var f, obj = {
-    contractId: msg.getContractId_asB64()
+    id: msg.getId_asB64(),
+    limit: jspb.Message.getFieldWithDefault(msg, 2, 0),
+    offset: jspb.Message.getFieldWithDefault(msg, 3, 0),
+    startAtMs: jspb.Message.getFieldWithDefault(msg, 4, 0),
+    prove: jspb.Message.getBooleanFieldWithDefault(msg, 5, false)
   };
 
   if (includeInstance) {
@@ -8139,23 +11305,23 @@ proto.org.dash.platform.dapi.v0.GetProofsRequest.ContractRequest.toObject = func
 /**
  * Deserializes binary data (in protobuf wire format).
  * @param {jspb.ByteSource} bytes The bytes to deserialize.
- * @return {!proto.org.dash.platform.dapi.v0.GetProofsRequest.ContractRequest}
+ * @return {!proto.org.dash.platform.dapi.v0.GetDataContractHistoryRequest}
  */
-proto.org.dash.platform.dapi.v0.GetProofsRequest.ContractRequest.deserializeBinary = function(bytes) {
+proto.org.dash.platform.dapi.v0.GetDataContractHistoryRequest.deserializeBinary = function(bytes) {
   var reader = new jspb.BinaryReader(bytes);
-  var msg = new proto.org.dash.platform.dapi.v0.GetProofsRequest.ContractRequest;
-  return proto.org.dash.platform.dapi.v0.GetProofsRequest.ContractRequest.deserializeBinaryFromReader(msg, reader);
+  var msg = new proto.org.dash.platform.dapi.v0.GetDataContractHistoryRequest;
+  return proto.org.dash.platform.dapi.v0.GetDataContractHistoryRequest.deserializeBinaryFromReader(msg, reader);
 };
 
 
 /**
  * Deserializes binary data (in protobuf wire format) from the
  * given reader into the given message object.
- * @param {!proto.org.dash.platform.dapi.v0.GetProofsRequest.ContractRequest} msg The message object to deserialize into.
+ * @param {!proto.org.dash.platform.dapi.v0.GetDataContractHistoryRequest} msg The message object to deserialize into.
  * @param {!jspb.BinaryReader} reader The BinaryReader to use.
- * @return {!proto.org.dash.platform.dapi.v0.GetProofsRequest.ContractRequest}
+ * @return {!proto.org.dash.platform.dapi.v0.GetDataContractHistoryRequest}
  */
-proto.org.dash.platform.dapi.v0.GetProofsRequest.ContractRequest.deserializeBinaryFromReader = function(msg, reader) {
+proto.org.dash.platform.dapi.v0.GetDataContractHistoryRequest.deserializeBinaryFromReader = function(msg, reader) {
   while (reader.nextField()) {
     if (reader.isEndGroup()) {
       break;
@@ -8164,7 +11330,23 @@ proto.org.dash.platform.dapi.v0.GetProofsRequest.ContractRequest.deserializeBina
     switch (field) {
     case 1:
       var value = /** @type {!Uint8Array} */ (reader.readBytes());
-      msg.setContractId(value);
+      msg.setId(value);
+      break;
+    case 2:
+      var value = /** @type {number} */ (reader.readUint32());
+      msg.setLimit(value);
+      break;
+    case 3:
+      var value = /** @type {number} */ (reader.readUint32());
+      msg.setOffset(value);
+      break;
+    case 4:
+      var value = /** @type {number} */ (reader.readUint64());
+      msg.setStartAtMs(value);
+      break;
+    case 5:
+      var value = /** @type {boolean} */ (reader.readBool());
+      msg.setProve(value);
       break;
     default:
       reader.skipField();
@@ -8179,9 +11361,9 @@ proto.org.dash.platform.dapi.v0.GetProofsRequest.ContractRequest.deserializeBina
  * Serializes the message to binary data (in protobuf wire format).
  * @return {!Uint8Array}
  */
-proto.org.dash.platform.dapi.v0.GetProofsRequest.ContractRequest.prototype.serializeBinary = function() {
+proto.org.dash.platform.dapi.v0.GetDataContractHistoryRequest.prototype.serializeBinary = function() {
   var writer = new jspb.BinaryWriter();
-  proto.org.dash.platform.dapi.v0.GetProofsRequest.ContractRequest.serializeBinaryToWriter(this, writer);
+  proto.org.dash.platform.dapi.v0.GetDataContractHistoryRequest.serializeBinaryToWriter(this, writer);
   return writer.getResultBuffer();
 };
 
@@ -8189,175 +11371,329 @@ proto.org.dash.platform.dapi.v0.GetProofsRequest.ContractRequest.prototype.seria
 /**
  * Serializes the given message to binary data (in protobuf wire
  * format), writing to the given BinaryWriter.
- * @param {!proto.org.dash.platform.dapi.v0.GetProofsRequest.ContractRequest} message
+ * @param {!proto.org.dash.platform.dapi.v0.GetDataContractHistoryRequest} message
  * @param {!jspb.BinaryWriter} writer
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.org.dash.platform.dapi.v0.GetProofsRequest.ContractRequest.serializeBinaryToWriter = function(message, writer) {
+proto.org.dash.platform.dapi.v0.GetDataContractHistoryRequest.serializeBinaryToWriter = function(message, writer) {
   var f = undefined;
-  f = message.getContractId_asU8();
+  f = message.getId_asU8();
   if (f.length > 0) {
     writer.writeBytes(
       1,
       f
     );
   }
+  f = message.getLimit();
+  if (f !== 0) {
+    writer.writeUint32(
+      2,
+      f
+    );
+  }
+  f = message.getOffset();
+  if (f !== 0) {
+    writer.writeUint32(
+      3,
+      f
+    );
+  }
+  f = message.getStartAtMs();
+  if (f !== 0) {
+    writer.writeUint64(
+      4,
+      f
+    );
+  }
+  f = message.getProve();
+  if (f) {
+    writer.writeBool(
+      5,
+      f
+    );
+  }
 };
 
 
 /**
- * optional bytes contract_id = 1;
+ * optional bytes id = 1;
  * @return {string}
  */
-proto.org.dash.platform.dapi.v0.GetProofsRequest.ContractRequest.prototype.getContractId = function() {
+proto.org.dash.platform.dapi.v0.GetDataContractHistoryRequest.prototype.getId = function() {
   return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 1, ""));
 };
 
 
 /**
- * optional bytes contract_id = 1;
- * This is a type-conversion wrapper around `getContractId()`
+ * optional bytes id = 1;
+ * This is a type-conversion wrapper around `getId()`
  * @return {string}
  */
-proto.org.dash.platform.dapi.v0.GetProofsRequest.ContractRequest.prototype.getContractId_asB64 = function() {
+proto.org.dash.platform.dapi.v0.GetDataContractHistoryRequest.prototype.getId_asB64 = function() {
   return /** @type {string} */ (jspb.Message.bytesAsB64(
-      this.getContractId()));
+      this.getId()));
+};
+
+
+/**
+ * optional bytes id = 1;
+ * Note that Uint8Array is not supported on all browsers.
+ * @see http://caniuse.com/Uint8Array
+ * This is a type-conversion wrapper around `getId()`
+ * @return {!Uint8Array}
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractHistoryRequest.prototype.getId_asU8 = function() {
+  return /** @type {!Uint8Array} */ (jspb.Message.bytesAsU8(
+      this.getId()));
+};
+
+
+/**
+ * @param {!(string|Uint8Array)} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetDataContractHistoryRequest} returns this
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractHistoryRequest.prototype.setId = function(value) {
+  return jspb.Message.setProto3BytesField(this, 1, value);
+};
+
+
+/**
+ * optional uint32 limit = 2;
+ * @return {number}
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractHistoryRequest.prototype.getLimit = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 2, 0));
+};
+
+
+/**
+ * @param {number} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetDataContractHistoryRequest} returns this
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractHistoryRequest.prototype.setLimit = function(value) {
+  return jspb.Message.setProto3IntField(this, 2, value);
+};
+
+
+/**
+ * optional uint32 offset = 3;
+ * @return {number}
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractHistoryRequest.prototype.getOffset = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 3, 0));
 };
 
 
 /**
- * optional bytes contract_id = 1;
- * Note that Uint8Array is not supported on all browsers.
- * @see http://caniuse.com/Uint8Array
- * This is a type-conversion wrapper around `getContractId()`
- * @return {!Uint8Array}
+ * @param {number} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetDataContractHistoryRequest} returns this
  */
-proto.org.dash.platform.dapi.v0.GetProofsRequest.ContractRequest.prototype.getContractId_asU8 = function() {
-  return /** @type {!Uint8Array} */ (jspb.Message.bytesAsU8(
-      this.getContractId()));
+proto.org.dash.platform.dapi.v0.GetDataContractHistoryRequest.prototype.setOffset = function(value) {
+  return jspb.Message.setProto3IntField(this, 3, value);
 };
 
 
 /**
- * @param {!(string|Uint8Array)} value
- * @return {!proto.org.dash.platform.dapi.v0.GetProofsRequest.ContractRequest} returns this
+ * optional uint64 start_at_ms = 4;
+ * @return {number}
  */
-proto.org.dash.platform.dapi.v0.GetProofsRequest.ContractRequest.prototype.setContractId = function(value) {
-  return jspb.Message.setProto3BytesField(this, 1, value);
+proto.org.dash.platform.dapi.v0.GetDataContractHistoryRequest.prototype.getStartAtMs = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 4, 0));
 };
 
 
 /**
- * repeated IdentityRequest identities = 1;
- * @return {!Array<!proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest>}
+ * @param {number} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetDataContractHistoryRequest} returns this
  */
-proto.org.dash.platform.dapi.v0.GetProofsRequest.prototype.getIdentitiesList = function() {
-  return /** @type{!Array<!proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest>} */ (
-    jspb.Message.getRepeatedWrapperField(this, proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest, 1));
+proto.org.dash.platform.dapi.v0.GetDataContractHistoryRequest.prototype.setStartAtMs = function(value) {
+  return jspb.Message.setProto3IntField(this, 4, value);
 };
 
 
 /**
- * @param {!Array<!proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest>} value
- * @return {!proto.org.dash.platform.dapi.v0.GetProofsRequest} returns this
-*/
-proto.org.dash.platform.dapi.v0.GetProofsRequest.prototype.setIdentitiesList = function(value) {
-  return jspb.Message.setRepeatedWrapperField(this, 1, value);
+ * optional bool prove = 5;
+ * @return {boolean}
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractHistoryRequest.prototype.getProve = function() {
+  return /** @type {boolean} */ (jspb.Message.getBooleanFieldWithDefault(this, 5, false));
 };
 
 
 /**
- * @param {!proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest=} opt_value
- * @param {number=} opt_index
- * @return {!proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest}
+ * @param {boolean} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetDataContractHistoryRequest} returns this
  */
-proto.org.dash.platform.dapi.v0.GetProofsRequest.prototype.addIdentities = function(opt_value, opt_index) {
-  return jspb.Message.addToRepeatedWrapperField(this, 1, opt_value, proto.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest, opt_index);
+proto.org.dash.platform.dapi.v0.GetDataContractHistoryRequest.prototype.setProve = function(value) {
+  return jspb.Message.setProto3BooleanField(this, 5, value);
 };
 
 
+
 /**
- * Clears the list making it empty but non-null.
- * @return {!proto.org.dash.platform.dapi.v0.GetProofsRequest} returns this
+ * Oneof group definitions for this message. Each group defines the field
+ * numbers belonging to that group. When of these fields' value is set, all
+ * other fields in the group are cleared. During deserialization, if multiple
+ * fields are encountered for a group, only the last value seen will be kept.
+ * @private {!Array<!Array<number>>}
+ * @const
  */
-proto.org.dash.platform.dapi.v0.GetProofsRequest.prototype.clearIdentitiesList = function() {
-  return this.setIdentitiesList([]);
-};
-
+proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.oneofGroups_ = [[1,2]];
 
 /**
- * repeated ContractRequest contracts = 2;
- * @return {!Array<!proto.org.dash.platform.dapi.v0.GetProofsRequest.ContractRequest>}
+ * @enum {number}
  */
-proto.org.dash.platform.dapi.v0.GetProofsRequest.prototype.getContractsList = function() {
-  return /** @type{!Array<!proto.org.dash.platform.dapi.v0.GetProofsRequest.ContractRequest>} */ (
-    jspb.Message.getRepeatedWrapperField(this, proto.org.dash.platform.dapi.v0.GetProofsRequest.ContractRequest, 2));
+proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.ResultCase = {
+  RESULT_NOT_SET: 0,
+  DATA_CONTRACT_HISTORY: 1,
+  PROOF: 2
 };
 
-
 /**
- * @param {!Array<!proto.org.dash.platform.dapi.v0.GetProofsRequest.ContractRequest>} value
- * @return {!proto.org.dash.platform.dapi.v0.GetProofsRequest} returns this
-*/
-proto.org.dash.platform.dapi.v0.GetProofsRequest.prototype.setContractsList = function(value) {
-  return jspb.Message.setRepeatedWrapperField(this, 2, value);
+ * @return {proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.ResultCase}
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.prototype.getResultCase = function() {
+  return /** @type {proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.ResultCase} */(jspb.Message.computeOneofCase(this, proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.oneofGroups_[0]));
 };
 
 
+
+if (jspb.Message.GENERATE_TO_OBJECT) {
 /**
- * @param {!proto.org.dash.platform.dapi.v0.GetProofsRequest.ContractRequest=} opt_value
- * @param {number=} opt_index
- * @return {!proto.org.dash.platform.dapi.v0.GetProofsRequest.ContractRequest}
+ * Creates an object representation of this proto.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * Optional fields that are not set will be set to undefined.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * For the list of reserved names please see:
+ *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
+ * @param {boolean=} opt_includeInstance Deprecated. whether to include the
+ *     JSPB instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @return {!Object}
  */
-proto.org.dash.platform.dapi.v0.GetProofsRequest.prototype.addContracts = function(opt_value, opt_index) {
-  return jspb.Message.addToRepeatedWrapperField(this, 2, opt_value, proto.org.dash.platform.dapi.v0.GetProofsRequest.ContractRequest, opt_index);
+proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.prototype.toObject = function(opt_includeInstance) {
+  return proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.toObject(opt_includeInstance, this);
 };
 
 
 /**
- * Clears the list making it empty but non-null.
- * @return {!proto.org.dash.platform.dapi.v0.GetProofsRequest} returns this
+ * Static version of the {@see toObject} method.
+ * @param {boolean|undefined} includeInstance Deprecated. Whether to include
+ *     the JSPB instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @param {!proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse} msg The msg instance to transform.
+ * @return {!Object}
+ * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.org.dash.platform.dapi.v0.GetProofsRequest.prototype.clearContractsList = function() {
-  return this.setContractsList([]);
+proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.toObject = function(includeInstance, msg) {
+  var f, obj = {
+    dataContractHistory: (f = msg.getDataContractHistory()) && proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistory.toObject(includeInstance, f),
+    proof: (f = msg.getProof()) && proto.org.dash.platform.dapi.v0.Proof.toObject(includeInstance, f),
+    metadata: (f = msg.getMetadata()) && proto.org.dash.platform.dapi.v0.ResponseMetadata.toObject(includeInstance, f)
+  };
+
+  if (includeInstance) {
+    obj.$jspbMessageInstance = msg;
+  }
+  return obj;
 };
+}
 
 
 /**
- * repeated DocumentRequest documents = 3;
- * @return {!Array<!proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest>}
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse}
  */
-proto.org.dash.platform.dapi.v0.GetProofsRequest.prototype.getDocumentsList = function() {
-  return /** @type{!Array<!proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest>} */ (
-    jspb.Message.getRepeatedWrapperField(this, proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest, 3));
+proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.deserializeBinary = function(bytes) {
+  var reader = new jspb.BinaryReader(bytes);
+  var msg = new proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse;
+  return proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.deserializeBinaryFromReader(msg, reader);
 };
 
 
 /**
- * @param {!Array<!proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest>} value
- * @return {!proto.org.dash.platform.dapi.v0.GetProofsRequest} returns this
-*/
-proto.org.dash.platform.dapi.v0.GetProofsRequest.prototype.setDocumentsList = function(value) {
-  return jspb.Message.setRepeatedWrapperField(this, 3, value);
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse}
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.deserializeBinaryFromReader = function(msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+    case 1:
+      var value = new proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistory;
+      reader.readMessage(value,proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistory.deserializeBinaryFromReader);
+      msg.setDataContractHistory(value);
+      break;
+    case 2:
+      var value = new proto.org.dash.platform.dapi.v0.Proof;
+      reader.readMessage(value,proto.org.dash.platform.dapi.v0.Proof.deserializeBinaryFromReader);
+      msg.setProof(value);
+      break;
+    case 3:
+      var value = new proto.org.dash.platform.dapi.v0.ResponseMetadata;
+      reader.readMessage(value,proto.org.dash.platform.dapi.v0.ResponseMetadata.deserializeBinaryFromReader);
+      msg.setMetadata(value);
+      break;
+    default:
+      reader.skipField();
+      break;
+    }
+  }
+  return msg;
 };
 
 
 /**
- * @param {!proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest=} opt_value
- * @param {number=} opt_index
- * @return {!proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest}
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
  */
-proto.org.dash.platform.dapi.v0.GetProofsRequest.prototype.addDocuments = function(opt_value, opt_index) {
-  return jspb.Message.addToRepeatedWrapperField(this, 3, opt_value, proto.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest, opt_index);
+proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
 };
 
 
 /**
- * Clears the list making it empty but non-null.
- * @return {!proto.org.dash.platform.dapi.v0.GetProofsRequest} returns this
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.org.dash.platform.dapi.v0.GetProofsRequest.prototype.clearDocumentsList = function() {
-  return this.setDocumentsList([]);
+proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+  f = message.getDataContractHistory();
+  if (f != null) {
+    writer.writeMessage(
+      1,
+      f,
+      proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistory.serializeBinaryToWriter
+    );
+  }
+  f = message.getProof();
+  if (f != null) {
+    writer.writeMessage(
+      2,
+      f,
+      proto.org.dash.platform.dapi.v0.Proof.serializeBinaryToWriter
+    );
+  }
+  f = message.getMetadata();
+  if (f != null) {
+    writer.writeMessage(
+      3,
+      f,
+      proto.org.dash.platform.dapi.v0.ResponseMetadata.serializeBinaryToWriter
+    );
+  }
 };
 
 
@@ -8377,8 +11713,8 @@ if (jspb.Message.GENERATE_TO_OBJECT) {
  *     http://goto/soy-param-migration
  * @return {!Object}
  */
-proto.org.dash.platform.dapi.v0.GetProofsResponse.prototype.toObject = function(opt_includeInstance) {
-  return proto.org.dash.platform.dapi.v0.GetProofsResponse.toObject(opt_includeInstance, this);
+proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistoryEntry.prototype.toObject = function(opt_includeInstance) {
+  return proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistoryEntry.toObject(opt_includeInstance, this);
 };
 
 
@@ -8387,14 +11723,14 @@ proto.org.dash.platform.dapi.v0.GetProofsResponse.prototype.toObject = function(
  * @param {boolean|undefined} includeInstance Deprecated. Whether to include
  *     the JSPB instance for transitional soy proto support:
  *     http://goto/soy-param-migration
- * @param {!proto.org.dash.platform.dapi.v0.GetProofsResponse} msg The msg instance to transform.
+ * @param {!proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistoryEntry} msg The msg instance to transform.
  * @return {!Object}
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.org.dash.platform.dapi.v0.GetProofsResponse.toObject = function(includeInstance, msg) {
+proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistoryEntry.toObject = function(includeInstance, msg) {
   var f, obj = {
-    proof: (f = msg.getProof()) && proto.org.dash.platform.dapi.v0.Proof.toObject(includeInstance, f),
-    metadata: (f = msg.getMetadata()) && proto.org.dash.platform.dapi.v0.ResponseMetadata.toObject(includeInstance, f)
+    date: jspb.Message.getFieldWithDefault(msg, 1, 0),
+    value: msg.getValue_asB64()
   };
 
   if (includeInstance) {
@@ -8408,23 +11744,23 @@ proto.org.dash.platform.dapi.v0.GetProofsResponse.toObject = function(includeIns
 /**
  * Deserializes binary data (in protobuf wire format).
  * @param {jspb.ByteSource} bytes The bytes to deserialize.
- * @return {!proto.org.dash.platform.dapi.v0.GetProofsResponse}
+ * @return {!proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistoryEntry}
  */
-proto.org.dash.platform.dapi.v0.GetProofsResponse.deserializeBinary = function(bytes) {
+proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistoryEntry.deserializeBinary = function(bytes) {
   var reader = new jspb.BinaryReader(bytes);
-  var msg = new proto.org.dash.platform.dapi.v0.GetProofsResponse;
-  return proto.org.dash.platform.dapi.v0.GetProofsResponse.deserializeBinaryFromReader(msg, reader);
+  var msg = new proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistoryEntry;
+  return proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistoryEntry.deserializeBinaryFromReader(msg, reader);
 };
 
 
 /**
  * Deserializes binary data (in protobuf wire format) from the
  * given reader into the given message object.
- * @param {!proto.org.dash.platform.dapi.v0.GetProofsResponse} msg The message object to deserialize into.
+ * @param {!proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistoryEntry} msg The message object to deserialize into.
  * @param {!jspb.BinaryReader} reader The BinaryReader to use.
- * @return {!proto.org.dash.platform.dapi.v0.GetProofsResponse}
+ * @return {!proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistoryEntry}
  */
-proto.org.dash.platform.dapi.v0.GetProofsResponse.deserializeBinaryFromReader = function(msg, reader) {
+proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistoryEntry.deserializeBinaryFromReader = function(msg, reader) {
   while (reader.nextField()) {
     if (reader.isEndGroup()) {
       break;
@@ -8432,14 +11768,12 @@ proto.org.dash.platform.dapi.v0.GetProofsResponse.deserializeBinaryFromReader =
     var field = reader.getFieldNumber();
     switch (field) {
     case 1:
-      var value = new proto.org.dash.platform.dapi.v0.Proof;
-      reader.readMessage(value,proto.org.dash.platform.dapi.v0.Proof.deserializeBinaryFromReader);
-      msg.setProof(value);
+      var value = /** @type {number} */ (reader.readUint64());
+      msg.setDate(value);
       break;
     case 2:
-      var value = new proto.org.dash.platform.dapi.v0.ResponseMetadata;
-      reader.readMessage(value,proto.org.dash.platform.dapi.v0.ResponseMetadata.deserializeBinaryFromReader);
-      msg.setMetadata(value);
+      var value = /** @type {!Uint8Array} */ (reader.readBytes());
+      msg.setValue(value);
       break;
     default:
       reader.skipField();
@@ -8454,9 +11788,9 @@ proto.org.dash.platform.dapi.v0.GetProofsResponse.deserializeBinaryFromReader =
  * Serializes the message to binary data (in protobuf wire format).
  * @return {!Uint8Array}
  */
-proto.org.dash.platform.dapi.v0.GetProofsResponse.prototype.serializeBinary = function() {
+proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistoryEntry.prototype.serializeBinary = function() {
   var writer = new jspb.BinaryWriter();
-  proto.org.dash.platform.dapi.v0.GetProofsResponse.serializeBinaryToWriter(this, writer);
+  proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistoryEntry.serializeBinaryToWriter(this, writer);
   return writer.getResultBuffer();
 };
 
@@ -8464,105 +11798,96 @@ proto.org.dash.platform.dapi.v0.GetProofsResponse.prototype.serializeBinary = fu
 /**
  * Serializes the given message to binary data (in protobuf wire
  * format), writing to the given BinaryWriter.
- * @param {!proto.org.dash.platform.dapi.v0.GetProofsResponse} message
+ * @param {!proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistoryEntry} message
  * @param {!jspb.BinaryWriter} writer
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.org.dash.platform.dapi.v0.GetProofsResponse.serializeBinaryToWriter = function(message, writer) {
+proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistoryEntry.serializeBinaryToWriter = function(message, writer) {
   var f = undefined;
-  f = message.getProof();
-  if (f != null) {
-    writer.writeMessage(
+  f = message.getDate();
+  if (f !== 0) {
+    writer.writeUint64(
       1,
-      f,
-      proto.org.dash.platform.dapi.v0.Proof.serializeBinaryToWriter
+      f
     );
   }
-  f = message.getMetadata();
-  if (f != null) {
-    writer.writeMessage(
+  f = message.getValue_asU8();
+  if (f.length > 0) {
+    writer.writeBytes(
       2,
-      f,
-      proto.org.dash.platform.dapi.v0.ResponseMetadata.serializeBinaryToWriter
+      f
     );
   }
 };
 
 
 /**
- * optional Proof proof = 1;
- * @return {?proto.org.dash.platform.dapi.v0.Proof}
+ * optional uint64 date = 1;
+ * @return {number}
  */
-proto.org.dash.platform.dapi.v0.GetProofsResponse.prototype.getProof = function() {
-  return /** @type{?proto.org.dash.platform.dapi.v0.Proof} */ (
-    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.Proof, 1));
-};
-
-
-/**
- * @param {?proto.org.dash.platform.dapi.v0.Proof|undefined} value
- * @return {!proto.org.dash.platform.dapi.v0.GetProofsResponse} returns this
-*/
-proto.org.dash.platform.dapi.v0.GetProofsResponse.prototype.setProof = function(value) {
-  return jspb.Message.setWrapperField(this, 1, value);
+proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistoryEntry.prototype.getDate = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 1, 0));
 };
 
 
 /**
- * Clears the message field making it undefined.
- * @return {!proto.org.dash.platform.dapi.v0.GetProofsResponse} returns this
+ * @param {number} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistoryEntry} returns this
  */
-proto.org.dash.platform.dapi.v0.GetProofsResponse.prototype.clearProof = function() {
-  return this.setProof(undefined);
+proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistoryEntry.prototype.setDate = function(value) {
+  return jspb.Message.setProto3IntField(this, 1, value);
 };
 
 
 /**
- * Returns whether this field is set.
- * @return {boolean}
+ * optional bytes value = 2;
+ * @return {string}
  */
-proto.org.dash.platform.dapi.v0.GetProofsResponse.prototype.hasProof = function() {
-  return jspb.Message.getField(this, 1) != null;
+proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistoryEntry.prototype.getValue = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 2, ""));
 };
 
 
 /**
- * optional ResponseMetadata metadata = 2;
- * @return {?proto.org.dash.platform.dapi.v0.ResponseMetadata}
+ * optional bytes value = 2;
+ * This is a type-conversion wrapper around `getValue()`
+ * @return {string}
  */
-proto.org.dash.platform.dapi.v0.GetProofsResponse.prototype.getMetadata = function() {
-  return /** @type{?proto.org.dash.platform.dapi.v0.ResponseMetadata} */ (
-    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.ResponseMetadata, 2));
+proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistoryEntry.prototype.getValue_asB64 = function() {
+  return /** @type {string} */ (jspb.Message.bytesAsB64(
+      this.getValue()));
 };
 
 
 /**
- * @param {?proto.org.dash.platform.dapi.v0.ResponseMetadata|undefined} value
- * @return {!proto.org.dash.platform.dapi.v0.GetProofsResponse} returns this
-*/
-proto.org.dash.platform.dapi.v0.GetProofsResponse.prototype.setMetadata = function(value) {
-  return jspb.Message.setWrapperField(this, 2, value);
+ * optional bytes value = 2;
+ * Note that Uint8Array is not supported on all browsers.
+ * @see http://caniuse.com/Uint8Array
+ * This is a type-conversion wrapper around `getValue()`
+ * @return {!Uint8Array}
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistoryEntry.prototype.getValue_asU8 = function() {
+  return /** @type {!Uint8Array} */ (jspb.Message.bytesAsU8(
+      this.getValue()));
 };
 
 
 /**
- * Clears the message field making it undefined.
- * @return {!proto.org.dash.platform.dapi.v0.GetProofsResponse} returns this
+ * @param {!(string|Uint8Array)} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistoryEntry} returns this
  */
-proto.org.dash.platform.dapi.v0.GetProofsResponse.prototype.clearMetadata = function() {
-  return this.setMetadata(undefined);
+proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistoryEntry.prototype.setValue = function(value) {
+  return jspb.Message.setProto3BytesField(this, 2, value);
 };
 
 
+
 /**
- * Returns whether this field is set.
- * @return {boolean}
+ * List of repeated fields within this message type.
+ * @private {!Array<number>}
+ * @const
  */
-proto.org.dash.platform.dapi.v0.GetProofsResponse.prototype.hasMetadata = function() {
-  return jspb.Message.getField(this, 2) != null;
-};
-
-
+proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistory.repeatedFields_ = [1];
 
 
 
@@ -8579,8 +11904,8 @@ if (jspb.Message.GENERATE_TO_OBJECT) {
  *     http://goto/soy-param-migration
  * @return {!Object}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractRequest.prototype.toObject = function(opt_includeInstance) {
-  return proto.org.dash.platform.dapi.v0.GetDataContractRequest.toObject(opt_includeInstance, this);
+proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistory.prototype.toObject = function(opt_includeInstance) {
+  return proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistory.toObject(opt_includeInstance, this);
 };
 
 
@@ -8589,14 +11914,14 @@ proto.org.dash.platform.dapi.v0.GetDataContractRequest.prototype.toObject = func
  * @param {boolean|undefined} includeInstance Deprecated. Whether to include
  *     the JSPB instance for transitional soy proto support:
  *     http://goto/soy-param-migration
- * @param {!proto.org.dash.platform.dapi.v0.GetDataContractRequest} msg The msg instance to transform.
+ * @param {!proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistory} msg The msg instance to transform.
  * @return {!Object}
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.org.dash.platform.dapi.v0.GetDataContractRequest.toObject = function(includeInstance, msg) {
+proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistory.toObject = function(includeInstance, msg) {
   var f, obj = {
-    id: msg.getId_asB64(),
-    prove: jspb.Message.getBooleanFieldWithDefault(msg, 2, false)
+    dataContractEntriesList: jspb.Message.toObjectList(msg.getDataContractEntriesList(),
+    proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistoryEntry.toObject, includeInstance)
   };
 
   if (includeInstance) {
@@ -8610,23 +11935,23 @@ proto.org.dash.platform.dapi.v0.GetDataContractRequest.toObject = function(inclu
 /**
  * Deserializes binary data (in protobuf wire format).
  * @param {jspb.ByteSource} bytes The bytes to deserialize.
- * @return {!proto.org.dash.platform.dapi.v0.GetDataContractRequest}
+ * @return {!proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistory}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractRequest.deserializeBinary = function(bytes) {
+proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistory.deserializeBinary = function(bytes) {
   var reader = new jspb.BinaryReader(bytes);
-  var msg = new proto.org.dash.platform.dapi.v0.GetDataContractRequest;
-  return proto.org.dash.platform.dapi.v0.GetDataContractRequest.deserializeBinaryFromReader(msg, reader);
+  var msg = new proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistory;
+  return proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistory.deserializeBinaryFromReader(msg, reader);
 };
 
 
 /**
  * Deserializes binary data (in protobuf wire format) from the
  * given reader into the given message object.
- * @param {!proto.org.dash.platform.dapi.v0.GetDataContractRequest} msg The message object to deserialize into.
+ * @param {!proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistory} msg The message object to deserialize into.
  * @param {!jspb.BinaryReader} reader The BinaryReader to use.
- * @return {!proto.org.dash.platform.dapi.v0.GetDataContractRequest}
+ * @return {!proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistory}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractRequest.deserializeBinaryFromReader = function(msg, reader) {
+proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistory.deserializeBinaryFromReader = function(msg, reader) {
   while (reader.nextField()) {
     if (reader.isEndGroup()) {
       break;
@@ -8634,12 +11959,9 @@ proto.org.dash.platform.dapi.v0.GetDataContractRequest.deserializeBinaryFromRead
     var field = reader.getFieldNumber();
     switch (field) {
     case 1:
-      var value = /** @type {!Uint8Array} */ (reader.readBytes());
-      msg.setId(value);
-      break;
-    case 2:
-      var value = /** @type {boolean} */ (reader.readBool());
-      msg.setProve(value);
+      var value = new proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistoryEntry;
+      reader.readMessage(value,proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistoryEntry.deserializeBinaryFromReader);
+      msg.addDataContractEntries(value);
       break;
     default:
       reader.skipField();
@@ -8654,9 +11976,9 @@ proto.org.dash.platform.dapi.v0.GetDataContractRequest.deserializeBinaryFromRead
  * Serializes the message to binary data (in protobuf wire format).
  * @return {!Uint8Array}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractRequest.prototype.serializeBinary = function() {
+proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistory.prototype.serializeBinary = function() {
   var writer = new jspb.BinaryWriter();
-  proto.org.dash.platform.dapi.v0.GetDataContractRequest.serializeBinaryToWriter(this, writer);
+  proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistory.serializeBinaryToWriter(this, writer);
   return writer.getResultBuffer();
 };
 
@@ -8664,86 +11986,169 @@ proto.org.dash.platform.dapi.v0.GetDataContractRequest.prototype.serializeBinary
 /**
  * Serializes the given message to binary data (in protobuf wire
  * format), writing to the given BinaryWriter.
- * @param {!proto.org.dash.platform.dapi.v0.GetDataContractRequest} message
+ * @param {!proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistory} message
  * @param {!jspb.BinaryWriter} writer
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.org.dash.platform.dapi.v0.GetDataContractRequest.serializeBinaryToWriter = function(message, writer) {
+proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistory.serializeBinaryToWriter = function(message, writer) {
   var f = undefined;
-  f = message.getId_asU8();
+  f = message.getDataContractEntriesList();
   if (f.length > 0) {
-    writer.writeBytes(
+    writer.writeRepeatedMessage(
       1,
-      f
-    );
-  }
-  f = message.getProve();
-  if (f) {
-    writer.writeBool(
-      2,
-      f
+      f,
+      proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistoryEntry.serializeBinaryToWriter
     );
   }
 };
 
 
 /**
- * optional bytes id = 1;
- * @return {string}
+ * repeated DataContractHistoryEntry data_contract_entries = 1;
+ * @return {!Array<!proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistoryEntry>}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractRequest.prototype.getId = function() {
-  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 1, ""));
+proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistory.prototype.getDataContractEntriesList = function() {
+  return /** @type{!Array<!proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistoryEntry>} */ (
+    jspb.Message.getRepeatedWrapperField(this, proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistoryEntry, 1));
+};
+
+
+/**
+ * @param {!Array<!proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistoryEntry>} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistory} returns this
+*/
+proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistory.prototype.setDataContractEntriesList = function(value) {
+  return jspb.Message.setRepeatedWrapperField(this, 1, value);
+};
+
+
+/**
+ * @param {!proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistoryEntry=} opt_value
+ * @param {number=} opt_index
+ * @return {!proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistoryEntry}
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistory.prototype.addDataContractEntries = function(opt_value, opt_index) {
+  return jspb.Message.addToRepeatedWrapperField(this, 1, opt_value, proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistoryEntry, opt_index);
+};
+
+
+/**
+ * Clears the list making it empty but non-null.
+ * @return {!proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistory} returns this
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistory.prototype.clearDataContractEntriesList = function() {
+  return this.setDataContractEntriesList([]);
+};
+
+
+/**
+ * optional DataContractHistory data_contract_history = 1;
+ * @return {?proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistory}
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.prototype.getDataContractHistory = function() {
+  return /** @type{?proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistory} */ (
+    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistory, 1));
+};
+
+
+/**
+ * @param {?proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistory|undefined} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse} returns this
+*/
+proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.prototype.setDataContractHistory = function(value) {
+  return jspb.Message.setOneofWrapperField(this, 1, proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.oneofGroups_[0], value);
+};
+
+
+/**
+ * Clears the message field making it undefined.
+ * @return {!proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse} returns this
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.prototype.clearDataContractHistory = function() {
+  return this.setDataContractHistory(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.prototype.hasDataContractHistory = function() {
+  return jspb.Message.getField(this, 1) != null;
+};
+
+
+/**
+ * optional Proof proof = 2;
+ * @return {?proto.org.dash.platform.dapi.v0.Proof}
+ */
+proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.prototype.getProof = function() {
+  return /** @type{?proto.org.dash.platform.dapi.v0.Proof} */ (
+    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.Proof, 2));
+};
+
+
+/**
+ * @param {?proto.org.dash.platform.dapi.v0.Proof|undefined} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse} returns this
+*/
+proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.prototype.setProof = function(value) {
+  return jspb.Message.setOneofWrapperField(this, 2, proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.oneofGroups_[0], value);
 };
 
 
 /**
- * optional bytes id = 1;
- * This is a type-conversion wrapper around `getId()`
- * @return {string}
+ * Clears the message field making it undefined.
+ * @return {!proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse} returns this
  */
-proto.org.dash.platform.dapi.v0.GetDataContractRequest.prototype.getId_asB64 = function() {
-  return /** @type {string} */ (jspb.Message.bytesAsB64(
-      this.getId()));
+proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.prototype.clearProof = function() {
+  return this.setProof(undefined);
 };
 
 
 /**
- * optional bytes id = 1;
- * Note that Uint8Array is not supported on all browsers.
- * @see http://caniuse.com/Uint8Array
- * This is a type-conversion wrapper around `getId()`
- * @return {!Uint8Array}
+ * Returns whether this field is set.
+ * @return {boolean}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractRequest.prototype.getId_asU8 = function() {
-  return /** @type {!Uint8Array} */ (jspb.Message.bytesAsU8(
-      this.getId()));
+proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.prototype.hasProof = function() {
+  return jspb.Message.getField(this, 2) != null;
 };
 
 
 /**
- * @param {!(string|Uint8Array)} value
- * @return {!proto.org.dash.platform.dapi.v0.GetDataContractRequest} returns this
+ * optional ResponseMetadata metadata = 3;
+ * @return {?proto.org.dash.platform.dapi.v0.ResponseMetadata}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractRequest.prototype.setId = function(value) {
-  return jspb.Message.setProto3BytesField(this, 1, value);
+proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.prototype.getMetadata = function() {
+  return /** @type{?proto.org.dash.platform.dapi.v0.ResponseMetadata} */ (
+    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.ResponseMetadata, 3));
 };
 
 
 /**
- * optional bool prove = 2;
- * @return {boolean}
+ * @param {?proto.org.dash.platform.dapi.v0.ResponseMetadata|undefined} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse} returns this
+*/
+proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.prototype.setMetadata = function(value) {
+  return jspb.Message.setWrapperField(this, 3, value);
+};
+
+
+/**
+ * Clears the message field making it undefined.
+ * @return {!proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse} returns this
  */
-proto.org.dash.platform.dapi.v0.GetDataContractRequest.prototype.getProve = function() {
-  return /** @type {boolean} */ (jspb.Message.getBooleanFieldWithDefault(this, 2, false));
+proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.prototype.clearMetadata = function() {
+  return this.setMetadata(undefined);
 };
 
 
 /**
- * @param {boolean} value
- * @return {!proto.org.dash.platform.dapi.v0.GetDataContractRequest} returns this
+ * Returns whether this field is set.
+ * @return {boolean}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractRequest.prototype.setProve = function(value) {
-  return jspb.Message.setProto3BooleanField(this, 2, value);
+proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.prototype.hasMetadata = function() {
+  return jspb.Message.getField(this, 3) != null;
 };
 
 
@@ -8756,22 +12161,22 @@ proto.org.dash.platform.dapi.v0.GetDataContractRequest.prototype.setProve = func
  * @private {!Array<!Array<number>>}
  * @const
  */
-proto.org.dash.platform.dapi.v0.GetDataContractResponse.oneofGroups_ = [[1,2]];
+proto.org.dash.platform.dapi.v0.GetDocumentsRequest.oneofGroups_ = [[6,7]];
 
 /**
  * @enum {number}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractResponse.ResultCase = {
-  RESULT_NOT_SET: 0,
-  DATA_CONTRACT: 1,
-  PROOF: 2
+proto.org.dash.platform.dapi.v0.GetDocumentsRequest.StartCase = {
+  START_NOT_SET: 0,
+  START_AFTER: 6,
+  START_AT: 7
 };
 
 /**
- * @return {proto.org.dash.platform.dapi.v0.GetDataContractResponse.ResultCase}
+ * @return {proto.org.dash.platform.dapi.v0.GetDocumentsRequest.StartCase}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractResponse.prototype.getResultCase = function() {
-  return /** @type {proto.org.dash.platform.dapi.v0.GetDataContractResponse.ResultCase} */(jspb.Message.computeOneofCase(this, proto.org.dash.platform.dapi.v0.GetDataContractResponse.oneofGroups_[0]));
+proto.org.dash.platform.dapi.v0.GetDocumentsRequest.prototype.getStartCase = function() {
+  return /** @type {proto.org.dash.platform.dapi.v0.GetDocumentsRequest.StartCase} */(jspb.Message.computeOneofCase(this, proto.org.dash.platform.dapi.v0.GetDocumentsRequest.oneofGroups_[0]));
 };
 
 
@@ -8789,8 +12194,8 @@ if (jspb.Message.GENERATE_TO_OBJECT) {
  *     http://goto/soy-param-migration
  * @return {!Object}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractResponse.prototype.toObject = function(opt_includeInstance) {
-  return proto.org.dash.platform.dapi.v0.GetDataContractResponse.toObject(opt_includeInstance, this);
+proto.org.dash.platform.dapi.v0.GetDocumentsRequest.prototype.toObject = function(opt_includeInstance) {
+  return proto.org.dash.platform.dapi.v0.GetDocumentsRequest.toObject(opt_includeInstance, this);
 };
 
 
@@ -8799,15 +12204,20 @@ proto.org.dash.platform.dapi.v0.GetDataContractResponse.prototype.toObject = fun
  * @param {boolean|undefined} includeInstance Deprecated. Whether to include
  *     the JSPB instance for transitional soy proto support:
  *     http://goto/soy-param-migration
- * @param {!proto.org.dash.platform.dapi.v0.GetDataContractResponse} msg The msg instance to transform.
+ * @param {!proto.org.dash.platform.dapi.v0.GetDocumentsRequest} msg The msg instance to transform.
  * @return {!Object}
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.org.dash.platform.dapi.v0.GetDataContractResponse.toObject = function(includeInstance, msg) {
+proto.org.dash.platform.dapi.v0.GetDocumentsRequest.toObject = function(includeInstance, msg) {
   var f, obj = {
-    dataContract: msg.getDataContract_asB64(),
-    proof: (f = msg.getProof()) && proto.org.dash.platform.dapi.v0.Proof.toObject(includeInstance, f),
-    metadata: (f = msg.getMetadata()) && proto.org.dash.platform.dapi.v0.ResponseMetadata.toObject(includeInstance, f)
+    dataContractId: msg.getDataContractId_asB64(),
+    documentType: jspb.Message.getFieldWithDefault(msg, 2, ""),
+    where: msg.getWhere_asB64(),
+    orderBy: msg.getOrderBy_asB64(),
+    limit: jspb.Message.getFieldWithDefault(msg, 5, 0),
+    startAfter: msg.getStartAfter_asB64(),
+    startAt: msg.getStartAt_asB64(),
+    prove: jspb.Message.getBooleanFieldWithDefault(msg, 8, false)
   };
 
   if (includeInstance) {
@@ -8821,23 +12231,23 @@ proto.org.dash.platform.dapi.v0.GetDataContractResponse.toObject = function(incl
 /**
  * Deserializes binary data (in protobuf wire format).
  * @param {jspb.ByteSource} bytes The bytes to deserialize.
- * @return {!proto.org.dash.platform.dapi.v0.GetDataContractResponse}
+ * @return {!proto.org.dash.platform.dapi.v0.GetDocumentsRequest}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractResponse.deserializeBinary = function(bytes) {
+proto.org.dash.platform.dapi.v0.GetDocumentsRequest.deserializeBinary = function(bytes) {
   var reader = new jspb.BinaryReader(bytes);
-  var msg = new proto.org.dash.platform.dapi.v0.GetDataContractResponse;
-  return proto.org.dash.platform.dapi.v0.GetDataContractResponse.deserializeBinaryFromReader(msg, reader);
+  var msg = new proto.org.dash.platform.dapi.v0.GetDocumentsRequest;
+  return proto.org.dash.platform.dapi.v0.GetDocumentsRequest.deserializeBinaryFromReader(msg, reader);
 };
 
 
 /**
  * Deserializes binary data (in protobuf wire format) from the
  * given reader into the given message object.
- * @param {!proto.org.dash.platform.dapi.v0.GetDataContractResponse} msg The message object to deserialize into.
+ * @param {!proto.org.dash.platform.dapi.v0.GetDocumentsRequest} msg The message object to deserialize into.
  * @param {!jspb.BinaryReader} reader The BinaryReader to use.
- * @return {!proto.org.dash.platform.dapi.v0.GetDataContractResponse}
+ * @return {!proto.org.dash.platform.dapi.v0.GetDocumentsRequest}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractResponse.deserializeBinaryFromReader = function(msg, reader) {
+proto.org.dash.platform.dapi.v0.GetDocumentsRequest.deserializeBinaryFromReader = function(msg, reader) {
   while (reader.nextField()) {
     if (reader.isEndGroup()) {
       break;
@@ -8846,17 +12256,35 @@ proto.org.dash.platform.dapi.v0.GetDataContractResponse.deserializeBinaryFromRea
     switch (field) {
     case 1:
       var value = /** @type {!Uint8Array} */ (reader.readBytes());
-      msg.setDataContract(value);
+      msg.setDataContractId(value);
       break;
     case 2:
-      var value = new proto.org.dash.platform.dapi.v0.Proof;
-      reader.readMessage(value,proto.org.dash.platform.dapi.v0.Proof.deserializeBinaryFromReader);
-      msg.setProof(value);
+      var value = /** @type {string} */ (reader.readString());
+      msg.setDocumentType(value);
       break;
     case 3:
-      var value = new proto.org.dash.platform.dapi.v0.ResponseMetadata;
-      reader.readMessage(value,proto.org.dash.platform.dapi.v0.ResponseMetadata.deserializeBinaryFromReader);
-      msg.setMetadata(value);
+      var value = /** @type {!Uint8Array} */ (reader.readBytes());
+      msg.setWhere(value);
+      break;
+    case 4:
+      var value = /** @type {!Uint8Array} */ (reader.readBytes());
+      msg.setOrderBy(value);
+      break;
+    case 5:
+      var value = /** @type {number} */ (reader.readUint32());
+      msg.setLimit(value);
+      break;
+    case 6:
+      var value = /** @type {!Uint8Array} */ (reader.readBytes());
+      msg.setStartAfter(value);
+      break;
+    case 7:
+      var value = /** @type {!Uint8Array} */ (reader.readBytes());
+      msg.setStartAt(value);
+      break;
+    case 8:
+      var value = /** @type {boolean} */ (reader.readBool());
+      msg.setProve(value);
       break;
     default:
       reader.skipField();
@@ -8871,9 +12299,9 @@ proto.org.dash.platform.dapi.v0.GetDataContractResponse.deserializeBinaryFromRea
  * Serializes the message to binary data (in protobuf wire format).
  * @return {!Uint8Array}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractResponse.prototype.serializeBinary = function() {
+proto.org.dash.platform.dapi.v0.GetDocumentsRequest.prototype.serializeBinary = function() {
   var writer = new jspb.BinaryWriter();
-  proto.org.dash.platform.dapi.v0.GetDataContractResponse.serializeBinaryToWriter(this, writer);
+  proto.org.dash.platform.dapi.v0.GetDocumentsRequest.serializeBinaryToWriter(this, writer);
   return writer.getResultBuffer();
 };
 
@@ -8881,379 +12309,368 @@ proto.org.dash.platform.dapi.v0.GetDataContractResponse.prototype.serializeBinar
 /**
  * Serializes the given message to binary data (in protobuf wire
  * format), writing to the given BinaryWriter.
- * @param {!proto.org.dash.platform.dapi.v0.GetDataContractResponse} message
+ * @param {!proto.org.dash.platform.dapi.v0.GetDocumentsRequest} message
  * @param {!jspb.BinaryWriter} writer
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.org.dash.platform.dapi.v0.GetDataContractResponse.serializeBinaryToWriter = function(message, writer) {
+proto.org.dash.platform.dapi.v0.GetDocumentsRequest.serializeBinaryToWriter = function(message, writer) {
   var f = undefined;
-  f = /** @type {!(string|Uint8Array)} */ (jspb.Message.getField(message, 1));
-  if (f != null) {
+  f = message.getDataContractId_asU8();
+  if (f.length > 0) {
     writer.writeBytes(
       1,
       f
     );
   }
-  f = message.getProof();
-  if (f != null) {
-    writer.writeMessage(
+  f = message.getDocumentType();
+  if (f.length > 0) {
+    writer.writeString(
       2,
-      f,
-      proto.org.dash.platform.dapi.v0.Proof.serializeBinaryToWriter
+      f
     );
   }
-  f = message.getMetadata();
-  if (f != null) {
-    writer.writeMessage(
+  f = message.getWhere_asU8();
+  if (f.length > 0) {
+    writer.writeBytes(
       3,
-      f,
-      proto.org.dash.platform.dapi.v0.ResponseMetadata.serializeBinaryToWriter
+      f
+    );
+  }
+  f = message.getOrderBy_asU8();
+  if (f.length > 0) {
+    writer.writeBytes(
+      4,
+      f
+    );
+  }
+  f = message.getLimit();
+  if (f !== 0) {
+    writer.writeUint32(
+      5,
+      f
+    );
+  }
+  f = /** @type {!(string|Uint8Array)} */ (jspb.Message.getField(message, 6));
+  if (f != null) {
+    writer.writeBytes(
+      6,
+      f
+    );
+  }
+  f = /** @type {!(string|Uint8Array)} */ (jspb.Message.getField(message, 7));
+  if (f != null) {
+    writer.writeBytes(
+      7,
+      f
+    );
+  }
+  f = message.getProve();
+  if (f) {
+    writer.writeBool(
+      8,
+      f
     );
   }
 };
 
 
 /**
- * optional bytes data_contract = 1;
+ * optional bytes data_contract_id = 1;
  * @return {string}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractResponse.prototype.getDataContract = function() {
+proto.org.dash.platform.dapi.v0.GetDocumentsRequest.prototype.getDataContractId = function() {
   return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 1, ""));
 };
 
 
 /**
- * optional bytes data_contract = 1;
- * This is a type-conversion wrapper around `getDataContract()`
+ * optional bytes data_contract_id = 1;
+ * This is a type-conversion wrapper around `getDataContractId()`
  * @return {string}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractResponse.prototype.getDataContract_asB64 = function() {
+proto.org.dash.platform.dapi.v0.GetDocumentsRequest.prototype.getDataContractId_asB64 = function() {
   return /** @type {string} */ (jspb.Message.bytesAsB64(
-      this.getDataContract()));
+      this.getDataContractId()));
 };
 
 
 /**
- * optional bytes data_contract = 1;
+ * optional bytes data_contract_id = 1;
  * Note that Uint8Array is not supported on all browsers.
  * @see http://caniuse.com/Uint8Array
- * This is a type-conversion wrapper around `getDataContract()`
+ * This is a type-conversion wrapper around `getDataContractId()`
  * @return {!Uint8Array}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractResponse.prototype.getDataContract_asU8 = function() {
+proto.org.dash.platform.dapi.v0.GetDocumentsRequest.prototype.getDataContractId_asU8 = function() {
   return /** @type {!Uint8Array} */ (jspb.Message.bytesAsU8(
-      this.getDataContract()));
+      this.getDataContractId()));
 };
 
 
 /**
  * @param {!(string|Uint8Array)} value
- * @return {!proto.org.dash.platform.dapi.v0.GetDataContractResponse} returns this
+ * @return {!proto.org.dash.platform.dapi.v0.GetDocumentsRequest} returns this
  */
-proto.org.dash.platform.dapi.v0.GetDataContractResponse.prototype.setDataContract = function(value) {
-  return jspb.Message.setOneofField(this, 1, proto.org.dash.platform.dapi.v0.GetDataContractResponse.oneofGroups_[0], value);
+proto.org.dash.platform.dapi.v0.GetDocumentsRequest.prototype.setDataContractId = function(value) {
+  return jspb.Message.setProto3BytesField(this, 1, value);
 };
 
 
 /**
- * Clears the field making it undefined.
- * @return {!proto.org.dash.platform.dapi.v0.GetDataContractResponse} returns this
+ * optional string document_type = 2;
+ * @return {string}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractResponse.prototype.clearDataContract = function() {
-  return jspb.Message.setOneofField(this, 1, proto.org.dash.platform.dapi.v0.GetDataContractResponse.oneofGroups_[0], undefined);
+proto.org.dash.platform.dapi.v0.GetDocumentsRequest.prototype.getDocumentType = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 2, ""));
 };
 
 
 /**
- * Returns whether this field is set.
- * @return {boolean}
+ * @param {string} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetDocumentsRequest} returns this
  */
-proto.org.dash.platform.dapi.v0.GetDataContractResponse.prototype.hasDataContract = function() {
-  return jspb.Message.getField(this, 1) != null;
+proto.org.dash.platform.dapi.v0.GetDocumentsRequest.prototype.setDocumentType = function(value) {
+  return jspb.Message.setProto3StringField(this, 2, value);
 };
 
 
 /**
- * optional Proof proof = 2;
- * @return {?proto.org.dash.platform.dapi.v0.Proof}
+ * optional bytes where = 3;
+ * @return {string}
+ */
+proto.org.dash.platform.dapi.v0.GetDocumentsRequest.prototype.getWhere = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 3, ""));
+};
+
+
+/**
+ * optional bytes where = 3;
+ * This is a type-conversion wrapper around `getWhere()`
+ * @return {string}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractResponse.prototype.getProof = function() {
-  return /** @type{?proto.org.dash.platform.dapi.v0.Proof} */ (
-    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.Proof, 2));
+proto.org.dash.platform.dapi.v0.GetDocumentsRequest.prototype.getWhere_asB64 = function() {
+  return /** @type {string} */ (jspb.Message.bytesAsB64(
+      this.getWhere()));
 };
 
 
 /**
- * @param {?proto.org.dash.platform.dapi.v0.Proof|undefined} value
- * @return {!proto.org.dash.platform.dapi.v0.GetDataContractResponse} returns this
-*/
-proto.org.dash.platform.dapi.v0.GetDataContractResponse.prototype.setProof = function(value) {
-  return jspb.Message.setOneofWrapperField(this, 2, proto.org.dash.platform.dapi.v0.GetDataContractResponse.oneofGroups_[0], value);
+ * optional bytes where = 3;
+ * Note that Uint8Array is not supported on all browsers.
+ * @see http://caniuse.com/Uint8Array
+ * This is a type-conversion wrapper around `getWhere()`
+ * @return {!Uint8Array}
+ */
+proto.org.dash.platform.dapi.v0.GetDocumentsRequest.prototype.getWhere_asU8 = function() {
+  return /** @type {!Uint8Array} */ (jspb.Message.bytesAsU8(
+      this.getWhere()));
 };
 
 
 /**
- * Clears the message field making it undefined.
- * @return {!proto.org.dash.platform.dapi.v0.GetDataContractResponse} returns this
+ * @param {!(string|Uint8Array)} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetDocumentsRequest} returns this
  */
-proto.org.dash.platform.dapi.v0.GetDataContractResponse.prototype.clearProof = function() {
-  return this.setProof(undefined);
+proto.org.dash.platform.dapi.v0.GetDocumentsRequest.prototype.setWhere = function(value) {
+  return jspb.Message.setProto3BytesField(this, 3, value);
 };
 
 
 /**
- * Returns whether this field is set.
- * @return {boolean}
+ * optional bytes order_by = 4;
+ * @return {string}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractResponse.prototype.hasProof = function() {
-  return jspb.Message.getField(this, 2) != null;
+proto.org.dash.platform.dapi.v0.GetDocumentsRequest.prototype.getOrderBy = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 4, ""));
 };
 
 
 /**
- * optional ResponseMetadata metadata = 3;
- * @return {?proto.org.dash.platform.dapi.v0.ResponseMetadata}
+ * optional bytes order_by = 4;
+ * This is a type-conversion wrapper around `getOrderBy()`
+ * @return {string}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractResponse.prototype.getMetadata = function() {
-  return /** @type{?proto.org.dash.platform.dapi.v0.ResponseMetadata} */ (
-    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.ResponseMetadata, 3));
+proto.org.dash.platform.dapi.v0.GetDocumentsRequest.prototype.getOrderBy_asB64 = function() {
+  return /** @type {string} */ (jspb.Message.bytesAsB64(
+      this.getOrderBy()));
 };
 
 
 /**
- * @param {?proto.org.dash.platform.dapi.v0.ResponseMetadata|undefined} value
- * @return {!proto.org.dash.platform.dapi.v0.GetDataContractResponse} returns this
-*/
-proto.org.dash.platform.dapi.v0.GetDataContractResponse.prototype.setMetadata = function(value) {
-  return jspb.Message.setWrapperField(this, 3, value);
+ * optional bytes order_by = 4;
+ * Note that Uint8Array is not supported on all browsers.
+ * @see http://caniuse.com/Uint8Array
+ * This is a type-conversion wrapper around `getOrderBy()`
+ * @return {!Uint8Array}
+ */
+proto.org.dash.platform.dapi.v0.GetDocumentsRequest.prototype.getOrderBy_asU8 = function() {
+  return /** @type {!Uint8Array} */ (jspb.Message.bytesAsU8(
+      this.getOrderBy()));
 };
 
 
 /**
- * Clears the message field making it undefined.
- * @return {!proto.org.dash.platform.dapi.v0.GetDataContractResponse} returns this
+ * @param {!(string|Uint8Array)} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetDocumentsRequest} returns this
  */
-proto.org.dash.platform.dapi.v0.GetDataContractResponse.prototype.clearMetadata = function() {
-  return this.setMetadata(undefined);
+proto.org.dash.platform.dapi.v0.GetDocumentsRequest.prototype.setOrderBy = function(value) {
+  return jspb.Message.setProto3BytesField(this, 4, value);
 };
 
 
 /**
- * Returns whether this field is set.
- * @return {boolean}
+ * optional uint32 limit = 5;
+ * @return {number}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractResponse.prototype.hasMetadata = function() {
-  return jspb.Message.getField(this, 3) != null;
+proto.org.dash.platform.dapi.v0.GetDocumentsRequest.prototype.getLimit = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 5, 0));
 };
 
 
-
 /**
- * List of repeated fields within this message type.
- * @private {!Array<number>}
- * @const
+ * @param {number} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetDocumentsRequest} returns this
  */
-proto.org.dash.platform.dapi.v0.GetDataContractsRequest.repeatedFields_ = [1];
-
+proto.org.dash.platform.dapi.v0.GetDocumentsRequest.prototype.setLimit = function(value) {
+  return jspb.Message.setProto3IntField(this, 5, value);
+};
 
 
-if (jspb.Message.GENERATE_TO_OBJECT) {
 /**
- * Creates an object representation of this proto.
- * Field names that are reserved in JavaScript and will be renamed to pb_name.
- * Optional fields that are not set will be set to undefined.
- * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
- * For the list of reserved names please see:
- *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
- * @param {boolean=} opt_includeInstance Deprecated. whether to include the
- *     JSPB instance for transitional soy proto support:
- *     http://goto/soy-param-migration
- * @return {!Object}
+ * optional bytes start_after = 6;
+ * @return {string}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractsRequest.prototype.toObject = function(opt_includeInstance) {
-  return proto.org.dash.platform.dapi.v0.GetDataContractsRequest.toObject(opt_includeInstance, this);
+proto.org.dash.platform.dapi.v0.GetDocumentsRequest.prototype.getStartAfter = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 6, ""));
 };
 
 
 /**
- * Static version of the {@see toObject} method.
- * @param {boolean|undefined} includeInstance Deprecated. Whether to include
- *     the JSPB instance for transitional soy proto support:
- *     http://goto/soy-param-migration
- * @param {!proto.org.dash.platform.dapi.v0.GetDataContractsRequest} msg The msg instance to transform.
- * @return {!Object}
- * @suppress {unusedLocalVariables} f is only used for nested messages
+ * optional bytes start_after = 6;
+ * This is a type-conversion wrapper around `getStartAfter()`
+ * @return {string}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractsRequest.toObject = function(includeInstance, msg) {
-  var f, obj = {
-    idsList: msg.getIdsList_asB64(),
-    prove: jspb.Message.getBooleanFieldWithDefault(msg, 2, false)
-  };
-
-  if (includeInstance) {
-    obj.$jspbMessageInstance = msg;
-  }
-  return obj;
+proto.org.dash.platform.dapi.v0.GetDocumentsRequest.prototype.getStartAfter_asB64 = function() {
+  return /** @type {string} */ (jspb.Message.bytesAsB64(
+      this.getStartAfter()));
 };
-}
 
 
 /**
- * Deserializes binary data (in protobuf wire format).
- * @param {jspb.ByteSource} bytes The bytes to deserialize.
- * @return {!proto.org.dash.platform.dapi.v0.GetDataContractsRequest}
+ * optional bytes start_after = 6;
+ * Note that Uint8Array is not supported on all browsers.
+ * @see http://caniuse.com/Uint8Array
+ * This is a type-conversion wrapper around `getStartAfter()`
+ * @return {!Uint8Array}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractsRequest.deserializeBinary = function(bytes) {
-  var reader = new jspb.BinaryReader(bytes);
-  var msg = new proto.org.dash.platform.dapi.v0.GetDataContractsRequest;
-  return proto.org.dash.platform.dapi.v0.GetDataContractsRequest.deserializeBinaryFromReader(msg, reader);
+proto.org.dash.platform.dapi.v0.GetDocumentsRequest.prototype.getStartAfter_asU8 = function() {
+  return /** @type {!Uint8Array} */ (jspb.Message.bytesAsU8(
+      this.getStartAfter()));
 };
 
 
 /**
- * Deserializes binary data (in protobuf wire format) from the
- * given reader into the given message object.
- * @param {!proto.org.dash.platform.dapi.v0.GetDataContractsRequest} msg The message object to deserialize into.
- * @param {!jspb.BinaryReader} reader The BinaryReader to use.
- * @return {!proto.org.dash.platform.dapi.v0.GetDataContractsRequest}
+ * @param {!(string|Uint8Array)} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetDocumentsRequest} returns this
  */
-proto.org.dash.platform.dapi.v0.GetDataContractsRequest.deserializeBinaryFromReader = function(msg, reader) {
-  while (reader.nextField()) {
-    if (reader.isEndGroup()) {
-      break;
-    }
-    var field = reader.getFieldNumber();
-    switch (field) {
-    case 1:
-      var value = /** @type {!Uint8Array} */ (reader.readBytes());
-      msg.addIds(value);
-      break;
-    case 2:
-      var value = /** @type {boolean} */ (reader.readBool());
-      msg.setProve(value);
-      break;
-    default:
-      reader.skipField();
-      break;
-    }
-  }
-  return msg;
+proto.org.dash.platform.dapi.v0.GetDocumentsRequest.prototype.setStartAfter = function(value) {
+  return jspb.Message.setOneofField(this, 6, proto.org.dash.platform.dapi.v0.GetDocumentsRequest.oneofGroups_[0], value);
 };
 
 
 /**
- * Serializes the message to binary data (in protobuf wire format).
- * @return {!Uint8Array}
+ * Clears the field making it undefined.
+ * @return {!proto.org.dash.platform.dapi.v0.GetDocumentsRequest} returns this
  */
-proto.org.dash.platform.dapi.v0.GetDataContractsRequest.prototype.serializeBinary = function() {
-  var writer = new jspb.BinaryWriter();
-  proto.org.dash.platform.dapi.v0.GetDataContractsRequest.serializeBinaryToWriter(this, writer);
-  return writer.getResultBuffer();
+proto.org.dash.platform.dapi.v0.GetDocumentsRequest.prototype.clearStartAfter = function() {
+  return jspb.Message.setOneofField(this, 6, proto.org.dash.platform.dapi.v0.GetDocumentsRequest.oneofGroups_[0], undefined);
 };
 
 
 /**
- * Serializes the given message to binary data (in protobuf wire
- * format), writing to the given BinaryWriter.
- * @param {!proto.org.dash.platform.dapi.v0.GetDataContractsRequest} message
- * @param {!jspb.BinaryWriter} writer
- * @suppress {unusedLocalVariables} f is only used for nested messages
+ * Returns whether this field is set.
+ * @return {boolean}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractsRequest.serializeBinaryToWriter = function(message, writer) {
-  var f = undefined;
-  f = message.getIdsList_asU8();
-  if (f.length > 0) {
-    writer.writeRepeatedBytes(
-      1,
-      f
-    );
-  }
-  f = message.getProve();
-  if (f) {
-    writer.writeBool(
-      2,
-      f
-    );
-  }
+proto.org.dash.platform.dapi.v0.GetDocumentsRequest.prototype.hasStartAfter = function() {
+  return jspb.Message.getField(this, 6) != null;
 };
 
 
 /**
- * repeated bytes ids = 1;
- * @return {!Array<string>}
+ * optional bytes start_at = 7;
+ * @return {string}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractsRequest.prototype.getIdsList = function() {
-  return /** @type {!Array<string>} */ (jspb.Message.getRepeatedField(this, 1));
+proto.org.dash.platform.dapi.v0.GetDocumentsRequest.prototype.getStartAt = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 7, ""));
 };
 
 
 /**
- * repeated bytes ids = 1;
- * This is a type-conversion wrapper around `getIdsList()`
- * @return {!Array<string>}
+ * optional bytes start_at = 7;
+ * This is a type-conversion wrapper around `getStartAt()`
+ * @return {string}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractsRequest.prototype.getIdsList_asB64 = function() {
-  return /** @type {!Array<string>} */ (jspb.Message.bytesListAsB64(
-      this.getIdsList()));
+proto.org.dash.platform.dapi.v0.GetDocumentsRequest.prototype.getStartAt_asB64 = function() {
+  return /** @type {string} */ (jspb.Message.bytesAsB64(
+      this.getStartAt()));
 };
 
 
 /**
- * repeated bytes ids = 1;
+ * optional bytes start_at = 7;
  * Note that Uint8Array is not supported on all browsers.
  * @see http://caniuse.com/Uint8Array
- * This is a type-conversion wrapper around `getIdsList()`
- * @return {!Array<!Uint8Array>}
+ * This is a type-conversion wrapper around `getStartAt()`
+ * @return {!Uint8Array}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractsRequest.prototype.getIdsList_asU8 = function() {
-  return /** @type {!Array<!Uint8Array>} */ (jspb.Message.bytesListAsU8(
-      this.getIdsList()));
+proto.org.dash.platform.dapi.v0.GetDocumentsRequest.prototype.getStartAt_asU8 = function() {
+  return /** @type {!Uint8Array} */ (jspb.Message.bytesAsU8(
+      this.getStartAt()));
 };
 
 
 /**
- * @param {!(Array<!Uint8Array>|Array<string>)} value
- * @return {!proto.org.dash.platform.dapi.v0.GetDataContractsRequest} returns this
+ * @param {!(string|Uint8Array)} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetDocumentsRequest} returns this
  */
-proto.org.dash.platform.dapi.v0.GetDataContractsRequest.prototype.setIdsList = function(value) {
-  return jspb.Message.setField(this, 1, value || []);
+proto.org.dash.platform.dapi.v0.GetDocumentsRequest.prototype.setStartAt = function(value) {
+  return jspb.Message.setOneofField(this, 7, proto.org.dash.platform.dapi.v0.GetDocumentsRequest.oneofGroups_[0], value);
 };
 
-
-/**
- * @param {!(string|Uint8Array)} value
- * @param {number=} opt_index
- * @return {!proto.org.dash.platform.dapi.v0.GetDataContractsRequest} returns this
+
+/**
+ * Clears the field making it undefined.
+ * @return {!proto.org.dash.platform.dapi.v0.GetDocumentsRequest} returns this
  */
-proto.org.dash.platform.dapi.v0.GetDataContractsRequest.prototype.addIds = function(value, opt_index) {
-  return jspb.Message.addToRepeatedField(this, 1, value, opt_index);
+proto.org.dash.platform.dapi.v0.GetDocumentsRequest.prototype.clearStartAt = function() {
+  return jspb.Message.setOneofField(this, 7, proto.org.dash.platform.dapi.v0.GetDocumentsRequest.oneofGroups_[0], undefined);
 };
 
 
 /**
- * Clears the list making it empty but non-null.
- * @return {!proto.org.dash.platform.dapi.v0.GetDataContractsRequest} returns this
+ * Returns whether this field is set.
+ * @return {boolean}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractsRequest.prototype.clearIdsList = function() {
-  return this.setIdsList([]);
+proto.org.dash.platform.dapi.v0.GetDocumentsRequest.prototype.hasStartAt = function() {
+  return jspb.Message.getField(this, 7) != null;
 };
 
 
 /**
- * optional bool prove = 2;
+ * optional bool prove = 8;
  * @return {boolean}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractsRequest.prototype.getProve = function() {
-  return /** @type {boolean} */ (jspb.Message.getBooleanFieldWithDefault(this, 2, false));
+proto.org.dash.platform.dapi.v0.GetDocumentsRequest.prototype.getProve = function() {
+  return /** @type {boolean} */ (jspb.Message.getBooleanFieldWithDefault(this, 8, false));
 };
 
 
 /**
  * @param {boolean} value
- * @return {!proto.org.dash.platform.dapi.v0.GetDataContractsRequest} returns this
+ * @return {!proto.org.dash.platform.dapi.v0.GetDocumentsRequest} returns this
  */
-proto.org.dash.platform.dapi.v0.GetDataContractsRequest.prototype.setProve = function(value) {
-  return jspb.Message.setProto3BooleanField(this, 2, value);
+proto.org.dash.platform.dapi.v0.GetDocumentsRequest.prototype.setProve = function(value) {
+  return jspb.Message.setProto3BooleanField(this, 8, value);
 };
 
 
@@ -9266,22 +12683,22 @@ proto.org.dash.platform.dapi.v0.GetDataContractsRequest.prototype.setProve = fun
  * @private {!Array<!Array<number>>}
  * @const
  */
-proto.org.dash.platform.dapi.v0.GetDataContractsResponse.oneofGroups_ = [[1,2]];
+proto.org.dash.platform.dapi.v0.GetDocumentsResponse.oneofGroups_ = [[1,2]];
 
 /**
  * @enum {number}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractsResponse.ResultCase = {
+proto.org.dash.platform.dapi.v0.GetDocumentsResponse.ResultCase = {
   RESULT_NOT_SET: 0,
-  DATA_CONTRACTS: 1,
+  DOCUMENTS: 1,
   PROOF: 2
 };
 
 /**
- * @return {proto.org.dash.platform.dapi.v0.GetDataContractsResponse.ResultCase}
+ * @return {proto.org.dash.platform.dapi.v0.GetDocumentsResponse.ResultCase}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractsResponse.prototype.getResultCase = function() {
-  return /** @type {proto.org.dash.platform.dapi.v0.GetDataContractsResponse.ResultCase} */(jspb.Message.computeOneofCase(this, proto.org.dash.platform.dapi.v0.GetDataContractsResponse.oneofGroups_[0]));
+proto.org.dash.platform.dapi.v0.GetDocumentsResponse.prototype.getResultCase = function() {
+  return /** @type {proto.org.dash.platform.dapi.v0.GetDocumentsResponse.ResultCase} */(jspb.Message.computeOneofCase(this, proto.org.dash.platform.dapi.v0.GetDocumentsResponse.oneofGroups_[0]));
 };
 
 
@@ -9299,8 +12716,8 @@ if (jspb.Message.GENERATE_TO_OBJECT) {
  *     http://goto/soy-param-migration
  * @return {!Object}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractsResponse.prototype.toObject = function(opt_includeInstance) {
-  return proto.org.dash.platform.dapi.v0.GetDataContractsResponse.toObject(opt_includeInstance, this);
+proto.org.dash.platform.dapi.v0.GetDocumentsResponse.prototype.toObject = function(opt_includeInstance) {
+  return proto.org.dash.platform.dapi.v0.GetDocumentsResponse.toObject(opt_includeInstance, this);
 };
 
 
@@ -9309,13 +12726,13 @@ proto.org.dash.platform.dapi.v0.GetDataContractsResponse.prototype.toObject = fu
  * @param {boolean|undefined} includeInstance Deprecated. Whether to include
  *     the JSPB instance for transitional soy proto support:
  *     http://goto/soy-param-migration
- * @param {!proto.org.dash.platform.dapi.v0.GetDataContractsResponse} msg The msg instance to transform.
+ * @param {!proto.org.dash.platform.dapi.v0.GetDocumentsResponse} msg The msg instance to transform.
  * @return {!Object}
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.org.dash.platform.dapi.v0.GetDataContractsResponse.toObject = function(includeInstance, msg) {
+proto.org.dash.platform.dapi.v0.GetDocumentsResponse.toObject = function(includeInstance, msg) {
   var f, obj = {
-    dataContracts: (f = msg.getDataContracts()) && proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContracts.toObject(includeInstance, f),
+    documents: (f = msg.getDocuments()) && proto.org.dash.platform.dapi.v0.GetDocumentsResponse.Documents.toObject(includeInstance, f),
     proof: (f = msg.getProof()) && proto.org.dash.platform.dapi.v0.Proof.toObject(includeInstance, f),
     metadata: (f = msg.getMetadata()) && proto.org.dash.platform.dapi.v0.ResponseMetadata.toObject(includeInstance, f)
   };
@@ -9331,23 +12748,23 @@ proto.org.dash.platform.dapi.v0.GetDataContractsResponse.toObject = function(inc
 /**
  * Deserializes binary data (in protobuf wire format).
  * @param {jspb.ByteSource} bytes The bytes to deserialize.
- * @return {!proto.org.dash.platform.dapi.v0.GetDataContractsResponse}
+ * @return {!proto.org.dash.platform.dapi.v0.GetDocumentsResponse}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractsResponse.deserializeBinary = function(bytes) {
+proto.org.dash.platform.dapi.v0.GetDocumentsResponse.deserializeBinary = function(bytes) {
   var reader = new jspb.BinaryReader(bytes);
-  var msg = new proto.org.dash.platform.dapi.v0.GetDataContractsResponse;
-  return proto.org.dash.platform.dapi.v0.GetDataContractsResponse.deserializeBinaryFromReader(msg, reader);
+  var msg = new proto.org.dash.platform.dapi.v0.GetDocumentsResponse;
+  return proto.org.dash.platform.dapi.v0.GetDocumentsResponse.deserializeBinaryFromReader(msg, reader);
 };
 
 
 /**
  * Deserializes binary data (in protobuf wire format) from the
  * given reader into the given message object.
- * @param {!proto.org.dash.platform.dapi.v0.GetDataContractsResponse} msg The message object to deserialize into.
+ * @param {!proto.org.dash.platform.dapi.v0.GetDocumentsResponse} msg The message object to deserialize into.
  * @param {!jspb.BinaryReader} reader The BinaryReader to use.
- * @return {!proto.org.dash.platform.dapi.v0.GetDataContractsResponse}
+ * @return {!proto.org.dash.platform.dapi.v0.GetDocumentsResponse}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractsResponse.deserializeBinaryFromReader = function(msg, reader) {
+proto.org.dash.platform.dapi.v0.GetDocumentsResponse.deserializeBinaryFromReader = function(msg, reader) {
   while (reader.nextField()) {
     if (reader.isEndGroup()) {
       break;
@@ -9355,9 +12772,9 @@ proto.org.dash.platform.dapi.v0.GetDataContractsResponse.deserializeBinaryFromRe
     var field = reader.getFieldNumber();
     switch (field) {
     case 1:
-      var value = new proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContracts;
-      reader.readMessage(value,proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContracts.deserializeBinaryFromReader);
-      msg.setDataContracts(value);
+      var value = new proto.org.dash.platform.dapi.v0.GetDocumentsResponse.Documents;
+      reader.readMessage(value,proto.org.dash.platform.dapi.v0.GetDocumentsResponse.Documents.deserializeBinaryFromReader);
+      msg.setDocuments(value);
       break;
     case 2:
       var value = new proto.org.dash.platform.dapi.v0.Proof;
@@ -9382,204 +12799,57 @@ proto.org.dash.platform.dapi.v0.GetDataContractsResponse.deserializeBinaryFromRe
  * Serializes the message to binary data (in protobuf wire format).
  * @return {!Uint8Array}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractsResponse.prototype.serializeBinary = function() {
-  var writer = new jspb.BinaryWriter();
-  proto.org.dash.platform.dapi.v0.GetDataContractsResponse.serializeBinaryToWriter(this, writer);
-  return writer.getResultBuffer();
-};
-
-
-/**
- * Serializes the given message to binary data (in protobuf wire
- * format), writing to the given BinaryWriter.
- * @param {!proto.org.dash.platform.dapi.v0.GetDataContractsResponse} message
- * @param {!jspb.BinaryWriter} writer
- * @suppress {unusedLocalVariables} f is only used for nested messages
- */
-proto.org.dash.platform.dapi.v0.GetDataContractsResponse.serializeBinaryToWriter = function(message, writer) {
-  var f = undefined;
-  f = message.getDataContracts();
-  if (f != null) {
-    writer.writeMessage(
-      1,
-      f,
-      proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContracts.serializeBinaryToWriter
-    );
-  }
-  f = message.getProof();
-  if (f != null) {
-    writer.writeMessage(
-      2,
-      f,
-      proto.org.dash.platform.dapi.v0.Proof.serializeBinaryToWriter
-    );
-  }
-  f = message.getMetadata();
-  if (f != null) {
-    writer.writeMessage(
-      3,
-      f,
-      proto.org.dash.platform.dapi.v0.ResponseMetadata.serializeBinaryToWriter
-    );
-  }
-};
-
-
-
-
-
-if (jspb.Message.GENERATE_TO_OBJECT) {
-/**
- * Creates an object representation of this proto.
- * Field names that are reserved in JavaScript and will be renamed to pb_name.
- * Optional fields that are not set will be set to undefined.
- * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
- * For the list of reserved names please see:
- *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
- * @param {boolean=} opt_includeInstance Deprecated. whether to include the
- *     JSPB instance for transitional soy proto support:
- *     http://goto/soy-param-migration
- * @return {!Object}
- */
-proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractValue.prototype.toObject = function(opt_includeInstance) {
-  return proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractValue.toObject(opt_includeInstance, this);
-};
-
-
-/**
- * Static version of the {@see toObject} method.
- * @param {boolean|undefined} includeInstance Deprecated. Whether to include
- *     the JSPB instance for transitional soy proto support:
- *     http://goto/soy-param-migration
- * @param {!proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractValue} msg The msg instance to transform.
- * @return {!Object}
- * @suppress {unusedLocalVariables} f is only used for nested messages
- */
-proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractValue.toObject = function(includeInstance, msg) {
-  var f, obj = {
-    value: msg.getValue_asB64()
-  };
-
-  if (includeInstance) {
-    obj.$jspbMessageInstance = msg;
-  }
-  return obj;
-};
-}
-
-
-/**
- * Deserializes binary data (in protobuf wire format).
- * @param {jspb.ByteSource} bytes The bytes to deserialize.
- * @return {!proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractValue}
- */
-proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractValue.deserializeBinary = function(bytes) {
-  var reader = new jspb.BinaryReader(bytes);
-  var msg = new proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractValue;
-  return proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractValue.deserializeBinaryFromReader(msg, reader);
-};
-
-
-/**
- * Deserializes binary data (in protobuf wire format) from the
- * given reader into the given message object.
- * @param {!proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractValue} msg The message object to deserialize into.
- * @param {!jspb.BinaryReader} reader The BinaryReader to use.
- * @return {!proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractValue}
- */
-proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractValue.deserializeBinaryFromReader = function(msg, reader) {
-  while (reader.nextField()) {
-    if (reader.isEndGroup()) {
-      break;
-    }
-    var field = reader.getFieldNumber();
-    switch (field) {
-    case 1:
-      var value = /** @type {!Uint8Array} */ (reader.readBytes());
-      msg.setValue(value);
-      break;
-    default:
-      reader.skipField();
-      break;
-    }
-  }
-  return msg;
-};
-
-
-/**
- * Serializes the message to binary data (in protobuf wire format).
- * @return {!Uint8Array}
- */
-proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractValue.prototype.serializeBinary = function() {
-  var writer = new jspb.BinaryWriter();
-  proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractValue.serializeBinaryToWriter(this, writer);
-  return writer.getResultBuffer();
-};
-
-
-/**
- * Serializes the given message to binary data (in protobuf wire
- * format), writing to the given BinaryWriter.
- * @param {!proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractValue} message
- * @param {!jspb.BinaryWriter} writer
- * @suppress {unusedLocalVariables} f is only used for nested messages
- */
-proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractValue.serializeBinaryToWriter = function(message, writer) {
-  var f = undefined;
-  f = message.getValue_asU8();
-  if (f.length > 0) {
-    writer.writeBytes(
-      1,
-      f
-    );
-  }
-};
-
-
-/**
- * optional bytes value = 1;
- * @return {string}
- */
-proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractValue.prototype.getValue = function() {
-  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 1, ""));
-};
-
-
-/**
- * optional bytes value = 1;
- * This is a type-conversion wrapper around `getValue()`
- * @return {string}
- */
-proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractValue.prototype.getValue_asB64 = function() {
-  return /** @type {string} */ (jspb.Message.bytesAsB64(
-      this.getValue()));
-};
-
-
-/**
- * optional bytes value = 1;
- * Note that Uint8Array is not supported on all browsers.
- * @see http://caniuse.com/Uint8Array
- * This is a type-conversion wrapper around `getValue()`
- * @return {!Uint8Array}
- */
-proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractValue.prototype.getValue_asU8 = function() {
-  return /** @type {!Uint8Array} */ (jspb.Message.bytesAsU8(
-      this.getValue()));
+proto.org.dash.platform.dapi.v0.GetDocumentsResponse.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.org.dash.platform.dapi.v0.GetDocumentsResponse.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
 };
 
 
 /**
- * @param {!(string|Uint8Array)} value
- * @return {!proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractValue} returns this
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.org.dash.platform.dapi.v0.GetDocumentsResponse} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractValue.prototype.setValue = function(value) {
-  return jspb.Message.setProto3BytesField(this, 1, value);
+proto.org.dash.platform.dapi.v0.GetDocumentsResponse.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+  f = message.getDocuments();
+  if (f != null) {
+    writer.writeMessage(
+      1,
+      f,
+      proto.org.dash.platform.dapi.v0.GetDocumentsResponse.Documents.serializeBinaryToWriter
+    );
+  }
+  f = message.getProof();
+  if (f != null) {
+    writer.writeMessage(
+      2,
+      f,
+      proto.org.dash.platform.dapi.v0.Proof.serializeBinaryToWriter
+    );
+  }
+  f = message.getMetadata();
+  if (f != null) {
+    writer.writeMessage(
+      3,
+      f,
+      proto.org.dash.platform.dapi.v0.ResponseMetadata.serializeBinaryToWriter
+    );
+  }
 };
 
 
 
+/**
+ * List of repeated fields within this message type.
+ * @private {!Array<number>}
+ * @const
+ */
+proto.org.dash.platform.dapi.v0.GetDocumentsResponse.Documents.repeatedFields_ = [1];
+
 
 
 if (jspb.Message.GENERATE_TO_OBJECT) {
@@ -9595,8 +12865,8 @@ if (jspb.Message.GENERATE_TO_OBJECT) {
  *     http://goto/soy-param-migration
  * @return {!Object}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntry.prototype.toObject = function(opt_includeInstance) {
-  return proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntry.toObject(opt_includeInstance, this);
+proto.org.dash.platform.dapi.v0.GetDocumentsResponse.Documents.prototype.toObject = function(opt_includeInstance) {
+  return proto.org.dash.platform.dapi.v0.GetDocumentsResponse.Documents.toObject(opt_includeInstance, this);
 };
 
 
@@ -9605,14 +12875,13 @@ proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntry.proto
  * @param {boolean|undefined} includeInstance Deprecated. Whether to include
  *     the JSPB instance for transitional soy proto support:
  *     http://goto/soy-param-migration
- * @param {!proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntry} msg The msg instance to transform.
+ * @param {!proto.org.dash.platform.dapi.v0.GetDocumentsResponse.Documents} msg The msg instance to transform.
  * @return {!Object}
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntry.toObject = function(includeInstance, msg) {
+proto.org.dash.platform.dapi.v0.GetDocumentsResponse.Documents.toObject = function(includeInstance, msg) {
   var f, obj = {
-    key: msg.getKey_asB64(),
-    value: (f = msg.getValue()) && proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractValue.toObject(includeInstance, f)
+    documentsList: msg.getDocumentsList_asB64()
   };
 
   if (includeInstance) {
@@ -9626,23 +12895,23 @@ proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntry.toObj
 /**
  * Deserializes binary data (in protobuf wire format).
  * @param {jspb.ByteSource} bytes The bytes to deserialize.
- * @return {!proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntry}
+ * @return {!proto.org.dash.platform.dapi.v0.GetDocumentsResponse.Documents}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntry.deserializeBinary = function(bytes) {
+proto.org.dash.platform.dapi.v0.GetDocumentsResponse.Documents.deserializeBinary = function(bytes) {
   var reader = new jspb.BinaryReader(bytes);
-  var msg = new proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntry;
-  return proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntry.deserializeBinaryFromReader(msg, reader);
+  var msg = new proto.org.dash.platform.dapi.v0.GetDocumentsResponse.Documents;
+  return proto.org.dash.platform.dapi.v0.GetDocumentsResponse.Documents.deserializeBinaryFromReader(msg, reader);
 };
 
 
 /**
  * Deserializes binary data (in protobuf wire format) from the
  * given reader into the given message object.
- * @param {!proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntry} msg The message object to deserialize into.
+ * @param {!proto.org.dash.platform.dapi.v0.GetDocumentsResponse.Documents} msg The message object to deserialize into.
  * @param {!jspb.BinaryReader} reader The BinaryReader to use.
- * @return {!proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntry}
+ * @return {!proto.org.dash.platform.dapi.v0.GetDocumentsResponse.Documents}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntry.deserializeBinaryFromReader = function(msg, reader) {
+proto.org.dash.platform.dapi.v0.GetDocumentsResponse.Documents.deserializeBinaryFromReader = function(msg, reader) {
   while (reader.nextField()) {
     if (reader.isEndGroup()) {
       break;
@@ -9651,12 +12920,7 @@ proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntry.deser
     switch (field) {
     case 1:
       var value = /** @type {!Uint8Array} */ (reader.readBytes());
-      msg.setKey(value);
-      break;
-    case 2:
-      var value = new proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractValue;
-      reader.readMessage(value,proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractValue.deserializeBinaryFromReader);
-      msg.setValue(value);
+      msg.addDocuments(value);
       break;
     default:
       reader.skipField();
@@ -9671,9 +12935,9 @@ proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntry.deser
  * Serializes the message to binary data (in protobuf wire format).
  * @return {!Uint8Array}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntry.prototype.serializeBinary = function() {
+proto.org.dash.platform.dapi.v0.GetDocumentsResponse.Documents.prototype.serializeBinary = function() {
   var writer = new jspb.BinaryWriter();
-  proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntry.serializeBinaryToWriter(this, writer);
+  proto.org.dash.platform.dapi.v0.GetDocumentsResponse.Documents.serializeBinaryToWriter(this, writer);
   return writer.getResultBuffer();
 };
 
@@ -9681,97 +12945,108 @@ proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntry.proto
 /**
  * Serializes the given message to binary data (in protobuf wire
  * format), writing to the given BinaryWriter.
- * @param {!proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntry} message
+ * @param {!proto.org.dash.platform.dapi.v0.GetDocumentsResponse.Documents} message
  * @param {!jspb.BinaryWriter} writer
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntry.serializeBinaryToWriter = function(message, writer) {
+proto.org.dash.platform.dapi.v0.GetDocumentsResponse.Documents.serializeBinaryToWriter = function(message, writer) {
   var f = undefined;
-  f = message.getKey_asU8();
+  f = message.getDocumentsList_asU8();
   if (f.length > 0) {
-    writer.writeBytes(
+    writer.writeRepeatedBytes(
       1,
       f
     );
   }
-  f = message.getValue();
-  if (f != null) {
-    writer.writeMessage(
-      2,
-      f,
-      proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractValue.serializeBinaryToWriter
-    );
-  }
 };
 
 
 /**
- * optional bytes key = 1;
- * @return {string}
+ * repeated bytes documents = 1;
+ * @return {!Array<string>}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntry.prototype.getKey = function() {
-  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 1, ""));
+proto.org.dash.platform.dapi.v0.GetDocumentsResponse.Documents.prototype.getDocumentsList = function() {
+  return /** @type {!Array<string>} */ (jspb.Message.getRepeatedField(this, 1));
 };
 
 
 /**
- * optional bytes key = 1;
- * This is a type-conversion wrapper around `getKey()`
- * @return {string}
+ * repeated bytes documents = 1;
+ * This is a type-conversion wrapper around `getDocumentsList()`
+ * @return {!Array<string>}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntry.prototype.getKey_asB64 = function() {
-  return /** @type {string} */ (jspb.Message.bytesAsB64(
-      this.getKey()));
+proto.org.dash.platform.dapi.v0.GetDocumentsResponse.Documents.prototype.getDocumentsList_asB64 = function() {
+  return /** @type {!Array<string>} */ (jspb.Message.bytesListAsB64(
+      this.getDocumentsList()));
 };
 
 
 /**
- * optional bytes key = 1;
+ * repeated bytes documents = 1;
  * Note that Uint8Array is not supported on all browsers.
  * @see http://caniuse.com/Uint8Array
- * This is a type-conversion wrapper around `getKey()`
- * @return {!Uint8Array}
+ * This is a type-conversion wrapper around `getDocumentsList()`
+ * @return {!Array<!Uint8Array>}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntry.prototype.getKey_asU8 = function() {
-  return /** @type {!Uint8Array} */ (jspb.Message.bytesAsU8(
-      this.getKey()));
+proto.org.dash.platform.dapi.v0.GetDocumentsResponse.Documents.prototype.getDocumentsList_asU8 = function() {
+  return /** @type {!Array<!Uint8Array>} */ (jspb.Message.bytesListAsU8(
+      this.getDocumentsList()));
+};
+
+
+/**
+ * @param {!(Array<!Uint8Array>|Array<string>)} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetDocumentsResponse.Documents} returns this
+ */
+proto.org.dash.platform.dapi.v0.GetDocumentsResponse.Documents.prototype.setDocumentsList = function(value) {
+  return jspb.Message.setField(this, 1, value || []);
 };
 
 
 /**
  * @param {!(string|Uint8Array)} value
- * @return {!proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntry} returns this
+ * @param {number=} opt_index
+ * @return {!proto.org.dash.platform.dapi.v0.GetDocumentsResponse.Documents} returns this
  */
-proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntry.prototype.setKey = function(value) {
-  return jspb.Message.setProto3BytesField(this, 1, value);
+proto.org.dash.platform.dapi.v0.GetDocumentsResponse.Documents.prototype.addDocuments = function(value, opt_index) {
+  return jspb.Message.addToRepeatedField(this, 1, value, opt_index);
 };
 
 
 /**
- * optional DataContractValue value = 2;
- * @return {?proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractValue}
+ * Clears the list making it empty but non-null.
+ * @return {!proto.org.dash.platform.dapi.v0.GetDocumentsResponse.Documents} returns this
  */
-proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntry.prototype.getValue = function() {
-  return /** @type{?proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractValue} */ (
-    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractValue, 2));
+proto.org.dash.platform.dapi.v0.GetDocumentsResponse.Documents.prototype.clearDocumentsList = function() {
+  return this.setDocumentsList([]);
 };
 
 
 /**
- * @param {?proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractValue|undefined} value
- * @return {!proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntry} returns this
+ * optional Documents documents = 1;
+ * @return {?proto.org.dash.platform.dapi.v0.GetDocumentsResponse.Documents}
+ */
+proto.org.dash.platform.dapi.v0.GetDocumentsResponse.prototype.getDocuments = function() {
+  return /** @type{?proto.org.dash.platform.dapi.v0.GetDocumentsResponse.Documents} */ (
+    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.GetDocumentsResponse.Documents, 1));
+};
+
+
+/**
+ * @param {?proto.org.dash.platform.dapi.v0.GetDocumentsResponse.Documents|undefined} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetDocumentsResponse} returns this
 */
-proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntry.prototype.setValue = function(value) {
-  return jspb.Message.setWrapperField(this, 2, value);
+proto.org.dash.platform.dapi.v0.GetDocumentsResponse.prototype.setDocuments = function(value) {
+  return jspb.Message.setOneofWrapperField(this, 1, proto.org.dash.platform.dapi.v0.GetDocumentsResponse.oneofGroups_[0], value);
 };
 
 
 /**
  * Clears the message field making it undefined.
- * @return {!proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntry} returns this
+ * @return {!proto.org.dash.platform.dapi.v0.GetDocumentsResponse} returns this
  */
-proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntry.prototype.clearValue = function() {
-  return this.setValue(undefined);
+proto.org.dash.platform.dapi.v0.GetDocumentsResponse.prototype.clearDocuments = function() {
+  return this.setDocuments(undefined);
 };
 
 
@@ -9779,18 +13054,92 @@ proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntry.proto
  * Returns whether this field is set.
  * @return {boolean}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntry.prototype.hasValue = function() {
+proto.org.dash.platform.dapi.v0.GetDocumentsResponse.prototype.hasDocuments = function() {
+  return jspb.Message.getField(this, 1) != null;
+};
+
+
+/**
+ * optional Proof proof = 2;
+ * @return {?proto.org.dash.platform.dapi.v0.Proof}
+ */
+proto.org.dash.platform.dapi.v0.GetDocumentsResponse.prototype.getProof = function() {
+  return /** @type{?proto.org.dash.platform.dapi.v0.Proof} */ (
+    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.Proof, 2));
+};
+
+
+/**
+ * @param {?proto.org.dash.platform.dapi.v0.Proof|undefined} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetDocumentsResponse} returns this
+*/
+proto.org.dash.platform.dapi.v0.GetDocumentsResponse.prototype.setProof = function(value) {
+  return jspb.Message.setOneofWrapperField(this, 2, proto.org.dash.platform.dapi.v0.GetDocumentsResponse.oneofGroups_[0], value);
+};
+
+
+/**
+ * Clears the message field making it undefined.
+ * @return {!proto.org.dash.platform.dapi.v0.GetDocumentsResponse} returns this
+ */
+proto.org.dash.platform.dapi.v0.GetDocumentsResponse.prototype.clearProof = function() {
+  return this.setProof(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.org.dash.platform.dapi.v0.GetDocumentsResponse.prototype.hasProof = function() {
   return jspb.Message.getField(this, 2) != null;
 };
 
 
+/**
+ * optional ResponseMetadata metadata = 3;
+ * @return {?proto.org.dash.platform.dapi.v0.ResponseMetadata}
+ */
+proto.org.dash.platform.dapi.v0.GetDocumentsResponse.prototype.getMetadata = function() {
+  return /** @type{?proto.org.dash.platform.dapi.v0.ResponseMetadata} */ (
+    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.ResponseMetadata, 3));
+};
+
+
+/**
+ * @param {?proto.org.dash.platform.dapi.v0.ResponseMetadata|undefined} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetDocumentsResponse} returns this
+*/
+proto.org.dash.platform.dapi.v0.GetDocumentsResponse.prototype.setMetadata = function(value) {
+  return jspb.Message.setWrapperField(this, 3, value);
+};
+
+
+/**
+ * Clears the message field making it undefined.
+ * @return {!proto.org.dash.platform.dapi.v0.GetDocumentsResponse} returns this
+ */
+proto.org.dash.platform.dapi.v0.GetDocumentsResponse.prototype.clearMetadata = function() {
+  return this.setMetadata(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.org.dash.platform.dapi.v0.GetDocumentsResponse.prototype.hasMetadata = function() {
+  return jspb.Message.getField(this, 3) != null;
+};
+
+
 
 /**
  * List of repeated fields within this message type.
  * @private {!Array<number>}
  * @const
  */
-proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContracts.repeatedFields_ = [1];
+proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesRequest.repeatedFields_ = [1];
 
 
 
@@ -9807,8 +13156,8 @@ if (jspb.Message.GENERATE_TO_OBJECT) {
  *     http://goto/soy-param-migration
  * @return {!Object}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContracts.prototype.toObject = function(opt_includeInstance) {
-  return proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContracts.toObject(opt_includeInstance, this);
+proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesRequest.prototype.toObject = function(opt_includeInstance) {
+  return proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesRequest.toObject(opt_includeInstance, this);
 };
 
 
@@ -9817,14 +13166,14 @@ proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContracts.prototype
  * @param {boolean|undefined} includeInstance Deprecated. Whether to include
  *     the JSPB instance for transitional soy proto support:
  *     http://goto/soy-param-migration
- * @param {!proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContracts} msg The msg instance to transform.
+ * @param {!proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesRequest} msg The msg instance to transform.
  * @return {!Object}
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContracts.toObject = function(includeInstance, msg) {
+proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesRequest.toObject = function(includeInstance, msg) {
   var f, obj = {
-    dataContractEntriesList: jspb.Message.toObjectList(msg.getDataContractEntriesList(),
-    proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntry.toObject, includeInstance)
+    publicKeyHashesList: msg.getPublicKeyHashesList_asB64(),
+    prove: jspb.Message.getBooleanFieldWithDefault(msg, 2, false)
   };
 
   if (includeInstance) {
@@ -9838,23 +13187,23 @@ proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContracts.toObject
 /**
  * Deserializes binary data (in protobuf wire format).
  * @param {jspb.ByteSource} bytes The bytes to deserialize.
- * @return {!proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContracts}
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesRequest}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContracts.deserializeBinary = function(bytes) {
+proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesRequest.deserializeBinary = function(bytes) {
   var reader = new jspb.BinaryReader(bytes);
-  var msg = new proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContracts;
-  return proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContracts.deserializeBinaryFromReader(msg, reader);
+  var msg = new proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesRequest;
+  return proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesRequest.deserializeBinaryFromReader(msg, reader);
 };
 
 
 /**
  * Deserializes binary data (in protobuf wire format) from the
  * given reader into the given message object.
- * @param {!proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContracts} msg The message object to deserialize into.
+ * @param {!proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesRequest} msg The message object to deserialize into.
  * @param {!jspb.BinaryReader} reader The BinaryReader to use.
- * @return {!proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContracts}
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesRequest}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContracts.deserializeBinaryFromReader = function(msg, reader) {
+proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesRequest.deserializeBinaryFromReader = function(msg, reader) {
   while (reader.nextField()) {
     if (reader.isEndGroup()) {
       break;
@@ -9862,9 +13211,12 @@ proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContracts.deseriali
     var field = reader.getFieldNumber();
     switch (field) {
     case 1:
-      var value = new proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntry;
-      reader.readMessage(value,proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntry.deserializeBinaryFromReader);
-      msg.addDataContractEntries(value);
+      var value = /** @type {!Uint8Array} */ (reader.readBytes());
+      msg.addPublicKeyHashes(value);
+      break;
+    case 2:
+      var value = /** @type {boolean} */ (reader.readBool());
+      msg.setProve(value);
       break;
     default:
       reader.skipField();
@@ -9879,9 +13231,9 @@ proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContracts.deseriali
  * Serializes the message to binary data (in protobuf wire format).
  * @return {!Uint8Array}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContracts.prototype.serializeBinary = function() {
+proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesRequest.prototype.serializeBinary = function() {
   var writer = new jspb.BinaryWriter();
-  proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContracts.serializeBinaryToWriter(this, writer);
+  proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesRequest.serializeBinaryToWriter(this, writer);
   return writer.getResultBuffer();
 };
 
@@ -9889,173 +13241,284 @@ proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContracts.prototype
 /**
  * Serializes the given message to binary data (in protobuf wire
  * format), writing to the given BinaryWriter.
- * @param {!proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContracts} message
+ * @param {!proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesRequest} message
  * @param {!jspb.BinaryWriter} writer
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContracts.serializeBinaryToWriter = function(message, writer) {
+proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesRequest.serializeBinaryToWriter = function(message, writer) {
   var f = undefined;
-  f = message.getDataContractEntriesList();
+  f = message.getPublicKeyHashesList_asU8();
   if (f.length > 0) {
-    writer.writeRepeatedMessage(
+    writer.writeRepeatedBytes(
       1,
-      f,
-      proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntry.serializeBinaryToWriter
+      f
+    );
+  }
+  f = message.getProve();
+  if (f) {
+    writer.writeBool(
+      2,
+      f
     );
   }
 };
 
 
 /**
- * repeated DataContractEntry data_contract_entries = 1;
- * @return {!Array<!proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntry>}
+ * repeated bytes public_key_hashes = 1;
+ * @return {!Array<string>}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContracts.prototype.getDataContractEntriesList = function() {
-  return /** @type{!Array<!proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntry>} */ (
-    jspb.Message.getRepeatedWrapperField(this, proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntry, 1));
+proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesRequest.prototype.getPublicKeyHashesList = function() {
+  return /** @type {!Array<string>} */ (jspb.Message.getRepeatedField(this, 1));
 };
 
 
 /**
- * @param {!Array<!proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntry>} value
- * @return {!proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContracts} returns this
-*/
-proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContracts.prototype.setDataContractEntriesList = function(value) {
-  return jspb.Message.setRepeatedWrapperField(this, 1, value);
+ * repeated bytes public_key_hashes = 1;
+ * This is a type-conversion wrapper around `getPublicKeyHashesList()`
+ * @return {!Array<string>}
+ */
+proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesRequest.prototype.getPublicKeyHashesList_asB64 = function() {
+  return /** @type {!Array<string>} */ (jspb.Message.bytesListAsB64(
+      this.getPublicKeyHashesList()));
 };
 
 
 /**
- * @param {!proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntry=} opt_value
- * @param {number=} opt_index
- * @return {!proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntry}
+ * repeated bytes public_key_hashes = 1;
+ * Note that Uint8Array is not supported on all browsers.
+ * @see http://caniuse.com/Uint8Array
+ * This is a type-conversion wrapper around `getPublicKeyHashesList()`
+ * @return {!Array<!Uint8Array>}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContracts.prototype.addDataContractEntries = function(opt_value, opt_index) {
-  return jspb.Message.addToRepeatedWrapperField(this, 1, opt_value, proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntry, opt_index);
+proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesRequest.prototype.getPublicKeyHashesList_asU8 = function() {
+  return /** @type {!Array<!Uint8Array>} */ (jspb.Message.bytesListAsU8(
+      this.getPublicKeyHashesList()));
 };
 
 
 /**
- * Clears the list making it empty but non-null.
- * @return {!proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContracts} returns this
+ * @param {!(Array<!Uint8Array>|Array<string>)} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesRequest} returns this
  */
-proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContracts.prototype.clearDataContractEntriesList = function() {
-  return this.setDataContractEntriesList([]);
+proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesRequest.prototype.setPublicKeyHashesList = function(value) {
+  return jspb.Message.setField(this, 1, value || []);
 };
 
 
 /**
- * optional DataContracts data_contracts = 1;
- * @return {?proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContracts}
+ * @param {!(string|Uint8Array)} value
+ * @param {number=} opt_index
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesRequest} returns this
  */
-proto.org.dash.platform.dapi.v0.GetDataContractsResponse.prototype.getDataContracts = function() {
-  return /** @type{?proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContracts} */ (
-    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContracts, 1));
+proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesRequest.prototype.addPublicKeyHashes = function(value, opt_index) {
+  return jspb.Message.addToRepeatedField(this, 1, value, opt_index);
 };
 
 
 /**
- * @param {?proto.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContracts|undefined} value
- * @return {!proto.org.dash.platform.dapi.v0.GetDataContractsResponse} returns this
-*/
-proto.org.dash.platform.dapi.v0.GetDataContractsResponse.prototype.setDataContracts = function(value) {
-  return jspb.Message.setOneofWrapperField(this, 1, proto.org.dash.platform.dapi.v0.GetDataContractsResponse.oneofGroups_[0], value);
+ * Clears the list making it empty but non-null.
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesRequest} returns this
+ */
+proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesRequest.prototype.clearPublicKeyHashesList = function() {
+  return this.setPublicKeyHashesList([]);
 };
 
 
 /**
- * Clears the message field making it undefined.
- * @return {!proto.org.dash.platform.dapi.v0.GetDataContractsResponse} returns this
+ * optional bool prove = 2;
+ * @return {boolean}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractsResponse.prototype.clearDataContracts = function() {
-  return this.setDataContracts(undefined);
+proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesRequest.prototype.getProve = function() {
+  return /** @type {boolean} */ (jspb.Message.getBooleanFieldWithDefault(this, 2, false));
 };
 
 
 /**
- * Returns whether this field is set.
- * @return {boolean}
+ * @param {boolean} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesRequest} returns this
  */
-proto.org.dash.platform.dapi.v0.GetDataContractsResponse.prototype.hasDataContracts = function() {
-  return jspb.Message.getField(this, 1) != null;
+proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesRequest.prototype.setProve = function(value) {
+  return jspb.Message.setProto3BooleanField(this, 2, value);
 };
 
 
+
 /**
- * optional Proof proof = 2;
- * @return {?proto.org.dash.platform.dapi.v0.Proof}
+ * Oneof group definitions for this message. Each group defines the field
+ * numbers belonging to that group. When of these fields' value is set, all
+ * other fields in the group are cleared. During deserialization, if multiple
+ * fields are encountered for a group, only the last value seen will be kept.
+ * @private {!Array<!Array<number>>}
+ * @const
  */
-proto.org.dash.platform.dapi.v0.GetDataContractsResponse.prototype.getProof = function() {
-  return /** @type{?proto.org.dash.platform.dapi.v0.Proof} */ (
-    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.Proof, 2));
-};
+proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.oneofGroups_ = [[1,2]];
 
+/**
+ * @enum {number}
+ */
+proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.ResultCase = {
+  RESULT_NOT_SET: 0,
+  IDENTITIES: 1,
+  PROOF: 2
+};
 
 /**
- * @param {?proto.org.dash.platform.dapi.v0.Proof|undefined} value
- * @return {!proto.org.dash.platform.dapi.v0.GetDataContractsResponse} returns this
-*/
-proto.org.dash.platform.dapi.v0.GetDataContractsResponse.prototype.setProof = function(value) {
-  return jspb.Message.setOneofWrapperField(this, 2, proto.org.dash.platform.dapi.v0.GetDataContractsResponse.oneofGroups_[0], value);
+ * @return {proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.ResultCase}
+ */
+proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.prototype.getResultCase = function() {
+  return /** @type {proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.ResultCase} */(jspb.Message.computeOneofCase(this, proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.oneofGroups_[0]));
 };
 
 
+
+if (jspb.Message.GENERATE_TO_OBJECT) {
 /**
- * Clears the message field making it undefined.
- * @return {!proto.org.dash.platform.dapi.v0.GetDataContractsResponse} returns this
+ * Creates an object representation of this proto.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * Optional fields that are not set will be set to undefined.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * For the list of reserved names please see:
+ *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
+ * @param {boolean=} opt_includeInstance Deprecated. whether to include the
+ *     JSPB instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @return {!Object}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractsResponse.prototype.clearProof = function() {
-  return this.setProof(undefined);
+proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.prototype.toObject = function(opt_includeInstance) {
+  return proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.toObject(opt_includeInstance, this);
 };
 
 
 /**
- * Returns whether this field is set.
- * @return {boolean}
+ * Static version of the {@see toObject} method.
+ * @param {boolean|undefined} includeInstance Deprecated. Whether to include
+ *     the JSPB instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @param {!proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse} msg The msg instance to transform.
+ * @return {!Object}
+ * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.org.dash.platform.dapi.v0.GetDataContractsResponse.prototype.hasProof = function() {
-  return jspb.Message.getField(this, 2) != null;
+proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.toObject = function(includeInstance, msg) {
+  var f, obj = {
+    identities: (f = msg.getIdentities()) && proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.Identities.toObject(includeInstance, f),
+    proof: (f = msg.getProof()) && proto.org.dash.platform.dapi.v0.Proof.toObject(includeInstance, f),
+    metadata: (f = msg.getMetadata()) && proto.org.dash.platform.dapi.v0.ResponseMetadata.toObject(includeInstance, f)
+  };
+
+  if (includeInstance) {
+    obj.$jspbMessageInstance = msg;
+  }
+  return obj;
 };
+}
 
 
 /**
- * optional ResponseMetadata metadata = 3;
- * @return {?proto.org.dash.platform.dapi.v0.ResponseMetadata}
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractsResponse.prototype.getMetadata = function() {
-  return /** @type{?proto.org.dash.platform.dapi.v0.ResponseMetadata} */ (
-    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.ResponseMetadata, 3));
+proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.deserializeBinary = function(bytes) {
+  var reader = new jspb.BinaryReader(bytes);
+  var msg = new proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse;
+  return proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.deserializeBinaryFromReader(msg, reader);
 };
 
 
 /**
- * @param {?proto.org.dash.platform.dapi.v0.ResponseMetadata|undefined} value
- * @return {!proto.org.dash.platform.dapi.v0.GetDataContractsResponse} returns this
-*/
-proto.org.dash.platform.dapi.v0.GetDataContractsResponse.prototype.setMetadata = function(value) {
-  return jspb.Message.setWrapperField(this, 3, value);
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse}
+ */
+proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.deserializeBinaryFromReader = function(msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+    case 1:
+      var value = new proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.Identities;
+      reader.readMessage(value,proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.Identities.deserializeBinaryFromReader);
+      msg.setIdentities(value);
+      break;
+    case 2:
+      var value = new proto.org.dash.platform.dapi.v0.Proof;
+      reader.readMessage(value,proto.org.dash.platform.dapi.v0.Proof.deserializeBinaryFromReader);
+      msg.setProof(value);
+      break;
+    case 3:
+      var value = new proto.org.dash.platform.dapi.v0.ResponseMetadata;
+      reader.readMessage(value,proto.org.dash.platform.dapi.v0.ResponseMetadata.deserializeBinaryFromReader);
+      msg.setMetadata(value);
+      break;
+    default:
+      reader.skipField();
+      break;
+    }
+  }
+  return msg;
 };
 
 
 /**
- * Clears the message field making it undefined.
- * @return {!proto.org.dash.platform.dapi.v0.GetDataContractsResponse} returns this
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractsResponse.prototype.clearMetadata = function() {
-  return this.setMetadata(undefined);
+proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
 };
 
 
 /**
- * Returns whether this field is set.
- * @return {boolean}
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.org.dash.platform.dapi.v0.GetDataContractsResponse.prototype.hasMetadata = function() {
-  return jspb.Message.getField(this, 3) != null;
+proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+  f = message.getIdentities();
+  if (f != null) {
+    writer.writeMessage(
+      1,
+      f,
+      proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.Identities.serializeBinaryToWriter
+    );
+  }
+  f = message.getProof();
+  if (f != null) {
+    writer.writeMessage(
+      2,
+      f,
+      proto.org.dash.platform.dapi.v0.Proof.serializeBinaryToWriter
+    );
+  }
+  f = message.getMetadata();
+  if (f != null) {
+    writer.writeMessage(
+      3,
+      f,
+      proto.org.dash.platform.dapi.v0.ResponseMetadata.serializeBinaryToWriter
+    );
+  }
 };
 
 
 
+/**
+ * List of repeated fields within this message type.
+ * @private {!Array<number>}
+ * @const
+ */
+proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.Identities.repeatedFields_ = [1];
+
 
 
 if (jspb.Message.GENERATE_TO_OBJECT) {
@@ -10071,8 +13534,8 @@ if (jspb.Message.GENERATE_TO_OBJECT) {
  *     http://goto/soy-param-migration
  * @return {!Object}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractHistoryRequest.prototype.toObject = function(opt_includeInstance) {
-  return proto.org.dash.platform.dapi.v0.GetDataContractHistoryRequest.toObject(opt_includeInstance, this);
+proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.Identities.prototype.toObject = function(opt_includeInstance) {
+  return proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.Identities.toObject(opt_includeInstance, this);
 };
 
 
@@ -10081,17 +13544,13 @@ proto.org.dash.platform.dapi.v0.GetDataContractHistoryRequest.prototype.toObject
  * @param {boolean|undefined} includeInstance Deprecated. Whether to include
  *     the JSPB instance for transitional soy proto support:
  *     http://goto/soy-param-migration
- * @param {!proto.org.dash.platform.dapi.v0.GetDataContractHistoryRequest} msg The msg instance to transform.
+ * @param {!proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.Identities} msg The msg instance to transform.
  * @return {!Object}
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.org.dash.platform.dapi.v0.GetDataContractHistoryRequest.toObject = function(includeInstance, msg) {
+proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.Identities.toObject = function(includeInstance, msg) {
   var f, obj = {
-    id: msg.getId_asB64(),
-    limit: jspb.Message.getFieldWithDefault(msg, 2, 0),
-    offset: jspb.Message.getFieldWithDefault(msg, 3, 0),
-    startAtMs: jspb.Message.getFieldWithDefault(msg, 4, 0),
-    prove: jspb.Message.getBooleanFieldWithDefault(msg, 5, false)
+    identitiesList: msg.getIdentitiesList_asB64()
   };
 
   if (includeInstance) {
@@ -10105,23 +13564,23 @@ proto.org.dash.platform.dapi.v0.GetDataContractHistoryRequest.toObject = functio
 /**
  * Deserializes binary data (in protobuf wire format).
  * @param {jspb.ByteSource} bytes The bytes to deserialize.
- * @return {!proto.org.dash.platform.dapi.v0.GetDataContractHistoryRequest}
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.Identities}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractHistoryRequest.deserializeBinary = function(bytes) {
+proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.Identities.deserializeBinary = function(bytes) {
   var reader = new jspb.BinaryReader(bytes);
-  var msg = new proto.org.dash.platform.dapi.v0.GetDataContractHistoryRequest;
-  return proto.org.dash.platform.dapi.v0.GetDataContractHistoryRequest.deserializeBinaryFromReader(msg, reader);
+  var msg = new proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.Identities;
+  return proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.Identities.deserializeBinaryFromReader(msg, reader);
 };
 
 
 /**
  * Deserializes binary data (in protobuf wire format) from the
  * given reader into the given message object.
- * @param {!proto.org.dash.platform.dapi.v0.GetDataContractHistoryRequest} msg The message object to deserialize into.
+ * @param {!proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.Identities} msg The message object to deserialize into.
  * @param {!jspb.BinaryReader} reader The BinaryReader to use.
- * @return {!proto.org.dash.platform.dapi.v0.GetDataContractHistoryRequest}
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.Identities}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractHistoryRequest.deserializeBinaryFromReader = function(msg, reader) {
+proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.Identities.deserializeBinaryFromReader = function(msg, reader) {
   while (reader.nextField()) {
     if (reader.isEndGroup()) {
       break;
@@ -10130,23 +13589,7 @@ proto.org.dash.platform.dapi.v0.GetDataContractHistoryRequest.deserializeBinaryF
     switch (field) {
     case 1:
       var value = /** @type {!Uint8Array} */ (reader.readBytes());
-      msg.setId(value);
-      break;
-    case 2:
-      var value = /** @type {number} */ (reader.readUint32());
-      msg.setLimit(value);
-      break;
-    case 3:
-      var value = /** @type {number} */ (reader.readUint32());
-      msg.setOffset(value);
-      break;
-    case 4:
-      var value = /** @type {number} */ (reader.readUint64());
-      msg.setStartAtMs(value);
-      break;
-    case 5:
-      var value = /** @type {boolean} */ (reader.readBool());
-      msg.setProve(value);
+      msg.addIdentities(value);
       break;
     default:
       reader.skipField();
@@ -10161,9 +13604,9 @@ proto.org.dash.platform.dapi.v0.GetDataContractHistoryRequest.deserializeBinaryF
  * Serializes the message to binary data (in protobuf wire format).
  * @return {!Uint8Array}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractHistoryRequest.prototype.serializeBinary = function() {
+proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.Identities.prototype.serializeBinary = function() {
   var writer = new jspb.BinaryWriter();
-  proto.org.dash.platform.dapi.v0.GetDataContractHistoryRequest.serializeBinaryToWriter(this, writer);
+  proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.Identities.serializeBinaryToWriter(this, writer);
   return writer.getResultBuffer();
 };
 
@@ -10171,193 +13614,197 @@ proto.org.dash.platform.dapi.v0.GetDataContractHistoryRequest.prototype.serializ
 /**
  * Serializes the given message to binary data (in protobuf wire
  * format), writing to the given BinaryWriter.
- * @param {!proto.org.dash.platform.dapi.v0.GetDataContractHistoryRequest} message
+ * @param {!proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.Identities} message
  * @param {!jspb.BinaryWriter} writer
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.org.dash.platform.dapi.v0.GetDataContractHistoryRequest.serializeBinaryToWriter = function(message, writer) {
+proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.Identities.serializeBinaryToWriter = function(message, writer) {
   var f = undefined;
-  f = message.getId_asU8();
+  f = message.getIdentitiesList_asU8();
   if (f.length > 0) {
-    writer.writeBytes(
+    writer.writeRepeatedBytes(
       1,
       f
     );
   }
-  f = message.getLimit();
-  if (f !== 0) {
-    writer.writeUint32(
-      2,
-      f
-    );
-  }
-  f = message.getOffset();
-  if (f !== 0) {
-    writer.writeUint32(
-      3,
-      f
-    );
-  }
-  f = message.getStartAtMs();
-  if (f !== 0) {
-    writer.writeUint64(
-      4,
-      f
-    );
-  }
-  f = message.getProve();
-  if (f) {
-    writer.writeBool(
-      5,
-      f
-    );
-  }
 };
 
 
 /**
- * optional bytes id = 1;
- * @return {string}
+ * repeated bytes identities = 1;
+ * @return {!Array<string>}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractHistoryRequest.prototype.getId = function() {
-  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 1, ""));
+proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.Identities.prototype.getIdentitiesList = function() {
+  return /** @type {!Array<string>} */ (jspb.Message.getRepeatedField(this, 1));
 };
 
 
 /**
- * optional bytes id = 1;
- * This is a type-conversion wrapper around `getId()`
- * @return {string}
+ * repeated bytes identities = 1;
+ * This is a type-conversion wrapper around `getIdentitiesList()`
+ * @return {!Array<string>}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractHistoryRequest.prototype.getId_asB64 = function() {
-  return /** @type {string} */ (jspb.Message.bytesAsB64(
-      this.getId()));
+proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.Identities.prototype.getIdentitiesList_asB64 = function() {
+  return /** @type {!Array<string>} */ (jspb.Message.bytesListAsB64(
+      this.getIdentitiesList()));
 };
 
 
 /**
- * optional bytes id = 1;
+ * repeated bytes identities = 1;
  * Note that Uint8Array is not supported on all browsers.
  * @see http://caniuse.com/Uint8Array
- * This is a type-conversion wrapper around `getId()`
- * @return {!Uint8Array}
+ * This is a type-conversion wrapper around `getIdentitiesList()`
+ * @return {!Array<!Uint8Array>}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractHistoryRequest.prototype.getId_asU8 = function() {
-  return /** @type {!Uint8Array} */ (jspb.Message.bytesAsU8(
-      this.getId()));
+proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.Identities.prototype.getIdentitiesList_asU8 = function() {
+  return /** @type {!Array<!Uint8Array>} */ (jspb.Message.bytesListAsU8(
+      this.getIdentitiesList()));
+};
+
+
+/**
+ * @param {!(Array<!Uint8Array>|Array<string>)} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.Identities} returns this
+ */
+proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.Identities.prototype.setIdentitiesList = function(value) {
+  return jspb.Message.setField(this, 1, value || []);
 };
 
 
 /**
  * @param {!(string|Uint8Array)} value
- * @return {!proto.org.dash.platform.dapi.v0.GetDataContractHistoryRequest} returns this
+ * @param {number=} opt_index
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.Identities} returns this
  */
-proto.org.dash.platform.dapi.v0.GetDataContractHistoryRequest.prototype.setId = function(value) {
-  return jspb.Message.setProto3BytesField(this, 1, value);
+proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.Identities.prototype.addIdentities = function(value, opt_index) {
+  return jspb.Message.addToRepeatedField(this, 1, value, opt_index);
 };
 
 
 /**
- * optional uint32 limit = 2;
- * @return {number}
+ * Clears the list making it empty but non-null.
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.Identities} returns this
  */
-proto.org.dash.platform.dapi.v0.GetDataContractHistoryRequest.prototype.getLimit = function() {
-  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 2, 0));
+proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.Identities.prototype.clearIdentitiesList = function() {
+  return this.setIdentitiesList([]);
 };
 
 
 /**
- * @param {number} value
- * @return {!proto.org.dash.platform.dapi.v0.GetDataContractHistoryRequest} returns this
+ * optional Identities identities = 1;
+ * @return {?proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.Identities}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractHistoryRequest.prototype.setLimit = function(value) {
-  return jspb.Message.setProto3IntField(this, 2, value);
+proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.prototype.getIdentities = function() {
+  return /** @type{?proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.Identities} */ (
+    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.Identities, 1));
 };
 
 
 /**
- * optional uint32 offset = 3;
- * @return {number}
+ * @param {?proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.Identities|undefined} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse} returns this
+*/
+proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.prototype.setIdentities = function(value) {
+  return jspb.Message.setOneofWrapperField(this, 1, proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.oneofGroups_[0], value);
+};
+
+
+/**
+ * Clears the message field making it undefined.
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse} returns this
  */
-proto.org.dash.platform.dapi.v0.GetDataContractHistoryRequest.prototype.getOffset = function() {
-  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 3, 0));
+proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.prototype.clearIdentities = function() {
+  return this.setIdentities(undefined);
 };
 
 
 /**
- * @param {number} value
- * @return {!proto.org.dash.platform.dapi.v0.GetDataContractHistoryRequest} returns this
+ * Returns whether this field is set.
+ * @return {boolean}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractHistoryRequest.prototype.setOffset = function(value) {
-  return jspb.Message.setProto3IntField(this, 3, value);
+proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.prototype.hasIdentities = function() {
+  return jspb.Message.getField(this, 1) != null;
 };
 
 
 /**
- * optional uint64 start_at_ms = 4;
- * @return {number}
+ * optional Proof proof = 2;
+ * @return {?proto.org.dash.platform.dapi.v0.Proof}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractHistoryRequest.prototype.getStartAtMs = function() {
-  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 4, 0));
+proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.prototype.getProof = function() {
+  return /** @type{?proto.org.dash.platform.dapi.v0.Proof} */ (
+    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.Proof, 2));
 };
 
 
 /**
- * @param {number} value
- * @return {!proto.org.dash.platform.dapi.v0.GetDataContractHistoryRequest} returns this
+ * @param {?proto.org.dash.platform.dapi.v0.Proof|undefined} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse} returns this
+*/
+proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.prototype.setProof = function(value) {
+  return jspb.Message.setOneofWrapperField(this, 2, proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.oneofGroups_[0], value);
+};
+
+
+/**
+ * Clears the message field making it undefined.
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse} returns this
  */
-proto.org.dash.platform.dapi.v0.GetDataContractHistoryRequest.prototype.setStartAtMs = function(value) {
-  return jspb.Message.setProto3IntField(this, 4, value);
+proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.prototype.clearProof = function() {
+  return this.setProof(undefined);
 };
 
 
 /**
- * optional bool prove = 5;
+ * Returns whether this field is set.
  * @return {boolean}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractHistoryRequest.prototype.getProve = function() {
-  return /** @type {boolean} */ (jspb.Message.getBooleanFieldWithDefault(this, 5, false));
+proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.prototype.hasProof = function() {
+  return jspb.Message.getField(this, 2) != null;
 };
 
 
 /**
- * @param {boolean} value
- * @return {!proto.org.dash.platform.dapi.v0.GetDataContractHistoryRequest} returns this
+ * optional ResponseMetadata metadata = 3;
+ * @return {?proto.org.dash.platform.dapi.v0.ResponseMetadata}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractHistoryRequest.prototype.setProve = function(value) {
-  return jspb.Message.setProto3BooleanField(this, 5, value);
+proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.prototype.getMetadata = function() {
+  return /** @type{?proto.org.dash.platform.dapi.v0.ResponseMetadata} */ (
+    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.ResponseMetadata, 3));
 };
 
 
-
 /**
- * Oneof group definitions for this message. Each group defines the field
- * numbers belonging to that group. When of these fields' value is set, all
- * other fields in the group are cleared. During deserialization, if multiple
- * fields are encountered for a group, only the last value seen will be kept.
- * @private {!Array<!Array<number>>}
- * @const
- */
-proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.oneofGroups_ = [[1,2]];
+ * @param {?proto.org.dash.platform.dapi.v0.ResponseMetadata|undefined} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse} returns this
+*/
+proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.prototype.setMetadata = function(value) {
+  return jspb.Message.setWrapperField(this, 3, value);
+};
+
 
 /**
- * @enum {number}
+ * Clears the message field making it undefined.
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse} returns this
  */
-proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.ResultCase = {
-  RESULT_NOT_SET: 0,
-  DATA_CONTRACT_HISTORY: 1,
-  PROOF: 2
+proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.prototype.clearMetadata = function() {
+  return this.setMetadata(undefined);
 };
 
+
 /**
- * @return {proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.ResultCase}
+ * Returns whether this field is set.
+ * @return {boolean}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.prototype.getResultCase = function() {
-  return /** @type {proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.ResultCase} */(jspb.Message.computeOneofCase(this, proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.oneofGroups_[0]));
+proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.prototype.hasMetadata = function() {
+  return jspb.Message.getField(this, 3) != null;
 };
 
 
 
+
+
 if (jspb.Message.GENERATE_TO_OBJECT) {
 /**
  * Creates an object representation of this proto.
@@ -10371,8 +13818,8 @@ if (jspb.Message.GENERATE_TO_OBJECT) {
  *     http://goto/soy-param-migration
  * @return {!Object}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.prototype.toObject = function(opt_includeInstance) {
-  return proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.toObject(opt_includeInstance, this);
+proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesRequest.prototype.toObject = function(opt_includeInstance) {
+  return proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesRequest.toObject(opt_includeInstance, this);
 };
 
 
@@ -10381,15 +13828,14 @@ proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.prototype.toObjec
  * @param {boolean|undefined} includeInstance Deprecated. Whether to include
  *     the JSPB instance for transitional soy proto support:
  *     http://goto/soy-param-migration
- * @param {!proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse} msg The msg instance to transform.
+ * @param {!proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesRequest} msg The msg instance to transform.
  * @return {!Object}
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.toObject = function(includeInstance, msg) {
+proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesRequest.toObject = function(includeInstance, msg) {
   var f, obj = {
-    dataContractHistory: (f = msg.getDataContractHistory()) && proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistory.toObject(includeInstance, f),
-    proof: (f = msg.getProof()) && proto.org.dash.platform.dapi.v0.Proof.toObject(includeInstance, f),
-    metadata: (f = msg.getMetadata()) && proto.org.dash.platform.dapi.v0.ResponseMetadata.toObject(includeInstance, f)
+    publicKeyHash: msg.getPublicKeyHash_asB64(),
+    prove: jspb.Message.getBooleanFieldWithDefault(msg, 2, false)
   };
 
   if (includeInstance) {
@@ -10403,23 +13849,23 @@ proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.toObject = functi
 /**
  * Deserializes binary data (in protobuf wire format).
  * @param {jspb.ByteSource} bytes The bytes to deserialize.
- * @return {!proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse}
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesRequest}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.deserializeBinary = function(bytes) {
+proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesRequest.deserializeBinary = function(bytes) {
   var reader = new jspb.BinaryReader(bytes);
-  var msg = new proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse;
-  return proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.deserializeBinaryFromReader(msg, reader);
+  var msg = new proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesRequest;
+  return proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesRequest.deserializeBinaryFromReader(msg, reader);
 };
 
 
 /**
  * Deserializes binary data (in protobuf wire format) from the
  * given reader into the given message object.
- * @param {!proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse} msg The message object to deserialize into.
+ * @param {!proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesRequest} msg The message object to deserialize into.
  * @param {!jspb.BinaryReader} reader The BinaryReader to use.
- * @return {!proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse}
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesRequest}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.deserializeBinaryFromReader = function(msg, reader) {
+proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesRequest.deserializeBinaryFromReader = function(msg, reader) {
   while (reader.nextField()) {
     if (reader.isEndGroup()) {
       break;
@@ -10427,19 +13873,12 @@ proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.deserializeBinary
     var field = reader.getFieldNumber();
     switch (field) {
     case 1:
-      var value = new proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistory;
-      reader.readMessage(value,proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistory.deserializeBinaryFromReader);
-      msg.setDataContractHistory(value);
+      var value = /** @type {!Uint8Array} */ (reader.readBytes());
+      msg.setPublicKeyHash(value);
       break;
     case 2:
-      var value = new proto.org.dash.platform.dapi.v0.Proof;
-      reader.readMessage(value,proto.org.dash.platform.dapi.v0.Proof.deserializeBinaryFromReader);
-      msg.setProof(value);
-      break;
-    case 3:
-      var value = new proto.org.dash.platform.dapi.v0.ResponseMetadata;
-      reader.readMessage(value,proto.org.dash.platform.dapi.v0.ResponseMetadata.deserializeBinaryFromReader);
-      msg.setMetadata(value);
+      var value = /** @type {boolean} */ (reader.readBool());
+      msg.setProve(value);
       break;
     default:
       reader.skipField();
@@ -10454,9 +13893,9 @@ proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.deserializeBinary
  * Serializes the message to binary data (in protobuf wire format).
  * @return {!Uint8Array}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.prototype.serializeBinary = function() {
+proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesRequest.prototype.serializeBinary = function() {
   var writer = new jspb.BinaryWriter();
-  proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.serializeBinaryToWriter(this, writer);
+  proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesRequest.serializeBinaryToWriter(this, writer);
   return writer.getResultBuffer();
 };
 
@@ -10464,39 +13903,115 @@ proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.prototype.seriali
 /**
  * Serializes the given message to binary data (in protobuf wire
  * format), writing to the given BinaryWriter.
- * @param {!proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse} message
+ * @param {!proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesRequest} message
  * @param {!jspb.BinaryWriter} writer
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.serializeBinaryToWriter = function(message, writer) {
+proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesRequest.serializeBinaryToWriter = function(message, writer) {
   var f = undefined;
-  f = message.getDataContractHistory();
-  if (f != null) {
-    writer.writeMessage(
+  f = message.getPublicKeyHash_asU8();
+  if (f.length > 0) {
+    writer.writeBytes(
       1,
-      f,
-      proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistory.serializeBinaryToWriter
+      f
     );
   }
-  f = message.getProof();
-  if (f != null) {
-    writer.writeMessage(
+  f = message.getProve();
+  if (f) {
+    writer.writeBool(
       2,
-      f,
-      proto.org.dash.platform.dapi.v0.Proof.serializeBinaryToWriter
-    );
-  }
-  f = message.getMetadata();
-  if (f != null) {
-    writer.writeMessage(
-      3,
-      f,
-      proto.org.dash.platform.dapi.v0.ResponseMetadata.serializeBinaryToWriter
+      f
     );
   }
 };
 
 
+/**
+ * optional bytes public_key_hash = 1;
+ * @return {string}
+ */
+proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesRequest.prototype.getPublicKeyHash = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 1, ""));
+};
+
+
+/**
+ * optional bytes public_key_hash = 1;
+ * This is a type-conversion wrapper around `getPublicKeyHash()`
+ * @return {string}
+ */
+proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesRequest.prototype.getPublicKeyHash_asB64 = function() {
+  return /** @type {string} */ (jspb.Message.bytesAsB64(
+      this.getPublicKeyHash()));
+};
+
+
+/**
+ * optional bytes public_key_hash = 1;
+ * Note that Uint8Array is not supported on all browsers.
+ * @see http://caniuse.com/Uint8Array
+ * This is a type-conversion wrapper around `getPublicKeyHash()`
+ * @return {!Uint8Array}
+ */
+proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesRequest.prototype.getPublicKeyHash_asU8 = function() {
+  return /** @type {!Uint8Array} */ (jspb.Message.bytesAsU8(
+      this.getPublicKeyHash()));
+};
+
+
+/**
+ * @param {!(string|Uint8Array)} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesRequest} returns this
+ */
+proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesRequest.prototype.setPublicKeyHash = function(value) {
+  return jspb.Message.setProto3BytesField(this, 1, value);
+};
+
+
+/**
+ * optional bool prove = 2;
+ * @return {boolean}
+ */
+proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesRequest.prototype.getProve = function() {
+  return /** @type {boolean} */ (jspb.Message.getBooleanFieldWithDefault(this, 2, false));
+};
+
+
+/**
+ * @param {boolean} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesRequest} returns this
+ */
+proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesRequest.prototype.setProve = function(value) {
+  return jspb.Message.setProto3BooleanField(this, 2, value);
+};
+
+
+
+/**
+ * Oneof group definitions for this message. Each group defines the field
+ * numbers belonging to that group. When of these fields' value is set, all
+ * other fields in the group are cleared. During deserialization, if multiple
+ * fields are encountered for a group, only the last value seen will be kept.
+ * @private {!Array<!Array<number>>}
+ * @const
+ */
+proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse.oneofGroups_ = [[1,2]];
+
+/**
+ * @enum {number}
+ */
+proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse.ResultCase = {
+  RESULT_NOT_SET: 0,
+  IDENTITY: 1,
+  PROOF: 2
+};
+
+/**
+ * @return {proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse.ResultCase}
+ */
+proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse.prototype.getResultCase = function() {
+  return /** @type {proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse.ResultCase} */(jspb.Message.computeOneofCase(this, proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse.oneofGroups_[0]));
+};
 
 
 
@@ -10513,8 +14028,8 @@ if (jspb.Message.GENERATE_TO_OBJECT) {
  *     http://goto/soy-param-migration
  * @return {!Object}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistoryEntry.prototype.toObject = function(opt_includeInstance) {
-  return proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistoryEntry.toObject(opt_includeInstance, this);
+proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse.prototype.toObject = function(opt_includeInstance) {
+  return proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse.toObject(opt_includeInstance, this);
 };
 
 
@@ -10523,14 +14038,15 @@ proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHisto
  * @param {boolean|undefined} includeInstance Deprecated. Whether to include
  *     the JSPB instance for transitional soy proto support:
  *     http://goto/soy-param-migration
- * @param {!proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistoryEntry} msg The msg instance to transform.
+ * @param {!proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse} msg The msg instance to transform.
  * @return {!Object}
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistoryEntry.toObject = function(includeInstance, msg) {
+proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse.toObject = function(includeInstance, msg) {
   var f, obj = {
-    date: jspb.Message.getFieldWithDefault(msg, 1, 0),
-    value: msg.getValue_asB64()
+    identity: msg.getIdentity_asB64(),
+    proof: (f = msg.getProof()) && proto.org.dash.platform.dapi.v0.Proof.toObject(includeInstance, f),
+    metadata: (f = msg.getMetadata()) && proto.org.dash.platform.dapi.v0.ResponseMetadata.toObject(includeInstance, f)
   };
 
   if (includeInstance) {
@@ -10544,23 +14060,23 @@ proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHisto
 /**
  * Deserializes binary data (in protobuf wire format).
  * @param {jspb.ByteSource} bytes The bytes to deserialize.
- * @return {!proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistoryEntry}
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistoryEntry.deserializeBinary = function(bytes) {
+proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse.deserializeBinary = function(bytes) {
   var reader = new jspb.BinaryReader(bytes);
-  var msg = new proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistoryEntry;
-  return proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistoryEntry.deserializeBinaryFromReader(msg, reader);
+  var msg = new proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse;
+  return proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse.deserializeBinaryFromReader(msg, reader);
 };
 
 
 /**
  * Deserializes binary data (in protobuf wire format) from the
  * given reader into the given message object.
- * @param {!proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistoryEntry} msg The message object to deserialize into.
+ * @param {!proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse} msg The message object to deserialize into.
  * @param {!jspb.BinaryReader} reader The BinaryReader to use.
- * @return {!proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistoryEntry}
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistoryEntry.deserializeBinaryFromReader = function(msg, reader) {
+proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse.deserializeBinaryFromReader = function(msg, reader) {
   while (reader.nextField()) {
     if (reader.isEndGroup()) {
       break;
@@ -10568,12 +14084,18 @@ proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHisto
     var field = reader.getFieldNumber();
     switch (field) {
     case 1:
-      var value = /** @type {number} */ (reader.readUint64());
-      msg.setDate(value);
+      var value = /** @type {!Uint8Array} */ (reader.readBytes());
+      msg.setIdentity(value);
       break;
     case 2:
-      var value = /** @type {!Uint8Array} */ (reader.readBytes());
-      msg.setValue(value);
+      var value = new proto.org.dash.platform.dapi.v0.Proof;
+      reader.readMessage(value,proto.org.dash.platform.dapi.v0.Proof.deserializeBinaryFromReader);
+      msg.setProof(value);
+      break;
+    case 3:
+      var value = new proto.org.dash.platform.dapi.v0.ResponseMetadata;
+      reader.readMessage(value,proto.org.dash.platform.dapi.v0.ResponseMetadata.deserializeBinaryFromReader);
+      msg.setMetadata(value);
       break;
     default:
       reader.skipField();
@@ -10588,9 +14110,9 @@ proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHisto
  * Serializes the message to binary data (in protobuf wire format).
  * @return {!Uint8Array}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistoryEntry.prototype.serializeBinary = function() {
+proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse.prototype.serializeBinary = function() {
   var writer = new jspb.BinaryWriter();
-  proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistoryEntry.serializeBinaryToWriter(this, writer);
+  proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse.serializeBinaryToWriter(this, writer);
   return writer.getResultBuffer();
 };
 
@@ -10598,96 +14120,172 @@ proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHisto
 /**
  * Serializes the given message to binary data (in protobuf wire
  * format), writing to the given BinaryWriter.
- * @param {!proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistoryEntry} message
+ * @param {!proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse} message
  * @param {!jspb.BinaryWriter} writer
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistoryEntry.serializeBinaryToWriter = function(message, writer) {
+proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse.serializeBinaryToWriter = function(message, writer) {
   var f = undefined;
-  f = message.getDate();
-  if (f !== 0) {
-    writer.writeUint64(
+  f = /** @type {!(string|Uint8Array)} */ (jspb.Message.getField(message, 1));
+  if (f != null) {
+    writer.writeBytes(
       1,
       f
     );
   }
-  f = message.getValue_asU8();
-  if (f.length > 0) {
-    writer.writeBytes(
+  f = message.getProof();
+  if (f != null) {
+    writer.writeMessage(
       2,
-      f
+      f,
+      proto.org.dash.platform.dapi.v0.Proof.serializeBinaryToWriter
+    );
+  }
+  f = message.getMetadata();
+  if (f != null) {
+    writer.writeMessage(
+      3,
+      f,
+      proto.org.dash.platform.dapi.v0.ResponseMetadata.serializeBinaryToWriter
     );
   }
 };
 
 
 /**
- * optional uint64 date = 1;
- * @return {number}
+ * optional bytes identity = 1;
+ * @return {string}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistoryEntry.prototype.getDate = function() {
-  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 1, 0));
+proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse.prototype.getIdentity = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 1, ""));
 };
 
 
 /**
- * @param {number} value
- * @return {!proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistoryEntry} returns this
+ * optional bytes identity = 1;
+ * This is a type-conversion wrapper around `getIdentity()`
+ * @return {string}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistoryEntry.prototype.setDate = function(value) {
-  return jspb.Message.setProto3IntField(this, 1, value);
+proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse.prototype.getIdentity_asB64 = function() {
+  return /** @type {string} */ (jspb.Message.bytesAsB64(
+      this.getIdentity()));
 };
 
 
 /**
- * optional bytes value = 2;
- * @return {string}
+ * optional bytes identity = 1;
+ * Note that Uint8Array is not supported on all browsers.
+ * @see http://caniuse.com/Uint8Array
+ * This is a type-conversion wrapper around `getIdentity()`
+ * @return {!Uint8Array}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistoryEntry.prototype.getValue = function() {
-  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 2, ""));
+proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse.prototype.getIdentity_asU8 = function() {
+  return /** @type {!Uint8Array} */ (jspb.Message.bytesAsU8(
+      this.getIdentity()));
 };
 
 
 /**
- * optional bytes value = 2;
- * This is a type-conversion wrapper around `getValue()`
- * @return {string}
+ * @param {!(string|Uint8Array)} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse} returns this
  */
-proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistoryEntry.prototype.getValue_asB64 = function() {
-  return /** @type {string} */ (jspb.Message.bytesAsB64(
-      this.getValue()));
+proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse.prototype.setIdentity = function(value) {
+  return jspb.Message.setOneofField(this, 1, proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse.oneofGroups_[0], value);
 };
 
 
 /**
- * optional bytes value = 2;
- * Note that Uint8Array is not supported on all browsers.
- * @see http://caniuse.com/Uint8Array
- * This is a type-conversion wrapper around `getValue()`
- * @return {!Uint8Array}
+ * Clears the field making it undefined.
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse} returns this
  */
-proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistoryEntry.prototype.getValue_asU8 = function() {
-  return /** @type {!Uint8Array} */ (jspb.Message.bytesAsU8(
-      this.getValue()));
+proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse.prototype.clearIdentity = function() {
+  return jspb.Message.setOneofField(this, 1, proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse.oneofGroups_[0], undefined);
 };
 
 
 /**
- * @param {!(string|Uint8Array)} value
- * @return {!proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistoryEntry} returns this
+ * Returns whether this field is set.
+ * @return {boolean}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistoryEntry.prototype.setValue = function(value) {
-  return jspb.Message.setProto3BytesField(this, 2, value);
+proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse.prototype.hasIdentity = function() {
+  return jspb.Message.getField(this, 1) != null;
+};
+
+
+/**
+ * optional Proof proof = 2;
+ * @return {?proto.org.dash.platform.dapi.v0.Proof}
+ */
+proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse.prototype.getProof = function() {
+  return /** @type{?proto.org.dash.platform.dapi.v0.Proof} */ (
+    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.Proof, 2));
 };
 
 
+/**
+ * @param {?proto.org.dash.platform.dapi.v0.Proof|undefined} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse} returns this
+*/
+proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse.prototype.setProof = function(value) {
+  return jspb.Message.setOneofWrapperField(this, 2, proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse.oneofGroups_[0], value);
+};
+
 
 /**
- * List of repeated fields within this message type.
- * @private {!Array<number>}
- * @const
+ * Clears the message field making it undefined.
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse} returns this
  */
-proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistory.repeatedFields_ = [1];
+proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse.prototype.clearProof = function() {
+  return this.setProof(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse.prototype.hasProof = function() {
+  return jspb.Message.getField(this, 2) != null;
+};
+
+
+/**
+ * optional ResponseMetadata metadata = 3;
+ * @return {?proto.org.dash.platform.dapi.v0.ResponseMetadata}
+ */
+proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse.prototype.getMetadata = function() {
+  return /** @type{?proto.org.dash.platform.dapi.v0.ResponseMetadata} */ (
+    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.ResponseMetadata, 3));
+};
+
+
+/**
+ * @param {?proto.org.dash.platform.dapi.v0.ResponseMetadata|undefined} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse} returns this
+*/
+proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse.prototype.setMetadata = function(value) {
+  return jspb.Message.setWrapperField(this, 3, value);
+};
+
+
+/**
+ * Clears the message field making it undefined.
+ * @return {!proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse} returns this
+ */
+proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse.prototype.clearMetadata = function() {
+  return this.setMetadata(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse.prototype.hasMetadata = function() {
+  return jspb.Message.getField(this, 3) != null;
+};
+
+
 
 
 
@@ -10704,8 +14302,8 @@ if (jspb.Message.GENERATE_TO_OBJECT) {
  *     http://goto/soy-param-migration
  * @return {!Object}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistory.prototype.toObject = function(opt_includeInstance) {
-  return proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistory.toObject(opt_includeInstance, this);
+proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultRequest.prototype.toObject = function(opt_includeInstance) {
+  return proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultRequest.toObject(opt_includeInstance, this);
 };
 
 
@@ -10714,14 +14312,14 @@ proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHisto
  * @param {boolean|undefined} includeInstance Deprecated. Whether to include
  *     the JSPB instance for transitional soy proto support:
  *     http://goto/soy-param-migration
- * @param {!proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistory} msg The msg instance to transform.
+ * @param {!proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultRequest} msg The msg instance to transform.
  * @return {!Object}
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistory.toObject = function(includeInstance, msg) {
+proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultRequest.toObject = function(includeInstance, msg) {
   var f, obj = {
-    dataContractEntriesList: jspb.Message.toObjectList(msg.getDataContractEntriesList(),
-    proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistoryEntry.toObject, includeInstance)
+    stateTransitionHash: msg.getStateTransitionHash_asB64(),
+    prove: jspb.Message.getBooleanFieldWithDefault(msg, 2, false)
   };
 
   if (includeInstance) {
@@ -10735,23 +14333,23 @@ proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHisto
 /**
  * Deserializes binary data (in protobuf wire format).
  * @param {jspb.ByteSource} bytes The bytes to deserialize.
- * @return {!proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistory}
+ * @return {!proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultRequest}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistory.deserializeBinary = function(bytes) {
+proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultRequest.deserializeBinary = function(bytes) {
   var reader = new jspb.BinaryReader(bytes);
-  var msg = new proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistory;
-  return proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistory.deserializeBinaryFromReader(msg, reader);
+  var msg = new proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultRequest;
+  return proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultRequest.deserializeBinaryFromReader(msg, reader);
 };
 
 
 /**
  * Deserializes binary data (in protobuf wire format) from the
  * given reader into the given message object.
- * @param {!proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistory} msg The message object to deserialize into.
+ * @param {!proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultRequest} msg The message object to deserialize into.
  * @param {!jspb.BinaryReader} reader The BinaryReader to use.
- * @return {!proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistory}
+ * @return {!proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultRequest}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistory.deserializeBinaryFromReader = function(msg, reader) {
+proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultRequest.deserializeBinaryFromReader = function(msg, reader) {
   while (reader.nextField()) {
     if (reader.isEndGroup()) {
       break;
@@ -10759,9 +14357,12 @@ proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHisto
     var field = reader.getFieldNumber();
     switch (field) {
     case 1:
-      var value = new proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistoryEntry;
-      reader.readMessage(value,proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistoryEntry.deserializeBinaryFromReader);
-      msg.addDataContractEntries(value);
+      var value = /** @type {!Uint8Array} */ (reader.readBytes());
+      msg.setStateTransitionHash(value);
+      break;
+    case 2:
+      var value = /** @type {boolean} */ (reader.readBool());
+      msg.setProve(value);
       break;
     default:
       reader.skipField();
@@ -10776,9 +14377,9 @@ proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHisto
  * Serializes the message to binary data (in protobuf wire format).
  * @return {!Uint8Array}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistory.prototype.serializeBinary = function() {
+proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultRequest.prototype.serializeBinary = function() {
   var writer = new jspb.BinaryWriter();
-  proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistory.serializeBinaryToWriter(this, writer);
+  proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultRequest.serializeBinaryToWriter(this, writer);
   return writer.getResultBuffer();
 };
 
@@ -10786,201 +14387,275 @@ proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHisto
 /**
  * Serializes the given message to binary data (in protobuf wire
  * format), writing to the given BinaryWriter.
- * @param {!proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistory} message
+ * @param {!proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultRequest} message
  * @param {!jspb.BinaryWriter} writer
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistory.serializeBinaryToWriter = function(message, writer) {
+proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultRequest.serializeBinaryToWriter = function(message, writer) {
   var f = undefined;
-  f = message.getDataContractEntriesList();
+  f = message.getStateTransitionHash_asU8();
   if (f.length > 0) {
-    writer.writeRepeatedMessage(
+    writer.writeBytes(
       1,
-      f,
-      proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistoryEntry.serializeBinaryToWriter
+      f
+    );
+  }
+  f = message.getProve();
+  if (f) {
+    writer.writeBool(
+      2,
+      f
     );
   }
 };
 
 
 /**
- * repeated DataContractHistoryEntry data_contract_entries = 1;
- * @return {!Array<!proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistoryEntry>}
- */
-proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistory.prototype.getDataContractEntriesList = function() {
-  return /** @type{!Array<!proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistoryEntry>} */ (
-    jspb.Message.getRepeatedWrapperField(this, proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistoryEntry, 1));
-};
-
-
-/**
- * @param {!Array<!proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistoryEntry>} value
- * @return {!proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistory} returns this
-*/
-proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistory.prototype.setDataContractEntriesList = function(value) {
-  return jspb.Message.setRepeatedWrapperField(this, 1, value);
-};
-
-
-/**
- * @param {!proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistoryEntry=} opt_value
- * @param {number=} opt_index
- * @return {!proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistoryEntry}
- */
-proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistory.prototype.addDataContractEntries = function(opt_value, opt_index) {
-  return jspb.Message.addToRepeatedWrapperField(this, 1, opt_value, proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistoryEntry, opt_index);
-};
-
-
-/**
- * Clears the list making it empty but non-null.
- * @return {!proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistory} returns this
- */
-proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistory.prototype.clearDataContractEntriesList = function() {
-  return this.setDataContractEntriesList([]);
+ * optional bytes state_transition_hash = 1;
+ * @return {string}
+ */
+proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultRequest.prototype.getStateTransitionHash = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 1, ""));
 };
 
 
 /**
- * optional DataContractHistory data_contract_history = 1;
- * @return {?proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistory}
+ * optional bytes state_transition_hash = 1;
+ * This is a type-conversion wrapper around `getStateTransitionHash()`
+ * @return {string}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.prototype.getDataContractHistory = function() {
-  return /** @type{?proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistory} */ (
-    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistory, 1));
+proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultRequest.prototype.getStateTransitionHash_asB64 = function() {
+  return /** @type {string} */ (jspb.Message.bytesAsB64(
+      this.getStateTransitionHash()));
 };
 
 
 /**
- * @param {?proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistory|undefined} value
- * @return {!proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse} returns this
-*/
-proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.prototype.setDataContractHistory = function(value) {
-  return jspb.Message.setOneofWrapperField(this, 1, proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.oneofGroups_[0], value);
+ * optional bytes state_transition_hash = 1;
+ * Note that Uint8Array is not supported on all browsers.
+ * @see http://caniuse.com/Uint8Array
+ * This is a type-conversion wrapper around `getStateTransitionHash()`
+ * @return {!Uint8Array}
+ */
+proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultRequest.prototype.getStateTransitionHash_asU8 = function() {
+  return /** @type {!Uint8Array} */ (jspb.Message.bytesAsU8(
+      this.getStateTransitionHash()));
 };
 
 
 /**
- * Clears the message field making it undefined.
- * @return {!proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse} returns this
+ * @param {!(string|Uint8Array)} value
+ * @return {!proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultRequest} returns this
  */
-proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.prototype.clearDataContractHistory = function() {
-  return this.setDataContractHistory(undefined);
+proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultRequest.prototype.setStateTransitionHash = function(value) {
+  return jspb.Message.setProto3BytesField(this, 1, value);
 };
 
 
 /**
- * Returns whether this field is set.
+ * optional bool prove = 2;
  * @return {boolean}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.prototype.hasDataContractHistory = function() {
-  return jspb.Message.getField(this, 1) != null;
+proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultRequest.prototype.getProve = function() {
+  return /** @type {boolean} */ (jspb.Message.getBooleanFieldWithDefault(this, 2, false));
 };
 
 
 /**
- * optional Proof proof = 2;
- * @return {?proto.org.dash.platform.dapi.v0.Proof}
+ * @param {boolean} value
+ * @return {!proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultRequest} returns this
  */
-proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.prototype.getProof = function() {
-  return /** @type{?proto.org.dash.platform.dapi.v0.Proof} */ (
-    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.Proof, 2));
+proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultRequest.prototype.setProve = function(value) {
+  return jspb.Message.setProto3BooleanField(this, 2, value);
 };
 
 
-/**
- * @param {?proto.org.dash.platform.dapi.v0.Proof|undefined} value
- * @return {!proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse} returns this
-*/
-proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.prototype.setProof = function(value) {
-  return jspb.Message.setOneofWrapperField(this, 2, proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.oneofGroups_[0], value);
-};
-
 
 /**
- * Clears the message field making it undefined.
- * @return {!proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse} returns this
+ * Oneof group definitions for this message. Each group defines the field
+ * numbers belonging to that group. When of these fields' value is set, all
+ * other fields in the group are cleared. During deserialization, if multiple
+ * fields are encountered for a group, only the last value seen will be kept.
+ * @private {!Array<!Array<number>>}
+ * @const
  */
-proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.prototype.clearProof = function() {
-  return this.setProof(undefined);
-};
-
+proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.oneofGroups_ = [[1,2,4]];
 
 /**
- * Returns whether this field is set.
- * @return {boolean}
+ * @enum {number}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.prototype.hasProof = function() {
-  return jspb.Message.getField(this, 2) != null;
+proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.ResultCase = {
+  RESULT_NOT_SET: 0,
+  ERROR: 1,
+  PROOF: 2,
+  STATE_TRANSITION_RESULT: 4
 };
 
-
 /**
- * optional ResponseMetadata metadata = 3;
- * @return {?proto.org.dash.platform.dapi.v0.ResponseMetadata}
+ * @return {proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.ResultCase}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.prototype.getMetadata = function() {
-  return /** @type{?proto.org.dash.platform.dapi.v0.ResponseMetadata} */ (
-    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.ResponseMetadata, 3));
+proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.prototype.getResultCase = function() {
+  return /** @type {proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.ResultCase} */(jspb.Message.computeOneofCase(this, proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.oneofGroups_[0]));
 };
 
 
+
+if (jspb.Message.GENERATE_TO_OBJECT) {
 /**
- * @param {?proto.org.dash.platform.dapi.v0.ResponseMetadata|undefined} value
- * @return {!proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse} returns this
-*/
-proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.prototype.setMetadata = function(value) {
-  return jspb.Message.setWrapperField(this, 3, value);
+ * Creates an object representation of this proto.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * Optional fields that are not set will be set to undefined.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * For the list of reserved names please see:
+ *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
+ * @param {boolean=} opt_includeInstance Deprecated. whether to include the
+ *     JSPB instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @return {!Object}
+ */
+proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.prototype.toObject = function(opt_includeInstance) {
+  return proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.toObject(opt_includeInstance, this);
 };
 
 
 /**
- * Clears the message field making it undefined.
- * @return {!proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse} returns this
+ * Static version of the {@see toObject} method.
+ * @param {boolean|undefined} includeInstance Deprecated. Whether to include
+ *     the JSPB instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @param {!proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse} msg The msg instance to transform.
+ * @return {!Object}
+ * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.prototype.clearMetadata = function() {
-  return this.setMetadata(undefined);
+proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.toObject = function(includeInstance, msg) {
+  var f, obj = {
+    error: (f = msg.getError()) && proto.org.dash.platform.dapi.v0.StateTransitionBroadcastError.toObject(includeInstance, f),
+    proof: (f = msg.getProof()) && proto.org.dash.platform.dapi.v0.Proof.toObject(includeInstance, f),
+    stateTransitionResult: (f = msg.getStateTransitionResult()) && proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.StateTransitionResult.toObject(includeInstance, f),
+    metadata: (f = msg.getMetadata()) && proto.org.dash.platform.dapi.v0.ResponseMetadata.toObject(includeInstance, f)
+  };
+
+  if (includeInstance) {
+    obj.$jspbMessageInstance = msg;
+  }
+  return obj;
 };
+}
 
 
 /**
- * Returns whether this field is set.
- * @return {boolean}
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse}
  */
-proto.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.prototype.hasMetadata = function() {
-  return jspb.Message.getField(this, 3) != null;
+proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.deserializeBinary = function(bytes) {
+  var reader = new jspb.BinaryReader(bytes);
+  var msg = new proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse;
+  return proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.deserializeBinaryFromReader(msg, reader);
 };
 
 
-
 /**
- * Oneof group definitions for this message. Each group defines the field
- * numbers belonging to that group. When of these fields' value is set, all
- * other fields in the group are cleared. During deserialization, if multiple
- * fields are encountered for a group, only the last value seen will be kept.
- * @private {!Array<!Array<number>>}
- * @const
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse}
  */
-proto.org.dash.platform.dapi.v0.GetDocumentsRequest.oneofGroups_ = [[6,7]];
+proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.deserializeBinaryFromReader = function(msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+    case 1:
+      var value = new proto.org.dash.platform.dapi.v0.StateTransitionBroadcastError;
+      reader.readMessage(value,proto.org.dash.platform.dapi.v0.StateTransitionBroadcastError.deserializeBinaryFromReader);
+      msg.setError(value);
+      break;
+    case 2:
+      var value = new proto.org.dash.platform.dapi.v0.Proof;
+      reader.readMessage(value,proto.org.dash.platform.dapi.v0.Proof.deserializeBinaryFromReader);
+      msg.setProof(value);
+      break;
+    case 4:
+      var value = new proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.StateTransitionResult;
+      reader.readMessage(value,proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.StateTransitionResult.deserializeBinaryFromReader);
+      msg.setStateTransitionResult(value);
+      break;
+    case 3:
+      var value = new proto.org.dash.platform.dapi.v0.ResponseMetadata;
+      reader.readMessage(value,proto.org.dash.platform.dapi.v0.ResponseMetadata.deserializeBinaryFromReader);
+      msg.setMetadata(value);
+      break;
+    default:
+      reader.skipField();
+      break;
+    }
+  }
+  return msg;
+};
+
 
 /**
- * @enum {number}
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
  */
-proto.org.dash.platform.dapi.v0.GetDocumentsRequest.StartCase = {
-  START_NOT_SET: 0,
-  START_AFTER: 6,
-  START_AT: 7
+proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
 };
 
+
 /**
- * @return {proto.org.dash.platform.dapi.v0.GetDocumentsRequest.StartCase}
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.org.dash.platform.dapi.v0.GetDocumentsRequest.prototype.getStartCase = function() {
-  return /** @type {proto.org.dash.platform.dapi.v0.GetDocumentsRequest.StartCase} */(jspb.Message.computeOneofCase(this, proto.org.dash.platform.dapi.v0.GetDocumentsRequest.oneofGroups_[0]));
+proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+  f = message.getError();
+  if (f != null) {
+    writer.writeMessage(
+      1,
+      f,
+      proto.org.dash.platform.dapi.v0.StateTransitionBroadcastError.serializeBinaryToWriter
+    );
+  }
+  f = message.getProof();
+  if (f != null) {
+    writer.writeMessage(
+      2,
+      f,
+      proto.org.dash.platform.dapi.v0.Proof.serializeBinaryToWriter
+    );
+  }
+  f = message.getStateTransitionResult();
+  if (f != null) {
+    writer.writeMessage(
+      4,
+      f,
+      proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.StateTransitionResult.serializeBinaryToWriter
+    );
+  }
+  f = message.getMetadata();
+  if (f != null) {
+    writer.writeMessage(
+      3,
+      f,
+      proto.org.dash.platform.dapi.v0.ResponseMetadata.serializeBinaryToWriter
+    );
+  }
 };
 
 
 
+
+
 if (jspb.Message.GENERATE_TO_OBJECT) {
 /**
  * Creates an object representation of this proto.
@@ -10994,8 +14669,8 @@ if (jspb.Message.GENERATE_TO_OBJECT) {
  *     http://goto/soy-param-migration
  * @return {!Object}
  */
-proto.org.dash.platform.dapi.v0.GetDocumentsRequest.prototype.toObject = function(opt_includeInstance) {
-  return proto.org.dash.platform.dapi.v0.GetDocumentsRequest.toObject(opt_includeInstance, this);
+proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.StateTransitionResult.prototype.toObject = function(opt_includeInstance) {
+  return proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.StateTransitionResult.toObject(opt_includeInstance, this);
 };
 
 
@@ -11004,20 +14679,15 @@ proto.org.dash.platform.dapi.v0.GetDocumentsRequest.prototype.toObject = functio
  * @param {boolean|undefined} includeInstance Deprecated. Whether to include
  *     the JSPB instance for transitional soy proto support:
  *     http://goto/soy-param-migration
- * @param {!proto.org.dash.platform.dapi.v0.GetDocumentsRequest} msg The msg instance to transform.
+ * @param {!proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.StateTransitionResult} msg The msg instance to transform.
  * @return {!Object}
- * @suppress {unusedLocalVariables} f is only used for nested messages
- */
-proto.org.dash.platform.dapi.v0.GetDocumentsRequest.toObject = function(includeInstance, msg) {
-  var f, obj = {
-    dataContractId: msg.getDataContractId_asB64(),
-    documentType: jspb.Message.getFieldWithDefault(msg, 2, ""),
-    where: msg.getWhere_asB64(),
-    orderBy: msg.getOrderBy_asB64(),
-    limit: jspb.Message.getFieldWithDefault(msg, 5, 0),
-    startAfter: msg.getStartAfter_asB64(),
-    startAt: msg.getStartAt_asB64(),
-    prove: jspb.Message.getBooleanFieldWithDefault(msg, 8, false)
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.StateTransitionResult.toObject = function(includeInstance, msg) {
+  var f, obj = {
+    height: jspb.Message.getFieldWithDefault(msg, 1, 0),
+    storageFee: jspb.Message.getFieldWithDefault(msg, 2, 0),
+    processingFee: jspb.Message.getFieldWithDefault(msg, 3, 0)
   };
 
   if (includeInstance) {
@@ -11031,23 +14701,23 @@ proto.org.dash.platform.dapi.v0.GetDocumentsRequest.toObject = function(includeI
 /**
  * Deserializes binary data (in protobuf wire format).
  * @param {jspb.ByteSource} bytes The bytes to deserialize.
- * @return {!proto.org.dash.platform.dapi.v0.GetDocumentsRequest}
+ * @return {!proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.StateTransitionResult}
  */
-proto.org.dash.platform.dapi.v0.GetDocumentsRequest.deserializeBinary = function(bytes) {
+proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.StateTransitionResult.deserializeBinary = function(bytes) {
   var reader = new jspb.BinaryReader(bytes);
-  var msg = new proto.org.dash.platform.dapi.v0.GetDocumentsRequest;
-  return proto.org.dash.platform.dapi.v0.GetDocumentsRequest.deserializeBinaryFromReader(msg, reader);
+  var msg = new proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.StateTransitionResult;
+  return proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.StateTransitionResult.deserializeBinaryFromReader(msg, reader);
 };
 
 
 /**
  * Deserializes binary data (in protobuf wire format) from the
  * given reader into the given message object.
- * @param {!proto.org.dash.platform.dapi.v0.GetDocumentsRequest} msg The message object to deserialize into.
+ * @param {!proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.StateTransitionResult} msg The message object to deserialize into.
  * @param {!jspb.BinaryReader} reader The BinaryReader to use.
- * @return {!proto.org.dash.platform.dapi.v0.GetDocumentsRequest}
+ * @return {!proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.StateTransitionResult}
  */
-proto.org.dash.platform.dapi.v0.GetDocumentsRequest.deserializeBinaryFromReader = function(msg, reader) {
+proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.StateTransitionResult.deserializeBinaryFromReader = function(msg, reader) {
   while (reader.nextField()) {
     if (reader.isEndGroup()) {
       break;
@@ -11055,36 +14725,16 @@ proto.org.dash.platform.dapi.v0.GetDocumentsRequest.deserializeBinaryFromReader
     var field = reader.getFieldNumber();
     switch (field) {
     case 1:
-      var value = /** @type {!Uint8Array} */ (reader.readBytes());
-      msg.setDataContractId(value);
+      var value = /** @type {number} */ (reader.readUint64());
+      msg.setHeight(value);
       break;
     case 2:
-      var value = /** @type {string} */ (reader.readString());
-      msg.setDocumentType(value);
+      var value = /** @type {number} */ (reader.readUint64());
+      msg.setStorageFee(value);
       break;
     case 3:
-      var value = /** @type {!Uint8Array} */ (reader.readBytes());
-      msg.setWhere(value);
-      break;
-    case 4:
-      var value = /** @type {!Uint8Array} */ (reader.readBytes());
-      msg.setOrderBy(value);
-      break;
-    case 5:
-      var value = /** @type {number} */ (reader.readUint32());
-      msg.setLimit(value);
-      break;
-    case 6:
-      var value = /** @type {!Uint8Array} */ (reader.readBytes());
-      msg.setStartAfter(value);
-      break;
-    case 7:
-      var value = /** @type {!Uint8Array} */ (reader.readBytes());
-      msg.setStartAt(value);
-      break;
-    case 8:
-      var value = /** @type {boolean} */ (reader.readBool());
-      msg.setProve(value);
+      var value = /** @type {number} */ (reader.readUint64());
+      msg.setProcessingFee(value);
       break;
     default:
       reader.skipField();
@@ -11099,9 +14749,9 @@ proto.org.dash.platform.dapi.v0.GetDocumentsRequest.deserializeBinaryFromReader
  * Serializes the message to binary data (in protobuf wire format).
  * @return {!Uint8Array}
  */
-proto.org.dash.platform.dapi.v0.GetDocumentsRequest.prototype.serializeBinary = function() {
+proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.StateTransitionResult.prototype.serializeBinary = function() {
   var writer = new jspb.BinaryWriter();
-  proto.org.dash.platform.dapi.v0.GetDocumentsRequest.serializeBinaryToWriter(this, writer);
+  proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.StateTransitionResult.serializeBinaryToWriter(this, writer);
   return writer.getResultBuffer();
 };
 
@@ -11109,65 +14759,30 @@ proto.org.dash.platform.dapi.v0.GetDocumentsRequest.prototype.serializeBinary =
 /**
  * Serializes the given message to binary data (in protobuf wire
  * format), writing to the given BinaryWriter.
- * @param {!proto.org.dash.platform.dapi.v0.GetDocumentsRequest} message
+ * @param {!proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.StateTransitionResult} message
  * @param {!jspb.BinaryWriter} writer
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.org.dash.platform.dapi.v0.GetDocumentsRequest.serializeBinaryToWriter = function(message, writer) {
+proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.StateTransitionResult.serializeBinaryToWriter = function(message, writer) {
   var f = undefined;
-  f = message.getDataContractId_asU8();
-  if (f.length > 0) {
-    writer.writeBytes(
+  f = message.getHeight();
+  if (f !== 0) {
+    writer.writeUint64(
       1,
       f
     );
   }
-  f = message.getDocumentType();
-  if (f.length > 0) {
-    writer.writeString(
+  f = message.getStorageFee();
+  if (f !== 0) {
+    writer.writeUint64(
       2,
       f
     );
   }
-  f = message.getWhere_asU8();
-  if (f.length > 0) {
-    writer.writeBytes(
-      3,
-      f
-    );
-  }
-  f = message.getOrderBy_asU8();
-  if (f.length > 0) {
-    writer.writeBytes(
-      4,
-      f
-    );
-  }
-  f = message.getLimit();
+  f = message.getProcessingFee();
   if (f !== 0) {
-    writer.writeUint32(
-      5,
-      f
-    );
-  }
-  f = /** @type {!(string|Uint8Array)} */ (jspb.Message.getField(message, 6));
-  if (f != null) {
-    writer.writeBytes(
-      6,
-      f
-    );
-  }
-  f = /** @type {!(string|Uint8Array)} */ (jspb.Message.getField(message, 7));
-  if (f != null) {
-    writer.writeBytes(
-      7,
-      f
-    );
-  }
-  f = message.getProve();
-  if (f) {
-    writer.writeBool(
-      8,
+    writer.writeUint64(
+      3,
       f
     );
   }
@@ -11175,334 +14790,210 @@ proto.org.dash.platform.dapi.v0.GetDocumentsRequest.serializeBinaryToWriter = fu
 
 
 /**
- * optional bytes data_contract_id = 1;
- * @return {string}
- */
-proto.org.dash.platform.dapi.v0.GetDocumentsRequest.prototype.getDataContractId = function() {
-  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 1, ""));
-};
-
-
-/**
- * optional bytes data_contract_id = 1;
- * This is a type-conversion wrapper around `getDataContractId()`
- * @return {string}
- */
-proto.org.dash.platform.dapi.v0.GetDocumentsRequest.prototype.getDataContractId_asB64 = function() {
-  return /** @type {string} */ (jspb.Message.bytesAsB64(
-      this.getDataContractId()));
-};
-
-
-/**
- * optional bytes data_contract_id = 1;
- * Note that Uint8Array is not supported on all browsers.
- * @see http://caniuse.com/Uint8Array
- * This is a type-conversion wrapper around `getDataContractId()`
- * @return {!Uint8Array}
- */
-proto.org.dash.platform.dapi.v0.GetDocumentsRequest.prototype.getDataContractId_asU8 = function() {
-  return /** @type {!Uint8Array} */ (jspb.Message.bytesAsU8(
-      this.getDataContractId()));
-};
-
-
-/**
- * @param {!(string|Uint8Array)} value
- * @return {!proto.org.dash.platform.dapi.v0.GetDocumentsRequest} returns this
- */
-proto.org.dash.platform.dapi.v0.GetDocumentsRequest.prototype.setDataContractId = function(value) {
-  return jspb.Message.setProto3BytesField(this, 1, value);
-};
-
-
-/**
- * optional string document_type = 2;
- * @return {string}
- */
-proto.org.dash.platform.dapi.v0.GetDocumentsRequest.prototype.getDocumentType = function() {
-  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 2, ""));
-};
-
-
-/**
- * @param {string} value
- * @return {!proto.org.dash.platform.dapi.v0.GetDocumentsRequest} returns this
- */
-proto.org.dash.platform.dapi.v0.GetDocumentsRequest.prototype.setDocumentType = function(value) {
-  return jspb.Message.setProto3StringField(this, 2, value);
-};
-
-
-/**
- * optional bytes where = 3;
- * @return {string}
- */
-proto.org.dash.platform.dapi.v0.GetDocumentsRequest.prototype.getWhere = function() {
-  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 3, ""));
-};
-
-
-/**
- * optional bytes where = 3;
- * This is a type-conversion wrapper around `getWhere()`
- * @return {string}
- */
-proto.org.dash.platform.dapi.v0.GetDocumentsRequest.prototype.getWhere_asB64 = function() {
-  return /** @type {string} */ (jspb.Message.bytesAsB64(
-      this.getWhere()));
-};
-
-
-/**
- * optional bytes where = 3;
- * Note that Uint8Array is not supported on all browsers.
- * @see http://caniuse.com/Uint8Array
- * This is a type-conversion wrapper around `getWhere()`
- * @return {!Uint8Array}
- */
-proto.org.dash.platform.dapi.v0.GetDocumentsRequest.prototype.getWhere_asU8 = function() {
-  return /** @type {!Uint8Array} */ (jspb.Message.bytesAsU8(
-      this.getWhere()));
-};
-
-
-/**
- * @param {!(string|Uint8Array)} value
- * @return {!proto.org.dash.platform.dapi.v0.GetDocumentsRequest} returns this
- */
-proto.org.dash.platform.dapi.v0.GetDocumentsRequest.prototype.setWhere = function(value) {
-  return jspb.Message.setProto3BytesField(this, 3, value);
-};
-
-
-/**
- * optional bytes order_by = 4;
- * @return {string}
+ * optional uint64 height = 1;
+ * @return {number}
  */
-proto.org.dash.platform.dapi.v0.GetDocumentsRequest.prototype.getOrderBy = function() {
-  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 4, ""));
+proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.StateTransitionResult.prototype.getHeight = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 1, 0));
 };
 
 
 /**
- * optional bytes order_by = 4;
- * This is a type-conversion wrapper around `getOrderBy()`
- * @return {string}
+ * @param {number} value
+ * @return {!proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.StateTransitionResult} returns this
  */
-proto.org.dash.platform.dapi.v0.GetDocumentsRequest.prototype.getOrderBy_asB64 = function() {
-  return /** @type {string} */ (jspb.Message.bytesAsB64(
-      this.getOrderBy()));
+proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.StateTransitionResult.prototype.setHeight = function(value) {
+  return jspb.Message.setProto3IntField(this, 1, value);
 };
 
 
 /**
- * optional bytes order_by = 4;
- * Note that Uint8Array is not supported on all browsers.
- * @see http://caniuse.com/Uint8Array
- * This is a type-conversion wrapper around `getOrderBy()`
- * @return {!Uint8Array}
+ * optional uint64 storage_fee = 2;
+ * @return {number}
  */
-proto.org.dash.platform.dapi.v0.GetDocumentsRequest.prototype.getOrderBy_asU8 = function() {
-  return /** @type {!Uint8Array} */ (jspb.Message.bytesAsU8(
-      this.getOrderBy()));
+proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.StateTransitionResult.prototype.getStorageFee = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 2, 0));
 };
 
 
 /**
- * @param {!(string|Uint8Array)} value
- * @return {!proto.org.dash.platform.dapi.v0.GetDocumentsRequest} returns this
+ * @param {number} value
+ * @return {!proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.StateTransitionResult} returns this
  */
-proto.org.dash.platform.dapi.v0.GetDocumentsRequest.prototype.setOrderBy = function(value) {
-  return jspb.Message.setProto3BytesField(this, 4, value);
+proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.StateTransitionResult.prototype.setStorageFee = function(value) {
+  return jspb.Message.setProto3IntField(this, 2, value);
 };
 
 
 /**
- * optional uint32 limit = 5;
+ * optional uint64 processing_fee = 3;
  * @return {number}
  */
-proto.org.dash.platform.dapi.v0.GetDocumentsRequest.prototype.getLimit = function() {
-  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 5, 0));
+proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.StateTransitionResult.prototype.getProcessingFee = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 3, 0));
 };
 
 
 /**
  * @param {number} value
- * @return {!proto.org.dash.platform.dapi.v0.GetDocumentsRequest} returns this
- */
-proto.org.dash.platform.dapi.v0.GetDocumentsRequest.prototype.setLimit = function(value) {
-  return jspb.Message.setProto3IntField(this, 5, value);
-};
-
-
-/**
- * optional bytes start_after = 6;
- * @return {string}
+ * @return {!proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.StateTransitionResult} returns this
  */
-proto.org.dash.platform.dapi.v0.GetDocumentsRequest.prototype.getStartAfter = function() {
-  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 6, ""));
+proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.StateTransitionResult.prototype.setProcessingFee = function(value) {
+  return jspb.Message.setProto3IntField(this, 3, value);
 };
 
 
 /**
- * optional bytes start_after = 6;
- * This is a type-conversion wrapper around `getStartAfter()`
- * @return {string}
+ * optional StateTransitionBroadcastError error = 1;
+ * @return {?proto.org.dash.platform.dapi.v0.StateTransitionBroadcastError}
  */
-proto.org.dash.platform.dapi.v0.GetDocumentsRequest.prototype.getStartAfter_asB64 = function() {
-  return /** @type {string} */ (jspb.Message.bytesAsB64(
-      this.getStartAfter()));
+proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.prototype.getError = function() {
+  return /** @type{?proto.org.dash.platform.dapi.v0.StateTransitionBroadcastError} */ (
+    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.StateTransitionBroadcastError, 1));
 };
 
 
 /**
- * optional bytes start_after = 6;
- * Note that Uint8Array is not supported on all browsers.
- * @see http://caniuse.com/Uint8Array
- * This is a type-conversion wrapper around `getStartAfter()`
- * @return {!Uint8Array}
- */
-proto.org.dash.platform.dapi.v0.GetDocumentsRequest.prototype.getStartAfter_asU8 = function() {
-  return /** @type {!Uint8Array} */ (jspb.Message.bytesAsU8(
-      this.getStartAfter()));
+ * @param {?proto.org.dash.platform.dapi.v0.StateTransitionBroadcastError|undefined} value
+ * @return {!proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse} returns this
+*/
+proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.prototype.setError = function(value) {
+  return jspb.Message.setOneofWrapperField(this, 1, proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.oneofGroups_[0], value);
 };
 
 
 /**
- * @param {!(string|Uint8Array)} value
- * @return {!proto.org.dash.platform.dapi.v0.GetDocumentsRequest} returns this
+ * Clears the message field making it undefined.
+ * @return {!proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse} returns this
  */
-proto.org.dash.platform.dapi.v0.GetDocumentsRequest.prototype.setStartAfter = function(value) {
-  return jspb.Message.setOneofField(this, 6, proto.org.dash.platform.dapi.v0.GetDocumentsRequest.oneofGroups_[0], value);
+proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.prototype.clearError = function() {
+  return this.setError(undefined);
 };
 
 
 /**
- * Clears the field making it undefined.
- * @return {!proto.org.dash.platform.dapi.v0.GetDocumentsRequest} returns this
+ * Returns whether this field is set.
+ * @return {boolean}
  */
-proto.org.dash.platform.dapi.v0.GetDocumentsRequest.prototype.clearStartAfter = function() {
-  return jspb.Message.setOneofField(this, 6, proto.org.dash.platform.dapi.v0.GetDocumentsRequest.oneofGroups_[0], undefined);
+proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.prototype.hasError = function() {
+  return jspb.Message.getField(this, 1) != null;
 };
 
 
 /**
- * Returns whether this field is set.
- * @return {boolean}
+ * optional Proof proof = 2;
+ * @return {?proto.org.dash.platform.dapi.v0.Proof}
  */
-proto.org.dash.platform.dapi.v0.GetDocumentsRequest.prototype.hasStartAfter = function() {
-  return jspb.Message.getField(this, 6) != null;
+proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.prototype.getProof = function() {
+  return /** @type{?proto.org.dash.platform.dapi.v0.Proof} */ (
+    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.Proof, 2));
 };
 
 
 /**
- * optional bytes start_at = 7;
- * @return {string}
- */
-proto.org.dash.platform.dapi.v0.GetDocumentsRequest.prototype.getStartAt = function() {
-  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 7, ""));
+ * @param {?proto.org.dash.platform.dapi.v0.Proof|undefined} value
+ * @return {!proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse} returns this
+*/
+proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.prototype.setProof = function(value) {
+  return jspb.Message.setOneofWrapperField(this, 2, proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.oneofGroups_[0], value);
 };
 
 
 /**
- * optional bytes start_at = 7;
- * This is a type-conversion wrapper around `getStartAt()`
- * @return {string}
+ * Clears the message field making it undefined.
+ * @return {!proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse} returns this
  */
-proto.org.dash.platform.dapi.v0.GetDocumentsRequest.prototype.getStartAt_asB64 = function() {
-  return /** @type {string} */ (jspb.Message.bytesAsB64(
-      this.getStartAt()));
+proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.prototype.clearProof = function() {
+  return this.setProof(undefined);
 };
 
 
 /**
- * optional bytes start_at = 7;
- * Note that Uint8Array is not supported on all browsers.
- * @see http://caniuse.com/Uint8Array
- * This is a type-conversion wrapper around `getStartAt()`
- * @return {!Uint8Array}
+ * Returns whether this field is set.
+ * @return {boolean}
  */
-proto.org.dash.platform.dapi.v0.GetDocumentsRequest.prototype.getStartAt_asU8 = function() {
-  return /** @type {!Uint8Array} */ (jspb.Message.bytesAsU8(
-      this.getStartAt()));
+proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.prototype.hasProof = function() {
+  return jspb.Message.getField(this, 2) != null;
 };
 
 
 /**
- * @param {!(string|Uint8Array)} value
- * @return {!proto.org.dash.platform.dapi.v0.GetDocumentsRequest} returns this
+ * optional StateTransitionResult state_transition_result = 4;
+ * @return {?proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.StateTransitionResult}
  */
-proto.org.dash.platform.dapi.v0.GetDocumentsRequest.prototype.setStartAt = function(value) {
-  return jspb.Message.setOneofField(this, 7, proto.org.dash.platform.dapi.v0.GetDocumentsRequest.oneofGroups_[0], value);
+proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.prototype.getStateTransitionResult = function() {
+  return /** @type{?proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.StateTransitionResult} */ (
+    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.StateTransitionResult, 4));
 };
 
 
 /**
- * Clears the field making it undefined.
- * @return {!proto.org.dash.platform.dapi.v0.GetDocumentsRequest} returns this
- */
-proto.org.dash.platform.dapi.v0.GetDocumentsRequest.prototype.clearStartAt = function() {
-  return jspb.Message.setOneofField(this, 7, proto.org.dash.platform.dapi.v0.GetDocumentsRequest.oneofGroups_[0], undefined);
+ * @param {?proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.StateTransitionResult|undefined} value
+ * @return {!proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse} returns this
+*/
+proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.prototype.setStateTransitionResult = function(value) {
+  return jspb.Message.setOneofWrapperField(this, 4, proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.oneofGroups_[0], value);
 };
 
 
 /**
- * Returns whether this field is set.
- * @return {boolean}
+ * Clears the message field making it undefined.
+ * @return {!proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse} returns this
  */
-proto.org.dash.platform.dapi.v0.GetDocumentsRequest.prototype.hasStartAt = function() {
-  return jspb.Message.getField(this, 7) != null;
+proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.prototype.clearStateTransitionResult = function() {
+  return this.setStateTransitionResult(undefined);
 };
 
 
 /**
- * optional bool prove = 8;
+ * Returns whether this field is set.
  * @return {boolean}
  */
-proto.org.dash.platform.dapi.v0.GetDocumentsRequest.prototype.getProve = function() {
-  return /** @type {boolean} */ (jspb.Message.getBooleanFieldWithDefault(this, 8, false));
+proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.prototype.hasStateTransitionResult = function() {
+  return jspb.Message.getField(this, 4) != null;
 };
 
 
 /**
- * @param {boolean} value
- * @return {!proto.org.dash.platform.dapi.v0.GetDocumentsRequest} returns this
+ * optional ResponseMetadata metadata = 3;
+ * @return {?proto.org.dash.platform.dapi.v0.ResponseMetadata}
  */
-proto.org.dash.platform.dapi.v0.GetDocumentsRequest.prototype.setProve = function(value) {
-  return jspb.Message.setProto3BooleanField(this, 8, value);
+proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.prototype.getMetadata = function() {
+  return /** @type{?proto.org.dash.platform.dapi.v0.ResponseMetadata} */ (
+    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.ResponseMetadata, 3));
 };
 
 
-
 /**
- * Oneof group definitions for this message. Each group defines the field
- * numbers belonging to that group. When of these fields' value is set, all
- * other fields in the group are cleared. During deserialization, if multiple
- * fields are encountered for a group, only the last value seen will be kept.
- * @private {!Array<!Array<number>>}
- * @const
- */
-proto.org.dash.platform.dapi.v0.GetDocumentsResponse.oneofGroups_ = [[1,2]];
+ * @param {?proto.org.dash.platform.dapi.v0.ResponseMetadata|undefined} value
+ * @return {!proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse} returns this
+*/
+proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.prototype.setMetadata = function(value) {
+  return jspb.Message.setWrapperField(this, 3, value);
+};
+
 
 /**
- * @enum {number}
+ * Clears the message field making it undefined.
+ * @return {!proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse} returns this
  */
-proto.org.dash.platform.dapi.v0.GetDocumentsResponse.ResultCase = {
-  RESULT_NOT_SET: 0,
-  DOCUMENTS: 1,
-  PROOF: 2
+proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.prototype.clearMetadata = function() {
+  return this.setMetadata(undefined);
 };
 
+
 /**
- * @return {proto.org.dash.platform.dapi.v0.GetDocumentsResponse.ResultCase}
+ * Returns whether this field is set.
+ * @return {boolean}
  */
-proto.org.dash.platform.dapi.v0.GetDocumentsResponse.prototype.getResultCase = function() {
-  return /** @type {proto.org.dash.platform.dapi.v0.GetDocumentsResponse.ResultCase} */(jspb.Message.computeOneofCase(this, proto.org.dash.platform.dapi.v0.GetDocumentsResponse.oneofGroups_[0]));
+proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.prototype.hasMetadata = function() {
+  return jspb.Message.getField(this, 3) != null;
 };
 
 
 
+
+
 if (jspb.Message.GENERATE_TO_OBJECT) {
 /**
  * Creates an object representation of this proto.
@@ -11516,8 +15007,8 @@ if (jspb.Message.GENERATE_TO_OBJECT) {
  *     http://goto/soy-param-migration
  * @return {!Object}
  */
-proto.org.dash.platform.dapi.v0.GetDocumentsResponse.prototype.toObject = function(opt_includeInstance) {
-  return proto.org.dash.platform.dapi.v0.GetDocumentsResponse.toObject(opt_includeInstance, this);
+proto.org.dash.platform.dapi.v0.ConsensusParamsBlock.prototype.toObject = function(opt_includeInstance) {
+  return proto.org.dash.platform.dapi.v0.ConsensusParamsBlock.toObject(opt_includeInstance, this);
 };
 
 
@@ -11526,15 +15017,15 @@ proto.org.dash.platform.dapi.v0.GetDocumentsResponse.prototype.toObject = functi
  * @param {boolean|undefined} includeInstance Deprecated. Whether to include
  *     the JSPB instance for transitional soy proto support:
  *     http://goto/soy-param-migration
- * @param {!proto.org.dash.platform.dapi.v0.GetDocumentsResponse} msg The msg instance to transform.
+ * @param {!proto.org.dash.platform.dapi.v0.ConsensusParamsBlock} msg The msg instance to transform.
  * @return {!Object}
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.org.dash.platform.dapi.v0.GetDocumentsResponse.toObject = function(includeInstance, msg) {
+proto.org.dash.platform.dapi.v0.ConsensusParamsBlock.toObject = function(includeInstance, msg) {
   var f, obj = {
-    documents: (f = msg.getDocuments()) && proto.org.dash.platform.dapi.v0.GetDocumentsResponse.Documents.toObject(includeInstance, f),
-    proof: (f = msg.getProof()) && proto.org.dash.platform.dapi.v0.Proof.toObject(includeInstance, f),
-    metadata: (f = msg.getMetadata()) && proto.org.dash.platform.dapi.v0.ResponseMetadata.toObject(includeInstance, f)
+    maxBytes: jspb.Message.getFieldWithDefault(msg, 1, ""),
+    maxGas: jspb.Message.getFieldWithDefault(msg, 2, ""),
+    timeIotaMs: jspb.Message.getFieldWithDefault(msg, 3, "")
   };
 
   if (includeInstance) {
@@ -11548,23 +15039,23 @@ proto.org.dash.platform.dapi.v0.GetDocumentsResponse.toObject = function(include
 /**
  * Deserializes binary data (in protobuf wire format).
  * @param {jspb.ByteSource} bytes The bytes to deserialize.
- * @return {!proto.org.dash.platform.dapi.v0.GetDocumentsResponse}
+ * @return {!proto.org.dash.platform.dapi.v0.ConsensusParamsBlock}
  */
-proto.org.dash.platform.dapi.v0.GetDocumentsResponse.deserializeBinary = function(bytes) {
+proto.org.dash.platform.dapi.v0.ConsensusParamsBlock.deserializeBinary = function(bytes) {
   var reader = new jspb.BinaryReader(bytes);
-  var msg = new proto.org.dash.platform.dapi.v0.GetDocumentsResponse;
-  return proto.org.dash.platform.dapi.v0.GetDocumentsResponse.deserializeBinaryFromReader(msg, reader);
+  var msg = new proto.org.dash.platform.dapi.v0.ConsensusParamsBlock;
+  return proto.org.dash.platform.dapi.v0.ConsensusParamsBlock.deserializeBinaryFromReader(msg, reader);
 };
 
 
 /**
  * Deserializes binary data (in protobuf wire format) from the
  * given reader into the given message object.
- * @param {!proto.org.dash.platform.dapi.v0.GetDocumentsResponse} msg The message object to deserialize into.
+ * @param {!proto.org.dash.platform.dapi.v0.ConsensusParamsBlock} msg The message object to deserialize into.
  * @param {!jspb.BinaryReader} reader The BinaryReader to use.
- * @return {!proto.org.dash.platform.dapi.v0.GetDocumentsResponse}
+ * @return {!proto.org.dash.platform.dapi.v0.ConsensusParamsBlock}
  */
-proto.org.dash.platform.dapi.v0.GetDocumentsResponse.deserializeBinaryFromReader = function(msg, reader) {
+proto.org.dash.platform.dapi.v0.ConsensusParamsBlock.deserializeBinaryFromReader = function(msg, reader) {
   while (reader.nextField()) {
     if (reader.isEndGroup()) {
       break;
@@ -11572,19 +15063,16 @@ proto.org.dash.platform.dapi.v0.GetDocumentsResponse.deserializeBinaryFromReader
     var field = reader.getFieldNumber();
     switch (field) {
     case 1:
-      var value = new proto.org.dash.platform.dapi.v0.GetDocumentsResponse.Documents;
-      reader.readMessage(value,proto.org.dash.platform.dapi.v0.GetDocumentsResponse.Documents.deserializeBinaryFromReader);
-      msg.setDocuments(value);
+      var value = /** @type {string} */ (reader.readString());
+      msg.setMaxBytes(value);
       break;
     case 2:
-      var value = new proto.org.dash.platform.dapi.v0.Proof;
-      reader.readMessage(value,proto.org.dash.platform.dapi.v0.Proof.deserializeBinaryFromReader);
-      msg.setProof(value);
+      var value = /** @type {string} */ (reader.readString());
+      msg.setMaxGas(value);
       break;
     case 3:
-      var value = new proto.org.dash.platform.dapi.v0.ResponseMetadata;
-      reader.readMessage(value,proto.org.dash.platform.dapi.v0.ResponseMetadata.deserializeBinaryFromReader);
-      msg.setMetadata(value);
+      var value = /** @type {string} */ (reader.readString());
+      msg.setTimeIotaMs(value);
       break;
     default:
       reader.skipField();
@@ -11599,9 +15087,9 @@ proto.org.dash.platform.dapi.v0.GetDocumentsResponse.deserializeBinaryFromReader
  * Serializes the message to binary data (in protobuf wire format).
  * @return {!Uint8Array}
  */
-proto.org.dash.platform.dapi.v0.GetDocumentsResponse.prototype.serializeBinary = function() {
+proto.org.dash.platform.dapi.v0.ConsensusParamsBlock.prototype.serializeBinary = function() {
   var writer = new jspb.BinaryWriter();
-  proto.org.dash.platform.dapi.v0.GetDocumentsResponse.serializeBinaryToWriter(this, writer);
+  proto.org.dash.platform.dapi.v0.ConsensusParamsBlock.serializeBinaryToWriter(this, writer);
   return writer.getResultBuffer();
 };
 
@@ -11609,46 +15097,90 @@ proto.org.dash.platform.dapi.v0.GetDocumentsResponse.prototype.serializeBinary =
 /**
  * Serializes the given message to binary data (in protobuf wire
  * format), writing to the given BinaryWriter.
- * @param {!proto.org.dash.platform.dapi.v0.GetDocumentsResponse} message
+ * @param {!proto.org.dash.platform.dapi.v0.ConsensusParamsBlock} message
  * @param {!jspb.BinaryWriter} writer
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.org.dash.platform.dapi.v0.GetDocumentsResponse.serializeBinaryToWriter = function(message, writer) {
+proto.org.dash.platform.dapi.v0.ConsensusParamsBlock.serializeBinaryToWriter = function(message, writer) {
   var f = undefined;
-  f = message.getDocuments();
-  if (f != null) {
-    writer.writeMessage(
+  f = message.getMaxBytes();
+  if (f.length > 0) {
+    writer.writeString(
       1,
-      f,
-      proto.org.dash.platform.dapi.v0.GetDocumentsResponse.Documents.serializeBinaryToWriter
+      f
     );
   }
-  f = message.getProof();
-  if (f != null) {
-    writer.writeMessage(
+  f = message.getMaxGas();
+  if (f.length > 0) {
+    writer.writeString(
       2,
-      f,
-      proto.org.dash.platform.dapi.v0.Proof.serializeBinaryToWriter
+      f
     );
   }
-  f = message.getMetadata();
-  if (f != null) {
-    writer.writeMessage(
+  f = message.getTimeIotaMs();
+  if (f.length > 0) {
+    writer.writeString(
       3,
-      f,
-      proto.org.dash.platform.dapi.v0.ResponseMetadata.serializeBinaryToWriter
+      f
     );
   }
 };
 
 
-
 /**
- * List of repeated fields within this message type.
- * @private {!Array<number>}
- * @const
+ * optional string max_bytes = 1;
+ * @return {string}
+ */
+proto.org.dash.platform.dapi.v0.ConsensusParamsBlock.prototype.getMaxBytes = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 1, ""));
+};
+
+
+/**
+ * @param {string} value
+ * @return {!proto.org.dash.platform.dapi.v0.ConsensusParamsBlock} returns this
+ */
+proto.org.dash.platform.dapi.v0.ConsensusParamsBlock.prototype.setMaxBytes = function(value) {
+  return jspb.Message.setProto3StringField(this, 1, value);
+};
+
+
+/**
+ * optional string max_gas = 2;
+ * @return {string}
+ */
+proto.org.dash.platform.dapi.v0.ConsensusParamsBlock.prototype.getMaxGas = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 2, ""));
+};
+
+
+/**
+ * @param {string} value
+ * @return {!proto.org.dash.platform.dapi.v0.ConsensusParamsBlock} returns this
+ */
+proto.org.dash.platform.dapi.v0.ConsensusParamsBlock.prototype.setMaxGas = function(value) {
+  return jspb.Message.setProto3StringField(this, 2, value);
+};
+
+
+/**
+ * optional string time_iota_ms = 3;
+ * @return {string}
+ */
+proto.org.dash.platform.dapi.v0.ConsensusParamsBlock.prototype.getTimeIotaMs = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 3, ""));
+};
+
+
+/**
+ * @param {string} value
+ * @return {!proto.org.dash.platform.dapi.v0.ConsensusParamsBlock} returns this
  */
-proto.org.dash.platform.dapi.v0.GetDocumentsResponse.Documents.repeatedFields_ = [1];
+proto.org.dash.platform.dapi.v0.ConsensusParamsBlock.prototype.setTimeIotaMs = function(value) {
+  return jspb.Message.setProto3StringField(this, 3, value);
+};
+
+
 
 
 
@@ -11665,8 +15197,8 @@ if (jspb.Message.GENERATE_TO_OBJECT) {
  *     http://goto/soy-param-migration
  * @return {!Object}
  */
-proto.org.dash.platform.dapi.v0.GetDocumentsResponse.Documents.prototype.toObject = function(opt_includeInstance) {
-  return proto.org.dash.platform.dapi.v0.GetDocumentsResponse.Documents.toObject(opt_includeInstance, this);
+proto.org.dash.platform.dapi.v0.ConsensusParamsEvidence.prototype.toObject = function(opt_includeInstance) {
+  return proto.org.dash.platform.dapi.v0.ConsensusParamsEvidence.toObject(opt_includeInstance, this);
 };
 
 
@@ -11675,13 +15207,15 @@ proto.org.dash.platform.dapi.v0.GetDocumentsResponse.Documents.prototype.toObjec
  * @param {boolean|undefined} includeInstance Deprecated. Whether to include
  *     the JSPB instance for transitional soy proto support:
  *     http://goto/soy-param-migration
- * @param {!proto.org.dash.platform.dapi.v0.GetDocumentsResponse.Documents} msg The msg instance to transform.
+ * @param {!proto.org.dash.platform.dapi.v0.ConsensusParamsEvidence} msg The msg instance to transform.
  * @return {!Object}
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.org.dash.platform.dapi.v0.GetDocumentsResponse.Documents.toObject = function(includeInstance, msg) {
+proto.org.dash.platform.dapi.v0.ConsensusParamsEvidence.toObject = function(includeInstance, msg) {
   var f, obj = {
-    documentsList: msg.getDocumentsList_asB64()
+    maxAgeNumBlocks: jspb.Message.getFieldWithDefault(msg, 1, ""),
+    maxAgeDuration: jspb.Message.getFieldWithDefault(msg, 2, ""),
+    maxBytes: jspb.Message.getFieldWithDefault(msg, 3, "")
   };
 
   if (includeInstance) {
@@ -11695,23 +15229,23 @@ proto.org.dash.platform.dapi.v0.GetDocumentsResponse.Documents.toObject = functi
 /**
  * Deserializes binary data (in protobuf wire format).
  * @param {jspb.ByteSource} bytes The bytes to deserialize.
- * @return {!proto.org.dash.platform.dapi.v0.GetDocumentsResponse.Documents}
+ * @return {!proto.org.dash.platform.dapi.v0.ConsensusParamsEvidence}
  */
-proto.org.dash.platform.dapi.v0.GetDocumentsResponse.Documents.deserializeBinary = function(bytes) {
+proto.org.dash.platform.dapi.v0.ConsensusParamsEvidence.deserializeBinary = function(bytes) {
   var reader = new jspb.BinaryReader(bytes);
-  var msg = new proto.org.dash.platform.dapi.v0.GetDocumentsResponse.Documents;
-  return proto.org.dash.platform.dapi.v0.GetDocumentsResponse.Documents.deserializeBinaryFromReader(msg, reader);
+  var msg = new proto.org.dash.platform.dapi.v0.ConsensusParamsEvidence;
+  return proto.org.dash.platform.dapi.v0.ConsensusParamsEvidence.deserializeBinaryFromReader(msg, reader);
 };
 
 
 /**
  * Deserializes binary data (in protobuf wire format) from the
  * given reader into the given message object.
- * @param {!proto.org.dash.platform.dapi.v0.GetDocumentsResponse.Documents} msg The message object to deserialize into.
+ * @param {!proto.org.dash.platform.dapi.v0.ConsensusParamsEvidence} msg The message object to deserialize into.
  * @param {!jspb.BinaryReader} reader The BinaryReader to use.
- * @return {!proto.org.dash.platform.dapi.v0.GetDocumentsResponse.Documents}
+ * @return {!proto.org.dash.platform.dapi.v0.ConsensusParamsEvidence}
  */
-proto.org.dash.platform.dapi.v0.GetDocumentsResponse.Documents.deserializeBinaryFromReader = function(msg, reader) {
+proto.org.dash.platform.dapi.v0.ConsensusParamsEvidence.deserializeBinaryFromReader = function(msg, reader) {
   while (reader.nextField()) {
     if (reader.isEndGroup()) {
       break;
@@ -11719,8 +15253,16 @@ proto.org.dash.platform.dapi.v0.GetDocumentsResponse.Documents.deserializeBinary
     var field = reader.getFieldNumber();
     switch (field) {
     case 1:
-      var value = /** @type {!Uint8Array} */ (reader.readBytes());
-      msg.addDocuments(value);
+      var value = /** @type {string} */ (reader.readString());
+      msg.setMaxAgeNumBlocks(value);
+      break;
+    case 2:
+      var value = /** @type {string} */ (reader.readString());
+      msg.setMaxAgeDuration(value);
+      break;
+    case 3:
+      var value = /** @type {string} */ (reader.readString());
+      msg.setMaxBytes(value);
       break;
     default:
       reader.skipField();
@@ -11735,9 +15277,9 @@ proto.org.dash.platform.dapi.v0.GetDocumentsResponse.Documents.deserializeBinary
  * Serializes the message to binary data (in protobuf wire format).
  * @return {!Uint8Array}
  */
-proto.org.dash.platform.dapi.v0.GetDocumentsResponse.Documents.prototype.serializeBinary = function() {
+proto.org.dash.platform.dapi.v0.ConsensusParamsEvidence.prototype.serializeBinary = function() {
   var writer = new jspb.BinaryWriter();
-  proto.org.dash.platform.dapi.v0.GetDocumentsResponse.Documents.serializeBinaryToWriter(this, writer);
+  proto.org.dash.platform.dapi.v0.ConsensusParamsEvidence.serializeBinaryToWriter(this, writer);
   return writer.getResultBuffer();
 };
 
@@ -11745,202 +15287,91 @@ proto.org.dash.platform.dapi.v0.GetDocumentsResponse.Documents.prototype.seriali
 /**
  * Serializes the given message to binary data (in protobuf wire
  * format), writing to the given BinaryWriter.
- * @param {!proto.org.dash.platform.dapi.v0.GetDocumentsResponse.Documents} message
+ * @param {!proto.org.dash.platform.dapi.v0.ConsensusParamsEvidence} message
  * @param {!jspb.BinaryWriter} writer
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.org.dash.platform.dapi.v0.GetDocumentsResponse.Documents.serializeBinaryToWriter = function(message, writer) {
+proto.org.dash.platform.dapi.v0.ConsensusParamsEvidence.serializeBinaryToWriter = function(message, writer) {
   var f = undefined;
-  f = message.getDocumentsList_asU8();
+  f = message.getMaxAgeNumBlocks();
   if (f.length > 0) {
-    writer.writeRepeatedBytes(
+    writer.writeString(
       1,
       f
     );
   }
+  f = message.getMaxAgeDuration();
+  if (f.length > 0) {
+    writer.writeString(
+      2,
+      f
+    );
+  }
+  f = message.getMaxBytes();
+  if (f.length > 0) {
+    writer.writeString(
+      3,
+      f
+    );
+  }
 };
 
 
 /**
- * repeated bytes documents = 1;
- * @return {!Array<string>}
- */
-proto.org.dash.platform.dapi.v0.GetDocumentsResponse.Documents.prototype.getDocumentsList = function() {
-  return /** @type {!Array<string>} */ (jspb.Message.getRepeatedField(this, 1));
-};
-
-
-/**
- * repeated bytes documents = 1;
- * This is a type-conversion wrapper around `getDocumentsList()`
- * @return {!Array<string>}
- */
-proto.org.dash.platform.dapi.v0.GetDocumentsResponse.Documents.prototype.getDocumentsList_asB64 = function() {
-  return /** @type {!Array<string>} */ (jspb.Message.bytesListAsB64(
-      this.getDocumentsList()));
-};
-
-
-/**
- * repeated bytes documents = 1;
- * Note that Uint8Array is not supported on all browsers.
- * @see http://caniuse.com/Uint8Array
- * This is a type-conversion wrapper around `getDocumentsList()`
- * @return {!Array<!Uint8Array>}
- */
-proto.org.dash.platform.dapi.v0.GetDocumentsResponse.Documents.prototype.getDocumentsList_asU8 = function() {
-  return /** @type {!Array<!Uint8Array>} */ (jspb.Message.bytesListAsU8(
-      this.getDocumentsList()));
-};
-
-
-/**
- * @param {!(Array<!Uint8Array>|Array<string>)} value
- * @return {!proto.org.dash.platform.dapi.v0.GetDocumentsResponse.Documents} returns this
- */
-proto.org.dash.platform.dapi.v0.GetDocumentsResponse.Documents.prototype.setDocumentsList = function(value) {
-  return jspb.Message.setField(this, 1, value || []);
-};
-
-
-/**
- * @param {!(string|Uint8Array)} value
- * @param {number=} opt_index
- * @return {!proto.org.dash.platform.dapi.v0.GetDocumentsResponse.Documents} returns this
- */
-proto.org.dash.platform.dapi.v0.GetDocumentsResponse.Documents.prototype.addDocuments = function(value, opt_index) {
-  return jspb.Message.addToRepeatedField(this, 1, value, opt_index);
-};
-
-
-/**
- * Clears the list making it empty but non-null.
- * @return {!proto.org.dash.platform.dapi.v0.GetDocumentsResponse.Documents} returns this
- */
-proto.org.dash.platform.dapi.v0.GetDocumentsResponse.Documents.prototype.clearDocumentsList = function() {
-  return this.setDocumentsList([]);
-};
-
-
-/**
- * optional Documents documents = 1;
- * @return {?proto.org.dash.platform.dapi.v0.GetDocumentsResponse.Documents}
- */
-proto.org.dash.platform.dapi.v0.GetDocumentsResponse.prototype.getDocuments = function() {
-  return /** @type{?proto.org.dash.platform.dapi.v0.GetDocumentsResponse.Documents} */ (
-    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.GetDocumentsResponse.Documents, 1));
-};
-
-
-/**
- * @param {?proto.org.dash.platform.dapi.v0.GetDocumentsResponse.Documents|undefined} value
- * @return {!proto.org.dash.platform.dapi.v0.GetDocumentsResponse} returns this
-*/
-proto.org.dash.platform.dapi.v0.GetDocumentsResponse.prototype.setDocuments = function(value) {
-  return jspb.Message.setOneofWrapperField(this, 1, proto.org.dash.platform.dapi.v0.GetDocumentsResponse.oneofGroups_[0], value);
-};
-
-
-/**
- * Clears the message field making it undefined.
- * @return {!proto.org.dash.platform.dapi.v0.GetDocumentsResponse} returns this
- */
-proto.org.dash.platform.dapi.v0.GetDocumentsResponse.prototype.clearDocuments = function() {
-  return this.setDocuments(undefined);
-};
-
-
-/**
- * Returns whether this field is set.
- * @return {boolean}
- */
-proto.org.dash.platform.dapi.v0.GetDocumentsResponse.prototype.hasDocuments = function() {
-  return jspb.Message.getField(this, 1) != null;
-};
-
-
-/**
- * optional Proof proof = 2;
- * @return {?proto.org.dash.platform.dapi.v0.Proof}
+ * optional string max_age_num_blocks = 1;
+ * @return {string}
  */
-proto.org.dash.platform.dapi.v0.GetDocumentsResponse.prototype.getProof = function() {
-  return /** @type{?proto.org.dash.platform.dapi.v0.Proof} */ (
-    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.Proof, 2));
-};
-
-
-/**
- * @param {?proto.org.dash.platform.dapi.v0.Proof|undefined} value
- * @return {!proto.org.dash.platform.dapi.v0.GetDocumentsResponse} returns this
-*/
-proto.org.dash.platform.dapi.v0.GetDocumentsResponse.prototype.setProof = function(value) {
-  return jspb.Message.setOneofWrapperField(this, 2, proto.org.dash.platform.dapi.v0.GetDocumentsResponse.oneofGroups_[0], value);
+proto.org.dash.platform.dapi.v0.ConsensusParamsEvidence.prototype.getMaxAgeNumBlocks = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 1, ""));
 };
 
 
 /**
- * Clears the message field making it undefined.
- * @return {!proto.org.dash.platform.dapi.v0.GetDocumentsResponse} returns this
+ * @param {string} value
+ * @return {!proto.org.dash.platform.dapi.v0.ConsensusParamsEvidence} returns this
  */
-proto.org.dash.platform.dapi.v0.GetDocumentsResponse.prototype.clearProof = function() {
-  return this.setProof(undefined);
+proto.org.dash.platform.dapi.v0.ConsensusParamsEvidence.prototype.setMaxAgeNumBlocks = function(value) {
+  return jspb.Message.setProto3StringField(this, 1, value);
 };
 
 
 /**
- * Returns whether this field is set.
- * @return {boolean}
+ * optional string max_age_duration = 2;
+ * @return {string}
  */
-proto.org.dash.platform.dapi.v0.GetDocumentsResponse.prototype.hasProof = function() {
-  return jspb.Message.getField(this, 2) != null;
+proto.org.dash.platform.dapi.v0.ConsensusParamsEvidence.prototype.getMaxAgeDuration = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 2, ""));
 };
 
 
 /**
- * optional ResponseMetadata metadata = 3;
- * @return {?proto.org.dash.platform.dapi.v0.ResponseMetadata}
+ * @param {string} value
+ * @return {!proto.org.dash.platform.dapi.v0.ConsensusParamsEvidence} returns this
  */
-proto.org.dash.platform.dapi.v0.GetDocumentsResponse.prototype.getMetadata = function() {
-  return /** @type{?proto.org.dash.platform.dapi.v0.ResponseMetadata} */ (
-    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.ResponseMetadata, 3));
-};
-
-
-/**
- * @param {?proto.org.dash.platform.dapi.v0.ResponseMetadata|undefined} value
- * @return {!proto.org.dash.platform.dapi.v0.GetDocumentsResponse} returns this
-*/
-proto.org.dash.platform.dapi.v0.GetDocumentsResponse.prototype.setMetadata = function(value) {
-  return jspb.Message.setWrapperField(this, 3, value);
+proto.org.dash.platform.dapi.v0.ConsensusParamsEvidence.prototype.setMaxAgeDuration = function(value) {
+  return jspb.Message.setProto3StringField(this, 2, value);
 };
 
 
 /**
- * Clears the message field making it undefined.
- * @return {!proto.org.dash.platform.dapi.v0.GetDocumentsResponse} returns this
+ * optional string max_bytes = 3;
+ * @return {string}
  */
-proto.org.dash.platform.dapi.v0.GetDocumentsResponse.prototype.clearMetadata = function() {
-  return this.setMetadata(undefined);
+proto.org.dash.platform.dapi.v0.ConsensusParamsEvidence.prototype.getMaxBytes = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 3, ""));
 };
 
 
 /**
- * Returns whether this field is set.
- * @return {boolean}
+ * @param {string} value
+ * @return {!proto.org.dash.platform.dapi.v0.ConsensusParamsEvidence} returns this
  */
-proto.org.dash.platform.dapi.v0.GetDocumentsResponse.prototype.hasMetadata = function() {
-  return jspb.Message.getField(this, 3) != null;
+proto.org.dash.platform.dapi.v0.ConsensusParamsEvidence.prototype.setMaxBytes = function(value) {
+  return jspb.Message.setProto3StringField(this, 3, value);
 };
 
 
 
-/**
- * List of repeated fields within this message type.
- * @private {!Array<number>}
- * @const
- */
-proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesRequest.repeatedFields_ = [1];
-
 
 
 if (jspb.Message.GENERATE_TO_OBJECT) {
@@ -11956,8 +15387,8 @@ if (jspb.Message.GENERATE_TO_OBJECT) {
  *     http://goto/soy-param-migration
  * @return {!Object}
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesRequest.prototype.toObject = function(opt_includeInstance) {
-  return proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesRequest.toObject(opt_includeInstance, this);
+proto.org.dash.platform.dapi.v0.GetConsensusParamsRequest.prototype.toObject = function(opt_includeInstance) {
+  return proto.org.dash.platform.dapi.v0.GetConsensusParamsRequest.toObject(opt_includeInstance, this);
 };
 
 
@@ -11966,13 +15397,13 @@ proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesRequest.prototype.
  * @param {boolean|undefined} includeInstance Deprecated. Whether to include
  *     the JSPB instance for transitional soy proto support:
  *     http://goto/soy-param-migration
- * @param {!proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesRequest} msg The msg instance to transform.
+ * @param {!proto.org.dash.platform.dapi.v0.GetConsensusParamsRequest} msg The msg instance to transform.
  * @return {!Object}
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesRequest.toObject = function(includeInstance, msg) {
+proto.org.dash.platform.dapi.v0.GetConsensusParamsRequest.toObject = function(includeInstance, msg) {
   var f, obj = {
-    publicKeyHashesList: msg.getPublicKeyHashesList_asB64(),
+    height: jspb.Message.getFieldWithDefault(msg, 1, 0),
     prove: jspb.Message.getBooleanFieldWithDefault(msg, 2, false)
   };
 
@@ -11987,23 +15418,23 @@ proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesRequest.toObject =
 /**
  * Deserializes binary data (in protobuf wire format).
  * @param {jspb.ByteSource} bytes The bytes to deserialize.
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesRequest}
+ * @return {!proto.org.dash.platform.dapi.v0.GetConsensusParamsRequest}
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesRequest.deserializeBinary = function(bytes) {
+proto.org.dash.platform.dapi.v0.GetConsensusParamsRequest.deserializeBinary = function(bytes) {
   var reader = new jspb.BinaryReader(bytes);
-  var msg = new proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesRequest;
-  return proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesRequest.deserializeBinaryFromReader(msg, reader);
+  var msg = new proto.org.dash.platform.dapi.v0.GetConsensusParamsRequest;
+  return proto.org.dash.platform.dapi.v0.GetConsensusParamsRequest.deserializeBinaryFromReader(msg, reader);
 };
 
 
 /**
  * Deserializes binary data (in protobuf wire format) from the
  * given reader into the given message object.
- * @param {!proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesRequest} msg The message object to deserialize into.
+ * @param {!proto.org.dash.platform.dapi.v0.GetConsensusParamsRequest} msg The message object to deserialize into.
  * @param {!jspb.BinaryReader} reader The BinaryReader to use.
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesRequest}
+ * @return {!proto.org.dash.platform.dapi.v0.GetConsensusParamsRequest}
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesRequest.deserializeBinaryFromReader = function(msg, reader) {
+proto.org.dash.platform.dapi.v0.GetConsensusParamsRequest.deserializeBinaryFromReader = function(msg, reader) {
   while (reader.nextField()) {
     if (reader.isEndGroup()) {
       break;
@@ -12011,8 +15442,8 @@ proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesRequest.deserializ
     var field = reader.getFieldNumber();
     switch (field) {
     case 1:
-      var value = /** @type {!Uint8Array} */ (reader.readBytes());
-      msg.addPublicKeyHashes(value);
+      var value = /** @type {number} */ (reader.readInt64());
+      msg.setHeight(value);
       break;
     case 2:
       var value = /** @type {boolean} */ (reader.readBool());
@@ -12031,9 +15462,9 @@ proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesRequest.deserializ
  * Serializes the message to binary data (in protobuf wire format).
  * @return {!Uint8Array}
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesRequest.prototype.serializeBinary = function() {
+proto.org.dash.platform.dapi.v0.GetConsensusParamsRequest.prototype.serializeBinary = function() {
   var writer = new jspb.BinaryWriter();
-  proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesRequest.serializeBinaryToWriter(this, writer);
+  proto.org.dash.platform.dapi.v0.GetConsensusParamsRequest.serializeBinaryToWriter(this, writer);
   return writer.getResultBuffer();
 };
 
@@ -12041,15 +15472,15 @@ proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesRequest.prototype.
 /**
  * Serializes the given message to binary data (in protobuf wire
  * format), writing to the given BinaryWriter.
- * @param {!proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesRequest} message
+ * @param {!proto.org.dash.platform.dapi.v0.GetConsensusParamsRequest} message
  * @param {!jspb.BinaryWriter} writer
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesRequest.serializeBinaryToWriter = function(message, writer) {
+proto.org.dash.platform.dapi.v0.GetConsensusParamsRequest.serializeBinaryToWriter = function(message, writer) {
   var f = undefined;
-  f = message.getPublicKeyHashesList_asU8();
-  if (f.length > 0) {
-    writer.writeRepeatedBytes(
+  f = message.getHeight();
+  if (f !== 0) {
+    writer.writeInt64(
       1,
       f
     );
@@ -12065,63 +15496,20 @@ proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesRequest.serializeB
 
 
 /**
- * repeated bytes public_key_hashes = 1;
- * @return {!Array<string>}
- */
-proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesRequest.prototype.getPublicKeyHashesList = function() {
-  return /** @type {!Array<string>} */ (jspb.Message.getRepeatedField(this, 1));
-};
-
-
-/**
- * repeated bytes public_key_hashes = 1;
- * This is a type-conversion wrapper around `getPublicKeyHashesList()`
- * @return {!Array<string>}
- */
-proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesRequest.prototype.getPublicKeyHashesList_asB64 = function() {
-  return /** @type {!Array<string>} */ (jspb.Message.bytesListAsB64(
-      this.getPublicKeyHashesList()));
-};
-
-
-/**
- * repeated bytes public_key_hashes = 1;
- * Note that Uint8Array is not supported on all browsers.
- * @see http://caniuse.com/Uint8Array
- * This is a type-conversion wrapper around `getPublicKeyHashesList()`
- * @return {!Array<!Uint8Array>}
- */
-proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesRequest.prototype.getPublicKeyHashesList_asU8 = function() {
-  return /** @type {!Array<!Uint8Array>} */ (jspb.Message.bytesListAsU8(
-      this.getPublicKeyHashesList()));
-};
-
-
-/**
- * @param {!(Array<!Uint8Array>|Array<string>)} value
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesRequest} returns this
- */
-proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesRequest.prototype.setPublicKeyHashesList = function(value) {
-  return jspb.Message.setField(this, 1, value || []);
-};
-
-
-/**
- * @param {!(string|Uint8Array)} value
- * @param {number=} opt_index
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesRequest} returns this
+ * optional int64 height = 1;
+ * @return {number}
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesRequest.prototype.addPublicKeyHashes = function(value, opt_index) {
-  return jspb.Message.addToRepeatedField(this, 1, value, opt_index);
+proto.org.dash.platform.dapi.v0.GetConsensusParamsRequest.prototype.getHeight = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 1, 0));
 };
 
 
 /**
- * Clears the list making it empty but non-null.
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesRequest} returns this
+ * @param {number} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetConsensusParamsRequest} returns this
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesRequest.prototype.clearPublicKeyHashesList = function() {
-  return this.setPublicKeyHashesList([]);
+proto.org.dash.platform.dapi.v0.GetConsensusParamsRequest.prototype.setHeight = function(value) {
+  return jspb.Message.setProto3IntField(this, 1, value);
 };
 
 
@@ -12129,47 +15517,21 @@ proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesRequest.prototype.
  * optional bool prove = 2;
  * @return {boolean}
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesRequest.prototype.getProve = function() {
+proto.org.dash.platform.dapi.v0.GetConsensusParamsRequest.prototype.getProve = function() {
   return /** @type {boolean} */ (jspb.Message.getBooleanFieldWithDefault(this, 2, false));
 };
 
 
 /**
  * @param {boolean} value
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesRequest} returns this
+ * @return {!proto.org.dash.platform.dapi.v0.GetConsensusParamsRequest} returns this
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesRequest.prototype.setProve = function(value) {
+proto.org.dash.platform.dapi.v0.GetConsensusParamsRequest.prototype.setProve = function(value) {
   return jspb.Message.setProto3BooleanField(this, 2, value);
 };
 
 
 
-/**
- * Oneof group definitions for this message. Each group defines the field
- * numbers belonging to that group. When of these fields' value is set, all
- * other fields in the group are cleared. During deserialization, if multiple
- * fields are encountered for a group, only the last value seen will be kept.
- * @private {!Array<!Array<number>>}
- * @const
- */
-proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.oneofGroups_ = [[1,2]];
-
-/**
- * @enum {number}
- */
-proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.ResultCase = {
-  RESULT_NOT_SET: 0,
-  IDENTITIES: 1,
-  PROOF: 2
-};
-
-/**
- * @return {proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.ResultCase}
- */
-proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.prototype.getResultCase = function() {
-  return /** @type {proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.ResultCase} */(jspb.Message.computeOneofCase(this, proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.oneofGroups_[0]));
-};
-
 
 
 if (jspb.Message.GENERATE_TO_OBJECT) {
@@ -12185,8 +15547,8 @@ if (jspb.Message.GENERATE_TO_OBJECT) {
  *     http://goto/soy-param-migration
  * @return {!Object}
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.prototype.toObject = function(opt_includeInstance) {
-  return proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.toObject(opt_includeInstance, this);
+proto.org.dash.platform.dapi.v0.GetConsensusParamsResponse.prototype.toObject = function(opt_includeInstance) {
+  return proto.org.dash.platform.dapi.v0.GetConsensusParamsResponse.toObject(opt_includeInstance, this);
 };
 
 
@@ -12195,15 +15557,14 @@ proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.prototype
  * @param {boolean|undefined} includeInstance Deprecated. Whether to include
  *     the JSPB instance for transitional soy proto support:
  *     http://goto/soy-param-migration
- * @param {!proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse} msg The msg instance to transform.
+ * @param {!proto.org.dash.platform.dapi.v0.GetConsensusParamsResponse} msg The msg instance to transform.
  * @return {!Object}
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.toObject = function(includeInstance, msg) {
+proto.org.dash.platform.dapi.v0.GetConsensusParamsResponse.toObject = function(includeInstance, msg) {
   var f, obj = {
-    identities: (f = msg.getIdentities()) && proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.Identities.toObject(includeInstance, f),
-    proof: (f = msg.getProof()) && proto.org.dash.platform.dapi.v0.Proof.toObject(includeInstance, f),
-    metadata: (f = msg.getMetadata()) && proto.org.dash.platform.dapi.v0.ResponseMetadata.toObject(includeInstance, f)
+    block: (f = msg.getBlock()) && proto.org.dash.platform.dapi.v0.ConsensusParamsBlock.toObject(includeInstance, f),
+    evidence: (f = msg.getEvidence()) && proto.org.dash.platform.dapi.v0.ConsensusParamsEvidence.toObject(includeInstance, f)
   };
 
   if (includeInstance) {
@@ -12217,23 +15578,23 @@ proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.toObject
 /**
  * Deserializes binary data (in protobuf wire format).
  * @param {jspb.ByteSource} bytes The bytes to deserialize.
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse}
+ * @return {!proto.org.dash.platform.dapi.v0.GetConsensusParamsResponse}
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.deserializeBinary = function(bytes) {
+proto.org.dash.platform.dapi.v0.GetConsensusParamsResponse.deserializeBinary = function(bytes) {
   var reader = new jspb.BinaryReader(bytes);
-  var msg = new proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse;
-  return proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.deserializeBinaryFromReader(msg, reader);
+  var msg = new proto.org.dash.platform.dapi.v0.GetConsensusParamsResponse;
+  return proto.org.dash.platform.dapi.v0.GetConsensusParamsResponse.deserializeBinaryFromReader(msg, reader);
 };
 
 
 /**
  * Deserializes binary data (in protobuf wire format) from the
  * given reader into the given message object.
- * @param {!proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse} msg The message object to deserialize into.
+ * @param {!proto.org.dash.platform.dapi.v0.GetConsensusParamsResponse} msg The message object to deserialize into.
  * @param {!jspb.BinaryReader} reader The BinaryReader to use.
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse}
+ * @return {!proto.org.dash.platform.dapi.v0.GetConsensusParamsResponse}
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.deserializeBinaryFromReader = function(msg, reader) {
+proto.org.dash.platform.dapi.v0.GetConsensusParamsResponse.deserializeBinaryFromReader = function(msg, reader) {
   while (reader.nextField()) {
     if (reader.isEndGroup()) {
       break;
@@ -12241,19 +15602,14 @@ proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.deseriali
     var field = reader.getFieldNumber();
     switch (field) {
     case 1:
-      var value = new proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.Identities;
-      reader.readMessage(value,proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.Identities.deserializeBinaryFromReader);
-      msg.setIdentities(value);
+      var value = new proto.org.dash.platform.dapi.v0.ConsensusParamsBlock;
+      reader.readMessage(value,proto.org.dash.platform.dapi.v0.ConsensusParamsBlock.deserializeBinaryFromReader);
+      msg.setBlock(value);
       break;
     case 2:
-      var value = new proto.org.dash.platform.dapi.v0.Proof;
-      reader.readMessage(value,proto.org.dash.platform.dapi.v0.Proof.deserializeBinaryFromReader);
-      msg.setProof(value);
-      break;
-    case 3:
-      var value = new proto.org.dash.platform.dapi.v0.ResponseMetadata;
-      reader.readMessage(value,proto.org.dash.platform.dapi.v0.ResponseMetadata.deserializeBinaryFromReader);
-      msg.setMetadata(value);
+      var value = new proto.org.dash.platform.dapi.v0.ConsensusParamsEvidence;
+      reader.readMessage(value,proto.org.dash.platform.dapi.v0.ConsensusParamsEvidence.deserializeBinaryFromReader);
+      msg.setEvidence(value);
       break;
     default:
       reader.skipField();
@@ -12268,9 +15624,9 @@ proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.deseriali
  * Serializes the message to binary data (in protobuf wire format).
  * @return {!Uint8Array}
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.prototype.serializeBinary = function() {
+proto.org.dash.platform.dapi.v0.GetConsensusParamsResponse.prototype.serializeBinary = function() {
   var writer = new jspb.BinaryWriter();
-  proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.serializeBinaryToWriter(this, writer);
+  proto.org.dash.platform.dapi.v0.GetConsensusParamsResponse.serializeBinaryToWriter(this, writer);
   return writer.getResultBuffer();
 };
 
@@ -12278,46 +15634,105 @@ proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.prototype
 /**
  * Serializes the given message to binary data (in protobuf wire
  * format), writing to the given BinaryWriter.
- * @param {!proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse} message
+ * @param {!proto.org.dash.platform.dapi.v0.GetConsensusParamsResponse} message
  * @param {!jspb.BinaryWriter} writer
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.serializeBinaryToWriter = function(message, writer) {
+proto.org.dash.platform.dapi.v0.GetConsensusParamsResponse.serializeBinaryToWriter = function(message, writer) {
   var f = undefined;
-  f = message.getIdentities();
+  f = message.getBlock();
   if (f != null) {
     writer.writeMessage(
       1,
       f,
-      proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.Identities.serializeBinaryToWriter
+      proto.org.dash.platform.dapi.v0.ConsensusParamsBlock.serializeBinaryToWriter
     );
   }
-  f = message.getProof();
+  f = message.getEvidence();
   if (f != null) {
     writer.writeMessage(
       2,
       f,
-      proto.org.dash.platform.dapi.v0.Proof.serializeBinaryToWriter
-    );
-  }
-  f = message.getMetadata();
-  if (f != null) {
-    writer.writeMessage(
-      3,
-      f,
-      proto.org.dash.platform.dapi.v0.ResponseMetadata.serializeBinaryToWriter
+      proto.org.dash.platform.dapi.v0.ConsensusParamsEvidence.serializeBinaryToWriter
     );
   }
 };
 
 
+/**
+ * optional ConsensusParamsBlock block = 1;
+ * @return {?proto.org.dash.platform.dapi.v0.ConsensusParamsBlock}
+ */
+proto.org.dash.platform.dapi.v0.GetConsensusParamsResponse.prototype.getBlock = function() {
+  return /** @type{?proto.org.dash.platform.dapi.v0.ConsensusParamsBlock} */ (
+    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.ConsensusParamsBlock, 1));
+};
+
+
+/**
+ * @param {?proto.org.dash.platform.dapi.v0.ConsensusParamsBlock|undefined} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetConsensusParamsResponse} returns this
+*/
+proto.org.dash.platform.dapi.v0.GetConsensusParamsResponse.prototype.setBlock = function(value) {
+  return jspb.Message.setWrapperField(this, 1, value);
+};
+
+
+/**
+ * Clears the message field making it undefined.
+ * @return {!proto.org.dash.platform.dapi.v0.GetConsensusParamsResponse} returns this
+ */
+proto.org.dash.platform.dapi.v0.GetConsensusParamsResponse.prototype.clearBlock = function() {
+  return this.setBlock(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.org.dash.platform.dapi.v0.GetConsensusParamsResponse.prototype.hasBlock = function() {
+  return jspb.Message.getField(this, 1) != null;
+};
+
+
+/**
+ * optional ConsensusParamsEvidence evidence = 2;
+ * @return {?proto.org.dash.platform.dapi.v0.ConsensusParamsEvidence}
+ */
+proto.org.dash.platform.dapi.v0.GetConsensusParamsResponse.prototype.getEvidence = function() {
+  return /** @type{?proto.org.dash.platform.dapi.v0.ConsensusParamsEvidence} */ (
+    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.ConsensusParamsEvidence, 2));
+};
+
+
+/**
+ * @param {?proto.org.dash.platform.dapi.v0.ConsensusParamsEvidence|undefined} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetConsensusParamsResponse} returns this
+*/
+proto.org.dash.platform.dapi.v0.GetConsensusParamsResponse.prototype.setEvidence = function(value) {
+  return jspb.Message.setWrapperField(this, 2, value);
+};
+
+
+/**
+ * Clears the message field making it undefined.
+ * @return {!proto.org.dash.platform.dapi.v0.GetConsensusParamsResponse} returns this
+ */
+proto.org.dash.platform.dapi.v0.GetConsensusParamsResponse.prototype.clearEvidence = function() {
+  return this.setEvidence(undefined);
+};
+
 
 /**
- * List of repeated fields within this message type.
- * @private {!Array<number>}
- * @const
+ * Returns whether this field is set.
+ * @return {boolean}
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.Identities.repeatedFields_ = [1];
+proto.org.dash.platform.dapi.v0.GetConsensusParamsResponse.prototype.hasEvidence = function() {
+  return jspb.Message.getField(this, 2) != null;
+};
+
+
 
 
 
@@ -12334,8 +15749,8 @@ if (jspb.Message.GENERATE_TO_OBJECT) {
  *     http://goto/soy-param-migration
  * @return {!Object}
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.Identities.prototype.toObject = function(opt_includeInstance) {
-  return proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.Identities.toObject(opt_includeInstance, this);
+proto.org.dash.platform.dapi.v0.GetBlockAttestationRequest.prototype.toObject = function(opt_includeInstance) {
+  return proto.org.dash.platform.dapi.v0.GetBlockAttestationRequest.toObject(opt_includeInstance, this);
 };
 
 
@@ -12344,13 +15759,13 @@ proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.Identitie
  * @param {boolean|undefined} includeInstance Deprecated. Whether to include
  *     the JSPB instance for transitional soy proto support:
  *     http://goto/soy-param-migration
- * @param {!proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.Identities} msg The msg instance to transform.
+ * @param {!proto.org.dash.platform.dapi.v0.GetBlockAttestationRequest} msg The msg instance to transform.
  * @return {!Object}
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.Identities.toObject = function(includeInstance, msg) {
+proto.org.dash.platform.dapi.v0.GetBlockAttestationRequest.toObject = function(includeInstance, msg) {
   var f, obj = {
-    identitiesList: msg.getIdentitiesList_asB64()
+    height: jspb.Message.getFieldWithDefault(msg, 1, 0)
   };
 
   if (includeInstance) {
@@ -12364,23 +15779,23 @@ proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.Identitie
 /**
  * Deserializes binary data (in protobuf wire format).
  * @param {jspb.ByteSource} bytes The bytes to deserialize.
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.Identities}
+ * @return {!proto.org.dash.platform.dapi.v0.GetBlockAttestationRequest}
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.Identities.deserializeBinary = function(bytes) {
+proto.org.dash.platform.dapi.v0.GetBlockAttestationRequest.deserializeBinary = function(bytes) {
   var reader = new jspb.BinaryReader(bytes);
-  var msg = new proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.Identities;
-  return proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.Identities.deserializeBinaryFromReader(msg, reader);
+  var msg = new proto.org.dash.platform.dapi.v0.GetBlockAttestationRequest;
+  return proto.org.dash.platform.dapi.v0.GetBlockAttestationRequest.deserializeBinaryFromReader(msg, reader);
 };
 
 
 /**
  * Deserializes binary data (in protobuf wire format) from the
  * given reader into the given message object.
- * @param {!proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.Identities} msg The message object to deserialize into.
+ * @param {!proto.org.dash.platform.dapi.v0.GetBlockAttestationRequest} msg The message object to deserialize into.
  * @param {!jspb.BinaryReader} reader The BinaryReader to use.
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.Identities}
+ * @return {!proto.org.dash.platform.dapi.v0.GetBlockAttestationRequest}
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.Identities.deserializeBinaryFromReader = function(msg, reader) {
+proto.org.dash.platform.dapi.v0.GetBlockAttestationRequest.deserializeBinaryFromReader = function(msg, reader) {
   while (reader.nextField()) {
     if (reader.isEndGroup()) {
       break;
@@ -12388,8 +15803,8 @@ proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.Identitie
     var field = reader.getFieldNumber();
     switch (field) {
     case 1:
-      var value = /** @type {!Uint8Array} */ (reader.readBytes());
-      msg.addIdentities(value);
+      var value = /** @type {number} */ (reader.readUint64());
+      msg.setHeight(value);
       break;
     default:
       reader.skipField();
@@ -12404,9 +15819,9 @@ proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.Identitie
  * Serializes the message to binary data (in protobuf wire format).
  * @return {!Uint8Array}
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.Identities.prototype.serializeBinary = function() {
+proto.org.dash.platform.dapi.v0.GetBlockAttestationRequest.prototype.serializeBinary = function() {
   var writer = new jspb.BinaryWriter();
-  proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.Identities.serializeBinaryToWriter(this, writer);
+  proto.org.dash.platform.dapi.v0.GetBlockAttestationRequest.serializeBinaryToWriter(this, writer);
   return writer.getResultBuffer();
 };
 
@@ -12414,15 +15829,15 @@ proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.Identitie
 /**
  * Serializes the given message to binary data (in protobuf wire
  * format), writing to the given BinaryWriter.
- * @param {!proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.Identities} message
+ * @param {!proto.org.dash.platform.dapi.v0.GetBlockAttestationRequest} message
  * @param {!jspb.BinaryWriter} writer
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.Identities.serializeBinaryToWriter = function(message, writer) {
+proto.org.dash.platform.dapi.v0.GetBlockAttestationRequest.serializeBinaryToWriter = function(message, writer) {
   var f = undefined;
-  f = message.getIdentitiesList_asU8();
-  if (f.length > 0) {
-    writer.writeRepeatedBytes(
+  f = message.getHeight();
+  if (f !== 0) {
+    writer.writeUint64(
       1,
       f
     );
@@ -12431,174 +15846,148 @@ proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.Identitie
 
 
 /**
- * repeated bytes identities = 1;
- * @return {!Array<string>}
- */
-proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.Identities.prototype.getIdentitiesList = function() {
-  return /** @type {!Array<string>} */ (jspb.Message.getRepeatedField(this, 1));
-};
-
-
-/**
- * repeated bytes identities = 1;
- * This is a type-conversion wrapper around `getIdentitiesList()`
- * @return {!Array<string>}
- */
-proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.Identities.prototype.getIdentitiesList_asB64 = function() {
-  return /** @type {!Array<string>} */ (jspb.Message.bytesListAsB64(
-      this.getIdentitiesList()));
-};
-
-
-/**
- * repeated bytes identities = 1;
- * Note that Uint8Array is not supported on all browsers.
- * @see http://caniuse.com/Uint8Array
- * This is a type-conversion wrapper around `getIdentitiesList()`
- * @return {!Array<!Uint8Array>}
- */
-proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.Identities.prototype.getIdentitiesList_asU8 = function() {
-  return /** @type {!Array<!Uint8Array>} */ (jspb.Message.bytesListAsU8(
-      this.getIdentitiesList()));
-};
-
-
-/**
- * @param {!(Array<!Uint8Array>|Array<string>)} value
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.Identities} returns this
- */
-proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.Identities.prototype.setIdentitiesList = function(value) {
-  return jspb.Message.setField(this, 1, value || []);
-};
-
-
-/**
- * @param {!(string|Uint8Array)} value
- * @param {number=} opt_index
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.Identities} returns this
- */
-proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.Identities.prototype.addIdentities = function(value, opt_index) {
-  return jspb.Message.addToRepeatedField(this, 1, value, opt_index);
-};
-
-
-/**
- * Clears the list making it empty but non-null.
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.Identities} returns this
+ * optional uint64 height = 1;
+ * @return {number}
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.Identities.prototype.clearIdentitiesList = function() {
-  return this.setIdentitiesList([]);
+proto.org.dash.platform.dapi.v0.GetBlockAttestationRequest.prototype.getHeight = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 1, 0));
 };
 
 
 /**
- * optional Identities identities = 1;
- * @return {?proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.Identities}
+ * @param {number} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetBlockAttestationRequest} returns this
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.prototype.getIdentities = function() {
-  return /** @type{?proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.Identities} */ (
-    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.Identities, 1));
+proto.org.dash.platform.dapi.v0.GetBlockAttestationRequest.prototype.setHeight = function(value) {
+  return jspb.Message.setProto3IntField(this, 1, value);
 };
 
 
-/**
- * @param {?proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.Identities|undefined} value
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse} returns this
-*/
-proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.prototype.setIdentities = function(value) {
-  return jspb.Message.setOneofWrapperField(this, 1, proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.oneofGroups_[0], value);
-};
-
 
-/**
- * Clears the message field making it undefined.
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse} returns this
- */
-proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.prototype.clearIdentities = function() {
-  return this.setIdentities(undefined);
-};
 
 
+if (jspb.Message.GENERATE_TO_OBJECT) {
 /**
- * Returns whether this field is set.
- * @return {boolean}
+ * Creates an object representation of this proto.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * Optional fields that are not set will be set to undefined.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * For the list of reserved names please see:
+ *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
+ * @param {boolean=} opt_includeInstance Deprecated. whether to include the
+ *     JSPB instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @return {!Object}
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.prototype.hasIdentities = function() {
-  return jspb.Message.getField(this, 1) != null;
+proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse.prototype.toObject = function(opt_includeInstance) {
+  return proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse.toObject(opt_includeInstance, this);
 };
 
 
 /**
- * optional Proof proof = 2;
- * @return {?proto.org.dash.platform.dapi.v0.Proof}
+ * Static version of the {@see toObject} method.
+ * @param {boolean|undefined} includeInstance Deprecated. Whether to include
+ *     the JSPB instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @param {!proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse} msg The msg instance to transform.
+ * @return {!Object}
+ * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.prototype.getProof = function() {
-  return /** @type{?proto.org.dash.platform.dapi.v0.Proof} */ (
-    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.Proof, 2));
-};
-
-
-/**
- * @param {?proto.org.dash.platform.dapi.v0.Proof|undefined} value
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse} returns this
-*/
-proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.prototype.setProof = function(value) {
-  return jspb.Message.setOneofWrapperField(this, 2, proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.oneofGroups_[0], value);
-};
-
+proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse.toObject = function(includeInstance, msg) {
+  var f, obj = {
+    blockAttestation: (f = msg.getBlockAttestation()) && proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse.BlockAttestation.toObject(includeInstance, f),
+    metadata: (f = msg.getMetadata()) && proto.org.dash.platform.dapi.v0.ResponseMetadata.toObject(includeInstance, f)
+  };
 
-/**
- * Clears the message field making it undefined.
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse} returns this
- */
-proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.prototype.clearProof = function() {
-  return this.setProof(undefined);
+  if (includeInstance) {
+    obj.$jspbMessageInstance = msg;
+  }
+  return obj;
 };
+}
 
 
 /**
- * Returns whether this field is set.
- * @return {boolean}
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse}
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.prototype.hasProof = function() {
-  return jspb.Message.getField(this, 2) != null;
+proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse.deserializeBinary = function(bytes) {
+  var reader = new jspb.BinaryReader(bytes);
+  var msg = new proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse;
+  return proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse.deserializeBinaryFromReader(msg, reader);
 };
 
 
 /**
- * optional ResponseMetadata metadata = 3;
- * @return {?proto.org.dash.platform.dapi.v0.ResponseMetadata}
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse}
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.prototype.getMetadata = function() {
-  return /** @type{?proto.org.dash.platform.dapi.v0.ResponseMetadata} */ (
-    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.ResponseMetadata, 3));
-};
-
-
-/**
- * @param {?proto.org.dash.platform.dapi.v0.ResponseMetadata|undefined} value
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse} returns this
-*/
-proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.prototype.setMetadata = function(value) {
-  return jspb.Message.setWrapperField(this, 3, value);
+proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse.deserializeBinaryFromReader = function(msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+    case 1:
+      var value = new proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse.BlockAttestation;
+      reader.readMessage(value,proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse.BlockAttestation.deserializeBinaryFromReader);
+      msg.setBlockAttestation(value);
+      break;
+    case 2:
+      var value = new proto.org.dash.platform.dapi.v0.ResponseMetadata;
+      reader.readMessage(value,proto.org.dash.platform.dapi.v0.ResponseMetadata.deserializeBinaryFromReader);
+      msg.setMetadata(value);
+      break;
+    default:
+      reader.skipField();
+      break;
+    }
+  }
+  return msg;
 };
 
 
 /**
- * Clears the message field making it undefined.
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse} returns this
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.prototype.clearMetadata = function() {
-  return this.setMetadata(undefined);
+proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
 };
 
 
 /**
- * Returns whether this field is set.
- * @return {boolean}
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.prototype.hasMetadata = function() {
-  return jspb.Message.getField(this, 3) != null;
+proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+  f = message.getBlockAttestation();
+  if (f != null) {
+    writer.writeMessage(
+      1,
+      f,
+      proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse.BlockAttestation.serializeBinaryToWriter
+    );
+  }
+  f = message.getMetadata();
+  if (f != null) {
+    writer.writeMessage(
+      2,
+      f,
+      proto.org.dash.platform.dapi.v0.ResponseMetadata.serializeBinaryToWriter
+    );
+  }
 };
 
 
@@ -12618,8 +16007,8 @@ if (jspb.Message.GENERATE_TO_OBJECT) {
  *     http://goto/soy-param-migration
  * @return {!Object}
  */
-proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesRequest.prototype.toObject = function(opt_includeInstance) {
-  return proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesRequest.toObject(opt_includeInstance, this);
+proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse.BlockAttestation.prototype.toObject = function(opt_includeInstance) {
+  return proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse.BlockAttestation.toObject(opt_includeInstance, this);
 };
 
 
@@ -12628,14 +16017,20 @@ proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesRequest.prototype.to
  * @param {boolean|undefined} includeInstance Deprecated. Whether to include
  *     the JSPB instance for transitional soy proto support:
  *     http://goto/soy-param-migration
- * @param {!proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesRequest} msg The msg instance to transform.
+ * @param {!proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse.BlockAttestation} msg The msg instance to transform.
  * @return {!Object}
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesRequest.toObject = function(includeInstance, msg) {
+proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse.BlockAttestation.toObject = function(includeInstance, msg) {
   var f, obj = {
-    publicKeyHash: msg.getPublicKeyHash_asB64(),
-    prove: jspb.Message.getBooleanFieldWithDefault(msg, 2, false)
+    height: jspb.Message.getFieldWithDefault(msg, 1, 0),
+    appHash: msg.getAppHash_asB64(),
+    epoch: jspb.Message.getFieldWithDefault(msg, 3, 0),
+    protocolVersion: jspb.Message.getFieldWithDefault(msg, 4, 0),
+    quorumHash: msg.getQuorumHash_asB64(),
+    quorumType: jspb.Message.getFieldWithDefault(msg, 6, 0),
+    round: jspb.Message.getFieldWithDefault(msg, 7, 0),
+    signature: msg.getSignature_asB64()
   };
 
   if (includeInstance) {
@@ -12649,23 +16044,23 @@ proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesRequest.toObject = f
 /**
  * Deserializes binary data (in protobuf wire format).
  * @param {jspb.ByteSource} bytes The bytes to deserialize.
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesRequest}
+ * @return {!proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse.BlockAttestation}
  */
-proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesRequest.deserializeBinary = function(bytes) {
+proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse.BlockAttestation.deserializeBinary = function(bytes) {
   var reader = new jspb.BinaryReader(bytes);
-  var msg = new proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesRequest;
-  return proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesRequest.deserializeBinaryFromReader(msg, reader);
+  var msg = new proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse.BlockAttestation;
+  return proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse.BlockAttestation.deserializeBinaryFromReader(msg, reader);
 };
 
 
 /**
  * Deserializes binary data (in protobuf wire format) from the
  * given reader into the given message object.
- * @param {!proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesRequest} msg The message object to deserialize into.
+ * @param {!proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse.BlockAttestation} msg The message object to deserialize into.
  * @param {!jspb.BinaryReader} reader The BinaryReader to use.
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesRequest}
+ * @return {!proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse.BlockAttestation}
  */
-proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesRequest.deserializeBinaryFromReader = function(msg, reader) {
+proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse.BlockAttestation.deserializeBinaryFromReader = function(msg, reader) {
   while (reader.nextField()) {
     if (reader.isEndGroup()) {
       break;
@@ -12673,12 +16068,36 @@ proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesRequest.deserializeB
     var field = reader.getFieldNumber();
     switch (field) {
     case 1:
-      var value = /** @type {!Uint8Array} */ (reader.readBytes());
-      msg.setPublicKeyHash(value);
+      var value = /** @type {number} */ (reader.readUint64());
+      msg.setHeight(value);
       break;
     case 2:
-      var value = /** @type {boolean} */ (reader.readBool());
-      msg.setProve(value);
+      var value = /** @type {!Uint8Array} */ (reader.readBytes());
+      msg.setAppHash(value);
+      break;
+    case 3:
+      var value = /** @type {number} */ (reader.readUint32());
+      msg.setEpoch(value);
+      break;
+    case 4:
+      var value = /** @type {number} */ (reader.readUint32());
+      msg.setProtocolVersion(value);
+      break;
+    case 5:
+      var value = /** @type {!Uint8Array} */ (reader.readBytes());
+      msg.setQuorumHash(value);
+      break;
+    case 6:
+      var value = /** @type {number} */ (reader.readUint32());
+      msg.setQuorumType(value);
+      break;
+    case 7:
+      var value = /** @type {number} */ (reader.readUint32());
+      msg.setRound(value);
+      break;
+    case 8:
+      var value = /** @type {!Uint8Array} */ (reader.readBytes());
+      msg.setSignature(value);
       break;
     default:
       reader.skipField();
@@ -12693,9 +16112,9 @@ proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesRequest.deserializeB
  * Serializes the message to binary data (in protobuf wire format).
  * @return {!Uint8Array}
  */
-proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesRequest.prototype.serializeBinary = function() {
+proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse.BlockAttestation.prototype.serializeBinary = function() {
   var writer = new jspb.BinaryWriter();
-  proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesRequest.serializeBinaryToWriter(this, writer);
+  proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse.BlockAttestation.serializeBinaryToWriter(this, writer);
   return writer.getResultBuffer();
 };
 
@@ -12703,340 +16122,312 @@ proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesRequest.prototype.se
 /**
  * Serializes the given message to binary data (in protobuf wire
  * format), writing to the given BinaryWriter.
- * @param {!proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesRequest} message
+ * @param {!proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse.BlockAttestation} message
  * @param {!jspb.BinaryWriter} writer
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesRequest.serializeBinaryToWriter = function(message, writer) {
+proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse.BlockAttestation.serializeBinaryToWriter = function(message, writer) {
   var f = undefined;
-  f = message.getPublicKeyHash_asU8();
-  if (f.length > 0) {
-    writer.writeBytes(
+  f = message.getHeight();
+  if (f !== 0) {
+    writer.writeUint64(
       1,
       f
     );
   }
-  f = message.getProve();
-  if (f) {
-    writer.writeBool(
+  f = message.getAppHash_asU8();
+  if (f.length > 0) {
+    writer.writeBytes(
       2,
       f
     );
   }
+  f = message.getEpoch();
+  if (f !== 0) {
+    writer.writeUint32(
+      3,
+      f
+    );
+  }
+  f = message.getProtocolVersion();
+  if (f !== 0) {
+    writer.writeUint32(
+      4,
+      f
+    );
+  }
+  f = message.getQuorumHash_asU8();
+  if (f.length > 0) {
+    writer.writeBytes(
+      5,
+      f
+    );
+  }
+  f = message.getQuorumType();
+  if (f !== 0) {
+    writer.writeUint32(
+      6,
+      f
+    );
+  }
+  f = message.getRound();
+  if (f !== 0) {
+    writer.writeUint32(
+      7,
+      f
+    );
+  }
+  f = message.getSignature_asU8();
+  if (f.length > 0) {
+    writer.writeBytes(
+      8,
+      f
+    );
+  }
 };
 
 
 /**
- * optional bytes public_key_hash = 1;
+ * optional uint64 height = 1;
+ * @return {number}
+ */
+proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse.BlockAttestation.prototype.getHeight = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 1, 0));
+};
+
+
+/**
+ * @param {number} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse.BlockAttestation} returns this
+ */
+proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse.BlockAttestation.prototype.setHeight = function(value) {
+  return jspb.Message.setProto3IntField(this, 1, value);
+};
+
+
+/**
+ * optional bytes app_hash = 2;
  * @return {string}
  */
-proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesRequest.prototype.getPublicKeyHash = function() {
-  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 1, ""));
+proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse.BlockAttestation.prototype.getAppHash = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 2, ""));
 };
 
 
 /**
- * optional bytes public_key_hash = 1;
- * This is a type-conversion wrapper around `getPublicKeyHash()`
+ * optional bytes app_hash = 2;
+ * This is a type-conversion wrapper around `getAppHash()`
  * @return {string}
  */
-proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesRequest.prototype.getPublicKeyHash_asB64 = function() {
+proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse.BlockAttestation.prototype.getAppHash_asB64 = function() {
   return /** @type {string} */ (jspb.Message.bytesAsB64(
-      this.getPublicKeyHash()));
+      this.getAppHash()));
 };
 
 
 /**
- * optional bytes public_key_hash = 1;
+ * optional bytes app_hash = 2;
  * Note that Uint8Array is not supported on all browsers.
  * @see http://caniuse.com/Uint8Array
- * This is a type-conversion wrapper around `getPublicKeyHash()`
+ * This is a type-conversion wrapper around `getAppHash()`
  * @return {!Uint8Array}
  */
-proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesRequest.prototype.getPublicKeyHash_asU8 = function() {
+proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse.BlockAttestation.prototype.getAppHash_asU8 = function() {
   return /** @type {!Uint8Array} */ (jspb.Message.bytesAsU8(
-      this.getPublicKeyHash()));
+      this.getAppHash()));
 };
 
 
 /**
  * @param {!(string|Uint8Array)} value
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesRequest} returns this
+ * @return {!proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse.BlockAttestation} returns this
  */
-proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesRequest.prototype.setPublicKeyHash = function(value) {
-  return jspb.Message.setProto3BytesField(this, 1, value);
+proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse.BlockAttestation.prototype.setAppHash = function(value) {
+  return jspb.Message.setProto3BytesField(this, 2, value);
 };
 
 
 /**
- * optional bool prove = 2;
- * @return {boolean}
+ * optional uint32 epoch = 3;
+ * @return {number}
  */
-proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesRequest.prototype.getProve = function() {
-  return /** @type {boolean} */ (jspb.Message.getBooleanFieldWithDefault(this, 2, false));
+proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse.BlockAttestation.prototype.getEpoch = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 3, 0));
 };
 
 
 /**
- * @param {boolean} value
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesRequest} returns this
+ * @param {number} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse.BlockAttestation} returns this
  */
-proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesRequest.prototype.setProve = function(value) {
-  return jspb.Message.setProto3BooleanField(this, 2, value);
+proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse.BlockAttestation.prototype.setEpoch = function(value) {
+  return jspb.Message.setProto3IntField(this, 3, value);
 };
 
 
-
 /**
- * Oneof group definitions for this message. Each group defines the field
- * numbers belonging to that group. When of these fields' value is set, all
- * other fields in the group are cleared. During deserialization, if multiple
- * fields are encountered for a group, only the last value seen will be kept.
- * @private {!Array<!Array<number>>}
- * @const
+ * optional uint32 protocol_version = 4;
+ * @return {number}
  */
-proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse.oneofGroups_ = [[1,2]];
+proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse.BlockAttestation.prototype.getProtocolVersion = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 4, 0));
+};
+
 
 /**
- * @enum {number}
+ * @param {number} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse.BlockAttestation} returns this
  */
-proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse.ResultCase = {
-  RESULT_NOT_SET: 0,
-  IDENTITY: 1,
-  PROOF: 2
+proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse.BlockAttestation.prototype.setProtocolVersion = function(value) {
+  return jspb.Message.setProto3IntField(this, 4, value);
 };
 
+
 /**
- * @return {proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse.ResultCase}
+ * optional bytes quorum_hash = 5;
+ * @return {string}
  */
-proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse.prototype.getResultCase = function() {
-  return /** @type {proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse.ResultCase} */(jspb.Message.computeOneofCase(this, proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse.oneofGroups_[0]));
+proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse.BlockAttestation.prototype.getQuorumHash = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 5, ""));
 };
 
 
-
-if (jspb.Message.GENERATE_TO_OBJECT) {
 /**
- * Creates an object representation of this proto.
- * Field names that are reserved in JavaScript and will be renamed to pb_name.
- * Optional fields that are not set will be set to undefined.
- * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
- * For the list of reserved names please see:
- *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
- * @param {boolean=} opt_includeInstance Deprecated. whether to include the
- *     JSPB instance for transitional soy proto support:
- *     http://goto/soy-param-migration
- * @return {!Object}
+ * optional bytes quorum_hash = 5;
+ * This is a type-conversion wrapper around `getQuorumHash()`
+ * @return {string}
  */
-proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse.prototype.toObject = function(opt_includeInstance) {
-  return proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse.toObject(opt_includeInstance, this);
+proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse.BlockAttestation.prototype.getQuorumHash_asB64 = function() {
+  return /** @type {string} */ (jspb.Message.bytesAsB64(
+      this.getQuorumHash()));
 };
 
 
 /**
- * Static version of the {@see toObject} method.
- * @param {boolean|undefined} includeInstance Deprecated. Whether to include
- *     the JSPB instance for transitional soy proto support:
- *     http://goto/soy-param-migration
- * @param {!proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse} msg The msg instance to transform.
- * @return {!Object}
- * @suppress {unusedLocalVariables} f is only used for nested messages
+ * optional bytes quorum_hash = 5;
+ * Note that Uint8Array is not supported on all browsers.
+ * @see http://caniuse.com/Uint8Array
+ * This is a type-conversion wrapper around `getQuorumHash()`
+ * @return {!Uint8Array}
  */
-proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse.toObject = function(includeInstance, msg) {
-  var f, obj = {
-    identity: msg.getIdentity_asB64(),
-    proof: (f = msg.getProof()) && proto.org.dash.platform.dapi.v0.Proof.toObject(includeInstance, f),
-    metadata: (f = msg.getMetadata()) && proto.org.dash.platform.dapi.v0.ResponseMetadata.toObject(includeInstance, f)
-  };
-
-  if (includeInstance) {
-    obj.$jspbMessageInstance = msg;
-  }
-  return obj;
+proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse.BlockAttestation.prototype.getQuorumHash_asU8 = function() {
+  return /** @type {!Uint8Array} */ (jspb.Message.bytesAsU8(
+      this.getQuorumHash()));
 };
-}
 
 
 /**
- * Deserializes binary data (in protobuf wire format).
- * @param {jspb.ByteSource} bytes The bytes to deserialize.
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse}
+ * @param {!(string|Uint8Array)} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse.BlockAttestation} returns this
  */
-proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse.deserializeBinary = function(bytes) {
-  var reader = new jspb.BinaryReader(bytes);
-  var msg = new proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse;
-  return proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse.deserializeBinaryFromReader(msg, reader);
+proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse.BlockAttestation.prototype.setQuorumHash = function(value) {
+  return jspb.Message.setProto3BytesField(this, 5, value);
 };
 
 
 /**
- * Deserializes binary data (in protobuf wire format) from the
- * given reader into the given message object.
- * @param {!proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse} msg The message object to deserialize into.
- * @param {!jspb.BinaryReader} reader The BinaryReader to use.
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse}
+ * optional uint32 quorum_type = 6;
+ * @return {number}
  */
-proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse.deserializeBinaryFromReader = function(msg, reader) {
-  while (reader.nextField()) {
-    if (reader.isEndGroup()) {
-      break;
-    }
-    var field = reader.getFieldNumber();
-    switch (field) {
-    case 1:
-      var value = /** @type {!Uint8Array} */ (reader.readBytes());
-      msg.setIdentity(value);
-      break;
-    case 2:
-      var value = new proto.org.dash.platform.dapi.v0.Proof;
-      reader.readMessage(value,proto.org.dash.platform.dapi.v0.Proof.deserializeBinaryFromReader);
-      msg.setProof(value);
-      break;
-    case 3:
-      var value = new proto.org.dash.platform.dapi.v0.ResponseMetadata;
-      reader.readMessage(value,proto.org.dash.platform.dapi.v0.ResponseMetadata.deserializeBinaryFromReader);
-      msg.setMetadata(value);
-      break;
-    default:
-      reader.skipField();
-      break;
-    }
-  }
-  return msg;
+proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse.BlockAttestation.prototype.getQuorumType = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 6, 0));
 };
 
 
 /**
- * Serializes the message to binary data (in protobuf wire format).
- * @return {!Uint8Array}
+ * @param {number} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse.BlockAttestation} returns this
  */
-proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse.prototype.serializeBinary = function() {
-  var writer = new jspb.BinaryWriter();
-  proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse.serializeBinaryToWriter(this, writer);
-  return writer.getResultBuffer();
+proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse.BlockAttestation.prototype.setQuorumType = function(value) {
+  return jspb.Message.setProto3IntField(this, 6, value);
+};
+
+
+/**
+ * optional uint32 round = 7;
+ * @return {number}
+ */
+proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse.BlockAttestation.prototype.getRound = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 7, 0));
 };
 
 
 /**
- * Serializes the given message to binary data (in protobuf wire
- * format), writing to the given BinaryWriter.
- * @param {!proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse} message
- * @param {!jspb.BinaryWriter} writer
- * @suppress {unusedLocalVariables} f is only used for nested messages
+ * @param {number} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse.BlockAttestation} returns this
  */
-proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse.serializeBinaryToWriter = function(message, writer) {
-  var f = undefined;
-  f = /** @type {!(string|Uint8Array)} */ (jspb.Message.getField(message, 1));
-  if (f != null) {
-    writer.writeBytes(
-      1,
-      f
-    );
-  }
-  f = message.getProof();
-  if (f != null) {
-    writer.writeMessage(
-      2,
-      f,
-      proto.org.dash.platform.dapi.v0.Proof.serializeBinaryToWriter
-    );
-  }
-  f = message.getMetadata();
-  if (f != null) {
-    writer.writeMessage(
-      3,
-      f,
-      proto.org.dash.platform.dapi.v0.ResponseMetadata.serializeBinaryToWriter
-    );
-  }
+proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse.BlockAttestation.prototype.setRound = function(value) {
+  return jspb.Message.setProto3IntField(this, 7, value);
 };
 
 
 /**
- * optional bytes identity = 1;
+ * optional bytes signature = 8;
  * @return {string}
  */
-proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse.prototype.getIdentity = function() {
-  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 1, ""));
+proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse.BlockAttestation.prototype.getSignature = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 8, ""));
 };
 
 
 /**
- * optional bytes identity = 1;
- * This is a type-conversion wrapper around `getIdentity()`
+ * optional bytes signature = 8;
+ * This is a type-conversion wrapper around `getSignature()`
  * @return {string}
  */
-proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse.prototype.getIdentity_asB64 = function() {
+proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse.BlockAttestation.prototype.getSignature_asB64 = function() {
   return /** @type {string} */ (jspb.Message.bytesAsB64(
-      this.getIdentity()));
+      this.getSignature()));
 };
 
 
 /**
- * optional bytes identity = 1;
+ * optional bytes signature = 8;
  * Note that Uint8Array is not supported on all browsers.
  * @see http://caniuse.com/Uint8Array
- * This is a type-conversion wrapper around `getIdentity()`
+ * This is a type-conversion wrapper around `getSignature()`
  * @return {!Uint8Array}
  */
-proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse.prototype.getIdentity_asU8 = function() {
+proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse.BlockAttestation.prototype.getSignature_asU8 = function() {
   return /** @type {!Uint8Array} */ (jspb.Message.bytesAsU8(
-      this.getIdentity()));
+      this.getSignature()));
 };
 
 
 /**
  * @param {!(string|Uint8Array)} value
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse} returns this
- */
-proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse.prototype.setIdentity = function(value) {
-  return jspb.Message.setOneofField(this, 1, proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse.oneofGroups_[0], value);
-};
-
-
-/**
- * Clears the field making it undefined.
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse} returns this
- */
-proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse.prototype.clearIdentity = function() {
-  return jspb.Message.setOneofField(this, 1, proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse.oneofGroups_[0], undefined);
-};
-
-
-/**
- * Returns whether this field is set.
- * @return {boolean}
+ * @return {!proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse.BlockAttestation} returns this
  */
-proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse.prototype.hasIdentity = function() {
-  return jspb.Message.getField(this, 1) != null;
+proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse.BlockAttestation.prototype.setSignature = function(value) {
+  return jspb.Message.setProto3BytesField(this, 8, value);
 };
 
 
 /**
- * optional Proof proof = 2;
- * @return {?proto.org.dash.platform.dapi.v0.Proof}
+ * optional BlockAttestation block_attestation = 1;
+ * @return {?proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse.BlockAttestation}
  */
-proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse.prototype.getProof = function() {
-  return /** @type{?proto.org.dash.platform.dapi.v0.Proof} */ (
-    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.Proof, 2));
+proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse.prototype.getBlockAttestation = function() {
+  return /** @type{?proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse.BlockAttestation} */ (
+    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse.BlockAttestation, 1));
 };
 
 
 /**
- * @param {?proto.org.dash.platform.dapi.v0.Proof|undefined} value
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse} returns this
+ * @param {?proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse.BlockAttestation|undefined} value
+ * @return {!proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse} returns this
 */
-proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse.prototype.setProof = function(value) {
-  return jspb.Message.setOneofWrapperField(this, 2, proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse.oneofGroups_[0], value);
+proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse.prototype.setBlockAttestation = function(value) {
+  return jspb.Message.setWrapperField(this, 1, value);
 };
 
 
 /**
  * Clears the message field making it undefined.
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse} returns this
+ * @return {!proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse} returns this
  */
-proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse.prototype.clearProof = function() {
-  return this.setProof(undefined);
+proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse.prototype.clearBlockAttestation = function() {
+  return this.setBlockAttestation(undefined);
 };
 
 
@@ -13044,35 +16435,35 @@ proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse.prototype.c
  * Returns whether this field is set.
  * @return {boolean}
  */
-proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse.prototype.hasProof = function() {
-  return jspb.Message.getField(this, 2) != null;
+proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse.prototype.hasBlockAttestation = function() {
+  return jspb.Message.getField(this, 1) != null;
 };
 
 
 /**
- * optional ResponseMetadata metadata = 3;
+ * optional ResponseMetadata metadata = 2;
  * @return {?proto.org.dash.platform.dapi.v0.ResponseMetadata}
  */
-proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse.prototype.getMetadata = function() {
+proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse.prototype.getMetadata = function() {
   return /** @type{?proto.org.dash.platform.dapi.v0.ResponseMetadata} */ (
-    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.ResponseMetadata, 3));
+    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.ResponseMetadata, 2));
 };
 
 
 /**
  * @param {?proto.org.dash.platform.dapi.v0.ResponseMetadata|undefined} value
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse} returns this
+ * @return {!proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse} returns this
 */
-proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse.prototype.setMetadata = function(value) {
-  return jspb.Message.setWrapperField(this, 3, value);
+proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse.prototype.setMetadata = function(value) {
+  return jspb.Message.setWrapperField(this, 2, value);
 };
 
 
 /**
  * Clears the message field making it undefined.
- * @return {!proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse} returns this
+ * @return {!proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse} returns this
  */
-proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse.prototype.clearMetadata = function() {
+proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse.prototype.clearMetadata = function() {
   return this.setMetadata(undefined);
 };
 
@@ -13081,8 +16472,8 @@ proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse.prototype.c
  * Returns whether this field is set.
  * @return {boolean}
  */
-proto.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse.prototype.hasMetadata = function() {
-  return jspb.Message.getField(this, 3) != null;
+proto.org.dash.platform.dapi.v0.GetBlockAttestationResponse.prototype.hasMetadata = function() {
+  return jspb.Message.getField(this, 2) != null;
 };
 
 
@@ -13102,8 +16493,8 @@ if (jspb.Message.GENERATE_TO_OBJECT) {
  *     http://goto/soy-param-migration
  * @return {!Object}
  */
-proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultRequest.prototype.toObject = function(opt_includeInstance) {
-  return proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultRequest.toObject(opt_includeInstance, this);
+proto.org.dash.platform.dapi.v0.EstimateFeeRequest.prototype.toObject = function(opt_includeInstance) {
+  return proto.org.dash.platform.dapi.v0.EstimateFeeRequest.toObject(opt_includeInstance, this);
 };
 
 
@@ -13112,14 +16503,13 @@ proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultRequest.prototype.to
  * @param {boolean|undefined} includeInstance Deprecated. Whether to include
  *     the JSPB instance for transitional soy proto support:
  *     http://goto/soy-param-migration
- * @param {!proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultRequest} msg The msg instance to transform.
+ * @param {!proto.org.dash.platform.dapi.v0.EstimateFeeRequest} msg The msg instance to transform.
  * @return {!Object}
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultRequest.toObject = function(includeInstance, msg) {
+proto.org.dash.platform.dapi.v0.EstimateFeeRequest.toObject = function(includeInstance, msg) {
   var f, obj = {
-    stateTransitionHash: msg.getStateTransitionHash_asB64(),
-    prove: jspb.Message.getBooleanFieldWithDefault(msg, 2, false)
+    stateTransition: msg.getStateTransition_asB64()
   };
 
   if (includeInstance) {
@@ -13133,23 +16523,23 @@ proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultRequest.toObject = f
 /**
  * Deserializes binary data (in protobuf wire format).
  * @param {jspb.ByteSource} bytes The bytes to deserialize.
- * @return {!proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultRequest}
+ * @return {!proto.org.dash.platform.dapi.v0.EstimateFeeRequest}
  */
-proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultRequest.deserializeBinary = function(bytes) {
+proto.org.dash.platform.dapi.v0.EstimateFeeRequest.deserializeBinary = function(bytes) {
   var reader = new jspb.BinaryReader(bytes);
-  var msg = new proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultRequest;
-  return proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultRequest.deserializeBinaryFromReader(msg, reader);
+  var msg = new proto.org.dash.platform.dapi.v0.EstimateFeeRequest;
+  return proto.org.dash.platform.dapi.v0.EstimateFeeRequest.deserializeBinaryFromReader(msg, reader);
 };
 
 
 /**
  * Deserializes binary data (in protobuf wire format) from the
  * given reader into the given message object.
- * @param {!proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultRequest} msg The message object to deserialize into.
+ * @param {!proto.org.dash.platform.dapi.v0.EstimateFeeRequest} msg The message object to deserialize into.
  * @param {!jspb.BinaryReader} reader The BinaryReader to use.
- * @return {!proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultRequest}
+ * @return {!proto.org.dash.platform.dapi.v0.EstimateFeeRequest}
  */
-proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultRequest.deserializeBinaryFromReader = function(msg, reader) {
+proto.org.dash.platform.dapi.v0.EstimateFeeRequest.deserializeBinaryFromReader = function(msg, reader) {
   while (reader.nextField()) {
     if (reader.isEndGroup()) {
       break;
@@ -13158,11 +16548,7 @@ proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultRequest.deserializeB
     switch (field) {
     case 1:
       var value = /** @type {!Uint8Array} */ (reader.readBytes());
-      msg.setStateTransitionHash(value);
-      break;
-    case 2:
-      var value = /** @type {boolean} */ (reader.readBool());
-      msg.setProve(value);
+      msg.setStateTransition(value);
       break;
     default:
       reader.skipField();
@@ -13177,9 +16563,9 @@ proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultRequest.deserializeB
  * Serializes the message to binary data (in protobuf wire format).
  * @return {!Uint8Array}
  */
-proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultRequest.prototype.serializeBinary = function() {
+proto.org.dash.platform.dapi.v0.EstimateFeeRequest.prototype.serializeBinary = function() {
   var writer = new jspb.BinaryWriter();
-  proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultRequest.serializeBinaryToWriter(this, writer);
+  proto.org.dash.platform.dapi.v0.EstimateFeeRequest.serializeBinaryToWriter(this, writer);
   return writer.getResultBuffer();
 };
 
@@ -13187,115 +16573,239 @@ proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultRequest.prototype.se
 /**
  * Serializes the given message to binary data (in protobuf wire
  * format), writing to the given BinaryWriter.
- * @param {!proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultRequest} message
+ * @param {!proto.org.dash.platform.dapi.v0.EstimateFeeRequest} message
  * @param {!jspb.BinaryWriter} writer
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultRequest.serializeBinaryToWriter = function(message, writer) {
+proto.org.dash.platform.dapi.v0.EstimateFeeRequest.serializeBinaryToWriter = function(message, writer) {
   var f = undefined;
-  f = message.getStateTransitionHash_asU8();
+  f = message.getStateTransition_asU8();
   if (f.length > 0) {
     writer.writeBytes(
       1,
       f
     );
   }
-  f = message.getProve();
-  if (f) {
-    writer.writeBool(
-      2,
-      f
-    );
-  }
 };
 
 
 /**
- * optional bytes state_transition_hash = 1;
+ * optional bytes state_transition = 1;
  * @return {string}
  */
-proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultRequest.prototype.getStateTransitionHash = function() {
+proto.org.dash.platform.dapi.v0.EstimateFeeRequest.prototype.getStateTransition = function() {
   return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 1, ""));
 };
 
 
 /**
- * optional bytes state_transition_hash = 1;
- * This is a type-conversion wrapper around `getStateTransitionHash()`
+ * optional bytes state_transition = 1;
+ * This is a type-conversion wrapper around `getStateTransition()`
  * @return {string}
  */
-proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultRequest.prototype.getStateTransitionHash_asB64 = function() {
+proto.org.dash.platform.dapi.v0.EstimateFeeRequest.prototype.getStateTransition_asB64 = function() {
   return /** @type {string} */ (jspb.Message.bytesAsB64(
-      this.getStateTransitionHash()));
+      this.getStateTransition()));
 };
 
 
 /**
- * optional bytes state_transition_hash = 1;
+ * optional bytes state_transition = 1;
  * Note that Uint8Array is not supported on all browsers.
  * @see http://caniuse.com/Uint8Array
- * This is a type-conversion wrapper around `getStateTransitionHash()`
+ * This is a type-conversion wrapper around `getStateTransition()`
  * @return {!Uint8Array}
  */
-proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultRequest.prototype.getStateTransitionHash_asU8 = function() {
+proto.org.dash.platform.dapi.v0.EstimateFeeRequest.prototype.getStateTransition_asU8 = function() {
   return /** @type {!Uint8Array} */ (jspb.Message.bytesAsU8(
-      this.getStateTransitionHash()));
+      this.getStateTransition()));
 };
 
 
 /**
  * @param {!(string|Uint8Array)} value
- * @return {!proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultRequest} returns this
+ * @return {!proto.org.dash.platform.dapi.v0.EstimateFeeRequest} returns this
  */
-proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultRequest.prototype.setStateTransitionHash = function(value) {
+proto.org.dash.platform.dapi.v0.EstimateFeeRequest.prototype.setStateTransition = function(value) {
   return jspb.Message.setProto3BytesField(this, 1, value);
 };
 
 
+
 /**
- * optional bool prove = 2;
- * @return {boolean}
+ * Oneof group definitions for this message. Each group defines the field
+ * numbers belonging to that group. When of these fields' value is set, all
+ * other fields in the group are cleared. During deserialization, if multiple
+ * fields are encountered for a group, only the last value seen will be kept.
+ * @private {!Array<!Array<number>>}
+ * @const
  */
-proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultRequest.prototype.getProve = function() {
-  return /** @type {boolean} */ (jspb.Message.getBooleanFieldWithDefault(this, 2, false));
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.oneofGroups_ = [[1,2]];
+
+/**
+ * @enum {number}
+ */
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.ResultCase = {
+  RESULT_NOT_SET: 0,
+  FEE_ESTIMATE: 1,
+  ERROR: 2
+};
+
+/**
+ * @return {proto.org.dash.platform.dapi.v0.EstimateFeeResponse.ResultCase}
+ */
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.prototype.getResultCase = function() {
+  return /** @type {proto.org.dash.platform.dapi.v0.EstimateFeeResponse.ResultCase} */(jspb.Message.computeOneofCase(this, proto.org.dash.platform.dapi.v0.EstimateFeeResponse.oneofGroups_[0]));
 };
 
 
+
+if (jspb.Message.GENERATE_TO_OBJECT) {
 /**
- * @param {boolean} value
- * @return {!proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultRequest} returns this
+ * Creates an object representation of this proto.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * Optional fields that are not set will be set to undefined.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * For the list of reserved names please see:
+ *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
+ * @param {boolean=} opt_includeInstance Deprecated. whether to include the
+ *     JSPB instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @return {!Object}
  */
-proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultRequest.prototype.setProve = function(value) {
-  return jspb.Message.setProto3BooleanField(this, 2, value);
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.prototype.toObject = function(opt_includeInstance) {
+  return proto.org.dash.platform.dapi.v0.EstimateFeeResponse.toObject(opt_includeInstance, this);
+};
+
+
+/**
+ * Static version of the {@see toObject} method.
+ * @param {boolean|undefined} includeInstance Deprecated. Whether to include
+ *     the JSPB instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @param {!proto.org.dash.platform.dapi.v0.EstimateFeeResponse} msg The msg instance to transform.
+ * @return {!Object}
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.toObject = function(includeInstance, msg) {
+  var f, obj = {
+    feeEstimate: (f = msg.getFeeEstimate()) && proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.toObject(includeInstance, f),
+    error: (f = msg.getError()) && proto.org.dash.platform.dapi.v0.StateTransitionBroadcastError.toObject(includeInstance, f),
+    metadata: (f = msg.getMetadata()) && proto.org.dash.platform.dapi.v0.ResponseMetadata.toObject(includeInstance, f)
+  };
+
+  if (includeInstance) {
+    obj.$jspbMessageInstance = msg;
+  }
+  return obj;
+};
+}
+
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.org.dash.platform.dapi.v0.EstimateFeeResponse}
+ */
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.deserializeBinary = function(bytes) {
+  var reader = new jspb.BinaryReader(bytes);
+  var msg = new proto.org.dash.platform.dapi.v0.EstimateFeeResponse;
+  return proto.org.dash.platform.dapi.v0.EstimateFeeResponse.deserializeBinaryFromReader(msg, reader);
+};
+
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.org.dash.platform.dapi.v0.EstimateFeeResponse} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.org.dash.platform.dapi.v0.EstimateFeeResponse}
+ */
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.deserializeBinaryFromReader = function(msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+    case 1:
+      var value = new proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate;
+      reader.readMessage(value,proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.deserializeBinaryFromReader);
+      msg.setFeeEstimate(value);
+      break;
+    case 2:
+      var value = new proto.org.dash.platform.dapi.v0.StateTransitionBroadcastError;
+      reader.readMessage(value,proto.org.dash.platform.dapi.v0.StateTransitionBroadcastError.deserializeBinaryFromReader);
+      msg.setError(value);
+      break;
+    case 3:
+      var value = new proto.org.dash.platform.dapi.v0.ResponseMetadata;
+      reader.readMessage(value,proto.org.dash.platform.dapi.v0.ResponseMetadata.deserializeBinaryFromReader);
+      msg.setMetadata(value);
+      break;
+    default:
+      reader.skipField();
+      break;
+    }
+  }
+  return msg;
+};
+
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.org.dash.platform.dapi.v0.EstimateFeeResponse.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
 };
 
 
-
-/**
- * Oneof group definitions for this message. Each group defines the field
- * numbers belonging to that group. When of these fields' value is set, all
- * other fields in the group are cleared. During deserialization, if multiple
- * fields are encountered for a group, only the last value seen will be kept.
- * @private {!Array<!Array<number>>}
- * @const
- */
-proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.oneofGroups_ = [[1,2]];
-
 /**
- * @enum {number}
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.org.dash.platform.dapi.v0.EstimateFeeResponse} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.ResultCase = {
-  RESULT_NOT_SET: 0,
-  ERROR: 1,
-  PROOF: 2
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+  f = message.getFeeEstimate();
+  if (f != null) {
+    writer.writeMessage(
+      1,
+      f,
+      proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.serializeBinaryToWriter
+    );
+  }
+  f = message.getError();
+  if (f != null) {
+    writer.writeMessage(
+      2,
+      f,
+      proto.org.dash.platform.dapi.v0.StateTransitionBroadcastError.serializeBinaryToWriter
+    );
+  }
+  f = message.getMetadata();
+  if (f != null) {
+    writer.writeMessage(
+      3,
+      f,
+      proto.org.dash.platform.dapi.v0.ResponseMetadata.serializeBinaryToWriter
+    );
+  }
 };
 
+
+
 /**
- * @return {proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.ResultCase}
+ * List of repeated fields within this message type.
+ * @private {!Array<number>}
+ * @const
  */
-proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.prototype.getResultCase = function() {
-  return /** @type {proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.ResultCase} */(jspb.Message.computeOneofCase(this, proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.oneofGroups_[0]));
-};
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.repeatedFields_ = [3];
 
 
 
@@ -13312,8 +16822,8 @@ if (jspb.Message.GENERATE_TO_OBJECT) {
  *     http://goto/soy-param-migration
  * @return {!Object}
  */
-proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.prototype.toObject = function(opt_includeInstance) {
-  return proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.toObject(opt_includeInstance, this);
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.prototype.toObject = function(opt_includeInstance) {
+  return proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.toObject(opt_includeInstance, this);
 };
 
 
@@ -13322,15 +16832,17 @@ proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.prototype.t
  * @param {boolean|undefined} includeInstance Deprecated. Whether to include
  *     the JSPB instance for transitional soy proto support:
  *     http://goto/soy-param-migration
- * @param {!proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse} msg The msg instance to transform.
+ * @param {!proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate} msg The msg instance to transform.
  * @return {!Object}
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.toObject = function(includeInstance, msg) {
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.toObject = function(includeInstance, msg) {
   var f, obj = {
-    error: (f = msg.getError()) && proto.org.dash.platform.dapi.v0.StateTransitionBroadcastError.toObject(includeInstance, f),
-    proof: (f = msg.getProof()) && proto.org.dash.platform.dapi.v0.Proof.toObject(includeInstance, f),
-    metadata: (f = msg.getMetadata()) && proto.org.dash.platform.dapi.v0.ResponseMetadata.toObject(includeInstance, f)
+    storageFee: jspb.Message.getFieldWithDefault(msg, 1, 0),
+    processingFee: jspb.Message.getFieldWithDefault(msg, 2, 0),
+    refundsList: jspb.Message.toObjectList(msg.getRefundsList(),
+    proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.Refund.toObject, includeInstance),
+    balanceChange: (f = msg.getBalanceChange()) && proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.toObject(includeInstance, f)
   };
 
   if (includeInstance) {
@@ -13344,23 +16856,23 @@ proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.toObject =
 /**
  * Deserializes binary data (in protobuf wire format).
  * @param {jspb.ByteSource} bytes The bytes to deserialize.
- * @return {!proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse}
+ * @return {!proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate}
  */
-proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.deserializeBinary = function(bytes) {
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.deserializeBinary = function(bytes) {
   var reader = new jspb.BinaryReader(bytes);
-  var msg = new proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse;
-  return proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.deserializeBinaryFromReader(msg, reader);
+  var msg = new proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate;
+  return proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.deserializeBinaryFromReader(msg, reader);
 };
 
 
 /**
  * Deserializes binary data (in protobuf wire format) from the
  * given reader into the given message object.
- * @param {!proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse} msg The message object to deserialize into.
+ * @param {!proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate} msg The message object to deserialize into.
  * @param {!jspb.BinaryReader} reader The BinaryReader to use.
- * @return {!proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse}
+ * @return {!proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate}
  */
-proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.deserializeBinaryFromReader = function(msg, reader) {
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.deserializeBinaryFromReader = function(msg, reader) {
   while (reader.nextField()) {
     if (reader.isEndGroup()) {
       break;
@@ -13368,19 +16880,22 @@ proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.deserialize
     var field = reader.getFieldNumber();
     switch (field) {
     case 1:
-      var value = new proto.org.dash.platform.dapi.v0.StateTransitionBroadcastError;
-      reader.readMessage(value,proto.org.dash.platform.dapi.v0.StateTransitionBroadcastError.deserializeBinaryFromReader);
-      msg.setError(value);
+      var value = /** @type {number} */ (reader.readUint64());
+      msg.setStorageFee(value);
       break;
     case 2:
-      var value = new proto.org.dash.platform.dapi.v0.Proof;
-      reader.readMessage(value,proto.org.dash.platform.dapi.v0.Proof.deserializeBinaryFromReader);
-      msg.setProof(value);
+      var value = /** @type {number} */ (reader.readUint64());
+      msg.setProcessingFee(value);
       break;
     case 3:
-      var value = new proto.org.dash.platform.dapi.v0.ResponseMetadata;
-      reader.readMessage(value,proto.org.dash.platform.dapi.v0.ResponseMetadata.deserializeBinaryFromReader);
-      msg.setMetadata(value);
+      var value = new proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.Refund;
+      reader.readMessage(value,proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.Refund.deserializeBinaryFromReader);
+      msg.addRefunds(value);
+      break;
+    case 4:
+      var value = new proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange;
+      reader.readMessage(value,proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.deserializeBinaryFromReader);
+      msg.setBalanceChange(value);
       break;
     default:
       reader.skipField();
@@ -13395,9 +16910,9 @@ proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.deserialize
  * Serializes the message to binary data (in protobuf wire format).
  * @return {!Uint8Array}
  */
-proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.prototype.serializeBinary = function() {
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.prototype.serializeBinary = function() {
   var writer = new jspb.BinaryWriter();
-  proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.serializeBinaryToWriter(this, writer);
+  proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.serializeBinaryToWriter(this, writer);
   return writer.getResultBuffer();
 };
 
@@ -13405,150 +16920,256 @@ proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.prototype.s
 /**
  * Serializes the given message to binary data (in protobuf wire
  * format), writing to the given BinaryWriter.
- * @param {!proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse} message
+ * @param {!proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate} message
  * @param {!jspb.BinaryWriter} writer
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.serializeBinaryToWriter = function(message, writer) {
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.serializeBinaryToWriter = function(message, writer) {
   var f = undefined;
-  f = message.getError();
-  if (f != null) {
-    writer.writeMessage(
+  f = message.getStorageFee();
+  if (f !== 0) {
+    writer.writeUint64(
       1,
-      f,
-      proto.org.dash.platform.dapi.v0.StateTransitionBroadcastError.serializeBinaryToWriter
+      f
     );
   }
-  f = message.getProof();
-  if (f != null) {
-    writer.writeMessage(
+  f = message.getProcessingFee();
+  if (f !== 0) {
+    writer.writeUint64(
       2,
+      f
+    );
+  }
+  f = message.getRefundsList();
+  if (f.length > 0) {
+    writer.writeRepeatedMessage(
+      3,
       f,
-      proto.org.dash.platform.dapi.v0.Proof.serializeBinaryToWriter
+      proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.Refund.serializeBinaryToWriter
     );
   }
-  f = message.getMetadata();
+  f = message.getBalanceChange();
   if (f != null) {
     writer.writeMessage(
-      3,
+      4,
       f,
-      proto.org.dash.platform.dapi.v0.ResponseMetadata.serializeBinaryToWriter
+      proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.serializeBinaryToWriter
     );
   }
 };
 
 
+
+
+
+if (jspb.Message.GENERATE_TO_OBJECT) {
 /**
- * optional StateTransitionBroadcastError error = 1;
- * @return {?proto.org.dash.platform.dapi.v0.StateTransitionBroadcastError}
+ * Creates an object representation of this proto.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * Optional fields that are not set will be set to undefined.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * For the list of reserved names please see:
+ *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
+ * @param {boolean=} opt_includeInstance Deprecated. whether to include the
+ *     JSPB instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @return {!Object}
  */
-proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.prototype.getError = function() {
-  return /** @type{?proto.org.dash.platform.dapi.v0.StateTransitionBroadcastError} */ (
-    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.StateTransitionBroadcastError, 1));
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.Refund.prototype.toObject = function(opt_includeInstance) {
+  return proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.Refund.toObject(opt_includeInstance, this);
 };
 
 
 /**
- * @param {?proto.org.dash.platform.dapi.v0.StateTransitionBroadcastError|undefined} value
- * @return {!proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse} returns this
-*/
-proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.prototype.setError = function(value) {
-  return jspb.Message.setOneofWrapperField(this, 1, proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.oneofGroups_[0], value);
+ * Static version of the {@see toObject} method.
+ * @param {boolean|undefined} includeInstance Deprecated. Whether to include
+ *     the JSPB instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @param {!proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.Refund} msg The msg instance to transform.
+ * @return {!Object}
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.Refund.toObject = function(includeInstance, msg) {
+  var f, obj = {
+    identityId: msg.getIdentityId_asB64(),
+    credits: jspb.Message.getFieldWithDefault(msg, 2, 0)
+  };
+
+  if (includeInstance) {
+    obj.$jspbMessageInstance = msg;
+  }
+  return obj;
 };
+}
 
 
 /**
- * Clears the message field making it undefined.
- * @return {!proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse} returns this
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.Refund}
  */
-proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.prototype.clearError = function() {
-  return this.setError(undefined);
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.Refund.deserializeBinary = function(bytes) {
+  var reader = new jspb.BinaryReader(bytes);
+  var msg = new proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.Refund;
+  return proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.Refund.deserializeBinaryFromReader(msg, reader);
 };
 
 
 /**
- * Returns whether this field is set.
- * @return {boolean}
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.Refund} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.Refund}
  */
-proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.prototype.hasError = function() {
-  return jspb.Message.getField(this, 1) != null;
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.Refund.deserializeBinaryFromReader = function(msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+    case 1:
+      var value = /** @type {!Uint8Array} */ (reader.readBytes());
+      msg.setIdentityId(value);
+      break;
+    case 2:
+      var value = /** @type {number} */ (reader.readUint64());
+      msg.setCredits(value);
+      break;
+    default:
+      reader.skipField();
+      break;
+    }
+  }
+  return msg;
 };
 
 
 /**
- * optional Proof proof = 2;
- * @return {?proto.org.dash.platform.dapi.v0.Proof}
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
  */
-proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.prototype.getProof = function() {
-  return /** @type{?proto.org.dash.platform.dapi.v0.Proof} */ (
-    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.Proof, 2));
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.Refund.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.Refund.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
 };
 
 
 /**
- * @param {?proto.org.dash.platform.dapi.v0.Proof|undefined} value
- * @return {!proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse} returns this
-*/
-proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.prototype.setProof = function(value) {
-  return jspb.Message.setOneofWrapperField(this, 2, proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.oneofGroups_[0], value);
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.Refund} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.Refund.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+  f = message.getIdentityId_asU8();
+  if (f.length > 0) {
+    writer.writeBytes(
+      1,
+      f
+    );
+  }
+  f = message.getCredits();
+  if (f !== 0) {
+    writer.writeUint64(
+      2,
+      f
+    );
+  }
 };
 
 
 /**
- * Clears the message field making it undefined.
- * @return {!proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse} returns this
+ * optional bytes identity_id = 1;
+ * @return {string}
  */
-proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.prototype.clearProof = function() {
-  return this.setProof(undefined);
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.Refund.prototype.getIdentityId = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 1, ""));
 };
 
 
 /**
- * Returns whether this field is set.
- * @return {boolean}
+ * optional bytes identity_id = 1;
+ * This is a type-conversion wrapper around `getIdentityId()`
+ * @return {string}
  */
-proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.prototype.hasProof = function() {
-  return jspb.Message.getField(this, 2) != null;
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.Refund.prototype.getIdentityId_asB64 = function() {
+  return /** @type {string} */ (jspb.Message.bytesAsB64(
+      this.getIdentityId()));
 };
 
 
 /**
- * optional ResponseMetadata metadata = 3;
- * @return {?proto.org.dash.platform.dapi.v0.ResponseMetadata}
+ * optional bytes identity_id = 1;
+ * Note that Uint8Array is not supported on all browsers.
+ * @see http://caniuse.com/Uint8Array
+ * This is a type-conversion wrapper around `getIdentityId()`
+ * @return {!Uint8Array}
  */
-proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.prototype.getMetadata = function() {
-  return /** @type{?proto.org.dash.platform.dapi.v0.ResponseMetadata} */ (
-    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.ResponseMetadata, 3));
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.Refund.prototype.getIdentityId_asU8 = function() {
+  return /** @type {!Uint8Array} */ (jspb.Message.bytesAsU8(
+      this.getIdentityId()));
 };
 
 
 /**
- * @param {?proto.org.dash.platform.dapi.v0.ResponseMetadata|undefined} value
- * @return {!proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse} returns this
-*/
-proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.prototype.setMetadata = function(value) {
-  return jspb.Message.setWrapperField(this, 3, value);
+ * @param {!(string|Uint8Array)} value
+ * @return {!proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.Refund} returns this
+ */
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.Refund.prototype.setIdentityId = function(value) {
+  return jspb.Message.setProto3BytesField(this, 1, value);
+};
+
+
+/**
+ * optional uint64 credits = 2;
+ * @return {number}
+ */
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.Refund.prototype.getCredits = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 2, 0));
 };
 
 
 /**
- * Clears the message field making it undefined.
- * @return {!proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse} returns this
+ * @param {number} value
+ * @return {!proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.Refund} returns this
  */
-proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.prototype.clearMetadata = function() {
-  return this.setMetadata(undefined);
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.Refund.prototype.setCredits = function(value) {
+  return jspb.Message.setProto3IntField(this, 2, value);
 };
 
 
+
 /**
- * Returns whether this field is set.
- * @return {boolean}
+ * Oneof group definitions for this message. Each group defines the field
+ * numbers belonging to that group. When of these fields' value is set, all
+ * other fields in the group are cleared. During deserialization, if multiple
+ * fields are encountered for a group, only the last value seen will be kept.
+ * @private {!Array<!Array<number>>}
+ * @const
  */
-proto.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.prototype.hasMetadata = function() {
-  return jspb.Message.getField(this, 3) != null;
-};
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.oneofGroups_ = [[1,2,3]];
 
+/**
+ * @enum {number}
+ */
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.ChangeCase = {
+  CHANGE_NOT_SET: 0,
+  ADD_TO_BALANCE: 1,
+  REMOVE_FROM_BALANCE: 2,
+  NO_BALANCE_CHANGE: 3
+};
 
+/**
+ * @return {proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.ChangeCase}
+ */
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.prototype.getChangeCase = function() {
+  return /** @type {proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.ChangeCase} */(jspb.Message.computeOneofCase(this, proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.oneofGroups_[0]));
+};
 
 
 
@@ -13565,8 +17186,8 @@ if (jspb.Message.GENERATE_TO_OBJECT) {
  *     http://goto/soy-param-migration
  * @return {!Object}
  */
-proto.org.dash.platform.dapi.v0.ConsensusParamsBlock.prototype.toObject = function(opt_includeInstance) {
-  return proto.org.dash.platform.dapi.v0.ConsensusParamsBlock.toObject(opt_includeInstance, this);
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.prototype.toObject = function(opt_includeInstance) {
+  return proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.toObject(opt_includeInstance, this);
 };
 
 
@@ -13575,15 +17196,15 @@ proto.org.dash.platform.dapi.v0.ConsensusParamsBlock.prototype.toObject = functi
  * @param {boolean|undefined} includeInstance Deprecated. Whether to include
  *     the JSPB instance for transitional soy proto support:
  *     http://goto/soy-param-migration
- * @param {!proto.org.dash.platform.dapi.v0.ConsensusParamsBlock} msg The msg instance to transform.
+ * @param {!proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange} msg The msg instance to transform.
  * @return {!Object}
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.org.dash.platform.dapi.v0.ConsensusParamsBlock.toObject = function(includeInstance, msg) {
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.toObject = function(includeInstance, msg) {
   var f, obj = {
-    maxBytes: jspb.Message.getFieldWithDefault(msg, 1, ""),
-    maxGas: jspb.Message.getFieldWithDefault(msg, 2, ""),
-    timeIotaMs: jspb.Message.getFieldWithDefault(msg, 3, "")
+    addToBalance: jspb.Message.getFieldWithDefault(msg, 1, 0),
+    removeFromBalance: (f = msg.getRemoveFromBalance()) && proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.RemoveFromBalance.toObject(includeInstance, f),
+    noBalanceChange: jspb.Message.getBooleanFieldWithDefault(msg, 3, false)
   };
 
   if (includeInstance) {
@@ -13597,23 +17218,23 @@ proto.org.dash.platform.dapi.v0.ConsensusParamsBlock.toObject = function(include
 /**
  * Deserializes binary data (in protobuf wire format).
  * @param {jspb.ByteSource} bytes The bytes to deserialize.
- * @return {!proto.org.dash.platform.dapi.v0.ConsensusParamsBlock}
+ * @return {!proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange}
  */
-proto.org.dash.platform.dapi.v0.ConsensusParamsBlock.deserializeBinary = function(bytes) {
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.deserializeBinary = function(bytes) {
   var reader = new jspb.BinaryReader(bytes);
-  var msg = new proto.org.dash.platform.dapi.v0.ConsensusParamsBlock;
-  return proto.org.dash.platform.dapi.v0.ConsensusParamsBlock.deserializeBinaryFromReader(msg, reader);
+  var msg = new proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange;
+  return proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.deserializeBinaryFromReader(msg, reader);
 };
 
 
 /**
  * Deserializes binary data (in protobuf wire format) from the
  * given reader into the given message object.
- * @param {!proto.org.dash.platform.dapi.v0.ConsensusParamsBlock} msg The message object to deserialize into.
+ * @param {!proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange} msg The message object to deserialize into.
  * @param {!jspb.BinaryReader} reader The BinaryReader to use.
- * @return {!proto.org.dash.platform.dapi.v0.ConsensusParamsBlock}
+ * @return {!proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange}
  */
-proto.org.dash.platform.dapi.v0.ConsensusParamsBlock.deserializeBinaryFromReader = function(msg, reader) {
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.deserializeBinaryFromReader = function(msg, reader) {
   while (reader.nextField()) {
     if (reader.isEndGroup()) {
       break;
@@ -13621,16 +17242,17 @@ proto.org.dash.platform.dapi.v0.ConsensusParamsBlock.deserializeBinaryFromReader
     var field = reader.getFieldNumber();
     switch (field) {
     case 1:
-      var value = /** @type {string} */ (reader.readString());
-      msg.setMaxBytes(value);
+      var value = /** @type {number} */ (reader.readUint64());
+      msg.setAddToBalance(value);
       break;
     case 2:
-      var value = /** @type {string} */ (reader.readString());
-      msg.setMaxGas(value);
+      var value = new proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.RemoveFromBalance;
+      reader.readMessage(value,proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.RemoveFromBalance.deserializeBinaryFromReader);
+      msg.setRemoveFromBalance(value);
       break;
     case 3:
-      var value = /** @type {string} */ (reader.readString());
-      msg.setTimeIotaMs(value);
+      var value = /** @type {boolean} */ (reader.readBool());
+      msg.setNoBalanceChange(value);
       break;
     default:
       reader.skipField();
@@ -13645,9 +17267,9 @@ proto.org.dash.platform.dapi.v0.ConsensusParamsBlock.deserializeBinaryFromReader
  * Serializes the message to binary data (in protobuf wire format).
  * @return {!Uint8Array}
  */
-proto.org.dash.platform.dapi.v0.ConsensusParamsBlock.prototype.serializeBinary = function() {
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.prototype.serializeBinary = function() {
   var writer = new jspb.BinaryWriter();
-  proto.org.dash.platform.dapi.v0.ConsensusParamsBlock.serializeBinaryToWriter(this, writer);
+  proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.serializeBinaryToWriter(this, writer);
   return writer.getResultBuffer();
 };
 
@@ -13655,29 +17277,30 @@ proto.org.dash.platform.dapi.v0.ConsensusParamsBlock.prototype.serializeBinary =
 /**
  * Serializes the given message to binary data (in protobuf wire
  * format), writing to the given BinaryWriter.
- * @param {!proto.org.dash.platform.dapi.v0.ConsensusParamsBlock} message
+ * @param {!proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange} message
  * @param {!jspb.BinaryWriter} writer
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.org.dash.platform.dapi.v0.ConsensusParamsBlock.serializeBinaryToWriter = function(message, writer) {
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.serializeBinaryToWriter = function(message, writer) {
   var f = undefined;
-  f = message.getMaxBytes();
-  if (f.length > 0) {
-    writer.writeString(
+  f = /** @type {number} */ (jspb.Message.getField(message, 1));
+  if (f != null) {
+    writer.writeUint64(
       1,
       f
     );
   }
-  f = message.getMaxGas();
-  if (f.length > 0) {
-    writer.writeString(
+  f = message.getRemoveFromBalance();
+  if (f != null) {
+    writer.writeMessage(
       2,
-      f
+      f,
+      proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.RemoveFromBalance.serializeBinaryToWriter
     );
   }
-  f = message.getTimeIotaMs();
-  if (f.length > 0) {
-    writer.writeString(
+  f = /** @type {boolean} */ (jspb.Message.getField(message, 3));
+  if (f != null) {
+    writer.writeBool(
       3,
       f
     );
@@ -13685,60 +17308,6 @@ proto.org.dash.platform.dapi.v0.ConsensusParamsBlock.serializeBinaryToWriter = f
 };
 
 
-/**
- * optional string max_bytes = 1;
- * @return {string}
- */
-proto.org.dash.platform.dapi.v0.ConsensusParamsBlock.prototype.getMaxBytes = function() {
-  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 1, ""));
-};
-
-
-/**
- * @param {string} value
- * @return {!proto.org.dash.platform.dapi.v0.ConsensusParamsBlock} returns this
- */
-proto.org.dash.platform.dapi.v0.ConsensusParamsBlock.prototype.setMaxBytes = function(value) {
-  return jspb.Message.setProto3StringField(this, 1, value);
-};
-
-
-/**
- * optional string max_gas = 2;
- * @return {string}
- */
-proto.org.dash.platform.dapi.v0.ConsensusParamsBlock.prototype.getMaxGas = function() {
-  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 2, ""));
-};
-
-
-/**
- * @param {string} value
- * @return {!proto.org.dash.platform.dapi.v0.ConsensusParamsBlock} returns this
- */
-proto.org.dash.platform.dapi.v0.ConsensusParamsBlock.prototype.setMaxGas = function(value) {
-  return jspb.Message.setProto3StringField(this, 2, value);
-};
-
-
-/**
- * optional string time_iota_ms = 3;
- * @return {string}
- */
-proto.org.dash.platform.dapi.v0.ConsensusParamsBlock.prototype.getTimeIotaMs = function() {
-  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 3, ""));
-};
-
-
-/**
- * @param {string} value
- * @return {!proto.org.dash.platform.dapi.v0.ConsensusParamsBlock} returns this
- */
-proto.org.dash.platform.dapi.v0.ConsensusParamsBlock.prototype.setTimeIotaMs = function(value) {
-  return jspb.Message.setProto3StringField(this, 3, value);
-};
-
-
 
 
 
@@ -13755,8 +17324,8 @@ if (jspb.Message.GENERATE_TO_OBJECT) {
  *     http://goto/soy-param-migration
  * @return {!Object}
  */
-proto.org.dash.platform.dapi.v0.ConsensusParamsEvidence.prototype.toObject = function(opt_includeInstance) {
-  return proto.org.dash.platform.dapi.v0.ConsensusParamsEvidence.toObject(opt_includeInstance, this);
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.RemoveFromBalance.prototype.toObject = function(opt_includeInstance) {
+  return proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.RemoveFromBalance.toObject(opt_includeInstance, this);
 };
 
 
@@ -13765,15 +17334,14 @@ proto.org.dash.platform.dapi.v0.ConsensusParamsEvidence.prototype.toObject = fun
  * @param {boolean|undefined} includeInstance Deprecated. Whether to include
  *     the JSPB instance for transitional soy proto support:
  *     http://goto/soy-param-migration
- * @param {!proto.org.dash.platform.dapi.v0.ConsensusParamsEvidence} msg The msg instance to transform.
+ * @param {!proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.RemoveFromBalance} msg The msg instance to transform.
  * @return {!Object}
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.org.dash.platform.dapi.v0.ConsensusParamsEvidence.toObject = function(includeInstance, msg) {
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.RemoveFromBalance.toObject = function(includeInstance, msg) {
   var f, obj = {
-    maxAgeNumBlocks: jspb.Message.getFieldWithDefault(msg, 1, ""),
-    maxAgeDuration: jspb.Message.getFieldWithDefault(msg, 2, ""),
-    maxBytes: jspb.Message.getFieldWithDefault(msg, 3, "")
+    requiredRemovedBalance: jspb.Message.getFieldWithDefault(msg, 1, 0),
+    desiredRemovedBalance: jspb.Message.getFieldWithDefault(msg, 2, 0)
   };
 
   if (includeInstance) {
@@ -13787,23 +17355,23 @@ proto.org.dash.platform.dapi.v0.ConsensusParamsEvidence.toObject = function(incl
 /**
  * Deserializes binary data (in protobuf wire format).
  * @param {jspb.ByteSource} bytes The bytes to deserialize.
- * @return {!proto.org.dash.platform.dapi.v0.ConsensusParamsEvidence}
+ * @return {!proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.RemoveFromBalance}
  */
-proto.org.dash.platform.dapi.v0.ConsensusParamsEvidence.deserializeBinary = function(bytes) {
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.RemoveFromBalance.deserializeBinary = function(bytes) {
   var reader = new jspb.BinaryReader(bytes);
-  var msg = new proto.org.dash.platform.dapi.v0.ConsensusParamsEvidence;
-  return proto.org.dash.platform.dapi.v0.ConsensusParamsEvidence.deserializeBinaryFromReader(msg, reader);
+  var msg = new proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.RemoveFromBalance;
+  return proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.RemoveFromBalance.deserializeBinaryFromReader(msg, reader);
 };
 
 
 /**
  * Deserializes binary data (in protobuf wire format) from the
  * given reader into the given message object.
- * @param {!proto.org.dash.platform.dapi.v0.ConsensusParamsEvidence} msg The message object to deserialize into.
+ * @param {!proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.RemoveFromBalance} msg The message object to deserialize into.
  * @param {!jspb.BinaryReader} reader The BinaryReader to use.
- * @return {!proto.org.dash.platform.dapi.v0.ConsensusParamsEvidence}
+ * @return {!proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.RemoveFromBalance}
  */
-proto.org.dash.platform.dapi.v0.ConsensusParamsEvidence.deserializeBinaryFromReader = function(msg, reader) {
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.RemoveFromBalance.deserializeBinaryFromReader = function(msg, reader) {
   while (reader.nextField()) {
     if (reader.isEndGroup()) {
       break;
@@ -13811,16 +17379,12 @@ proto.org.dash.platform.dapi.v0.ConsensusParamsEvidence.deserializeBinaryFromRea
     var field = reader.getFieldNumber();
     switch (field) {
     case 1:
-      var value = /** @type {string} */ (reader.readString());
-      msg.setMaxAgeNumBlocks(value);
+      var value = /** @type {number} */ (reader.readUint64());
+      msg.setRequiredRemovedBalance(value);
       break;
     case 2:
-      var value = /** @type {string} */ (reader.readString());
-      msg.setMaxAgeDuration(value);
-      break;
-    case 3:
-      var value = /** @type {string} */ (reader.readString());
-      msg.setMaxBytes(value);
+      var value = /** @type {number} */ (reader.readUint64());
+      msg.setDesiredRemovedBalance(value);
       break;
     default:
       reader.skipField();
@@ -13835,9 +17399,9 @@ proto.org.dash.platform.dapi.v0.ConsensusParamsEvidence.deserializeBinaryFromRea
  * Serializes the message to binary data (in protobuf wire format).
  * @return {!Uint8Array}
  */
-proto.org.dash.platform.dapi.v0.ConsensusParamsEvidence.prototype.serializeBinary = function() {
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.RemoveFromBalance.prototype.serializeBinary = function() {
   var writer = new jspb.BinaryWriter();
-  proto.org.dash.platform.dapi.v0.ConsensusParamsEvidence.serializeBinaryToWriter(this, writer);
+  proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.RemoveFromBalance.serializeBinaryToWriter(this, writer);
   return writer.getResultBuffer();
 };
 
@@ -13845,403 +17409,310 @@ proto.org.dash.platform.dapi.v0.ConsensusParamsEvidence.prototype.serializeBinar
 /**
  * Serializes the given message to binary data (in protobuf wire
  * format), writing to the given BinaryWriter.
- * @param {!proto.org.dash.platform.dapi.v0.ConsensusParamsEvidence} message
+ * @param {!proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.RemoveFromBalance} message
  * @param {!jspb.BinaryWriter} writer
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.org.dash.platform.dapi.v0.ConsensusParamsEvidence.serializeBinaryToWriter = function(message, writer) {
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.RemoveFromBalance.serializeBinaryToWriter = function(message, writer) {
   var f = undefined;
-  f = message.getMaxAgeNumBlocks();
-  if (f.length > 0) {
-    writer.writeString(
+  f = message.getRequiredRemovedBalance();
+  if (f !== 0) {
+    writer.writeUint64(
       1,
       f
     );
   }
-  f = message.getMaxAgeDuration();
-  if (f.length > 0) {
-    writer.writeString(
+  f = message.getDesiredRemovedBalance();
+  if (f !== 0) {
+    writer.writeUint64(
       2,
       f
     );
   }
-  f = message.getMaxBytes();
-  if (f.length > 0) {
-    writer.writeString(
-      3,
-      f
-    );
-  }
 };
 
 
 /**
- * optional string max_age_num_blocks = 1;
- * @return {string}
+ * optional uint64 required_removed_balance = 1;
+ * @return {number}
  */
-proto.org.dash.platform.dapi.v0.ConsensusParamsEvidence.prototype.getMaxAgeNumBlocks = function() {
-  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 1, ""));
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.RemoveFromBalance.prototype.getRequiredRemovedBalance = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 1, 0));
 };
 
 
 /**
- * @param {string} value
- * @return {!proto.org.dash.platform.dapi.v0.ConsensusParamsEvidence} returns this
+ * @param {number} value
+ * @return {!proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.RemoveFromBalance} returns this
  */
-proto.org.dash.platform.dapi.v0.ConsensusParamsEvidence.prototype.setMaxAgeNumBlocks = function(value) {
-  return jspb.Message.setProto3StringField(this, 1, value);
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.RemoveFromBalance.prototype.setRequiredRemovedBalance = function(value) {
+  return jspb.Message.setProto3IntField(this, 1, value);
 };
 
 
 /**
- * optional string max_age_duration = 2;
- * @return {string}
+ * optional uint64 desired_removed_balance = 2;
+ * @return {number}
  */
-proto.org.dash.platform.dapi.v0.ConsensusParamsEvidence.prototype.getMaxAgeDuration = function() {
-  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 2, ""));
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.RemoveFromBalance.prototype.getDesiredRemovedBalance = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 2, 0));
 };
 
 
 /**
- * @param {string} value
- * @return {!proto.org.dash.platform.dapi.v0.ConsensusParamsEvidence} returns this
+ * @param {number} value
+ * @return {!proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.RemoveFromBalance} returns this
  */
-proto.org.dash.platform.dapi.v0.ConsensusParamsEvidence.prototype.setMaxAgeDuration = function(value) {
-  return jspb.Message.setProto3StringField(this, 2, value);
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.RemoveFromBalance.prototype.setDesiredRemovedBalance = function(value) {
+  return jspb.Message.setProto3IntField(this, 2, value);
 };
 
 
 /**
- * optional string max_bytes = 3;
- * @return {string}
+ * optional uint64 add_to_balance = 1;
+ * @return {number}
  */
-proto.org.dash.platform.dapi.v0.ConsensusParamsEvidence.prototype.getMaxBytes = function() {
-  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 3, ""));
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.prototype.getAddToBalance = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 1, 0));
 };
 
 
 /**
- * @param {string} value
- * @return {!proto.org.dash.platform.dapi.v0.ConsensusParamsEvidence} returns this
+ * @param {number} value
+ * @return {!proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange} returns this
  */
-proto.org.dash.platform.dapi.v0.ConsensusParamsEvidence.prototype.setMaxBytes = function(value) {
-  return jspb.Message.setProto3StringField(this, 3, value);
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.prototype.setAddToBalance = function(value) {
+  return jspb.Message.setOneofField(this, 1, proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.oneofGroups_[0], value);
 };
 
 
+/**
+ * Clears the field making it undefined.
+ * @return {!proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange} returns this
+ */
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.prototype.clearAddToBalance = function() {
+  return jspb.Message.setOneofField(this, 1, proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.oneofGroups_[0], undefined);
+};
+
 
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.prototype.hasAddToBalance = function() {
+  return jspb.Message.getField(this, 1) != null;
+};
 
 
-if (jspb.Message.GENERATE_TO_OBJECT) {
 /**
- * Creates an object representation of this proto.
- * Field names that are reserved in JavaScript and will be renamed to pb_name.
- * Optional fields that are not set will be set to undefined.
- * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
- * For the list of reserved names please see:
- *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
- * @param {boolean=} opt_includeInstance Deprecated. whether to include the
- *     JSPB instance for transitional soy proto support:
- *     http://goto/soy-param-migration
- * @return {!Object}
+ * optional RemoveFromBalance remove_from_balance = 2;
+ * @return {?proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.RemoveFromBalance}
  */
-proto.org.dash.platform.dapi.v0.GetConsensusParamsRequest.prototype.toObject = function(opt_includeInstance) {
-  return proto.org.dash.platform.dapi.v0.GetConsensusParamsRequest.toObject(opt_includeInstance, this);
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.prototype.getRemoveFromBalance = function() {
+  return /** @type{?proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.RemoveFromBalance} */ (
+    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.RemoveFromBalance, 2));
 };
 
 
 /**
- * Static version of the {@see toObject} method.
- * @param {boolean|undefined} includeInstance Deprecated. Whether to include
- *     the JSPB instance for transitional soy proto support:
- *     http://goto/soy-param-migration
- * @param {!proto.org.dash.platform.dapi.v0.GetConsensusParamsRequest} msg The msg instance to transform.
- * @return {!Object}
- * @suppress {unusedLocalVariables} f is only used for nested messages
+ * @param {?proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.RemoveFromBalance|undefined} value
+ * @return {!proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange} returns this
+*/
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.prototype.setRemoveFromBalance = function(value) {
+  return jspb.Message.setOneofWrapperField(this, 2, proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.oneofGroups_[0], value);
+};
+
+
+/**
+ * Clears the message field making it undefined.
+ * @return {!proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange} returns this
  */
-proto.org.dash.platform.dapi.v0.GetConsensusParamsRequest.toObject = function(includeInstance, msg) {
-  var f, obj = {
-    height: jspb.Message.getFieldWithDefault(msg, 1, 0),
-    prove: jspb.Message.getBooleanFieldWithDefault(msg, 2, false)
-  };
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.prototype.clearRemoveFromBalance = function() {
+  return this.setRemoveFromBalance(undefined);
+};
 
-  if (includeInstance) {
-    obj.$jspbMessageInstance = msg;
-  }
-  return obj;
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.prototype.hasRemoveFromBalance = function() {
+  return jspb.Message.getField(this, 2) != null;
 };
-}
 
 
 /**
- * Deserializes binary data (in protobuf wire format).
- * @param {jspb.ByteSource} bytes The bytes to deserialize.
- * @return {!proto.org.dash.platform.dapi.v0.GetConsensusParamsRequest}
+ * optional bool no_balance_change = 3;
+ * @return {boolean}
  */
-proto.org.dash.platform.dapi.v0.GetConsensusParamsRequest.deserializeBinary = function(bytes) {
-  var reader = new jspb.BinaryReader(bytes);
-  var msg = new proto.org.dash.platform.dapi.v0.GetConsensusParamsRequest;
-  return proto.org.dash.platform.dapi.v0.GetConsensusParamsRequest.deserializeBinaryFromReader(msg, reader);
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.prototype.getNoBalanceChange = function() {
+  return /** @type {boolean} */ (jspb.Message.getBooleanFieldWithDefault(this, 3, false));
 };
 
 
 /**
- * Deserializes binary data (in protobuf wire format) from the
- * given reader into the given message object.
- * @param {!proto.org.dash.platform.dapi.v0.GetConsensusParamsRequest} msg The message object to deserialize into.
- * @param {!jspb.BinaryReader} reader The BinaryReader to use.
- * @return {!proto.org.dash.platform.dapi.v0.GetConsensusParamsRequest}
+ * @param {boolean} value
+ * @return {!proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange} returns this
  */
-proto.org.dash.platform.dapi.v0.GetConsensusParamsRequest.deserializeBinaryFromReader = function(msg, reader) {
-  while (reader.nextField()) {
-    if (reader.isEndGroup()) {
-      break;
-    }
-    var field = reader.getFieldNumber();
-    switch (field) {
-    case 1:
-      var value = /** @type {number} */ (reader.readInt64());
-      msg.setHeight(value);
-      break;
-    case 2:
-      var value = /** @type {boolean} */ (reader.readBool());
-      msg.setProve(value);
-      break;
-    default:
-      reader.skipField();
-      break;
-    }
-  }
-  return msg;
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.prototype.setNoBalanceChange = function(value) {
+  return jspb.Message.setOneofField(this, 3, proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.oneofGroups_[0], value);
 };
 
 
 /**
- * Serializes the message to binary data (in protobuf wire format).
- * @return {!Uint8Array}
+ * Clears the field making it undefined.
+ * @return {!proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange} returns this
  */
-proto.org.dash.platform.dapi.v0.GetConsensusParamsRequest.prototype.serializeBinary = function() {
-  var writer = new jspb.BinaryWriter();
-  proto.org.dash.platform.dapi.v0.GetConsensusParamsRequest.serializeBinaryToWriter(this, writer);
-  return writer.getResultBuffer();
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.prototype.clearNoBalanceChange = function() {
+  return jspb.Message.setOneofField(this, 3, proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.oneofGroups_[0], undefined);
 };
 
 
 /**
- * Serializes the given message to binary data (in protobuf wire
- * format), writing to the given BinaryWriter.
- * @param {!proto.org.dash.platform.dapi.v0.GetConsensusParamsRequest} message
- * @param {!jspb.BinaryWriter} writer
- * @suppress {unusedLocalVariables} f is only used for nested messages
+ * Returns whether this field is set.
+ * @return {boolean}
  */
-proto.org.dash.platform.dapi.v0.GetConsensusParamsRequest.serializeBinaryToWriter = function(message, writer) {
-  var f = undefined;
-  f = message.getHeight();
-  if (f !== 0) {
-    writer.writeInt64(
-      1,
-      f
-    );
-  }
-  f = message.getProve();
-  if (f) {
-    writer.writeBool(
-      2,
-      f
-    );
-  }
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange.prototype.hasNoBalanceChange = function() {
+  return jspb.Message.getField(this, 3) != null;
 };
 
 
 /**
- * optional int64 height = 1;
+ * optional uint64 storage_fee = 1;
  * @return {number}
  */
-proto.org.dash.platform.dapi.v0.GetConsensusParamsRequest.prototype.getHeight = function() {
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.prototype.getStorageFee = function() {
   return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 1, 0));
 };
 
 
 /**
  * @param {number} value
- * @return {!proto.org.dash.platform.dapi.v0.GetConsensusParamsRequest} returns this
+ * @return {!proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate} returns this
  */
-proto.org.dash.platform.dapi.v0.GetConsensusParamsRequest.prototype.setHeight = function(value) {
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.prototype.setStorageFee = function(value) {
   return jspb.Message.setProto3IntField(this, 1, value);
 };
 
 
 /**
- * optional bool prove = 2;
- * @return {boolean}
+ * optional uint64 processing_fee = 2;
+ * @return {number}
  */
-proto.org.dash.platform.dapi.v0.GetConsensusParamsRequest.prototype.getProve = function() {
-  return /** @type {boolean} */ (jspb.Message.getBooleanFieldWithDefault(this, 2, false));
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.prototype.getProcessingFee = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 2, 0));
 };
 
 
 /**
- * @param {boolean} value
- * @return {!proto.org.dash.platform.dapi.v0.GetConsensusParamsRequest} returns this
+ * @param {number} value
+ * @return {!proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate} returns this
  */
-proto.org.dash.platform.dapi.v0.GetConsensusParamsRequest.prototype.setProve = function(value) {
-  return jspb.Message.setProto3BooleanField(this, 2, value);
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.prototype.setProcessingFee = function(value) {
+  return jspb.Message.setProto3IntField(this, 2, value);
 };
 
 
+/**
+ * repeated Refund refunds = 3;
+ * @return {!Array<!proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.Refund>}
+ */
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.prototype.getRefundsList = function() {
+  return /** @type{!Array<!proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.Refund>} */ (
+    jspb.Message.getRepeatedWrapperField(this, proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.Refund, 3));
+};
+
 
+/**
+ * @param {!Array<!proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.Refund>} value
+ * @return {!proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate} returns this
+*/
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.prototype.setRefundsList = function(value) {
+  return jspb.Message.setRepeatedWrapperField(this, 3, value);
+};
 
 
-if (jspb.Message.GENERATE_TO_OBJECT) {
 /**
- * Creates an object representation of this proto.
- * Field names that are reserved in JavaScript and will be renamed to pb_name.
- * Optional fields that are not set will be set to undefined.
- * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
- * For the list of reserved names please see:
- *     net/proto2/compiler/js/internal/generator.cc#kKeyword.
- * @param {boolean=} opt_includeInstance Deprecated. whether to include the
- *     JSPB instance for transitional soy proto support:
- *     http://goto/soy-param-migration
- * @return {!Object}
+ * @param {!proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.Refund=} opt_value
+ * @param {number=} opt_index
+ * @return {!proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.Refund}
  */
-proto.org.dash.platform.dapi.v0.GetConsensusParamsResponse.prototype.toObject = function(opt_includeInstance) {
-  return proto.org.dash.platform.dapi.v0.GetConsensusParamsResponse.toObject(opt_includeInstance, this);
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.prototype.addRefunds = function(opt_value, opt_index) {
+  return jspb.Message.addToRepeatedWrapperField(this, 3, opt_value, proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.Refund, opt_index);
 };
 
 
 /**
- * Static version of the {@see toObject} method.
- * @param {boolean|undefined} includeInstance Deprecated. Whether to include
- *     the JSPB instance for transitional soy proto support:
- *     http://goto/soy-param-migration
- * @param {!proto.org.dash.platform.dapi.v0.GetConsensusParamsResponse} msg The msg instance to transform.
- * @return {!Object}
- * @suppress {unusedLocalVariables} f is only used for nested messages
+ * Clears the list making it empty but non-null.
+ * @return {!proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate} returns this
  */
-proto.org.dash.platform.dapi.v0.GetConsensusParamsResponse.toObject = function(includeInstance, msg) {
-  var f, obj = {
-    block: (f = msg.getBlock()) && proto.org.dash.platform.dapi.v0.ConsensusParamsBlock.toObject(includeInstance, f),
-    evidence: (f = msg.getEvidence()) && proto.org.dash.platform.dapi.v0.ConsensusParamsEvidence.toObject(includeInstance, f)
-  };
-
-  if (includeInstance) {
-    obj.$jspbMessageInstance = msg;
-  }
-  return obj;
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.prototype.clearRefundsList = function() {
+  return this.setRefundsList([]);
 };
-}
 
 
 /**
- * Deserializes binary data (in protobuf wire format).
- * @param {jspb.ByteSource} bytes The bytes to deserialize.
- * @return {!proto.org.dash.platform.dapi.v0.GetConsensusParamsResponse}
+ * optional BalanceChange balance_change = 4;
+ * @return {?proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange}
  */
-proto.org.dash.platform.dapi.v0.GetConsensusParamsResponse.deserializeBinary = function(bytes) {
-  var reader = new jspb.BinaryReader(bytes);
-  var msg = new proto.org.dash.platform.dapi.v0.GetConsensusParamsResponse;
-  return proto.org.dash.platform.dapi.v0.GetConsensusParamsResponse.deserializeBinaryFromReader(msg, reader);
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.prototype.getBalanceChange = function() {
+  return /** @type{?proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange} */ (
+    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange, 4));
 };
 
 
 /**
- * Deserializes binary data (in protobuf wire format) from the
- * given reader into the given message object.
- * @param {!proto.org.dash.platform.dapi.v0.GetConsensusParamsResponse} msg The message object to deserialize into.
- * @param {!jspb.BinaryReader} reader The BinaryReader to use.
- * @return {!proto.org.dash.platform.dapi.v0.GetConsensusParamsResponse}
- */
-proto.org.dash.platform.dapi.v0.GetConsensusParamsResponse.deserializeBinaryFromReader = function(msg, reader) {
-  while (reader.nextField()) {
-    if (reader.isEndGroup()) {
-      break;
-    }
-    var field = reader.getFieldNumber();
-    switch (field) {
-    case 1:
-      var value = new proto.org.dash.platform.dapi.v0.ConsensusParamsBlock;
-      reader.readMessage(value,proto.org.dash.platform.dapi.v0.ConsensusParamsBlock.deserializeBinaryFromReader);
-      msg.setBlock(value);
-      break;
-    case 2:
-      var value = new proto.org.dash.platform.dapi.v0.ConsensusParamsEvidence;
-      reader.readMessage(value,proto.org.dash.platform.dapi.v0.ConsensusParamsEvidence.deserializeBinaryFromReader);
-      msg.setEvidence(value);
-      break;
-    default:
-      reader.skipField();
-      break;
-    }
-  }
-  return msg;
+ * @param {?proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.BalanceChange|undefined} value
+ * @return {!proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate} returns this
+*/
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.prototype.setBalanceChange = function(value) {
+  return jspb.Message.setWrapperField(this, 4, value);
 };
 
 
 /**
- * Serializes the message to binary data (in protobuf wire format).
- * @return {!Uint8Array}
+ * Clears the message field making it undefined.
+ * @return {!proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate} returns this
  */
-proto.org.dash.platform.dapi.v0.GetConsensusParamsResponse.prototype.serializeBinary = function() {
-  var writer = new jspb.BinaryWriter();
-  proto.org.dash.platform.dapi.v0.GetConsensusParamsResponse.serializeBinaryToWriter(this, writer);
-  return writer.getResultBuffer();
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.prototype.clearBalanceChange = function() {
+  return this.setBalanceChange(undefined);
 };
 
 
 /**
- * Serializes the given message to binary data (in protobuf wire
- * format), writing to the given BinaryWriter.
- * @param {!proto.org.dash.platform.dapi.v0.GetConsensusParamsResponse} message
- * @param {!jspb.BinaryWriter} writer
- * @suppress {unusedLocalVariables} f is only used for nested messages
+ * Returns whether this field is set.
+ * @return {boolean}
  */
-proto.org.dash.platform.dapi.v0.GetConsensusParamsResponse.serializeBinaryToWriter = function(message, writer) {
-  var f = undefined;
-  f = message.getBlock();
-  if (f != null) {
-    writer.writeMessage(
-      1,
-      f,
-      proto.org.dash.platform.dapi.v0.ConsensusParamsBlock.serializeBinaryToWriter
-    );
-  }
-  f = message.getEvidence();
-  if (f != null) {
-    writer.writeMessage(
-      2,
-      f,
-      proto.org.dash.platform.dapi.v0.ConsensusParamsEvidence.serializeBinaryToWriter
-    );
-  }
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate.prototype.hasBalanceChange = function() {
+  return jspb.Message.getField(this, 4) != null;
 };
 
 
 /**
- * optional ConsensusParamsBlock block = 1;
- * @return {?proto.org.dash.platform.dapi.v0.ConsensusParamsBlock}
+ * optional FeeEstimate fee_estimate = 1;
+ * @return {?proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate}
  */
-proto.org.dash.platform.dapi.v0.GetConsensusParamsResponse.prototype.getBlock = function() {
-  return /** @type{?proto.org.dash.platform.dapi.v0.ConsensusParamsBlock} */ (
-    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.ConsensusParamsBlock, 1));
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.prototype.getFeeEstimate = function() {
+  return /** @type{?proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate} */ (
+    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate, 1));
 };
 
 
 /**
- * @param {?proto.org.dash.platform.dapi.v0.ConsensusParamsBlock|undefined} value
- * @return {!proto.org.dash.platform.dapi.v0.GetConsensusParamsResponse} returns this
+ * @param {?proto.org.dash.platform.dapi.v0.EstimateFeeResponse.FeeEstimate|undefined} value
+ * @return {!proto.org.dash.platform.dapi.v0.EstimateFeeResponse} returns this
 */
-proto.org.dash.platform.dapi.v0.GetConsensusParamsResponse.prototype.setBlock = function(value) {
-  return jspb.Message.setWrapperField(this, 1, value);
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.prototype.setFeeEstimate = function(value) {
+  return jspb.Message.setOneofWrapperField(this, 1, proto.org.dash.platform.dapi.v0.EstimateFeeResponse.oneofGroups_[0], value);
 };
 
 
 /**
  * Clears the message field making it undefined.
- * @return {!proto.org.dash.platform.dapi.v0.GetConsensusParamsResponse} returns this
+ * @return {!proto.org.dash.platform.dapi.v0.EstimateFeeResponse} returns this
  */
-proto.org.dash.platform.dapi.v0.GetConsensusParamsResponse.prototype.clearBlock = function() {
-  return this.setBlock(undefined);
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.prototype.clearFeeEstimate = function() {
+  return this.setFeeEstimate(undefined);
 };
 
 
@@ -14249,36 +17720,36 @@ proto.org.dash.platform.dapi.v0.GetConsensusParamsResponse.prototype.clearBlock
  * Returns whether this field is set.
  * @return {boolean}
  */
-proto.org.dash.platform.dapi.v0.GetConsensusParamsResponse.prototype.hasBlock = function() {
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.prototype.hasFeeEstimate = function() {
   return jspb.Message.getField(this, 1) != null;
 };
 
 
 /**
- * optional ConsensusParamsEvidence evidence = 2;
- * @return {?proto.org.dash.platform.dapi.v0.ConsensusParamsEvidence}
+ * optional StateTransitionBroadcastError error = 2;
+ * @return {?proto.org.dash.platform.dapi.v0.StateTransitionBroadcastError}
  */
-proto.org.dash.platform.dapi.v0.GetConsensusParamsResponse.prototype.getEvidence = function() {
-  return /** @type{?proto.org.dash.platform.dapi.v0.ConsensusParamsEvidence} */ (
-    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.ConsensusParamsEvidence, 2));
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.prototype.getError = function() {
+  return /** @type{?proto.org.dash.platform.dapi.v0.StateTransitionBroadcastError} */ (
+    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.StateTransitionBroadcastError, 2));
 };
 
 
 /**
- * @param {?proto.org.dash.platform.dapi.v0.ConsensusParamsEvidence|undefined} value
- * @return {!proto.org.dash.platform.dapi.v0.GetConsensusParamsResponse} returns this
+ * @param {?proto.org.dash.platform.dapi.v0.StateTransitionBroadcastError|undefined} value
+ * @return {!proto.org.dash.platform.dapi.v0.EstimateFeeResponse} returns this
 */
-proto.org.dash.platform.dapi.v0.GetConsensusParamsResponse.prototype.setEvidence = function(value) {
-  return jspb.Message.setWrapperField(this, 2, value);
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.prototype.setError = function(value) {
+  return jspb.Message.setOneofWrapperField(this, 2, proto.org.dash.platform.dapi.v0.EstimateFeeResponse.oneofGroups_[0], value);
 };
 
 
 /**
  * Clears the message field making it undefined.
- * @return {!proto.org.dash.platform.dapi.v0.GetConsensusParamsResponse} returns this
+ * @return {!proto.org.dash.platform.dapi.v0.EstimateFeeResponse} returns this
  */
-proto.org.dash.platform.dapi.v0.GetConsensusParamsResponse.prototype.clearEvidence = function() {
-  return this.setEvidence(undefined);
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.prototype.clearError = function() {
+  return this.setError(undefined);
 };
 
 
@@ -14286,9 +17757,46 @@ proto.org.dash.platform.dapi.v0.GetConsensusParamsResponse.prototype.clearEviden
  * Returns whether this field is set.
  * @return {boolean}
  */
-proto.org.dash.platform.dapi.v0.GetConsensusParamsResponse.prototype.hasEvidence = function() {
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.prototype.hasError = function() {
   return jspb.Message.getField(this, 2) != null;
 };
 
 
+/**
+ * optional ResponseMetadata metadata = 3;
+ * @return {?proto.org.dash.platform.dapi.v0.ResponseMetadata}
+ */
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.prototype.getMetadata = function() {
+  return /** @type{?proto.org.dash.platform.dapi.v0.ResponseMetadata} */ (
+    jspb.Message.getWrapperField(this, proto.org.dash.platform.dapi.v0.ResponseMetadata, 3));
+};
+
+
+/**
+ * @param {?proto.org.dash.platform.dapi.v0.ResponseMetadata|undefined} value
+ * @return {!proto.org.dash.platform.dapi.v0.EstimateFeeResponse} returns this
+*/
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.prototype.setMetadata = function(value) {
+  return jspb.Message.setWrapperField(this, 3, value);
+};
+
+
+/**
+ * Clears the message field making it undefined.
+ * @return {!proto.org.dash.platform.dapi.v0.EstimateFeeResponse} returns this
+ */
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.prototype.clearMetadata = function() {
+  return this.setMetadata(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.org.dash.platform.dapi.v0.EstimateFeeResponse.prototype.hasMetadata = function() {
+  return jspb.Message.getField(this, 3) != null;
+};
+
+
 goog.object.extend(exports, proto.org.dash.platform.dapi.v0);
@@ -58,6 +58,24 @@ type PlatformgetIdentityBalanceAndRevision = {
   readonly responseType: typeof platform_pb.GetIdentityBalanceAndRevisionResponse;
 };
 
+type PlatformgetIdentityNonce = {
+  readonly methodName: string;
+  readonly service: typeof Platform;
+  readonly requestStream: false;
+  readonly responseStream: false;
+  readonly requestType: typeof platform_pb.GetIdentityRequest;
+  readonly responseType: typeof platform_pb.GetIdentityNonceResponse;
+};
+
+type PlatformgetIdentityContractNonce = {
+  readonly methodName: string;
+  readonly service: typeof Platform;
+  readonly requestStream: false;
+  readonly responseStream: false;
+  readonly requestType: typeof platform_pb.GetIdentityContractNonceRequest;
+  readonly responseType: typeof platform_pb.GetIdentityContractNonceResponse;
+};
+
 type PlatformgetProofs = {
   readonly methodName: string;
   readonly service: typeof Platform;
@@ -139,6 +157,24 @@ type PlatformgetConsensusParams = {
   readonly responseType: typeof platform_pb.GetConsensusParamsResponse;
 };
 
+type PlatformgetBlockAttestation = {
+  readonly methodName: string;
+  readonly service: typeof Platform;
+  readonly requestStream: false;
+  readonly responseStream: false;
+  readonly requestType: typeof platform_pb.GetBlockAttestationRequest;
+  readonly responseType: typeof platform_pb.GetBlockAttestationResponse;
+};
+
+type PlatformestimateFee = {
+  readonly methodName: string;
+  readonly service: typeof Platform;
+  readonly requestStream: false;
+  readonly responseStream: false;
+  readonly requestType: typeof platform_pb.EstimateFeeRequest;
+  readonly responseType: typeof platform_pb.EstimateFeeResponse;
+};
+
 export class Platform {
   static readonly serviceName: string;
   static readonly broadcastStateTransition: PlatformbroadcastStateTransition;
@@ -147,6 +183,8 @@ export class Platform {
   static readonly getIdentityKeys: PlatformgetIdentityKeys;
   static readonly getIdentityBalance: PlatformgetIdentityBalance;
   static readonly getIdentityBalanceAndRevision: PlatformgetIdentityBalanceAndRevision;
+  static readonly getIdentityNonce: PlatformgetIdentityNonce;
+  static readonly getIdentityContractNonce: PlatformgetIdentityContractNonce;
   static readonly getProofs: PlatformgetProofs;
   static readonly getDataContract: PlatformgetDataContract;
   static readonly getDataContractHistory: PlatformgetDataContractHistory;
@@ -156,6 +194,8 @@ export class Platform {
   static readonly getIdentityByPublicKeyHashes: PlatformgetIdentityByPublicKeyHashes;
   static readonly waitForStateTransitionResult: PlatformwaitForStateTransitionResult;
   static readonly getConsensusParams: PlatformgetConsensusParams;
+  static readonly getBlockAttestation: PlatformgetBlockAttestation;
+  static readonly estimateFee: PlatformestimateFee;
 }
 
 export type ServiceError = { message: string, code: number; metadata: grpc.Metadata }
@@ -244,6 +284,24 @@ export class PlatformClient {
     requestMessage: platform_pb.GetIdentityRequest,
     callback: (error: ServiceError|null, responseMessage: platform_pb.GetIdentityBalanceAndRevisionResponse|null) => void
   ): UnaryResponse;
+  getIdentityNonce(
+    requestMessage: platform_pb.GetIdentityRequest,
+    metadata: grpc.Metadata,
+    callback: (error: ServiceError|null, responseMessage: platform_pb.GetIdentityNonceResponse|null) => void
+  ): UnaryResponse;
+  getIdentityNonce(
+    requestMessage: platform_pb.GetIdentityRequest,
+    callback: (error: ServiceError|null, responseMessage: platform_pb.GetIdentityNonceResponse|null) => void
+  ): UnaryResponse;
+  getIdentityContractNonce(
+    requestMessage: platform_pb.GetIdentityContractNonceRequest,
+    metadata: grpc.Metadata,
+    callback: (error: ServiceError|null, responseMessage: platform_pb.GetIdentityContractNonceResponse|null) => void
+  ): UnaryResponse;
+  getIdentityContractNonce(
+    requestMessage: platform_pb.GetIdentityContractNonceRequest,
+    callback: (error: ServiceError|null, responseMessage: platform_pb.GetIdentityContractNonceResponse|null) => void
+  ): UnaryResponse;
   getProofs(
     requestMessage: platform_pb.GetProofsRequest,
     metadata: grpc.Metadata,
@@ -325,5 +383,23 @@ export class PlatformClient {
     requestMessage: platform_pb.GetConsensusParamsRequest,
     callback: (error: ServiceError|null, responseMessage: platform_pb.GetConsensusParamsResponse|null) => void
   ): UnaryResponse;
+  getBlockAttestation(
+    requestMessage: platform_pb.GetBlockAttestationRequest,
+    metadata: grpc.Metadata,
+    callback: (error: ServiceError|null, responseMessage: platform_pb.GetBlockAttestationResponse|null) => void
+  ): UnaryResponse;
+  getBlockAttestation(
+    requestMessage: platform_pb.GetBlockAttestationRequest,
+    callback: (error: ServiceError|null, responseMessage: platform_pb.GetBlockAttestationResponse|null) => void
+  ): UnaryResponse;
+  estimateFee(
+    requestMessage: platform_pb.EstimateFeeRequest,
+    metadata: grpc.Metadata,
+    callback: (error: ServiceError|null, responseMessage: platform_pb.EstimateFeeResponse|null) => void
+  ): UnaryResponse;
+  estimateFee(
+    requestMessage: platform_pb.EstimateFeeRequest,
+    callback: (error: ServiceError|null, responseMessage: platform_pb.EstimateFeeResponse|null) => void
+  ): UnaryResponse;
 }
 
@@ -64,6 +64,24 @@ Platform.getIdentityBalanceAndRevision = {
   responseType: platform_pb.GetIdentityBalanceAndRevisionResponse
 };
 
+Platform.getIdentityNonce = {
+  methodName: "getIdentityNonce",
+  service: Platform,
+  requestStream: false,
+  responseStream: false,
+  requestType: platform_pb.GetIdentityRequest,
+  responseType: platform_pb.GetIdentityNonceResponse
+};
+
+Platform.getIdentityContractNonce = {
+  methodName: "getIdentityContractNonce",
+  service: Platform,
+  requestStream: false,
+  responseStream: false,
+  requestType: platform_pb.GetIdentityContractNonceRequest,
+  responseType: platform_pb.GetIdentityContractNonceResponse
+};
+
 Platform.getProofs = {
   methodName: "getProofs",
   service: Platform,
@@ -145,6 +163,24 @@ Platform.getConsensusParams = {
   responseType: platform_pb.GetConsensusParamsResponse
 };
 
+Platform.getBlockAttestation = {
+  methodName: "getBlockAttestation",
+  service: Platform,
+  requestStream: false,
+  responseStream: false,
+  requestType: platform_pb.GetBlockAttestationRequest,
+  responseType: platform_pb.GetBlockAttestationResponse
+};
+
+Platform.estimateFee = {
+  methodName: "estimateFee",
+  service: Platform,
+  requestStream: false,
+  responseStream: false,
+  requestType: platform_pb.EstimateFeeRequest,
+  responseType: platform_pb.EstimateFeeResponse
+};
+
 exports.Platform = Platform;
 
 function PlatformClient(serviceHost, options) {
@@ -338,6 +374,68 @@ PlatformClient.prototype.getIdentityBalanceAndRevision = function getIdentityBal
   };
 };
 
+PlatformClient.prototype.getIdentityNonce = function getIdentityNonce(requestMessage, metadata, callback) {
+  if (arguments.length === 2) {
+    callback = arguments[1];
+  }
+  var client = grpc.unary(Platform.getIdentityNonce, {
+    request: requestMessage,
+    host: this.serviceHost,
+    metadata: metadata,
+    transport: this.options.transport,
+    debug: this.options.debug,
+    onEnd: function (response) {
+      if (callback) {
+        if (response.status !== grpc.Code.OK) {
+          var err = new Error(response.statusMessage);
+          err.code = response.status;
+          err.metadata = response.trailers;
+          callback(err, null);
+        } else {
+          callback(null, response.message);
+        }
+      }
+    }
+  });
+  return {
+    cancel: function () {
+      callback = null;
+      client.close();
+    }
+  };
+};
+
+PlatformClient.prototype.getIdentityContractNonce = function getIdentityContractNonce(requestMessage, metadata, callback) {
+  if (arguments.length === 2) {
+    callback = arguments[1];
+  }
+  var client = grpc.unary(Platform.getIdentityContractNonce, {
+    request: requestMessage,
+    host: this.serviceHost,
+    metadata: metadata,
+    transport: this.options.transport,
+    debug: this.options.debug,
+    onEnd: function (response) {
+      if (callback) {
+        if (response.status !== grpc.Code.OK) {
+          var err = new Error(response.statusMessage);
+          err.code = response.status;
+          err.metadata = response.trailers;
+          callback(err, null);
+        } else {
+          callback(null, response.message);
+        }
+      }
+    }
+  });
+  return {
+    cancel: function () {
+      callback = null;
+      client.close();
+    }
+  };
+};
+
 PlatformClient.prototype.getProofs = function getProofs(requestMessage, metadata, callback) {
   if (arguments.length === 2) {
     callback = arguments[1];
@@ -617,5 +715,67 @@ PlatformClient.prototype.getConsensusParams = function getConsensusParams(reques
   };
 };
 
+PlatformClient.prototype.getBlockAttestation = function getBlockAttestation(requestMessage, metadata, callback) {
+  if (arguments.length === 2) {
+    callback = arguments[1];
+  }
+  var client = grpc.unary(Platform.getBlockAttestation, {
+    request: requestMessage,
+    host: this.serviceHost,
+    metadata: metadata,
+    transport: this.options.transport,
+    debug: this.options.debug,
+    onEnd: function (response) {
+      if (callback) {
+        if (response.status !== grpc.Code.OK) {
+          var err = new Error(response.statusMessage);
+          err.code = response.status;
+          err.metadata = response.trailers;
+          callback(err, null);
+        } else {
+          callback(null, response.message);
+        }
+      }
+    }
+  });
+  return {
+    cancel: function () {
+      callback = null;
+      client.close();
+    }
+  };
+};
+
+PlatformClient.prototype.estimateFee = function estimateFee(requestMessage, metadata, callback) {
+  if (arguments.length === 2) {
+    callback = arguments[1];
+  }
+  var client = grpc.unary(Platform.estimateFee, {
+    request: requestMessage,
+    host: this.serviceHost,
+    metadata: metadata,
+    transport: this.options.transport,
+    debug: this.options.debug,
+    onEnd: function (response) {
+      if (callback) {
+        if (response.status !== grpc.Code.OK) {
+          var err = new Error(response.statusMessage);
+          err.code = response.status;
+          err.metadata = response.trailers;
+          callback(err, null);
+        } else {
+          callback(null, response.message);
+        }
+      }
+    }
+  });
+  return {
+    cancel: function () {
+      callback = null;
+      client.close();
+    }
+  };
+};
+
 exports.PlatformClient = PlatformClient;
 
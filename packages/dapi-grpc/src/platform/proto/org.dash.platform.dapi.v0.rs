@@ -171,6 +171,54 @@ pub mod get_identity_balance_and_revision_response {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetIdentityNonceResponse {
+    #[prost(message, optional, tag = "3")]
+    pub metadata: ::core::option::Option<ResponseMetadata>,
+    #[prost(oneof = "get_identity_nonce_response::Result", tags = "1, 2")]
+    pub result: ::core::option::Option<get_identity_nonce_response::Result>,
+}
+/// Nested message and enum types in `GetIdentityNonceResponse`.
+pub mod get_identity_nonce_response {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Result {
+        #[prost(message, tag = "1")]
+        Nonce(u64),
+        #[prost(message, tag = "2")]
+        Proof(super::Proof),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetIdentityContractNonceRequest {
+    #[prost(bytes = "vec", tag = "1")]
+    pub identity_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub contract_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bool, tag = "3")]
+    pub prove: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetIdentityContractNonceResponse {
+    #[prost(message, optional, tag = "3")]
+    pub metadata: ::core::option::Option<ResponseMetadata>,
+    #[prost(oneof = "get_identity_contract_nonce_response::Result", tags = "1, 2")]
+    pub result: ::core::option::Option<get_identity_contract_nonce_response::Result>,
+}
+/// Nested message and enum types in `GetIdentityContractNonceResponse`.
+pub mod get_identity_contract_nonce_response {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Result {
+        #[prost(message, tag = "1")]
+        Nonce(u64),
+        #[prost(message, tag = "2")]
+        Proof(super::Proof),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct KeyRequestType {
     #[prost(oneof = "key_request_type::Request", tags = "1, 2, 3")]
     pub request: ::core::option::Option<key_request_type::Request>,
@@ -739,13 +787,26 @@ pub struct WaitForStateTransitionResultRequest {
 pub struct WaitForStateTransitionResultResponse {
     #[prost(message, optional, tag = "3")]
     pub metadata: ::core::option::Option<ResponseMetadata>,
-    #[prost(oneof = "wait_for_state_transition_result_response::Result", tags = "1, 2")]
+    #[prost(
+        oneof = "wait_for_state_transition_result_response::Result",
+        tags = "1, 2, 4"
+    )]
     pub result: ::core::option::Option<
         wait_for_state_transition_result_response::Result,
     >,
 }
 /// Nested message and enum types in `WaitForStateTransitionResultResponse`.
 pub mod wait_for_state_transition_result_response {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct StateTransitionResult {
+        #[prost(uint64, tag = "1")]
+        pub height: u64,
+        #[prost(uint64, tag = "2")]
+        pub storage_fee: u64,
+        #[prost(uint64, tag = "3")]
+        pub processing_fee: u64,
+    }
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Oneof)]
     pub enum Result {
@@ -753,6 +814,8 @@ pub mod wait_for_state_transition_result_response {
         Error(super::StateTransitionBroadcastError),
         #[prost(message, tag = "2")]
         Proof(super::Proof),
+        #[prost(message, tag = "4")]
+        StateTransitionResult(StateTransitionResult),
     }
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -791,6 +854,121 @@ pub struct GetConsensusParamsResponse {
     #[prost(message, optional, tag = "2")]
     pub evidence: ::core::option::Option<ConsensusParamsEvidence>,
 }
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetBlockAttestationRequest {
+    /// Height of the attested block, 0 for the last attested one
+    #[prost(uint64, tag = "1")]
+    pub height: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetBlockAttestationResponse {
+    #[prost(message, optional, tag = "1")]
+    pub block_attestation: ::core::option::Option<
+        get_block_attestation_response::BlockAttestation,
+    >,
+    #[prost(message, optional, tag = "2")]
+    pub metadata: ::core::option::Option<ResponseMetadata>,
+}
+/// Nested message and enum types in `GetBlockAttestationResponse`.
+pub mod get_block_attestation_response {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct BlockAttestation {
+        #[prost(uint64, tag = "1")]
+        pub height: u64,
+        #[prost(bytes = "vec", tag = "2")]
+        pub app_hash: ::prost::alloc::vec::Vec<u8>,
+        #[prost(uint32, tag = "3")]
+        pub epoch: u32,
+        #[prost(uint32, tag = "4")]
+        pub protocol_version: u32,
+        #[prost(bytes = "vec", tag = "5")]
+        pub quorum_hash: ::prost::alloc::vec::Vec<u8>,
+        #[prost(uint32, tag = "6")]
+        pub quorum_type: u32,
+        #[prost(uint32, tag = "7")]
+        pub round: u32,
+        #[prost(bytes = "vec", tag = "8")]
+        pub signature: ::prost::alloc::vec::Vec<u8>,
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EstimateFeeRequest {
+    #[prost(bytes = "vec", tag = "1")]
+    pub state_transition: ::prost::alloc::vec::Vec<u8>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EstimateFeeResponse {
+    #[prost(message, optional, tag = "3")]
+    pub metadata: ::core::option::Option<ResponseMetadata>,
+    #[prost(oneof = "estimate_fee_response::Result", tags = "1, 2")]
+    pub result: ::core::option::Option<estimate_fee_response::Result>,
+}
+/// Nested message and enum types in `EstimateFeeResponse`.
+pub mod estimate_fee_response {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct FeeEstimate {
+        #[prost(uint64, tag = "1")]
+        pub storage_fee: u64,
+        #[prost(uint64, tag = "2")]
+        pub processing_fee: u64,
+        #[prost(message, repeated, tag = "3")]
+        pub refunds: ::prost::alloc::vec::Vec<fee_estimate::Refund>,
+        #[prost(message, optional, tag = "4")]
+        pub balance_change: ::core::option::Option<fee_estimate::BalanceChange>,
+    }
+    /// Nested message and enum types in `FeeEstimate`.
+    pub mod fee_estimate {
+        #[allow(clippy::derive_partial_eq_without_eq)]
+        #[derive(Clone, PartialEq, ::prost::Message)]
+        pub struct Refund {
+            #[prost(bytes = "vec", tag = "1")]
+            pub identity_id: ::prost::alloc::vec::Vec<u8>,
+            #[prost(uint64, tag = "2")]
+            pub credits: u64,
+        }
+        #[allow(clippy::derive_partial_eq_without_eq)]
+        #[derive(Clone, PartialEq, ::prost::Message)]
+        pub struct BalanceChange {
+            #[prost(oneof = "balance_change::Change", tags = "1, 2, 3")]
+            pub change: ::core::option::Option<balance_change::Change>,
+        }
+        /// Nested message and enum types in `BalanceChange`.
+        pub mod balance_change {
+            #[allow(clippy::derive_partial_eq_without_eq)]
+            #[derive(Clone, PartialEq, ::prost::Message)]
+            pub struct RemoveFromBalance {
+                #[prost(uint64, tag = "1")]
+                pub required_removed_balance: u64,
+                #[prost(uint64, tag = "2")]
+                pub desired_removed_balance: u64,
+            }
+            #[allow(clippy::derive_partial_eq_without_eq)]
+            #[derive(Clone, PartialEq, ::prost::Oneof)]
+            pub enum Change {
+                #[prost(uint64, tag = "1")]
+                AddToBalance(u64),
+                #[prost(message, tag = "2")]
+                RemoveFromBalance(RemoveFromBalance),
+                #[prost(bool, tag = "3")]
+                NoBalanceChange(bool),
+            }
+        }
+    }
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Result {
+        #[prost(message, tag = "1")]
+        FeeEstimate(FeeEstimate),
+        #[prost(message, tag = "2")]
+        Error(super::StateTransitionBroadcastError),
+    }
+}
 /// Generated client implementations.
 pub mod platform_client {
     #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
@@ -1054,6 +1232,66 @@ pub mod platform_client {
                 );
             self.inner.unary(req, path, codec).await
         }
+        pub async fn get_identity_nonce(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetIdentityRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetIdentityNonceResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/org.dash.platform.dapi.v0.Platform/getIdentityNonce",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "org.dash.platform.dapi.v0.Platform",
+                        "getIdentityNonce",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_identity_contract_nonce(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetIdentityContractNonceRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetIdentityContractNonceResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/org.dash.platform.dapi.v0.Platform/getIdentityContractNonce",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "org.dash.platform.dapi.v0.Platform",
+                        "getIdentityContractNonce",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
         pub async fn get_proofs(
             &mut self,
             request: impl tonic::IntoRequest<super::GetProofsRequest>,
@@ -1320,5 +1558,62 @@ pub mod platform_client {
                 );
             self.inner.unary(req, path, codec).await
         }
+        pub async fn get_block_attestation(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetBlockAttestationRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetBlockAttestationResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/org.dash.platform.dapi.v0.Platform/getBlockAttestation",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "org.dash.platform.dapi.v0.Platform",
+                        "getBlockAttestation",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn estimate_fee(
+            &mut self,
+            request: impl tonic::IntoRequest<super::EstimateFeeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::EstimateFeeResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/org.dash.platform.dapi.v0.Platform/estimateFee",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("org.dash.platform.dapi.v0.Platform", "estimateFee"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
     }
 }
@@ -8,6 +8,7 @@ export async function creditTransfer(
   identity: Identity,
   recipientId: Identifier | string,
   amount: number,
+  nonce: number,
 ): Promise<any> {
   this.logger.debug(`[Identity#creditTransfer] credit transfer from ${identity.getId().toString()} to ${recipientId.toString()} with amount ${amount}`);
   await this.initialize();
@@ -21,6 +22,7 @@ export async function creditTransfer(
       identity.getId(),
       recipientId,
       BigInt(amount),
+      BigInt(nonce),
     );
 
   this.logger.silly('[Identity#creditTransfer] Created IdentityCreditTransferTransition');
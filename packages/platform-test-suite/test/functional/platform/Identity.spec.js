@@ -509,6 +509,7 @@ describe('Platform', () => {
             identityBeforeTransfer,
             recipient.getId(),
             transferAmount,
+            1,
           );
 
           await waitForSTPropagated();
@@ -543,6 +544,7 @@ describe('Platform', () => {
               identity,
               recipient.getId(),
               identity.getBalance() + 1,
+              2,
             );
 
             expect.fail('should throw an error');
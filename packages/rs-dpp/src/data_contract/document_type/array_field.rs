@@ -1,8 +1,11 @@
+use super::document_field::varint_prefixed_len;
 use super::*;
 use crate::ProtocolError;
-use integer_encoding::VarInt;
+use byteorder::{BigEndian, ReadBytesExt};
+use integer_encoding::{VarInt, VarIntReader};
 use platform_value::Value;
 use serde::{Deserialize, Serialize};
+use std::io::{BufReader, Read};
 
 #[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
 pub enum ArrayFieldType {
@@ -16,6 +19,25 @@ pub enum ArrayFieldType {
 }
 
 impl ArrayFieldType {
+    /// The document field type of a single element of the array
+    pub fn element_field_type(&self) -> DocumentFieldType {
+        match self {
+            ArrayFieldType::Integer => DocumentFieldType::Integer,
+            ArrayFieldType::Number => DocumentFieldType::Number,
+            ArrayFieldType::String(min_length, max_length) => DocumentFieldType::String(
+                min_length.map(|size| size as u16),
+                max_length.map(|size| size as u16),
+            ),
+            ArrayFieldType::ByteArray(min_size, max_size) => DocumentFieldType::ByteArray(
+                min_size.map(|size| size as u16),
+                max_size.map(|size| size as u16),
+            ),
+            ArrayFieldType::Identifier => DocumentFieldType::Identifier,
+            ArrayFieldType::Boolean => DocumentFieldType::Boolean,
+            ArrayFieldType::Date => DocumentFieldType::Date,
+        }
+    }
+
     pub fn encode_value_with_size(&self, value: Value) -> Result<Vec<u8>, ProtocolError> {
         match self {
             ArrayFieldType::String(_, _) => {
@@ -118,6 +140,87 @@ impl ArrayFieldType {
             }
         };
     }
+
+    /// The length of the element serialized with [`ArrayFieldType::encode_value_ref_with_size`]
+    /// at the start of the bytes, found without decoding it
+    pub fn encoded_len(&self, bytes: &[u8]) -> Result<usize, ProtocolError> {
+        let len = match self {
+            ArrayFieldType::Date | ArrayFieldType::Number | ArrayFieldType::Integer => 8,
+            ArrayFieldType::Boolean => 1,
+            ArrayFieldType::String(_, _)
+            | ArrayFieldType::ByteArray(_, _)
+            | ArrayFieldType::Identifier => varint_prefixed_len(bytes)?,
+        };
+        if len > bytes.len() {
+            return Err(ProtocolError::DataContractError(
+                DataContractError::CorruptedSerialization("serialized document is truncated"),
+            ));
+        }
+        Ok(len)
+    }
+
+    /// Reads an element serialized with [`ArrayFieldType::encode_value_ref_with_size`]
+    pub fn read_from(&self, buf: &mut BufReader<&[u8]>) -> Result<Value, ProtocolError> {
+        match self {
+            ArrayFieldType::String(_, _) => {
+                let bytes = read_varint_value(buf)?;
+                let string = String::from_utf8(bytes).map_err(|_| {
+                    ProtocolError::DataContractError(DataContractError::CorruptedSerialization(
+                        "error reading string array element from serialized document",
+                    ))
+                })?;
+                Ok(Value::Text(string))
+            }
+            ArrayFieldType::Date | ArrayFieldType::Number => {
+                let float = buf.read_f64::<BigEndian>().map_err(|_| {
+                    ProtocolError::DataContractError(DataContractError::CorruptedSerialization(
+                        "error reading date/number array element from serialized document",
+                    ))
+                })?;
+                Ok(Value::Float(float))
+            }
+            ArrayFieldType::Integer => {
+                let integer = buf.read_i64::<BigEndian>().map_err(|_| {
+                    ProtocolError::DataContractError(DataContractError::CorruptedSerialization(
+                        "error reading integer array element from serialized document",
+                    ))
+                })?;
+                Ok(Value::I64(integer))
+            }
+            ArrayFieldType::ByteArray(_, _) => Ok(Value::Bytes(read_varint_value(buf)?)),
+            ArrayFieldType::Identifier => {
+                let bytes: [u8; 32] = read_varint_value(buf)?.try_into().map_err(|_| {
+                    ProtocolError::DataContractError(DataContractError::CorruptedSerialization(
+                        "error reading identifier array element from serialized document",
+                    ))
+                })?;
+                Ok(Value::Identifier(bytes))
+            }
+            ArrayFieldType::Boolean => {
+                let value = buf.read_u8().map_err(|_| {
+                    ProtocolError::DataContractError(DataContractError::CorruptedSerialization(
+                        "error reading bool array element from serialized document",
+                    ))
+                })?;
+                Ok(Value::Bool(value != 0))
+            }
+        }
+    }
+}
+
+fn read_varint_value(buf: &mut BufReader<&[u8]>) -> Result<Vec<u8>, ProtocolError> {
+    let bytes: usize = buf.read_varint().map_err(|_| {
+        ProtocolError::DataContractError(DataContractError::CorruptedSerialization(
+            "error reading varint length from serialized document",
+        ))
+    })?;
+    let mut value: Vec<u8> = vec![0u8; bytes];
+    buf.read_exact(&mut value).map_err(|_| {
+        ProtocolError::DataContractError(DataContractError::CorruptedSerialization(
+            "error reading varint from serialized document",
+        ))
+    })?;
+    Ok(value)
 }
 
 fn get_field_type_matching_error() -> ProtocolError {
@@ -1,4 +1,4 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::convert::TryInto;
 
 use std::io::{BufReader, Read};
@@ -28,6 +28,11 @@ pub enum DocumentFieldType {
     ///Todo decompose integer
     Integer,
     Number,
+    /// An unsigned 128 bit integer, held in documents as a string of decimal digits
+    U128,
+    /// A fixed-point decimal with the given number of fractional digits, held in documents as
+    /// a decimal string and stored as a scaled 128 bit integer
+    Decimal(u8),
     String(Option<u16>, Option<u16>),
     ByteArray(Option<u16>, Option<u16>),
     Identifier,
@@ -43,6 +48,7 @@ impl DocumentFieldType {
         match self {
             DocumentFieldType::Integer => Some(8),
             DocumentFieldType::Number => Some(8),
+            DocumentFieldType::U128 | DocumentFieldType::Decimal(_) => Some(16),
             DocumentFieldType::String(min_length, _) => match min_length {
                 None => Some(0),
                 Some(size) => Some(*size),
@@ -67,6 +73,7 @@ impl DocumentFieldType {
         match self {
             DocumentFieldType::Integer => Some(8),
             DocumentFieldType::Number => Some(8),
+            DocumentFieldType::U128 | DocumentFieldType::Decimal(_) => Some(16),
             DocumentFieldType::String(min_length, _) => match min_length {
                 None => Some(0),
                 Some(size) => Some(*size * 4),
@@ -91,6 +98,7 @@ impl DocumentFieldType {
         match self {
             DocumentFieldType::Integer => Some(8),
             DocumentFieldType::Number => Some(8),
+            DocumentFieldType::U128 | DocumentFieldType::Decimal(_) => Some(16),
             DocumentFieldType::String(_, max_length) => match max_length {
                 None => Some(u16::MAX),
                 Some(size) => Some(*size * 4),
@@ -115,6 +123,7 @@ impl DocumentFieldType {
         match self {
             DocumentFieldType::Integer => Some(8),
             DocumentFieldType::Number => Some(8),
+            DocumentFieldType::U128 | DocumentFieldType::Decimal(_) => Some(16),
             DocumentFieldType::String(_, max_length) => match max_length {
                 None => Some(16383),
                 Some(size) => Some(*size),
@@ -189,6 +198,10 @@ impl DocumentFieldType {
         match self {
             DocumentFieldType::Integer => Value::I64(rng.gen::<i64>()),
             DocumentFieldType::Number => Value::Float(rng.gen::<f64>()),
+            DocumentFieldType::U128 => Value::Text(rng.gen::<u128>().to_string()),
+            DocumentFieldType::Decimal(scale) => {
+                Value::Text(mantissa_to_decimal_string(rng.gen::<i64>() as i128, *scale))
+            }
             DocumentFieldType::String(_, _) => {
                 let size = self.random_size(rng);
                 Value::Text(
@@ -244,6 +257,10 @@ impl DocumentFieldType {
         match self {
             DocumentFieldType::Integer => Value::I64(rng.gen::<i64>()),
             DocumentFieldType::Number => Value::Float(rng.gen::<f64>()),
+            DocumentFieldType::U128 => Value::Text(rng.gen::<u128>().to_string()),
+            DocumentFieldType::Decimal(scale) => {
+                Value::Text(mantissa_to_decimal_string(rng.gen::<i64>() as i128, *scale))
+            }
             DocumentFieldType::String(_, _) => {
                 let size = self.max_size().unwrap();
                 Value::Text(
@@ -340,6 +357,24 @@ impl DocumentFieldType {
                 })?;
                 Ok(Some(Value::I64(integer)))
             }
+            DocumentFieldType::U128 => {
+                let integer = buf.read_u128::<BigEndian>().map_err(|_| {
+                    ProtocolError::DataContractError(DataContractError::CorruptedSerialization(
+                        "error reading u128 from serialized document",
+                    ))
+                })?;
+                Ok(Some(Value::Text(integer.to_string())))
+            }
+            DocumentFieldType::Decimal(scale) => {
+                let mantissa = buf.read_i128::<BigEndian>().map_err(|_| {
+                    ProtocolError::DataContractError(DataContractError::CorruptedSerialization(
+                        "error reading decimal from serialized document",
+                    ))
+                })?;
+                Ok(Some(Value::Text(mantissa_to_decimal_string(
+                    mantissa, *scale,
+                ))))
+            }
             DocumentFieldType::Boolean => {
                 let value = buf.read_u8().map_err(|_| {
                     ProtocolError::DataContractError(DataContractError::CorruptedSerialization(
@@ -407,15 +442,86 @@ impl DocumentFieldType {
                     Ok(Some(Value::Map(values)))
                 }
             }
-            DocumentFieldType::Array(_array_field_type) => Err(ProtocolError::DataContractError(
-                DataContractError::Unsupported("serialization of arrays not yet supported"),
-            )),
+            DocumentFieldType::Array(array_field_type) => {
+                let len: usize = buf.read_varint().map_err(|_| {
+                    ProtocolError::DataContractError(DataContractError::CorruptedSerialization(
+                        "error reading array length from serialized document",
+                    ))
+                })?;
+                let values = (0..len)
+                    .map(|_| array_field_type.read_from(buf))
+                    .collect::<Result<Vec<Value>, ProtocolError>>()?;
+                Ok(Some(Value::Array(values)))
+            }
             DocumentFieldType::VariableTypeArray(_) => Err(ProtocolError::DataContractError(
                 DataContractError::Unsupported("serialization of arrays not yet supported"),
             )),
         }
     }
 
+    /// The length of the value serialized with [`DocumentFieldType::encode_value_ref_with_size`]
+    /// at the start of the bytes, including its optional marker, found without decoding it
+    pub fn encoded_len(&self, bytes: &[u8], required: bool) -> Result<usize, ProtocolError> {
+        let offset = if required {
+            0
+        } else {
+            let marker = bytes.first().ok_or_else(|| {
+                ProtocolError::DataContractError(DataContractError::CorruptedSerialization(
+                    "error reading from serialized document",
+                ))
+            })?;
+            if *marker == 0 {
+                return Ok(1);
+            }
+            1
+        };
+        let value_bytes = &bytes[offset..];
+        let len = match self {
+            DocumentFieldType::Date | DocumentFieldType::Number | DocumentFieldType::Integer => 8,
+            DocumentFieldType::U128 | DocumentFieldType::Decimal(_) => 16,
+            DocumentFieldType::Boolean => 1,
+            DocumentFieldType::Identifier => 32,
+            DocumentFieldType::ByteArray(Some(min), Some(max)) if min == max => *min as usize,
+            DocumentFieldType::String(_, _) | DocumentFieldType::ByteArray(_, _) => {
+                varint_prefixed_len(value_bytes)?
+            }
+            DocumentFieldType::Object(inner_fields) => {
+                inner_fields.values().try_fold(0, |len, field| {
+                    Ok::<usize, ProtocolError>(
+                        len + field.document_type.encoded_len(
+                            value_bytes.get(len..).unwrap_or_default(),
+                            field.required,
+                        )?,
+                    )
+                })?
+            }
+            DocumentFieldType::Array(array_field_type) => {
+                let (count, prefix_len) = usize::decode_var(value_bytes).ok_or_else(|| {
+                    ProtocolError::DataContractError(DataContractError::CorruptedSerialization(
+                        "error reading array length from serialized document",
+                    ))
+                })?;
+                (0..count).try_fold(prefix_len, |len, _| {
+                    Ok::<usize, ProtocolError>(
+                        len + array_field_type
+                            .encoded_len(value_bytes.get(len..).unwrap_or_default())?,
+                    )
+                })?
+            }
+            DocumentFieldType::VariableTypeArray(_) => {
+                return Err(ProtocolError::DataContractError(
+                    DataContractError::Unsupported("serialization of arrays not yet supported"),
+                ))
+            }
+        };
+        if len > value_bytes.len() {
+            return Err(ProtocolError::DataContractError(
+                DataContractError::CorruptedSerialization("serialized document is truncated"),
+            ));
+        }
+        Ok(offset + len)
+    }
+
     pub fn encode_value_with_size(
         &self,
         value: Value,
@@ -471,6 +577,17 @@ impl DocumentFieldType {
                     Ok(r_vec)
                 }
             }
+            DocumentFieldType::U128 | DocumentFieldType::Decimal(_) => {
+                let mut value_bytes = self.encode_value_ref_with_size(&value, required)?;
+                if required {
+                    Ok(value_bytes)
+                } else {
+                    // if the value wasn't required we need to add a byte to prove it existed
+                    let mut r_vec = vec![255u8];
+                    r_vec.append(&mut value_bytes);
+                    Ok(r_vec)
+                }
+            }
             DocumentFieldType::ByteArray(_, _) => {
                 let mut bytes = value.into_binary_bytes()?;
 
@@ -582,6 +699,10 @@ impl DocumentFieldType {
                 let value_as_f64 = value.to_float().map_err(ProtocolError::ValueError)?;
                 Ok(value_as_f64.to_be_bytes().to_vec())
             }
+            DocumentFieldType::U128 => Ok(value_to_u128(value)?.to_be_bytes().to_vec()),
+            DocumentFieldType::Decimal(scale) => Ok(value_to_decimal_mantissa(value, *scale)?
+                .to_be_bytes()
+                .to_vec()),
             DocumentFieldType::ByteArray(min, max) => match (min, max) {
                 (Some(min), Some(max)) if min == max => Ok(value.to_binary_bytes()?),
                 _ => {
@@ -681,6 +802,10 @@ impl DocumentFieldType {
             DocumentFieldType::Number => Ok(encode_float(
                 value.to_float().map_err(ProtocolError::ValueError)?,
             )),
+            DocumentFieldType::U128 => Ok(encode_u128(value_to_u128(value)?)),
+            DocumentFieldType::Decimal(scale) => {
+                Ok(encode_i128(value_to_decimal_mantissa(value, *scale)?))
+            }
             DocumentFieldType::ByteArray(_, _) => {
                 value.to_binary_bytes().map_err(ProtocolError::ValueError)
             }
@@ -710,6 +835,37 @@ impl DocumentFieldType {
         }
     }
 
+    /// The field type used for the keys of an index on this field. Arrays are indexed by
+    /// each of their elements
+    pub fn index_key_field_type(&self) -> DocumentFieldType {
+        match self {
+            DocumentFieldType::Array(array_field_type) => array_field_type.element_field_type(),
+            field_type => field_type.clone(),
+        }
+    }
+
+    /// Encodes every element of an array value for tree keys. The keys are sorted and
+    /// deduplicated, as an element present several times is only indexed once
+    pub fn encode_array_value_for_tree_keys(
+        &self,
+        value: &Value,
+    ) -> Result<Vec<Vec<u8>>, ProtocolError> {
+        let DocumentFieldType::Array(array_field_type) = self else {
+            return Err(get_field_type_matching_error());
+        };
+        if value.is_null() {
+            return Ok(vec![]);
+        }
+        let element_field_type = array_field_type.element_field_type();
+        let keys = value
+            .as_array()
+            .ok_or_else(get_field_type_matching_error)?
+            .iter()
+            .map(|element| element_field_type.encode_value_for_tree_keys(element))
+            .collect::<Result<BTreeSet<Vec<u8>>, ProtocolError>>()?;
+        Ok(keys.into_iter().collect())
+    }
+
     // Given a field type and a value this function chooses and executes the right encoding method
     pub fn value_from_string(&self, str: &str) -> Result<Value, ProtocolError> {
         match self {
@@ -735,6 +891,11 @@ impl DocumentFieldType {
                     "value is not an integer from string",
                 ))
             }),
+            DocumentFieldType::U128 => {
+                parse_u128(str).map(|integer| Value::Text(integer.to_string()))
+            }
+            DocumentFieldType::Decimal(scale) => decimal_string_to_mantissa(str, *scale)
+                .map(|mantissa| Value::Text(mantissa_to_decimal_string(mantissa, *scale))),
             DocumentFieldType::Number | DocumentFieldType::Date => {
                 str.parse::<f64>().map(Value::Float).map_err(|_| {
                     ProtocolError::DataContractError(DataContractError::ValueWrongType(
@@ -795,12 +956,121 @@ impl DocumentFieldType {
     }
 }
 
+/// The length of a varint length prefix and the bytes it announces
+pub(super) fn varint_prefixed_len(bytes: &[u8]) -> Result<usize, ProtocolError> {
+    let (len, prefix_len) = usize::decode_var(bytes).ok_or_else(|| {
+        ProtocolError::DataContractError(DataContractError::CorruptedSerialization(
+            "error reading varint length from serialized document",
+        ))
+    })?;
+    prefix_len.checked_add(len).ok_or_else(|| {
+        ProtocolError::DataContractError(DataContractError::CorruptedSerialization(
+            "varint length in serialized document is too big",
+        ))
+    })
+}
+
 fn get_field_type_matching_error() -> ProtocolError {
     ProtocolError::DataContractError(DataContractError::ValueWrongType(
         "document field type doesn't match document value",
     ))
 }
 
+/// The highest scale of decimal fields, as 10^38 is the biggest power of ten fitting an i128
+pub const MAX_DECIMAL_SCALE: u8 = 38;
+
+fn parse_u128(str: &str) -> Result<u128, ProtocolError> {
+    // only plain digits, `u128::from_str` would also accept a leading `+`
+    if str.is_empty() || !str.bytes().all(|byte| byte.is_ascii_digit()) {
+        return Err(ProtocolError::DataContractError(
+            DataContractError::ValueWrongType("value is not an unsigned integer string"),
+        ));
+    }
+    str.parse::<u128>().map_err(|_| {
+        ProtocolError::DataContractError(DataContractError::ValueWrongType(
+            "value is too big for an unsigned 128 bit integer",
+        ))
+    })
+}
+
+/// Converts a value of a `U128` field, either a string of decimal digits or an integer
+pub fn value_to_u128(value: &Value) -> Result<u128, ProtocolError> {
+    match value {
+        Value::Text(text) => parse_u128(text),
+        value => value.to_integer().map_err(ProtocolError::ValueError),
+    }
+}
+
+/// Converts a value of a `Decimal` field, either a decimal string or an integer amount of
+/// whole units, to its mantissa scaled by 10^scale
+pub fn value_to_decimal_mantissa(value: &Value, scale: u8) -> Result<i128, ProtocolError> {
+    match value {
+        Value::Text(text) => decimal_string_to_mantissa(text, scale),
+        value => {
+            let integer: i128 = value.to_integer().map_err(ProtocolError::ValueError)?;
+            10i128
+                .checked_pow(scale as u32)
+                .and_then(|multiplier| integer.checked_mul(multiplier))
+                .ok_or(ProtocolError::DataContractError(
+                    DataContractError::ValueWrongType("decimal value is out of range"),
+                ))
+        }
+    }
+}
+
+/// Parses a decimal string such as `-12.34` to its mantissa scaled by 10^scale. Strings with
+/// more fractional digits than the scale are rejected rather than rounded
+pub fn decimal_string_to_mantissa(str: &str, scale: u8) -> Result<i128, ProtocolError> {
+    let wrong_type_error =
+        |reason| ProtocolError::DataContractError(DataContractError::ValueWrongType(reason));
+
+    let (negative, unsigned) = match str.strip_prefix('-') {
+        Some(unsigned) => (true, unsigned),
+        None => (false, str),
+    };
+    let (integer_part, fractional_part) = match unsigned.split_once('.') {
+        Some((integer_part, fractional_part)) => (integer_part, fractional_part),
+        None => (unsigned, ""),
+    };
+    if integer_part.is_empty()
+        || !integer_part.bytes().all(|byte| byte.is_ascii_digit())
+        || !fractional_part.bytes().all(|byte| byte.is_ascii_digit())
+        || (unsigned.contains('.') && fractional_part.is_empty())
+    {
+        return Err(wrong_type_error("value is not a decimal string"));
+    }
+    if fractional_part.len() > scale as usize {
+        return Err(wrong_type_error(
+            "decimal string has more fractional digits than the field scale",
+        ));
+    }
+
+    let digits = format!(
+        "{}{}{}",
+        integer_part,
+        fractional_part,
+        "0".repeat(scale as usize - fractional_part.len())
+    );
+    let magnitude = digits
+        .parse::<i128>()
+        .map_err(|_| wrong_type_error("decimal value is out of range"))?;
+    Ok(if negative { -magnitude } else { magnitude })
+}
+
+/// Formats a mantissa scaled by 10^scale as a decimal string with exactly `scale` fractional
+/// digits, the canonical form of decimal field values
+pub fn mantissa_to_decimal_string(mantissa: i128, scale: u8) -> String {
+    let sign = if mantissa < 0 { "-" } else { "" };
+    let digits = mantissa.unsigned_abs().to_string();
+    let scale = scale as usize;
+    if scale == 0 {
+        return format!("{}{}", sign, digits);
+    }
+    let digits = format!("{:0>width$}", digits, width = scale + 1);
+    let (integer_part, fractional_part) = digits.split_at(digits.len() - scale);
+    format!("{}{}.{}", sign, integer_part, fractional_part)
+}
+
 pub fn encode_date_timestamp(val: TimestampMillis) -> Result<Vec<u8>, ProtocolError> {
     encode_unsigned_integer(val)
 }
@@ -893,3 +1163,99 @@ pub fn encode_float(val: f64) -> Vec<u8> {
 
     wtr
 }
+
+pub fn encode_u128(val: u128) -> Vec<u8> {
+    // Big endian unsigned integers already sort like their values
+    val.to_be_bytes().to_vec()
+}
+
+pub fn encode_i128(val: i128) -> Vec<u8> {
+    // Same as signed 64 bit integers, flipping the sign bit places negative values,
+    // in 2's complement form, below positive ones
+    let mut wtr = val.to_be_bytes().to_vec();
+    wtr[0] ^= 0b1000_0000;
+    wtr
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decimal_strings_should_round_trip_through_mantissas() {
+        assert_eq!(decimal_string_to_mantissa("12.34", 2).unwrap(), 1234);
+        assert_eq!(decimal_string_to_mantissa("12.3", 2).unwrap(), 1230);
+        assert_eq!(decimal_string_to_mantissa("-0.05", 2).unwrap(), -5);
+        assert_eq!(decimal_string_to_mantissa("7", 0).unwrap(), 7);
+
+        assert_eq!(mantissa_to_decimal_string(1230, 2), "12.30");
+        assert_eq!(mantissa_to_decimal_string(-5, 2), "-0.05");
+        assert_eq!(mantissa_to_decimal_string(7, 0), "7");
+
+        for invalid in ["12.345", "", "-", "1.", ".5", "+1", "1e3", "1,5"] {
+            assert!(
+                decimal_string_to_mantissa(invalid, 2).is_err(),
+                "{} should be rejected",
+                invalid
+            );
+        }
+    }
+
+    #[test]
+    fn exact_numeric_tree_keys_should_preserve_order() {
+        let decimal = DocumentFieldType::Decimal(2);
+        let decimal_keys = ["-100.5", "-0.01", "0", "0.01", "12.3", "12.34", "100"]
+            .iter()
+            .map(|value| {
+                decimal
+                    .encode_value_for_tree_keys(&Value::Text(value.to_string()))
+                    .expect("expected to encode decimal")
+            })
+            .collect::<Vec<_>>();
+        assert!(decimal_keys.windows(2).all(|keys| keys[0] < keys[1]));
+
+        let big_integer = DocumentFieldType::U128;
+        let max = u128::MAX.to_string();
+        let integer_keys = ["0", "9", "10", "18446744073709551616", max.as_str()]
+            .iter()
+            .map(|value| {
+                big_integer
+                    .encode_value_for_tree_keys(&Value::Text(value.to_string()))
+                    .expect("expected to encode u128")
+            })
+            .collect::<Vec<_>>();
+        assert!(integer_keys.windows(2).all(|keys| keys[0] < keys[1]));
+
+        // integers are accepted as whole units
+        assert_eq!(
+            decimal.encode_value_for_tree_keys(&Value::U64(3)).unwrap(),
+            decimal
+                .encode_value_for_tree_keys(&Value::Text("3.00".to_string()))
+                .unwrap()
+        );
+        assert!(big_integer
+            .encode_value_for_tree_keys(&Value::Text(format!("{}0", max)))
+            .is_err());
+    }
+
+    #[test]
+    fn exact_numeric_values_should_be_read_back_canonically() {
+        let decimal = DocumentFieldType::Decimal(3);
+        let serialized = decimal
+            .encode_value_with_size(Value::Text("-1.5".to_string()), true)
+            .expect("expected to serialize decimal");
+        let read_value = decimal
+            .read_from(&mut BufReader::new(serialized.as_slice()), true)
+            .expect("expected to read decimal");
+        assert_eq!(read_value, Some(Value::Text("-1.500".to_string())));
+
+        let big_integer = DocumentFieldType::U128;
+        let serialized = big_integer
+            .encode_value_with_size(Value::U128(u128::MAX), false)
+            .expect("expected to serialize u128");
+        let read_value = big_integer
+            .read_from(&mut BufReader::new(serialized.as_slice()), false)
+            .expect("expected to read u128");
+        assert_eq!(read_value, Some(Value::Text(u128::MAX.to_string())));
+    }
+}
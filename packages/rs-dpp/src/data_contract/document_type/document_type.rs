@@ -1,19 +1,31 @@
 use itertools::Itertools;
+use std::borrow::Cow;
 use std::collections::{BTreeMap, BTreeSet};
 use std::convert::TryInto;
 
 use super::{
-    document_field::{DocumentField, DocumentFieldType},
-    index::{Index, IndexProperty},
+    document_field::{DocumentField, DocumentFieldType, MAX_DECIMAL_SCALE},
+    index::{Index, IndexCollation, IndexProperty},
+};
+use crate::consensus::basic::document::{
+    InvalidDocumentFieldValueError, InvalidEncryptedDocumentFieldError,
+};
+use crate::consensus::basic::BasicError;
+use crate::data_contract::document_type::{
+    property_names, ArrayFieldType, EncryptedField, TransitionSchemaValidators,
 };
-use crate::data_contract::document_type::{property_names, ArrayFieldType};
 use crate::data_contract::errors::{DataContractError, StructureError};
 
 use crate::document::document_transition::INITIAL_REVISION;
 use crate::document::Document;
-use crate::prelude::Revision;
+use crate::identity::encryption::{ECIES_EPHEMERAL_PUBLIC_KEY_SIZE, ECIES_OVERHEAD};
+use crate::prelude::{Revision, TimestampMillis};
+use crate::validation::SimpleConsensusValidationResult;
 use crate::ProtocolError;
-use platform_value::btreemap_extensions::{BTreeValueMapHelper, BTreeValueRemoveFromMapHelper};
+use dashcore::secp256k1::PublicKey;
+use platform_value::btreemap_extensions::{
+    BTreeValueMapHelper, BTreeValueMapPathHelper, BTreeValueRemoveFromMapHelper,
+};
 use platform_value::{Identifier, ReplacementType, Value};
 use serde::{Deserialize, Serialize};
 
@@ -50,8 +62,17 @@ pub struct DocumentType {
     pub required_fields: BTreeSet<String>,
     pub documents_keep_history: bool,
     pub documents_mutable: bool,
+    /// Milliseconds after which documents of this type are removed by the platform
+    #[serde(default)]
+    pub documents_ttl: Option<TimestampMillis>,
+    /// Byte array properties encrypted to a key of a recipient identity
+    #[serde(skip)]
+    pub encrypted_fields: BTreeMap<String, EncryptedField>,
     #[serde(skip)]
     pub data_contract_id: Identifier,
+    /// Compiled schemas of the create and replace transitions
+    #[serde(skip)]
+    pub transition_schema_validators: TransitionSchemaValidators,
 }
 
 #[derive(Debug, PartialEq, Default, Clone)]
@@ -108,14 +129,17 @@ impl DocumentType {
             name,
             indices,
             index_structure,
-            flattened_properties: properties.clone(),
+            flattened_properties: Self::flatten_properties(&properties, ""),
             properties,
             identifier_paths,
             binary_paths,
             required_fields,
             documents_keep_history,
             documents_mutable,
+            documents_ttl: None,
+            encrypted_fields: BTreeMap::new(),
             data_contract_id,
+            transition_schema_validators: TransitionSchemaValidators::default(),
         }
     }
     // index_names can be in any order
@@ -157,6 +181,34 @@ impl DocumentType {
         bytes
     }
 
+    /// Returns true if the field is an array, which is indexed by each of its elements
+    pub fn is_array_field(&self, key: &str) -> bool {
+        matches!(
+            self.flattened_properties
+                .get(key)
+                .map(|field| &field.document_type),
+            Some(DocumentFieldType::Array(_))
+        )
+    }
+
+    /// Returns the collation of the indices containing the property, validation ensures
+    /// that all these indices have the same collation
+    pub fn collation_for_property(&self, key: &str) -> Option<IndexCollation> {
+        self.indices
+            .iter()
+            .filter(|index| index.properties.iter().any(|property| property.name == key))
+            .find_map(|index| index.collation)
+    }
+
+    /// Returns the value as it is stored in indices, normalized by the collation of the
+    /// indices containing the property
+    pub fn index_value_for_key<'a>(&self, key: &str, value: &'a Value) -> Cow<'a, Value> {
+        match self.collation_for_property(key) {
+            Some(collation) => Cow::Owned(collation.normalize_value(value)),
+            None => Cow::Borrowed(value),
+        }
+    }
+
     pub fn serialize_value_for_key(
         &self,
         key: &str,
@@ -181,7 +233,11 @@ impl DocumentType {
                 let field_type = self.flattened_properties.get(key).ok_or_else(|| {
                     DataContractError::DocumentTypeFieldNotFound(format!("expected contract to have field: {key}, contract fields are {} on document type {}", self.flattened_properties.keys().join(" | "), self.name))
                 })?;
-                let bytes = field_type.document_type.encode_value_for_tree_keys(value)?;
+                // values of array fields are queried by element
+                let bytes = field_type
+                    .document_type
+                    .index_key_field_type()
+                    .encode_value_for_tree_keys(&self.index_value_for_key(key, value))?;
                 if bytes.len() > MAX_INDEX_SIZE {
                     Err(ProtocolError::DataContractError(
                         DataContractError::FieldRequirementUnmet(
@@ -245,6 +301,21 @@ impl DocumentType {
                 .map_err(ProtocolError::ValueError)?
                 .unwrap_or(default_mutability);
 
+        // Are documents of this type removed after some time?
+        let documents_ttl: Option<TimestampMillis> = Value::inner_optional_integer_value(
+            document_type_value_map,
+            property_names::DOCUMENTS_TTL,
+        )
+        .map_err(ProtocolError::ValueError)?;
+
+        if documents_ttl.is_some() && documents_keep_history {
+            return Err(ProtocolError::DataContractError(
+                DataContractError::InvalidContractStructure(
+                    "documents that keep history can not have a ttl",
+                ),
+            ));
+        }
+
         let index_values = Value::inner_optional_array_slice_value(
             document_type_value_map,
             property_names::INDICES,
@@ -280,6 +351,9 @@ impl DocumentType {
             property_names::PROPERTIES,
             property_names::REQUIRED,
         );
+        // Encrypted fields are validated once all properties are known
+        let encryption_definitions = find_encryption_definitions(&property_values)?;
+
         // Based on the property name, determine the type
         for (property_key, property_value) in property_values {
             insert_values(
@@ -334,6 +408,18 @@ impl DocumentType {
             );
         }
 
+        let encrypted_fields = encryption_definitions
+            .into_iter()
+            .map(|(property_key, encryption_value)| {
+                let encrypted_field = EncryptedField::from_property_definition(
+                    &property_key,
+                    encryption_value,
+                    &flattened_document_properties,
+                )?;
+                Ok((property_key, encrypted_field))
+            })
+            .collect::<Result<BTreeMap<String, EncryptedField>, ProtocolError>>()?;
+
         let index_structure = IndexLevel::from(indices.as_slice());
 
         let (identifier_paths, binary_paths) =
@@ -349,7 +435,10 @@ impl DocumentType {
             required_fields,
             documents_keep_history,
             documents_mutable,
+            documents_ttl,
+            encrypted_fields,
             data_contract_id,
+            transition_schema_validators: TransitionSchemaValidators::default(),
         })
     }
 
@@ -428,6 +517,123 @@ impl DocumentType {
         self.documents_mutable
     }
 
+    /// Checks that encrypted fields of a raw document hold a well formed ciphertext
+    /// and that the recipient identity and key are set
+    pub fn validate_encrypted_fields(
+        &self,
+        raw_document: &BTreeMap<String, &Value>,
+    ) -> SimpleConsensusValidationResult {
+        let mut result = SimpleConsensusValidationResult::default();
+
+        for (field_name, encrypted_field) in self.encrypted_fields.iter() {
+            let value = match raw_document.get_optional_at_path(field_name) {
+                Ok(Some(value)) if !value.is_null() => value,
+                _ => continue,
+            };
+
+            let error_reason = match value.to_bytes() {
+                Err(_) => Some("must be a byte array".to_string()),
+                Ok(ciphertext) if ciphertext.len() < ECIES_OVERHEAD => Some(format!(
+                    "must be at least {} bytes long, got {}",
+                    ECIES_OVERHEAD,
+                    ciphertext.len()
+                )),
+                Ok(ciphertext)
+                    if PublicKey::from_slice(&ciphertext[..ECIES_EPHEMERAL_PUBLIC_KEY_SIZE])
+                        .is_err() =>
+                {
+                    Some("must start with a valid ephemeral public key".to_string())
+                }
+                Ok(_) => [
+                    &encrypted_field.recipient_identity_field,
+                    &encrypted_field.recipient_key_id_field,
+                ]
+                .into_iter()
+                .find(|recipient_field| {
+                    raw_document
+                        .get_optional_at_path(recipient_field.as_str())
+                        .ok()
+                        .flatten()
+                        .map_or(true, |value| value.is_null())
+                })
+                .map(|recipient_field| format!("requires {} to be set", recipient_field)),
+            };
+
+            if let Some(reason) = error_reason {
+                result.add_error(BasicError::InvalidEncryptedDocumentFieldError(
+                    InvalidEncryptedDocumentFieldError::new(
+                        self.name.clone(),
+                        field_name.clone(),
+                        reason,
+                    ),
+                ));
+            }
+        }
+
+        result
+    }
+
+    /// Checks that the exact numeric fields of a raw document, held as strings, hold values
+    /// that fit their field type
+    pub fn validate_exact_numeric_fields(
+        &self,
+        raw_document: &BTreeMap<String, &Value>,
+    ) -> SimpleConsensusValidationResult {
+        let mut result = SimpleConsensusValidationResult::default();
+
+        for (field_name, field) in self.flattened_properties.iter() {
+            if !matches!(
+                field.document_type,
+                DocumentFieldType::U128 | DocumentFieldType::Decimal(_)
+            ) {
+                continue;
+            }
+            let value = match raw_document.get_optional_at_path(field_name) {
+                Ok(Some(value)) if !value.is_null() => value,
+                _ => continue,
+            };
+
+            if let Err(ProtocolError::DataContractError(DataContractError::ValueWrongType(
+                reason,
+            ))) = field.document_type.encode_value_for_tree_keys(value)
+            {
+                result.add_error(BasicError::InvalidDocumentFieldValueError(
+                    InvalidDocumentFieldValueError::new(
+                        self.name.clone(),
+                        field_name.clone(),
+                        reason.to_string(),
+                    ),
+                ));
+            }
+        }
+
+        result
+    }
+
+    /// Flattens nested object properties into dotted paths, as used by indexes
+    fn flatten_properties(
+        properties: &BTreeMap<String, DocumentField>,
+        current_path: &str,
+    ) -> BTreeMap<String, DocumentField> {
+        let mut flattened_properties = BTreeMap::new();
+
+        for (key, value) in properties.iter() {
+            let new_path = if current_path.is_empty() {
+                key.clone()
+            } else {
+                format!("{}.{}", current_path, key)
+            };
+
+            if let DocumentFieldType::Object(inner_properties) = &value.document_type {
+                flattened_properties.extend(Self::flatten_properties(inner_properties, &new_path));
+            } else {
+                flattened_properties.insert(new_path, value.clone());
+            }
+        }
+
+        flattened_properties
+    }
+
     pub(crate) fn find_identifier_and_binary_paths(
         properties: &BTreeMap<String, DocumentField>,
     ) -> (BTreeSet<String>, BTreeSet<String>) {
@@ -505,6 +711,66 @@ pub fn string_to_field_type(field_type_name: &str) -> Option<DocumentFieldType>
     }
 }
 
+/// Returns the type of a string property. Strings holding exact numbers are declared with
+/// `bigInteger` or `decimalScale`, their values being too big for JSON numbers
+fn string_field_type(
+    inner_properties: &BTreeMap<String, &Value>,
+) -> Result<DocumentFieldType, ProtocolError> {
+    if let Some(scale) =
+        inner_properties.get_optional_integer::<u8>(property_names::DECIMAL_SCALE)?
+    {
+        if scale > MAX_DECIMAL_SCALE {
+            return Err(ProtocolError::DataContractError(
+                DataContractError::InvalidContractStructure("decimalScale is too big"),
+            ));
+        }
+        return Ok(DocumentFieldType::Decimal(scale));
+    }
+
+    if inner_properties.get_optional_bool(property_names::BIG_INTEGER)? == Some(true) {
+        return Ok(DocumentFieldType::U128);
+    }
+
+    Ok(DocumentFieldType::String(
+        inner_properties.get_optional_integer(property_names::MIN_LENGTH)?,
+        inner_properties.get_optional_integer(property_names::MAX_LENGTH)?,
+    ))
+}
+
+/// Returns the element type of an array property from its `items` definition
+// TODO: Arrays of objects, nested arrays and `prefixItems` tuples are still only usable
+//   as document fields with current cbor encoding. They keep the v0.22 behavior of a
+//   boolean element type and can't be indexed
+fn array_items_field_type(
+    inner_properties: &BTreeMap<String, &Value>,
+) -> Result<ArrayFieldType, ProtocolError> {
+    let items = match inner_properties.get(property_names::ITEMS) {
+        Some(items) if items.is_map() => items.to_btree_ref_string_map()?,
+        _ => return Ok(ArrayFieldType::Boolean),
+    };
+
+    let field_type = match items.get_optional_str(property_names::TYPE)? {
+        Some("string") => ArrayFieldType::String(
+            items.get_optional_integer(property_names::MIN_LENGTH)?,
+            items.get_optional_integer(property_names::MAX_LENGTH)?,
+        ),
+        Some("integer") => ArrayFieldType::Integer,
+        Some("number") => ArrayFieldType::Number,
+        Some("array") if items.get_optional_bool(property_names::BYTE_ARRAY)? == Some(true) => {
+            match items.get_optional_str(property_names::CONTENT_MEDIA_TYPE)? {
+                Some("application/x.dash.dpp.identifier") => ArrayFieldType::Identifier,
+                Some(_) | None => ArrayFieldType::ByteArray(
+                    items.get_optional_integer(property_names::MIN_ITEMS)?,
+                    items.get_optional_integer(property_names::MAX_ITEMS)?,
+                ),
+            }
+        }
+        _ => ArrayFieldType::Boolean,
+    };
+
+    Ok(field_type)
+}
+
 fn insert_values_nested(
     document_properties: &mut BTreeMap<String, DocumentField>,
     known_required: &BTreeSet<String>,
@@ -546,10 +812,7 @@ fn insert_values_nested(
             field_type = DocumentFieldType::Number;
         }
         "string" => {
-            field_type = DocumentFieldType::String(
-                inner_properties.get_optional_integer(property_names::MIN_LENGTH)?,
-                inner_properties.get_optional_integer(property_names::MAX_LENGTH)?,
-            );
+            field_type = string_field_type(&inner_properties)?;
         }
         "array" => {
             // Only handling bytearrays for v1
@@ -578,11 +841,7 @@ fn insert_values_nested(
                         ));
                     }
                 }
-                // TODO: Contract indices and new encoding format don't support arrays
-                //   but we still can use them as document fields with current cbor encoding
-                //   This is a temporary workaround to bring back v0.22 behavior and should be
-                //   replaced with a proper array support in future versions
-                None => DocumentFieldType::Array(ArrayFieldType::Boolean),
+                None => DocumentFieldType::Array(array_items_field_type(&inner_properties)?),
             };
         }
         "object" => {
@@ -663,6 +922,48 @@ fn insert_values_nested(
     Ok(())
 }
 
+/// Finds the properties, nested ones included, that define an encryption, keyed by their
+/// dotted path
+fn find_encryption_definitions<'a>(
+    property_values: &BTreeMap<String, &'a Value>,
+) -> Result<Vec<(String, &'a Value)>, ProtocolError> {
+    let mut encryption_definitions = vec![];
+    let mut to_visit: Vec<(String, &Value)> = property_values
+        .iter()
+        .map(|(property_key, property_value)| (property_key.clone(), *property_value))
+        .collect();
+
+    while let Some((property_path, property_value)) = to_visit.pop() {
+        if let Some(encryption_value) =
+            property_value.get_optional_value(property_names::ENCRYPTION)?
+        {
+            encryption_definitions.push((property_path.clone(), encryption_value));
+        }
+
+        let Some(properties) = property_value
+            .get_optional_value(property_names::PROPERTIES)?
+            .and_then(|properties| properties.as_map())
+        else {
+            continue;
+        };
+
+        for (object_property_key, object_property_value) in properties.iter() {
+            let object_property_string =
+                object_property_key
+                    .as_text()
+                    .ok_or(ProtocolError::StructureError(StructureError::KeyWrongType(
+                        "property key must be a string",
+                    )))?;
+            to_visit.push((
+                [property_path.as_str(), object_property_string].join("."),
+                object_property_value,
+            ));
+        }
+    }
+
+    Ok(encryption_definitions)
+}
+
 fn insert_values(
     document_properties: &mut BTreeMap<String, DocumentField>,
     known_required: &BTreeSet<String>,
@@ -735,11 +1036,7 @@ fn insert_values(
                             ));
                         }
                     }
-                    // TODO: Contract indices and new encoding format don't support arrays
-                    //   but we still can use them as document fields with current cbor encoding
-                    //   This is a temporary workaround to bring back v0.22 behavior and should be
-                    //   replaced with a proper array support in future versions
-                    None => DocumentFieldType::Array(ArrayFieldType::Boolean),
+                    None => DocumentFieldType::Array(array_items_field_type(&inner_properties)?),
                 };
 
                 document_properties.insert(
@@ -777,10 +1074,7 @@ fn insert_values(
             }
 
             "string" => {
-                field_type = DocumentFieldType::String(
-                    inner_properties.get_optional_integer(property_names::MIN_LENGTH)?,
-                    inner_properties.get_optional_integer(property_names::MAX_LENGTH)?,
-                );
+                field_type = string_field_type(&inner_properties)?;
                 document_properties.insert(
                     prefixed_property_key,
                     DocumentField {
@@ -806,3 +1100,166 @@ fn insert_values(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::consensus::ConsensusError;
+    use crate::identity::encryption::encrypt_to_public_key;
+    use crate::identity::KeyType;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use serde_json::json;
+
+    fn message_document_type() -> DocumentType {
+        let document_def: Value = json!({
+            "type": "object",
+            "properties": {
+                "message": {
+                    "type": "object",
+                    "properties": {
+                        "toUserId": {
+                            "type": "array",
+                            "byteArray": true,
+                            "minItems": 32,
+                            "maxItems": 32,
+                            "contentMediaType": "application/x.dash.dpp.identifier",
+                        },
+                        "recipientKeyIndex": {
+                            "type": "integer",
+                        },
+                        "payload": {
+                            "type": "array",
+                            "byteArray": true,
+                            "minItems": 61,
+                            "maxItems": 256,
+                            "encryption": {
+                                "recipientIdentityField": "message.toUserId",
+                                "recipientKeyIdField": "message.recipientKeyIndex",
+                            },
+                        },
+                    },
+                    "additionalProperties": false,
+                },
+            },
+            "additionalProperties": false,
+        })
+        .into();
+
+        DocumentType::from_platform_value(
+            Default::default(),
+            "message",
+            document_def.to_map().expect("expected a map"),
+            &BTreeMap::new(),
+            false,
+            false,
+        )
+        .expect("expected a document type")
+    }
+
+    fn raw_message(payload: Value, recipient_key_index: Option<u32>) -> Value {
+        let mut message = vec![
+            (
+                Value::Text("toUserId".to_string()),
+                Value::Identifier([1; 32]),
+            ),
+            (Value::Text("payload".to_string()), payload),
+        ];
+        if let Some(recipient_key_index) = recipient_key_index {
+            message.push((
+                Value::Text("recipientKeyIndex".to_string()),
+                Value::U32(recipient_key_index),
+            ));
+        }
+        Value::Map(vec![(
+            Value::Text("message".to_string()),
+            Value::Map(message),
+        )])
+    }
+
+    fn valid_ciphertext() -> Vec<u8> {
+        let mut rng = StdRng::seed_from_u64(5);
+        let (public_key, _) = KeyType::ECDSA_SECP256K1.random_public_and_private_key_data(&mut rng);
+        encrypt_to_public_key(&public_key, b"a message for the contact")
+            .expect("expected to encrypt")
+    }
+
+    fn encrypted_field_error_reason(result: &SimpleConsensusValidationResult) -> Option<String> {
+        result.errors.first().map(|error| error.to_string())
+    }
+
+    #[test]
+    fn should_find_nested_encrypted_fields() {
+        let document_type = message_document_type();
+
+        assert_eq!(
+            document_type.encrypted_fields.get("message.payload"),
+            Some(&EncryptedField {
+                recipient_identity_field: "message.toUserId".to_string(),
+                recipient_key_id_field: "message.recipientKeyIndex".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn should_accept_a_valid_nested_ciphertext() {
+        let document_type = message_document_type();
+        let raw_document = raw_message(Value::Bytes(valid_ciphertext()), Some(2));
+
+        let result = document_type
+            .validate_encrypted_fields(&raw_document.to_btree_ref_string_map().unwrap());
+
+        assert!(result.is_valid(), "{:?}", result.errors);
+    }
+
+    #[test]
+    fn should_reject_a_short_nested_ciphertext() {
+        let document_type = message_document_type();
+        let raw_document = raw_message(Value::Bytes(vec![2; 40]), Some(2));
+
+        let result = document_type
+            .validate_encrypted_fields(&raw_document.to_btree_ref_string_map().unwrap());
+
+        assert!(matches!(
+            result.errors.as_slice(),
+            [ConsensusError::BasicError(
+                BasicError::InvalidEncryptedDocumentFieldError(_)
+            )]
+        ));
+        assert!(encrypted_field_error_reason(&result)
+            .unwrap()
+            .contains("must be at least 61 bytes long, got 40"));
+    }
+
+    #[test]
+    fn should_reject_a_malformed_nested_ciphertext() {
+        let document_type = message_document_type();
+
+        let raw_document = raw_message(Value::Bytes(vec![0; 80]), Some(2));
+        let result = document_type
+            .validate_encrypted_fields(&raw_document.to_btree_ref_string_map().unwrap());
+        assert!(encrypted_field_error_reason(&result)
+            .unwrap()
+            .contains("must start with a valid ephemeral public key"));
+
+        let raw_document = raw_message(Value::Text("not a ciphertext".to_string()), Some(2));
+        let result = document_type
+            .validate_encrypted_fields(&raw_document.to_btree_ref_string_map().unwrap());
+        assert!(encrypted_field_error_reason(&result)
+            .unwrap()
+            .contains("must be a byte array"));
+    }
+
+    #[test]
+    fn should_reject_a_nested_ciphertext_without_recipient_key() {
+        let document_type = message_document_type();
+        let raw_document = raw_message(Value::Bytes(valid_ciphertext()), None);
+
+        let result = document_type
+            .validate_encrypted_fields(&raw_document.to_btree_ref_string_map().unwrap());
+
+        assert!(encrypted_field_error_reason(&result)
+            .unwrap()
+            .contains("requires message.recipientKeyIndex to be set"));
+    }
+}
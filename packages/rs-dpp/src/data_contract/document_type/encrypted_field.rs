@@ -0,0 +1,97 @@
+use std::collections::BTreeMap;
+
+use platform_value::btreemap_extensions::BTreeValueMapHelper;
+use platform_value::Value;
+use serde::{Deserialize, Serialize};
+
+use crate::data_contract::document_type::{property_names, DocumentField, DocumentFieldType};
+use crate::data_contract::errors::{DataContractError, StructureError};
+use crate::identity::encryption::ECIES_OVERHEAD;
+use crate::ProtocolError;
+
+/// A byte array property encrypted to a key of the recipient identity
+///
+/// Defined in a document schema as
+/// `"encryption": { "recipientIdentityField": "toUserId", "recipientKeyIdField": "recipientKeyIndex" }`
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct EncryptedField {
+    /// The property, as a dotted path from the document root, holding the id of the identity
+    /// the field is encrypted to
+    pub recipient_identity_field: String,
+    /// The property, as a dotted path from the document root, holding the id of the recipient
+    /// key the field is encrypted to
+    pub recipient_key_id_field: String,
+}
+
+impl EncryptedField {
+    /// Parses the encryption annotation of a property and checks that it
+    /// references properties of the right types
+    pub fn from_property_definition(
+        property_name: &str,
+        encryption_value: &Value,
+        flattened_properties: &BTreeMap<String, DocumentField>,
+    ) -> Result<Self, ProtocolError> {
+        let encryption_map = encryption_value.to_btree_ref_string_map()?;
+
+        let encrypted_field = EncryptedField {
+            recipient_identity_field: encryption_map
+                .get_string(property_names::RECIPIENT_IDENTITY_FIELD)
+                .map_err(|_| {
+                    ProtocolError::StructureError(StructureError::ValueWrongType(
+                        "encryption must define recipientIdentityField",
+                    ))
+                })?,
+            recipient_key_id_field: encryption_map
+                .get_string(property_names::RECIPIENT_KEY_ID_FIELD)
+                .map_err(|_| {
+                    ProtocolError::StructureError(StructureError::ValueWrongType(
+                        "encryption must define recipientKeyIdField",
+                    ))
+                })?,
+        };
+
+        match flattened_properties
+            .get(property_name)
+            .map(|field| &field.document_type)
+        {
+            Some(DocumentFieldType::ByteArray(Some(min_items), Some(_)))
+                if *min_items as usize >= ECIES_OVERHEAD => {}
+            _ => {
+                return Err(ProtocolError::DataContractError(
+                    DataContractError::InvalidContractStructure(
+                        "encrypted fields must be byte arrays with maxItems and minItems of at least 61",
+                    ),
+                ))
+            }
+        }
+
+        if !matches!(
+            flattened_properties
+                .get(&encrypted_field.recipient_identity_field)
+                .map(|field| &field.document_type),
+            Some(DocumentFieldType::Identifier)
+        ) {
+            return Err(ProtocolError::DataContractError(
+                DataContractError::InvalidContractStructure(
+                    "recipientIdentityField must reference an identifier property",
+                ),
+            ));
+        }
+
+        if !matches!(
+            flattened_properties
+                .get(&encrypted_field.recipient_key_id_field)
+                .map(|field| &field.document_type),
+            Some(DocumentFieldType::Integer)
+        ) {
+            return Err(ProtocolError::DataContractError(
+                DataContractError::InvalidContractStructure(
+                    "recipientKeyIdField must reference an integer property",
+                ),
+            ));
+        }
+
+        Ok(encrypted_field)
+    }
+}
@@ -6,6 +6,7 @@ use platform_value::{Value, ValueMap};
 use rand::distributions::{Alphanumeric, DistString};
 use serde::{Deserialize, Serialize};
 use std::{collections::BTreeMap, convert::TryFrom};
+use unicode_normalization::UnicodeNormalization;
 
 // Indices documentation:  https://dashplatform.readme.io/docs/reference-data-contracts#document-indices
 #[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
@@ -13,6 +14,73 @@ pub struct Index {
     pub name: String,
     pub properties: Vec<IndexProperty>,
     pub unique: bool,
+    /// The normalization applied to the string values of the index
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub collation: Option<IndexCollation>,
+}
+
+/// How string values are normalized before being stored as index keys, values that
+/// normalize to the same string are equal for the index
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, PartialEq, Eq, Hash)]
+#[serde(rename_all = "camelCase")]
+pub enum IndexCollation {
+    /// Values are lowercased
+    CaseInsensitive,
+    /// Values are in Unicode normalization form KC
+    Nfkc,
+    /// Values are in normalization form KC, lowercased and have the characters that look
+    /// like digits replaced by them, `o` by `0`, `i` and `l` by `1`
+    Homoglyph,
+}
+
+impl IndexCollation {
+    /// Normalizes a string
+    pub fn normalize_str(&self, value: &str) -> String {
+        match self {
+            IndexCollation::CaseInsensitive => value.to_lowercase(),
+            IndexCollation::Nfkc => value.nfkc().collect(),
+            IndexCollation::Homoglyph => value
+                .nfkc()
+                .collect::<String>()
+                .to_lowercase()
+                .chars()
+                .map(|character| match character {
+                    'o' => '0',
+                    'i' | 'l' => '1',
+                    character => character,
+                })
+                .collect(),
+        }
+    }
+
+    /// Normalizes the strings of a value, the elements of arrays being normalized as well
+    pub fn normalize_value(&self, value: &Value) -> Value {
+        match value {
+            Value::Text(text) => Value::Text(self.normalize_str(text)),
+            Value::Array(elements) => Value::Array(
+                elements
+                    .iter()
+                    .map(|element| self.normalize_value(element))
+                    .collect(),
+            ),
+            value => value.clone(),
+        }
+    }
+}
+
+impl TryFrom<&str> for IndexCollation {
+    type Error = ProtocolError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "caseInsensitive" => Ok(IndexCollation::CaseInsensitive),
+            "nfkc" => Ok(IndexCollation::Nfkc),
+            "homoglyph" => Ok(IndexCollation::Homoglyph),
+            _ => Err(ProtocolError::DataContractError(
+                DataContractError::InvalidContractStructure("unknown index collation"),
+            )),
+        }
+    }
 }
 
 impl Index {
@@ -29,7 +97,12 @@ impl Index {
             let Some(value2) = Value::get_optional_from_map(object2,property.name.as_str()) else {
                 return false;
             };
-            value1 == value2
+            match self.collation {
+                Some(collation) => {
+                    collation.normalize_value(value1) == collation.normalize_value(value2)
+                }
+                None => value1 == value2,
+            }
         })
     }
 
@@ -56,6 +129,8 @@ pub struct IndexWithRawProperties {
     pub properties: Vec<BTreeMap<String, String>>,
     #[serde(default)]
     pub unique: bool,
+    #[serde(default)]
+    pub collation: Option<IndexCollation>,
 }
 
 impl TryFrom<BTreeMap<String, String>> for IndexProperty {
@@ -101,6 +176,7 @@ impl TryFrom<IndexWithRawProperties> for Index {
             name: index.name,
             unique: index.unique,
             properties,
+            collation: index.collation,
         })
     }
 }
@@ -202,6 +278,7 @@ impl TryFrom<&[(Value, Value)]> for Index {
         // For properties, we iterate each and move it to IndexProperty
 
         let mut unique = false;
+        let mut collation = None;
         let mut name = None;
         let mut index_properties: Vec<IndexProperty> = Vec::new();
 
@@ -228,6 +305,16 @@ impl TryFrom<&[(Value, Value)]> for Index {
                         unique = value_value.as_bool().expect("confirmed as bool");
                     }
                 }
+                "collation" => {
+                    let collation_str = value_value.as_text().ok_or({
+                        ProtocolError::DataContractError(
+                            DataContractError::InvalidContractStructure(
+                                "index collation should be a string",
+                            ),
+                        )
+                    })?;
+                    collation = Some(IndexCollation::try_from(collation_str)?);
+                }
                 "properties" => {
                     let properties =
                         value_value.as_array().ok_or(ProtocolError::StructureError(
@@ -258,6 +345,7 @@ impl TryFrom<&[(Value, Value)]> for Index {
             name,
             properties: index_properties,
             unique,
+            collation,
         })
     }
 }
@@ -0,0 +1,402 @@
+//! Human readable JSON of documents, following their document type
+//!
+//! Identifiers are rendered as base58 strings and byte arrays as base64 strings, as their
+//! document type says, and dates either as milliseconds since the Unix epoch or as ISO-8601
+//! strings. Properties the document type doesn't describe are rendered like any platform value.
+//! Parsing goes the other way and accepts both date formats.
+
+use std::convert::TryInto;
+
+use chrono::{DateTime, NaiveDateTime, SecondsFormat, Utc};
+use platform_value::Value;
+use serde_json::{Map, Number, Value as JsonValue};
+
+use crate::data_contract::document_type::{DocumentFieldType, DocumentType};
+use crate::document::document::property_names;
+use crate::document::{Document, DocumentView};
+use crate::identity::TimestampMillis;
+use crate::prelude::Identifier;
+use crate::ProtocolError;
+
+/// How dates are rendered in JSON
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum JsonDateFormat {
+    /// Milliseconds since the Unix epoch, as dates are stored
+    #[default]
+    Milliseconds,
+    /// ISO-8601 date and time in UTC, with milliseconds
+    Iso8601,
+}
+
+impl JsonDateFormat {
+    fn render(&self, timestamp: f64) -> Result<JsonValue, ProtocolError> {
+        match self {
+            JsonDateFormat::Milliseconds if timestamp.fract() == 0.0 => {
+                Ok(JsonValue::Number((timestamp as i64).into()))
+            }
+            JsonDateFormat::Milliseconds => Number::from_f64(timestamp)
+                .map(JsonValue::Number)
+                .ok_or_else(|| {
+                    ProtocolError::EncodingError(format!("date {timestamp} is not a number"))
+                }),
+            JsonDateFormat::Iso8601 => {
+                let naive =
+                    NaiveDateTime::from_timestamp_millis(timestamp as i64).ok_or_else(|| {
+                        ProtocolError::EncodingError(format!("date {timestamp} is out of range"))
+                    })?;
+                let datetime: DateTime<Utc> = DateTime::from_utc(naive, Utc);
+                Ok(JsonValue::String(
+                    datetime.to_rfc3339_opts(SecondsFormat::Millis, true),
+                ))
+            }
+        }
+    }
+}
+
+/// Parses a date given as milliseconds since the Unix epoch or as an ISO-8601 string
+fn parse_date(json: &JsonValue) -> Result<f64, ProtocolError> {
+    match json {
+        JsonValue::Number(number) => number
+            .as_f64()
+            .ok_or_else(|| ProtocolError::DecodingError(format!("invalid date {number}"))),
+        JsonValue::String(string) => DateTime::parse_from_rfc3339(string)
+            .map(|datetime| datetime.timestamp_millis() as f64)
+            .map_err(|e| ProtocolError::DecodingError(format!("invalid date {string}: {e}"))),
+        _ => Err(ProtocolError::DecodingError(format!(
+            "expected a date, got {json}"
+        ))),
+    }
+}
+
+fn parse_identifier(json: &JsonValue) -> Result<Identifier, ProtocolError> {
+    match json {
+        JsonValue::String(string) => {
+            let bytes: [u8; 32] = bs58::decode(string)
+                .into_vec()
+                .map_err(|e| ProtocolError::DecodingError(format!("invalid identifier: {e}")))?
+                .try_into()
+                .map_err(|_| {
+                    ProtocolError::DecodingError(format!(
+                        "identifier {string} must be 32 bytes long"
+                    ))
+                })?;
+            Ok(Identifier::new(bytes))
+        }
+        json => Ok(Identifier::new(
+            Value::from(json)
+                .to_identifier_bytes()?
+                .try_into()
+                .map_err(|_| {
+                    ProtocolError::DecodingError("identifier must be 32 bytes long".to_string())
+                })?,
+        )),
+    }
+}
+
+fn render_value(value: &Value) -> Result<JsonValue, ProtocolError> {
+    value.clone().try_into().map_err(ProtocolError::ValueError)
+}
+
+impl DocumentFieldType {
+    /// Renders a value of the field type as human readable JSON
+    pub fn value_to_json(
+        &self,
+        value: &Value,
+        date_format: JsonDateFormat,
+    ) -> Result<JsonValue, ProtocolError> {
+        if value.is_null() {
+            return Ok(JsonValue::Null);
+        }
+        match self {
+            DocumentFieldType::Identifier => Ok(JsonValue::String(
+                bs58::encode(value.to_identifier_bytes()?).into_string(),
+            )),
+            DocumentFieldType::ByteArray(_, _) => {
+                Ok(JsonValue::String(base64::encode(value.to_binary_bytes()?)))
+            }
+            DocumentFieldType::Date => {
+                date_format.render(value.to_float().map_err(ProtocolError::ValueError)?)
+            }
+            DocumentFieldType::Object(inner_fields) => {
+                let map = value.to_map().map_err(ProtocolError::ValueError)?;
+                map.iter()
+                    .map(|(key, value)| {
+                        let key = key.to_text().map_err(ProtocolError::ValueError)?;
+                        let json = match inner_fields.get(&key) {
+                            Some(field) => field.document_type.value_to_json(value, date_format)?,
+                            None => render_value(value)?,
+                        };
+                        Ok((key, json))
+                    })
+                    .collect::<Result<Map<String, JsonValue>, ProtocolError>>()
+                    .map(JsonValue::Object)
+            }
+            DocumentFieldType::Array(array_field_type) => {
+                let element_field_type = array_field_type.element_field_type();
+                value
+                    .to_array_slice()
+                    .map_err(ProtocolError::ValueError)?
+                    .iter()
+                    .map(|element| element_field_type.value_to_json(element, date_format))
+                    .collect::<Result<Vec<JsonValue>, ProtocolError>>()
+                    .map(JsonValue::Array)
+            }
+            _ => render_value(value),
+        }
+    }
+
+    /// Parses a value of the field type from its human readable JSON
+    pub fn value_from_json(&self, json: &JsonValue) -> Result<Value, ProtocolError> {
+        if json.is_null() {
+            return Ok(Value::Null);
+        }
+        match self {
+            DocumentFieldType::Identifier => Ok(parse_identifier(json)?.into()),
+            DocumentFieldType::ByteArray(_, _) => match json {
+                JsonValue::String(string) => base64::decode(string)
+                    .map(Value::Bytes)
+                    .map_err(|e| ProtocolError::DecodingError(format!("invalid bytes: {e}"))),
+                json => Ok(Value::Bytes(Value::from(json).to_binary_bytes()?)),
+            },
+            DocumentFieldType::Date => Ok(Value::Float(parse_date(json)?)),
+            DocumentFieldType::Object(inner_fields) => {
+                let object = json.as_object().ok_or_else(|| {
+                    ProtocolError::DecodingError(format!("expected an object, got {json}"))
+                })?;
+                object
+                    .iter()
+                    .map(|(key, json)| {
+                        let value = match inner_fields.get(key) {
+                            Some(field) => field.document_type.value_from_json(json)?,
+                            None => json.into(),
+                        };
+                        Ok((Value::Text(key.clone()), value))
+                    })
+                    .collect::<Result<Vec<(Value, Value)>, ProtocolError>>()
+                    .map(Value::Map)
+            }
+            DocumentFieldType::Array(array_field_type) => {
+                let element_field_type = array_field_type.element_field_type();
+                let array = json.as_array().ok_or_else(|| {
+                    ProtocolError::DecodingError(format!("expected an array, got {json}"))
+                })?;
+                array
+                    .iter()
+                    .map(|element| element_field_type.value_from_json(element))
+                    .collect::<Result<Vec<Value>, ProtocolError>>()
+                    .map(Value::Array)
+            }
+            _ => Ok(json.into()),
+        }
+    }
+}
+
+impl DocumentType {
+    /// Renders a document of this type as human readable JSON
+    pub fn document_to_json(
+        &self,
+        document: &Document,
+        date_format: JsonDateFormat,
+    ) -> Result<JsonValue, ProtocolError> {
+        let mut json = self.system_properties_to_json(
+            &document.id,
+            &document.owner_id,
+            document.revision,
+            document.created_at,
+            document.updated_at,
+            date_format,
+        )?;
+        for (name, value) in document.properties.iter() {
+            json.insert(
+                name.clone(),
+                self.property_to_json(name, value, date_format)?,
+            );
+        }
+        Ok(JsonValue::Object(json))
+    }
+
+    /// Renders a serialized document of this type as human readable JSON, without building the
+    /// document first
+    pub fn serialized_document_to_json(
+        &self,
+        serialized_document: &[u8],
+        date_format: JsonDateFormat,
+    ) -> Result<JsonValue, ProtocolError> {
+        let view = DocumentView::new(serialized_document, self)?;
+        let mut json = self.system_properties_to_json(
+            &view.id(),
+            &view.owner_id(),
+            view.revision(),
+            view.created_at()?,
+            view.updated_at()?,
+            date_format,
+        )?;
+        for property in view.properties() {
+            let (name, value) = property?;
+            // absent optional properties are left out, and timestamps are already rendered
+            if value.is_null()
+                || name == property_names::CREATED_AT
+                || name == property_names::UPDATED_AT
+            {
+                continue;
+            }
+            json.insert(
+                name.clone(),
+                self.property_to_json(name, &value, date_format)?,
+            );
+        }
+        Ok(JsonValue::Object(json))
+    }
+
+    /// Parses a document of this type from its human readable JSON
+    pub fn document_from_json(&self, json: &JsonValue) -> Result<Document, ProtocolError> {
+        let object = json.as_object().ok_or_else(|| {
+            ProtocolError::DecodingError(format!("expected a document object, got {json}"))
+        })?;
+        let mut document = Document::default();
+        for (name, json) in object.iter() {
+            match name.as_str() {
+                property_names::ID => document.id = parse_identifier(json)?,
+                property_names::OWNER_ID => document.owner_id = parse_identifier(json)?,
+                property_names::REVISION => {
+                    document.revision = Value::from(json)
+                        .into_integer()
+                        .map(Some)
+                        .map_err(ProtocolError::ValueError)?
+                }
+                property_names::CREATED_AT => document.created_at = parse_timestamp(json)?,
+                property_names::UPDATED_AT => document.updated_at = parse_timestamp(json)?,
+                _ => {
+                    let value = match self.properties.get(name) {
+                        Some(field) => field.document_type.value_from_json(json)?,
+                        None => json.into(),
+                    };
+                    document.properties.insert(name.clone(), value);
+                }
+            }
+        }
+        Ok(document)
+    }
+
+    fn property_to_json(
+        &self,
+        name: &str,
+        value: &Value,
+        date_format: JsonDateFormat,
+    ) -> Result<JsonValue, ProtocolError> {
+        match self.properties.get(name) {
+            Some(field) => field.document_type.value_to_json(value, date_format),
+            None => render_value(value),
+        }
+    }
+
+    fn system_properties_to_json(
+        &self,
+        id: &Identifier,
+        owner_id: &Identifier,
+        revision: Option<u64>,
+        created_at: Option<TimestampMillis>,
+        updated_at: Option<TimestampMillis>,
+        date_format: JsonDateFormat,
+    ) -> Result<Map<String, JsonValue>, ProtocolError> {
+        let mut json = Map::new();
+        json.insert(
+            property_names::ID.to_string(),
+            JsonValue::String(bs58::encode(id.as_slice()).into_string()),
+        );
+        json.insert(
+            property_names::OWNER_ID.to_string(),
+            JsonValue::String(bs58::encode(owner_id.as_slice()).into_string()),
+        );
+        if let Some(revision) = revision {
+            json.insert(property_names::REVISION.to_string(), revision.into());
+        }
+        if let Some(created_at) = created_at {
+            json.insert(
+                property_names::CREATED_AT.to_string(),
+                date_format.render(created_at as f64)?,
+            );
+        }
+        if let Some(updated_at) = updated_at {
+            json.insert(
+                property_names::UPDATED_AT.to_string(),
+                date_format.render(updated_at as f64)?,
+            );
+        }
+        Ok(json)
+    }
+}
+
+fn parse_timestamp(json: &JsonValue) -> Result<Option<TimestampMillis>, ProtocolError> {
+    if json.is_null() {
+        return Ok(None);
+    }
+    Ok(Some(parse_date(json)? as TimestampMillis))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_contract::document_type::random_document::CreateRandomDocument;
+    use crate::data_contract::extra::common::json_document_to_contract;
+    use serde_json::json;
+
+    #[test]
+    fn should_render_identifiers_bytes_and_dates_from_the_document_type() {
+        let contract = json_document_to_contract(
+            "../rs-dpp/src/tests/payloads/contract/dashpay-contract.json",
+        )
+        .expect("expected to get dashpay contract");
+        let document_type = contract
+            .document_type_for_name("contactRequest")
+            .expect("expected to get contact request document type");
+        let mut document = document_type.random_document(Some(3333));
+        document.created_at = Some(1_681_000_000_123);
+
+        let json = document_type
+            .document_to_json(&document, JsonDateFormat::Iso8601)
+            .expect("expected to render the document");
+        assert_eq!(
+            json["$id"],
+            json!(bs58::encode(document.id.as_slice()).into_string())
+        );
+        assert_eq!(json["$createdAt"], json!("2023-04-09T00:26:40.123Z"));
+        assert_eq!(
+            json["toUserId"],
+            json!(bs58::encode(
+                document.properties["toUserId"]
+                    .to_identifier_bytes()
+                    .unwrap()
+            )
+            .into_string())
+        );
+        assert_eq!(
+            json["encryptedPublicKey"],
+            json!(base64::encode(
+                document.properties["encryptedPublicKey"]
+                    .to_binary_bytes()
+                    .unwrap()
+            ))
+        );
+
+        let parsed = document_type
+            .document_from_json(&json)
+            .expect("expected to parse the document");
+        assert_eq!(
+            document_type
+                .document_to_json(&parsed, JsonDateFormat::Milliseconds)
+                .unwrap(),
+            document_type
+                .document_to_json(&document, JsonDateFormat::Milliseconds)
+                .unwrap()
+        );
+
+        let serialized = document.serialize(document_type).unwrap();
+        assert_eq!(
+            document_type
+                .serialized_document_to_json(&serialized, JsonDateFormat::Iso8601)
+                .unwrap(),
+            json
+        );
+    }
+}
@@ -2,26 +2,34 @@ pub mod array_field;
 pub mod document_factory;
 pub mod document_field;
 pub mod document_type;
+pub mod encrypted_field;
 pub mod index;
+pub mod json_conversion;
 pub mod random_document;
 pub mod random_document_type;
 pub mod random_index;
+pub mod transition_schema_validators;
 
 use super::errors::DataContractError;
 
 pub use {
     array_field::ArrayFieldType,
     document_field::{
-        encode_date_timestamp, encode_float, encode_signed_integer, encode_unsigned_integer,
-        DocumentField, DocumentFieldType,
+        decimal_string_to_mantissa, encode_date_timestamp, encode_float, encode_i128,
+        encode_signed_integer, encode_u128, encode_unsigned_integer, mantissa_to_decimal_string,
+        DocumentField, DocumentFieldType, MAX_DECIMAL_SCALE,
     },
     document_type::{DocumentType, IndexLevel},
-    index::{Index, IndexProperty},
+    encrypted_field::EncryptedField,
+    index::{Index, IndexCollation, IndexProperty},
+    json_conversion::JsonDateFormat,
+    transition_schema_validators::TransitionSchemaValidators,
 };
 
 pub(self) mod property_names {
     pub const DOCUMENTS_KEEP_HISTORY: &str = "documentsKeepHistory";
     pub const DOCUMENTS_MUTABLE: &str = "documentsMutable";
+    pub const DOCUMENTS_TTL: &str = "documentsTtl";
     pub const INDICES: &str = "indices";
     pub const PROPERTIES: &str = "properties";
     pub const REQUIRED: &str = "required";
@@ -35,4 +43,10 @@ pub(self) mod property_names {
     pub const MAX_LENGTH: &str = "maxLength";
     pub const BYTE_ARRAY: &str = "byteArray";
     pub const CONTENT_MEDIA_TYPE: &str = "contentMediaType";
+    pub const BIG_INTEGER: &str = "bigInteger";
+    pub const DECIMAL_SCALE: &str = "decimalScale";
+    pub const ITEMS: &str = "items";
+    pub const ENCRYPTION: &str = "encryption";
+    pub const RECIPIENT_IDENTITY_FIELD: &str = "recipientIdentityField";
+    pub const RECIPIENT_KEY_ID_FIELD: &str = "recipientKeyIdField";
 }
@@ -98,7 +98,7 @@ impl RandomDocumentTypeParameters {
 }
 
 use crate::data_contract::document_type::{
-    DocumentField, DocumentFieldType, DocumentType, Index, IndexLevel,
+    DocumentField, DocumentFieldType, DocumentType, Index, IndexLevel, TransitionSchemaValidators,
 };
 use crate::ProtocolError;
 use platform_value::Identifier;
@@ -236,7 +236,10 @@ impl DocumentType {
             required_fields,
             documents_keep_history,
             documents_mutable,
+            documents_ttl: None,
+            encrypted_fields: BTreeMap::new(),
             data_contract_id,
+            transition_schema_validators: TransitionSchemaValidators::default(),
         })
     }
 }
@@ -0,0 +1,56 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+use crate::document::document_transition::Action;
+use crate::validation::ValueSchemaValidator;
+use crate::ProtocolError;
+
+/// The compiled schemas of the transitions creating and replacing documents of a document type
+///
+/// Schemas are compiled the first time a transition of the action is validated and kept as long
+/// as the document type, so contracts kept in the drive cache are compiled once and not for
+/// every batch. A clone starts with no compiled schemas, as its schema may be changed.
+#[derive(Default)]
+pub struct TransitionSchemaValidators {
+    validators: Mutex<HashMap<Action, Arc<ValueSchemaValidator>>>,
+}
+
+impl TransitionSchemaValidators {
+    /// Returns the validator of the action, compiling it with `compile` if it wasn't yet
+    pub fn get_or_compile(
+        &self,
+        action: Action,
+        compile: impl FnOnce() -> Result<ValueSchemaValidator, ProtocolError>,
+    ) -> Result<Arc<ValueSchemaValidator>, ProtocolError> {
+        let mut validators = self
+            .validators
+            .lock()
+            .expect("transition schema validators lock should not be poisoned");
+        if let Some(validator) = validators.get(&action) {
+            return Ok(validator.clone());
+        }
+        let validator = Arc::new(compile()?);
+        validators.insert(action, validator.clone());
+        Ok(validator)
+    }
+}
+
+impl Clone for TransitionSchemaValidators {
+    fn clone(&self) -> Self {
+        Self::default()
+    }
+}
+
+impl fmt::Debug for TransitionSchemaValidators {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TransitionSchemaValidators").finish()
+    }
+}
+
+/// Compiled schemas are derived from the document type, they don't make two types different
+impl PartialEq for TransitionSchemaValidators {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
@@ -1,5 +1,6 @@
 use crate::data_contract::state_transition::data_contract_update_transition::DataContractUpdateTransition;
 use crate::data_contract::DataContract;
+use crate::state_transition::fee::Credits;
 use serde::{Deserialize, Serialize};
 
 pub const DATA_CONTRACT_UPDATE_TRANSITION_ACTION_VERSION: u32 = 0;
@@ -9,6 +10,10 @@ pub const DATA_CONTRACT_UPDATE_TRANSITION_ACTION_VERSION: u32 = 0;
 pub struct DataContractUpdateTransitionAction {
     pub version: u32,
     pub data_contract: DataContract,
+    /// Whether the indices added by the update are built from the existing documents
+    pub backfill_indices: bool,
+    /// The processing fee of counting the documents to backfill during validation
+    pub backfill_validation_processing_fee: Credits,
 }
 
 impl From<DataContractUpdateTransition> for DataContractUpdateTransitionAction {
@@ -16,6 +21,8 @@ impl From<DataContractUpdateTransition> for DataContractUpdateTransitionAction {
         DataContractUpdateTransitionAction {
             version: DATA_CONTRACT_UPDATE_TRANSITION_ACTION_VERSION,
             data_contract: value.data_contract,
+            backfill_indices: false,
+            backfill_validation_processing_fee: 0,
         }
     }
 }
@@ -25,6 +32,8 @@ impl From<&DataContractUpdateTransition> for DataContractUpdateTransitionAction
         DataContractUpdateTransitionAction {
             version: DATA_CONTRACT_UPDATE_TRANSITION_ACTION_VERSION,
             data_contract: value.data_contract.clone(),
+            backfill_indices: false,
+            backfill_validation_processing_fee: 0,
         }
     }
 }
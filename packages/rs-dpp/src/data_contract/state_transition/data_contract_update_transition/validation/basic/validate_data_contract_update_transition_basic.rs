@@ -291,6 +291,7 @@ where
         let result = validate_indices_are_backward_compatible(
             existing_data_contract.documents.iter(),
             new_documents,
+            true,
         )?;
         if !result.is_valid() {
             return Ok(result);
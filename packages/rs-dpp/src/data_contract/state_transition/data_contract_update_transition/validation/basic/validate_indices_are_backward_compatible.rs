@@ -19,9 +19,14 @@ type DocumentType = String;
 type JsonSchema = serde_json::Value;
 
 //todo: change this to use Platform value and document types
+/// Validates that the indices of an updated contract are compatible with the existing ones.
+/// When `new_indices_are_backfilled` is set, Drive builds new non-unique indices from the
+/// existing documents, so they may use any property. Otherwise new indices can only extend
+/// existing indices or cover a newly added property.
 pub fn validate_indices_are_backward_compatible<'a>(
     existing_documents: impl IntoIterator<Item = (&'a DocumentType, &'a JsonSchema)>,
     new_documents: impl IntoIterator<Item = (&'a DocumentType, &'a JsonSchema)>,
+    new_indices_are_backfilled: bool,
 ) -> Result<SimpleConsensusValidationResult, ProtocolError> {
     let mut result = SimpleConsensusValidationResult::default();
     let new_documents_by_type: HashMap<&DocumentType, &JsonSchema> =
@@ -92,6 +97,7 @@ pub fn validate_indices_are_backward_compatible<'a>(
             ))
         }
 
+        // New unique indices could be violated by the existing documents
         let maybe_new_unique_index =
             get_new_unique_index(&existing_schema_indices, name_new_index_map.values())?;
         if let Some(index) = maybe_new_unique_index {
@@ -102,6 +108,11 @@ pub fn validate_indices_are_backward_compatible<'a>(
                 ),
             ))
         }
+
+        // Drive builds new non-unique indices from the existing documents
+        if new_indices_are_backfilled {
+            continue;
+        }
         let maybe_wrongly_constructed_new_index = get_wrongly_constructed_new_index(
             existing_schema_indices.iter(),
             name_new_index_map.values(),
@@ -228,6 +239,11 @@ fn get_wrongly_updated_non_unique_index<'a>(
     for index_definition in existing_schema_indices.iter().filter(|i| !i.unique) {
         let maybe_new_index_definition = new_indices.get(&index_definition.name);
         if let Some(new_index_definition) = maybe_new_index_definition {
+            // Stored keys are normalized with the collation, it can't change for existing keys
+            if new_index_definition.collation != index_definition.collation {
+                return Ok(Some(index_definition));
+            }
+
             // Non-unique index can be ONLY updated by appending. The 'old' properties in the new
             // index must remain intact.
             let index_properties_len = index_definition.properties.len();
@@ -15,7 +15,7 @@ use crate::validation::{ConsensusValidationResult, SimpleConsensusValidationResu
 use crate::{
     consensus::basic::BasicError,
     data_contract::{
-        enrich_with_base_schema::PREFIX_BYTE_0,
+        document_type::IndexCollation, enrich_with_base_schema::PREFIX_BYTE_0,
         get_property_definition_by_path::get_property_definition_by_path, DataContract,
     },
     util::{
@@ -248,6 +248,9 @@ impl DataContract {
             if should_stop_further_validation {
                 return Ok(result);
             }
+
+            trace!("\t validating index collations");
+            result.merge(Self::validate_index_collations(&indices, document_type));
         }
 
         Ok(result)
@@ -297,6 +300,21 @@ impl DataContract {
                 return (result, true);
             }
 
+            result.merge(DataContract::validate_array_properties_of_index(
+                &property_definition_entities,
+                document_type,
+                index_definition,
+            ));
+
+            for property_name in property_definition_entities.keys() {
+                result.merge(DataContract::validate_nested_property_path(
+                    property_name,
+                    document_schema,
+                    document_type,
+                    index_definition,
+                ));
+            }
+
             // Validation of property defs
             for (property_name, maybe_property_definition) in property_definition_entities {
                 result.merge(DataContract::validate_property_definition(
@@ -350,6 +368,132 @@ impl DataContract {
         (result, false)
     }
 
+    /// checks that all the indices containing a property use the same collation, as indices
+    /// share the trees of their common properties
+    fn validate_index_collations(
+        indices: &[Index],
+        document_type: &str,
+    ) -> SimpleConsensusValidationResult {
+        let mut result = SimpleConsensusValidationResult::default();
+        let mut property_collations: HashMap<&str, Option<IndexCollation>> = HashMap::new();
+
+        for index_definition in indices.iter() {
+            for property in index_definition.properties.iter() {
+                let collation = property_collations
+                    .entry(property.name.as_str())
+                    .or_insert(index_definition.collation);
+                if *collation != index_definition.collation {
+                    result.add_error(ConsensusError::BasicError(
+                        BasicError::InvalidIndexedPropertyConstraintError(
+                            InvalidIndexedPropertyConstraintError::new(
+                                document_type.to_owned(),
+                                index_definition.name.to_owned(),
+                                property.name.to_owned(),
+                                String::from("collation"),
+                                String::from(
+                                    "all indices of a property must use the same collation",
+                                ),
+                            ),
+                        ),
+                    ));
+                }
+            }
+        }
+
+        result
+    }
+
+    /// checks that an index has at most one array property, and none when it is unique,
+    /// as array properties create an index entry for every element
+    fn validate_array_properties_of_index(
+        properties: &HashMap<&String, Option<&JsonValue>>,
+        document_type: &str,
+        index_definition: &Index,
+    ) -> SimpleConsensusValidationResult {
+        let mut result = SimpleConsensusValidationResult::default();
+
+        let array_properties = index_definition
+            .properties
+            .iter()
+            .map(|property| &property.name)
+            .filter(|property_name| {
+                properties
+                    .get(property_name)
+                    .and_then(|definition| *definition)
+                    .map(|definition| {
+                        definition.is_type_of_array() && !definition.is_type_of_byte_array()
+                    })
+                    .unwrap_or_default()
+            })
+            .collect::<Vec<_>>();
+
+        if let Some(property_name) = array_properties.first() {
+            if index_definition.unique {
+                result.add_error(ConsensusError::BasicError(
+                    BasicError::InvalidIndexedPropertyConstraintError(
+                        InvalidIndexedPropertyConstraintError::new(
+                            document_type.to_owned(),
+                            index_definition.name.to_owned(),
+                            (*property_name).to_owned(),
+                            String::from("type"),
+                            String::from("array properties can't be part of a unique index"),
+                        ),
+                    ),
+                ));
+            }
+        }
+
+        if let Some(property_name) = array_properties.get(1) {
+            result.add_error(ConsensusError::BasicError(
+                BasicError::InvalidIndexedPropertyConstraintError(
+                    InvalidIndexedPropertyConstraintError::new(
+                        document_type.to_owned(),
+                        index_definition.name.to_owned(),
+                        (*property_name).to_owned(),
+                        String::from("type"),
+                        String::from("an index can contain at most one array property"),
+                    ),
+                ),
+            ));
+        }
+
+        result
+    }
+
+    /// checks that every parent of a nested index property is an object, as values nested
+    /// inside arrays of objects can't be resolved to a single index key
+    fn validate_nested_property_path(
+        property_name: &str,
+        document_schema: &JsonValue,
+        document_type: &str,
+        index_definition: &Index,
+    ) -> SimpleConsensusValidationResult {
+        let mut result = SimpleConsensusValidationResult::default();
+
+        let parent_paths = property_name
+            .match_indices('.')
+            .map(|(position, _)| &property_name[..position]);
+
+        for parent_path in parent_paths {
+            let parent_is_array = get_property_definition_by_path(document_schema, parent_path)
+                .map(|parent_definition| parent_definition.is_type_of_array())
+                .unwrap_or_default();
+            if parent_is_array {
+                result.add_error(ConsensusError::BasicError(
+                    BasicError::InvalidIndexPropertyTypeError(InvalidIndexPropertyTypeError::new(
+                        document_type.to_owned(),
+                        index_definition.name.to_owned(),
+                        property_name.to_owned(),
+                        String::from("array"),
+                    )),
+                ));
+                break;
+            }
+        }
+
+        result
+    }
+
     fn validate_property_definition(
         property_name: &str,
         maybe_property_definition: Option<&JsonValue>,
@@ -367,22 +511,23 @@ impl DataContract {
             invalid_property_type = "object".to_string()
         }
 
-        // Validate arrays contain scalar values or have the same types
-        // https://github.com/dashevo/platform/blob/ab6391f4b47a970c733e7b81115b44329fbdf993/packages/js-dpp/lib/dataContract/validation/validateDataContractFactory.js#L210
+        // Arrays are indexed by each of their elements, so only arrays of scalar items
+        // defined with `items` are allowed
         if property_definition.is_type_of_array() && !is_byte_array {
-            invalid_property_type = "array".to_string();
-            // const isInvalidPrefixItems = prefixItems
-            //   && (
-            // prefixItems.some((prefixItem) =>
-            // prefixItem.type === 'object' || prefixItem.type === 'array')
-            //     || !prefixItems.every((prefixItem) => prefixItem.type === prefixItems[0].type)
-            //   );
-            //
-            // const isInvalidItemTypes = items.type === 'object' || items.type === 'array';
-            //
-            // if (isInvalidPrefixItems || isInvalidItemTypes) {
-            //   invalidPropertyType = 'array';
-            // }
+            let has_prefix_items = property_definition.get("prefixItems").is_some();
+            let items_type = property_definition
+                .get("items")
+                .and_then(|items| items.get("type"))
+                .and_then(|items_type| items_type.as_str());
+
+            if has_prefix_items
+                || !matches!(
+                    items_type,
+                    Some("string") | Some("integer") | Some("number") | Some("boolean")
+                )
+            {
+                invalid_property_type = "array".to_string();
+            }
         }
 
         if !invalid_property_type.is_empty() {
@@ -396,34 +541,6 @@ impl DataContract {
             ));
         }
 
-        // https://github.com/dashevo/platform/blob/ab6391f4b47a970c733e7b81115b44329fbdf993/packages/js-dpp/lib/dataContract/validation/validateDataContractFactory.js#L236
-        // Validate sting length inside arrays
-        // if (!invalidPropertyType && propertyType === 'array' && !isByteArray) {
-        //   const isInvalidPrefixItems = prefixItems && prefixItems.some((prefixItem) => (
-        //     prefixItem.type === 'string'
-        //     && (
-        // !prefixItem.maxLength || prefixItem.maxLength > MAX_INDEXED_STRING_PROPERTY_LENGTH
-        //     )
-        //   ));
-        //
-        //   const isInvalidItemTypes = items.type === 'string' && (
-        //     !items.maxLength || items.maxLength > MAX_INDEXED_STRING_PROPERTY_LENGTH
-        //   );
-        //
-        //   if (isInvalidPrefixItems || isInvalidItemTypes) {
-        //     result.addError(
-        //       new InvalidIndexedPropertyConstraintError(
-        //         documentType,
-        //         indexDefinition,
-        //         propertyName,
-        //         'maxLength',
-        //         `should be less or equal ${MAX_INDEXED_STRING_PROPERTY_LENGTH}`,
-        //       ),
-        //     );
-        //   }
-        // }
-        //
-
         if invalid_property_type.is_empty() && property_definition.is_type_of_array() {
             let max_items = property_definition.get_u64("maxItems").ok();
             let max_limit = if is_byte_array {
@@ -447,6 +564,37 @@ impl DataContract {
             }
         }
 
+        // Validate string length inside arrays
+        if invalid_property_type.is_empty()
+            && property_definition.is_type_of_array()
+            && !is_byte_array
+        {
+            if let Some(items) = property_definition.get("items") {
+                if items.is_type_of_string() {
+                    let max_length = items.get_u64("maxLength").ok();
+
+                    if max_length.is_none()
+                        || max_length.unwrap() > MAX_INDEXED_STRING_PROPERTY_LENGTH as u64
+                    {
+                        result.add_error(ConsensusError::BasicError(
+                            BasicError::InvalidIndexedPropertyConstraintError(
+                                InvalidIndexedPropertyConstraintError::new(
+                                    document_type.to_owned(),
+                                    index_definition.name.to_owned(),
+                                    property_name.to_owned(),
+                                    String::from("items.maxLength"),
+                                    format!(
+                                        "should be less or equal than {}",
+                                        MAX_INDEXED_STRING_PROPERTY_LENGTH
+                                    ),
+                                ),
+                            ),
+                        ))
+                    }
+                }
+            }
+        }
+
         if property_definition.is_type_of_string() {
             let max_length = property_definition.get_u64("maxLength").ok();
 
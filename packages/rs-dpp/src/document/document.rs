@@ -47,7 +47,8 @@ use platform_value::btreemap_extensions::BTreeValueRemoveFromMapHelper;
 use platform_value::Value;
 use serde::{Deserialize, Serialize};
 
-use crate::data_contract::document_type::{encode_date_timestamp, DocumentType};
+use crate::data_contract::document_type::document_type::MAX_INDEX_SIZE;
+use crate::data_contract::document_type::{encode_date_timestamp, DocumentFieldType, DocumentType};
 use crate::data_contract::errors::DataContractError;
 
 use crate::document::errors::DocumentError;
@@ -135,6 +136,36 @@ impl Document {
         }
     }
 
+    /// Return the values of every element of an array field given the path to its key
+    /// for a document type. Returns `None` if the field is not an array.
+    pub fn get_raw_array_elements_for_document_type(
+        &self,
+        key_path: &str,
+        document_type: &DocumentType,
+    ) -> Result<Option<Vec<Vec<u8>>>, ProtocolError> {
+        let Some(field) = document_type.flattened_properties.get(key_path) else {
+            return Ok(None);
+        };
+        if !matches!(field.document_type, DocumentFieldType::Array(_)) {
+            return Ok(None);
+        }
+        let Some(value) = self.properties.get_optional_at_path(key_path)? else {
+            return Ok(Some(vec![]));
+        };
+        let elements = field.document_type.encode_array_value_for_tree_keys(
+            &document_type.index_value_for_key(key_path, value),
+        )?;
+        if elements
+            .iter()
+            .any(|element| element.len() > MAX_INDEX_SIZE)
+        {
+            return Err(ProtocolError::DataContractError(
+                DataContractError::FieldRequirementUnmet("value must be less than 256 bytes long"),
+            ));
+        }
+        Ok(Some(elements))
+    }
+
     /// Return a value given the path to its key and the document type for a contract.
     pub fn get_raw_for_contract(
         &self,
@@ -1,11 +1,15 @@
 use platform_value::Value;
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use crate::document::ExtendedDocument;
 use crate::validation::ConsensusValidationResult;
 use crate::{
-    data_contract::DataContract, prelude::Identifier, state_repository::StateRepositoryLike,
-    version::ProtocolVersionValidator, ProtocolError,
+    data_contract::DataContract,
+    prelude::{Identifier, IdentityNonce},
+    state_repository::StateRepositoryLike,
+    version::ProtocolVersionValidator,
+    ProtocolError,
 };
 
 use super::{
@@ -95,8 +99,10 @@ where
     pub fn create_state_transition(
         &self,
         documents: impl IntoIterator<Item = (Action, Vec<ExtendedDocument>)>,
+        nonce_counter: &mut BTreeMap<(Identifier, Identifier), IdentityNonce>,
     ) -> Result<DocumentsBatchTransition, ProtocolError> {
-        self.factory.create_state_transition(documents)
+        self.factory
+            .create_state_transition(documents, nonce_counter)
     }
 
     /// Creates Documents State Transition
@@ -18,6 +18,7 @@ use crate::consensus::ConsensusError;
 use crate::document::document_transition::INITIAL_REVISION;
 use crate::document::Document;
 use crate::identity::TimestampMillis;
+use crate::prelude::IdentityNonce;
 use crate::serialization_traits::PlatformDeserializable;
 use crate::util::entropy_generator::{DefaultEntropyGenerator, EntropyGenerator};
 use crate::{
@@ -51,6 +52,7 @@ const PROPERTY_REVISION: &str = "$revision";
 const PROPERTY_CREATED_AT: &str = "$createdAt";
 const PROPERTY_UPDATED_AT: &str = "$updatedAt";
 const PROPERTY_DOCUMENT_TYPE: &str = "$type";
+const PROPERTY_IDENTITY_CONTRACT_NONCE: &str = "$identityContractNonce";
 
 const DOCUMENT_CREATE_KEYS_TO_STAY: [&str; 5] = [
     PROPERTY_ID,
@@ -205,9 +207,14 @@ where
         Ok(extended_document)
     }
 
+    /// Creates a batch transition of the documents
+    ///
+    /// `nonce_counter` holds the last nonce used by each (owner, data contract) pair; the
+    /// transitions of each contract get the next nonce, which is stored back in the counter.
     pub fn create_state_transition(
         &self,
         documents_iter: impl IntoIterator<Item = (Action, Vec<ExtendedDocument>)>,
+        nonce_counter: &mut BTreeMap<(Identifier, Identifier), IdentityNonce>,
     ) -> Result<DocumentsBatchTransition, ProtocolError> {
         let mut raw_documents_transitions: Vec<Value> = vec![];
         let mut data_contracts: Vec<DataContract> = vec![];
@@ -236,13 +243,34 @@ where
             .unwrap()
             .owner_id()
             .to_owned();
+
+        // All transitions of a contract in the batch share the same nonce
+        let mut identity_contract_nonces: BTreeMap<Identifier, IdentityNonce> = BTreeMap::new();
+        for document in flattened_documents_iter.clone() {
+            identity_contract_nonces
+                .entry(document.data_contract_id)
+                .or_insert_with(|| {
+                    let nonce = nonce_counter
+                        .entry((owner_id, document.data_contract_id))
+                        .or_default();
+                    *nonce += 1;
+                    *nonce
+                });
+        }
+
         for (action, documents) in documents {
             data_contracts.extend(documents.iter().map(|d| d.data_contract.clone()));
 
             let raw_transitions = match action {
-                Action::Create => Self::raw_document_create_transitions(documents)?,
-                Action::Delete => Self::raw_document_delete_transitions(documents)?,
-                Action::Replace => Self::raw_document_replace_transitions(documents)?,
+                Action::Create => {
+                    Self::raw_document_create_transitions(documents, &identity_contract_nonces)?
+                }
+                Action::Delete => {
+                    Self::raw_document_delete_transitions(documents, &identity_contract_nonces)?
+                }
+                Action::Replace => {
+                    Self::raw_document_replace_transitions(documents, &identity_contract_nonces)?
+                }
             };
 
             raw_documents_transitions.extend(raw_transitions);
@@ -363,6 +391,7 @@ where
 
     fn raw_document_create_transitions(
         documents: Vec<ExtendedDocument>,
+        identity_contract_nonces: &BTreeMap<Identifier, IdentityNonce>,
     ) -> Result<Vec<Value>, ProtocolError> {
         let mut raw_transitions = vec![];
         for document in documents {
@@ -389,6 +418,7 @@ where
                 PROPERTY_ENTROPY.to_string(),
                 Value::Bytes(document.entropy.to_vec()),
             );
+            Self::insert_identity_contract_nonce(&mut map, &document, identity_contract_nonces);
             raw_transitions.push(map.into());
         }
 
@@ -397,6 +427,7 @@ where
 
     fn raw_document_replace_transitions(
         documents: Vec<ExtendedDocument>,
+        identity_contract_nonces: &BTreeMap<Identifier, IdentityNonce>,
     ) -> Result<Vec<Value>, ProtocolError> {
         let mut raw_transitions = vec![];
         for document in documents {
@@ -434,6 +465,7 @@ where
                 let now = Utc::now().timestamp_millis() as TimestampMillis;
                 map.insert(PROPERTY_UPDATED_AT.to_string(), Value::U64(now));
             }
+            Self::insert_identity_contract_nonce(&mut map, &document, identity_contract_nonces);
 
             raw_transitions.push(map.into());
         }
@@ -442,12 +474,14 @@ where
 
     fn raw_document_delete_transitions(
         documents: Vec<ExtendedDocument>,
+        identity_contract_nonces: &BTreeMap<Identifier, IdentityNonce>,
     ) -> Result<Vec<Value>, ProtocolError> {
         Ok(documents
             .into_iter()
             .map(|document| {
                 let mut map: BTreeMap<String, Value> = BTreeMap::new();
                 map.insert(PROPERTY_ACTION.to_string(), Value::U8(Action::Delete as u8));
+                Self::insert_identity_contract_nonce(&mut map, &document, identity_contract_nonces);
                 map.insert(PROPERTY_ID.to_string(), document.document.id.into());
                 map.insert(
                     PROPERTY_TYPE.to_string(),
@@ -462,6 +496,19 @@ where
             .collect())
     }
 
+    fn insert_identity_contract_nonce(
+        map: &mut BTreeMap<String, Value>,
+        document: &ExtendedDocument,
+        identity_contract_nonces: &BTreeMap<Identifier, IdentityNonce>,
+    ) {
+        if let Some(nonce) = identity_contract_nonces.get(&document.data_contract_id) {
+            map.insert(
+                PROPERTY_IDENTITY_CONTRACT_NONCE.to_string(),
+                Value::U64(*nonce),
+            );
+        }
+    }
+
     fn is_empty<T>(data: impl IntoIterator<Item = T>) -> bool {
         data.into_iter().next().is_none()
     }
@@ -548,7 +595,7 @@ mod test {
             DataContractFetcherAndValidator::new(Arc::new(MockStateRepositoryLike::new())),
         );
 
-        let result = factory.create_state_transition(vec![]);
+        let result = factory.create_state_transition(vec![], &mut BTreeMap::new());
         assert_error_contains!(result, "No documents were supplied to state transition")
     }
 
@@ -565,7 +612,8 @@ mod test {
 
         documents[0].document.owner_id = generate_random_identifier_struct();
 
-        let result = factory.create_state_transition(vec![(Action::Create, documents)]);
+        let result = factory
+            .create_state_transition(vec![(Action::Create, documents)], &mut BTreeMap::new());
         assert_error_contains!(result, "Documents have mixed owner ids")
     }
 
@@ -580,7 +628,8 @@ mod test {
             get_document_validator_fixture(),
             DataContractFetcherAndValidator::new(Arc::new(MockStateRepositoryLike::new())),
         );
-        let result = factory.create_state_transition(vec![(Action::Create, documents)]);
+        let result = factory
+            .create_state_transition(vec![(Action::Create, documents)], &mut BTreeMap::new());
         assert_error_contains!(result, "Invalid Document initial revision '3'")
     }
 
@@ -596,10 +645,13 @@ mod test {
 
         let new_document = documents[0].clone();
         let batch_transition = factory
-            .create_state_transition(vec![
-                (Action::Create, documents),
-                (Action::Replace, vec![new_document]),
-            ])
+            .create_state_transition(
+                vec![
+                    (Action::Create, documents),
+                    (Action::Replace, vec![new_document]),
+                ],
+                &mut BTreeMap::new(),
+            )
             .expect("state transitions should be created");
         assert_eq!(11, batch_transition.transitions.len());
         assert_eq!(
@@ -619,4 +671,39 @@ mod test {
                 .count()
         )
     }
+
+    #[test]
+    fn create_transitions_with_the_next_identity_contract_nonce() {
+        let data_contract = get_data_contract_fixture(None).data_contract;
+        let documents = get_extended_documents_fixture(data_contract).unwrap();
+        let owner_id = documents[0].owner_id();
+        let data_contract_id = documents[0].data_contract_id;
+        let factory = DocumentFactory::new(
+            1,
+            get_document_validator_fixture(),
+            DataContractFetcherAndValidator::new(Arc::new(MockStateRepositoryLike::new())),
+        );
+
+        let mut nonce_counter = BTreeMap::from([((owner_id, data_contract_id), 4)]);
+
+        let batch_transition = factory
+            .create_state_transition(
+                vec![(Action::Create, documents.clone())],
+                &mut nonce_counter,
+            )
+            .expect("state transitions should be created");
+        assert!(batch_transition
+            .transitions
+            .iter()
+            .all(|t| t.base().identity_contract_nonce == 5));
+
+        let batch_transition = factory
+            .create_state_transition(vec![(Action::Delete, documents)], &mut nonce_counter)
+            .expect("state transitions should be created");
+        assert!(batch_transition
+            .transitions
+            .iter()
+            .all(|t| t.base().identity_contract_nonce == 6));
+        assert_eq!(nonce_counter.get(&(owner_id, data_contract_id)), Some(&6));
+    }
 }
@@ -12,7 +12,7 @@ use crate::validation::SimpleConsensusValidationResult;
 use crate::{
     consensus::basic::BasicError,
     data_contract::{enrich_with_base_schema::PREFIX_BYTE_0, DataContract},
-    validation::JsonSchemaValidator,
+    validation::{JsonSchemaValidator, ValueSchemaValidator},
     version::ProtocolVersionValidator,
     ProtocolError,
 };
@@ -101,22 +101,16 @@ impl DocumentValidator {
 
         let enriched_data_contract =
             data_contract.enrich_with_base_schema(&EXTENDED_DOCUMENT_SCHEMA, PREFIX_BYTE_0, &[])?;
-        let document_schema = enriched_data_contract
-            .get_document_schema(document_type_name)?
-            .to_owned();
+        let document_schema = enriched_data_contract.get_document_schema(document_type_name)?;
 
-        let json_schema_validator = if let Some(defs) = &data_contract.defs {
-            JsonSchemaValidator::new_with_definitions(document_schema, defs.iter())
+        let schema_validator = if let Some(defs) = &data_contract.defs {
+            ValueSchemaValidator::new_with_definitions(document_schema, defs.iter())
         } else {
-            JsonSchemaValidator::new(document_schema)
+            ValueSchemaValidator::new(document_schema)
         }
         .map_err(|e| anyhow!("unable to process the contract: {}", e))?;
 
-        let json_value = raw_document
-            .try_to_validating_json()
-            .map_err(ProtocolError::ValueError)?;
-        let json_schema_validation_result = json_schema_validator.validate(&json_value)?;
-        result.merge(json_schema_validation_result);
+        result.merge(schema_validator.validate(raw_document));
 
         if !result.is_valid() {
             return Ok(result);
@@ -0,0 +1,295 @@
+use std::cell::OnceCell;
+use std::collections::BTreeMap;
+use std::convert::TryInto;
+use std::io::BufReader;
+use std::ops::Range;
+
+use integer_encoding::VarInt;
+use platform_value::{Identifier, Value};
+
+use crate::data_contract::document_type::{DocumentField, DocumentType};
+use crate::data_contract::errors::DataContractError;
+use crate::document::document::property_names::{CREATED_AT, UPDATED_AT};
+use crate::document::Document;
+use crate::identity::TimestampMillis;
+use crate::prelude::Revision;
+use crate::ProtocolError;
+
+/// A document read in place from its serialized form
+///
+/// Only the id, the owner id and the revision are read when the view is created. Properties are
+/// found by walking the serialization in the order of the document type properties, the first
+/// time one is asked for, and are only decoded one by one when they are accessed. The owned
+/// [`Document`] is built on demand with [`DocumentView::to_document`].
+#[derive(Debug, Clone)]
+pub struct DocumentView<'a> {
+    serialized_document: &'a [u8],
+    document_type: &'a DocumentType,
+    id: &'a [u8; 32],
+    owner_id: &'a [u8; 32],
+    revision: Option<Revision>,
+    properties_offset: usize,
+    /// The serialized bytes of every document type property, in property order
+    property_ranges: OnceCell<Vec<Range<usize>>>,
+}
+
+impl<'a> DocumentView<'a> {
+    /// Creates a view of a document serialized with [`Document::serialize`]
+    pub fn new(
+        serialized_document: &'a [u8],
+        document_type: &'a DocumentType,
+    ) -> Result<Self, ProtocolError> {
+        if serialized_document.len() < 64 {
+            return Err(ProtocolError::DecodingError(
+                "serialized document is too small, must have id and owner id".to_string(),
+            ));
+        }
+        let id = serialized_document[..32]
+            .try_into()
+            .expect("expected 32 bytes for the id");
+        let owner_id = serialized_document[32..64]
+            .try_into()
+            .expect("expected 32 bytes for the owner id");
+
+        // if the document type is mutable then the revision follows the owner id
+        let (revision, properties_offset) = if document_type.requires_revision() {
+            let (revision, revision_len) = Revision::decode_var(&serialized_document[64..])
+                .ok_or_else(|| {
+                    ProtocolError::DecodingError(
+                        "error reading revision from serialized document for revision".to_string(),
+                    )
+                })?;
+            (Some(revision), 64 + revision_len)
+        } else {
+            (None, 64)
+        };
+
+        Ok(DocumentView {
+            serialized_document,
+            document_type,
+            id,
+            owner_id,
+            revision,
+            properties_offset,
+            property_ranges: OnceCell::new(),
+        })
+    }
+
+    /// The serialized document the view reads from
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.serialized_document
+    }
+
+    pub fn document_type(&self) -> &'a DocumentType {
+        self.document_type
+    }
+
+    pub fn id(&self) -> Identifier {
+        Identifier::new(*self.id)
+    }
+
+    pub fn owner_id(&self) -> Identifier {
+        Identifier::new(*self.owner_id)
+    }
+
+    pub fn revision(&self) -> Option<Revision> {
+        self.revision
+    }
+
+    pub fn created_at(&self) -> Result<Option<TimestampMillis>, ProtocolError> {
+        self.timestamp(CREATED_AT)
+    }
+
+    pub fn updated_at(&self) -> Result<Option<TimestampMillis>, ProtocolError> {
+        self.timestamp(UPDATED_AT)
+    }
+
+    /// The serialized bytes of a property, with the marker of optional properties, or None if the
+    /// document type doesn't have the property
+    pub fn property_bytes(&self, name: &str) -> Result<Option<&'a [u8]>, ProtocolError> {
+        let index = match self.property_index(name) {
+            Some(index) => index,
+            None => return Ok(None),
+        };
+        let range = self.property_ranges()?[index].clone();
+        Ok(Some(&self.serialized_document[range]))
+    }
+
+    /// Decodes a single property, or returns None if the document type doesn't have it
+    ///
+    /// Absent optional properties are null, as they are in the properties of the [`Document`].
+    /// `$createdAt` and `$updatedAt` are decoded as unsigned integers.
+    pub fn get(&self, name: &str) -> Result<Option<Value>, ProtocolError> {
+        let index = match self.property_index(name) {
+            Some(index) => index,
+            None => return Ok(None),
+        };
+        let (name, field) = self
+            .document_type
+            .properties
+            .iter()
+            .nth(index)
+            .expect("expected the property at its index");
+        self.read_property(index, name, field)
+    }
+
+    /// Decodes the properties one by one, in the order of the document type properties
+    pub fn properties(
+        &self,
+    ) -> impl Iterator<Item = Result<(&'a String, Value), ProtocolError>> + '_ {
+        self.document_type.properties.iter().enumerate().filter_map(
+            move |(index, (name, field))| {
+                self.read_property(index, name, field)
+                    .map(|value| value.map(|value| (name, value)))
+                    .transpose()
+            },
+        )
+    }
+
+    /// Builds the owned document, decoding every property
+    pub fn to_document(&self) -> Result<Document, ProtocolError> {
+        let mut created_at = None;
+        let mut updated_at = None;
+        let mut properties = BTreeMap::new();
+        for property in self.properties() {
+            let (name, value) = property?;
+            match (name.as_str(), value) {
+                (CREATED_AT, Value::U64(timestamp)) => created_at = Some(timestamp),
+                (UPDATED_AT, Value::U64(timestamp)) => updated_at = Some(timestamp),
+                (_, value) => {
+                    properties.insert(name.clone(), value);
+                }
+            }
+        }
+        Ok(Document {
+            id: self.id(),
+            properties,
+            owner_id: self.owner_id(),
+            revision: self.revision,
+            created_at,
+            updated_at,
+        })
+    }
+
+    fn property_index(&self, name: &str) -> Option<usize> {
+        self.document_type
+            .properties
+            .keys()
+            .position(|property_name| property_name == name)
+    }
+
+    fn property_ranges(&self) -> Result<&[Range<usize>], ProtocolError> {
+        if let Some(ranges) = self.property_ranges.get() {
+            return Ok(ranges.as_slice());
+        }
+        let mut offset = self.properties_offset;
+        let ranges = self
+            .document_type
+            .properties
+            .values()
+            .map(|field| {
+                let len = field
+                    .document_type
+                    .encoded_len(&self.serialized_document[offset..], field.required)?;
+                let range = offset..offset + len;
+                offset += len;
+                Ok(range)
+            })
+            .collect::<Result<Vec<Range<usize>>, ProtocolError>>()?;
+        Ok(self.property_ranges.get_or_init(|| ranges).as_slice())
+    }
+
+    fn read_property(
+        &self,
+        index: usize,
+        name: &str,
+        field: &DocumentField,
+    ) -> Result<Option<Value>, ProtocolError> {
+        let range = self.property_ranges()?[index].clone();
+        let bytes = &self.serialized_document[range];
+        if name == CREATED_AT || name == UPDATED_AT {
+            // timestamps are serialized as big endian integers
+            let timestamp_bytes = if field.required {
+                bytes
+            } else if bytes[0] == 0 {
+                return Ok(Some(Value::Null));
+            } else {
+                &bytes[1..]
+            };
+            let timestamp = timestamp_bytes.try_into().map_err(|_| {
+                ProtocolError::DataContractError(DataContractError::CorruptedSerialization(
+                    "error reading timestamp from serialized document",
+                ))
+            })?;
+            return Ok(Some(Value::U64(TimestampMillis::from_be_bytes(timestamp))));
+        }
+        field
+            .document_type
+            .read_from(&mut BufReader::new(bytes), field.required)
+    }
+
+    fn timestamp(&self, name: &str) -> Result<Option<TimestampMillis>, ProtocolError> {
+        match self.get(name)? {
+            Some(Value::U64(timestamp)) => Ok(Some(timestamp)),
+            _ => Ok(None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_contract::document_type::random_document::CreateRandomDocument;
+    use crate::data_contract::extra::common::json_document_to_contract;
+    use crate::data_contract::DataContract;
+
+    fn dashpay_contract() -> DataContract {
+        json_document_to_contract("../rs-dpp/src/tests/payloads/contract/dashpay-contract.json")
+            .expect("expected to get dashpay contract")
+    }
+
+    #[test]
+    fn should_read_properties_in_place() {
+        let contract = dashpay_contract();
+        let document_type = contract
+            .document_type_for_name("profile")
+            .expect("expected to get profile document type");
+        let document = document_type.random_document(Some(3333));
+        let serialized_document = document
+            .serialize(document_type)
+            .expect("expected to serialize");
+
+        let view = DocumentView::new(serialized_document.as_slice(), document_type)
+            .expect("expected to create a view");
+        assert_eq!(view.id(), document.id);
+        assert_eq!(view.owner_id(), document.owner_id);
+        assert_eq!(view.revision(), document.revision);
+        assert_eq!(view.created_at().unwrap(), document.created_at);
+        assert_eq!(view.updated_at().unwrap(), document.updated_at);
+        assert_eq!(
+            view.get("displayName").unwrap().as_ref(),
+            document.properties.get("displayName")
+        );
+        assert_eq!(view.get("unknownProperty").unwrap(), None);
+        assert_eq!(view.property_bytes("unknownProperty").unwrap(), None);
+        assert_eq!(view.to_document().unwrap(), document);
+    }
+
+    #[test]
+    fn should_only_fail_on_access_for_corrupted_properties() {
+        let contract = dashpay_contract();
+        let document_type = contract
+            .document_type_for_name("contactRequest")
+            .expect("expected to get contact request document type");
+        let document = document_type.random_document(Some(3333));
+        let serialized_document = document
+            .serialize(document_type)
+            .expect("expected to serialize");
+        let truncated = &serialized_document[..serialized_document.len() - 1];
+
+        let view = DocumentView::new(truncated, document_type).expect("expected to create a view");
+        assert_eq!(view.id(), document.id);
+        assert!(view.get("toUserId").is_err());
+        assert!(view.to_document().is_err());
+    }
+}
@@ -9,6 +9,7 @@ pub mod document_facade;
 pub mod document_factory;
 mod document_patch;
 pub mod document_validator;
+mod document_view;
 pub mod errors;
 pub mod extended_document;
 pub mod fetch_and_validate_data_contract;
@@ -17,6 +18,7 @@ pub mod serialize;
 pub mod state_transition;
 
 pub use document::Document;
+pub use document_view::DocumentView;
 pub use extended_document::property_names as extended_document_property_names;
 pub use extended_document::ExtendedDocument;
 pub use extended_document::IDENTIFIER_FIELDS as EXTENDED_DOCUMENT_IDENTIFIER_FIELDS;
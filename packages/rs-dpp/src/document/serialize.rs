@@ -5,7 +5,7 @@ use crate::data_contract::errors::{DataContractError, StructureError};
 use crate::document::document::property_names;
 use crate::document::document::property_names::{CREATED_AT, UPDATED_AT};
 
-use crate::document::Document;
+use crate::document::{Document, DocumentView};
 
 use crate::identity::TimestampMillis;
 use crate::prelude::Revision;
@@ -13,16 +13,14 @@ use crate::util::deserializer;
 use crate::util::deserializer::SplitProtocolVersionOutcome;
 use crate::ProtocolError;
 
-use byteorder::{BigEndian, ReadBytesExt};
 #[cfg(feature = "cbor")]
 use ciborium::Value as CborValue;
-use integer_encoding::{VarInt, VarIntReader, VarIntWriter};
+use integer_encoding::{VarInt, VarIntWriter};
 use platform_value::btreemap_extensions::BTreeValueRemoveFromMapHelper;
 use platform_value::{Identifier, Value};
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 use std::convert::TryFrom;
-use std::io::{BufReader, Read};
 
 #[cfg(feature = "cbor")]
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
@@ -247,120 +245,13 @@ impl Document {
     }
 
     /// Reads a serialized document and creates a Document from it.
+    ///
+    /// Use a [`DocumentView`] to read only some of the properties.
     pub fn from_bytes(
         serialized_document: &[u8],
         document_type: &DocumentType,
     ) -> Result<Self, ProtocolError> {
-        let mut buf = BufReader::new(serialized_document);
-        if serialized_document.len() < 64 {
-            return Err(ProtocolError::DecodingError(
-                "serialized document is too small, must have id and owner id".to_string(),
-            ));
-        }
-        let mut id = [0; 32];
-        buf.read_exact(&mut id).map_err(|_| {
-            ProtocolError::DecodingError(
-                "error reading from serialized document for id".to_string(),
-            )
-        })?;
-
-        let mut owner_id = [0; 32];
-        buf.read_exact(&mut owner_id).map_err(|_| {
-            ProtocolError::DecodingError(
-                "error reading from serialized document for owner id".to_string(),
-            )
-        })?;
-
-        // if the document type is mutable then we should deserialize the revision
-        let revision: Option<Revision> = if document_type.requires_revision() {
-            let revision = buf.read_varint().map_err(|_| {
-                ProtocolError::DecodingError(
-                    "error reading revision from serialized document for revision".to_string(),
-                )
-            })?;
-            Some(revision)
-        } else {
-            None
-        };
-        let mut created_at = None;
-        let mut updated_at = None;
-        let properties = document_type
-            .properties
-            .iter()
-            .filter_map(|(key, field)| {
-                if key == CREATED_AT {
-                    if !field.required {
-                        let marker_result = buf.read_u8().map_err(|_| {
-                            ProtocolError::DataContractError(DataContractError::CorruptedSerialization(
-                                "error reading created at optional byte from serialized document",
-                            ))
-                        });
-                        match marker_result {
-                            Ok(marker) => {
-                                if marker == 0 {
-                                    return Some(Ok((key.clone(), Value::Null)));
-                                }
-                            }
-                            Err(e) => return Some(Err(e)),
-                        }
-                    }
-                    let integer_result = buf.read_u64::<BigEndian>().map_err(|_| {
-                        ProtocolError::DataContractError(DataContractError::CorruptedSerialization(
-                            "error reading created at from serialized document",
-                        ))
-                    });
-                    match integer_result {
-                        Ok(integer) => {
-                            created_at = Some(integer);
-                            None
-                        }
-                        Err(e) => Some(Err(e)),
-                    }
-                } else if key == UPDATED_AT {
-                    if !field.required {
-                        let marker_result = buf.read_u8().map_err(|_| {
-                            ProtocolError::DataContractError(DataContractError::CorruptedSerialization(
-                                "error reading updated at optional byte from serialized document",
-                            ))
-                        });
-                        match marker_result {
-                            Ok(marker) => {
-                                if marker == 0 {
-                                    return Some(Ok((key.clone(), Value::Null)));
-                                }
-                            }
-                            Err(e) => return Some(Err(e)),
-                        }
-                    }
-                    let integer_result = buf.read_u64::<BigEndian>().map_err(|_| {
-                        ProtocolError::DataContractError(DataContractError::CorruptedSerialization(
-                            "error reading updated at from serialized document",
-                        ))
-                    });
-                    match integer_result {
-                        Ok(integer) => {
-                            updated_at = Some(integer);
-                            None
-                        }
-                        Err(e) => Some(Err(e)),
-                    }
-                } else {
-                    let read_value = field.document_type.read_from(&mut buf, field.required);
-                    match read_value {
-                        Ok(read_value) => read_value.map(|read_value| Ok((key.clone(), read_value))),
-                        Err(e) => Some(Err(e)),
-                    }
-                }
-            })
-            .collect::<Result<BTreeMap<String, Value>, ProtocolError>>()?;
-        Ok(Document {
-            id: Identifier::new(id),
-            properties,
-            owner_id: Identifier::new(owner_id),
-            revision,
-            created_at,
-            updated_at,
-        })
+        DocumentView::new(serialized_document, document_type)?.to_document()
     }
 
     /// Reads a CBOR-serialized document and creates a Document from it.
@@ -14,6 +14,7 @@ use serde_repr::*;
 use crate::document::document_transition::Action::{Create, Delete, Replace};
 
 use crate::document::errors::DocumentError;
+use crate::prelude::IdentityNonce;
 use crate::{data_contract::DataContract, errors::ProtocolError, identifier::Identifier};
 
 pub(self) mod property_names {
@@ -21,6 +22,7 @@ pub(self) mod property_names {
     pub const DATA_CONTRACT_ID: &str = "$dataContractId";
     pub const DOCUMENT_TYPE: &str = "$type";
     pub const ACTION: &str = "$action";
+    pub const IDENTITY_CONTRACT_NONCE: &str = "$identityContractNonce";
 }
 
 pub const IDENTIFIER_FIELDS: [&str; 2] = [property_names::ID, property_names::DATA_CONTRACT_ID];
@@ -106,6 +108,9 @@ pub struct DocumentBaseTransition {
     /// Data contract ID generated from the data contract's `owner_id` and `entropy`
     #[serde(rename = "$dataContractId")]
     pub data_contract_id: Identifier,
+    /// Must be one more than the nonce of the owner for this data contract
+    #[serde(rename = "$identityContractNonce")]
+    pub identity_contract_nonce: IdentityNonce,
 
     #[serde(skip)]
     pub data_contract: DataContract,
@@ -133,6 +138,9 @@ impl DocumentBaseTransition {
                     .map_err(ProtocolError::ValueError)?
                     .unwrap_or(data_contract.id.to_buffer()),
             ),
+            identity_contract_nonce: map
+                .remove_integer(property_names::IDENTITY_CONTRACT_NONCE)
+                .map_err(ProtocolError::ValueError)?,
             data_contract,
         })
     }
@@ -177,6 +185,9 @@ impl DocumentTransitionObjectLike for DocumentBaseTransition {
                 .map_err(ProtocolError::ValueError)?
                 .try_into()?,
             data_contract_id: data_contract.id,
+            identity_contract_nonce: map
+                .get_integer(property_names::IDENTITY_CONTRACT_NONCE)
+                .map_err(ProtocolError::ValueError)?,
             data_contract,
         })
     }
@@ -203,6 +214,10 @@ impl DocumentTransitionObjectLike for DocumentBaseTransition {
             property_names::DOCUMENT_TYPE.to_string(),
             Value::Text(self.document_type_name.clone()),
         );
+        btree_map.insert(
+            property_names::IDENTITY_CONTRACT_NONCE.to_string(),
+            Value::U64(self.identity_contract_nonce),
+        );
         Ok(btree_map)
     }
 
@@ -1,6 +1,7 @@
 use crate::data_contract::DataContract;
 use crate::document::document_transition::document_base_transition::DocumentBaseTransition;
 use crate::identifier::Identifier;
+use crate::prelude::IdentityNonce;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -15,6 +16,9 @@ pub struct DocumentBaseTransitionAction {
     /// Data contract ID generated from the data contract's `owner_id` and `entropy`
     #[serde(rename = "$dataContractId")]
     pub data_contract_id: Identifier,
+    /// The nonce of the owner for the data contract this transition sets
+    #[serde(rename = "$identityContractNonce")]
+    pub identity_contract_nonce: IdentityNonce,
     #[serde(skip)]
     pub data_contract: DataContract,
 }
@@ -25,6 +29,7 @@ impl From<DocumentBaseTransition> for DocumentBaseTransitionAction {
             id,
             document_type_name,
             data_contract_id,
+            identity_contract_nonce,
             data_contract,
             ..
         } = value;
@@ -32,6 +37,7 @@ impl From<DocumentBaseTransition> for DocumentBaseTransitionAction {
             id,
             document_type_name,
             data_contract_id,
+            identity_contract_nonce,
             data_contract,
         }
     }
@@ -43,6 +49,7 @@ impl From<&DocumentBaseTransition> for DocumentBaseTransitionAction {
             id,
             document_type_name,
             data_contract_id,
+            identity_contract_nonce,
             data_contract,
             ..
         } = value;
@@ -50,6 +57,7 @@ impl From<&DocumentBaseTransition> for DocumentBaseTransitionAction {
             id: *id,
             document_type_name: document_type_name.clone(),
             data_contract_id: *data_contract_id,
+            identity_contract_nonce: *identity_contract_nonce,
             data_contract: data_contract.clone(),
         }
     }
@@ -288,6 +288,7 @@ mod test {
             "alphaIdentifier" : alpha_identifier,
             "$entropy" : entropy,
             "$action": 0u8,
+            "$identityContractNonce": 1u64,
         });
 
         let transition: DocumentCreateTransition =
@@ -331,6 +332,7 @@ mod test {
             "alphaIdentifier" : alpha_value,
             "$entropy" : entropy,
             "$action": 0 ,
+            "$identityContractNonce": 1,
         });
 
         let document: DocumentCreateTransition =
@@ -84,6 +84,7 @@ mod test {
                     "$action": 3,
                     "$dataContractId": "5wpZAEWndYcTeuwZpkmSa8s49cHXU5q2DhdibesxFSu8",
 					"$id": "6oCKUeLVgjr7VZCyn1LdGbrepqKLmoabaff5WQqyTKYP",
+					"$identityContractNonce": 1,
 					"$type": "note"
 				}"#;
 
@@ -215,6 +215,7 @@ mod test {
                     "$action": 1,
                     "$dataContractId": "5wpZAEWndYcTeuwZpkmSa8s49cHXU5q2DhdibesxFSu8",
 					"$id": "6oCKUeLVgjr7VZCyn1LdGbrepqKLmoabaff5WQqyTKYP",
+					"$identityContractNonce": 1,
 					"$revision" : 1,
 					"$type": "note",
 					"message": "example_message_replace"
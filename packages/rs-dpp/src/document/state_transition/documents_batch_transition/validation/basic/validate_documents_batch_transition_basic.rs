@@ -25,14 +25,13 @@ use crate::{
     prelude::Identifier,
     state_repository::StateRepositoryLike,
     state_transition::state_transition_execution_context::StateTransitionExecutionContext,
-    validation::JsonSchemaValidator,
+    validation::{JsonSchemaValidator, ValueSchemaValidator},
     version::ProtocolVersionValidator,
     ProtocolError,
 };
 use anyhow::anyhow;
 use lazy_static::lazy_static;
 use platform_value::btreemap_extensions::BTreeValueMapHelper;
-use platform_value::converter::serde_json::BTreeValueRefJsonConverter;
 use platform_value::Value;
 use serde_json::Value as JsonValue;
 
@@ -61,6 +60,9 @@ lazy_static! {
     pub static ref DOCUMENTS_BATCH_TRANSITIONS_SCHEMA_VALIDATOR: JsonSchemaValidator =
         JsonSchemaValidator::new(DOCUMENTS_BATCH_TRANSITIONS_SCHEMA.clone())
             .expect("unable to compile jsonschema");
+    static ref BASE_TRANSITION_SCHEMA_VALIDATOR: ValueSchemaValidator =
+        ValueSchemaValidator::new(&BASE_TRANSITION_SCHEMA)
+            .expect("unable to compile base transition schema");
 }
 
 pub trait Validator {
@@ -209,45 +211,55 @@ pub fn validate_document_transitions<'a>(
     raw_document_transitions: impl IntoIterator<Item = BTreeMap<String, &'a Value>>,
 ) -> Result<SimpleConsensusValidationResult, ProtocolError> {
     let mut result = SimpleConsensusValidationResult::default();
-    let enriched_contracts_by_action = get_enriched_contracts_by_action(data_contract)?;
 
-    let validation_result = validate_raw_transitions(
-        data_contract,
-        raw_document_transitions,
-        &enriched_contracts_by_action,
-        owner_id,
-    )?;
+    let validation_result =
+        validate_raw_transitions(data_contract, raw_document_transitions, owner_id)?;
     result.merge(validation_result);
 
     Ok(result)
 }
 
-fn get_enriched_contracts_by_action(
+fn get_enriched_contract_for_action(
     data_contract: &DataContract,
-) -> Result<HashMap<Action, DataContract>, ProtocolError> {
+    action: Action,
+) -> Result<DataContract, ProtocolError> {
     let enriched_base_contract =
         data_contract.enrich_with_base_schema(&BASE_TRANSITION_SCHEMA, PREFIX_BYTE_1, &[])?;
-    let enriched_create_contract = enriched_base_contract.enrich_with_base_schema(
-        &CREATE_TRANSITION_SCHEMA,
-        PREFIX_BYTE_2,
-        &[],
-    )?;
-    let enriched_replace_contract = enriched_base_contract.enrich_with_base_schema(
-        &REPLACE_TRANSITION_SCHEMA,
-        PREFIX_BYTE_3,
-        &["$createdAt"],
-    )?;
-    let mut enriched_contracts_by_action: HashMap<Action, DataContract> = HashMap::new();
-    enriched_contracts_by_action.insert(Action::Create, enriched_create_contract);
-    enriched_contracts_by_action.insert(Action::Replace, enriched_replace_contract);
+    match action {
+        Action::Create => enriched_base_contract.enrich_with_base_schema(
+            &CREATE_TRANSITION_SCHEMA,
+            PREFIX_BYTE_2,
+            &[],
+        ),
+        Action::Replace => enriched_base_contract.enrich_with_base_schema(
+            &REPLACE_TRANSITION_SCHEMA,
+            PREFIX_BYTE_3,
+            &["$createdAt"],
+        ),
+        Action::Delete => Ok(enriched_base_contract),
+    }
+}
+
+fn compile_transition_schema_validator(
+    data_contract: &DataContract,
+    document_type: &str,
+    action: Action,
+) -> Result<ValueSchemaValidator, ProtocolError> {
+    let enriched_data_contract = get_enriched_contract_for_action(data_contract, action)?;
+    let document_schema = enriched_data_contract.get_document_schema(document_type)?;
+    let validator = if let Some(defs) = &enriched_data_contract.defs {
+        ValueSchemaValidator::new_with_definitions(document_schema, defs.iter())
+    } else {
+        ValueSchemaValidator::new(document_schema)
+    }
+    .map_err(|e| anyhow!("unable to compile enriched schema: {}", e))?;
 
-    Ok(enriched_contracts_by_action)
+    Ok(validator)
 }
 
 fn validate_raw_transitions<'a>(
     data_contract: &DataContract,
     raw_document_transitions: impl IntoIterator<Item = BTreeMap<String, &'a Value>>,
-    enriched_contracts_by_action: &HashMap<Action, DataContract>,
     owner_id: Identifier,
 ) -> Result<SimpleConsensusValidationResult, ProtocolError> {
     let mut result = SimpleConsensusValidationResult::default();
@@ -283,23 +295,34 @@ fn validate_raw_transitions<'a>(
 
         match action {
             Action::Create | Action::Replace => {
-                let enriched_data_contract = &enriched_contracts_by_action[&action];
-                let document_schema = enriched_data_contract.get_document_schema(document_type)?;
+                let document_type_definition =
+                    data_contract.document_type_for_name(document_type)?;
+
+                // schemas are compiled once per document type of the contract and action
+                let schema_validator = document_type_definition
+                    .transition_schema_validators
+                    .get_or_compile(action, || {
+                        compile_transition_schema_validator(data_contract, document_type, action)
+                    })?;
+
+                let schema_result =
+                    schema_validator.validate(&Value::from(raw_document_transition.clone()));
+                if !schema_result.is_valid() {
+                    result.merge(schema_result);
+                    return Ok(result);
+                }
 
-                let schema_validator = if let Some(defs) = &enriched_data_contract.defs {
-                    JsonSchemaValidator::new_with_definitions(document_schema.clone(), defs.iter())
-                } else {
-                    JsonSchemaValidator::new(document_schema.clone())
+                let encrypted_fields_result =
+                    document_type_definition.validate_encrypted_fields(&raw_document_transition);
+                if !encrypted_fields_result.is_valid() {
+                    result.merge(encrypted_fields_result);
+                    return Ok(result);
                 }
-                .map_err(|e| anyhow!("unable to compile enriched schema: {}", e))?;
 
-                let schema_result = schema_validator.validate(
-                    &raw_document_transition
-                        .to_validating_json_value()
-                        .map_err(ProtocolError::ValueError)?,
-                )?;
-                if !schema_result.is_valid() {
-                    result.merge(schema_result);
+                let numeric_fields_result = document_type_definition
+                    .validate_exact_numeric_fields(&raw_document_transition);
+                if !numeric_fields_result.is_valid() {
+                    result.merge(numeric_fields_result);
                     return Ok(result);
                 }
 
@@ -331,13 +354,8 @@ fn validate_raw_transitions<'a>(
             }
 
             Action::Delete => {
-                let validator = JsonSchemaValidator::new(BASE_TRANSITION_SCHEMA.clone())
-                    .map_err(|e| anyhow!("unable to compile base transition schema: {}", e))?;
-                let validation_result = validator.validate(
-                    &raw_document_transition
-                        .to_validating_json_value()
-                        .map_err(ProtocolError::ValueError)?,
-                )?;
+                let validation_result = BASE_TRANSITION_SCHEMA_VALIDATOR
+                    .validate(&Value::from(raw_document_transition.clone()));
                 if !validation_result.is_valid() {
                     result.merge(validation_result);
                     return Ok(result);
@@ -0,0 +1,47 @@
+use crate::consensus::basic::BasicError;
+use crate::consensus::ConsensusError;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use bincode::{Decode, Encode};
+
+#[derive(Error, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Encode, Decode)]
+#[error("Encrypted field {field_name} of document type {document_type} is invalid: {reason}")]
+pub struct InvalidEncryptedDocumentFieldError {
+    /*
+
+    DO NOT CHANGE ORDER OF FIELDS WITHOUT INTRODUCING OF NEW VERSION
+
+    */
+    document_type: String,
+    field_name: String,
+    reason: String,
+}
+
+impl InvalidEncryptedDocumentFieldError {
+    pub fn new(document_type: String, field_name: String, reason: String) -> Self {
+        Self {
+            document_type,
+            field_name,
+            reason,
+        }
+    }
+
+    pub fn document_type(&self) -> String {
+        self.document_type.clone()
+    }
+
+    pub fn field_name(&self) -> String {
+        self.field_name.clone()
+    }
+
+    pub fn reason(&self) -> String {
+        self.reason.clone()
+    }
+}
+
+impl From<InvalidEncryptedDocumentFieldError> for ConsensusError {
+    fn from(err: InvalidEncryptedDocumentFieldError) -> Self {
+        Self::BasicError(BasicError::InvalidEncryptedDocumentFieldError(err))
+    }
+}
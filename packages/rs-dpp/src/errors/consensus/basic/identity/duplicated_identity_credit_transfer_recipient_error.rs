@@ -0,0 +1,35 @@
+use crate::consensus::basic::BasicError;
+use crate::consensus::ConsensusError;
+use crate::prelude::Identifier;
+use bincode::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+#[derive(Error, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Encode, Decode)]
+#[error("Recipient {recipient_id} is present more than once in credit transfer")]
+pub struct DuplicatedIdentityCreditTransferRecipientError {
+    /*
+
+    DO NOT CHANGE ORDER OF FIELDS WITHOUT INTRODUCING OF NEW VERSION
+
+    */
+    recipient_id: Identifier,
+}
+
+impl DuplicatedIdentityCreditTransferRecipientError {
+    pub fn new(recipient_id: Identifier) -> Self {
+        Self { recipient_id }
+    }
+
+    pub fn recipient_id(&self) -> Identifier {
+        self.recipient_id
+    }
+}
+
+impl From<DuplicatedIdentityCreditTransferRecipientError> for ConsensusError {
+    fn from(err: DuplicatedIdentityCreditTransferRecipientError) -> Self {
+        Self::BasicError(BasicError::DuplicatedIdentityCreditTransferRecipientError(
+            err,
+        ))
+    }
+}
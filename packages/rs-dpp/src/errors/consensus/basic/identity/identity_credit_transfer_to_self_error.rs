@@ -0,0 +1,33 @@
+use crate::consensus::basic::BasicError;
+use crate::consensus::ConsensusError;
+use crate::prelude::Identifier;
+use bincode::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+#[derive(Error, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Encode, Decode)]
+#[error("Identity {identity_id} can't transfer credits to itself")]
+pub struct IdentityCreditTransferToSelfError {
+    /*
+
+    DO NOT CHANGE ORDER OF FIELDS WITHOUT INTRODUCING OF NEW VERSION
+
+    */
+    identity_id: Identifier,
+}
+
+impl IdentityCreditTransferToSelfError {
+    pub fn new(identity_id: Identifier) -> Self {
+        Self { identity_id }
+    }
+
+    pub fn identity_id(&self) -> Identifier {
+        self.identity_id
+    }
+}
+
+impl From<IdentityCreditTransferToSelfError> for ConsensusError {
+    fn from(err: IdentityCreditTransferToSelfError) -> Self {
+        Self::BasicError(BasicError::IdentityCreditTransferToSelfError(err))
+    }
+}
@@ -1,9 +1,11 @@
+pub use duplicated_identity_credit_transfer_recipient_error::*;
 pub use duplicated_identity_public_key_basic_error::*;
 pub use duplicated_identity_public_key_id_basic_error::*;
 pub use identity_asset_lock_proof_locked_transaction_mismatch_error::*;
 pub use identity_asset_lock_transaction_is_not_found_error::*;
 pub use identity_asset_lock_transaction_out_point_already_exists_error::*;
 pub use identity_asset_lock_transaction_output_not_found_error::*;
+pub use identity_credit_transfer_to_self_error::*;
 pub use invalid_asset_lock_proof_core_chain_height_error::*;
 pub use invalid_asset_lock_proof_transaction_height_error::*;
 pub use invalid_asset_lock_transaction_output_return_size::*;
@@ -20,12 +22,14 @@ pub use invalid_instant_asset_lock_proof_signature_error::*;
 pub use missing_master_public_key_error::*;
 pub use not_implemented_identity_credit_withdrawal_transition_pooling_error::*;
 
+mod duplicated_identity_credit_transfer_recipient_error;
 mod duplicated_identity_public_key_basic_error;
 mod duplicated_identity_public_key_id_basic_error;
 mod identity_asset_lock_proof_locked_transaction_mismatch_error;
 mod identity_asset_lock_transaction_is_not_found_error;
 mod identity_asset_lock_transaction_out_point_already_exists_error;
 mod identity_asset_lock_transaction_output_not_found_error;
+mod identity_credit_transfer_to_self_error;
 mod invalid_asset_lock_proof_core_chain_height_error;
 mod invalid_asset_lock_proof_transaction_height_error;
 mod invalid_asset_lock_transaction_output_return_size;
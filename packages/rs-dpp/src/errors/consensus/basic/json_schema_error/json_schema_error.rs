@@ -3,6 +3,7 @@ use crate::consensus::basic::BasicError;
 use crate::consensus::ConsensusError;
 use bincode::{Decode, Encode};
 use jsonschema::ValidationError;
+use platform_value::schema::SchemaValidationError;
 use platform_value::Value;
 use serde::{Deserialize, Serialize};
 use serde_json::Value as JsonValue;
@@ -51,6 +52,27 @@ impl<'a> From<ValidationError<'a>> for ConsensusError {
     }
 }
 
+impl From<SchemaValidationError> for JsonSchemaError {
+    fn from(validation_error: SchemaValidationError) -> Self {
+        Self {
+            error_summary: "".to_string(),
+            params: validation_error.params_value(),
+            keyword: validation_error.keyword,
+            instance_path: validation_error.instance_path,
+            schema_path: validation_error.schema_path,
+            property_name: validation_error.property_name,
+        }
+    }
+}
+
+impl From<SchemaValidationError> for ConsensusError {
+    fn from(validation_error: SchemaValidationError) -> Self {
+        Self::BasicError(BasicError::JsonSchemaError(JsonSchemaError::from(
+            validation_error,
+        )))
+    }
+}
+
 impl From<JsonSchemaError> for ConsensusError {
     fn from(e: JsonSchemaError) -> Self {
         Self::BasicError(BasicError::JsonSchemaError(e))
@@ -74,6 +74,8 @@ impl ErrorWithCode for BasicError {
             Self::MissingDocumentTransitionActionError { .. } => 1026,
             Self::MissingDocumentTransitionTypeError { .. } => 1027,
             Self::MissingDocumentTypeError { .. } => 1028,
+            Self::InvalidEncryptedDocumentFieldError { .. } => 1063,
+            Self::InvalidDocumentFieldValueError { .. } => 1064,
 
             // Identity
             Self::DuplicatedIdentityPublicKeyBasicError(_) => 1029,
@@ -98,6 +100,8 @@ impl ErrorWithCode for BasicError {
             Self::InvalidIdentityCreditWithdrawalTransitionOutputScriptError(_) => 1057,
             Self::InvalidIdentityCreditWithdrawalTransitionCoreFeeError(_) => 1058,
             Self::NotImplementedIdentityCreditWithdrawalTransitionPoolingError(_) => 1059,
+            Self::DuplicatedIdentityCreditTransferRecipientError(_) => 1061,
+            Self::IdentityCreditTransferToSelfError(_) => 1062,
 
             // State Transition
             Self::InvalidStateTransitionTypeError { .. } => 1043,
@@ -121,6 +125,7 @@ impl ErrorWithCode for SignatureError {
             Self::SignatureShouldNotBePresentError(_) => 2008,
             Self::BasicECDSAError(_) => 2009,
             Self::BasicBLSError(_) => 2010,
+            Self::PublicKeyIsExpiredError { .. } => 2011,
         }
     }
 }
@@ -142,6 +147,7 @@ impl ErrorWithCode for StateError {
             Self::DataTriggerError(ref e) => e.code(),
             Self::DataTriggerActionError(ref e) => e.code(),
             Self::DataContractConfigUpdateError { .. } => 4027,
+            Self::DataContractTooManyDocumentsToBackfillIndexError { .. } => 4031,
 
             // Document
             Self::DocumentAlreadyPresentError { .. } => 4004,
@@ -165,6 +171,9 @@ impl ErrorWithCode for StateError {
             Self::IdentityPublicKeyIsDisabledError { .. } => 4023,
             Self::MissingIdentityPublicKeyIdsError { .. } => 4024,
             Self::IdentityInsufficientBalanceError(_) => 4024,
+            Self::InvalidIdentityNonceError { .. } => 4028,
+            Self::InvalidIdentityContractNonceError { .. } => 4029,
+            Self::IdentityPublicKeyValidUntilInPastError { .. } => 4030,
         }
     }
 }
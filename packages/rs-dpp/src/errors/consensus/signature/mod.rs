@@ -6,6 +6,7 @@ mod invalid_signature_public_key_security_level_error;
 mod invalid_state_transition_signature_error;
 mod missing_public_key_error;
 mod public_key_is_disabled_error;
+mod public_key_is_expired_error;
 mod public_key_security_level_not_met_error;
 mod signature_error;
 mod signature_should_not_be_present_error;
@@ -19,6 +20,7 @@ pub use crate::consensus::signature::invalid_signature_public_key_security_level
 pub use crate::consensus::signature::invalid_state_transition_signature_error::InvalidStateTransitionSignatureError;
 pub use crate::consensus::signature::missing_public_key_error::MissingPublicKeyError;
 pub use crate::consensus::signature::public_key_is_disabled_error::PublicKeyIsDisabledError;
+pub use crate::consensus::signature::public_key_is_expired_error::PublicKeyIsExpiredError;
 pub use crate::consensus::signature::public_key_security_level_not_met_error::PublicKeySecurityLevelNotMetError;
 pub use crate::consensus::signature::signature_error::SignatureError;
 pub use crate::consensus::signature::signature_should_not_be_present_error::SignatureShouldNotBePresentError;
@@ -0,0 +1,43 @@
+use thiserror::Error;
+
+use crate::consensus::signature::signature_error::SignatureError;
+use crate::consensus::ConsensusError;
+use crate::identity::{KeyID, TimestampMillis};
+use serde::{Deserialize, Serialize};
+
+use bincode::{Decode, Encode};
+
+#[derive(Error, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Encode, Decode)]
+#[error("Identity key {public_key_id} expired at {valid_until}")]
+pub struct PublicKeyIsExpiredError {
+    /*
+
+    DO NOT CHANGE ORDER OF FIELDS WITHOUT INTRODUCING OF NEW VERSION
+
+    */
+    public_key_id: KeyID,
+    valid_until: TimestampMillis,
+}
+
+impl PublicKeyIsExpiredError {
+    pub fn new(public_key_id: KeyID, valid_until: TimestampMillis) -> Self {
+        Self {
+            public_key_id,
+            valid_until,
+        }
+    }
+
+    pub fn public_key_id(&self) -> KeyID {
+        self.public_key_id
+    }
+
+    pub fn valid_until(&self) -> TimestampMillis {
+        self.valid_until
+    }
+}
+
+impl From<PublicKeyIsExpiredError> for ConsensusError {
+    fn from(err: PublicKeyIsExpiredError) -> Self {
+        Self::SignatureError(SignatureError::PublicKeyIsExpiredError(err))
+    }
+}
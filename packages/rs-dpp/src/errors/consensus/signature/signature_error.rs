@@ -1,8 +1,9 @@
 use crate::consensus::signature::{
     BasicBLSError, BasicECDSAError, IdentityNotFoundError, InvalidIdentityPublicKeyTypeError,
     InvalidSignaturePublicKeySecurityLevelError, InvalidStateTransitionSignatureError,
-    MissingPublicKeyError, PublicKeyIsDisabledError, PublicKeySecurityLevelNotMetError,
-    SignatureShouldNotBePresentError, WrongPublicKeyPurposeError,
+    MissingPublicKeyError, PublicKeyIsDisabledError, PublicKeyIsExpiredError,
+    PublicKeySecurityLevelNotMetError, SignatureShouldNotBePresentError,
+    WrongPublicKeyPurposeError,
 };
 use crate::consensus::ConsensusError;
 use bincode::{Decode, Encode};
@@ -49,6 +50,9 @@ pub enum SignatureError {
 
     #[error(transparent)]
     BasicBLSError(BasicBLSError),
+
+    #[error(transparent)]
+    PublicKeyIsExpiredError(PublicKeyIsExpiredError),
 }
 
 impl From<SignatureError> for ConsensusError {
@@ -0,0 +1,44 @@
+use crate::consensus::state::state_error::StateError;
+use crate::consensus::ConsensusError;
+use bincode::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+#[derive(Error, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Encode, Decode)]
+#[error("Can't add index '{index_name}' to document type '{document_type}': it has more than {max_documents} documents to backfill the index with")]
+pub struct DataContractTooManyDocumentsToBackfillIndexError {
+    /*
+
+    DO NOT CHANGE ORDER OF FIELDS WITHOUT INTRODUCING OF NEW VERSION
+
+    */
+    document_type: String,
+    index_name: String,
+    max_documents: u16,
+}
+
+impl DataContractTooManyDocumentsToBackfillIndexError {
+    pub fn new(document_type: String, index_name: String, max_documents: u16) -> Self {
+        Self {
+            document_type,
+            index_name,
+            max_documents,
+        }
+    }
+
+    pub fn document_type(&self) -> &str {
+        &self.document_type
+    }
+    pub fn index_name(&self) -> &str {
+        &self.index_name
+    }
+    pub fn max_documents(&self) -> u16 {
+        self.max_documents
+    }
+}
+
+impl From<DataContractTooManyDocumentsToBackfillIndexError> for ConsensusError {
+    fn from(err: DataContractTooManyDocumentsToBackfillIndexError) -> Self {
+        Self::StateError(StateError::DataContractTooManyDocumentsToBackfillIndexError(err))
+    }
+}
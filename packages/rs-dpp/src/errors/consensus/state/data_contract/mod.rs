@@ -1,3 +1,4 @@
 pub mod data_contract_already_present_error;
 pub mod data_contract_config_update_error;
 pub mod data_contract_is_readonly_error;
+pub mod data_contract_too_many_documents_to_backfill_index_error;
@@ -0,0 +1,39 @@
+use crate::consensus::state::state_error::StateError;
+use crate::consensus::ConsensusError;
+use bincode::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+#[derive(Error, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Encode, Decode)]
+#[error("Identity public key valid until time ({valid_until}) must be after the last block time ({last_block_time})")]
+pub struct IdentityPublicKeyValidUntilInPastError {
+    /*
+
+    DO NOT CHANGE ORDER OF FIELDS WITHOUT INTRODUCING OF NEW VERSION
+
+    */
+    valid_until: u64,
+    last_block_time: u64,
+}
+
+impl IdentityPublicKeyValidUntilInPastError {
+    pub fn new(valid_until: u64, last_block_time: u64) -> Self {
+        Self {
+            valid_until,
+            last_block_time,
+        }
+    }
+
+    pub fn valid_until(&self) -> u64 {
+        self.valid_until
+    }
+
+    pub fn last_block_time(&self) -> u64 {
+        self.last_block_time
+    }
+}
+impl From<IdentityPublicKeyValidUntilInPastError> for ConsensusError {
+    fn from(err: IdentityPublicKeyValidUntilInPastError) -> Self {
+        Self::StateError(StateError::IdentityPublicKeyValidUntilInPastError(err))
+    }
+}
@@ -0,0 +1,56 @@
+use crate::consensus::state::state_error::StateError;
+use crate::consensus::ConsensusError;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::prelude::{Identifier, IdentityNonce};
+
+use bincode::{Decode, Encode};
+
+#[derive(Error, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Encode, Decode)]
+#[error("Identity {identity_id} is trying to set nonce {setting_nonce} for contract {contract_id}, but the current nonce is {current_nonce}")]
+pub struct InvalidIdentityContractNonceError {
+    /*
+
+    DO NOT CHANGE ORDER OF FIELDS WITHOUT INTRODUCING OF NEW VERSION
+
+    */
+    identity_id: Identifier,
+    contract_id: Identifier,
+    current_nonce: IdentityNonce,
+    setting_nonce: IdentityNonce,
+}
+
+impl InvalidIdentityContractNonceError {
+    pub fn new(
+        identity_id: Identifier,
+        contract_id: Identifier,
+        current_nonce: IdentityNonce,
+        setting_nonce: IdentityNonce,
+    ) -> Self {
+        Self {
+            identity_id,
+            contract_id,
+            current_nonce,
+            setting_nonce,
+        }
+    }
+
+    pub fn identity_id(&self) -> &Identifier {
+        &self.identity_id
+    }
+    pub fn contract_id(&self) -> &Identifier {
+        &self.contract_id
+    }
+    pub fn current_nonce(&self) -> IdentityNonce {
+        self.current_nonce
+    }
+    pub fn setting_nonce(&self) -> IdentityNonce {
+        self.setting_nonce
+    }
+}
+impl From<InvalidIdentityContractNonceError> for ConsensusError {
+    fn from(err: InvalidIdentityContractNonceError) -> Self {
+        Self::StateError(StateError::InvalidIdentityContractNonceError(err))
+    }
+}
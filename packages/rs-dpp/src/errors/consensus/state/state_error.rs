@@ -5,6 +5,7 @@ use thiserror::Error;
 use crate::consensus::state::data_contract::data_contract_already_present_error::DataContractAlreadyPresentError;
 use crate::consensus::state::data_contract::data_contract_config_update_error::DataContractConfigUpdateError;
 use crate::consensus::state::data_contract::data_contract_is_readonly_error::DataContractIsReadonlyError;
+use crate::consensus::state::data_contract::data_contract_too_many_documents_to_backfill_index_error::DataContractTooManyDocumentsToBackfillIndexError;
 use crate::consensus::state::data_trigger::data_trigger_error::{
     DataTriggerActionError, DataTriggerError,
 };
@@ -20,6 +21,9 @@ use crate::consensus::state::identity::duplicated_identity_public_key_state_erro
 use crate::consensus::state::identity::identity_public_key_disabled_at_window_violation_error::IdentityPublicKeyDisabledAtWindowViolationError;
 use crate::consensus::state::identity::identity_public_key_is_disabled_error::IdentityPublicKeyIsDisabledError;
 use crate::consensus::state::identity::identity_public_key_is_read_only_error::IdentityPublicKeyIsReadOnlyError;
+use crate::consensus::state::identity::identity_public_key_valid_until_in_past_error::IdentityPublicKeyValidUntilInPastError;
+use crate::consensus::state::identity::invalid_identity_contract_nonce_error::InvalidIdentityContractNonceError;
+use crate::consensus::state::identity::invalid_identity_nonce_error::InvalidIdentityNonceError;
 use crate::consensus::state::identity::invalid_identity_public_key_id_error::InvalidIdentityPublicKeyIdError;
 use crate::consensus::state::identity::invalid_identity_revision_error::InvalidIdentityRevisionError;
 use crate::consensus::state::identity::max_identity_public_key_limit_reached_error::MaxIdentityPublicKeyLimitReachedError;
@@ -111,6 +115,20 @@ pub enum StateError {
 
     #[error(transparent)]
     DataContractConfigUpdateError(DataContractConfigUpdateError),
+
+    #[error(transparent)]
+    InvalidIdentityNonceError(InvalidIdentityNonceError),
+
+    #[error(transparent)]
+    InvalidIdentityContractNonceError(InvalidIdentityContractNonceError),
+
+    #[error(transparent)]
+    IdentityPublicKeyValidUntilInPastError(IdentityPublicKeyValidUntilInPastError),
+
+    #[error(transparent)]
+    DataContractTooManyDocumentsToBackfillIndexError(
+        DataContractTooManyDocumentsToBackfillIndexError,
+    ),
 }
 
 impl From<StateError> for ConsensusError {
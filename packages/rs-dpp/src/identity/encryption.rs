@@ -0,0 +1,192 @@
+//! ECIES encryption against identity keys.
+//!
+//! A message is encrypted with an ephemeral secp256k1 key. The AES-256-GCM key is derived
+//! from the ECDH shared secret of the ephemeral key and the recipient key.
+//! The ciphertext layout is `[ephemeral public key (33), nonce (12), encrypted message, tag (16)]`.
+
+use aes_gcm::aead::{Aead, KeyInit};
+use aes_gcm::{Aes256Gcm, Nonce};
+use dashcore::secp256k1::ecdh::SharedSecret;
+use dashcore::secp256k1::rand::rngs::StdRng as EcdsaRng;
+use dashcore::secp256k1::rand::{RngCore, SeedableRng};
+use dashcore::secp256k1::{PublicKey, Secp256k1, SecretKey};
+use sha2::{Digest, Sha256};
+
+use crate::identity::{IdentityPublicKey, KeyType, Purpose};
+use crate::state_transition::errors::{
+    InvalidIdentityPublicKeyTypeError, WrongPublicKeyPurposeError,
+};
+use crate::ProtocolError;
+
+pub const ECIES_EPHEMERAL_PUBLIC_KEY_SIZE: usize = 33;
+pub const ECIES_NONCE_SIZE: usize = 12;
+pub const ECIES_TAG_SIZE: usize = 16;
+/// The size a message grows by when it is encrypted
+pub const ECIES_OVERHEAD: usize =
+    ECIES_EPHEMERAL_PUBLIC_KEY_SIZE + ECIES_NONCE_SIZE + ECIES_TAG_SIZE;
+
+fn derive_symmetric_key(shared_secret: &SharedSecret, ephemeral_public_key: &[u8]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(shared_secret.secret_bytes());
+    hasher.update(ephemeral_public_key);
+    hasher.finalize().into()
+}
+
+/// Encrypts a message to a compressed or uncompressed secp256k1 public key
+pub fn encrypt_to_public_key(
+    recipient_public_key: &[u8],
+    message: &[u8],
+) -> Result<Vec<u8>, ProtocolError> {
+    let recipient_public_key = PublicKey::from_slice(recipient_public_key)
+        .map_err(|e| ProtocolError::Generic(format!("invalid recipient public key: {}", e)))?;
+
+    let secp = Secp256k1::new();
+    let mut rng = EcdsaRng::from_entropy();
+    let ephemeral_secret_key = SecretKey::new(&mut rng);
+    let ephemeral_public_key = PublicKey::from_secret_key(&secp, &ephemeral_secret_key).serialize();
+
+    let shared_secret = SharedSecret::new(&recipient_public_key, &ephemeral_secret_key);
+    let key = derive_symmetric_key(&shared_secret, &ephemeral_public_key);
+
+    let mut nonce = [0u8; ECIES_NONCE_SIZE];
+    rng.fill_bytes(&mut nonce);
+
+    let cipher = Aes256Gcm::new_from_slice(&key)
+        .map_err(|e| ProtocolError::Generic(format!("invalid encryption key: {}", e)))?;
+    let encrypted_message = cipher
+        .encrypt(Nonce::from_slice(&nonce), message)
+        .map_err(|e| ProtocolError::Generic(format!("unable to encrypt message: {}", e)))?;
+
+    let mut ciphertext = Vec::with_capacity(message.len() + ECIES_OVERHEAD);
+    ciphertext.extend_from_slice(&ephemeral_public_key);
+    ciphertext.extend_from_slice(&nonce);
+    ciphertext.extend(encrypted_message);
+    Ok(ciphertext)
+}
+
+/// Decrypts a message encrypted with [`encrypt_to_public_key`] using the recipient private key
+pub fn decrypt_with_private_key(
+    private_key: &[u8],
+    ciphertext: &[u8],
+) -> Result<Vec<u8>, ProtocolError> {
+    if ciphertext.len() < ECIES_OVERHEAD {
+        return Err(ProtocolError::DecodingError(format!(
+            "ciphertext must be at least {} bytes long, got {}",
+            ECIES_OVERHEAD,
+            ciphertext.len()
+        )));
+    }
+
+    let secret_key = SecretKey::from_slice(private_key)
+        .map_err(|e| ProtocolError::Generic(format!("invalid private key: {}", e)))?;
+
+    let (ephemeral_public_key, rest) = ciphertext.split_at(ECIES_EPHEMERAL_PUBLIC_KEY_SIZE);
+    let (nonce, encrypted_message) = rest.split_at(ECIES_NONCE_SIZE);
+
+    let ephemeral_public_key_point = PublicKey::from_slice(ephemeral_public_key)
+        .map_err(|e| ProtocolError::DecodingError(format!("invalid ephemeral key: {}", e)))?;
+
+    let shared_secret = SharedSecret::new(&ephemeral_public_key_point, &secret_key);
+    let key = derive_symmetric_key(&shared_secret, ephemeral_public_key);
+
+    let cipher = Aes256Gcm::new_from_slice(&key)
+        .map_err(|e| ProtocolError::Generic(format!("invalid encryption key: {}", e)))?;
+    cipher
+        .decrypt(Nonce::from_slice(nonce), encrypted_message)
+        .map_err(|e| ProtocolError::Generic(format!("unable to decrypt message: {}", e)))
+}
+
+impl IdentityPublicKey {
+    /// Encrypts a message so that only the owner of this key can read it
+    pub fn encrypt_message(&self, message: &[u8]) -> Result<Vec<u8>, ProtocolError> {
+        self.verify_can_be_used_for_encryption()?;
+        encrypt_to_public_key(self.data.as_slice(), message)
+    }
+
+    /// Decrypts a message that was encrypted to this key
+    pub fn decrypt_message(
+        &self,
+        private_key: &[u8],
+        ciphertext: &[u8],
+    ) -> Result<Vec<u8>, ProtocolError> {
+        self.verify_can_be_used_for_encryption()?;
+        decrypt_with_private_key(private_key, ciphertext)
+    }
+
+    fn verify_can_be_used_for_encryption(&self) -> Result<(), ProtocolError> {
+        if self.purpose != Purpose::ENCRYPTION && self.purpose != Purpose::DECRYPTION {
+            return Err(ProtocolError::WrongPublicKeyPurposeError(
+                WrongPublicKeyPurposeError::new(self.purpose, Purpose::ENCRYPTION),
+            ));
+        }
+
+        if self.key_type != KeyType::ECDSA_SECP256K1 {
+            return Err(ProtocolError::InvalidIdentityPublicKeyTypeError(
+                InvalidIdentityPublicKeyTypeError::new(self.key_type),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::identity::SecurityLevel;
+    use platform_value::BinaryData;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn encryption_key(purpose: Purpose) -> (IdentityPublicKey, Vec<u8>) {
+        let mut rng = StdRng::seed_from_u64(10);
+        let (public_key, private_key) =
+            KeyType::ECDSA_SECP256K1.random_public_and_private_key_data(&mut rng);
+        let key = IdentityPublicKey {
+            id: 2,
+            purpose,
+            security_level: SecurityLevel::MEDIUM,
+            key_type: KeyType::ECDSA_SECP256K1,
+            read_only: false,
+            data: BinaryData::new(public_key),
+            disabled_at: None,
+            valid_until: None,
+        };
+        (key, private_key)
+    }
+
+    #[test]
+    fn should_decrypt_encrypted_message() {
+        let (key, private_key) = encryption_key(Purpose::DECRYPTION);
+        let message = b"xpub of the contact";
+
+        let ciphertext = key.encrypt_message(message).expect("expected to encrypt");
+        assert_eq!(ciphertext.len(), message.len() + ECIES_OVERHEAD);
+
+        let decrypted = key
+            .decrypt_message(&private_key, &ciphertext)
+            .expect("expected to decrypt");
+        assert_eq!(decrypted, message);
+    }
+
+    #[test]
+    fn should_not_decrypt_tampered_message() {
+        let (key, private_key) = encryption_key(Purpose::ENCRYPTION);
+
+        let mut ciphertext = key.encrypt_message(b"secret").expect("expected to encrypt");
+        let last = ciphertext.len() - 1;
+        ciphertext[last] ^= 1;
+
+        assert!(key.decrypt_message(&private_key, &ciphertext).is_err());
+    }
+
+    #[test]
+    fn should_not_encrypt_to_authentication_key() {
+        let (key, _) = encryption_key(Purpose::AUTHENTICATION);
+
+        assert!(matches!(
+            key.encrypt_message(b"secret"),
+            Err(ProtocolError::WrongPublicKeyPurposeError(_))
+        ));
+    }
+}
@@ -7,7 +7,7 @@ use crate::identity::state_transition::identity_topup_transition::IdentityTopUpT
 use crate::identity::state_transition::identity_update_transition::identity_update_transition::IdentityUpdateTransition;
 use crate::identity::validation::{IdentityValidator, PublicKeysValidator};
 use crate::identity::{Identity, IdentityPublicKey, KeyID, TimestampMillis};
-use crate::prelude::Identifier;
+use crate::prelude::{Identifier, IdentityNonce};
 
 use crate::{BlsModule, Convertible, ProtocolError};
 
@@ -23,7 +23,9 @@ use crate::consensus::basic::BasicError;
 use crate::consensus::ConsensusError;
 use crate::serialization_traits::PlatformDeserializable;
 
-use crate::identity::state_transition::identity_credit_transfer_transition::IdentityCreditTransferTransition;
+use crate::identity::state_transition::identity_credit_transfer_transition::{
+    IdentityCreditTransferRecipient, IdentityCreditTransferTransition,
+};
 use platform_value::Value;
 use std::sync::Arc;
 
@@ -270,14 +272,14 @@ where
     pub fn create_identity_credit_transfer_transition(
         &self,
         identity_id: Identifier,
-        recipient_id: Identifier,
-        amount: u64,
+        recipients: Vec<IdentityCreditTransferRecipient>,
+        nonce: IdentityNonce,
     ) -> Result<IdentityCreditTransferTransition, ProtocolError> {
         let mut identity_credit_transfer_transition = IdentityCreditTransferTransition::default();
         identity_credit_transfer_transition.set_protocol_version(self.protocol_version);
         identity_credit_transfer_transition.set_identity_id(identity_id);
-        identity_credit_transfer_transition.set_recipient_id(recipient_id);
-        identity_credit_transfer_transition.set_amount(amount);
+        identity_credit_transfer_transition.set_recipients(recipients);
+        identity_credit_transfer_transition.set_nonce(nonce);
 
         Ok(identity_credit_transfer_transition)
     }
@@ -12,9 +12,11 @@ use crate::identity::state_transition::identity_topup_transition::IdentityTopUpT
 use crate::identity::state_transition::identity_update_transition::identity_update_transition::IdentityUpdateTransition;
 use crate::identity::validation::{IdentityValidator, PublicKeysValidator};
 use crate::identity::{Identity, IdentityPublicKey, KeyID, TimestampMillis};
-use crate::prelude::Identifier;
+use crate::prelude::{Identifier, IdentityNonce};
 
-use crate::identity::state_transition::identity_credit_transfer_transition::IdentityCreditTransferTransition;
+use crate::identity::state_transition::identity_credit_transfer_transition::{
+    IdentityCreditTransferRecipient, IdentityCreditTransferTransition,
+};
 use crate::validation::SimpleConsensusValidationResult;
 use crate::version::ProtocolVersionValidator;
 use crate::{BlsModule, DashPlatformProtocolInitError, NonConsensusError, ProtocolError};
@@ -116,11 +118,11 @@ where
     pub fn create_identity_credit_transfer_transition(
         &self,
         identity_id: Identifier,
-        recipient_id: Identifier,
-        amount: u64,
+        recipients: Vec<IdentityCreditTransferRecipient>,
+        nonce: IdentityNonce,
     ) -> Result<IdentityCreditTransferTransition, ProtocolError> {
         self.factory
-            .create_identity_credit_transfer_transition(identity_id, recipient_id, amount)
+            .create_identity_credit_transfer_transition(identity_id, recipients, nonce)
     }
 
     pub fn create_identity_update_transition(
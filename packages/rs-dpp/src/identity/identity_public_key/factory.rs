@@ -104,6 +104,7 @@ impl IdentityPublicKey {
             security_level,
             read_only,
             disabled_at: None,
+            valid_until: None,
             data,
         })
     }
@@ -153,6 +154,7 @@ impl IdentityPublicKey {
                 security_level,
                 read_only,
                 disabled_at: None,
+                valid_until: None,
                 data,
             },
             private_data,
@@ -205,6 +207,7 @@ impl IdentityPublicKey {
             security_level,
             read_only,
             disabled_at: None,
+            valid_until: None,
             data,
         })
     }
@@ -224,6 +227,7 @@ impl IdentityPublicKey {
             security_level,
             read_only,
             disabled_at: None,
+            valid_until: None,
             data,
         }
     }
@@ -246,6 +250,7 @@ impl IdentityPublicKey {
                 security_level,
                 read_only,
                 disabled_at: None,
+                valid_until: None,
                 data: data.into(),
             },
             private_data,
@@ -270,6 +275,7 @@ impl IdentityPublicKey {
                 security_level,
                 read_only,
                 disabled_at: None,
+                valid_until: None,
                 data: data.into(),
             },
             private_data,
@@ -1,3 +1,6 @@
+use crate::identity::multisig::{
+    MultisigPublicKeyData, MULTISIG_MAX_KEYS, MULTISIG_MIN_KEYS, MULTISIG_PUBLIC_KEY_SIZE,
+};
 use crate::util::hash::ripemd160_sha256;
 use anyhow::bail;
 use bincode::{Decode, Encode};
@@ -38,6 +41,7 @@ pub enum KeyType {
     ECDSA_HASH160 = 2,
     BIP13_SCRIPT_HASH = 3,
     EDDSA_25519_HASH160 = 4,
+    ECDSA_SECP256K1_MULTISIG = 5,
 }
 
 lazy_static! {
@@ -45,7 +49,11 @@ lazy_static! {
         (KeyType::ECDSA_SECP256K1, 33),
         (KeyType::BLS12_381, 48),
         (KeyType::ECDSA_HASH160, 20),
-        (KeyType::BIP13_SCRIPT_HASH, 20)
+        (KeyType::BIP13_SCRIPT_HASH, 20),
+        (
+            KeyType::ECDSA_SECP256K1_MULTISIG,
+            MultisigPublicKeyData::encoded_size(MULTISIG_MAX_KEYS)
+        )
     ]
     .iter()
     .copied()
@@ -72,6 +80,7 @@ impl KeyType {
             KeyType::ECDSA_HASH160 => false,
             KeyType::BIP13_SCRIPT_HASH => false,
             KeyType::EDDSA_25519_HASH160 => false,
+            KeyType::ECDSA_SECP256K1_MULTISIG => false,
         }
     }
 
@@ -98,6 +107,7 @@ impl KeyType {
             KeyType::ECDSA_HASH160 | KeyType::BIP13_SCRIPT_HASH | KeyType::EDDSA_25519_HASH160 => {
                 (0..self.default_size()).map(|_| rng.gen::<u8>()).collect()
             }
+            KeyType::ECDSA_SECP256K1_MULTISIG => self.random_public_and_private_key_data(rng).0,
         }
     }
 
@@ -153,6 +163,24 @@ impl KeyType {
                     private_key.to_bytes(),
                 )
             }
+            KeyType::ECDSA_SECP256K1_MULTISIG => {
+                // a 2 of 2 key, the private keys are concatenated in key index order
+                let secp = Secp256k1::new();
+                let mut rng = EcdsaRng::from_rng(rng).unwrap();
+                let mut public_keys = Vec::with_capacity(MULTISIG_MIN_KEYS as usize);
+                let mut private_keys = Vec::new();
+                for _ in 0..MULTISIG_MIN_KEYS {
+                    let secret_key = dashcore::secp256k1::SecretKey::new(&mut rng);
+                    let private_key = dashcore::PrivateKey::new(secret_key, Network::Dash);
+                    let mut public_key = [0u8; MULTISIG_PUBLIC_KEY_SIZE];
+                    public_key.copy_from_slice(&private_key.public_key(&secp).to_bytes());
+                    public_keys.push(public_key);
+                    private_keys.extend(private_key.to_bytes());
+                }
+                let public_key_data = MultisigPublicKeyData::new(MULTISIG_MIN_KEYS, public_keys)
+                    .expect("expected a valid multisig key");
+                (public_key_data.to_bytes(), private_keys)
+            }
         }
     }
 }
@@ -171,6 +199,7 @@ impl TryFrom<u8> for KeyType {
             1 => Ok(Self::BLS12_381),
             2 => Ok(Self::ECDSA_HASH160),
             3 => Ok(Self::BIP13_SCRIPT_HASH),
+            5 => Ok(Self::ECDSA_SECP256K1_MULTISIG),
             value => bail!("unrecognized key type: {}", value),
         }
     }
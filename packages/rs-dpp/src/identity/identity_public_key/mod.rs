@@ -2,6 +2,7 @@
 
 pub mod factory;
 pub mod key_type;
+pub mod multisig;
 pub mod purpose;
 pub mod security_level;
 
@@ -67,6 +68,9 @@ pub struct IdentityPublicKey {
     pub data: BinaryData,
     #[serde(default)]
     pub disabled_at: Option<TimestampMillis>,
+    /// The key can not be used to sign state transitions from this time on
+    #[serde(default)]
+    pub valid_until: Option<TimestampMillis>,
 }
 
 impl Into<IdentityPublicKeyInCreation> for &IdentityPublicKey {
@@ -78,6 +82,7 @@ impl Into<IdentityPublicKeyInCreation> for &IdentityPublicKey {
             key_type: self.key_type,
             read_only: self.read_only,
             data: self.data.clone(),
+            valid_until: self.valid_until,
             signature: BinaryData::default(),
         }
     }
@@ -95,6 +100,11 @@ impl Convertible for IdentityPublicKey {
                 .remove("disabledAt")
                 .map_err(ProtocolError::ValueError)?;
         }
+        if self.valid_until.is_none() {
+            value
+                .remove("validUntil")
+                .map_err(ProtocolError::ValueError)?;
+        }
         Ok(value)
     }
 
@@ -137,6 +147,18 @@ impl IdentityPublicKey {
         self.disabled_at.is_some()
     }
 
+    /// Set validUntil
+    pub fn set_valid_until(&mut self, timestamp_millis: u64) {
+        self.valid_until = Some(timestamp_millis);
+    }
+
+    /// Is public key expired at the given time
+    pub fn is_expired_at(&self, timestamp_millis: TimestampMillis) -> bool {
+        self.valid_until
+            .map(|valid_until| valid_until <= timestamp_millis)
+            .unwrap_or(false)
+    }
+
     /// Checks if public key security level is MASTER
     pub fn is_master(&self) -> bool {
         self.security_level == SecurityLevel::MASTER
@@ -176,6 +198,7 @@ impl IdentityPublicKey {
             KeyType::ECDSA_HASH160 | KeyType::BIP13_SCRIPT_HASH | KeyType::EDDSA_25519_HASH160 => {
                 Ok(Bytes20::from_vec(self.data.to_vec())?.into_buffer())
             }
+            KeyType::ECDSA_SECP256K1_MULTISIG => Ok(ripemd160_sha256(self.data.as_slice())),
         }
     }
 
@@ -213,6 +236,7 @@ impl IdentityPublicKey {
         let public_key_bytes =
             key_value_map.as_bytes("data", "Identity public key must have a data")?;
         let disabled_at = key_value_map.as_u64("disabledAt", "").ok();
+        let valid_until = key_value_map.as_u64("validUntil", "").ok();
 
         Ok(IdentityPublicKey {
             id: id.into(),
@@ -222,6 +246,7 @@ impl IdentityPublicKey {
             data: BinaryData::new(public_key_bytes),
             read_only: readonly,
             disabled_at,
+            valid_until,
         })
     }
 
@@ -238,6 +263,9 @@ impl IdentityPublicKey {
         if let Some(ts) = self.disabled_at {
             pk_map.insert("disabledAt", ts)
         }
+        if let Some(ts) = self.valid_until {
+            pk_map.insert("validUntil", ts)
+        }
 
         pk_map.to_value_sorted()
     }
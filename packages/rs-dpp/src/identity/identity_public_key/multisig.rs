@@ -0,0 +1,314 @@
+//! Threshold (m-of-n) ECDSA keys.
+//!
+//! The public key data of an `ECDSA_SECP256K1_MULTISIG` key is encoded as
+//! `[threshold, key_count, key_0 (33 bytes), .., key_n (33 bytes)]`.
+//!
+//! A signature made with such a key is a concatenation of entries
+//! `[key_index, compact recoverable signature (65 bytes)]`, one entry per co-signer.
+//! The signature is valid when at least `threshold` distinct keys signed the data.
+
+use std::collections::BTreeSet;
+
+use dashcore::signer;
+
+use crate::ProtocolError;
+
+/// Size of a compressed secp256k1 public key
+pub const MULTISIG_PUBLIC_KEY_SIZE: usize = 33;
+/// Size of a compact recoverable ECDSA signature
+pub const MULTISIG_SIGNATURE_SIZE: usize = 65;
+/// Maximum number of keys in a multisig key
+pub const MULTISIG_MAX_KEYS: u8 = 16;
+/// Minimum number of keys in a multisig key
+pub const MULTISIG_MIN_KEYS: u8 = 2;
+
+const SIGNATURE_ENTRY_SIZE: usize = 1 + MULTISIG_SIGNATURE_SIZE;
+/// Maximum size of a multisig signature, a signature entry for every key
+pub const MULTISIG_MAX_SIGNATURE_SIZE: usize = MULTISIG_MAX_KEYS as usize * SIGNATURE_ENTRY_SIZE;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MultisigPublicKeyData {
+    pub threshold: u8,
+    pub public_keys: Vec<[u8; MULTISIG_PUBLIC_KEY_SIZE]>,
+}
+
+impl MultisigPublicKeyData {
+    pub fn new(
+        threshold: u8,
+        public_keys: Vec<[u8; MULTISIG_PUBLIC_KEY_SIZE]>,
+    ) -> Result<Self, ProtocolError> {
+        let data = Self {
+            threshold,
+            public_keys,
+        };
+        data.validate()?;
+        Ok(data)
+    }
+
+    /// Encoded size of a multisig key with `key_count` keys
+    pub const fn encoded_size(key_count: u8) -> usize {
+        2 + key_count as usize * MULTISIG_PUBLIC_KEY_SIZE
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, ProtocolError> {
+        let [threshold, key_count, keys @ ..] = bytes else {
+            return Err(ProtocolError::DecodingError(
+                "multisig key data must contain a threshold and a key count".to_string(),
+            ));
+        };
+
+        if keys.len() != *key_count as usize * MULTISIG_PUBLIC_KEY_SIZE {
+            return Err(ProtocolError::DecodingError(format!(
+                "multisig key data declares {} keys but contains {} bytes of keys",
+                key_count,
+                keys.len()
+            )));
+        }
+
+        let public_keys = keys
+            .chunks_exact(MULTISIG_PUBLIC_KEY_SIZE)
+            .map(|key| {
+                let mut public_key = [0u8; MULTISIG_PUBLIC_KEY_SIZE];
+                public_key.copy_from_slice(key);
+                public_key
+            })
+            .collect();
+
+        Self::new(*threshold, public_keys)
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(Self::encoded_size(self.public_keys.len() as u8));
+        bytes.push(self.threshold);
+        bytes.push(self.public_keys.len() as u8);
+        for public_key in self.public_keys.iter() {
+            bytes.extend_from_slice(public_key);
+        }
+        bytes
+    }
+
+    fn validate(&self) -> Result<(), ProtocolError> {
+        let key_count = self.public_keys.len();
+        if key_count < MULTISIG_MIN_KEYS as usize || key_count > MULTISIG_MAX_KEYS as usize {
+            return Err(ProtocolError::DecodingError(format!(
+                "multisig key must have between {} and {} keys, got {}",
+                MULTISIG_MIN_KEYS, MULTISIG_MAX_KEYS, key_count
+            )));
+        }
+
+        if self.threshold == 0 || self.threshold as usize > key_count {
+            return Err(ProtocolError::DecodingError(format!(
+                "multisig threshold must be between 1 and {}, got {}",
+                key_count, self.threshold
+            )));
+        }
+
+        let unique_keys = self.public_keys.iter().collect::<BTreeSet<_>>();
+        if unique_keys.len() != key_count {
+            return Err(ProtocolError::DecodingError(
+                "multisig key must not contain duplicated keys".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Verifies that at least `threshold` distinct keys signed the data
+    pub fn verify_signature(&self, data: &[u8], signature: &[u8]) -> Result<(), ProtocolError> {
+        if signature.is_empty() || signature.len() % SIGNATURE_ENTRY_SIZE != 0 {
+            return Err(ProtocolError::DecodingError(format!(
+                "multisig signature length must be a multiple of {}, got {}",
+                SIGNATURE_ENTRY_SIZE,
+                signature.len()
+            )));
+        }
+
+        let mut signers = BTreeSet::new();
+
+        for entry in signature.chunks_exact(SIGNATURE_ENTRY_SIZE) {
+            let key_index = entry[0];
+            let Some(public_key) = self.public_keys.get(key_index as usize) else {
+                return Err(ProtocolError::DecodingError(format!(
+                    "multisig signature references unknown key index {}",
+                    key_index
+                )));
+            };
+
+            if !signers.insert(key_index) {
+                return Err(ProtocolError::DecodingError(format!(
+                    "multisig signature contains key index {} more than once",
+                    key_index
+                )));
+            }
+
+            signer::verify_data_signature(data, &entry[1..], public_key).map_err(|e| {
+                ProtocolError::Generic(format!(
+                    "multisig signature for key index {} is invalid: {}",
+                    key_index, e
+                ))
+            })?;
+        }
+
+        if signers.len() < self.threshold as usize {
+            return Err(ProtocolError::Generic(format!(
+                "multisig signature has {} of {} required signatures",
+                signers.len(),
+                self.threshold
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+/// Signs the data with a single co-signer private key and appends the resulting entry
+/// to an existing (possibly empty) multisig signature
+pub fn add_multisig_signature(
+    signature: &mut Vec<u8>,
+    key_index: u8,
+    data: &[u8],
+    private_key: &[u8],
+) -> Result<(), ProtocolError> {
+    let entry_signature = signer::sign(data, private_key)?;
+    signature.push(key_index);
+    signature.extend_from_slice(entry_signature.as_slice());
+    Ok(())
+}
+
+/// Signs the data with private keys concatenated in key index order,
+/// the first private key signs for key index 0, the second for key index 1 and so on
+pub fn sign_with_concatenated_private_keys(
+    data: &[u8],
+    private_keys: &[u8],
+) -> Result<Vec<u8>, ProtocolError> {
+    if private_keys.is_empty() || private_keys.len() % 32 != 0 {
+        return Err(ProtocolError::Generic(format!(
+            "multisig private keys must be a concatenation of 32 byte keys, got {} bytes",
+            private_keys.len()
+        )));
+    }
+
+    let mut signature = Vec::with_capacity(private_keys.len() / 32 * SIGNATURE_ENTRY_SIZE);
+    for (key_index, private_key) in private_keys.chunks_exact(32).enumerate() {
+        add_multisig_signature(&mut signature, key_index as u8, data, private_key)?;
+    }
+    Ok(signature)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dashcore::secp256k1::rand::rngs::StdRng;
+    use dashcore::secp256k1::rand::SeedableRng;
+    use dashcore::secp256k1::Secp256k1;
+    use dashcore::{Network, PrivateKey};
+
+    fn random_keys(count: usize) -> (Vec<Vec<u8>>, Vec<[u8; MULTISIG_PUBLIC_KEY_SIZE]>) {
+        let secp = Secp256k1::new();
+        let mut rng = StdRng::seed_from_u64(5);
+        (0..count)
+            .map(|_| {
+                let secret_key = dashcore::secp256k1::SecretKey::new(&mut rng);
+                let private_key = PrivateKey::new(secret_key, Network::Dash);
+                let mut public_key = [0u8; MULTISIG_PUBLIC_KEY_SIZE];
+                public_key.copy_from_slice(&private_key.public_key(&secp).to_bytes());
+                (private_key.to_bytes(), public_key)
+            })
+            .unzip()
+    }
+
+    #[test]
+    fn should_fit_max_signature_into_identity_signed_state_transitions() {
+        let schemas = [
+            include_str!("../../schema/data_contract/stateTransition/dataContractCreate.json"),
+            include_str!("../../schema/data_contract/stateTransition/dataContractUpdate.json"),
+            include_str!("../../schema/document/stateTransition/documentsBatch.json"),
+            include_str!("../../schema/identity/stateTransition/identityCreditTransfer.json"),
+            include_str!("../../schema/identity/stateTransition/identityCreditWithdrawal.json"),
+            include_str!("../../schema/identity/stateTransition/identityUpdate.json"),
+        ];
+
+        for schema in schemas {
+            let schema: serde_json::Value =
+                serde_json::from_str(schema).expect("expected a valid schema");
+            assert_eq!(
+                schema["properties"]["signature"]["maxItems"].as_u64(),
+                Some(MULTISIG_MAX_SIGNATURE_SIZE as u64)
+            );
+        }
+    }
+
+    #[test]
+    fn should_round_trip_public_key_data() {
+        let (_, public_keys) = random_keys(3);
+        let data = MultisigPublicKeyData::new(2, public_keys).expect("expected valid key");
+
+        let bytes = data.to_bytes();
+        assert_eq!(bytes.len(), MultisigPublicKeyData::encoded_size(3));
+        assert_eq!(
+            MultisigPublicKeyData::from_bytes(&bytes).expect("expected to decode"),
+            data
+        );
+    }
+
+    #[test]
+    fn should_reject_invalid_threshold() {
+        let (_, public_keys) = random_keys(3);
+        assert!(MultisigPublicKeyData::new(0, public_keys.clone()).is_err());
+        assert!(MultisigPublicKeyData::new(4, public_keys).is_err());
+    }
+
+    #[test]
+    fn should_verify_signature_when_threshold_is_reached() {
+        let (private_keys, public_keys) = random_keys(3);
+        let key = MultisigPublicKeyData::new(2, public_keys).expect("expected valid key");
+        let data = b"some state transition";
+
+        let mut signature = vec![];
+        add_multisig_signature(&mut signature, 0, data, &private_keys[0]).unwrap();
+        assert!(key.verify_signature(data, &signature).is_err());
+
+        add_multisig_signature(&mut signature, 2, data, &private_keys[2]).unwrap();
+        key.verify_signature(data, &signature)
+            .expect("expected signature to be valid");
+    }
+
+    #[test]
+    fn should_sign_with_concatenated_private_keys() {
+        let (private_keys, public_keys) = random_keys(3);
+        let key = MultisigPublicKeyData::new(2, public_keys).expect("expected valid key");
+        let data = b"some state transition";
+
+        let signature = sign_with_concatenated_private_keys(data, &private_keys[0..2].concat())
+            .expect("expected to sign");
+
+        key.verify_signature(data, &signature)
+            .expect("expected signature to be valid");
+    }
+
+    #[test]
+    fn should_not_count_the_same_signer_twice() {
+        let (private_keys, public_keys) = random_keys(3);
+        let key = MultisigPublicKeyData::new(2, public_keys).expect("expected valid key");
+        let data = b"some state transition";
+
+        let mut signature = vec![];
+        add_multisig_signature(&mut signature, 1, data, &private_keys[1]).unwrap();
+        add_multisig_signature(&mut signature, 1, data, &private_keys[1]).unwrap();
+
+        assert!(key.verify_signature(data, &signature).is_err());
+    }
+
+    #[test]
+    fn should_reject_signature_from_wrong_key() {
+        let (private_keys, public_keys) = random_keys(3);
+        let key = MultisigPublicKeyData::new(2, public_keys).expect("expected valid key");
+        let data = b"some state transition";
+
+        let mut signature = vec![];
+        add_multisig_signature(&mut signature, 0, data, &private_keys[0]).unwrap();
+        add_multisig_signature(&mut signature, 1, data, &private_keys[2]).unwrap();
+
+        assert!(key.verify_signature(data, &signature).is_err());
+    }
+}
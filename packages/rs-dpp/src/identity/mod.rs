@@ -6,6 +6,7 @@ pub use identity_facade::*;
 pub use identity_public_key::*;
 
 pub mod core_script;
+pub mod encryption;
 mod get_biggest_possible_identity;
 mod identity;
 mod identity_facade;
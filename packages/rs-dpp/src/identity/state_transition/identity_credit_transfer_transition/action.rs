@@ -1,7 +1,9 @@
 use crate::identity::state_transition::identity_credit_transfer_transition::IdentityCreditTransferTransition;
+use crate::prelude::IdentityNonce;
 use crate::state_transition::fee::Credits;
 use platform_value::Identifier;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 pub const IDENTITY_CREDIT_TRANSFER_TRANSITION_ACTION_VERSION: u32 = 0;
 
@@ -9,24 +11,36 @@ pub const IDENTITY_CREDIT_TRANSFER_TRANSITION_ACTION_VERSION: u32 = 0;
 #[serde(rename_all = "camelCase")]
 pub struct IdentityCreditTransferTransitionAction {
     pub version: u32,
-    pub transfer_amount: Credits,
-    pub recipient_id: Identifier,
+    pub recipients: BTreeMap<Identifier, Credits>,
     pub identity_id: Identifier,
+    pub nonce: IdentityNonce,
+}
+
+impl IdentityCreditTransferTransitionAction {
+    /// The sum of credits transferred to all recipients, `None` on overflow
+    pub fn total_transfer_amount(&self) -> Option<Credits> {
+        self.recipients
+            .values()
+            .try_fold(0 as Credits, |total, amount| total.checked_add(*amount))
+    }
 }
 
 impl From<IdentityCreditTransferTransition> for IdentityCreditTransferTransitionAction {
     fn from(value: IdentityCreditTransferTransition) -> Self {
         let IdentityCreditTransferTransition {
             identity_id: owner_id,
-            recipient_id,
-            amount,
+            recipients,
+            nonce,
             ..
         } = value;
         IdentityCreditTransferTransitionAction {
             version: IDENTITY_CREDIT_TRANSFER_TRANSITION_ACTION_VERSION,
             identity_id: owner_id,
-            recipient_id,
-            transfer_amount: amount,
+            recipients: recipients
+                .into_iter()
+                .map(|recipient| (recipient.recipient_id, recipient.amount))
+                .collect(),
+            nonce,
         }
     }
 }
@@ -35,15 +49,18 @@ impl From<&IdentityCreditTransferTransition> for IdentityCreditTransferTransitio
     fn from(value: &IdentityCreditTransferTransition) -> Self {
         let IdentityCreditTransferTransition {
             identity_id,
-            recipient_id,
-            amount,
+            recipients,
+            nonce,
             ..
         } = value;
         IdentityCreditTransferTransitionAction {
             version: IDENTITY_CREDIT_TRANSFER_TRANSITION_ACTION_VERSION,
             identity_id: *identity_id,
-            recipient_id: *recipient_id,
-            transfer_amount: *amount,
+            recipients: recipients
+                .iter()
+                .map(|recipient| (recipient.recipient_id, recipient.amount))
+                .collect(),
+            nonce: *nonce,
         }
     }
 }
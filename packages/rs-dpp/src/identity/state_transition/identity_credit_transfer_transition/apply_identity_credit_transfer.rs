@@ -3,7 +3,7 @@ use crate::state_repository::StateRepositoryLike;
 use crate::state_transition::state_transition_execution_context::StateTransitionExecutionContext;
 use std::sync::Arc;
 
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 
 #[derive(Clone)]
 pub struct ApplyIdentityCreditTransferTransition<SR>
@@ -27,22 +27,23 @@ where
         execution_context: &StateTransitionExecutionContext,
     ) -> Result<()> {
         let identity_id = state_transition.get_identity_id();
-        let recipient_id = state_transition.get_recipient_id();
 
-        self.state_repository
-            .add_to_identity_balance(
-                recipient_id,
-                state_transition.amount,
-                Some(execution_context),
-            )
-            .await?;
+        let total_amount = state_transition
+            .get_total_amount()
+            .ok_or_else(|| anyhow!("total credit transfer amount overflow"))?;
+
+        for recipient in state_transition.get_recipients() {
+            self.state_repository
+                .add_to_identity_balance(
+                    &recipient.recipient_id,
+                    recipient.amount,
+                    Some(execution_context),
+                )
+                .await?;
+        }
 
         self.state_repository
-            .remove_from_identity_balance(
-                identity_id,
-                state_transition.amount,
-                Some(execution_context),
-            )
+            .remove_from_identity_balance(identity_id, total_amount, Some(execution_context))
             .await?;
 
         Ok(())
@@ -1,7 +1,7 @@
 use crate::identity::SecurityLevel::MASTER;
 use crate::identity::{KeyID, SecurityLevel};
 use crate::platform_serialization::PlatformSignable;
-use crate::prelude::Identifier;
+use crate::prelude::{Identifier, IdentityNonce};
 use crate::serialization_traits::{PlatformDeserializable, PlatformSerializable, Signable};
 use crate::state_transition::{
     StateTransitionConvert, StateTransitionIdentitySigned, StateTransitionLike, StateTransitionType,
@@ -20,7 +20,28 @@ mod property_names {
     pub const PROTOCOL_VERSION: &str = "protocolVersion";
     pub const TRANSITION_TYPE: &str = "type";
     pub const IDENTITY_ID: &str = "identityId";
-    pub const RECIPIENT_ID: &str = "recipientId";
+    pub const RECIPIENTS_RECIPIENT_ID: &str = "recipients[].recipientId";
+}
+
+/// The maximum number of recipients a single credit transfer can pay out to.
+/// Must be kept in sync with `maxItems` of `recipients` in the JSON schema.
+pub const MAX_IDENTITY_CREDIT_TRANSFER_RECIPIENTS: usize = 100;
+
+/// A single output of an identity credit transfer
+#[derive(Debug, Clone, Serialize, Deserialize, Encode, Decode, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct IdentityCreditTransferRecipient {
+    pub recipient_id: Identifier,
+    pub amount: u64,
+}
+
+impl IdentityCreditTransferRecipient {
+    pub fn new(recipient_id: Identifier, amount: u64) -> Self {
+        Self {
+            recipient_id,
+            amount,
+        }
+    }
 }
 
 #[derive(
@@ -42,8 +63,9 @@ pub struct IdentityCreditTransferTransition {
     pub transition_type: StateTransitionType,
     // Own ST fields
     pub identity_id: Identifier,
-    pub recipient_id: Identifier,
-    pub amount: u64,
+    pub recipients: Vec<IdentityCreditTransferRecipient>,
+    /// Must be exactly one more than the identity nonce stored in state
+    pub nonce: IdentityNonce,
     // Generic identity ST fields
     pub protocol_version: u32,
     #[platform_signable(exclude_from_sig_hash)]
@@ -57,8 +79,8 @@ impl Default for IdentityCreditTransferTransition {
         IdentityCreditTransferTransition {
             transition_type: StateTransitionType::IdentityCreditTransfer,
             identity_id: Identifier::default(),
-            recipient_id: Identifier::default(),
-            amount: Default::default(),
+            recipients: Default::default(),
+            nonce: Default::default(),
             protocol_version: LATEST_VERSION,
             signature_public_key_id: Default::default(),
             signature: Default::default(),
@@ -95,20 +117,32 @@ impl IdentityCreditTransferTransition {
         &self.identity_id
     }
 
-    pub fn set_recipient_id(&mut self, recipient_id: Identifier) {
-        self.recipient_id = recipient_id;
+    pub fn set_recipients(&mut self, recipients: Vec<IdentityCreditTransferRecipient>) {
+        self.recipients = recipients;
+    }
+
+    pub fn get_recipients(&self) -> &Vec<IdentityCreditTransferRecipient> {
+        &self.recipients
     }
 
-    pub fn get_recipient_id(&self) -> &Identifier {
-        &self.recipient_id
+    pub fn add_recipient(&mut self, recipient_id: Identifier, amount: u64) {
+        self.recipients
+            .push(IdentityCreditTransferRecipient::new(recipient_id, amount));
     }
 
-    pub fn get_amount(&self) -> u64 {
-        self.amount
+    /// The sum of all amounts sent to recipients, `None` on overflow
+    pub fn get_total_amount(&self) -> Option<u64> {
+        self.recipients
+            .iter()
+            .try_fold(0u64, |total, recipient| total.checked_add(recipient.amount))
     }
 
-    pub fn set_amount(&mut self, amount: u64) {
-        self.amount = amount;
+    pub fn set_nonce(&mut self, nonce: IdentityNonce) {
+        self.nonce = nonce;
+    }
+
+    pub fn get_nonce(&self) -> IdentityNonce {
+        self.nonce
     }
 
     pub fn set_protocol_version(&mut self, protocol_version: u32) {
@@ -122,7 +156,10 @@ impl StateTransitionConvert for IdentityCreditTransferTransition {
     }
 
     fn identifiers_property_paths() -> Vec<&'static str> {
-        vec![property_names::IDENTITY_ID, property_names::RECIPIENT_ID]
+        vec![
+            property_names::IDENTITY_ID,
+            property_names::RECIPIENTS_RECIPIENT_ID,
+        ]
     }
 
     fn binary_property_paths() -> Vec<&'static str> {
@@ -173,7 +210,13 @@ impl StateTransitionLike for IdentityCreditTransferTransition {
     }
 
     fn get_modified_data_ids(&self) -> Vec<Identifier> {
-        vec![self.identity_id, self.recipient_id]
+        let mut ids = vec![self.identity_id];
+        ids.extend(
+            self.recipients
+                .iter()
+                .map(|recipient| recipient.recipient_id),
+        );
+        ids
     }
 
     fn set_signature_bytes(&mut self, signature: Vec<u8>) {
@@ -202,7 +245,9 @@ impl StateTransitionIdentitySigned for IdentityCreditTransferTransition {
 
 #[cfg(test)]
 mod test {
-    use crate::identity::state_transition::identity_credit_transfer_transition::IdentityCreditTransferTransition;
+    use crate::identity::state_transition::identity_credit_transfer_transition::{
+        IdentityCreditTransferRecipient, IdentityCreditTransferTransition,
+    };
 
     use crate::serialization_traits::{PlatformDeserializable, PlatformSerializable};
     use crate::state_transition::StateTransitionType;
@@ -227,8 +272,11 @@ mod test {
         let transition = IdentityCreditTransferTransition {
             transition_type: StateTransitionType::IdentityCreditTransfer,
             identity_id: Identifier::random(),
-            recipient_id: Identifier::random(),
-            amount: rng.gen(),
+            recipients: vec![IdentityCreditTransferRecipient::new(
+                Identifier::random(),
+                rng.gen(),
+            )],
+            nonce: rng.gen(),
             protocol_version: rng.gen(),
             signature_public_key_id: rng.gen(),
             signature: [0; 65].to_vec().into(),
@@ -236,4 +284,35 @@ mod test {
 
         test_identity_credit_transfer_transition(transition);
     }
+
+    #[test]
+    fn test_identity_credit_transfer_transition_multiple_recipients() {
+        let mut rng = rand::thread_rng();
+        let transition = IdentityCreditTransferTransition {
+            transition_type: StateTransitionType::IdentityCreditTransfer,
+            identity_id: Identifier::random(),
+            recipients: (0..10)
+                .map(|_| IdentityCreditTransferRecipient::new(Identifier::random(), rng.gen()))
+                .collect(),
+            nonce: rng.gen(),
+            protocol_version: rng.gen(),
+            signature_public_key_id: rng.gen(),
+            signature: [0; 65].to_vec().into(),
+        };
+
+        test_identity_credit_transfer_transition(transition);
+    }
+
+    #[test]
+    fn test_identity_credit_transfer_transition_total_amount() {
+        let mut transition = IdentityCreditTransferTransition::default();
+        transition.add_recipient(Identifier::random(), 1000);
+        transition.add_recipient(Identifier::random(), 2500);
+
+        assert_eq!(transition.get_total_amount(), Some(3500));
+
+        transition.add_recipient(Identifier::random(), u64::MAX);
+
+        assert_eq!(transition.get_total_amount(), None);
+    }
 }
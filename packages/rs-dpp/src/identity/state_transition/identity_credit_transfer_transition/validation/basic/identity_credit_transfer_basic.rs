@@ -1,11 +1,19 @@
+use std::collections::HashSet;
+
 use lazy_static::lazy_static;
-use platform_value::Value;
+use platform_value::{Identifier, Value};
 use serde_json::Value as JsonValue;
 
+use crate::consensus::basic::identity::{
+    DuplicatedIdentityCreditTransferRecipientError, IdentityCreditTransferToSelfError,
+};
+use crate::identity::state_transition::identity_credit_transfer_transition::{
+    IdentityCreditTransferRecipient, IdentityCreditTransferTransition,
+};
 use crate::validation::SimpleConsensusValidationResult;
 use crate::{
     validation::JsonSchemaValidator, version::ProtocolVersionValidator,
-    DashPlatformProtocolInitError, NonConsensusError,
+    DashPlatformProtocolInitError, NonConsensusError, SerdeParsingError,
 };
 
 lazy_static! {
@@ -60,6 +68,20 @@ impl IdentityCreditTransferTransitionBasicValidator {
             )?,
         );
 
+        if !result.is_valid() {
+            return Ok(result);
+        }
+
+        let transition = IdentityCreditTransferTransition::from_raw_object(
+            transition_object.clone(),
+        )
+        .map_err(|e| NonConsensusError::SerdeParsingError(SerdeParsingError::new(e.to_string())))?;
+
+        result.merge(validate_identity_credit_transfer_recipients(
+            &transition.identity_id,
+            &transition.recipients,
+        ));
+
         Ok(result)
     }
 
@@ -67,3 +89,138 @@ impl IdentityCreditTransferTransitionBasicValidator {
         &mut self.protocol_version_validator
     }
 }
+
+/// Recipients of a credit transfer must be unique and must not include the sender,
+/// otherwise balance updates for the same identity would collide in one batch
+pub fn validate_identity_credit_transfer_recipients(
+    identity_id: &Identifier,
+    recipients: &[IdentityCreditTransferRecipient],
+) -> SimpleConsensusValidationResult {
+    let mut result = SimpleConsensusValidationResult::default();
+    let mut seen_recipient_ids = HashSet::with_capacity(recipients.len());
+
+    for recipient in recipients {
+        if &recipient.recipient_id == identity_id {
+            result.add_error(IdentityCreditTransferToSelfError::new(*identity_id));
+            return result;
+        }
+
+        if !seen_recipient_ids.insert(recipient.recipient_id) {
+            result.add_error(DuplicatedIdentityCreditTransferRecipientError::new(
+                recipient.recipient_id,
+            ));
+            return result;
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::consensus::basic::BasicError;
+    use crate::consensus::ConsensusError;
+    use crate::identity::state_transition::identity_credit_transfer_transition::MAX_IDENTITY_CREDIT_TRANSFER_RECIPIENTS;
+    use crate::state_transition::StateTransitionConvert;
+
+    fn transition_with_recipients(recipient_count: usize) -> IdentityCreditTransferTransition {
+        let mut transition = IdentityCreditTransferTransition {
+            identity_id: Identifier::random(),
+            nonce: 1,
+            signature: [0; 65].to_vec().into(),
+            ..Default::default()
+        };
+        for _ in 0..recipient_count {
+            transition.add_recipient(Identifier::random(), 1000);
+        }
+        transition
+    }
+
+    fn validate_schema(
+        transition: &IdentityCreditTransferTransition,
+    ) -> SimpleConsensusValidationResult {
+        let json = transition
+            .to_object(false)
+            .expect("expected a raw object")
+            .try_to_validating_json()
+            .expect("expected a validating json");
+        IDENTITY_CREDIT_TRANSFER_TRANSITION_SCHEMA_VALIDATOR
+            .validate(&json)
+            .expect("expected to validate the schema")
+    }
+
+    #[test]
+    fn should_limit_recipients_in_the_schema_to_the_maximum_number_of_recipients() {
+        assert_eq!(
+            IDENTITY_CREDIT_TRANSFER_TRANSITION_SCHEMA["properties"]["recipients"]["maxItems"],
+            MAX_IDENTITY_CREDIT_TRANSFER_RECIPIENTS
+        );
+    }
+
+    #[test]
+    fn should_accept_the_maximum_number_of_recipients() {
+        let transition = transition_with_recipients(MAX_IDENTITY_CREDIT_TRANSFER_RECIPIENTS);
+
+        assert!(validate_schema(&transition).is_valid());
+    }
+
+    #[test]
+    fn should_return_invalid_result_if_there_are_too_many_recipients() {
+        let transition = transition_with_recipients(MAX_IDENTITY_CREDIT_TRANSFER_RECIPIENTS + 1);
+
+        let result = validate_schema(&transition);
+
+        assert!(matches!(
+            result.errors.as_slice(),
+            [ConsensusError::BasicError(BasicError::JsonSchemaError(e))] if e.keyword() == "maxItems"
+        ));
+    }
+
+    #[test]
+    fn should_return_valid_result_for_unique_recipients() {
+        let identity_id = Identifier::random();
+        let recipients = vec![
+            IdentityCreditTransferRecipient::new(Identifier::random(), 1000),
+            IdentityCreditTransferRecipient::new(Identifier::random(), 2000),
+        ];
+
+        let result = validate_identity_credit_transfer_recipients(&identity_id, &recipients);
+
+        assert!(result.is_valid());
+    }
+
+    #[test]
+    fn should_return_invalid_result_if_recipient_is_duplicated() {
+        let identity_id = Identifier::random();
+        let recipient_id = Identifier::random();
+        let recipients = vec![
+            IdentityCreditTransferRecipient::new(recipient_id, 1000),
+            IdentityCreditTransferRecipient::new(recipient_id, 2000),
+        ];
+
+        let result = validate_identity_credit_transfer_recipients(&identity_id, &recipients);
+
+        assert!(matches!(
+            result.errors.as_slice(),
+            [ConsensusError::BasicError(
+                BasicError::DuplicatedIdentityCreditTransferRecipientError(e)
+            )] if e.recipient_id() == recipient_id
+        ));
+    }
+
+    #[test]
+    fn should_return_invalid_result_if_sender_is_a_recipient() {
+        let identity_id = Identifier::random();
+        let recipients = vec![IdentityCreditTransferRecipient::new(identity_id, 1000)];
+
+        let result = validate_identity_credit_transfer_recipients(&identity_id, &recipients);
+
+        assert!(matches!(
+            result.errors.as_slice(),
+            [ConsensusError::BasicError(
+                BasicError::IdentityCreditTransferToSelfError(e)
+            )] if e.identity_id() == identity_id
+        ));
+    }
+}
@@ -8,7 +8,6 @@ use crate::consensus::state::identity::IdentityInsufficientBalanceError;
 
 use crate::identity::state_transition::identity_credit_transfer_transition::action::IdentityCreditTransferTransitionAction;
 use crate::identity::state_transition::identity_credit_transfer_transition::IdentityCreditTransferTransition;
-use crate::identity::state_transition::identity_credit_withdrawal_transition::IdentityCreditWithdrawalTransitionAction;
 use crate::state_transition::state_transition_execution_context::StateTransitionExecutionContext;
 use crate::validation::ConsensusValidationResult;
 use crate::{state_repository::StateRepositoryLike, NonConsensusError, ProtocolError};
@@ -61,7 +60,13 @@ where
             return Ok(result);
         };
 
-        if existing_identity.get_balance() < state_transition.amount {
+        let Some(total_amount) = state_transition.get_total_amount() else {
+            return Err(ProtocolError::Overflow(
+                "credit transfer total amount overflow",
+            ));
+        };
+
+        if existing_identity.get_balance() < total_amount {
             let err = IdentityInsufficientBalanceError {
                 identity_id: state_transition.identity_id,
                 balance: existing_identity.balance,
@@ -72,34 +77,30 @@ where
             return Ok(result);
         }
 
-        let maybe_existing_recipient = self
-            .state_repository
-            .fetch_identity(&state_transition.recipient_id, Some(execution_context))
-            .await?
-            .map(TryInto::try_into)
-            .transpose()
-            .map_err(Into::into)
-            .map_err(|e| {
-                NonConsensusError::StateRepositoryFetchError(format!(
-                    "state repository fetch identity for transfer verification error: {}",
-                    e
-                ))
-            })?;
-
-        if maybe_existing_recipient.is_none() {
-            let err = IdentityNotFoundError::new(state_transition.recipient_id);
-
-            result.add_error(err);
-
-            return Ok(result);
-        };
-
-        Ok(IdentityCreditTransferTransitionAction {
-            version: IdentityCreditWithdrawalTransitionAction::current_version(),
-            identity_id: state_transition.identity_id,
-            transfer_amount: state_transition.amount,
-            recipient_id: state_transition.recipient_id,
+        for recipient in state_transition.get_recipients() {
+            let maybe_existing_recipient = self
+                .state_repository
+                .fetch_identity(&recipient.recipient_id, Some(execution_context))
+                .await?
+                .map(TryInto::try_into)
+                .transpose()
+                .map_err(Into::into)
+                .map_err(|e| {
+                    NonConsensusError::StateRepositoryFetchError(format!(
+                        "state repository fetch identity for transfer verification error: {}",
+                        e
+                    ))
+                })?;
+
+            if maybe_existing_recipient.is_none() {
+                let err = IdentityNotFoundError::new(recipient.recipient_id);
+
+                result.add_error(err);
+
+                return Ok(result);
+            };
         }
-        .into())
+
+        Ok(IdentityCreditTransferTransitionAction::from(state_transition).into())
     }
 }
@@ -1,4 +1,4 @@
-use crate::identity::{IdentityPublicKey, KeyID, KeyType, Purpose, SecurityLevel};
+use crate::identity::{IdentityPublicKey, KeyID, KeyType, Purpose, SecurityLevel, TimestampMillis};
 #[cfg(feature = "cbor")]
 use ciborium::value::Value as CborValue;
 
@@ -40,6 +40,9 @@ pub struct IdentityPublicKeyInCreation {
     pub security_level: SecurityLevel,
     pub read_only: bool,
     pub data: BinaryData,
+    /// The key can not be used to sign state transitions from this time on
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub valid_until: Option<TimestampMillis>,
     /// The signature is needed for ECDSA_SECP256K1 Key type and BLS12_381 Key type
     #[platform_signable(exclude_from_sig_hash)]
     pub signature: BinaryData,
@@ -86,6 +89,7 @@ impl IdentityPublicKeyInCreation {
             key_type,
             data,
             read_only,
+            valid_until,
             ..
         } = self;
         IdentityPublicKey {
@@ -96,6 +100,7 @@ impl IdentityPublicKeyInCreation {
             data,
             read_only,
             disabled_at: None,
+            valid_until,
         }
     }
 
@@ -124,7 +129,7 @@ impl IdentityPublicKeyInCreation {
     ) -> Result<Self, ProtocolError> {
         let mut public_key_with_witness: IdentityPublicKeyInCreation = public_key.clone().into();
         match public_key.key_type {
-            KeyType::ECDSA_SECP256K1 | KeyType::BLS12_381 => {
+            KeyType::ECDSA_SECP256K1 | KeyType::BLS12_381 | KeyType::ECDSA_SECP256K1_MULTISIG => {
                 public_key_with_witness.signature =
                     signer.sign(&public_key, state_transition_bytes)?;
             }
@@ -159,6 +164,9 @@ impl IdentityPublicKeyInCreation {
             read_only: value_map
                 .get_bool("readOnly")
                 .map_err(ProtocolError::ValueError)?,
+            valid_until: value_map
+                .get_optional_integer("validUntil")
+                .map_err(ProtocolError::ValueError)?,
             signature: value_map
                 .remove_binary_data("signature")
                 .map_err(ProtocolError::ValueError)?,
@@ -263,6 +271,7 @@ impl IdentityPublicKeyInCreation {
         let public_key_bytes =
             key_value_map.as_bytes("data", "Identity public key must have a data")?;
         let signature_bytes = key_value_map.as_bytes("signature", "").unwrap_or_default();
+        let valid_until = key_value_map.as_u64("validUntil", "").ok();
 
         Ok(Self {
             id: id.into(),
@@ -271,6 +280,7 @@ impl IdentityPublicKeyInCreation {
             key_type: key_type.try_into()?,
             data: BinaryData::from(public_key_bytes),
             read_only: readonly,
+            valid_until,
             signature: BinaryData::from(signature_bytes),
         })
     }
@@ -285,6 +295,9 @@ impl IdentityPublicKeyInCreation {
         pk_map.insert("purpose", self.purpose);
         pk_map.insert("readOnly", self.read_only);
         pk_map.insert("securityLevel", self.security_level);
+        if let Some(ts) = self.valid_until {
+            pk_map.insert("validUntil", ts)
+        }
 
         if !self.signature.is_empty() {
             pk_map.insert("signature", self.signature.as_slice())
@@ -304,6 +317,7 @@ impl From<&IdentityPublicKeyInCreation> for IdentityPublicKey {
             read_only: val.read_only,
             data: val.data.clone(),
             disabled_at: None,
+            valid_until: val.valid_until,
         }
     }
 }
@@ -317,6 +331,7 @@ impl From<IdentityPublicKey> for IdentityPublicKeyInCreation {
             key_type: val.key_type,
             read_only: val.read_only,
             data: val.data,
+            valid_until: val.valid_until,
             signature: Default::default(),
         }
     }
@@ -14,6 +14,8 @@ pub struct IdentityUpdateTransitionAction {
     pub add_public_keys: Vec<IdentityPublicKey>,
     pub disable_public_keys: Vec<KeyID>,
     pub public_keys_disabled_at: Option<TimestampMillis>,
+    pub expire_public_keys: Vec<KeyID>,
+    pub public_keys_valid_until: Option<TimestampMillis>,
     pub identity_id: Identifier,
     pub revision: Revision,
 }
@@ -25,6 +27,8 @@ impl From<IdentityUpdateTransition> for IdentityUpdateTransitionAction {
             add_public_keys,
             disable_public_keys,
             public_keys_disabled_at,
+            expire_public_keys,
+            public_keys_valid_until,
             revision,
             ..
         } = value;
@@ -36,6 +40,8 @@ impl From<IdentityUpdateTransition> for IdentityUpdateTransitionAction {
                 .collect(),
             disable_public_keys,
             public_keys_disabled_at,
+            expire_public_keys,
+            public_keys_valid_until,
             identity_id,
             revision,
         }
@@ -49,6 +55,8 @@ impl From<&IdentityUpdateTransition> for IdentityUpdateTransitionAction {
             add_public_keys,
             disable_public_keys,
             public_keys_disabled_at,
+            expire_public_keys,
+            public_keys_valid_until,
             revision,
             ..
         } = value;
@@ -60,6 +68,8 @@ impl From<&IdentityUpdateTransition> for IdentityUpdateTransitionAction {
                 .collect(),
             disable_public_keys: disable_public_keys.clone(),
             public_keys_disabled_at: *public_keys_disabled_at,
+            expire_public_keys: expire_public_keys.clone(),
+            public_keys_valid_until: *public_keys_valid_until,
             identity_id: *identity_id,
             revision: *revision,
         }
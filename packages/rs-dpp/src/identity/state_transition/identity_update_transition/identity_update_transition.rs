@@ -39,6 +39,8 @@ pub mod property_names {
     pub const ADD_PUBLIC_KEYS: &str = "addPublicKeys";
     pub const DISABLE_PUBLIC_KEYS: &str = "disablePublicKeys";
     pub const PUBLIC_KEYS_DISABLED_AT: &str = "publicKeysDisabledAt";
+    pub const EXPIRE_PUBLIC_KEYS: &str = "expirePublicKeys";
+    pub const PUBLIC_KEYS_VALID_UNTIL: &str = "publicKeysValidUntil";
     pub const SIGNATURE: &str = "signature";
     pub const SIGNATURE_PUBLIC_KEY_ID: &str = "signaturePublicKeyId";
 }
@@ -88,6 +90,14 @@ pub struct IdentityUpdateTransition {
     /// Timestamp when keys were disabled
     pub public_keys_disabled_at: Option<TimestampMillis>,
 
+    /// Identity Public Keys ID's to schedule the expiration of
+    #[serde(default)]
+    pub expire_public_keys: Vec<KeyID>,
+
+    /// Timestamp from which the keys to expire can no longer be used
+    #[serde(default)]
+    pub public_keys_valid_until: Option<TimestampMillis>,
+
     /// The ID of the public key used to sing the State Transition
     #[platform_signable(exclude_from_sig_hash)]
     pub signature_public_key_id: KeyID,
@@ -108,6 +118,8 @@ impl Default for IdentityUpdateTransition {
             add_public_keys: Default::default(),
             disable_public_keys: Default::default(),
             public_keys_disabled_at: Default::default(),
+            expire_public_keys: Default::default(),
+            public_keys_valid_until: Default::default(),
         }
     }
 }
@@ -140,6 +152,8 @@ impl IdentityUpdateTransition {
             add_public_keys: add_public_keys_in_creation,
             disable_public_keys,
             public_keys_disabled_at,
+            expire_public_keys: Default::default(),
+            public_keys_valid_until: Default::default(),
         };
 
         let key_signable_bytes = identity_update_transition.signable_bytes()?;
@@ -206,6 +220,11 @@ impl IdentityUpdateTransition {
         let public_keys_disabled_at = raw_object
             .remove_optional_integer(property_names::PUBLIC_KEYS_DISABLED_AT)
             .map_err(ProtocolError::ValueError)?;
+        let expire_public_keys =
+            remove_integer_list_or_default(&mut raw_object, property_names::EXPIRE_PUBLIC_KEYS)?;
+        let public_keys_valid_until = raw_object
+            .remove_optional_integer(property_names::PUBLIC_KEYS_VALID_UNTIL)
+            .map_err(ProtocolError::ValueError)?;
 
         Ok(IdentityUpdateTransition {
             protocol_version,
@@ -216,6 +235,8 @@ impl IdentityUpdateTransition {
             add_public_keys,
             disable_public_keys,
             public_keys_disabled_at,
+            expire_public_keys,
+            public_keys_valid_until,
             transition_type: StateTransitionType::IdentityUpdate,
         })
     }
@@ -272,6 +293,25 @@ impl IdentityUpdateTransition {
         self.public_keys_disabled_at
     }
 
+    pub fn set_public_key_ids_to_expire(&mut self, expire_public_keys: Vec<KeyID>) {
+        self.expire_public_keys = expire_public_keys;
+    }
+
+    pub fn get_public_key_ids_to_expire(&self) -> &[KeyID] {
+        &self.expire_public_keys
+    }
+
+    pub fn set_public_keys_valid_until(
+        &mut self,
+        public_keys_valid_until: Option<TimestampMillis>,
+    ) {
+        self.public_keys_valid_until = public_keys_valid_until;
+    }
+
+    pub fn get_public_keys_valid_until(&self) -> Option<TimestampMillis> {
+        self.public_keys_valid_until
+    }
+
     pub fn set_protocol_version(&mut self, protocol_version: u32) {
         self.protocol_version = protocol_version;
     }
@@ -400,6 +440,10 @@ impl StateTransitionConvert for IdentityUpdateTransition {
 
         value.remove_optional_value_if_null(property_names::PUBLIC_KEYS_DISABLED_AT)?;
 
+        value.remove_optional_value_if_empty_array(property_names::EXPIRE_PUBLIC_KEYS)?;
+
+        value.remove_optional_value_if_null(property_names::PUBLIC_KEYS_VALID_UNTIL)?;
+
         Ok(value)
     }
 
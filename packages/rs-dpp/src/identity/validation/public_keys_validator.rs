@@ -7,6 +7,7 @@ use crate::errors::consensus::basic::identity::{
     DuplicatedIdentityPublicKeyBasicError, DuplicatedIdentityPublicKeyIdBasicError,
     InvalidIdentityPublicKeyDataError, InvalidIdentityPublicKeySecurityLevelError,
 };
+use crate::identity::multisig::MultisigPublicKeyData;
 use crate::identity::{IdentityPublicKey, KeyID, KeyType};
 use crate::validation::{JsonSchemaValidator, SimpleConsensusValidationResult};
 use crate::{
@@ -108,6 +109,16 @@ impl<T: BlsModule> TPublicKeysValidator for PublicKeysValidator<T> {
                 KeyType::BIP13_SCRIPT_HASH => None,
                 // Do nothing
                 KeyType::EDDSA_25519_HASH160 => None,
+                KeyType::ECDSA_SECP256K1_MULTISIG => {
+                    match MultisigPublicKeyData::from_bytes(public_key.data.as_slice()) {
+                        Ok(multisig_public_key) => multisig_public_key
+                            .public_keys
+                            .iter()
+                            .find_map(|key_bytes| PublicKey::from_slice(key_bytes).err())
+                            .map(|e| PublicKeyValidationError::new(e.to_string())),
+                        Err(e) => Some(PublicKeyValidationError::new(e.to_string())),
+                    }
+                }
             };
 
             if let Some(error) = validation_error {
@@ -64,6 +64,7 @@ pub mod prelude {
     pub use super::convertible::Convertible;
     pub type TimestampMillis = u64;
     pub type Revision = u64;
+    pub type IdentityNonce = u64;
 }
 
 pub use bincode;
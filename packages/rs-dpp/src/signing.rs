@@ -1,6 +1,7 @@
 use crate::consensus::signature::{
     BasicBLSError, BasicECDSAError, SignatureError, SignatureShouldNotBePresentError,
 };
+use crate::identity::multisig::{sign_with_concatenated_private_keys, MultisigPublicKeyData};
 use crate::identity::KeyType;
 use crate::serialization_traits::PlatformMessageSignable;
 use crate::state_transition::errors::InvalidIdentityPublicKeyTypeError;
@@ -96,6 +97,17 @@ impl PlatformMessageSignable for &[u8] {
                     Ok(SimpleConsensusValidationResult::default())
                 }
             }
+            KeyType::ECDSA_SECP256K1_MULTISIG => {
+                if let Err(e) = MultisigPublicKeyData::from_bytes(public_key_data)
+                    .and_then(|public_key| public_key.verify_signature(signable_data, signature))
+                {
+                    Ok(SimpleConsensusValidationResult::new_with_error(
+                        SignatureError::BasicECDSAError(BasicECDSAError::new(e.to_string())).into(),
+                    ))
+                } else {
+                    Ok(SimpleConsensusValidationResult::default())
+                }
+            }
         }
     }
 
@@ -114,6 +126,10 @@ impl PlatformMessageSignable for &[u8] {
                 Ok(signature.to_vec())
             }
 
+            KeyType::ECDSA_SECP256K1_MULTISIG => {
+                sign_with_concatenated_private_keys(self, private_key)
+            }
+
             // the default behavior from
             // https://github.com/dashevo/platform/blob/6b02b26e5cd3a7c877c5fdfe40c4a4385a8dda15/packages/js-dpp/lib/stateTransition/AbstractStateTransition.js#L187
             // is to return the error for the BIP13_SCRIPT_HASH
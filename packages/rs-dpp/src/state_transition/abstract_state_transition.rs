@@ -10,6 +10,7 @@ use crate::consensus::signature::InvalidStateTransitionSignatureError;
 use crate::consensus::signature::SignatureError;
 use crate::consensus::ConsensusError;
 
+use crate::identity::multisig::{sign_with_concatenated_private_keys, MultisigPublicKeyData};
 use crate::serialization_traits::{PlatformSerializable, Signable};
 use crate::state_transition::errors::{
     InvalidIdentityPublicKeyTypeError, StateTransitionIsNotSignedError,
@@ -76,6 +77,11 @@ pub trait StateTransitionLike:
                 self.set_signature(signature.to_vec().into());
             }
 
+            KeyType::ECDSA_SECP256K1_MULTISIG => {
+                let signature = sign_with_concatenated_private_keys(&data, private_key)?;
+                self.set_signature(signature.into());
+            }
+
             // the default behavior from
             // https://github.com/dashevo/platform/blob/6b02b26e5cd3a7c877c5fdfe40c4a4385a8dda15/packages/js-dpp/lib/stateTransition/AbstractStateTransition.js#L187
             // is to return the error for the BIP13_SCRIPT_HASH
@@ -100,6 +106,9 @@ pub trait StateTransitionLike:
                 self.verify_ecdsa_hash_160_signature_by_public_key_hash(public_key)
             }
             KeyType::BLS12_381 => self.verify_bls_signature_by_public_key(public_key, bls),
+            KeyType::ECDSA_SECP256K1_MULTISIG => {
+                self.verify_ecdsa_multisig_signature_by_public_key(public_key)
+            }
             KeyType::BIP13_SCRIPT_HASH | KeyType::EDDSA_25519_HASH160 => {
                 Err(ProtocolError::InvalidIdentityPublicKeyTypeError(
                     InvalidIdentityPublicKeyTypeError::new(public_key_type),
@@ -151,6 +160,32 @@ pub trait StateTransitionLike:
         )
     }
 
+    /// Verifies an m-of-n ECDSA signature with the multisig public key data
+    fn verify_ecdsa_multisig_signature_by_public_key(
+        &self,
+        public_key: &[u8],
+    ) -> Result<(), ProtocolError> {
+        if self.get_signature().is_empty() {
+            return Err(ProtocolError::StateTransitionIsNotSignedError(
+                StateTransitionIsNotSignedError::new(self.clone().into()),
+            ));
+        }
+        let data = self.signable_bytes()?;
+
+        MultisigPublicKeyData::from_bytes(public_key)
+            .and_then(|public_key| {
+                public_key.verify_signature(&data, self.get_signature().as_slice())
+            })
+            .map_err(|_| {
+                // TODO: it shouldn't respond with consensus error
+                ProtocolError::from(ConsensusError::SignatureError(
+                    SignatureError::InvalidStateTransitionSignatureError(
+                        InvalidStateTransitionSignatureError::new(),
+                    ),
+                ))
+            })
+    }
+
     /// Verifies a BLS signature with the public key
     fn verify_bls_signature_by_public_key<T: BlsModule>(
         &self,
@@ -3,6 +3,7 @@ use dashcore::secp256k1::{PublicKey as RawPublicKey, SecretKey as RawSecretKey};
 
 use crate::consensus::signature::InvalidSignaturePublicKeySecurityLevelError;
 use crate::data_contract::state_transition::errors::PublicKeyIsDisabledError;
+use crate::identity::multisig::MultisigPublicKeyData;
 use crate::identity::signer::Signer;
 use crate::state_transition::errors::{
     InvalidIdentityPublicKeyTypeError, InvalidSignaturePublicKeyError, PublicKeyMismatchError,
@@ -85,6 +86,24 @@ where
                 }
                 self.sign_by_private_key(private_key, identity_public_key.key_type, bls)
             }
+            KeyType::ECDSA_SECP256K1_MULTISIG => {
+                // the private key is a concatenation of co-signer private keys,
+                // each of them has to belong to the multisig key
+                let multisig_public_key =
+                    MultisigPublicKeyData::from_bytes(identity_public_key.data.as_slice())?;
+                for (key_index, co_signer_private_key) in private_key.chunks(32).enumerate() {
+                    let public_key_compressed =
+                        get_compressed_public_ec_key(co_signer_private_key)?;
+                    if multisig_public_key.public_keys.get(key_index)
+                        != Some(&public_key_compressed)
+                    {
+                        return Err(ProtocolError::InvalidSignaturePublicKeyError(
+                            InvalidSignaturePublicKeyError::new(identity_public_key.data.to_vec()),
+                        ));
+                    }
+                }
+                self.sign_by_private_key(private_key, identity_public_key.key_type, bls)
+            }
 
             // the default behavior from
             // https://github.com/dashevo/platform/blob/6b02b26e5cd3a7c877c5fdfe40c4a4385a8dda15/packages/js-dpp/lib/stateTransition/AbstractStateTransitionIdentitySigned.js#L108
@@ -132,6 +151,10 @@ where
 
             KeyType::BLS12_381 => self.verify_bls_signature_by_public_key(public_key_bytes, bls),
 
+            KeyType::ECDSA_SECP256K1_MULTISIG => {
+                self.verify_ecdsa_multisig_signature_by_public_key(public_key_bytes)
+            }
+
             // per https://github.com/dashevo/platform/pull/353, signing and verification is not supported
             KeyType::BIP13_SCRIPT_HASH | KeyType::EDDSA_25519_HASH160 => Ok(()),
         }
@@ -363,6 +386,7 @@ mod test {
             data: BinaryData::new(ec_public_compressed_bytes.try_into().unwrap()),
             read_only: false,
             disabled_at: None,
+            valid_until: None,
         };
 
         Keys {
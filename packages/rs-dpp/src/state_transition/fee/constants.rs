@@ -18,5 +18,6 @@ pub const fn signature_verify_cost(key_type: KeyType) -> Credits {
         KeyType::ECDSA_HASH160 => 4000,
         KeyType::BIP13_SCRIPT_HASH => 6000,
         KeyType::EDDSA_25519_HASH160 => 3000,
+        KeyType::ECDSA_SECP256K1_MULTISIG => 9000,
     }
 }
@@ -104,6 +104,8 @@ mod tests {
             add_public_keys: add_public_keys_in_creation,
             disable_public_keys: vec![],
             public_keys_disabled_at: None,
+            expire_public_keys: vec![],
+            public_keys_valid_until: None,
         };
 
         let key_signable_bytes = identity_update_transition
@@ -164,6 +166,8 @@ mod tests {
             add_public_keys: add_public_keys_in_creation,
             disable_public_keys: vec![3, 4, 5],
             public_keys_disabled_at: Some(15),
+            expire_public_keys: vec![],
+            public_keys_valid_until: None,
         };
 
         let key_signable_bytes = identity_update_transition
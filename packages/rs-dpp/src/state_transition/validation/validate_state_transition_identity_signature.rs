@@ -28,6 +28,7 @@ lazy_static! {
         keys.insert(KeyType::ECDSA_SECP256K1);
         keys.insert(KeyType::BLS12_381);
         keys.insert(KeyType::ECDSA_HASH160);
+        keys.insert(KeyType::ECDSA_SECP256K1_MULTISIG);
         keys
     };
 }
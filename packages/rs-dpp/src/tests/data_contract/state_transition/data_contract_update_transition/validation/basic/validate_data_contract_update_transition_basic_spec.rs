@@ -252,7 +252,7 @@ async fn should_be_not_less_than_n_bytes(property_name: &str, n_bytes: usize) {
     assert_eq!("minItems", schema_error.keyword());
 }
 
-#[test_case(property_names::SIGNATURE, 96)]
+#[test_case(property_names::SIGNATURE, 1056)]
 #[tokio::test]
 async fn should_be_not_longer_than_n_bytes(property_name: &str, n_bytes: usize) {
     let TestData {
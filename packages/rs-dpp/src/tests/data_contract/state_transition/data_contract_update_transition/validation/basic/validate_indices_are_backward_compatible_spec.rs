@@ -90,6 +90,7 @@ fn should_return_invalid_result_if_some_of_unique_indices_have_changed() {
     let result = validate_indices_are_backward_compatible(
         old_documents_schema.iter(),
         new_documents_schema.iter(),
+        true,
     )
     .expect("validation result should be returned");
 
@@ -123,6 +124,7 @@ fn should_return_invalid_result_if_non_unique_index_update_failed_due_to_changed
     let result = validate_indices_are_backward_compatible(
         old_documents_schema.iter(),
         new_documents_schema.iter(),
+        true,
     )
     .expect("validation result should be returned");
 
@@ -157,6 +159,7 @@ fn should_return_invalid_result_if_already_indexed_properties_are_added_to_exist
     let result = validate_indices_are_backward_compatible(
         old_documents_schema.iter(),
         new_documents_schema.iter(),
+        true,
     )
     .expect("validation result should be returned");
 
@@ -177,7 +180,7 @@ fn should_return_invalid_result_if_already_indexed_properties_are_added_to_exist
 }
 
 #[test]
-fn should_return_invalid_result_if_one_of_new_indices_contains_old_properties_in_the_wrong_order() {
+fn should_return_valid_result_if_one_of_new_indices_contains_old_properties_in_another_order() {
     let TestData {
         old_documents_schema,
         mut new_documents_schema,
@@ -197,26 +200,11 @@ fn should_return_invalid_result_if_one_of_new_indices_contains_old_properties_in
     let result = validate_indices_are_backward_compatible(
         old_documents_schema.iter(),
         new_documents_schema.iter(),
+        true,
     )
     .expect("validation result should be returned");
 
-    assert_eq!(1, result.errors.len());
-    assert_eq!(1054, result.errors[0].code());
-
-    let basic_error = get_basic_error(&result, 0);
-
-    // the JS-version imports DataContractInvalidIndexDefinitionUpdateError as DataContractHaveNewIndexWithOldPropertiesError as
-    // we should decide if we want to have a separate error called DataContractInvalidIndexDefinitionUpdateError
-    match basic_error {
-        BasicError::DataContractInvalidIndexDefinitionUpdateError(err) => {
-            assert_eq!(err.document_type(), "indexedDocument".to_string());
-            assert_eq!(err.index_name(), "index_other".to_string());
-        }
-        _ => panic!(
-            "Expected DataContractInvalidIndexDefinitionUpdateError, got {}",
-            basic_error
-        ),
-    }
+    assert!(result.is_valid());
 }
 
 #[test]
@@ -239,6 +227,7 @@ fn should_return_invalid_result_if_one_of_new_indices_is_unique() {
     let result = validate_indices_are_backward_compatible(
         old_documents_schema.iter(),
         new_documents_schema.iter(),
+        true,
     )
     .expect("validation result should be returned");
 
@@ -270,6 +259,7 @@ fn should_return_valid_result_if_indices_are_not_changed() {
     let result = validate_indices_are_backward_compatible(
         old_documents_schema.iter(),
         new_documents_schema.iter(),
+        true,
     )
     .expect("validation result should be returned");
 
@@ -277,7 +267,7 @@ fn should_return_valid_result_if_indices_are_not_changed() {
 }
 
 #[test]
-fn should_return_invalid_result_if_non_unique_index_added_for_non_indexed_property() {
+fn should_return_valid_result_if_non_unique_index_added_for_non_indexed_property() {
     let TestData {
         mut old_documents_schema,
         mut new_documents_schema,
@@ -307,6 +297,88 @@ fn should_return_invalid_result_if_non_unique_index_added_for_non_indexed_proper
     let result = validate_indices_are_backward_compatible(
         old_documents_schema.iter(),
         new_documents_schema.iter(),
+        true,
+    )
+    .expect("validation result should be returned");
+
+    assert!(result.is_valid());
+}
+
+#[test]
+fn should_return_invalid_result_if_one_of_new_indices_contains_old_properties_in_the_wrong_order_without_backfill(
+) {
+    let TestData {
+        old_documents_schema,
+        mut new_documents_schema,
+        ..
+    } = setup_test();
+    new_documents_schema.get_mut("indexedDocument").unwrap()["indices"]
+        .push(json!({
+        "name": "index_other",
+        "properties": [
+          { "firstName": "asc" },
+          { "$ownerId": "asc" },
+        ],
+
+          }))
+        .expect("the new index should be added");
+
+    let result = validate_indices_are_backward_compatible(
+        old_documents_schema.iter(),
+        new_documents_schema.iter(),
+        false,
+    )
+    .expect("validation result should be returned");
+
+    assert_eq!(1, result.errors.len());
+    assert_eq!(1054, result.errors[0].code());
+
+    let basic_error = get_basic_error(&result, 0);
+
+    match basic_error {
+        BasicError::DataContractInvalidIndexDefinitionUpdateError(err) => {
+            assert_eq!(err.document_type(), "indexedDocument".to_string());
+            assert_eq!(err.index_name(), "index_other".to_string());
+        }
+        _ => panic!(
+            "Expected DataContractInvalidIndexDefinitionUpdateError, got {}",
+            basic_error
+        ),
+    }
+}
+
+#[test]
+fn should_return_invalid_result_if_non_unique_index_added_for_non_indexed_property_without_backfill(
+) {
+    let TestData {
+        mut old_documents_schema,
+        mut new_documents_schema,
+        ..
+    } = setup_test();
+
+    old_documents_schema.get_mut("indexedDocument").unwrap()["properties"]
+        ["oldUnindexedProperty"] = json!({
+        "type": "string",
+        "maxLength": "420",
+    });
+
+    new_documents_schema.get_mut("indexedDocument").unwrap()["indices"]
+        .push(json!(
+            {
+                "name": "index1337",
+                "properties": [
+                    {
+                        "oldUnindexedProperty": "asc",
+                    },
+                ],
+                "unique": false,
+            }
+        ))
+        .unwrap();
+    let result = validate_indices_are_backward_compatible(
+        old_documents_schema.iter(),
+        new_documents_schema.iter(),
+        false,
     )
     .expect("validation result should be returned");
 
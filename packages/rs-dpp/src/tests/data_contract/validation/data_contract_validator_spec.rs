@@ -1471,6 +1471,111 @@ mod byte_array {
     }
 }
 
+mod exact_numbers {
+    use super::*;
+
+    #[test]
+    fn decimal_scale_should_be_used_with_type_string() {
+        let TestData {
+            mut raw_data_contract,
+            data_contract_validator,
+            ..
+        } = setup_test();
+
+        raw_data_contract["documents"]["amounts"] = platform_value!({
+            "type": "object",
+            "properties": {
+              "amount": {
+                "type": "integer",
+                "decimalScale": 2,
+              },
+            },
+            "additionalProperties": false,
+        });
+
+        let result = data_contract_validator
+            .validate(&raw_data_contract)
+            .expect("validation result should be returned");
+        let schema_error = get_schema_error(&result, 0);
+
+        assert_eq!(
+            "/documents/amounts/properties/amount/type",
+            schema_error.instance_path().to_string()
+        );
+        assert_eq!("const", schema_error.keyword());
+    }
+
+    #[test]
+    fn decimal_scale_should_be_at_most_38() {
+        let TestData {
+            mut raw_data_contract,
+            data_contract_validator,
+            ..
+        } = setup_test();
+
+        raw_data_contract["documents"]["amounts"] = platform_value!({
+            "type": "object",
+            "properties": {
+              "amount": {
+                "type": "string",
+                "decimalScale": 39,
+              },
+            },
+            "additionalProperties": false,
+        });
+
+        let result = data_contract_validator
+            .validate(&raw_data_contract)
+            .expect("validation result should be returned");
+        let schema_error = get_schema_error(&result, 0);
+
+        assert_eq!(
+            "/documents/amounts/properties/amount/decimalScale",
+            schema_error.instance_path().to_string()
+        );
+        assert_eq!("maximum", schema_error.keyword());
+    }
+
+    #[test]
+    fn exact_number_properties_should_be_valid() {
+        let TestData {
+            mut raw_data_contract,
+            data_contract_validator,
+            ..
+        } = setup_test();
+
+        raw_data_contract["documents"]["amounts"] = platform_value!({
+            "type": "object",
+            "indices": [
+              {
+                "name": "amount",
+                "properties": [
+                  { "amount": "asc" },
+                ],
+              },
+            ],
+            "properties": {
+              "amount": {
+                "type": "string",
+                "decimalScale": 8,
+                "maxLength": 48,
+              },
+              "supply": {
+                "type": "string",
+                "bigInteger": true,
+              },
+            },
+            "additionalProperties": false,
+        });
+
+        let result = data_contract_validator
+            .validate(&raw_data_contract)
+            .expect("validation result should be returned");
+
+        assert!(result.is_valid());
+    }
+}
+
 mod identifier {
     use super::*;
 
@@ -2136,6 +2241,194 @@ mod indices {
         }
     }
 
+    #[test]
+    fn nested_object_property_should_be_indexable() {
+        let TestData {
+            mut raw_data_contract,
+            data_contract_validator,
+            ..
+        } = setup_test();
+
+        raw_data_contract["documents"]["indexedAddress"] = platform_value!({
+            "type": "object",
+            "indices": [
+              {
+                "name": "city",
+                "properties": [
+                  { "address.city": "asc" },
+                ],
+              },
+            ],
+            "properties": {
+              "address": {
+                "type": "object",
+                "properties": {
+                  "city": {
+                    "type": "string",
+                    "maxLength": 63,
+                  },
+                },
+                "additionalProperties": false,
+              },
+            },
+            "additionalProperties": false,
+        });
+
+        let result = data_contract_validator
+            .validate(&raw_data_contract)
+            .expect("validation result should be returned");
+
+        assert!(result.is_valid());
+    }
+
+    #[test]
+    fn nested_index_property_should_not_be_inside_array() {
+        let TestData {
+            mut raw_data_contract,
+            data_contract_validator,
+            ..
+        } = setup_test();
+
+        raw_data_contract["documents"]["indexedAddresses"] = platform_value!({
+            "type": "object",
+            "indices": [
+              {
+                "name": "city",
+                "properties": [
+                  { "addresses.city": "asc" },
+                ],
+              },
+            ],
+            "properties": {
+              "addresses": {
+                "type": "array",
+                "items": {
+                  "type": "object",
+                  "properties": {
+                    "city": {
+                      "type": "string",
+                      "maxLength": 63,
+                    },
+                  },
+                  "additionalProperties": false,
+                },
+                "maxItems": 5,
+              },
+            },
+            "additionalProperties": false,
+        });
+
+        let result = data_contract_validator
+            .validate(&raw_data_contract)
+            .expect("validation result should be returned");
+        let error = result.errors.get(0).expect("the error should be present");
+        let basic_error = get_basic_error(error);
+
+        assert_eq!(1013, basic_error.code());
+
+        match basic_error {
+            BasicError::InvalidIndexPropertyTypeError(err) => {
+                assert_eq!(err.document_type(), "indexedAddresses".to_string());
+                assert_eq!(err.property_name(), "addresses.city".to_string());
+                assert_eq!(err.property_type(), "array".to_string());
+            }
+            _ => panic!(
+                "Expected InvalidIndexPropertyTypeError, got {}",
+                basic_error
+            ),
+        }
+    }
+
+    #[test]
+    fn index_with_collation_should_be_valid() {
+        let TestData {
+            mut raw_data_contract,
+            data_contract_validator,
+            ..
+        } = setup_test();
+
+        raw_data_contract["documents"]["indexedUsername"] = platform_value!({
+            "type": "object",
+            "indices": [
+              {
+                "name": "username",
+                "properties": [
+                  { "username": "asc" },
+                ],
+                "unique": true,
+                "collation": "homoglyph",
+              },
+            ],
+            "properties": {
+              "username": {
+                "type": "string",
+                "maxLength": 63,
+              },
+            },
+            "additionalProperties": false,
+        });
+
+        let result = data_contract_validator
+            .validate(&raw_data_contract)
+            .expect("validation result should be returned");
+
+        assert!(result.is_valid());
+    }
+
+    #[test]
+    fn indices_of_a_property_should_use_the_same_collation() {
+        let TestData {
+            mut raw_data_contract,
+            data_contract_validator,
+            ..
+        } = setup_test();
+
+        raw_data_contract["documents"]["indexedUsername"] = platform_value!({
+            "type": "object",
+            "indices": [
+              {
+                "name": "username",
+                "properties": [
+                  { "username": "asc" },
+                ],
+                "collation": "caseInsensitive",
+              },
+              {
+                "name": "usernameOwner",
+                "properties": [
+                  { "username": "asc" },
+                  { "$ownerId": "asc" },
+                ],
+              },
+            ],
+            "properties": {
+              "username": {
+                "type": "string",
+                "maxLength": 63,
+              },
+            },
+            "additionalProperties": false,
+        });
+
+        let result = data_contract_validator
+            .validate(&raw_data_contract)
+            .expect("validation result should be returned");
+        let error = result.errors.get(0).expect("the error should be present");
+        let basic_error = get_basic_error(error);
+
+        match basic_error {
+            BasicError::InvalidIndexedPropertyConstraintError(err) => {
+                assert_eq!(err.index_name(), "usernameOwner".to_string());
+                assert_eq!(err.property_name(), "username".to_string());
+                assert_eq!(err.constraint_name(), "collation".to_string());
+            }
+            _ => panic!(
+                "Expected InvalidIndexedPropertyConstraintError, got {}",
+                basic_error
+            ),
+        }
+    }
+
     #[test]
     fn index_property_should_not_point_to_array() {
         let TestData {
@@ -2959,6 +3252,117 @@ mod indexed_array {
             ),
         }
     }
+
+    fn indexed_tags_document(unique: bool, items_max_length: Option<u64>) -> Value {
+        let mut items = platform_value!({ "type": "string" });
+        if let Some(max_length) = items_max_length {
+            items
+                .insert("maxLength".to_string(), platform_value!(max_length))
+                .expect("items should be a map");
+        }
+        platform_value!({
+            "type": "object",
+            "indices": [
+              {
+                "name": "index1",
+                "properties": [
+                  { "tags": "asc" },
+                ],
+                "unique": unique,
+              },
+            ],
+            "properties": {
+              "tags": {
+                "type": "array",
+                "items": items,
+                "maxItems": 10,
+              },
+            },
+            "additionalProperties": false,
+        })
+    }
+
+    #[test]
+    fn should_return_valid_result_if_indexed_array_property_has_scalar_items() {
+        let TestData {
+            mut raw_data_contract,
+            data_contract_validator,
+            ..
+        } = setup_test();
+
+        raw_data_contract["documents"]["indexedTags"] = indexed_tags_document(false, Some(20));
+
+        let result = data_contract_validator
+            .validate(&raw_data_contract)
+            .expect("validation result should be returned");
+
+        assert!(result.is_valid());
+    }
+
+    #[test]
+    fn should_return_invalid_result_if_indexed_array_property_is_in_unique_index() {
+        let TestData {
+            mut raw_data_contract,
+            data_contract_validator,
+            ..
+        } = setup_test();
+
+        raw_data_contract["documents"]["indexedTags"] = indexed_tags_document(true, Some(20));
+
+        let result = data_contract_validator
+            .validate(&raw_data_contract)
+            .expect("validation result should be returned");
+        let validation_error = result
+            .errors
+            .get(0)
+            .expect("the validation error should exist");
+        let index_error = get_basic_error(validation_error);
+
+        assert_eq!(1012, index_error.code());
+        match index_error {
+            BasicError::InvalidIndexedPropertyConstraintError(err) => {
+                assert_eq!(err.property_name(), "tags".to_string());
+                assert_eq!(err.constraint_name(), "type".to_string());
+            }
+            _ => panic!(
+                "Expected InvalidIndexedPropertyConstraintError, got {}",
+                index_error
+            ),
+        }
+    }
+
+    #[test]
+    fn should_return_invalid_result_if_indexed_string_array_items_have_no_max_length() {
+        let TestData {
+            mut raw_data_contract,
+            data_contract_validator,
+            ..
+        } = setup_test();
+
+        raw_data_contract["documents"]["indexedTags"] = indexed_tags_document(false, None);
+
+        let result = data_contract_validator
+            .validate(&raw_data_contract)
+            .expect("validation result should be returned");
+        let validation_error = result
+            .errors
+            .get(0)
+            .expect("the validation error should exist");
+        let index_error = get_basic_error(validation_error);
+
+        assert_eq!(1012, index_error.code());
+        match index_error {
+            BasicError::InvalidIndexedPropertyConstraintError(err) => {
+                assert_eq!(err.property_name(), "tags".to_string());
+                assert_eq!(err.constraint_name(), "items.maxLength".to_string());
+                assert_eq!(err.reason(), "should be less or equal than 63".to_string());
+            }
+            _ => panic!(
+                "Expected InvalidIndexedPropertyConstraintError, got {}",
+                index_error
+            ),
+        }
+    }
 }
 
 #[test]
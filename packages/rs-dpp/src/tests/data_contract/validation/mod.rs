@@ -1 +1,2 @@
 mod data_contract_validator_spec;
+mod value_schema_validator_spec;
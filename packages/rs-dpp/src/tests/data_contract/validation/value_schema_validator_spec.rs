@@ -0,0 +1,176 @@
+//! Documents were validated by the JSON schema validator before the value schema validator
+//! replaced it, so both must agree on the validity of any document of the system contracts and
+//! of the fixtures.
+
+use platform_value::Value;
+
+use crate::data_contract::document_type::random_document::CreateRandomDocument;
+use crate::system_data_contracts::{load_system_data_contract, SystemDataContract};
+use crate::tests::fixtures::{
+    get_contact_request_document_fixture, get_dashpay_contract_fixture, get_data_contract_fixture,
+    get_dpns_data_contract_fixture, get_dpns_parent_document_fixture,
+    get_extended_documents_fixture, get_masternode_reward_shares_documents_fixture,
+    ParentDocumentOptions,
+};
+use crate::validation::{JsonSchemaValidator, ValueSchemaValidator};
+use crate::{document::ExtendedDocument, prelude::*};
+
+/// Strings exercising the differences between regex engines and ECMA 262 character classes
+const SAMPLE_STRINGS: [&str; 14] = [
+    "",
+    "a",
+    "ab",
+    "Ab0",
+    "-ab",
+    "ab-",
+    "a--b",
+    "a_b",
+    "l1o0",
+    "\u{0661}\u{0662}",
+    "\u{00e9}t\u{00e9}",
+    "a b",
+    "a\nb",
+    "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+];
+
+struct Validators {
+    json: JsonSchemaValidator,
+    value: ValueSchemaValidator,
+}
+
+fn validators(data_contract: &DataContract, document_type: &str) -> Validators {
+    let schema = data_contract
+        .get_document_schema(document_type)
+        .expect("document type should exist");
+    match &data_contract.defs {
+        Some(defs) => Validators {
+            json: JsonSchemaValidator::new_with_definitions(schema.clone(), defs.iter())
+                .expect("schema should compile"),
+            value: ValueSchemaValidator::new_with_definitions(schema, defs.iter())
+                .expect("schema should compile"),
+        },
+        None => Validators {
+            json: JsonSchemaValidator::new(schema.clone()).expect("schema should compile"),
+            value: ValueSchemaValidator::new(schema).expect("schema should compile"),
+        },
+    }
+}
+
+fn assert_same_validity(validators: &Validators, document_type: &str, properties: &Value) {
+    let json = properties
+        .try_to_validating_json()
+        .expect("properties should convert to json");
+    let json_valid = validators
+        .json
+        .validate(&json)
+        .expect("json schema validation should run")
+        .is_valid();
+    let value_valid = validators.value.validate(properties).is_valid();
+    assert_eq!(
+        json_valid, value_valid,
+        "validators disagree on {document_type} properties {properties:?}"
+    );
+}
+
+/// The properties and variations of them: each property removed, replaced with values of other
+/// types and, for strings, replaced with each sample string
+fn variations(properties: &Value) -> Vec<Value> {
+    let map = properties.as_map().expect("properties should be a map");
+    let mut variations = vec![properties.clone()];
+    for (index, (_, property)) in map.iter().enumerate() {
+        let mut removed = map.clone();
+        removed.remove(index);
+        variations.push(Value::Map(removed));
+
+        let mut replacements = vec![
+            Value::Bool(true),
+            Value::U64(u64::MAX),
+            Value::I64(-1),
+            Value::Bytes(vec![1; 32]),
+        ];
+        if let Value::Text(_) = property {
+            replacements.extend(SAMPLE_STRINGS.iter().map(|s| Value::Text(s.to_string())));
+        }
+        for replacement in replacements {
+            let mut replaced = map.clone();
+            replaced[index].1 = replacement;
+            variations.push(Value::Map(replaced));
+        }
+    }
+    variations
+}
+
+fn assert_same_validity_of_variations(
+    data_contract: &DataContract,
+    document_type: &str,
+    properties: &Value,
+) {
+    let validators = validators(data_contract, document_type);
+    for variation in variations(properties) {
+        assert_same_validity(&validators, document_type, &variation);
+    }
+}
+
+fn assert_same_validity_of_extended_documents(documents: &[ExtendedDocument]) {
+    for document in documents {
+        assert_same_validity_of_variations(
+            &document.data_contract,
+            &document.document_type_name,
+            &Value::from(document.document.properties.clone()),
+        );
+    }
+}
+
+fn assert_same_validity_of_random_documents(data_contract: &DataContract) {
+    for (name, document_type) in &data_contract.document_types {
+        for document in document_type.random_documents(5, Some(3)) {
+            assert_same_validity_of_variations(
+                data_contract,
+                name,
+                &Value::from(document.properties),
+            );
+        }
+    }
+}
+
+#[test]
+fn should_agree_on_documents_of_system_contracts() {
+    for system_contract in [
+        SystemDataContract::Withdrawals,
+        SystemDataContract::MasternodeRewards,
+        SystemDataContract::FeatureFlags,
+        SystemDataContract::DPNS,
+        SystemDataContract::Dashpay,
+    ] {
+        let data_contract =
+            load_system_data_contract(system_contract).expect("system contract should load");
+        assert_same_validity_of_random_documents(&data_contract);
+    }
+}
+
+#[test]
+fn should_agree_on_documents_of_fixture_contracts() {
+    for data_contract in [
+        get_data_contract_fixture(None).data_contract,
+        get_dpns_data_contract_fixture(None).data_contract,
+        get_dashpay_contract_fixture(None).data_contract,
+    ] {
+        assert_same_validity_of_random_documents(&data_contract);
+    }
+}
+
+#[test]
+fn should_agree_on_document_fixtures() {
+    let data_contract = get_data_contract_fixture(None).data_contract;
+    assert_same_validity_of_extended_documents(
+        &get_extended_documents_fixture(data_contract).expect("documents should be created"),
+    );
+
+    assert_same_validity_of_extended_documents(&[
+        get_dpns_parent_document_fixture(ParentDocumentOptions::default()),
+        get_contact_request_document_fixture(None, None),
+    ]);
+
+    let (documents, _) = get_masternode_reward_shares_documents_fixture();
+    assert_same_validity_of_extended_documents(&documents);
+}
@@ -411,6 +411,7 @@ async fn transitions_should_have_an_object_as_elements() {
 
 #[test_case("$id")]
 #[test_case("$entropy")]
+#[test_case("$identityContractNonce")]
 #[tokio::test]
 async fn property_in_document_transition_should_be_present(property: &str) {
     let TestData {
@@ -875,7 +876,7 @@ async fn signature_should_be_not_less_than_65_bytes() {
 }
 
 #[tokio::test]
-async fn signature_should_be_not_longer_than_96_bytes() {
+async fn signature_should_be_not_longer_than_1056_bytes() {
     let TestData {
         mut raw_state_transition,
         protocol_version_validator,
@@ -883,7 +884,7 @@ async fn signature_should_be_not_longer_than_96_bytes() {
         ..
     } = setup_test(Action::Create);
 
-    let array = [0u8; 97].to_vec();
+    let array = [0u8; 1057].to_vec();
     raw_state_transition["signature"] = platform_value!(array);
 
     let validator = DocumentBatchTransitionBasicValidator::new(
@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::sync::Arc;
 
 use crate::document::fetch_and_validate_data_contract::DataContractFetcherAndValidator;
@@ -37,11 +37,14 @@ pub fn get_document_transitions_fixture(
         .unwrap_or_default();
 
     document_factory
-        .create_state_transition([
-            (Action::Create, create_documents),
-            (Action::Replace, replace_documents),
-            (Action::Delete, delete_documents),
-        ])
+        .create_state_transition(
+            [
+                (Action::Create, create_documents),
+                (Action::Replace, replace_documents),
+                (Action::Delete, delete_documents),
+            ],
+            &mut BTreeMap::new(),
+        )
         .expect("the transitions should be created")
         .get_transitions()
         .to_owned()
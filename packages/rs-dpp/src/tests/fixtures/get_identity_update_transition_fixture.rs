@@ -30,6 +30,7 @@ pub fn get_identity_update_transition_fixture() -> IdentityUpdateTransition {
             )
             .unwrap(),
             security_level: SecurityLevel::MASTER,
+            valid_until: None,
             signature: BinaryData::new(vec![0; 65]),
         }],
         disable_public_keys: vec![0],
@@ -184,6 +184,7 @@ mod api {
             security_level: SecurityLevel::MASTER,
             read_only: false,
             disabled_at: None,
+            valid_until: None,
         };
         let identity_public_key_2 = IdentityPublicKey {
             id: 50,
@@ -193,6 +194,7 @@ mod api {
             security_level: SecurityLevel::MASTER,
             read_only: false,
             disabled_at: None,
+            valid_until: None,
         };
 
         identity.add_public_keys([identity_public_key_1, identity_public_key_2]);
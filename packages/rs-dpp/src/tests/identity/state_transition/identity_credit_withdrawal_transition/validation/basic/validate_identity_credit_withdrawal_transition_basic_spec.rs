@@ -593,11 +593,11 @@ mod validate_identity_credit_withdrawal_transition_basic_factory {
         }
 
         #[tokio::test]
-        pub async fn should_be_not_longer_than_65_bytes() {
+        pub async fn should_be_not_longer_than_1056_bytes() {
             let (mut raw_state_transition, validator) = setup_test();
 
             raw_state_transition
-                .set_into_value("signature", vec![0; 66])
+                .set_into_value("signature", vec![0; 1057])
                 .unwrap();
 
             let result = validator.validate(&raw_state_transition).await.unwrap();
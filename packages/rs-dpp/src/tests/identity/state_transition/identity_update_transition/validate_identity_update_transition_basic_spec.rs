@@ -76,6 +76,7 @@ fn setup_test() -> TestData {
         data: BinaryData::new(ec_public_key.try_into().unwrap()),
         read_only: false,
         disabled_at: None,
+        valid_until: None,
     };
 
     state_transition
@@ -238,7 +239,7 @@ fn signature_should_be_not_less_than_n_bytes(property_name: &str, n_bytes: usize
 }
 
 #[test_case(property_names::IDENTITY_ID, 32)]
-#[test_case(property_names::SIGNATURE, 96)]
+#[test_case(property_names::SIGNATURE, 1056)]
 fn signature_should_be_not_longer_than_n_bytes(property_name: &str, n_bytes: usize) {
     let TestData {
         protocol_version_validator,
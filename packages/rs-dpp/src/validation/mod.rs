@@ -11,6 +11,7 @@ pub use validation_result::{
     ConsensusValidationResult, SimpleConsensusValidationResult, SimpleValidationResult,
     ValidationResult,
 };
+pub use value_schema_validator::ValueSchemaValidator;
 
 use crate::{
     state_transition::state_transition_execution_context::StateTransitionExecutionContext,
@@ -21,6 +22,7 @@ pub mod byte_array_meta;
 mod json_schema_validator;
 mod meta_validators;
 mod validation_result;
+mod value_schema_validator;
 
 /// Validator validates data of given type
 pub trait DataValidator {
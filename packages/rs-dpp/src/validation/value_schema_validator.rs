@@ -0,0 +1,41 @@
+use platform_value::schema::ValueSchema;
+use platform_value::Value;
+use serde_json::Value as JsonValue;
+
+use crate::consensus::ConsensusError;
+use crate::validation::SimpleConsensusValidationResult;
+
+/// Validates platform values against a JSON schema compiled once, without converting them to
+/// JSON. Errors are the same consensus errors as the ones of [`super::JsonSchemaValidator`].
+#[derive(Debug, Clone)]
+pub struct ValueSchemaValidator {
+    schema: ValueSchema,
+}
+
+impl ValueSchemaValidator {
+    pub fn new(schema_json: &JsonValue) -> Result<Self, platform_value::Error> {
+        Ok(Self {
+            schema: ValueSchema::compile(schema_json)?,
+        })
+    }
+
+    /// creates a new value schema validator from the json schema and allows to add the definitions
+    pub fn new_with_definitions<'a>(
+        schema_json: &JsonValue,
+        definitions: impl IntoIterator<Item = (&'a String, &'a JsonValue)>,
+    ) -> Result<Self, platform_value::Error> {
+        Ok(Self {
+            schema: ValueSchema::compile_with_definitions(schema_json, definitions)?,
+        })
+    }
+
+    pub fn validate(&self, value: &Value) -> SimpleConsensusValidationResult {
+        let mut validation_result = SimpleConsensusValidationResult::default();
+
+        if let Err(errors) = self.schema.validate(value) {
+            validation_result.add_errors(errors.into_iter().map(ConsensusError::from).collect());
+        }
+
+        validation_result
+    }
+}
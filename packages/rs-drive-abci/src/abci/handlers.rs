@@ -47,13 +47,12 @@ use drive::fee::credits::SignedCredits;
 use serde_json::{json, Value};
 use tenderdash_abci::proto::abci::response_verify_vote_extension::VerifyStatus;
 use tenderdash_abci::proto::abci::tx_record::TxAction;
-use tenderdash_abci::proto::abci::{self as proto, ExtendVoteExtension, ResponseException};
+use tenderdash_abci::proto::abci::{self as proto, ResponseException};
 use tenderdash_abci::proto::abci::{
     ExecTxResult, RequestCheckTx, RequestFinalizeBlock, RequestInitChain, RequestPrepareProposal,
     RequestProcessProposal, RequestQuery, ResponseCheckTx, ResponseFinalizeBlock,
     ResponseInitChain, ResponsePrepareProposal, ResponseProcessProposal, ResponseQuery, TxRecord,
 };
-use tenderdash_abci::proto::types::VoteExtensionType;
 
 use super::AbciError;
 
@@ -403,15 +402,8 @@ where
             )))
             .into())
         } else {
-            // we only want to sign the hash of the transaction
-            let extensions = block_execution_context
-                .withdrawal_transactions
-                .keys()
-                .map(|tx_id| ExtendVoteExtension {
-                    r#type: VoteExtensionType::ThresholdRecover as i32,
-                    extension: tx_id.to_vec(),
-                })
-                .collect();
+            // we only want to sign the hash of the transaction and the block attestation
+            let extensions = block_execution_context.vote_extensions()?;
             Ok(proto::ResponseExtendVote {
                 vote_extensions: extensions,
             })
@@ -457,15 +449,7 @@ where
         }
 
         let got: withdrawal_txs::v0::WithdrawalTxs = vote_extensions.into();
-        let expected = block_execution_context
-            .withdrawal_transactions
-            .keys()
-            .map(|tx_id| ExtendVoteExtension {
-                r#type: VoteExtensionType::ThresholdRecover as i32,
-                extension: tx_id.to_vec(),
-            })
-            .collect::<Vec<_>>()
-            .into();
+        let expected = block_execution_context.vote_extensions()?.into();
 
         // let state = self.platform.state.read().unwrap();
         //
@@ -526,6 +510,8 @@ where
             ),
         ))?;
 
+        let height = request.height as u64;
+
         let block_finalization_outcome = self
             .platform
             .finalize_block_proposal(request.try_into()?, transaction)?;
@@ -548,6 +534,16 @@ where
 
         self.commit_transaction()?;
 
+        // The block is already committed, failing to checkpoint it must not stop the chain
+        if let Err(error) = self.platform.create_checkpoint_if_needed(height) {
+            tracing::error!(
+                method = "finalize_block",
+                height,
+                ?error,
+                "failed to create a checkpoint"
+            );
+        }
+
         Ok(ResponseFinalizeBlock {
             events: vec![],
             retain_height: 0,
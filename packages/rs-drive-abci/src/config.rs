@@ -168,6 +168,23 @@ pub struct PlatformConfig {
     /// Path to data storage
     pub db_path: PathBuf,
 
+    /// Path to checkpoints of the data storage, `checkpoints` next to `db_path` if not set
+    #[serde(default)]
+    pub checkpoints_path: Option<PathBuf>,
+
+    /// Create a checkpoint of the data storage every given number of blocks, 0 to disable
+    #[serde(default)]
+    pub checkpoint_interval: u64,
+
+    /// How many of the latest checkpoints to keep
+    #[serde(default = "PlatformConfig::default_checkpoints_to_keep")]
+    pub checkpoints_to_keep: usize,
+
+    /// Validate the structure and the signatures of the state transitions of a block on all
+    /// cores before applying them in order
+    #[serde(default = "PlatformConfig::default_parallel_state_transition_validation")]
+    pub parallel_state_transition_validation: bool,
+
     // todo: put this in tests like #[cfg(test)]
     /// This should be None, except in the case of Testing platform
     #[serde(skip)]
@@ -186,6 +203,14 @@ impl PlatformConfig {
         1
     }
 
+    fn default_checkpoints_to_keep() -> usize {
+        5
+    }
+
+    fn default_parallel_state_transition_validation() -> bool {
+        true
+    }
+
     /// Return type of quorum
     pub fn quorum_type(&self) -> QuorumType {
         let found = if let Ok(t) = self.quorum_type.trim().parse::<u32>() {
@@ -236,6 +261,11 @@ impl Default for PlatformConfig {
             abci: Default::default(),
             core: Default::default(),
             db_path: PathBuf::from("/var/lib/dash-platform/data"),
+            checkpoints_path: None,
+            checkpoint_interval: 0,
+            checkpoints_to_keep: PlatformConfig::default_checkpoints_to_keep(),
+            parallel_state_transition_validation:
+                PlatformConfig::default_parallel_state_transition_validation(),
             testing_configs: PlatformTestConfig::default(),
             initial_protocol_version: 1,
         }
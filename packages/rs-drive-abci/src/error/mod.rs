@@ -47,6 +47,9 @@ pub enum Error {
     /// Error from metrics subsystem
     #[error("metrics: {0}")]
     Metrics(#[from] crate::metrics::Error),
+    /// Error from offline maintenance
+    #[error("maintenance: {0}")]
+    Maintenance(#[from] crate::maintenance::Error),
 }
 
 impl From<Error> for ResponseException {
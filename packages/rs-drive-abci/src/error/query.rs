@@ -32,6 +32,10 @@ pub enum QueryError {
     /// Decoding error Error
     #[error("protobuf decoding error: {0}")]
     ProtobufDecode(#[from] DecodeError),
+
+    /// Not found Error
+    #[error("not found error: {0}")]
+    NotFound(String),
 }
 
 impl From<QueryError> for ResponseException {
@@ -36,9 +36,14 @@ where
             state: &state_read_guard,
             config: &self.config,
             core_rpc: &self.core_rpc,
+            check_tx: false,
         };
-        let state_transition_execution_event =
-            process_state_transition(&platform_ref, state_transition, Some(transaction))?;
+        let state_transition_execution_event = process_state_transition(
+            &platform_ref,
+            state_transition,
+            block_info.time_ms,
+            Some(transaction),
+        )?;
 
         if state_transition_execution_event.is_valid() {
             let execution_event = state_transition_execution_event.into_data()?;
@@ -77,8 +82,13 @@ where
             state: &state_read_guard,
             config: &self.config,
             core_rpc: &self.core_rpc,
+            check_tx: true,
         };
-        let execution_event = process_state_transition(&platform_ref, state_transition, None)?;
+        // The state transition is executed in a later block, keys expiring before are rejected
+        // when it is
+        let block_time_ms = state_read_guard.last_block_time_ms().unwrap_or_default();
+        let execution_event =
+            process_state_transition(&platform_ref, state_transition, block_time_ms, None)?;
 
         // We should run the execution event in dry run to see if we would have enough fees for the transaction
 
@@ -99,7 +109,10 @@ where
 #[cfg(test)]
 mod tests {
     use crate::config::PlatformConfig;
-    use crate::execution::types::execution_result::ExecutionResult::SuccessfulPaidExecution;
+    use crate::execution::types::execution_result::ExecutionResult::{
+        ConsensusExecutionError, SuccessfulPaidExecution,
+    };
+    use crate::execution::validation::state_transition::common::validate_identity_nonce::v0::MAX_PENDING_NONCES;
     use crate::test::helpers::setup::TestPlatformBuilder;
     use dpp::block::block_info::BlockInfo;
     use dpp::consensus::basic::BasicError;
@@ -109,12 +122,20 @@ mod tests {
     use dpp::dashcore::secp256k1::Secp256k1;
     use dpp::dashcore::{signer, KeyPair};
     use dpp::data_contracts::dpns_contract;
+    use dpp::document::document_transition::document_base_transition::DocumentBaseTransition;
+    use dpp::document::document_transition::{Action, DocumentCreateTransition};
+    use dpp::document::generate_document_id::generate_document_id;
+    use dpp::document::DocumentsBatchTransition;
+    use dpp::identity::multisig::{sign_with_concatenated_private_keys, MultisigPublicKeyData};
+    use dpp::identity::state_transition::identity_credit_transfer_transition::IdentityCreditTransferTransition;
     use dpp::identity::state_transition::identity_public_key_transitions::IdentityPublicKeyInCreation;
     use dpp::identity::state_transition::identity_update_transition::identity_update_transition::IdentityUpdateTransition;
     use dpp::identity::{Identity, KeyType, Purpose, SecurityLevel};
+    use dpp::platform_value::Value;
     use dpp::prelude::{Identifier, IdentityPublicKey};
     use dpp::serialization_traits::{PlatformSerializable, Signable};
     use dpp::state_transition::{StateTransition, StateTransitionType};
+    use dpp::system_data_contracts::{load_system_data_contract, SystemDataContract};
     use dpp::version::LATEST_VERSION;
     use rand::rngs::StdRng;
     use rand::SeedableRng;
@@ -460,6 +481,7 @@ mod tests {
             key_type: KeyType::ECDSA_SECP256K1,
             read_only: false,
             data: new_key_pair.public_key().serialize().to_vec().into(),
+            valid_until: None,
             signature: Default::default(),
         };
 
@@ -480,6 +502,8 @@ mod tests {
             add_public_keys: vec![new_key],
             disable_public_keys: vec![],
             public_keys_disabled_at: None,
+            expire_public_keys: vec![],
+            public_keys_valid_until: None,
             signature_public_key_id: 1,
             signature: Default::default(),
         };
@@ -506,4 +530,602 @@ mod tests {
 
         validation_result.errors.first().expect("expected an error");
     }
+
+    #[test]
+    fn identity_credit_transfer_with_non_master_key_check_tx() {
+        let mut config = PlatformConfig::default();
+
+        let mut rng = StdRng::seed_from_u64(1);
+
+        let secp = Secp256k1::new();
+
+        let master_key_pair = KeyPair::new(&secp, &mut rng);
+
+        config.abci.keys.dpns_master_public_key = master_key_pair.public_key().serialize().to_vec();
+
+        let high_key_pair = KeyPair::new(&secp, &mut rng);
+
+        let high_secret_key = high_key_pair.secret_key();
+
+        config.abci.keys.dpns_second_public_key = high_key_pair.public_key().serialize().to_vec();
+
+        let platform = TestPlatformBuilder::new()
+            .with_config(config)
+            .build_with_mock_rpc();
+
+        let genesis_time = 0;
+
+        platform
+            .create_genesis_state_v0(genesis_time, platform.config.abci.keys.clone().into(), None)
+            .expect("expected to create genesis state");
+
+        let mut transfer_transition = IdentityCreditTransferTransition {
+            identity_id: dpns_contract::OWNER_ID_BYTES.into(),
+            nonce: 1,
+            signature_public_key_id: 1,
+            ..Default::default()
+        };
+
+        transfer_transition.add_recipient(Identifier::random_with_rng(&mut rng), 1000);
+
+        let signature = signer::sign(
+            &transfer_transition
+                .signable_bytes()
+                .expect("expected signable bytes"),
+            &high_secret_key.secret_bytes(),
+        )
+        .expect("expected to sign");
+
+        transfer_transition.signature = signature.to_vec().into();
+
+        let transition: StateTransition = transfer_transition.into();
+
+        let transfer_transition_bytes = transition.serialize().expect("expected to serialize");
+
+        let validation_result = platform
+            .check_tx_v0(transfer_transition_bytes.as_slice())
+            .expect("expected to check identity credit transfer tx");
+
+        // Only master keys can sign a credit transfer
+        assert!(matches!(
+            validation_result.errors.as_slice(),
+            [ConsensusError::SignatureError(
+                SignatureError::InvalidSignaturePublicKeySecurityLevelError(_)
+            )]
+        ));
+    }
+
+    #[test]
+    fn identity_credit_transfers_with_consecutive_nonces_can_be_pending_check_tx() {
+        let mut config = PlatformConfig::default();
+
+        let mut rng = StdRng::seed_from_u64(1);
+
+        let secp = Secp256k1::new();
+
+        let master_key_pair = KeyPair::new(&secp, &mut rng);
+
+        let master_secret_key = master_key_pair.secret_key();
+
+        config.abci.keys.dpns_master_public_key = master_key_pair.public_key().serialize().to_vec();
+
+        let high_key_pair = KeyPair::new(&secp, &mut rng);
+
+        config.abci.keys.dpns_second_public_key = high_key_pair.public_key().serialize().to_vec();
+
+        let platform = TestPlatformBuilder::new()
+            .with_config(config)
+            .build_with_mock_rpc();
+
+        let genesis_time = 0;
+
+        platform
+            .create_genesis_state_v0(genesis_time, platform.config.abci.keys.clone().into(), None)
+            .expect("expected to create genesis state");
+
+        platform
+            .drive
+            .add_to_identity_balance(
+                dpns_contract::OWNER_ID_BYTES,
+                100_000_000_000,
+                &BlockInfo::default(),
+                true,
+                None,
+            )
+            .expect("expected to add balance to the dpns owner");
+
+        let recipient = Identity::random_identity(3, Some(14));
+        let recipient_id = recipient.id;
+
+        platform
+            .drive
+            .add_new_identity(recipient, &BlockInfo::default(), true, None)
+            .expect("expected to add the recipient");
+
+        let transfer_transition_bytes = |nonce| {
+            let mut transfer_transition = IdentityCreditTransferTransition {
+                identity_id: dpns_contract::OWNER_ID_BYTES.into(),
+                nonce,
+                signature_public_key_id: 0,
+                ..Default::default()
+            };
+
+            transfer_transition.add_recipient(recipient_id, 1000);
+
+            let signature = signer::sign(
+                &transfer_transition
+                    .signable_bytes()
+                    .expect("expected signable bytes"),
+                &master_secret_key.secret_bytes(),
+            )
+            .expect("expected to sign");
+
+            transfer_transition.signature = signature.to_vec().into();
+
+            StateTransition::from(transfer_transition)
+                .serialize()
+                .expect("expected to serialize")
+        };
+
+        let is_invalid_nonce_error = |error: &ConsensusError| {
+            matches!(
+                error,
+                ConsensusError::StateError(StateError::InvalidIdentityNonceError(_))
+            )
+        };
+
+        // Both transfers are pending, neither of them is executed yet
+        for nonce in [1, 2] {
+            let validation_result = platform
+                .check_tx_v0(transfer_transition_bytes(nonce).as_slice())
+                .expect("expected to check identity credit transfer tx");
+
+            assert!(validation_result.errors.is_empty());
+        }
+
+        let validation_result = platform
+            .check_tx_v0(transfer_transition_bytes(MAX_PENDING_NONCES + 1).as_slice())
+            .expect("expected to check identity credit transfer tx");
+
+        assert!(validation_result.errors.iter().any(is_invalid_nonce_error));
+
+        // In a block the transfers have to be executed in order
+        let transaction = platform.drive.grove.start_transaction();
+
+        let execution_result = platform
+            .execute_tx(
+                transfer_transition_bytes(2),
+                &BlockInfo::default(),
+                &transaction,
+            )
+            .expect("expected to execute identity credit transfer tx");
+
+        match execution_result {
+            ConsensusExecutionError(validation_result) => {
+                assert!(validation_result.errors.iter().any(is_invalid_nonce_error))
+            }
+            _ => panic!("expected the nonce to be rejected in a block"),
+        }
+
+        for nonce in [1, 2] {
+            let execution_result = platform
+                .execute_tx(
+                    transfer_transition_bytes(nonce),
+                    &BlockInfo::default(),
+                    &transaction,
+                )
+                .expect("expected to execute identity credit transfer tx");
+
+            assert!(matches!(execution_result, SuccessfulPaidExecution(..)));
+        }
+    }
+
+    #[test]
+    fn keys_are_checked_to_not_be_expired_at_the_time_of_the_executing_block() {
+        let mut config = PlatformConfig::default();
+
+        let mut rng = StdRng::seed_from_u64(1);
+
+        let secp = Secp256k1::new();
+
+        let master_key_pair = KeyPair::new(&secp, &mut rng);
+
+        let master_secret_key = master_key_pair.secret_key();
+
+        config.abci.keys.dpns_master_public_key = master_key_pair.public_key().serialize().to_vec();
+
+        let high_key_pair = KeyPair::new(&secp, &mut rng);
+
+        config.abci.keys.dpns_second_public_key = high_key_pair.public_key().serialize().to_vec();
+
+        let platform = TestPlatformBuilder::new()
+            .with_config(config)
+            .build_with_mock_rpc();
+
+        let genesis_time = 0;
+
+        platform
+            .create_genesis_state_v0(genesis_time, platform.config.abci.keys.clone().into(), None)
+            .expect("expected to create genesis state");
+
+        platform
+            .drive
+            .set_identity_keys_valid_until(
+                dpns_contract::OWNER_ID_BYTES,
+                vec![0],
+                5000,
+                &BlockInfo::default(),
+                true,
+                None,
+            )
+            .expect("expected to schedule the expiration of the master key");
+
+        let mut transfer_transition = IdentityCreditTransferTransition {
+            identity_id: dpns_contract::OWNER_ID_BYTES.into(),
+            nonce: 1,
+            signature_public_key_id: 0,
+            ..Default::default()
+        };
+
+        transfer_transition.add_recipient(Identifier::random_with_rng(&mut rng), 1000);
+
+        let signature = signer::sign(
+            &transfer_transition
+                .signable_bytes()
+                .expect("expected signable bytes"),
+            &master_secret_key.secret_bytes(),
+        )
+        .expect("expected to sign");
+
+        transfer_transition.signature = signature.to_vec().into();
+
+        let transition: StateTransition = transfer_transition.into();
+
+        let transfer_transition_bytes = transition.serialize().expect("expected to serialize");
+
+        let is_expired_key_error = |error: &ConsensusError| {
+            matches!(
+                error,
+                ConsensusError::SignatureError(SignatureError::PublicKeyIsExpiredError(_))
+            )
+        };
+
+        // There is no block yet
+        let validation_result = platform
+            .check_tx_v0(transfer_transition_bytes.as_slice())
+            .expect("expected to check identity credit transfer tx");
+
+        assert!(!validation_result.errors.iter().any(is_expired_key_error));
+
+        let transaction = platform.drive.grove.start_transaction();
+
+        let execution_result = platform
+            .execute_tx(
+                transfer_transition_bytes,
+                &BlockInfo {
+                    time_ms: 5000,
+                    ..Default::default()
+                },
+                &transaction,
+            )
+            .expect("expected to execute identity credit transfer tx");
+
+        match execution_result {
+            ConsensusExecutionError(validation_result) => {
+                assert!(validation_result.errors.iter().any(is_expired_key_error))
+            }
+            _ => panic!("expected the key to be expired at the time of the block"),
+        }
+    }
+
+    #[test]
+    fn identity_update_signature_verifications_are_cached_until_key_is_disabled() {
+        let mut config = PlatformConfig::default();
+
+        let mut rng = StdRng::seed_from_u64(1);
+
+        let secp = Secp256k1::new();
+
+        let master_key_pair = KeyPair::new(&secp, &mut rng);
+
+        let master_secret_key = master_key_pair.secret_key();
+
+        config.abci.keys.dpns_master_public_key = master_key_pair.public_key().serialize().to_vec();
+
+        let high_key_pair = KeyPair::new(&secp, &mut rng);
+
+        config.abci.keys.dpns_second_public_key = high_key_pair.public_key().serialize().to_vec();
+
+        let platform = TestPlatformBuilder::new()
+            .with_config(config)
+            .build_with_mock_rpc();
+
+        let genesis_time = 0;
+
+        platform
+            .create_genesis_state_v0(genesis_time, platform.config.abci.keys.clone().into(), None)
+            .expect("expected to create genesis state");
+
+        let new_key_pair = KeyPair::new(&secp, &mut rng);
+
+        let mut new_key = IdentityPublicKeyInCreation {
+            id: 2,
+            purpose: Purpose::AUTHENTICATION,
+            security_level: SecurityLevel::HIGH,
+            key_type: KeyType::ECDSA_SECP256K1,
+            read_only: false,
+            data: new_key_pair.public_key().serialize().to_vec().into(),
+            valid_until: None,
+            signature: Default::default(),
+        };
+
+        let signable_bytes = new_key
+            .signable_bytes()
+            .expect("expected to get signable bytes");
+        let secret = new_key_pair.secret_key();
+        let signature =
+            signer::sign(&signable_bytes, &secret.secret_bytes()).expect("expected to sign");
+
+        new_key.signature = signature.to_vec().into();
+
+        let mut update_transition = IdentityUpdateTransition {
+            protocol_version: LATEST_VERSION,
+            transition_type: StateTransitionType::IdentityUpdate,
+            identity_id: dpns_contract::OWNER_ID_BYTES.into(),
+            revision: 1,
+            add_public_keys: vec![new_key],
+            disable_public_keys: vec![],
+            public_keys_disabled_at: None,
+            expire_public_keys: vec![],
+            public_keys_valid_until: None,
+            signature_public_key_id: 0,
+            signature: Default::default(),
+        };
+
+        let signature = signer::sign(
+            &update_transition
+                .signable_bytes()
+                .expect("expected signable bytes"),
+            &master_secret_key.secret_bytes(),
+        )
+        .expect("expected to sign");
+
+        update_transition.signature = signature.to_vec().into();
+
+        let transition: StateTransition = update_transition.into();
+
+        let update_transition_bytes = transition.serialize().expect("expected to serialize");
+
+        for _ in 0..2 {
+            let validation_result = platform
+                .check_tx_v0(update_transition_bytes.as_slice())
+                .expect("expected to check identity update tx");
+
+            assert!(!validation_result
+                .errors
+                .iter()
+                .any(|error| matches!(error, ConsensusError::SignatureError(_))));
+
+            // The signatures of the new key and of the master key are verified once
+            assert_eq!(
+                platform
+                    .drive
+                    .cache
+                    .read()
+                    .unwrap()
+                    .signature_verifications
+                    .len(),
+                2
+            );
+        }
+
+        platform
+            .drive
+            .disable_identity_keys(
+                dpns_contract::OWNER_ID_BYTES,
+                vec![0],
+                genesis_time,
+                &BlockInfo::default(),
+                true,
+                None,
+            )
+            .expect("expected to disable the master key");
+
+        assert_eq!(
+            platform
+                .drive
+                .cache
+                .read()
+                .unwrap()
+                .signature_verifications
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn documents_batch_and_identity_update_signed_with_multisig_key_check_tx() {
+        let mut config = PlatformConfig::default();
+
+        let mut rng = StdRng::seed_from_u64(1);
+
+        let secp = Secp256k1::new();
+
+        let master_key_pair = KeyPair::new(&secp, &mut rng);
+
+        config.abci.keys.dpns_master_public_key = master_key_pair.public_key().serialize().to_vec();
+
+        let high_key_pair = KeyPair::new(&secp, &mut rng);
+
+        config.abci.keys.dpns_second_public_key = high_key_pair.public_key().serialize().to_vec();
+
+        let platform = TestPlatformBuilder::new()
+            .with_config(config)
+            .build_with_mock_rpc();
+
+        let genesis_time = 0;
+
+        platform
+            .create_genesis_state_v0(genesis_time, platform.config.abci.keys.clone().into(), None)
+            .expect("expected to create genesis state");
+
+        platform
+            .drive
+            .add_to_identity_balance(
+                dpns_contract::OWNER_ID_BYTES,
+                100_000_000_000,
+                &BlockInfo::default(),
+                true,
+                None,
+            )
+            .expect("expected to add balance to the dpns owner");
+
+        // a 2 of 3 multisig key, the transitions are signed by the first two co-signers
+        let co_signer_key_pairs: Vec<KeyPair> =
+            (0..3).map(|_| KeyPair::new(&secp, &mut rng)).collect();
+
+        let multisig_key_data = MultisigPublicKeyData::new(
+            2,
+            co_signer_key_pairs
+                .iter()
+                .map(|key_pair| key_pair.public_key().serialize())
+                .collect(),
+        )
+        .expect("expected a valid multisig key")
+        .to_bytes();
+
+        let co_signer_private_keys: Vec<u8> = co_signer_key_pairs[0..2]
+            .iter()
+            .flat_map(|key_pair| key_pair.secret_key().secret_bytes())
+            .collect();
+
+        let multisig_keys = [(2, SecurityLevel::MASTER), (3, SecurityLevel::HIGH)]
+            .into_iter()
+            .map(|(id, security_level)| IdentityPublicKey {
+                id,
+                key_type: KeyType::ECDSA_SECP256K1_MULTISIG,
+                purpose: Purpose::AUTHENTICATION,
+                security_level,
+                read_only: false,
+                disabled_at: None,
+                valid_until: None,
+                data: multisig_key_data.clone().into(),
+            })
+            .collect();
+
+        platform
+            .drive
+            .add_new_non_unique_keys_to_identity(
+                dpns_contract::OWNER_ID_BYTES,
+                multisig_keys,
+                &BlockInfo::default(),
+                true,
+                None,
+            )
+            .expect("expected to add multisig keys to the dpns owner");
+
+        let data_contract = load_system_data_contract(SystemDataContract::DPNS)
+            .expect("expected to load the dpns contract");
+
+        let owner_id: Identifier = dpns_contract::OWNER_ID_BYTES.into();
+
+        let entropy = [7u8; 32];
+
+        let preorder_create_transition = DocumentCreateTransition {
+            base: DocumentBaseTransition {
+                id: generate_document_id(&data_contract.id, &owner_id, "preorder", &entropy),
+                document_type_name: "preorder".to_string(),
+                action: Action::Create,
+                data_contract_id: data_contract.id,
+                identity_contract_nonce: 1,
+                data_contract,
+            },
+            entropy,
+            created_at: None,
+            updated_at: None,
+            data: Some(BTreeMap::from([(
+                "saltedDomainHash".to_string(),
+                Value::Bytes(vec![1; 32]),
+            )])),
+        };
+
+        let mut documents_batch_transition = DocumentsBatchTransition {
+            protocol_version: LATEST_VERSION,
+            transition_type: StateTransitionType::DocumentsBatch,
+            owner_id,
+            transitions: vec![preorder_create_transition.into()],
+            signature_public_key_id: Some(3),
+            signature: None,
+        };
+
+        let signature = sign_with_concatenated_private_keys(
+            &documents_batch_transition
+                .signable_bytes()
+                .expect("expected signable bytes"),
+            &co_signer_private_keys,
+        )
+        .expect("expected to sign");
+
+        documents_batch_transition.signature = Some(signature.into());
+
+        let new_key_pair = KeyPair::new(&secp, &mut rng);
+
+        let mut new_key = IdentityPublicKeyInCreation {
+            id: 4,
+            purpose: Purpose::AUTHENTICATION,
+            security_level: SecurityLevel::HIGH,
+            key_type: KeyType::ECDSA_SECP256K1,
+            read_only: false,
+            data: new_key_pair.public_key().serialize().to_vec().into(),
+            valid_until: None,
+            signature: Default::default(),
+        };
+
+        let signature = signer::sign(
+            &new_key
+                .signable_bytes()
+                .expect("expected to get signable bytes"),
+            &new_key_pair.secret_key().secret_bytes(),
+        )
+        .expect("expected to sign");
+
+        new_key.signature = signature.to_vec().into();
+
+        let mut update_transition = IdentityUpdateTransition {
+            protocol_version: LATEST_VERSION,
+            transition_type: StateTransitionType::IdentityUpdate,
+            identity_id: owner_id,
+            revision: 1,
+            add_public_keys: vec![new_key],
+            disable_public_keys: vec![],
+            public_keys_disabled_at: None,
+            expire_public_keys: vec![],
+            public_keys_valid_until: None,
+            signature_public_key_id: 2,
+            signature: Default::default(),
+        };
+
+        let signature = sign_with_concatenated_private_keys(
+            &update_transition
+                .signable_bytes()
+                .expect("expected signable bytes"),
+            &co_signer_private_keys,
+        )
+        .expect("expected to sign");
+
+        update_transition.signature = signature.into();
+
+        for transition in [
+            StateTransition::from(documents_batch_transition),
+            StateTransition::from(update_transition),
+        ] {
+            let transition_bytes = transition.serialize().expect("expected to serialize");
+
+            let validation_result = platform
+                .check_tx_v0(transition_bytes.as_slice())
+                .expect("expected to check tx");
+
+            assert!(validation_result.errors.is_empty());
+        }
+    }
 }
@@ -18,6 +18,7 @@ use crate::error::execution::ExecutionError;
 use crate::error::Error;
 use crate::execution::types::block_execution_context;
 
+use crate::platform_types::block_attestation::v0::SignedBlockAttestation;
 use crate::platform_types::block_execution_outcome;
 use crate::platform_types::cleaned_abci_messages::cleaned_block::v0::CleanedBlock;
 use crate::platform_types::cleaned_abci_messages::finalized_block_cleaned_request::v0::FinalizeBlockCleanedRequest;
@@ -133,6 +134,24 @@ where
 
         let quorum_public_key = &state_cache.current_validator_set()?.threshold_public_key;
 
+        // The quorum signs the attestation of the block with a threshold vote extension, the
+        // recovered signature comes with the commit
+        let block_attestation = block_execution_context.block_attestation()?;
+        let block_attestation_bytes = block_attestation.to_bytes();
+        let signed_block_attestation = commit_info
+            .threshold_vote_extensions
+            .iter()
+            .find(|vote_extension| vote_extension.extension == block_attestation_bytes)
+            .and_then(|vote_extension| {
+                <[u8; 96]>::try_from(vote_extension.signature.as_slice()).ok()
+            })
+            .map(|signature| SignedBlockAttestation {
+                attestation: block_attestation,
+                quorum_hash: current_quorum_hash,
+                round,
+                signature,
+            });
+
         // In production this will always be true
         if self
             .config
@@ -155,6 +174,18 @@ where
             if !validation_result.is_valid() {
                 return Ok(validation_result.into());
             }
+
+            if let Some(signed_block_attestation) = &signed_block_attestation {
+                let validation_result = signed_block_attestation.verify_signature(
+                    &block_header.chain_id,
+                    quorum_type,
+                    quorum_public_key,
+                );
+
+                if !validation_result.is_valid() {
+                    return Ok(validation_result.into());
+                }
+            }
         }
         drop(state_cache);
 
@@ -192,6 +223,18 @@ where
         // // Finalize withdrawal processing
         // our_withdrawals.finalize(Some(transaction), &self.drive, &to_commit_block_info)?;
 
+        // Unsigned attestations are only received when block signing is disabled for tests
+        if let Some(signed_block_attestation) = signed_block_attestation {
+            signed_block_attestation.store(&self.drive, Some(transaction))?;
+        } else {
+            tracing::warn!(
+                method = "finalize_block_proposal_v0",
+                height,
+                round,
+                "commit has no signed vote extension for the block attestation, it is not stored"
+            );
+        }
+
         // At the end we update the state cache
 
         drop(guarded_block_execution_context);
@@ -211,7 +254,16 @@ where
 
         drive_cache.cached_contracts.clear_block_cache();
 
+        let data_contract_cache_stats = drive_cache.cached_contracts.stats();
+
+        drop(drive_cache);
+
+        // The most used contracts are preloaded in cache after a restart
+        self.drive
+            .store_warm_start_contract_ids(Some(transaction))?;
+
         // Gather some metrics
+        crate::metrics::data_contract_cache_stats(&data_contract_cache_stats);
         crate::metrics::abci_last_block_time(block_header.time.seconds as u64);
         crate::metrics::abci_last_platform_height(height);
         crate::metrics::abci_last_finalized_round(round);
@@ -4,6 +4,9 @@ use dashcore_rpc::dashcore::Txid;
 use dpp::block::epoch::Epoch;
 
 use dpp::validation::ValidationResult;
+use drive::drive::defaults::{
+    EXPIRING_DOCUMENTS_PROTOCOL_VERSION, STATE_TRANSITION_RESULTS_PROTOCOL_VERSION,
+};
 use drive::error::Error::GroveDB;
 
 use drive::grovedb::Transaction;
@@ -153,6 +156,10 @@ where
             .epoch_info
             .is_epoch_change_but_not_genesis()
         {
+            let previous_protocol_version = block_execution_context
+                .block_platform_state
+                .current_protocol_version_in_consensus;
+
             // Set current protocol version to the version from upcoming epoch
             block_execution_context
                 .block_platform_state
@@ -160,6 +167,15 @@ where
                 .block_platform_state
                 .next_epoch_protocol_version;
 
+            // Create the state structure of the new protocol version before it is used
+            self.drive.migrate_state_structure(
+                previous_protocol_version,
+                block_execution_context
+                    .block_platform_state
+                    .current_protocol_version_in_consensus,
+                Some(transaction),
+            )?;
+
             // Determine new protocol version based on votes for the next epoch
             let maybe_new_protocol_version = self.check_for_desired_protocol_upgrade(
                 block_execution_context.hpmn_count,
@@ -210,7 +226,7 @@ where
             })
             .collect();
 
-        let (block_fees, tx_results) = self.process_raw_state_transitions_v0(
+        let (mut block_fees, tx_results) = self.process_raw_state_transitions_v0(
             raw_state_transitions,
             &block_execution_context.block_platform_state,
             &block_info,
@@ -219,6 +235,27 @@ where
 
         self.pool_withdrawals_into_transactions_queue_v0(&block_execution_context, transaction)?;
 
+        // Documents that reached the end of their time to live are removed and refunded
+        if block_execution_context
+            .block_platform_state
+            .current_protocol_version_in_consensus
+            >= EXPIRING_DOCUMENTS_PROTOCOL_VERSION
+        {
+            let expired_documents_fee_result =
+                self.remove_expired_documents_v0(&block_info, transaction)?;
+            block_fees.checked_add_assign(expired_documents_fee_result)?;
+        }
+
+        // Results of state transitions are only kept for a retention window
+        if block_execution_context
+            .block_platform_state
+            .current_protocol_version_in_consensus
+            >= STATE_TRANSITION_RESULTS_PROTOCOL_VERSION
+        {
+            self.drive
+                .remove_expired_state_transition_results(block_info.height, Some(transaction))?;
+        }
+
         // while we have the state transitions executed, we now need to process the block fees
 
         // Process fees
@@ -0,0 +1,38 @@
+use crate::error::Error;
+use crate::execution::types::fee_estimate::v0::FeeEstimate;
+use crate::platform_types::platform::Platform;
+use crate::platform_types::platform_state::v0::PlatformState;
+use crate::rpc::core::CoreRPCLike;
+use dpp::prelude::ConsensusValidationResult;
+use dpp::state_transition::StateTransition;
+
+mod v0;
+
+impl<C> Platform<C>
+where
+    C: CoreRPCLike,
+{
+    /// Estimates the fees of a state transition before it is broadcast.
+    ///
+    /// The state transition is validated and executed in dry run against the last committed state,
+    /// the same way `check_tx` does. It does not need to be signed yet, in which case it is paid by
+    /// its owner identity.
+    ///
+    /// # Arguments
+    ///
+    /// * `state_transition` - The signed or unsigned state transition.
+    /// * `platform_state` - The state of the platform at the last committed block.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<ConsensusValidationResult<FeeEstimate>, Error>` - The estimated fees, or the
+    ///   consensus errors the state transition would be rejected with.
+    pub fn estimate_fee(
+        &self,
+        state_transition: StateTransition,
+        platform_state: &PlatformState,
+    ) -> Result<ConsensusValidationResult<FeeEstimate>, Error> {
+        //todo: use protocol version to determine version
+        self.estimate_fee_v0(state_transition, platform_state)
+    }
+}
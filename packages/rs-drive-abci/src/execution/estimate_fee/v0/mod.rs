@@ -0,0 +1,236 @@
+use crate::error::Error;
+use crate::execution::types::execution_event::ExecutionEvent;
+use crate::execution::types::fee_estimate::v0::FeeEstimate;
+use crate::execution::validation::state_transition::processor::process_state_transition_for_fee_estimation;
+use crate::platform_types::platform::{Platform, PlatformRef};
+use crate::platform_types::platform_state::v0::PlatformState;
+use crate::rpc::core::CoreRPCLike;
+use dpp::prelude::ConsensusValidationResult;
+use dpp::state_transition::StateTransition;
+use drive::fee::result::BalanceChange;
+
+impl<C> Platform<C>
+where
+    C: CoreRPCLike,
+{
+    pub(super) fn estimate_fee_v0(
+        &self,
+        state_transition: StateTransition,
+        platform_state: &PlatformState,
+    ) -> Result<ConsensusValidationResult<FeeEstimate>, Error> {
+        let platform_ref = PlatformRef {
+            drive: &self.drive,
+            state: platform_state,
+            config: &self.config,
+            core_rpc: &self.core_rpc,
+            check_tx: true,
+        };
+        // Like in check tx, the last committed block approximates the block the state transition
+        // will be executed in
+        let block_info = platform_state
+            .last_committed_block_info
+            .as_ref()
+            .map(|block_info| block_info.basic_info.clone())
+            .unwrap_or_default();
+
+        let execution_event = process_state_transition_for_fee_estimation(
+            &platform_ref,
+            state_transition,
+            block_info.time_ms,
+            None,
+        )?;
+
+        execution_event.and_then_borrowed_validation(|execution_event| {
+            // Nothing is written because there is no transaction and the operations are not applied
+            let fee_validation_result =
+                self.validate_fees_of_event_v0(execution_event, &block_info, None)?;
+
+            Ok(fee_validation_result.map(|fee_result| {
+                let balance_change = match execution_event {
+                    ExecutionEvent::PaidDriveEvent { identity, .. }
+                    | ExecutionEvent::PaidFromAssetLockDriveEvent { identity, .. } => fee_result
+                        .clone()
+                        .into_balance_change(identity.id.to_buffer())
+                        .change()
+                        .clone(),
+                    ExecutionEvent::FreeDriveEvent { .. } => BalanceChange::NoBalanceChange,
+                };
+                FeeEstimate {
+                    fee_result,
+                    balance_change,
+                }
+            }))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::PlatformConfig;
+    use crate::test::helpers::setup::TestPlatformBuilder;
+    use dpp::block::block_info::BlockInfo;
+    use dpp::consensus::signature::SignatureError;
+    use dpp::consensus::ConsensusError;
+    use dpp::dashcore::secp256k1::Secp256k1;
+    use dpp::dashcore::{signer, KeyPair};
+    use dpp::data_contracts::dpns_contract;
+    use dpp::identity::state_transition::identity_public_key_transitions::IdentityPublicKeyInCreation;
+    use dpp::identity::state_transition::identity_update_transition::identity_update_transition::IdentityUpdateTransition;
+    use dpp::identity::{KeyType, Purpose, SecurityLevel};
+    use dpp::serialization_traits::Signable;
+    use dpp::state_transition::StateTransitionType;
+    use dpp::version::LATEST_VERSION;
+    use drive::fee::result::BalanceChange;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn should_estimate_the_same_fees_for_unsigned_and_signed_state_transitions() {
+        let mut config = PlatformConfig::default();
+
+        let mut rng = StdRng::seed_from_u64(1);
+
+        let secp = Secp256k1::new();
+
+        let master_key_pair = KeyPair::new(&secp, &mut rng);
+
+        config.abci.keys.dpns_master_public_key = master_key_pair.public_key().serialize().to_vec();
+
+        config.abci.keys.dpns_second_public_key = KeyPair::new(&secp, &mut rng)
+            .public_key()
+            .serialize()
+            .to_vec();
+
+        let platform = TestPlatformBuilder::new()
+            .with_config(config)
+            .build_with_mock_rpc();
+
+        platform
+            .create_genesis_state_v0(0, platform.config.abci.keys.clone().into(), None)
+            .expect("expected to create genesis state");
+
+        platform
+            .drive
+            .add_to_identity_balance(
+                dpns_contract::OWNER_ID_BYTES,
+                100000000000,
+                &BlockInfo::default(),
+                true,
+                None,
+            )
+            .expect("expected to add to identity balance");
+
+        let new_key_pair = KeyPair::new(&secp, &mut rng);
+
+        let mut new_key = IdentityPublicKeyInCreation {
+            id: 2,
+            purpose: Purpose::AUTHENTICATION,
+            security_level: SecurityLevel::HIGH,
+            key_type: KeyType::ECDSA_SECP256K1,
+            read_only: false,
+            data: new_key_pair.public_key().serialize().to_vec().into(),
+            valid_until: None,
+            signature: Default::default(),
+        };
+
+        let signature = signer::sign(
+            &new_key
+                .signable_bytes()
+                .expect("expected to get signable bytes"),
+            &new_key_pair.secret_key().secret_bytes(),
+        )
+        .expect("expected to sign");
+
+        new_key.signature = signature.to_vec().into();
+
+        let mut update_transition = IdentityUpdateTransition {
+            protocol_version: LATEST_VERSION,
+            transition_type: StateTransitionType::IdentityUpdate,
+            identity_id: dpns_contract::OWNER_ID_BYTES.into(),
+            revision: 1,
+            add_public_keys: vec![new_key],
+            disable_public_keys: vec![],
+            public_keys_disabled_at: None,
+            expire_public_keys: vec![],
+            public_keys_valid_until: None,
+            signature_public_key_id: 0,
+            signature: Default::default(),
+        };
+
+        let state = platform.state.read().unwrap();
+
+        let unsigned_validation_result = platform
+            .estimate_fee(update_transition.clone().into(), &state)
+            .expect("expected to estimate fees");
+
+        assert!(unsigned_validation_result.is_valid());
+
+        let unsigned_estimate = unsigned_validation_result
+            .into_data()
+            .expect("expected an estimate");
+
+        assert!(unsigned_estimate.fee_result.processing_fee > 0);
+        assert!(matches!(
+            unsigned_estimate.balance_change,
+            BalanceChange::RemoveFromBalance { .. }
+        ));
+
+        let signature = signer::sign(
+            &update_transition
+                .signable_bytes()
+                .expect("expected signable bytes"),
+            &master_key_pair.secret_key().secret_bytes(),
+        )
+        .expect("expected to sign");
+
+        update_transition.signature = signature.to_vec().into();
+
+        let signed_validation_result = platform
+            .estimate_fee(update_transition.into(), &state)
+            .expect("expected to estimate fees");
+
+        assert!(signed_validation_result.is_valid());
+
+        let signed_estimate = signed_validation_result
+            .into_data()
+            .expect("expected an estimate");
+
+        assert_eq!(signed_estimate.fee_result, unsigned_estimate.fee_result);
+    }
+
+    #[test]
+    fn should_not_estimate_fees_of_unsigned_state_transitions_of_unknown_identities() {
+        let platform = TestPlatformBuilder::new()
+            .with_config(PlatformConfig::default())
+            .build_with_mock_rpc();
+
+        platform
+            .create_genesis_state_v0(0, platform.config.abci.keys.clone().into(), None)
+            .expect("expected to create genesis state");
+
+        let update_transition = IdentityUpdateTransition {
+            protocol_version: LATEST_VERSION,
+            transition_type: StateTransitionType::IdentityUpdate,
+            identity_id: [7; 32].into(),
+            revision: 1,
+            add_public_keys: vec![],
+            disable_public_keys: vec![2],
+            public_keys_disabled_at: Some(1),
+            expire_public_keys: vec![],
+            public_keys_valid_until: None,
+            signature_public_key_id: 0,
+            signature: Default::default(),
+        };
+
+        let state = platform.state.read().unwrap();
+
+        let validation_result = platform
+            .estimate_fee(update_transition.into(), &state)
+            .expect("expected to estimate fees");
+
+        assert!(matches!(
+            validation_result.errors.first().expect("expected an error"),
+            ConsensusError::SignatureError(SignatureError::IdentityNotFoundError(_))
+        ));
+    }
+}
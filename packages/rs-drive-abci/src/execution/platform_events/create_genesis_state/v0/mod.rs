@@ -71,6 +71,16 @@ impl<C> Platform<C> {
             .create_initial_state_structure(transaction)
             .map_err(Error::Drive)?;
 
+        // The structure introduced by later protocol versions is created right away when the
+        // chain starts with one of them
+        self.drive
+            .migrate_state_structure(
+                PROTOCOL_VERSION,
+                self.config.initial_protocol_version,
+                transaction,
+            )
+            .map_err(Error::Drive)?;
+
         let mut operations = vec![];
 
         // Create system identities and contracts
@@ -127,6 +137,7 @@ impl<C> Platform<C> {
                         read_only: false,
                         data: identity_public_keys_set.master.into(),
                         disabled_at: None,
+                        valid_until: None,
                     },
                 ),
                 (
@@ -139,6 +150,7 @@ impl<C> Platform<C> {
                         read_only: false,
                         data: identity_public_keys_set.high.into(),
                         disabled_at: None,
+                        valid_until: None,
                     },
                 ),
             ];
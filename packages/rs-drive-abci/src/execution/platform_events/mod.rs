@@ -16,6 +16,8 @@ pub(in crate::execution) mod get_genesis_time;
 pub(in crate::execution) mod identity_credit_withdrawal;
 /// Protocol upgrade events
 pub(in crate::execution) mod protocol_upgrade;
+/// Removal of documents that reached the end of their time to live
+pub(in crate::execution) mod remove_expired_documents;
 /// State transition processing
 pub(in crate::execution) mod state_transition_processing;
 /// Storage of the ephemeral state
@@ -0,0 +1 @@
+pub(in crate::execution) mod v0;
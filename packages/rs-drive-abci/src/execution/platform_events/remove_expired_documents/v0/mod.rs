@@ -0,0 +1,42 @@
+use crate::error::Error;
+use crate::platform_types::platform::Platform;
+use crate::rpc::core::CoreRPCLike;
+
+use dpp::block::block_info::BlockInfo;
+use drive::fee::result::FeeResult;
+use drive::grovedb::Transaction;
+
+impl<C> Platform<C>
+where
+    C: CoreRPCLike,
+{
+    /// Removes the documents that reached the end of their time to live.
+    ///
+    /// The storage of removed documents is refunded to their owners based on the epochs
+    /// in their storage flags.
+    ///
+    /// # Arguments
+    ///
+    /// * `block_info` - Information about the current block.
+    /// * `transaction` - A `Transaction` reference.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<FeeResult, Error>` - The refunds that need to be taken out of the epoch pools.
+    ///
+    pub(in crate::execution) fn remove_expired_documents_v0(
+        &self,
+        block_info: &BlockInfo,
+        transaction: &Transaction,
+    ) -> Result<FeeResult, Error> {
+        let removal_fee_result = self
+            .drive
+            .remove_expired_documents(block_info, Some(transaction))?;
+
+        // Nobody pays for the removal, only the refunds are relevant to the block fees
+        Ok(FeeResult {
+            fee_refunds: removal_fee_result.fee_refunds,
+            ..Default::default()
+        })
+    }
+}
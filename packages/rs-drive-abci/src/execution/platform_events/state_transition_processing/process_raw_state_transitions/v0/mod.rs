@@ -1,18 +1,106 @@
 use crate::error::Error;
 use crate::execution::types::execution_result::ExecutionResult::{
-    ConsensusExecutionError, SuccessfulPaidExecution,
+    ConsensusExecutionError, SuccessfulFreeExecution, SuccessfulPaidExecution,
+};
+use crate::execution::validation::state_transition::processor::{
+    process_state_transition, process_validated_state_transition,
+    validate_state_transition_structure_and_signatures,
 };
-use crate::execution::validation::state_transition::processor::process_state_transition;
 use crate::platform_types::platform::{Platform, PlatformRef};
 use crate::platform_types::platform_state;
 use crate::rpc::core::CoreRPCLike;
+use dashcore_rpc::dashcore::hashes::{sha256, Hash};
 use dpp::block::block_info::BlockInfo;
+use dpp::identity::PartialIdentity;
+use dpp::prelude::{ConsensusValidationResult, Identifier};
+use dpp::serialization_traits::PlatformDeserializable;
 use dpp::state_transition::StateTransition;
 use dpp::validation::SimpleConsensusValidationResult;
+use drive::drive::defaults::STATE_TRANSITION_RESULTS_PROTOCOL_VERSION;
+use drive::drive::state_transition_result::StateTransitionResult;
 use drive::fee::result::FeeResult;
 use drive::grovedb::Transaction;
+use std::collections::{BTreeMap, BTreeSet};
+use std::num::NonZeroUsize;
+use std::panic;
+use std::thread;
 use tenderdash_abci::proto::abci::ExecTxResult;
 
+/// The identities and contracts written by the state transitions applied so far in a block
+#[derive(Default)]
+struct BlockWrites {
+    identities: BTreeSet<Identifier>,
+    contracts: BTreeSet<Identifier>,
+}
+
+impl BlockWrites {
+    /// Whether the structure and signature validation of the state transition reads an identity
+    /// or a contract written in the block
+    fn are_read_by(&self, state_transition: &StateTransition) -> bool {
+        if self.identities.contains(state_transition.get_owner_id()) {
+            return true;
+        }
+
+        match state_transition {
+            StateTransition::DocumentsBatch(documents_batch) => documents_batch
+                .get_transitions()
+                .iter()
+                .any(|transition| self.contracts.contains(transition.get_data_contract_id())),
+            _ => false,
+        }
+    }
+
+    /// Adds what the state transition may write, whether it is valid or not
+    fn add(&mut self, state_transition: &StateTransition) {
+        // Paying the fees changes the balance of the owner
+        self.identities.insert(*state_transition.get_owner_id());
+
+        match state_transition {
+            StateTransition::DataContractCreate(data_contract_create) => {
+                self.contracts.insert(data_contract_create.data_contract.id);
+            }
+            StateTransition::DataContractUpdate(data_contract_update) => {
+                self.contracts.insert(data_contract_update.data_contract.id);
+            }
+            StateTransition::IdentityCreditTransfer(identity_credit_transfer) => {
+                self.identities.extend(
+                    identity_credit_transfer
+                        .recipients
+                        .iter()
+                        .map(|recipient| recipient.recipient_id),
+                );
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Maps the items on all the available cores, keeping their order
+fn parallel_map<T: Sync, R: Send>(items: &[T], f: impl Fn(&T) -> R + Sync) -> Vec<R> {
+    let threads = thread::available_parallelism()
+        .map(NonZeroUsize::get)
+        .unwrap_or(1);
+
+    if threads == 1 || items.len() <= 1 {
+        return items.iter().map(f).collect();
+    }
+
+    let chunk_size = items.len().div_ceil(threads);
+    let f = &f;
+
+    thread::scope(|scope| {
+        let handles: Vec<_> = items
+            .chunks(chunk_size)
+            .map(|chunk| scope.spawn(move || chunk.iter().map(f).collect::<Vec<R>>()))
+            .collect();
+
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().unwrap_or_else(|e| panic::resume_unwind(e)))
+            .collect()
+    })
+}
+
 impl<C> Platform<C>
 where
     C: CoreRPCLike,
@@ -23,6 +111,15 @@ where
     /// as input and performs the corresponding state transition operations. It deserializes the raw state
     /// transitions into a `StateTransition` and processes them.
     ///
+    /// The results of the state transitions that were applied are recorded by the hash of the
+    /// raw state transition. State transitions failing validation are removed from the block by
+    /// the proposer, so recording them would make the app hash depend on transactions that are
+    /// not part of the block.
+    ///
+    /// When `parallel_state_transition_validation` is enabled, the state transitions are
+    /// deserialized and their structure and signatures are validated on all cores first, then
+    /// they are applied in order. The results are the same as processing them one by one.
+    ///
     /// # Arguments
     ///
     /// * `raw_state_transitions` - A reference to a vector of raw state transitions.
@@ -47,20 +144,55 @@ where
         block_info: &BlockInfo,
         transaction: &Transaction,
     ) -> Result<(FeeResult, Vec<(Vec<u8>, ExecTxResult)>), Error> {
-        let state_transitions = StateTransition::deserialize_many(raw_state_transitions)?;
+        let state_transitions = if self.config.parallel_state_transition_validation {
+            parallel_map(raw_state_transitions, |raw_state_transition| {
+                StateTransition::deserialize(raw_state_transition)
+            })
+            .into_iter()
+            .collect::<Result<Vec<_>, _>>()?
+        } else {
+            StateTransition::deserialize_many(raw_state_transitions)?
+        };
+        let parallel_validations = self.validate_structure_and_signatures_in_parallel_v0(
+            &state_transitions,
+            block_platform_state,
+            block_info,
+        );
+        let mut block_writes = BlockWrites::default();
         let mut aggregate_fee_result = FeeResult::default();
+        let mut state_transition_results = BTreeMap::new();
         let platform_ref = PlatformRef {
             drive: &self.drive,
             state: block_platform_state,
             config: &self.config,
             core_rpc: &self.core_rpc,
+            check_tx: false,
         };
         let exec_tx_results = state_transitions
             .into_iter()
             .zip(raw_state_transitions.iter())
-            .map(|(state_transition, raw_state_transition)| {
-                let state_transition_execution_event =
-                    process_state_transition(&platform_ref, state_transition, Some(transaction))?;
+            .zip(parallel_validations)
+            .map(|((state_transition, raw_state_transition), validation)| {
+                // The validation is redone if it read what an earlier state transition wrote
+                let validation =
+                    validation.filter(|_| !block_writes.are_read_by(&state_transition));
+                block_writes.add(&state_transition);
+
+                let state_transition_execution_event = if let Some(result) = validation {
+                    process_validated_state_transition(
+                        &platform_ref,
+                        state_transition,
+                        result,
+                        Some(transaction),
+                    )?
+                } else {
+                    process_state_transition(
+                        &platform_ref,
+                        state_transition,
+                        block_info.time_ms,
+                        Some(transaction),
+                    )?
+                };
 
                 let execution_result = if state_transition_execution_event.is_valid() {
                     let execution_event = state_transition_execution_event.into_data()?;
@@ -70,13 +202,85 @@ where
                         state_transition_execution_event.errors,
                     ))
                 };
-                if let SuccessfulPaidExecution(_, fee_result) = &execution_result {
-                    aggregate_fee_result.checked_add_assign(fee_result.clone())?;
+                let state_transition_result = match &execution_result {
+                    SuccessfulPaidExecution(_, fee_result) => {
+                        aggregate_fee_result.checked_add_assign(fee_result.clone())?;
+                        Some(StateTransitionResult {
+                            height: block_info.height,
+                            code: 0,
+                            storage_fee: fee_result.storage_fee,
+                            processing_fee: fee_result.processing_fee,
+                        })
+                    }
+                    SuccessfulFreeExecution => Some(StateTransitionResult {
+                        height: block_info.height,
+                        ..Default::default()
+                    }),
+                    ConsensusExecutionError(_) => None,
+                };
+                if let Some(state_transition_result) = state_transition_result {
+                    state_transition_results.insert(
+                        sha256::Hash::hash(raw_state_transition).into_inner(),
+                        state_transition_result,
+                    );
                 }
 
                 Ok((raw_state_transition.clone(), execution_result.into()))
             })
             .collect::<Result<Vec<(Vec<u8>, ExecTxResult)>, Error>>()?;
+
+        // The results are only recorded once the protocol version created their structure
+        if block_platform_state.current_protocol_version_in_consensus
+            >= STATE_TRANSITION_RESULTS_PROTOCOL_VERSION
+        {
+            self.drive
+                .store_state_transition_results(&state_transition_results, Some(transaction))?;
+        }
+
         Ok((aggregate_fee_result, exec_tx_results))
     }
+
+    /// Validates the structure and the signatures of the state transitions of a block on all
+    /// cores, against the state committed by the previous block as the block transaction can
+    /// only be read from one thread.
+    ///
+    /// The committed state is the same as the block state for what was not written in the
+    /// block yet. Masternode identities are updated before the state transitions when the core
+    /// chain locked height changes, these blocks are validated in order. What the state
+    /// transitions themselves write is tracked with [`BlockWrites`] while they are applied.
+    ///
+    /// State transitions are `None` when they have to be validated in order, including when
+    /// their validation failed with an error so that it fails the same way.
+    fn validate_structure_and_signatures_in_parallel_v0(
+        &self,
+        state_transitions: &[StateTransition],
+        block_platform_state: &platform_state::v0::PlatformState,
+        block_info: &BlockInfo,
+    ) -> Vec<Option<ConsensusValidationResult<Option<PartialIdentity>>>> {
+        let last_core_height = block_platform_state
+            .last_committed_block_info
+            .as_ref()
+            .map(|last_block_info| last_block_info.basic_info.core_height);
+
+        if !self.config.parallel_state_transition_validation
+            || last_core_height != Some(block_info.core_height)
+        {
+            return state_transitions.iter().map(|_| None).collect();
+        }
+
+        let drive = &self.drive;
+        let protocol_version = block_platform_state.current_protocol_version_in_consensus;
+        let block_time_ms = block_info.time_ms;
+
+        parallel_map(state_transitions, |state_transition| {
+            validate_state_transition_structure_and_signatures(
+                drive,
+                protocol_version,
+                block_time_ms,
+                state_transition,
+                None,
+            )
+            .ok()
+        })
+    }
 }
@@ -416,6 +416,7 @@ where
                             .to_vec(),
                     ),
                     disabled_at: None,
+                    valid_until: None,
                 };
                 non_unique_keys_to_add.push(key);
                 new_key_id += 1;
@@ -439,6 +440,7 @@ where
                         read_only: true,
                         data: BinaryData::new(new_operator_payout_address.to_vec()),
                         disabled_at: None,
+                        valid_until: None,
                     };
                     non_unique_keys_to_add.push(key);
                     // new_key_id += 1;
@@ -637,6 +639,7 @@ where
             read_only: true,
             data: BinaryData::new(payout_address.to_vec()),
             disabled_at: None,
+            valid_until: None,
         })
     }
 
@@ -652,6 +655,7 @@ where
             read_only: true,
             data: BinaryData::new(voting_address.to_vec()),
             disabled_at: None,
+            valid_until: None,
         })
     }
 
@@ -669,6 +673,7 @@ where
             read_only: true,
             data: BinaryData::new(pub_key_operator),
             disabled_at: None,
+            valid_until: None,
         }];
         if let Some(operator_payout_address) = operator_payout_address {
             identity_public_keys.push(IdentityPublicKey {
@@ -679,6 +684,7 @@ where
                 read_only: true,
                 data: BinaryData::new(operator_payout_address.to_vec()),
                 disabled_at: None,
+                valid_until: None,
             });
         }
         if let Some(node_id) = platform_node_id {
@@ -690,6 +696,7 @@ where
                 read_only: true,
                 data: BinaryData::new(node_id.to_vec()),
                 disabled_at: None,
+                valid_until: None,
             });
         }
 
@@ -27,11 +27,15 @@
 // DEALINGS IN THE SOFTWARE.
 //
 
+use crate::error::execution::ExecutionError;
+use crate::error::Error;
 use crate::execution::types::block_state_info;
+use crate::platform_types::block_attestation::v0::BlockAttestation;
 use crate::platform_types::{epoch, platform_state};
 use dashcore_rpc::dashcore::Txid;
 use std::collections::BTreeMap;
-use tenderdash_abci::proto::abci::ResponsePrepareProposal;
+use tenderdash_abci::proto::abci::{ExtendVoteExtension, ResponsePrepareProposal};
+use tenderdash_abci::proto::types::VoteExtensionType;
 
 /// V0 of the Block execution context
 pub struct BlockExecutionContext {
@@ -48,3 +52,37 @@ pub struct BlockExecutionContext {
     /// The response prepare proposal if proposed by us
     pub proposer_results: Option<ResponsePrepareProposal>,
 }
+
+impl BlockExecutionContext {
+    /// The attestation of the executed block that the validator quorum signs
+    pub fn block_attestation(&self) -> Result<BlockAttestation, Error> {
+        let app_hash = self.block_state_info.app_hash.ok_or(Error::Execution(
+            ExecutionError::CorruptedCodeExecution(
+                "the block must be executed before it can be attested",
+            ),
+        ))?;
+        Ok(BlockAttestation {
+            height: self.block_state_info.height,
+            app_hash,
+            epoch: self.epoch_info.current_epoch_index,
+            protocol_version: self
+                .block_platform_state
+                .current_protocol_version_in_consensus,
+        })
+    }
+
+    /// The vote extensions validators sign: the hashes of the withdrawal transactions, followed
+    /// by the block attestation
+    pub fn vote_extensions(&self) -> Result<Vec<ExtendVoteExtension>, Error> {
+        let mut extensions: Vec<ExtendVoteExtension> = self
+            .withdrawal_transactions
+            .keys()
+            .map(|tx_id| ExtendVoteExtension {
+                r#type: VoteExtensionType::ThresholdRecover as i32,
+                extension: tx_id.to_vec(),
+            })
+            .collect();
+        extensions.push(self.block_attestation()?.to_vote_extension());
+        Ok(extensions)
+    }
+}
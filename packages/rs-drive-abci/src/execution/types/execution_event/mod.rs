@@ -106,6 +106,30 @@ impl<'a> TryFrom<(Option<PartialIdentity>, StateTransitionAction, &Epoch)> for E
                     )))
                 }
             }
+            StateTransitionAction::IdentityCreditTransferAction(
+                identity_credit_transfer_action,
+            ) => {
+                let total_transfer_amount = identity_credit_transfer_action
+                    .total_transfer_amount()
+                    .ok_or(Error::Execution(ExecutionError::CorruptedCodeExecution(
+                        "credit transfer total amount overflow should be checked in validation",
+                    )))?;
+                let operations = action.into_high_level_drive_operations(epoch)?;
+                if let Some(mut identity) = identity {
+                    // The fees of every recipient are paid from what is left after the transfer
+                    identity.balance = identity
+                        .balance
+                        .map(|balance| balance.saturating_sub(total_transfer_amount));
+                    Ok(PaidDriveEvent {
+                        identity,
+                        operations,
+                    })
+                } else {
+                    Err(Error::Execution(ExecutionError::CorruptedCodeExecution(
+                        "partial identity should be present",
+                    )))
+                }
+            }
             _ => {
                 let operations = action.into_high_level_drive_operations(epoch)?;
                 if let Some(identity) = identity {
@@ -0,0 +1,10 @@
+use drive::fee::result::{BalanceChange, FeeResult};
+
+/// The fees a state transition would pay if it was executed on the last committed state
+#[derive(Clone, Debug)]
+pub struct FeeEstimate {
+    /// Storage and processing fees, and the refunds for the storage it would free
+    pub fee_result: FeeResult,
+    /// How the balance of the identity paying for the state transition would change
+    pub balance_change: BalanceChange,
+}
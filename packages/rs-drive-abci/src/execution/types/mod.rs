@@ -8,6 +8,8 @@ pub mod block_state_info;
 pub(in crate::execution) mod execution_event;
 /// The execution result
 pub(in crate::execution) mod execution_result;
+/// The estimated fees of a state transition
+pub mod fee_estimate;
 /// A structure showing the storage and processing fees in a pool
 pub(in crate::execution) mod fees_in_pools;
 /// The outcome of processing block fees
@@ -154,6 +154,7 @@ mod test {
             drive: &platform.drive,
             state: &state_read_guard,
             config: &platform.config,
+            check_tx: false,
         };
 
         let mut contact_request_document = get_contact_request_document_fixture(None, None);
@@ -223,6 +224,7 @@ mod test {
             drive: &platform.drive,
             state: &state_write_guard,
             config: &platform.config,
+            check_tx: false,
         };
 
         let mut contact_request_document = get_contact_request_document_fixture(None, None);
@@ -304,6 +306,7 @@ mod test {
             drive: &platform.drive,
             state: &state_write_guard,
             config: &platform.config,
+            check_tx: false,
         };
 
         let contact_request_document = get_contact_request_document_fixture(None, None);
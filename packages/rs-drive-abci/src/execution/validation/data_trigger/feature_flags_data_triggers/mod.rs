@@ -106,6 +106,7 @@ mod test {
             drive: &platform.drive,
             state: &state_read_guard,
             config: &platform.config,
+            check_tx: false,
         };
 
         let transition_execution_context = StateTransitionExecutionContext::default();
@@ -310,6 +310,7 @@ mod test {
             drive: &platform.drive,
             state: &state_write_guard,
             config: &platform.config,
+            check_tx: false,
         };
 
         for (i, document) in extended_documents.iter().enumerate() {
@@ -412,6 +413,7 @@ mod test {
             drive: &platform.drive,
             state: &state_write_guard,
             config: &platform.config,
+            check_tx: false,
         };
 
         let execution_context = StateTransitionExecutionContext::default();
@@ -459,6 +461,7 @@ mod test {
             drive: &platform.drive,
             state: &state_write_guard,
             config: &platform.config,
+            check_tx: false,
         };
 
         let execution_context = StateTransitionExecutionContext::default();
@@ -499,6 +502,7 @@ mod test {
             drive: &platform.drive,
             state: &state_write_guard,
             config: &platform.config,
+            check_tx: false,
         };
 
         let mut identity = Identity::random_identity(2, Some(9));
@@ -546,6 +550,7 @@ mod test {
             drive: &platform.drive,
             state: &state_write_guard,
             config: &platform.config,
+            check_tx: false,
         };
 
         platform_ref
@@ -642,6 +647,7 @@ mod test {
             drive: &platform.drive,
             state: &state_write_guard,
             config: &platform.config,
+            check_tx: false,
         };
 
         let execution_context = StateTransitionExecutionContext::default();
@@ -141,6 +141,7 @@ mod tests {
             drive: &platform.drive,
             state: &state_read_guard,
             config: &platform.config,
+            check_tx: false,
         };
 
         let transition_execution_context = StateTransitionExecutionContext::default();
@@ -209,6 +210,7 @@ mod tests {
             drive: &platform.drive,
             state: &state_read_guard,
             config: &platform.config,
+            check_tx: false,
         };
 
         let transition_execution_context = StateTransitionExecutionContext::default();
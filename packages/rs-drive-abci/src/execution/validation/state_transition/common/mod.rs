@@ -1,3 +1,5 @@
+pub mod validate_documents_ttl_protocol_version;
+pub mod validate_identity_nonce;
 pub mod validate_identity_public_key_ids_dont_exist_in_state;
 pub mod validate_identity_public_key_ids_exist_in_state;
 pub mod validate_identity_public_keys_structure;
@@ -5,3 +7,4 @@ pub mod validate_protocol_version;
 pub mod validate_schema;
 pub mod validate_state_transition_identity_signature;
 pub mod validate_unique_identity_public_key_hashes_in_state;
+pub mod verify_signature_with_cache;
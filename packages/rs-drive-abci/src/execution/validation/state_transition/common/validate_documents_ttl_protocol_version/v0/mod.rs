@@ -0,0 +1,27 @@
+use dpp::consensus::basic::IncompatibleProtocolVersionError;
+use dpp::data_contract::DataContract;
+use dpp::validation::SimpleConsensusValidationResult;
+use drive::drive::defaults::EXPIRING_DOCUMENTS_PROTOCOL_VERSION;
+
+/// Document types can only define a time to live once the chain removes expired documents
+pub(crate) fn validate_documents_ttl_protocol_version_v0(
+    data_contract: &DataContract,
+    protocol_version: u32,
+) -> SimpleConsensusValidationResult {
+    if protocol_version < EXPIRING_DOCUMENTS_PROTOCOL_VERSION
+        && data_contract
+            .document_types
+            .values()
+            .any(|document_type| document_type.documents_ttl.is_some())
+    {
+        return SimpleConsensusValidationResult::new_with_error(
+            IncompatibleProtocolVersionError::new(
+                protocol_version,
+                EXPIRING_DOCUMENTS_PROTOCOL_VERSION,
+            )
+            .into(),
+        );
+    }
+
+    SimpleConsensusValidationResult::default()
+}
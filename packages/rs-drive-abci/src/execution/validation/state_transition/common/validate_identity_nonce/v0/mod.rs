@@ -0,0 +1,78 @@
+use crate::error::Error;
+
+use dpp::consensus::state::identity::invalid_identity_contract_nonce_error::InvalidIdentityContractNonceError;
+use dpp::consensus::state::identity::invalid_identity_nonce_error::InvalidIdentityNonceError;
+
+use dpp::platform_value::Identifier;
+use dpp::prelude::IdentityNonce;
+
+use dpp::validation::SimpleConsensusValidationResult;
+
+use drive::drive::Drive;
+use drive::grovedb::TransactionArg;
+
+/// How far above the nonce in state a state transition's nonce can be in check tx, so that
+/// several state transitions of an identity can be pending in the mempool at once
+pub(crate) const MAX_PENDING_NONCES: IdentityNonce = 24;
+
+/// In a block the nonce must be exactly one more than the nonce in state, state transitions
+/// are executed in order. In check tx it can be up to `MAX_PENDING_NONCES` more.
+fn is_next_nonce(current_nonce: IdentityNonce, nonce: IdentityNonce, check_tx: bool) -> bool {
+    let max_nonce = if check_tx {
+        current_nonce.saturating_add(MAX_PENDING_NONCES)
+    } else {
+        current_nonce.saturating_add(1)
+    };
+    nonce > current_nonce && nonce <= max_nonce
+}
+
+/// This will validate that the nonce is exactly one more than the identity nonce in state,
+/// or at most `MAX_PENDING_NONCES` more in check tx
+pub(crate) fn validate_identity_nonce_v0(
+    identity_id: Identifier,
+    nonce: IdentityNonce,
+    check_tx: bool,
+    drive: &Drive,
+    transaction: TransactionArg,
+) -> Result<SimpleConsensusValidationResult, Error> {
+    let current_nonce = drive
+        .fetch_identity_nonce(identity_id.to_buffer(), true, transaction)?
+        .unwrap_or_default();
+
+    if !is_next_nonce(current_nonce, nonce, check_tx) {
+        return Ok(SimpleConsensusValidationResult::new_with_error(
+            InvalidIdentityNonceError::new(identity_id, current_nonce, nonce).into(),
+        ));
+    }
+
+    Ok(SimpleConsensusValidationResult::default())
+}
+
+/// This will validate that the nonce is exactly one more than the identity nonce
+/// for the contract in state, or at most `MAX_PENDING_NONCES` more in check tx
+pub(crate) fn validate_identity_contract_nonce_v0(
+    identity_id: Identifier,
+    contract_id: Identifier,
+    nonce: IdentityNonce,
+    check_tx: bool,
+    drive: &Drive,
+    transaction: TransactionArg,
+) -> Result<SimpleConsensusValidationResult, Error> {
+    let current_nonce = drive
+        .fetch_identity_contract_nonce(
+            identity_id.to_buffer(),
+            contract_id.to_buffer(),
+            true,
+            transaction,
+        )?
+        .unwrap_or_default();
+
+    if !is_next_nonce(current_nonce, nonce, check_tx) {
+        return Ok(SimpleConsensusValidationResult::new_with_error(
+            InvalidIdentityContractNonceError::new(identity_id, contract_id, current_nonce, nonce)
+                .into(),
+        ));
+    }
+
+    Ok(SimpleConsensusValidationResult::default())
+}
@@ -1,23 +1,27 @@
 use crate::error::Error;
+use crate::execution::validation::state_transition::common::verify_signature_with_cache::v0::verify_with_signature_verification_cache_v0;
 
 use dpp::consensus::signature::{
     IdentityNotFoundError, InvalidSignaturePublicKeySecurityLevelError,
 };
 
 use dpp::identity::PartialIdentity;
+use dpp::prelude::TimestampMillis;
+use dpp::serialization_traits::Signable;
 
 use dpp::state_transition::StateTransitionIdentitySigned;
-use dpp::validation::ConsensusValidationResult;
+use dpp::validation::{ConsensusValidationResult, SimpleConsensusValidationResult};
 use dpp::ProtocolError;
 use dpp::{
     consensus::signature::{
         InvalidIdentityPublicKeyTypeError, MissingPublicKeyError, PublicKeyIsDisabledError,
-        SignatureError,
+        PublicKeyIsExpiredError, SignatureError,
     },
     state_transition::validation::validate_state_transition_identity_signature::convert_to_consensus_signature_error,
     NativeBlsModule,
 };
 use drive::dpp::identity::KeyType;
+use drive::drive::cache::SignatureVerificationCacheKey;
 use drive::drive::identity::key::fetch::IdentityKeysRequest;
 use drive::drive::Drive;
 use drive::grovedb::TransactionArg;
@@ -30,6 +34,7 @@ lazy_static! {
         keys.insert(KeyType::ECDSA_SECP256K1);
         keys.insert(KeyType::BLS12_381);
         keys.insert(KeyType::ECDSA_HASH160);
+        keys.insert(KeyType::ECDSA_SECP256K1_MULTISIG);
         keys
     };
 }
@@ -38,6 +43,7 @@ pub(crate) fn validate_state_transition_identity_signature_v0(
     drive: &Drive,
     state_transition: &impl StateTransitionIdentitySigned,
     request_revision: bool,
+    block_time_ms: TimestampMillis,
     transaction: TransactionArg,
 ) -> Result<ConsensusValidationResult<PartialIdentity>, Error> {
     let mut validation_result = ConsensusValidationResult::<PartialIdentity>::default();
@@ -110,6 +116,15 @@ pub(crate) fn validate_state_transition_identity_signature_v0(
         return Ok(validation_result);
     }
 
+    if let Some(valid_until) = public_key.valid_until {
+        if public_key.is_expired_at(block_time_ms) {
+            validation_result.add_error(SignatureError::PublicKeyIsExpiredError(
+                PublicKeyIsExpiredError::new(public_key.id, valid_until),
+            ));
+            return Ok(validation_result);
+        }
+    }
+
     // let operation = SignatureVerificationOperation::new(public_key.key_type);
     // execution_context.add_operation(Operation::SignatureVerification(operation));
     //
@@ -117,12 +132,25 @@ pub(crate) fn validate_state_transition_identity_signature_v0(
     //     return Ok(validation_result);
     // }
 
-    let signature_is_valid =
-        state_transition.verify_signature(public_key, &NativeBlsModule::default());
+    let cache_key = SignatureVerificationCacheKey::new(
+        state_transition.signable_bytes()?.as_slice(),
+        state_transition.get_signature().as_slice(),
+        key_id,
+        public_key.key_type,
+        public_key.data.as_slice(),
+    );
+
+    let signature_result = verify_with_signature_verification_cache_v0(drive, cache_key, || {
+        match state_transition.verify_signature(public_key, &NativeBlsModule::default()) {
+            Ok(()) => Ok(SimpleConsensusValidationResult::default()),
+            Err(err) => Ok(SimpleConsensusValidationResult::new_with_error(
+                convert_to_consensus_signature_error(err)?,
+            )),
+        }
+    })?;
 
-    if let Err(err) = signature_is_valid {
-        let consensus_error = convert_to_consensus_signature_error(err)?;
-        validation_result.add_error(consensus_error);
+    if !signature_result.is_valid() {
+        validation_result.add_errors(signature_result.errors);
         return Ok(validation_result);
     }
 
@@ -0,0 +1,63 @@
+use crate::error::Error;
+
+use dpp::identity::{KeyID, KeyType};
+use dpp::serialization_traits::PlatformMessageSignable;
+use dpp::validation::SimpleConsensusValidationResult;
+
+use drive::drive::cache::SignatureVerificationCacheKey;
+use drive::drive::Drive;
+
+/// This will verify the signature of the signable bytes with a public key, unless the same
+/// signature was already verified successfully with this key
+pub(crate) fn verify_signature_with_cache_v0(
+    drive: &Drive,
+    signable_bytes: &[u8],
+    public_key_id: KeyID,
+    public_key_type: KeyType,
+    public_key_data: &[u8],
+    signature: &[u8],
+) -> Result<SimpleConsensusValidationResult, Error> {
+    let cache_key = SignatureVerificationCacheKey::new(
+        signable_bytes,
+        signature,
+        public_key_id,
+        public_key_type,
+        public_key_data,
+    );
+
+    verify_with_signature_verification_cache_v0(drive, cache_key, || {
+        Ok(signable_bytes.verify_signature(public_key_type, public_key_data, signature)?)
+    })
+}
+
+/// This will run the verification of a signature, unless the verification identified by the
+/// cache key already succeeded, and remembers it when it succeeds
+pub(crate) fn verify_with_signature_verification_cache_v0(
+    drive: &Drive,
+    cache_key: SignatureVerificationCacheKey,
+    verify: impl FnOnce() -> Result<SimpleConsensusValidationResult, Error>,
+) -> Result<SimpleConsensusValidationResult, Error> {
+    let is_verified = drive
+        .cache
+        .read()
+        .unwrap()
+        .signature_verifications
+        .contains(&cache_key);
+
+    if is_verified {
+        return Ok(SimpleConsensusValidationResult::default());
+    }
+
+    let result = verify()?;
+
+    if result.is_valid() {
+        drive
+            .cache
+            .read()
+            .unwrap()
+            .signature_verifications
+            .insert(cache_key);
+    }
+
+    Ok(result)
+}
@@ -4,8 +4,10 @@ use crate::error::Error;
 use crate::execution::types::execution_event::ExecutionEvent;
 use crate::platform_types::platform::PlatformRef;
 use crate::rpc::core::CoreRPCLike;
-use dpp::prelude::ConsensusValidationResult;
+use dpp::identity::PartialIdentity;
+use dpp::prelude::{ConsensusValidationResult, TimestampMillis};
 use dpp::state_transition::StateTransition;
+use drive::drive::Drive;
 use drive::grovedb::TransactionArg;
 
 /// There are 3 stages in a state transition processing:
@@ -21,13 +23,72 @@ use drive::grovedb::TransactionArg;
 /// Validate state verifies that there are no state based conflicts, for example that a document
 /// with a unique index isn't already taken.
 ///
+/// Keys are checked to not be expired at `block_time_ms`, the time of the block executing the
+/// state transition.
+///
 pub(in crate::execution) fn process_state_transition<'a, C: CoreRPCLike>(
     platform: &'a PlatformRef<C>,
     state_transition: StateTransition,
+    block_time_ms: TimestampMillis,
     transaction: TransactionArg,
 ) -> Result<ConsensusValidationResult<ExecutionEvent<'a>>, Error> {
     //Todo: feature type (next versioning pr)
     // We will need to check the protocol version and use feature type to determine the version of
     // the processing.
-    v0::process_state_transition_v0(platform, state_transition, transaction)
+    v0::process_state_transition_v0(platform, state_transition, block_time_ms, transaction)
+}
+
+/// Runs the structure and signature validation stages of [`process_state_transition`].
+///
+/// These stages only read the identity signing the state transition and the contracts it uses,
+/// so they can run for many state transitions at once, see [`process_validated_state_transition`].
+pub(in crate::execution) fn validate_state_transition_structure_and_signatures(
+    drive: &Drive,
+    protocol_version: u32,
+    block_time_ms: TimestampMillis,
+    state_transition: &StateTransition,
+    transaction: TransactionArg,
+) -> Result<ConsensusValidationResult<Option<PartialIdentity>>, Error> {
+    v0::validate_structure_and_signatures_v0(
+        drive,
+        protocol_version,
+        block_time_ms,
+        state_transition,
+        transaction,
+    )
+}
+
+/// Finishes processing a state transition like [`process_state_transition`], given the result
+/// of [`validate_state_transition_structure_and_signatures`]. The result must have been
+/// obtained from a state in which the identity and contracts it read are the same.
+pub(in crate::execution) fn process_validated_state_transition<'a, C: CoreRPCLike>(
+    platform: &'a PlatformRef<C>,
+    state_transition: StateTransition,
+    structure_and_signatures_result: ConsensusValidationResult<Option<PartialIdentity>>,
+    transaction: TransactionArg,
+) -> Result<ConsensusValidationResult<ExecutionEvent<'a>>, Error> {
+    v0::process_validated_state_transition_v0(
+        platform,
+        state_transition,
+        structure_and_signatures_result,
+        transaction,
+    )
+}
+
+/// Processes a state transition like [`process_state_transition`] to estimate its fees, without
+/// verifying signatures if the state transition is not signed yet
+///
+/// An unsigned state transition is paid by the identity owning it, which must exist.
+pub(in crate::execution) fn process_state_transition_for_fee_estimation<'a, C: CoreRPCLike>(
+    platform: &'a PlatformRef<C>,
+    state_transition: StateTransition,
+    block_time_ms: TimestampMillis,
+    transaction: TransactionArg,
+) -> Result<ConsensusValidationResult<ExecutionEvent<'a>>, Error> {
+    v0::process_state_transition_for_fee_estimation_v0(
+        platform,
+        state_transition,
+        block_time_ms,
+        transaction,
+    )
 }
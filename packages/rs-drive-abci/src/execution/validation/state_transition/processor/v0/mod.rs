@@ -3,9 +3,10 @@ use crate::execution::types::execution_event::ExecutionEvent;
 use crate::execution::validation::state_transition::transformer::StateTransitionActionTransformerV0;
 use crate::platform_types::platform::PlatformRef;
 use crate::rpc::core::CoreRPCLike;
+use dpp::consensus::signature::{IdentityNotFoundError, SignatureError};
 use dpp::identity::PartialIdentity;
-use dpp::prelude::ConsensusValidationResult;
-use dpp::state_transition::{StateTransition, StateTransitionAction};
+use dpp::prelude::{ConsensusValidationResult, TimestampMillis};
+use dpp::state_transition::{StateTransition, StateTransitionAction, StateTransitionLike};
 use dpp::validation::SimpleConsensusValidationResult;
 use drive::drive::Drive;
 use drive::grovedb::TransactionArg;
@@ -13,20 +14,73 @@ use drive::grovedb::TransactionArg;
 pub(in crate::execution) fn process_state_transition_v0<'a, C: CoreRPCLike>(
     platform: &'a PlatformRef<C>,
     state_transition: StateTransition,
+    block_time_ms: TimestampMillis,
     transaction: TransactionArg,
 ) -> Result<ConsensusValidationResult<ExecutionEvent<'a>>, Error> {
-    // Validating structure
-    let result = state_transition.validate_structure(
+    let result = validate_structure_and_signatures_v0(
         platform.drive,
         platform.state.current_protocol_version_in_consensus,
+        block_time_ms,
+        &state_transition,
         transaction,
     )?;
+
+    process_validated_state_transition_v0(platform, state_transition, result, transaction)
+}
+
+pub(in crate::execution) fn validate_structure_and_signatures_v0(
+    drive: &Drive,
+    protocol_version: u32,
+    block_time_ms: TimestampMillis,
+    state_transition: &StateTransition,
+    transaction: TransactionArg,
+) -> Result<ConsensusValidationResult<Option<PartialIdentity>>, Error> {
+    // Validating structure
+    let result = state_transition.validate_structure(drive, protocol_version, transaction)?;
     if !result.is_valid() {
-        return Ok(ConsensusValidationResult::<ExecutionEvent>::new_with_errors(result.errors));
+        return Ok(ConsensusValidationResult::new_with_errors(result.errors));
     }
 
     // Validating signatures
-    let result = state_transition.validate_identity_and_signatures(
+    state_transition.validate_identity_and_signatures(
+        drive,
+        protocol_version,
+        block_time_ms,
+        transaction,
+    )
+}
+
+pub(in crate::execution) fn process_validated_state_transition_v0<'a, C: CoreRPCLike>(
+    platform: &'a PlatformRef<C>,
+    state_transition: StateTransition,
+    structure_and_signatures_result: ConsensusValidationResult<Option<PartialIdentity>>,
+    transaction: TransactionArg,
+) -> Result<ConsensusValidationResult<ExecutionEvent<'a>>, Error> {
+    if !structure_and_signatures_result.is_valid() {
+        return Ok(ConsensusValidationResult::new_with_errors(
+            structure_and_signatures_result.errors,
+        ));
+    }
+    let maybe_identity = structure_and_signatures_result.into_data()?;
+
+    // Validating state
+    let result = state_transition.validate_state(platform, transaction)?;
+
+    result.map_result(|action| (maybe_identity, action, &platform.state.epoch()).try_into())
+}
+
+pub(in crate::execution) fn process_state_transition_for_fee_estimation_v0<'a, C: CoreRPCLike>(
+    platform: &'a PlatformRef<C>,
+    state_transition: StateTransition,
+    block_time_ms: TimestampMillis,
+    transaction: TransactionArg,
+) -> Result<ConsensusValidationResult<ExecutionEvent<'a>>, Error> {
+    if !state_transition.get_signature().is_empty() {
+        return process_state_transition_v0(platform, state_transition, block_time_ms, transaction);
+    }
+
+    // Validating structure
+    let result = state_transition.validate_structure(
         platform.drive,
         platform.state.current_protocol_version_in_consensus,
         transaction,
@@ -34,7 +88,29 @@ pub(in crate::execution) fn process_state_transition_v0<'a, C: CoreRPCLike>(
     if !result.is_valid() {
         return Ok(ConsensusValidationResult::<ExecutionEvent>::new_with_errors(result.errors));
     }
-    let maybe_identity = result.into_data()?;
+
+    // Fetching the identity paying for the state transition, there is nothing to verify yet
+    let maybe_identity = match &state_transition {
+        // New identities pay from their asset lock
+        StateTransition::IdentityCreate(_) => None,
+        _ => {
+            let owner_id = state_transition.get_owner_id();
+            match platform
+                .drive
+                .fetch_identity_with_balance(owner_id.to_buffer(), transaction)?
+            {
+                Some(identity) => Some(identity),
+                None => {
+                    return Ok(ConsensusValidationResult::<ExecutionEvent>::new_with_error(
+                        SignatureError::IdentityNotFoundError(IdentityNotFoundError::new(
+                            *owner_id,
+                        ))
+                        .into(),
+                    ))
+                }
+            }
+        }
+    };
 
     // Validating state
     let result = state_transition.validate_state(platform, transaction)?;
@@ -66,6 +142,7 @@ pub(crate) trait StateTransitionValidationV0: StateTransitionActionTransformerV0
     /// # Arguments
     ///
     /// * `drive` - A reference to the drive containing the transaction data.
+    /// * `block_time_ms` - The time of the block executing the transaction, used to reject expired keys.
     /// * `tx` - The transaction argument to be authenticated.
     ///
     /// # Returns
@@ -75,6 +152,7 @@ pub(crate) trait StateTransitionValidationV0: StateTransitionActionTransformerV0
         &self,
         drive: &Drive,
         protocol_version: u32,
+        block_time_ms: TimestampMillis,
         tx: TransactionArg,
     ) -> Result<ConsensusValidationResult<Option<PartialIdentity>>, Error>;
 
@@ -138,32 +216,33 @@ impl StateTransitionValidationV0 for StateTransition {
         &self,
         drive: &Drive,
         protocol_version: u32,
+        block_time_ms: TimestampMillis,
         tx: TransactionArg,
     ) -> Result<ConsensusValidationResult<Option<PartialIdentity>>, Error> {
         match self {
             StateTransition::DataContractCreate(st) => {
-                st.validate_identity_and_signatures(drive, protocol_version, tx)
+                st.validate_identity_and_signatures(drive, protocol_version, block_time_ms, tx)
             }
             StateTransition::DataContractUpdate(st) => {
-                st.validate_identity_and_signatures(drive, protocol_version, tx)
+                st.validate_identity_and_signatures(drive, protocol_version, block_time_ms, tx)
             }
             StateTransition::IdentityCreate(st) => {
-                st.validate_identity_and_signatures(drive, protocol_version, tx)
+                st.validate_identity_and_signatures(drive, protocol_version, block_time_ms, tx)
             }
             StateTransition::IdentityUpdate(st) => {
-                st.validate_identity_and_signatures(drive, protocol_version, tx)
+                st.validate_identity_and_signatures(drive, protocol_version, block_time_ms, tx)
             }
             StateTransition::IdentityTopUp(st) => {
-                st.validate_identity_and_signatures(drive, protocol_version, tx)
+                st.validate_identity_and_signatures(drive, protocol_version, block_time_ms, tx)
             }
             StateTransition::IdentityCreditWithdrawal(st) => {
-                st.validate_identity_and_signatures(drive, protocol_version, tx)
+                st.validate_identity_and_signatures(drive, protocol_version, block_time_ms, tx)
             }
             StateTransition::DocumentsBatch(st) => {
-                st.validate_identity_and_signatures(drive, protocol_version, tx)
+                st.validate_identity_and_signatures(drive, protocol_version, block_time_ms, tx)
             }
             StateTransition::IdentityCreditTransfer(st) => {
-                st.validate_identity_and_signatures(drive, protocol_version, tx)
+                st.validate_identity_and_signatures(drive, protocol_version, block_time_ms, tx)
             }
         }
     }
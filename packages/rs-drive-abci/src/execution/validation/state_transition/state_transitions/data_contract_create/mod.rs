@@ -8,6 +8,7 @@ use dpp::data_contract::state_transition::data_contract_create_transition::DataC
 use dpp::state_transition::StateTransitionAction;
 
 use dpp::prelude::ConsensusValidationResult;
+use dpp::prelude::TimestampMillis;
 use dpp::validation::SimpleConsensusValidationResult;
 use drive::drive::Drive;
 use drive::grovedb::TransactionArg;
@@ -37,21 +38,22 @@ impl StateTransitionValidationV0 for DataContractCreateTransition {
     fn validate_structure(
         &self,
         _drive: &Drive,
-        _protocol_version: u32,
+        protocol_version: u32,
         _tx: TransactionArg,
     ) -> Result<SimpleConsensusValidationResult, Error> {
         //todo: use protocol version to determine validation
-        self.validate_structure_v0()
+        self.validate_structure_v0(protocol_version)
     }
 
     fn validate_identity_and_signatures(
         &self,
         drive: &Drive,
         _protocol_version: u32,
+        block_time_ms: TimestampMillis,
         transaction: TransactionArg,
     ) -> Result<ConsensusValidationResult<Option<PartialIdentity>>, Error> {
         //todo: use protocol version to determine validation
-        self.validate_identity_and_signatures_v0(drive, transaction)
+        self.validate_identity_and_signatures_v0(drive, block_time_ms, transaction)
     }
 
     fn validate_state<C: CoreRPCLike>(
@@ -5,14 +5,21 @@ use dpp::data_contract::state_transition::data_contract_create_transition::DataC
 use dpp::data_contract::state_transition::data_contract_create_transition::validation::state::validate_data_contract_create_transition_basic::DATA_CONTRACT_CREATE_SCHEMA_VALIDATOR;
 use dpp::validation::SimpleConsensusValidationResult;
 use crate::error::Error;
+use crate::execution::validation::state_transition::common::validate_documents_ttl_protocol_version::v0::validate_documents_ttl_protocol_version_v0;
 use crate::execution::validation::state_transition::common::validate_schema::v0::validate_schema_v0;
 
 pub(crate) trait StateTransitionStructureValidationV0 {
-    fn validate_structure_v0(&self) -> Result<SimpleConsensusValidationResult, Error>;
+    fn validate_structure_v0(
+        &self,
+        protocol_version: u32,
+    ) -> Result<SimpleConsensusValidationResult, Error>;
 }
 
 impl StateTransitionStructureValidationV0 for DataContractCreateTransition {
-    fn validate_structure_v0(&self) -> Result<SimpleConsensusValidationResult, Error> {
+    fn validate_structure_v0(
+        &self,
+        protocol_version: u32,
+    ) -> Result<SimpleConsensusValidationResult, Error> {
         let result = validate_schema_v0(&DATA_CONTRACT_CREATE_SCHEMA_VALIDATOR, self);
         if !result.is_valid() {
             return Ok(result);
@@ -49,6 +56,12 @@ impl StateTransitionStructureValidationV0 for DataContractCreateTransition {
             ));
         }
 
+        let result =
+            validate_documents_ttl_protocol_version_v0(&self.data_contract, protocol_version);
+        if !result.is_valid() {
+            return Ok(result);
+        }
+
         self.data_contract
             .validate_structure()
             .map_err(Error::Protocol)
@@ -3,6 +3,7 @@ use crate::execution::validation::state_transition::common::validate_state_trans
 use dpp::data_contract::state_transition::data_contract_update_transition::DataContractUpdateTransition;
 use dpp::identity::PartialIdentity;
 use dpp::prelude::ConsensusValidationResult;
+use dpp::prelude::TimestampMillis;
 use drive::drive::Drive;
 use drive::grovedb::TransactionArg;
 
@@ -10,6 +11,7 @@ pub(crate) trait StateTransitionIdentityAndSignaturesValidationV0 {
     fn validate_identity_and_signatures_v0(
         &self,
         drive: &Drive,
+        block_time_ms: TimestampMillis,
         transaction: TransactionArg,
     ) -> Result<ConsensusValidationResult<Option<PartialIdentity>>, Error>;
 }
@@ -18,11 +20,16 @@ impl StateTransitionIdentityAndSignaturesValidationV0 for DataContractUpdateTran
     fn validate_identity_and_signatures_v0(
         &self,
         drive: &Drive,
+        block_time_ms: TimestampMillis,
         transaction: TransactionArg,
     ) -> Result<ConsensusValidationResult<Option<PartialIdentity>>, Error> {
-        Ok(
-            validate_state_transition_identity_signature_v0(drive, self, false, transaction)?
-                .map(Some),
-        )
+        Ok(validate_state_transition_identity_signature_v0(
+            drive,
+            self,
+            false,
+            block_time_ms,
+            transaction,
+        )?
+        .map(Some))
     }
 }
@@ -5,6 +5,7 @@ mod structure;
 use dpp::identity::PartialIdentity;
 
 use dpp::data_contract::state_transition::data_contract_update_transition::DataContractUpdateTransition;
+use dpp::prelude::TimestampMillis;
 use dpp::validation::{ConsensusValidationResult, SimpleConsensusValidationResult};
 use drive::drive::Drive;
 use drive::grovedb::TransactionArg;
@@ -27,7 +28,8 @@ impl StateTransitionActionTransformerV0 for DataContractUpdateTransition {
         _tx: TransactionArg,
     ) -> Result<ConsensusValidationResult<StateTransitionAction>, Error> {
         //todo: use protocol version to determine validation
-        self.transform_into_action_v0()
+        // documents to backfill new indices with are only counted by state validation
+        self.transform_into_action_v0(false, 0)
     }
 }
 
@@ -35,21 +37,22 @@ impl StateTransitionValidationV0 for DataContractUpdateTransition {
     fn validate_structure(
         &self,
         _drive: &Drive,
-        _protocol_version: u32,
+        protocol_version: u32,
         _tx: TransactionArg,
     ) -> Result<SimpleConsensusValidationResult, Error> {
         //todo: use protocol version to determine validation
-        self.validate_structure_v0()
+        self.validate_structure_v0(protocol_version)
     }
 
     fn validate_identity_and_signatures(
         &self,
         drive: &Drive,
         _protocol_version: u32,
+        block_time_ms: TimestampMillis,
         transaction: TransactionArg,
     ) -> Result<ConsensusValidationResult<Option<PartialIdentity>>, Error> {
         //todo: use protocol version to determine validation
-        self.validate_identity_and_signatures_v0(drive, transaction)
+        self.validate_identity_and_signatures_v0(drive, block_time_ms, transaction)
     }
 
     fn validate_state<C: CoreRPCLike>(
@@ -187,6 +190,7 @@ mod tests {
                 state: &platform.state.read().unwrap(),
                 config: &platform.config,
                 core_rpc: &platform.core_rpc,
+                check_tx: false,
             };
 
             let result = state_transition
@@ -255,6 +259,7 @@ mod tests {
                 state: &platform.state.read().unwrap(),
                 config: &platform.config,
                 core_rpc: &platform.core_rpc,
+                check_tx: false,
             };
 
             let result = state_transition
@@ -373,6 +378,7 @@ mod tests {
                 state: &platform.state.read().unwrap(),
                 config: &platform.config,
                 core_rpc: &platform.core_rpc,
+                check_tx: false,
             };
 
             let result = state_transition
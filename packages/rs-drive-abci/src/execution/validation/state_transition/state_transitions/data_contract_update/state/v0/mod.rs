@@ -7,6 +7,7 @@ use dpp::consensus::basic::document::DataContractNotPresentError;
 use dpp::consensus::basic::BasicError;
 use dpp::consensus::state::data_contract::data_contract_config_update_error::DataContractConfigUpdateError;
 use dpp::consensus::state::data_contract::data_contract_is_readonly_error::DataContractIsReadonlyError;
+use dpp::consensus::state::data_contract::data_contract_too_many_documents_to_backfill_index_error::DataContractTooManyDocumentsToBackfillIndexError;
 use dpp::consensus::ConsensusError;
 use dpp::data_contract::contract_config::ContractConfig;
 use dpp::data_contract::state_transition::data_contract_update_transition::validation::basic::any_schema_changes;
@@ -35,6 +36,10 @@ use dpp::{
     state_transition::StateTransitionAction,
     Convertible, ProtocolError,
 };
+use drive::drive::defaults::{INDEX_BACKFILL_PROTOCOL_VERSION, MAX_INDEX_BACKFILL_DOCUMENTS};
+use drive::drive::document::index_backfill::indices_to_backfill;
+use drive::fee::calculate_fee;
+use drive::fee::credits::Credits;
 use drive::grovedb::TransactionArg;
 
 pub(crate) trait StateTransitionStateValidationV0 {
@@ -46,6 +51,8 @@ pub(crate) trait StateTransitionStateValidationV0 {
 
     fn transform_into_action_v0(
         &self,
+        backfill_indices: bool,
+        backfill_validation_processing_fee: Credits,
     ) -> Result<ConsensusValidationResult<StateTransitionAction>, Error>;
 }
 
@@ -243,22 +250,64 @@ impl StateTransitionStateValidationV0 for DataContractUpdateTransition {
         let new_documents = new_documents.as_object().ok_or_else(|| {
             ProtocolError::ParsingError("new documents is not a json object".to_owned())
         })?;
+        let backfill_indices =
+            platform.state.current_protocol_version_in_consensus >= INDEX_BACKFILL_PROTOCOL_VERSION;
         validation_result.merge(validate_indices_are_backward_compatible(
             existing_data_contract.documents.iter(),
             new_documents,
+            backfill_indices,
         )?);
         if !validation_result.is_valid() {
             return Ok(validation_result);
         }
+        if !backfill_indices {
+            return self.transform_into_action_v0(false, 0);
+        }
+
+        // new indices are built from the existing documents in this block, which is only
+        // allowed for document types with few enough documents
+        let mut drive_operations = vec![];
+        for (document_type_name, document_type) in self.data_contract.document_types.iter() {
+            let original_document_type = match existing_data_contract
+                .document_types
+                .get(document_type_name)
+            {
+                Some(original_document_type) => original_document_type,
+                None => continue,
+            };
+            let indices = indices_to_backfill(document_type, original_document_type);
+            if indices.is_empty()
+                || drive.can_backfill_indices(original_document_type, tx, &mut drive_operations)?
+            {
+                continue;
+            }
+            for index in indices {
+                validation_result.add_error(DataContractTooManyDocumentsToBackfillIndexError::new(
+                    document_type_name.clone(),
+                    index.name.clone(),
+                    MAX_INDEX_BACKFILL_DOCUMENTS,
+                ));
+            }
+        }
+        if !validation_result.is_valid() {
+            return Ok(validation_result);
+        }
+
+        let backfill_validation_processing_fee =
+            calculate_fee(None, Some(drive_operations), &platform.state.epoch())?.processing_fee;
 
-        self.transform_into_action_v0()
+        self.transform_into_action_v0(true, backfill_validation_processing_fee)
     }
 
     fn transform_into_action_v0(
         &self,
+        backfill_indices: bool,
+        backfill_validation_processing_fee: Credits,
     ) -> Result<ConsensusValidationResult<StateTransitionAction>, Error> {
-        let action: StateTransitionAction =
-            Into::<DataContractUpdateTransitionAction>::into(self).into();
+        let mut action: DataContractUpdateTransitionAction = self.into();
+        action.backfill_indices = backfill_indices;
+        action.backfill_validation_processing_fee = backfill_validation_processing_fee;
+        let action: StateTransitionAction = action.into();
         Ok(action.into())
     }
 }
@@ -12,6 +12,7 @@ use dpp::{
     validation::{ConsensusValidationResult, SimpleConsensusValidationResult},
 };
 
+use dpp::prelude::TimestampMillis;
 use drive::drive::Drive;
 use drive::grovedb::TransactionArg;
 
@@ -52,10 +53,11 @@ impl StateTransitionValidationV0 for DocumentsBatchTransition {
         &self,
         drive: &Drive,
         _protocol_version: u32,
+        block_time_ms: TimestampMillis,
         transaction: TransactionArg,
     ) -> Result<ConsensusValidationResult<Option<PartialIdentity>>, Error> {
         //todo: use protocol version to determine validation
-        self.validate_identity_and_signatures_v0(drive, transaction)
+        self.validate_identity_and_signatures_v0(drive, block_time_ms, transaction)
     }
 
     fn validate_state<C: CoreRPCLike>(
@@ -67,3 +69,88 @@ impl StateTransitionValidationV0 for DocumentsBatchTransition {
         self.validate_state_v0(platform, tx)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::StateTransitionValidationV0;
+    use crate::platform_types::platform::PlatformRef;
+    use crate::test::helpers::setup::TestPlatformBuilder;
+    use dpp::assert_state_consensus_errors;
+    use dpp::block::block_info::BlockInfo;
+    use dpp::consensus::state::state_error::StateError;
+    use dpp::document::document_transition::Action;
+    use dpp::document::DocumentsBatchTransition;
+    use dpp::errors::consensus::ConsensusError;
+    use dpp::identity::Identity;
+    use dpp::state_transition::StateTransitionType;
+    use dpp::tests::fixtures::{
+        get_data_contract_fixture, get_document_transitions_fixture, get_extended_documents_fixture,
+    };
+    use dpp::version::LATEST_VERSION;
+    use drive::drive::batch::transitions::DriveHighLevelOperationConverter;
+
+    #[test]
+    fn should_reject_a_replayed_batch() {
+        let platform = TestPlatformBuilder::new()
+            .build_with_mock_rpc()
+            .set_initial_state_structure();
+        let block_info = BlockInfo::default();
+
+        let identity = Identity::random_identity(3, Some(14));
+        let owner_id = identity.id;
+        platform
+            .drive
+            .add_new_identity(identity, &block_info, true, None)
+            .expect("expected to add an identity");
+
+        let data_contract = get_data_contract_fixture(None).data_contract;
+        platform
+            .drive
+            .apply_contract(&data_contract, block_info.clone(), true, None, None)
+            .expect("expected to apply the contract");
+
+        let mut document = get_extended_documents_fixture(data_contract)
+            .expect("expected documents")
+            .remove(0);
+        document.document.owner_id = owner_id;
+
+        let batch_transition = DocumentsBatchTransition {
+            protocol_version: LATEST_VERSION,
+            transition_type: StateTransitionType::DocumentsBatch,
+            owner_id,
+            transitions: get_document_transitions_fixture([(Action::Create, vec![document])]),
+            signature_public_key_id: None,
+            signature: None,
+        };
+
+        let platform_ref = PlatformRef {
+            drive: &platform.drive,
+            state: &platform.state.read().unwrap(),
+            config: &platform.config,
+            core_rpc: &platform.core_rpc,
+            check_tx: false,
+        };
+
+        let result = batch_transition
+            .validate_state(&platform_ref, None)
+            .expect("state transition to be validated");
+
+        assert!(result.is_valid());
+
+        let operations = result
+            .into_data()
+            .expect("expected an action")
+            .into_high_level_drive_operations(&block_info.epoch)
+            .expect("expected drive operations");
+        platform
+            .drive
+            .apply_drive_operations(operations, true, &block_info, None)
+            .expect("expected to apply the batch");
+
+        let result = batch_transition
+            .validate_state(&platform_ref, None)
+            .expect("state transition to be validated");
+
+        assert_state_consensus_errors!(result, StateError::InvalidIdentityContractNonceError, 1);
+    }
+}
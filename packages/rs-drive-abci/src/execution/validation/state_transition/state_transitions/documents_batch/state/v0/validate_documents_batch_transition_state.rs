@@ -1,5 +1,5 @@
 use std::collections::btree_map::Entry;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 
 use crate::error::Error;
 use crate::platform_types::platform::PlatformStateRef;
@@ -31,7 +31,7 @@ use dpp::{
         document_transition::{DocumentTransition, DocumentTransitionExt},
         DocumentsBatchTransition,
     },
-    prelude::{Identifier, TimestampMillis},
+    prelude::{Identifier, IdentityNonce, TimestampMillis},
     state_transition::{
         state_transition_execution_context::StateTransitionExecutionContext,
         StateTransitionIdentitySigned,
@@ -42,6 +42,7 @@ use dpp::{
 use drive::grovedb::TransactionArg;
 use crate::execution::validation::data_trigger::DataTriggerExecutionContext;
 use crate::execution::validation::state_transition::documents_batch::state::v0::fetch_documents::fetch_documents_for_transitions_knowing_contract_and_document_type;
+use crate::execution::validation::state_transition::common::validate_identity_nonce::v0::validate_identity_contract_nonce_v0;
 
 pub(crate) fn validate_document_batch_transition_state(
     bypass_validation: bool,
@@ -134,6 +135,32 @@ fn validate_document_transitions_within_contract(
 
     let data_contract = &contract_fetch_info.contract;
 
+    if !bypass_validation {
+        // All transitions for a contract share the nonce, so differing nonces can't all be valid
+        let identity_contract_nonces: BTreeSet<IdentityNonce> = document_transitions
+            .values()
+            .flatten()
+            .map(|transition| transition.base().identity_contract_nonce)
+            .collect();
+
+        for nonce in identity_contract_nonces {
+            let nonce_validation_result = validate_identity_contract_nonce_v0(
+                owner_id,
+                *data_contract_id,
+                nonce,
+                platform.check_tx,
+                drive,
+                transaction,
+            )?;
+
+            if !nonce_validation_result.is_valid() {
+                return Ok(ConsensusValidationResult::new_with_errors(
+                    nonce_validation_result.errors,
+                ));
+            }
+        }
+    }
+
     let validation_result = document_transitions
         .iter()
         .map(|(document_type_name, document_transitions)| {
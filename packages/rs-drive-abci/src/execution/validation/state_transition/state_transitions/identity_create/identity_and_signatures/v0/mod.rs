@@ -1,4 +1,5 @@
 use crate::error::Error;
+use crate::execution::validation::state_transition::common::verify_signature_with_cache::v0::verify_signature_with_cache_v0;
 use dpp::consensus::basic::identity::IdentityAssetLockTransactionOutputNotFoundError;
 use dpp::consensus::basic::invalid_identifier_error::InvalidIdentifierError;
 use dpp::consensus::basic::BasicError;
@@ -6,22 +7,28 @@ use dpp::consensus::ConsensusError;
 use dpp::identity::state_transition::identity_create_transition::IdentityCreateTransition;
 use dpp::identity::PartialIdentity;
 use dpp::prelude::ConsensusValidationResult;
-use dpp::serialization_traits::{PlatformMessageSignable, Signable};
+use dpp::serialization_traits::Signable;
+use drive::drive::Drive;
 
 pub(crate) trait StateTransitionIdentityAndSignaturesValidationV0 {
     fn validate_identity_and_signatures_v0(
         &self,
+        drive: &Drive,
     ) -> Result<ConsensusValidationResult<Option<PartialIdentity>>, Error>;
 }
 
 impl StateTransitionIdentityAndSignaturesValidationV0 for IdentityCreateTransition {
     fn validate_identity_and_signatures_v0(
         &self,
+        drive: &Drive,
     ) -> Result<ConsensusValidationResult<Option<PartialIdentity>>, Error> {
         let mut validation_result = ConsensusValidationResult::<Option<PartialIdentity>>::default();
         let bytes: Vec<u8> = self.signable_bytes()?;
         for key in self.public_keys.iter() {
-            let result = bytes.as_slice().verify_signature(
+            let result = verify_signature_with_cache_v0(
+                drive,
+                bytes.as_slice(),
+                key.id,
                 key.key_type,
                 key.data.as_slice(),
                 key.signature.as_slice(),
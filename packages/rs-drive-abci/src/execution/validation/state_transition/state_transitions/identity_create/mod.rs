@@ -16,6 +16,7 @@ use dpp::identity::PartialIdentity;
 use dpp::prelude::ConsensusValidationResult;
 use dpp::state_transition::StateTransitionAction;
 use dpp::validation::SimpleConsensusValidationResult;
+use dpp::prelude::TimestampMillis;
 use drive::drive::Drive;
 use drive::grovedb::TransactionArg;
 
@@ -43,12 +44,13 @@ impl StateTransitionValidationV0 for IdentityCreateTransition {
 
     fn validate_identity_and_signatures(
         &self,
-        _drive: &Drive,
+        drive: &Drive,
         _protocol_version: u32,
+        _block_time_ms: TimestampMillis,
         _tx: TransactionArg,
     ) -> Result<ConsensusValidationResult<Option<PartialIdentity>>, Error> {
         //todo: use protocol version to determine validation
-        self.validate_identity_and_signatures_v0()
+        self.validate_identity_and_signatures_v0(drive)
     }
 
     fn validate_state<C: CoreRPCLike>(
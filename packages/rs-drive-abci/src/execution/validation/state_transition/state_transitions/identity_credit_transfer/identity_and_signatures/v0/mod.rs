@@ -1,9 +1,11 @@
 use crate::error::Error;
 
-use dpp::consensus::signature::{IdentityNotFoundError, SignatureError};
 use dpp::identity::state_transition::identity_credit_transfer_transition::IdentityCreditTransferTransition;
 use dpp::identity::PartialIdentity;
 use dpp::prelude::ConsensusValidationResult;
+
+use crate::execution::validation::state_transition::common::validate_state_transition_identity_signature::v0::validate_state_transition_identity_signature_v0;
+use dpp::prelude::TimestampMillis;
 use drive::drive::Drive;
 use drive::grovedb::TransactionArg;
 
@@ -11,7 +13,8 @@ pub(crate) trait StateTransitionIdentityAndSignaturesValidationV0 {
     fn validate_identity_and_signatures_v0(
         &self,
         drive: &Drive,
-        tx: TransactionArg,
+        block_time_ms: TimestampMillis,
+        transaction: TransactionArg,
     ) -> Result<ConsensusValidationResult<Option<PartialIdentity>>, Error>;
 }
 
@@ -19,24 +22,16 @@ impl StateTransitionIdentityAndSignaturesValidationV0 for IdentityCreditTransfer
     fn validate_identity_and_signatures_v0(
         &self,
         drive: &Drive,
-        tx: TransactionArg,
+        block_time_ms: TimestampMillis,
+        transaction: TransactionArg,
     ) -> Result<ConsensusValidationResult<Option<PartialIdentity>>, Error> {
-        let mut validation_result = ConsensusValidationResult::<Option<PartialIdentity>>::default();
-
-        let maybe_partial_identity =
-            drive.fetch_identity_with_balance(self.identity_id.to_buffer(), tx)?;
-
-        let partial_identity = match maybe_partial_identity {
-            None => {
-                validation_result.add_error(SignatureError::IdentityNotFoundError(
-                    IdentityNotFoundError::new(self.identity_id),
-                ));
-                return Ok(validation_result);
-            }
-            Some(partial_identity) => partial_identity,
-        };
-
-        validation_result.set_data(Some(partial_identity));
-        Ok(validation_result)
+        Ok(validate_state_transition_identity_signature_v0(
+            drive,
+            self,
+            false,
+            block_time_ms,
+            transaction,
+        )?
+        .map(Some))
     }
 }
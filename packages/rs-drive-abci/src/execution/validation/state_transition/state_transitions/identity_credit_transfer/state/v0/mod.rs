@@ -1,4 +1,5 @@
 use crate::error::Error;
+use crate::execution::validation::state_transition::common::validate_identity_nonce::v0::validate_identity_nonce_v0;
 use crate::platform_types::platform::PlatformRef;
 use crate::rpc::core::CoreRPCLike;
 
@@ -39,23 +40,46 @@ impl StateTransitionStateValidationV0 for IdentityCreditTransferTransition {
             return Ok(ConsensusValidationResult::new_with_error(IdentityNotFoundError::new(self.identity_id).into()));
         };
 
-        if existing_identity_balance < self.amount {
+        let nonce_validation_result = validate_identity_nonce_v0(
+            self.identity_id,
+            self.nonce,
+            platform.check_tx,
+            platform.drive,
+            tx,
+        )?;
+
+        if !nonce_validation_result.is_valid() {
+            return Ok(ConsensusValidationResult::new_with_errors(
+                nonce_validation_result.errors,
+            ));
+        }
+
+        let Some(total_amount) = self.get_total_amount() else {
             return Ok(ConsensusValidationResult::new_with_error(
                 IdentityInsufficientBalanceError::new(self.identity_id, existing_identity_balance)
                     .into(),
             ));
-        }
-
-        let maybe_existing_recipient = platform
-            .drive
-            .fetch_identity_balance(self.recipient_id.to_buffer(), tx)?;
+        };
 
-        if maybe_existing_recipient.is_none() {
+        if existing_identity_balance < total_amount {
             return Ok(ConsensusValidationResult::new_with_error(
-                IdentityNotFoundError::new(self.recipient_id).into(),
+                IdentityInsufficientBalanceError::new(self.identity_id, existing_identity_balance)
+                    .into(),
             ));
         }
 
+        for recipient in self.get_recipients() {
+            let maybe_existing_recipient = platform
+                .drive
+                .fetch_identity_balance(recipient.recipient_id.to_buffer(), tx)?;
+
+            if maybe_existing_recipient.is_none() {
+                return Ok(ConsensusValidationResult::new_with_error(
+                    IdentityNotFoundError::new(recipient.recipient_id).into(),
+                ));
+            }
+        }
+
         self.transform_into_action_v0()
     }
 
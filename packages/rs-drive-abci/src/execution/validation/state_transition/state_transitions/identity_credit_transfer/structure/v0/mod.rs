@@ -1,5 +1,8 @@
 use dpp::identity::state_transition::identity_credit_transfer_transition::IdentityCreditTransferTransition;
-use dpp::identity::state_transition::identity_credit_transfer_transition::validation::basic::identity_credit_transfer_basic::IDENTITY_CREDIT_TRANSFER_TRANSITION_SCHEMA_VALIDATOR;
+use dpp::identity::state_transition::identity_credit_transfer_transition::validation::basic::identity_credit_transfer_basic::{
+    validate_identity_credit_transfer_recipients,
+    IDENTITY_CREDIT_TRANSFER_TRANSITION_SCHEMA_VALIDATOR,
+};
 use dpp::validation::SimpleConsensusValidationResult;
 use crate::error::Error;
 use crate::execution::validation::state_transition::common::validate_protocol_version::v0::validate_protocol_version_v0;
@@ -17,6 +20,14 @@ impl StateTransitionStructureValidationV0 for IdentityCreditTransferTransition {
             return Ok(result);
         }
 
-        Ok(validate_protocol_version_v0(self.protocol_version))
+        let result = validate_protocol_version_v0(self.protocol_version);
+        if !result.is_valid() {
+            return Ok(result);
+        }
+
+        Ok(validate_identity_credit_transfer_recipients(
+            &self.identity_id,
+            &self.recipients,
+        ))
     }
 }
@@ -5,6 +5,7 @@ use dpp::identity::PartialIdentity;
 use dpp::prelude::ConsensusValidationResult;
 
 use crate::execution::validation::state_transition::common::validate_state_transition_identity_signature::v0::validate_state_transition_identity_signature_v0;
+use dpp::prelude::TimestampMillis;
 use drive::drive::Drive;
 use drive::grovedb::TransactionArg;
 
@@ -12,6 +13,7 @@ pub(crate) trait StateTransitionIdentityAndSignaturesValidationV0 {
     fn validate_identity_and_signatures_v0(
         &self,
         drive: &Drive,
+        block_time_ms: TimestampMillis,
         transaction: TransactionArg,
     ) -> Result<ConsensusValidationResult<Option<PartialIdentity>>, Error>;
 }
@@ -20,11 +22,16 @@ impl StateTransitionIdentityAndSignaturesValidationV0 for IdentityCreditWithdraw
     fn validate_identity_and_signatures_v0(
         &self,
         drive: &Drive,
+        block_time_ms: TimestampMillis,
         transaction: TransactionArg,
     ) -> Result<ConsensusValidationResult<Option<PartialIdentity>>, Error> {
-        Ok(
-            validate_state_transition_identity_signature_v0(drive, self, false, transaction)?
-                .map(Some),
-        )
+        Ok(validate_state_transition_identity_signature_v0(
+            drive,
+            self,
+            false,
+            block_time_ms,
+            transaction,
+        )?
+        .map(Some))
     }
 }
@@ -9,6 +9,7 @@ use dpp::{
     validation::{ConsensusValidationResult, SimpleConsensusValidationResult},
 };
 
+use dpp::prelude::TimestampMillis;
 use drive::drive::Drive;
 use drive::grovedb::TransactionArg;
 
@@ -49,10 +50,11 @@ impl StateTransitionValidationV0 for IdentityCreditWithdrawalTransition {
         &self,
         drive: &Drive,
         _protocol_version: u32,
+        block_time_ms: TimestampMillis,
         transaction: TransactionArg,
     ) -> Result<ConsensusValidationResult<Option<PartialIdentity>>, Error> {
         //todo: use protocol version to determine validation
-        self.validate_identity_and_signatures_v0(drive, transaction)
+        self.validate_identity_and_signatures_v0(drive, block_time_ms, transaction)
     }
 
     fn validate_state<C: CoreRPCLike>(
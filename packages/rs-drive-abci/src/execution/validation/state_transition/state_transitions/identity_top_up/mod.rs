@@ -4,6 +4,7 @@ mod structure;
 
 use dpp::identity::PartialIdentity;
 
+use dpp::prelude::TimestampMillis;
 use dpp::{
     identity::state_transition::identity_topup_transition::IdentityTopUpTransition,
     state_transition::StateTransitionAction,
@@ -49,6 +50,7 @@ impl StateTransitionValidationV0 for IdentityTopUpTransition {
         &self,
         drive: &Drive,
         _protocol_version: u32,
+        _block_time_ms: TimestampMillis,
         tx: TransactionArg,
     ) -> Result<ConsensusValidationResult<Option<PartialIdentity>>, Error> {
         //todo: use protocol version to determine validation
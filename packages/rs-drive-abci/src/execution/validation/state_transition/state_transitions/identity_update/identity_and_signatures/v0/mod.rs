@@ -5,10 +5,12 @@ use dpp::consensus::state::identity::invalid_identity_revision_error::InvalidIde
 use dpp::consensus::state::state_error::StateError;
 
 use crate::execution::validation::state_transition::common::validate_state_transition_identity_signature::v0::validate_state_transition_identity_signature_v0;
+use crate::execution::validation::state_transition::common::verify_signature_with_cache::v0::verify_signature_with_cache_v0;
 use dpp::identity::state_transition::identity_update_transition::identity_update_transition::IdentityUpdateTransition;
 use dpp::identity::PartialIdentity;
 use dpp::prelude::ConsensusValidationResult;
-use dpp::serialization_traits::{PlatformMessageSignable, Signable};
+use dpp::prelude::TimestampMillis;
+use dpp::serialization_traits::Signable;
 use drive::drive::Drive;
 use drive::grovedb::TransactionArg;
 
@@ -16,6 +18,7 @@ pub(crate) trait StateTransitionIdentityAndSignaturesValidationV0 {
     fn validate_identity_and_signatures_v0(
         &self,
         drive: &Drive,
+        block_time_ms: TimestampMillis,
         transaction: TransactionArg,
     ) -> Result<ConsensusValidationResult<Option<PartialIdentity>>, Error>;
 }
@@ -24,13 +27,17 @@ impl StateTransitionIdentityAndSignaturesValidationV0 for IdentityUpdateTransiti
     fn validate_identity_and_signatures_v0(
         &self,
         drive: &Drive,
+        block_time_ms: TimestampMillis,
         transaction: TransactionArg,
     ) -> Result<ConsensusValidationResult<Option<PartialIdentity>>, Error> {
         let mut result = ConsensusValidationResult::<Option<PartialIdentity>>::default();
 
         let bytes: Vec<u8> = self.signable_bytes()?;
         for key in self.add_public_keys.iter() {
-            let validation_result = bytes.as_slice().verify_signature(
+            let validation_result = verify_signature_with_cache_v0(
+                drive,
+                bytes.as_slice(),
+                key.id,
                 key.key_type,
                 key.data.as_slice(),
                 key.signature.as_slice(),
@@ -40,8 +47,13 @@ impl StateTransitionIdentityAndSignaturesValidationV0 for IdentityUpdateTransiti
             }
         }
 
-        let validation_result =
-            validate_state_transition_identity_signature_v0(drive, self, true, transaction)?;
+        let validation_result = validate_state_transition_identity_signature_v0(
+            drive,
+            self,
+            true,
+            block_time_ms,
+            transaction,
+        )?;
 
         if !validation_result.is_valid() {
             result.merge(validation_result);
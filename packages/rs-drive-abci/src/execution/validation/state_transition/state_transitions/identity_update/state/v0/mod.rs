@@ -6,6 +6,7 @@ use crate::rpc::core::CoreRPCLike;
 
 use dpp::block_time_window::validate_time_in_block_time_window::validate_time_in_block_time_window;
 use dpp::consensus::state::identity::identity_public_key_disabled_at_window_violation_error::IdentityPublicKeyDisabledAtWindowViolationError;
+use dpp::consensus::state::identity::identity_public_key_valid_until_in_past_error::IdentityPublicKeyValidUntilInPastError;
 use dpp::consensus::state::state_error::StateError;
 
 use dpp::identity::state_transition::identity_update_transition::identity_update_transition::IdentityUpdateTransition;
@@ -116,6 +117,63 @@ impl StateTransitionStateValidationV0 for IdentityUpdateTransition {
                 }
             }
         }
+
+        if !self.expire_public_keys.is_empty() {
+            // We need to validate that all keys to expire exist
+            validation_result.add_errors(
+                validate_identity_public_key_ids_exist_in_state_v0(
+                    self.identity_id,
+                    self.expire_public_keys.clone(),
+                    drive,
+                    tx,
+                )?
+                .errors,
+            );
+
+            if !validation_result.is_valid() {
+                return Ok(validation_result);
+            }
+        }
+
+        let scheduled_valid_until = if self.expire_public_keys.is_empty() {
+            None
+        } else {
+            self.public_keys_valid_until
+        };
+
+        let mut valid_until_times = self
+            .add_public_keys
+            .iter()
+            .filter_map(|key| key.valid_until)
+            .chain(scheduled_valid_until)
+            .peekable();
+
+        if valid_until_times.peek().is_some() {
+            // Keys can only be scheduled to expire in the future
+            let last_block_time = platform.state.last_block_time_ms().ok_or(Error::Execution(
+                ExecutionError::StateNotInitialized(
+                    "expected a last platform block during identity update validation",
+                ),
+            ))?;
+
+            for valid_until in valid_until_times {
+                if valid_until <= last_block_time {
+                    validation_result.add_error(
+                        StateError::IdentityPublicKeyValidUntilInPastError(
+                            IdentityPublicKeyValidUntilInPastError::new(
+                                valid_until,
+                                last_block_time,
+                            ),
+                        ),
+                    );
+                }
+            }
+
+            if !validation_result.is_valid() {
+                return Ok(validation_result);
+            }
+        }
+
         self.transform_into_action_v0()
     }
 
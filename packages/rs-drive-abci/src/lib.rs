@@ -32,6 +32,8 @@ pub mod rpc;
 pub mod core;
 /// Metrics subsystem
 pub mod metrics;
+/// Offline maintenance of the data storage
+pub mod maintenance;
 /// Test helpers and fixtures
 pub mod test;
 
@@ -3,13 +3,20 @@
 //! RS-Drive-ABCI server starts a single-threaded server and listens to connections from Tenderdash.
 
 use clap::{Parser, Subcommand};
+use dpp::platform_value::string_encoding::Encoding;
+use dpp::platform_value::Identifier;
 use drive_abci::config::{FromEnv, PlatformConfig};
 use drive_abci::core::wait_for_core_to_sync::v0::wait_for_core_to_sync_v0;
 use drive_abci::logging::{LogBuilder, LogConfig, Loggers};
+use drive_abci::maintenance::archive::import_archive;
+use drive_abci::maintenance::checkpoints::{list_checkpoints, rollback_to_checkpoint};
 use drive_abci::metrics::{Prometheus, DEFAULT_PROMETHEUS_PORT};
+use drive_abci::platform_types::platform::Platform;
 use drive_abci::rpc::core::DefaultCoreRPC;
 use itertools::Itertools;
-use std::path::PathBuf;
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
+use std::path::{Path, PathBuf};
 use std::process::ExitCode;
 use tokio::runtime::Builder;
 use tokio::signal::unix::{signal, SignalKind};
@@ -35,6 +42,88 @@ enum Commands {
     /// Returns 0 on success.
     #[command()]
     Status,
+
+    /// Verify the data storage.
+    ///
+    /// Recomputes the GroveDB root hash and compares it with the app hash of the last committed
+    /// and attested block, and checks that identity balances and distribution pools add up to
+    /// the system credits. Returns 0 if the data storage is valid.
+    ///
+    /// The server must not be running.
+    #[command()]
+    Verify,
+
+    /// Print stored platform data.
+    ///
+    /// The server must not be running.
+    #[command(subcommand)]
+    Inspect(InspectCommands),
+
+    /// Replace the data storage with a checkpoint.
+    ///
+    /// Checkpoints are created every CHECKPOINT_INTERVAL blocks. Tenderdash has to be rolled back
+    /// to the same height. The server must not be running.
+    #[command()]
+    Rollback {
+        /// Height of the checkpoint to roll back to
+        #[arg(long)]
+        height: u64,
+    },
+
+    /// Export the data storage and the platform state to an archive.
+    ///
+    /// The server must not be running.
+    #[command()]
+    Export {
+        /// Path of the archive to create
+        #[arg(long, value_hint = clap::ValueHint::FilePath)]
+        output: PathBuf,
+    },
+
+    /// Import an archive created with `export` into an empty data storage.
+    ///
+    /// The root hash of the imported data storage is verified against the exported one.
+    #[command()]
+    Import {
+        /// Path of the archive to import
+        #[arg(long, value_hint = clap::ValueHint::FilePath)]
+        input: PathBuf,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+enum InspectCommands {
+    /// Print the platform state.
+    #[command()]
+    State,
+    /// Print the start time and height of the epochs.
+    #[command()]
+    Epochs,
+    /// Print the votes for protocol versions in the current upgrade window.
+    #[command()]
+    Votes,
+    /// Print the ids of the data contracts.
+    #[command()]
+    Contracts,
+    /// Print the checkpoints heights.
+    #[command()]
+    Checkpoints,
+    /// Print an identity.
+    #[command()]
+    Identity {
+        /// Base58 encoded identity id
+        id: String,
+    },
+    /// Print a document.
+    #[command()]
+    Document {
+        /// Base58 encoded data contract id
+        contract_id: String,
+        /// Document type name
+        document_type: String,
+        /// Base58 encoded document id
+        id: String,
+    },
 }
 
 /// Server that accepts connections from Tenderdash, and
@@ -95,6 +184,11 @@ impl Cli {
             }
             Commands::Config => dump_config(&config)?,
             Commands::Status => check_status(&config)?,
+            Commands::Verify => verify(&config)?,
+            Commands::Inspect(command) => inspect(&config, command)?,
+            Commands::Rollback { height } => rollback(&config, height)?,
+            Commands::Export { output } => export(&config, &output)?,
+            Commands::Import { input } => import(&config, &input)?,
         };
 
         Ok(())
@@ -228,6 +322,145 @@ fn check_status(config: &PlatformConfig) -> Result<(), String> {
     }
 }
 
+/// Open the platform with the data storage at the configured path, for maintenance commands
+fn open_platform(config: &PlatformConfig) -> Result<Platform<DefaultCoreRPC>, String> {
+    Platform::<DefaultCoreRPC>::open(&config.db_path, Some(config.clone()))
+        .map_err(|e| e.to_string())
+}
+
+/// Verify the data storage.
+fn verify(config: &PlatformConfig) -> Result<(), String> {
+    let verification = open_platform(config)?
+        .verify_stored_state()
+        .map_err(|e| e.to_string())?;
+
+    println!("{}", verification);
+
+    if verification.is_valid() {
+        Ok(())
+    } else {
+        Err("data storage verification failed".to_string())
+    }
+}
+
+/// Print stored platform data.
+fn inspect(config: &PlatformConfig, command: InspectCommands) -> Result<(), String> {
+    let parse_identifier = |id: &str| {
+        Identifier::from_string(id, Encoding::Base58)
+            .map_err(|e| format!("invalid identifier {}: {}", id, e))
+    };
+
+    match command {
+        InspectCommands::State => println!("{:#?}", open_platform(config)?.inspect_state()),
+        InspectCommands::Epochs => {
+            for epoch in open_platform(config)?
+                .inspect_epochs()
+                .map_err(|e| e.to_string())?
+            {
+                println!(
+                    "epoch {}: start time {} ms, start height {}",
+                    epoch.index, epoch.start_time_ms, epoch.start_block_height
+                );
+            }
+        }
+        InspectCommands::Votes => {
+            let votes = open_platform(config)?
+                .inspect_protocol_version_votes()
+                .map_err(|e| e.to_string())?;
+            for (protocol_version, count) in votes {
+                println!("protocol version {}: {} votes", protocol_version, count);
+            }
+        }
+        InspectCommands::Contracts => {
+            let contract_ids = open_platform(config)?
+                .inspect_contracts()
+                .map_err(|e| e.to_string())?;
+            for contract_id in contract_ids {
+                println!("{}", contract_id);
+            }
+        }
+        InspectCommands::Checkpoints => {
+            for height in list_checkpoints(config).map_err(|e| e.to_string())? {
+                println!("{}", height);
+            }
+        }
+        InspectCommands::Identity { id } => {
+            let identity = open_platform(config)?
+                .inspect_identity(parse_identifier(&id)?)
+                .map_err(|e| e.to_string())?
+                .ok_or(format!("identity {} not found", id))?;
+            println!("{:#?}", identity);
+        }
+        InspectCommands::Document {
+            contract_id,
+            document_type,
+            id,
+        } => {
+            let document = open_platform(config)?
+                .inspect_document(
+                    parse_identifier(&contract_id)?,
+                    &document_type,
+                    parse_identifier(&id)?,
+                )
+                .map_err(|e| e.to_string())?
+                .ok_or(format!("document {} not found", id))?;
+            println!("{:#?}", document);
+        }
+    };
+
+    Ok(())
+}
+
+/// Replace the data storage with a checkpoint.
+fn rollback(config: &PlatformConfig, height: u64) -> Result<(), String> {
+    let replaced_path = rollback_to_checkpoint(config, height).map_err(|e| e.to_string())?;
+
+    println!(
+        "rolled back to height {}, the replaced data storage was moved to {}",
+        height,
+        replaced_path.display()
+    );
+
+    Ok(())
+}
+
+/// Export the data storage and the platform state to an archive.
+fn export(config: &PlatformConfig, output: &Path) -> Result<(), String> {
+    let platform = open_platform(config)?;
+    let file = File::create(output).map_err(|e| format!("{}: {}", output.display(), e))?;
+
+    let root_hash = platform
+        .export_archive(BufWriter::new(file))
+        .map_err(|e| e.to_string())?;
+
+    println!(
+        "exported state with root hash {} to {}",
+        hex::encode(root_hash),
+        output.display()
+    );
+
+    Ok(())
+}
+
+/// Import an archive into an empty data storage.
+fn import(config: &PlatformConfig, input: &Path) -> Result<(), String> {
+    let file = File::open(input).map_err(|e| format!("{}: {}", input.display(), e))?;
+
+    let platform_state = import_archive(BufReader::new(file), config).map_err(|e| e.to_string())?;
+
+    match platform_state.last_committed_block_info {
+        Some(block_info) => println!(
+            "imported state at height {} with app hash {} to {}",
+            block_info.basic_info.height,
+            hex::encode(block_info.app_hash),
+            config.db_path.display()
+        ),
+        None => println!("imported genesis state to {}", config.db_path.display()),
+    }
+
+    Ok(())
+}
+
 fn load_config(path: &Option<PathBuf>) -> PlatformConfig {
     if let Some(path) = path {
         if let Err(e) = dotenvy::from_path(path) {
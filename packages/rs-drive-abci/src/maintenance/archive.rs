@@ -0,0 +1,100 @@
+use crate::config::PlatformConfig;
+use crate::error::Error;
+use crate::maintenance::Error as MaintenanceError;
+use crate::platform_types::platform::Platform;
+use crate::platform_types::platform_state::v0::PlatformState;
+use dpp::serialization_traits::{PlatformDeserializable, PlatformSerializable};
+use drive::drive::Drive;
+use std::io::{Read, Write};
+
+impl<C> Platform<C> {
+    /// Exports the committed data storage and the platform state to an archive
+    ///
+    /// Returns the root hash of the exported data storage.
+    pub fn export_archive<W: Write>(&self, writer: W) -> Result<[u8; 32], Error> {
+        let serialized_platform_state = self.state.read().unwrap().serialize()?;
+
+        Ok(self.drive.export(writer, &serialized_platform_state)?)
+    }
+}
+
+/// Imports an archive created with [`Platform::export_archive`] into the data storage path,
+/// which must be empty
+///
+/// Besides the verifications of Drive, the app hash of the last block committed in the platform
+/// state of the archive is compared with the root hash of the imported data storage.
+pub fn import_archive<R: Read>(reader: R, config: &PlatformConfig) -> Result<PlatformState, Error> {
+    let (_, archive_info) = Drive::import(reader, &config.db_path, Some(config.drive.clone()))?;
+
+    let platform_state = PlatformState::deserialize(&archive_info.platform_state)?;
+
+    if let Some(block_info) = &platform_state.last_committed_block_info {
+        if block_info.app_hash != archive_info.root_hash {
+            return Err(MaintenanceError::AppHashMismatch(
+                hex::encode(block_info.app_hash),
+                hex::encode(archive_info.root_hash),
+            )
+            .into());
+        }
+    }
+
+    Ok(platform_state)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::helpers::setup::TestPlatformBuilder;
+    use tempfile::TempDir;
+
+    #[test]
+    fn should_export_and_import_archives() {
+        let platform = TestPlatformBuilder::new()
+            .with_config(PlatformConfig::default())
+            .build_with_mock_rpc();
+
+        platform
+            .create_genesis_state_v0(0, platform.config.abci.keys.clone().into(), None)
+            .expect("expected to create genesis state");
+
+        let mut archive = vec![];
+        let root_hash = platform
+            .export_archive(&mut archive)
+            .expect("expected to export archive");
+
+        let db_dir = TempDir::new().expect("expected a temporary directory");
+        let config = PlatformConfig {
+            db_path: db_dir.path().join("imported"),
+            ..platform.config.clone()
+        };
+
+        let platform_state =
+            import_archive(archive.as_slice(), &config).expect("expected to import archive");
+
+        assert_eq!(
+            platform_state.current_protocol_version_in_consensus,
+            platform
+                .state
+                .read()
+                .unwrap()
+                .current_protocol_version_in_consensus
+        );
+
+        let imported_drive = Drive::open(&config.db_path, None).expect("expected to open drive");
+        assert_eq!(
+            imported_drive
+                .grove
+                .root_hash(None)
+                .unwrap()
+                .expect("expected a root hash"),
+            root_hash
+        );
+
+        assert!(matches!(
+            import_archive(archive.as_slice(), &config),
+            Err(Error::Drive(drive::error::Error::Drive(
+                drive::error::drive::DriveError::ImportDestinationNotEmpty(_)
+            )))
+        ));
+    }
+}
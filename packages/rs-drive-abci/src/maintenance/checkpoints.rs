@@ -0,0 +1,171 @@
+use crate::config::PlatformConfig;
+use crate::error::Error;
+use crate::maintenance::Error as MaintenanceError;
+use crate::platform_types::platform::Platform;
+use drive::error::Error::GroveDB;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Directory holding the checkpoints, one sub directory named after the block height per checkpoint
+pub fn checkpoints_dir(config: &PlatformConfig) -> PathBuf {
+    config
+        .checkpoints_path
+        .clone()
+        .unwrap_or_else(|| config.db_path.with_file_name("checkpoints"))
+}
+
+/// Path of the checkpoint created at a block height
+pub fn checkpoint_path(config: &PlatformConfig, height: u64) -> PathBuf {
+    checkpoints_dir(config).join(height.to_string())
+}
+
+/// Heights of the stored checkpoints, in ascending order
+pub fn list_checkpoints(config: &PlatformConfig) -> Result<Vec<u64>, Error> {
+    let dir = checkpoints_dir(config);
+    if !dir.exists() {
+        return Ok(vec![]);
+    }
+
+    let mut heights = fs::read_dir(&dir)
+        .map_err(|e| MaintenanceError::Io(dir.clone(), e))?
+        .filter_map(|entry| {
+            entry
+                .ok()
+                .and_then(|entry| entry.file_name().to_str()?.parse::<u64>().ok())
+        })
+        .collect::<Vec<_>>();
+    heights.sort_unstable();
+
+    Ok(heights)
+}
+
+/// Replaces the data storage with the checkpoint created at a block height
+///
+/// The replaced data storage is moved next to it rather than deleted, and the checkpoints created
+/// after the height are removed since they belong to the abandoned chain. Tenderdash has to be
+/// rolled back to the same height.
+///
+/// Returns the path the replaced data storage was moved to.
+pub fn rollback_to_checkpoint(config: &PlatformConfig, height: u64) -> Result<PathBuf, Error> {
+    let checkpoint = checkpoint_path(config, height);
+    if !checkpoint.is_dir() {
+        return Err(MaintenanceError::CheckpointNotFound(height).into());
+    }
+
+    let db_path = &config.db_path;
+    let mut replaced_path = db_path.clone().into_os_string();
+    replaced_path.push(".replaced");
+    let replaced_path = PathBuf::from(replaced_path);
+
+    if replaced_path.exists() {
+        remove_dir(&replaced_path)?;
+    }
+    if db_path.exists() {
+        fs::rename(db_path, &replaced_path)
+            .map_err(|e| MaintenanceError::Io(db_path.clone(), e))?;
+    }
+    fs::rename(&checkpoint, db_path).map_err(|e| MaintenanceError::Io(checkpoint.clone(), e))?;
+
+    for later_height in list_checkpoints(config)?
+        .into_iter()
+        .filter(|checkpoint_height| *checkpoint_height > height)
+    {
+        remove_dir(&checkpoint_path(config, later_height))?;
+    }
+
+    Ok(replaced_path)
+}
+
+fn remove_dir(path: &Path) -> Result<(), Error> {
+    fs::remove_dir_all(path).map_err(|e| MaintenanceError::Io(path.to_path_buf(), e).into())
+}
+
+impl<C> Platform<C> {
+    /// Creates a checkpoint of the committed data storage if the block height is a multiple of
+    /// the configured checkpoint interval, and removes the checkpoints above the number to keep
+    ///
+    /// Checkpoints are hard links to the data storage files, cheap to create as long as they are
+    /// on the same file system.
+    pub fn create_checkpoint_if_needed(&self, height: u64) -> Result<(), Error> {
+        let interval = self.config.checkpoint_interval;
+        if interval == 0 || height % interval != 0 {
+            return Ok(());
+        }
+
+        let dir = checkpoints_dir(&self.config);
+        fs::create_dir_all(&dir).map_err(|e| MaintenanceError::Io(dir.clone(), e))?;
+
+        let path = checkpoint_path(&self.config, height);
+        if path.exists() {
+            // Left by a previous run that was rolled back, or that crashed before committing
+            remove_dir(&path)?;
+        }
+        self.drive
+            .grove
+            .create_checkpoint(&path)
+            .map_err(|e| Error::Drive(GroveDB(e)))?;
+
+        let heights = list_checkpoints(&self.config)?;
+        let to_remove = heights
+            .len()
+            .saturating_sub(self.config.checkpoints_to_keep.max(1));
+        for old_height in heights.into_iter().take(to_remove) {
+            remove_dir(&checkpoint_path(&self.config, old_height))?;
+        }
+
+        tracing::debug!(height, path = %path.display(), "created checkpoint");
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::helpers::setup::TestPlatformBuilder;
+    use tempfile::TempDir;
+
+    #[test]
+    fn should_create_checkpoints_and_roll_back_to_them() {
+        let checkpoints_dir = TempDir::new().expect("expected a temporary directory");
+        let config = PlatformConfig {
+            checkpoints_path: Some(checkpoints_dir.path().to_path_buf()),
+            checkpoint_interval: 10,
+            checkpoints_to_keep: 2,
+            ..Default::default()
+        };
+
+        let platform = TestPlatformBuilder::new()
+            .with_config(config)
+            .build_with_mock_rpc();
+
+        for height in 1..=30 {
+            platform
+                .create_checkpoint_if_needed(height)
+                .expect("expected to create checkpoint");
+        }
+
+        assert_eq!(
+            list_checkpoints(&platform.config).expect("expected to list checkpoints"),
+            vec![20, 30]
+        );
+
+        let db_dir = TempDir::new().expect("expected a temporary directory");
+        let rollback_config = PlatformConfig {
+            db_path: db_dir.path().join("data"),
+            ..platform.config.clone()
+        };
+
+        assert!(matches!(
+            rollback_to_checkpoint(&rollback_config, 10),
+            Err(Error::Maintenance(MaintenanceError::CheckpointNotFound(10)))
+        ));
+
+        rollback_to_checkpoint(&rollback_config, 20).expect("expected to roll back");
+
+        assert!(rollback_config.db_path.is_dir());
+        assert!(list_checkpoints(&rollback_config)
+            .expect("expected to list checkpoints")
+            .is_empty());
+    }
+}
@@ -0,0 +1,118 @@
+use crate::error::Error;
+use crate::maintenance::Error as MaintenanceError;
+use crate::platform_types::platform::Platform;
+use crate::platform_types::platform_state::v0::PlatformState;
+use dpp::block::epoch::{Epoch, EpochIndex};
+use dpp::document::Document;
+use dpp::identity::Identity;
+use dpp::platform_value::{Identifier, Value};
+use dpp::util::deserializer::ProtocolVersion;
+use drive::query::{DriveQuery, InternalClauses, WhereClause, WhereOperator};
+use std::collections::BTreeMap;
+
+/// Start of a fee epoch
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EpochStart {
+    /// Epoch index
+    pub index: EpochIndex,
+    /// Time of the first block of the epoch
+    pub start_time_ms: u64,
+    /// Height of the first block of the epoch
+    pub start_block_height: u64,
+}
+
+impl<C> Platform<C> {
+    /// The platform state loaded from the data storage
+    pub fn inspect_state(&self) -> PlatformState {
+        self.state.read().unwrap().clone()
+    }
+
+    /// Starts of the epochs up to the one of the last committed block
+    pub fn inspect_epochs(&self) -> Result<Vec<EpochStart>, Error> {
+        let current_epoch_index = match self.state.read().unwrap().last_committed_block_info {
+            Some(ref block_info) => block_info.basic_info.epoch.index,
+            None => return Ok(vec![]),
+        };
+
+        (0..=current_epoch_index)
+            .map(|index| {
+                let epoch = Epoch::new(index)?;
+                Ok(EpochStart {
+                    index,
+                    start_time_ms: self.drive.get_epoch_start_time(&epoch, None)?,
+                    start_block_height: self.drive.get_epoch_start_block_height(&epoch, None)?,
+                })
+            })
+            .collect()
+    }
+
+    /// Number of votes for every protocol version in the current upgrade window
+    pub fn inspect_protocol_version_votes(&self) -> Result<BTreeMap<ProtocolVersion, u64>, Error> {
+        Ok(self
+            .drive
+            .fetch_versions_with_counter(None)?
+            .into_iter()
+            .collect())
+    }
+
+    /// Ids of the data contracts in state
+    pub fn inspect_contracts(&self) -> Result<Vec<Identifier>, Error> {
+        Ok(self
+            .drive
+            .fetch_contract_ids(None)?
+            .into_iter()
+            .map(Identifier::new)
+            .collect())
+    }
+
+    /// Fetches an identity with its keys and balance
+    pub fn inspect_identity(&self, identity_id: Identifier) -> Result<Option<Identity>, Error> {
+        Ok(self
+            .drive
+            .fetch_full_identity(identity_id.to_buffer(), None)?)
+    }
+
+    /// Fetches a document by its id
+    pub fn inspect_document(
+        &self,
+        contract_id: Identifier,
+        document_type_name: &str,
+        document_id: Identifier,
+    ) -> Result<Option<Document>, Error> {
+        let contract_fetch_info = self
+            .drive
+            .get_contract_with_fetch_info(contract_id.to_buffer(), false, None)?
+            .ok_or(MaintenanceError::ContractNotFound(contract_id))?;
+        let contract = &contract_fetch_info.contract;
+        let document_type = contract.document_type_for_name(document_type_name)?;
+
+        let drive_query = DriveQuery {
+            contract,
+            document_type,
+            internal_clauses: InternalClauses {
+                primary_key_in_clause: None,
+                primary_key_equal_clause: Some(WhereClause {
+                    field: "$id".to_string(),
+                    operator: WhereOperator::Equal,
+                    value: Value::Identifier(document_id.to_buffer()),
+                }),
+                in_clause: None,
+                range_clause: None,
+                equal_clauses: Default::default(),
+            },
+            offset: None,
+            limit: Some(1),
+            order_by: Default::default(),
+            start_at: None,
+            start_at_included: false,
+            block_time_ms: None,
+        };
+
+        Ok(self
+            .drive
+            .query_documents(drive_query, None, false, None)?
+            .documents
+            .into_iter()
+            .next())
+    }
+}
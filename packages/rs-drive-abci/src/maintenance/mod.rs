@@ -0,0 +1,36 @@
+//! Offline maintenance of the platform data storage
+//!
+//! Used by the `verify`, `inspect`, `rollback`, `export` and `import` commands of the `drive-abci`
+//! binary, which must not run while the ABCI server uses the same data storage.
+
+use dpp::platform_value::Identifier;
+use std::path::PathBuf;
+
+/// Export and import of the data storage
+pub mod archive;
+/// Checkpoints of the data storage
+pub mod checkpoints;
+/// Printing of the stored platform data
+pub mod inspect;
+/// Verification of the stored platform data
+pub mod verify;
+
+/// Errors returned by maintenance
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    /// There is no checkpoint for the height
+    #[error("no checkpoint at height {0}")]
+    CheckpointNotFound(u64),
+
+    /// There is no data contract with the id
+    #[error("data contract {0} not found")]
+    ContractNotFound(Identifier),
+
+    /// The app hash of the last committed block does not match the root hash of the data storage
+    #[error("app hash {0} of the last committed block does not match root hash {1}")]
+    AppHashMismatch(String, String),
+
+    /// File system error
+    #[error("{0}: {1}")]
+    Io(PathBuf, std::io::Error),
+}
@@ -0,0 +1,155 @@
+use crate::error::Error;
+use crate::platform_types::block_attestation::v0::SignedBlockAttestation;
+use crate::platform_types::platform::Platform;
+use drive::drive::balances::TotalCreditsBalance;
+use drive::error::Error::GroveDB;
+use std::fmt;
+
+/// Outcome of the verification of the stored platform data
+#[derive(Clone, Debug)]
+pub struct StateVerification {
+    /// Height of the last committed block, None before genesis
+    pub height: Option<u64>,
+    /// Root hash recomputed from GroveDB
+    pub root_hash: [u8; 32],
+    /// App hash of the last committed block, as saved in the platform state
+    pub committed_app_hash: Option<[u8; 32]>,
+    /// App hash of the last block attestation signed by the validator quorum
+    pub attested_app_hash: Option<[u8; 32]>,
+    /// Credits in the sum trees and in the system credits counter
+    pub total_credits_balance: TotalCreditsBalance,
+    /// Whether identity balances and distribution pools add up to the system credits
+    pub credits_balanced: bool,
+}
+
+impl StateVerification {
+    /// Whether the root hash matches the committed and attested app hashes, and credits are
+    /// balanced
+    pub fn is_valid(&self) -> bool {
+        self.committed_app_hash
+            .map_or(true, |app_hash| app_hash == self.root_hash)
+            && self
+                .attested_app_hash
+                .map_or(true, |app_hash| app_hash == self.root_hash)
+            && self.credits_balanced
+    }
+}
+
+impl fmt::Display for StateVerification {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let hash_line = |app_hash: Option<[u8; 32]>| match app_hash {
+            Some(app_hash) if app_hash == self.root_hash => {
+                format!("{} (ok)", hex::encode(app_hash))
+            }
+            Some(app_hash) => format!("{} (MISMATCH)", hex::encode(app_hash)),
+            None => "none".to_string(),
+        };
+
+        match self.height {
+            Some(height) => writeln!(f, "last committed height: {}", height)?,
+            None => writeln!(f, "last committed height: none")?,
+        }
+        writeln!(f, "root hash:             {}", hex::encode(self.root_hash))?;
+        writeln!(
+            f,
+            "committed app hash:    {}",
+            hash_line(self.committed_app_hash)
+        )?;
+        writeln!(
+            f,
+            "attested app hash:     {}",
+            hash_line(self.attested_app_hash)
+        )?;
+        writeln!(
+            f,
+            "system credits:        {}",
+            self.total_credits_balance.total_credits_in_platform
+        )?;
+        writeln!(
+            f,
+            "identity balances:     {}",
+            self.total_credits_balance.total_identity_balances
+        )?;
+        writeln!(
+            f,
+            "distribution pools:    {}",
+            self.total_credits_balance.total_in_pools
+        )?;
+        write!(
+            f,
+            "credits balanced:      {}",
+            if self.credits_balanced {
+                "ok"
+            } else {
+                "MISMATCH"
+            }
+        )
+    }
+}
+
+impl<C> Platform<C> {
+    /// Verifies the committed data storage
+    ///
+    /// The GroveDB root hash is compared with the app hash of the last committed block and with
+    /// the one of the last block attestation, and the sum trees of identity balances and
+    /// distribution pools with the system credits added and removed by system operations.
+    pub fn verify_stored_state(&self) -> Result<StateVerification, Error> {
+        let root_hash = self
+            .drive
+            .grove
+            .root_hash(None)
+            .unwrap()
+            .map_err(|e| Error::Drive(GroveDB(e)))?;
+
+        let state = self.state.read().unwrap();
+        let last_committed_block_info = state.last_committed_block_info.as_ref();
+
+        let attested_app_hash = SignedBlockAttestation::fetch_last(&self.drive, None)?
+            .map(|signed_attestation| signed_attestation.attestation)
+            // An attestation of an older block can be left after a rollback
+            .filter(|attestation| {
+                Some(attestation.height)
+                    == last_committed_block_info.map(|block_info| block_info.basic_info.height)
+            })
+            .map(|attestation| attestation.app_hash);
+
+        let total_credits_balance = self.drive.calculate_total_credits_balance(None)?;
+        // A corrupted balance is reported as unbalanced rather than as a failure to verify
+        let credits_balanced = total_credits_balance.ok().unwrap_or(false);
+
+        Ok(StateVerification {
+            height: last_committed_block_info.map(|block_info| block_info.basic_info.height),
+            root_hash,
+            committed_app_hash: last_committed_block_info.map(|block_info| block_info.app_hash),
+            attested_app_hash,
+            total_credits_balance,
+            credits_balanced,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::PlatformConfig;
+    use crate::test::helpers::setup::TestPlatformBuilder;
+
+    #[test]
+    fn should_verify_state_after_genesis() {
+        let platform = TestPlatformBuilder::new()
+            .with_config(PlatformConfig::default())
+            .build_with_mock_rpc();
+
+        platform
+            .create_genesis_state_v0(0, platform.config.abci.keys.clone().into(), None)
+            .expect("expected to create genesis state");
+
+        let verification = platform
+            .verify_stored_state()
+            .expect("expected to verify stored state");
+
+        assert_eq!(verification.height, None);
+        assert_eq!(verification.committed_app_hash, None);
+        assert!(verification.credits_balanced);
+        assert!(verification.is_valid());
+    }
+}
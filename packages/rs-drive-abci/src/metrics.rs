@@ -4,7 +4,10 @@
 
 use std::{sync::Once, time::Instant};
 
-use metrics::{absolute_counter, describe_counter, describe_histogram, histogram, Label};
+use drive::drive::cache::DataContractCacheStats;
+use metrics::{
+    absolute_counter, describe_counter, describe_gauge, describe_histogram, gauge, histogram, Label,
+};
 use metrics_exporter_prometheus::PrometheusBuilder;
 
 /// Default Prometheus port (29090)
@@ -15,6 +18,11 @@ const COUNTER_LAST_HEIGHT: &str = "abci_last_finalized_height";
 const HISTOGRAM_FINALIZED_ROUND: &str = "abci_finalized_round";
 const HISTOGRAM_ABCI_REQUEST_DURATION: &str = "abci_request_duration_seconds";
 const LABEL_ENDPOINT: &str = "endpoint";
+const COUNTER_DATA_CONTRACT_CACHE_HITS: &str = "drive_data_contract_cache_hits";
+const COUNTER_DATA_CONTRACT_CACHE_MISSES: &str = "drive_data_contract_cache_misses";
+const COUNTER_DATA_CONTRACT_CACHE_EVICTIONS: &str = "drive_data_contract_cache_evictions";
+const GAUGE_DATA_CONTRACT_CACHE_ENTRIES: &str = "drive_data_contract_cache_entries";
+const GAUGE_DATA_CONTRACT_CACHE_WEIGHTED_SIZE: &str = "drive_data_contract_cache_weighted_size";
 
 /// Error returned by metrics subsystem
 #[derive(thiserror::Error, Debug)]
@@ -166,6 +174,31 @@ impl Prometheus {
             describe_histogram!(
                 HISTOGRAM_ABCI_REQUEST_DURATION,
                 "Duration of ABCI request execution inside Drive per endpoint, in seconds"
+            );
+
+            describe_counter!(
+                COUNTER_DATA_CONTRACT_CACHE_HITS,
+                "Data contracts found in the Drive cache"
+            );
+
+            describe_counter!(
+                COUNTER_DATA_CONTRACT_CACHE_MISSES,
+                "Data contracts not found in the Drive cache"
+            );
+
+            describe_counter!(
+                COUNTER_DATA_CONTRACT_CACHE_EVICTIONS,
+                "Data contracts evicted from the Drive global cache to make space"
+            );
+
+            describe_gauge!(
+                GAUGE_DATA_CONTRACT_CACHE_ENTRIES,
+                "Data contracts in the Drive global cache"
+            );
+
+            describe_gauge!(
+                GAUGE_DATA_CONTRACT_CACHE_WEIGHTED_SIZE,
+                "Weight of the data contracts in the Drive global cache, per the eviction policy"
             )
         });
     }
@@ -195,6 +228,21 @@ pub fn abci_last_block_time(time: u64) {
     absolute_counter!(COUNTER_LAST_BLOCK_TIME, time);
 }
 
+/// Sets the data contract cache metrics to the statistics of the cache.
+pub fn data_contract_cache_stats(stats: &DataContractCacheStats) {
+    absolute_counter!(COUNTER_DATA_CONTRACT_CACHE_HITS, stats.hits);
+    absolute_counter!(COUNTER_DATA_CONTRACT_CACHE_MISSES, stats.misses);
+    absolute_counter!(COUNTER_DATA_CONTRACT_CACHE_EVICTIONS, stats.evictions);
+    gauge!(
+        GAUGE_DATA_CONTRACT_CACHE_ENTRIES,
+        stats.global_cache_entries as f64
+    );
+    gauge!(
+        GAUGE_DATA_CONTRACT_CACHE_WEIGHTED_SIZE,
+        stats.global_cache_weighted_size as f64
+    );
+}
+
 /// Returns a `[HistogramTiming]` instance for measuring ABCI request duration.
 ///
 /// Duration measurement starts when this function is called, and stops when returned value
@@ -11,6 +11,7 @@ use dashcore_rpc::dashcore::blockdata::transaction::special_transaction::Transac
 use dashcore_rpc::dashcore::bls_sig_utils::BLSSignature;
 use dashcore_rpc::dashcore::consensus::Decodable;
 use dashcore_rpc::dashcore;
+use dashcore_rpc::dashcore::hashes::Hash;
 use rand::rngs::StdRng;
 use rand::{Rng, SeedableRng};
 use dpp::block::block_info::BlockInfo;
@@ -18,10 +19,11 @@ use dpp::serialization_traits::PlatformSerializable;
 use dpp::state_transition::StateTransition;
 use dpp::util::deserializer::ProtocolVersion;
 use tenderdash_abci::proto::abci::response_verify_vote_extension::VerifyStatus;
-use tenderdash_abci::proto::abci::{CommitInfo, RequestExtendVote, RequestFinalizeBlock, RequestPrepareProposal, RequestProcessProposal, RequestVerifyVoteExtension, ResponsePrepareProposal, ValidatorSetUpdate};
+use tenderdash_abci::proto::abci::tx_record::TxAction;
+use tenderdash_abci::proto::abci::{CommitInfo, ExecTxResult, RequestExtendVote, RequestFinalizeBlock, RequestPrepareProposal, RequestProcessProposal, RequestVerifyVoteExtension, ResponsePrepareProposal, ValidatorSetUpdate};
 use tenderdash_abci::proto::google::protobuf::Timestamp;
 use tenderdash_abci::proto::types::{
-    Block, BlockId, Data, EvidenceList, Header, PartSetHeader, VoteExtension, VoteExtensionType, StateId, CanonicalVote, SignedMsgType,
+    Block, BlockId, Data, EvidenceList, Header, PartSetHeader, VoteExtension, StateId, CanonicalVote, SignedMsgType,
 };
 use tenderdash_abci::signatures::SignBytes;
 use tenderdash_abci::{signatures::SignDigest, proto::version::Consensus, Application};
@@ -52,6 +54,9 @@ pub struct MimicExecuteBlockOutcome {
     pub signature: [u8; 96],
     /// Version of Drive app used to generate this block
     pub app_version: u64,
+    /// Execution results of the state transitions, in the order they were given,
+    /// None for the ones removed from the block because they were invalid
+    pub state_transition_results: Vec<Option<ExecTxResult>>,
 }
 
 /// Options for execution
@@ -136,6 +141,18 @@ impl<'a, C: CoreRPCLike> AbciApplication<'a, C> {
             core_height = core_chain_lock_update.core_block_height;
         }
 
+        let mut kept_tx_results = tx_results.iter();
+        let state_transition_results = tx_records
+            .iter()
+            .map(|tx_record| {
+                if tx_record.action == TxAction::Unmodified as i32 {
+                    kept_tx_results.next().cloned()
+                } else {
+                    None
+                }
+            })
+            .collect();
+
         if !expect_validation_errors {
             if tx_results.len() != tx_records.len() {
                 return Err(Error::Abci(AbciError::GenericWithCode(0)));
@@ -260,18 +277,6 @@ impl<'a, C: CoreRPCLike> AbciApplication<'a, C> {
                     "block execution context must be set in block begin handler for mimic block execution",
                 )))?;
 
-        let extensions = block_execution_context
-            .withdrawal_transactions
-            .keys()
-            .map(|tx_id| {
-                VoteExtension {
-                    r#type: VoteExtensionType::ThresholdRecover as i32,
-                    extension: tx_id.to_vec(),
-                    signature: vec![], //todo: signature
-                }
-            })
-            .collect();
-
         //todo: tidy up and fix
         let withdrawals = block_execution_context
             .withdrawal_transactions
@@ -321,6 +326,35 @@ impl<'a, C: CoreRPCLike> AbciApplication<'a, C> {
 
         let quorum_hash = current_quorum.quorum_hash.to_vec();
 
+        // The quorum recovers the threshold signatures of the vote extensions
+        let extensions = vote_extensions
+            .into_iter()
+            .map(|vote_extension| {
+                let mut vote_extension = VoteExtension {
+                    r#type: vote_extension.r#type,
+                    extension: vote_extension.extension,
+                    signature: vec![],
+                };
+                if self.platform.config.testing_configs.block_signing {
+                    let digest = vote_extension
+                        .sign_digest(
+                            CHAIN_ID,
+                            quorum_type as u8,
+                            &current_quorum.quorum_hash.into_inner(),
+                            height as i64,
+                            ROUND,
+                        )
+                        .expect("expected to sign digest");
+                    vote_extension.signature = current_quorum
+                        .private_key
+                        .sign(digest.as_slice())
+                        .to_bytes()
+                        .to_vec();
+                }
+                vote_extension
+            })
+            .collect();
+
         let mut commit_info = CommitInfo {
             round: ROUND,
             quorum_hash: quorum_hash.clone(),
@@ -450,6 +484,7 @@ impl<'a, C: CoreRPCLike> AbciApplication<'a, C> {
                 .block_signature
                 .try_into()
                 .expect("signature mut be 96 bytes long"),
+            state_transition_results,
         })
     }
 }
@@ -0,0 +1,2 @@
+/// Version 0
+pub mod v0;
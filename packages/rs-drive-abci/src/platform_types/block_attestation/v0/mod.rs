@@ -0,0 +1,305 @@
+//! Block attestations threshold signed by the validator quorum through vote extensions
+
+use crate::abci::AbciError;
+use crate::error::Error;
+use dashcore_rpc::dashcore_rpc_json::QuorumType;
+use dpp::bls_signatures;
+use dpp::util::deserializer::ProtocolVersion;
+use dpp::validation::SimpleValidationResult;
+use drive::drive::Drive;
+use drive::error::Error::GroveDB;
+use drive::query::TransactionArg;
+use tenderdash_abci::proto::abci::ExtendVoteExtension;
+use tenderdash_abci::proto::types::{VoteExtension, VoteExtensionType};
+use tenderdash_abci::signatures::SignDigest;
+
+/// The auxiliary storage key of the last signed block attestation
+const SIGNED_BLOCK_ATTESTATION_KEY: &[u8] = b"signed_block_attestation";
+
+/// The prefix of the auxiliary storage keys of signed block attestations by block height
+const SIGNED_BLOCK_ATTESTATION_AT_HEIGHT_KEY_PREFIX: &[u8] = b"signed_block_attestation_at_height";
+
+/// Compact statement about a block that the validator quorum signs every block
+///
+/// With the signature recovered from the quorum, anyone knowing the quorum public key can trust
+/// the app hash of the block, and verify proofs against it, without following the commits.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BlockAttestation {
+    /// Block height
+    pub height: u64,
+    /// Root hash of the state after the block was executed
+    pub app_hash: [u8; 32],
+    /// Fee epoch index of the block
+    pub epoch: u16,
+    /// Protocol version the block was executed with
+    pub protocol_version: ProtocolVersion,
+}
+
+impl BlockAttestation {
+    /// The length of a serialized attestation
+    pub const SERIALIZED_LEN: usize = 8 + 32 + 2 + 4;
+
+    /// Serializes the attestation as height, app hash, epoch and protocol version, with integers
+    /// in big endian
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(Self::SERIALIZED_LEN);
+        bytes.extend_from_slice(&self.height.to_be_bytes());
+        bytes.extend_from_slice(&self.app_hash);
+        bytes.extend_from_slice(&self.epoch.to_be_bytes());
+        bytes.extend_from_slice(&self.protocol_version.to_be_bytes());
+        bytes
+    }
+
+    /// Deserializes an attestation serialized with [`BlockAttestation::to_bytes`]
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, AbciError> {
+        if bytes.len() != Self::SERIALIZED_LEN {
+            return Err(AbciError::BadRequestDataSize(format!(
+                "block attestation must be {} bytes long, got {}",
+                Self::SERIALIZED_LEN,
+                bytes.len()
+            )));
+        }
+        let (height, rest) = bytes.split_at(8);
+        let (app_hash, rest) = rest.split_at(32);
+        let (epoch, protocol_version) = rest.split_at(2);
+        Ok(Self {
+            height: u64::from_be_bytes(height.try_into().expect("expected 8 bytes")),
+            app_hash: app_hash.try_into().expect("expected 32 bytes"),
+            epoch: u16::from_be_bytes(epoch.try_into().expect("expected 2 bytes")),
+            protocol_version: ProtocolVersion::from_be_bytes(
+                protocol_version.try_into().expect("expected 4 bytes"),
+            ),
+        })
+    }
+
+    /// The vote extension validators sign the attestation with
+    pub fn to_vote_extension(&self) -> ExtendVoteExtension {
+        ExtendVoteExtension {
+            r#type: VoteExtensionType::ThresholdRecover as i32,
+            extension: self.to_bytes(),
+        }
+    }
+}
+
+/// A block attestation with the signature recovered from the validator quorum
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SignedBlockAttestation {
+    /// The signed attestation
+    pub attestation: BlockAttestation,
+    /// Hash of the validator quorum that signed the attestation
+    pub quorum_hash: [u8; 32],
+    /// Round in which the block was committed
+    pub round: u32,
+    /// Threshold signature of the vote extension holding the attestation
+    pub signature: [u8; 96],
+}
+
+impl SignedBlockAttestation {
+    /// The length of a serialized signed attestation
+    pub const SERIALIZED_LEN: usize = BlockAttestation::SERIALIZED_LEN + 32 + 4 + 96;
+
+    /// Serializes the attestation followed by the quorum hash, the round and the signature
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = self.attestation.to_bytes();
+        bytes.reserve(Self::SERIALIZED_LEN - bytes.len());
+        bytes.extend_from_slice(&self.quorum_hash);
+        bytes.extend_from_slice(&self.round.to_be_bytes());
+        bytes.extend_from_slice(&self.signature);
+        bytes
+    }
+
+    /// Deserializes a signed attestation serialized with [`SignedBlockAttestation::to_bytes`]
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, AbciError> {
+        if bytes.len() != Self::SERIALIZED_LEN {
+            return Err(AbciError::BadRequestDataSize(format!(
+                "signed block attestation must be {} bytes long, got {}",
+                Self::SERIALIZED_LEN,
+                bytes.len()
+            )));
+        }
+        let (attestation, rest) = bytes.split_at(BlockAttestation::SERIALIZED_LEN);
+        let (quorum_hash, rest) = rest.split_at(32);
+        let (round, signature) = rest.split_at(4);
+        Ok(Self {
+            attestation: BlockAttestation::from_bytes(attestation)?,
+            quorum_hash: quorum_hash.try_into().expect("expected 32 bytes"),
+            round: u32::from_be_bytes(round.try_into().expect("expected 4 bytes")),
+            signature: signature.try_into().expect("expected 96 bytes"),
+        })
+    }
+
+    /// The digest signed by the quorum, computed the way Tenderdash computes it for threshold
+    /// vote extensions
+    pub fn sign_digest(
+        &self,
+        chain_id: &str,
+        quorum_type: QuorumType,
+    ) -> Result<Vec<u8>, AbciError> {
+        VoteExtension {
+            r#type: VoteExtensionType::ThresholdRecover as i32,
+            extension: self.attestation.to_bytes(),
+            signature: vec![],
+        }
+        .sign_digest(
+            chain_id,
+            quorum_type as u8,
+            &self.quorum_hash,
+            self.attestation.height as i64,
+            self.round as i32,
+        )
+        .map_err(AbciError::Tenderdash)
+    }
+
+    /// Verifies the signature against the public key of the validator quorum
+    pub fn verify_signature(
+        &self,
+        chain_id: &str,
+        quorum_type: QuorumType,
+        public_key: &bls_signatures::PublicKey,
+    ) -> SimpleValidationResult<AbciError> {
+        let signature = match bls_signatures::Signature::from_bytes(&self.signature) {
+            Ok(signature) => signature,
+            Err(e) => {
+                return SimpleValidationResult::new_with_error(
+                    AbciError::BlsErrorOfTenderdashThresholdMechanism(
+                        e,
+                        "verification of a block attestation signature".to_string(),
+                    ),
+                )
+            }
+        };
+
+        let hash = match self.sign_digest(chain_id, quorum_type) {
+            Ok(hash) => hash,
+            Err(e) => return SimpleValidationResult::new_with_error(e),
+        };
+
+        if public_key.verify(&signature, &hash) {
+            SimpleValidationResult::default()
+        } else {
+            SimpleValidationResult::new_with_error(AbciError::VoteExtensionsSignatureInvalid)
+        }
+    }
+
+    /// The auxiliary storage key of the attestation of the block at the given height
+    fn key_at_height(height: u64) -> Vec<u8> {
+        let mut key = SIGNED_BLOCK_ATTESTATION_AT_HEIGHT_KEY_PREFIX.to_vec();
+        key.extend_from_slice(&height.to_be_bytes());
+        key
+    }
+
+    /// Stores the attestation under the height of its block and as the last signed one, outside
+    /// of the state so that the app hash it signs is not changed
+    pub fn store(&self, drive: &Drive, transaction: TransactionArg) -> Result<(), Error> {
+        let bytes = self.to_bytes();
+        drive
+            .grove
+            .put_aux(
+                Self::key_at_height(self.attestation.height),
+                &bytes,
+                None,
+                transaction,
+            )
+            .unwrap()
+            .map_err(|e| Error::Drive(GroveDB(e)))?;
+        drive
+            .grove
+            .put_aux(SIGNED_BLOCK_ATTESTATION_KEY, &bytes, None, transaction)
+            .unwrap()
+            .map_err(|e| Error::Drive(GroveDB(e)))
+    }
+
+    /// Fetches the last signed attestation
+    pub fn fetch_last(drive: &Drive, transaction: TransactionArg) -> Result<Option<Self>, Error> {
+        drive
+            .grove
+            .get_aux(SIGNED_BLOCK_ATTESTATION_KEY, transaction)
+            .unwrap()
+            .map_err(|e| Error::Drive(GroveDB(e)))?
+            .map(|bytes| Self::from_bytes(&bytes).map_err(Error::Abci))
+            .transpose()
+    }
+
+    /// Fetches the signed attestation of the block at the given height
+    pub fn fetch_at_height(
+        drive: &Drive,
+        height: u64,
+        transaction: TransactionArg,
+    ) -> Result<Option<Self>, Error> {
+        drive
+            .grove
+            .get_aux(Self::key_at_height(height), transaction)
+            .unwrap()
+            .map_err(|e| Error::Drive(GroveDB(e)))?
+            .map(|bytes| Self::from_bytes(&bytes).map_err(Error::Abci))
+            .transpose()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use dpp::bls_signatures::PrivateKey;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    const CHAIN_ID: &str = "test-chain";
+
+    fn attestation() -> BlockAttestation {
+        BlockAttestation {
+            height: 1234,
+            app_hash: [7; 32],
+            epoch: 3,
+            protocol_version: 1,
+        }
+    }
+
+    #[test]
+    fn should_serialize_and_deserialize_signed_attestations() {
+        let signed_attestation = SignedBlockAttestation {
+            attestation: attestation(),
+            quorum_hash: [2; 32],
+            round: 1,
+            signature: [3; 96],
+        };
+        let bytes = signed_attestation.to_bytes();
+        assert_eq!(bytes.len(), SignedBlockAttestation::SERIALIZED_LEN);
+        assert_eq!(
+            SignedBlockAttestation::from_bytes(&bytes).expect("expected to deserialize"),
+            signed_attestation
+        );
+        assert!(SignedBlockAttestation::from_bytes(&bytes[1..]).is_err());
+    }
+
+    #[test]
+    fn should_verify_signatures_of_the_quorum() {
+        let mut rng = StdRng::seed_from_u64(5);
+        let private_key = PrivateKey::generate_dash(&mut rng).expect("expected a private key");
+        let public_key = private_key.g1_element().expect("expected a public key");
+
+        let mut signed_attestation = SignedBlockAttestation {
+            attestation: attestation(),
+            quorum_hash: [2; 32],
+            round: 0,
+            signature: [0; 96],
+        };
+        let digest = signed_attestation
+            .sign_digest(CHAIN_ID, QuorumType::LlmqTest)
+            .expect("expected a digest");
+        signed_attestation.signature = private_key
+            .sign(&digest)
+            .to_bytes()
+            .as_slice()
+            .try_into()
+            .expect("expected 96 bytes");
+
+        assert!(signed_attestation
+            .verify_signature(CHAIN_ID, QuorumType::LlmqTest, &public_key)
+            .is_valid());
+
+        signed_attestation.attestation.app_hash = [8; 32];
+        assert!(!signed_attestation
+            .verify_signature(CHAIN_ID, QuorumType::LlmqTest, &public_key)
+            .is_valid());
+    }
+}
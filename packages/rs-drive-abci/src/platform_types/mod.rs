@@ -27,6 +27,8 @@
 // DEALINGS IN THE SOFTWARE.
 //
 
+/// Block attestations threshold signed by the validator quorum
+pub mod block_attestation;
 /// The outcome of a block execution
 pub mod block_execution_outcome;
 /// The block proposal
@@ -49,6 +49,8 @@ pub struct PlatformRef<'a, C> {
     pub config: &'a PlatformConfig,
     /// Core RPC Client
     pub core_rpc: &'a C,
+    /// Whether state transitions are checked for the mempool instead of executed in a block
+    pub check_tx: bool,
 }
 
 // @append_only
@@ -60,6 +62,8 @@ pub struct PlatformStateRef<'a> {
     pub state: &'a PlatformState,
     /// Configuration
     pub config: &'a PlatformConfig,
+    /// Whether state transitions are checked for the mempool instead of executed in a block
+    pub check_tx: bool,
 }
 
 impl<'a, C> From<&PlatformRef<'a, C>> for PlatformStateRef<'a> {
@@ -68,6 +72,7 @@ impl<'a, C> From<&PlatformRef<'a, C>> for PlatformStateRef<'a> {
             drive,
             state,
             config,
+            check_tx,
             ..
         } = value;
 
@@ -75,6 +80,7 @@ impl<'a, C> From<&PlatformRef<'a, C>> for PlatformStateRef<'a> {
             drive,
             state,
             config,
+            check_tx: *check_tx,
         }
     }
 }
@@ -160,6 +166,27 @@ impl<C> Platform<C> {
         let config = config.unwrap_or_default();
         let drive = Drive::open(path, Some(config.drive.clone())).map_err(Error::Drive)?;
 
+        // Contracts are slow to fetch, the ones used the most before the restart are preloaded.
+        // This is only an optimization, so failures do not prevent platform from starting
+        match drive.warm_up_contract_cache(None) {
+            Ok(warm_up) => {
+                for (contract_id, error) in warm_up.failed_contracts {
+                    tracing::warn!(
+                        contract_id = hex::encode(contract_id),
+                        ?error,
+                        "unable to preload data contract in cache, skipping it"
+                    );
+                }
+                tracing::debug!(
+                    preloaded_contracts = warm_up.loaded_contracts,
+                    "data contract cache warmed up"
+                );
+            }
+            Err(error) => {
+                tracing::warn!(?error, "unable to warm up the data contract cache");
+            }
+        }
+
         // TODO: factor out key so we don't duplicate
         let maybe_serialized_platform_state = drive
             .grove
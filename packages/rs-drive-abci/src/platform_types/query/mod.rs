@@ -3,13 +3,17 @@ mod v0;
 use crate::error::query::QueryError;
 use crate::error::Error;
 use crate::platform_types::platform::Platform;
+use crate::rpc::core::CoreRPCLike;
 
 use dpp::validation::ValidationResult;
 
 /// A query validation result
 pub type QueryValidationResult<TData> = ValidationResult<TData, QueryError>;
 
-impl<C> Platform<C> {
+impl<C> Platform<C>
+where
+    C: CoreRPCLike,
+{
     /// Querying
     pub fn query(
         &self,
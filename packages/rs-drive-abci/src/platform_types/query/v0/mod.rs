@@ -1,32 +1,46 @@
 use crate::error::query::QueryError;
 use crate::error::Error;
+use crate::platform_types::block_attestation::v0::SignedBlockAttestation;
 use crate::platform_types::platform::Platform;
+use crate::rpc::core::CoreRPCLike;
 use dapi_grpc::platform::v0::get_documents_request::Start;
 use dapi_grpc::platform::v0::{
-    get_data_contract_history_response, get_data_contract_response, get_data_contracts_response,
-    get_documents_response, get_identities_by_public_key_hashes_response, get_identities_response,
+    estimate_fee_response, get_block_attestation_response, get_data_contract_history_response,
+    get_data_contract_response, get_data_contracts_response, get_documents_response,
+    get_identities_by_public_key_hashes_response, get_identities_response,
     get_identity_balance_and_revision_response, get_identity_balance_response,
-    get_identity_by_public_key_hashes_response, get_identity_keys_response, get_identity_response,
-    GetDataContractHistoryRequest, GetDataContractHistoryResponse, GetDataContractRequest,
-    GetDataContractResponse, GetDataContractsRequest, GetDataContractsResponse,
-    GetDocumentsRequest, GetDocumentsResponse, GetIdentitiesByPublicKeyHashesRequest,
-    GetIdentitiesByPublicKeyHashesResponse, GetIdentitiesRequest, GetIdentitiesResponse,
-    GetIdentityBalanceAndRevisionResponse, GetIdentityBalanceResponse,
-    GetIdentityByPublicKeyHashesRequest, GetIdentityByPublicKeyHashesResponse,
-    GetIdentityKeysRequest, GetIdentityKeysResponse, GetIdentityRequest, GetIdentityResponse,
-    GetProofsRequest, GetProofsResponse, Proof, ResponseMetadata,
+    get_identity_by_public_key_hashes_response, get_identity_contract_nonce_response,
+    get_identity_keys_response, get_identity_nonce_response, get_identity_response,
+    wait_for_state_transition_result_response, EstimateFeeRequest, EstimateFeeResponse,
+    GetBlockAttestationRequest, GetBlockAttestationResponse, GetDataContractHistoryRequest,
+    GetDataContractHistoryResponse, GetDataContractRequest, GetDataContractResponse,
+    GetDataContractsRequest, GetDataContractsResponse, GetDocumentsRequest, GetDocumentsResponse,
+    GetIdentitiesByPublicKeyHashesRequest, GetIdentitiesByPublicKeyHashesResponse,
+    GetIdentitiesRequest, GetIdentitiesResponse, GetIdentityBalanceAndRevisionResponse,
+    GetIdentityBalanceResponse, GetIdentityByPublicKeyHashesRequest,
+    GetIdentityByPublicKeyHashesResponse, GetIdentityContractNonceRequest,
+    GetIdentityContractNonceResponse, GetIdentityKeysRequest, GetIdentityKeysResponse,
+    GetIdentityNonceResponse, GetIdentityRequest, GetIdentityResponse, GetProofsRequest,
+    GetProofsResponse, Proof, ResponseMetadata, StateTransitionBroadcastError,
+    WaitForStateTransitionResultRequest, WaitForStateTransitionResultResponse,
 };
+use dpp::errors::consensus::codes::ErrorWithCode;
 use dpp::identifier::Identifier;
 use dpp::platform_value::{Bytes20, Bytes32};
 use std::collections::BTreeMap;
 
-use dpp::serialization_traits::PlatformSerializable;
+use dpp::serialization_traits::{PlatformDeserializable, PlatformSerializable};
+use dpp::state_transition::StateTransition;
 use dpp::validation::ValidationResult;
 use dpp::{check_validation_result_with_data, ProtocolError};
+use drive::drive::defaults::STATE_TRANSITION_RESULTS_PROTOCOL_VERSION;
 use drive::drive::identity::IdentityDriveQuery;
 use drive::drive::identity::IdentityProveRequestType;
 
 use crate::platform_types::query::QueryValidationResult;
+use dapi_grpc::platform::v0::estimate_fee_response::fee_estimate::balance_change::RemoveFromBalance;
+use dapi_grpc::platform::v0::estimate_fee_response::fee_estimate::{balance_change, Refund};
+use dapi_grpc::platform::v0::estimate_fee_response::{fee_estimate, FeeEstimate};
 use dapi_grpc::platform::v0::get_data_contracts_response::DataContractEntry;
 use dapi_grpc::platform::v0::get_identities_response::IdentityEntry;
 use dapi_grpc::platform::v0::get_identity_balance_and_revision_response::BalanceAndRevision;
@@ -37,7 +51,8 @@ use drive::drive::identity::key::fetch::{
 };
 use drive::error::contract::ContractError;
 use drive::error::query::QuerySyntaxError;
-use drive::query::{DriveQuery, SingleDocumentDriveQuery};
+use drive::fee::result::BalanceChange;
+use drive::query::{DriveQuery, QueryResultEncoding, SingleDocumentDriveQuery};
 use prost::Message;
 
 fn from_i32_to_key_kind_request_type(value: i32) -> Option<KeyKindRequestType> {
@@ -90,7 +105,10 @@ fn convert_key_request_type(
     }
 }
 
-impl<C> Platform<C> {
+impl<C> Platform<C>
+where
+    C: CoreRPCLike,
+{
     /// Querying
     pub(super) fn query_v0(
         &self,
@@ -279,6 +297,88 @@ impl<C> Platform<C> {
                 };
                 Ok(QueryValidationResult::new_with_data(response_data))
             }
+            "/identity/nonce" => {
+                let GetIdentityRequest { id, prove } =
+                    check_validation_result_with_data!(GetIdentityRequest::decode(query_data));
+                let identity_id: Identifier = check_validation_result_with_data!(id.try_into());
+                let response_data = if prove {
+                    let proof = check_validation_result_with_data!(self
+                        .drive
+                        .prove_identity_nonce(identity_id.into_buffer(), None));
+                    GetIdentityNonceResponse {
+                        result: Some(get_identity_nonce_response::Result::Proof(Proof {
+                            grovedb_proof: proof,
+                            quorum_hash: state.last_quorum_hash().to_vec(),
+                            quorum_type,
+                            block_id_hash: state.last_block_id_hash().to_vec(),
+                            signature: state.last_block_signature().to_vec(),
+                            round: state.last_block_round(),
+                        })),
+                        metadata: Some(metadata),
+                    }
+                    .encode_to_vec()
+                } else {
+                    let maybe_nonce = check_validation_result_with_data!(self
+                        .drive
+                        .fetch_identity_nonce(identity_id.into_buffer(), true, None));
+                    GetIdentityNonceResponse {
+                        result: maybe_nonce.map(get_identity_nonce_response::Result::Nonce),
+                        metadata: Some(metadata),
+                    }
+                    .encode_to_vec()
+                };
+                Ok(QueryValidationResult::new_with_data(response_data))
+            }
+            "/identity/contractNonce" => {
+                let GetIdentityContractNonceRequest {
+                    identity_id,
+                    contract_id,
+                    prove,
+                } = check_validation_result_with_data!(GetIdentityContractNonceRequest::decode(
+                    query_data
+                ));
+                let identity_id: Identifier =
+                    check_validation_result_with_data!(identity_id.try_into());
+                let contract_id: Identifier =
+                    check_validation_result_with_data!(contract_id.try_into());
+                let response_data = if prove {
+                    let proof = check_validation_result_with_data!(self
+                        .drive
+                        .prove_identity_contract_nonce(
+                            identity_id.into_buffer(),
+                            contract_id.into_buffer(),
+                            None
+                        ));
+                    GetIdentityContractNonceResponse {
+                        result: Some(get_identity_contract_nonce_response::Result::Proof(Proof {
+                            grovedb_proof: proof,
+                            quorum_hash: state.last_quorum_hash().to_vec(),
+                            quorum_type,
+                            block_id_hash: state.last_block_id_hash().to_vec(),
+                            signature: state.last_block_signature().to_vec(),
+                            round: state.last_block_round(),
+                        })),
+                        metadata: Some(metadata),
+                    }
+                    .encode_to_vec()
+                } else {
+                    let maybe_nonce = check_validation_result_with_data!(self
+                        .drive
+                        .fetch_identity_contract_nonce(
+                            identity_id.into_buffer(),
+                            contract_id.into_buffer(),
+                            true,
+                            None
+                        ));
+                    GetIdentityContractNonceResponse {
+                        result: maybe_nonce
+                            .map(get_identity_contract_nonce_response::Result::Nonce),
+                        metadata: Some(metadata),
+                    }
+                    .encode_to_vec()
+                };
+                Ok(QueryValidationResult::new_with_data(response_data))
+            }
             "/identity/keys" => {
                 let GetIdentityKeysRequest {
                     identity_id,
@@ -540,7 +640,7 @@ impl<C> Platform<C> {
                 };
                 Ok(QueryValidationResult::new_with_data(response_data))
             }
-            "/documents" | "/dataContract/documents" => {
+            "/documents" | "/dataContract/documents" | "/documents/json" => {
                 let GetDocumentsRequest {
                     data_contract_id,
                     document_type: document_type_name,
@@ -642,7 +742,24 @@ impl<C> Platform<C> {
                         document_type,
                         &self.config.drive,
                     ));
-                let response_data = if prove {
+                let response_data = if query_path == "/documents/json" {
+                    // the json route returns the documents rendered by their document type
+                    // instead of a protobuf response
+                    if prove {
+                        return Ok(QueryValidationResult::new_with_error(QueryError::Query(
+                            QuerySyntaxError::InvalidParameter(
+                                "proofs can not be json encoded".to_string(),
+                            ),
+                        )));
+                    }
+                    check_validation_result_with_data!(drive_query
+                        .execute_serialized_as_result_no_proof(
+                            &self.drive,
+                            None,
+                            QueryResultEncoding::JsonEncodedQueryResult,
+                            None,
+                        ))
+                } else if prove {
                     let (proof, _) = check_validation_result_with_data!(
                         drive_query.execute_with_proof(&self.drive, None, None)
                     );
@@ -843,6 +960,175 @@ impl<C> Platform<C> {
                 .encode_to_vec();
                 Ok(QueryValidationResult::new_with_data(response_data))
             }
+            "/blockAttestation" => {
+                let GetBlockAttestationRequest { height } = check_validation_result_with_data!(
+                    GetBlockAttestationRequest::decode(query_data)
+                );
+                let signed_attestation = if height == 0 {
+                    SignedBlockAttestation::fetch_last(&self.drive, None)?
+                } else {
+                    SignedBlockAttestation::fetch_at_height(&self.drive, height, None)?
+                };
+                let block_attestation = signed_attestation.map(|signed_attestation| {
+                    get_block_attestation_response::BlockAttestation {
+                        height: signed_attestation.attestation.height,
+                        app_hash: signed_attestation.attestation.app_hash.to_vec(),
+                        epoch: signed_attestation.attestation.epoch as u32,
+                        protocol_version: signed_attestation.attestation.protocol_version,
+                        quorum_hash: signed_attestation.quorum_hash.to_vec(),
+                        quorum_type,
+                        round: signed_attestation.round,
+                        signature: signed_attestation.signature.to_vec(),
+                    }
+                });
+                let response_data = GetBlockAttestationResponse {
+                    block_attestation,
+                    metadata: Some(metadata),
+                }
+                .encode_to_vec();
+                Ok(QueryValidationResult::new_with_data(response_data))
+            }
+            "/estimateFee" => {
+                let EstimateFeeRequest { state_transition } =
+                    check_validation_result_with_data!(EstimateFeeRequest::decode(query_data));
+                let state_transition = check_validation_result_with_data!(
+                    StateTransition::deserialize(&state_transition)
+                );
+                let validation_result = self.estimate_fee(state_transition, &state)?;
+                let result = if let Some(consensus_error) = validation_result.errors.first() {
+                    estimate_fee_response::Result::Error(StateTransitionBroadcastError {
+                        code: consensus_error.code(),
+                        message: consensus_error.to_string(),
+                        data: check_validation_result_with_data!(consensus_error.serialize()),
+                    })
+                } else {
+                    let estimate =
+                        check_validation_result_with_data!(validation_result.into_data());
+                    let refunds = estimate
+                        .fee_result
+                        .fee_refunds
+                        .iter()
+                        .filter_map(|(identity_id, _)| {
+                            estimate
+                                .fee_result
+                                .fee_refunds
+                                .calculate_refunds_amount_for_identity(*identity_id)
+                                .map(|credits| Refund {
+                                    identity_id: identity_id.to_vec(),
+                                    credits,
+                                })
+                        })
+                        .collect();
+                    let change = match estimate.balance_change {
+                        BalanceChange::AddToBalance(credits) => {
+                            balance_change::Change::AddToBalance(credits)
+                        }
+                        BalanceChange::RemoveFromBalance {
+                            required_removed_balance,
+                            desired_removed_balance,
+                        } => balance_change::Change::RemoveFromBalance(RemoveFromBalance {
+                            required_removed_balance,
+                            desired_removed_balance,
+                        }),
+                        BalanceChange::NoBalanceChange => {
+                            balance_change::Change::NoBalanceChange(true)
+                        }
+                    };
+                    estimate_fee_response::Result::FeeEstimate(FeeEstimate {
+                        storage_fee: estimate.fee_result.storage_fee,
+                        processing_fee: estimate.fee_result.processing_fee,
+                        refunds,
+                        balance_change: Some(fee_estimate::BalanceChange {
+                            change: Some(change),
+                        }),
+                    })
+                };
+                let response_data = EstimateFeeResponse {
+                    result: Some(result),
+                    metadata: Some(metadata),
+                }
+                .encode_to_vec();
+                Ok(QueryValidationResult::new_with_data(response_data))
+            }
+            "/waitForStateTransitionResult" => {
+                let WaitForStateTransitionResultRequest {
+                    state_transition_hash,
+                    prove,
+                } = check_validation_result_with_data!(
+                    WaitForStateTransitionResultRequest::decode(query_data)
+                );
+                if state.current_protocol_version_in_consensus
+                    < STATE_TRANSITION_RESULTS_PROTOCOL_VERSION
+                {
+                    return Ok(QueryValidationResult::new_with_error(QueryError::Query(
+                        QuerySyntaxError::Unsupported(
+                            "state transition results are not recorded by the current protocol version"
+                                .to_string(),
+                        ),
+                    )));
+                }
+                let state_transition_hash: [u8; 32] = check_validation_result_with_data!(
+                    state_transition_hash.try_into().map_err(|_| {
+                        QueryError::Query(QuerySyntaxError::InvalidParameter(
+                            "state transition hash must be 32 bytes".to_string(),
+                        ))
+                    })
+                );
+                let result = if prove {
+                    let proof = check_validation_result_with_data!(self
+                        .drive
+                        .prove_state_transition_result(state_transition_hash, None));
+                    wait_for_state_transition_result_response::Result::Proof(Proof {
+                        grovedb_proof: proof,
+                        quorum_hash: state.last_quorum_hash().to_vec(),
+                        quorum_type,
+                        block_id_hash: state.last_block_id_hash().to_vec(),
+                        signature: state.last_block_signature().to_vec(),
+                        round: state.last_block_round(),
+                    })
+                } else {
+                    let state_transition_result = check_validation_result_with_data!(self
+                        .drive
+                        .fetch_state_transition_result(state_transition_hash, None));
+                    match state_transition_result {
+                        Some(state_transition_result) if state_transition_result.is_success() => {
+                            wait_for_state_transition_result_response::Result::StateTransitionResult(
+                                wait_for_state_transition_result_response::StateTransitionResult {
+                                    height: state_transition_result.height,
+                                    storage_fee: state_transition_result.storage_fee,
+                                    processing_fee: state_transition_result.processing_fee,
+                                },
+                            )
+                        }
+                        Some(state_transition_result) => {
+                            wait_for_state_transition_result_response::Result::Error(
+                                StateTransitionBroadcastError {
+                                    code: state_transition_result.code,
+                                    message: format!(
+                                        "state transition failed at height {}",
+                                        state_transition_result.height
+                                    ),
+                                    data: vec![],
+                                },
+                            )
+                        }
+                        None => {
+                            return Ok(QueryValidationResult::new_with_error(QueryError::NotFound(
+                                format!(
+                                    "no result for state transition {}",
+                                    hex::encode(state_transition_hash)
+                                ),
+                            )))
+                        }
+                    }
+                };
+                let response_data = WaitForStateTransitionResultResponse {
+                    result: Some(result),
+                    metadata: Some(metadata),
+                }
+                .encode_to_vec();
+                Ok(QueryValidationResult::new_with_data(response_data))
+            }
             other => Ok(QueryValidationResult::new_with_error(QueryError::Query(
                 QuerySyntaxError::Unsupported(format!("query path '{}' is not supported", other)),
             ))),
@@ -1197,4 +1483,91 @@ mod test {
             }
         }
     }
+
+    pub mod query_documents_json {
+        use crate::test::helpers::setup::TestPlatformBuilder;
+        use dapi_grpc::platform::v0::GetDocumentsRequest;
+        use dpp::block::block_info::BlockInfo;
+        use dpp::platform_value::Value;
+        use dpp::tests::fixtures::{get_data_contract_fixture, get_extended_documents_fixture};
+        use drive::drive::object_size_info::DocumentInfo::DocumentRefInfo;
+        use drive::drive::object_size_info::{DocumentAndContractInfo, OwnedDocumentInfo};
+        use prost::Message;
+        use serde_json::json;
+
+        #[test]
+        pub fn should_return_documents_rendered_by_their_document_type() {
+            let platform = TestPlatformBuilder::new()
+                .build_with_mock_rpc()
+                .set_initial_state_structure();
+
+            let data_contract = get_data_contract_fixture(None).data_contract;
+            platform
+                .drive
+                .apply_contract(&data_contract, BlockInfo::default(), true, None, None)
+                .expect("expected to apply the contract");
+
+            let document = get_extended_documents_fixture(data_contract.clone())
+                .expect("expected documents")
+                .into_iter()
+                .find(|document| document.document_type_name == "niceDocument")
+                .expect("expected a nice document")
+                .document;
+            let document_type = data_contract
+                .document_type_for_name("niceDocument")
+                .expect("expected a document type");
+            platform
+                .drive
+                .add_document_for_contract(
+                    DocumentAndContractInfo {
+                        owned_document_info: OwnedDocumentInfo {
+                            document_info: DocumentRefInfo((&document, None)),
+                            owner_id: Some(document.owner_id.to_buffer()),
+                        },
+                        contract: &data_contract,
+                        document_type,
+                    },
+                    false,
+                    BlockInfo::default(),
+                    true,
+                    None,
+                )
+                .expect("expected to insert a document");
+
+            let mut where_clause = vec![];
+            ciborium::ser::into_writer(&Value::Array(vec![]), &mut where_clause)
+                .expect("expected to encode the where clause");
+            let request = GetDocumentsRequest {
+                data_contract_id: data_contract.id.to_vec(),
+                document_type: "niceDocument".to_string(),
+                r#where: where_clause,
+                order_by: vec![],
+                limit: 0,
+                prove: false,
+                start: None,
+            };
+
+            let result = platform
+                .query_v0("/documents/json", &request.encode_to_vec())
+                .expect("expected to query the documents");
+            assert!(result.is_valid());
+            let documents: serde_json::Value =
+                serde_json::from_slice(&result.into_data().expect("expected data"))
+                    .expect("expected the documents to be json");
+
+            let id = json!(bs58::encode(document.id.as_slice()).into_string());
+            assert_eq!(documents["documents"][0][0], id);
+            assert_eq!(documents["documents"][0][1]["$id"], id);
+            assert_eq!(documents["documents"][0][1]["name"], json!("Cutie"));
+
+            let request = GetDocumentsRequest {
+                prove: true,
+                ..request
+            };
+            let result = platform
+                .query_v0("/documents/json", &request.encode_to_vec())
+                .expect("expected to query the documents");
+            assert!(!result.is_valid());
+        }
+    }
 }
@@ -34,8 +34,6 @@ extern crate core;
 
 use dashcore_rpc::dashcore::QuorumHash;
 
-use dpp::bls_signatures::PrivateKey as BlsPrivateKey;
-
 use drive_abci::test::helpers::setup::TestPlatformBuilder;
 use drive_abci::{config::PlatformConfig, test::helpers::setup::TempPlatform};
 use frequency::Frequency;
@@ -44,21 +42,11 @@ use std::collections::BTreeMap;
 
 use strategy::{ChainExecutionOutcome, ChainExecutionParameters, Strategy, StrategyRandomness};
 
+use strategy_simulator::{execution, frequency, operations, query, strategy};
+
 mod core_update_tests;
-mod execution;
 mod failures;
-mod frequency;
-mod masternode_list_item_helpers;
-mod masternodes;
-mod operations;
-mod query;
-mod signer;
-mod strategy;
-mod transitions;
 mod upgrade_fork_tests;
-mod verify_state_transitions;
-
-pub type BlockHeight = u64;
 
 #[cfg(test)]
 mod tests {
@@ -70,14 +58,20 @@ mod tests {
     };
     use crate::query::QueryStrategy;
     use crate::strategy::MasternodeListChangesStrategy;
+    use dapi_grpc::platform::v0::{GetBlockAttestationRequest, GetBlockAttestationResponse};
     use dashcore_rpc::dashcore::hashes::Hash;
     use dashcore_rpc::dashcore::BlockHash;
     use dashcore_rpc::dashcore_rpc_json::ExtendedQuorumDetails;
     use dpp::data_contract::extra::common::json_document_to_created_contract;
     use dpp::util::hash::hash_to_hex_string;
     use drive_abci::config::PlatformTestConfig;
+    use drive_abci::mimic::CHAIN_ID;
+    use drive_abci::platform_types::block_attestation::v0::{
+        BlockAttestation, SignedBlockAttestation,
+    };
     use drive_abci::rpc::core::QuorumListExtendedInfo;
     use itertools::Itertools;
+    use prost::Message;
     use tenderdash_abci::proto::abci::{RequestInfo, ResponseInfo};
     use tenderdash_abci::proto::types::CoreChainLock;
     use tenderdash_abci::Application;
@@ -201,6 +195,110 @@ mod tests {
         run_chain_for_strategy(&mut platform, 50, strategy, config, 13);
     }
 
+    #[test]
+    fn run_chain_block_signing_stores_block_attestations_by_height() {
+        let strategy = Strategy {
+            contracts_with_updates: vec![],
+            operations: vec![],
+            identities_inserts: Frequency {
+                times_per_block_range: Default::default(),
+                chance_per_block: None,
+            },
+            total_hpmns: 100,
+            extra_normal_mns: 0,
+            quorum_count: 24,
+            upgrading_info: None,
+            core_height_increase: Frequency {
+                times_per_block_range: Default::default(),
+                chance_per_block: None,
+            },
+            proposer_strategy: Default::default(),
+            rotate_quorums: false,
+            failure_testing: None,
+            query_testing: None,
+            verify_state_transition_results: false,
+        };
+        let config = PlatformConfig {
+            verify_sum_trees: true,
+            quorum_size: 100,
+            validator_set_quorum_rotation_block_count: 25,
+            block_spacing_ms: 3000,
+            testing_configs: PlatformTestConfig::default(),
+            ..Default::default()
+        };
+        let mut platform = TestPlatformBuilder::new()
+            .with_config(config.clone())
+            .build_with_mock_rpc();
+
+        platform
+            .core_rpc
+            .expect_get_best_chain_lock()
+            .returning(move || {
+                Ok(CoreChainLock {
+                    core_block_height: 10,
+                    core_block_hash: [1; 32].to_vec(),
+                    signature: [2; 96].to_vec(),
+                })
+            });
+        let outcome = run_chain_for_strategy(&mut platform, 10, strategy, config, 13);
+
+        let quorum_type = outcome.abci_app.platform.config.quorum_type();
+        let public_key = &outcome.current_quorum().public_key;
+
+        let query_block_attestation = |height: u64| {
+            let request = GetBlockAttestationRequest { height }.encode_to_vec();
+            let query_data = outcome
+                .abci_app
+                .platform
+                .query("/blockAttestation", request.as_slice())
+                .expect("expected to run query")
+                .into_data()
+                .expect("expected data on query_validation_result");
+            GetBlockAttestationResponse::decode(query_data.as_slice())
+                .expect("expected to deserialize")
+                .block_attestation
+        };
+
+        for (height, app_hash) in outcome.app_hashes.iter() {
+            let block_attestation =
+                query_block_attestation(*height).expect("expected an attestation for every block");
+            assert_eq!(block_attestation.height, *height);
+            assert_eq!(block_attestation.app_hash, app_hash.to_vec());
+            assert_eq!(block_attestation.quorum_type, quorum_type as u32);
+
+            let signed_attestation = SignedBlockAttestation {
+                attestation: BlockAttestation {
+                    height: block_attestation.height,
+                    app_hash: *app_hash,
+                    epoch: block_attestation.epoch as u16,
+                    protocol_version: block_attestation.protocol_version,
+                },
+                quorum_hash: block_attestation
+                    .quorum_hash
+                    .try_into()
+                    .expect("expected 32 bytes"),
+                round: block_attestation.round,
+                signature: block_attestation
+                    .signature
+                    .try_into()
+                    .expect("expected 96 bytes"),
+            };
+            assert!(signed_attestation
+                .verify_signature(CHAIN_ID, quorum_type, public_key)
+                .is_valid());
+        }
+
+        let (last_height, _) = outcome
+            .app_hashes
+            .last_key_value()
+            .expect("expected blocks");
+        assert_eq!(
+            query_block_attestation(0).map(|block_attestation| block_attestation.height),
+            Some(*last_height)
+        );
+        assert_eq!(query_block_attestation(last_height + 1), None);
+    }
+
     #[test]
     fn run_chain_stop_and_restart() {
         let strategy = Strategy {
@@ -2528,4 +2626,125 @@ mod tests {
         // We transferred funds to the last identity, so we need to check that last identity balance was increased
         assert!(last_identity_balance > 100000000000u64);
     }
+
+    #[test]
+    fn run_chain_parallel_state_transition_validation_is_equivalent_to_sequential() {
+        let created_contract = json_document_to_created_contract(
+            "tests/supporting_files/contract/dashpay/dashpay-contract-all-mutable.json",
+        )
+        .expect("expected to get contract from a json document");
+
+        let contract = &created_contract.data_contract;
+
+        let document_insertion_op = DocumentOp {
+            contract: contract.clone(),
+            action: DocumentAction::DocumentActionInsert,
+            document_type: contract
+                .document_type_for_name("contactRequest")
+                .expect("expected a profile document type")
+                .clone(),
+        };
+
+        let strategy = Strategy {
+            contracts_with_updates: vec![(created_contract, None)],
+            operations: vec![
+                Operation {
+                    op_type: OperationType::Document(document_insertion_op),
+                    frequency: Frequency {
+                        times_per_block_range: 1..20,
+                        chance_per_block: None,
+                    },
+                },
+                Operation {
+                    op_type: OperationType::IdentityTransfer,
+                    frequency: Frequency {
+                        times_per_block_range: 1..3,
+                        chance_per_block: None,
+                    },
+                },
+            ],
+            identities_inserts: Frequency {
+                times_per_block_range: 1..6,
+                chance_per_block: None,
+            },
+            total_hpmns: 100,
+            extra_normal_mns: 0,
+            quorum_count: 24,
+            upgrading_info: None,
+            // blocks changing the core height are validated sequentially
+            core_height_increase: Frequency {
+                times_per_block_range: 1..3,
+                chance_per_block: Some(0.5),
+            },
+            proposer_strategy: Default::default(),
+            rotate_quorums: false,
+            failure_testing: None,
+            query_testing: None,
+            verify_state_transition_results: true,
+        };
+
+        let mut outcomes = Vec::new();
+
+        for parallel_state_transition_validation in [true, false] {
+            let config = PlatformConfig {
+                verify_sum_trees: true,
+                quorum_size: 100,
+                validator_set_quorum_rotation_block_count: 25,
+                block_spacing_ms: 3000,
+                parallel_state_transition_validation,
+                testing_configs: PlatformTestConfig::default_with_no_block_signing(),
+                ..Default::default()
+            };
+            let genesis_core_height = config.abci.genesis_core_height;
+
+            let mut platform = TestPlatformBuilder::new()
+                .with_config(config.clone())
+                .build_with_mock_rpc();
+            platform
+                .core_rpc
+                .expect_get_best_chain_lock()
+                .returning(move || {
+                    Ok(CoreChainLock {
+                        core_block_height: 10,
+                        core_block_hash: [1; 32].to_vec(),
+                        signature: [2; 96].to_vec(),
+                    })
+                });
+            let outcome = run_chain_for_strategy(&mut platform, 15, strategy.clone(), config, 15);
+
+            assert!(
+                outcome
+                    .abci_app
+                    .platform
+                    .state
+                    .read()
+                    .unwrap()
+                    .core_height()
+                    > genesis_core_height,
+                "expected the core height to change during the chain"
+            );
+
+            let app_hash = outcome
+                .abci_app
+                .platform
+                .drive
+                .grove
+                .root_hash(None)
+                .unwrap()
+                .expect("should return app hash");
+
+            outcomes.push((
+                app_hash,
+                outcome.app_hashes,
+                outcome.state_transition_results_per_block,
+            ));
+        }
+
+        let (parallel_app_hash, parallel_app_hashes, parallel_results) = &outcomes[0];
+        let (sequential_app_hash, sequential_app_hashes, sequential_results) = &outcomes[1];
+
+        assert_eq!(parallel_results, sequential_results);
+        assert_eq!(parallel_app_hashes, sequential_app_hashes);
+        assert_eq!(parallel_app_hash, sequential_app_hash);
+    }
 }
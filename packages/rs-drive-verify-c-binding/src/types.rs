@@ -181,6 +181,8 @@ pub struct IdentityPublicKey {
     pub data: *const u8,
     pub has_disabled_at: bool,
     pub disabled_at: u64,
+    pub has_valid_until: bool,
+    pub valid_until: u64,
 }
 
 /// Represents an asset lock proof
@@ -36,6 +36,8 @@ pub(crate) fn build_c_public_keys_struct(identity: &DppIdentity) -> *const *cons
                 data: vec_to_pointer(identity_public_key.data.to_vec()),
                 has_disabled_at: identity_public_key.disabled_at.is_some(),
                 disabled_at: identity_public_key.disabled_at.unwrap_or(0),
+                has_valid_until: identity_public_key.valid_until.is_some(),
+                valid_until: identity_public_key.valid_until.unwrap_or(0),
             })),
         })))
     }
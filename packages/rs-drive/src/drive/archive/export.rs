@@ -0,0 +1,133 @@
+// MIT LICENSE
+//
+// Copyright (c) 2023 Dash Core Group
+//
+// Permission is hereby granted, free of charge, to any
+// person obtaining a copy of this software and associated
+// documentation files (the "Software"), to deal in the
+// Software without restriction, including without
+// limitation the rights to use, copy, modify, merge,
+// publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software
+// is furnished to do so, subject to the following
+// conditions:
+//
+// The above copyright notice and this permission notice
+// shall be included in all copies or substantial portions
+// of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF
+// ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED
+// TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A
+// PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT
+// SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY
+// CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR
+// IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+//
+
+//! Export of the state to an archive.
+//!
+
+use crate::drive::archive::{
+    archive_io, is_archived_file_name, ChecksumWriter, ARCHIVE_FORMAT_VERSION, ARCHIVE_MAGIC,
+    END_OF_RECORDS, FILE_RECORD,
+};
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use grovedb::GroveDb;
+use std::fs::{self, File};
+use std::io::{self, Write};
+use tempfile::TempDir;
+
+impl Drive {
+    /// Exports the committed state to an archive, along with the serialized platform state.
+    ///
+    /// A checkpoint of GroveDB is created in a temporary directory and its files are streamed to
+    /// the writer. Returns the root hash of the exported state.
+    pub fn export<W: Write>(&self, writer: W, platform_state: &[u8]) -> Result<[u8; 32], Error> {
+        let tmp_dir = TempDir::new().map_err(archive_io)?;
+        let checkpoint_path = tmp_dir.path().join("checkpoint");
+        self.grove
+            .create_checkpoint(&checkpoint_path)
+            .map_err(Error::GroveDB)?;
+
+        // The root hash is read from the checkpoint rather than from the live state, in which
+        // a block could be committed meanwhile
+        let root_hash = GroveDb::open(&checkpoint_path)
+            .map_err(Error::GroveDB)?
+            .root_hash(None)
+            .unwrap()
+            .map_err(Error::GroveDB)?;
+
+        let mut file_names = fs::read_dir(&checkpoint_path)
+            .map_err(archive_io)?
+            .map(|entry| {
+                let entry = entry.map_err(archive_io)?;
+                let file_name = entry.file_name().into_string().map_err(|file_name| {
+                    Error::Drive(DriveError::CorruptedArchive(format!(
+                        "file name {:?} is not valid unicode",
+                        file_name
+                    )))
+                })?;
+                Ok((entry.file_type().map_err(archive_io)?.is_file(), file_name))
+            })
+            .filter_map(|entry| match entry {
+                Ok((true, file_name)) if is_archived_file_name(&file_name) => Some(Ok(file_name)),
+                Ok(_) => None,
+                Err(e) => Some(Err(e)),
+            })
+            .collect::<Result<Vec<String>, Error>>()?;
+        file_names.sort();
+
+        let mut archive = ChecksumWriter::new(writer);
+
+        archive.write_all(ARCHIVE_MAGIC).map_err(archive_io)?;
+        archive
+            .write_all(&ARCHIVE_FORMAT_VERSION.to_be_bytes())
+            .map_err(archive_io)?;
+        archive.write_all(&root_hash).map_err(archive_io)?;
+
+        let platform_state_len = u32::try_from(platform_state.len()).map_err(|_| {
+            Error::Drive(DriveError::CorruptedArchive(
+                "platform state is too large".to_string(),
+            ))
+        })?;
+        archive
+            .write_all(&platform_state_len.to_be_bytes())
+            .map_err(archive_io)?;
+        archive.write_all(platform_state).map_err(archive_io)?;
+
+        for file_name in file_names {
+            let mut file = File::open(checkpoint_path.join(&file_name)).map_err(archive_io)?;
+            let file_len = file.metadata().map_err(archive_io)?.len();
+
+            archive.write_all(&[FILE_RECORD]).map_err(archive_io)?;
+            // File names of RocksDB are short
+            archive
+                .write_all(&(file_name.len() as u16).to_be_bytes())
+                .map_err(archive_io)?;
+            archive
+                .write_all(file_name.as_bytes())
+                .map_err(archive_io)?;
+            archive
+                .write_all(&file_len.to_be_bytes())
+                .map_err(archive_io)?;
+
+            let copied_len = io::copy(&mut file, &mut archive).map_err(archive_io)?;
+            if copied_len != file_len {
+                return Err(Error::Drive(DriveError::CorruptedArchive(format!(
+                    "file {} changed while it was exported",
+                    file_name
+                ))));
+            }
+        }
+
+        archive.write_all(&[END_OF_RECORDS]).map_err(archive_io)?;
+        archive.finish()?;
+
+        Ok(root_hash)
+    }
+}
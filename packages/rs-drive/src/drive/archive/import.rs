@@ -0,0 +1,157 @@
+// MIT LICENSE
+//
+// Copyright (c) 2023 Dash Core Group
+//
+// Permission is hereby granted, free of charge, to any
+// person obtaining a copy of this software and associated
+// documentation files (the "Software"), to deal in the
+// Software without restriction, including without
+// limitation the rights to use, copy, modify, merge,
+// publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software
+// is furnished to do so, subject to the following
+// conditions:
+//
+// The above copyright notice and this permission notice
+// shall be included in all copies or substantial portions
+// of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF
+// ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED
+// TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A
+// PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT
+// SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY
+// CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR
+// IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+//
+
+//! Import of the state from an archive.
+//!
+
+use crate::drive::archive::{
+    archive_io, is_archived_file_name, ArchiveInfo, ChecksumReader, ARCHIVE_FORMAT_VERSION,
+    ARCHIVE_MAGIC, END_OF_RECORDS, FILE_RECORD,
+};
+use crate::drive::config::DriveConfig;
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use std::ffi::OsStr;
+use std::fs::{self, File};
+use std::io::{self, Read};
+use std::path::Path;
+
+impl Drive {
+    /// Imports an archive created with [`Drive::export`] into an empty directory and opens it.
+    ///
+    /// The checksum of the archive is verified, and the root hash of the imported state against
+    /// the exported one. On failure, the directory is left as is and must be removed before
+    /// importing again.
+    pub fn import<R: Read, P: AsRef<Path>>(
+        reader: R,
+        path: P,
+        config: Option<DriveConfig>,
+    ) -> Result<(Self, ArchiveInfo), Error> {
+        let path = path.as_ref();
+        if path.exists() && fs::read_dir(path).map_err(archive_io)?.next().is_some() {
+            return Err(Error::Drive(DriveError::ImportDestinationNotEmpty(
+                path.display().to_string(),
+            )));
+        }
+        fs::create_dir_all(path).map_err(archive_io)?;
+
+        let mut archive = ChecksumReader::new(reader);
+
+        if &archive.read_array::<8>()? != ARCHIVE_MAGIC {
+            return Err(corrupted_archive("not a drive archive"));
+        }
+        let format_version = u16::from_be_bytes(archive.read_array()?);
+        if format_version != ARCHIVE_FORMAT_VERSION {
+            return Err(corrupted_archive(format!(
+                "unsupported archive format version {}, expected {}",
+                format_version, ARCHIVE_FORMAT_VERSION
+            )));
+        }
+        let root_hash = archive.read_array::<32>()?;
+
+        let platform_state_len = u32::from_be_bytes(archive.read_array()?) as u64;
+        let mut platform_state = vec![];
+        (&mut archive)
+            .take(platform_state_len)
+            .read_to_end(&mut platform_state)
+            .map_err(archive_io)?;
+        if platform_state.len() as u64 != platform_state_len {
+            return Err(corrupted_archive("truncated platform state"));
+        }
+
+        loop {
+            match archive.read_array::<1>()? {
+                [END_OF_RECORDS] => break,
+                [FILE_RECORD] => {}
+                [marker] => {
+                    return Err(corrupted_archive(format!(
+                        "unknown record marker {}",
+                        marker
+                    )))
+                }
+            }
+
+            let file_name_len = u16::from_be_bytes(archive.read_array()?) as u64;
+            let mut file_name = vec![];
+            (&mut archive)
+                .take(file_name_len)
+                .read_to_end(&mut file_name)
+                .map_err(archive_io)?;
+            let file_name = String::from_utf8(file_name)
+                .map_err(|_| corrupted_archive("file name is not valid unicode"))?;
+            // Files are only written in the import directory
+            if !is_archived_file_name(&file_name)
+                || Path::new(&file_name).file_name() != Some(OsStr::new(&file_name))
+            {
+                return Err(corrupted_archive(format!(
+                    "invalid file name {:?}",
+                    file_name
+                )));
+            }
+
+            let file_len = u64::from_be_bytes(archive.read_array()?);
+            let mut file = File::create(path.join(&file_name)).map_err(archive_io)?;
+            let copied_len =
+                io::copy(&mut (&mut archive).take(file_len), &mut file).map_err(archive_io)?;
+            if copied_len != file_len {
+                return Err(corrupted_archive(format!("truncated file {}", file_name)));
+            }
+        }
+
+        archive.verify_checksum()?;
+
+        let drive = Drive::open(path, config)?;
+
+        let imported_root_hash = drive
+            .grove
+            .root_hash(None)
+            .unwrap()
+            .map_err(Error::GroveDB)?;
+        if imported_root_hash != root_hash {
+            return Err(corrupted_archive(format!(
+                "root hash {} of the imported state does not match the exported root hash {}",
+                hex::encode(imported_root_hash),
+                hex::encode(root_hash)
+            )));
+        }
+
+        Ok((
+            drive,
+            ArchiveInfo {
+                root_hash,
+                platform_state,
+            },
+        ))
+    }
+}
+
+fn corrupted_archive(message: impl Into<String>) -> Error {
+    Error::Drive(DriveError::CorruptedArchive(message.into()))
+}
@@ -0,0 +1,232 @@
+// MIT LICENSE
+//
+// Copyright (c) 2023 Dash Core Group
+//
+// Permission is hereby granted, free of charge, to any
+// person obtaining a copy of this software and associated
+// documentation files (the "Software"), to deal in the
+// Software without restriction, including without
+// limitation the rights to use, copy, modify, merge,
+// publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software
+// is furnished to do so, subject to the following
+// conditions:
+//
+// The above copyright notice and this permission notice
+// shall be included in all copies or substantial portions
+// of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF
+// ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED
+// TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A
+// PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT
+// SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY
+// CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR
+// IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+//
+
+//! Drive State Archives.
+//!
+//! This module defines the export of the committed state to a portable archive, and its import
+//! on another machine, to move a node or to seed a devnet or testnet with real data.
+//!
+//! The root hash of GroveDB depends on the shape of the Merk trees, which depends on the order
+//! elements were inserted in, so elements can't be re-inserted one by one to rebuild the same
+//! state. An archive holds the files of a GroveDB checkpoint instead:
+//!
+//! - the magic bytes `DRIVEARC` and the archive format version, as a big endian u16
+//! - the root hash of the exported state
+//! - the serialized platform state, prefixed with its length as a big endian u32
+//! - one record per file, made of the file name prefixed with its length as a big endian u16,
+//!   and the file content prefixed with its length as a big endian u64
+//! - an end of records marker
+//! - the SHA-256 checksum of everything before it
+//!
+
+mod export;
+mod import;
+
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use dpp::dashcore::hashes::{sha256, Hash, HashEngine};
+use std::io::{self, Read, Write};
+
+/// Magic bytes an archive starts with
+const ARCHIVE_MAGIC: &[u8; 8] = b"DRIVEARC";
+
+/// Version of the archive format
+pub const ARCHIVE_FORMAT_VERSION: u16 = 1;
+
+/// Marker of a file record
+const FILE_RECORD: u8 = 1;
+
+/// Marker of the end of the records
+const END_OF_RECORDS: u8 = 0;
+
+/// What an archive holds besides the state
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ArchiveInfo {
+    /// Root hash of the exported state
+    pub root_hash: [u8; 32],
+    /// Serialized platform state, opaque to Drive
+    pub platform_state: Vec<u8>,
+}
+
+fn archive_io(e: io::Error) -> Error {
+    Error::Drive(DriveError::ArchiveIo(e))
+}
+
+/// Whether a file of the GroveDB directory goes into archives
+///
+/// The lock and the informational logs of RocksDB belong to the instance that wrote them.
+fn is_archived_file_name(file_name: &str) -> bool {
+    file_name != "LOCK" && !file_name.starts_with("LOG")
+}
+
+/// Writer hashing what is written to it
+struct ChecksumWriter<W> {
+    writer: W,
+    engine: sha256::HashEngine,
+}
+
+impl<W: Write> ChecksumWriter<W> {
+    fn new(writer: W) -> Self {
+        Self {
+            writer,
+            engine: sha256::Hash::engine(),
+        }
+    }
+
+    /// Writes the checksum of everything written so far
+    fn finish(mut self) -> Result<(), Error> {
+        let checksum = sha256::Hash::from_engine(self.engine).into_inner();
+        self.writer.write_all(&checksum).map_err(archive_io)?;
+        self.writer.flush().map_err(archive_io)
+    }
+}
+
+impl<W: Write> Write for ChecksumWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let written = self.writer.write(buf)?;
+        self.engine.input(&buf[..written]);
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+/// Reader hashing what is read from it
+struct ChecksumReader<R> {
+    reader: R,
+    engine: sha256::HashEngine,
+}
+
+impl<R: Read> ChecksumReader<R> {
+    fn new(reader: R) -> Self {
+        Self {
+            reader,
+            engine: sha256::Hash::engine(),
+        }
+    }
+
+    fn read_array<const N: usize>(&mut self) -> Result<[u8; N], Error> {
+        let mut bytes = [0; N];
+        self.read_exact(&mut bytes).map_err(archive_io)?;
+        Ok(bytes)
+    }
+
+    /// Reads the checksum and verifies it against everything read before
+    fn verify_checksum(mut self) -> Result<(), Error> {
+        let checksum = sha256::Hash::from_engine(self.engine).into_inner();
+        let mut expected_checksum = [0; 32];
+        self.reader
+            .read_exact(&mut expected_checksum)
+            .map_err(archive_io)?;
+        if checksum != expected_checksum {
+            return Err(Error::Drive(DriveError::CorruptedArchive(
+                "checksum mismatch".to_string(),
+            )));
+        }
+        Ok(())
+    }
+}
+
+impl<R: Read> Read for ChecksumReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let read = self.reader.read(buf)?;
+        self.engine.input(&buf[..read]);
+        Ok(read)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::drive::Drive;
+    use crate::tests::helpers::setup::setup_drive_with_initial_state_structure;
+    use tempfile::TempDir;
+
+    #[test]
+    fn should_export_and_import_state() {
+        let drive = setup_drive_with_initial_state_structure();
+
+        let mut archive = vec![];
+        let root_hash = drive
+            .export(&mut archive, b"platform state")
+            .expect("expected to export state");
+
+        assert_eq!(
+            root_hash,
+            drive
+                .grove
+                .root_hash(None)
+                .unwrap()
+                .expect("expected a root hash")
+        );
+
+        let tmp_dir = TempDir::new().expect("expected a temporary directory");
+        let (imported_drive, info) =
+            Drive::import(archive.as_slice(), tmp_dir.path().join("imported"), None)
+                .expect("expected to import state");
+
+        assert_eq!(
+            info,
+            ArchiveInfo {
+                root_hash,
+                platform_state: b"platform state".to_vec(),
+            }
+        );
+        assert_eq!(
+            imported_drive
+                .grove
+                .root_hash(None)
+                .unwrap()
+                .expect("expected a root hash"),
+            root_hash
+        );
+    }
+
+    #[test]
+    fn should_not_import_corrupted_archives() {
+        let drive = setup_drive_with_initial_state_structure();
+
+        let mut archive = vec![];
+        drive
+            .export(&mut archive, b"platform state")
+            .expect("expected to export state");
+
+        // Corrupt the first byte of the platform state, after the magic bytes, the version, the
+        // root hash and the length
+        archive[ARCHIVE_MAGIC.len() + 2 + 32 + 4] ^= 1;
+
+        let tmp_dir = TempDir::new().expect("expected a temporary directory");
+        assert!(matches!(
+            Drive::import(archive.as_slice(), tmp_dir.path().join("imported"), None),
+            Err(Error::Drive(DriveError::CorruptedArchive(_)))
+        ));
+    }
+}
@@ -2,7 +2,10 @@ use crate::drive::batch::drive_op_batch::DriveLowLevelOperationConverter;
 use crate::drive::flags::StorageFlags;
 use crate::drive::Drive;
 use crate::error::Error;
+use crate::fee::credits::Credits;
 use crate::fee::op::LowLevelDriveOperation;
+use crate::fee::op::LowLevelDriveOperation::PreCalculatedFeeResult;
+use crate::fee::result::FeeResult;
 use dpp::block::block_info::BlockInfo;
 use dpp::data_contract::DataContract as Contract;
 use dpp::platform_value::Identifier;
@@ -45,6 +48,15 @@ pub enum ContractOperationType<'a> {
         /// Storage flags for the contract
         storage_flags: Option<Cow<'a, StorageFlags>>,
     },
+    /// Applies a contract update, building the indices it adds from the existing documents.
+    ApplyContractWithIndexBackfill {
+        /// The contract
+        contract: Cow<'a, Contract>,
+        /// Storage flags for the contract
+        storage_flags: Option<Cow<'a, StorageFlags>>,
+        /// The processing fee of counting the existing documents when validating the update
+        validation_processing_fee: Credits,
+    },
 }
 
 impl DriveLowLevelOperationConverter for ContractOperationType<'_> {
@@ -73,6 +85,7 @@ impl DriveLowLevelOperationConverter for ContractOperationType<'_> {
                     block_info,
                     estimated_costs_only_with_layer_info,
                     storage_flags,
+                    false,
                     transaction,
                 )
             }
@@ -86,6 +99,7 @@ impl DriveLowLevelOperationConverter for ContractOperationType<'_> {
                 block_info,
                 estimated_costs_only_with_layer_info,
                 storage_flags,
+                false,
                 transaction,
             ),
             ContractOperationType::ApplyContract {
@@ -96,8 +110,28 @@ impl DriveLowLevelOperationConverter for ContractOperationType<'_> {
                 block_info,
                 estimated_costs_only_with_layer_info,
                 storage_flags,
+                false,
                 transaction,
             ),
+            ContractOperationType::ApplyContractWithIndexBackfill {
+                contract,
+                storage_flags,
+                validation_processing_fee,
+            } => {
+                let mut drive_operations = drive.apply_contract_operations(
+                    contract.borrow(),
+                    block_info,
+                    estimated_costs_only_with_layer_info,
+                    storage_flags,
+                    true,
+                    transaction,
+                )?;
+                drive_operations.push(PreCalculatedFeeResult(FeeResult {
+                    processing_fee: validation_processing_fee,
+                    ..Default::default()
+                }));
+                Ok(drive_operations)
+            }
         }
     }
 }
@@ -4,10 +4,10 @@ use crate::error::Error;
 use crate::fee::op::LowLevelDriveOperation;
 use dpp::block::block_info::BlockInfo;
 use dpp::identity::{Identity, IdentityPublicKey, KeyID, TimestampMillis};
-use dpp::prelude::Revision;
+use dpp::prelude::{IdentityNonce, Revision};
 use grovedb::batch::KeyInfoPath;
 use grovedb::{EstimatedLayerInformation, TransactionArg};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 /// Operations on Identities
 #[derive(Clone, Debug)]
@@ -24,6 +24,12 @@ pub enum IdentityOperationType {
         /// The added balance
         added_balance: u64,
     },
+    /// Adds balances to several identities, one balance update per identity.
+    /// The fees scale with the number of identities.
+    AddToIdentitiesBalances {
+        /// The balance added to each identity, keyed by identity id
+        added_balances: BTreeMap<[u8; 32], u64>,
+    },
     /// Removes balance from an identity
     RemoveFromIdentityBalance {
         /// The identity id of the identity
@@ -51,6 +57,16 @@ pub enum IdentityOperationType {
         disable_at: TimestampMillis,
     },
 
+    /// Schedule the expiration of Identity Keys
+    SetIdentityKeysValidUntil {
+        /// The identity id of the identity
+        identity_id: [u8; 32],
+        /// The keys to be expired
+        keys_ids: Vec<KeyID>,
+        /// The time from which they can no longer be used
+        valid_until: TimestampMillis,
+    },
+
     /// Re-Enable Identity Keys
     /// This should only be used internally in Drive (for masternode identities)
     ReEnableIdentityKeys {
@@ -67,6 +83,22 @@ pub enum IdentityOperationType {
         /// The revision we are updating to
         revision: Revision,
     },
+    /// Updates an identity nonce
+    UpdateIdentityNonce {
+        /// The identity id
+        identity_id: [u8; 32],
+        /// The nonce we are updating to
+        nonce: IdentityNonce,
+    },
+    /// Updates the nonce of an identity for a contract
+    UpdateIdentityContractNonce {
+        /// The identity id
+        identity_id: [u8; 32],
+        /// The contract id
+        contract_id: [u8; 32],
+        /// The nonce we are updating to
+        nonce: IdentityNonce,
+    },
 }
 
 impl DriveLowLevelOperationConverter for IdentityOperationType {
@@ -97,6 +129,13 @@ impl DriveLowLevelOperationConverter for IdentityOperationType {
                 estimated_costs_only_with_layer_info,
                 transaction,
             ),
+            IdentityOperationType::AddToIdentitiesBalances { added_balances } => drive
+                .add_to_identities_balances_operations(
+                    added_balances,
+                    &block_info.epoch,
+                    estimated_costs_only_with_layer_info,
+                    transaction,
+                ),
             IdentityOperationType::RemoveFromIdentityBalance {
                 identity_id,
                 balance_to_remove,
@@ -129,6 +168,17 @@ impl DriveLowLevelOperationConverter for IdentityOperationType {
                 estimated_costs_only_with_layer_info,
                 transaction,
             ),
+            IdentityOperationType::SetIdentityKeysValidUntil {
+                identity_id,
+                keys_ids,
+                valid_until,
+            } => drive.set_identity_keys_valid_until_operations(
+                identity_id,
+                keys_ids,
+                valid_until,
+                estimated_costs_only_with_layer_info,
+                transaction,
+            ),
             IdentityOperationType::ReEnableIdentityKeys {
                 identity_id,
                 keys_ids,
@@ -146,6 +196,24 @@ impl DriveLowLevelOperationConverter for IdentityOperationType {
                 revision,
                 estimated_costs_only_with_layer_info,
             )]),
+            IdentityOperationType::UpdateIdentityNonce { identity_id, nonce } => Ok(vec![drive
+                .update_identity_nonce_operation(
+                    identity_id,
+                    nonce,
+                    estimated_costs_only_with_layer_info,
+                )]),
+            IdentityOperationType::UpdateIdentityContractNonce {
+                identity_id,
+                contract_id,
+                nonce,
+            } => drive.update_identity_contract_nonce_operations(
+                identity_id,
+                contract_id,
+                nonce,
+                &block_info.epoch,
+                estimated_costs_only_with_layer_info,
+                transaction,
+            ),
         }
     }
 }
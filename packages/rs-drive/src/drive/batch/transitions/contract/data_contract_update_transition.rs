@@ -11,13 +11,28 @@ impl DriveHighLevelOperationConverter for DataContractUpdateTransitionAction {
         self,
         _epoch: &Epoch,
     ) -> Result<Vec<DriveOperation<'a>>, Error> {
-        let DataContractUpdateTransitionAction { data_contract, .. } = self;
+        let DataContractUpdateTransitionAction {
+            data_contract,
+            backfill_indices,
+            backfill_validation_processing_fee,
+            ..
+        } = self;
         let mut drive_operations = vec![];
         // We must create the contract
-        drive_operations.push(ContractOperation(ContractOperationType::ApplyContract {
-            contract: Cow::Owned(data_contract),
-            storage_flags: None,
-        }));
+        if backfill_indices {
+            drive_operations.push(ContractOperation(
+                ContractOperationType::ApplyContractWithIndexBackfill {
+                    contract: Cow::Owned(data_contract),
+                    storage_flags: None,
+                    validation_processing_fee: backfill_validation_processing_fee,
+                },
+            ));
+        } else {
+            drive_operations.push(ContractOperation(ContractOperationType::ApplyContract {
+                contract: Cow::Owned(data_contract),
+                storage_flags: None,
+            }));
+        }
 
         Ok(drive_operations)
     }
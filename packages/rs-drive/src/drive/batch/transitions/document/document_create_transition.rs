@@ -32,6 +32,7 @@ impl DriveHighLevelDocumentOperationConverter for DocumentCreateTransitionAction
             document_type_name,
             data_contract_id,
             data_contract,
+            ..
         } = base;
 
         let document_type = data_contract.document_type_for_name(document_type_name.as_str())?;
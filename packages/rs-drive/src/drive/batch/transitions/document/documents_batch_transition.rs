@@ -1,9 +1,13 @@
 use crate::drive::batch::transitions::document::DriveHighLevelDocumentOperationConverter;
 use crate::drive::batch::transitions::DriveHighLevelOperationConverter;
-use crate::drive::batch::DriveOperation;
+use crate::drive::batch::DriveOperation::IdentityOperation;
+use crate::drive::batch::{DriveOperation, IdentityOperationType};
 use crate::error::Error;
 use dpp::block::epoch::Epoch;
 use dpp::document::state_transition::documents_batch_transition::DocumentsBatchTransitionAction;
+use dpp::identifier::Identifier;
+use dpp::prelude::IdentityNonce;
+use std::collections::BTreeMap;
 
 impl DriveHighLevelOperationConverter for DocumentsBatchTransitionAction {
     fn into_high_level_drive_operations<'a>(
@@ -15,12 +19,34 @@ impl DriveHighLevelOperationConverter for DocumentsBatchTransitionAction {
             transitions,
             ..
         } = self;
-        Ok(transitions
+
+        // Transitions of the same contract share the same nonce, this is checked in validation
+        let identity_contract_nonces: BTreeMap<Identifier, IdentityNonce> = transitions
+            .iter()
+            .map(|transition| {
+                let base = transition.base();
+                (base.data_contract_id, base.identity_contract_nonce)
+            })
+            .collect();
+
+        let mut drive_operations: Vec<DriveOperation> = transitions
             .into_iter()
             .map(|transition| transition.into_high_level_document_drive_operations(epoch, owner_id))
             .collect::<Result<Vec<Vec<DriveOperation>>, Error>>()?
             .into_iter()
             .flatten()
-            .collect())
+            .collect();
+
+        drive_operations.extend(identity_contract_nonces.into_iter().map(
+            |(contract_id, nonce)| {
+                IdentityOperation(IdentityOperationType::UpdateIdentityContractNonce {
+                    identity_id: owner_id.to_buffer(),
+                    contract_id: contract_id.to_buffer(),
+                    nonce,
+                })
+            },
+        ));
+
+        Ok(drive_operations)
     }
 }
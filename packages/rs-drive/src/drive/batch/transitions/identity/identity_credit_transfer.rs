@@ -2,6 +2,7 @@ use crate::drive::batch::transitions::DriveHighLevelOperationConverter;
 use crate::drive::batch::DriveOperation::IdentityOperation;
 use crate::drive::batch::{DriveOperation, IdentityOperationType};
 
+use crate::error::drive::DriveError;
 use crate::error::Error;
 use dpp::block::epoch::Epoch;
 use dpp::identity::state_transition::identity_credit_transfer_transition::IdentityCreditTransferTransitionAction;
@@ -11,21 +12,33 @@ impl DriveHighLevelOperationConverter for IdentityCreditTransferTransitionAction
         self,
         _epoch: &Epoch,
     ) -> Result<Vec<DriveOperation<'a>>, Error> {
+        let total_transfer_amount = self.total_transfer_amount().ok_or(Error::Drive(
+            DriveError::CorruptedCodeExecution(
+                "credit transfer total amount overflow should be checked in validation",
+            ),
+        ))?;
+
         let IdentityCreditTransferTransitionAction {
-            recipient_id,
+            recipients,
             identity_id,
-            transfer_amount,
+            nonce,
             ..
         } = self;
 
+        let added_balances = recipients
+            .into_iter()
+            .map(|(recipient_id, amount)| (recipient_id.to_buffer(), amount))
+            .collect();
+
         let drive_operations = vec![
             IdentityOperation(IdentityOperationType::RemoveFromIdentityBalance {
                 identity_id: identity_id.to_buffer(),
-                balance_to_remove: transfer_amount,
+                balance_to_remove: total_transfer_amount,
             }),
-            IdentityOperation(IdentityOperationType::AddToIdentityBalance {
-                identity_id: recipient_id.to_buffer(),
-                added_balance: transfer_amount,
+            IdentityOperation(IdentityOperationType::AddToIdentitiesBalances { added_balances }),
+            IdentityOperation(IdentityOperationType::UpdateIdentityNonce {
+                identity_id: identity_id.to_buffer(),
+                nonce,
             }),
         ];
         Ok(drive_operations)
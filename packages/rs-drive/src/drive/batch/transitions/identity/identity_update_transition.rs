@@ -4,7 +4,7 @@ use crate::drive::batch::{DriveOperation, IdentityOperationType};
 
 use crate::error::Error;
 use dpp::block::epoch::Epoch;
-use dpp::identity::IdentityPublicKey;
+use dpp::identity::{IdentityPublicKey, KeyID};
 
 use dpp::identity::state_transition::identity_update_transition::IdentityUpdateTransitionAction;
 
@@ -17,6 +17,8 @@ impl DriveHighLevelOperationConverter for IdentityUpdateTransitionAction {
             add_public_keys,
             disable_public_keys,
             public_keys_disabled_at,
+            expire_public_keys,
+            public_keys_valid_until,
             identity_id,
             revision,
             ..
@@ -45,6 +47,22 @@ impl DriveHighLevelOperationConverter for IdentityUpdateTransitionAction {
                 },
             ));
         }
+        if let Some(public_keys_valid_until) = public_keys_valid_until {
+            // Keys that are disabled in the same transition don't need to expire
+            let keys_ids: Vec<KeyID> = expire_public_keys
+                .into_iter()
+                .filter(|key_id| !disable_public_keys.contains(key_id))
+                .collect();
+            if !keys_ids.is_empty() {
+                drive_operations.push(IdentityOperation(
+                    IdentityOperationType::SetIdentityKeysValidUntil {
+                        identity_id: identity_id.to_buffer(),
+                        keys_ids,
+                        valid_until: public_keys_valid_until,
+                    },
+                ));
+            }
+        }
         if let Some(public_keys_disabled_at) = public_keys_disabled_at {
             if !disable_public_keys.is_empty() {
                 drive_operations.push(IdentityOperation(
@@ -1,15 +1,31 @@
+#[cfg(feature = "full")]
+use crate::drive::config::DataContractCacheEvictionPolicy;
 #[cfg(any(feature = "full", feature = "verify"))]
 use crate::drive::contract::ContractFetchInfo;
 #[cfg(any(feature = "full", feature = "verify"))]
 use dpp::identity::TimestampMillis;
+#[cfg(feature = "full")]
+use dpp::identity::{KeyID, KeyType};
 #[cfg(any(feature = "full", feature = "verify"))]
 use dpp::util::deserializer::ProtocolVersion;
+#[cfg(feature = "full")]
+use dpp::util::hash::hash;
+#[cfg(feature = "full")]
+use moka::notification::RemovalCause;
 #[cfg(any(feature = "full", feature = "verify"))]
 use moka::sync::Cache;
 #[cfg(any(feature = "full", feature = "verify"))]
 use nohash_hasher::IntMap;
+#[cfg(feature = "full")]
+use std::collections::BTreeMap;
+#[cfg(feature = "full")]
+use std::collections::HashMap;
+#[cfg(feature = "full")]
+use std::sync::atomic::{AtomicU64, Ordering};
 #[cfg(any(feature = "full", feature = "verify"))]
 use std::sync::Arc;
+#[cfg(feature = "full")]
+use std::sync::Mutex;
 
 /// Drive cache struct
 #[cfg(feature = "full")]
@@ -20,6 +36,8 @@ pub struct DriveCache {
     pub genesis_time_ms: Option<TimestampMillis>,
     /// Lazy loaded counter of votes to upgrade protocol version
     pub protocol_versions_counter: Option<IntMap<ProtocolVersion, u64>>,
+    /// Successful state transition signature verifications
+    pub signature_verifications: SignatureVerificationCache,
 }
 
 /// Data Contract cache that handle both non global and block data
@@ -27,15 +45,129 @@ pub struct DriveCache {
 pub struct DataContractCache {
     global_cache: Cache<[u8; 32], Arc<ContractFetchInfo>>,
     block_cache: Cache<[u8; 32], Arc<ContractFetchInfo>>,
+    /// How many times the contracts in the global cache were used, to find the most used ones
+    use_counts: Arc<Mutex<HashMap<[u8; 32], u64>>>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+    insertions: AtomicU64,
+    evictions: Arc<AtomicU64>,
+}
+
+/// Statistics of the data contract cache since it was created
+#[cfg(feature = "full")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DataContractCacheStats {
+    /// Contracts found in cache
+    pub hits: u64,
+    /// Contracts not found in cache
+    pub misses: u64,
+    /// Contracts inserted in cache
+    pub insertions: u64,
+    /// Contracts evicted from the global cache to make space
+    pub evictions: u64,
+    /// Contracts in the global cache
+    pub global_cache_entries: u64,
+    /// Weight of the contracts in the global cache, see [`DataContractCacheEvictionPolicy`]
+    pub global_cache_weighted_size: u64,
+}
+
+/// Identifies a verification of a signature of signed data with a public key
+#[cfg(feature = "full")]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SignatureVerificationCacheKey {
+    /// Hash of the signable bytes followed by the signature, as the signature is not signed
+    pub signed_data_hash: [u8; 32],
+    /// Id of the public key
+    pub public_key_id: KeyID,
+    /// Type of the public key, keys of different types can have the same data
+    pub public_key_type: KeyType,
+    /// Data of the public key
+    pub public_key_data: Vec<u8>,
+}
+
+/// Least recently used cache of successful signature verifications, so that a state transition
+/// is not verified again in check tx, recheck tx and block proposals
+#[cfg(feature = "full")]
+pub struct SignatureVerificationCache {
+    max_capacity: u64,
+    verifications: Mutex<SignatureVerifications>,
+}
+
+#[cfg(feature = "full")]
+#[derive(Default)]
+struct SignatureVerifications {
+    last_uses: HashMap<SignatureVerificationCacheKey, u64>,
+    keys_by_last_use: BTreeMap<u64, SignatureVerificationCacheKey>,
+    use_counter: u64,
 }
 
 #[cfg(feature = "full")]
 impl DataContractCache {
     /// Create a new Data Contract cache instance
     pub fn new(global_cache_max_capacity: u64, block_cache_max_capacity: u64) -> Self {
+        Self::new_with_eviction_policy(
+            global_cache_max_capacity,
+            block_cache_max_capacity,
+            DataContractCacheEvictionPolicy::default(),
+        )
+    }
+
+    /// Create a new Data Contract cache instance evicting contracts with the given policy
+    pub fn new_with_eviction_policy(
+        global_cache_max_capacity: u64,
+        block_cache_max_capacity: u64,
+        eviction_policy: DataContractCacheEvictionPolicy,
+    ) -> Self {
+        let use_counts = Arc::new(Mutex::new(HashMap::new()));
+        let evictions = Arc::new(AtomicU64::new(0));
+
+        let global_cache_use_counts = Arc::clone(&use_counts);
+        let global_cache_evictions = Arc::clone(&evictions);
+
+        let global_cache = Cache::builder()
+            .max_capacity(global_cache_max_capacity)
+            .weigher(move |_, fetch_info| Self::weight(eviction_policy, fetch_info))
+            .eviction_listener(move |contract_id, _, cause| {
+                // The use count of a replaced contract carries over to its new version
+                if cause == RemovalCause::Replaced {
+                    return;
+                }
+                if cause == RemovalCause::Size {
+                    global_cache_evictions.fetch_add(1, Ordering::Relaxed);
+                }
+                global_cache_use_counts
+                    .lock()
+                    .unwrap()
+                    .remove(contract_id.as_ref());
+            })
+            .build();
+
+        let block_cache = Cache::builder()
+            .max_capacity(block_cache_max_capacity)
+            .weigher(move |_, fetch_info| Self::weight(eviction_policy, fetch_info))
+            .build();
+
         Self {
-            global_cache: Cache::new(global_cache_max_capacity),
-            block_cache: Cache::new(block_cache_max_capacity),
+            global_cache,
+            block_cache,
+            use_counts,
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+            insertions: AtomicU64::new(0),
+            evictions,
+        }
+    }
+
+    fn weight(
+        eviction_policy: DataContractCacheEvictionPolicy,
+        fetch_info: &Arc<ContractFetchInfo>,
+    ) -> u32 {
+        match eviction_policy {
+            DataContractCacheEvictionPolicy::Frequency => 1,
+            // The bytes loaded to fetch the contract are dominated by the serialized contract
+            DataContractCacheEvictionPolicy::SizeAndFrequency => {
+                (fetch_info.cost.storage_loaded_bytes / 1024).max(1)
+            }
         }
     }
 
@@ -44,6 +176,8 @@ impl DataContractCache {
     pub fn insert(&mut self, fetch_info: Arc<ContractFetchInfo>, is_block_cache: bool) {
         let data_contract_id_bytes = fetch_info.contract.id.to_buffer();
 
+        self.insertions.fetch_add(1, Ordering::Relaxed);
+
         if is_block_cache {
             self.block_cache.insert(data_contract_id_bytes, fetch_info);
         } else {
@@ -65,7 +199,73 @@ impl DataContractCache {
             None
         };
 
-        maybe_fetch_info.or_else(|| self.global_cache.get(&contract_id))
+        let maybe_fetch_info = maybe_fetch_info.or_else(|| {
+            let maybe_fetch_info = self.global_cache.get(&contract_id);
+            if maybe_fetch_info.is_some() {
+                *self
+                    .use_counts
+                    .lock()
+                    .unwrap()
+                    .entry(contract_id)
+                    .or_default() += 1;
+            }
+            maybe_fetch_info
+        });
+
+        if maybe_fetch_info.is_some() {
+            self.hits.fetch_add(1, Ordering::Relaxed);
+        } else {
+            self.misses.fetch_add(1, Ordering::Relaxed);
+        }
+
+        maybe_fetch_info
+    }
+
+    /// Ids of the contracts in the global cache
+    pub fn global_cache_contract_ids(&self) -> Vec<[u8; 32]> {
+        self.global_cache
+            .iter()
+            .map(|(contract_id, _)| *contract_id)
+            .collect()
+    }
+
+    /// Ids of the contracts in the global cache which were used the most, most used first
+    pub fn most_used_contract_ids(&self, limit: usize) -> Vec<[u8; 32]> {
+        let contract_ids = self.global_cache_contract_ids();
+
+        let use_counts = self.use_counts.lock().unwrap();
+
+        let mut contract_ids: Vec<([u8; 32], u64)> = contract_ids
+            .into_iter()
+            .map(|contract_id| {
+                let use_count = use_counts.get(&contract_id).copied();
+                (contract_id, use_count.unwrap_or_default())
+            })
+            .collect();
+
+        drop(use_counts);
+
+        contract_ids.sort_unstable_by(|(id_a, count_a), (id_b, count_b)| {
+            count_b.cmp(count_a).then_with(|| id_a.cmp(id_b))
+        });
+
+        contract_ids
+            .into_iter()
+            .take(limit)
+            .map(|(contract_id, _)| contract_id)
+            .collect()
+    }
+
+    /// Statistics of the cache
+    pub fn stats(&self) -> DataContractCacheStats {
+        DataContractCacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            insertions: self.insertions.load(Ordering::Relaxed),
+            evictions: self.evictions.load(Ordering::Relaxed),
+            global_cache_entries: self.global_cache.entry_count(),
+            global_cache_weighted_size: self.global_cache.weighted_size(),
+        }
     }
 
     /// Merge block cache to global cache
@@ -81,6 +281,116 @@ impl DataContractCache {
     }
 }
 
+#[cfg(feature = "full")]
+impl SignatureVerificationCacheKey {
+    /// Create a new key for the verification of the signature of the signable bytes
+    pub fn new(
+        signable_bytes: &[u8],
+        signature: &[u8],
+        public_key_id: KeyID,
+        public_key_type: KeyType,
+        public_key_data: &[u8],
+    ) -> Self {
+        Self {
+            signed_data_hash: hash([signable_bytes, signature].concat()),
+            public_key_id,
+            public_key_type,
+            public_key_data: public_key_data.to_vec(),
+        }
+    }
+}
+
+#[cfg(feature = "full")]
+impl SignatureVerifications {
+    fn touch(&mut self, key: &SignatureVerificationCacheKey) -> bool {
+        self.use_counter += 1;
+
+        match self.last_uses.get_mut(key) {
+            Some(last_use) => {
+                self.keys_by_last_use.remove(&*last_use);
+                *last_use = self.use_counter;
+                self.keys_by_last_use.insert(self.use_counter, key.clone());
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+#[cfg(feature = "full")]
+impl SignatureVerificationCache {
+    /// Create a new signature verification cache instance, a max capacity of 0 disables it
+    pub fn new(max_capacity: u64) -> Self {
+        Self {
+            max_capacity,
+            verifications: Mutex::new(SignatureVerifications::default()),
+        }
+    }
+
+    /// Returns true if the signature was already verified successfully
+    pub fn contains(&self, key: &SignatureVerificationCacheKey) -> bool {
+        if self.max_capacity == 0 {
+            return false;
+        }
+
+        self.verifications.lock().unwrap().touch(key)
+    }
+
+    /// Remembers a successful signature verification, evicting the least recently used one
+    /// when the cache is full
+    pub fn insert(&self, key: SignatureVerificationCacheKey) {
+        if self.max_capacity == 0 {
+            return;
+        }
+
+        let mut verifications = self.verifications.lock().unwrap();
+
+        if verifications.touch(&key) {
+            return;
+        }
+
+        if verifications.last_uses.len() as u64 >= self.max_capacity {
+            if let Some((_, evicted_key)) = verifications.keys_by_last_use.pop_first() {
+                verifications.last_uses.remove(&evicted_key);
+            }
+        }
+
+        let last_use = verifications.use_counter;
+        verifications.keys_by_last_use.insert(last_use, key.clone());
+        verifications.last_uses.insert(key, last_use);
+    }
+
+    /// Forgets the verifications made with a public key, when it is disabled
+    pub fn invalidate_public_key(&self, public_key_id: KeyID, public_key_data: &[u8]) {
+        let mut verifications = self.verifications.lock().unwrap();
+
+        let SignatureVerifications {
+            last_uses,
+            keys_by_last_use,
+            ..
+        } = &mut *verifications;
+
+        last_uses.retain(|key, last_use| {
+            let is_public_key = key.public_key_id == public_key_id
+                && key.public_key_data.as_slice() == public_key_data;
+            if is_public_key {
+                keys_by_last_use.remove(&*last_use);
+            }
+            !is_public_key
+        });
+    }
+
+    /// Number of verifications in cache
+    pub fn len(&self) -> usize {
+        self.verifications.lock().unwrap().last_uses.len()
+    }
+
+    /// Returns true if there are no verifications in cache
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
 #[cfg(feature = "full")]
 #[cfg(test)]
 mod tests {
@@ -158,4 +468,156 @@ mod tests {
             assert_eq!(fetch_info_from_cache, fetch_info_block)
         }
     }
+
+    mod stats {
+        use super::*;
+
+        #[test]
+        fn test_hits_and_misses_are_counted() {
+            let mut data_contract_cache = DataContractCache::new(10, 10);
+
+            let fetch_info = Arc::new(ContractFetchInfo::default());
+
+            let contract_id = fetch_info.contract.id.to_buffer();
+
+            assert!(data_contract_cache.get(contract_id, false).is_none());
+
+            data_contract_cache.insert(fetch_info, false);
+
+            assert!(data_contract_cache.get(contract_id, false).is_some());
+            assert!(data_contract_cache.get(contract_id, true).is_some());
+
+            let stats = data_contract_cache.stats();
+
+            assert_eq!(stats.hits, 2);
+            assert_eq!(stats.misses, 1);
+            assert_eq!(stats.insertions, 1);
+        }
+    }
+
+    mod most_used_contract_ids {
+        use super::*;
+        use dpp::prelude::Identifier;
+
+        fn fetch_info_with_id(id: [u8; 32]) -> Arc<ContractFetchInfo> {
+            let mut fetch_info = ContractFetchInfo::default();
+            fetch_info.contract.id = Identifier::new(id);
+            Arc::new(fetch_info)
+        }
+
+        #[test]
+        fn test_most_used_contracts_come_first() {
+            let mut data_contract_cache = DataContractCache::new(10, 10);
+
+            data_contract_cache.insert(fetch_info_with_id([1; 32]), false);
+            data_contract_cache.insert(fetch_info_with_id([2; 32]), false);
+            data_contract_cache.insert(fetch_info_with_id([3; 32]), false);
+
+            data_contract_cache.get([2; 32], false);
+            data_contract_cache.get([2; 32], false);
+            data_contract_cache.get([3; 32], false);
+
+            assert_eq!(
+                data_contract_cache.most_used_contract_ids(2),
+                vec![[2; 32], [3; 32]]
+            );
+        }
+
+        #[test]
+        fn test_block_cache_contracts_are_not_included() {
+            let mut data_contract_cache = DataContractCache::new(10, 10);
+
+            data_contract_cache.insert(fetch_info_with_id([1; 32]), true);
+
+            data_contract_cache.get([1; 32], true);
+
+            assert!(data_contract_cache.most_used_contract_ids(10).is_empty());
+        }
+    }
+
+    mod signature_verifications {
+        use super::*;
+
+        fn key(signable_bytes: &[u8], public_key_id: KeyID) -> SignatureVerificationCacheKey {
+            SignatureVerificationCacheKey::new(
+                signable_bytes,
+                &[1; 65],
+                public_key_id,
+                KeyType::ECDSA_SECP256K1,
+                &[2; 33],
+            )
+        }
+
+        #[test]
+        fn test_least_recently_used_verification_is_evicted() {
+            let cache = SignatureVerificationCache::new(2);
+
+            cache.insert(key(b"first", 0));
+            cache.insert(key(b"second", 0));
+
+            assert!(cache.contains(&key(b"first", 0)));
+
+            cache.insert(key(b"third", 0));
+
+            assert_eq!(cache.len(), 2);
+            assert!(cache.contains(&key(b"first", 0)));
+            assert!(!cache.contains(&key(b"second", 0)));
+            assert!(cache.contains(&key(b"third", 0)));
+        }
+
+        #[test]
+        fn test_verifications_with_a_disabled_key_are_invalidated() {
+            let cache = SignatureVerificationCache::new(10);
+
+            cache.insert(key(b"first", 0));
+            cache.insert(key(b"second", 0));
+            cache.insert(key(b"first", 1));
+
+            cache.invalidate_public_key(0, &[2; 33]);
+
+            assert_eq!(cache.len(), 1);
+            assert!(!cache.contains(&key(b"first", 0)));
+            assert!(cache.contains(&key(b"first", 1)));
+        }
+
+        #[test]
+        fn test_signature_is_part_of_the_key() {
+            let cache = SignatureVerificationCache::new(10);
+
+            cache.insert(key(b"first", 0));
+
+            let other_signature = SignatureVerificationCacheKey::new(
+                b"first",
+                &[3; 65],
+                0,
+                KeyType::ECDSA_SECP256K1,
+                &[2; 33],
+            );
+
+            assert!(!cache.contains(&other_signature));
+        }
+
+        #[test]
+        fn test_key_type_is_part_of_the_key() {
+            let cache = SignatureVerificationCache::new(10);
+
+            cache.insert(SignatureVerificationCacheKey::new(
+                b"first",
+                &[],
+                0,
+                KeyType::ECDSA_HASH160,
+                &[2; 20],
+            ));
+
+            let other_key_type = SignatureVerificationCacheKey::new(
+                b"first",
+                &[],
+                0,
+                KeyType::BIP13_SCRIPT_HASH,
+                &[2; 20],
+            );
+
+            assert!(!cache.contains(&other_key_type));
+        }
+    }
 }
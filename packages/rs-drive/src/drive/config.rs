@@ -42,6 +42,10 @@ pub const DEFAULT_DEFAULT_QUERY_LIMIT: u16 = 100;
 pub const DEFAULT_MAX_QUERY_LIMIT: u16 = 100;
 /// Default maximum number of contracts in cache
 pub const DEFAULT_DATA_CONTRACTS_CACHE_SIZE: u64 = 500;
+/// Default number of most used contracts preloaded in cache when Drive is opened
+pub const DEFAULT_DATA_CONTRACTS_WARM_START_SIZE: u16 = 100;
+/// Default maximum number of successful signature verifications in cache
+pub const DEFAULT_SIGNATURE_VERIFICATION_CACHE_SIZE: u64 = 10000;
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 /// Encoding for Drive
@@ -52,6 +56,19 @@ pub enum DriveEncoding {
     DriveProtobuf,
 }
 
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+/// How contracts are evicted from the data contracts cache
+pub enum DataContractCacheEvictionPolicy {
+    /// Every contract weighs one, cache sizes are numbers of contracts.
+    /// The least frequently used contracts are evicted first.
+    #[default]
+    Frequency,
+    /// Contracts weigh their serialized size in kibibytes, cache sizes are in kibibytes.
+    /// The least frequently used contracts are evicted first, large contracts free more space.
+    SizeAndFrequency,
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 /// Drive configuration struct
 pub struct DriveConfig {
@@ -94,6 +111,26 @@ pub struct DriveConfig {
         deserialize_with = "from_str_or_number"
     )]
     pub data_contracts_block_cache_size: u64,
+
+    /// How contracts are evicted from the data contracts caches
+    #[serde(default)]
+    pub data_contracts_cache_eviction_policy: DataContractCacheEvictionPolicy,
+
+    /// Number of most used contracts remembered at every block and preloaded in the global
+    /// cache when Drive is opened, 0 disables the warm start
+    #[serde(
+        default = "default_data_contracts_warm_start_size",
+        deserialize_with = "from_str_or_number"
+    )]
+    pub data_contracts_warm_start_size: u16,
+
+    /// Maximum number of successful state transition signature verifications in cache,
+    /// 0 disables the cache
+    #[serde(
+        default = "default_signature_verification_cache_size",
+        deserialize_with = "from_str_or_number"
+    )]
+    pub signature_verification_cache_size: u64,
 }
 
 fn from_str_or_number<'de, D, T>(deserializer: D) -> Result<T, D::Error>
@@ -129,6 +166,14 @@ fn default_data_contracts_cache_size() -> u64 {
     DEFAULT_DATA_CONTRACTS_CACHE_SIZE
 }
 
+fn default_data_contracts_warm_start_size() -> u16 {
+    DEFAULT_DATA_CONTRACTS_WARM_START_SIZE
+}
+
+fn default_signature_verification_cache_size() -> u64 {
+    DEFAULT_SIGNATURE_VERIFICATION_CACHE_SIZE
+}
+
 impl Default for DriveConfig {
     fn default() -> Self {
         DriveConfig {
@@ -140,6 +185,9 @@ impl Default for DriveConfig {
             default_genesis_time: None,
             data_contracts_global_cache_size: DEFAULT_DATA_CONTRACTS_CACHE_SIZE,
             data_contracts_block_cache_size: DEFAULT_DATA_CONTRACTS_CACHE_SIZE,
+            data_contracts_cache_eviction_policy: DataContractCacheEvictionPolicy::default(),
+            data_contracts_warm_start_size: DEFAULT_DATA_CONTRACTS_WARM_START_SIZE,
+            signature_verification_cache_size: DEFAULT_SIGNATURE_VERIFICATION_CACHE_SIZE,
         }
     }
 }
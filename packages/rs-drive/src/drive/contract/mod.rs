@@ -41,6 +41,10 @@ pub(crate) mod paths;
 pub(crate) mod prove;
 #[cfg(any(feature = "full", feature = "verify"))]
 pub(crate) mod queries;
+#[cfg(feature = "full")]
+mod warm_start;
+#[cfg(feature = "full")]
+pub use warm_start::ContractCacheWarmUp;
 
 #[cfg(feature = "full")]
 use std::borrow::Cow;
@@ -49,6 +53,8 @@ use std::collections::BTreeMap;
 use std::collections::{HashMap, HashSet};
 use std::ops::AddAssign;
 #[cfg(feature = "full")]
+use std::ops::RangeFull;
+#[cfg(feature = "full")]
 use std::sync::Arc;
 
 #[cfg(feature = "full")]
@@ -67,11 +73,12 @@ use grovedb::batch::KeyInfoPath;
 #[cfg(feature = "full")]
 use grovedb::reference_path::ReferencePathType::SiblingReference;
 
-use dpp::platform_value::{platform_value, Identifier, Value};
+use dpp::data_contract::IDENTIFIER_FIELDS;
+use dpp::platform_value::{platform_value, Identifier, ReplacementType, Value};
 use dpp::Convertible;
 use grovedb::query_result_type::{QueryResultElement, QueryResultType};
 #[cfg(feature = "full")]
-use grovedb::{Element, EstimatedLayerInformation, TransactionArg};
+use grovedb::{Element, EstimatedLayerInformation, PathQuery, QueryItem, TransactionArg};
 
 #[cfg(any(feature = "full", feature = "verify"))]
 use crate::contract::Contract;
@@ -579,6 +586,7 @@ impl Drive {
             original_contract,
             block_info,
             &mut estimated_costs_only_with_layer_info,
+            true,
             transaction,
         )?;
         self.apply_batch_low_level_drive_operations(
@@ -599,6 +607,7 @@ impl Drive {
         estimated_costs_only_with_layer_info: &mut Option<
             HashMap<KeyInfoPath, EstimatedLayerInformation>,
         >,
+        backfill_indices: bool,
         transaction: TransactionArg,
         drive_operations: &mut Vec<LowLevelDriveOperation>,
     ) -> Result<(), Error> {
@@ -608,6 +617,7 @@ impl Drive {
             original_contract,
             block_info,
             estimated_costs_only_with_layer_info,
+            backfill_indices,
             transaction,
         )?;
         drive_operations.extend(batch_operations);
@@ -615,6 +625,8 @@ impl Drive {
     }
 
     /// operations for updating a contract.
+    /// When `backfill_indices` is set, indices added by the update are built from the existing
+    /// documents, otherwise they only index documents inserted after the update.
     fn update_contract_operations(
         &self,
         contract_element: Element,
@@ -624,6 +636,7 @@ impl Drive {
         estimated_costs_only_with_layer_info: &mut Option<
             HashMap<KeyInfoPath, EstimatedLayerInformation>,
         >,
+        backfill_indices: bool,
         transaction: TransactionArg,
     ) -> Result<Vec<LowLevelDriveOperation>, Error> {
         let mut batch_operations: Vec<LowLevelDriveOperation> = vec![];
@@ -736,6 +749,27 @@ impl Drive {
                         index_cache.insert(index_bytes);
                     }
                 }
+
+                // new indices are built from the existing documents, estimated costs of
+                // contract updates account for the most documents that can be backfilled
+                if backfill_indices {
+                    if estimated_costs_only_with_layer_info.is_none() {
+                        self.backfill_indices_operations(
+                            document_type,
+                            original_document_type,
+                            storage_flags.as_ref().map(|flags| flags.as_ref()),
+                            transaction,
+                            &mut batch_operations,
+                        )?;
+                    } else {
+                        Self::add_estimation_costs_for_index_backfill(
+                            document_type,
+                            original_document_type,
+                            storage_flags.as_ref().map(|flags| flags.as_ref()),
+                            &mut batch_operations,
+                        )?;
+                    }
+                }
             } else {
                 // We can just insert this directly because the original document type already exists
                 self.batch_insert_empty_tree(
@@ -817,8 +851,11 @@ impl Drive {
         let contract_value = match contract_fetch_info {
             None => Value::Null,
             Some(contract_fetch_info) => {
-                let contract = &contract_fetch_info.contract;
-                contract.to_object()?
+                let mut contract_value = contract_fetch_info.contract.to_object()?;
+                // identifiers serialize as plain bytes, mark them as the data contract schema
+                // describes them so human readable encodings render them in base58
+                contract_value.replace_at_paths(IDENTIFIER_FIELDS, ReplacementType::Identifier)?;
+                contract_value
             }
         };
 
@@ -1300,6 +1337,33 @@ impl Drive {
         contracts
     }
 
+    /// Fetches the ids of all the contracts in state
+    pub fn fetch_contract_ids(&self, transaction: TransactionArg) -> Result<Vec<[u8; 32]>, Error> {
+        let path_query = PathQuery::new_single_query_item(
+            vec![vec![RootTree::ContractDocuments as u8]],
+            QueryItem::RangeFull(RangeFull),
+        );
+
+        let (results, _) = self.grove_get_raw_path_query(
+            &path_query,
+            transaction,
+            QueryResultType::QueryKeyElementPairResultType,
+            &mut vec![],
+        )?;
+
+        results
+            .to_keys()
+            .into_iter()
+            .map(|key| {
+                key.try_into().map_err(|_| {
+                    Error::Drive(DriveError::CorruptedContractPath(
+                        "contract id must be 32 bytes",
+                    ))
+                })
+            })
+            .collect()
+    }
+
     /// Applies a contract and returns the fee for applying.
     ///
     /// This function applies a given contract to the storage. If the contract already exists,
@@ -1366,6 +1430,7 @@ impl Drive {
             &block_info,
             &mut estimated_costs_only_with_layer_info,
             storage_flags,
+            true,
             transaction,
         )?;
         let fetch_cost = LowLevelDriveOperation::combine_cost_operations(&batch_operations);
@@ -1391,6 +1456,7 @@ impl Drive {
             HashMap<KeyInfoPath, EstimatedLayerInformation>,
         >,
         storage_flags: Option<Cow<StorageFlags>>,
+        backfill_indices: bool,
         transaction: TransactionArg,
     ) -> Result<Vec<LowLevelDriveOperation>, Error> {
         let serialized_contract = contract.serialize().map_err(Error::Protocol)?;
@@ -1400,6 +1466,7 @@ impl Drive {
             block_info,
             estimated_costs_only_with_layer_info,
             storage_flags,
+            backfill_indices,
             transaction,
         )
     }
@@ -1416,6 +1483,7 @@ impl Drive {
             HashMap<KeyInfoPath, EstimatedLayerInformation>,
         >,
         storage_flags: Option<Cow<StorageFlags>>,
+        backfill_indices: bool,
         transaction: TransactionArg,
     ) -> Result<Vec<LowLevelDriveOperation>, Error> {
         let mut drive_operations: Vec<LowLevelDriveOperation> = vec![];
@@ -1516,6 +1584,7 @@ impl Drive {
                     &original_contract,
                     block_info,
                     estimated_costs_only_with_layer_info,
+                    backfill_indices,
                     transaction,
                     &mut drive_operations,
                 )?;
@@ -1818,6 +1887,41 @@ mod tests {
             .expect("expected to apply contract successfully");
     }
 
+    #[test]
+    fn test_fetch_contract_ids() {
+        let (drive, contract) = setup_deep_nested_10_contract();
+
+        let contract_ids = drive
+            .fetch_contract_ids(None)
+            .expect("expected to fetch contract ids");
+
+        assert_eq!(contract_ids, vec![contract.id.to_buffer()]);
+    }
+
+    #[test]
+    fn test_query_contract_as_json_renders_identifiers_in_base58() {
+        let (drive, contract) = setup_deep_nested_10_contract();
+
+        let encoded = drive
+            .query_contract_as_serialized(
+                contract.id.to_buffer(),
+                QueryResultEncoding::JsonEncodedQueryResult,
+                None,
+            )
+            .expect("expected to query the contract");
+        let json: serde_json::Value =
+            serde_json::from_slice(&encoded).expect("expected the contract to be json");
+
+        assert_eq!(
+            json["contract"]["$id"],
+            serde_json::json!(contract.id.to_string(Encoding::Base58))
+        );
+        assert_eq!(
+            json["contract"]["ownerId"],
+            serde_json::json!(contract.owner_id.to_string(Encoding::Base58))
+        );
+    }
+
     mod get_contract_with_fetch_info {
         use super::*;
         use dpp::prelude::Identifier;
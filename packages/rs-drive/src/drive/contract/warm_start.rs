@@ -0,0 +1,162 @@
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+
+use grovedb::TransactionArg;
+
+/// Auxiliary storage key of the ids of the most used contracts
+const WARM_START_CONTRACT_IDS_KEY: &[u8] = b"warm_start_contract_ids";
+
+/// The contracts preloaded in cache when Drive is opened
+#[derive(Debug, Default)]
+pub struct ContractCacheWarmUp {
+    /// How many contracts were loaded
+    pub loaded_contracts: usize,
+    /// The contracts which could not be fetched and why
+    pub failed_contracts: Vec<([u8; 32], Error)>,
+}
+
+impl Drive {
+    /// Remembers the ids of the most used contracts of the global cache, so that they can be
+    /// preloaded the next time Drive is opened.
+    ///
+    /// The ids depend on the queries served by this node, they are kept in auxiliary storage
+    /// and do not change the state root.
+    pub fn store_warm_start_contract_ids(&self, transaction: TransactionArg) -> Result<(), Error> {
+        let warm_start_size = self.config.data_contracts_warm_start_size as usize;
+
+        if warm_start_size == 0 {
+            return Ok(());
+        }
+
+        let contract_ids = self
+            .cache
+            .read()
+            .unwrap()
+            .cached_contracts
+            .most_used_contract_ids(warm_start_size);
+
+        self.grove
+            .put_aux(
+                WARM_START_CONTRACT_IDS_KEY,
+                &contract_ids.concat(),
+                None,
+                transaction,
+            )
+            .unwrap()
+            .map_err(Error::GroveDB)
+    }
+
+    /// Fetches the ids of the contracts to preload in cache, most used first
+    pub fn fetch_warm_start_contract_ids(
+        &self,
+        transaction: TransactionArg,
+    ) -> Result<Vec<[u8; 32]>, Error> {
+        let bytes = match self
+            .grove
+            .get_aux(WARM_START_CONTRACT_IDS_KEY, transaction)
+            .unwrap()
+            .map_err(Error::GroveDB)?
+        {
+            Some(bytes) => bytes,
+            None => return Ok(vec![]),
+        };
+
+        if bytes.len() % 32 != 0 {
+            return Err(Error::Drive(DriveError::CorruptedSerialization(
+                "warm start contract ids must be 32 bytes each",
+            )));
+        }
+
+        Ok(bytes
+            .chunks_exact(32)
+            .map(|contract_id| contract_id.try_into().unwrap())
+            .collect())
+    }
+
+    /// Preloads the most used contracts of the previous run in the global cache. Contracts which
+    /// no longer exist are skipped, the ones which can not be fetched are skipped and returned
+    /// with their error, as preloading is only an optimization.
+    pub fn warm_up_contract_cache(
+        &self,
+        transaction: TransactionArg,
+    ) -> Result<ContractCacheWarmUp, Error> {
+        let warm_start_size = self.config.data_contracts_warm_start_size as usize;
+
+        let mut warm_up = ContractCacheWarmUp::default();
+
+        for contract_id in self
+            .fetch_warm_start_contract_ids(transaction)?
+            .into_iter()
+            .take(warm_start_size)
+        {
+            let maybe_contract_fetch_info = match self
+                .fetch_contract(contract_id, None, None, transaction)
+                .unwrap()
+            {
+                Ok(maybe_contract_fetch_info) => maybe_contract_fetch_info,
+                Err(error) => {
+                    warm_up.failed_contracts.push((contract_id, error));
+                    continue;
+                }
+            };
+
+            if let Some(contract_fetch_info) = maybe_contract_fetch_info {
+                self.cache
+                    .write()
+                    .unwrap()
+                    .cached_contracts
+                    .insert(contract_fetch_info, false);
+
+                warm_up.loaded_contracts += 1;
+            }
+        }
+
+        Ok(warm_up)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::drive::Drive;
+    use crate::tests::helpers::setup::setup_drive_with_initial_state_structure;
+    use dpp::block::block_info::BlockInfo;
+    use dpp::tests::fixtures::get_data_contract_fixture;
+
+    #[test]
+    fn should_preload_the_most_used_contracts_after_restart() {
+        let drive = setup_drive_with_initial_state_structure();
+
+        let contract = get_data_contract_fixture(None).data_contract;
+        let contract_id = contract.id.to_buffer();
+
+        drive
+            .apply_contract(&contract, BlockInfo::default(), true, None, None)
+            .expect("expected to apply contract");
+
+        drive
+            .get_contract_with_fetch_info(contract_id, true, None)
+            .expect("expected to fetch the contract")
+            .expect("expected the contract");
+
+        drive
+            .store_warm_start_contract_ids(None)
+            .expect("expected to store warm start contract ids");
+
+        drive.drop_cache();
+
+        assert!(drive
+            .get_cached_contract_with_fetch_info(contract_id, None)
+            .is_none());
+
+        let warm_up = drive
+            .warm_up_contract_cache(None)
+            .expect("expected to warm up the cache");
+
+        assert_eq!(warm_up.loaded_contracts, 1);
+        assert!(warm_up.failed_contracts.is_empty());
+        assert!(drive
+            .get_cached_contract_with_fetch_info(contract_id, None)
+            .is_some());
+    }
+}
@@ -34,6 +34,12 @@
 
 /// Protocol version
 pub const PROTOCOL_VERSION: u32 = 1;
+/// First protocol version recording the results of the state transitions
+pub const STATE_TRANSITION_RESULTS_PROTOCOL_VERSION: u32 = 2;
+/// First protocol version removing the documents of types that define a time to live
+pub const EXPIRING_DOCUMENTS_PROTOCOL_VERSION: u32 = 2;
+/// First protocol version building the indices added by contract updates from existing documents
+pub const INDEX_BACKFILL_PROTOCOL_VERSION: u32 = 2;
 /// Contract Documents subtree path height
 pub const CONTRACT_DOCUMENTS_PATH_HEIGHT: u16 = 4;
 /// Base contract root path size
@@ -101,3 +107,7 @@ pub const ESTIMATED_AVERAGE_INDEX_NAME_SIZE: u8 = 16;
 
 /// The estimated count of identities having the same key if they are not unique
 pub const ESTIMATED_NON_UNIQUE_KEY_DUPLICATES: u32 = 2;
+
+/// The maximum number of existing documents a contract update can build new indices from,
+/// the indices are built in the block of the update
+pub const MAX_INDEX_BACKFILL_DOCUMENTS: u16 = 1000;
@@ -381,7 +381,7 @@ impl Drive {
         document_and_contract_info: &DocumentAndContractInfo,
         index_path_info: PathInfo<0>,
         index_level: &IndexLevel,
-        mut any_fields_null: bool,
+        any_fields_null: bool,
         storage_flags: &Option<&StorageFlags>,
         previous_batch_operations: &Option<&mut Vec<LowLevelDriveOperation>>,
         estimated_costs_only_with_layer_info: &mut Option<
@@ -431,16 +431,16 @@ impl Drive {
             let mut sub_level_index_path_info = index_path_info.clone();
             let index_property_key = KeyRef(name.as_bytes());
 
-            let document_index_field = document_and_contract_info
+            // array fields have an index entry for each of their elements
+            let document_index_fields = document_and_contract_info
                 .owned_document_info
                 .document_info
-                .get_raw_for_document_type(
+                .get_raw_index_keys_for_document_type(
                     name,
                     document_type,
                     document_and_contract_info.owned_document_info.owner_id,
                     Some((sub_level, event_id)),
-                )?
-                .unwrap_or_default();
+                )?;
 
             sub_level_index_path_info.push(index_property_key)?;
 
@@ -474,24 +474,28 @@ impl Drive {
             // Iteration 1. the index path is now something like Contracts/ContractID/Documents(1)/$ownerId/<ownerId>/toUserId
             // Iteration 2. the index path is now something like Contracts/ContractID/Documents(1)/$ownerId/<ownerId>/toUserId/<ToUserId>/accountReference
 
-            any_fields_null |= document_index_field.is_empty();
-
-            // we push the actual value of the index path
-            sub_level_index_path_info.push(document_index_field)?;
-            // Iteration 1. the index path is now something like Contracts/ContractID/Documents(1)/$ownerId/<ownerId>/toUserId/<ToUserId>/
-            // Iteration 2. the index path is now something like Contracts/ContractID/Documents(1)/$ownerId/<ownerId>/toUserId/<ToUserId>/accountReference/<accountReference>
-            self.remove_indices_for_index_level_for_contract_operations(
-                document_and_contract_info,
-                sub_level_index_path_info,
-                sub_level,
-                any_fields_null,
-                storage_flags,
-                previous_batch_operations,
-                estimated_costs_only_with_layer_info,
-                event_id,
-                transaction,
-                batch_operations,
-            )?;
+            for document_index_field in document_index_fields {
+                let mut value_index_path_info = sub_level_index_path_info.clone();
+
+                let any_fields_null = any_fields_null || document_index_field.is_empty();
+
+                // we push the actual value of the index path
+                value_index_path_info.push(document_index_field)?;
+                // Iteration 1. the index path is now something like Contracts/ContractID/Documents(1)/$ownerId/<ownerId>/toUserId/<ToUserId>/
+                // Iteration 2. the index path is now something like Contracts/ContractID/Documents(1)/$ownerId/<ownerId>/toUserId/<ToUserId>/accountReference/<accountReference>
+                self.remove_indices_for_index_level_for_contract_operations(
+                    document_and_contract_info,
+                    value_index_path_info,
+                    sub_level,
+                    any_fields_null,
+                    storage_flags,
+                    previous_batch_operations,
+                    estimated_costs_only_with_layer_info,
+                    event_id,
+                    transaction,
+                    batch_operations,
+                )?;
+            }
         }
         Ok(())
     }
@@ -558,16 +562,16 @@ impl Drive {
 
             // with the example of the dashpay contract's first index
             // the index path is now something like Contracts/ContractID/Documents(1)/$ownerId
-            let document_top_field = document_and_contract_info
+            // array fields have an index entry for each of their elements
+            let document_top_fields = document_and_contract_info
                 .owned_document_info
                 .document_info
-                .get_raw_for_document_type(
+                .get_raw_index_keys_for_document_type(
                     name,
                     document_type,
                     document_and_contract_info.owned_document_info.owner_id,
                     Some((sub_level, event_id)),
-                )?
-                .unwrap_or_default();
+                )?;
 
             if let Some(estimated_costs_only_with_layer_info) = estimated_costs_only_with_layer_info
             {
@@ -597,35 +601,37 @@ impl Drive {
                 );
             }
 
-            let any_fields_null = document_top_field.is_empty();
-
-            let mut index_path_info = if document_and_contract_info
-                .owned_document_info
-                .document_info
-                .is_document_size()
-            {
-                // This is a stateless operation
-                PathInfo::PathWithSizes(KeyInfoPath::from_known_owned_path(index_path))
-            } else {
-                PathInfo::PathIterator::<0>(index_path)
-            };
-
-            // we push the actual value of the index path
-            index_path_info.push(document_top_field)?;
-            // the index path is now something like Contracts/ContractID/Documents(1)/$ownerId/<ownerId>
+            for document_top_field in document_top_fields {
+                let any_fields_null = document_top_field.is_empty();
 
-            self.remove_indices_for_index_level_for_contract_operations(
-                document_and_contract_info,
-                index_path_info,
-                sub_level,
-                any_fields_null,
-                &storage_flags,
-                previous_batch_operations,
-                estimated_costs_only_with_layer_info,
-                event_id,
-                transaction,
-                batch_operations,
-            )?;
+                let mut index_path_info = if document_and_contract_info
+                    .owned_document_info
+                    .document_info
+                    .is_document_size()
+                {
+                    // This is a stateless operation
+                    PathInfo::PathWithSizes(KeyInfoPath::from_known_owned_path(index_path.clone()))
+                } else {
+                    PathInfo::PathIterator::<0>(index_path.clone())
+                };
+
+                // we push the actual value of the index path
+                index_path_info.push(document_top_field)?;
+                // the index path is now something like Contracts/ContractID/Documents(1)/$ownerId/<ownerId>
+
+                self.remove_indices_for_index_level_for_contract_operations(
+                    document_and_contract_info,
+                    index_path_info,
+                    sub_level,
+                    any_fields_null,
+                    &storage_flags,
+                    previous_batch_operations,
+                    estimated_costs_only_with_layer_info,
+                    event_id,
+                    transaction,
+                    batch_operations,
+                )?;
+            }
         }
         Ok(())
     }
@@ -701,8 +707,6 @@ impl Drive {
         >,
         transaction: TransactionArg,
     ) -> Result<Vec<LowLevelDriveOperation>, Error> {
-        let mut batch_operations: Vec<LowLevelDriveOperation> = vec![];
-
         if !document_type.documents_mutable {
             return Err(Error::Drive(DriveError::UpdatingReadOnlyImmutableDocument(
                 "this document type is not mutable and can not be deleted",
@@ -717,6 +721,31 @@ impl Drive {
             ));
         }
 
+        self.remove_document_for_contract_operations(
+            document_id,
+            contract,
+            document_type,
+            previous_batch_operations,
+            estimated_costs_only_with_layer_info,
+            transaction,
+        )
+    }
+
+    /// Prepares the operations for removing a document without checking that
+    /// its document type allows deletion, used when documents expire.
+    pub(crate) fn remove_document_for_contract_operations(
+        &self,
+        document_id: [u8; 32],
+        contract: &Contract,
+        document_type: &DocumentType,
+        previous_batch_operations: Option<&mut Vec<LowLevelDriveOperation>>,
+        estimated_costs_only_with_layer_info: &mut Option<
+            HashMap<KeyInfoPath, EstimatedLayerInformation>,
+        >,
+        transaction: TransactionArg,
+    ) -> Result<Vec<LowLevelDriveOperation>, Error> {
+        let mut batch_operations: Vec<LowLevelDriveOperation> = vec![];
+
         // first we need to construct the path for documents on the contract
         // the path is
         //  * Document and Contract root tree
@@ -781,6 +810,15 @@ impl Drive {
             &mut batch_operations,
         )?;
 
+        self.remove_document_expiration_operations(
+            contract.id.as_bytes(),
+            document_id.as_slice(),
+            document_type,
+            estimated_costs_only_with_layer_info,
+            transaction,
+            &mut batch_operations,
+        )?;
+
         let document_and_contract_info = DocumentAndContractInfo {
             owned_document_info: OwnedDocumentInfo {
                 document_info,
@@ -0,0 +1,846 @@
+//! Document Expiration.
+//!
+//! Documents of types that define `documentsTtl` are indexed by their expiration time
+//! in the misc tree. At every block the platform removes the documents that expired,
+//! refunding their storage to the owners through the storage flags epochs.
+//!
+//! The expiration time of every expiring document is also kept by contract and document id,
+//! so that deleting a document removes its expiration entry as well.
+//!
+//! Documents expire from [`EXPIRING_DOCUMENTS_PROTOCOL_VERSION`], the expiring documents tree
+//! is created when the chain upgrades to it.
+//!
+//! [`EXPIRING_DOCUMENTS_PROTOCOL_VERSION`]: crate::drive::defaults::EXPIRING_DOCUMENTS_PROTOCOL_VERSION
+
+use std::collections::{BTreeMap, HashMap};
+
+use dpp::block::block_info::BlockInfo;
+use dpp::data_contract::document_type::DocumentType;
+use dpp::prelude::TimestampMillis;
+use grovedb::batch::key_info::KeyInfo;
+use grovedb::batch::KeyInfoPath;
+use grovedb::query_result_type::QueryResultType::QueryKeyElementPairResultType;
+use grovedb::EstimatedLayerCount::PotentiallyAtMaxElements;
+use grovedb::EstimatedLayerSizes::AllItems;
+use grovedb::{Element, EstimatedLayerInformation, PathQuery, Query, SizedQuery, TransactionArg};
+
+use crate::drive::batch::GroveDbOpBatch;
+use crate::drive::grove_operations::BatchDeleteApplyType::{
+    StatefulBatchDelete, StatelessBatchDelete,
+};
+use crate::drive::grove_operations::DirectQueryType;
+use crate::drive::grove_operations::DirectQueryType::{StatefulDirectQuery, StatelessDirectQuery};
+use crate::drive::grove_operations::QueryTarget::QueryTargetValue;
+use crate::drive::object_size_info::DocumentAndContractInfo;
+use crate::drive::object_size_info::PathKeyElementInfo::{PathKeyElement, PathKeyElementSize};
+use crate::drive::system::misc_tree_constants::{
+    DOCUMENT_EXPIRATION_TIMES_TREE_KEY, EXPIRING_DOCUMENTS_TREE_KEY,
+};
+use crate::drive::{Drive, RootTree};
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::fee::calculate_fee;
+use crate::fee::op::LowLevelDriveOperation;
+use crate::fee::result::FeeResult;
+
+/// The maximum amount of expired documents removed in a single block,
+/// documents that are not removed are picked up by the following blocks
+pub const MAX_EXPIRED_DOCUMENTS_REMOVED_PER_BLOCK: u16 = 256;
+
+/// The size of an expiring document key, the expiration time, contract id and document id
+const EXPIRING_DOCUMENT_KEY_SIZE: usize = 8 + 32 + 32;
+
+/// The size of a document expiration time key, the contract id and document id
+const DOCUMENT_EXPIRATION_TIME_KEY_SIZE: usize = 32 + 32;
+
+/// The size of a document expiration time value
+const DOCUMENT_EXPIRATION_TIME_SIZE: usize = 8;
+
+/// Returns the path to the expiring documents tree.
+pub(crate) fn expiring_documents_path() -> [&'static [u8]; 2] {
+    [
+        Into::<&[u8; 1]>::into(RootTree::Misc),
+        EXPIRING_DOCUMENTS_TREE_KEY,
+    ]
+}
+
+/// Returns the path to the expiring documents tree as a vec.
+pub(crate) fn expiring_documents_path_vec() -> Vec<Vec<u8>> {
+    vec![
+        vec![RootTree::Misc as u8],
+        EXPIRING_DOCUMENTS_TREE_KEY.to_vec(),
+    ]
+}
+
+/// Returns the path to the document expiration times tree.
+pub(crate) fn document_expiration_times_path() -> [&'static [u8]; 2] {
+    [
+        Into::<&[u8; 1]>::into(RootTree::Misc),
+        DOCUMENT_EXPIRATION_TIMES_TREE_KEY,
+    ]
+}
+
+/// Returns the path to the document expiration times tree as a vec.
+pub(crate) fn document_expiration_times_path_vec() -> Vec<Vec<u8>> {
+    vec![
+        vec![RootTree::Misc as u8],
+        DOCUMENT_EXPIRATION_TIMES_TREE_KEY.to_vec(),
+    ]
+}
+
+/// Add operations for creating the expiring documents structure
+pub fn add_initial_expiring_documents_structure_operations(batch: &mut GroveDbOpBatch) {
+    batch.add_insert_empty_tree(
+        vec![vec![RootTree::Misc as u8]],
+        EXPIRING_DOCUMENTS_TREE_KEY.to_vec(),
+    );
+    batch.add_insert_empty_tree(
+        vec![vec![RootTree::Misc as u8]],
+        DOCUMENT_EXPIRATION_TIMES_TREE_KEY.to_vec(),
+    );
+}
+
+/// A document that reached its expiration time
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExpiredDocument {
+    /// The time at which the document expired
+    pub expires_at: TimestampMillis,
+    /// The contract of the document
+    pub contract_id: [u8; 32],
+    /// The document id
+    pub document_id: [u8; 32],
+    /// The name of the document type
+    pub document_type_name: String,
+}
+
+impl ExpiredDocument {
+    /// Expiring documents are sorted by expiration time, so the time is big endian encoded first
+    fn key(&self) -> Vec<u8> {
+        expiring_document_key(self.expires_at, &self.contract_id, &self.document_id)
+    }
+
+    fn from_key_and_element(key: Vec<u8>, element: Element) -> Result<Self, Error> {
+        if key.len() != EXPIRING_DOCUMENT_KEY_SIZE {
+            return Err(Error::Drive(DriveError::CorruptedSerialization(
+                "expiring document key has the wrong size",
+            )));
+        }
+        let Element::Item(document_type_name, _) = element else {
+            return Err(Error::Drive(DriveError::CorruptedElementType(
+                "expiring document must be an item",
+            )));
+        };
+
+        let (expires_at, ids) = key.split_at(8);
+        let (contract_id, document_id) = ids.split_at(32);
+
+        Ok(ExpiredDocument {
+            expires_at: TimestampMillis::from_be_bytes(expires_at.try_into().map_err(|_| {
+                Error::Drive(DriveError::CorruptedSerialization(
+                    "expiration time must be 8 bytes",
+                ))
+            })?),
+            contract_id: contract_id.try_into().map_err(|_| {
+                Error::Drive(DriveError::CorruptedSerialization(
+                    "contract id must be 32 bytes",
+                ))
+            })?,
+            document_id: document_id.try_into().map_err(|_| {
+                Error::Drive(DriveError::CorruptedSerialization(
+                    "document id must be 32 bytes",
+                ))
+            })?,
+            document_type_name: String::from_utf8(document_type_name).map_err(|_| {
+                Error::Drive(DriveError::CorruptedSerialization(
+                    "document type name must be utf8",
+                ))
+            })?,
+        })
+    }
+}
+
+fn expiring_document_key(
+    expires_at: TimestampMillis,
+    contract_id: &[u8],
+    document_id: &[u8],
+) -> Vec<u8> {
+    let mut key = Vec::with_capacity(EXPIRING_DOCUMENT_KEY_SIZE);
+    key.extend_from_slice(&expires_at.to_be_bytes());
+    key.extend_from_slice(contract_id);
+    key.extend_from_slice(document_id);
+    key
+}
+
+fn document_expiration_time_key(contract_id: &[u8], document_id: &[u8]) -> Vec<u8> {
+    let mut key = Vec::with_capacity(DOCUMENT_EXPIRATION_TIME_KEY_SIZE);
+    key.extend_from_slice(contract_id);
+    key.extend_from_slice(document_id);
+    key
+}
+
+impl Drive {
+    /// Adds the estimation costs for the expiring documents and document expiration times trees
+    pub(crate) fn add_estimation_costs_for_document_expiration(
+        estimated_costs_only_with_layer_info: &mut HashMap<KeyInfoPath, EstimatedLayerInformation>,
+    ) {
+        estimated_costs_only_with_layer_info.insert(
+            KeyInfoPath::from_known_owned_path(expiring_documents_path_vec()),
+            EstimatedLayerInformation {
+                is_sum_tree: false,
+                estimated_layer_count: PotentiallyAtMaxElements,
+                estimated_layer_sizes: AllItems(EXPIRING_DOCUMENT_KEY_SIZE as u8, 64, None),
+            },
+        );
+        estimated_costs_only_with_layer_info.insert(
+            KeyInfoPath::from_known_owned_path(document_expiration_times_path_vec()),
+            EstimatedLayerInformation {
+                is_sum_tree: false,
+                estimated_layer_count: PotentiallyAtMaxElements,
+                estimated_layer_sizes: AllItems(
+                    DOCUMENT_EXPIRATION_TIME_KEY_SIZE as u8,
+                    DOCUMENT_EXPIRATION_TIME_SIZE as u32,
+                    None,
+                ),
+            },
+        );
+    }
+
+    /// Indexes a newly added document by its expiration time if its document type has a ttl
+    pub(crate) fn add_document_expiration_operations(
+        &self,
+        document_and_contract_info: &DocumentAndContractInfo,
+        block_info: &BlockInfo,
+        estimated_costs_only_with_layer_info: &mut Option<
+            HashMap<KeyInfoPath, EstimatedLayerInformation>,
+        >,
+        batch_operations: &mut Vec<LowLevelDriveOperation>,
+    ) -> Result<(), Error> {
+        let document_type = document_and_contract_info.document_type;
+        let Some(documents_ttl) = document_type.documents_ttl else {
+            return Ok(());
+        };
+
+        let expires_at = block_info
+            .time_ms
+            .checked_add(documents_ttl)
+            .ok_or(Error::Drive(DriveError::CorruptedCodeExecution(
+                "document expiration time overflow",
+            )))?;
+
+        let document_info = &document_and_contract_info.owned_document_info.document_info;
+        let element_flags = document_info
+            .get_storage_flags_ref()
+            .and_then(|storage_flags| storage_flags.to_some_element_flags());
+        let element = Element::Item(
+            document_type.name.as_bytes().to_vec(),
+            element_flags.clone(),
+        );
+        let expiration_time_element =
+            Element::Item(expires_at.to_be_bytes().to_vec(), element_flags);
+
+        if let Some(estimated_costs_only_with_layer_info) = estimated_costs_only_with_layer_info {
+            Self::add_estimation_costs_for_document_expiration(
+                estimated_costs_only_with_layer_info,
+            );
+            self.batch_insert::<0>(
+                PathKeyElementSize((
+                    KeyInfoPath::from_known_owned_path(expiring_documents_path_vec()),
+                    KeyInfo::MaxKeySize {
+                        unique_id: expiring_documents_path_vec().concat(),
+                        max_size: EXPIRING_DOCUMENT_KEY_SIZE as u8,
+                    },
+                    element,
+                )),
+                batch_operations,
+            )?;
+            self.batch_insert::<0>(
+                PathKeyElementSize((
+                    KeyInfoPath::from_known_owned_path(document_expiration_times_path_vec()),
+                    KeyInfo::MaxKeySize {
+                        unique_id: document_expiration_times_path_vec().concat(),
+                        max_size: DOCUMENT_EXPIRATION_TIME_KEY_SIZE as u8,
+                    },
+                    expiration_time_element,
+                )),
+                batch_operations,
+            )
+        } else {
+            let document_id = document_info
+                .get_document_id_as_slice()
+                .ok_or(Error::Drive(DriveError::CorruptedCodeExecution(
+                    "expected a document when applying",
+                )))?;
+            self.batch_insert::<0>(
+                PathKeyElement((
+                    expiring_documents_path_vec(),
+                    expiring_document_key(
+                        expires_at,
+                        document_and_contract_info.contract.id.as_bytes(),
+                        document_id,
+                    ),
+                    element,
+                )),
+                batch_operations,
+            )?;
+            self.batch_insert::<0>(
+                PathKeyElement((
+                    document_expiration_times_path_vec(),
+                    document_expiration_time_key(
+                        document_and_contract_info.contract.id.as_bytes(),
+                        document_id,
+                    ),
+                    expiration_time_element,
+                )),
+                batch_operations,
+            )
+        }
+    }
+
+    /// Fetches the time at which a document expires, if its document type has a ttl
+    fn fetch_document_expiration_time_operations(
+        &self,
+        contract_id: &[u8],
+        document_id: &[u8],
+        query_type: DirectQueryType,
+        transaction: TransactionArg,
+        drive_operations: &mut Vec<LowLevelDriveOperation>,
+    ) -> Result<Option<TimestampMillis>, Error> {
+        let element = self.grove_get_raw(
+            document_expiration_times_path().as_ref().into(),
+            document_expiration_time_key(contract_id, document_id).as_slice(),
+            query_type,
+            transaction,
+            drive_operations,
+        )?;
+
+        element
+            .map(|element| {
+                let Element::Item(expires_at, _) = element else {
+                    return Err(Error::Drive(DriveError::CorruptedElementType(
+                        "document expiration time must be an item",
+                    )));
+                };
+                Ok(TimestampMillis::from_be_bytes(
+                    expires_at.as_slice().try_into().map_err(|_| {
+                        Error::Drive(DriveError::CorruptedSerialization(
+                            "expiration time must be 8 bytes",
+                        ))
+                    })?,
+                ))
+            })
+            .transpose()
+    }
+
+    /// Removes the expiration entries of a document that is being removed, so that a document
+    /// re-created later with the same id is not removed at the expiration time of this one
+    pub(crate) fn remove_document_expiration_operations(
+        &self,
+        contract_id: &[u8],
+        document_id: &[u8],
+        document_type: &DocumentType,
+        estimated_costs_only_with_layer_info: &mut Option<
+            HashMap<KeyInfoPath, EstimatedLayerInformation>,
+        >,
+        transaction: TransactionArg,
+        batch_operations: &mut Vec<LowLevelDriveOperation>,
+    ) -> Result<(), Error> {
+        if document_type.documents_ttl.is_none() {
+            return Ok(());
+        }
+
+        let expiration_time_key = document_expiration_time_key(contract_id, document_id);
+
+        if let Some(estimated_costs_only_with_layer_info) = estimated_costs_only_with_layer_info {
+            Self::add_estimation_costs_for_document_expiration(
+                estimated_costs_only_with_layer_info,
+            );
+            self.fetch_document_expiration_time_operations(
+                contract_id,
+                document_id,
+                StatelessDirectQuery {
+                    in_tree_using_sums: false,
+                    query_target: QueryTargetValue(DOCUMENT_EXPIRATION_TIME_SIZE as u32),
+                },
+                transaction,
+                batch_operations,
+            )?;
+            // the expiration time is unknown without state, only the size of the key matters
+            self.batch_delete(
+                expiring_documents_path().as_ref().into(),
+                expiring_document_key(0, contract_id, document_id).as_slice(),
+                StatelessBatchDelete {
+                    is_sum_tree: false,
+                    estimated_value_size: document_type.name.len() as u32,
+                },
+                transaction,
+                batch_operations,
+            )?;
+            return self.batch_delete(
+                document_expiration_times_path().as_ref().into(),
+                expiration_time_key.as_slice(),
+                StatelessBatchDelete {
+                    is_sum_tree: false,
+                    estimated_value_size: DOCUMENT_EXPIRATION_TIME_SIZE as u32,
+                },
+                transaction,
+                batch_operations,
+            );
+        }
+
+        let Some(expires_at) = self.fetch_document_expiration_time_operations(
+            contract_id,
+            document_id,
+            StatefulDirectQuery,
+            transaction,
+            batch_operations,
+        )?
+        else {
+            return Ok(());
+        };
+
+        self.batch_delete(
+            expiring_documents_path().as_ref().into(),
+            expiring_document_key(expires_at, contract_id, document_id).as_slice(),
+            StatefulBatchDelete {
+                is_known_to_be_subtree_with_sum: Some((false, false)),
+            },
+            transaction,
+            batch_operations,
+        )?;
+        self.batch_delete(
+            document_expiration_times_path().as_ref().into(),
+            expiration_time_key.as_slice(),
+            StatefulBatchDelete {
+                is_known_to_be_subtree_with_sum: Some((false, false)),
+            },
+            transaction,
+            batch_operations,
+        )
+    }
+
+    /// Fetches the documents that expired at or before the given time, oldest first
+    pub fn fetch_expired_documents(
+        &self,
+        block_time_ms: TimestampMillis,
+        limit: u16,
+        transaction: TransactionArg,
+    ) -> Result<Vec<ExpiredDocument>, Error> {
+        let mut query = Query::new();
+        query.insert_range_to(..block_time_ms.saturating_add(1).to_be_bytes().to_vec());
+
+        let path_query = PathQuery::new(
+            expiring_documents_path_vec(),
+            SizedQuery::new(query, Some(limit), None),
+        );
+
+        let (results, _) = self.grove_get_raw_path_query(
+            &path_query,
+            transaction,
+            QueryKeyElementPairResultType,
+            &mut vec![],
+        )?;
+
+        results
+            .to_key_elements()
+            .into_iter()
+            .map(|(key, element)| ExpiredDocument::from_key_and_element(key, element))
+            .collect()
+    }
+
+    /// Removes the documents that expired at or before the block time and refunds
+    /// their storage to the owners. Returns the fees of the removal, the refunds of
+    /// which need to be taken out of the epoch pools.
+    pub fn remove_expired_documents(
+        &self,
+        block_info: &BlockInfo,
+        transaction: TransactionArg,
+    ) -> Result<FeeResult, Error> {
+        let expired_documents = self.fetch_expired_documents(
+            block_info.time_ms,
+            MAX_EXPIRED_DOCUMENTS_REMOVED_PER_BLOCK,
+            transaction,
+        )?;
+
+        if expired_documents.is_empty() {
+            return Ok(FeeResult::default());
+        }
+
+        let mut batch_operations = vec![];
+
+        for expired_document in expired_documents.iter() {
+            self.remove_expired_document_operations(
+                expired_document,
+                block_info,
+                transaction,
+                &mut batch_operations,
+            )?;
+        }
+
+        let mut drive_operations = vec![];
+        self.apply_batch_low_level_drive_operations(
+            None,
+            transaction,
+            batch_operations,
+            &mut drive_operations,
+        )?;
+
+        let fee_result = calculate_fee(None, Some(drive_operations), &block_info.epoch)?;
+
+        let refunded_balances: BTreeMap<[u8; 32], _> = fee_result
+            .fee_refunds
+            .iter()
+            .filter_map(|(identity_id, _)| {
+                fee_result
+                    .fee_refunds
+                    .calculate_refunds_amount_for_identity(*identity_id)
+                    .map(|credits| (*identity_id, credits))
+            })
+            .collect();
+
+        self.add_to_identities_balances(refunded_balances, block_info, true, transaction)?;
+
+        Ok(fee_result)
+    }
+
+    /// Prepares the operations removing an expired document and its expiration entries.
+    /// An entry that is no longer the expiration time of its document is only removed itself.
+    fn remove_expired_document_operations(
+        &self,
+        expired_document: &ExpiredDocument,
+        block_info: &BlockInfo,
+        transaction: TransactionArg,
+        batch_operations: &mut Vec<LowLevelDriveOperation>,
+    ) -> Result<(), Error> {
+        let contract_fetch_info = self.get_contract_with_fetch_info_and_add_to_operations(
+            expired_document.contract_id,
+            Some(&block_info.epoch),
+            true,
+            transaction,
+            batch_operations,
+        )?;
+
+        if let Some(contract_fetch_info) = contract_fetch_info {
+            let contract = &contract_fetch_info.contract;
+            let document_type =
+                contract.document_type_for_name(expired_document.document_type_name.as_str())?;
+
+            let expires_at = self.fetch_document_expiration_time_operations(
+                contract.id.as_bytes(),
+                expired_document.document_id.as_slice(),
+                StatefulDirectQuery,
+                transaction,
+                batch_operations,
+            )?;
+
+            if expires_at == Some(expired_document.expires_at) {
+                // removing the document also removes its expiration entries
+                let removal_operations = self.remove_document_for_contract_operations(
+                    expired_document.document_id,
+                    contract,
+                    document_type,
+                    Some(&mut *batch_operations),
+                    &mut None,
+                    transaction,
+                )?;
+                batch_operations.extend(removal_operations);
+                return Ok(());
+            }
+        }
+
+        self.batch_delete(
+            expiring_documents_path().as_ref().into(),
+            expired_document.key().as_slice(),
+            StatefulBatchDelete {
+                is_known_to_be_subtree_with_sum: Some((false, false)),
+            },
+            transaction,
+            batch_operations,
+        )
+    }
+}
+
+#[cfg(feature = "full")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::drive::defaults::EXPIRING_DOCUMENTS_PROTOCOL_VERSION;
+    use crate::drive::document::contract_documents_primary_key_path;
+    use crate::drive::flags::StorageFlags;
+    use crate::drive::object_size_info::DocumentInfo::DocumentRefInfo;
+    use crate::drive::object_size_info::OwnedDocumentInfo;
+    use dpp::data_contract::extra::common::{json_document_to_contract, json_document_to_document};
+    use dpp::identity::Identity;
+    use std::borrow::Cow;
+    use tempfile::TempDir;
+
+    #[test]
+    fn should_order_expiring_document_keys_by_expiration_time() {
+        let earlier = expiring_document_key(1000, &[255; 32], &[255; 32]);
+        let later = expiring_document_key(1001, &[0; 32], &[0; 32]);
+
+        assert!(earlier < later);
+        assert!(earlier < 1001u64.to_be_bytes().to_vec());
+    }
+
+    #[test]
+    fn should_decode_expired_document_from_key_and_element() {
+        let expired_document = ExpiredDocument {
+            expires_at: 1684233625697,
+            contract_id: [1; 32],
+            document_id: [2; 32],
+            document_type_name: "preorder".to_string(),
+        };
+
+        let decoded = ExpiredDocument::from_key_and_element(
+            expired_document.key(),
+            Element::Item(b"preorder".to_vec(), None),
+        )
+        .expect("expected to decode expired document");
+
+        assert_eq!(decoded, expired_document);
+    }
+
+    #[test]
+    fn should_remove_expired_documents_and_refund_their_storage() {
+        let tmp_dir = TempDir::new().unwrap();
+        let drive: Drive = Drive::open(tmp_dir, None).expect("expected to open Drive successfully");
+
+        drive
+            .create_initial_state_structure(None)
+            .expect("expected to create root tree successfully");
+
+        drive
+            .migrate_state_structure(1, EXPIRING_DOCUMENTS_PROTOCOL_VERSION, None)
+            .expect("expected to migrate the state structure");
+
+        let mut contract = json_document_to_contract(
+            "tests/supporting_files/contract/dashpay/dashpay-contract.json",
+        )
+        .expect("expected to get contract");
+
+        contract
+            .document_types
+            .get_mut("profile")
+            .expect("expected to get profile document type")
+            .documents_ttl = Some(1000);
+
+        drive
+            .apply_contract(&contract, BlockInfo::default(), true, None, None)
+            .expect("expected to apply contract");
+
+        let identity = Identity::random_identity(3, Some(12345));
+        let owner_id = identity.id.to_buffer();
+
+        drive
+            .add_new_identity(identity, &BlockInfo::default(), true, None)
+            .expect("expected to insert identity");
+
+        let balance_before = drive
+            .fetch_identity_balance(owner_id, None)
+            .expect("expected to fetch balance")
+            .expect("expected the identity to have a balance");
+
+        let document_type = contract
+            .document_type_for_name("profile")
+            .expect("expected to get profile document type");
+
+        let document = json_document_to_document(
+            "tests/supporting_files/contract/dashpay/profile0.json",
+            Some(owner_id.into()),
+            document_type,
+        )
+        .expect("expected to get document");
+
+        let storage_flags = Some(Cow::Owned(StorageFlags::SingleEpochOwned(0, owner_id)));
+
+        drive
+            .add_document_for_contract(
+                DocumentAndContractInfo {
+                    owned_document_info: OwnedDocumentInfo {
+                        document_info: DocumentRefInfo((&document, storage_flags)),
+                        owner_id: Some(owner_id),
+                    },
+                    contract: &contract,
+                    document_type,
+                },
+                false,
+                BlockInfo::default_with_time(5000),
+                true,
+                None,
+            )
+            .expect("expected to insert a document successfully");
+
+        let primary_key_path =
+            contract_documents_primary_key_path(contract.id.as_bytes(), "profile");
+
+        // Before its expiration time the document is kept
+        let fee_result = drive
+            .remove_expired_documents(&BlockInfo::default_with_time(5999), None)
+            .expect("expected to remove expired documents");
+
+        assert_eq!(fee_result, FeeResult::default());
+        assert!(drive
+            .grove
+            .get(&primary_key_path, document.id.as_slice(), None)
+            .unwrap()
+            .is_ok());
+
+        let fee_result = drive
+            .remove_expired_documents(&BlockInfo::default_with_time(6000), None)
+            .expect("expected to remove expired documents");
+
+        assert!(drive
+            .grove
+            .get(&primary_key_path, document.id.as_slice(), None)
+            .unwrap()
+            .is_err());
+
+        assert!(drive
+            .fetch_expired_documents(u64::MAX, MAX_EXPIRED_DOCUMENTS_REMOVED_PER_BLOCK, None)
+            .expect("expected to fetch expired documents")
+            .is_empty());
+
+        let refunded_credits = fee_result
+            .fee_refunds
+            .calculate_refunds_amount_for_identity(owner_id)
+            .expect("expected the owner to be refunded");
+
+        assert!(refunded_credits > 0);
+
+        let balance_after = drive
+            .fetch_identity_balance(owner_id, None)
+            .expect("expected to fetch balance")
+            .expect("expected the identity to have a balance");
+
+        assert_eq!(balance_after, balance_before + refunded_credits);
+    }
+
+    #[test]
+    fn should_not_remove_a_re_created_document_at_the_expiration_time_of_the_deleted_one() {
+        let tmp_dir = TempDir::new().unwrap();
+        let drive: Drive = Drive::open(tmp_dir, None).expect("expected to open Drive successfully");
+
+        drive
+            .create_initial_state_structure(None)
+            .expect("expected to create root tree successfully");
+
+        drive
+            .migrate_state_structure(1, EXPIRING_DOCUMENTS_PROTOCOL_VERSION, None)
+            .expect("expected to migrate the state structure");
+
+        let mut contract = json_document_to_contract(
+            "tests/supporting_files/contract/dashpay/dashpay-contract.json",
+        )
+        .expect("expected to get contract");
+
+        contract
+            .document_types
+            .get_mut("profile")
+            .expect("expected to get profile document type")
+            .documents_ttl = Some(1000);
+
+        drive
+            .apply_contract(&contract, BlockInfo::default(), true, None, None)
+            .expect("expected to apply contract");
+
+        let identity = Identity::random_identity(3, Some(12345));
+        let owner_id = identity.id.to_buffer();
+
+        drive
+            .add_new_identity(identity, &BlockInfo::default(), true, None)
+            .expect("expected to insert identity");
+
+        let document_type = contract
+            .document_type_for_name("profile")
+            .expect("expected to get profile document type");
+
+        let document = json_document_to_document(
+            "tests/supporting_files/contract/dashpay/profile0.json",
+            Some(owner_id.into()),
+            document_type,
+        )
+        .expect("expected to get document");
+
+        let add_document = |time_ms| {
+            drive
+                .add_document_for_contract(
+                    DocumentAndContractInfo {
+                        owned_document_info: OwnedDocumentInfo {
+                            document_info: DocumentRefInfo((
+                                &document,
+                                Some(Cow::Owned(StorageFlags::SingleEpochOwned(0, owner_id))),
+                            )),
+                            owner_id: Some(owner_id),
+                        },
+                        contract: &contract,
+                        document_type,
+                    },
+                    false,
+                    BlockInfo::default_with_time(time_ms),
+                    true,
+                    None,
+                )
+                .expect("expected to insert a document successfully");
+        };
+
+        // The document expires at 6000
+        add_document(5000);
+
+        drive
+            .delete_document_for_contract(
+                document.id.to_buffer(),
+                &contract,
+                "profile",
+                Some(owner_id),
+                BlockInfo::default_with_time(5500),
+                true,
+                None,
+            )
+            .expect("expected to delete the document");
+
+        assert!(drive
+            .fetch_expired_documents(u64::MAX, MAX_EXPIRED_DOCUMENTS_REMOVED_PER_BLOCK, None)
+            .expect("expected to fetch expired documents")
+            .is_empty());
+
+        // The re-created document expires at 6800
+        add_document(5800);
+
+        let balance_before = drive
+            .fetch_identity_balance(owner_id, None)
+            .expect("expected to fetch balance")
+            .expect("expected the identity to have a balance");
+
+        let fee_result = drive
+            .remove_expired_documents(&BlockInfo::default_with_time(6000), None)
+            .expect("expected to remove expired documents");
+
+        assert_eq!(fee_result, FeeResult::default());
+
+        let primary_key_path =
+            contract_documents_primary_key_path(contract.id.as_bytes(), "profile");
+
+        assert!(drive
+            .grove
+            .get(&primary_key_path, document.id.as_slice(), None)
+            .unwrap()
+            .is_ok());
+
+        let balance_after = drive
+            .fetch_identity_balance(owner_id, None)
+            .expect("expected to fetch balance")
+            .expect("expected the identity to have a balance");
+
+        assert_eq!(balance_after, balance_before);
+
+        drive
+            .remove_expired_documents(&BlockInfo::default_with_time(6800), None)
+            .expect("expected to remove expired documents");
+
+        assert!(drive
+            .grove
+            .get(&primary_key_path, document.id.as_slice(), None)
+            .unwrap()
+            .is_err());
+    }
+}
@@ -0,0 +1,342 @@
+//! Index Backfill.
+//!
+//! Contract updates may add non-unique indices over properties that existing documents
+//! already have. The trees of these indices are built from the existing documents in the
+//! same batch as the contract update, with their storage paid for by the contract owner.
+//! To bound the work of a block, updates adding indices to document types holding more than
+//! [MAX_INDEX_BACKFILL_DOCUMENTS] documents are refused by validation.
+//! Indices are backfilled from [`INDEX_BACKFILL_PROTOCOL_VERSION`], before it new indices could
+//! only extend existing ones or cover newly added properties.
+//!
+//! [`INDEX_BACKFILL_PROTOCOL_VERSION`]: crate::drive::defaults::INDEX_BACKFILL_PROTOCOL_VERSION
+
+use std::collections::HashSet;
+
+use costs::storage_cost::StorageCost;
+use costs::OperationCost;
+use dpp::data_contract::document_type::{DocumentType, Index};
+use dpp::document::Document;
+use grovedb::query_result_type::QueryResultType;
+use grovedb::{PathQuery, Query, SizedQuery, TransactionArg};
+
+use crate::drive::defaults::{
+    DEFAULT_HASH_SIZE_U32, EMPTY_TREE_STORAGE_SIZE, MAX_INDEX_BACKFILL_DOCUMENTS,
+    OPTIMIZED_DOCUMENT_REFERENCE,
+};
+use crate::drive::document::{
+    contract_document_type_path_vec, contract_documents_primary_key_path,
+    document_index_value_paths, make_document_reference,
+};
+use crate::drive::flags::StorageFlags;
+use crate::drive::grove_operations::BatchInsertTreeApplyType;
+use crate::drive::object_size_info::PathKeyElementInfo::PathKeyRefElement;
+use crate::drive::object_size_info::{DocumentInfo, PathKeyInfo};
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::fee::op::LowLevelDriveOperation;
+use crate::fee::op::LowLevelDriveOperation::CalculatedCostOperation;
+
+/// Returns the non-unique indices of a document type that have to be built from existing
+/// documents, those whose properties are not already an index of the original document type
+pub fn indices_to_backfill<'a>(
+    document_type: &'a DocumentType,
+    original_document_type: &DocumentType,
+) -> Vec<&'a Index> {
+    document_type
+        .indices
+        .iter()
+        .filter(|index| {
+            // validation doesn't allow new unique indices, existing documents could violate them
+            !index.unique
+                && !original_document_type
+                    .indices
+                    .iter()
+                    .any(|original_index| original_index.properties == index.properties)
+        })
+        .collect()
+}
+
+impl Drive {
+    /// Fetches up to `limit` documents of a document type, the latest revision of each document
+    /// for types keeping history
+    fn fetch_documents_of_type_operations(
+        &self,
+        document_type: &DocumentType,
+        limit: u16,
+        transaction: TransactionArg,
+        drive_operations: &mut Vec<LowLevelDriveOperation>,
+    ) -> Result<Vec<Document>, Error> {
+        let mut query = Query::new();
+        query.insert_all();
+        if document_type.documents_keep_history {
+            query.set_subquery_key(vec![0]);
+        }
+
+        let path_query = PathQuery::new(
+            contract_documents_primary_key_path(
+                document_type.data_contract_id.as_bytes(),
+                document_type.name.as_str(),
+            )
+            .iter()
+            .map(|&x| Vec::from(x))
+            .collect(),
+            SizedQuery::new(query, Some(limit), None),
+        );
+
+        let (serialized_documents, _) = self.grove_get_path_query_serialized_results(
+            &path_query,
+            transaction,
+            drive_operations,
+        )?;
+
+        serialized_documents
+            .iter()
+            .map(|serialized_document| {
+                Document::from_bytes(serialized_document.as_slice(), document_type)
+                    .map_err(Error::Protocol)
+            })
+            .collect()
+    }
+
+    /// Returns whether a contract update can build new indices of a document type from its
+    /// existing documents, which it can while they are at most [MAX_INDEX_BACKFILL_DOCUMENTS].
+    /// Only the keys of the primary key tree are counted, up to one more than the bound, and
+    /// the cost of the query is added to `drive_operations`.
+    pub fn can_backfill_indices(
+        &self,
+        document_type: &DocumentType,
+        transaction: TransactionArg,
+        drive_operations: &mut Vec<LowLevelDriveOperation>,
+    ) -> Result<bool, Error> {
+        let mut query = Query::new();
+        query.insert_all();
+
+        let path_query = PathQuery::new(
+            contract_documents_primary_key_path(
+                document_type.data_contract_id.as_bytes(),
+                document_type.name.as_str(),
+            )
+            .iter()
+            .map(|&x| Vec::from(x))
+            .collect(),
+            SizedQuery::new(query, Some(MAX_INDEX_BACKFILL_DOCUMENTS + 1), None),
+        );
+
+        let (results, _) = self.grove_get_raw_path_query(
+            &path_query,
+            transaction,
+            QueryResultType::QueryKeyElementPairResultType,
+            drive_operations,
+        )?;
+        Ok(results.to_keys().len() <= MAX_INDEX_BACKFILL_DOCUMENTS as usize)
+    }
+
+    /// Adds the cost of backfilling new indices when estimating the cost of a contract update,
+    /// the worst case of the most documents that can be backfilled, none of them sharing trees
+    pub(crate) fn add_estimation_costs_for_index_backfill(
+        document_type: &DocumentType,
+        original_document_type: &DocumentType,
+        storage_flags: Option<&StorageFlags>,
+        batch_operations: &mut Vec<LowLevelDriveOperation>,
+    ) -> Result<(), Error> {
+        let flags_size = storage_flags
+            .map(|flags| flags.serialized_size())
+            .unwrap_or_default();
+        let estimation_document_info = DocumentInfo::DocumentEstimatedAverageSize(0);
+
+        for index in indices_to_backfill(document_type, original_document_type) {
+            // the reference to the document under the [0] tree ending the index path
+            let mut added_bytes = 1
+                + EMPTY_TREE_STORAGE_SIZE
+                + flags_size
+                + DEFAULT_HASH_SIZE_U32
+                + OPTIMIZED_DOCUMENT_REFERENCE as u32
+                + flags_size;
+            for property in index.properties.iter() {
+                let key_size = estimation_document_info
+                    .get_estimated_size_for_document_type(&property.name, document_type)?
+                    as u32;
+                added_bytes += key_size + EMPTY_TREE_STORAGE_SIZE + flags_size;
+            }
+            let operations_per_document = index.properties.len() as u32 + 2;
+
+            let documents = MAX_INDEX_BACKFILL_DOCUMENTS as u32;
+            batch_operations.push(CalculatedCostOperation(OperationCost {
+                seek_count: (documents * operations_per_document) as u16,
+                storage_cost: StorageCost {
+                    added_bytes: documents * added_bytes,
+                    replaced_bytes: 0,
+                    removed_bytes: Default::default(),
+                },
+                storage_loaded_bytes: 0,
+                hash_node_calls: documents * operations_per_document,
+            }));
+        }
+
+        Ok(())
+    }
+
+    /// Adds the operations inserting the existing documents of a document type into the
+    /// indices that were added to it by a contract update
+    pub(crate) fn backfill_indices_operations(
+        &self,
+        document_type: &DocumentType,
+        original_document_type: &DocumentType,
+        storage_flags: Option<&StorageFlags>,
+        transaction: TransactionArg,
+        batch_operations: &mut Vec<LowLevelDriveOperation>,
+    ) -> Result<(), Error> {
+        let indices = indices_to_backfill(document_type, original_document_type);
+        if indices.is_empty() {
+            return Ok(());
+        }
+
+        // documents were serialized with the original document type
+        let documents = self.fetch_documents_of_type_operations(
+            original_document_type,
+            MAX_INDEX_BACKFILL_DOCUMENTS + 1,
+            transaction,
+            batch_operations,
+        )?;
+        if documents.len() > MAX_INDEX_BACKFILL_DOCUMENTS as usize {
+            return Err(Error::Drive(DriveError::TooManyDocumentsToBackfillIndices(
+                "contract updates adding indices to document types with too many documents must be refused by validation",
+            )));
+        }
+
+        let contract_document_type_path = contract_document_type_path_vec(
+            document_type.data_contract_id.as_bytes(),
+            document_type.name.as_str(),
+        );
+
+        // many documents share the same index trees, they must only be inserted once
+        let mut batch_insertion_cache: HashSet<Vec<Vec<u8>>> = HashSet::new();
+
+        for document in documents.iter() {
+            let document_reference =
+                make_document_reference(document, document_type, storage_flags);
+
+            for index in indices.iter() {
+                let index_paths = document_index_value_paths(document, document_type, index, None)?;
+                for index_path in index_paths {
+                    let mut reference_path = index_path;
+                    reference_path.push(vec![0]);
+
+                    // the property name tree at the top level was added with the contract update
+                    for depth in (contract_document_type_path.len() + 2)..=reference_path.len() {
+                        let qualified_path = reference_path[..depth].to_vec();
+                        if batch_insertion_cache.contains(&qualified_path) {
+                            continue;
+                        }
+                        self.batch_insert_empty_tree_if_not_exists(
+                            PathKeyInfo::PathKeyRef::<0>((
+                                reference_path[..depth - 1].to_vec(),
+                                reference_path[depth - 1].as_slice(),
+                            )),
+                            storage_flags,
+                            BatchInsertTreeApplyType::StatefulBatchInsertTree,
+                            transaction,
+                            &mut None,
+                            batch_operations,
+                        )?;
+                        batch_insertion_cache.insert(qualified_path);
+                    }
+
+                    self.batch_insert(
+                        PathKeyRefElement::<0>((
+                            reference_path,
+                            document.id.as_slice(),
+                            document_reference.clone(),
+                        )),
+                        batch_operations,
+                    )?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "full")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dpp::data_contract::extra::common::json_document_to_contract;
+
+    #[test]
+    fn should_only_backfill_new_non_unique_indices() {
+        let contract = json_document_to_contract(
+            "tests/supporting_files/contract/dashpay/dashpay-contract.json",
+        )
+        .expect("expected to get contract");
+        let original_document_type = contract
+            .document_type_for_name("contactRequest")
+            .expect("expected to get document type");
+
+        let mut document_type = original_document_type.clone();
+        assert!(indices_to_backfill(&document_type, original_document_type).is_empty());
+
+        let mut new_index = document_type
+            .indices
+            .iter()
+            .find(|index| index.unique)
+            .expect("expected a unique index")
+            .clone();
+        new_index.name = "newUnique".to_string();
+        new_index.properties.reverse();
+        document_type.indices.push(new_index.clone());
+        assert!(indices_to_backfill(&document_type, original_document_type).is_empty());
+
+        new_index.name = "newNonUnique".to_string();
+        new_index.unique = false;
+        document_type.indices.push(new_index);
+        let indices = indices_to_backfill(&document_type, original_document_type);
+        assert_eq!(indices.len(), 1);
+        assert_eq!(indices[0].name, "newNonUnique");
+    }
+
+    #[test]
+    fn should_estimate_the_backfill_of_the_most_documents_allowed() {
+        let contract = json_document_to_contract(
+            "tests/supporting_files/contract/dashpay/dashpay-contract.json",
+        )
+        .expect("expected to get contract");
+        let original_document_type = contract
+            .document_type_for_name("contactRequest")
+            .expect("expected to get document type");
+
+        let mut operations = vec![];
+        Drive::add_estimation_costs_for_index_backfill(
+            original_document_type,
+            original_document_type,
+            None,
+            &mut operations,
+        )
+        .expect("expected to estimate");
+        assert!(operations.is_empty());
+
+        let mut document_type = original_document_type.clone();
+        let mut new_index = document_type.indices[0].clone();
+        new_index.name = "newNonUnique".to_string();
+        new_index.unique = false;
+        new_index.properties.reverse();
+        document_type.indices.push(new_index);
+
+        Drive::add_estimation_costs_for_index_backfill(
+            &document_type,
+            original_document_type,
+            None,
+            &mut operations,
+        )
+        .expect("expected to estimate");
+        let cost = LowLevelDriveOperation::combine_cost_operations(&operations);
+        assert_eq!(operations.len(), 1);
+        assert_eq!(
+            cost.storage_cost.added_bytes % MAX_INDEX_BACKFILL_DOCUMENTS as u32,
+            0
+        );
+        assert!(cost.storage_cost.added_bytes > MAX_INDEX_BACKFILL_DOCUMENTS as u32);
+    }
+}
@@ -47,6 +47,7 @@ use dpp::document::document_transition::{
 };
 use dpp::document::Document;
 use dpp::identifier::Identifier;
+use dpp::platform_value::btreemap_extensions::BTreeValueMapPathHelper;
 use dpp::platform_value::{platform_value, Value};
 use dpp::prelude::TimestampMillis;
 use dpp::validation::SimpleConsensusValidationResult;
@@ -181,7 +182,12 @@ impl Drive {
                                 }
 
                                 _ => {
-                                    if let Some(value) = data.get(property.name.as_str()) {
+                                    // index properties can be paths to nested values
+                                    if let Some(value) = data
+                                        .get_optional_at_path(property.name.as_str())
+                                        .ok()
+                                        .flatten()
+                                    {
                                         value.clone()
                                     } else {
                                         return None;
@@ -851,7 +851,7 @@ impl Drive {
         document_and_contract_info: &DocumentAndContractInfo,
         index_path_info: PathInfo<0>,
         index_level: &IndexLevel,
-        mut any_fields_null: bool,
+        any_fields_null: bool,
         previous_batch_operations: &mut Option<&mut Vec<LowLevelDriveOperation>>,
         storage_flags: &Option<&StorageFlags>,
         estimated_costs_only_with_layer_info: &mut Option<
@@ -912,16 +912,16 @@ impl Drive {
             let mut sub_level_index_path_info = index_path_info.clone();
             let index_property_key = KeyRef(name.as_bytes());
 
-            let document_index_field = document_and_contract_info
+            // array fields have an index entry for each of their elements
+            let document_index_fields = document_and_contract_info
                 .owned_document_info
                 .document_info
-                .get_raw_for_document_type(
+                .get_raw_index_keys_for_document_type(
                     name,
                     document_type,
                     document_and_contract_info.owned_document_info.owner_id,
                     Some((sub_level, event_id)),
-                )?
-                .unwrap_or_default();
+                )?;
 
             let path_key_info = index_property_key
                 .clone()
@@ -969,38 +969,42 @@ impl Drive {
             // Iteration 1. the index path is now something like Contracts/ContractID/Documents(1)/$ownerId/<ownerId>/toUserId
             // Iteration 2. the index path is now something like Contracts/ContractID/Documents(1)/$ownerId/<ownerId>/toUserId/<ToUserId>/accountReference
 
-            let path_key_info = document_index_field
-                .clone()
-                .add_path_info(sub_level_index_path_info.clone());
-
-            // here we are inserting an empty tree that will have a subtree of all other index properties
-            self.batch_insert_empty_tree_if_not_exists(
-                path_key_info.clone(),
-                *storage_flags,
-                apply_type,
-                transaction,
-                previous_batch_operations,
-                batch_operations,
-            )?;
-
-            any_fields_null |= document_index_field.is_empty();
-
-            // we push the actual value of the index path
-            sub_level_index_path_info.push(document_index_field)?;
-            // Iteration 1. the index path is now something like Contracts/ContractID/Documents(1)/$ownerId/<ownerId>/toUserId/<ToUserId>/
-            // Iteration 2. the index path is now something like Contracts/ContractID/Documents(1)/$ownerId/<ownerId>/toUserId/<ToUserId>/accountReference/<accountReference>
-            self.add_indices_for_index_level_for_contract_operations(
-                document_and_contract_info,
-                sub_level_index_path_info,
-                sub_level,
-                any_fields_null,
-                previous_batch_operations,
-                storage_flags,
-                estimated_costs_only_with_layer_info,
-                event_id,
-                transaction,
-                batch_operations,
-            )?;
+            for document_index_field in document_index_fields {
+                let mut value_index_path_info = sub_level_index_path_info.clone();
+
+                let path_key_info = document_index_field
+                    .clone()
+                    .add_path_info(value_index_path_info.clone());
+
+                // here we are inserting an empty tree that will have a subtree of all other index properties
+                self.batch_insert_empty_tree_if_not_exists(
+                    path_key_info.clone(),
+                    *storage_flags,
+                    apply_type,
+                    transaction,
+                    previous_batch_operations,
+                    batch_operations,
+                )?;
+
+                let any_fields_null = any_fields_null || document_index_field.is_empty();
+
+                // we push the actual value of the index path
+                value_index_path_info.push(document_index_field)?;
+                // Iteration 1. the index path is now something like Contracts/ContractID/Documents(1)/$ownerId/<ownerId>/toUserId/<ToUserId>/
+                // Iteration 2. the index path is now something like Contracts/ContractID/Documents(1)/$ownerId/<ownerId>/toUserId/<ToUserId>/accountReference/<accountReference>
+                self.add_indices_for_index_level_for_contract_operations(
+                    document_and_contract_info,
+                    value_index_path_info,
+                    sub_level,
+                    any_fields_null,
+                    previous_batch_operations,
+                    storage_flags,
+                    estimated_costs_only_with_layer_info,
+                    event_id,
+                    transaction,
+                    batch_operations,
+                )?;
+            }
         }
         Ok(())
     }
@@ -1081,28 +1085,16 @@ impl Drive {
 
             // with the example of the dashpay contract's first index
             // the index path is now something like Contracts/ContractID/Documents(1)/$ownerId
-            let document_top_field = document_and_contract_info
+            // array fields have an index entry for each of their elements
+            let document_top_fields = document_and_contract_info
                 .owned_document_info
                 .document_info
-                .get_raw_for_document_type(
+                .get_raw_index_keys_for_document_type(
                     name,
                     document_type,
                     document_and_contract_info.owned_document_info.owner_id,
                     Some((sub_level, event_id)),
-                )?
-                .unwrap_or_default();
-
-            // The zero will not matter here, because the PathKeyInfo is variable
-            let path_key_info = document_top_field.clone().add_path::<0>(index_path.clone());
-            // here we are inserting an empty tree that will have a subtree of all other index properties
-            self.batch_insert_empty_tree_if_not_exists(
-                path_key_info.clone(),
-                storage_flags,
-                apply_type,
-                transaction,
-                previous_batch_operations,
-                batch_operations,
-            )?;
+                )?;
 
             if let Some(estimated_costs_only_with_layer_info) = estimated_costs_only_with_layer_info
             {
@@ -1132,35 +1124,49 @@ impl Drive {
                 );
             }
 
-            let any_fields_null = document_top_field.is_empty();
-
-            let mut index_path_info = if document_and_contract_info
-                .owned_document_info
-                .document_info
-                .is_document_size()
-            {
-                // This is a stateless operation
-                PathInfo::PathWithSizes(KeyInfoPath::from_known_owned_path(index_path))
-            } else {
-                PathInfo::PathIterator::<0>(index_path)
-            };
-
-            // we push the actual value of the index path
-            index_path_info.push(document_top_field)?;
-            // the index path is now something like Contracts/ContractID/Documents(1)/$ownerId/<ownerId>
+            for document_top_field in document_top_fields {
+                // The zero will not matter here, because the PathKeyInfo is variable
+                let path_key_info = document_top_field.clone().add_path::<0>(index_path.clone());
+                // here we are inserting an empty tree that will have a subtree of all other index properties
+                self.batch_insert_empty_tree_if_not_exists(
+                    path_key_info.clone(),
+                    storage_flags,
+                    apply_type,
+                    transaction,
+                    previous_batch_operations,
+                    batch_operations,
+                )?;
+
+                let any_fields_null = document_top_field.is_empty();
+
+                let mut index_path_info = if document_and_contract_info
+                    .owned_document_info
+                    .document_info
+                    .is_document_size()
+                {
+                    // This is a stateless operation
+                    PathInfo::PathWithSizes(KeyInfoPath::from_known_owned_path(index_path.clone()))
+                } else {
+                    PathInfo::PathIterator::<0>(index_path.clone())
+                };
 
-            self.add_indices_for_index_level_for_contract_operations(
-                document_and_contract_info,
-                index_path_info,
-                sub_level,
-                any_fields_null,
-                previous_batch_operations,
-                &storage_flags,
-                estimated_costs_only_with_layer_info,
-                event_id,
-                transaction,
-                batch_operations,
-            )?;
+                // we push the actual value of the index path
+                index_path_info.push(document_top_field)?;
+                // the index path is now something like Contracts/ContractID/Documents(1)/$ownerId/<ownerId>
+
+                self.add_indices_for_index_level_for_contract_operations(
+                    document_and_contract_info,
+                    index_path_info,
+                    sub_level,
+                    any_fields_null,
+                    previous_batch_operations,
+                    &storage_flags,
+                    estimated_costs_only_with_layer_info,
+                    event_id,
+                    transaction,
+                    batch_operations,
+                )?;
+            }
         }
         Ok(())
     }
@@ -1239,6 +1245,13 @@ impl Drive {
                 transaction,
                 &mut batch_operations,
             )?;
+
+            self.add_document_expiration_operations(
+                &document_and_contract_info,
+                block_info,
+                estimated_costs_only_with_layer_info,
+                &mut batch_operations,
+            )?;
         }
 
         self.add_indices_for_top_index_level_for_contract_operations(
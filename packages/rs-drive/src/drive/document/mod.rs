@@ -42,6 +42,10 @@ use crate::drive::{defaults, RootTree};
 #[cfg(any(feature = "full", feature = "verify"))]
 use dpp::data_contract::document_type::DocumentType;
 #[cfg(feature = "full")]
+use crate::error::Error;
+#[cfg(feature = "full")]
+use dpp::data_contract::document_type::Index;
+#[cfg(feature = "full")]
 use dpp::document::Document;
 #[cfg(feature = "full")]
 use grovedb::batch::key_info::KeyInfo;
@@ -52,10 +56,18 @@ use grovedb::reference_path::ReferencePathType::UpstreamRootHeightReference;
 #[cfg(feature = "full")]
 use grovedb::Element;
 #[cfg(feature = "full")]
+use std::collections::BTreeSet;
+#[cfg(feature = "full")]
 mod delete;
 #[cfg(feature = "full")]
 mod estimation_costs;
 #[cfg(feature = "full")]
+/// Expiration of documents with a ttl
+pub mod expiration;
+#[cfg(feature = "full")]
+/// Building the indices added by contract updates from existing documents
+pub mod index_backfill;
+#[cfg(feature = "full")]
 mod index_uniqueness;
 #[cfg(feature = "full")]
 mod insert;
@@ -192,6 +204,44 @@ fn make_document_reference(
     )
 }
 
+#[cfg(feature = "full")]
+/// Returns the paths of the index value trees under which a document is referenced by an
+/// index. Array properties yield a path for each of their elements.
+fn document_index_value_paths(
+    document: &Document,
+    document_type: &DocumentType,
+    index: &Index,
+    owner_id: Option<[u8; 32]>,
+) -> Result<BTreeSet<Vec<Vec<u8>>>, Error> {
+    let mut paths = vec![contract_document_type_path_vec(
+        document_type.data_contract_id.as_bytes(),
+        document_type.name.as_str(),
+    )];
+    for index_property in &index.properties {
+        let values = match document
+            .get_raw_array_elements_for_document_type(&index_property.name, document_type)?
+        {
+            Some(elements) if !elements.is_empty() => elements,
+            Some(_) => vec![vec![]],
+            None => vec![document
+                .get_raw_for_document_type(&index_property.name, document_type, owner_id)?
+                .unwrap_or_default()],
+        };
+        paths = paths
+            .into_iter()
+            .flat_map(|path| {
+                values.iter().map(move |value| {
+                    let mut path = path.clone();
+                    path.push(Vec::from(index_property.name.as_bytes()));
+                    path.push(value.clone());
+                    path
+                })
+            })
+            .collect();
+    }
+    Ok(paths.into_iter().collect())
+}
+
 #[cfg(feature = "full")]
 /// size of a document reference.
 fn document_reference_size(document_type: &DocumentType) -> u32 {
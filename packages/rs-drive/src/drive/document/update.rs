@@ -33,9 +33,9 @@
 //!
 
 use std::borrow::Cow;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeSet, HashMap, HashSet};
 
-use dpp::data_contract::document_type::DocumentType;
+use dpp::data_contract::document_type::{DocumentType, Index};
 
 use grovedb::batch::key_info::KeyInfo;
 use grovedb::batch::key_info::KeyInfo::KnownKey;
@@ -49,9 +49,9 @@ use crate::drive::batch::drive_op_batch::{
 use crate::drive::batch::{DocumentOperationType, DriveOperation};
 use crate::drive::defaults::CONTRACT_DOCUMENTS_PATH_HEIGHT;
 use crate::drive::document::{
-    contract_document_type_path,
+    contract_document_type_path, contract_document_type_path_vec,
     contract_documents_keeping_history_primary_key_path_for_document_id,
-    contract_documents_primary_key_path, make_document_reference,
+    contract_documents_primary_key_path, document_index_value_paths, make_document_reference,
 };
 use crate::drive::flags::StorageFlags;
 use crate::drive::object_size_info::DocumentInfo::{
@@ -423,9 +423,37 @@ impl Drive {
             )));
         };
 
+        let old_document = old_document_info
+            .get_borrowed_document()
+            .ok_or(Error::Drive(DriveError::CorruptedCodeExecution(
+                "old document info must have a document",
+            )))?;
+
         let mut batch_insertion_cache: HashSet<Vec<Vec<u8>>> = HashSet::new();
         // fourth we need to store a reference to the document for each index
         for index in &document_type.indices {
+            // array properties give a document an index entry for each of their elements
+            if index
+                .properties
+                .iter()
+                .any(|property| document_type.is_array_field(&property.name))
+            {
+                self.update_multi_value_index_operations(
+                    index,
+                    document,
+                    old_document,
+                    document_type,
+                    owner_id,
+                    &document_reference,
+                    storage_flags,
+                    &mut batch_insertion_cache,
+                    previous_batch_operations,
+                    transaction,
+                    &mut batch_operations,
+                )?;
+                continue;
+            }
+
             // at this point the contract path is to the contract documents
             // for each index the top index component will already have been added
             // when the contract itself was created
@@ -698,6 +726,114 @@ impl Drive {
         Ok(batch_operations)
     }
 
+    /// Updates the entries of an index that contains an array property. The entries of
+    /// values the document no longer has are removed, the new ones inserted and the
+    /// others refreshed.
+    fn update_multi_value_index_operations(
+        &self,
+        index: &Index,
+        document: &Document,
+        old_document: &Document,
+        document_type: &DocumentType,
+        owner_id: Option<[u8; 32]>,
+        document_reference: &Element,
+        storage_flags: Option<&StorageFlags>,
+        batch_insertion_cache: &mut HashSet<Vec<Vec<u8>>>,
+        previous_batch_operations: &mut Option<&mut Vec<LowLevelDriveOperation>>,
+        transaction: TransactionArg,
+        batch_operations: &mut Vec<LowLevelDriveOperation>,
+    ) -> Result<(), Error> {
+        let contract_document_type_path = contract_document_type_path_vec(
+            document_type.data_contract_id.as_bytes(),
+            document_type.name.as_str(),
+        );
+
+        let new_paths = document_index_value_paths(document, document_type, index, owner_id)?;
+        let old_paths = document_index_value_paths(old_document, document_type, index, owner_id)?;
+
+        // indices with array properties are never unique, the references are stored
+        // in a tree at key "0" based off of the primary key
+        for old_path in old_paths.difference(&new_paths) {
+            let mut key_info_path = KeyInfoPath::from_known_owned_path(old_path.clone());
+            key_info_path.push(KnownKey(vec![0]));
+
+            self.batch_delete_up_tree_while_empty(
+                key_info_path,
+                document.id.as_slice(),
+                Some(CONTRACT_DOCUMENTS_PATH_HEIGHT),
+                BatchDeleteUpTreeApplyType::StatefulBatchDelete {
+                    is_known_to_be_subtree_with_sum: Some((false, false)),
+                },
+                transaction,
+                previous_batch_operations,
+                batch_operations,
+            )?;
+        }
+
+        for new_path in new_paths.difference(&old_paths) {
+            // the property name tree at the top level was added with the contract
+            for depth in (contract_document_type_path.len() + 2)..=new_path.len() {
+                let qualified_path = new_path[..depth].to_vec();
+                if batch_insertion_cache.contains(&qualified_path) {
+                    continue;
+                }
+                let inserted = self.batch_insert_empty_tree_if_not_exists(
+                    PathKeyInfo::PathKeyRef::<0>((
+                        new_path[..depth - 1].to_vec(),
+                        new_path[depth - 1].as_slice(),
+                    )),
+                    storage_flags,
+                    BatchInsertTreeApplyType::StatefulBatchInsertTree,
+                    transaction,
+                    previous_batch_operations,
+                    batch_operations,
+                )?;
+                if inserted {
+                    batch_insertion_cache.insert(qualified_path);
+                }
+            }
+
+            self.batch_insert_empty_tree_if_not_exists(
+                PathKeyInfo::PathKeyRef::<0>((new_path.clone(), &[0])),
+                storage_flags,
+                BatchInsertTreeApplyType::StatefulBatchInsertTree,
+                transaction,
+                previous_batch_operations,
+                batch_operations,
+            )?;
+
+            let mut reference_path = new_path.clone();
+            reference_path.push(vec![0]);
+
+            self.batch_insert(
+                PathKeyRefElement::<0>((
+                    reference_path,
+                    document.id.as_slice(),
+                    document_reference.clone(),
+                )),
+                batch_operations,
+            )?;
+        }
+
+        // We can only trust the reference content has not changed if there are no storage flags
+        let trust_refresh_reference = storage_flags.is_none();
+
+        for unchanged_path in new_paths.intersection(&old_paths) {
+            let mut reference_path = unchanged_path.clone();
+            reference_path.push(vec![0]);
+
+            self.batch_refresh_reference(
+                reference_path,
+                document.id.to_vec(),
+                document_reference.clone(),
+                trust_refresh_reference,
+                batch_operations,
+            )?;
+        }
+
+        Ok(())
+    }
+
     /// Add update multiple documents operations
     pub fn add_update_multiple_documents_operations<'a>(
         &self,
@@ -6,12 +6,16 @@ use crate::error::identity::IdentityError;
 use crate::error::Error;
 use crate::fee::calculate_fee;
 use crate::fee::credits::{Credits, MAX_CREDITS};
+use crate::fee::default_costs::EpochCosts;
+use crate::fee::default_costs::KnownCostItem::FetchIdentityBalanceProcessingCost;
 use crate::fee::op::LowLevelDriveOperation;
+use crate::fee::op::LowLevelDriveOperation::PreCalculatedFeeResult;
 use crate::fee::result::{BalanceChange, BalanceChangeForIdentity, FeeResult};
 use dpp::block::block_info::BlockInfo;
+use dpp::block::epoch::Epoch;
 use grovedb::batch::KeyInfoPath;
 use grovedb::{Element, EstimatedLayerInformation, TransactionArg};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 /// The outcome of paying for a fee
 pub struct ApplyBalanceChangeOutcome {
@@ -257,6 +261,71 @@ impl Drive {
         Ok(drive_operations)
     }
 
+    /// Adds balances to several identities at once, for example the recipients of a
+    /// credit transfer. Keys are unique so every identity gets a single balance update
+    /// within the batch.
+    pub fn add_to_identities_balances(
+        &self,
+        added_balances: BTreeMap<[u8; 32], Credits>,
+        block_info: &BlockInfo,
+        apply: bool,
+        transaction: TransactionArg,
+    ) -> Result<FeeResult, Error> {
+        let mut estimated_costs_only_with_layer_info = if apply {
+            None::<HashMap<KeyInfoPath, EstimatedLayerInformation>>
+        } else {
+            Some(HashMap::new())
+        };
+
+        let batch_operations = self.add_to_identities_balances_operations(
+            added_balances,
+            &block_info.epoch,
+            &mut estimated_costs_only_with_layer_info,
+            transaction,
+        )?;
+
+        let mut drive_operations: Vec<LowLevelDriveOperation> = vec![];
+        self.apply_batch_low_level_drive_operations(
+            estimated_costs_only_with_layer_info,
+            transaction,
+            batch_operations,
+            &mut drive_operations,
+        )?;
+
+        let fees = calculate_fee(None, Some(drive_operations), &block_info.epoch)?;
+
+        Ok(fees)
+    }
+
+    /// Balances are stored in the balance tree under the identity's id
+    /// This gets operations for every identity based on apply flag (stateful vs stateless)
+    /// Every identity is charged as a balance fetch so the fees scale with their number
+    pub(crate) fn add_to_identities_balances_operations(
+        &self,
+        added_balances: BTreeMap<[u8; 32], Credits>,
+        epoch: &Epoch,
+        estimated_costs_only_with_layer_info: &mut Option<
+            HashMap<KeyInfoPath, EstimatedLayerInformation>,
+        >,
+        transaction: TransactionArg,
+    ) -> Result<Vec<LowLevelDriveOperation>, Error> {
+        let balance_cost = epoch.cost_for_known_cost_item(FetchIdentityBalanceProcessingCost);
+        let mut drive_operations = vec![PreCalculatedFeeResult(
+            FeeResult::new_from_processing_fee(balance_cost * added_balances.len() as u64),
+        )];
+
+        for (identity_id, added_balance) in added_balances {
+            drive_operations.extend(self.add_to_identity_balance_operations(
+                identity_id,
+                added_balance,
+                estimated_costs_only_with_layer_info,
+                transaction,
+            )?);
+        }
+
+        Ok(drive_operations)
+    }
+
     /// Balances are stored in the balance tree under the identity's id
     pub fn apply_balance_change_from_fee_to_identity(
         &self,
@@ -715,6 +784,112 @@ mod tests {
         }
     }
 
+    mod add_to_identities_balances {
+        use super::*;
+
+        #[test]
+        fn should_add_to_balances_of_all_identities() {
+            let drive = setup_drive_with_initial_state_structure();
+
+            let block_info = BlockInfo::default_with_epoch(Epoch::new(0).unwrap());
+
+            let identities: Vec<Identity> = (0..3)
+                .map(|i| Identity::random_identity(5, Some(12345 + i)))
+                .collect();
+
+            for identity in &identities {
+                drive
+                    .add_new_identity(identity.clone(), &block_info, true, None)
+                    .expect("expected to insert identity");
+            }
+
+            let added_balances: BTreeMap<[u8; 32], Credits> = identities
+                .iter()
+                .enumerate()
+                .map(|(i, identity)| (identity.id.to_buffer(), 100 * (i as Credits + 1)))
+                .collect();
+
+            drive
+                .add_to_identities_balances(added_balances.clone(), &block_info, true, None)
+                .expect("expected to add to identities balances");
+
+            for identity in &identities {
+                let (balance, _fee_cost) = drive
+                    .fetch_identity_balance_with_costs(
+                        identity.id.to_buffer(),
+                        &block_info,
+                        true,
+                        None,
+                    )
+                    .expect("expected to get balance");
+
+                assert_eq!(
+                    balance.expect("balance should present"),
+                    identity.balance + added_balances[&identity.id.to_buffer()]
+                );
+            }
+        }
+
+        #[test]
+        fn should_scale_processing_fees_with_the_number_of_identities() {
+            let drive = setup_drive_with_initial_state_structure();
+
+            let block_info = BlockInfo::default_with_epoch(Epoch::new(0).unwrap());
+
+            let identities: Vec<Identity> = (0..3)
+                .map(|i| Identity::random_identity(5, Some(12345 + i)))
+                .collect();
+
+            for identity in &identities {
+                drive
+                    .add_new_identity(identity.clone(), &block_info, true, None)
+                    .expect("expected to insert identity");
+            }
+
+            let fee_result_for = |count: usize| {
+                let added_balances = identities
+                    .iter()
+                    .take(count)
+                    .map(|identity| (identity.id.to_buffer(), 300))
+                    .collect();
+
+                drive
+                    .add_to_identities_balances(added_balances, &block_info, false, None)
+                    .expect("expected to estimate the fees")
+            };
+
+            let balance_cost = block_info
+                .epoch
+                .cost_for_known_cost_item(FetchIdentityBalanceProcessingCost);
+
+            assert!(
+                fee_result_for(3).processing_fee
+                    >= fee_result_for(1).processing_fee + 2 * balance_cost
+            );
+        }
+
+        #[test]
+        fn should_fail_if_one_of_balances_is_not_persisted() {
+            let drive = setup_drive_with_initial_state_structure();
+
+            let block_info = BlockInfo::default_with_epoch(Epoch::new(0).unwrap());
+
+            let identity = Identity::random_identity(5, Some(12345));
+
+            drive
+                .add_new_identity(identity.clone(), &block_info, true, None)
+                .expect("expected to insert identity");
+
+            let added_balances = BTreeMap::from([(identity.id.to_buffer(), 300), ([0; 32], 300)]);
+
+            let result = drive.add_to_identities_balances(added_balances, &block_info, true, None);
+
+            assert!(
+                matches!(result, Err(Error::Drive(DriveError::CorruptedCodeExecution(m))) if m == "there should always be a balance")
+            );
+        }
+    }
+
     mod remove_from_identity_balance {
         use super::*;
 
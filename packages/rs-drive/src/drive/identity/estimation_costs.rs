@@ -66,6 +66,7 @@ use grovedb::EstimatedLayerInformation;
 use grovedb::EstimatedLayerSizes::{AllItems, AllReference, AllSubtrees, Mix};
 
 use crate::drive::identity::{
+    identity_contract_info_path_vec, identity_contract_info_root_path_vec,
     identity_key_tree_path_vec, identity_path_vec, identity_query_keys_purpose_tree_path_vec,
     identity_query_keys_security_level_tree_path_vec, identity_query_keys_tree_path_vec,
 };
@@ -173,6 +174,88 @@ impl Drive {
         );
     }
 
+    pub(crate) fn add_estimation_costs_for_update_nonce(
+        identity_id: [u8; 32],
+        estimated_costs_only_with_layer_info: &mut HashMap<KeyInfoPath, EstimatedLayerInformation>,
+    ) {
+        // we need to add the root
+        estimated_costs_only_with_layer_info.insert(
+            KeyInfoPath::from_known_path([]),
+            EstimatedLayerInformation {
+                is_sum_tree: false,
+                estimated_layer_count: EstimatedLevel(0, false),
+                estimated_layer_sizes: AllSubtrees(1, NoSumTrees, None),
+            },
+        );
+
+        // we then need to insert the root identity layer
+        estimated_costs_only_with_layer_info.insert(
+            KeyInfoPath::from_known_path(identity_tree_path()),
+            EstimatedLayerInformation {
+                is_sum_tree: false,
+                estimated_layer_count: PotentiallyAtMaxElements,
+                estimated_layer_sizes: AllSubtrees(DEFAULT_HASH_SIZE_U8, NoSumTrees, None),
+            },
+        );
+
+        // In this layer we have
+        //                   Negative Credit
+        //              /                   \
+        //          Keys                     Contract Info
+        //        /      \                              \
+        //  Revision   Query Keys                       Nonce
+
+        // we then need to insert the identity layer
+        estimated_costs_only_with_layer_info.insert(
+            KeyInfoPath::from_known_owned_path(identity_path_vec(identity_id.as_slice())),
+            EstimatedLayerInformation {
+                is_sum_tree: false,
+                estimated_layer_count: EstimatedLevel(2, false),
+                estimated_layer_sizes: Mix {
+                    subtrees_size: Some((1, NoSumTrees, None, 2)),
+                    items_size: Some((1, 8, None, 1)),
+                    references_size: None,
+                },
+            },
+        );
+    }
+
+    pub(crate) fn add_estimation_costs_for_update_contract_nonce(
+        identity_id: [u8; 32],
+        contract_id: [u8; 32],
+        estimated_costs_only_with_layer_info: &mut HashMap<KeyInfoPath, EstimatedLayerInformation>,
+    ) {
+        Self::add_estimation_costs_for_update_nonce(
+            identity_id,
+            estimated_costs_only_with_layer_info,
+        );
+
+        // the identity has some contracts it has interacted with
+        estimated_costs_only_with_layer_info.insert(
+            KeyInfoPath::from_known_owned_path(identity_contract_info_root_path_vec(
+                identity_id.as_slice(),
+            )),
+            EstimatedLayerInformation {
+                is_sum_tree: false,
+                estimated_layer_count: ApproximateElements(16),
+                estimated_layer_sizes: AllSubtrees(DEFAULT_HASH_SIZE_U8, NoSumTrees, None),
+            },
+        );
+
+        // the contract info for a single contract only holds the nonce
+        estimated_costs_only_with_layer_info.insert(
+            KeyInfoPath::from_known_owned_path(identity_contract_info_path_vec(
+                identity_id.as_slice(),
+                contract_id.as_slice(),
+            )),
+            EstimatedLayerInformation {
+                is_sum_tree: false,
+                estimated_layer_count: EstimatedLevel(0, false),
+                estimated_layer_sizes: AllItems(1, 8, None),
+            },
+        );
+    }
+
     pub(crate) fn add_estimation_costs_for_negative_credit(
         identity_id: [u8; 32],
         estimated_costs_only_with_layer_info: &mut HashMap<KeyInfoPath, EstimatedLayerInformation>,
@@ -62,6 +62,8 @@ mod insert;
 #[cfg(any(feature = "full", feature = "verify"))]
 /// Module related to Identity Keys
 pub mod key;
+#[cfg(any(feature = "full", feature = "verify"))]
+mod nonce;
 #[cfg(feature = "full")]
 mod update;
 
@@ -118,7 +120,7 @@ pub(crate) fn identity_contract_info_path<'a>(
     ]
 }
 
-#[cfg(feature = "full")]
+#[cfg(any(feature = "full", feature = "verify"))]
 pub(crate) fn identity_contract_info_path_vec(
     identity_id: &[u8],
     contract_id: &[u8],
@@ -255,6 +257,8 @@ pub enum IdentityRootStructure {
     IdentityTreeNegativeCredit = 3,
     /// Identity contract information
     IdentityContractInfo = 4,
+    /// The nonce of the identity, used for replay protection
+    IdentityTreeNonce = 5,
 }
 
 #[cfg(feature = "full")]
@@ -287,6 +291,7 @@ impl From<IdentityRootStructure> for &'static [u8; 1] {
             IdentityRootStructure::IdentityTreeKeyReferences => &[2],
             IdentityRootStructure::IdentityTreeNegativeCredit => &[3],
             IdentityRootStructure::IdentityContractInfo => &[4],
+            IdentityRootStructure::IdentityTreeNonce => &[5],
         }
     }
 }
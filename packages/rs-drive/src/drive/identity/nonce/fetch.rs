@@ -0,0 +1,171 @@
+use crate::drive::grove_operations::DirectQueryType;
+use crate::drive::grove_operations::QueryTarget::QueryTargetValue;
+use crate::drive::identity::nonce::IDENTITY_CONTRACT_NONCE_KEY;
+use crate::drive::identity::IdentityRootStructure::IdentityTreeNonce;
+use crate::drive::identity::{identity_contract_info_path, identity_path};
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::fee::calculate_fee;
+use crate::fee::op::LowLevelDriveOperation;
+use crate::fee::result::FeeResult;
+use dpp::block::block_info::BlockInfo;
+use dpp::prelude::IdentityNonce;
+use grovedb::Element::Item;
+use grovedb::{Element, TransactionArg};
+
+impl Drive {
+    /// Fetches the Identity's nonce from the backing store
+    /// Passing apply as false get the estimated cost instead
+    pub fn fetch_identity_nonce(
+        &self,
+        identity_id: [u8; 32],
+        apply: bool,
+        transaction: TransactionArg,
+    ) -> Result<Option<IdentityNonce>, Error> {
+        let mut drive_operations: Vec<LowLevelDriveOperation> = vec![];
+        self.fetch_identity_nonce_operations(identity_id, apply, transaction, &mut drive_operations)
+    }
+
+    /// Fetches the Identity's nonce from the backing store
+    /// Passing apply as false get the estimated cost instead
+    pub fn fetch_identity_nonce_with_fees(
+        &self,
+        identity_id: [u8; 32],
+        block_info: &BlockInfo,
+        apply: bool,
+        transaction: TransactionArg,
+    ) -> Result<(Option<IdentityNonce>, FeeResult), Error> {
+        let mut drive_operations: Vec<LowLevelDriveOperation> = vec![];
+        let value = self.fetch_identity_nonce_operations(
+            identity_id,
+            apply,
+            transaction,
+            &mut drive_operations,
+        )?;
+        let fees = calculate_fee(None, Some(drive_operations), &block_info.epoch)?;
+        Ok((value, fees))
+    }
+
+    /// Creates the operations to get Identity's nonce from the backing store
+    /// This gets operations based on apply flag (stateful vs stateless)
+    pub(crate) fn fetch_identity_nonce_operations(
+        &self,
+        identity_id: [u8; 32],
+        apply: bool,
+        transaction: TransactionArg,
+        drive_operations: &mut Vec<LowLevelDriveOperation>,
+    ) -> Result<Option<IdentityNonce>, Error> {
+        let direct_query_type = if apply {
+            DirectQueryType::StatefulDirectQuery
+        } else {
+            DirectQueryType::StatelessDirectQuery {
+                in_tree_using_sums: false,
+                query_target: QueryTargetValue(8),
+            }
+        };
+        let identity_path = identity_path(identity_id.as_slice());
+        let element = self.grove_get_raw_optional(
+            (&identity_path).into(),
+            &[IdentityTreeNonce as u8],
+            direct_query_type,
+            transaction,
+            drive_operations,
+        )?;
+        Self::decode_identity_nonce_element(element)
+    }
+
+    /// Fetches the Identity's nonce for a contract from the backing store
+    /// Passing apply as false get the estimated cost instead
+    pub fn fetch_identity_contract_nonce(
+        &self,
+        identity_id: [u8; 32],
+        contract_id: [u8; 32],
+        apply: bool,
+        transaction: TransactionArg,
+    ) -> Result<Option<IdentityNonce>, Error> {
+        let mut drive_operations: Vec<LowLevelDriveOperation> = vec![];
+        self.fetch_identity_contract_nonce_operations(
+            identity_id,
+            contract_id,
+            apply,
+            transaction,
+            &mut drive_operations,
+        )
+    }
+
+    /// Fetches the Identity's nonce for a contract from the backing store
+    /// Passing apply as false get the estimated cost instead
+    pub fn fetch_identity_contract_nonce_with_fees(
+        &self,
+        identity_id: [u8; 32],
+        contract_id: [u8; 32],
+        block_info: &BlockInfo,
+        apply: bool,
+        transaction: TransactionArg,
+    ) -> Result<(Option<IdentityNonce>, FeeResult), Error> {
+        let mut drive_operations: Vec<LowLevelDriveOperation> = vec![];
+        let value = self.fetch_identity_contract_nonce_operations(
+            identity_id,
+            contract_id,
+            apply,
+            transaction,
+            &mut drive_operations,
+        )?;
+        let fees = calculate_fee(None, Some(drive_operations), &block_info.epoch)?;
+        Ok((value, fees))
+    }
+
+    /// Creates the operations to get Identity's nonce for a contract from the backing store
+    /// This gets operations based on apply flag (stateful vs stateless)
+    pub(crate) fn fetch_identity_contract_nonce_operations(
+        &self,
+        identity_id: [u8; 32],
+        contract_id: [u8; 32],
+        apply: bool,
+        transaction: TransactionArg,
+        drive_operations: &mut Vec<LowLevelDriveOperation>,
+    ) -> Result<Option<IdentityNonce>, Error> {
+        let direct_query_type = if apply {
+            DirectQueryType::StatefulDirectQuery
+        } else {
+            DirectQueryType::StatelessDirectQuery {
+                in_tree_using_sums: false,
+                query_target: QueryTargetValue(8),
+            }
+        };
+        let identity_contract_path =
+            identity_contract_info_path(identity_id.as_slice(), contract_id.as_slice());
+        let element = self.grove_get_raw_optional(
+            (&identity_contract_path).into(),
+            &IDENTITY_CONTRACT_NONCE_KEY,
+            direct_query_type,
+            transaction,
+            drive_operations,
+        )?;
+        Self::decode_identity_nonce_element(element)
+    }
+
+    fn decode_identity_nonce_element(
+        element: Option<Element>,
+    ) -> Result<Option<IdentityNonce>, Error> {
+        match element {
+            Some(Item(encoded_nonce, _)) => {
+                let nonce =
+                    IdentityNonce::from_be_bytes(encoded_nonce.try_into().map_err(|_| {
+                        Error::Drive(DriveError::CorruptedElementType(
+                            "identity nonce was not 8 bytes as expected",
+                        ))
+                    })?);
+
+                Ok(Some(nonce))
+            }
+
+            None => Ok(None),
+
+            Some(..) => Err(Error::Drive(DriveError::CorruptedElementType(
+                "identity nonce was present but was not identified as an item",
+            ))),
+        }
+    }
+}
@@ -0,0 +1,18 @@
+//! Identity nonces protect identity state transitions against replays.
+//!
+//! Every identity has a nonce stored next to its revision, and a nonce for every
+//! contract it interacted with stored in its contract info. Absent nonces are
+//! treated as 0, and each accepted state transition must set exactly the next one.
+
+#[cfg(feature = "full")]
+mod fetch;
+#[cfg(feature = "full")]
+mod prove;
+#[cfg(any(feature = "full", feature = "verify"))]
+mod queries;
+#[cfg(feature = "full")]
+mod update;
+
+/// The key of the nonce within the contract info of an identity
+#[cfg(any(feature = "full", feature = "verify"))]
+pub(crate) const IDENTITY_CONTRACT_NONCE_KEY: [u8; 1] = [0];
@@ -0,0 +1,91 @@
+use crate::drive::Drive;
+use crate::error::Error;
+
+use grovedb::TransactionArg;
+
+impl Drive {
+    /// Proves an Identity's nonce from the backing store
+    pub fn prove_identity_nonce(
+        &self,
+        identity_id: [u8; 32],
+        transaction: TransactionArg,
+    ) -> Result<Vec<u8>, Error> {
+        let nonce_query = Self::identity_nonce_query(identity_id);
+        self.grove_get_proved_path_query(&nonce_query, false, transaction, &mut vec![])
+    }
+
+    /// Proves an Identity's nonce for a contract from the backing store
+    pub fn prove_identity_contract_nonce(
+        &self,
+        identity_id: [u8; 32],
+        contract_id: [u8; 32],
+        transaction: TransactionArg,
+    ) -> Result<Vec<u8>, Error> {
+        let nonce_query = Self::identity_contract_nonce_query(identity_id, contract_id);
+        self.grove_get_proved_path_query(&nonce_query, false, transaction, &mut vec![])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::helpers::setup::setup_drive_with_initial_state_structure;
+    use dpp::block::block_info::BlockInfo;
+    use dpp::identity::Identity;
+
+    #[test]
+    fn should_prove_identity_nonce() {
+        let drive = setup_drive_with_initial_state_structure();
+        let identity = Identity::random_identity(3, Some(14));
+        let identity_id = identity.id.to_buffer();
+
+        drive
+            .add_new_identity(identity, &BlockInfo::default(), true, None)
+            .expect("expected to add an identity");
+
+        drive
+            .update_identity_nonce(identity_id, 5, &BlockInfo::default(), true, None)
+            .expect("expected to update the nonce");
+
+        let proof = drive
+            .prove_identity_nonce(identity_id, None)
+            .expect("should not error when proving the nonce");
+
+        let (_, proved_nonce) = Drive::verify_identity_nonce(proof.as_slice(), identity_id, false)
+            .expect("expect that this be verified");
+
+        assert_eq!(proved_nonce, Some(5));
+    }
+
+    #[test]
+    fn should_prove_identity_contract_nonce() {
+        let drive = setup_drive_with_initial_state_structure();
+        let identity = Identity::random_identity(3, Some(14));
+        let identity_id = identity.id.to_buffer();
+
+        drive
+            .add_new_identity(identity, &BlockInfo::default(), true, None)
+            .expect("expected to add an identity");
+
+        drive
+            .update_identity_contract_nonce(
+                identity_id,
+                [3; 32],
+                2,
+                &BlockInfo::default(),
+                true,
+                None,
+            )
+            .expect("expected to update the contract nonce");
+
+        let proof = drive
+            .prove_identity_contract_nonce(identity_id, [3; 32], None)
+            .expect("should not error when proving the nonce");
+
+        let (_, proved_nonce) =
+            Drive::verify_identity_contract_nonce(proof.as_slice(), identity_id, [3; 32], false)
+                .expect("expect that this be verified");
+
+        assert_eq!(proved_nonce, Some(2));
+    }
+}
@@ -0,0 +1,23 @@
+use crate::drive::identity::nonce::IDENTITY_CONTRACT_NONCE_KEY;
+use crate::drive::identity::IdentityRootStructure::IdentityTreeNonce;
+use crate::drive::identity::{identity_contract_info_path_vec, identity_path_vec};
+use crate::drive::Drive;
+use grovedb::PathQuery;
+
+impl Drive {
+    /// The query for the identity nonce
+    pub fn identity_nonce_query(identity_id: [u8; 32]) -> PathQuery {
+        let identity_path = identity_path_vec(identity_id.as_slice());
+        PathQuery::new_single_key(identity_path, vec![IdentityTreeNonce as u8])
+    }
+
+    /// The query for the nonce of an identity for a specific contract
+    pub fn identity_contract_nonce_query(
+        identity_id: [u8; 32],
+        contract_id: [u8; 32],
+    ) -> PathQuery {
+        let identity_contract_path =
+            identity_contract_info_path_vec(identity_id.as_slice(), contract_id.as_slice());
+        PathQuery::new_single_key(identity_contract_path, IDENTITY_CONTRACT_NONCE_KEY.to_vec())
+    }
+}
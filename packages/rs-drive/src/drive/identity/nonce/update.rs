@@ -0,0 +1,278 @@
+use crate::drive::flags::StorageFlags;
+use crate::drive::grove_operations::BatchInsertTreeApplyType;
+use crate::drive::identity::nonce::IDENTITY_CONTRACT_NONCE_KEY;
+use crate::drive::identity::IdentityRootStructure::{IdentityContractInfo, IdentityTreeNonce};
+use crate::drive::identity::{
+    identity_contract_info_path_vec, identity_contract_info_root_path_vec, identity_path_vec,
+};
+use crate::drive::object_size_info::PathKeyInfo;
+use crate::drive::Drive;
+use crate::error::Error;
+use crate::fee::calculate_fee;
+use crate::fee::op::LowLevelDriveOperation;
+use crate::fee::result::FeeResult;
+use dpp::block::block_info::BlockInfo;
+use dpp::block::epoch::Epoch;
+use dpp::prelude::IdentityNonce;
+use grovedb::batch::KeyInfoPath;
+use grovedb::{Element, EstimatedLayerInformation, TransactionArg};
+use std::collections::HashMap;
+
+impl Drive {
+    /// Sets the nonce of an identity
+    pub fn update_identity_nonce(
+        &self,
+        identity_id: [u8; 32],
+        nonce: IdentityNonce,
+        block_info: &BlockInfo,
+        apply: bool,
+        transaction: TransactionArg,
+    ) -> Result<FeeResult, Error> {
+        let mut estimated_costs_only_with_layer_info = if apply {
+            None::<HashMap<KeyInfoPath, EstimatedLayerInformation>>
+        } else {
+            Some(HashMap::new())
+        };
+
+        let batch_operations = vec![self.update_identity_nonce_operation(
+            identity_id,
+            nonce,
+            &mut estimated_costs_only_with_layer_info,
+        )];
+
+        let mut drive_operations: Vec<LowLevelDriveOperation> = vec![];
+
+        self.apply_batch_low_level_drive_operations(
+            estimated_costs_only_with_layer_info,
+            transaction,
+            batch_operations,
+            &mut drive_operations,
+        )?;
+
+        calculate_fee(None, Some(drive_operations), &block_info.epoch)
+    }
+
+    /// The operation setting the nonce of an identity
+    /// The nonce is inserted on first use, and replaced afterwards
+    pub(crate) fn update_identity_nonce_operation(
+        &self,
+        identity_id: [u8; 32],
+        nonce: IdentityNonce,
+        estimated_costs_only_with_layer_info: &mut Option<
+            HashMap<KeyInfoPath, EstimatedLayerInformation>,
+        >,
+    ) -> LowLevelDriveOperation {
+        if let Some(estimated_costs_only_with_layer_info) = estimated_costs_only_with_layer_info {
+            Self::add_estimation_costs_for_update_nonce(
+                identity_id,
+                estimated_costs_only_with_layer_info,
+            );
+        }
+        let identity_path = identity_path_vec(identity_id.as_slice());
+        LowLevelDriveOperation::insert_for_known_path_key_element(
+            identity_path,
+            vec![IdentityTreeNonce as u8],
+            Element::new_item(nonce.to_be_bytes().to_vec()),
+        )
+    }
+
+    /// Sets the nonce of an identity for a contract
+    pub fn update_identity_contract_nonce(
+        &self,
+        identity_id: [u8; 32],
+        contract_id: [u8; 32],
+        nonce: IdentityNonce,
+        block_info: &BlockInfo,
+        apply: bool,
+        transaction: TransactionArg,
+    ) -> Result<FeeResult, Error> {
+        let mut estimated_costs_only_with_layer_info = if apply {
+            None::<HashMap<KeyInfoPath, EstimatedLayerInformation>>
+        } else {
+            Some(HashMap::new())
+        };
+
+        let batch_operations = self.update_identity_contract_nonce_operations(
+            identity_id,
+            contract_id,
+            nonce,
+            &block_info.epoch,
+            &mut estimated_costs_only_with_layer_info,
+            transaction,
+        )?;
+
+        let mut drive_operations: Vec<LowLevelDriveOperation> = vec![];
+
+        self.apply_batch_low_level_drive_operations(
+            estimated_costs_only_with_layer_info,
+            transaction,
+            batch_operations,
+            &mut drive_operations,
+        )?;
+
+        calculate_fee(None, Some(drive_operations), &block_info.epoch)
+    }
+
+    /// The operations setting the nonce of an identity for a contract
+    /// The contract info trees of the identity are created if they do not exist yet
+    pub(crate) fn update_identity_contract_nonce_operations(
+        &self,
+        identity_id: [u8; 32],
+        contract_id: [u8; 32],
+        nonce: IdentityNonce,
+        epoch: &Epoch,
+        estimated_costs_only_with_layer_info: &mut Option<
+            HashMap<KeyInfoPath, EstimatedLayerInformation>,
+        >,
+        transaction: TransactionArg,
+    ) -> Result<Vec<LowLevelDriveOperation>, Error> {
+        let mut batch_operations: Vec<LowLevelDriveOperation> = vec![];
+        let storage_flags = StorageFlags::SingleEpoch(epoch.index);
+
+        if let Some(estimated_costs_only_with_layer_info) = estimated_costs_only_with_layer_info {
+            Self::add_estimation_costs_for_update_contract_nonce(
+                identity_id,
+                contract_id,
+                estimated_costs_only_with_layer_info,
+            );
+        }
+
+        let apply_type = if estimated_costs_only_with_layer_info.is_none() {
+            BatchInsertTreeApplyType::StatefulBatchInsertTree
+        } else {
+            BatchInsertTreeApplyType::StatelessBatchInsertTree {
+                in_tree_using_sums: false,
+                is_sum_tree: false,
+                flags_len: storage_flags.serialized_size(),
+            }
+        };
+
+        // we insert the contract root tree if it doesn't exist already
+        self.batch_insert_empty_tree_if_not_exists(
+            PathKeyInfo::<0>::PathKey((
+                identity_path_vec(identity_id.as_slice()),
+                vec![IdentityContractInfo as u8],
+            )),
+            Some(&storage_flags),
+            apply_type,
+            transaction,
+            &mut None,
+            &mut batch_operations,
+        )?;
+
+        self.batch_insert_empty_tree_if_not_exists(
+            PathKeyInfo::<0>::PathKey((
+                identity_contract_info_root_path_vec(identity_id.as_slice()),
+                contract_id.to_vec(),
+            )),
+            Some(&storage_flags),
+            apply_type,
+            transaction,
+            &mut None,
+            &mut batch_operations,
+        )?;
+
+        batch_operations.push(LowLevelDriveOperation::insert_for_known_path_key_element(
+            identity_contract_info_path_vec(identity_id.as_slice(), contract_id.as_slice()),
+            IDENTITY_CONTRACT_NONCE_KEY.to_vec(),
+            Element::new_item(nonce.to_be_bytes().to_vec()),
+        ));
+
+        Ok(batch_operations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::helpers::setup::setup_drive_with_initial_state_structure;
+    use dpp::block::block_info::BlockInfo;
+    use dpp::identity::Identity;
+
+    #[test]
+    fn should_return_none_for_an_identity_without_nonce() {
+        let drive = setup_drive_with_initial_state_structure();
+        let identity = Identity::random_identity(3, Some(14));
+        let identity_id = identity.id.to_buffer();
+
+        drive
+            .add_new_identity(identity, &BlockInfo::default(), true, None)
+            .expect("expected to add an identity");
+
+        let nonce = drive
+            .fetch_identity_nonce(identity_id, true, None)
+            .expect("expected to fetch the nonce");
+
+        assert_eq!(nonce, None);
+
+        let contract_nonce = drive
+            .fetch_identity_contract_nonce(identity_id, [1; 32], true, None)
+            .expect("expected to fetch the contract nonce");
+
+        assert_eq!(contract_nonce, None);
+    }
+
+    #[test]
+    fn should_update_identity_nonce() {
+        let drive = setup_drive_with_initial_state_structure();
+        let identity = Identity::random_identity(3, Some(14));
+        let identity_id = identity.id.to_buffer();
+        let block_info = BlockInfo::default();
+
+        drive
+            .add_new_identity(identity, &block_info, true, None)
+            .expect("expected to add an identity");
+
+        for nonce in 1..=2 {
+            drive
+                .update_identity_nonce(identity_id, nonce, &block_info, true, None)
+                .expect("expected to update the nonce");
+
+            let fetched_nonce = drive
+                .fetch_identity_nonce(identity_id, true, None)
+                .expect("expected to fetch the nonce");
+
+            assert_eq!(fetched_nonce, Some(nonce));
+        }
+    }
+
+    #[test]
+    fn should_update_identity_contract_nonces_independently() {
+        let drive = setup_drive_with_initial_state_structure();
+        let identity = Identity::random_identity(3, Some(14));
+        let identity_id = identity.id.to_buffer();
+        let block_info = BlockInfo::default();
+
+        drive
+            .add_new_identity(identity, &block_info, true, None)
+            .expect("expected to add an identity");
+
+        drive
+            .update_identity_contract_nonce(identity_id, [1; 32], 1, &block_info, true, None)
+            .expect("expected to update the contract nonce");
+
+        drive
+            .update_identity_contract_nonce(identity_id, [2; 32], 1, &block_info, true, None)
+            .expect("expected to update the contract nonce");
+
+        drive
+            .update_identity_contract_nonce(identity_id, [1; 32], 2, &block_info, true, None)
+            .expect("expected to update the contract nonce");
+
+        let first_nonce = drive
+            .fetch_identity_contract_nonce(identity_id, [1; 32], true, None)
+            .expect("expected to fetch the contract nonce");
+
+        let second_nonce = drive
+            .fetch_identity_contract_nonce(identity_id, [2; 32], true, None)
+            .expect("expected to fetch the contract nonce");
+
+        assert_eq!(first_nonce, Some(2));
+        assert_eq!(second_nonce, Some(1));
+
+        let identity_nonce = drive
+            .fetch_identity_nonce(identity_id, true, None)
+            .expect("expected to fetch the nonce");
+
+        assert_eq!(identity_nonce, None);
+    }
+}
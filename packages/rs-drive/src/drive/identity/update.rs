@@ -186,6 +186,15 @@ impl Drive {
         for (_, mut key) in keys {
             key.set_disabled_at(disable_at);
 
+            if estimated_costs_only_with_layer_info.is_none() {
+                // Signatures verified with the key must be verified again once it is disabled
+                self.cache
+                    .read()
+                    .unwrap()
+                    .signature_verifications
+                    .invalidate_public_key(key.id, key.data.as_slice());
+            }
+
             let key_id_bytes = key.id.encode_var_vec();
 
             self.replace_key_in_storage_operations(
@@ -200,6 +209,114 @@ impl Drive {
         Ok(drive_operations)
     }
 
+    /// Schedule the expiration of identity keys
+    pub fn set_identity_keys_valid_until(
+        &self,
+        identity_id: [u8; 32],
+        keys_ids: Vec<KeyID>,
+        valid_until: TimestampMillis,
+        block_info: &BlockInfo,
+        apply: bool,
+        transaction: TransactionArg,
+    ) -> Result<FeeResult, Error> {
+        let mut estimated_costs_only_with_layer_info = if apply {
+            None::<HashMap<KeyInfoPath, EstimatedLayerInformation>>
+        } else {
+            Some(HashMap::new())
+        };
+
+        let batch_operations = self.set_identity_keys_valid_until_operations(
+            identity_id,
+            keys_ids,
+            valid_until,
+            &mut estimated_costs_only_with_layer_info,
+            transaction,
+        )?;
+
+        let mut drive_operations: Vec<LowLevelDriveOperation> = vec![];
+
+        self.apply_batch_low_level_drive_operations(
+            estimated_costs_only_with_layer_info,
+            transaction,
+            batch_operations,
+            &mut drive_operations,
+        )?;
+
+        let fees = calculate_fee(None, Some(drive_operations), &block_info.epoch)?;
+
+        Ok(fees)
+    }
+
+    pub(crate) fn set_identity_keys_valid_until_operations(
+        &self,
+        identity_id: [u8; 32],
+        key_ids: Vec<KeyID>,
+        valid_until: TimestampMillis,
+        estimated_costs_only_with_layer_info: &mut Option<
+            HashMap<KeyInfoPath, EstimatedLayerInformation>,
+        >,
+        transaction: TransactionArg,
+    ) -> Result<Vec<LowLevelDriveOperation>, Error> {
+        let mut drive_operations = vec![];
+
+        let key_ids_len = key_ids.len();
+
+        let keys: KeyIDIdentityPublicKeyPairVec = if let Some(
+            estimated_costs_only_with_layer_info,
+        ) = estimated_costs_only_with_layer_info
+        {
+            Self::add_estimation_costs_for_keys_for_identity_id(
+                identity_id,
+                estimated_costs_only_with_layer_info,
+            );
+            key_ids
+                .into_iter()
+                .map(|key_id| (key_id, IdentityPublicKey::max_possible_size_key(key_id)))
+                .collect()
+        } else {
+            let key_request = IdentityKeysRequest {
+                identity_id,
+                request_type: KeyRequestType::SpecificKeys(key_ids),
+                limit: Some(key_ids_len as u16),
+                offset: None,
+            };
+
+            self.fetch_identity_keys_operations(key_request, transaction, &mut drive_operations)?
+        };
+
+        if keys.len() != key_ids_len {
+            // TODO Choose / add an appropriate error
+            return Err(Error::Drive(DriveError::UpdatingDocumentThatDoesNotExist(
+                "key to expire with specified ID is not found",
+            )));
+        }
+
+        const VALID_UNTIL_TIME_BYTE_COST: i32 = 9;
+
+        for (_, mut key) in keys {
+            // Rescheduling an already set expiration doesn't change the size of the key
+            let change_in_bytes = if key.valid_until.is_some() {
+                0
+            } else {
+                VALID_UNTIL_TIME_BYTE_COST
+            };
+
+            key.set_valid_until(valid_until);
+
+            let key_id_bytes = key.id.encode_var_vec();
+
+            self.replace_key_in_storage_operations(
+                identity_id.as_slice(),
+                &key,
+                &key_id_bytes,
+                change_in_bytes,
+                &mut drive_operations,
+            )?;
+        }
+
+        Ok(drive_operations)
+    }
+
     pub(crate) fn re_enable_identity_keys_operations(
         &self,
         identity_id: [u8; 32],
@@ -682,6 +799,92 @@ mod tests {
         }
     }
 
+    mod set_identity_keys_valid_until {
+        use super::*;
+        use chrono::Utc;
+        use dpp::block::epoch::Epoch;
+
+        #[test]
+        fn should_set_valid_until_on_a_few_keys() {
+            let drive = setup_drive_with_initial_state_structure();
+
+            let identity = Identity::random_identity(5, Some(12345));
+
+            let block_info = BlockInfo::default_with_epoch(Epoch::new(0).unwrap());
+
+            drive
+                .add_new_identity(identity.clone(), &block_info, true, None)
+                .expect("expected to insert identity");
+
+            let valid_until = Utc::now().timestamp_millis() as TimestampMillis + 3_600_000;
+
+            drive
+                .set_identity_keys_valid_until(
+                    identity.id.to_buffer(),
+                    vec![3, 4],
+                    valid_until,
+                    &block_info,
+                    true,
+                    None,
+                )
+                .expect("should set valid until on a few keys");
+
+            let identity_keys = drive
+                .fetch_all_identity_keys(identity.id.to_buffer(), None)
+                .expect("expected to get keys");
+
+            assert_eq!(identity_keys.len(), 5);
+
+            for (key_id, key) in identity_keys {
+                if key_id >= 3 {
+                    assert_eq!(key.valid_until, Some(valid_until));
+                    assert!(key.is_expired_at(valid_until));
+                } else {
+                    assert_eq!(key.valid_until, None);
+                }
+            }
+        }
+
+        #[test]
+        fn estimated_costs_should_have_same_storage_cost() {
+            let drive = setup_drive_with_initial_state_structure();
+
+            let identity = Identity::random_identity(5, Some(12345));
+
+            drive
+                .add_new_identity(identity.clone(), &BlockInfo::default(), true, None)
+                .expect("expected to add an identity");
+
+            let block_info = BlockInfo::default_with_epoch(Epoch::new(0).unwrap());
+
+            let valid_until = Utc::now().timestamp_millis() as TimestampMillis;
+
+            let expected_fee_result = drive
+                .set_identity_keys_valid_until(
+                    identity.id.to_buffer(),
+                    vec![0, 1],
+                    valid_until,
+                    &block_info,
+                    false,
+                    None,
+                )
+                .expect("should estimate the expiration of a few keys");
+
+            let fee_result = drive
+                .set_identity_keys_valid_until(
+                    identity.id.to_buffer(),
+                    vec![0, 1],
+                    valid_until,
+                    &block_info,
+                    true,
+                    None,
+                )
+                .expect("should get the cost of the expiration of a few keys");
+
+            assert_eq!(expected_fee_result.storage_fee, fee_result.storage_fee,);
+        }
+    }
+
     mod update_identity_revision {
         use super::*;
         use dpp::block::epoch::Epoch;
@@ -63,6 +63,9 @@ use crate::fee::op::LowLevelDriveOperation;
 #[cfg(feature = "full")]
 use crate::fee::op::LowLevelDriveOperation::GroveOperation;
 
+/// Export and import of the state to a portable archive
+#[cfg(feature = "full")]
+pub mod archive;
 #[cfg(any(feature = "full", feature = "verify"))]
 pub mod balances;
 /// Batch module
@@ -104,6 +107,9 @@ pub mod object_size_info;
 mod protocol_upgrade;
 #[cfg(feature = "full")]
 pub mod query;
+/// State transition results module
+#[cfg(any(feature = "full", feature = "verify"))]
+pub mod state_transition_result;
 #[cfg(feature = "full")]
 mod system;
 #[cfg(test)]
@@ -124,6 +130,8 @@ use crate::drive::cache::DataContractCache;
 #[cfg(feature = "full")]
 use crate::drive::cache::DriveCache;
 #[cfg(feature = "full")]
+use crate::drive::cache::SignatureVerificationCache;
+#[cfg(feature = "full")]
 use crate::drive::object_size_info::OwnedDocumentInfo;
 #[cfg(feature = "full")]
 use crate::drive::system_contracts_cache::SystemContracts;
@@ -304,18 +312,25 @@ impl Drive {
                 let genesis_time_ms = config.default_genesis_time;
                 let data_contracts_global_cache_size = config.data_contracts_global_cache_size;
                 let data_contracts_block_cache_size = config.data_contracts_block_cache_size;
+                let data_contracts_cache_eviction_policy =
+                    config.data_contracts_cache_eviction_policy;
+                let signature_verification_cache_size = config.signature_verification_cache_size;
 
                 Ok(Drive {
                     grove,
                     config,
                     system_contracts: SystemContracts::load_system_contracts()?,
                     cache: RwLock::new(DriveCache {
-                        cached_contracts: DataContractCache::new(
+                        cached_contracts: DataContractCache::new_with_eviction_policy(
                             data_contracts_global_cache_size,
                             data_contracts_block_cache_size,
+                            data_contracts_cache_eviction_policy,
                         ),
                         genesis_time_ms,
                         protocol_versions_counter: None,
+                        signature_verifications: SignatureVerificationCache::new(
+                            signature_verification_cache_size,
+                        ),
                     }),
                 })
             }
@@ -328,13 +343,18 @@ impl Drive {
         let genesis_time_ms = self.config.default_genesis_time;
         let data_contracts_global_cache_size = self.config.data_contracts_global_cache_size;
         let data_contracts_block_cache_size = self.config.data_contracts_block_cache_size;
+        let data_contracts_cache_eviction_policy = self.config.data_contracts_cache_eviction_policy;
+        let signature_verification_cache_size = self.config.signature_verification_cache_size;
         let mut cache = self.cache.write().unwrap();
-        cache.cached_contracts = DataContractCache::new(
+        cache.cached_contracts = DataContractCache::new_with_eviction_policy(
             data_contracts_global_cache_size,
             data_contracts_block_cache_size,
+            data_contracts_cache_eviction_policy,
         );
         cache.genesis_time_ms = genesis_time_ms;
         cache.protocol_versions_counter = None;
+        cache.signature_verifications =
+            SignatureVerificationCache::new(signature_verification_cache_size);
     }
 
     /// Commits a transaction.
@@ -595,6 +595,7 @@ impl<'a> DocumentInfo<'a> {
                     })?;
                 let estimated_size = document_field_type
                     .document_type
+                    .index_key_field_type()
                     .middle_byte_size_ceil()
                     .ok_or({
                         Error::Drive(DriveError::CorruptedCodeExecution(
@@ -654,6 +655,7 @@ impl<'a> DocumentInfo<'a> {
 
                         let estimated_middle_size = document_field_type
                             .document_type
+                            .index_key_field_type()
                             .middle_byte_size_ceil()
                             .ok_or({
                                 Error::Drive(DriveError::CorruptedCodeExecution(
@@ -678,6 +680,36 @@ impl<'a> DocumentInfo<'a> {
         }
     }
 
+    /// Gets the raw keys the document is indexed under for the given document type field.
+    /// Array fields are indexed under each of their elements, an empty array being
+    /// indexed like a missing value
+    pub fn get_raw_index_keys_for_document_type(
+        &self,
+        key_path: &str,
+        document_type: &DocumentType,
+        owner_id: Option<[u8; 32]>,
+        size_info_with_base_event: Option<(&IndexLevel, [u8; 32])>,
+    ) -> Result<Vec<DriveKeyInfo>, Error> {
+        if let Some(document) = self.get_borrowed_document() {
+            if let Some(elements) =
+                document.get_raw_array_elements_for_document_type(key_path, document_type)?
+            {
+                if elements.is_empty() {
+                    return Ok(vec![DriveKeyInfo::default()]);
+                }
+                return Ok(elements.into_iter().map(Key).collect());
+            }
+        }
+        Ok(vec![self
+            .get_raw_for_document_type(
+                key_path,
+                document_type,
+                owner_id,
+                size_info_with_base_event,
+            )?
+            .unwrap_or_default()])
+    }
+
     /// Gets the borrowed document
     pub fn get_borrowed_document_and_storage_flags(
         &self,
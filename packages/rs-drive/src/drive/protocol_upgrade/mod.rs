@@ -1,7 +1,12 @@
 use crate::drive::batch::GroveDbOpBatch;
+use crate::drive::defaults::{
+    EXPIRING_DOCUMENTS_PROTOCOL_VERSION, STATE_TRANSITION_RESULTS_PROTOCOL_VERSION,
+};
+use crate::drive::document::expiration::add_initial_expiring_documents_structure_operations;
 use crate::drive::grove_operations::BatchDeleteApplyType::StatefulBatchDelete;
 use crate::drive::grove_operations::BatchInsertApplyType;
 use crate::drive::object_size_info::PathKeyElementInfo;
+use crate::drive::state_transition_result::add_initial_state_transition_results_structure_operations;
 use std::collections::BTreeMap;
 
 use crate::drive::{Drive, RootTree};
@@ -79,6 +84,36 @@ impl Drive {
         Ok(())
     }
 
+    /// Creates the state structure introduced by the protocol versions after
+    /// `from_protocol_version` up to `to_protocol_version`, this is done at genesis and when the
+    /// protocol version in consensus changes
+    pub fn migrate_state_structure(
+        &self,
+        from_protocol_version: ProtocolVersion,
+        to_protocol_version: ProtocolVersion,
+        transaction: TransactionArg,
+    ) -> Result<(), Error> {
+        let introduced_by_upgrade = |protocol_version: ProtocolVersion| {
+            from_protocol_version < protocol_version && protocol_version <= to_protocol_version
+        };
+
+        let mut batch = GroveDbOpBatch::new();
+
+        if introduced_by_upgrade(STATE_TRANSITION_RESULTS_PROTOCOL_VERSION) {
+            add_initial_state_transition_results_structure_operations(&mut batch);
+        }
+
+        if introduced_by_upgrade(EXPIRING_DOCUMENTS_PROTOCOL_VERSION) {
+            add_initial_expiring_documents_structure_operations(&mut batch);
+        }
+
+        if batch.is_empty() {
+            return Ok(());
+        }
+
+        self.grove_apply_batch(batch, false, transaction)
+    }
+
     /// Clear all version information from the backing store, this is done on epoch change in
     /// execution logic
     pub fn change_to_new_version_and_clear_version_information(
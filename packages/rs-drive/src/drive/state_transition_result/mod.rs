@@ -0,0 +1,196 @@
+//! State Transition Results.
+//!
+//! The result of every state transition executed in a block is recorded in the misc tree,
+//! keyed by the hash of the state transition, so that clients can prove that their transition
+//! was applied and what it cost. Results are also indexed by block height, the ones older than
+//! the retention window are removed at every block.
+//!
+//! Results are recorded from [`STATE_TRANSITION_RESULTS_PROTOCOL_VERSION`], the trees are
+//! created when the chain upgrades to it.
+//!
+//! [`STATE_TRANSITION_RESULTS_PROTOCOL_VERSION`]: crate::drive::defaults::STATE_TRANSITION_RESULTS_PROTOCOL_VERSION
+
+#[cfg(feature = "full")]
+mod store;
+
+use grovedb::{PathQuery, Query, SizedQuery};
+
+#[cfg(feature = "full")]
+use crate::drive::batch::GroveDbOpBatch;
+use crate::drive::{Drive, RootTree};
+use crate::error::drive::DriveError;
+use crate::error::Error;
+
+/// State transition results tree key in the misc tree
+pub const STATE_TRANSITION_RESULTS_TREE_KEY: &[u8; 1] = b"r";
+
+/// Key of the results by state transition hash
+pub const RESULTS_BY_HASH_KEY: &[u8; 1] = &[0];
+
+/// Key of the results by block height
+pub const RESULTS_BY_HEIGHT_KEY: &[u8; 1] = &[1];
+
+/// For how many blocks the results are kept after the block that executed the state transition
+pub const STATE_TRANSITION_RESULTS_RETENTION_BLOCKS: u64 = 10_000;
+
+/// The size of a serialized result, the height, the code and the fees
+const STATE_TRANSITION_RESULT_SIZE: usize = 8 + 4 + 8 + 8;
+
+#[cfg(feature = "full")]
+/// Add operations for creating the state transition results structure
+pub fn add_initial_state_transition_results_structure_operations(batch: &mut GroveDbOpBatch) {
+    batch.add_insert_empty_tree(
+        vec![vec![RootTree::Misc as u8]],
+        STATE_TRANSITION_RESULTS_TREE_KEY.to_vec(),
+    );
+
+    batch.add_insert_empty_tree(
+        vec![
+            vec![RootTree::Misc as u8],
+            STATE_TRANSITION_RESULTS_TREE_KEY.to_vec(),
+        ],
+        RESULTS_BY_HASH_KEY.to_vec(),
+    );
+
+    batch.add_insert_empty_tree(
+        vec![
+            vec![RootTree::Misc as u8],
+            STATE_TRANSITION_RESULTS_TREE_KEY.to_vec(),
+        ],
+        RESULTS_BY_HEIGHT_KEY.to_vec(),
+    );
+}
+
+#[cfg(feature = "full")]
+/// Returns the path to the results by state transition hash.
+pub(crate) fn state_transition_results_by_hash_path() -> [&'static [u8]; 3] {
+    [
+        Into::<&[u8; 1]>::into(RootTree::Misc),
+        STATE_TRANSITION_RESULTS_TREE_KEY,
+        RESULTS_BY_HASH_KEY,
+    ]
+}
+
+/// Returns the path to the results by state transition hash as a vec.
+pub(crate) fn state_transition_results_by_hash_path_vec() -> Vec<Vec<u8>> {
+    vec![
+        vec![RootTree::Misc as u8],
+        STATE_TRANSITION_RESULTS_TREE_KEY.to_vec(),
+        RESULTS_BY_HASH_KEY.to_vec(),
+    ]
+}
+
+#[cfg(feature = "full")]
+/// Returns the path to the results by block height as a vec.
+pub(crate) fn state_transition_results_by_height_path_vec() -> Vec<Vec<u8>> {
+    vec![
+        vec![RootTree::Misc as u8],
+        STATE_TRANSITION_RESULTS_TREE_KEY.to_vec(),
+        RESULTS_BY_HEIGHT_KEY.to_vec(),
+    ]
+}
+
+/// The recorded outcome of a state transition
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct StateTransitionResult {
+    /// The height of the block which executed the state transition
+    pub height: u64,
+    /// The consensus error code, 0 on success
+    pub code: u32,
+    /// The storage fee paid
+    pub storage_fee: u64,
+    /// The processing fee paid
+    pub processing_fee: u64,
+}
+
+impl StateTransitionResult {
+    /// Was the state transition applied
+    pub fn is_success(&self) -> bool {
+        self.code == 0
+    }
+
+    /// Serializes the result with a fixed width encoding
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(STATE_TRANSITION_RESULT_SIZE);
+        bytes.extend_from_slice(&self.height.to_be_bytes());
+        bytes.extend_from_slice(&self.code.to_be_bytes());
+        bytes.extend_from_slice(&self.storage_fee.to_be_bytes());
+        bytes.extend_from_slice(&self.processing_fee.to_be_bytes());
+        bytes
+    }
+
+    /// Deserializes a result
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        if bytes.len() != STATE_TRANSITION_RESULT_SIZE {
+            return Err(Error::Drive(DriveError::CorruptedSerialization(
+                "state transition result has the wrong size",
+            )));
+        }
+
+        let (height, rest) = bytes.split_at(8);
+        let (code, fees) = rest.split_at(4);
+        let (storage_fee, processing_fee) = fees.split_at(8);
+
+        // the sizes were checked above
+        Ok(StateTransitionResult {
+            height: u64::from_be_bytes(height.try_into().unwrap()),
+            code: u32::from_be_bytes(code.try_into().unwrap()),
+            storage_fee: u64::from_be_bytes(storage_fee.try_into().unwrap()),
+            processing_fee: u64::from_be_bytes(processing_fee.try_into().unwrap()),
+        })
+    }
+}
+
+#[cfg(feature = "full")]
+/// Results are indexed by height first so that the oldest ones come first
+fn state_transition_result_height_key(height: u64, state_transition_hash: &[u8; 32]) -> Vec<u8> {
+    let mut key = Vec::with_capacity(8 + 32);
+    key.extend_from_slice(&height.to_be_bytes());
+    key.extend_from_slice(state_transition_hash);
+    key
+}
+
+impl Drive {
+    /// The path query of the result of a state transition
+    pub fn state_transition_result_query(state_transition_hash: &[u8; 32]) -> PathQuery {
+        let mut query = Query::new();
+        query.insert_key(state_transition_hash.to_vec());
+        PathQuery {
+            path: state_transition_results_by_hash_path_vec(),
+            query: SizedQuery {
+                query,
+                limit: None,
+                offset: None,
+            },
+        }
+    }
+}
+
+#[cfg(feature = "full")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_serialize_and_deserialize_state_transition_result() {
+        let result = StateTransitionResult {
+            height: 42,
+            code: 4001,
+            storage_fee: 1_000_000,
+            processing_fee: 25_000,
+        };
+
+        let decoded = StateTransitionResult::from_bytes(&result.to_bytes())
+            .expect("expected to decode the result");
+
+        assert_eq!(decoded, result);
+    }
+
+    #[test]
+    fn should_order_result_height_keys_by_height() {
+        let earlier = state_transition_result_height_key(9, &[255; 32]);
+        let later = state_transition_result_height_key(10, &[0; 32]);
+
+        assert!(earlier < later);
+    }
+}
@@ -0,0 +1,283 @@
+use std::collections::BTreeMap;
+
+use grovedb::query_result_type::QueryResultType::QueryKeyElementPairResultType;
+use grovedb::{Element, PathQuery, Query, SizedQuery, TransactionArg};
+
+use crate::drive::batch::GroveDbOpBatch;
+use crate::drive::grove_operations::DirectQueryType::StatefulDirectQuery;
+use crate::drive::state_transition_result::{
+    state_transition_result_height_key, state_transition_results_by_hash_path,
+    state_transition_results_by_hash_path_vec, state_transition_results_by_height_path_vec,
+    StateTransitionResult, STATE_TRANSITION_RESULTS_RETENTION_BLOCKS,
+};
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+
+/// The maximum amount of results removed in a single block, the results that are not removed
+/// are picked up by the following blocks
+pub const MAX_STATE_TRANSITION_RESULTS_REMOVED_PER_BLOCK: u16 = 1024;
+
+impl Drive {
+    /// Records the results of the state transitions executed in a block, by state transition hash
+    pub fn store_state_transition_results(
+        &self,
+        results: &BTreeMap<[u8; 32], StateTransitionResult>,
+        transaction: TransactionArg,
+    ) -> Result<(), Error> {
+        if results.is_empty() {
+            return Ok(());
+        }
+
+        let mut batch = GroveDbOpBatch::new();
+
+        for (state_transition_hash, result) in results {
+            batch.add_insert(
+                state_transition_results_by_hash_path_vec(),
+                state_transition_hash.to_vec(),
+                Element::Item(result.to_bytes(), None),
+            );
+            batch.add_insert(
+                state_transition_results_by_height_path_vec(),
+                state_transition_result_height_key(result.height, state_transition_hash),
+                Element::Item(vec![], None),
+            );
+        }
+
+        self.grove_apply_batch(batch, false, transaction)
+    }
+
+    /// Removes the results of the state transitions executed more than the retention window
+    /// before the given block height, oldest first
+    pub fn remove_expired_state_transition_results(
+        &self,
+        block_height: u64,
+        transaction: TransactionArg,
+    ) -> Result<(), Error> {
+        let expired_height =
+            match block_height.checked_sub(STATE_TRANSITION_RESULTS_RETENTION_BLOCKS) {
+                Some(expired_height) => expired_height,
+                None => return Ok(()),
+            };
+
+        let mut query = Query::new();
+        query.insert_range_to(..(expired_height + 1).to_be_bytes().to_vec());
+
+        let path_query = PathQuery::new(
+            state_transition_results_by_height_path_vec(),
+            SizedQuery::new(
+                query,
+                Some(MAX_STATE_TRANSITION_RESULTS_REMOVED_PER_BLOCK),
+                None,
+            ),
+        );
+
+        let (results, _) = self.grove_get_raw_path_query(
+            &path_query,
+            transaction,
+            QueryKeyElementPairResultType,
+            &mut vec![],
+        )?;
+
+        let height_keys = results.to_key_elements();
+
+        if height_keys.is_empty() {
+            return Ok(());
+        }
+
+        let mut batch = GroveDbOpBatch::new();
+
+        for (height_key, _) in height_keys {
+            if height_key.len() != 8 + 32 {
+                return Err(Error::Drive(DriveError::CorruptedSerialization(
+                    "state transition result height key has the wrong size",
+                )));
+            }
+            let state_transition_hash = height_key[8..].to_vec();
+
+            batch.add_delete(
+                state_transition_results_by_hash_path_vec(),
+                state_transition_hash,
+            );
+            batch.add_delete(state_transition_results_by_height_path_vec(), height_key);
+        }
+
+        self.grove_apply_batch(batch, false, transaction)
+    }
+
+    /// Fetches the result of a state transition, `None` if it was not executed or if its result
+    /// is past the retention window
+    pub fn fetch_state_transition_result(
+        &self,
+        state_transition_hash: [u8; 32],
+        transaction: TransactionArg,
+    ) -> Result<Option<StateTransitionResult>, Error> {
+        let element = self.grove_get_raw_optional(
+            state_transition_results_by_hash_path().as_ref().into(),
+            state_transition_hash.as_slice(),
+            StatefulDirectQuery,
+            transaction,
+            &mut vec![],
+        )?;
+
+        element
+            .map(|element| match element {
+                Element::Item(bytes, _) => StateTransitionResult::from_bytes(&bytes),
+                _ => Err(Error::Drive(DriveError::CorruptedElementType(
+                    "state transition result must be an item",
+                ))),
+            })
+            .transpose()
+    }
+
+    /// Proves the result of a state transition, or its absence
+    pub fn prove_state_transition_result(
+        &self,
+        state_transition_hash: [u8; 32],
+        transaction: TransactionArg,
+    ) -> Result<Vec<u8>, Error> {
+        let path_query = Self::state_transition_result_query(&state_transition_hash);
+        self.grove_get_proved_path_query(&path_query, false, transaction, &mut vec![])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::drive::defaults::STATE_TRANSITION_RESULTS_PROTOCOL_VERSION;
+    use crate::tests::helpers::setup::setup_drive_with_initial_state_structure;
+
+    fn setup_drive_recording_state_transition_results() -> Drive {
+        let drive = setup_drive_with_initial_state_structure();
+
+        drive
+            .migrate_state_structure(1, STATE_TRANSITION_RESULTS_PROTOCOL_VERSION, None)
+            .expect("expected to create the state transition results structure");
+
+        drive
+    }
+
+    fn result_at_height(height: u64) -> StateTransitionResult {
+        StateTransitionResult {
+            height,
+            code: 0,
+            storage_fee: 5000,
+            processing_fee: 700,
+        }
+    }
+
+    #[test]
+    fn should_store_and_prove_state_transition_results() {
+        let drive = setup_drive_recording_state_transition_results();
+
+        let results = BTreeMap::from([([1; 32], result_at_height(1))]);
+
+        drive
+            .store_state_transition_results(&results, None)
+            .expect("expected to store results");
+
+        assert_eq!(
+            drive
+                .fetch_state_transition_result([1; 32], None)
+                .expect("expected to fetch the result"),
+            Some(result_at_height(1))
+        );
+
+        let proof = drive
+            .prove_state_transition_result([1; 32], None)
+            .expect("expected to prove the result");
+        let (root_hash, proved_result) =
+            Drive::verify_state_transition_result(&proof, [1; 32], false)
+                .expect("expected to verify the proof");
+
+        assert_eq!(
+            root_hash,
+            drive
+                .grove
+                .root_hash(None)
+                .unwrap()
+                .expect("expected a root hash")
+        );
+        assert_eq!(proved_result, Some(result_at_height(1)));
+
+        let absence_proof = drive
+            .prove_state_transition_result([2; 32], None)
+            .expect("expected to prove the absence of a result");
+        let (_, proved_result) =
+            Drive::verify_state_transition_result(&absence_proof, [2; 32], false)
+                .expect("expected to verify the proof");
+
+        assert_eq!(proved_result, None);
+    }
+
+    #[test]
+    fn should_remove_results_past_the_retention_window() {
+        let drive = setup_drive_recording_state_transition_results();
+
+        let results = BTreeMap::from([
+            ([1; 32], result_at_height(1)),
+            ([2; 32], result_at_height(2)),
+        ]);
+
+        drive
+            .store_state_transition_results(&results, None)
+            .expect("expected to store results");
+
+        drive
+            .remove_expired_state_transition_results(
+                STATE_TRANSITION_RESULTS_RETENTION_BLOCKS + 1,
+                None,
+            )
+            .expect("expected to remove expired results");
+
+        assert_eq!(
+            drive
+                .fetch_state_transition_result([1; 32], None)
+                .expect("expected to fetch the result"),
+            None
+        );
+        assert_eq!(
+            drive
+                .fetch_state_transition_result([2; 32], None)
+                .expect("expected to fetch the result"),
+            Some(result_at_height(2))
+        );
+    }
+
+    #[test]
+    fn should_only_create_the_results_structure_when_upgrading_to_its_protocol_version() {
+        let drive = setup_drive_with_initial_state_structure();
+
+        let root_hash = || {
+            drive
+                .grove
+                .root_hash(None)
+                .unwrap()
+                .expect("expected a root hash")
+        };
+
+        let genesis_root_hash = root_hash();
+
+        drive
+            .migrate_state_structure(1, STATE_TRANSITION_RESULTS_PROTOCOL_VERSION - 1, None)
+            .expect("expected to migrate the state structure");
+
+        assert_eq!(root_hash(), genesis_root_hash);
+
+        drive
+            .migrate_state_structure(
+                STATE_TRANSITION_RESULTS_PROTOCOL_VERSION - 1,
+                STATE_TRANSITION_RESULTS_PROTOCOL_VERSION,
+                None,
+            )
+            .expect("expected to migrate the state structure");
+
+        assert_ne!(root_hash(), genesis_root_hash);
+        assert_eq!(
+            drive
+                .fetch_state_transition_result([1; 32], None)
+                .expect("expected to fetch the result"),
+            None
+        );
+    }
+}
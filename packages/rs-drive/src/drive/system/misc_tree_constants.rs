@@ -3,3 +3,9 @@ pub const PROTOCOL_VERSION_STORAGE_KEY: &[u8; 1] = b"v";
 
 /// Next Epoch Protocol Version Storage key
 pub const NEXT_PROTOCOL_VERSION_STORAGE_KEY: &[u8; 1] = b"n";
+
+/// Expiring documents tree key
+pub const EXPIRING_DOCUMENTS_TREE_KEY: &[u8; 1] = b"e";
+
+/// Document expiration times tree key
+pub const DOCUMENT_EXPIRATION_TIMES_TREE_KEY: &[u8; 1] = b"x";
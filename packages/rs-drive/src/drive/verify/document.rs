@@ -47,7 +47,7 @@ impl<'a> DriveQuery<'a> {
             .filter_map(|(_path, _key, element)| element)
             .map(|element| element.into_item_bytes().map_err(Error::GroveDB))
             .collect::<Result<Vec<Vec<u8>>, Error>>()?;
-        Ok((root_hash, documents))
+        Ok((root_hash, self.deduplicate_serialized_results(documents)))
     }
 
     /// Verifies a proof for a collection of documents.
@@ -73,12 +73,9 @@ impl<'a> DriveQuery<'a> {
     pub fn verify_proof(&self, proof: &[u8]) -> Result<(RootHash, Vec<Document>), Error> {
         self.verify_proof_keep_serialized(proof)
             .map(|(root_hash, documents)| {
-                let documents = documents
-                    .into_iter()
-                    .map(|serialized| {
-                        Document::from_bytes(serialized.as_slice(), self.document_type)
-                            .map_err(Error::Protocol)
-                    })
+                let documents = self
+                    .document_views(&documents)
+                    .map(|view| view?.to_document().map_err(Error::Protocol))
                     .collect::<Result<Vec<Document>, Error>>()?;
                 Ok((root_hash, documents))
             })?
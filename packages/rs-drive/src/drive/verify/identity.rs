@@ -1,7 +1,9 @@
 use crate::drive::balances::balance_path;
 use crate::drive::defaults::PROTOCOL_VERSION;
 use crate::drive::identity::IdentityRootStructure::IdentityTreeRevision;
-use crate::drive::identity::{identity_key_tree_path, identity_path};
+use crate::drive::identity::{
+    identity_contract_info_path_vec, identity_key_tree_path, identity_path, identity_path_vec,
+};
 use crate::drive::{unique_key_hashes_tree_path_vec, Drive};
 
 use crate::error::proof::ProofError;
@@ -12,9 +14,9 @@ use crate::drive::identity::key::fetch::IdentityKeysRequest;
 use crate::drive::verify::RootHash;
 use dpp::identifier::Identifier;
 use dpp::identity::{IdentityPublicKey, KeyID, PartialIdentity};
-pub use dpp::prelude::{Identity, Revision};
+pub use dpp::prelude::{Identity, IdentityNonce, Revision};
 use dpp::serialization_traits::PlatformDeserializable;
-use grovedb::GroveDb;
+use grovedb::{GroveDb, PathQuery};
 use std::collections::BTreeMap;
 
 impl Drive {
@@ -593,4 +595,108 @@ impl Drive {
             )))
         }
     }
+
+    /// Verifies the nonce of an identity by its identity ID.
+    ///
+    /// # Parameters
+    ///
+    /// - `proof`: A byte slice representing the proof of the nonce.
+    /// - `identity_id`: A 32-byte array representing the identity ID.
+    /// - `verify_subset_of_proof`: A boolean indicating whether we are verifying a subset of a larger proof.
+    ///
+    /// # Returns
+    ///
+    /// If the verification is successful, it returns a `Result` with a tuple of `RootHash` and
+    /// an `Option<IdentityNonce>`. The nonce is `None` if the identity never set one.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if the proof is not valid, or if it proves something other than
+    /// a single nonce for the given identity.
+    ///
+    pub fn verify_identity_nonce(
+        proof: &[u8],
+        identity_id: [u8; 32],
+        verify_subset_of_proof: bool,
+    ) -> Result<(RootHash, Option<IdentityNonce>), Error> {
+        let path_query = Self::identity_nonce_query(identity_id);
+        Self::verify_single_identity_nonce(
+            proof,
+            &path_query,
+            identity_path_vec(identity_id.as_slice()),
+            verify_subset_of_proof,
+        )
+    }
+
+    /// Verifies the nonce of an identity for a contract.
+    ///
+    /// # Parameters
+    ///
+    /// - `proof`: A byte slice representing the proof of the nonce.
+    /// - `identity_id`: A 32-byte array representing the identity ID.
+    /// - `contract_id`: A 32-byte array representing the contract ID.
+    /// - `verify_subset_of_proof`: A boolean indicating whether we are verifying a subset of a larger proof.
+    ///
+    /// # Returns
+    ///
+    /// If the verification is successful, it returns a `Result` with a tuple of `RootHash` and
+    /// an `Option<IdentityNonce>`. The nonce is `None` if the identity never set one for the contract.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if the proof is not valid, or if it proves something other than
+    /// a single nonce for the given identity and contract.
+    ///
+    pub fn verify_identity_contract_nonce(
+        proof: &[u8],
+        identity_id: [u8; 32],
+        contract_id: [u8; 32],
+        verify_subset_of_proof: bool,
+    ) -> Result<(RootHash, Option<IdentityNonce>), Error> {
+        let path_query = Self::identity_contract_nonce_query(identity_id, contract_id);
+        Self::verify_single_identity_nonce(
+            proof,
+            &path_query,
+            identity_contract_info_path_vec(identity_id.as_slice(), contract_id.as_slice()),
+            verify_subset_of_proof,
+        )
+    }
+
+    fn verify_single_identity_nonce(
+        proof: &[u8],
+        path_query: &PathQuery,
+        expected_path: Vec<Vec<u8>>,
+        verify_subset_of_proof: bool,
+    ) -> Result<(RootHash, Option<IdentityNonce>), Error> {
+        let (root_hash, mut proved_key_values) = if verify_subset_of_proof {
+            GroveDb::verify_subset_query(proof, path_query)?
+        } else {
+            GroveDb::verify_query(proof, path_query)?
+        };
+        if proved_key_values.len() == 1 {
+            let (path, _, maybe_element) = proved_key_values.remove(0);
+            if path != expected_path {
+                return Err(Error::Proof(ProofError::CorruptedProof(
+                    "we did not get back an element for the correct path of the nonce",
+                )));
+            }
+            let nonce = maybe_element
+                .map(|element| {
+                    let encoded_nonce = element.into_item_bytes().map_err(Error::GroveDB)?;
+                    Ok::<IdentityNonce, Error>(IdentityNonce::from_be_bytes(
+                        encoded_nonce.try_into().map_err(|_| {
+                            Error::Proof(ProofError::IncorrectValueSize(
+                                "nonce was not 8 bytes as expected",
+                            ))
+                        })?,
+                    ))
+                })
+                .transpose()?;
+            Ok((root_hash, nonce))
+        } else {
+            Err(Error::Proof(ProofError::TooManyElements(
+                "expected one identity nonce",
+            )))
+        }
+    }
 }
@@ -6,6 +6,8 @@ pub mod document;
 pub mod identity;
 /// Single Document verification methods on proofs
 pub mod single_document;
+/// State transition result verification methods on proofs
+pub mod state_transition_result;
 
 /// Represents the root hash of the grovedb tree
 pub type RootHash = [u8; 32];
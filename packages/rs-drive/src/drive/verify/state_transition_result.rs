@@ -0,0 +1,71 @@
+use crate::drive::state_transition_result::{
+    state_transition_results_by_hash_path_vec, StateTransitionResult,
+};
+use crate::drive::verify::RootHash;
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::proof::ProofError;
+use crate::error::Error;
+use grovedb::{Element, GroveDb};
+
+impl Drive {
+    /// Verifies the result of a state transition, `None` when the proof shows that no result is
+    /// recorded for the state transition.
+    ///
+    /// # Parameters
+    ///
+    /// - `proof`: A byte slice representing the proof to be verified.
+    /// - `state_transition_hash`: The hash of the serialized state transition.
+    /// - `is_proof_subset`: A boolean indicating whether we are verifying a subset of a larger proof.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with a tuple of `RootHash` and `Option<StateTransitionResult>`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if:
+    ///
+    /// - The proof is corrupted.
+    /// - The proved element is not a serialized result.
+    ///
+    pub fn verify_state_transition_result(
+        proof: &[u8],
+        state_transition_hash: [u8; 32],
+        is_proof_subset: bool,
+    ) -> Result<(RootHash, Option<StateTransitionResult>), Error> {
+        let path_query = Self::state_transition_result_query(&state_transition_hash);
+        let (root_hash, mut proved_key_values) = if is_proof_subset {
+            GroveDb::verify_subset_query(proof, &path_query)?
+        } else {
+            GroveDb::verify_query(proof, &path_query)?
+        };
+        if proved_key_values.len() == 1 {
+            let (path, key, maybe_element) = proved_key_values.remove(0);
+            if path != state_transition_results_by_hash_path_vec() {
+                return Err(Error::Proof(ProofError::CorruptedProof(
+                    "we did not get back an element for the correct path in state transition results",
+                )));
+            }
+            if key != state_transition_hash {
+                return Err(Error::Proof(ProofError::CorruptedProof(
+                    "we did not get back an element for the correct key in state transition results",
+                )));
+            }
+
+            let result = maybe_element
+                .map(|element| match element {
+                    Element::Item(bytes, _) => StateTransitionResult::from_bytes(&bytes),
+                    _ => Err(Error::Drive(DriveError::CorruptedElementType(
+                        "state transition result must be an item",
+                    ))),
+                })
+                .transpose()?;
+            Ok((root_hash, result))
+        } else {
+            Err(Error::Proof(ProofError::TooManyElements(
+                "expected one state transition result",
+            )))
+        }
+    }
+}
@@ -142,4 +142,20 @@ pub enum DriveError {
     /// Error
     #[error("invalid contract history fetch limit: {0}. The limit must be between 1 and {MAX_CONTRACT_HISTORY_FETCH_LIMIT}")]
     InvalidContractHistoryFetchLimit(u16),
+
+    /// Error
+    #[error("corrupted archive error: {0}")]
+    CorruptedArchive(String),
+
+    /// Error
+    #[error("archive io error: {0}")]
+    ArchiveIo(std::io::Error),
+
+    /// Error
+    #[error("import destination is not empty error: {0}")]
+    ImportDestinationNotEmpty(String),
+
+    /// Error
+    #[error("too many documents to backfill indices error: {0}")]
+    TooManyDocumentsToBackfillIndices(&'static str),
 }
@@ -43,6 +43,9 @@ pub enum QuerySyntaxError {
     /// Invalid starts with clause error
     #[error("invalid STARTSWITH clause error: {0}")]
     InvalidStartsWithClause(&'static str),
+    /// Invalid contains clause error
+    #[error("invalid CONTAINS clause error: {0}")]
+    InvalidContainsClause(&'static str),
 
     /// Invalid where clause order error
     #[error("invalid where clause order error: {0}")]
@@ -36,8 +36,8 @@ use std::borrow::Cow;
 use std::collections::{BTreeMap, BTreeSet};
 
 use WhereOperator::{
-    Between, BetweenExcludeBounds, BetweenExcludeLeft, BetweenExcludeRight, Equal, GreaterThan,
-    GreaterThanOrEquals, In, LessThan, LessThanOrEquals, StartsWith,
+    Between, BetweenExcludeBounds, BetweenExcludeLeft, BetweenExcludeRight, Contains, ContainsAny,
+    Equal, GreaterThan, GreaterThanOrEquals, In, LessThan, LessThanOrEquals, StartsWith,
 };
 
 use crate::error::query::QuerySyntaxError;
@@ -70,6 +70,22 @@ fn sql_value_to_platform_value(sql_value: ast::Value) -> Option<Value> {
     }
 }
 
+/// Converts an SQL identifier to a field name, joining compound identifiers into
+/// the dotted path of a nested property.
+fn sql_identifier_to_field_name(expr: &ast::Expr) -> Option<String> {
+    match expr {
+        ast::Expr::Identifier(ident) => Some(ident.value.clone()),
+        ast::Expr::CompoundIdentifier(idents) => Some(
+            idents
+                .iter()
+                .map(|ident| ident.value.as_str())
+                .collect::<Vec<_>>()
+                .join("."),
+        ),
+        _ => None,
+    }
+}
+
 /// Where operator arguments
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum WhereOperator {
@@ -95,6 +111,10 @@ pub enum WhereOperator {
     In,
     /// Starts with
     StartsWith,
+    /// An array field contains the value
+    Contains,
+    /// An array field contains any of the values
+    ContainsAny,
 }
 
 impl WhereOperator {
@@ -112,6 +132,8 @@ impl WhereOperator {
             BetweenExcludeRight => false,
             In => false,
             StartsWith => false,
+            Contains => false,
+            ContainsAny => false,
         }
     }
 
@@ -141,6 +163,12 @@ impl WhereOperator {
             StartsWith => Err(Error::Query(QuerySyntaxError::InvalidWhereClauseOrder(
                 "Startswith clause order invalid",
             ))),
+            Contains => Err(Error::Query(QuerySyntaxError::InvalidWhereClauseOrder(
+                "Contains clause order invalid",
+            ))),
+            ContainsAny => Err(Error::Query(QuerySyntaxError::InvalidWhereClauseOrder(
+                "ContainsAny clause order invalid",
+            ))),
         }
     }
 }
@@ -149,14 +177,18 @@ impl WhereOperator {
     /// Returns true if the where operator result is a range
     pub const fn is_range(self) -> bool {
         match self {
-            Equal => false,
+            Equal | Contains => false,
             GreaterThan | GreaterThanOrEquals | LessThan | LessThanOrEquals | Between
-            | BetweenExcludeBounds | BetweenExcludeLeft | BetweenExcludeRight | In | StartsWith => {
-                true
-            }
+            | BetweenExcludeBounds | BetweenExcludeLeft | BetweenExcludeRight | In | StartsWith
+            | ContainsAny => true,
         }
     }
 
+    /// Returns true if the where operator is for array fields, which are queried by element
+    pub const fn is_contains(self) -> bool {
+        matches!(self, Contains | ContainsAny)
+    }
+
     /// Matches the where operator as a string and returns it as a proper `WhereOperator`
     pub(crate) fn from_string(string: &str) -> Option<Self> {
         match string {
@@ -180,6 +212,8 @@ impl WhereOperator {
             | "between_exclude_right" => Some(BetweenExcludeRight),
             "In" | "in" => Some(In),
             "StartsWith" | "startsWith" | "startswith" | "starts_with" => Some(StartsWith),
+            "Contains" | "contains" => Some(Contains),
+            "ContainsAny" | "containsAny" | "containsany" | "contains_any" => Some(ContainsAny),
             &_ => None,
         }
     }
@@ -441,7 +475,7 @@ impl<'a> WhereClause {
             where_clauses
                 .iter()
                 .filter_map(|where_clause| match where_clause.operator {
-                    Equal => match where_clause.is_identifier() {
+                    Equal | Contains => match where_clause.is_identifier() {
                         true => None,
                         false => Some(where_clause.clone()),
                     },
@@ -467,7 +501,7 @@ impl<'a> WhereClause {
         let in_clauses_array = where_clauses
             .iter()
             .filter_map(|where_clause| match where_clause.operator {
-                WhereOperator::In => match where_clause.is_identifier() {
+                In | ContainsAny => match where_clause.is_identifier() {
                     true => None,
                     false => Some(where_clause.clone()),
                 },
@@ -510,6 +544,8 @@ impl<'a> WhereClause {
                 BetweenExcludeBounds => false,
                 BetweenExcludeRight => false,
                 BetweenExcludeLeft => false,
+                Contains => false,
+                ContainsAny => false,
             })
             .collect();
 
@@ -527,6 +563,8 @@ impl<'a> WhereClause {
                 BetweenExcludeBounds => true,
                 BetweenExcludeRight => true,
                 BetweenExcludeLeft => true,
+                Contains => false,
+                ContainsAny => false,
             })
             .collect();
 
@@ -679,6 +717,13 @@ impl<'a> WhereClause {
         // current field.
         let starts_at_key_option = match start_at_document {
             None => None,
+            // a document is indexed under every element of an array, so the position of the
+            // start at document can't be derived from its value for contains clauses
+            Some(_) if self.operator.is_contains() => {
+                return Err(Error::Query(QuerySyntaxError::InvalidContainsClause(
+                    "startAt and startAfter can not be used with contains or containsAny",
+                )))
+            }
             Some((document, included)) => {
                 // if the key doesn't exist then we should ignore the starts at key
                 document
@@ -689,6 +734,17 @@ impl<'a> WhereClause {
 
         let mut query = Query::new_with_direction(left_to_right);
         match self.operator {
+            Contains => {
+                let key =
+                    document_type.serialize_value_for_key(self.field.as_str(), &self.value)?;
+                query.insert_key(key);
+            }
+            ContainsAny => {
+                for value in self.in_values()?.iter() {
+                    let key = document_type.serialize_value_for_key(self.field.as_str(), value)?;
+                    query.insert_key(key);
+                }
+            }
             Equal => {
                 let key =
                     document_type.serialize_value_for_key(self.field.as_str(), &self.value)?;
@@ -1022,13 +1078,11 @@ impl<'a> WhereClause {
                     )));
                 }
 
-                let field_name = if let ast::Expr::Identifier(ident) = &**expr {
-                    ident.value.clone()
-                } else {
-                    return Err(Error::Query(QuerySyntaxError::InvalidInClause(
+                let field_name = sql_identifier_to_field_name(expr).ok_or(Error::Query(
+                    QuerySyntaxError::InvalidInClause(
                         "Invalid query: in clause should start with an identifier",
-                    )));
-                };
+                    ),
+                ))?;
 
                 let mut in_values: Vec<Value> = Vec::new();
                 for value in list {
@@ -1067,12 +1121,12 @@ impl<'a> WhereClause {
                     let identifier;
                     let value_expr;
 
-                    if matches!(&**left, ast::Expr::Identifier(_))
+                    if sql_identifier_to_field_name(left).is_some()
                         && matches!(&**right, ast::Expr::Value(_))
                     {
                         identifier = &**left;
                         value_expr = &**right;
-                    } else if matches!(&**right, ast::Expr::Identifier(_))
+                    } else if sql_identifier_to_field_name(right).is_some()
                         && matches!(&**left, ast::Expr::Value(_))
                     {
                         identifier = &**right;
@@ -1084,11 +1138,8 @@ impl<'a> WhereClause {
                         )));
                     }
 
-                    let field_name = if let ast::Expr::Identifier(ident) = identifier {
-                        ident.value.clone()
-                    } else {
-                        panic!("unreachable: confirmed it's identifier variant");
-                    };
+                    let field_name = sql_identifier_to_field_name(identifier)
+                        .expect("unreachable: confirmed it's an identifier");
 
                     let value = if let ast::Expr::Value(value) = value_expr {
                         let cbor_val = sql_value_to_platform_value(value.clone()).ok_or({
@@ -28,7 +28,7 @@
 //
 
 #[cfg(any(feature = "full", feature = "verify"))]
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 #[cfg(any(feature = "full", feature = "verify"))]
 use std::ops::BitXor;
 
@@ -66,6 +66,8 @@ use dpp::block::block_info::BlockInfo;
 
 #[cfg(any(feature = "full", feature = "verify"))]
 use dpp::data_contract::document_type::DocumentType;
+#[cfg(feature = "full")]
+use dpp::data_contract::document_type::JsonDateFormat;
 #[cfg(any(feature = "full", feature = "verify"))]
 use dpp::data_contract::document_type::{Index, IndexProperty};
 #[cfg(any(feature = "full", feature = "verify"))]
@@ -95,7 +97,7 @@ use crate::fee::op::LowLevelDriveOperation;
 use crate::drive::contract::paths::ContractPaths;
 
 #[cfg(any(feature = "full", feature = "verify"))]
-use dpp::document::Document;
+use dpp::document::{Document, DocumentView};
 
 #[cfg(any(feature = "full", feature = "verify"))]
 use dpp::platform_value::btreemap_extensions::BTreeValueRemoveFromMapHelper;
@@ -105,9 +107,13 @@ use dpp::platform_value::Value;
 
 use crate::common::encode::encode_u64;
 use crate::drive::config::DriveConfig;
+#[cfg(any(feature = "full", feature = "verify"))]
+use crate::drive::config::DEFAULT_MAX_QUERY_LIMIT;
 use crate::error::Error::GroveDB;
 #[cfg(any(feature = "full", feature = "verify"))]
 use dpp::ProtocolError;
+#[cfg(any(feature = "full", feature = "verify"))]
+use serde_json::Value as JsonValue;
 
 #[cfg(any(feature = "full", feature = "verify"))]
 pub mod conditions;
@@ -255,6 +261,9 @@ impl InternalClauses {
 pub enum QueryResultEncoding {
     /// Cbor encoding
     CborEncodedQueryResult,
+    /// Human readable JSON encoding, with identifiers as base58 strings, bytes as base64 strings
+    /// and dates as milliseconds, documents being rendered by their document type
+    JsonEncodedQueryResult,
 }
 
 #[cfg(any(feature = "full", feature = "verify"))]
@@ -267,9 +276,67 @@ impl QueryResultEncoding {
                 ciborium::ser::into_writer(value, &mut buffer)
                     .map_err(|e| ProtocolError::EncodingError(e.to_string()))?;
             }
+            QueryResultEncoding::JsonEncodedQueryResult => {
+                let json: JsonValue = value
+                    .clone()
+                    .try_into()
+                    .map_err(ProtocolError::ValueError)?;
+                serde_json::to_writer(&mut buffer, &json)
+                    .map_err(|e| ProtocolError::EncodingError(e.to_string()))?;
+            }
         }
         Ok(buffer)
     }
+
+    #[cfg(feature = "full")]
+    /// Encode the key element pairs of documents returned by a query, rendering each document the
+    /// way its document type describes it when the encoding is human readable
+    pub fn encode_documents(
+        &self,
+        documents: Vec<(Vec<u8>, Element)>,
+        document_type: &DocumentType,
+    ) -> Result<Vec<u8>, Error> {
+        match self {
+            QueryResultEncoding::CborEncodedQueryResult => self.encode_value(&platform_value!({
+                "documents": documents
+            })),
+            QueryResultEncoding::JsonEncodedQueryResult => {
+                let documents = documents
+                    .into_iter()
+                    .map(|(key, element)| match element {
+                        Element::Item(serialized, _) => {
+                            let document = document_type.serialized_document_to_json(
+                                serialized.as_slice(),
+                                JsonDateFormat::Milliseconds,
+                            )?;
+                            Ok(serde_json::json!([
+                                bs58::encode(key).into_string(),
+                                document
+                            ]))
+                        }
+                        _ => Err(Error::Drive(DriveError::CorruptedQueryReturnedNonItem(
+                            "documents query should only return items",
+                        ))),
+                    })
+                    .collect::<Result<Vec<JsonValue>, Error>>()?;
+                serde_json::to_vec(&serde_json::json!({ "documents": documents }))
+                    .map_err(|e| Error::Protocol(ProtocolError::EncodingError(e.to_string())))
+            }
+        }
+    }
+
+    /// Decode a value encoded with [`QueryResultEncoding::encode_value`]
+    pub fn decode_value(&self, bytes: &[u8]) -> Result<Value, Error> {
+        match self {
+            QueryResultEncoding::CborEncodedQueryResult => ciborium::de::from_reader(bytes)
+                .map_err(|e| Error::Protocol(ProtocolError::DecodingError(e.to_string()))),
+            QueryResultEncoding::JsonEncodedQueryResult => {
+                serde_json::from_slice::<JsonValue>(bytes)
+                    .map(Value::from)
+                    .map_err(|e| Error::Protocol(ProtocolError::DecodingError(e.to_string())))
+            }
+        }
+    }
 }
 
 #[cfg(any(feature = "full", feature = "verify"))]
@@ -1001,6 +1068,27 @@ impl<'a> DriveQuery<'a> {
     #[cfg(any(feature = "full", feature = "verify"))]
     /// Finds the best index for the query.
     pub fn find_best_index(&self) -> Result<&Index, Error> {
+        // array fields are indexed by element, so they can only be queried with contains operators
+        for where_clause in self
+            .internal_clauses
+            .equal_clauses
+            .values()
+            .chain(self.internal_clauses.in_clause.as_ref())
+            .chain(self.internal_clauses.range_clause.as_ref())
+        {
+            let is_array_field = self.document_type.is_array_field(&where_clause.field);
+            if is_array_field && !where_clause.operator.is_contains() {
+                return Err(Error::Query(QuerySyntaxError::InvalidContainsClause(
+                    "array fields can only be queried with contains or containsAny",
+                )));
+            }
+            if !is_array_field && where_clause.operator.is_contains() {
+                return Err(Error::Query(QuerySyntaxError::InvalidContainsClause(
+                    "contains and containsAny can only be used on array fields",
+                )));
+            }
+        }
+
         let equal_fields = self
             .internal_clauses
             .equal_clauses
@@ -1054,6 +1142,57 @@ impl<'a> DriveQuery<'a> {
         Ok(index)
     }
 
+    #[cfg(any(feature = "full", feature = "verify"))]
+    /// Returns the number of values of the `containsAny` clause of the query, if it has one.
+    fn contains_any_values_count(&self) -> Option<usize> {
+        self.internal_clauses
+            .in_clause
+            .as_ref()
+            .filter(|in_clause| in_clause.operator == WhereOperator::ContainsAny)
+            .and_then(|in_clause| in_clause.in_values().ok())
+            .map(|in_values| in_values.len())
+    }
+
+    #[cfg(any(feature = "full", feature = "verify"))]
+    /// Returns the limit and offset of the path query.
+    ///
+    /// A document containing several of the values of a `containsAny` clause is found once
+    /// per value, so these queries fetch enough items to fill the limit once duplicates are
+    /// removed, and the offset and limit are applied by `deduplicate_serialized_results`.
+    /// The items fetched are capped at [DEFAULT_MAX_QUERY_LIMIT], documents found under several
+    /// values can then fill fewer results than the limit. The cap doesn't come from the config
+    /// so that proofs are verified against the same path query they were generated with.
+    fn path_query_limit_and_offset(&self) -> (Option<u16>, Option<u16>) {
+        match self.contains_any_values_count() {
+            None => (self.limit, self.offset),
+            Some(values_count) => {
+                let limit = self.limit.map(|limit| {
+                    let items =
+                        (limit as usize + self.offset.unwrap_or_default() as usize) * values_count;
+                    items.min(DEFAULT_MAX_QUERY_LIMIT as usize) as u16
+                });
+                (limit, None)
+            }
+        }
+    }
+
+    #[cfg(any(feature = "full", feature = "verify"))]
+    /// Removes the duplicate documents a `containsAny` clause returns for documents that
+    /// contain several of the values, keeping the first occurrence, then applies the offset
+    /// and limit of the query.
+    pub(crate) fn deduplicate_serialized_results(&self, items: Vec<Vec<u8>>) -> Vec<Vec<u8>> {
+        if self.contains_any_values_count().is_none() {
+            return items;
+        }
+        let mut seen = BTreeSet::new();
+        items
+            .into_iter()
+            .filter(|item| seen.insert(item.clone()))
+            .skip(self.offset.unwrap_or_default() as usize)
+            .take(self.limit.map_or(usize::MAX, |limit| limit as usize))
+            .collect()
+    }
+
     #[cfg(any(feature = "full", feature = "verify"))]
     /// Returns a `QueryItem` given a start key and query direction.
     fn query_item_for_starts_at_key(starts_at_key: Vec<u8>, left_to_right: bool) -> QueryItem {
@@ -1476,9 +1615,10 @@ impl<'a> DriveQuery<'a> {
 
         path.push(last_index.name.as_bytes().to_vec());
 
+        let (limit, offset) = self.path_query_limit_and_offset();
         Ok(PathQuery::new(
             path,
-            SizedQuery::new(final_query, self.limit, self.offset),
+            SizedQuery::new(final_query, limit, offset),
         ))
     }
 
@@ -1574,10 +1714,7 @@ impl<'a> DriveQuery<'a> {
             &mut drive_operations,
         )?;
         //todo: we could probably give better results depending on the query
-        let result = platform_value!({
-            "documents": items.to_key_elements()
-        });
-        query_result_encoding.encode_value(&result)
+        query_result_encoding.encode_documents(items.to_key_elements(), self.document_type)
     }
 
     #[cfg(feature = "full")]
@@ -1600,6 +1737,25 @@ impl<'a> DriveQuery<'a> {
         Ok((items, skipped, cost))
     }
 
+    #[cfg(any(feature = "full", feature = "verify"))]
+    /// Reads documents serialized by the query in place, for results of
+    /// `execute_raw_results_no_proof` or `verify_proof_keep_serialized`.
+    ///
+    /// Properties are only decoded when they are accessed, so reading a few fields or
+    /// forwarding the serialized documents doesn't build every document.
+    pub fn document_views<'b>(
+        &self,
+        serialized_documents: &'b [Vec<u8>],
+    ) -> impl Iterator<Item = Result<DocumentView<'b>, Error>> + 'b
+    where
+        'a: 'b,
+    {
+        let document_type = self.document_type;
+        serialized_documents.iter().map(move |serialized| {
+            DocumentView::new(serialized.as_slice(), document_type).map_err(Error::Protocol)
+        })
+    }
+
     #[cfg(feature = "full")]
     /// Executes an internal query with no proof and returns the values and skipped items.
     pub(crate) fn execute_raw_results_no_proof_internal(
@@ -1622,7 +1778,7 @@ impl<'a> DriveQuery<'a> {
             _ => {
                 let (data, skipped) = query_result?;
                 {
-                    Ok((data, skipped))
+                    Ok((self.deduplicate_serialized_results(data), skipped))
                 }
             }
         }
@@ -1675,7 +1831,7 @@ mod tests {
     use dpp::util::cbor_serializer;
     use serde_json::Value::Null;
 
-    use crate::drive::config::DriveConfig;
+    use crate::drive::config::{DriveConfig, DEFAULT_MAX_QUERY_LIMIT};
     use dpp::block::block_info::BlockInfo;
 
     fn setup_family_contract() -> (Drive, Contract) {
@@ -2048,6 +2204,45 @@ mod tests {
             .expect_err("query should not be able to execute with too many elements");
     }
 
+    #[test]
+    fn test_contains_any_query_with_most_values_stays_within_max_query_limit() {
+        let contract =
+            json_document_to_contract("tests/supporting_files/contract/tags/tags-contract.json")
+                .expect("expected to get contract");
+
+        let document_type = contract
+            .document_type_for_name("post")
+            .expect("expected to get a document type");
+
+        // in clauses take at most 100 values
+        let tags: Vec<String> = (0..100).map(|i| format!("tag{}", i)).collect();
+        let query_value = json!({
+            "where": [
+                ["tags", "containsAny", tags],
+            ],
+            "limit": 100,
+            "orderBy": [
+                ["tags", "asc"],
+            ],
+        });
+
+        let where_cbor = cbor_serializer::serializable_value_to_cbor(&query_value, None)
+            .expect("expected to serialize to cbor");
+        let query = DriveQuery::from_cbor(
+            where_cbor.as_slice(),
+            &contract,
+            document_type,
+            &DriveConfig::default(),
+        )
+        .expect("query should be valid");
+
+        let path_query = query
+            .construct_path_query(None)
+            .expect("expected to construct a path query");
+        assert_eq!(path_query.query.limit, Some(DEFAULT_MAX_QUERY_LIMIT));
+        assert_eq!(path_query.query.offset, None);
+    }
+
     #[test]
     fn test_invalid_query_in_unique_elements() {
         let (drive, contract) = setup_family_contract();
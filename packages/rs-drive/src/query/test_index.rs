@@ -23,6 +23,7 @@ mod tests {
                         ascending: true,
                     }],
                     unique: false,
+                    collation: None,
                 },
                 Index {
                     name: "b".to_string(),
@@ -31,6 +32,7 @@ mod tests {
                         ascending: false,
                     }],
                     unique: false,
+                    collation: None,
                 },
                 Index {
                     name: "c".to_string(),
@@ -45,6 +47,7 @@ mod tests {
                         },
                     ],
                     unique: false,
+                    collation: None,
                 },
                 Index {
                     name: "d".to_string(),
@@ -63,6 +66,7 @@ mod tests {
                         },
                     ],
                     unique: false,
+                    collation: None,
                 },
             ],
             Default::default(),
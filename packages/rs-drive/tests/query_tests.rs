@@ -36,7 +36,7 @@ use grovedb::TransactionArg;
 #[cfg(feature = "full")]
 use std::borrow::Cow;
 #[cfg(feature = "full")]
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 #[cfg(feature = "full")]
 use std::fs::File;
 #[cfg(feature = "full")]
@@ -64,7 +64,7 @@ use drive::common;
 #[cfg(feature = "full")]
 use drive::common::setup_contract;
 #[cfg(feature = "full")]
-use drive::drive::batch::GroveDbOpBatch;
+use drive::drive::batch::{ContractOperationType, DriveOperation, GroveDbOpBatch};
 #[cfg(feature = "full")]
 use drive::drive::config::DriveConfig;
 #[cfg(feature = "full")]
@@ -78,7 +78,7 @@ use drive::drive::Drive;
 #[cfg(feature = "full")]
 use drive::error::{query::QuerySyntaxError, Error};
 #[cfg(feature = "full")]
-use drive::query::DriveQuery;
+use drive::query::{DriveQuery, QueryResultEncoding};
 #[cfg(feature = "full")]
 #[cfg(test)]
 use drive::tests::helpers::setup::setup_drive;
@@ -2249,6 +2249,164 @@ fn test_family_basic_queries() {
     );
 }
 
+#[cfg(feature = "full")]
+#[test]
+fn test_family_query_document_views() {
+    let (drive, contract) = setup_family_tests(10, 73509);
+
+    let query_value = json!({
+        "where": [
+            ["firstName", ">", "Cammi"]
+        ],
+        "limit": 3,
+        "orderBy": [
+            ["firstName", "asc"]
+        ]
+    });
+    let where_cbor = cbor_serializer::serializable_value_to_cbor(&query_value, None)
+        .expect("expected to serialize to cbor");
+    let person_document_type = contract
+        .document_types
+        .get("person")
+        .expect("contract should have a person document type");
+    let query = DriveQuery::from_cbor(
+        where_cbor.as_slice(),
+        &contract,
+        person_document_type,
+        &drive.config,
+    )
+    .expect("query should be built");
+    let (results, _, _) = query
+        .execute_raw_results_no_proof(&drive, None, None)
+        .expect("query should be executed");
+
+    let names: Vec<Value> = query
+        .document_views(&results)
+        .map(|view| {
+            view.expect("expected to read the document")
+                .get("firstName")
+                .expect("expected to decode the first name")
+                .expect("expected a person to have a first name")
+        })
+        .collect();
+    assert_eq!(
+        names,
+        vec![
+            Value::Text("Celinda".to_string()),
+            Value::Text("Dalia".to_string()),
+            Value::Text("Gilligan".to_string()),
+        ]
+    );
+
+    for (view, serialized) in query.document_views(&results).zip(results.iter()) {
+        let view = view.expect("expected to read the document");
+        assert_eq!(view.as_bytes(), serialized.as_slice());
+        assert_eq!(
+            view.to_document().expect("expected to build the document"),
+            Document::from_bytes(serialized.as_slice(), person_document_type)
+                .expect("expected to deserialize the document")
+        );
+    }
+
+    let (_, proof_results, _) = query
+        .clone()
+        .execute_with_proof_only_get_elements(&drive, None, None)
+        .expect("we should be able to a proof");
+    let proved_ids: Vec<Identifier> = query
+        .document_views(&proof_results)
+        .map(|view| view.expect("expected to read the document").id())
+        .collect();
+    let ids: Vec<Identifier> = query
+        .document_views(&results)
+        .map(|view| view.expect("expected to read the document").id())
+        .collect();
+    assert_eq!(proved_ids, ids);
+}
+
+#[cfg(feature = "full")]
+#[test]
+fn test_family_query_json_encoded_results() {
+    let (drive, contract) = setup_family_tests(10, 73509);
+
+    let query_value = json!({
+        "where": [
+            ["firstName", ">", "Cammi"]
+        ],
+        "limit": 2,
+        "orderBy": [
+            ["firstName", "asc"]
+        ]
+    });
+    let where_cbor = cbor_serializer::serializable_value_to_cbor(&query_value, None)
+        .expect("expected to serialize to cbor");
+    let person_document_type = contract
+        .document_types
+        .get("person")
+        .expect("contract should have a person document type");
+    let query = DriveQuery::from_cbor(
+        where_cbor.as_slice(),
+        &contract,
+        person_document_type,
+        &drive.config,
+    )
+    .expect("query should be built");
+
+    let encoded = query
+        .execute_serialized_as_result_no_proof(
+            &drive,
+            None,
+            QueryResultEncoding::JsonEncodedQueryResult,
+            None,
+        )
+        .expect("query should be executed");
+    let result: serde_json::Value =
+        serde_json::from_slice(&encoded).expect("expected the result to be json");
+    let documents = result["documents"]
+        .as_array()
+        .expect("expected the documents to be an array");
+
+    // both encodings return the documents as key element pairs
+    let cbor_encoded = query
+        .execute_serialized_as_result_no_proof(
+            &drive,
+            None,
+            QueryResultEncoding::CborEncodedQueryResult,
+            None,
+        )
+        .expect("query should be executed");
+    let cbor_result = QueryResultEncoding::CborEncodedQueryResult
+        .decode_value(&cbor_encoded)
+        .expect("expected the result to be cbor");
+    let cbor_documents = cbor_result
+        .get_value("documents")
+        .expect("expected documents")
+        .as_array()
+        .expect("expected the documents to be an array");
+    assert_eq!(documents.len(), cbor_documents.len());
+
+    let (serialized_documents, _, _) = query
+        .execute_raw_results_no_proof(&drive, None, None)
+        .expect("query should be executed");
+    assert_eq!(documents.len(), serialized_documents.len());
+    for (pair, serialized) in documents.iter().zip(serialized_documents.iter()) {
+        let document = Document::from_bytes(serialized.as_slice(), person_document_type)
+            .expect("expected to deserialize the document");
+        let id = json!(bs58::encode(document.id.as_slice()).into_string());
+        assert_eq!(pair[0], id);
+        let json_document = &pair[1];
+        assert_eq!(json_document["$id"], id);
+        assert_eq!(
+            person_document_type
+                .document_from_json(json_document)
+                .expect("expected to parse the document")
+                .id,
+            document.id
+        );
+    }
+    assert_eq!(documents[0][1]["firstName"], json!("Celinda"));
+    assert_eq!(documents[1][1]["firstName"], json!("Dalia"));
+}
+
 #[cfg(feature = "full")]
 #[test]
 fn test_family_person_update() {
@@ -4961,6 +5119,803 @@ fn test_query_documents_by_created_at() {
     assert_eq!(query_result.documents.len(), 1);
 }
 
+#[cfg(feature = "full")]
+fn tagged_post(id: u8, tags: Vec<&str>) -> Document {
+    Document {
+        id: Identifier::from([id; 32]),
+        owner_id: Identifier::from([1; 32]),
+        properties: BTreeMap::from([
+            ("title".to_string(), Value::Text(format!("post {}", id))),
+            (
+                "tags".to_string(),
+                Value::Array(
+                    tags.into_iter()
+                        .map(|tag| Value::Text(tag.to_string()))
+                        .collect(),
+                ),
+            ),
+        ]),
+        revision: Some(1),
+        created_at: None,
+        updated_at: None,
+    }
+}
+
+#[cfg(feature = "full")]
+fn query_tagged_posts(drive: &Drive, contract: &Contract, where_clause: Value) -> Vec<Document> {
+    query_tagged_posts_with_limit(drive, contract, where_clause, None)
+}
+
+#[cfg(feature = "full")]
+fn query_tagged_posts_with_limit(
+    drive: &Drive,
+    contract: &Contract,
+    where_clause: Value,
+    limit: Option<u16>,
+) -> Vec<Document> {
+    let document_type = contract
+        .document_type_for_name("post")
+        .expect("expected to get document type");
+    let mut query_value = platform_value!({
+        "where": [where_clause],
+        "orderBy": [["tags", "asc"]],
+    });
+    if let Some(limit) = limit {
+        query_value
+            .insert("limit".to_string(), Value::U16(limit))
+            .expect("expected to set the limit");
+    }
+    let query = DriveQuery::from_value(query_value, contract, document_type, &drive.config)
+        .expect("query should be built");
+
+    let (results, _, _) = query
+        .execute_raw_results_no_proof(drive, None, None)
+        .expect("query should be executed");
+
+    let (proof, _) = query
+        .execute_with_proof(drive, None, None)
+        .expect("expected proof to be generated");
+    let (_, proved_documents) = query
+        .verify_proof(&proof)
+        .expect("expected to verify proof");
+
+    let documents = results
+        .iter()
+        .map(|serialized| {
+            Document::from_bytes(serialized, document_type).expect("expected a document")
+        })
+        .collect::<Vec<Document>>();
+    assert_eq!(documents, proved_documents);
+    documents
+}
+
+#[cfg(feature = "full")]
+#[test]
+fn test_array_index_contains_queries() {
+    let drive = setup_drive_with_initial_state_structure();
+
+    let contract =
+        json_document_to_contract("tests/supporting_files/contract/tags/tags-contract.json")
+            .expect("expected to get contract");
+    drive
+        .apply_contract(
+            &contract,
+            BlockInfo::default(),
+            true,
+            StorageFlags::optional_default_as_cow(),
+            None,
+        )
+        .expect("expected to apply contract successfully");
+
+    let document_type = contract
+        .document_type_for_name("post")
+        .expect("expected to get document type");
+
+    for document in [
+        tagged_post(2, vec!["rust", "grovedb"]),
+        tagged_post(3, vec!["rust"]),
+        tagged_post(4, vec!["dash", "rust", "rust"]),
+        tagged_post(5, vec![]),
+    ] {
+        drive
+            .add_document_for_contract(
+                DocumentAndContractInfo {
+                    owned_document_info: OwnedDocumentInfo {
+                        document_info: DocumentRefInfo((
+                            &document,
+                            StorageFlags::optional_default_as_cow(),
+                        )),
+                        owner_id: None,
+                    },
+                    contract: &contract,
+                    document_type,
+                },
+                false,
+                BlockInfo::genesis(),
+                true,
+                None,
+            )
+            .expect("document should be inserted");
+    }
+
+    let ids = |documents: Vec<Document>| {
+        documents
+            .into_iter()
+            .map(|document| document.id.to_buffer()[0])
+            .collect::<Vec<u8>>()
+    };
+
+    assert_eq!(
+        ids(query_tagged_posts(
+            &drive,
+            &contract,
+            platform_value!(["tags", "contains", "rust"])
+        )),
+        vec![2, 3, 4]
+    );
+    assert_eq!(
+        ids(query_tagged_posts(
+            &drive,
+            &contract,
+            platform_value!(["tags", "contains", "grovedb"])
+        )),
+        vec![2]
+    );
+    // a document containing several of the values is only returned once
+    assert_eq!(
+        ids(query_tagged_posts(
+            &drive,
+            &contract,
+            platform_value!(["tags", "containsAny", ["dash", "rust"]])
+        )),
+        vec![4, 2, 3]
+    );
+    // the limit counts documents, not the entries of each value they were found under
+    assert_eq!(
+        ids(query_tagged_posts_with_limit(
+            &drive,
+            &contract,
+            platform_value!(["tags", "containsAny", ["grovedb", "rust"]]),
+            Some(2)
+        )),
+        vec![2, 3]
+    );
+
+    let query_value = platform_value!({
+        "where": [["tags", "contains", "rust"]],
+        "orderBy": [["tags", "asc"]],
+        "startAt": Identifier::from([3; 32]),
+    });
+    let query = DriveQuery::from_value(query_value, &contract, document_type, &drive.config)
+        .expect("query should be built");
+    assert!(matches!(
+        query.execute_raw_results_no_proof(&drive, None, None),
+        Err(Error::Query(QuerySyntaxError::InvalidContainsClause(_)))
+    ));
+
+    drive
+        .update_document_for_contract(
+            &tagged_post(2, vec!["dash"]),
+            &contract,
+            document_type,
+            None,
+            BlockInfo::genesis(),
+            true,
+            StorageFlags::optional_default_as_cow(),
+            None,
+        )
+        .expect("expected to update document");
+
+    assert!(query_tagged_posts(
+        &drive,
+        &contract,
+        platform_value!(["tags", "contains", "grovedb"])
+    )
+    .is_empty());
+    assert_eq!(
+        ids(query_tagged_posts(
+            &drive,
+            &contract,
+            platform_value!(["tags", "contains", "dash"])
+        )),
+        vec![2, 4]
+    );
+
+    drive
+        .delete_document_for_contract(
+            [4; 32],
+            &contract,
+            "post",
+            None,
+            BlockInfo::genesis(),
+            true,
+            None,
+        )
+        .expect("expected to delete document");
+
+    assert_eq!(
+        ids(query_tagged_posts(
+            &drive,
+            &contract,
+            platform_value!(["tags", "contains", "rust"])
+        )),
+        vec![3]
+    );
+
+    let query_value = platform_value!({
+        "where": [["tags", "==", "rust"]],
+    });
+    let query = DriveQuery::from_value(query_value, &contract, document_type, &drive.config)
+        .expect("query should be built");
+    assert!(matches!(
+        query.execute_raw_results_no_proof(&drive, None, None),
+        Err(Error::Query(QuerySyntaxError::InvalidContainsClause(_)))
+    ));
+}
+
+#[cfg(feature = "full")]
+fn person_with_address(id: u8, name: &str, city: &str) -> Document {
+    Document {
+        id: Identifier::from([id; 32]),
+        owner_id: Identifier::from([1; 32]),
+        properties: BTreeMap::from([
+            ("name".to_string(), Value::Text(name.to_string())),
+            (
+                "address".to_string(),
+                platform_value!({
+                    "street": format!("{} street", id),
+                    "city": city,
+                }),
+            ),
+        ]),
+        revision: Some(1),
+        created_at: None,
+        updated_at: None,
+    }
+}
+
+#[cfg(feature = "full")]
+#[test]
+fn test_nested_property_index_queries() {
+    let drive = setup_drive_with_initial_state_structure();
+
+    let contract =
+        json_document_to_contract("tests/supporting_files/contract/address/address-contract.json")
+            .expect("expected to get contract");
+    drive
+        .apply_contract(
+            &contract,
+            BlockInfo::default(),
+            true,
+            StorageFlags::optional_default_as_cow(),
+            None,
+        )
+        .expect("expected to apply contract successfully");
+
+    let document_type = contract
+        .document_type_for_name("person")
+        .expect("expected to get document type");
+
+    for document in [
+        person_with_address(2, "Alice", "Paris"),
+        person_with_address(3, "Bob", "Oslo"),
+        person_with_address(4, "Carol", "Paris"),
+    ] {
+        drive
+            .add_document_for_contract(
+                DocumentAndContractInfo {
+                    owned_document_info: OwnedDocumentInfo {
+                        document_info: DocumentRefInfo((
+                            &document,
+                            StorageFlags::optional_default_as_cow(),
+                        )),
+                        owner_id: None,
+                    },
+                    contract: &contract,
+                    document_type,
+                },
+                false,
+                BlockInfo::genesis(),
+                true,
+                None,
+            )
+            .expect("document should be inserted");
+    }
+
+    let names_in_city = |city: &str| {
+        let query_value = platform_value!({
+            "where": [["address.city", "==", city]],
+            "orderBy": [["name", "asc"]],
+        });
+        let query = DriveQuery::from_value(query_value, &contract, document_type, &drive.config)
+            .expect("query should be built");
+        let (results, _, _) = query
+            .execute_raw_results_no_proof(&drive, None, None)
+            .expect("query should be executed");
+        results
+            .iter()
+            .map(|serialized| {
+                let document =
+                    Document::from_bytes(serialized, document_type).expect("expected a document");
+                document
+                    .properties
+                    .get("name")
+                    .and_then(|name| name.as_text())
+                    .expect("expected a name")
+                    .to_string()
+            })
+            .collect::<Vec<String>>()
+    };
+
+    assert_eq!(names_in_city("Paris"), vec!["Alice", "Carol"]);
+    assert_eq!(names_in_city("Oslo"), vec!["Bob"]);
+
+    // dotted field names are parsed from compound sql identifiers
+    let sql_query = DriveQuery::from_sql_expr(
+        "select * from person where address.city = 'Paris' order by name asc",
+        &contract,
+        &DriveConfig::default(),
+    )
+    .expect("should build query");
+    let value_query = DriveQuery::from_value(
+        platform_value!({
+            "where": [["address.city", "==", "Paris"]],
+            "orderBy": [["name", "asc"]],
+        }),
+        &contract,
+        document_type,
+        &drive.config,
+    )
+    .expect("should build query");
+    assert_eq!(sql_query, value_query);
+
+    drive
+        .update_document_for_contract(
+            &person_with_address(2, "Alice", "Oslo"),
+            &contract,
+            document_type,
+            None,
+            BlockInfo::genesis(),
+            true,
+            StorageFlags::optional_default_as_cow(),
+            None,
+        )
+        .expect("expected to update document");
+
+    assert_eq!(names_in_city("Paris"), vec!["Carol"]);
+    assert_eq!(names_in_city("Oslo"), vec!["Alice", "Bob"]);
+
+    drive
+        .delete_document_for_contract(
+            [3; 32],
+            &contract,
+            "person",
+            None,
+            BlockInfo::genesis(),
+            true,
+            None,
+        )
+        .expect("expected to delete document");
+
+    assert_eq!(names_in_city("Oslo"), vec!["Alice"]);
+}
+
+#[cfg(feature = "full")]
+#[test]
+fn test_contract_update_backfills_new_index() {
+    let drive = setup_drive_with_initial_state_structure();
+
+    let contract =
+        json_document_to_contract("tests/supporting_files/contract/address/address-contract.json")
+            .expect("expected to get contract");
+    drive
+        .apply_contract(
+            &contract,
+            BlockInfo::default(),
+            true,
+            StorageFlags::optional_default_as_cow(),
+            None,
+        )
+        .expect("expected to apply contract successfully");
+
+    let document_type = contract
+        .document_type_for_name("person")
+        .expect("expected to get document type");
+
+    for document in [
+        person_with_address(2, "Alice", "Paris"),
+        person_with_address(3, "Bob", "Oslo"),
+        person_with_address(4, "Alice", "Oslo"),
+    ] {
+        drive
+            .add_document_for_contract(
+                DocumentAndContractInfo {
+                    owned_document_info: OwnedDocumentInfo {
+                        document_info: DocumentRefInfo((
+                            &document,
+                            StorageFlags::optional_default_as_cow(),
+                        )),
+                        owner_id: None,
+                    },
+                    contract: &contract,
+                    document_type,
+                },
+                false,
+                BlockInfo::genesis(),
+                true,
+                None,
+            )
+            .expect("document should be inserted");
+    }
+
+    // the updated contract adds an index on the name, which existing documents already have
+    let updated_contract = json_document_to_contract(
+        "tests/supporting_files/contract/address/address-contract-name-index.json",
+    )
+    .expect("expected to get contract");
+    drive
+        .apply_contract(
+            &updated_contract,
+            BlockInfo::default(),
+            true,
+            StorageFlags::optional_default_as_cow(),
+            None,
+        )
+        .expect("expected to update contract successfully");
+
+    let document_type = updated_contract
+        .document_type_for_name("person")
+        .expect("expected to get document type");
+
+    let ids_with_name = |name: &str| {
+        let query_value = platform_value!({
+            "where": [["name", "==", name]],
+        });
+        let query =
+            DriveQuery::from_value(query_value, &updated_contract, document_type, &drive.config)
+                .expect("query should be built");
+        let (results, _, _) = query
+            .execute_raw_results_no_proof(&drive, None, None)
+            .expect("query should be executed");
+        results
+            .iter()
+            .map(|serialized| {
+                Document::from_bytes(serialized, document_type)
+                    .expect("expected a document")
+                    .id
+                    .to_buffer()[0]
+            })
+            .collect::<Vec<u8>>()
+    };
+
+    assert_eq!(ids_with_name("Alice"), vec![2, 4]);
+    assert_eq!(ids_with_name("Bob"), vec![3]);
+    assert!(drive
+        .can_backfill_indices(document_type, None, &mut vec![])
+        .expect("expected to count documents"));
+
+    // documents removed after the backfill are removed from the new index
+    drive
+        .delete_document_for_contract(
+            [4; 32],
+            &updated_contract,
+            "person",
+            None,
+            BlockInfo::genesis(),
+            true,
+            None,
+        )
+        .expect("expected to delete document");
+
+    assert_eq!(ids_with_name("Alice"), vec![2]);
+}
+
+#[cfg(feature = "full")]
+#[test]
+fn test_contract_update_operations_only_backfill_new_index_when_asked() {
+    let contract =
+        json_document_to_contract("tests/supporting_files/contract/address/address-contract.json")
+            .expect("expected to get contract");
+    let updated_contract = json_document_to_contract(
+        "tests/supporting_files/contract/address/address-contract-name-index.json",
+    )
+    .expect("expected to get contract");
+
+    let update_contract_with_people = |operation: ContractOperationType| {
+        let drive = setup_drive_with_initial_state_structure();
+        drive
+            .apply_contract(
+                &contract,
+                BlockInfo::default(),
+                true,
+                StorageFlags::optional_default_as_cow(),
+                None,
+            )
+            .expect("expected to apply contract successfully");
+
+        let document_type = contract
+            .document_type_for_name("person")
+            .expect("expected to get document type");
+        for document in [
+            person_with_address(2, "Alice", "Paris"),
+            person_with_address(3, "Bob", "Oslo"),
+        ] {
+            drive
+                .add_document_for_contract(
+                    DocumentAndContractInfo {
+                        owned_document_info: OwnedDocumentInfo {
+                            document_info: DocumentRefInfo((
+                                &document,
+                                StorageFlags::optional_default_as_cow(),
+                            )),
+                            owner_id: None,
+                        },
+                        contract: &contract,
+                        document_type,
+                    },
+                    false,
+                    BlockInfo::genesis(),
+                    true,
+                    None,
+                )
+                .expect("document should be inserted");
+        }
+
+        let fee = drive
+            .apply_drive_operations(
+                vec![DriveOperation::ContractOperation(operation)],
+                true,
+                &BlockInfo::default(),
+                None,
+            )
+            .expect("expected to update contract successfully");
+
+        let document_type = updated_contract
+            .document_type_for_name("person")
+            .expect("expected to get document type");
+        let query_value = platform_value!({
+            "where": [["name", "==", "Alice"]],
+        });
+        let query =
+            DriveQuery::from_value(query_value, &updated_contract, document_type, &drive.config)
+                .expect("query should be built");
+        let (results, _, _) = query
+            .execute_raw_results_no_proof(&drive, None, None)
+            .expect("query should be executed");
+        (results.len(), fee)
+    };
+
+    let (alice_count, _) = update_contract_with_people(ContractOperationType::ApplyContract {
+        contract: Cow::Borrowed(&updated_contract),
+        storage_flags: None,
+    });
+    assert_eq!(alice_count, 0);
+
+    let (alice_count, fee) =
+        update_contract_with_people(ContractOperationType::ApplyContractWithIndexBackfill {
+            contract: Cow::Borrowed(&updated_contract),
+            storage_flags: None,
+            validation_processing_fee: 1_000_000,
+        });
+    assert_eq!(alice_count, 1);
+    // the cost of counting the documents during validation is paid with the update
+    assert!(fee.processing_fee > 1_000_000);
+}
+
+#[cfg(feature = "full")]
+#[test]
+fn test_case_insensitive_index_queries() {
+    let drive = setup_drive_with_initial_state_structure();
+
+    let contract = json_document_to_contract(
+        "tests/supporting_files/contract/username/username-contract.json",
+    )
+    .expect("expected to get contract");
+    drive
+        .apply_contract(
+            &contract,
+            BlockInfo::default(),
+            true,
+            StorageFlags::optional_default_as_cow(),
+            None,
+        )
+        .expect("expected to apply contract successfully");
+
+    let document_type = contract
+        .document_type_for_name("profile")
+        .expect("expected to get document type");
+
+    let profile = |id: u8, username: &str| Document {
+        id: Identifier::from([id; 32]),
+        owner_id: Identifier::from([id; 32]),
+        properties: BTreeMap::from([("username".to_string(), Value::Text(username.to_string()))]),
+        revision: Some(1),
+        created_at: None,
+        updated_at: None,
+    };
+
+    for document in [profile(2, "Alice"), profile(3, "bob")] {
+        drive
+            .add_document_for_contract(
+                DocumentAndContractInfo {
+                    owned_document_info: OwnedDocumentInfo {
+                        document_info: DocumentRefInfo((
+                            &document,
+                            StorageFlags::optional_default_as_cow(),
+                        )),
+                        owner_id: None,
+                    },
+                    contract: &contract,
+                    document_type,
+                },
+                false,
+                BlockInfo::genesis(),
+                true,
+                None,
+            )
+            .expect("document should be inserted");
+    }
+
+    let usernames_where = |operator: &str, value: &str| {
+        let query_value = platform_value!({
+            "where": [["username", operator, value]],
+            "orderBy": [["username", "asc"]],
+        });
+        let query = DriveQuery::from_value(query_value, &contract, document_type, &drive.config)
+            .expect("query should be built");
+        let (results, _, _) = query
+            .execute_raw_results_no_proof(&drive, None, None)
+            .expect("query should be executed");
+        results
+            .iter()
+            .map(|serialized| {
+                let document =
+                    Document::from_bytes(serialized, document_type).expect("expected a document");
+                document
+                    .properties
+                    .get("username")
+                    .and_then(|username| username.as_text())
+                    .expect("expected a username")
+                    .to_string()
+            })
+            .collect::<Vec<String>>()
+    };
+
+    // documents keep their original case, only the index keys are normalized
+    assert_eq!(usernames_where("==", "ALICE"), vec!["Alice"]);
+    assert_eq!(usernames_where("==", "alice"), vec!["Alice"]);
+    assert_eq!(usernames_where("startsWith", "B"), vec!["bob"]);
+    assert_eq!(usernames_where(">", "ALICE"), vec!["bob"]);
+
+    let validation_result = drive
+        .validate_document_uniqueness(
+            &contract,
+            document_type,
+            &profile(4, "aLiCe"),
+            &Identifier::from([4; 32]),
+            false,
+            None,
+        )
+        .expect("expected to validate uniqueness");
+    assert!(!validation_result.is_valid());
+
+    let validation_result = drive
+        .validate_document_uniqueness(
+            &contract,
+            document_type,
+            &profile(4, "carol"),
+            &Identifier::from([4; 32]),
+            false,
+            None,
+        )
+        .expect("expected to validate uniqueness");
+    assert!(validation_result.is_valid());
+}
+
+#[cfg(feature = "full")]
+#[test]
+fn test_exact_numeric_field_range_queries() {
+    let drive = setup_drive_with_initial_state_structure();
+
+    let contract =
+        json_document_to_contract("tests/supporting_files/contract/product/product-contract.json")
+            .expect("expected to get contract");
+    drive
+        .apply_contract(
+            &contract,
+            BlockInfo::default(),
+            true,
+            StorageFlags::optional_default_as_cow(),
+            None,
+        )
+        .expect("expected to apply contract successfully");
+
+    let document_type = contract
+        .document_type_for_name("product")
+        .expect("expected to get document type");
+
+    let product = |id: u8, price: &str, supply: &str| Document {
+        id: Identifier::from([id; 32]),
+        owner_id: Identifier::from([1; 32]),
+        properties: BTreeMap::from([
+            ("price".to_string(), Value::Text(price.to_string())),
+            ("supply".to_string(), Value::Text(supply.to_string())),
+        ]),
+        revision: Some(1),
+        created_at: None,
+        updated_at: None,
+    };
+
+    for document in [
+        product(2, "-1.5", "18446744073709551616"),
+        product(3, "10.05", "9"),
+        product(4, "10.5", "340282366920938463463374607431768211455"),
+        product(5, "9.99", "10"),
+    ] {
+        drive
+            .add_document_for_contract(
+                DocumentAndContractInfo {
+                    owned_document_info: OwnedDocumentInfo {
+                        document_info: DocumentRefInfo((
+                            &document,
+                            StorageFlags::optional_default_as_cow(),
+                        )),
+                        owner_id: None,
+                    },
+                    contract: &contract,
+                    document_type,
+                },
+                false,
+                BlockInfo::genesis(),
+                true,
+                None,
+            )
+            .expect("document should be inserted");
+    }
+
+    let values_where = |field: &str, operator: &str, value: &str| {
+        let query_value = platform_value!({
+            "where": [[field, operator, value]],
+            "orderBy": [[field, "asc"]],
+        });
+        let query = DriveQuery::from_value(query_value, &contract, document_type, &drive.config)
+            .expect("query should be built");
+        let (results, _, _) = query
+            .execute_raw_results_no_proof(&drive, None, None)
+            .expect("query should be executed");
+        results
+            .iter()
+            .map(|serialized| {
+                let document =
+                    Document::from_bytes(serialized, document_type).expect("expected a document");
+                document
+                    .properties
+                    .get(field)
+                    .and_then(|value| value.as_text())
+                    .expect("expected a value")
+                    .to_string()
+            })
+            .collect::<Vec<String>>()
+    };
+
+    // decimals are ordered by value and read back with all the digits of their scale
+    assert_eq!(
+        values_where("price", ">", "-2"),
+        vec!["-1.50", "9.99", "10.05", "10.50"]
+    );
+    assert_eq!(values_where("price", ">=", "10.1"), vec!["10.50"]);
+    assert_eq!(values_where("price", "==", "10.50"), vec!["10.50"]);
+
+    assert_eq!(
+        values_where("supply", ">", "9"),
+        vec![
+            "10",
+            "18446744073709551616",
+            "340282366920938463463374607431768211455"
+        ]
+    );
+}
+
 #[cfg(feature = "full")]
 #[test]
 #[ignore]
@@ -3,6 +3,34 @@ use crate::{Error, Value, ValueMapHelper};
 use ciborium::value::Integer;
 use ciborium::Value as CborValue;
 
+/// CBOR tag of an unsigned bignum, RFC 8949 section 3.4.3
+const POSITIVE_BIGNUM_TAG: u64 = 2;
+/// CBOR tag of a negative bignum, RFC 8949 section 3.4.3
+const NEGATIVE_BIGNUM_TAG: u64 = 3;
+
+fn bignum_bytes(value: u128) -> Vec<u8> {
+    let bytes = value.to_be_bytes();
+    let first_significant = bytes
+        .iter()
+        .position(|byte| *byte != 0)
+        .unwrap_or(bytes.len());
+    bytes[first_significant..].to_vec()
+}
+
+fn bignum_from_bytes(bytes: &[u8]) -> Result<u128, Error> {
+    let first_significant = bytes
+        .iter()
+        .position(|byte| *byte != 0)
+        .unwrap_or(bytes.len());
+    let significant = &bytes[first_significant..];
+    if significant.len() > 16 {
+        return Err(Error::IntegerSizeError);
+    }
+    let mut buffer = [0u8; 16];
+    buffer[16 - significant.len()..].copy_from_slice(significant);
+    Ok(u128::from_be_bytes(buffer))
+}
+
 impl Value {
     pub fn convert_from_cbor_map<I, R>(map: I) -> Result<R, Error>
     where
@@ -36,18 +64,39 @@ impl TryFrom<CborValue> for Value {
             CborValue::Text(string) => Self::Text(string),
             CborValue::Bool(value) => Self::Bool(value),
             CborValue::Null => Self::Null,
-            CborValue::Tag(_, _) => {
-                return Err(Error::Unsupported(
-                    "conversion from cbor tags are currently not supported".to_string(),
-                ))
+            CborValue::Tag(POSITIVE_BIGNUM_TAG, inner) => match *inner {
+                CborValue::Bytes(bytes) => Self::U128(bignum_from_bytes(&bytes)?),
+                _ => {
+                    return Err(Error::StructureError(
+                        "cbor bignum tag must hold a byte string".to_string(),
+                    ))
+                }
+            },
+            CborValue::Tag(NEGATIVE_BIGNUM_TAG, inner) => match *inner {
+                CborValue::Bytes(bytes) => {
+                    let magnitude = i128::try_from(bignum_from_bytes(&bytes)?)
+                        .map_err(|_| Error::IntegerSizeError)?;
+                    Self::I128(-1 - magnitude)
+                }
+                _ => {
+                    return Err(Error::StructureError(
+                        "cbor bignum tag must hold a byte string".to_string(),
+                    ))
+                }
+            },
+            CborValue::Tag(tag, _) => {
+                return Err(Error::Unsupported(format!(
+                    "conversion from cbor tag {} is not supported",
+                    tag
+                )))
             }
             CborValue::Array(array) => {
                 let len = array.len();
                 if len > 10
                     && array.iter().all(|v| {
                         let Some(int) = v.as_integer() else {
-                        return false;
-                    };
+                            return false;
+                        };
                         int.le(&Integer::from(u8::MAX)) && int.ge(&Integer::from(0))
                     })
                 {
@@ -72,7 +121,11 @@ impl TryFrom<CborValue> for Value {
                     .map(|(k, v)| Ok((k.try_into()?, v.try_into()?)))
                     .collect::<Result<ValueMap, Error>>()?,
             ),
-            _ => panic!("unsupported"),
+            _ => {
+                return Err(Error::Unsupported(
+                    "conversion from this cbor value is not supported".to_string(),
+                ))
+            }
         })
     }
 }
@@ -88,8 +141,24 @@ impl TryInto<CborValue> for Value {
 
     fn try_into(self) -> Result<CborValue, Self::Error> {
         Ok(match self {
-            Value::U128(i) => CborValue::Integer((i as u64).into()),
-            Value::I128(i) => CborValue::Integer((i as i64).into()),
+            Value::U128(i) => match Integer::try_from(i) {
+                Ok(integer) => CborValue::Integer(integer),
+                Err(_) => CborValue::Tag(
+                    POSITIVE_BIGNUM_TAG,
+                    Box::new(CborValue::Bytes(bignum_bytes(i))),
+                ),
+            },
+            Value::I128(i) => match Integer::try_from(i) {
+                Ok(integer) => CborValue::Integer(integer),
+                Err(_) if i < 0 => CborValue::Tag(
+                    NEGATIVE_BIGNUM_TAG,
+                    Box::new(CborValue::Bytes(bignum_bytes((-1 - i) as u128))),
+                ),
+                Err(_) => CborValue::Tag(
+                    POSITIVE_BIGNUM_TAG,
+                    Box::new(CborValue::Bytes(bignum_bytes(i as u128))),
+                ),
+            },
             Value::U64(i) => CborValue::Integer(i.into()),
             Value::I64(i) => CborValue::Integer(i.into()),
             Value::U32(i) => CborValue::Integer(i.into()),
@@ -121,8 +190,15 @@ impl TryInto<CborValue> for Value {
                 )
             }
             Value::Identifier(bytes) => CborValue::Bytes(bytes.to_vec()),
-            Value::EnumU8(_) => todo!(),
-            Value::EnumString(_) => todo!(),
+            Value::EnumU8(values) => CborValue::Array(
+                values
+                    .into_iter()
+                    .map(|value| CborValue::Integer(value.into()))
+                    .collect(),
+            ),
+            Value::EnumString(values) => {
+                CborValue::Array(values.into_iter().map(CborValue::Text).collect())
+            }
         })
     }
 }
@@ -1,2 +1,372 @@
 pub mod ciborium;
 pub mod serde_json;
+
+#[cfg(test)]
+mod tests {
+    use crate::{from_value, to_value, Value, ValueMapHelper};
+    use ciborium::Value as CborValue;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+    use serde_json::Value as JsonValue;
+
+    const ITERATIONS: usize = 500;
+
+    fn random_string(rng: &mut StdRng) -> String {
+        let len = rng.gen_range(0..12);
+        (0..len).map(|_| rng.gen_range('a'..='z')).collect()
+    }
+
+    fn random_u128(rng: &mut StdRng) -> u128 {
+        match rng.gen_range(0..4) {
+            0 => rng.gen::<u64>() as u128,
+            1 => u128::MAX,
+            _ => rng.gen(),
+        }
+    }
+
+    fn random_i128(rng: &mut StdRng) -> i128 {
+        match rng.gen_range(0..5) {
+            0 => rng.gen::<i64>() as i128,
+            1 => i128::MIN,
+            2 => i128::MAX,
+            _ => rng.gen(),
+        }
+    }
+
+    /// Generates a value of any variant, maps keyed by text so every format can hold them
+    fn random_value(rng: &mut StdRng, depth: u32) -> Value {
+        let variants = if depth == 0 { 20 } else { 22 };
+        match rng.gen_range(0..variants) {
+            0 => Value::U128(random_u128(rng)),
+            1 => Value::I128(random_i128(rng)),
+            2 => Value::U64(rng.gen()),
+            3 => Value::I64(rng.gen()),
+            4 => Value::U32(rng.gen()),
+            5 => Value::I32(rng.gen()),
+            6 => Value::U16(rng.gen()),
+            7 => Value::I16(rng.gen()),
+            8 => Value::U8(rng.gen()),
+            9 => Value::I8(rng.gen()),
+            10 => Value::Bytes((0..rng.gen_range(0..40)).map(|_| rng.gen()).collect()),
+            11 => Value::Bytes20(rng.gen()),
+            12 => Value::Bytes32(rng.gen()),
+            13 => Value::Bytes36([rng.gen::<u8>(); 36]),
+            14 => Value::Identifier(rng.gen()),
+            15 => Value::Float(rng.gen_range(-1e12..1e12)),
+            16 => Value::Text(random_string(rng)),
+            17 => Value::Bool(rng.gen()),
+            18 => Value::EnumU8((0..rng.gen_range(0..16)).map(|_| rng.gen()).collect()),
+            19 => {
+                if rng.gen() {
+                    Value::Null
+                } else {
+                    Value::EnumString(
+                        (0..rng.gen_range(0..5))
+                            .map(|_| random_string(rng))
+                            .collect(),
+                    )
+                }
+            }
+            20 => Value::Array(
+                (0..rng.gen_range(0..5))
+                    .map(|_| random_value(rng, depth - 1))
+                    .collect(),
+            ),
+            _ => Value::Map(
+                (0..rng.gen_range(0..5))
+                    .map(|i| {
+                        (
+                            Value::Text(format!("{}{}", random_string(rng), i)),
+                            random_value(rng, depth - 1),
+                        )
+                    })
+                    .collect(),
+            ),
+        }
+    }
+
+    fn random_values() -> impl Iterator<Item = Value> {
+        let mut rng = StdRng::seed_from_u64(37);
+        (0..ITERATIONS).map(move |_| random_value(&mut rng, 3))
+    }
+
+    /// The value `value` reads back as once converted to json, which has no bytes and only 64 bit
+    /// integers
+    fn read_back_from_json(value: Value) -> Value {
+        match value {
+            Value::U128(i) => u64::try_from(i)
+                .map(Value::U64)
+                .unwrap_or_else(|_| Value::Text(i.to_string())),
+            Value::I128(i) => match i64::try_from(i) {
+                Ok(i) => read_back_from_json(Value::I64(i)),
+                Err(_) => Value::Text(i.to_string()),
+            },
+            Value::I64(i) => u64::try_from(i).map(Value::U64).unwrap_or(Value::I64(i)),
+            Value::I32(i) => read_back_from_json(Value::I64(i as i64)),
+            Value::I16(i) => read_back_from_json(Value::I64(i as i64)),
+            Value::I8(i) => read_back_from_json(Value::I64(i as i64)),
+            Value::U32(i) => Value::U64(i as u64),
+            Value::U16(i) => Value::U64(i as u64),
+            Value::U8(i) => Value::U64(i as u64),
+            Value::Bytes(bytes) => Value::Text(base64::encode(bytes)),
+            Value::Bytes20(bytes) => Value::Text(base64::encode(bytes)),
+            Value::Bytes32(bytes) => Value::Text(base64::encode(bytes)),
+            Value::Bytes36(bytes) => Value::Text(base64::encode(bytes)),
+            Value::Identifier(bytes) => Value::Text(bs58::encode(bytes).into_string()),
+            Value::EnumU8(values) => {
+                read_back_from_json(Value::Array(values.into_iter().map(Value::U8).collect()))
+            }
+            Value::EnumString(values) => {
+                Value::Array(values.into_iter().map(Value::Text).collect())
+            }
+            Value::Array(array) => {
+                let array: Vec<Value> = array.into_iter().map(read_back_from_json).collect();
+                // long arrays of small integers are read as bytes
+                if array.len() >= 10
+                    && array
+                        .iter()
+                        .all(|value| matches!(value, Value::U64(i) if *i <= u8::MAX as u64))
+                {
+                    Value::Bytes(
+                        array
+                            .iter()
+                            .map(|value| value.to_integer().unwrap())
+                            .collect(),
+                    )
+                } else {
+                    Value::Array(array)
+                }
+            }
+            Value::Map(map) => Value::Map(
+                map.into_iter()
+                    .map(|(key, value)| (key, read_back_from_json(value)))
+                    .collect(),
+            ),
+            value => value,
+        }
+    }
+
+    /// The value `value` reads back as once converted to cbor, which keeps integers as `I128`
+    /// unless they need a bignum, bytes without their size and maps sorted by their keys
+    fn read_back_from_cbor(value: Value) -> Value {
+        match value {
+            Value::U128(i) if i > u64::MAX as u128 => Value::U128(i),
+            Value::I128(i) if i > u64::MAX as i128 => Value::U128(i as u128),
+            Value::U128(_)
+            | Value::I128(_)
+            | Value::U64(_)
+            | Value::I64(_)
+            | Value::U32(_)
+            | Value::I32(_)
+            | Value::U16(_)
+            | Value::I16(_)
+            | Value::U8(_)
+            | Value::I8(_) => Value::I128(value.to_integer().unwrap()),
+            Value::Bytes20(bytes) => Value::Bytes(bytes.to_vec()),
+            Value::Bytes32(bytes) => Value::Bytes(bytes.to_vec()),
+            Value::Bytes36(bytes) => Value::Bytes(bytes.to_vec()),
+            Value::Identifier(bytes) => Value::Bytes(bytes.to_vec()),
+            Value::EnumU8(values) => {
+                read_back_from_cbor(Value::Array(values.into_iter().map(Value::U8).collect()))
+            }
+            Value::EnumString(values) => {
+                Value::Array(values.into_iter().map(Value::Text).collect())
+            }
+            Value::Array(array) => {
+                let array: Vec<Value> = array.into_iter().map(read_back_from_cbor).collect();
+                // long arrays of small integers are read as bytes
+                if array.len() > 10
+                    && array
+                        .iter()
+                        .all(|value| matches!(value, Value::I128(i) if (0..=255).contains(i)))
+                {
+                    Value::Bytes(
+                        array
+                            .iter()
+                            .map(|value| value.to_integer().unwrap())
+                            .collect(),
+                    )
+                } else {
+                    Value::Array(array)
+                }
+            }
+            Value::Map(mut map) => {
+                map.sort_by_keys();
+                Value::Map(
+                    map.into_iter()
+                        .map(|(key, value)| (key, read_back_from_cbor(value)))
+                        .collect(),
+                )
+            }
+            value => value,
+        }
+    }
+
+    /// The value `value` reads back as once serialized with `to_value`, bytes lose their size
+    /// and enumerations become arrays
+    fn read_back_from_serde(value: Value) -> Value {
+        match value {
+            Value::Bytes20(bytes) => Value::Bytes(bytes.to_vec()),
+            Value::Bytes32(bytes) => Value::Bytes(bytes.to_vec()),
+            Value::Bytes36(bytes) => Value::Bytes(bytes.to_vec()),
+            Value::Identifier(bytes) => Value::Bytes(bytes.to_vec()),
+            Value::EnumU8(values) => Value::Array(values.into_iter().map(Value::U8).collect()),
+            Value::EnumString(values) => {
+                Value::Array(values.into_iter().map(Value::Text).collect())
+            }
+            Value::Array(array) => {
+                Value::Array(array.into_iter().map(read_back_from_serde).collect())
+            }
+            Value::Map(map) => Value::Map(
+                map.into_iter()
+                    .map(|(key, value)| (key, read_back_from_serde(value)))
+                    .collect(),
+            ),
+            value => value,
+        }
+    }
+
+    #[test]
+    fn every_value_should_round_trip_through_json() {
+        for value in random_values() {
+            let json: JsonValue = value
+                .clone()
+                .try_into()
+                .unwrap_or_else(|e| panic!("{value:?} should convert to json: {e}"));
+            let read_back = Value::from(json);
+            assert_eq!(read_back, read_back_from_json(value.clone()), "{value:?}");
+            value
+                .try_to_validating_json()
+                .unwrap_or_else(|e| panic!("{value:?} should convert to validating json: {e}"));
+        }
+    }
+
+    #[test]
+    fn every_value_should_round_trip_through_cbor() {
+        for value in random_values() {
+            let cbor: CborValue = value
+                .clone()
+                .try_into()
+                .unwrap_or_else(|e| panic!("{value:?} should convert to cbor: {e}"));
+            let read_back = Value::try_from(cbor).expect("cbor should decode");
+            assert_eq!(read_back, read_back_from_cbor(value.clone()), "{value:?}");
+        }
+    }
+
+    #[test]
+    fn every_value_should_round_trip_through_serde() {
+        for value in random_values() {
+            let serialized = to_value(&value).expect("value should serialize");
+            let deserialized: Value = from_value(serialized).expect("should deserialize");
+            assert_eq!(
+                deserialized,
+                read_back_from_serde(value.clone()),
+                "{value:?}"
+            );
+            serde_json::to_value(&value)
+                .unwrap_or_else(|e| panic!("{value:?} should serialize to json: {e}"));
+        }
+    }
+
+    #[test]
+    fn every_value_should_display() {
+        for value in random_values() {
+            assert!(!value.to_string().is_empty());
+        }
+    }
+
+    #[test]
+    fn big_integers_should_keep_their_value_in_json() {
+        let json: JsonValue = Value::U128(u128::MAX).try_into().unwrap();
+        assert_eq!(json, JsonValue::String(u128::MAX.to_string()));
+
+        let json: JsonValue = Value::I128(i128::MIN).try_into().unwrap();
+        assert_eq!(json, JsonValue::String(i128::MIN.to_string()));
+
+        let json: JsonValue = Value::U128(42).try_into().unwrap();
+        assert_eq!(json, JsonValue::from(42));
+
+        assert_eq!(
+            serde_json::to_value(Value::I128(i128::MAX)).unwrap(),
+            JsonValue::String(i128::MAX.to_string())
+        );
+
+        assert_eq!(
+            Value::U128(u128::MAX).try_to_validating_json().unwrap(),
+            JsonValue::String(u128::MAX.to_string())
+        );
+        assert_eq!(
+            Value::I128(i128::MIN).try_into_validating_json().unwrap(),
+            JsonValue::String(i128::MIN.to_string())
+        );
+        assert_eq!(
+            Value::I128(-42).try_into_validating_json().unwrap(),
+            JsonValue::from(-42)
+        );
+    }
+
+    #[test]
+    fn big_integers_should_keep_their_value_in_cbor() {
+        for value in [
+            Value::U128(u128::MAX),
+            Value::U128(u64::MAX as u128 + 1),
+            Value::I128(i128::MIN),
+            Value::I128(-(u64::MAX as i128) - 2),
+        ] {
+            let cbor: CborValue = value.clone().try_into().unwrap();
+            assert!(matches!(cbor, CborValue::Tag(2 | 3, _)));
+            assert_eq!(Value::try_from(cbor).unwrap(), value);
+        }
+
+        let cbor: CborValue = Value::I128(i128::MAX).try_into().unwrap();
+        assert_eq!(
+            Value::try_from(cbor).unwrap(),
+            Value::U128(i128::MAX as u128)
+        );
+    }
+
+    #[test]
+    fn enums_should_convert_to_arrays() {
+        let json: JsonValue = Value::EnumU8(vec![1, 2]).try_into().unwrap();
+        assert_eq!(json, serde_json::json!([1, 2]));
+
+        let json = Value::EnumString(vec!["a".to_string(), "b".to_string()])
+            .try_into_validating_json()
+            .unwrap();
+        assert_eq!(json, serde_json::json!(["a", "b"]));
+
+        let cbor: CborValue = Value::EnumU8(vec![3]).try_into().unwrap();
+        assert_eq!(cbor, CborValue::Array(vec![CborValue::Integer(3.into())]));
+
+        assert_eq!(
+            to_value(Value::EnumString(vec!["a".to_string()])).unwrap(),
+            Value::Array(vec![Value::Text("a".to_string())])
+        );
+    }
+
+    #[test]
+    fn rust_enums_should_deserialize_from_values() {
+        #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+        enum Shape {
+            Point,
+            Circle(u32),
+            Segment(u8, u8),
+            Rectangle { width: u32, height: u32 },
+        }
+
+        for shape in [
+            Shape::Point,
+            Shape::Circle(3),
+            Shape::Segment(1, 2),
+            Shape::Rectangle {
+                width: 4,
+                height: 5,
+            },
+        ] {
+            let value = to_value(&shape).unwrap();
+            assert_eq!(from_value::<Shape>(value).unwrap(), shape);
+        }
+
+        assert!(from_value::<Shape>(Value::U8(1)).is_err());
+    }
+}
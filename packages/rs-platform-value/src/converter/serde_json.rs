@@ -16,21 +16,14 @@ impl Value {
 
     pub fn try_into_validating_json(self) -> Result<JsonValue, Error> {
         Ok(match self {
-            Value::U128(i) => {
-                if i > u64::MAX as u128 {
-                    return Err(Error::IntegerSizeError);
-                }
-                JsonValue::Number((i as u64).into())
-            }
-            Value::I128(i) => {
-                if i > i64::MAX as i128 {
-                    return Err(Error::IntegerSizeError);
-                }
-                if i < i64::MIN as i128 {
-                    return Err(Error::IntegerSizeError);
-                }
-                JsonValue::Number((i as i64).into())
-            }
+            Value::U128(i) => match u64::try_from(i) {
+                Ok(i) => JsonValue::Number(i.into()),
+                Err(_) => JsonValue::String(i.to_string()),
+            },
+            Value::I128(i) => match i64::try_from(i) {
+                Ok(i) => JsonValue::Number(i.into()),
+                Err(_) => JsonValue::String(i.to_string()),
+            },
             Value::U64(i) => JsonValue::Number(i.into()),
             Value::I64(i) => JsonValue::Number(i.into()),
             Value::U32(i) => JsonValue::Number(i.into()),
@@ -90,8 +83,15 @@ impl Value {
                     .map(|byte| JsonValue::Number(byte.into()))
                     .collect(),
             ),
-            Value::EnumU8(_) => todo!(),
-            Value::EnumString(_) => todo!(),
+            Value::EnumU8(values) => JsonValue::Array(
+                values
+                    .into_iter()
+                    .map(|value| JsonValue::Number(value.into()))
+                    .collect(),
+            ),
+            Value::EnumString(values) => {
+                JsonValue::Array(values.into_iter().map(JsonValue::String).collect())
+            }
         })
     }
 
@@ -104,21 +104,14 @@ impl Value {
 
     pub fn try_to_validating_json(&self) -> Result<JsonValue, Error> {
         Ok(match self {
-            Value::U128(i) => {
-                if *i > u64::MAX as u128 {
-                    return Err(Error::IntegerSizeError);
-                }
-                JsonValue::Number((*i as u64).into())
-            }
-            Value::I128(i) => {
-                if *i > i64::MAX as i128 {
-                    return Err(Error::IntegerSizeError);
-                }
-                if *i < i64::MIN as i128 {
-                    return Err(Error::IntegerSizeError);
-                }
-                JsonValue::Number((*i as i64).into())
-            }
+            Value::U128(i) => match u64::try_from(*i) {
+                Ok(i) => JsonValue::Number(i.into()),
+                Err(_) => JsonValue::String(i.to_string()),
+            },
+            Value::I128(i) => match i64::try_from(*i) {
+                Ok(i) => JsonValue::Number(i.into()),
+                Err(_) => JsonValue::String(i.to_string()),
+            },
             Value::U64(i) => JsonValue::Number((*i).into()),
             Value::I64(i) => JsonValue::Number((*i).into()),
             Value::U32(i) => JsonValue::Number((*i).into()),
@@ -178,8 +171,18 @@ impl Value {
                     .map(|byte| JsonValue::Number((*byte).into()))
                     .collect(),
             ),
-            Value::EnumU8(_) => todo!(),
-            Value::EnumString(_) => todo!(),
+            Value::EnumU8(values) => JsonValue::Array(
+                values
+                    .iter()
+                    .map(|value| JsonValue::Number((*value).into()))
+                    .collect(),
+            ),
+            Value::EnumString(values) => JsonValue::Array(
+                values
+                    .iter()
+                    .map(|value| JsonValue::String(value.clone()))
+                    .collect(),
+            ),
         })
     }
 }
@@ -207,8 +210,8 @@ impl From<JsonValue> for Value {
                 if len >= 10
                     && array.iter().all(|v| {
                         let Some(int) = v.as_u64() else {
-                        return false;
-                    };
+                            return false;
+                        };
                         int.le(&u8_max)
                     })
                 {
@@ -253,8 +256,8 @@ impl From<&JsonValue> for Value {
                 if len >= 10
                     && array.iter().all(|v| {
                         let Some(int) = v.as_u64() else {
-                        return false;
-                    };
+                            return false;
+                        };
                         int.le(&u8_max)
                     })
                 {
@@ -284,8 +287,14 @@ impl TryInto<JsonValue> for Value {
 
     fn try_into(self) -> Result<JsonValue, Self::Error> {
         Ok(match self {
-            Value::U128(i) => JsonValue::Number((i as u64).into()),
-            Value::I128(i) => JsonValue::Number((i as i64).into()),
+            Value::U128(i) => match u64::try_from(i) {
+                Ok(i) => JsonValue::Number(i.into()),
+                Err(_) => JsonValue::String(i.to_string()),
+            },
+            Value::I128(i) => match i64::try_from(i) {
+                Ok(i) => JsonValue::Number(i.into()),
+                Err(_) => JsonValue::String(i.to_string()),
+            },
             Value::U64(i) => JsonValue::Number(i.into()),
             Value::I64(i) => JsonValue::Number(i.into()),
             Value::U32(i) => JsonValue::Number(i.into()),
@@ -319,8 +328,15 @@ impl TryInto<JsonValue> for Value {
             Value::Identifier(bytes) => {
                 JsonValue::String(bs58::encode(bytes.as_slice()).into_string())
             }
-            Value::EnumU8(_) => todo!(),
-            Value::EnumString(_) => todo!(),
+            Value::EnumU8(values) => JsonValue::Array(
+                values
+                    .into_iter()
+                    .map(|value| JsonValue::Number(value.into()))
+                    .collect(),
+            ),
+            Value::EnumString(values) => {
+                JsonValue::Array(values.into_iter().map(JsonValue::String).collect())
+            }
         })
     }
 }
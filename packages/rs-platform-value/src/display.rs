@@ -52,8 +52,8 @@ impl Value {
                 "identifier {}",
                 bs58::encode(identifier.as_slice()).into_string()
             ),
-            Value::EnumU8(_) => todo!(),
-            Value::EnumString(_) => todo!(),
+            Value::EnumU8(values) => format!("enum u8 {:?}", values),
+            Value::EnumString(values) => format!("enum string {:?}", values),
         }
     }
 
@@ -109,8 +109,8 @@ impl Value {
                 "identifier {}",
                 bs58::encode(identifier.as_slice()).into_string()
             ),
-            Value::EnumU8(_) => todo!(),
-            Value::EnumString(_) => todo!(),
+            Value::EnumU8(values) => format!("enum u8 {:?}", values),
+            Value::EnumString(values) => format!("enum string {:?}", values),
         }
     }
 }
@@ -20,6 +20,7 @@ mod macros;
 pub mod patch;
 mod pointer;
 mod replace;
+pub mod schema;
 pub mod string_encoding;
 pub mod system_bytes;
 mod types;
@@ -51,9 +52,13 @@ pub use patch::{patch, Patch};
 #[derive(Clone, Debug, PartialEq, PartialOrd, Encode, Decode)]
 pub enum Value {
     /// A u128 integer
+    /// In JSON it is a number when it fits a u64 and a string of its decimal digits otherwise,
+    /// as JSON numbers can't hold it exactly. CBOR uses a bignum tag for the same values
     U128(u128),
 
     /// A i128 integer
+    /// In JSON it is a number when it fits an i64 and a string of its decimal digits otherwise,
+    /// as JSON numbers can't hold it exactly. CBOR uses a bignum tag for the same values
     I128(i128),
 
     /// A u64 integer
@@ -93,9 +98,11 @@ pub enum Value {
     Bytes36([u8; 36]),
 
     /// An enumeration of u8
+    /// It is converted to an array of integers, in JSON and CBOR alike
     EnumU8(Vec<u8>),
 
     /// An enumeration of strings
+    /// It is converted to an array of strings, in JSON and CBOR alike
     EnumString(Vec<String>),
 
     /// Identifier
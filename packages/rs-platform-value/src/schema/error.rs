@@ -0,0 +1,51 @@
+use crate::Value;
+
+/// A schema violation found while validating a value
+///
+/// The fields follow the error reports of JSON schema validators, so the consensus errors built
+/// from them don't depend on which validator found the violation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SchemaValidationError {
+    /// The schema keyword that failed, e.g. `required` or `maxItems`
+    pub keyword: String,
+    /// JSON pointer to the invalid part of the value
+    pub instance_path: String,
+    /// JSON pointer to the failed keyword in the schema
+    pub schema_path: String,
+    /// Details of the failure, like the limit of a `maxItems` keyword
+    pub params: Vec<(String, Value)>,
+    /// Name of the missing property of a `required` failure
+    pub property_name: String,
+}
+
+impl SchemaValidationError {
+    pub(super) fn new(keyword: impl Into<String>) -> Self {
+        Self {
+            keyword: keyword.into(),
+            instance_path: String::new(),
+            schema_path: String::new(),
+            params: vec![],
+            property_name: String::new(),
+        }
+    }
+
+    pub(super) fn with_param(mut self, name: &str, value: impl Into<Value>) -> Self {
+        self.params.push((name.to_string(), value.into()));
+        self
+    }
+
+    pub(super) fn with_property_name(mut self, property_name: impl Into<String>) -> Self {
+        self.property_name = property_name.into();
+        self
+    }
+
+    /// Returns the params as a map value
+    pub fn params_value(&self) -> Value {
+        Value::Map(
+            self.params
+                .iter()
+                .map(|(name, value)| (Value::Text(name.clone()), value.clone()))
+                .collect(),
+        )
+    }
+}
@@ -0,0 +1,87 @@
+//! Checks of the `format` keyword
+//!
+//! Unknown formats are annotations only, as in JSON schema validators ignoring unknown formats.
+
+use std::net::{Ipv4Addr, Ipv6Addr};
+use std::str::FromStr;
+
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use super::keywords::ecma_pattern;
+
+lazy_static! {
+    static ref DATE_RE: Regex = Regex::new(r"^\d{4}-\d{2}-\d{2}$").unwrap();
+    static ref TIME_RE: Regex =
+        Regex::new(r"^(?i)\d{2}:\d{2}:\d{2}(\.\d+)?(z|[+-]\d{2}:\d{2})$").unwrap();
+    static ref EMAIL_RE: Regex = Regex::new(r"^[^@\s]+@[^@\s]+$").unwrap();
+    static ref HOSTNAME_LABEL_RE: Regex =
+        Regex::new(r"^(?i)[a-z0-9]([a-z0-9-]{0,61}[a-z0-9])?$").unwrap();
+    static ref URI_RE: Regex = Regex::new(r"^(?i)[a-z][a-z0-9+.-]*:[^\s]*$").unwrap();
+    static ref URI_REFERENCE_RE: Regex = Regex::new(r"^[^\s]*$").unwrap();
+    static ref UUID_RE: Regex =
+        Regex::new(r"^(?i)[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}$").unwrap();
+    static ref JSON_POINTER_RE: Regex = Regex::new(r"^(/([^/~]|~[01])*)*$").unwrap();
+}
+
+/// Returns whether the string has the format, or None for formats that aren't checked
+pub(super) fn is_valid_format(format: &str, string: &str) -> Option<bool> {
+    Some(match format {
+        "date" => is_date(string),
+        "time" => TIME_RE.is_match(string) && is_time(string),
+        "date-time" => match string.find(|c| c == 'T' || c == 't') {
+            Some(position) => {
+                is_date(&string[..position])
+                    && TIME_RE.is_match(&string[position + 1..])
+                    && is_time(&string[position + 1..])
+            }
+            None => false,
+        },
+        "email" => EMAIL_RE.is_match(string),
+        "hostname" => is_hostname(string),
+        "ipv4" => Ipv4Addr::from_str(string).is_ok(),
+        "ipv6" => Ipv6Addr::from_str(string).is_ok(),
+        "uri" => URI_RE.is_match(string),
+        "uri-reference" => URI_REFERENCE_RE.is_match(string),
+        "uuid" => UUID_RE.is_match(string),
+        "regex" => fancy_regex::Regex::new(&ecma_pattern(string)).is_ok(),
+        "json-pointer" => JSON_POINTER_RE.is_match(string),
+        _ => return None,
+    })
+}
+
+fn is_date(string: &str) -> bool {
+    if !DATE_RE.is_match(string) {
+        return false;
+    }
+    let year: u32 = string[0..4].parse().unwrap_or_default();
+    let month: u32 = string[5..7].parse().unwrap_or_default();
+    let day: u32 = string[8..10].parse().unwrap_or_default();
+    let leap_year = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+    let days_in_month = match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if leap_year => 29,
+        2 => 28,
+        _ => return false,
+    };
+    (1..=days_in_month).contains(&day)
+}
+
+/// Checks the ranges of a time already matching the time pattern
+fn is_time(string: &str) -> bool {
+    let hours: u32 = string[0..2].parse().unwrap_or(u32::MAX);
+    let minutes: u32 = string[3..5].parse().unwrap_or(u32::MAX);
+    // leap seconds are allowed
+    let seconds: u32 = string[6..8].parse().unwrap_or(u32::MAX);
+    hours < 24 && minutes < 60 && seconds <= 60
+}
+
+fn is_hostname(string: &str) -> bool {
+    let hostname = string.strip_suffix('.').unwrap_or(string);
+    !hostname.is_empty()
+        && hostname.len() <= 253
+        && hostname
+            .split('.')
+            .all(|label| HOSTNAME_LABEL_RE.is_match(label))
+}
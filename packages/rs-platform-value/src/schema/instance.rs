@@ -0,0 +1,225 @@
+//! How schema keywords see platform values
+//!
+//! Values are looked at as their validating JSON conversion would show them, without doing that
+//! conversion: byte arrays and identifiers are arrays of integers, enumerations are arrays and
+//! numbers compare by value whatever their width.
+
+use std::borrow::Cow;
+use std::cmp::Ordering;
+
+use crate::Value;
+
+/// JSON schema primitive types
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum PrimitiveType {
+    Array,
+    Boolean,
+    Integer,
+    Null,
+    Number,
+    Object,
+    String,
+}
+
+impl PrimitiveType {
+    pub(super) fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "array" => Self::Array,
+            "boolean" => Self::Boolean,
+            "integer" => Self::Integer,
+            "null" => Self::Null,
+            "number" => Self::Number,
+            "object" => Self::Object,
+            "string" => Self::String,
+            _ => return None,
+        })
+    }
+
+    pub(super) fn name(&self) -> &'static str {
+        match self {
+            Self::Array => "array",
+            Self::Boolean => "boolean",
+            Self::Integer => "integer",
+            Self::Null => "null",
+            Self::Number => "number",
+            Self::Object => "object",
+            Self::String => "string",
+        }
+    }
+
+    pub(super) fn matches(&self, value: &Value) -> bool {
+        match self {
+            Self::Array => is_array(value),
+            Self::Boolean => matches!(value, Value::Bool(_)),
+            Self::Integer => match number(value) {
+                Some(Number::Float(float)) => float.fract() == 0.0,
+                Some(_) => true,
+                None => false,
+            },
+            Self::Null => value.is_null(),
+            Self::Number => number(value).is_some(),
+            Self::Object => matches!(value, Value::Map(_)),
+            Self::String => matches!(value, Value::Text(_)),
+        }
+    }
+}
+
+/// A number, whatever the width of the value holding it
+#[derive(Debug, Clone, Copy)]
+pub(super) enum Number {
+    Integer(i128),
+    /// Unsigned integers too big for an i128
+    BigUnsigned(u128),
+    Float(f64),
+}
+
+impl Number {
+    pub(super) fn from_json(number: &serde_json::Number) -> Option<Self> {
+        if let Some(integer) = number.as_i64() {
+            Some(Self::Integer(integer as i128))
+        } else if let Some(integer) = number.as_u64() {
+            Some(Self::Integer(integer as i128))
+        } else {
+            number.as_f64().map(Self::Float)
+        }
+    }
+
+    pub(super) fn as_f64(&self) -> f64 {
+        match self {
+            Self::Integer(integer) => *integer as f64,
+            Self::BigUnsigned(integer) => *integer as f64,
+            Self::Float(float) => *float,
+        }
+    }
+
+    pub(super) fn compare(&self, other: &Number) -> Option<Ordering> {
+        match (self, other) {
+            (Self::Integer(a), Self::Integer(b)) => Some(a.cmp(b)),
+            (Self::BigUnsigned(a), Self::BigUnsigned(b)) => Some(a.cmp(b)),
+            (Self::BigUnsigned(_), Self::Integer(_)) => Some(Ordering::Greater),
+            (Self::Integer(_), Self::BigUnsigned(_)) => Some(Ordering::Less),
+            (a, b) => a.as_f64().partial_cmp(&b.as_f64()),
+        }
+    }
+
+    pub(super) fn is_multiple_of(&self, divisor: &Number) -> bool {
+        match (self, divisor) {
+            (Self::Integer(value), Self::Integer(divisor)) if *divisor != 0 => value % divisor == 0,
+            (Self::BigUnsigned(value), Self::Integer(divisor)) if *divisor > 0 => {
+                value % (*divisor as u128) == 0
+            }
+            (value, divisor) => {
+                let quotient = value.as_f64() / divisor.as_f64();
+                quotient.is_finite() && (quotient - quotient.round()).abs() < f64::EPSILON
+            }
+        }
+    }
+}
+
+pub(super) fn number(value: &Value) -> Option<Number> {
+    Some(match value {
+        Value::U128(integer) => match i128::try_from(*integer) {
+            Ok(integer) => Number::Integer(integer),
+            Err(_) => Number::BigUnsigned(*integer),
+        },
+        Value::I128(integer) => Number::Integer(*integer),
+        Value::U64(integer) => Number::Integer(*integer as i128),
+        Value::I64(integer) => Number::Integer(*integer as i128),
+        Value::U32(integer) => Number::Integer(*integer as i128),
+        Value::I32(integer) => Number::Integer(*integer as i128),
+        Value::U16(integer) => Number::Integer(*integer as i128),
+        Value::I16(integer) => Number::Integer(*integer as i128),
+        Value::U8(integer) => Number::Integer(*integer as i128),
+        Value::I8(integer) => Number::Integer(*integer as i128),
+        Value::Float(float) => Number::Float(*float),
+        _ => return None,
+    })
+}
+
+/// Byte arrays hold integers from 0 to 255, so a schema asking for them always accepts bytes
+pub(super) fn is_byte_array(value: &Value) -> bool {
+    matches!(
+        value,
+        Value::Bytes(_)
+            | Value::Bytes20(_)
+            | Value::Bytes32(_)
+            | Value::Bytes36(_)
+            | Value::Identifier(_)
+            | Value::EnumU8(_)
+    )
+}
+
+pub(super) fn is_array(value: &Value) -> bool {
+    matches!(value, Value::Array(_) | Value::EnumString(_)) || is_byte_array(value)
+}
+
+pub(super) fn array_len(value: &Value) -> Option<usize> {
+    Some(match value {
+        Value::Array(values) => values.len(),
+        Value::Bytes(bytes) => bytes.len(),
+        Value::Bytes20(_) => 20,
+        Value::Bytes32(_) | Value::Identifier(_) => 32,
+        Value::Bytes36(_) => 36,
+        Value::EnumU8(values) => values.len(),
+        Value::EnumString(values) => values.len(),
+        _ => return None,
+    })
+}
+
+/// The items of array-like values, only allocating for the ones that aren't arrays of values
+pub(super) fn array_items(value: &Value) -> Option<Cow<[Value]>> {
+    let bytes = match value {
+        Value::Array(values) => return Some(Cow::Borrowed(values.as_slice())),
+        Value::EnumString(values) => {
+            return Some(Cow::Owned(
+                values.iter().cloned().map(Value::Text).collect(),
+            ))
+        }
+        Value::Bytes(bytes) => bytes.as_slice(),
+        Value::Bytes20(bytes) => bytes.as_slice(),
+        Value::Bytes32(bytes) => bytes.as_slice(),
+        Value::Bytes36(bytes) => bytes.as_slice(),
+        Value::Identifier(bytes) => bytes.as_slice(),
+        Value::EnumU8(bytes) => bytes.as_slice(),
+        _ => return None,
+    };
+    Some(Cow::Owned(bytes.iter().copied().map(Value::U8).collect()))
+}
+
+/// The name of a map key, as a property name
+pub(super) fn key_name(key: &Value) -> Cow<str> {
+    match key {
+        Value::Text(text) => Cow::Borrowed(text.as_str()),
+        key => Cow::Owned(key.to_string()),
+    }
+}
+
+/// Equality as JSON sees it: numbers are equal by value and bytes are equal to arrays of integers
+pub(super) fn values_equal(a: &Value, b: &Value) -> bool {
+    if let (Some(a), Some(b)) = (number(a), number(b)) {
+        return a.compare(&b) == Some(Ordering::Equal);
+    }
+    match (a, b) {
+        (Value::Map(a), Value::Map(b)) => {
+            a.len() == b.len()
+                && a.iter().all(|(key, a_value)| {
+                    b.iter().any(|(other_key, b_value)| {
+                        key_name(key) == key_name(other_key) && values_equal(a_value, b_value)
+                    })
+                })
+        }
+        (a, b) if is_array(a) && is_array(b) => {
+            if array_len(a) != array_len(b) {
+                return false;
+            }
+            match (array_items(a), array_items(b)) {
+                (Some(a), Some(b)) => a.iter().zip(b.iter()).all(|(a, b)| values_equal(a, b)),
+                _ => false,
+            }
+        }
+        (Value::Text(a), Value::Text(b)) => a == b,
+        (Value::Bool(a), Value::Bool(b)) => a == b,
+        (Value::Null, Value::Null) => true,
+        _ => false,
+    }
+}
@@ -0,0 +1,393 @@
+use fancy_regex::Regex;
+use lazy_static::lazy_static;
+use serde_json::{Map, Value as JsonValue};
+
+use super::instance::{Number, PrimitiveType};
+use crate::{Error, Value};
+
+/// A compiled schema, or a boolean schema accepting or refusing everything
+#[derive(Debug, Clone)]
+pub(super) enum SchemaNode {
+    Bool(bool),
+    Keywords(Vec<Keyword>),
+}
+
+/// Where a `$ref` points to
+#[derive(Debug, Clone)]
+pub(super) enum Reference {
+    Root,
+    Definition(String),
+}
+
+#[derive(Debug, Clone)]
+pub(super) enum Keyword {
+    Type(Vec<PrimitiveType>),
+    Properties(Vec<(String, SchemaNode)>),
+    PatternProperties(Vec<(Regex, SchemaNode)>),
+    AdditionalProperties {
+        schema: Box<SchemaNode>,
+        properties: Vec<String>,
+        patterns: Vec<Regex>,
+    },
+    Required(Vec<String>),
+    MinProperties(u64),
+    MaxProperties(u64),
+    PropertyNames(Box<SchemaNode>),
+    DependentRequired(Vec<(String, Vec<String>)>),
+    DependentSchemas(Vec<(String, SchemaNode)>),
+    PrefixItems(Vec<SchemaNode>),
+    /// Items after the ones described by `prefixItems`
+    Items {
+        schema: Box<SchemaNode>,
+        skip: usize,
+    },
+    Contains(Box<SchemaNode>),
+    MinItems(u64),
+    MaxItems(u64),
+    UniqueItems,
+    ByteArray,
+    MinLength(u64),
+    MaxLength(u64),
+    Pattern(Regex),
+    Format(String),
+    Minimum(Number, Value),
+    Maximum(Number, Value),
+    ExclusiveMinimum(Number, Value),
+    ExclusiveMaximum(Number, Value),
+    MultipleOf(Number, f64),
+    Const(Value),
+    Enum(Vec<Value>),
+    Ref(Reference),
+    AllOf(Vec<SchemaNode>),
+    AnyOf(Vec<SchemaNode>),
+    OneOf(Vec<SchemaNode>),
+    Not(Box<SchemaNode>, Value),
+    If {
+        condition: Box<SchemaNode>,
+        then: Option<Box<SchemaNode>>,
+        otherwise: Option<Box<SchemaNode>>,
+    },
+}
+
+impl Keyword {
+    /// The name of the keyword in the schema
+    pub(super) fn name(&self) -> &'static str {
+        match self {
+            Keyword::Type(_) => "type",
+            Keyword::Properties(_) => "properties",
+            Keyword::PatternProperties(_) => "patternProperties",
+            Keyword::AdditionalProperties { .. } => "additionalProperties",
+            Keyword::Required(_) => "required",
+            Keyword::MinProperties(_) => "minProperties",
+            Keyword::MaxProperties(_) => "maxProperties",
+            Keyword::PropertyNames(_) => "propertyNames",
+            Keyword::DependentRequired(_) => "dependentRequired",
+            Keyword::DependentSchemas(_) => "dependentSchemas",
+            Keyword::PrefixItems(_) => "prefixItems",
+            Keyword::Items { .. } => "items",
+            Keyword::Contains(_) => "contains",
+            Keyword::MinItems(_) => "minItems",
+            Keyword::MaxItems(_) => "maxItems",
+            Keyword::UniqueItems => "uniqueItems",
+            Keyword::ByteArray => "byteArray",
+            Keyword::MinLength(_) => "minLength",
+            Keyword::MaxLength(_) => "maxLength",
+            Keyword::Pattern(_) => "pattern",
+            Keyword::Format(_) => "format",
+            Keyword::Minimum(..) => "minimum",
+            Keyword::Maximum(..) => "maximum",
+            Keyword::ExclusiveMinimum(..) => "exclusiveMinimum",
+            Keyword::ExclusiveMaximum(..) => "exclusiveMaximum",
+            Keyword::MultipleOf(..) => "multipleOf",
+            Keyword::Const(_) => "const",
+            Keyword::Enum(_) => "enum",
+            Keyword::Ref(_) => "$ref",
+            Keyword::AllOf(_) => "allOf",
+            Keyword::AnyOf(_) => "anyOf",
+            Keyword::OneOf(_) => "oneOf",
+            Keyword::Not(..) => "not",
+            Keyword::If { .. } => "if",
+        }
+    }
+}
+
+fn invalid_keyword(keyword: &str, expected: &str) -> Error {
+    Error::StructureError(format!("schema keyword {keyword} must be {expected}"))
+}
+
+/// Compiles a regular expression with the engine of the JSON schema validator, so both
+/// validators agree on which strings match
+fn compile_regex(keyword: &str, pattern: &str) -> Result<Regex, Error> {
+    Regex::new(pattern).map_err(|e| {
+        Error::StructureError(format!(
+            "schema keyword {keyword} has an invalid pattern {pattern}: {e}"
+        ))
+    })
+}
+
+lazy_static! {
+    static ref CONTROL_ESCAPE_RE: regex::Regex = regex::Regex::new(r"\\c[A-Za-z]").unwrap();
+}
+
+/// Rewrites the ECMA 262 escapes of a `pattern` the way the JSON schema validator does:
+/// control escapes become the control characters and character classes are ASCII only
+pub(super) fn ecma_pattern(pattern: &str) -> String {
+    let pattern = CONTROL_ESCAPE_RE.replace_all(pattern, |captures: &regex::Captures| {
+        let letter = captures[0].as_bytes()[2].to_ascii_uppercase();
+        ((letter - 64) as char).to_string()
+    });
+    let mut converted = String::with_capacity(pattern.len());
+    let mut chars = pattern.chars();
+    while let Some(current) = chars.next() {
+        if current != '\\' {
+            converted.push(current);
+            continue;
+        }
+        match chars.next() {
+            Some('d') => converted.push_str("[0-9]"),
+            Some('D') => converted.push_str("[^0-9]"),
+            Some('w') => converted.push_str("[A-Za-z0-9_]"),
+            Some('W') => converted.push_str("[^A-Za-z0-9_]"),
+            Some('s') => converted.push_str("[ \t\n\r\u{000b}\u{000c}\u{2003}\u{feff}\u{2029}]"),
+            Some('S') => converted.push_str("[^ \t\n\r\u{000b}\u{000c}\u{2003}\u{feff}\u{2029}]"),
+            Some(next) => {
+                converted.push(current);
+                converted.push(next);
+            }
+            None => converted.push(current),
+        }
+    }
+    converted
+}
+
+fn as_u64(keyword: &str, value: &JsonValue) -> Result<u64, Error> {
+    value
+        .as_u64()
+        .or_else(|| {
+            value
+                .as_f64()
+                .filter(|float| float.fract() == 0.0 && *float >= 0.0)
+                .map(|float| float as u64)
+        })
+        .ok_or_else(|| invalid_keyword(keyword, "a non negative integer"))
+}
+
+fn as_number(keyword: &str, value: &JsonValue) -> Result<(Number, Value), Error> {
+    let number = match value {
+        JsonValue::Number(number) => Number::from_json(number),
+        _ => None,
+    }
+    .ok_or_else(|| invalid_keyword(keyword, "a number"))?;
+    Ok((number, Value::from(value)))
+}
+
+fn as_object<'a>(keyword: &str, value: &'a JsonValue) -> Result<&'a Map<String, JsonValue>, Error> {
+    value
+        .as_object()
+        .ok_or_else(|| invalid_keyword(keyword, "an object"))
+}
+
+fn as_array<'a>(keyword: &str, value: &'a JsonValue) -> Result<&'a Vec<JsonValue>, Error> {
+    value
+        .as_array()
+        .ok_or_else(|| invalid_keyword(keyword, "an array"))
+}
+
+fn as_strings(keyword: &str, value: &JsonValue) -> Result<Vec<String>, Error> {
+    as_array(keyword, value)?
+        .iter()
+        .map(|item| {
+            item.as_str()
+                .map(ToString::to_string)
+                .ok_or_else(|| invalid_keyword(keyword, "an array of strings"))
+        })
+        .collect()
+}
+
+fn compile_schemas(keyword: &str, value: &JsonValue) -> Result<Vec<SchemaNode>, Error> {
+    as_array(keyword, value)?.iter().map(compile).collect()
+}
+
+fn compile_schema_map(
+    keyword: &str,
+    value: &JsonValue,
+) -> Result<Vec<(String, SchemaNode)>, Error> {
+    as_object(keyword, value)?
+        .iter()
+        .map(|(name, schema)| Ok((name.clone(), compile(schema)?)))
+        .collect()
+}
+
+fn compile_reference(reference: &str) -> Result<Reference, Error> {
+    if reference == "#" {
+        return Ok(Reference::Root);
+    }
+    match reference.strip_prefix("#/$defs/") {
+        Some(name) if !name.contains('/') => Ok(Reference::Definition(
+            name.replace("~1", "/").replace("~0", "~"),
+        )),
+        _ => Err(Error::Unsupported(format!(
+            "schema references other than to #/$defs are not supported: {reference}"
+        ))),
+    }
+}
+
+/// Compiles a schema, keeping the order of its keywords
+pub(super) fn compile(schema: &JsonValue) -> Result<SchemaNode, Error> {
+    let object = match schema {
+        JsonValue::Bool(value) => return Ok(SchemaNode::Bool(*value)),
+        JsonValue::Object(object) => object,
+        _ => {
+            return Err(Error::StructureError(
+                "a schema must be an object or a boolean".to_string(),
+            ))
+        }
+    };
+
+    let mut keywords = vec![];
+    for (name, value) in object {
+        let keyword = match name.as_str() {
+            "type" => {
+                let names = match value {
+                    JsonValue::String(name) => vec![name.clone()],
+                    value => as_strings(name, value)?,
+                };
+                Keyword::Type(
+                    names
+                        .iter()
+                        .map(|name| {
+                            PrimitiveType::from_name(name)
+                                .ok_or_else(|| invalid_keyword("type", "a JSON schema type"))
+                        })
+                        .collect::<Result<_, Error>>()?,
+                )
+            }
+            "properties" => Keyword::Properties(compile_schema_map(name, value)?),
+            "patternProperties" => Keyword::PatternProperties(
+                as_object(name, value)?
+                    .iter()
+                    .map(|(pattern, schema)| Ok((compile_regex(name, pattern)?, compile(schema)?)))
+                    .collect::<Result<_, Error>>()?,
+            ),
+            "additionalProperties" => Keyword::AdditionalProperties {
+                schema: Box::new(compile(value)?),
+                properties: object
+                    .get("properties")
+                    .and_then(JsonValue::as_object)
+                    .map(|properties| properties.keys().cloned().collect())
+                    .unwrap_or_default(),
+                patterns: object
+                    .get("patternProperties")
+                    .and_then(JsonValue::as_object)
+                    .map(|patterns| {
+                        patterns
+                            .keys()
+                            .map(|pattern| compile_regex("patternProperties", pattern))
+                            .collect::<Result<_, Error>>()
+                    })
+                    .transpose()?
+                    .unwrap_or_default(),
+            },
+            "required" => Keyword::Required(as_strings(name, value)?),
+            "minProperties" => Keyword::MinProperties(as_u64(name, value)?),
+            "maxProperties" => Keyword::MaxProperties(as_u64(name, value)?),
+            "propertyNames" => Keyword::PropertyNames(Box::new(compile(value)?)),
+            "dependentRequired" => Keyword::DependentRequired(
+                as_object(name, value)?
+                    .iter()
+                    .map(|(property, required)| Ok((property.clone(), as_strings(name, required)?)))
+                    .collect::<Result<_, Error>>()?,
+            ),
+            "dependentSchemas" => Keyword::DependentSchemas(compile_schema_map(name, value)?),
+            "prefixItems" => Keyword::PrefixItems(compile_schemas(name, value)?),
+            "items" => match value {
+                // the draft 7 form of prefixItems
+                JsonValue::Array(_) => Keyword::PrefixItems(compile_schemas(name, value)?),
+                value => Keyword::Items {
+                    schema: Box::new(compile(value)?),
+                    skip: object
+                        .get("prefixItems")
+                        .and_then(JsonValue::as_array)
+                        .map(Vec::len)
+                        .unwrap_or_default(),
+                },
+            },
+            "contains" => Keyword::Contains(Box::new(compile(value)?)),
+            "minItems" => Keyword::MinItems(as_u64(name, value)?),
+            "maxItems" => Keyword::MaxItems(as_u64(name, value)?),
+            "uniqueItems" => match value.as_bool() {
+                Some(true) => Keyword::UniqueItems,
+                Some(false) => continue,
+                None => return Err(invalid_keyword(name, "a boolean")),
+            },
+            "byteArray" => match value.as_bool() {
+                Some(true) => Keyword::ByteArray,
+                Some(false) => continue,
+                None => return Err(invalid_keyword(name, "a boolean")),
+            },
+            "minLength" => Keyword::MinLength(as_u64(name, value)?),
+            "maxLength" => Keyword::MaxLength(as_u64(name, value)?),
+            "pattern" => Keyword::Pattern(compile_regex(
+                name,
+                &ecma_pattern(
+                    value
+                        .as_str()
+                        .ok_or_else(|| invalid_keyword(name, "a string"))?,
+                ),
+            )?),
+            "format" => Keyword::Format(
+                value
+                    .as_str()
+                    .ok_or_else(|| invalid_keyword(name, "a string"))?
+                    .to_string(),
+            ),
+            "minimum" => {
+                let (number, limit) = as_number(name, value)?;
+                Keyword::Minimum(number, limit)
+            }
+            "maximum" => {
+                let (number, limit) = as_number(name, value)?;
+                Keyword::Maximum(number, limit)
+            }
+            "exclusiveMinimum" => {
+                let (number, limit) = as_number(name, value)?;
+                Keyword::ExclusiveMinimum(number, limit)
+            }
+            "exclusiveMaximum" => {
+                let (number, limit) = as_number(name, value)?;
+                Keyword::ExclusiveMaximum(number, limit)
+            }
+            "multipleOf" => {
+                let (number, _) = as_number(name, value)?;
+                Keyword::MultipleOf(number, number.as_f64())
+            }
+            "const" => Keyword::Const(Value::from(value)),
+            "enum" => Keyword::Enum(as_array(name, value)?.iter().map(Value::from).collect()),
+            "$ref" => Keyword::Ref(compile_reference(
+                value
+                    .as_str()
+                    .ok_or_else(|| invalid_keyword(name, "a string"))?,
+            )?),
+            "allOf" => Keyword::AllOf(compile_schemas(name, value)?),
+            "anyOf" => Keyword::AnyOf(compile_schemas(name, value)?),
+            "oneOf" => Keyword::OneOf(compile_schemas(name, value)?),
+            "not" => Keyword::Not(Box::new(compile(value)?), Value::from(value)),
+            "if" => Keyword::If {
+                condition: Box::new(compile(value)?),
+                then: object
+                    .get("then")
+                    .map(|then| compile(then).map(Box::new))
+                    .transpose()?,
+                otherwise: object
+                    .get("else")
+                    .map(|otherwise| compile(otherwise).map(Box::new))
+                    .transpose()?,
+            },
+            // annotations, definitions compiled separately and keywords validated elsewhere
+            _ => continue,
+        };
+        keywords.push(keyword);
+    }
+
+    Ok(SchemaNode::Keywords(keywords))
+}
@@ -0,0 +1,310 @@
+//! JSON schema validation of platform values
+//!
+//! A schema is compiled once into a [`ValueSchema`], which validates values as they are, without
+//! converting them to JSON first. Byte arrays, identifiers and enumerations are arrays of integers
+//! or strings to the schema, so the `byteArray` keyword holds for them by construction and the
+//! `minItems` and `maxItems` keywords count the bytes they hold. Like `contentMediaType`,
+//! unknown keywords are annotations and aren't checked.
+//!
+//! Patterns are compiled with the regex engine of the JSON schema validator, after rewriting
+//! their ECMA 262 escapes the same way, since both validators must agree on every document.
+//!
+//! Errors are reported like JSON schema validators report them: with the failed keyword, JSON
+//! pointers to the invalid value and to the keyword, and the keyword params.
+//!
+//! ```rust
+//! use platform_value::schema::ValueSchema;
+//! use platform_value::Value;
+//! use serde_json::json;
+//!
+//! let schema = ValueSchema::compile(&json!({
+//!     "type": "object",
+//!     "properties": {
+//!         "ownerId": { "type": "array", "byteArray": true, "minItems": 32, "maxItems": 32 }
+//!     },
+//!     "required": ["ownerId"]
+//! }))
+//! .unwrap();
+//!
+//! let value = Value::Map(vec![(Value::Text("ownerId".into()), Value::Identifier([1; 32]))]);
+//! assert!(schema.is_valid(&value));
+//! ```
+
+mod error;
+mod format;
+mod instance;
+mod keywords;
+mod validator;
+
+use std::collections::BTreeMap;
+
+use serde_json::Value as JsonValue;
+
+pub use error::SchemaValidationError;
+
+use crate::{Error, Value};
+use keywords::SchemaNode;
+use validator::Validator;
+
+/// A compiled JSON schema validating platform values
+#[derive(Debug, Clone)]
+pub struct ValueSchema {
+    root: SchemaNode,
+    definitions: BTreeMap<String, SchemaNode>,
+}
+
+impl ValueSchema {
+    /// Compiles a schema, with the definitions it holds under `$defs`
+    pub fn compile(schema: &JsonValue) -> Result<Self, Error> {
+        Self::compile_with_definitions(schema, [])
+    }
+
+    /// Compiles a schema, adding definitions to the ones it holds under `$defs`
+    pub fn compile_with_definitions<'a>(
+        schema: &JsonValue,
+        definitions: impl IntoIterator<Item = (&'a String, &'a JsonValue)>,
+    ) -> Result<Self, Error> {
+        let own_definitions = schema
+            .get("$defs")
+            .map(|definitions| {
+                definitions.as_object().ok_or_else(|| {
+                    Error::StructureError("schema $defs must be an object".to_string())
+                })
+            })
+            .transpose()?;
+
+        let definitions = own_definitions
+            .into_iter()
+            .flatten()
+            .chain(definitions)
+            .map(|(name, definition)| Ok((name.clone(), keywords::compile(definition)?)))
+            .collect::<Result<BTreeMap<String, SchemaNode>, Error>>()?;
+
+        Ok(Self {
+            root: keywords::compile(schema)?,
+            definitions,
+        })
+    }
+
+    /// Validates the value, returning every schema violation found
+    pub fn validate(&self, value: &Value) -> Result<(), Vec<SchemaValidationError>> {
+        let errors = self.validator().validate(value);
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    pub fn is_valid(&self, value: &Value) -> bool {
+        self.validate(value).is_ok()
+    }
+
+    fn validator(&self) -> Validator {
+        Validator {
+            root: &self.root,
+            definitions: &self.definitions,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::platform_value;
+    use serde_json::json;
+
+    fn document_schema() -> ValueSchema {
+        ValueSchema::compile(&json!({
+            "type": "object",
+            "properties": {
+                "$id": {
+                    "type": "array",
+                    "byteArray": true,
+                    "minItems": 32,
+                    "maxItems": 32,
+                    "contentMediaType": "application/x.dash.dpp.identifier"
+                },
+                "name": { "type": "string", "maxLength": 5, "pattern": "^[a-z]+$" },
+                "age": { "type": "integer", "minimum": 0, "maximum": 150 },
+                "tags": { "type": "array", "items": { "$ref": "#/$defs/tag" }, "maxItems": 2 },
+                "avatar": { "type": "array", "byteArray": true, "maxItems": 4 }
+            },
+            "required": ["$id", "name"],
+            "additionalProperties": false,
+            "$defs": {
+                "tag": { "type": "string", "enum": ["a", "b"] }
+            }
+        }))
+        .expect("schema should compile")
+    }
+
+    fn errors(schema: &ValueSchema, value: Value) -> Vec<SchemaValidationError> {
+        schema.validate(&value).err().unwrap_or_default()
+    }
+
+    #[test]
+    fn should_accept_identifiers_and_bytes_as_byte_arrays() {
+        let schema = document_schema();
+        let value = platform_value!({
+            "$id": Value::Identifier([3; 32]),
+            "name": "bob",
+            "avatar": Value::Bytes(vec![1, 2, 3]),
+        });
+        assert_eq!(schema.validate(&value), Ok(()));
+    }
+
+    #[test]
+    fn should_check_the_items_of_arrays_of_values_as_bytes() {
+        let schema = document_schema();
+        let mut id = vec![Value::U8(1); 31];
+        id.push(Value::U64(256));
+        let found = errors(
+            &schema,
+            platform_value!({ "$id": Value::Array(id), "name": "bob" }),
+        );
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].keyword, "maximum");
+        assert_eq!(found[0].instance_path, "/$id/31");
+        assert_eq!(
+            found[0].schema_path,
+            "/properties/$id/byteArray/items/maximum"
+        );
+    }
+
+    #[test]
+    fn should_report_errors_like_json_schema_validators() {
+        let schema = document_schema();
+        let found = errors(
+            &schema,
+            platform_value!({
+                "$id": Value::Bytes(vec![1; 20]),
+                "age": -1,
+                "tags": ["a", "c", "b"],
+                "other": true,
+            }),
+        );
+        let keywords: Vec<&str> = found.iter().map(|error| error.keyword.as_str()).collect();
+        assert_eq!(
+            keywords,
+            vec![
+                "minItems",
+                "minimum",
+                "enum",
+                "maxItems",
+                "required",
+                "additionalProperties"
+            ]
+        );
+
+        assert_eq!(
+            found[0].params,
+            vec![("minItems".to_string(), Value::U64(32))]
+        );
+        assert_eq!(found[2].instance_path, "/tags/1");
+        assert_eq!(found[2].schema_path, "/properties/tags/items/$ref/enum");
+        assert_eq!(found[4].property_name, "name");
+        assert_eq!(
+            found[4].params,
+            vec![(
+                "missingProperty".to_string(),
+                Value::Text("name".to_string())
+            )]
+        );
+        assert_eq!(
+            found[5].params,
+            vec![(
+                "additionalProperties".to_string(),
+                Value::Array(vec![Value::Text("other".to_string())])
+            )]
+        );
+    }
+
+    #[test]
+    fn should_compare_numbers_of_any_width() {
+        let schema = ValueSchema::compile(&json!({
+            "type": "integer",
+            "minimum": 10,
+            "exclusiveMaximum": 20,
+            "multipleOf": 5
+        }))
+        .unwrap();
+        assert!(schema.is_valid(&Value::U8(10)));
+        assert!(schema.is_valid(&Value::I128(15)));
+        assert!(schema.is_valid(&Value::Float(15.0)));
+        assert!(!schema.is_valid(&Value::U128(20)));
+        assert!(!schema.is_valid(&Value::I8(-5)));
+        assert!(!schema.is_valid(&Value::U32(12)));
+        assert!(!schema.is_valid(&Value::Float(12.5)));
+        assert!(!schema.is_valid(&Value::Text("15".to_string())));
+    }
+
+    #[test]
+    fn should_apply_combinators() {
+        let schema = ValueSchema::compile(&json!({
+            "oneOf": [{ "type": "string" }, { "type": "integer" }],
+            "not": { "const": 3 },
+            "if": { "type": "string" },
+            "then": { "minLength": 2 },
+            "else": { "maximum": 5 }
+        }))
+        .unwrap();
+        assert!(schema.is_valid(&Value::Text("ab".to_string())));
+        assert!(schema.is_valid(&Value::U64(4)));
+
+        let found = errors(&schema, Value::Text("a".to_string()));
+        assert_eq!(found[0].keyword, "minLength");
+        assert_eq!(found[0].schema_path, "/then/minLength");
+
+        let found = errors(&schema, Value::U64(3));
+        assert_eq!(found[0].keyword, "not");
+
+        let found = errors(&schema, Value::Bool(true));
+        assert_eq!(found[0].keyword, "oneOfNotValid");
+    }
+
+    #[test]
+    fn should_check_unique_items_across_value_widths() {
+        let schema =
+            ValueSchema::compile(&json!({ "type": "array", "uniqueItems": true })).unwrap();
+        assert!(schema.is_valid(&Value::EnumString(vec!["a".into(), "b".into()])));
+        assert!(!schema.is_valid(&Value::Array(vec![Value::U8(1), Value::I64(1)])));
+        assert!(!schema.is_valid(&Value::Bytes(vec![7, 7])));
+    }
+
+    #[test]
+    fn should_check_known_formats() {
+        let schema = ValueSchema::compile(&json!({ "type": "string", "format": "date" })).unwrap();
+        assert!(schema.is_valid(&Value::Text("2024-02-29".to_string())));
+        assert!(!schema.is_valid(&Value::Text("2023-02-29".to_string())));
+
+        let schema = ValueSchema::compile(&json!({ "format": "lalala" })).unwrap();
+        assert!(schema.is_valid(&Value::Text("anything".to_string())));
+    }
+
+    #[test]
+    fn should_match_patterns_with_ecma_262_character_classes() {
+        let schema =
+            ValueSchema::compile(&json!({ "type": "string", "pattern": "^\\d+$" })).unwrap();
+        assert!(schema.is_valid(&Value::Text("0123".to_string())));
+        // Arabic-Indic digits are digits to unicode classes but not to ECMA 262 ones
+        assert!(!schema.is_valid(&Value::Text("\u{0661}\u{0662}".to_string())));
+
+        let schema =
+            ValueSchema::compile(&json!({ "type": "string", "pattern": "^\\w\\cJ$" })).unwrap();
+        assert!(schema.is_valid(&Value::Text("a\n".to_string())));
+        assert!(!schema.is_valid(&Value::Text("\u{00e9}\n".to_string())));
+
+        // look arounds are supported, as by the JSON schema validator
+        let schema =
+            ValueSchema::compile(&json!({ "type": "string", "pattern": "^(?!-)[a-z-]+$" }))
+                .unwrap();
+        assert!(schema.is_valid(&Value::Text("a-b".to_string())));
+        assert!(!schema.is_valid(&Value::Text("-ab".to_string())));
+    }
+
+    #[test]
+    fn should_refuse_unsupported_references() {
+        assert!(ValueSchema::compile(&json!({ "$ref": "https://example.com/schema" })).is_err());
+    }
+}
@@ -0,0 +1,664 @@
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+
+use super::error::SchemaValidationError;
+use super::format::is_valid_format;
+use super::instance::{
+    array_items, array_len, key_name, number, values_equal, Number, PrimitiveType,
+};
+use super::keywords::{Keyword, Reference, SchemaNode};
+use crate::Value;
+
+/// References followed without going deeper into the value, past which a schema is deemed cyclic
+const MAX_REFERENCE_DEPTH: usize = 64;
+
+#[derive(Debug, Clone, Copy)]
+enum Chunk<'a> {
+    Keyword(&'static str),
+    Property(&'a str),
+    Index(usize),
+}
+
+/// A JSON pointer that is only turned into a string when an error is found
+#[derive(Debug, Clone, Copy)]
+struct Location<'a> {
+    parent: Option<&'a Location<'a>>,
+    chunk: Option<Chunk<'a>>,
+}
+
+impl<'a> Location<'a> {
+    fn root() -> Self {
+        Self {
+            parent: None,
+            chunk: None,
+        }
+    }
+
+    fn push(&'a self, chunk: Chunk<'a>) -> Location<'a> {
+        Location {
+            parent: Some(self),
+            chunk: Some(chunk),
+        }
+    }
+
+    fn last_keyword(&self) -> Option<&'static str> {
+        match self.chunk {
+            Some(Chunk::Keyword(keyword)) => Some(keyword),
+            _ => None,
+        }
+    }
+
+    fn to_pointer(self) -> String {
+        let mut chunks = vec![];
+        let mut location = Some(&self);
+        while let Some(current) = location {
+            if let Some(chunk) = current.chunk {
+                chunks.push(chunk);
+            }
+            location = current.parent;
+        }
+        chunks
+            .iter()
+            .rev()
+            .map(|chunk| match chunk {
+                Chunk::Keyword(keyword) => format!("/{keyword}"),
+                Chunk::Property(property) => {
+                    format!("/{}", property.replace('~', "~0").replace('/', "~1"))
+                }
+                Chunk::Index(index) => format!("/{index}"),
+            })
+            .collect()
+    }
+}
+
+pub(super) struct Validator<'s> {
+    pub(super) root: &'s SchemaNode,
+    pub(super) definitions: &'s BTreeMap<String, SchemaNode>,
+}
+
+impl<'s> Validator<'s> {
+    pub(super) fn validate(&self, value: &Value) -> Vec<SchemaValidationError> {
+        let mut errors = vec![];
+        self.validate_node(
+            self.root,
+            value,
+            &Location::root(),
+            &Location::root(),
+            0,
+            &mut errors,
+        );
+        errors
+    }
+
+    fn is_valid(&self, node: &SchemaNode, value: &Value, depth: usize) -> bool {
+        let mut errors = vec![];
+        self.validate_node(
+            node,
+            value,
+            &Location::root(),
+            &Location::root(),
+            depth,
+            &mut errors,
+        );
+        errors.is_empty()
+    }
+
+    fn validate_node(
+        &self,
+        node: &SchemaNode,
+        value: &Value,
+        instance: &Location,
+        schema: &Location,
+        depth: usize,
+        errors: &mut Vec<SchemaValidationError>,
+    ) {
+        match node {
+            SchemaNode::Bool(true) => {}
+            SchemaNode::Bool(false) => {
+                let keyword = schema.last_keyword().unwrap_or("falseSchema");
+                errors.push(located(
+                    SchemaValidationError::new(keyword),
+                    instance,
+                    schema,
+                ));
+            }
+            SchemaNode::Keywords(keywords) => {
+                for keyword in keywords {
+                    let keyword_location = schema.push(Chunk::Keyword(keyword.name()));
+                    self.validate_keyword(
+                        keyword,
+                        value,
+                        instance,
+                        &keyword_location,
+                        schema,
+                        depth,
+                        errors,
+                    );
+                }
+            }
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn validate_keyword(
+        &self,
+        keyword: &Keyword,
+        value: &Value,
+        instance: &Location,
+        schema: &Location,
+        parent_schema: &Location,
+        depth: usize,
+        errors: &mut Vec<SchemaValidationError>,
+    ) {
+        let mut fail = |error: SchemaValidationError| errors.push(located(error, instance, schema));
+        match keyword {
+            Keyword::Type(types) => {
+                if !types.iter().any(|primitive| primitive.matches(value)) {
+                    let expected = match types.as_slice() {
+                        [single] => Value::Text(single.name().to_string()),
+                        types => Value::Array(
+                            types
+                                .iter()
+                                .map(|primitive| Value::Text(primitive.name().to_string()))
+                                .collect(),
+                        ),
+                    };
+                    fail(SchemaValidationError::new("type").with_param("type", expected));
+                }
+            }
+            Keyword::Required(required) => {
+                if let Value::Map(map) = value {
+                    for property in required {
+                        if !map
+                            .iter()
+                            .any(|(key, _)| key_name(key) == property.as_str())
+                        {
+                            fail(missing_property(property));
+                        }
+                    }
+                }
+            }
+            Keyword::MinProperties(limit) => {
+                if let Value::Map(map) = value {
+                    if (map.len() as u64) < *limit {
+                        fail(
+                            SchemaValidationError::new("minProperties")
+                                .with_param("minProperties", *limit),
+                        );
+                    }
+                }
+            }
+            Keyword::MaxProperties(limit) => {
+                if let Value::Map(map) = value {
+                    if map.len() as u64 > *limit {
+                        fail(
+                            SchemaValidationError::new("maxProperties")
+                                .with_param("maxProperties", *limit),
+                        );
+                    }
+                }
+            }
+            Keyword::MinItems(limit) => {
+                if let Some(len) = array_len(value) {
+                    if (len as u64) < *limit {
+                        fail(SchemaValidationError::new("minItems").with_param("minItems", *limit));
+                    }
+                }
+            }
+            Keyword::MaxItems(limit) => {
+                if let Some(len) = array_len(value) {
+                    if len as u64 > *limit {
+                        fail(SchemaValidationError::new("maxItems").with_param("maxItems", *limit));
+                    }
+                }
+            }
+            Keyword::UniqueItems => {
+                if let Some(items) = array_items(value) {
+                    let has_duplicates = items.iter().enumerate().any(|(position, item)| {
+                        items[position + 1..]
+                            .iter()
+                            .any(|other| values_equal(item, other))
+                    });
+                    if has_duplicates {
+                        fail(SchemaValidationError::new("uniqueItems"));
+                    }
+                }
+            }
+            Keyword::MinLength(limit) => {
+                if let Value::Text(text) = value {
+                    if (text.chars().count() as u64) < *limit {
+                        fail(
+                            SchemaValidationError::new("minLength").with_param("minLength", *limit),
+                        );
+                    }
+                }
+            }
+            Keyword::MaxLength(limit) => {
+                if let Value::Text(text) = value {
+                    if text.chars().count() as u64 > *limit {
+                        fail(
+                            SchemaValidationError::new("maxLength").with_param("maxLength", *limit),
+                        );
+                    }
+                }
+            }
+            Keyword::Pattern(regex) => {
+                if let Value::Text(text) = value {
+                    if !regex.is_match(text).unwrap_or(false) {
+                        fail(
+                            SchemaValidationError::new("pattern")
+                                .with_param("pattern", regex.as_str()),
+                        );
+                    }
+                }
+            }
+            Keyword::Format(format) => {
+                if let Value::Text(text) = value {
+                    if is_valid_format(format, text) == Some(false) {
+                        fail(
+                            SchemaValidationError::new("format")
+                                .with_param("format", format.as_str()),
+                        );
+                    }
+                }
+            }
+            Keyword::Minimum(limit, limit_value) => {
+                if compares(value, limit, |ordering| ordering == Ordering::Less) {
+                    fail(
+                        SchemaValidationError::new("minimum")
+                            .with_param("minimum", limit_value.clone()),
+                    );
+                }
+            }
+            Keyword::Maximum(limit, limit_value) => {
+                if compares(value, limit, |ordering| ordering == Ordering::Greater) {
+                    fail(
+                        SchemaValidationError::new("maximum")
+                            .with_param("maximum", limit_value.clone()),
+                    );
+                }
+            }
+            Keyword::ExclusiveMinimum(limit, limit_value) => {
+                if compares(value, limit, |ordering| ordering != Ordering::Greater) {
+                    fail(
+                        SchemaValidationError::new("exclusiveMinimum")
+                            .with_param("exclusiveMinimum", limit_value.clone()),
+                    );
+                }
+            }
+            Keyword::ExclusiveMaximum(limit, limit_value) => {
+                if compares(value, limit, |ordering| ordering != Ordering::Less) {
+                    fail(
+                        SchemaValidationError::new("exclusiveMaximum")
+                            .with_param("exclusiveMaximum", limit_value.clone()),
+                    );
+                }
+            }
+            Keyword::MultipleOf(divisor, divisor_value) => {
+                if let Some(number) = number(value) {
+                    if !number.is_multiple_of(divisor) {
+                        fail(
+                            SchemaValidationError::new("multipleOf")
+                                .with_param("multipleOf", Value::Float(*divisor_value)),
+                        );
+                    }
+                }
+            }
+            Keyword::Const(expected) => {
+                if !values_equal(value, expected) {
+                    fail(
+                        SchemaValidationError::new("const")
+                            .with_param("allowedValue", expected.clone()),
+                    );
+                }
+            }
+            Keyword::Enum(options) => {
+                if !options.iter().any(|option| values_equal(value, option)) {
+                    fail(
+                        SchemaValidationError::new("enum")
+                            .with_param("enum", Value::Array(options.clone())),
+                    );
+                }
+            }
+            Keyword::ByteArray => {
+                // byte arrays are valid by construction, only arrays of values need their items checked
+                if let Value::Array(items) = value {
+                    let items_location = schema.push(Chunk::Keyword("items"));
+                    for (index, item) in items.iter().enumerate() {
+                        let item_instance = instance.push(Chunk::Index(index));
+                        validate_byte(item, &item_instance, &items_location, errors);
+                    }
+                }
+            }
+            Keyword::Properties(properties) => {
+                if let Value::Map(map) = value {
+                    for (property, property_schema) in properties {
+                        if let Some((_, property_value)) = map
+                            .iter()
+                            .find(|(key, _)| key_name(key) == property.as_str())
+                        {
+                            let property_instance = instance.push(Chunk::Property(property));
+                            let property_location = schema.push(Chunk::Property(property));
+                            self.validate_node(
+                                property_schema,
+                                property_value,
+                                &property_instance,
+                                &property_location,
+                                depth,
+                                errors,
+                            );
+                        }
+                    }
+                }
+            }
+            Keyword::PatternProperties(patterns) => {
+                if let Value::Map(map) = value {
+                    for (key, property_value) in map {
+                        let name = key_name(key);
+                        for (regex, pattern_schema) in patterns {
+                            if regex.is_match(&name).unwrap_or(false) {
+                                let property_instance = instance.push(Chunk::Property(&name));
+                                let pattern_location = schema.push(Chunk::Property(regex.as_str()));
+                                self.validate_node(
+                                    pattern_schema,
+                                    property_value,
+                                    &property_instance,
+                                    &pattern_location,
+                                    depth,
+                                    errors,
+                                );
+                            }
+                        }
+                    }
+                }
+            }
+            Keyword::AdditionalProperties {
+                schema: additional_schema,
+                properties,
+                patterns,
+            } => {
+                if let Value::Map(map) = value {
+                    let additional = map.iter().filter(|(key, _)| {
+                        let name = key_name(key);
+                        !properties.iter().any(|property| property.as_str() == name)
+                            && !patterns
+                                .iter()
+                                .any(|regex| regex.is_match(&name).unwrap_or(false))
+                    });
+                    if let SchemaNode::Bool(false) = additional_schema.as_ref() {
+                        let unexpected: Vec<Value> = additional
+                            .map(|(key, _)| Value::Text(key_name(key).into_owned()))
+                            .collect();
+                        if !unexpected.is_empty() {
+                            fail(
+                                SchemaValidationError::new("additionalProperties")
+                                    .with_param("additionalProperties", Value::Array(unexpected)),
+                            );
+                        }
+                    } else {
+                        for (key, property_value) in additional {
+                            let name = key_name(key);
+                            let property_instance = instance.push(Chunk::Property(&name));
+                            self.validate_node(
+                                additional_schema,
+                                property_value,
+                                &property_instance,
+                                schema,
+                                depth,
+                                errors,
+                            );
+                        }
+                    }
+                }
+            }
+            Keyword::PropertyNames(names_schema) => {
+                if let Value::Map(map) = value {
+                    for (key, _) in map {
+                        let name = Value::Text(key_name(key).into_owned());
+                        let mut name_errors = vec![];
+                        self.validate_node(
+                            names_schema,
+                            &name,
+                            &Location::root(),
+                            &Location::root(),
+                            depth,
+                            &mut name_errors,
+                        );
+                        for name_error in name_errors {
+                            let params = name_error.params_value();
+                            errors.push(located(
+                                SchemaValidationError::new("propertyNames")
+                                    .with_param("instancePath", name_error.instance_path)
+                                    .with_param("schemaPath", name_error.schema_path)
+                                    .with_param("instance", name.clone())
+                                    .with_param("params", params)
+                                    .with_param("keyword", name_error.keyword)
+                                    .with_param("propertyName", name_error.property_name),
+                                instance,
+                                schema,
+                            ));
+                        }
+                    }
+                }
+            }
+            Keyword::DependentRequired(dependencies) => {
+                if let Value::Map(map) = value {
+                    let has_property =
+                        |property: &str| map.iter().any(|(key, _)| key_name(key) == property);
+                    for (property, required) in dependencies {
+                        if !has_property(property) {
+                            continue;
+                        }
+                        let dependency_location = schema.push(Chunk::Property(property));
+                        for required_property in required {
+                            if !has_property(required_property) {
+                                errors.push(located(
+                                    missing_property(required_property),
+                                    instance,
+                                    &dependency_location,
+                                ));
+                            }
+                        }
+                    }
+                }
+            }
+            Keyword::DependentSchemas(dependencies) => {
+                if let Value::Map(map) = value {
+                    for (property, dependency_schema) in dependencies {
+                        if map
+                            .iter()
+                            .any(|(key, _)| key_name(key) == property.as_str())
+                        {
+                            let dependency_location = schema.push(Chunk::Property(property));
+                            self.validate_node(
+                                dependency_schema,
+                                value,
+                                instance,
+                                &dependency_location,
+                                depth,
+                                errors,
+                            );
+                        }
+                    }
+                }
+            }
+            Keyword::PrefixItems(prefix_schemas) => {
+                if let Some(items) = array_items(value) {
+                    for (index, (item, item_schema)) in
+                        items.iter().zip(prefix_schemas.iter()).enumerate()
+                    {
+                        let item_instance = instance.push(Chunk::Index(index));
+                        let item_location = schema.push(Chunk::Index(index));
+                        self.validate_node(
+                            item_schema,
+                            item,
+                            &item_instance,
+                            &item_location,
+                            depth,
+                            errors,
+                        );
+                    }
+                }
+            }
+            Keyword::Items {
+                schema: items_schema,
+                skip,
+            } => {
+                if let SchemaNode::Bool(true) = items_schema.as_ref() {
+                    return;
+                }
+                if let Some(items) = array_items(value) {
+                    for (index, item) in items.iter().enumerate().skip(*skip) {
+                        let item_instance = instance.push(Chunk::Index(index));
+                        self.validate_node(
+                            items_schema,
+                            item,
+                            &item_instance,
+                            schema,
+                            depth,
+                            errors,
+                        );
+                    }
+                }
+            }
+            Keyword::Contains(contains_schema) => {
+                if let Some(items) = array_items(value) {
+                    if !items
+                        .iter()
+                        .any(|item| self.is_valid(contains_schema, item, depth))
+                    {
+                        fail(SchemaValidationError::new("contains"));
+                    }
+                }
+            }
+            Keyword::Ref(reference) => {
+                let referenced = match reference {
+                    Reference::Root => Some(self.root),
+                    Reference::Definition(name) => self.definitions.get(name),
+                };
+                match referenced {
+                    Some(_) if depth >= MAX_REFERENCE_DEPTH => {
+                        fail(SchemaValidationError::new("schema"));
+                    }
+                    Some(referenced) => {
+                        self.validate_node(referenced, value, instance, schema, depth + 1, errors)
+                    }
+                    None => {
+                        let name = match reference {
+                            Reference::Definition(name) => name.as_str(),
+                            Reference::Root => "",
+                        };
+                        fail(
+                            SchemaValidationError::new("invalidReference")
+                                .with_param("invalidReference", format!("#/$defs/{name}")),
+                        );
+                    }
+                }
+            }
+            Keyword::AllOf(schemas) => {
+                for (index, sub_schema) in schemas.iter().enumerate() {
+                    let sub_location = schema.push(Chunk::Index(index));
+                    self.validate_node(sub_schema, value, instance, &sub_location, depth, errors);
+                }
+            }
+            Keyword::AnyOf(schemas) => {
+                if !schemas
+                    .iter()
+                    .any(|sub_schema| self.is_valid(sub_schema, value, depth))
+                {
+                    fail(SchemaValidationError::new("anyOf"));
+                }
+            }
+            Keyword::OneOf(schemas) => {
+                let valid = schemas
+                    .iter()
+                    .filter(|sub_schema| self.is_valid(sub_schema, value, depth))
+                    .take(2)
+                    .count();
+                match valid {
+                    0 => fail(SchemaValidationError::new("oneOfNotValid")),
+                    1 => {}
+                    _ => fail(SchemaValidationError::new("oneOfMultipleValid")),
+                }
+            }
+            Keyword::Not(not_schema, not_value) => {
+                if self.is_valid(not_schema, value, depth) {
+                    fail(SchemaValidationError::new("not").with_param("not", not_value.clone()));
+                }
+            }
+            Keyword::If {
+                condition,
+                then,
+                otherwise,
+            } => {
+                let (branch, name) = if self.is_valid(condition, value, depth) {
+                    (then, "then")
+                } else {
+                    (otherwise, "else")
+                };
+                if let Some(branch) = branch {
+                    let branch_location = parent_schema.push(Chunk::Keyword(name));
+                    self.validate_node(branch, value, instance, &branch_location, depth, errors);
+                }
+            }
+        }
+    }
+}
+
+fn located(
+    mut error: SchemaValidationError,
+    instance: &Location,
+    schema: &Location,
+) -> SchemaValidationError {
+    error.instance_path = instance.to_pointer();
+    error.schema_path = schema.to_pointer();
+    error
+}
+
+fn missing_property(property: &str) -> SchemaValidationError {
+    SchemaValidationError::new("required")
+        .with_property_name(property)
+        .with_param("missingProperty", property)
+}
+
+/// Whether the value is a number whose ordering to the limit is the searched one
+fn compares(value: &Value, limit: &Number, searched: impl Fn(Ordering) -> bool) -> bool {
+    number(value)
+        .and_then(|number| number.compare(limit))
+        .map(searched)
+        .unwrap_or_default()
+}
+
+/// Validates an item of an array of values against the items schema of byte arrays
+fn validate_byte(
+    item: &Value,
+    instance: &Location,
+    schema: &Location,
+    errors: &mut Vec<SchemaValidationError>,
+) {
+    if !PrimitiveType::Integer.matches(item) {
+        errors.push(located(
+            SchemaValidationError::new("type").with_param("type", "integer"),
+            instance,
+            &schema.push(Chunk::Keyword("type")),
+        ));
+    }
+    if compares(item, &Number::Integer(0), |ordering| {
+        ordering == Ordering::Less
+    }) {
+        errors.push(located(
+            SchemaValidationError::new("minimum").with_param("minimum", 0u64),
+            instance,
+            &schema.push(Chunk::Keyword("minimum")),
+        ));
+    }
+    if compares(item, &Number::Integer(u8::MAX as i128), |ordering| {
+        ordering == Ordering::Greater
+    }) {
+        errors.push(located(
+            SchemaValidationError::new("maximum").with_param("maximum", u8::MAX as u64),
+            instance,
+            &schema.push(Chunk::Keyword("maximum")),
+        ));
+    }
+}
@@ -15,8 +15,15 @@ impl<'a> From<&'a Value> for de::Unexpected<'a> {
             Value::Array(..) => Self::Seq,
             Value::Map(..) => Self::Map,
             Value::Null => Self::Other("null"),
-            Value::U128(_x) => todo!(), // TODO: it seems serde is not happy about u128
-            Value::I128(_x) => todo!(), // TODO: ... and for i128 either
+            // serde has no unexpected variant for 128 bits, so only values out of 64 bits get a name
+            Value::U128(x) => match u64::try_from(*x) {
+                Ok(x) => Self::Unsigned(x),
+                Err(_) => Self::Other("u128"),
+            },
+            Value::I128(x) => match i64::try_from(*x) {
+                Ok(x) => Self::Signed(x),
+                Err(_) => Self::Other("i128"),
+            },
             Value::U64(x) => Self::Unsigned(*x),
             Value::I64(x) => Self::Signed(*x),
             Value::U32(x) => Self::Unsigned(*x as u64),
@@ -28,8 +35,8 @@ impl<'a> From<&'a Value> for de::Unexpected<'a> {
             Value::Bytes20(x) => Self::Bytes(x),
             Value::Bytes32(x) => Self::Bytes(x),
             Value::Bytes36(x) => Self::Bytes(x),
-            Value::EnumU8(_x) => todo!(),
-            Value::EnumString(_x) => todo!(),
+            Value::EnumU8(..) => Self::Seq,
+            Value::EnumString(..) => Self::Seq,
             Value::Identifier(x) => Self::Bytes(x),
         }
     }
@@ -123,34 +130,17 @@ impl<'de> de::Visitor<'de> for Visitor {
         Ok(Value::Map(map))
     }
 
-    fn visit_enum<A: de::EnumAccess<'de>>(self, _acc: A) -> Result<Self::Value, A::Error> {
-        // use serde::de::VariantAccess;
-
-        // struct Inner;
-
-        // impl<'de> serde::de::Visitor<'de> for Inner {
-        //     type Value = Value;
-
-        //     fn expecting(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        //         write!(formatter, "a valid CBOR item")
-        //     }
-
-        //             //     fn visit_seq<A: de::SeqAccess<'de>>(self, mut acc: A) -> Result<Self::Value, A::Error> {
-        //         let tag: u64 = acc
-        //             .next_element()?
-        //             .ok_or_else(|| de::Error::custom("expected tag"))?;
-        //         let val = acc
-        //             .next_element()?
-        //             .ok_or_else(|| de::Error::custom("expected val"))?;
-        //         Ok(Value::EnumU8(tag, Box::new(val)))
-        //     }
-        // }
-
-        // let (name, data): (String, _) = acc.variant()?;
-        // assert_eq!("@@TAGGED@@", name);
-        // data.tuple_variant(2, Inner)
+    fn visit_enum<A: de::EnumAccess<'de>>(self, acc: A) -> Result<Self::Value, A::Error> {
+        use serde::de::VariantAccess;
 
-        todo!()
+        // enums are represented the same way `to_value` serializes them, unit variants by
+        // their name and newtype, tuple and struct variants as a map from their name to their
+        // content
+        let (variant, data): (String, _) = acc.variant()?;
+        match data.newtype_variant()? {
+            Value::Null => Ok(Value::Text(variant)),
+            content => Ok(Value::Map(vec![(Value::Text(variant), content)])),
+        }
     }
 }
 
@@ -212,8 +202,10 @@ impl<'de> de::Deserializer<'de> for Deserializer<Value> {
                     visitor.visit_bytes(&x)
                 }
             }
-            Value::EnumU8(_x) => todo!(),
-            Value::EnumString(_x) => todo!(),
+            value @ (Value::EnumU8(_) | Value::EnumString(_)) => {
+                let values = enum_values_as_array(value);
+                visitor.visit_seq(ArrayDeserializer(values.iter()))
+            }
             Value::Identifier(x) => {
                 if human_readable {
                     visitor.visit_str(bs58::encode(x).into_string().as_str())
@@ -347,6 +339,10 @@ impl<'de> de::Deserializer<'de> for Deserializer<Value> {
 
         match value {
             Value::Array(x) => visitor.visit_seq(ArrayDeserializer(x.iter())),
+            value @ (Value::EnumU8(_) | Value::EnumString(_)) => {
+                let values = enum_values_as_array(value);
+                visitor.visit_seq(ArrayDeserializer(values.iter()))
+            }
             _ => Err(de::Error::invalid_type((&value).into(), &"array")),
         }
     }
@@ -434,14 +430,25 @@ impl<'de> de::Deserializer<'de> for Deserializer<Value> {
         self,
         _name: &'static str,
         _variants: &'static [&'static str],
-        _visitor: V,
+        visitor: V,
     ) -> Result<V::Value, Self::Error> {
-        // match self.0 {
-        //     Value::Map(x) if x.len() == 1 => visitor.visit_enum(Deserializer(&x[0])),
-        //     x @ Value::Text(..) => visitor.visit_enum(Deserializer(x)),
-        //     _ => Err(de::Error::invalid_type(self.0.into(), &"map")),
-        // }
-        todo!()
+        match self.0 {
+            Value::Text(variant) => visitor.visit_enum(EnumDeserializer {
+                variant,
+                value: Value::Null,
+            }),
+            Value::Map(mut map) if map.len() == 1 => match map.pop() {
+                Some((Value::Text(variant), value)) => {
+                    visitor.visit_enum(EnumDeserializer { variant, value })
+                }
+                Some((key, _)) => Err(de::Error::invalid_type((&key).into(), &"string")),
+                None => unreachable!("map has exactly one entry"),
+            },
+            value => Err(de::Error::invalid_type(
+                (&value).into(),
+                &"string or map with a single entry",
+            )),
+        }
     }
 
     fn is_human_readable(&self) -> bool {
@@ -449,6 +456,33 @@ impl<'de> de::Deserializer<'de> for Deserializer<Value> {
     }
 }
 
+/// Expands enumerations into the array of values they hold
+fn enum_values_as_array(value: Value) -> Vec<Value> {
+    match value {
+        Value::EnumU8(values) => values.into_iter().map(Value::U8).collect(),
+        Value::EnumString(values) => values.into_iter().map(Value::Text).collect(),
+        value => vec![value],
+    }
+}
+
+struct EnumDeserializer {
+    variant: String,
+    value: Value,
+}
+
+impl<'de> de::EnumAccess<'de> for EnumDeserializer {
+    type Error = Error;
+    type Variant = Deserializer<Value>;
+
+    fn variant_seed<V: de::DeserializeSeed<'de>>(
+        self,
+        seed: V,
+    ) -> Result<(V::Value, Self::Variant), Self::Error> {
+        let variant = seed.deserialize(Deserializer(Value::Text(self.variant)))?;
+        Ok((variant, Deserializer(self.value)))
+    }
+}
+
 struct ArrayDeserializer<'a>(slice::Iter<'a, Value>);
 
 impl<'a, 'de> de::SeqAccess<'de> for ArrayDeserializer<'a> {
@@ -143,4 +143,50 @@ mod tests {
 
         assert_eq!(yeet, yeet_back);
     }
+
+    /// Deserializer handing every request to `deserialize_enum`, like formats that tag enums
+    struct EnumDeserializer(Value);
+
+    impl<'de> serde::Deserializer<'de> for EnumDeserializer {
+        type Error = Error;
+
+        fn deserialize_any<V: serde::de::Visitor<'de>>(
+            self,
+            visitor: V,
+        ) -> Result<V::Value, Self::Error> {
+            de::Deserializer(self.0).deserialize_enum("Shape", &[], visitor)
+        }
+
+        serde::forward_to_deserialize_any! {
+            bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes
+            byte_buf option unit unit_struct newtype_struct seq tuple tuple_struct map struct
+            enum identifier ignored_any
+        }
+    }
+
+    #[test]
+    fn enum_variants_should_deserialize_into_values() {
+        #[derive(Serialize)]
+        enum Shape {
+            Point,
+            Circle(u32),
+            Segment(u8, u8),
+            Rectangle { width: u32, height: u32 },
+        }
+
+        for shape in [
+            Shape::Point,
+            Shape::Circle(3),
+            Shape::Segment(1, 2),
+            Shape::Rectangle {
+                width: 4,
+                height: 5,
+            },
+        ] {
+            let value = to_value(&shape).expect("shape should serialize");
+            let deserialized =
+                Value::deserialize(EnumDeserializer(value.clone())).expect("should deserialize");
+            assert_eq!(deserialized, value);
+        }
+    }
 }
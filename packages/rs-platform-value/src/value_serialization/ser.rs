@@ -33,8 +33,27 @@ impl Serialize for Value {
                 }
                 map.end()
             }
-            Value::U128(i) => serializer.serialize_u128(*i),
-            Value::I128(i) => serializer.serialize_i128(*i),
+            Value::U128(i) => {
+                // human readable formats like JSON usually can't hold more than 64 bits
+                if serializer.is_human_readable() {
+                    match u64::try_from(*i) {
+                        Ok(i) => serializer.serialize_u64(i),
+                        Err(_) => serializer.serialize_str(i.to_string().as_str()),
+                    }
+                } else {
+                    serializer.serialize_u128(*i)
+                }
+            }
+            Value::I128(i) => {
+                if serializer.is_human_readable() {
+                    match i64::try_from(*i) {
+                        Ok(i) => serializer.serialize_i64(i),
+                        Err(_) => serializer.serialize_str(i.to_string().as_str()),
+                    }
+                } else {
+                    serializer.serialize_i128(*i)
+                }
+            }
             Value::U64(i) => serializer.serialize_u64(*i),
             Value::I64(i) => serializer.serialize_i64(*i),
             Value::U32(i) => serializer.serialize_u32(*i),
@@ -80,8 +99,8 @@ impl Serialize for Value {
             }
             Value::Float(f64) => serializer.serialize_f64(*f64),
             Value::Text(string) => serializer.serialize_str(string),
-            Value::EnumU8(_x) => todo!(),
-            Value::EnumString(_x) => todo!(),
+            Value::EnumU8(values) => values.serialize(serializer),
+            Value::EnumString(values) => values.serialize(serializer),
         }
     }
 }
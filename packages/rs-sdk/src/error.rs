@@ -0,0 +1,49 @@
+//! Errors of the SDK
+
+use dpp::ProtocolError;
+
+/// SDK error
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// Could not connect to the node
+    #[error("connection error: {0}")]
+    Connection(#[from] tonic::transport::Error),
+    /// The node failed to answer the request
+    #[error("request error: {0}")]
+    Request(#[from] tonic::Status),
+    /// The proof could not be verified by Drive
+    #[error("drive error: {0}")]
+    Drive(#[from] drive::error::Error),
+    /// Protocol error
+    #[error("protocol error: {0}")]
+    Protocol(#[from] ProtocolError),
+    /// The response has no proof
+    #[error("response has no proof")]
+    MissingProof,
+    /// The response has no metadata
+    #[error("response has no metadata")]
+    MissingMetadata,
+    /// The proof doesn't prove the response
+    #[error("invalid proof: {0}")]
+    InvalidProof(String),
+    /// The public key of the quorum which signed the proof is unknown
+    #[error("unknown quorum {0}")]
+    UnknownQuorum(String),
+    /// The query can't be made
+    #[error("invalid query: {0}")]
+    InvalidQuery(String),
+    /// The state transition was rejected by the platform
+    #[error("state transition rejected with code {code}: {message}")]
+    StateTransitionRejected {
+        /// Consensus error code
+        code: u32,
+        /// Error message
+        message: String,
+    },
+    /// The proof shows that the state transition was not executed yet
+    #[error("state transition result not found")]
+    StateTransitionResultNotFound,
+    /// The state transition is signed with an asset lock and not by an identity key
+    #[error("state transition is not signed by an identity")]
+    NotIdentitySigned,
+}
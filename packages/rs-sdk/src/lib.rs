@@ -0,0 +1,32 @@
+//! Dash Platform SDK
+//!
+//! A client of the platform gRPC API served by DAPI which never trusts the node it talks to.
+//! Every query is made with `prove: true`: the GroveDB proof of the response is verified with the
+//! Drive verifiers, which give the root hash the proof leads to, and the signature of the proof is
+//! checked against the public key of the validator quorum, over the root hash and the response
+//! metadata, the same way Tenderdash signs the commit of a block.
+//!
+//! ```ignore
+//! let sdk = Sdk::connect("https://127.0.0.1:1443", quorum_public_keys).await?;
+//! let identity = sdk.fetch_identity(identity_id).await?;
+//!
+//! let query = DocumentQuery::new(contract, "domain")?
+//!     .with_where("normalizedParentDomainName", WhereOperator::Equal, "dash")
+//!     .with_order_by("normalizedLabel", true)
+//!     .with_limit(10);
+//! let documents = sdk.fetch_documents(&query).await?;
+//! ```
+
+#![deny(missing_docs)]
+
+pub mod error;
+pub mod proof;
+pub mod query;
+pub mod quorum;
+pub mod sdk;
+pub mod signer;
+
+pub use error::Error;
+pub use query::DocumentQuery;
+pub use quorum::QuorumPublicKeyProvider;
+pub use sdk::Sdk;
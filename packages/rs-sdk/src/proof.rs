@@ -0,0 +1,173 @@
+//! Verification of the signature of the proofs
+//!
+//! A proof is served together with the signature of the commit of the block which resulted in
+//! the proven state. The commit signs a canonical vote made of the hash of the block id and the
+//! hash of the state id, and the state id holds the app hash along with the height, the time, the
+//! core chain locked height and the protocol version returned in the response metadata. Rebuilding
+//! the state id from the root hash given by the GroveDB proof and from the metadata, and checking
+//! the signature of the vote with the quorum public key, proves both.
+
+use dapi_grpc::platform::v0::{Proof, ResponseMetadata};
+use dpp::bls_signatures::Signature;
+use tenderdash_abci::proto::types::{CanonicalVote, SignedMsgType, StateId};
+use tenderdash_abci::signatures::{SignBytes, SignDigest};
+
+use crate::{Error, QuorumPublicKeyProvider};
+
+/// Verifies that the quorum signed the root hash of the proof and the response metadata
+pub fn verify_proof_signature<P: QuorumPublicKeyProvider + ?Sized>(
+    proof: &Proof,
+    metadata: &ResponseMetadata,
+    root_hash: [u8; 32],
+    quorum_public_keys: &P,
+) -> Result<(), Error> {
+    let quorum_hash = quorum_hash(proof)?;
+
+    let signature = Signature::from_bytes(&proof.signature)
+        .map_err(|e| Error::InvalidProof(format!("malformed signature: {}", e)))?;
+
+    let digest = sign_digest(proof, metadata, root_hash)?;
+
+    let public_key = quorum_public_keys.quorum_public_key(proof.quorum_type, quorum_hash)?;
+
+    if public_key.verify(&signature, &digest) {
+        Ok(())
+    } else {
+        Err(Error::InvalidProof(format!(
+            "signature {} doesn't match the root hash {} and the metadata at height {}",
+            hex::encode(&proof.signature),
+            hex::encode(root_hash),
+            metadata.height
+        )))
+    }
+}
+
+fn quorum_hash(proof: &Proof) -> Result<[u8; 32], Error> {
+    proof.quorum_hash.as_slice().try_into().map_err(|_| {
+        Error::InvalidProof(format!(
+            "quorum hash must be 32 bytes, got {}",
+            proof.quorum_hash.len()
+        ))
+    })
+}
+
+/// The digest the quorum signed for a state with the given root hash
+fn sign_digest(
+    proof: &Proof,
+    metadata: &ResponseMetadata,
+    root_hash: [u8; 32],
+) -> Result<Vec<u8>, Error> {
+    let chain_id = metadata.chain_id.as_str();
+    let height = metadata.height as i64;
+    let round = proof.round as i32;
+
+    let state_id = StateId {
+        app_version: metadata.protocol_version as u64,
+        core_chain_locked_height: metadata.core_chain_locked_height,
+        app_hash: root_hash.to_vec(),
+        height: metadata.height,
+        time: metadata.time_ms,
+    };
+
+    let state_id_hash = state_id
+        .sha256(chain_id, height, round)
+        .map_err(|e| Error::InvalidProof(format!("cannot hash the state id: {}", e)))?;
+
+    let vote = CanonicalVote {
+        block_id: proof.block_id_hash.clone(),
+        state_id: state_id_hash,
+        chain_id: chain_id.to_string(),
+        height,
+        round: proof.round as i64,
+        r#type: SignedMsgType::Precommit.into(),
+    };
+
+    vote.sign_digest(
+        chain_id,
+        proof.quorum_type as u8,
+        &quorum_hash(proof)?,
+        height,
+        round,
+    )
+    .map_err(|e| Error::InvalidProof(format!("cannot compute the signed digest: {}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dpp::bls_signatures::{PrivateKey, PublicKey};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use std::collections::BTreeMap;
+
+    const QUORUM_HASH: [u8; 32] = [7; 32];
+    const ROOT_HASH: [u8; 32] = [3; 32];
+
+    fn signed_proof() -> (Proof, ResponseMetadata, BTreeMap<[u8; 32], PublicKey>) {
+        let mut rng = StdRng::seed_from_u64(5);
+        let private_key = PrivateKey::generate_dash_many(1, &mut rng)
+            .expect("expected to generate a private key")
+            .remove(0);
+        let public_key = private_key
+            .g1_element()
+            .expect("expected to get the public key");
+
+        let mut proof = Proof {
+            grovedb_proof: vec![],
+            quorum_hash: QUORUM_HASH.to_vec(),
+            signature: vec![],
+            round: 1,
+            block_id_hash: [9; 32].to_vec(),
+            quorum_type: 106,
+        };
+        let metadata = ResponseMetadata {
+            height: 42,
+            core_chain_locked_height: 1000,
+            time_ms: 1_681_094_380_000,
+            protocol_version: 1,
+            chain_id: "dash-testnet".to_string(),
+        };
+
+        let digest = sign_digest(&proof, &metadata, ROOT_HASH).expect("expected a digest");
+        proof.signature = private_key.sign(&digest).to_bytes().to_vec();
+
+        (proof, metadata, BTreeMap::from([(QUORUM_HASH, public_key)]))
+    }
+
+    #[test]
+    fn should_verify_signature_of_proof() {
+        let (proof, metadata, quorum_public_keys) = signed_proof();
+
+        verify_proof_signature(&proof, &metadata, ROOT_HASH, &quorum_public_keys)
+            .expect("expected the signature to be valid");
+    }
+
+    #[test]
+    fn should_reject_proof_with_altered_metadata() {
+        let (proof, mut metadata, quorum_public_keys) = signed_proof();
+        metadata.time_ms += 1;
+
+        let result = verify_proof_signature(&proof, &metadata, ROOT_HASH, &quorum_public_keys);
+
+        assert!(matches!(result, Err(Error::InvalidProof(_))));
+    }
+
+    #[test]
+    fn should_reject_proof_of_another_root_hash() {
+        let (proof, metadata, quorum_public_keys) = signed_proof();
+
+        let result = verify_proof_signature(&proof, &metadata, [4; 32], &quorum_public_keys);
+
+        assert!(matches!(result, Err(Error::InvalidProof(_))));
+    }
+
+    #[test]
+    fn should_reject_proof_of_unknown_quorum() {
+        let (mut proof, metadata, quorum_public_keys) = signed_proof();
+        proof.quorum_hash = [8; 32].to_vec();
+
+        let result = verify_proof_signature(&proof, &metadata, ROOT_HASH, &quorum_public_keys);
+
+        assert!(matches!(result, Err(Error::UnknownQuorum(_))));
+    }
+}
@@ -0,0 +1,235 @@
+//! Document queries
+//!
+//! A [`DocumentQuery`] is turned both into the `getDocuments` request sent to the node and into
+//! the [`DriveQuery`] the proof of the response is verified with, so the two can't disagree on
+//! the where clauses, the ordering, the limit or the start.
+
+use std::sync::Arc;
+
+use dapi_grpc::platform::v0::{get_documents_request, GetDocumentsRequest};
+use dpp::platform_value::{Identifier, Value};
+use dpp::prelude::DataContract;
+use drive::drive::config::DriveConfig;
+use drive::query::{DriveQuery, WhereOperator};
+
+use crate::Error;
+
+/// Query of the documents of a type of a data contract
+#[derive(Clone, Debug)]
+pub struct DocumentQuery {
+    contract: Arc<DataContract>,
+    document_type_name: String,
+    where_clauses: Vec<Value>,
+    order_by: Vec<Value>,
+    limit: u16,
+    start: Option<(Identifier, bool)>,
+}
+
+impl DocumentQuery {
+    /// Query of all the documents of the type, up to the default limit
+    pub fn new(contract: Arc<DataContract>, document_type_name: &str) -> Result<Self, Error> {
+        contract.document_type_for_name(document_type_name)?;
+
+        Ok(Self {
+            contract,
+            document_type_name: document_type_name.to_string(),
+            where_clauses: vec![],
+            order_by: vec![],
+            limit: DriveConfig::default().default_query_limit,
+            start: None,
+        })
+    }
+
+    /// Adds a where clause, the field must be indexed
+    pub fn with_where(
+        mut self,
+        field: &str,
+        operator: WhereOperator,
+        value: impl Into<Value>,
+    ) -> Self {
+        self.where_clauses.push(Value::Array(vec![
+            Value::Text(field.to_string()),
+            Value::Text(operator_name(operator).to_string()),
+            value.into(),
+        ]));
+        self
+    }
+
+    /// Orders the documents by the field
+    pub fn with_order_by(mut self, field: &str, ascending: bool) -> Self {
+        self.order_by.push(Value::Array(vec![
+            Value::Text(field.to_string()),
+            Value::Text(if ascending { "asc" } else { "desc" }.to_string()),
+        ]));
+        self
+    }
+
+    /// Limits the number of documents returned
+    pub fn with_limit(mut self, limit: u16) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    /// Starts after the document with the given id, to page through the results
+    pub fn with_start_after(mut self, document_id: Identifier) -> Self {
+        self.start = Some((document_id, false));
+        self
+    }
+
+    /// Starts at the document with the given id
+    pub fn with_start_at(mut self, document_id: Identifier) -> Self {
+        self.start = Some((document_id, true));
+        self
+    }
+
+    /// The data contract of the documents
+    pub fn contract(&self) -> &DataContract {
+        &self.contract
+    }
+
+    /// The `getDocuments` request of the query, always asking for a proof
+    pub fn to_request(&self) -> Result<GetDocumentsRequest, Error> {
+        let r#where = encode_cbor(&Value::Array(self.where_clauses.clone()))?;
+
+        // the node fails to decode an empty ordering, it is left out instead
+        let order_by = if self.order_by.is_empty() {
+            vec![]
+        } else {
+            encode_cbor(&Value::Array(self.order_by.clone()))?
+        };
+
+        let start = self.start.map(|(document_id, included)| {
+            if included {
+                get_documents_request::Start::StartAt(document_id.to_vec())
+            } else {
+                get_documents_request::Start::StartAfter(document_id.to_vec())
+            }
+        });
+
+        Ok(GetDocumentsRequest {
+            data_contract_id: self.contract.id.to_vec(),
+            document_type: self.document_type_name.clone(),
+            r#where,
+            order_by,
+            limit: self.limit as u32,
+            prove: true,
+            start,
+        })
+    }
+
+    /// The Drive query the node executes for the request, to verify the proof with
+    pub fn to_drive_query(&self) -> Result<DriveQuery<'_>, Error> {
+        let document_type = self
+            .contract
+            .document_type_for_name(&self.document_type_name)?;
+
+        let order_by = if self.order_by.is_empty() {
+            None
+        } else {
+            Some(Value::Array(self.order_by.clone()))
+        };
+
+        let (start_at, start_at_included) = match self.start {
+            Some((document_id, included)) => (Some(document_id.to_buffer()), included),
+            None => (None, true),
+        };
+
+        Ok(DriveQuery::from_decomposed_values(
+            Value::Array(self.where_clauses.clone()),
+            order_by,
+            Some(self.limit),
+            start_at,
+            start_at_included,
+            None,
+            &self.contract,
+            document_type,
+            &DriveConfig::default(),
+        )?)
+    }
+}
+
+fn encode_cbor(value: &Value) -> Result<Vec<u8>, Error> {
+    let mut buffer = vec![];
+    ciborium::ser::into_writer(value, &mut buffer)
+        .map_err(|e| Error::InvalidQuery(format!("cannot encode the query: {}", e)))?;
+    Ok(buffer)
+}
+
+fn operator_name(operator: WhereOperator) -> &'static str {
+    match operator {
+        WhereOperator::Equal => "==",
+        WhereOperator::GreaterThan => ">",
+        WhereOperator::GreaterThanOrEquals => ">=",
+        WhereOperator::LessThan => "<",
+        WhereOperator::LessThanOrEquals => "<=",
+        WhereOperator::Between => "between",
+        WhereOperator::BetweenExcludeBounds => "betweenExcludeBounds",
+        WhereOperator::BetweenExcludeLeft => "betweenExcludeLeft",
+        WhereOperator::BetweenExcludeRight => "betweenExcludeRight",
+        WhereOperator::In => "in",
+        WhereOperator::StartsWith => "startsWith",
+        WhereOperator::Contains => "contains",
+        WhereOperator::ContainsAny => "containsAny",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dpp::tests::fixtures::get_dpns_data_contract_fixture;
+
+    fn dpns_query() -> DocumentQuery {
+        let contract = get_dpns_data_contract_fixture(None).data_contract;
+
+        DocumentQuery::new(Arc::new(contract), "domain")
+            .expect("expected the domain document type")
+            .with_where("normalizedParentDomainName", WhereOperator::Equal, "dash")
+            .with_where("normalizedLabel", WhereOperator::StartsWith, "al")
+            .with_order_by("normalizedLabel", true)
+            .with_limit(5)
+    }
+
+    #[test]
+    fn should_encode_query_the_way_the_node_decodes_it() {
+        let query = dpns_query().with_start_after(Identifier::new([1; 32]));
+
+        let request = query.to_request().expect("expected a request");
+
+        assert!(request.prove);
+        assert_eq!(request.limit, 5);
+        assert_eq!(
+            request.start,
+            Some(get_documents_request::Start::StartAfter(vec![1; 32]))
+        );
+
+        let where_clause: Value = ciborium::de::from_reader(request.r#where.as_slice())
+            .expect("expected to decode the where clauses");
+        let order_by: Value = ciborium::de::from_reader(request.order_by.as_slice())
+            .expect("expected to decode the ordering");
+
+        let drive_query = query.to_drive_query().expect("expected a drive query");
+        let decoded_query = DriveQuery::from_decomposed_values(
+            where_clause,
+            Some(order_by),
+            Some(request.limit as u16),
+            Some([1; 32]),
+            false,
+            None,
+            query.contract(),
+            drive_query.document_type,
+            &DriveConfig::default(),
+        )
+        .expect("expected the node to build the same query");
+
+        assert_eq!(drive_query, decoded_query);
+    }
+
+    #[test]
+    fn should_reject_unknown_document_type() {
+        let contract = get_dpns_data_contract_fixture(None).data_contract;
+
+        let result = DocumentQuery::new(Arc::new(contract), "unknown");
+
+        assert!(matches!(result, Err(Error::Protocol(_))));
+    }
+}
@@ -0,0 +1,32 @@
+//! Public keys of the validator quorums
+
+use std::collections::BTreeMap;
+
+use dpp::bls_signatures::PublicKey;
+
+use crate::Error;
+
+/// Gives the threshold public key of the validator quorum which signed a proof
+///
+/// The SDK doesn't talk to Core, the public keys are expected to come from a source trusted by the
+/// application, for example a Core node of its own or an SPV client following the quorums.
+pub trait QuorumPublicKeyProvider {
+    /// The public key of the quorum, the quorum hash is in the byte order of the proofs
+    fn quorum_public_key(
+        &self,
+        quorum_type: u32,
+        quorum_hash: [u8; 32],
+    ) -> Result<PublicKey, Error>;
+}
+
+impl QuorumPublicKeyProvider for BTreeMap<[u8; 32], PublicKey> {
+    fn quorum_public_key(
+        &self,
+        _quorum_type: u32,
+        quorum_hash: [u8; 32],
+    ) -> Result<PublicKey, Error> {
+        self.get(&quorum_hash)
+            .cloned()
+            .ok_or_else(|| Error::UnknownQuorum(hex::encode(quorum_hash)))
+    }
+}
@@ -0,0 +1,246 @@
+//! Client of the platform gRPC API
+
+use dapi_grpc::platform::v0::platform_client::PlatformClient;
+use dapi_grpc::platform::v0::{
+    get_data_contract_response, get_documents_response, get_identity_balance_response,
+    get_identity_response, wait_for_state_transition_result_response,
+    BroadcastStateTransitionRequest, GetDataContractRequest, GetIdentityRequest, Proof,
+    ResponseMetadata, WaitForStateTransitionResultRequest,
+};
+use dpp::dashcore::hashes::{sha256, Hash};
+use dpp::document::Document;
+use dpp::prelude::{DataContract, Identifier, Identity};
+use dpp::serialization_traits::PlatformSerializable;
+use dpp::state_transition::StateTransition;
+use drive::drive::state_transition_result::StateTransitionResult;
+use drive::drive::Drive;
+use tonic::transport::Channel;
+
+use crate::proof::verify_proof_signature;
+use crate::{DocumentQuery, Error, QuorumPublicKeyProvider};
+
+/// Takes the proof out of the result of a response, the variant is named the same in all of them
+macro_rules! proof_of {
+    ($response:expr, $result:ident) => {
+        match $response.result {
+            Some($result::Result::Proof(proof)) => proof,
+            _ => return Err(Error::MissingProof),
+        }
+    };
+}
+
+/// Platform client verifying the proofs of all the responses
+#[derive(Clone, Debug)]
+pub struct Sdk<P> {
+    client: PlatformClient<Channel>,
+    quorum_public_keys: P,
+    chain_id: Option<String>,
+}
+
+impl<P: QuorumPublicKeyProvider> Sdk<P> {
+    /// Connects to the platform gRPC API of a DAPI node, for example `https://127.0.0.1:1443`
+    pub async fn connect(address: impl Into<String>, quorum_public_keys: P) -> Result<Self, Error> {
+        let client = PlatformClient::connect(address.into()).await?;
+
+        Ok(Self::new(client, quorum_public_keys))
+    }
+
+    /// Uses a client which is already connected
+    pub fn new(client: PlatformClient<Channel>, quorum_public_keys: P) -> Self {
+        Self {
+            client,
+            quorum_public_keys,
+            chain_id: None,
+        }
+    }
+
+    /// Rejects the responses signed for another chain
+    pub fn with_chain_id(mut self, chain_id: impl Into<String>) -> Self {
+        self.chain_id = Some(chain_id.into());
+        self
+    }
+
+    /// Fetches an identity, `None` when the proof shows it doesn't exist
+    pub async fn fetch_identity(&self, identity_id: Identifier) -> Result<Option<Identity>, Error> {
+        let response = self
+            .client
+            .clone()
+            .get_identity(GetIdentityRequest {
+                id: identity_id.to_vec(),
+                prove: true,
+            })
+            .await?
+            .into_inner();
+
+        let proof = proof_of!(response, get_identity_response);
+
+        let (root_hash, identity) = Drive::verify_full_identity_by_identity_id(
+            &proof.grovedb_proof,
+            false,
+            identity_id.to_buffer(),
+        )?;
+
+        self.verify_response(&proof, response.metadata, root_hash)?;
+
+        Ok(identity)
+    }
+
+    /// Fetches the balance of an identity in credits, `None` when the identity doesn't exist
+    pub async fn fetch_identity_balance(
+        &self,
+        identity_id: Identifier,
+    ) -> Result<Option<u64>, Error> {
+        let response = self
+            .client
+            .clone()
+            .get_identity_balance(GetIdentityRequest {
+                id: identity_id.to_vec(),
+                prove: true,
+            })
+            .await?
+            .into_inner();
+
+        let proof = proof_of!(response, get_identity_balance_response);
+
+        let (root_hash, balance) = Drive::verify_identity_balance_for_identity_id(
+            &proof.grovedb_proof,
+            identity_id.to_buffer(),
+            false,
+        )?;
+
+        self.verify_response(&proof, response.metadata, root_hash)?;
+
+        Ok(balance)
+    }
+
+    /// Fetches a data contract, `None` when the proof shows it doesn't exist
+    pub async fn fetch_data_contract(
+        &self,
+        contract_id: Identifier,
+    ) -> Result<Option<DataContract>, Error> {
+        let response = self
+            .client
+            .clone()
+            .get_data_contract(GetDataContractRequest {
+                id: contract_id.to_vec(),
+                prove: true,
+            })
+            .await?
+            .into_inner();
+
+        let proof = proof_of!(response, get_data_contract_response);
+
+        let (root_hash, contract) =
+            Drive::verify_contract(&proof.grovedb_proof, None, false, contract_id.to_buffer())?;
+
+        self.verify_response(&proof, response.metadata, root_hash)?;
+
+        Ok(contract)
+    }
+
+    /// Fetches the documents matching the query
+    pub async fn fetch_documents(&self, query: &DocumentQuery) -> Result<Vec<Document>, Error> {
+        let response = self
+            .client
+            .clone()
+            .get_documents(query.to_request()?)
+            .await?
+            .into_inner();
+
+        let proof = proof_of!(response, get_documents_response);
+
+        let (root_hash, documents) = query.to_drive_query()?.verify_proof(&proof.grovedb_proof)?;
+
+        self.verify_response(&proof, response.metadata, root_hash)?;
+
+        Ok(documents)
+    }
+
+    /// Broadcasts a signed state transition, it is only checked by the node before being added
+    /// to the mempool
+    pub async fn broadcast_state_transition(
+        &self,
+        state_transition: &StateTransition,
+    ) -> Result<(), Error> {
+        self.client
+            .clone()
+            .broadcast_state_transition(BroadcastStateTransitionRequest {
+                state_transition: state_transition.serialize()?,
+            })
+            .await?;
+
+        Ok(())
+    }
+
+    /// Waits for a state transition to be executed in a block, the proof of its result is
+    /// verified
+    pub async fn wait_for_state_transition_result(
+        &self,
+        state_transition: &StateTransition,
+    ) -> Result<StateTransitionResult, Error> {
+        let state_transition_hash = sha256::Hash::hash(&state_transition.serialize()?);
+
+        let response = self
+            .client
+            .clone()
+            .wait_for_state_transition_result(WaitForStateTransitionResultRequest {
+                state_transition_hash: state_transition_hash.to_vec(),
+                prove: true,
+            })
+            .await?
+            .into_inner();
+
+        let proof = proof_of!(response, wait_for_state_transition_result_response);
+
+        let (root_hash, result) = Drive::verify_state_transition_result(
+            &proof.grovedb_proof,
+            state_transition_hash.into_inner(),
+            false,
+        )?;
+
+        self.verify_response(&proof, response.metadata, root_hash)?;
+
+        match result {
+            Some(result) if result.is_success() => Ok(result),
+            Some(result) => Err(Error::StateTransitionRejected {
+                code: result.code,
+                message: format!("state transition failed at height {}", result.height),
+            }),
+            None => Err(Error::StateTransitionResultNotFound),
+        }
+    }
+
+    /// Broadcasts a signed state transition and waits for its result, the wait starts first so
+    /// the result can't be missed
+    pub async fn broadcast_and_wait(
+        &self,
+        state_transition: &StateTransition,
+    ) -> Result<StateTransitionResult, Error> {
+        let (result, _) = tokio::try_join!(
+            self.wait_for_state_transition_result(state_transition),
+            self.broadcast_state_transition(state_transition),
+        )?;
+
+        Ok(result)
+    }
+
+    fn verify_response(
+        &self,
+        proof: &Proof,
+        metadata: Option<ResponseMetadata>,
+        root_hash: [u8; 32],
+    ) -> Result<(), Error> {
+        let metadata = metadata.ok_or(Error::MissingMetadata)?;
+
+        if let Some(chain_id) = &self.chain_id {
+            if &metadata.chain_id != chain_id {
+                return Err(Error::InvalidProof(format!(
+                    "response is for chain {}, expected {}",
+                    metadata.chain_id, chain_id
+                )));
+            }
+        }
+
+        verify_proof_signature(proof, &metadata, root_hash, &self.quorum_public_keys)
+    }
+}
@@ -0,0 +1,44 @@
+//! Signing of state transitions
+
+use dpp::identity::signer::Signer;
+use dpp::identity::IdentityPublicKey;
+use dpp::state_transition::{StateTransition, StateTransitionIdentitySigned};
+
+use crate::Error;
+
+/// Signs the state transition with a key of its owner identity
+///
+/// The private key never reaches the SDK, the [`Signer`] is given the public key to look it up.
+/// Identity create and top up transitions are signed with the key of their asset lock instead and
+/// can't be signed this way.
+pub fn sign_state_transition<S: Signer>(
+    state_transition: &mut StateTransition,
+    identity_public_key: &IdentityPublicKey,
+    signer: &S,
+) -> Result<(), Error> {
+    match state_transition {
+        StateTransition::DataContractCreate(transition) => {
+            transition.sign_external(identity_public_key, signer)?
+        }
+        StateTransition::DataContractUpdate(transition) => {
+            transition.sign_external(identity_public_key, signer)?
+        }
+        StateTransition::DocumentsBatch(transition) => {
+            transition.sign_external(identity_public_key, signer)?
+        }
+        StateTransition::IdentityCreditWithdrawal(transition) => {
+            transition.sign_external(identity_public_key, signer)?
+        }
+        StateTransition::IdentityUpdate(transition) => {
+            transition.sign_external(identity_public_key, signer)?
+        }
+        StateTransition::IdentityCreditTransfer(transition) => {
+            transition.sign_external(identity_public_key, signer)?
+        }
+        StateTransition::IdentityCreate(_) | StateTransition::IdentityTopUp(_) => {
+            return Err(Error::NotIdentitySigned)
+        }
+    }
+
+    Ok(())
+}
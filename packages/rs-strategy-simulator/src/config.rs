@@ -0,0 +1,413 @@
+//! Simulations read from TOML or JSON files
+//!
+//! A simulation file holds the strategy to run and the platform settings to run it with:
+//!
+//! ```toml
+//! total_hpmns = 100
+//! quorum_count = 24
+//! identities_inserts = { times_per_block_range = { start = 1, end = 2 } }
+//!
+//! [[contracts]]
+//! name = "dashpay"
+//! path = "dashpay-contract.json"
+//! updates = [{ block = 10, path = "dashpay-contract-update.json" }]
+//!
+//! [[operations]]
+//! type = "document"
+//! contract = "dashpay"
+//! document_type = "contactRequest"
+//! action = "insert"
+//! frequency = { times_per_block_range = { start = 1, end = 3 }, chance_per_block = 0.5 }
+//!
+//! [platform]
+//! block_spacing_ms = 3000
+//! ```
+//!
+//! Contract paths are relative to the simulation file.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use dpp::data_contract::extra::common::json_document_to_created_contract;
+use dpp::data_contract::CreatedDataContract;
+use dpp::ProtocolError;
+use drive_abci::config::{PlatformConfig, PlatformTestConfig};
+use serde::{Deserialize, Serialize};
+
+use crate::frequency::Frequency;
+use crate::operations::{DocumentAction, DocumentOp, IdentityUpdateOp, Operation, OperationType};
+use crate::query::QueryStrategy;
+use crate::strategy::{FailureStrategy, MasternodeListChangesStrategy, Strategy, UpgradingInfo};
+
+type ContractWithUpdates = (
+    CreatedDataContract,
+    Option<BTreeMap<u64, CreatedDataContract>>,
+);
+
+/// Errors reading a simulation
+#[derive(Debug, thiserror::Error)]
+pub enum ConfigError {
+    /// The simulation or a contract file could not be read
+    #[error("can't read {0}: {1}")]
+    Io(PathBuf, std::io::Error),
+    /// The simulation file is neither TOML nor JSON
+    #[error("unknown simulation file extension for {0}, expected .toml or .json")]
+    UnknownFormat(PathBuf),
+    /// The simulation file doesn't hold a valid simulation
+    #[error("invalid simulation: {0}")]
+    InvalidSimulation(String),
+    /// A contract file doesn't hold a valid contract
+    #[error("invalid contract {0}: {1}")]
+    InvalidContract(PathBuf, ProtocolError),
+    /// An operation refers to a contract or a document type that doesn't exist
+    #[error("unknown {0}")]
+    UnknownReference(String),
+}
+
+/// A strategy with the platform settings to run it with
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SimulationConfig {
+    /// Contracts created on the first block, with their updates
+    #[serde(default)]
+    pub contracts: Vec<ContractConfig>,
+    #[serde(default)]
+    pub operations: Vec<OperationConfig>,
+    #[serde(default)]
+    pub identities_inserts: Frequency,
+    pub total_hpmns: u16,
+    #[serde(default)]
+    pub extra_normal_mns: u16,
+    pub quorum_count: u16,
+    pub upgrading_info: Option<UpgradingInfo>,
+    #[serde(default)]
+    pub core_height_increase: Frequency,
+    #[serde(default)]
+    pub proposer_strategy: MasternodeListChangesStrategy,
+    #[serde(default)]
+    pub rotate_quorums: bool,
+    pub failure_testing: Option<FailureStrategy>,
+    pub query_testing: Option<QueryStrategy>,
+    #[serde(default)]
+    pub verify_state_transition_results: bool,
+    #[serde(default)]
+    pub platform: PlatformSettings,
+}
+
+/// A contract read from a JSON file
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ContractConfig {
+    /// The name operations refer to the contract with
+    pub name: String,
+    pub path: PathBuf,
+    #[serde(default)]
+    pub updates: Vec<ContractUpdateConfig>,
+}
+
+/// A contract update read from a JSON file, applied at a block height
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ContractUpdateConfig {
+    pub block: u64,
+    pub path: PathBuf,
+}
+
+/// An operation and how often it happens
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct OperationConfig {
+    #[serde(flatten)]
+    pub op_type: OperationTypeConfig,
+    pub frequency: Frequency,
+}
+
+/// The operations that can be described in a file
+///
+/// Random contract creations and updates need document type generation parameters and can only
+/// be described in Rust.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum OperationTypeConfig {
+    Document {
+        contract: String,
+        document_type: String,
+        action: DocumentActionConfig,
+    },
+    IdentityTopUp,
+    IdentityUpdateAddKeys {
+        count: u16,
+    },
+    IdentityUpdateDisableKeys {
+        count: u16,
+    },
+    IdentityWithdrawal,
+    IdentityTransfer,
+}
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DocumentActionConfig {
+    Insert,
+    Delete,
+    Replace,
+}
+
+/// The platform settings of a simulation, defaulting to the ones of the strategy tests
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PlatformSettings {
+    pub verify_sum_trees: bool,
+    pub quorum_size: u16,
+    pub validator_set_quorum_rotation_block_count: u32,
+    pub block_spacing_ms: u64,
+    /// Signs blocks and verifies commit signatures, which makes the simulation much slower
+    pub block_signing: bool,
+    /// The core height of the chain locks returned by the mocked Core RPC
+    pub core_chain_lock_height: u32,
+}
+
+impl Default for PlatformSettings {
+    fn default() -> Self {
+        Self {
+            verify_sum_trees: true,
+            quorum_size: 100,
+            validator_set_quorum_rotation_block_count: 25,
+            block_spacing_ms: 3000,
+            block_signing: false,
+            core_chain_lock_height: 10,
+        }
+    }
+}
+
+impl PlatformSettings {
+    pub fn platform_config(&self) -> PlatformConfig {
+        let testing_configs = if self.block_signing {
+            PlatformTestConfig::default()
+        } else {
+            PlatformTestConfig::default_with_no_block_signing()
+        };
+        PlatformConfig {
+            verify_sum_trees: self.verify_sum_trees,
+            quorum_size: self.quorum_size,
+            validator_set_quorum_rotation_block_count: self
+                .validator_set_quorum_rotation_block_count,
+            block_spacing_ms: self.block_spacing_ms,
+            testing_configs,
+            ..Default::default()
+        }
+    }
+}
+
+impl SimulationConfig {
+    /// Reads a simulation from a `.toml` or a `.json` file
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, ConfigError> {
+        let path = path.as_ref();
+        let content =
+            fs::read_to_string(path).map_err(|e| ConfigError::Io(path.to_path_buf(), e))?;
+        let mut config: Self = match path.extension().and_then(|extension| extension.to_str()) {
+            Some("toml") => toml::from_str(&content)
+                .map_err(|e| ConfigError::InvalidSimulation(e.to_string()))?,
+            Some("json") => serde_json::from_str(&content)
+                .map_err(|e| ConfigError::InvalidSimulation(e.to_string()))?,
+            _ => return Err(ConfigError::UnknownFormat(path.to_path_buf())),
+        };
+        if let Some(directory) = path.parent() {
+            config.resolve_paths(directory);
+        }
+        Ok(config)
+    }
+
+    /// Reads the contracts and builds the strategy
+    pub fn strategy(&self) -> Result<Strategy, ConfigError> {
+        let contracts = self
+            .contracts
+            .iter()
+            .map(|contract| Ok((contract.name.as_str(), contract.read()?)))
+            .collect::<Result<Vec<_>, ConfigError>>()?;
+
+        let operations = self
+            .operations
+            .iter()
+            .map(|operation| {
+                Ok(Operation {
+                    op_type: operation.op_type.operation_type(&contracts)?,
+                    frequency: operation.frequency.clone(),
+                })
+            })
+            .collect::<Result<Vec<Operation>, ConfigError>>()?;
+
+        Ok(Strategy {
+            contracts_with_updates: contracts
+                .into_iter()
+                .map(|(_, contract_with_updates)| contract_with_updates)
+                .collect(),
+            operations,
+            identities_inserts: self.identities_inserts.clone(),
+            total_hpmns: self.total_hpmns,
+            extra_normal_mns: self.extra_normal_mns,
+            quorum_count: self.quorum_count,
+            upgrading_info: self.upgrading_info.clone(),
+            core_height_increase: self.core_height_increase.clone(),
+            proposer_strategy: self.proposer_strategy.clone(),
+            rotate_quorums: self.rotate_quorums,
+            failure_testing: self.failure_testing.clone(),
+            query_testing: self.query_testing.clone(),
+            verify_state_transition_results: self.verify_state_transition_results,
+        })
+    }
+
+    fn resolve_paths(&mut self, directory: &Path) {
+        for contract in self.contracts.iter_mut() {
+            contract.path = directory.join(&contract.path);
+            for update in contract.updates.iter_mut() {
+                update.path = directory.join(&update.path);
+            }
+        }
+    }
+}
+
+impl ContractConfig {
+    fn read(&self) -> Result<ContractWithUpdates, ConfigError> {
+        let contract = read_contract(&self.path)?;
+        if self.updates.is_empty() {
+            return Ok((contract, None));
+        }
+        let mut updates = self.updates.iter().collect::<Vec<_>>();
+        updates.sort_by_key(|update| update.block);
+        let updates = updates
+            .into_iter()
+            .enumerate()
+            .map(|(index, update)| {
+                let mut contract_update = read_contract(&update.path)?;
+                //todo: versions should start at 0 (so the first update should be 1)
+                contract_update.data_contract.version = index as u32 + 2;
+                Ok((update.block, contract_update))
+            })
+            .collect::<Result<BTreeMap<u64, CreatedDataContract>, ConfigError>>()?;
+        Ok((contract, Some(updates)))
+    }
+}
+
+impl OperationTypeConfig {
+    fn operation_type(
+        &self,
+        contracts: &[(&str, ContractWithUpdates)],
+    ) -> Result<OperationType, ConfigError> {
+        Ok(match self {
+            OperationTypeConfig::Document {
+                contract,
+                document_type,
+                action,
+            } => {
+                let (_, (created_contract, _)) = contracts
+                    .iter()
+                    .find(|(name, _)| *name == contract.as_str())
+                    .ok_or_else(|| {
+                        ConfigError::UnknownReference(format!("contract {}", contract))
+                    })?;
+                let contract = &created_contract.data_contract;
+                let document_type = contract
+                    .document_type_for_name(document_type)
+                    .map_err(|_| {
+                        ConfigError::UnknownReference(format!("document type {}", document_type))
+                    })?
+                    .clone();
+                OperationType::Document(DocumentOp {
+                    contract: contract.clone(),
+                    document_type,
+                    action: match action {
+                        DocumentActionConfig::Insert => DocumentAction::DocumentActionInsert,
+                        DocumentActionConfig::Delete => DocumentAction::DocumentActionDelete,
+                        DocumentActionConfig::Replace => DocumentAction::DocumentActionReplace,
+                    },
+                })
+            }
+            OperationTypeConfig::IdentityTopUp => OperationType::IdentityTopUp,
+            OperationTypeConfig::IdentityUpdateAddKeys { count } => {
+                OperationType::IdentityUpdate(IdentityUpdateOp::IdentityUpdateAddKeys(*count))
+            }
+            OperationTypeConfig::IdentityUpdateDisableKeys { count } => {
+                OperationType::IdentityUpdate(IdentityUpdateOp::IdentityUpdateDisableKey(*count))
+            }
+            OperationTypeConfig::IdentityWithdrawal => OperationType::IdentityWithdrawal,
+            OperationTypeConfig::IdentityTransfer => OperationType::IdentityTransfer,
+        })
+    }
+}
+
+fn read_contract(path: &Path) -> Result<CreatedDataContract, ConfigError> {
+    json_document_to_created_contract(path)
+        .map_err(|e| ConfigError::InvalidContract(path.to_path_buf(), e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_build_a_strategy_from_toml() {
+        let config: SimulationConfig = toml::from_str(
+            r#"
+            total_hpmns = 100
+            quorum_count = 24
+            identities_inserts = { times_per_block_range = { start = 1, end = 2 } }
+
+            [[contracts]]
+            name = "dashpay"
+            path = "../rs-drive-abci/tests/supporting_files/contract/dashpay/dashpay-contract-all-mutable.json"
+
+            [[operations]]
+            type = "document"
+            contract = "dashpay"
+            document_type = "contactRequest"
+            action = "insert"
+            frequency = { times_per_block_range = { start = 1, end = 3 }, chance_per_block = 0.5 }
+
+            [[operations]]
+            type = "identity_update_add_keys"
+            count = 3
+            frequency = { times_per_block_range = { start = 1, end = 2 } }
+            "#,
+        )
+        .expect("expected a valid simulation");
+
+        assert_eq!(config.platform.quorum_size, 100);
+
+        let strategy = config.strategy().expect("expected a strategy");
+        assert_eq!(strategy.contracts_with_updates.len(), 1);
+        assert_eq!(strategy.identities_inserts.times_per_block_range, 1..2);
+        assert!(matches!(
+            &strategy.operations[0].op_type,
+            OperationType::Document(DocumentOp {
+                action: DocumentAction::DocumentActionInsert,
+                ..
+            })
+        ));
+        assert_eq!(strategy.operations[0].frequency.chance_per_block, Some(0.5));
+        assert!(matches!(
+            strategy.operations[1].op_type,
+            OperationType::IdentityUpdate(IdentityUpdateOp::IdentityUpdateAddKeys(3))
+        ));
+    }
+
+    #[test]
+    fn should_refuse_operations_on_unknown_contracts() {
+        let config: SimulationConfig = serde_json::from_str(
+            r#"{
+                "total_hpmns": 100,
+                "quorum_count": 24,
+                "operations": [{
+                    "type": "document",
+                    "contract": "dpns",
+                    "document_type": "domain",
+                    "action": "insert",
+                    "frequency": {}
+                }]
+            }"#,
+        )
+        .expect("expected a valid simulation");
+
+        assert!(matches!(
+            config.strategy(),
+            Err(ConfigError::UnknownReference(_))
+        ));
+    }
+}
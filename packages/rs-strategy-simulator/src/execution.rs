@@ -34,7 +34,7 @@ use tenderdash_abci::proto::google::protobuf::Timestamp;
 use tenderdash_abci::proto::serializers::timestamp::FromMilis;
 use tenderdash_abci::Application;
 
-pub(crate) fn run_chain_for_strategy(
+pub fn run_chain_for_strategy(
     platform: &mut Platform<MockCoreRPCLike>,
     block_count: u64,
     strategy: Strategy,
@@ -459,7 +459,7 @@ pub(crate) fn run_chain_for_strategy(
     )
 }
 
-pub(crate) fn create_chain_for_strategy(
+pub fn create_chain_for_strategy(
     platform: &Platform<MockCoreRPCLike>,
     block_count: u64,
     proposers_with_updates: Vec<MasternodeListItemWithUpdates>,
@@ -486,7 +486,7 @@ pub(crate) fn create_chain_for_strategy(
     )
 }
 
-pub(crate) fn start_chain_for_strategy(
+pub fn start_chain_for_strategy(
     abci_application: AbciApplication<MockCoreRPCLike>,
     block_count: u64,
     proposers_with_updates: Vec<MasternodeListItemWithUpdates>,
@@ -570,7 +570,7 @@ pub(crate) fn start_chain_for_strategy(
     )
 }
 
-pub(crate) fn continue_chain_for_strategy(
+pub fn continue_chain_for_strategy(
     abci_app: AbciApplication<MockCoreRPCLike>,
     chain_execution_parameters: ChainExecutionParameters,
     mut strategy: Strategy,
@@ -622,6 +622,10 @@ pub(crate) fn continue_chain_for_strategy(
 
     let mut validator_set_updates = BTreeMap::new();
 
+    let mut app_hashes = BTreeMap::new();
+
+    let mut state_transition_results_per_block = BTreeMap::new();
+
     for block_height in block_start..(block_start + block_count) {
         let epoch_info = EpochInfo::calculate(
             first_block_time,
@@ -688,6 +692,7 @@ pub(crate) fn continue_chain_for_strategy(
             block_id_hash: block_hash,
             signature,
             app_version,
+            state_transition_results,
         } = abci_app
             .mimic_execute_block(
                 proposer.pro_tx_hash.into_inner(),
@@ -712,6 +717,17 @@ pub(crate) fn continue_chain_for_strategy(
 
         total_withdrawals.append(&mut withdrawals_this_block);
 
+        app_hashes.insert(block_height, root_app_hash);
+
+        state_transition_results_per_block.insert(
+            block_height,
+            state_transitions
+                .iter()
+                .cloned()
+                .zip(state_transition_results)
+                .collect(),
+        );
+
         for finalize_block_operation in finalize_block_operations {
             match finalize_block_operation {
                 IdentityAddKeys(identifier, keys) => {
@@ -808,5 +824,7 @@ pub(crate) fn continue_chain_for_strategy(
         strategy,
         withdrawals: total_withdrawals,
         validator_set_updates,
+        app_hashes,
+        state_transition_results_per_block,
     }
 }
@@ -0,0 +1,26 @@
+//! Strategy based chain simulations
+//!
+//! A [`Strategy`](strategy::Strategy) describes what happens on a chain block after block: the
+//! identities and contracts created, the documents and identities updated, the masternode list
+//! changes and the protocol upgrades. [`run_chain_for_strategy`](execution::run_chain_for_strategy)
+//! executes the strategy for a number of blocks against a platform with a mocked Core RPC, the
+//! way Tenderdash would drive it through ABCI.
+//!
+//! Strategies can also be read from TOML or JSON files with [`config::SimulationConfig`] and run
+//! with the `strategy-simulator` binary, which reports the app hash, the fees and the results of
+//! the state transitions of every block.
+
+pub mod config;
+pub mod execution;
+pub mod frequency;
+pub mod masternode_list_item_helpers;
+pub mod masternodes;
+pub mod operations;
+pub mod query;
+pub mod report;
+pub mod signer;
+pub mod strategy;
+pub mod transitions;
+pub mod verify_state_transitions;
+
+pub type BlockHeight = u64;
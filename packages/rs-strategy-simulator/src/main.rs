@@ -0,0 +1,79 @@
+//! Runs a strategy read from a TOML or JSON file against a temporary platform with a mocked Core
+//! RPC, and writes the app hash, the fees and the state transition results of every block as JSON.
+
+use clap::Parser;
+use drive_abci::test::helpers::setup::TestPlatformBuilder;
+use std::fs::File;
+use std::io::{stdout, Write};
+use std::path::PathBuf;
+use std::process::ExitCode;
+use strategy_simulator::config::SimulationConfig;
+use strategy_simulator::execution::run_chain_for_strategy;
+use strategy_simulator::report::SimulationReport;
+use tenderdash_abci::proto::types::CoreChainLock;
+
+/// Strategy based chain simulator
+#[derive(Debug, Parser)]
+#[command(author, version)]
+struct Cli {
+    /// Path to the simulation (.toml or .json) file.
+    #[arg(value_hint = clap::ValueHint::FilePath)]
+    simulation: PathBuf,
+
+    /// Number of blocks to run.
+    #[arg(short, long, default_value_t = 100)]
+    blocks: u64,
+
+    /// Seed of the randomness, the same seed runs the same chain.
+    #[arg(short, long, default_value_t = 0)]
+    seed: u64,
+
+    /// Path of the JSON report, written to stdout if not set.
+    #[arg(short, long, value_hint = clap::ValueHint::FilePath)]
+    output: Option<PathBuf>,
+}
+
+impl Cli {
+    fn run(self) -> Result<(), String> {
+        let simulation =
+            SimulationConfig::from_file(&self.simulation).map_err(|e| e.to_string())?;
+        let strategy = simulation.strategy().map_err(|e| e.to_string())?;
+        let config = simulation.platform.platform_config();
+
+        let mut platform = TestPlatformBuilder::new()
+            .with_config(config.clone())
+            .build_with_mock_rpc();
+
+        let core_chain_lock_height = simulation.platform.core_chain_lock_height;
+        platform
+            .core_rpc
+            .expect_get_best_chain_lock()
+            .returning(move || {
+                Ok(CoreChainLock {
+                    core_block_height: core_chain_lock_height,
+                    core_block_hash: [1; 32].to_vec(),
+                    signature: [2; 96].to_vec(),
+                })
+            });
+
+        let outcome =
+            run_chain_for_strategy(&mut platform, self.blocks, strategy, config, self.seed);
+        let report = SimulationReport::from(&outcome);
+
+        let writer: Box<dyn Write> = match &self.output {
+            Some(path) => Box::new(File::create(path).map_err(|e| e.to_string())?),
+            None => Box::new(stdout()),
+        };
+        serde_json::to_writer_pretty(writer, &report).map_err(|e| e.to_string())
+    }
+}
+
+fn main() -> ExitCode {
+    match Cli::parse().run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("strategy-simulator failed: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
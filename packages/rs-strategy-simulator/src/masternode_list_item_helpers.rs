@@ -1,4 +1,4 @@
-use crate::BlsPrivateKey;
+use dpp::bls_signatures::PrivateKey as BlsPrivateKey;
 use dashcore_rpc::json::MasternodeListItem;
 use rand::prelude::IteratorRandom;
 use rand::rngs::StdRng;
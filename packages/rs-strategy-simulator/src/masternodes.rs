@@ -556,11 +556,11 @@ pub struct MasternodeListItemWithUpdates {
 }
 
 impl MasternodeListItemWithUpdates {
-    pub(crate) fn pro_tx_hash(&self) -> ProTxHash {
+    pub fn pro_tx_hash(&self) -> ProTxHash {
         self.masternode.pro_tx_hash
     }
 
-    pub(crate) fn get_state_at_height(&self, height: u32) -> &MasternodeListItem {
+    pub fn get_state_at_height(&self, height: u32) -> &MasternodeListItem {
         // Find the closest height less than or equal to the given height
         let closest_height = self.updates.range(..=height).rev().next().map(|(k, _)| *k);
 
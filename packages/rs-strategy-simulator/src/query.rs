@@ -16,13 +16,15 @@ use prost::Message;
 use rand::prelude::SliceRandom;
 use rand::rngs::StdRng;
 use rand::{Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
 use tenderdash_abci::proto::google::protobuf::Timestamp;
 use tenderdash_abci::proto::serializers::timestamp::ToMilis;
 use tenderdash_abci::proto::types::{CanonicalVote, SignedMsgType, StateId};
 use tenderdash_abci::signatures::{SignBytes, SignDigest};
 
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct QueryStrategy {
     pub query_identities_by_public_key_hashes: Frequency,
 }
@@ -158,7 +160,7 @@ impl<'a> ProofVerification<'a> {
 }
 
 impl QueryStrategy {
-    pub(crate) fn query_chain_for_strategy(
+    pub fn query_chain_for_strategy(
         &self,
         proof_verification: &ProofVerification,
         current_identities: &Vec<Identity>,
@@ -183,7 +185,7 @@ impl QueryStrategy {
         }
     }
 
-    pub(crate) fn query_identities_by_public_key_hashes(
+    pub fn query_identities_by_public_key_hashes(
         proof_verification: &ProofVerification,
         current_identities: &Vec<Identity>,
         frequency: &Frequency,
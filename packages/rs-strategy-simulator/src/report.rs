@@ -0,0 +1,114 @@
+//! What a simulation did, block by block
+
+use dpp::serialization_traits::PlatformSerializable;
+use dpp::state_transition::{StateTransition, StateTransitionLike};
+use drive::fee::credits::Credits;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tenderdash_abci::proto::abci::ExecTxResult;
+
+use crate::strategy::ChainExecutionOutcome;
+
+/// The outcome of a simulation, serializable to JSON
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SimulationReport {
+    pub blocks: Vec<BlockReport>,
+    /// Fees paid by the state transitions of all blocks
+    pub total_fees: Credits,
+    pub valid_state_transitions: u64,
+    pub invalid_state_transitions: u64,
+    pub end_epoch_index: u16,
+    pub end_time_ms: u64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockReport {
+    pub height: u64,
+    /// Hex encoded root app hash after the block was finalized
+    pub app_hash: String,
+    /// Fees paid by the state transitions of the block
+    pub fees: Credits,
+    pub state_transitions: Vec<StateTransitionReport>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StateTransitionReport {
+    /// Hex encoded hash of the serialized state transition, as Tenderdash hashes transactions
+    pub hash: String,
+    pub state_transition_type: String,
+    /// False if the state transition was removed from the block because it was invalid
+    pub valid: bool,
+    /// Fee estimated before execution
+    pub estimated_fee: Credits,
+    /// Fee paid
+    pub fee: Credits,
+}
+
+impl StateTransitionReport {
+    fn new(state_transition: &StateTransition, result: Option<&ExecTxResult>) -> Self {
+        let hash = state_transition
+            .serialize()
+            .map(|serialized| hex::encode(Sha256::digest(serialized)))
+            .unwrap_or_default();
+        Self {
+            hash,
+            state_transition_type: state_transition.get_type().to_string(),
+            valid: result.is_some(),
+            estimated_fee: result
+                .map(|result| result.gas_wanted as Credits)
+                .unwrap_or_default(),
+            fee: result
+                .map(|result| result.gas_used as Credits)
+                .unwrap_or_default(),
+        }
+    }
+}
+
+impl From<&ChainExecutionOutcome<'_>> for SimulationReport {
+    fn from(outcome: &ChainExecutionOutcome<'_>) -> Self {
+        let blocks: Vec<BlockReport> = outcome
+            .app_hashes
+            .iter()
+            .map(|(height, app_hash)| {
+                let state_transitions: Vec<StateTransitionReport> = outcome
+                    .state_transition_results_per_block
+                    .get(height)
+                    .into_iter()
+                    .flatten()
+                    .map(|(state_transition, result)| {
+                        StateTransitionReport::new(state_transition, result.as_ref())
+                    })
+                    .collect();
+                BlockReport {
+                    height: *height,
+                    app_hash: hex::encode(app_hash),
+                    fees: state_transitions.iter().map(|report| report.fee).sum(),
+                    state_transitions,
+                }
+            })
+            .collect();
+
+        let (valid_state_transitions, invalid_state_transitions) = blocks
+            .iter()
+            .flat_map(|block| block.state_transitions.iter())
+            .fold((0, 0), |(valid, invalid), report| {
+                if report.valid {
+                    (valid + 1, invalid)
+                } else {
+                    (valid, invalid + 1)
+                }
+            });
+
+        SimulationReport {
+            total_fees: blocks.iter().map(|block| block.fees).sum(),
+            blocks,
+            valid_state_transitions,
+            invalid_state_transitions,
+            end_epoch_index: outcome.end_epoch_index,
+            end_time_ms: outcome.end_time_ms,
+        }
+    }
+}
@@ -1,6 +1,7 @@
 use anyhow::anyhow;
 use dashcore_rpc::dashcore::signer;
 use dpp::ed25519_dalek::Signer as BlsSigner;
+use dpp::identity::multisig::sign_with_concatenated_private_keys;
 use dpp::identity::signer::Signer;
 use dpp::identity::{IdentityPublicKey, KeyType};
 use dpp::platform_value::BinaryData;
@@ -52,6 +53,10 @@ impl Signer for SimpleSigner {
                 let signature = signer::sign(data, private_key)?;
                 Ok(signature.to_vec().into())
             }
+            KeyType::ECDSA_SECP256K1_MULTISIG => {
+                let signature = sign_with_concatenated_private_keys(data, private_key)?;
+                Ok(signature.into())
+            }
             KeyType::BLS12_381 => {
                 let pk =
                     bls_signatures::PrivateKey::from_bytes(private_key, false).map_err(|_e| {
@@ -20,6 +20,7 @@ use dpp::document::document_transition::{
 };
 use dpp::document::{Document, DocumentsBatchTransition};
 use dpp::identity::{Identity, KeyType, Purpose, SecurityLevel};
+use dpp::prelude::{Identifier, IdentityNonce};
 use dpp::serialization_traits::PlatformSerializable;
 use dpp::state_transition::{StateTransition, StateTransitionIdentitySigned, StateTransitionType};
 use dpp::util::deserializer::ProtocolVersion;
@@ -35,11 +36,13 @@ use drive_abci::platform_types::platform::Platform;
 use drive_abci::rpc::core::MockCoreRPCLike;
 use rand::prelude::{IteratorRandom, SliceRandom, StdRng};
 use rand::Rng;
+use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
 use std::collections::{BTreeMap, HashMap, HashSet};
-use tenderdash_abci::proto::abci::ValidatorSetUpdate;
+use tenderdash_abci::proto::abci::{ExecTxResult, ValidatorSetUpdate};
 
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct MasternodeListChangesStrategy {
     /// How many new hpmns on average per core chain lock increase
     pub new_hpmns: Frequency,
@@ -121,7 +124,8 @@ pub enum StrategyMode {
     //ProposerAndValidatorSigning, todo
 }
 
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct FailureStrategy {
     pub deterministic_start_seed: Option<u64>,
     pub dont_finalize_block: bool,
@@ -156,7 +160,7 @@ pub struct Strategy {
     pub verify_state_transition_results: bool,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct UpgradingInfo {
     pub current_protocol_version: ProtocolVersion,
     pub proposed_protocol_versions_with_weight: Vec<(ProtocolVersion, u16)>,
@@ -355,6 +359,7 @@ impl Strategy {
     ) -> (Vec<StateTransition>, Vec<FinalizeBlockOperation>) {
         let mut operations = vec![];
         let mut finalize_block_operations = vec![];
+        let mut identity_contract_nonces = BTreeMap::new();
         for op in &self.operations {
             if op.frequency.check_hit(rng) {
                 let count = rng.gen_range(op.frequency.times_per_block_range.clone());
@@ -385,6 +390,12 @@ impl Strategy {
                                         document_type_name: document_type.name.clone(),
                                         action: Action::Create,
                                         data_contract_id: contract.id,
+                                        identity_contract_nonce: next_identity_contract_nonce(
+                                            platform,
+                                            &mut identity_contract_nonces,
+                                            identity.id,
+                                            contract.id,
+                                        ),
                                         data_contract: contract.clone(),
                                     },
                                     entropy: entropy.to_buffer(),
@@ -465,6 +476,12 @@ impl Strategy {
                                     document_type_name: document_type.name.clone(),
                                     action: Action::Delete,
                                     data_contract_id: contract.id,
+                                    identity_contract_nonce: next_identity_contract_nonce(
+                                        platform,
+                                        &mut identity_contract_nonces,
+                                        identity.id,
+                                        contract.id,
+                                    ),
                                     data_contract: contract.clone(),
                                 },
                             };
@@ -534,6 +551,12 @@ impl Strategy {
                                     document_type_name: document_type.name.clone(),
                                     action: Action::Replace,
                                     data_contract_id: contract.id,
+                                    identity_contract_nonce: next_identity_contract_nonce(
+                                        platform,
+                                        &mut identity_contract_nonces,
+                                        identity.id,
+                                        contract.id,
+                                    ),
                                     data_contract: contract.clone(),
                                 },
                                 revision: document.revision.expect("expected to unwrap revision")
@@ -643,12 +666,19 @@ impl Strategy {
                             .expect("expected to be able to get identity")
                             .expect("expected to get an identity");
 
+                        let fetched_owner_nonce = platform
+                            .drive
+                            .fetch_identity_nonce(owner.id.to_buffer(), true, None)
+                            .expect("expected to be able to get identity nonce")
+                            .unwrap_or_default();
+
                         let state_transition =
                             crate::transitions::create_identity_credit_transfer_transition(
                                 owner,
                                 recipient,
                                 signer,
                                 fetched_owner_balance - 100,
+                                fetched_owner_nonce + 1,
                             );
                         operations.push(state_transition);
                     }
@@ -709,6 +739,32 @@ impl Strategy {
     }
 }
 
+/// Returns the nonce of the next transition of the identity for the contract in this block,
+/// following the nonce stored in drive and the ones already used in the block
+fn next_identity_contract_nonce(
+    platform: &Platform<MockCoreRPCLike>,
+    identity_contract_nonces: &mut BTreeMap<(Identifier, Identifier), IdentityNonce>,
+    identity_id: Identifier,
+    contract_id: Identifier,
+) -> IdentityNonce {
+    let nonce = identity_contract_nonces
+        .entry((identity_id, contract_id))
+        .or_insert_with(|| {
+            platform
+                .drive
+                .fetch_identity_contract_nonce(
+                    identity_id.to_buffer(),
+                    contract_id.to_buffer(),
+                    true,
+                    None,
+                )
+                .expect("expected to fetch the identity contract nonce")
+                .unwrap_or_default()
+        });
+    *nonce += 1;
+    *nonce
+}
+
 pub enum StrategyRandomness {
     SeedEntropy(u64),
     RNGEntropy(StdRng),
@@ -736,6 +792,12 @@ pub struct ChainExecutionOutcome<'a> {
     pub withdrawals: Vec<dashcore::Transaction>,
     /// height to the validator set update at that height
     pub validator_set_updates: BTreeMap<u64, ValidatorSetUpdate>,
+    /// height to the root app hash after the block at that height was finalized
+    pub app_hashes: BTreeMap<u64, [u8; 32]>,
+    /// height to the state transitions of the block at that height with their execution results,
+    /// None for the ones removed from the block because they were invalid
+    pub state_transition_results_per_block:
+        BTreeMap<u64, Vec<(StateTransition, Option<ExecTxResult>)>>,
 }
 
 impl<'a> ChainExecutionOutcome<'a> {
@@ -4,7 +4,9 @@ use dashcore_rpc::dashcore::{Network, PrivateKey};
 use dpp::identifier::Identifier;
 use dpp::identity::core_script::CoreScript;
 use dpp::identity::state_transition::identity_create_transition::IdentityCreateTransition;
-use dpp::identity::state_transition::identity_credit_transfer_transition::IdentityCreditTransferTransition;
+use dpp::identity::state_transition::identity_credit_transfer_transition::{
+    IdentityCreditTransferRecipient, IdentityCreditTransferTransition,
+};
 use dpp::identity::state_transition::identity_credit_withdrawal_transition::{
     IdentityCreditWithdrawalTransition, Pooling,
 };
@@ -14,6 +16,7 @@ use dpp::identity::KeyType::ECDSA_SECP256K1;
 use dpp::identity::Purpose::AUTHENTICATION;
 use dpp::identity::SecurityLevel::{CRITICAL, MASTER};
 use dpp::identity::{Identity, IdentityPublicKey, KeyID, KeyType, Purpose, SecurityLevel};
+use dpp::prelude::IdentityNonce;
 use dpp::state_transition::{StateTransition, StateTransitionIdentitySigned, StateTransitionType};
 use dpp::tests::fixtures::instant_asset_lock_proof_fixture;
 use dpp::version::LATEST_VERSION;
@@ -175,12 +178,13 @@ pub fn create_identity_credit_transfer_transition(
     recipient: &Identity,
     signer: &mut SimpleSigner,
     amount: u64,
+    nonce: IdentityNonce,
 ) -> StateTransition {
     let mut transition = IdentityCreditTransferTransition {
         transition_type: StateTransitionType::IdentityCreditTransfer,
         identity_id: identity.id,
-        recipient_id: recipient.id,
-        amount,
+        recipients: vec![IdentityCreditTransferRecipient::new(recipient.id, amount)],
+        nonce,
         protocol_version: LATEST_VERSION,
         signature_public_key_id: 0,
         signature: Default::default(),
@@ -1,4 +1,7 @@
-use dapi_grpc::platform::v0::{get_proofs_request, GetProofsRequest, GetProofsResponse};
+use dapi_grpc::platform::v0::{
+    get_identity_nonce_response, get_proofs_request, GetIdentityNonceResponse, GetIdentityRequest,
+    GetProofsRequest, GetProofsResponse,
+};
 
 use dpp::document::document_transition::DocumentTransitionAction;
 use dpp::document::Document;
@@ -13,7 +16,7 @@ use drive_abci::rpc::core::MockCoreRPCLike;
 use drive_abci::execution::validation::state_transition::transformer::StateTransitionActionTransformerV0;
 use prost::Message;
 
-pub(crate) fn verify_state_transitions_were_executed(
+pub fn verify_state_transitions_were_executed(
     abci_app: &AbciApplication<MockCoreRPCLike>,
     expected_root_hash: &[u8; 32],
     state_transitions: &Vec<StateTransition>,
@@ -24,6 +27,7 @@ pub(crate) fn verify_state_transitions_were_executed(
         state: &state,
         config: &abci_app.platform.config,
         core_rpc: &abci_app.platform.core_rpc,
+        check_tx: false,
     };
 
     //actions are easier to transform to queries
@@ -419,12 +423,15 @@ pub(crate) fn verify_state_transitions_were_executed(
                         request_type: get_proofs_request::identity_request::Type::Balance.into(),
                     });
 
-                proofs_request
-                    .identities
-                    .push(get_proofs_request::IdentityRequest {
-                        identity_id: identity_credit_transfer_action.recipient_id.to_vec(),
-                        request_type: get_proofs_request::identity_request::Type::Balance.into(),
-                    });
+                for recipient_id in identity_credit_transfer_action.recipients.keys() {
+                    proofs_request
+                        .identities
+                        .push(get_proofs_request::IdentityRequest {
+                            identity_id: recipient_id.to_vec(),
+                            request_type: get_proofs_request::identity_request::Type::Balance
+                                .into(),
+                        });
+                }
 
                 let result = abci_app
                     .platform
@@ -454,23 +461,62 @@ pub(crate) fn verify_state_transitions_were_executed(
                     platform.state.last_committed_block_info
                 );
 
-                let (root_hash_recipient, balance_recipient) =
-                    Drive::verify_identity_balance_for_identity_id(
-                        &response_proof.grovedb_proof,
-                        identity_credit_transfer_action.recipient_id.into_buffer(),
-                        true,
-                    )
-                    .expect("expected to verify balance recipient");
+                for (recipient_id, amount) in &identity_credit_transfer_action.recipients {
+                    let (root_hash_recipient, balance_recipient) =
+                        Drive::verify_identity_balance_for_identity_id(
+                            &response_proof.grovedb_proof,
+                            recipient_id.into_buffer(),
+                            true,
+                        )
+                        .expect("expected to verify balance recipient");
+
+                    assert_eq!(
+                        &root_hash_recipient, expected_root_hash,
+                        "state last block info {:?}",
+                        platform.state.last_committed_block_info
+                    );
+
+                    let balance_recipient = balance_recipient.expect("expected a balance");
+
+                    assert!(balance_recipient >= *amount);
+                }
+
+                let nonce_request = GetIdentityRequest {
+                    id: identity_credit_transfer_action.identity_id.to_vec(),
+                    prove: true,
+                };
+
+                let result = abci_app
+                    .platform
+                    .query("/identity/nonce", &nonce_request.encode_to_vec())
+                    .expect("expected to query nonce");
+                let serialized_nonce_response =
+                    result.into_data().expect("expected queries to be valid");
+
+                let GetIdentityNonceResponse {
+                    result,
+                    metadata: _,
+                } = GetIdentityNonceResponse::decode(serialized_nonce_response.as_slice())
+                    .expect("expected to decode nonce response");
+
+                let Some(get_identity_nonce_response::Result::Proof(nonce_proof)) = result else {
+                    panic!("expected a proof of the nonce");
+                };
+
+                let (root_hash_nonce, nonce) = Drive::verify_identity_nonce(
+                    &nonce_proof.grovedb_proof,
+                    identity_credit_transfer_action.identity_id.into_buffer(),
+                    false,
+                )
+                .expect("expected to verify nonce");
 
                 assert_eq!(
-                    &root_hash_recipient, expected_root_hash,
+                    &root_hash_nonce, expected_root_hash,
                     "state last block info {:?}",
                     platform.state.last_committed_block_info
                 );
 
-                let balance_recipient = balance_recipient.expect("expected a balance");
-
-                assert!(balance_recipient >= identity_credit_transfer_action.transfer_amount);
+                assert_eq!(nonce, Some(identity_credit_transfer_action.nonce));
             }
         }
     }
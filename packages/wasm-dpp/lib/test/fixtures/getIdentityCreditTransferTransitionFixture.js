@@ -10,9 +10,12 @@ module.exports = async function getIdentityUpdateTransitionFixture() {
     signaturePublicKeyId: 0,
     protocolVersion: 1,
     type: 7,
-    amount: 1000,
     identityId: (await generateRandomIdentifier()).toBuffer(),
-    recipientId: (await generateRandomIdentifier()).toBuffer(),
+    recipients: [{
+      recipientId: (await generateRandomIdentifier()).toBuffer(),
+      amount: 1000,
+    }],
+    nonce: 1,
   };
 
   return new IdentityCreditTransferTransition(rawStateTransition);
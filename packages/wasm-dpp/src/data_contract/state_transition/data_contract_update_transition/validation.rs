@@ -56,9 +56,12 @@ pub fn validate_indices_are_backward_compatible(
         new_documents_schema,
     )?;
 
-    let result =
-        dpp_validate_indices_are_backward_compatible(old_documents.iter(), new_documents.iter())
-            .map_err(from_protocol_error)?;
+    let result = dpp_validate_indices_are_backward_compatible(
+        old_documents.iter(),
+        new_documents.iter(),
+        true,
+    )
+    .map_err(from_protocol_error)?;
 
     Ok(result.map(|_| JsValue::undefined()).into())
 }
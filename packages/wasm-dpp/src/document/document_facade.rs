@@ -106,8 +106,10 @@ impl DocumentFacadeWasm {
     pub fn create_state_transition(
         &self,
         documents: &JsValue,
+        nonce_counter: &JsValue,
     ) -> Result<DocumentsBatchTransitionWasm, JsValue> {
-        self.factory.create_state_transition(documents)
+        self.factory
+            .create_state_transition(documents, nonce_counter)
     }
 
     /// Creates Documents State Transition
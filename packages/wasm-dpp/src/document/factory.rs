@@ -1,4 +1,5 @@
 use anyhow::anyhow;
+use dpp::platform_value::string_encoding::Encoding;
 use dpp::platform_value::ReplacementType;
 use dpp::{
     document::{
@@ -9,13 +10,13 @@ use dpp::{
     },
     ProtocolError,
 };
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 use wasm_bindgen::prelude::*;
 
 use crate::document::errors::InvalidActionNameError;
 use dpp::platform_value::btreemap_extensions::BTreeValueMapReplacementPathHelper;
-use dpp::prelude::ExtendedDocument;
+use dpp::prelude::{ExtendedDocument, Identifier, IdentityNonce};
 use std::convert::TryFrom;
 use std::sync::Arc;
 
@@ -139,12 +140,15 @@ impl DocumentFactoryWASM {
     pub fn create_state_transition(
         &self,
         documents: &JsValue,
+        nonce_counter_value: &JsValue,
     ) -> Result<DocumentsBatchTransitionWasm, JsValue> {
         let documents_by_action = extract_documents_by_action(documents)?;
+        let mut nonce_counter = extract_nonce_counter(nonce_counter_value)?;
         let batch_transition = self
             .0
-            .create_state_transition(documents_by_action)
+            .create_state_transition(documents_by_action, &mut nonce_counter)
             .with_js_error()?;
+        update_nonce_counter(nonce_counter_value, &nonce_counter)?;
 
         Ok(batch_transition.into())
     }
@@ -248,6 +252,79 @@ fn extract_documents_by_action(
     Ok(documents_by_action)
 }
 
+/// Reads the last nonces used by identities for data contracts from an object of the shape
+/// `{ [identityId]: { [contractId]: nonce } }`, where undefined means no nonces were used yet
+fn extract_nonce_counter(
+    nonce_counter_value: &JsValue,
+) -> Result<BTreeMap<(Identifier, Identifier), IdentityNonce>, JsValue> {
+    let mut nonce_counter = BTreeMap::new();
+    if nonce_counter_value.is_undefined() || nonce_counter_value.is_null() {
+        return Ok(nonce_counter);
+    }
+    if !nonce_counter_value.is_object() {
+        return Err(anyhow!("Expected nonce counter to be an object")).with_js_error();
+    }
+
+    let identities = js_sys::Object::from(nonce_counter_value.clone());
+    for identity_entry in js_sys::Object::entries(&identities).iter() {
+        let identity_entry = js_sys::Array::from(&identity_entry);
+        let identity_id = identifier_from_nonce_counter_key(&identity_entry.get(0))?;
+        let contracts = identity_entry.get(1);
+        if !contracts.is_object() {
+            return Err(anyhow!("Expected nonces of an identity to be an object")).with_js_error();
+        }
+
+        for contract_entry in js_sys::Object::entries(&js_sys::Object::from(contracts)).iter() {
+            let contract_entry = js_sys::Array::from(&contract_entry);
+            let contract_id = identifier_from_nonce_counter_key(&contract_entry.get(0))?;
+            let nonce = contract_entry
+                .get(1)
+                .as_f64()
+                .ok_or_else(|| anyhow!("Expected nonces to be numbers"))
+                .with_js_error()?;
+            nonce_counter.insert((identity_id, contract_id), nonce as IdentityNonce);
+        }
+    }
+
+    Ok(nonce_counter)
+}
+
+fn identifier_from_nonce_counter_key(key: &JsValue) -> Result<Identifier, JsValue> {
+    let key = key
+        .as_string()
+        .ok_or_else(|| anyhow!("Expected nonce counter keys to be strings"))
+        .with_js_error()?;
+    Identifier::from_string(&key, Encoding::Base58)
+        .map_err(|e| anyhow!("Expected nonce counter keys to be identifiers: {e}"))
+        .with_js_error()
+}
+
+/// Writes the nonces used for the new transitions back to the nonce counter object, if any
+fn update_nonce_counter(
+    nonce_counter_value: &JsValue,
+    nonce_counter: &BTreeMap<(Identifier, Identifier), IdentityNonce>,
+) -> Result<(), JsValue> {
+    if !nonce_counter_value.is_object() {
+        return Ok(());
+    }
+
+    for ((identity_id, contract_id), nonce) in nonce_counter {
+        let identity_key: JsValue = identity_id.to_string(Encoding::Base58).into();
+        let mut contracts = js_sys::Reflect::get(nonce_counter_value, &identity_key)?;
+        if !contracts.is_object() {
+            contracts = js_sys::Object::new().into();
+            js_sys::Reflect::set(nonce_counter_value, &identity_key, &contracts)?;
+        }
+        js_sys::Reflect::set(
+            &contracts,
+            &contract_id.to_string(Encoding::Base58).into(),
+            &JsValue::from_f64(*nonce as f64),
+        )?;
+    }
+
+    Ok(())
+}
+
 fn check_actions(documents: &JsValue) -> Result<(), JsValue> {
     if !documents.is_object() {
         return Err(anyhow!("Expected documents to be an object")).with_js_error();
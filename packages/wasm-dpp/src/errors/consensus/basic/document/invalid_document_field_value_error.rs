@@ -0,0 +1,55 @@
+use dpp::consensus::basic::document::InvalidDocumentFieldValueError;
+use dpp::consensus::codes::ErrorWithCode;
+use dpp::consensus::ConsensusError;
+use dpp::serialization_traits::PlatformSerializable;
+use wasm_bindgen::prelude::*;
+
+use crate::buffer::Buffer;
+
+#[wasm_bindgen(js_name=InvalidDocumentFieldValueError)]
+pub struct InvalidDocumentFieldValueErrorWasm {
+    inner: InvalidDocumentFieldValueError,
+}
+
+impl From<&InvalidDocumentFieldValueError> for InvalidDocumentFieldValueErrorWasm {
+    fn from(e: &InvalidDocumentFieldValueError) -> Self {
+        Self { inner: e.clone() }
+    }
+}
+
+#[wasm_bindgen(js_class=InvalidDocumentFieldValueError)]
+impl InvalidDocumentFieldValueErrorWasm {
+    #[wasm_bindgen(js_name=getDocumentType)]
+    pub fn get_document_type(&self) -> String {
+        self.inner.document_type()
+    }
+
+    #[wasm_bindgen(js_name=getFieldName)]
+    pub fn get_field_name(&self) -> String {
+        self.inner.field_name()
+    }
+
+    #[wasm_bindgen(js_name=getReason)]
+    pub fn get_reason(&self) -> String {
+        self.inner.reason()
+    }
+
+    #[wasm_bindgen(js_name=getCode)]
+    pub fn get_code(&self) -> u32 {
+        ConsensusError::from(self.inner.clone()).code()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn message(&self) -> String {
+        self.inner.to_string()
+    }
+
+    #[wasm_bindgen(js_name=serialize)]
+    pub fn serialize(&self) -> Result<Buffer, JsError> {
+        let bytes = ConsensusError::from(self.inner.clone())
+            .serialize()
+            .map_err(JsError::from)?;
+
+        Ok(Buffer::from_bytes(bytes.as_slice()))
+    }
+}
@@ -2,9 +2,11 @@ mod data_contract_not_present_error;
 mod duplicate_document_transitions_with_ids_error;
 mod duplicate_document_transitions_with_indices_error;
 mod inconsistent_compound_index_data_error;
+mod invalid_document_field_value_error;
 mod invalid_document_transition_action_error;
 mod invalid_document_transition_id_error;
 mod invalid_document_type_error;
+mod invalid_encrypted_document_field_error;
 mod missing_data_contract_id_error;
 mod missing_document_transition_action_error;
 mod missing_document_transition_type_error;
@@ -14,9 +16,11 @@ pub use data_contract_not_present_error::*;
 pub use duplicate_document_transitions_with_ids_error::*;
 pub use duplicate_document_transitions_with_indices_error::*;
 pub use inconsistent_compound_index_data_error::*;
+pub use invalid_document_field_value_error::*;
 pub use invalid_document_transition_action_error::*;
 pub use invalid_document_transition_id_error::*;
 pub use invalid_document_type_error::*;
+pub use invalid_encrypted_document_field_error::*;
 pub use missing_data_contract_id_error::*;
 pub use missing_document_transition_action_error::*;
 pub use missing_document_transition_type_error::*;
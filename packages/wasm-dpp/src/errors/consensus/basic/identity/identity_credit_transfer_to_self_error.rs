@@ -0,0 +1,44 @@
+use crate::buffer::Buffer;
+use dpp::consensus::basic::identity::IdentityCreditTransferToSelfError;
+use dpp::consensus::codes::ErrorWithCode;
+use dpp::consensus::ConsensusError;
+use dpp::serialization_traits::PlatformSerializable;
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen(js_name=IdentityCreditTransferToSelfError)]
+pub struct IdentityCreditTransferToSelfErrorWasm {
+    inner: IdentityCreditTransferToSelfError,
+}
+
+impl From<&IdentityCreditTransferToSelfError> for IdentityCreditTransferToSelfErrorWasm {
+    fn from(e: &IdentityCreditTransferToSelfError) -> Self {
+        Self { inner: e.clone() }
+    }
+}
+
+#[wasm_bindgen(js_class=IdentityCreditTransferToSelfError)]
+impl IdentityCreditTransferToSelfErrorWasm {
+    #[wasm_bindgen(js_name=getIdentityId)]
+    pub fn identity_id(&self) -> Buffer {
+        Buffer::from_bytes(self.inner.identity_id().as_bytes())
+    }
+
+    #[wasm_bindgen(js_name=getCode)]
+    pub fn get_code(&self) -> u32 {
+        ConsensusError::from(self.inner.clone()).code()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn message(&self) -> String {
+        self.inner.to_string()
+    }
+
+    #[wasm_bindgen(js_name=serialize)]
+    pub fn serialize(&self) -> Result<Buffer, JsError> {
+        let bytes = ConsensusError::from(self.inner.clone())
+            .serialize()
+            .map_err(JsError::from)?;
+
+        Ok(Buffer::from_bytes(bytes.as_slice()))
+    }
+}
@@ -1,9 +1,11 @@
+mod duplicated_identity_credit_transfer_recipient_error;
 mod duplicated_identity_public_key_error;
 mod duplicated_identity_public_key_id_error;
 mod identity_asset_lock_proof_locked_transaction_mismatch_error;
 mod identity_asset_lock_transaction_is_not_found_error;
 mod identity_asset_lock_transaction_out_point_already_exists_error;
 mod identity_asset_lock_transaction_output_not_found_error;
+mod identity_credit_transfer_to_self_error;
 mod identity_insufficient_balance_error;
 mod invalid_asset_lock_proof_core_chain_height_error;
 mod invalid_asset_lock_proof_transaction_height_error;
@@ -23,12 +25,14 @@ mod invalid_instant_asset_lock_proof_signature_error;
 mod missing_master_public_key_error;
 mod missing_public_key_error;
 
+pub use duplicated_identity_credit_transfer_recipient_error::*;
 pub use duplicated_identity_public_key_error::*;
 pub use duplicated_identity_public_key_id_error::*;
 pub use identity_asset_lock_proof_locked_transaction_mismatch_error::*;
 pub use identity_asset_lock_transaction_is_not_found_error::*;
 pub use identity_asset_lock_transaction_out_point_already_exists_error::*;
 pub use identity_asset_lock_transaction_output_not_found_error::*;
+pub use identity_credit_transfer_to_self_error::*;
 pub use identity_insufficient_balance_error::*;
 pub use invalid_asset_lock_proof_core_chain_height_error::*;
 pub use invalid_asset_lock_proof_transaction_height_error::*;
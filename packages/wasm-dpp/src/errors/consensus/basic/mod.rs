@@ -9,6 +9,7 @@ mod invalid_state_transition_signature_error;
 mod json_schema_compilation_error;
 mod json_schema_error;
 mod public_key_is_disabled_error;
+mod public_key_is_expired_error;
 mod public_key_security_level_not_met_error;
 pub mod state_transition;
 #[cfg(test)]
@@ -23,6 +24,7 @@ pub use invalid_state_transition_signature_error::*;
 pub use json_schema_compilation_error::*;
 pub use json_schema_error::*;
 pub use public_key_is_disabled_error::*;
+pub use public_key_is_expired_error::*;
 pub use public_key_security_level_not_met_error::*;
 #[cfg(test)]
 pub use test_consensus_error::*;
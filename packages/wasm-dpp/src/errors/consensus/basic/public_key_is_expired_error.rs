@@ -0,0 +1,50 @@
+use crate::buffer::Buffer;
+use dpp::consensus::codes::ErrorWithCode;
+use dpp::consensus::signature::PublicKeyIsExpiredError;
+use dpp::consensus::ConsensusError;
+use dpp::identity::KeyID;
+use dpp::serialization_traits::PlatformSerializable;
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen(js_name=PublicKeyIsExpiredError)]
+pub struct PublicKeyIsExpiredErrorWasm {
+    inner: PublicKeyIsExpiredError,
+}
+
+impl From<&PublicKeyIsExpiredError> for PublicKeyIsExpiredErrorWasm {
+    fn from(e: &PublicKeyIsExpiredError) -> Self {
+        Self { inner: e.clone() }
+    }
+}
+
+#[wasm_bindgen(js_class=PublicKeyIsExpiredError)]
+impl PublicKeyIsExpiredErrorWasm {
+    #[wasm_bindgen(js_name=getPublicKeyId)]
+    pub fn get_public_key_id(&self) -> KeyID {
+        self.inner.public_key_id()
+    }
+
+    #[wasm_bindgen(js_name=getValidUntil)]
+    pub fn get_valid_until(&self) -> js_sys::Date {
+        js_sys::Date::new(&JsValue::from_f64(self.inner.valid_until() as f64))
+    }
+
+    #[wasm_bindgen(js_name=getCode)]
+    pub fn get_code(&self) -> u32 {
+        ConsensusError::from(self.inner.clone()).code()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn message(&self) -> String {
+        self.inner.to_string()
+    }
+
+    #[wasm_bindgen(js_name=serialize)]
+    pub fn serialize(&self) -> Result<Buffer, JsError> {
+        let bytes = ConsensusError::from(self.inner.clone())
+            .serialize()
+            .map_err(JsError::from)?;
+
+        Ok(Buffer::from_bytes(bytes.as_slice()))
+    }
+}
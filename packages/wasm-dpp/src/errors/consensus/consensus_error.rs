@@ -6,12 +6,14 @@ use dpp::consensus::ConsensusError as DPPConsensusError;
 use std::ops::Deref;
 
 use crate::errors::consensus::basic::identity::{
-    DuplicatedIdentityPublicKeyErrorWasm, DuplicatedIdentityPublicKeyIdErrorWasm,
+    DuplicatedIdentityCreditTransferRecipientErrorWasm, DuplicatedIdentityPublicKeyErrorWasm,
+    DuplicatedIdentityPublicKeyIdErrorWasm,
     IdentityAssetLockProofLockedTransactionMismatchErrorWasm,
     IdentityAssetLockTransactionIsNotFoundErrorWasm,
     IdentityAssetLockTransactionOutPointAlreadyExistsErrorWasm,
-    IdentityAssetLockTransactionOutputNotFoundErrorWasm, IdentityInsufficientBalanceErrorWasm,
-    InvalidAssetLockProofCoreChainHeightErrorWasm, InvalidAssetLockProofTransactionHeightErrorWasm,
+    IdentityAssetLockTransactionOutputNotFoundErrorWasm, IdentityCreditTransferToSelfErrorWasm,
+    IdentityInsufficientBalanceErrorWasm, InvalidAssetLockProofCoreChainHeightErrorWasm,
+    InvalidAssetLockProofTransactionHeightErrorWasm,
     InvalidAssetLockTransactionOutputReturnSizeErrorWasm,
     InvalidIdentityAssetLockProofChainLockValidationErrorWasm,
     InvalidIdentityAssetLockTransactionErrorWasm,
@@ -25,7 +27,8 @@ use crate::errors::consensus::basic::identity::{
 };
 use crate::errors::consensus::state::identity::{
     DuplicatedIdentityPublicKeyIdStateErrorWasm, DuplicatedIdentityPublicKeyStateErrorWasm,
-    MissingIdentityPublicKeyIdsErrorWasm,
+    IdentityPublicKeyValidUntilInPastErrorWasm, InvalidIdentityContractNonceErrorWasm,
+    InvalidIdentityNonceErrorWasm, MissingIdentityPublicKeyIdsErrorWasm,
 };
 use dpp::consensus::basic::BasicError;
 use dpp::consensus::basic::BasicError::{
@@ -76,7 +79,7 @@ use crate::errors::consensus::state::data_contract::data_trigger::{
 };
 use crate::errors::consensus::state::data_contract::{
     DataContractAlreadyPresentErrorWasm, DataContractConfigUpdateErrorWasm,
-    DataContractIsReadonlyErrorWasm,
+    DataContractIsReadonlyErrorWasm, DataContractTooManyDocumentsToBackfillIndexErrorWasm,
 };
 use crate::errors::consensus::state::document::{
     DocumentAlreadyPresentErrorWasm, DocumentNotFoundErrorWasm, DocumentOwnerIdMismatchErrorWasm,
@@ -103,7 +106,8 @@ use crate::errors::consensus::basic::decode::{
 };
 use crate::errors::consensus::basic::document::{
     DataContractNotPresentErrorWasm, InconsistentCompoundIndexDataErrorWasm,
-    InvalidDocumentTypeErrorWasm, MissingDocumentTransitionActionErrorWasm,
+    InvalidDocumentFieldValueErrorWasm, InvalidDocumentTypeErrorWasm,
+    InvalidEncryptedDocumentFieldErrorWasm, MissingDocumentTransitionActionErrorWasm,
     MissingDocumentTransitionTypeErrorWasm,
 };
 use crate::errors::consensus::basic::identity::{
@@ -111,7 +115,7 @@ use crate::errors::consensus::basic::identity::{
 };
 use crate::errors::consensus::basic::{
     InvalidSignaturePublicKeySecurityLevelErrorWasm, InvalidStateTransitionSignatureErrorWasm,
-    JsonSchemaCompilationErrorWasm, PublicKeyIsDisabledErrorWasm,
+    JsonSchemaCompilationErrorWasm, PublicKeyIsDisabledErrorWasm, PublicKeyIsExpiredErrorWasm,
     PublicKeySecurityLevelNotMetErrorWasm, WrongPublicKeyPurposeErrorWasm,
 };
 use crate::errors::consensus::fee::BalanceIsNotEnoughErrorWasm;
@@ -211,6 +215,16 @@ pub fn from_state_error(state_error: &StateError) -> JsValue {
         StateError::DataContractConfigUpdateError(e) => {
             DataContractConfigUpdateErrorWasm::from(e).into()
         }
+        StateError::InvalidIdentityNonceError(e) => InvalidIdentityNonceErrorWasm::from(e).into(),
+        StateError::InvalidIdentityContractNonceError(e) => {
+            InvalidIdentityContractNonceErrorWasm::from(e).into()
+        }
+        StateError::IdentityPublicKeyValidUntilInPastError(e) => {
+            IdentityPublicKeyValidUntilInPastErrorWasm::from(e).into()
+        }
+        StateError::DataContractTooManyDocumentsToBackfillIndexError(e) => {
+            DataContractTooManyDocumentsToBackfillIndexErrorWasm::from(e).into()
+        }
     }
 }
 
@@ -298,6 +312,18 @@ fn from_basic_error(basic_error: &BasicError) -> JsValue {
         BasicError::StateTransitionMaxSizeExceededError(err) => {
             StateTransitionMaxSizeExceededErrorWasm::from(err).into()
         }
+        BasicError::DuplicatedIdentityCreditTransferRecipientError(err) => {
+            DuplicatedIdentityCreditTransferRecipientErrorWasm::from(err).into()
+        }
+        BasicError::IdentityCreditTransferToSelfError(err) => {
+            IdentityCreditTransferToSelfErrorWasm::from(err).into()
+        }
+        BasicError::InvalidEncryptedDocumentFieldError(err) => {
+            InvalidEncryptedDocumentFieldErrorWasm::from(err).into()
+        }
+        BasicError::InvalidDocumentFieldValueError(err) => {
+            InvalidDocumentFieldValueErrorWasm::from(err).into()
+        }
         BasicError::DataContractImmutablePropertiesUpdateError(err) => {
             DataContractImmutablePropertiesUpdateErrorWasm::from(err).into()
         }
@@ -404,6 +430,9 @@ fn from_signature_error(signature_error: &SignatureError) -> JsValue {
         }
         SignatureError::BasicECDSAError(err) => BasicECDSAErrorWasm::from(err).into(),
         SignatureError::BasicBLSError(err) => BasicBLSErrorWasm::from(err).into(),
+        SignatureError::PublicKeyIsExpiredError(err) => {
+            PublicKeyIsExpiredErrorWasm::from(err).into()
+        }
     }
 }
 
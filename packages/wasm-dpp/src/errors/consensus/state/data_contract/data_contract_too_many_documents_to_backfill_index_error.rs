@@ -0,0 +1,57 @@
+use crate::buffer::Buffer;
+use dpp::consensus::codes::ErrorWithCode;
+use dpp::consensus::state::data_contract::data_contract_too_many_documents_to_backfill_index_error::DataContractTooManyDocumentsToBackfillIndexError;
+use dpp::consensus::ConsensusError;
+
+use dpp::serialization_traits::PlatformSerializable;
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen(js_name=DataContractTooManyDocumentsToBackfillIndexError)]
+pub struct DataContractTooManyDocumentsToBackfillIndexErrorWasm {
+    inner: DataContractTooManyDocumentsToBackfillIndexError,
+}
+
+impl From<&DataContractTooManyDocumentsToBackfillIndexError>
+    for DataContractTooManyDocumentsToBackfillIndexErrorWasm
+{
+    fn from(e: &DataContractTooManyDocumentsToBackfillIndexError) -> Self {
+        Self { inner: e.clone() }
+    }
+}
+
+#[wasm_bindgen(js_class=DataContractTooManyDocumentsToBackfillIndexError)]
+impl DataContractTooManyDocumentsToBackfillIndexErrorWasm {
+    #[wasm_bindgen(js_name=getDocumentType)]
+    pub fn document_type(&self) -> String {
+        self.inner.document_type().to_string()
+    }
+
+    #[wasm_bindgen(js_name=getIndexName)]
+    pub fn index_name(&self) -> String {
+        self.inner.index_name().to_string()
+    }
+
+    #[wasm_bindgen(js_name=getMaxDocuments)]
+    pub fn max_documents(&self) -> u16 {
+        self.inner.max_documents()
+    }
+
+    #[wasm_bindgen(js_name=getCode)]
+    pub fn get_code(&self) -> u32 {
+        ConsensusError::from(self.inner.clone()).code()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn message(&self) -> String {
+        self.inner.to_string()
+    }
+
+    #[wasm_bindgen(js_name=serialize)]
+    pub fn serialize(&self) -> Result<Buffer, JsError> {
+        let bytes = ConsensusError::from(self.inner.clone())
+            .serialize()
+            .map_err(JsError::from)?;
+
+        Ok(Buffer::from_bytes(bytes.as_slice()))
+    }
+}
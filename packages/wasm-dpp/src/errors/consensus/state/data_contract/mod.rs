@@ -1,8 +1,10 @@
 mod data_contract_already_present_error;
 mod data_contract_config_update_error;
 mod data_contract_is_readonly_error;
+mod data_contract_too_many_documents_to_backfill_index_error;
 pub mod data_trigger;
 
 pub use data_contract_already_present_error::*;
 pub use data_contract_config_update_error::*;
 pub use data_contract_is_readonly_error::*;
+pub use data_contract_too_many_documents_to_backfill_index_error::*;
@@ -0,0 +1,49 @@
+use crate::buffer::Buffer;
+use dpp::consensus::codes::ErrorWithCode;
+use dpp::consensus::state::identity::identity_public_key_valid_until_in_past_error::IdentityPublicKeyValidUntilInPastError;
+use dpp::consensus::ConsensusError;
+use dpp::serialization_traits::PlatformSerializable;
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen(js_name=IdentityPublicKeyValidUntilInPastError)]
+pub struct IdentityPublicKeyValidUntilInPastErrorWasm {
+    inner: IdentityPublicKeyValidUntilInPastError,
+}
+
+impl From<&IdentityPublicKeyValidUntilInPastError> for IdentityPublicKeyValidUntilInPastErrorWasm {
+    fn from(e: &IdentityPublicKeyValidUntilInPastError) -> Self {
+        Self { inner: e.clone() }
+    }
+}
+
+#[wasm_bindgen(js_class=IdentityPublicKeyValidUntilInPastError)]
+impl IdentityPublicKeyValidUntilInPastErrorWasm {
+    #[wasm_bindgen(js_name=getValidUntil)]
+    pub fn valid_until(&self) -> js_sys::Date {
+        js_sys::Date::new(&JsValue::from_f64(self.inner.valid_until() as f64))
+    }
+
+    #[wasm_bindgen(js_name=getLastBlockTime)]
+    pub fn last_block_time(&self) -> js_sys::Date {
+        js_sys::Date::new(&JsValue::from_f64(self.inner.last_block_time() as f64))
+    }
+
+    #[wasm_bindgen(js_name=getCode)]
+    pub fn get_code(&self) -> u32 {
+        ConsensusError::from(self.inner.clone()).code()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn message(&self) -> String {
+        self.inner.to_string()
+    }
+
+    #[wasm_bindgen(js_name=serialize)]
+    pub fn serialize(&self) -> Result<Buffer, JsError> {
+        let bytes = ConsensusError::from(self.inner.clone())
+            .serialize()
+            .map_err(JsError::from)?;
+
+        Ok(Buffer::from_bytes(bytes.as_slice()))
+    }
+}
@@ -0,0 +1,62 @@
+use crate::buffer::Buffer;
+use dpp::consensus::codes::ErrorWithCode;
+use dpp::consensus::state::identity::invalid_identity_contract_nonce_error::InvalidIdentityContractNonceError;
+use dpp::consensus::ConsensusError;
+use dpp::serialization_traits::PlatformSerializable;
+use js_sys::Number;
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen(js_name=InvalidIdentityContractNonceError)]
+pub struct InvalidIdentityContractNonceErrorWasm {
+    inner: InvalidIdentityContractNonceError,
+}
+
+impl From<&InvalidIdentityContractNonceError> for InvalidIdentityContractNonceErrorWasm {
+    fn from(e: &InvalidIdentityContractNonceError) -> Self {
+        Self { inner: e.clone() }
+    }
+}
+
+#[wasm_bindgen(js_class=InvalidIdentityContractNonceError)]
+impl InvalidIdentityContractNonceErrorWasm {
+    #[wasm_bindgen(js_name=getIdentityId)]
+    pub fn identity_id(&self) -> Buffer {
+        Buffer::from_bytes(self.inner.identity_id().as_bytes())
+    }
+
+    #[wasm_bindgen(js_name=getContractId)]
+    pub fn contract_id(&self) -> Buffer {
+        Buffer::from_bytes(self.inner.contract_id().as_bytes())
+    }
+
+    #[wasm_bindgen(js_name=getCurrentNonce)]
+    pub fn current_nonce(&self) -> Number {
+        // It might be overflow
+        Number::from(self.inner.current_nonce() as f64)
+    }
+
+    #[wasm_bindgen(js_name=getSettingNonce)]
+    pub fn setting_nonce(&self) -> Number {
+        // It might be overflow
+        Number::from(self.inner.setting_nonce() as f64)
+    }
+
+    #[wasm_bindgen(js_name=getCode)]
+    pub fn get_code(&self) -> u32 {
+        ConsensusError::from(self.inner.clone()).code()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn message(&self) -> String {
+        self.inner.to_string()
+    }
+
+    #[wasm_bindgen(js_name=serialize)]
+    pub fn serialize(&self) -> Result<Buffer, JsError> {
+        let bytes = ConsensusError::from(self.inner.clone())
+            .serialize()
+            .map_err(JsError::from)?;
+
+        Ok(Buffer::from_bytes(bytes.as_slice()))
+    }
+}
@@ -0,0 +1,57 @@
+use crate::buffer::Buffer;
+use dpp::consensus::codes::ErrorWithCode;
+use dpp::consensus::state::identity::invalid_identity_nonce_error::InvalidIdentityNonceError;
+use dpp::consensus::ConsensusError;
+use dpp::serialization_traits::PlatformSerializable;
+use js_sys::Number;
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen(js_name=InvalidIdentityNonceError)]
+pub struct InvalidIdentityNonceErrorWasm {
+    inner: InvalidIdentityNonceError,
+}
+
+impl From<&InvalidIdentityNonceError> for InvalidIdentityNonceErrorWasm {
+    fn from(e: &InvalidIdentityNonceError) -> Self {
+        Self { inner: e.clone() }
+    }
+}
+
+#[wasm_bindgen(js_class=InvalidIdentityNonceError)]
+impl InvalidIdentityNonceErrorWasm {
+    #[wasm_bindgen(js_name=getIdentityId)]
+    pub fn identity_id(&self) -> Buffer {
+        Buffer::from_bytes(self.inner.identity_id().as_bytes())
+    }
+
+    #[wasm_bindgen(js_name=getCurrentNonce)]
+    pub fn current_nonce(&self) -> Number {
+        // It might be overflow
+        Number::from(self.inner.current_nonce() as f64)
+    }
+
+    #[wasm_bindgen(js_name=getSettingNonce)]
+    pub fn setting_nonce(&self) -> Number {
+        // It might be overflow
+        Number::from(self.inner.setting_nonce() as f64)
+    }
+
+    #[wasm_bindgen(js_name=getCode)]
+    pub fn get_code(&self) -> u32 {
+        ConsensusError::from(self.inner.clone()).code()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn message(&self) -> String {
+        self.inner.to_string()
+    }
+
+    #[wasm_bindgen(js_name=serialize)]
+    pub fn serialize(&self) -> Result<Buffer, JsError> {
+        let bytes = ConsensusError::from(self.inner.clone())
+            .serialize()
+            .map_err(JsError::from)?;
+
+        Ok(Buffer::from_bytes(bytes.as_slice()))
+    }
+}
@@ -4,6 +4,9 @@ mod identity_already_exists_error;
 mod identity_public_key_disabled_at_window_violation_error;
 mod identity_public_key_is_disabled_error;
 mod identity_public_key_is_read_only_error;
+mod identity_public_key_valid_until_in_past_error;
+mod invalid_identity_contract_nonce_error;
+mod invalid_identity_nonce_error;
 mod invalid_identity_public_key_id_error;
 mod invalid_identity_revision_error;
 mod max_identity_public_key_limit_reached_error;
@@ -15,6 +18,9 @@ pub use identity_already_exists_error::*;
 pub use identity_public_key_disabled_at_window_violation_error::*;
 pub use identity_public_key_is_disabled_error::*;
 pub use identity_public_key_is_read_only_error::*;
+pub use identity_public_key_valid_until_in_past_error::*;
+pub use invalid_identity_contract_nonce_error::*;
+pub use invalid_identity_nonce_error::*;
 pub use invalid_identity_public_key_id_error::*;
 pub use invalid_identity_revision_error::*;
 pub use max_identity_public_key_limit_reached_error::*;
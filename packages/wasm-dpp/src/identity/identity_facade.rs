@@ -4,6 +4,7 @@ use std::sync::Arc;
 
 use wasm_bindgen::prelude::*;
 
+use dpp::identity::state_transition::identity_credit_transfer_transition::IdentityCreditTransferRecipient;
 use dpp::identity::validation::PublicKeysValidator;
 use dpp::identity::{Identity, IdentityFacade};
 
@@ -198,12 +199,16 @@ impl IdentityFacadeWasm {
         identity_id: &IdentifierWrapper,
         recipient_id: &IdentifierWrapper,
         amount: u64,
+        nonce: u64,
     ) -> Result<IdentityCreditTransferTransitionWasm, JsValue> {
         self.0
             .create_identity_credit_transfer_transition(
                 identity_id.to_owned().into(),
-                recipient_id.to_owned().into(),
-                amount,
+                vec![IdentityCreditTransferRecipient::new(
+                    recipient_id.to_owned().into(),
+                    amount,
+                )],
+                nonce,
             )
             .map(Into::into)
             .with_js_error()
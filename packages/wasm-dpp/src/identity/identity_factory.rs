@@ -13,6 +13,7 @@ use crate::{
 };
 use dpp::dashcore::{consensus, InstantLock, Transaction};
 use dpp::identity::factory::IdentityFactory;
+use dpp::identity::state_transition::identity_credit_transfer_transition::IdentityCreditTransferRecipient;
 
 use dpp::prelude::Identity;
 
@@ -184,12 +185,16 @@ impl IdentityFactoryWasm {
         identity_id: &IdentifierWrapper,
         recipient_id: &IdentifierWrapper,
         amount: u64,
+        nonce: u64,
     ) -> Result<IdentityCreditTransferTransitionWasm, JsValue> {
         self.0
             .create_identity_credit_transfer_transition(
                 identity_id.to_owned().into(),
-                recipient_id.to_owned().into(),
-                amount,
+                vec![IdentityCreditTransferRecipient::new(
+                    recipient_id.to_owned().into(),
+                    amount,
+                )],
+                nonce,
             )
             .map(Into::into)
             .with_js_error()
@@ -120,6 +120,19 @@ impl IdentityPublicKeyWasm {
             .map(|timestamp| js_sys::Date::new(&JsValue::from_f64(timestamp as f64)))
     }
 
+    #[wasm_bindgen(js_name=setValidUntil)]
+    pub fn set_valid_until(&mut self, timestamp: js_sys::Date) {
+        self.0
+            .set_valid_until(timestamp.get_time() as TimestampMillis);
+    }
+
+    #[wasm_bindgen(js_name=getValidUntil)]
+    pub fn get_valid_until(&self) -> Option<js_sys::Date> {
+        self.0
+            .valid_until
+            .map(|timestamp| js_sys::Date::new(&JsValue::from_f64(timestamp as f64)))
+    }
+
     #[wasm_bindgen(js_name=hash)]
     pub fn hash(&self) -> Result<Vec<u8>, JsValue> {
         self.0.hash().map(|result| result.to_vec()).with_js_error()
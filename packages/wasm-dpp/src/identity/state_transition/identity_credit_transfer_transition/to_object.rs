@@ -1,8 +1,11 @@
 use dpp::identity::KeyID;
+use dpp::prelude::IdentityNonce;
 use dpp::state_transition::StateTransitionIdentitySigned;
 use dpp::{
     identifier::Identifier,
-    identity::state_transition::identity_credit_transfer_transition::IdentityCreditTransferTransition,
+    identity::state_transition::identity_credit_transfer_transition::{
+        IdentityCreditTransferRecipient, IdentityCreditTransferTransition,
+    },
     state_transition::StateTransitionLike,
 };
 use serde::Deserialize;
@@ -19,8 +22,8 @@ pub struct ToObject {
     pub transition_type: u8,
     pub protocol_version: u32,
     pub identity_id: Identifier,
-    pub recipient_id: Identifier,
-    pub amount: u64,
+    pub recipients: Vec<IdentityCreditTransferRecipient>,
+    pub nonce: IdentityNonce,
     pub signature: Option<Vec<u8>>,
     pub signature_public_key_id: Option<KeyID>,
 }
@@ -33,8 +36,8 @@ pub fn to_object_struct(
         transition_type: transition.get_type() as u8,
         protocol_version: transition.get_protocol_version(),
         identity_id: *transition.get_identity_id(),
-        recipient_id: *transition.get_recipient_id(),
-        amount: transition.get_amount(),
+        recipients: transition.get_recipients().clone(),
+        nonce: transition.get_nonce(),
         ..ToObject::default()
     };
 
@@ -56,14 +56,9 @@ impl IdentityCreditTransferTransitionWasm {
         self.get_identity_id()
     }
 
-    #[wasm_bindgen(getter, js_name=recipientId)]
-    pub fn recipient_id(&self) -> IdentifierWrapper {
-        self.get_recipient_id()
-    }
-
-    #[wasm_bindgen(getter, js_name=amount)]
-    pub fn amount(&self) -> u64 {
-        self.0.get_amount()
+    #[wasm_bindgen(getter, js_name=recipients)]
+    pub fn recipients(&self) -> Result<js_sys::Array, JsValue> {
+        self.get_recipients()
     }
 
     #[wasm_bindgen(js_name=getIdentityId)]
@@ -71,19 +66,58 @@ impl IdentityCreditTransferTransitionWasm {
         (*self.0.get_identity_id()).into()
     }
 
-    #[wasm_bindgen(js_name=getRecipientId)]
-    pub fn get_recipient_id(&self) -> IdentifierWrapper {
-        (*self.0.get_recipient_id()).into()
+    #[wasm_bindgen(js_name=getRecipients)]
+    pub fn get_recipients(&self) -> Result<js_sys::Array, JsValue> {
+        self.0
+            .get_recipients()
+            .iter()
+            .map(|recipient| {
+                let js_recipient = js_sys::Object::new();
+
+                js_sys::Reflect::set(
+                    &js_recipient,
+                    &"recipientId".to_owned().into(),
+                    &IdentifierWrapper::from(recipient.recipient_id).into(),
+                )?;
+
+                js_sys::Reflect::set(
+                    &js_recipient,
+                    &"amount".to_owned().into(),
+                    &JsValue::from_f64(recipient.amount as f64),
+                )?;
+
+                Ok::<JsValue, JsValue>(js_recipient.into())
+            })
+            .collect()
     }
 
-    #[wasm_bindgen(js_name=getAmount)]
-    pub fn get_amount(&self) -> f64 {
-        self.0.get_amount() as f64
+    #[wasm_bindgen(js_name=addRecipient)]
+    pub fn add_recipient(&mut self, recipient_id: &IdentifierWrapper, amount: f64) {
+        self.0
+            .add_recipient(recipient_id.to_owned().into(), amount as u64);
     }
 
-    #[wasm_bindgen(js_name=setAmount)]
-    pub fn set_amount(&mut self, amount: f64) {
-        self.0.set_amount(amount as u64);
+    #[wasm_bindgen(getter, js_name=nonce)]
+    pub fn nonce(&self) -> f64 {
+        self.get_nonce()
+    }
+
+    #[wasm_bindgen(js_name=getNonce)]
+    pub fn get_nonce(&self) -> f64 {
+        self.0.get_nonce() as f64
+    }
+
+    #[wasm_bindgen(js_name=setNonce)]
+    pub fn set_nonce(&mut self, nonce: f64) {
+        self.0.set_nonce(nonce as u64);
+    }
+
+    #[wasm_bindgen(js_name=getTotalAmount)]
+    pub fn get_total_amount(&self) -> Result<f64, JsValue> {
+        self.0
+            .get_total_amount()
+            .map(|total_amount| total_amount as f64)
+            .ok_or_else(|| JsError::new("total amount overflow").into())
     }
 
     #[wasm_bindgen(js_name=toObject)]
@@ -139,16 +173,32 @@ impl IdentityCreditTransferTransitionWasm {
             &Buffer::from_bytes(object.identity_id.to_buffer().as_slice()),
         )?;
 
-        js_sys::Reflect::set(
-            &js_object,
-            &"recipientId".to_owned().into(),
-            &Buffer::from_bytes(object.recipient_id.to_buffer().as_slice()),
-        )?;
+        let recipients = js_sys::Array::new();
+
+        for recipient in object.recipients {
+            let js_recipient = js_sys::Object::new();
+
+            js_sys::Reflect::set(
+                &js_recipient,
+                &"recipientId".to_owned().into(),
+                &Buffer::from_bytes(recipient.recipient_id.to_buffer().as_slice()),
+            )?;
+
+            js_sys::Reflect::set(
+                &js_recipient,
+                &"amount".to_owned().into(),
+                &JsValue::from_f64(recipient.amount as f64),
+            )?;
+
+            recipients.push(&js_recipient);
+        }
+
+        js_sys::Reflect::set(&js_object, &"recipients".to_owned().into(), &recipients)?;
 
         js_sys::Reflect::set(
             &js_object,
-            &"amount".to_owned().into(),
-            &JsValue::from_f64(object.amount as f64),
+            &"nonce".to_owned().into(),
+            &JsValue::from_f64(object.nonce as f64),
         )?;
 
         Ok(js_object.into())
@@ -212,18 +262,34 @@ impl IdentityCreditTransferTransitionWasm {
             &identity_id.into(),
         )?;
 
-        let recipient_id = object.recipient_id.to_string(Encoding::Base58);
+        let recipients = js_sys::Array::new();
 
-        js_sys::Reflect::set(
-            &js_object,
-            &"recipientId".to_owned().into(),
-            &recipient_id.into(),
-        )?;
+        for recipient in object.recipients {
+            let js_recipient = js_sys::Object::new();
+
+            let recipient_id = recipient.recipient_id.to_string(Encoding::Base58);
+
+            js_sys::Reflect::set(
+                &js_recipient,
+                &"recipientId".to_owned().into(),
+                &recipient_id.into(),
+            )?;
+
+            js_sys::Reflect::set(
+                &js_recipient,
+                &"amount".to_owned().into(),
+                &JsValue::from_f64(recipient.amount as f64),
+            )?;
+
+            recipients.push(&js_recipient);
+        }
+
+        js_sys::Reflect::set(&js_object, &"recipients".to_owned().into(), &recipients)?;
 
         js_sys::Reflect::set(
             &js_object,
-            &"amount".to_owned().into(),
-            &JsValue::from_f64(object.amount as f64),
+            &"nonce".to_owned().into(),
+            &JsValue::from_f64(object.nonce as f64),
         )?;
 
         Ok(js_object.into())
@@ -47,6 +47,8 @@ struct IdentityUpdateTransitionParams {
     add_public_keys: Option<Vec<IdentityPublicKeyInCreation>>,
     disable_public_keys: Option<Vec<KeyID>>,
     public_keys_disabled_at: Option<TimestampMillis>,
+    expire_public_keys: Option<Vec<KeyID>>,
+    public_keys_valid_until: Option<TimestampMillis>,
 }
 
 impl From<IdentityUpdateTransition> for IdentityUpdateTransitionWasm {
@@ -164,6 +166,45 @@ impl IdentityUpdateTransitionWasm {
         }
     }
 
+    #[wasm_bindgen(js_name=getPublicKeyIdsToExpire)]
+    pub fn get_public_key_ids_to_expire(&self) -> Vec<JsValue> {
+        self.0
+            .get_public_key_ids_to_expire()
+            .iter()
+            .map(|key| JsValue::from_f64(key.to_owned() as f64))
+            .collect()
+    }
+
+    #[wasm_bindgen(js_name=setPublicKeyIdsToExpire)]
+    pub fn set_public_key_ids_to_expire(&mut self, public_key_ids: Option<Vec<u32>>) {
+        let mut keys = vec![];
+        if let Some(public_key_ids) = public_key_ids {
+            keys = public_key_ids
+                .iter()
+                .map(|key| key.to_owned() as KeyID)
+                .collect::<Vec<KeyID>>();
+        }
+
+        self.0.set_public_key_ids_to_expire(keys);
+    }
+
+    #[wasm_bindgen(js_name=getPublicKeysValidUntil)]
+    pub fn get_public_keys_valid_until(&self) -> Option<js_sys::Date> {
+        self.0
+            .get_public_keys_valid_until()
+            .map(|timestamp| js_sys::Date::new(&JsValue::from_f64(timestamp as f64)))
+    }
+
+    #[wasm_bindgen(js_name=setPublicKeysValidUntil)]
+    pub fn set_public_keys_valid_until(&mut self, timestamp: Option<js_sys::Date>) {
+        if let Some(timestamp) = timestamp {
+            self.0
+                .set_public_keys_valid_until(Some(timestamp.get_time() as TimestampMillis));
+        } else {
+            self.0.set_public_keys_valid_until(None);
+        }
+    }
+
     #[wasm_bindgen(js_name=getType)]
     pub fn get_type(&self) -> u8 {
         self.0.get_type() as u8
@@ -276,6 +317,27 @@ impl IdentityUpdateTransitionWasm {
             )?;
         }
 
+        if let Some(public_key_ids_to_expire) = object.public_key_ids_to_expire {
+            let public_key_ids_to_expire = public_key_ids_to_expire
+                .into_iter()
+                .map(|key| JsValue::from_f64(key as f64))
+                .collect::<js_sys::Array>();
+
+            js_sys::Reflect::set(
+                &js_object,
+                &"expirePublicKeys".to_owned().into(),
+                &public_key_ids_to_expire.into(),
+            )?;
+        }
+
+        if let Some(timestamp) = object.public_keys_valid_until {
+            js_sys::Reflect::set(
+                &js_object,
+                &"publicKeysValidUntil".to_owned().into(),
+                &JsValue::from_f64(timestamp as f64),
+            )?;
+        }
+
         js_sys::Reflect::set(
             &js_object,
             &"identityId".to_owned().into(),
@@ -374,6 +436,27 @@ impl IdentityUpdateTransitionWasm {
             )?;
         }
 
+        if let Some(public_key_ids_to_expire) = object.public_key_ids_to_expire {
+            let public_key_ids_to_expire = public_key_ids_to_expire
+                .into_iter()
+                .map(|key| JsValue::from_f64(key as f64))
+                .collect::<js_sys::Array>();
+
+            js_sys::Reflect::set(
+                &js_object,
+                &"expirePublicKeys".to_owned().into(),
+                &public_key_ids_to_expire.into(),
+            )?;
+        }
+
+        if let Some(timestamp) = object.public_keys_valid_until {
+            js_sys::Reflect::set(
+                &js_object,
+                &"publicKeysValidUntil".to_owned().into(),
+                &JsValue::from_f64(timestamp as f64),
+            )?;
+        }
+
         let identity_id = object.identity_id.to_string(Encoding::Base58);
 
         js_sys::Reflect::set(
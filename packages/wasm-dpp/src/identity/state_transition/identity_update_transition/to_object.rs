@@ -25,6 +25,8 @@ pub struct ToObject {
     pub public_keys_disabled_at: Option<u64>,
     pub public_keys_to_add: Option<Vec<IdentityPublicKeyInCreation>>,
     pub public_key_ids_to_disable: Option<Vec<KeyID>>,
+    pub public_key_ids_to_expire: Option<Vec<KeyID>>,
+    pub public_keys_valid_until: Option<u64>,
     pub identity_id: Identifier,
 }
 
@@ -62,5 +64,11 @@ pub fn to_object_struct(
         to_object.public_key_ids_to_disable = Some(public_key_ids_to_disable.to_owned());
     }
 
+    let public_key_ids_to_expire = transition.get_public_key_ids_to_expire();
+    if !public_key_ids_to_expire.is_empty() {
+        to_object.public_key_ids_to_expire = Some(public_key_ids_to_expire.to_owned());
+        to_object.public_keys_valid_until = transition.get_public_keys_valid_until();
+    }
+
     to_object
 }
@@ -29,11 +29,12 @@ describe('IdentityCreditTransferTransition', () => {
       expect(stateTransition.getIdentityId().toBuffer()).to.be.deep.equal(
         rawStateTransition.identityId,
       );
-      expect(stateTransition.getRecipientId().toBuffer()).to.be.deep.equal(
-        rawStateTransition.recipientId,
+      const [recipient] = stateTransition.getRecipients();
+      expect(recipient.recipientId.toBuffer()).to.be.deep.equal(
+        rawStateTransition.recipients[0].recipientId,
       );
-      expect(stateTransition.getAmount()).to.be.equal(
-        rawStateTransition.amount,
+      expect(recipient.amount).to.be.equal(
+        rawStateTransition.recipients[0].amount,
       );
     });
   });
@@ -52,14 +53,46 @@ describe('IdentityCreditTransferTransition', () => {
     });
   });
 
-  describe('#getRecipientId', () => {
-    it('should return recipient id', () => {
-      expect(stateTransition.getRecipientId().toBuffer()).to.deep.equal(
-        rawStateTransition.recipientId,
+  describe('#getRecipients', () => {
+    it('should return recipients', () => {
+      const recipients = stateTransition.getRecipients();
+
+      expect(recipients.length).to.equal(1);
+      expect(recipients[0].recipientId.toBuffer()).to.deep.equal(
+        rawStateTransition.recipients[0].recipientId,
       );
     });
   });
 
+  describe('#getNonce', () => {
+    it('should return nonce', () => {
+      expect(stateTransition.getNonce()).to.equal(rawStateTransition.nonce);
+    });
+  });
+
+  describe('#setNonce', () => {
+    it('should set nonce', () => {
+      stateTransition.setNonce(5);
+
+      expect(stateTransition.getNonce()).to.equal(5);
+    });
+  });
+
+  describe('#addRecipient', () => {
+    it('should add a recipient and update total amount', () => {
+      const recipientId = new Identifier(Buffer.alloc(32).fill(1));
+
+      stateTransition.addRecipient(recipientId, 2000);
+
+      const recipients = stateTransition.getRecipients();
+
+      expect(recipients.length).to.equal(2);
+      expect(recipients[1].recipientId.toBuffer()).to.deep.equal(recipientId.toBuffer());
+      expect(recipients[1].amount).to.equal(2000);
+      expect(stateTransition.getTotalAmount()).to.equal(3000);
+    });
+  });
+
   describe('#toObject', () => {
     it('should return raw state transition', () => {
       rawStateTransition = stateTransition.toObject();
@@ -68,8 +101,8 @@ describe('IdentityCreditTransferTransition', () => {
         protocolVersion: getLatestProtocolVersion(),
         type: StateTransitionTypes.IdentityCreditTransfer,
         identityId: rawStateTransition.identityId,
-        recipientId: rawStateTransition.recipientId,
-        amount: rawStateTransition.amount,
+        recipients: rawStateTransition.recipients,
+        nonce: 1,
         signature: Buffer.alloc(32).fill(0),
         signaturePublicKeyId: 0,
       });
@@ -82,8 +115,8 @@ describe('IdentityCreditTransferTransition', () => {
         protocolVersion: getLatestProtocolVersion(),
         type: StateTransitionTypes.IdentityCreditTransfer,
         identityId: rawStateTransition.identityId,
-        recipientId: rawStateTransition.recipientId,
-        amount: rawStateTransition.amount,
+        recipients: rawStateTransition.recipients,
+        nonce: 1,
       });
     });
   });
@@ -96,8 +129,11 @@ describe('IdentityCreditTransferTransition', () => {
         protocolVersion: getLatestProtocolVersion(),
         type: StateTransitionTypes.IdentityCreditTransfer,
         identityId: new Identifier(rawStateTransition.identityId).toString(),
-        recipientId: new Identifier(rawStateTransition.recipientId).toString(),
-        amount: rawStateTransition.amount,
+        recipients: [{
+          recipientId: new Identifier(rawStateTransition.recipients[0].recipientId).toString(),
+          amount: rawStateTransition.recipients[0].amount,
+        }],
+        nonce: 1,
         signature: Buffer.alloc(32).fill(0).toString('base64'),
         signaturePublicKeyId: 0,
       });
@@ -115,7 +151,9 @@ describe('IdentityCreditTransferTransition', () => {
       expect(identityId.toBuffer()).to.be.deep.equal(rawStateTransition.identityId);
 
       expect(recipientId).to.be.an.instanceOf(Identifier);
-      expect(recipientId.toBuffer()).to.be.deep.equal(rawStateTransition.recipientId);
+      expect(recipientId.toBuffer()).to.be.deep.equal(
+        rawStateTransition.recipients[0].recipientId,
+      );
     });
   });
 